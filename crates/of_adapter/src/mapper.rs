@@ -63,6 +63,7 @@ pub fn build_replay_doc(engine_result: &MatchResult) -> (ReplayDoc, Vec<GoalHeat
             rosters,
             timeline,
             tactics,
+localized_text: Vec::new(),
         },
         goal_heat_samples,
     )
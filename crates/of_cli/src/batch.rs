@@ -0,0 +1,240 @@
+//! `batch`: request templates × seeds → one row per match
+//!
+//! Rows carry the attestation `result_hash` (score + event log), so a saved batch
+//! output doubles as a regression baseline: re-run with `--baseline` and any
+//! behaviour change shows up as a mismatch.
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use of_core::api::{attest_result, simulate_matches_parallel, BatchMatchOutcome};
+
+use crate::output::{self, Format};
+
+/// Matches simulated per parallel call (bounds memory on long seed ranges)
+const CHUNK_SIZE: usize = 256;
+
+/// One simulated match (timing is left out so outputs diff cleanly)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BatchRow {
+    pub request: String,
+    pub seed: u64,
+    pub score_home: u8,
+    pub score_away: u8,
+    pub shots_home: u16,
+    pub shots_away: u16,
+    pub possession_home: f32,
+    pub xg_home: f32,
+    pub xg_away: f32,
+    pub result_hash: String,
+    pub error: String,
+}
+
+pub struct BatchReport {
+    pub rows: Vec<BatchRow>,
+    pub wall_clock_ms: f64,
+}
+
+impl BatchReport {
+    pub fn failed(&self) -> usize {
+        self.rows.iter().filter(|row| !row.error.is_empty()).count()
+    }
+}
+
+/// Simulate every template in `input` with every seed (template-major order)
+pub fn run(input: &Path, seeds: &[u64], threads: usize) -> Result<BatchReport> {
+    let templates = load_templates(input)?;
+    let started = Instant::now();
+
+    let jobs: Vec<(&str, u64, serde_json::Value)> = templates
+        .iter()
+        .flat_map(|(name, template)| {
+            seeds.iter().map(move |&seed| {
+                let mut request = template.clone();
+                request["seed"] = seed.into();
+                (name.as_str(), seed, request)
+            })
+        })
+        .collect();
+
+    let mut rows = Vec::with_capacity(jobs.len());
+    for chunk in jobs.chunks(CHUNK_SIZE) {
+        let requests: Vec<serde_json::Value> = chunk.iter().map(|(_, _, r)| r.clone()).collect();
+        let response = simulate_matches_parallel(requests, threads).map_err(anyhow::Error::msg)?;
+        for (outcome, (name, seed, request)) in response.results.iter().zip(chunk) {
+            rows.push(row(name, *seed, request, outcome));
+        }
+        eprintln!("simulated {}/{}", rows.len(), jobs.len());
+    }
+
+    Ok(BatchReport {
+        rows,
+        wall_clock_ms: started.elapsed().as_secs_f64() * 1000.0,
+    })
+}
+
+fn row(
+    name: &str,
+    seed: u64,
+    request: &serde_json::Value,
+    outcome: &BatchMatchOutcome,
+) -> BatchRow {
+    let mut row = BatchRow {
+        request: name.to_string(),
+        seed,
+        score_home: 0,
+        score_away: 0,
+        shots_home: 0,
+        shots_away: 0,
+        possession_home: 0.0,
+        xg_home: 0.0,
+        xg_away: 0.0,
+        result_hash: String::new(),
+        error: outcome.error.clone().unwrap_or_default(),
+    };
+    if let Some(result) = &outcome.result {
+        let stats = &result.statistics;
+        row.score_home = result.score_home;
+        row.score_away = result.score_away;
+        row.shots_home = stats.shots_home;
+        row.shots_away = stats.shots_away;
+        row.possession_home = stats.possession_home;
+        row.xg_home = stats.xg_home;
+        row.xg_away = stats.xg_away;
+        match attest_result(request, result) {
+            Ok(attestation) => row.result_hash = attestation.result_hash,
+            Err(e) => row.error = e,
+        }
+    }
+    row
+}
+
+/// `(file stem, request)` for a single file or every `*.json` in a directory, sorted by name
+fn load_templates(input: &Path) -> Result<Vec<(String, serde_json::Value)>> {
+    let mut paths: Vec<PathBuf> = if input.is_dir() {
+        std::fs::read_dir(input)
+            .with_context(|| format!("Failed to read {}", input.display()))?
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .collect()
+    } else {
+        vec![input.to_path_buf()]
+    };
+    paths.sort();
+    if paths.is_empty() {
+        bail!("No *.json requests in {}", input.display());
+    }
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let json = output::read_to_string(&path)?;
+            let request: serde_json::Value = serde_json::from_str(&json)
+                .with_context(|| format!("Invalid JSON in {}", path.display()))?;
+            if !request.is_object() {
+                bail!("{} is not a match request object", path.display());
+            }
+            let name = path
+                .file_stem()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .into_owned();
+            Ok((name, request))
+        })
+        .collect()
+}
+
+pub fn render(rows: &[BatchRow], format: Format) -> Result<String> {
+    match format {
+        Format::Json => output::to_json(rows),
+        Format::Csv => output::to_csv(rows),
+    }
+}
+
+/// Rows from an earlier `batch` output (`.json` = JSON array, anything else = CSV)
+pub fn load_baseline(path: &Path) -> Result<Vec<BatchRow>> {
+    let content = output::read_to_string(path)?;
+    if path.extension().is_some_and(|ext| ext == "json") {
+        return serde_json::from_str(&content)
+            .with_context(|| format!("Invalid baseline {}", path.display()));
+    }
+    csv::Reader::from_reader(content.as_bytes())
+        .deserialize()
+        .collect::<Result<_, _>>()
+        .with_context(|| format!("Invalid baseline {}", path.display()))
+}
+
+/// Human-readable differences on score, result hash or error, keyed by (request, seed)
+pub fn compare(expected: &[BatchRow], actual: &[BatchRow]) -> Vec<String> {
+    let actual: HashMap<(&str, u64), &BatchRow> = actual
+        .iter()
+        .map(|row| ((row.request.as_str(), row.seed), row))
+        .collect();
+
+    let mut diffs = Vec::new();
+    for want in expected {
+        let key = format!("{} seed {}", want.request, want.seed);
+        let Some(got) = actual.get(&(want.request.as_str(), want.seed)) else {
+            diffs.push(format!("{}: missing from this run", key));
+            continue;
+        };
+        if (want.score_home, want.score_away) != (got.score_home, got.score_away) {
+            diffs.push(format!(
+                "{}: score {}-{} → {}-{}",
+                key, want.score_home, want.score_away, got.score_home, got.score_away
+            ));
+        } else if want.result_hash != got.result_hash {
+            diffs.push(format!("{}: event log changed (result_hash)", key));
+        } else if want.error != got.error {
+            diffs.push(format!("{}: error {:?} → {:?}", key, want.error, got.error));
+        }
+    }
+    diffs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_row(seed: u64, score_home: u8, result_hash: &str) -> BatchRow {
+        BatchRow {
+            request: "derby".to_string(),
+            seed,
+            score_home,
+            score_away: 1,
+            shots_home: 12,
+            shots_away: 9,
+            possession_home: 54.5,
+            xg_home: 1.4,
+            xg_away: 0.8,
+            result_hash: result_hash.to_string(),
+            error: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_csv_baseline_round_trips_and_flags_changes() {
+        let baseline = vec![
+            sample_row(1, 2, "aa"),
+            sample_row(2, 0, "bb"),
+            sample_row(3, 1, "cc"),
+        ];
+        let csv = render(&baseline, Format::Csv).unwrap();
+        let parsed: Vec<BatchRow> = csv::Reader::from_reader(csv.as_bytes())
+            .deserialize()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(parsed, baseline);
+        assert!(compare(&parsed, &baseline).is_empty());
+
+        let current = vec![sample_row(1, 3, "aa"), sample_row(2, 0, "zz")];
+        let diffs = compare(&baseline, &current);
+        assert_eq!(diffs.len(), 3);
+        assert!(diffs[0].contains("score 2-1 → 3-1"));
+        assert!(diffs[1].contains("result_hash"));
+        assert!(diffs[2].contains("missing"));
+    }
+}
@@ -0,0 +1,111 @@
+//! `calibrate`: calibration suite with targets from a TOML (or JSON) file
+//!
+//! The file has the `CalibrationTargets` shape; a metric left out is not checked:
+//!
+//! ```toml
+//! goals_per_match = { min = 2.2, max = 3.4 }
+//! shots_per_match = { min = 20.0, max = 30.0 }
+//! goal_distribution = [
+//!     { min = 0.03, max = 0.13 }, { min = 0.12, max = 0.24 }, { min = 0.17, max = 0.29 },
+//!     { min = 0.16, max = 0.28 }, { min = 0.09, max = 0.21 }, { min = 0.07, max = 0.21 },
+//! ]
+//! ```
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::path::Path;
+
+use of_core::api::json_api::match_plan_from_match_request_v2_json;
+use of_core::calibration::{
+    run_calibration_suite, CalibrationReport, CalibrationSuiteConfig, CalibrationTargets,
+};
+
+use crate::output::{self, Format};
+
+/// One CSV row per metric
+#[derive(Debug, Serialize)]
+struct CheckRow<'a> {
+    metric: &'a str,
+    value: f32,
+    min: f32,
+    max: f32,
+    passed: bool,
+}
+
+pub fn run(
+    targets: &Path,
+    request: &Path,
+    matches: u32,
+    base_seed: u64,
+) -> Result<CalibrationReport> {
+    let targets = load_targets(targets)?;
+    let request_json = output::read_to_string(request)?;
+    let (plan, _) =
+        match_plan_from_match_request_v2_json(&request_json).map_err(anyhow::Error::msg)?;
+
+    let mut config = CalibrationSuiteConfig::new(plan.home_team, plan.away_team);
+    config.matches = matches;
+    config.base_seed = base_seed;
+    config.targets = targets;
+    eprintln!("running {} matches from seed {}", matches, base_seed);
+    run_calibration_suite(&config).map_err(anyhow::Error::msg)
+}
+
+/// `.json` → JSON, anything else → TOML
+pub fn load_targets(path: &Path) -> Result<CalibrationTargets> {
+    let content = output::read_to_string(path)?;
+    if path.extension().is_some_and(|ext| ext == "json") {
+        return CalibrationTargets::from_json_str(&content).map_err(anyhow::Error::msg);
+    }
+    parse_toml_targets(&content).with_context(|| format!("Invalid targets {}", path.display()))
+}
+
+fn parse_toml_targets(content: &str) -> Result<CalibrationTargets> {
+    Ok(toml::from_str(content)?)
+}
+
+pub fn render(report: &CalibrationReport, format: Format) -> Result<String> {
+    match format {
+        Format::Json => report.to_json().map_err(anyhow::Error::msg),
+        Format::Csv => {
+            let rows: Vec<CheckRow> = report
+                .checks
+                .iter()
+                .map(|c| CheckRow {
+                    metric: &c.metric,
+                    value: c.value,
+                    min: c.target.min,
+                    max: c.target.max,
+                    passed: c.passed,
+                })
+                .collect();
+            output::to_csv(&rows)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use of_core::calibration::MetricRange;
+
+    #[test]
+    fn test_toml_targets_leave_missing_metrics_unchecked() {
+        let targets = parse_toml_targets(
+            r#"
+            goals_per_match = { min = 2.0, max = 3.5 }
+
+            [fouls_per_match]
+            min = 15.0
+            max = 32.0
+            "#,
+        )
+        .unwrap();
+        assert_eq!(targets.goals_per_match, Some(MetricRange::new(2.0, 3.5)));
+        assert_eq!(targets.fouls_per_match, Some(MetricRange::new(15.0, 32.0)));
+        assert_eq!(targets.shots_per_match, None);
+        assert_eq!(targets.goal_distribution, None);
+
+        assert!(parse_toml_targets("goals_per_match = { min = 2.0 }").is_err());
+    }
+}
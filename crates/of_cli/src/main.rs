@@ -0,0 +1,218 @@
+//! of_cli - headless of_core runner
+//!
+//! `simulate`   : one request → result JSON
+//! `batch`      : request templates × seed range → per-match rows (regression baselines)
+//! `replay`     : MRB0 replay checks (`replay verify file.mrb`)
+//! `calibrate`  : calibration suite against TOML/JSON targets
+//!
+//! Reports go to `--out` (or stdout); progress and diagnostics go to stderr. The engine's
+//! own debug logging also prints to stdout, so CI should pass `--out`.
+//! A failed check (baseline mismatch, invalid replay, calibration miss) exits non-zero.
+
+use anyhow::Result;
+use clap::{Parser, Subcommand};
+use std::path::PathBuf;
+
+mod batch;
+mod calibrate;
+mod output;
+mod replay;
+mod seeds;
+
+use output::Format;
+
+#[derive(Parser)]
+#[command(name = "of_cli")]
+#[command(about = "Headless match simulation, regression baselines and calibration", long_about = None)]
+#[command(version)]
+struct Cli {
+    #[command(subcommand)]
+    command: Commands,
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    /// Simulate one MatchRequest (v1), MatchRequestV2 or MatchRequestV3 JSON file
+    Simulate {
+        /// Request JSON file
+        request: PathBuf,
+
+        /// Output file (default: stdout)
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+
+    /// Simulate every request template in a directory for a range of seeds
+    Batch {
+        /// Directory of MatchRequestV2 / V3 JSON files (or a single file)
+        input: PathBuf,
+
+        /// Seeds: `1..1000` (end exclusive), `1..=1000`, `7` or `1,5,9`
+        #[arg(long, default_value = "1..=100")]
+        seeds: String,
+
+        /// Worker threads (0 = one per logical CPU)
+        #[arg(long, default_value_t = 0)]
+        threads: usize,
+
+        /// Output format
+        #[arg(long, value_enum, default_value_t = Format::Csv)]
+        format: Format,
+
+        /// Output file (default: stdout)
+        #[arg(long)]
+        out: Option<PathBuf>,
+
+        /// Previous batch output (CSV or JSON); any score/result change fails the run
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+    },
+
+    /// Replay file tools
+    Replay {
+        #[command(subcommand)]
+        command: ReplayCommands,
+    },
+
+    /// Run the calibration suite against target ranges
+    Calibrate {
+        /// Targets file (`.toml` or `.json`, `CalibrationTargets` shape)
+        targets: PathBuf,
+
+        /// MatchRequestV2 fixture whose teams play every match (its seed is ignored)
+        #[arg(long)]
+        request: PathBuf,
+
+        /// Number of matches
+        #[arg(long, default_value_t = 1000)]
+        matches: u32,
+
+        /// Match `i` uses seed `base_seed + i`
+        #[arg(long, default_value_t = 0)]
+        base_seed: u64,
+
+        /// Output format
+        #[arg(long, value_enum, default_value_t = Format::Json)]
+        format: Format,
+
+        /// Output file (default: stdout)
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+enum ReplayCommands {
+    /// Decode an MRB0 replay and check frame order, frame index and (optionally) determinism
+    Verify {
+        /// MRB0 replay file
+        file: PathBuf,
+
+        /// MatchRequestV2 that produced the replay; re-simulates and compares bytes
+        #[arg(long)]
+        request: Option<PathBuf>,
+
+        /// Output format
+        #[arg(long, value_enum, default_value_t = Format::Json)]
+        format: Format,
+
+        /// Output file (default: stdout)
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Commands::Simulate { request, out } => {
+            let request_json = output::read_to_string(&request)?;
+            let result_json = simulate(&request_json)?;
+            output::write(out.as_deref(), &result_json)?;
+        }
+
+        Commands::Batch {
+            input,
+            seeds,
+            threads,
+            format,
+            out,
+            baseline,
+        } => {
+            let seeds = seeds::parse_seeds(&seeds)?;
+            let report = batch::run(&input, &seeds, threads)?;
+            eprintln!(
+                "{} matches, {} failed, {:.1}s wall clock",
+                report.rows.len(),
+                report.failed(),
+                report.wall_clock_ms / 1000.0
+            );
+            output::write(out.as_deref(), &batch::render(&report.rows, format)?)?;
+
+            if let Some(baseline) = baseline {
+                let expected = batch::load_baseline(&baseline)?;
+                let diffs = batch::compare(&expected, &report.rows);
+                for diff in &diffs {
+                    eprintln!("MISMATCH {}", diff);
+                }
+                if !diffs.is_empty() {
+                    anyhow::bail!(
+                        "{} match(es) differ from {}",
+                        diffs.len(),
+                        baseline.display()
+                    );
+                }
+                eprintln!("baseline {} matches", baseline.display());
+            }
+        }
+
+        Commands::Replay {
+            command:
+                ReplayCommands::Verify {
+                    file,
+                    request,
+                    format,
+                    out,
+                },
+        } => {
+            let report = replay::verify(&file, request.as_deref())?;
+            output::write(out.as_deref(), &replay::render(&report, format)?)?;
+            if !report.valid {
+                anyhow::bail!("replay {} failed verification", file.display());
+            }
+        }
+
+        Commands::Calibrate {
+            targets,
+            request,
+            matches,
+            base_seed,
+            format,
+            out,
+        } => {
+            let report = calibrate::run(&targets, &request, matches, base_seed)?;
+            output::write(out.as_deref(), &calibrate::render(&report, format)?)?;
+            if !report.passed {
+                let failed: Vec<_> = report.failed_checks().map(|c| c.metric.as_str()).collect();
+                anyhow::bail!("calibration failed: {}", failed.join(", "));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// `schema_version: 2` / `3` → v2 / v3 API, anything else → v1 API
+fn simulate(request_json: &str) -> Result<String> {
+    let request: serde_json::Value = serde_json::from_str(request_json)?;
+    let result = match request
+        .get("schema_version")
+        .and_then(serde_json::Value::as_u64)
+    {
+        Some(2) => of_core::simulate_match_v2_json(request_json),
+        Some(3) => of_core::simulate_match_v3_json(request_json),
+        _ => of_core::simulate_match_json(request_json),
+    };
+    result.map_err(anyhow::Error::msg)
+}
@@ -0,0 +1,188 @@
+//! `replay verify`: structural checks on an MRB0 replay
+//!
+//! - decodes fully
+//! - ball / player frame timestamps and event minutes never go backwards
+//! - v4+: the frame index covers exactly the frames of a full decode
+//! - with `--request`: re-simulating the request gives byte-identical MRB0
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::path::Path;
+
+use of_core::api::json_api::match_plan_from_match_request_v2_json;
+use of_core::engine::MatchEngine;
+use of_core::replay::{decode_mrb0, encode_mrb0, BinaryReplay, ReplayReader, MRB0_INDEXED_VERSION};
+
+use crate::output::{self, Format};
+
+#[derive(Debug, Serialize)]
+pub struct ReplayCheck {
+    pub check: String,
+    pub passed: bool,
+    pub detail: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReplayReport {
+    pub file: String,
+    pub format_version: u8,
+    pub duration_seconds: f32,
+    pub score_home: u8,
+    pub score_away: u8,
+    pub events: usize,
+    pub ball_frames: usize,
+    pub player_frames: usize,
+    pub checks: Vec<ReplayCheck>,
+    pub valid: bool,
+}
+
+pub fn verify(file: &Path, request: Option<&Path>) -> Result<ReplayReport> {
+    let bytes =
+        std::fs::read(file).with_context(|| format!("Failed to read {}", file.display()))?;
+    let replay = decode_mrb0(&bytes).map_err(anyhow::Error::msg)?;
+
+    let mut checks = vec![
+        order_check("ball_frame_order", replay.ball_frames.iter().map(|f| f.t)),
+        player_order_check(&replay),
+        order_check(
+            "event_minute_order",
+            replay.events.iter().map(|e| e.minute as f32),
+        ),
+    ];
+    if replay.format_version >= MRB0_INDEXED_VERSION {
+        checks.push(index_check(&bytes, &replay));
+    }
+    if let Some(request) = request {
+        checks.push(determinism_check(&bytes, request)?);
+    }
+
+    let valid = checks.iter().all(|c| c.passed);
+    Ok(ReplayReport {
+        file: file.display().to_string(),
+        format_version: replay.format_version,
+        duration_seconds: replay.duration_seconds,
+        score_home: replay.score_home,
+        score_away: replay.score_away,
+        events: replay.events.len(),
+        ball_frames: replay.ball_frames.len(),
+        player_frames: replay.players.iter().map(|t| t.frames.len()).sum(),
+        checks,
+        valid,
+    })
+}
+
+/// Passes when `values` never decrease
+fn order_check(name: &str, values: impl Iterator<Item = f32>) -> ReplayCheck {
+    let mut last = f32::MIN;
+    for (i, value) in values.enumerate() {
+        if value < last {
+            return ReplayCheck {
+                check: name.to_string(),
+                passed: false,
+                detail: format!("out of order at #{}", i),
+            };
+        }
+        last = value;
+    }
+    ReplayCheck {
+        check: name.to_string(),
+        passed: true,
+        detail: String::new(),
+    }
+}
+
+/// Frame timestamps within each player track
+fn player_order_check(replay: &BinaryReplay) -> ReplayCheck {
+    for track in &replay.players {
+        let check = order_check("player_frame_order", track.frames.iter().map(|f| f.t));
+        if !check.passed {
+            return ReplayCheck {
+                detail: format!("track {}: {}", track.player_id, check.detail),
+                ..check
+            };
+        }
+    }
+    ReplayCheck {
+        check: "player_frame_order".to_string(),
+        passed: true,
+        detail: String::new(),
+    }
+}
+
+/// Windowed read over the whole match must return every frame
+fn index_check(bytes: &[u8], replay: &BinaryReplay) -> ReplayCheck {
+    let check = |passed: bool, detail: String| ReplayCheck {
+        check: "frame_index".to_string(),
+        passed,
+        detail,
+    };
+    let window = ReplayReader::open(bytes)
+        .and_then(|reader| reader.read_window(0, reader.duration_ms() + 1000));
+    match window {
+        Ok(window) => {
+            let frames = |r: &BinaryReplay| {
+                (
+                    r.ball_frames.len(),
+                    r.players.iter().map(|t| t.frames.len()).sum::<usize>(),
+                )
+            };
+            let (full, indexed) = (frames(replay), frames(&window));
+            check(
+                full == indexed,
+                format!("decoded {:?} frames, index covers {:?}", full, indexed),
+            )
+        }
+        Err(e) => check(false, e),
+    }
+}
+
+/// Re-simulate `request` with position tracking and compare MRB0 bytes
+fn determinism_check(bytes: &[u8], request: &Path) -> Result<ReplayCheck> {
+    let request_json = output::read_to_string(request)?;
+    let (plan, _) =
+        match_plan_from_match_request_v2_json(&request_json).map_err(anyhow::Error::msg)?;
+    let seed = plan.seed;
+    let result = MatchEngine::new(plan)
+        .map_err(anyhow::Error::msg)?
+        .with_position_tracking()
+        .simulate();
+    let expected = encode_mrb0(&BinaryReplay::from_match_result(&result, seed));
+
+    let passed = expected.as_slice() == bytes;
+    let detail = if passed {
+        String::new()
+    } else {
+        let first_diff = expected.iter().zip(bytes).position(|(a, b)| a != b);
+        format!(
+            "re-simulated replay differs ({} vs {} bytes, first difference at {:?})",
+            expected.len(),
+            bytes.len(),
+            first_diff
+        )
+    };
+    Ok(ReplayCheck {
+        check: "determinism".to_string(),
+        passed,
+        detail,
+    })
+}
+
+pub fn render(report: &ReplayReport, format: Format) -> Result<String> {
+    match format {
+        Format::Json => output::to_json(report),
+        Format::Csv => output::to_csv(&report.checks),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_order_check_reports_first_regression() {
+        assert!(order_check("t", [0.0, 0.5, 0.5, 1.0].into_iter()).passed);
+        let failed = order_check("t", [0.0, 1.0, 0.5].into_iter());
+        assert!(!failed.passed);
+        assert_eq!(failed.detail, "out of order at #2");
+    }
+}
@@ -0,0 +1,63 @@
+//! `--seeds` parsing
+
+use anyhow::{bail, Context, Result};
+
+/// Upper bound on seeds per run (a typo like `1..10000000` should not run for a week)
+const MAX_SEEDS: u64 = 1_000_000;
+
+/// `a..b` (end exclusive), `a..=b`, `n`, or a comma list of any of those
+pub fn parse_seeds(spec: &str) -> Result<Vec<u64>> {
+    let mut seeds = Vec::new();
+    for part in spec.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        if let Some((start, end)) = part.split_once("..") {
+            let (end, inclusive) = match end.strip_prefix('=') {
+                Some(end) => (end, true),
+                None => (end, false),
+            };
+            let start = parse_seed(start)?;
+            let end = parse_seed(end)?;
+            let end = if inclusive {
+                end.saturating_add(1)
+            } else {
+                end
+            };
+            if end <= start {
+                bail!("Empty seed range: {}", part);
+            }
+            if end - start + seeds.len() as u64 > MAX_SEEDS {
+                bail!("Too many seeds (max {})", MAX_SEEDS);
+            }
+            seeds.extend(start..end);
+        } else {
+            seeds.push(parse_seed(part)?);
+        }
+    }
+    if seeds.is_empty() {
+        bail!("No seeds in {:?}", spec);
+    }
+    Ok(seeds)
+}
+
+fn parse_seed(value: &str) -> Result<u64> {
+    value
+        .trim()
+        .parse()
+        .with_context(|| format!("Invalid seed: {:?}", value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_seed_ranges_and_lists() {
+        assert_eq!(parse_seeds("1..4").unwrap(), vec![1, 2, 3]);
+        assert_eq!(parse_seeds("1..=3, 9").unwrap(), vec![1, 2, 3, 9]);
+        assert_eq!(parse_seeds("42").unwrap(), vec![42]);
+        assert_eq!(parse_seeds("1..1000").unwrap().len(), 999);
+        assert!(parse_seeds("5..5").is_err());
+        assert!(parse_seeds("a..3").is_err());
+        assert!(parse_seeds("").is_err());
+        assert!(parse_seeds("0..=2000000").is_err());
+    }
+}
@@ -0,0 +1,91 @@
+//! MRB0 replay codec benchmarks: v4 raw f32 frames vs v5 delta + LZ4 chunks.
+//!
+//! 90-minute match sampled at 10Hz (ball + 22 players). Prints encoded sizes once,
+//! then measures full decode and a 2-minute `ReplayReader` window per format.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use of_core::replay::{
+    decode_mrb0, encode_mrb0, BinaryBallFrame, BinaryPlayerFrame, BinaryPlayerTrack, BinaryReplay,
+    ReplayReader, MRB0_FORMAT_VERSION, MRB0_INDEXED_VERSION,
+};
+
+fn full_match(format_version: u8) -> BinaryReplay {
+    let ticks = 54_000;
+    let ball_frames = (0..=ticks)
+        .map(|i| {
+            let t = i as f32 * 0.1;
+            BinaryBallFrame {
+                t,
+                x: 52.5 + (t * 0.05).sin() * 40.0,
+                y: 34.0 + (t * 0.07).cos() * 25.0,
+                z: ((t * 0.3).sin() * 2.0).max(0.0),
+                vx: (t * 0.05).cos() * 2.0,
+                vy: -(t * 0.07).sin() * 1.75,
+            }
+        })
+        .collect();
+    let players = (0..22u32)
+        .map(|id| BinaryPlayerTrack {
+            player_id: id,
+            frames: (0..=ticks)
+                .map(|i| {
+                    let t = i as f32 * 0.1;
+                    let phase = id as f32 * 0.3;
+                    BinaryPlayerFrame {
+                        t,
+                        x: 10.0 + id as f32 * 4.0 + (t * 0.02 + phase).sin() * 8.0,
+                        y: 34.0 + (t * 0.03 + phase).cos() * 12.0,
+                        vx: (t * 0.02 + phase).cos() * 0.16,
+                        vy: -(t * 0.03 + phase).sin() * 0.36,
+                    }
+                })
+                .collect(),
+        })
+        .collect();
+    BinaryReplay {
+        format_version,
+        header: serde_json::json!({"match_id": "bench"}),
+        duration_seconds: 5400.0,
+        score_home: 0,
+        score_away: 0,
+        events: Vec::new(),
+        ball_frames,
+        players,
+    }
+}
+
+fn bench_replay_codec(c: &mut Criterion) {
+    let formats = [("v4_raw", MRB0_INDEXED_VERSION), ("v5_delta_lz4", MRB0_FORMAT_VERSION)];
+    let encoded: Vec<(&str, BinaryReplay, Vec<u8>)> = formats
+        .iter()
+        .map(|&(name, version)| {
+            let replay = full_match(version);
+            let bytes = encode_mrb0(&replay);
+            println!("MRB0 {}: {} bytes", name, bytes.len());
+            (name, replay, bytes)
+        })
+        .collect();
+
+    let mut group = c.benchmark_group("replay_codec");
+    group.sample_size(10);
+    for (name, replay, bytes) in &encoded {
+        group.bench_with_input(BenchmarkId::new("encode", name), replay, |b, replay| {
+            b.iter(|| encode_mrb0(black_box(replay)))
+        });
+        group.bench_with_input(BenchmarkId::new("decode_full", name), bytes, |b, bytes| {
+            b.iter(|| decode_mrb0(black_box(bytes)).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("decode_window_2min", name), bytes, |b, bytes| {
+            b.iter(|| {
+                ReplayReader::open(black_box(bytes))
+                    .unwrap()
+                    .read_window(45 * 60_000, 47 * 60_000)
+                    .unwrap()
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_replay_codec);
+criterion_main!(benches);
@@ -4,9 +4,12 @@
 //!
 //! ## Submodules
 //!
+//! - `commentary` - Localized (ko/en) commentary lines from the event log
 //! - `events` - Event extraction (carry, sprint, run)
 //! - `metrics` - Statistical metrics (gini, shape, movement)
+//! - `pass_network` - Pass adjacency, shot-ending possession chains, centrality
 //! - `qa` - Quality assurance validators (physics, consistency, likeness)
+//! - `ratings` - Post-match player ratings and man of the match
 //! - `scout` - Scout report generation (model, style_tags, report)
 //!
 //! ## FIX_2601/NEW_FUNC
@@ -17,7 +20,10 @@
 //! - REALTIME_SYSTEMS_ANALYSIS.md
 //! - SCOUT_REPORT_SYSTEM.md
 
+pub mod commentary;
 pub mod events;
 pub mod metrics;
+pub mod pass_network;
 pub mod qa;
+pub mod ratings;
 pub mod scout;
@@ -0,0 +1,364 @@
+//! # Pass Network Module
+//!
+//! Per-team passing network, possession chains that end in a shot and player
+//! centrality, computed from a match event log (finalized or still running).
+//!
+//! ## Model
+//! - **Adjacency**: completed passes between pitch slots. A pass is completed
+//!   when the next on-ball action belongs to its intended receiver (same rule as
+//!   the player ratings). Row = passer, column = receiver, index `i` maps to
+//!   `track_ids[i]`.
+//! - **Possession chains**: consecutive on-ball actions by one team. A chain is
+//!   broken by an opponent action, a stoppage (foul, offside, kick-off, ...) or
+//!   a restart, and only chains that end in a shot are kept.
+//! - **Centrality**: degree share of pass involvement and eigenvector
+//!   centrality of the symmetrised, weighted network (max = 1.0).
+
+use serde::{Deserialize, Serialize};
+
+use super::ratings::pass_completed;
+use crate::models::{EventType, MatchEvent};
+
+/// Power-iteration steps for eigenvector centrality (fixed for determinism)
+const EIGENVECTOR_ITERATIONS: usize = 50;
+
+/// One player's place in the team network
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PlayerCentrality {
+    pub track_id: u8,
+    pub passes_completed: u32,
+    pub passes_received: u32,
+    /// (made + received) / (2 × team completed passes)
+    pub degree: f32,
+    /// Eigenvector centrality, normalised so the hub is 1.0
+    pub eigenvector: f32,
+    /// Shot-ending possession chains this player touched
+    pub shot_chains: u32,
+}
+
+/// Passing network for one team
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TeamPassNetwork {
+    pub is_home: bool,
+    /// Slot → track_id (0-10 home, 11-21 away)
+    pub track_ids: Vec<u8>,
+    /// `adjacency[passer][receiver]` completed pass counts (11 × 11)
+    pub adjacency: Vec<Vec<u32>>,
+    pub total_completed: u32,
+    pub centrality: Vec<PlayerCentrality>,
+}
+
+/// A possession sequence that ended in a shot
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PossessionChain {
+    pub is_home: bool,
+    pub start_ms: u64,
+    pub end_ms: u64,
+    /// Players in touch order (consecutive touches by one player collapsed)
+    pub track_ids: Vec<u8>,
+    pub passes: u32,
+    /// Event type of the finishing shot
+    pub shot_type: EventType,
+    pub xg: f32,
+    pub goal: bool,
+}
+
+/// Pass networks for both teams plus their shot-ending chains
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MatchPassNetwork {
+    pub home: TeamPassNetwork,
+    pub away: TeamPassNetwork,
+    /// Chronological
+    pub shot_chains: Vec<PossessionChain>,
+}
+
+/// Build both teams' pass networks from an event log
+pub fn compute_pass_network(events: &[MatchEvent]) -> MatchPassNetwork {
+    let mut events: Vec<&MatchEvent> = events.iter().collect();
+    events.sort_by_key(|e| e.timeline_ms());
+
+    let mut adjacency = [[[0u32; 11]; 11]; 2];
+    for (i, event) in events.iter().enumerate() {
+        if event.event_type != EventType::Pass {
+            continue;
+        }
+        let (Some(passer), Some(receiver)) = (event.player_track_id, event.target_track_id) else {
+            continue;
+        };
+        if passer >= 22 || receiver >= 22 || (passer < 11) != (receiver < 11) {
+            continue;
+        }
+        if pass_completed(event, &events[i + 1..]) {
+            let team = usize::from(passer >= 11);
+            adjacency[team][passer as usize % 11][receiver as usize % 11] += 1;
+        }
+    }
+
+    let shot_chains = possession_chains(&events);
+    MatchPassNetwork {
+        home: team_network(true, &adjacency[0], &shot_chains),
+        away: team_network(false, &adjacency[1], &shot_chains),
+        shot_chains,
+    }
+}
+
+fn team_network(
+    is_home: bool,
+    adjacency: &[[u32; 11]; 11],
+    shot_chains: &[PossessionChain],
+) -> TeamPassNetwork {
+    let offset = if is_home { 0 } else { 11 };
+    let total_completed: u32 = adjacency.iter().flatten().sum();
+    let eigenvector = eigenvector_centrality(adjacency);
+
+    let centrality = (0..11)
+        .map(|slot| {
+            let track_id = (offset + slot) as u8;
+            let passes_completed: u32 = adjacency[slot].iter().sum();
+            let passes_received: u32 = adjacency.iter().map(|row| row[slot]).sum();
+            let degree = if total_completed > 0 {
+                (passes_completed + passes_received) as f32 / (2 * total_completed) as f32
+            } else {
+                0.0
+            };
+            let shot_chains = shot_chains
+                .iter()
+                .filter(|c| c.is_home == is_home && c.track_ids.contains(&track_id))
+                .count() as u32;
+            PlayerCentrality {
+                track_id,
+                passes_completed,
+                passes_received,
+                degree,
+                eigenvector: eigenvector[slot],
+                shot_chains,
+            }
+        })
+        .collect();
+
+    TeamPassNetwork {
+        is_home,
+        track_ids: (0..11).map(|slot| (offset + slot) as u8).collect(),
+        adjacency: adjacency.iter().map(|row| row.to_vec()).collect(),
+        total_completed,
+        centrality,
+    }
+}
+
+/// Power iteration on A + Aᵀ; isolated players score 0.
+///
+/// Iterates with an added identity (same eigenvectors) so bipartite shapes such
+/// as a single hub with spokes converge instead of oscillating.
+fn eigenvector_centrality(adjacency: &[[u32; 11]; 11]) -> [f32; 11] {
+    let mut weights = [[0.0f32; 11]; 11];
+    for i in 0..11 {
+        for j in 0..11 {
+            weights[i][j] = (adjacency[i][j] + adjacency[j][i]) as f32;
+        }
+    }
+
+    let mut scores = [1.0f32; 11];
+    for _ in 0..EIGENVECTOR_ITERATIONS {
+        let mut next = [0.0f32; 11];
+        for (i, row) in weights.iter().enumerate() {
+            next[i] = scores[i] + row.iter().zip(&scores).map(|(w, s)| w * s).sum::<f32>();
+        }
+        let max = next.iter().copied().fold(0.0f32, f32::max);
+        scores = next.map(|s| s / max);
+    }
+    // Drop players with no completed passes either way
+    for (i, row) in weights.iter().enumerate() {
+        if row.iter().all(|&w| w == 0.0) {
+            scores[i] = 0.0;
+        }
+    }
+    scores
+}
+
+fn is_shot(event_type: &EventType) -> bool {
+    matches!(
+        event_type,
+        EventType::Shot
+            | EventType::ShotOnTarget
+            | EventType::ShotOffTarget
+            | EventType::ShotBlocked
+            | EventType::PostHit
+            | EventType::BarHit
+            | EventType::Goal
+    )
+}
+
+/// Actions that keep (or win) the ball for the acting team
+fn is_on_ball(event_type: &EventType) -> bool {
+    is_shot(event_type)
+        || matches!(
+            event_type,
+            EventType::Pass | EventType::Dribble | EventType::Tackle | EventType::Save
+        )
+}
+
+/// Restarts open a fresh chain for the taking team
+fn is_restart(event_type: &EventType) -> bool {
+    matches!(
+        event_type,
+        EventType::Corner
+            | EventType::Freekick
+            | EventType::Penalty
+            | EventType::GoalKick
+            | EventType::ThrowIn
+    )
+}
+
+/// Stoppages close the current chain without starting a new one
+fn is_stoppage(event_type: &EventType) -> bool {
+    matches!(
+        event_type,
+        EventType::KickOff
+            | EventType::HalfTime
+            | EventType::FullTime
+            | EventType::Foul
+            | EventType::Offside
+            | EventType::Handball
+            | EventType::BackPassOffence
+            | EventType::GoalkeeperHolding
+            | EventType::OwnGoal
+    )
+}
+
+struct OpenChain {
+    is_home: bool,
+    start_ms: u64,
+    track_ids: Vec<u8>,
+    passes: u32,
+}
+
+impl OpenChain {
+    fn new(is_home: bool, start_ms: u64) -> Self {
+        Self { is_home, start_ms, track_ids: Vec::new(), passes: 0 }
+    }
+
+    fn touch(&mut self, track_id: u8) {
+        if self.track_ids.last() != Some(&track_id) {
+            self.track_ids.push(track_id);
+        }
+    }
+}
+
+fn possession_chains(events: &[&MatchEvent]) -> Vec<PossessionChain> {
+    let mut chains: Vec<PossessionChain> = Vec::new();
+    let mut open: Option<OpenChain> = None;
+    // The shot event is followed by a separate Goal event when it goes in:
+    // (index into `chains`, shooter) while no other on-ball action intervened.
+    let mut last_shot: Option<(usize, u8)> = None;
+
+    for event in events {
+        let event_type = &event.event_type;
+        let Some(track_id) = event.player_track_id.filter(|&t| t < 22) else {
+            if is_stoppage(event_type) {
+                open = None;
+            }
+            continue;
+        };
+
+        if *event_type == EventType::Goal {
+            if let Some((idx, shooter)) = last_shot.take() {
+                if shooter == track_id && chains[idx].is_home == event.is_home_team {
+                    chains[idx].goal = true;
+                    open = None;
+                    continue;
+                }
+            }
+        }
+
+        if is_stoppage(event_type) {
+            open = None;
+            last_shot = None;
+            continue;
+        }
+        if is_restart(event_type) {
+            let mut chain = OpenChain::new(event.is_home_team, event.timeline_ms());
+            chain.touch(track_id);
+            open = Some(chain);
+            last_shot = None;
+            continue;
+        }
+        if !is_on_ball(event_type) {
+            continue;
+        }
+        last_shot = None;
+
+        let chain = match open.as_mut() {
+            Some(chain) if chain.is_home == event.is_home_team => chain,
+            _ => open.insert(OpenChain::new(event.is_home_team, event.timeline_ms())),
+        };
+        chain.touch(track_id);
+        if *event_type == EventType::Pass {
+            chain.passes += 1;
+        }
+
+        if is_shot(event_type) {
+            let chain = open.take().expect("chain opened above");
+            chains.push(PossessionChain {
+                is_home: chain.is_home,
+                start_ms: chain.start_ms,
+                end_ms: event.timeline_ms(),
+                track_ids: chain.track_ids,
+                passes: chain.passes,
+                shot_type: event_type.clone(),
+                xg: event.details.as_ref().and_then(|d| d.xg_value).unwrap_or(0.0),
+                goal: *event_type == EventType::Goal,
+            });
+            last_shot = Some((chains.len() - 1, track_id));
+        }
+    }
+
+    chains
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pass(ms: u64, from: usize, to: usize) -> MatchEvent {
+        let home = from < 11;
+        MatchEvent::pass((ms / 60_000) as u8, ms, home, from, (0.5, 0.5, 0.0))
+            .with_target_track_id(Some(to))
+    }
+
+    #[test]
+    fn test_network_and_shot_chains() {
+        let events = vec![
+            pass(1_000, 4, 6),
+            pass(2_000, 6, 9),
+            MatchEvent::shot(0, 3_000, true, 9, true, 0.3),
+            MatchEvent::goal(0, 3_000, true, 9, Some(6)),
+            MatchEvent::kick_off(0, 4_000, false),
+            // Away: 15 → 18 completed, then 18 → 19 intercepted by home 5
+            pass(5_000, 15, 18),
+            pass(6_000, 18, 19),
+            MatchEvent::tackle(0, 7_000, true, 5, (0.5, 0.5, 0.0)),
+            pass(8_000, 5, 6),
+            MatchEvent::shot(0, 9_000, true, 6, false, 0.05),
+        ];
+        let network = compute_pass_network(&events);
+
+        assert_eq!(network.home.adjacency[4][6], 1);
+        assert_eq!(network.home.adjacency[6][9], 1);
+        assert_eq!(network.home.adjacency[5][6], 1);
+        assert_eq!(network.home.total_completed, 3);
+        assert_eq!(network.away.adjacency[4][7], 1);
+        assert_eq!(network.away.total_completed, 1, "intercepted pass is not completed");
+
+        let hub = &network.home.centrality[6];
+        assert_eq!((hub.passes_completed, hub.passes_received, hub.shot_chains), (1, 2, 2));
+        assert_eq!(hub.eigenvector, 1.0);
+        assert_eq!(network.home.centrality[10].eigenvector, 0.0);
+
+        assert_eq!(network.shot_chains.len(), 2);
+        let first = &network.shot_chains[0];
+        assert_eq!((first.track_ids.clone(), first.passes, first.goal), (vec![4, 6, 9], 2, true));
+        let second = &network.shot_chains[1];
+        assert_eq!((second.track_ids.clone(), second.goal), (vec![5, 6], false));
+        assert_eq!(compute_pass_network(&events), network);
+    }
+}
@@ -0,0 +1,462 @@
+//! # Player Ratings Module
+//!
+//! Deterministic post-match player ratings (0.0-10.0) and man-of-the-match
+//! selection, computed purely from a finalized `MatchResult`.
+//!
+//! Ratings are keyed by pitch slot (`track_id` 0-21). A substitution hands the
+//! slot to a new occupant, so contributions are reset and the entry describes
+//! the player who finished the match in that slot.
+//!
+//! ## Model
+//! Every player starts from `BASE_RATING` and gains/loses for goals, assists,
+//! second assists, key passes, shots (xG), passing, tackles, dribbles, saves, goals conceded, cards and the
+//! team result. Pass volume is normalised per 90 minutes so late substitutes
+//! are not rewarded for a handful of touches.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::{EventType, MatchEvent, MatchResult, Position};
+
+/// Starting point for every player who took part
+pub const BASE_RATING: f32 = 6.0;
+/// Minutes a player needs to be considered for man of the match
+pub const MOTM_MIN_MINUTES: u8 = 30;
+
+const GOAL: f32 = 1.0;
+const ASSIST: f32 = 0.6;
+const SECOND_ASSIST: f32 = 0.25;
+/// Per pass that led to a shot (capped by `KEY_PASS_CAP`; assists count too)
+const KEY_PASS: f32 = 0.15;
+const KEY_PASS_CAP: f32 = 0.6;
+const OWN_GOAL: f32 = -1.0;
+const XG: f32 = 0.5;
+const SHOT_ON_TARGET: f32 = 0.1;
+const SHOT_OFF_TARGET: f32 = -0.05;
+/// Per completed pass, per 90 minutes (capped by `PASS_VOLUME_CAP`)
+const PASS_VOLUME: f32 = 0.01;
+const PASS_VOLUME_CAP: f32 = 0.6;
+/// Scaled by (accuracy - `PASS_ACCURACY_PAR`)
+const PASS_ACCURACY: f32 = 2.0;
+const PASS_ACCURACY_PAR: f32 = 0.75;
+/// Passes needed before accuracy counts
+const PASS_ACCURACY_MIN_ATTEMPTS: u16 = 5;
+const TACKLE: f32 = 0.1;
+const DRIBBLE: f32 = 0.05;
+const SAVE: f32 = 0.3;
+const CONCEDED_GK: f32 = -0.4;
+const CONCEDED_DEF: f32 = -0.2;
+const CLEAN_SHEET_GK: f32 = 0.7;
+const CLEAN_SHEET_DEF: f32 = 0.4;
+/// Minutes needed for a clean-sheet bonus
+const CLEAN_SHEET_MIN_MINUTES: u8 = 60;
+const YELLOW: f32 = -0.3;
+const RED: f32 = -1.5;
+const WIN: f32 = 0.3;
+const LOSS: f32 = -0.2;
+
+/// One player's rating and the contributions behind it
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PlayerMatchRating {
+    pub track_id: u8,
+    pub name: String,
+    pub is_home: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<Position>,
+    pub rating: f32,
+    pub minutes: u8,
+    pub goals: u8,
+    pub assists: u8,
+    #[serde(default)]
+    pub second_assists: u8,
+    #[serde(default)]
+    pub key_passes: u16,
+    pub own_goals: u8,
+    pub shots: u16,
+    pub shots_on_target: u16,
+    pub xg: f32,
+    pub passes: u16,
+    pub passes_completed: u16,
+    pub tackles: u16,
+    pub dribbles: u16,
+    pub saves: u16,
+    pub goals_conceded: u8,
+    pub yellow_cards: u8,
+    pub red_cards: u8,
+}
+
+/// Per-slot tracking while walking the timeline
+#[derive(Default)]
+struct SlotState {
+    rating: PlayerMatchRating,
+    /// Minute the current occupant came on
+    on_minute: u8,
+    /// Minute the current occupant left (red card)
+    off_minute: Option<u8>,
+}
+
+impl SlotState {
+    fn on_pitch(&self) -> bool {
+        self.off_minute.is_none()
+    }
+}
+
+/// Compute ratings for every slot that had a player on the pitch
+pub fn compute_player_ratings(result: &MatchResult) -> BTreeMap<u8, PlayerMatchRating> {
+    let mut events: Vec<&MatchEvent> = result.events.iter().collect();
+    events.sort_by_key(|e| e.timeline_ms());
+
+    let full_time = events
+        .iter()
+        .filter(|e| e.event_type == EventType::FullTime)
+        .map(|e| e.minute)
+        .max()
+        .or_else(|| events.iter().map(|e| e.minute).max())
+        .unwrap_or(90)
+        .max(90);
+
+    let mut slots: Vec<SlotState> = (0..22u8)
+        .map(|track_id| {
+            let (name, position) = starter(result, track_id);
+            SlotState {
+                rating: PlayerMatchRating {
+                    track_id,
+                    name,
+                    is_home: track_id < 11,
+                    position,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        })
+        .collect();
+    let mut finished: Vec<PlayerMatchRating> = Vec::new();
+
+    for (i, event) in events.iter().enumerate() {
+        // Chance creation is credited by the engine at shot time (save events are
+        // keyed by the goalkeeper, so credit is read before the actor lookup).
+        if let Some(creation) = event.chance_creation() {
+            if let Some(key) = creation.key_pass_track_id.filter(|&t| t < 22) {
+                slots[key as usize].rating.key_passes += 1;
+            }
+            if event.event_type == EventType::Goal {
+                if let Some(second) = creation.second_assist_track_id.filter(|&t| t < 22) {
+                    slots[second as usize].rating.second_assists += 1;
+                }
+            }
+        }
+
+        let Some(track_id) = event.player_track_id.filter(|&t| t < 22) else {
+            continue;
+        };
+        let idx = track_id as usize;
+
+        match event.event_type {
+            EventType::Substitution => {
+                let Some(sub) = event.details.as_ref().and_then(|d| d.substitution.as_ref()) else {
+                    continue;
+                };
+                let slot = &mut slots[idx];
+                let mut outgoing = std::mem::take(&mut slot.rating);
+                outgoing.minutes = event.minute.saturating_sub(slot.on_minute);
+                let position = bench_position(result, track_id < 11, &sub.player_in_name);
+                slot.rating = PlayerMatchRating {
+                    track_id,
+                    name: sub.player_in_name.clone(),
+                    is_home: track_id < 11,
+                    position,
+                    ..Default::default()
+                };
+                slot.on_minute = event.minute;
+                slot.off_minute = None;
+                finished.push(outgoing);
+            }
+            EventType::Goal => {
+                slots[idx].rating.goals += 1;
+                slots[idx].rating.shots += 1;
+                slots[idx].rating.shots_on_target += 1;
+                slots[idx].rating.xg += event_xg(event);
+                if let Some(assist) = event.target_track_id.filter(|&t| t < 22 && t != track_id) {
+                    slots[assist as usize].rating.assists += 1;
+                }
+                concede(&mut slots, !event.is_home_team);
+            }
+            EventType::OwnGoal => {
+                slots[idx].rating.own_goals += 1;
+                concede(&mut slots, !event.is_home_team);
+            }
+            EventType::ShotOnTarget => {
+                slots[idx].rating.shots += 1;
+                slots[idx].rating.shots_on_target += 1;
+                slots[idx].rating.xg += event_xg(event);
+            }
+            EventType::Shot
+            | EventType::ShotOffTarget
+            | EventType::ShotBlocked
+            | EventType::PostHit
+            | EventType::BarHit => {
+                slots[idx].rating.shots += 1;
+                slots[idx].rating.xg += event_xg(event);
+            }
+            EventType::Pass => {
+                slots[idx].rating.passes += 1;
+                if pass_completed(event, &events[i + 1..]) {
+                    slots[idx].rating.passes_completed += 1;
+                }
+            }
+            EventType::Tackle => slots[idx].rating.tackles += 1,
+            EventType::Dribble => slots[idx].rating.dribbles += 1,
+            EventType::Save => slots[idx].rating.saves += 1,
+            EventType::YellowCard => slots[idx].rating.yellow_cards += 1,
+            EventType::RedCard => {
+                slots[idx].rating.red_cards += 1;
+                slots[idx].off_minute.get_or_insert(event.minute);
+            }
+            _ => {}
+        }
+    }
+
+    for slot in slots {
+        let mut rating = slot.rating;
+        let off = slot.off_minute.unwrap_or(full_time);
+        rating.minutes = off.saturating_sub(slot.on_minute);
+        finished.push(rating);
+    }
+
+    let (score_home, score_away) = (result.score_home, result.score_away);
+    let mut ratings = BTreeMap::new();
+    // Later entries (the slot's final occupant) win on a shared track_id.
+    for mut player in finished {
+        if player.minutes == 0 && !has_contribution(&player) {
+            continue;
+        }
+        let goal_diff = if player.is_home {
+            score_home as i16 - score_away as i16
+        } else {
+            score_away as i16 - score_home as i16
+        };
+        player.rating = rate(&player, goal_diff);
+        ratings.insert(player.track_id, player);
+    }
+    ratings
+}
+
+/// Highest-rated player with enough minutes (falls back to anyone who played)
+///
+/// Ties go to more goals, then the winning side, then the lower track_id.
+pub fn select_man_of_the_match(
+    ratings: &BTreeMap<u8, PlayerMatchRating>,
+    score_home: u8,
+    score_away: u8,
+) -> Option<u8> {
+    let won = |p: &PlayerMatchRating| {
+        if p.is_home {
+            score_home > score_away
+        } else {
+            score_away > score_home
+        }
+    };
+    let best = |min_minutes: u8| {
+        ratings
+            .values()
+            .filter(|p| p.minutes >= min_minutes)
+            .max_by(|a, b| {
+                a.rating
+                    .total_cmp(&b.rating)
+                    .then(a.goals.cmp(&b.goals))
+                    .then(won(a).cmp(&won(b)))
+                    .then(b.track_id.cmp(&a.track_id))
+            })
+            .map(|p| p.track_id)
+    };
+    best(MOTM_MIN_MINUTES).or_else(|| best(0))
+}
+
+fn rate(p: &PlayerMatchRating, goal_diff: i16) -> f32 {
+    let mut r = BASE_RATING;
+
+    r += GOAL * p.goals as f32 + ASSIST * p.assists as f32 + OWN_GOAL * p.own_goals as f32;
+    r += SECOND_ASSIST * p.second_assists as f32;
+    r += (KEY_PASS * p.key_passes as f32).min(KEY_PASS_CAP);
+    r += XG * p.xg;
+    r += SHOT_ON_TARGET * p.shots_on_target as f32;
+    r += SHOT_OFF_TARGET * p.shots.saturating_sub(p.shots_on_target) as f32;
+
+    let minutes = p.minutes.max(1) as f32;
+    r += (PASS_VOLUME * p.passes_completed as f32 * 90.0 / minutes).min(PASS_VOLUME_CAP);
+    if p.passes >= PASS_ACCURACY_MIN_ATTEMPTS {
+        let accuracy = p.passes_completed as f32 / p.passes as f32;
+        r += PASS_ACCURACY * (accuracy - PASS_ACCURACY_PAR);
+    }
+
+    r += TACKLE * p.tackles as f32 + DRIBBLE * p.dribbles as f32 + SAVE * p.saves as f32;
+
+    let (conceded, clean_sheet) = match p.position {
+        Some(pos) if pos.is_goalkeeper() => (CONCEDED_GK, CLEAN_SHEET_GK),
+        Some(pos) if pos.is_defender() => (CONCEDED_DEF, CLEAN_SHEET_DEF),
+        _ => (0.0, 0.0),
+    };
+    r += conceded * p.goals_conceded as f32;
+    if p.goals_conceded == 0 && p.minutes >= CLEAN_SHEET_MIN_MINUTES {
+        r += clean_sheet;
+    }
+
+    r += YELLOW * p.yellow_cards as f32 + RED * p.red_cards as f32;
+    r += match goal_diff.signum() {
+        1 => WIN,
+        -1 => LOSS,
+        _ => 0.0,
+    };
+
+    ((r.clamp(0.0, 10.0)) * 10.0).round() / 10.0
+}
+
+/// A pass counts as completed when the next on-ball action is the receiver's
+pub(crate) fn pass_completed(pass: &MatchEvent, rest: &[&MatchEvent]) -> bool {
+    let Some(receiver) = pass.target_track_id else {
+        return false;
+    };
+    rest.iter()
+        .find(|e| {
+            matches!(
+                e.event_type,
+                EventType::Pass
+                    | EventType::Dribble
+                    | EventType::Goal
+                    | EventType::Shot
+                    | EventType::ShotOnTarget
+                    | EventType::ShotOffTarget
+                    | EventType::ShotBlocked
+                    | EventType::PostHit
+                    | EventType::BarHit
+                    | EventType::Tackle
+                    | EventType::Offside
+            )
+        })
+        .is_some_and(|next| {
+            next.player_track_id == Some(receiver) && next.event_type != EventType::Offside
+        })
+}
+
+fn concede(slots: &mut [SlotState], home_conceded: bool) {
+    let range = if home_conceded { 0..11 } else { 11..22 };
+    for slot in &mut slots[range] {
+        if slot.on_pitch() {
+            slot.rating.goals_conceded += 1;
+        }
+    }
+}
+
+fn event_xg(event: &MatchEvent) -> f32 {
+    event.details.as_ref().and_then(|d| d.xg_value).unwrap_or(0.0)
+}
+
+fn has_contribution(p: &PlayerMatchRating) -> bool {
+    p.goals + p.assists + p.second_assists + p.own_goals + p.yellow_cards + p.red_cards > 0
+        || p.shots + p.passes + p.key_passes + p.tackles + p.dribbles + p.saves > 0
+}
+
+/// Starting-lineup name/position for a slot (roster first, then match setup)
+pub(crate) fn starter(result: &MatchResult, track_id: u8) -> (String, Option<Position>) {
+    let (team, slot) = if track_id < 11 {
+        (result.home_team.as_ref(), track_id as usize)
+    } else {
+        (result.away_team.as_ref(), track_id as usize - 11)
+    };
+    if let Some(player) = team.and_then(|t| t.players.get(slot)) {
+        return (player.name.clone(), Some(player.position));
+    }
+    let setup_slot = result
+        .match_setup
+        .as_ref()
+        .and_then(|s| s.player_slots.iter().find(|p| p.track_id == track_id as u32));
+    match setup_slot {
+        Some(p) => (p.name.clone(), p.position.parse().ok()),
+        None => (format!("Player #{}", track_id), None),
+    }
+}
+
+fn bench_position(result: &MatchResult, is_home: bool, name: &str) -> Option<Position> {
+    let team = if is_home { result.home_team.as_ref() } else { result.away_team.as_ref() };
+    team.and_then(|t| t.players.iter().find(|p| p.name == name)).map(|p| p.position)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{EventDetails, SubstitutionDetails};
+
+    fn substitution(minute: u8, track_id: usize, name: &str) -> MatchEvent {
+        let mut event = MatchEvent::substitution(minute, minute as u64 * 60_000, true, track_id, 0);
+        event.target_track_id = None;
+        event.details = Some(EventDetails {
+            substitution: Some(SubstitutionDetails {
+                player_in_name: name.to_string(),
+                player_out_name: String::new(),
+                bench_slot: 0,
+            }),
+            ..Default::default()
+        });
+        event
+    }
+
+    #[test]
+    fn test_ratings_reward_contributions_and_pick_motm() {
+        let mut result = MatchResult::new();
+        result.score_home = 2;
+        result.events = vec![
+            MatchEvent::pass(5, 300_000, true, 6, (0.5, 0.5, 0.0)).with_target_track_id(Some(9)),
+            MatchEvent::goal(5, 301_000, true, 9, Some(6)),
+            MatchEvent::save(20, 1_200_000, true, 0),
+            MatchEvent::yellow_card(30, 1_800_000, false, 15),
+            MatchEvent::red_card(60, 3_600_000, false, 15),
+            substitution(70, 9, "Sub Striker"),
+            MatchEvent::goal(80, 4_800_000, true, 9, None),
+        ];
+        let ratings = compute_player_ratings(&result);
+
+        // Slot 9 now belongs to the substitute: 20 minutes, one goal.
+        let sub = &ratings[&9];
+        assert_eq!((sub.name.as_str(), sub.goals, sub.minutes), ("Sub Striker", 1, 20));
+        assert_eq!(ratings[&6].assists, 1);
+        assert_eq!(ratings[&6].passes_completed, 1);
+        assert_eq!(ratings[&15].minutes, 60);
+        assert!(ratings[&15].rating < BASE_RATING);
+        assert!(ratings[&6].rating > ratings[&5].rating);
+        assert!(ratings.values().all(|p| (0.0..=10.0).contains(&p.rating)));
+
+        // Substitute is under the minutes bar; the assisting midfielder wins it.
+        assert_eq!(select_man_of_the_match(&ratings, 2, 0), Some(6));
+        assert_eq!(compute_player_ratings(&result), ratings);
+    }
+
+    #[test]
+    fn test_creative_passes_raise_ratings() {
+        use crate::models::ChanceCreationDetails;
+
+        let creation =
+            ChanceCreationDetails { key_pass_track_id: Some(7), second_assist_track_id: Some(4) };
+        let mut result = MatchResult::new();
+        result.score_home = 1;
+        result.events = vec![
+            MatchEvent::shot(20, 1_200_000, true, 9, true, 0.1).with_chance_creation(creation),
+            // Save events are keyed by the goalkeeper; credit still goes to the passer.
+            MatchEvent::save(40, 2_400_000, false, 11)
+                .with_target_track_id(Some(10))
+                .with_chance_creation(ChanceCreationDetails {
+                    key_pass_track_id: Some(7),
+                    second_assist_track_id: None,
+                }),
+            MatchEvent::goal(60, 3_600_000, true, 9, Some(7)).with_chance_creation(creation),
+        ];
+        let ratings = compute_player_ratings(&result);
+
+        let creator = &ratings[&7];
+        assert_eq!((creator.key_passes, creator.assists), (3, 1));
+        assert_eq!(ratings[&4].second_assists, 1);
+        assert_eq!(ratings[&4].key_passes, 0);
+        assert!(creator.rating > ratings[&4].rating);
+        assert!(ratings[&4].rating > ratings[&5].rating);
+    }
+}
@@ -0,0 +1,88 @@
+// Aging JSON API Layer
+// Season-end aging and retirement for the squad (career mode)
+
+use crate::player::aging::{self, SeasonAging};
+use crate::player::CorePlayer;
+use serde::{Deserialize, Serialize};
+
+// ========== Request/Response Structures ==========
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct AdvanceSeasonRequest {
+    /// Players to age (None = the stored squad, updated in place)
+    #[serde(default)]
+    pub players: Option<Vec<CorePlayer>>,
+    /// Season seed; the same seed ages the same squad the same way
+    #[serde(default)]
+    pub seed: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AdvanceSeasonResponse {
+    /// One entry per player, in squad order
+    pub reports: Vec<SeasonAging>,
+    /// Players still active after the season (retirees removed)
+    pub players: Vec<CorePlayer>,
+}
+
+// ========== API Functions ==========
+
+/// Age the squad by one season; returns `AdvanceSeasonResponse` JSON
+pub fn advance_season_json(request_json: &str) -> Result<String, String> {
+    let request: AdvanceSeasonRequest =
+        serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
+
+    let response = match request.players {
+        Some(mut players) => advance_season(&mut players, request.seed),
+        None => {
+            let mut state = crate::state::get_state_mut();
+            let response = advance_season(&mut state.players, request.seed);
+            state.players = response.players.clone();
+            response
+        }
+    };
+
+    serde_json::to_string(&response).map_err(|e| format!("Failed to serialize season aging: {}", e))
+}
+
+fn advance_season(players: &mut [CorePlayer], seed: u64) -> AdvanceSeasonResponse {
+    let reports: Vec<SeasonAging> =
+        players.iter_mut().map(|player| aging::advance_season(player, seed)).collect();
+    let players = players
+        .iter()
+        .zip(&reports)
+        .filter(|(_, report)| !report.retires)
+        .map(|(player, _)| player.clone())
+        .collect();
+    AdvanceSeasonResponse { reports, players }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::player::Position;
+
+    #[test]
+    fn test_advance_season_json() {
+        let mut young = CorePlayer::create_average_player("Young".to_string(), Position::LW, 1);
+        young.id = "young".to_string();
+        let mut veteran = CorePlayer::create_average_player("Old".to_string(), Position::CB, 2);
+        veteran.id = "old".to_string();
+        veteran.age_months = 39.5;
+
+        let request = serde_json::json!({ "players": [young, veteran], "seed": 3 }).to_string();
+        let run = || -> AdvanceSeasonResponse {
+            serde_json::from_str(&advance_season_json(&request).unwrap()).unwrap()
+        };
+        let response = run();
+        assert_eq!(response.reports, run().reports, "seeded");
+
+        assert_eq!(response.reports.len(), 2);
+        assert_eq!(response.reports[0].age_after, 17.0);
+        assert!(response.reports[1].retires);
+        assert_eq!(response.players.len(), 1);
+        assert_eq!(response.players[0].id, "young");
+
+        assert!(advance_season_json("{").is_err());
+    }
+}
@@ -0,0 +1,256 @@
+// Result Attestation API Layer
+// Binds a MatchRequestV2, its seed, the engine version and the simulated result
+// into one hash. A server holding the request re-simulates to check a
+// client-submitted score (leaderboards, async PvP).
+//
+// Attested simulations never apply env exp-config: client and verifier must run
+// the exact same engine setup.
+
+use serde::{Deserialize, Serialize};
+
+use super::challenge::canonical_hash;
+use super::json_api::{match_plan_from_match_request_v2, MatchRequestV2};
+use crate::engine::MatchEngine;
+use crate::models::{MatchEvent, MatchResult};
+
+/// Attestation format version
+pub const ATTESTATION_VERSION: u8 = 1;
+
+const REQUEST_DOMAIN: &[u8] = b"of_core/attest_request/v1";
+const RESULT_DOMAIN: &[u8] = b"of_core/attest_result/v1";
+const ATTESTATION_DOMAIN: &[u8] = b"of_core/attestation/v1";
+
+// ========== Attestation Structures ==========
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResultAttestation {
+    pub version: u8,
+    /// of_core version that produced the result
+    pub engine_version: String,
+    pub seed: u64,
+    pub score_home: u8,
+    pub score_away: u8,
+    /// sha256 of the canonical request JSON (hex)
+    pub request_hash: String,
+    /// sha256 of the score and event log (hex)
+    pub result_hash: String,
+    /// sha256 over all fields above (hex)
+    pub attestation_hash: String,
+}
+
+#[derive(Serialize)]
+struct ResultBody<'a> {
+    score_home: u8,
+    score_away: u8,
+    events: &'a [MatchEvent],
+}
+
+#[derive(Serialize)]
+struct AttestationBody<'a> {
+    version: u8,
+    engine_version: &'a str,
+    seed: u64,
+    score_home: u8,
+    score_away: u8,
+    request_hash: &'a str,
+    result_hash: &'a str,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AttestationVerdict {
+    pub valid: bool,
+    /// Why verification failed (None = valid)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Attestation of the re-simulated result (None = not re-simulated)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected: Option<ResultAttestation>,
+}
+
+// ========== Request/Response Structures ==========
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AttestedMatchResponse {
+    pub result: MatchResult,
+    pub attestation: ResultAttestation,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct VerifyAttestationRequest {
+    /// The MatchRequestV2 exactly as submitted
+    pub request: serde_json::Value,
+    pub attestation: ResultAttestation,
+}
+
+// ========== Core Functions ==========
+
+/// Hash binding `request` (MatchRequestV2 JSON), its seed, the engine version
+/// and `result`
+pub fn attest_result(
+    request: &serde_json::Value,
+    result: &MatchResult,
+) -> Result<ResultAttestation, String> {
+    let seed = request
+        .get("seed")
+        .and_then(serde_json::Value::as_u64)
+        .ok_or_else(|| "Attested request needs a numeric seed".to_string())?;
+    let request_hash = canonical_hash(REQUEST_DOMAIN, request)?;
+    let result_hash = canonical_hash(
+        RESULT_DOMAIN,
+        &ResultBody {
+            score_home: result.score_home,
+            score_away: result.score_away,
+            events: &result.events,
+        },
+    )?;
+    let engine_version = crate::VERSION.to_string();
+    let attestation_hash = canonical_hash(
+        ATTESTATION_DOMAIN,
+        &AttestationBody {
+            version: ATTESTATION_VERSION,
+            engine_version: &engine_version,
+            seed,
+            score_home: result.score_home,
+            score_away: result.score_away,
+            request_hash: &request_hash,
+            result_hash: &result_hash,
+        },
+    )?;
+
+    Ok(ResultAttestation {
+        version: ATTESTATION_VERSION,
+        engine_version,
+        seed,
+        score_home: result.score_home,
+        score_away: result.score_away,
+        request_hash,
+        result_hash,
+        attestation_hash,
+    })
+}
+
+/// Simulate a MatchRequestV2 for attestation (no env exp-config, no tracking)
+pub fn simulate_for_attestation(request: &serde_json::Value) -> Result<MatchResult, String> {
+    let request: MatchRequestV2 = serde_json::from_value(request.clone())
+        .map_err(|e| format!("Invalid JSON request: {}", e))?;
+    let (plan, _) = match_plan_from_match_request_v2(request)?;
+    let mut engine = MatchEngine::new(plan)?;
+    Ok(engine.simulate())
+}
+
+/// Re-simulate `request` and compare against a claimed attestation
+pub fn verify_attestation(
+    request: &serde_json::Value,
+    claimed: &ResultAttestation,
+) -> Result<AttestationVerdict, String> {
+    let reject = |reason: String| {
+        Ok(AttestationVerdict { valid: false, reason: Some(reason), expected: None })
+    };
+    if claimed.version != ATTESTATION_VERSION {
+        return reject(format!("Unsupported attestation version: {}", claimed.version));
+    }
+    if claimed.engine_version != crate::VERSION {
+        return reject(format!(
+            "Engine version mismatch: attested {}, verifier {}",
+            claimed.engine_version,
+            crate::VERSION
+        ));
+    }
+    if claimed.request_hash != canonical_hash(REQUEST_DOMAIN, request)? {
+        return reject("Request hash mismatch".to_string());
+    }
+
+    let expected = attest_result(request, &simulate_for_attestation(request)?)?;
+    let reason = (expected != *claimed).then(|| {
+        if expected.result_hash != claimed.result_hash {
+            "Result does not match re-simulation".to_string()
+        } else {
+            "Attestation hash mismatch".to_string()
+        }
+    });
+    Ok(AttestationVerdict { valid: reason.is_none(), reason, expected: Some(expected) })
+}
+
+// ========== API Functions ==========
+
+/// Simulate a MatchRequestV2 and attest it; returns `AttestedMatchResponse` JSON
+pub fn simulate_match_v2_attested_json(request_json: &str) -> Result<String, String> {
+    let request: serde_json::Value =
+        serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
+
+    let result = simulate_for_attestation(&request)?;
+    let attestation = attest_result(&request, &result)?;
+
+    serde_json::to_string(&AttestedMatchResponse { result, attestation })
+        .map_err(|e| format!("Failed to serialize attested result: {}", e))
+}
+
+/// Verify a claimed attestation by re-simulating; returns `AttestationVerdict` JSON
+pub fn verify_result_attestation_json(request_json: &str) -> Result<String, String> {
+    let request: VerifyAttestationRequest =
+        serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
+
+    let verdict = verify_attestation(&request.request, &request.attestation)?;
+
+    serde_json::to_string(&verdict).map_err(|e| format!("Failed to serialize verdict: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn team_json(prefix: &str) -> serde_json::Value {
+        let positions = [
+            "GK", "LB", "CB", "CB", "RB", "LM", "CM", "CM", "RM", "ST", "ST", "GK", "CB", "CM",
+            "CM", "LW", "RW", "ST",
+        ];
+        let roster: Vec<_> = positions
+            .iter()
+            .enumerate()
+            .map(|(i, pos)| {
+                json!({"name": format!("{prefix}{i}"), "position": pos, "overall": 70, "condition": 3})
+            })
+            .collect();
+        json!({"name": prefix, "formation": "4-4-2", "roster": roster})
+    }
+
+    #[test]
+    fn test_attestation_round_trip_and_tampering() {
+        let request = json!({
+            "schema_version": 2,
+            "seed": 31,
+            "home_team": team_json("H"),
+            "away_team": team_json("A")
+        });
+        let response: AttestedMatchResponse =
+            serde_json::from_str(&simulate_match_v2_attested_json(&request.to_string()).unwrap())
+                .unwrap();
+        let attestation = response.attestation;
+        assert_eq!(attestation.seed, 31);
+        assert_eq!(attestation.engine_version, crate::VERSION);
+
+        let verify = json!({"request": request, "attestation": attestation});
+        let verdict: AttestationVerdict =
+            serde_json::from_str(&verify_result_attestation_json(&verify.to_string()).unwrap())
+                .unwrap();
+        assert!(verdict.valid, "{:?}", verdict.reason);
+
+        // Inflated score
+        let mut forged = attestation.clone();
+        forged.score_home += 3;
+        let verdict = verify_attestation(&request, &forged).unwrap();
+        assert!(!verdict.valid);
+        assert_eq!(verdict.expected.unwrap().attestation_hash, attestation.attestation_hash);
+
+        // Attestation reused for a different request
+        let mut other = request.clone();
+        other["seed"] = json!(32);
+        let verdict = verify_attestation(&other, &attestation).unwrap();
+        assert_eq!(verdict.reason.as_deref(), Some("Request hash mismatch"));
+
+        let mut old_engine = attestation;
+        old_engine.engine_version = "0.0.0".to_string();
+        assert!(!verify_attestation(&request, &old_engine).unwrap().valid);
+    }
+}
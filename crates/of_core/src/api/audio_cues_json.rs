@@ -0,0 +1,25 @@
+// Audio Cue JSON API Layer
+// Presentation cue track (crowd / whistle) for replays and offline playback
+
+use crate::engine::audio_cues::build_audio_cue_track;
+use crate::models::MatchEvent;
+use serde::{Deserialize, Serialize};
+
+// ========== Request/Response Structures ==========
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AudioCueTrackRequest {
+    pub events: Vec<MatchEvent>,
+}
+
+// ========== API Functions ==========
+
+/// Build the audio cue track for a match event list; returns `Vec<AudioCue>` JSON
+pub fn build_audio_cues_json(request_json: &str) -> Result<String, String> {
+    let request: AudioCueTrackRequest =
+        serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
+
+    let cues = build_audio_cue_track(&request.events);
+
+    serde_json::to_string(&cues).map_err(|e| format!("Failed to serialize audio cues: {}", e))
+}
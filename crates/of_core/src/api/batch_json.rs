@@ -0,0 +1,229 @@
+// Batch JSON API Layer
+// Runs independent seeded v2 matches on a rayon pool; each match owns its engine and seed,
+// so results are identical to running the same requests one by one.
+
+use super::json_api::{simulate_match_v2, simulate_match_v3, MatchRequestV2, MatchRequestV3};
+use crate::models::MatchResult;
+use rayon::prelude::*;
+use serde::Serialize;
+use std::time::Instant;
+
+/// Upper bound for `thread_count` (keeps a bad request from spawning hundreds of threads)
+pub const MAX_BATCH_THREADS: usize = 64;
+
+// ========== Response Structures ==========
+
+#[derive(Debug, Serialize)]
+pub struct BatchMatchOutcome {
+    /// Position in the request array (results keep request order)
+    pub index: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<MatchResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub elapsed_ms: f64,
+}
+
+#[derive(Debug, Default, Serialize, PartialEq)]
+pub struct BatchAggregate {
+    pub matches: usize,
+    pub failed: usize,
+    pub home_wins: usize,
+    pub draws: usize,
+    pub away_wins: usize,
+    pub home_goals: u32,
+    pub away_goals: u32,
+    pub avg_goals_per_match: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BatchTiming {
+    pub threads: usize,
+    pub wall_clock_ms: f64,
+    /// Sum of per-match simulation times (≈ sequential cost)
+    pub total_match_ms: f64,
+    pub min_match_ms: f64,
+    pub max_match_ms: f64,
+    pub mean_match_ms: f64,
+    /// `total_match_ms / wall_clock_ms`
+    pub speedup: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ParallelBatchResponse {
+    pub results: Vec<BatchMatchOutcome>,
+    pub aggregate: BatchAggregate,
+    pub timing: BatchTiming,
+}
+
+// ========== API Functions ==========
+
+/// Simulate a JSON array of `MatchRequestV2` / `MatchRequestV3` (by `schema_version`) in
+/// parallel; returns `ParallelBatchResponse` JSON.
+///
+/// `thread_count == 0` uses rayon's default (one per logical CPU). A request that fails to
+/// parse or simulate is reported in its own slot and does not abort the batch.
+pub fn simulate_matches_parallel_json(
+    requests_json: &str,
+    thread_count: usize,
+) -> Result<String, String> {
+    let requests: Vec<serde_json::Value> =
+        serde_json::from_str(requests_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
+
+    let response = simulate_matches_parallel(requests, thread_count)?;
+    serde_json::to_string(&response).map_err(|e| format!("Failed to serialize response: {}", e))
+}
+
+pub fn simulate_matches_parallel(
+    requests: Vec<serde_json::Value>,
+    thread_count: usize,
+) -> Result<ParallelBatchResponse, String> {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(thread_count.min(MAX_BATCH_THREADS))
+        .build()
+        .map_err(|e| format!("Failed to build thread pool: {}", e))?;
+
+    let started = Instant::now();
+    let results: Vec<BatchMatchOutcome> = pool.install(|| {
+        requests.into_par_iter().enumerate().map(|(index, value)| run_one(index, value)).collect()
+    });
+    let wall_clock_ms = elapsed_ms(started);
+
+    let aggregate = aggregate_results(&results);
+    let timing = batch_timing(&results, pool.current_num_threads(), wall_clock_ms);
+    Ok(ParallelBatchResponse { results, aggregate, timing })
+}
+
+fn run_one(index: usize, value: serde_json::Value) -> BatchMatchOutcome {
+    let started = Instant::now();
+    let seed = value.get("seed").and_then(serde_json::Value::as_u64);
+    let invalid = |e: serde_json::Error| format!("Invalid JSON request: {}", e);
+    let outcome = match value.get("schema_version").and_then(serde_json::Value::as_u64) {
+        Some(3) => serde_json::from_value::<MatchRequestV3>(value)
+            .map_err(invalid)
+            .and_then(simulate_match_v3),
+        _ => serde_json::from_value::<MatchRequestV2>(value)
+            .map_err(invalid)
+            .and_then(simulate_match_v2),
+    };
+
+    let (result, error) = match outcome {
+        Ok(result) => (Some(result), None),
+        Err(e) => (None, Some(e)),
+    };
+    BatchMatchOutcome { index, seed, result, error, elapsed_ms: elapsed_ms(started) }
+}
+
+fn aggregate_results(results: &[BatchMatchOutcome]) -> BatchAggregate {
+    let mut agg = BatchAggregate::default();
+    for result in results.iter().filter_map(|o| o.result.as_ref()) {
+        agg.matches += 1;
+        agg.home_goals += u32::from(result.score_home);
+        agg.away_goals += u32::from(result.score_away);
+        match result.score_home.cmp(&result.score_away) {
+            std::cmp::Ordering::Greater => agg.home_wins += 1,
+            std::cmp::Ordering::Equal => agg.draws += 1,
+            std::cmp::Ordering::Less => agg.away_wins += 1,
+        }
+    }
+    agg.failed = results.len() - agg.matches;
+    if agg.matches > 0 {
+        agg.avg_goals_per_match = f64::from(agg.home_goals + agg.away_goals) / agg.matches as f64;
+    }
+    agg
+}
+
+fn batch_timing(results: &[BatchMatchOutcome], threads: usize, wall_clock_ms: f64) -> BatchTiming {
+    let times = results.iter().map(|o| o.elapsed_ms);
+    let total_match_ms: f64 = times.clone().sum();
+    let (min_match_ms, max_match_ms) = if results.is_empty() {
+        (0.0, 0.0)
+    } else {
+        times.fold((f64::MAX, 0.0_f64), |(lo, hi), t| (lo.min(t), hi.max(t)))
+    };
+    BatchTiming {
+        threads,
+        wall_clock_ms,
+        total_match_ms,
+        min_match_ms,
+        max_match_ms,
+        mean_match_ms: if results.is_empty() { 0.0 } else { total_match_ms / results.len() as f64 },
+        speedup: if wall_clock_ms > 0.0 { total_match_ms / wall_clock_ms } else { 0.0 },
+    }
+}
+
+fn elapsed_ms(started: Instant) -> f64 {
+    started.elapsed().as_secs_f64() * 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::simulate_match_v2_json;
+    use serde_json::json;
+
+    fn team_json(prefix: &str) -> serde_json::Value {
+        let positions = [
+            "GK", "LB", "CB", "CB", "RB", "LM", "CM", "CM", "RM", "ST", "ST", "GK", "CB", "CM",
+            "CM", "LW", "RW", "ST",
+        ];
+        let roster: Vec<_> = positions
+            .iter()
+            .enumerate()
+            .map(|(i, pos)| {
+                json!({"name": format!("{prefix}{i}"), "position": pos, "overall": 70, "condition": 3})
+            })
+            .collect();
+        json!({"name": prefix, "formation": "4-4-2", "roster": roster})
+    }
+
+    fn request(seed: u64) -> serde_json::Value {
+        json!({
+            "schema_version": 2,
+            "seed": seed,
+            "home_team": team_json("Home"),
+            "away_team": team_json("Away"),
+        })
+    }
+
+    #[test]
+    fn test_parallel_batch_matches_sequential_results() {
+        let requests: Vec<_> = (0..4).map(|i| request(9_000 + i)).collect();
+        let response = simulate_matches_parallel(requests.clone(), 4).unwrap();
+
+        assert_eq!(response.results.len(), 4);
+        for (outcome, req) in response.results.iter().zip(&requests) {
+            let sequential = simulate_match_v2_json(&req.to_string()).unwrap();
+            let parallel = serde_json::to_string(outcome.result.as_ref().unwrap()).unwrap();
+            assert_eq!(outcome.seed, req["seed"].as_u64());
+            assert!(parallel == sequential, "match {} diverged under parallel run", outcome.index);
+        }
+
+        let agg = &response.aggregate;
+        assert_eq!(agg.matches, 4);
+        assert_eq!(agg.failed, 0);
+        assert_eq!(agg.home_wins + agg.draws + agg.away_wins, 4);
+        assert_eq!(response.timing.threads, 4);
+        assert!(response.timing.max_match_ms >= response.timing.min_match_ms);
+    }
+
+    #[test]
+    fn test_invalid_entry_is_reported_without_aborting_batch() {
+        let requests = json!([request(7), {"schema_version": 2, "seed": 8}]).to_string();
+        let response: serde_json::Value =
+            serde_json::from_str(&simulate_matches_parallel_json(&requests, 2).unwrap()).unwrap();
+
+        assert!(response["results"][0]["result"].is_object());
+        assert_eq!(response["results"][1]["seed"], 8);
+        assert!(response["results"][1]["error"].as_str().unwrap().contains("Invalid JSON request"));
+        assert_eq!(response["aggregate"]["matches"], 1);
+        assert_eq!(response["aggregate"]["failed"], 1);
+    }
+
+    #[test]
+    fn test_rejects_non_array_payload() {
+        assert!(simulate_matches_parallel_json(r#"{"seed": 1}"#, 2).is_err());
+    }
+}
@@ -1,5 +1,7 @@
 use std::time::{Duration, Instant};
 
+use crate::engine::cancellation::{CancellationToken, CANCELLED_CUT_REASON};
+
 /// Simulation budget to prevent infinite loops and timeouts
 /// Tracks progress and allows cooperative cancellation
 #[derive(Debug, Clone)]
@@ -12,6 +14,9 @@ pub struct SimBudget {
     // Progress tracking
     minutes_done: u16,
     events_done: usize,
+
+    /// Host-side abort (checked alongside the limits)
+    cancel: Option<CancellationToken>,
 }
 
 impl Default for SimBudget {
@@ -23,6 +28,7 @@ impl Default for SimBudget {
             max_events: 500,  // 500 events max
             minutes_done: 0,
             events_done: 0,
+            cancel: None,
         }
     }
 }
@@ -37,6 +43,7 @@ impl SimBudget {
             max_events,
             minutes_done: 0,
             events_done: 0,
+            cancel: None,
         }
     }
 
@@ -50,6 +57,12 @@ impl SimBudget {
         Self::new(100, 150, 1000)
     }
 
+    /// Stop early (partial result) once `token` is cancelled
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancel = Some(token);
+        self
+    }
+
     /// Reset the budget timer (for reuse)
     pub fn reset(&mut self) {
         self.start_time = Instant::now();
@@ -74,7 +87,16 @@ impl SimBudget {
     /// Check if any budget limit has been exceeded
     #[inline]
     pub fn is_exceeded(&self) -> bool {
-        self.is_timeout() || self.is_minute_overflow() || self.is_event_overflow()
+        self.is_cancelled()
+            || self.is_timeout()
+            || self.is_minute_overflow()
+            || self.is_event_overflow()
+    }
+
+    /// Check if the host cancelled the run
+    #[inline]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel.as_ref().is_some_and(CancellationToken::is_cancelled)
     }
 
     /// Check if wall clock time exceeded
@@ -107,7 +129,9 @@ impl SimBudget {
 
     /// Get reason for budget exceeded (if any)
     pub fn get_exceeded_reason(&self) -> Option<String> {
-        if self.is_timeout() {
+        if self.is_cancelled() {
+            Some(CANCELLED_CUT_REASON.to_string())
+        } else if self.is_timeout() {
             Some(format!("Wall clock timeout: {}ms > {}ms", self.elapsed_ms(), self.max_wall_ms))
         } else if self.is_minute_overflow() {
             Some(format!("Minute overflow: {} > {}", self.minutes_done, self.max_minutes))
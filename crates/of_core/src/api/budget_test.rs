@@ -183,4 +183,58 @@ mod tests {
         assert_eq!(score1_home, score2_home, "Home scores differ");
         assert_eq!(score1_away, score2_away, "Away scores differ");
     }
+
+    #[test]
+    fn test_cancelled_budget_returns_flagged_partial() {
+        use crate::engine::{CancellationToken, CANCELLED_CUT_REASON};
+
+        let plan = minimal_plan_json();
+        let token = CancellationToken::new();
+        token.cancel();
+        let budget = SimBudget::new(1000, 120, 500).with_cancellation(token);
+
+        let result = simulate_match_json_budget(&plan, budget).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
+        assert_eq!(parsed["partial"], json!(true));
+        assert_eq!(parsed["reason"], json!(CANCELLED_CUT_REASON));
+        assert_eq!(parsed["determinism"]["cut_reason"], json!(CANCELLED_CUT_REASON));
+    }
+
+    #[test]
+    fn test_progress_run_resumes_to_identical_result() {
+        let plan = minimal_plan_json();
+        let uninterrupted =
+            simulate_match_json_budget(&plan, SimBudget::new(60_000, 200, 5_000)).unwrap();
+
+        let mut minutes_seen = Vec::new();
+        let first = simulate_match_json_budget_with_progress(
+            &plan,
+            SimBudget::new(60_000, 10, 5_000),
+            |p| minutes_seen.push(p.minutes_simulated),
+        )
+        .unwrap();
+        assert_eq!(minutes_seen, (1..=10).collect::<Vec<u16>>());
+        let partial: serde_json::Value = serde_json::from_str(&first.response_json).unwrap();
+        assert_eq!(partial["partial"], json!(true));
+        let token = first.resume.expect("minute budget cuts the run");
+        assert_eq!(token.minutes_simulated(), 10);
+        assert_eq!(token.match_minute(), first.progress.match_minute);
+
+        let mut last = None;
+        let resumed = resume_match_json_budget(token, SimBudget::new(60_000, 200, 5_000), |p| {
+            last = Some(*p)
+        })
+        .unwrap();
+        assert!(resumed.resume.is_none());
+        assert!(last.unwrap().minutes_simulated > 10);
+        assert_eq!(resumed.progress.fraction(), 1.0);
+
+        let full: serde_json::Value = serde_json::from_str(&uninterrupted).unwrap();
+        let resumed: serde_json::Value = serde_json::from_str(&resumed.response_json).unwrap();
+        assert_eq!(resumed.get("partial"), None);
+        assert_eq!(resumed["score_home"], full["score_home"]);
+        assert_eq!(resumed["score_away"], full["score_away"]);
+        assert_eq!(resumed["events"], full["events"]);
+        assert_eq!(resumed["statistics"], full["statistics"]);
+    }
 }
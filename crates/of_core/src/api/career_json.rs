@@ -0,0 +1,207 @@
+// Career JSON API Layer
+// Fixture calendar + congestion, squad rotation advisor, Elo rating ladder, career highlights
+
+use crate::career::{
+    advise_rotation, compile_career_highlights, congestion_effect, extract_career_highlights,
+    CareerHighlightFilter, CongestionEffect, FixtureCalendar, LadderKind, LadderMatch,
+    RatingLadder, RotationCandidate, ScheduleConfig, SquadLoad, UpcomingFixture,
+};
+use crate::models::chemistry::MatchAppearance;
+use crate::models::MatchResult;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+// ========== Request/Response Structures ==========
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RotationAdviceRequest {
+    pub formation: String,
+    pub candidates: Vec<RotationCandidate>,
+    /// Career fixture list from today onwards (first = match being selected for)
+    #[serde(default)]
+    pub fixtures: Vec<UpcomingFixture>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LeaderboardRequest {
+    #[serde(default)]
+    pub kind: LadderKind,
+    /// Max rows (0 = all)
+    #[serde(default)]
+    pub limit: usize,
+    /// Rank a ladder replayed from saved match history for this club id
+    /// instead of the persisted ladder
+    #[serde(default)]
+    pub from_history: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ScheduleFixturesRequest {
+    pub opponents: Vec<String>,
+    #[serde(default)]
+    pub config: ScheduleConfig,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RecordFixtureLineupRequest {
+    pub fixture_id: u32,
+    pub appearances: Vec<MatchAppearance>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CongestionReportRequest {
+    pub today: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PlayerCongestion {
+    pub player: String,
+    #[serde(flatten)]
+    pub effect: CongestionEffect,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CongestionReport {
+    /// Rotation advisor `fixtures` input
+    pub upcoming: Vec<UpcomingFixture>,
+    /// MatchRequestV2 `home_load` input
+    pub load: SquadLoad,
+    pub players: Vec<PlayerCongestion>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RecordMatchHighlightsRequest {
+    /// `MatchRecord.id` in the saved match history
+    pub match_id: u32,
+    pub match_result: MatchResult,
+    /// Player UID → name as used in `match_result` (squad players to track)
+    pub players: HashMap<String, String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CareerHighlightsRequest {
+    pub player_uid: String,
+    #[serde(flatten)]
+    pub filter: CareerHighlightFilter,
+}
+
+// ========== API Functions ==========
+
+/// Suggest starting XI + bench; returns `RotationAdvice` JSON
+pub fn advise_rotation_json(request_json: &str) -> Result<String, String> {
+    let request: RotationAdviceRequest =
+        serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
+
+    let advice = advise_rotation(&request.formation, &request.candidates, &request.fixtures)?;
+
+    serde_json::to_string(&advice).map_err(|e| format!("Failed to serialize advice: {}", e))
+}
+
+/// Apply a finished match to the saved rating ladder; returns `LadderUpdate` JSON
+pub fn record_ladder_match_json(request_json: &str) -> Result<String, String> {
+    let ladder_match: LadderMatch =
+        serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
+
+    let update = crate::state::get_state_mut().record_ladder_match(&ladder_match);
+
+    serde_json::to_string(&update).map_err(|e| format!("Failed to serialize update: {}", e))
+}
+
+/// Ranked ladder rows; returns `LeaderboardRow[]` JSON
+pub fn get_ladder_leaderboard_json(request_json: &str) -> Result<String, String> {
+    let request: LeaderboardRequest =
+        serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
+
+    let rows = {
+        let state = crate::state::get_state();
+        match &request.from_history {
+            Some(club) => RatingLadder::from_history(club, &state.match_history)
+                .leaderboard(request.kind, request.limit),
+            None => state.rating_ladder.leaderboard(request.kind, request.limit),
+        }
+    };
+
+    serde_json::to_string(&rows).map_err(|e| format!("Failed to serialize leaderboard: {}", e))
+}
+
+/// Generate and store the career fixture calendar; returns `FixtureCalendar` JSON
+pub fn schedule_fixtures_json(request_json: &str) -> Result<String, String> {
+    let request: ScheduleFixturesRequest =
+        serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
+
+    let calendar = FixtureCalendar::generate(&request.opponents, &request.config)?;
+    crate::state::get_state_mut().fixture_calendar = calendar.clone();
+
+    serde_json::to_string(&calendar).map_err(|e| format!("Failed to serialize calendar: {}", e))
+}
+
+/// Store who played a calendar fixture (feeds rest-day tracking)
+pub fn record_fixture_lineup_json(request_json: &str) -> Result<String, String> {
+    let request: RecordFixtureLineupRequest =
+        serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
+
+    crate::state::get_state_mut()
+        .fixture_calendar
+        .record_lineup(request.fixture_id, request.appearances)?;
+
+    Ok(r#"{"success":true}"#.to_string())
+}
+
+/// Rest days, congestion effects and upcoming fixtures as of `today`
+pub fn get_congestion_report_json(request_json: &str) -> Result<String, String> {
+    let request: CongestionReportRequest =
+        serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
+
+    let report = {
+        let state = crate::state::get_state();
+        let load = state.squad_load(request.today);
+        let players = load
+            .players
+            .iter()
+            .map(|p| PlayerCongestion {
+                player: p.player.clone(),
+                effect: congestion_effect(&p.match_days, request.today),
+            })
+            .collect();
+        CongestionReport { upcoming: state.fixture_calendar.upcoming(request.today), load, players }
+    };
+
+    serde_json::to_string(&report).map_err(|e| format!("Failed to serialize report: {}", e))
+}
+
+/// Extract squad player highlights from a finished match and store them on its
+/// saved `MatchRecord`; returns `CareerHighlight[]` JSON
+pub fn record_match_highlights_json(request_json: &str) -> Result<String, String> {
+    let request: RecordMatchHighlightsRequest =
+        serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
+
+    let highlights =
+        extract_career_highlights(&request.match_result, request.match_id, &request.players);
+    {
+        let mut state = crate::state::get_state_mut();
+        let record = state
+            .match_history
+            .iter_mut()
+            .find(|record| record.id == request.match_id)
+            .ok_or_else(|| format!("Match {} not found in match history", request.match_id))?;
+        record.highlights = highlights.clone();
+    }
+
+    serde_json::to_string(&highlights)
+        .map_err(|e| format!("Failed to serialize highlights: {}", e))
+}
+
+/// One player's season/career montage from saved match history;
+/// returns `CareerHighlightManifest` JSON
+pub fn compile_career_highlights_json(request_json: &str) -> Result<String, String> {
+    let request: CareerHighlightsRequest =
+        serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
+
+    let manifest = compile_career_highlights(
+        &crate::state::get_state().match_history,
+        &request.player_uid,
+        &request.filter,
+    );
+
+    serde_json::to_string(&manifest).map_err(|e| format!("Failed to serialize manifest: {}", e))
+}
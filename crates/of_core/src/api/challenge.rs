@@ -0,0 +1,379 @@
+// Async PvP Challenge API Layer
+// Player A packs squad + tactics + deck into a signed payload; player B simulates
+// against it locally, and either side can re-simulate to verify the result hash.
+//
+// The signature is a SHA-256 integrity seal (optionally keyed with a secret shared
+// by the game server). It detects edits to the payload; it is not a public-key
+// signature and cannot prove authorship on its own.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::json_api::{match_plan_from_match_request_v2, MatchRequestV2, TeamDataV2};
+use crate::engine::MatchEngine;
+use crate::models::MatchEvent;
+use crate::tactics::team_instructions::TeamInstructions;
+
+/// Challenge payload format version
+pub const CHALLENGE_FORMAT_VERSION: u8 = 1;
+
+const SIGNATURE_DOMAIN: &[u8] = b"of_core/pvp_challenge/v1";
+const SEED_DOMAIN: &[u8] = b"of_core/pvp_seed/v1";
+const RESULT_DOMAIN: &[u8] = b"of_core/pvp_result/v1";
+
+// ========== Payload Structures ==========
+
+/// Deck effect as a (mod_id, value) pair (see `TeamMatchModifiers::apply_mod_id`)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DeckModEntry {
+    pub mod_id: u8,
+    pub value: f32,
+}
+
+/// One side of a challenge: squad, tactics, deck effects and a seed nonce
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChallengeSide {
+    pub team: TeamDataV2,
+    #[serde(default)]
+    pub instructions: Option<TeamInstructions>,
+    #[serde(default)]
+    pub deck_mods: Vec<DeckModEntry>,
+    /// Seed contribution of this side
+    pub nonce: u64,
+}
+
+/// Signed challenge exported by player A
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChallengePayload {
+    pub format_version: u8,
+    pub challenger_id: String,
+    pub side: ChallengeSide,
+    /// sha256 of the canonical JSON body (hex)
+    pub body_hash: String,
+    /// sha256(domain | signing key | body_hash) (hex)
+    pub signature: String,
+}
+
+#[derive(Serialize)]
+struct ChallengeBody<'a> {
+    format_version: u8,
+    challenger_id: &'a str,
+    side: &'a ChallengeSide,
+}
+
+#[derive(Serialize)]
+struct ResultBody<'a> {
+    signature: &'a str,
+    seed: u64,
+    score_home: u8,
+    score_away: u8,
+    events: &'a [MatchEvent],
+}
+
+// ========== Request/Response Structures ==========
+
+#[derive(Debug, Deserialize)]
+pub struct PackChallengeRequest {
+    pub challenger_id: String,
+    pub side: ChallengeSide,
+    /// Shared secret mixed into the signature (empty = unkeyed)
+    #[serde(default)]
+    pub signing_key: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SimulateChallengeRequest {
+    pub challenge: ChallengePayload,
+    /// Responder side (plays at home)
+    pub responder: ChallengeSide,
+    #[serde(default)]
+    pub signing_key: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChallengeOutcome {
+    pub seed: u64,
+    pub score_home: u8,
+    pub score_away: u8,
+    pub result_hash: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct VerifyChallengeRequest {
+    pub challenge: ChallengePayload,
+    pub responder: ChallengeSide,
+    #[serde(default)]
+    pub signing_key: String,
+    /// Result hash claimed by the responder
+    pub result_hash: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VerifyChallengeResponse {
+    pub valid: bool,
+    pub expected_hash: String,
+    pub claimed_hash: String,
+    pub score_home: u8,
+    pub score_away: u8,
+}
+
+// ========== Core Functions ==========
+
+/// Seal a challenge side into a signed payload
+pub fn pack_challenge(
+    challenger_id: &str,
+    side: ChallengeSide,
+    signing_key: &str,
+) -> Result<ChallengePayload, String> {
+    let body_hash = body_hash(CHALLENGE_FORMAT_VERSION, challenger_id, &side)?;
+    let signature = sign(signing_key, &body_hash);
+
+    Ok(ChallengePayload {
+        format_version: CHALLENGE_FORMAT_VERSION,
+        challenger_id: challenger_id.to_string(),
+        side,
+        body_hash,
+        signature,
+    })
+}
+
+/// Check the payload body and signature against `signing_key`
+pub fn verify_challenge_payload(
+    payload: &ChallengePayload,
+    signing_key: &str,
+) -> Result<(), String> {
+    if payload.format_version != CHALLENGE_FORMAT_VERSION {
+        return Err(format!("Unsupported challenge format version: {}", payload.format_version));
+    }
+    let expected = body_hash(payload.format_version, &payload.challenger_id, &payload.side)?;
+    if expected != payload.body_hash {
+        return Err("Challenge body hash mismatch".to_string());
+    }
+    if sign(signing_key, &payload.body_hash) != payload.signature {
+        return Err("Challenge signature mismatch".to_string());
+    }
+    Ok(())
+}
+
+/// Match seed both players can derive from the signed payload and the responder nonce
+pub fn derive_challenge_seed(payload: &ChallengePayload, responder_nonce: u64) -> u64 {
+    let mut hasher = Sha256::new();
+    hasher.update(SEED_DOMAIN);
+    hasher.update(payload.signature.as_bytes());
+    hasher.update(payload.side.nonce.to_le_bytes());
+    hasher.update(responder_nonce.to_le_bytes());
+    let digest = hasher.finalize();
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&digest[..8]);
+    u64::from_le_bytes(bytes)
+}
+
+/// Simulate the challenge (responder at home, challenger away)
+pub fn simulate_challenge(
+    payload: &ChallengePayload,
+    responder: &ChallengeSide,
+    signing_key: &str,
+) -> Result<ChallengeOutcome, String> {
+    verify_challenge_payload(payload, signing_key)?;
+
+    let seed = derive_challenge_seed(payload, responder.nonce);
+    let request = MatchRequestV2 {
+        schema_version: 2,
+        seed,
+        home_team: responder.team.clone(),
+        away_team: payload.side.team.clone(),
+        user_player: None,
+        home_instructions: responder.instructions.clone(),
+        away_instructions: payload.side.instructions.clone(),
+        enable_position_tracking: false,
+        use_real_names: false,
+        home_ai_difficulty: None,
+        away_ai_difficulty: None,
+        pitch: None,
+        home_chemistry: None,
+        away_chemistry: None,
+        home_load: None,
+        away_load: None,
+        stadium: None,
+        use_home_stadium: false,
+        attendance: None,
+        conditions: None,
+        penalty_shootout: None,
+        highlight_config: None,
+        home_match_prep: None,
+        away_match_prep: None,
+        match_day: None,
+        referee: None,
+        referee_seed: None,
+    };
+    let (mut plan, _) = match_plan_from_match_request_v2(request)?;
+    let deck = crate::engine::ModifierSource::Deck;
+    plan.modifier_stack.push_mod_list(true, deck, "deck", &mod_list(&responder.deck_mods));
+    plan.modifier_stack.push_mod_list(false, deck, "deck", &mod_list(&payload.side.deck_mods));
+
+    // No env exp-config here: both players must run the exact same engine setup.
+    let mut engine = MatchEngine::new(plan)?;
+    let result = engine.simulate();
+
+    let result_hash = canonical_hash(
+        RESULT_DOMAIN,
+        &ResultBody {
+            signature: &payload.signature,
+            seed,
+            score_home: result.score_home,
+            score_away: result.score_away,
+            events: &result.events,
+        },
+    )?;
+
+    Ok(ChallengeOutcome {
+        seed,
+        score_home: result.score_home,
+        score_away: result.score_away,
+        result_hash,
+    })
+}
+
+fn mod_list(deck_mods: &[DeckModEntry]) -> Vec<(u8, f32)> {
+    deck_mods.iter().map(|m| (m.mod_id, m.value)).collect()
+}
+
+fn body_hash(
+    format_version: u8,
+    challenger_id: &str,
+    side: &ChallengeSide,
+) -> Result<String, String> {
+    canonical_hash(SIGNATURE_DOMAIN, &ChallengeBody { format_version, challenger_id, side })
+}
+
+fn sign(signing_key: &str, body_hash: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(SIGNATURE_DOMAIN);
+    hasher.update((signing_key.len() as u64).to_le_bytes());
+    hasher.update(signing_key.as_bytes());
+    hasher.update(body_hash.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+/// sha256 over JSON with object keys sorted (HashMap order is not stable)
+pub(super) fn canonical_hash<T: Serialize>(domain: &[u8], value: &T) -> Result<String, String> {
+    // serde_json::Value objects are BTreeMap-backed, so re-serializing sorts keys.
+    let value = serde_json::to_value(value)
+        .map_err(|e| format!("Failed to serialize challenge data: {}", e))?;
+    let bytes = serde_json::to_vec(&value)
+        .map_err(|e| format!("Failed to serialize challenge data: {}", e))?;
+
+    let mut hasher = Sha256::new();
+    hasher.update(domain);
+    hasher.update(&bytes);
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+// ========== API Functions ==========
+
+/// Pack a challenge; returns `ChallengePayload` JSON
+pub fn pack_challenge_json(request_json: &str) -> Result<String, String> {
+    let request: PackChallengeRequest =
+        serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
+
+    let payload = pack_challenge(&request.challenger_id, request.side, &request.signing_key)?;
+
+    serde_json::to_string(&payload).map_err(|e| format!("Failed to serialize challenge: {}", e))
+}
+
+/// Simulate a received challenge; returns `ChallengeOutcome` JSON
+pub fn simulate_challenge_json(request_json: &str) -> Result<String, String> {
+    let request: SimulateChallengeRequest =
+        serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
+
+    let outcome = simulate_challenge(&request.challenge, &request.responder, &request.signing_key)?;
+
+    serde_json::to_string(&outcome).map_err(|e| format!("Failed to serialize outcome: {}", e))
+}
+
+/// Re-simulate and compare against a claimed result hash; returns `VerifyChallengeResponse` JSON
+pub fn verify_challenge_result_json(request_json: &str) -> Result<String, String> {
+    let request: VerifyChallengeRequest =
+        serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
+
+    let outcome = simulate_challenge(&request.challenge, &request.responder, &request.signing_key)?;
+
+    let response = VerifyChallengeResponse {
+        valid: outcome.result_hash == request.result_hash,
+        expected_hash: outcome.result_hash,
+        claimed_hash: request.result_hash,
+        score_home: outcome.score_home,
+        score_away: outcome.score_away,
+    };
+    serde_json::to_string(&response).map_err(|e| format!("Failed to serialize response: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn side_json(prefix: &str, nonce: u64) -> serde_json::Value {
+        let positions = [
+            "GK", "LB", "CB", "CB", "RB", "LM", "CM", "CM", "RM", "ST", "ST", "GK", "CB", "CM",
+            "CM", "LW", "RW", "ST",
+        ];
+        let roster: Vec<_> = positions
+            .iter()
+            .enumerate()
+            .map(|(i, pos)| {
+                json!({"name": format!("{prefix}{i}"), "position": pos, "overall": 70, "condition": 3})
+            })
+            .collect();
+        json!({
+            "team": {"name": prefix, "formation": "4-4-2", "roster": roster},
+            "deck_mods": [{"mod_id": 1, "value": 1.05}],
+            "nonce": nonce
+        })
+    }
+
+    #[test]
+    fn test_challenge_round_trip_and_tamper_detection() {
+        let pack_request = json!({
+            "challenger_id": "player_a",
+            "side": side_json("A", 11),
+            "signing_key": "secret"
+        });
+        let payload_json = pack_challenge_json(&pack_request.to_string()).unwrap();
+        let payload: serde_json::Value = serde_json::from_str(&payload_json).unwrap();
+
+        let sim_request = json!({
+            "challenge": payload,
+            "responder": side_json("B", 22),
+            "signing_key": "secret"
+        });
+        let outcome: ChallengeOutcome =
+            serde_json::from_str(&simulate_challenge_json(&sim_request.to_string()).unwrap())
+                .unwrap();
+
+        let mut verify_request = sim_request.clone();
+        verify_request["result_hash"] = json!(outcome.result_hash);
+        let verified: VerifyChallengeResponse = serde_json::from_str(
+            &verify_challenge_result_json(&verify_request.to_string()).unwrap(),
+        )
+        .unwrap();
+        assert!(verified.valid);
+        assert_eq!(verified.score_home, outcome.score_home);
+
+        // Forged result hash
+        verify_request["result_hash"] = json!("00");
+        let forged: VerifyChallengeResponse = serde_json::from_str(
+            &verify_challenge_result_json(&verify_request.to_string()).unwrap(),
+        )
+        .unwrap();
+        assert!(!forged.valid);
+
+        // Edited squad / wrong key
+        let mut tampered = sim_request.clone();
+        tampered["challenge"]["side"]["team"]["roster"][9]["overall"] = json!(99);
+        assert!(simulate_challenge_json(&tampered.to_string()).unwrap_err().contains("body hash"));
+        let mut wrong_key = sim_request;
+        wrong_key["signing_key"] = json!("other");
+        assert!(simulate_challenge_json(&wrong_key.to_string()).unwrap_err().contains("signature"));
+    }
+}
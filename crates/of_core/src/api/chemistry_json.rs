@@ -0,0 +1,116 @@
+// Squad Chemistry JSON API Layer
+// Chemistry matrix and per-link breakdown for the squad screen
+// (the matrix feeds MatchRequestV2 home/away_chemistry)
+
+use crate::models::chemistry::{
+    chemistry_links, ChemistryLink, ChemistryProfile, MatchAppearance, SharedMinutes,
+    SquadChemistry,
+};
+use serde::{Deserialize, Serialize};
+
+// ========== Request/Response Structures ==========
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SquadChemistryRequest {
+    pub players: Vec<ChemistryProfile>,
+    /// Extra lineups (one entry per match) on top of saved history
+    #[serde(default)]
+    pub history: Vec<Vec<MatchAppearance>>,
+    /// Include lineups recorded in the global game state match history, and fill
+    /// missing `clubs` from the saved players' career history
+    #[serde(default)]
+    pub use_saved_history: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SquadChemistryResponse {
+    pub success: bool,
+    pub chemistry: Option<SquadChemistry>,
+    pub player_scores: Vec<u8>,
+    pub team_score: u8,
+    /// Every pair with its score breakdown
+    #[serde(default)]
+    pub links: Vec<ChemistryLink>,
+    pub error: Option<String>,
+}
+
+// ========== API Functions ==========
+
+/// Compute the squad chemistry matrix
+pub fn calculate_squad_chemistry_json(request_json: &str) -> String {
+    let mut request: SquadChemistryRequest = match serde_json::from_str(request_json) {
+        Ok(req) => req,
+        Err(e) => {
+            return serde_json::to_string(&SquadChemistryResponse {
+                success: false,
+                chemistry: None,
+                player_scores: vec![],
+                team_score: 0,
+                links: vec![],
+                error: Some(format!("Invalid request format: {}", e)),
+            })
+            .unwrap_or_else(|_| r#"{"success":false,"error":"Serialization failed"}"#.to_string());
+        }
+    };
+
+    let mut shared = if request.use_saved_history {
+        let state = crate::state::get_state();
+        for profile in request.players.iter_mut().filter(|p| p.clubs.is_empty()) {
+            profile.clubs = state.career_clubs(&profile.name);
+        }
+        state.shared_minutes()
+    } else {
+        SharedMinutes::new()
+    };
+    for appearances in &request.history {
+        shared.record_match(appearances);
+    }
+
+    let chemistry = SquadChemistry::compute(&request.players, &shared);
+    let player_scores = (0..chemistry.players.len()).map(|i| chemistry.player_score(i)).collect();
+    let team_score = chemistry.team_score();
+    let links = chemistry_links(&request.players, &shared);
+
+    serde_json::to_string(&SquadChemistryResponse {
+        success: true,
+        chemistry: Some(chemistry),
+        player_scores,
+        team_score,
+        links,
+        error: None,
+    })
+    .unwrap_or_else(|_| r#"{"success":false,"error":"Serialization failed"}"#.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_squad_chemistry_json() {
+        let request = r#"{
+            "players": [
+                {"name": "A", "nationality": "KOR", "personality": "Leader", "clubs": ["Ulsan"]},
+                {"name": "B", "nationality": "KOR", "personality": "Workhorse"},
+                {"name": "C", "clubs": ["Ulsan"]}
+            ],
+            "history": [[{"name": "A"}, {"name": "B"}]]
+        }"#;
+
+        let response: SquadChemistryResponse =
+            serde_json::from_str(&calculate_squad_chemistry_json(request)).unwrap();
+        assert!(response.success);
+        let chemistry = response.chemistry.unwrap();
+        assert_eq!(chemistry.matrix.len(), 3);
+        assert_eq!(response.player_scores.len(), 3);
+        assert!(chemistry.pair("A", "B").unwrap() > chemistry.pair("A", "C").unwrap());
+        assert_eq!(response.links.len(), 3);
+        assert!(response.links[0].history > 0);
+        assert_eq!(response.links[0].nationality, 10);
+        assert_eq!(response.links[1].shared_club.as_deref(), Some("Ulsan"));
+
+        let bad: SquadChemistryResponse =
+            serde_json::from_str(&calculate_squad_chemistry_json("{")).unwrap();
+        assert!(!bad.success);
+    }
+}
@@ -0,0 +1,23 @@
+// Commentary JSON API Layer
+// Localized (ko/en) commentary lines generated from a match result's event log
+
+use crate::analysis::commentary::generate_commentary;
+use crate::models::MatchResult;
+
+// ========== API Functions ==========
+
+/// Generate commentary for `lang` (`ko` / `en`); returns `MatchCommentary` JSON.
+///
+/// Accepts a `MatchResult` directly or wrapped as `{ "match_result": { ... } }`.
+pub fn generate_commentary_json(match_result_json: &str, lang: &str) -> Result<String, String> {
+    let mut value: serde_json::Value = serde_json::from_str(match_result_json)
+        .map_err(|e| format!("Invalid JSON request: {}", e))?;
+    if let Some(inner) = value.get_mut("match_result") {
+        value = inner.take();
+    }
+    let result: MatchResult =
+        serde_json::from_value(value).map_err(|e| format!("Invalid match result: {}", e))?;
+
+    let commentary = generate_commentary(&result, lang)?;
+    serde_json::to_string(&commentary).map_err(|e| format!("Failed to serialize commentary: {}", e))
+}
@@ -0,0 +1,445 @@
+// Daily Challenge API Layer
+// One challenge per calendar date: the date seeds the opponent squad, squad
+// constraints and scoring rules, so every player faces the identical match.
+// Results carry a hash over the submitted squad and the full event log; the
+// leaderboard server re-simulates to verify a submission.
+
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::challenge::canonical_hash;
+use super::json_api::{match_plan_from_match_request_v2, MatchRequestV2, TeamDataV2};
+use crate::engine::MatchEngine;
+use crate::models::MatchEvent;
+use crate::story::youth_tournament::{
+    generate_youth_opponent, YouthAgeGroup, YouthOpponentRequest,
+};
+use crate::tactics::team_instructions::TeamInstructions;
+
+/// Daily challenge generator version (bump when generation or scoring changes)
+pub const DAILY_CHALLENGE_VERSION: u8 = 1;
+
+const SEED_DOMAIN: &[u8] = b"of_core/daily_seed/v1";
+const RESULT_DOMAIN: &[u8] = b"of_core/daily_result/v1";
+
+/// Formations a challenge may impose on the player's squad
+const FORCED_FORMATIONS: [&str; 4] = ["4-4-2", "4-3-3", "4-2-3-1", "3-5-2"];
+
+// ========== Challenge Structures ==========
+
+/// Theme of the day; picks the scoring table
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DailyRuleset {
+    Balanced,
+    /// Goals are worth the most
+    GoalFest,
+    /// Clean sheets are worth the most
+    Fortress,
+    /// Any result against the odds pays; goals conceded cost little
+    Underdog,
+}
+
+impl DailyRuleset {
+    const ALL: [DailyRuleset; 4] = [
+        DailyRuleset::Balanced,
+        DailyRuleset::GoalFest,
+        DailyRuleset::Fortress,
+        DailyRuleset::Underdog,
+    ];
+
+    pub fn scoring(self) -> DailyScoring {
+        let (win, draw, per_goal, per_goal_conceded, clean_sheet) = match self {
+            DailyRuleset::Balanced => (300, 100, 50, 30, 100),
+            DailyRuleset::GoalFest => (200, 50, 120, 20, 0),
+            DailyRuleset::Fortress => (250, 150, 30, 80, 300),
+            DailyRuleset::Underdog => (400, 200, 60, 10, 50),
+        };
+        DailyScoring { win, draw, per_goal, per_goal_conceded, clean_sheet }
+    }
+}
+
+/// Points table for a challenge result (total never drops below zero)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DailyScoring {
+    pub win: u32,
+    pub draw: u32,
+    pub per_goal: u32,
+    pub per_goal_conceded: u32,
+    pub clean_sheet: u32,
+}
+
+impl DailyScoring {
+    pub fn points(&self, goals_for: u8, goals_against: u8) -> u32 {
+        let result = match goals_for.cmp(&goals_against) {
+            std::cmp::Ordering::Greater => self.win,
+            std::cmp::Ordering::Equal => self.draw,
+            std::cmp::Ordering::Less => 0,
+        };
+        let clean_sheet = if goals_against == 0 { self.clean_sheet } else { 0 };
+        (result + clean_sheet + goals_for as u32 * self.per_goal)
+            .saturating_sub(goals_against as u32 * self.per_goal_conceded)
+    }
+}
+
+/// Squad limits for the player's team
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DailyConstraints {
+    /// Required formation (None = free choice)
+    pub formation: Option<String>,
+    /// Cap on the starting XI's average overall
+    pub max_average_overall: u8,
+    /// Cap on any single squad player's overall
+    pub max_player_overall: u8,
+}
+
+/// The challenge everyone plays on `date`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DailyChallenge {
+    pub version: u8,
+    /// "daily-YYYY-MM-DD"
+    pub challenge_id: String,
+    pub date: String,
+    /// Match seed shared by all players
+    pub seed: u64,
+    pub ruleset: DailyRuleset,
+    pub scoring: DailyScoring,
+    pub constraints: DailyConstraints,
+    /// Opponent squad (plays away)
+    pub opponent: TeamDataV2,
+    pub opponent_average_overall: u8,
+}
+
+/// A player's squad for the day
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DailyChallengeEntry {
+    pub date: String,
+    pub player_id: String,
+    /// Player's squad (plays at home)
+    pub team: TeamDataV2,
+    #[serde(default)]
+    pub instructions: Option<TeamInstructions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DailyChallengeResult {
+    pub challenge_id: String,
+    pub player_id: String,
+    pub seed: u64,
+    pub goals_for: u8,
+    pub goals_against: u8,
+    pub points: u32,
+    /// Leaderboard submission hash (re-simulate with the entry to verify)
+    pub result_hash: String,
+}
+
+#[derive(Serialize)]
+struct ResultBody<'a> {
+    version: u8,
+    challenge_id: &'a str,
+    seed: u64,
+    player_id: &'a str,
+    team: &'a TeamDataV2,
+    instructions: &'a Option<TeamInstructions>,
+    goals_for: u8,
+    goals_against: u8,
+    points: u32,
+    events: &'a [MatchEvent],
+}
+
+// ========== Request/Response Structures ==========
+
+#[derive(Debug, Deserialize)]
+pub struct DailyChallengeRequest {
+    /// "YYYY-MM-DD"
+    pub date: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct VerifyDailyResultRequest {
+    pub entry: DailyChallengeEntry,
+    /// Result hash claimed by the player
+    pub result_hash: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VerifyDailyResultResponse {
+    pub valid: bool,
+    pub expected_hash: String,
+    pub claimed_hash: String,
+    pub points: u32,
+}
+
+// ========== Core Functions ==========
+
+/// Canonical "YYYY-MM-DD" form of a challenge date
+fn normalize_date(date: &str) -> Result<String, String> {
+    chrono::NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d")
+        .map(|d| d.format("%Y-%m-%d").to_string())
+        .map_err(|e| format!("Invalid challenge date '{}': {}", date, e))
+}
+
+/// Global seed for a date; identical on every client
+pub fn daily_seed(date: &str) -> Result<u64, String> {
+    let date = normalize_date(date)?;
+    let mut hasher = Sha256::new();
+    hasher.update(SEED_DOMAIN);
+    hasher.update([DAILY_CHALLENGE_VERSION]);
+    hasher.update(date.as_bytes());
+    let digest = hasher.finalize();
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&digest[..8]);
+    Ok(u64::from_le_bytes(bytes))
+}
+
+/// Derive the challenge for `date` (deterministic)
+pub fn generate_daily_challenge(date: &str) -> Result<DailyChallenge, String> {
+    let date = normalize_date(date)?;
+    let seed = daily_seed(&date)?;
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+    let ruleset = DailyRuleset::ALL[rng.gen_range(0..DailyRuleset::ALL.len())];
+    let opponent = generate_youth_opponent(&YouthOpponentRequest {
+        // No trailing digits: chapter progression bonus stays off
+        chapter_id: format!("daily:{}:opponent", date),
+        age_group: YouthAgeGroup::U19,
+        fixture_index: 0,
+        strength_offset: rng.gen_range(-5..=15),
+    })?;
+
+    let formation = rng
+        .gen_bool(0.5)
+        .then(|| FORCED_FORMATIONS[rng.gen_range(0..FORCED_FORMATIONS.len())].to_string());
+    let max_average_overall = opponent.average_overall.saturating_add(rng.gen_range(0..=6));
+    let constraints = DailyConstraints {
+        formation,
+        max_average_overall,
+        max_player_overall: max_average_overall.saturating_add(10).min(99),
+    };
+
+    Ok(DailyChallenge {
+        version: DAILY_CHALLENGE_VERSION,
+        challenge_id: format!("daily-{}", date),
+        date,
+        seed,
+        ruleset,
+        scoring: ruleset.scoring(),
+        constraints,
+        opponent: opponent.team,
+        opponent_average_overall: opponent.average_overall,
+    })
+}
+
+/// Play an entry against its date's challenge
+pub fn play_daily_challenge(entry: &DailyChallengeEntry) -> Result<DailyChallengeResult, String> {
+    let challenge = generate_daily_challenge(&entry.date)?;
+    if entry.player_id.trim().is_empty() {
+        return Err("player_id must not be empty".to_string());
+    }
+
+    let request = MatchRequestV2 {
+        schema_version: 2,
+        seed: challenge.seed,
+        home_team: entry.team.clone(),
+        away_team: challenge.opponent.clone(),
+        user_player: None,
+        home_instructions: entry.instructions.clone(),
+        away_instructions: None,
+        enable_position_tracking: false,
+        use_real_names: false,
+        home_ai_difficulty: None,
+        away_ai_difficulty: None,
+        pitch: None,
+        home_chemistry: None,
+        away_chemistry: None,
+        home_load: None,
+        away_load: None,
+        stadium: None,
+        use_home_stadium: false,
+        attendance: None,
+        conditions: None,
+        penalty_shootout: None,
+        highlight_config: None,
+        home_match_prep: None,
+        away_match_prep: None,
+        match_day: None,
+        referee: None,
+        referee_seed: None,
+    };
+    let (plan, _) = match_plan_from_match_request_v2(request)?;
+    check_constraints(&challenge.constraints, &plan.home_team)?;
+
+    // No env exp-config here: every player must run the exact same engine setup.
+    let mut engine = MatchEngine::new(plan)?;
+    let result = engine.simulate();
+    let (goals_for, goals_against) = (result.score_home, result.score_away);
+    let points = challenge.scoring.points(goals_for, goals_against);
+
+    let result_hash = canonical_hash(
+        RESULT_DOMAIN,
+        &ResultBody {
+            version: challenge.version,
+            challenge_id: &challenge.challenge_id,
+            seed: challenge.seed,
+            player_id: &entry.player_id,
+            team: &entry.team,
+            instructions: &entry.instructions,
+            goals_for,
+            goals_against,
+            points,
+            events: &result.events,
+        },
+    )?;
+
+    Ok(DailyChallengeResult {
+        challenge_id: challenge.challenge_id,
+        player_id: entry.player_id.clone(),
+        seed: challenge.seed,
+        goals_for,
+        goals_against,
+        points,
+        result_hash,
+    })
+}
+
+/// Resolved squad (starters first) against the day's limits
+fn check_constraints(
+    constraints: &DailyConstraints,
+    team: &crate::models::Team,
+) -> Result<(), String> {
+    if let Some(required) = &constraints.formation {
+        if team.formation.code() != required {
+            return Err(format!(
+                "Daily challenge requires formation {}, got {}",
+                required,
+                team.formation.code()
+            ));
+        }
+    }
+    if let Some(player) = team.players.iter().find(|p| p.overall > constraints.max_player_overall) {
+        return Err(format!(
+            "{} (overall {}) exceeds the daily cap of {}",
+            player.name, player.overall, constraints.max_player_overall
+        ));
+    }
+    let starters = &team.players[..team.players.len().min(11)];
+    let average =
+        starters.iter().map(|p| p.overall as u32).sum::<u32>() / starters.len().max(1) as u32;
+    if average > constraints.max_average_overall as u32 {
+        return Err(format!(
+            "Starting XI average overall {} exceeds the daily cap of {}",
+            average, constraints.max_average_overall
+        ));
+    }
+    Ok(())
+}
+
+// ========== API Functions ==========
+
+/// Today's (or any date's) challenge; returns `DailyChallenge` JSON
+pub fn get_daily_challenge_json(request_json: &str) -> Result<String, String> {
+    let request: DailyChallengeRequest =
+        serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
+
+    let challenge = generate_daily_challenge(&request.date)?;
+
+    serde_json::to_string(&challenge).map_err(|e| format!("Failed to serialize challenge: {}", e))
+}
+
+/// Play a `DailyChallengeEntry`; returns `DailyChallengeResult` JSON
+pub fn play_daily_challenge_json(request_json: &str) -> Result<String, String> {
+    let entry: DailyChallengeEntry =
+        serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
+
+    let result = play_daily_challenge(&entry)?;
+
+    serde_json::to_string(&result).map_err(|e| format!("Failed to serialize result: {}", e))
+}
+
+/// Re-simulate an entry and compare against a claimed result hash;
+/// returns `VerifyDailyResultResponse` JSON
+pub fn verify_daily_result_json(request_json: &str) -> Result<String, String> {
+    let request: VerifyDailyResultRequest =
+        serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
+
+    let result = play_daily_challenge(&request.entry)?;
+
+    let response = VerifyDailyResultResponse {
+        valid: result.result_hash == request.result_hash,
+        expected_hash: result.result_hash,
+        claimed_hash: request.result_hash,
+        points: result.points,
+    };
+    serde_json::to_string(&response).map_err(|e| format!("Failed to serialize response: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn entry_json(challenge: &DailyChallenge, overall: u8) -> serde_json::Value {
+        let formation = challenge.constraints.formation.as_deref().unwrap_or("4-4-2");
+        let starters: [&str; 11] = match formation {
+            "4-3-3" => ["GK", "LB", "CB", "CB", "RB", "CM", "CM", "CM", "LW", "ST", "RW"],
+            "4-2-3-1" => ["GK", "LB", "CB", "CB", "RB", "CDM", "CDM", "LM", "CAM", "RM", "ST"],
+            "3-5-2" => ["GK", "CB", "CB", "CB", "LM", "CM", "CDM", "CM", "RM", "ST", "ST"],
+            _ => ["GK", "LB", "CB", "CB", "RB", "LM", "CM", "CM", "RM", "ST", "ST"],
+        };
+        let bench = ["GK", "CB", "RB", "CM", "CM", "LM", "ST"];
+        let roster: Vec<_> = starters
+            .iter()
+            .chain(bench.iter())
+            .enumerate()
+            .map(|(i, pos)| {
+                json!({"name": format!("P{i}"), "position": pos, "overall": overall, "condition": 3})
+            })
+            .collect();
+        json!({
+            "date": challenge.date,
+            "player_id": "player_a",
+            "team": {"name": "Daily FC", "formation": formation, "roster": roster}
+        })
+    }
+
+    #[test]
+    fn test_daily_challenge_is_shared_per_date() {
+        let a = generate_daily_challenge("2026-10-16").unwrap();
+        let b = generate_daily_challenge(" 2026-10-16").unwrap();
+        assert_eq!(serde_json::to_value(&a).unwrap(), serde_json::to_value(&b).unwrap());
+        assert_eq!(a.challenge_id, "daily-2026-10-16");
+        assert_ne!(a.seed, daily_seed("2026-10-17").unwrap());
+        assert!(generate_daily_challenge("2026-13-01").is_err());
+
+        assert_eq!(DailyRuleset::Balanced.scoring().points(2, 0), 300 + 100 + 100);
+        assert_eq!(DailyRuleset::Fortress.scoring().points(0, 3), 0);
+    }
+
+    #[test]
+    fn test_daily_result_hash_verifies() {
+        let challenge = generate_daily_challenge("2026-10-16").unwrap();
+        let entry = entry_json(&challenge, challenge.constraints.max_average_overall);
+        let result: DailyChallengeResult =
+            serde_json::from_str(&play_daily_challenge_json(&entry.to_string()).unwrap()).unwrap();
+        assert_eq!(result.seed, challenge.seed);
+        assert_eq!(result.points, challenge.scoring.points(result.goals_for, result.goals_against));
+
+        let verify = json!({"entry": entry, "result_hash": result.result_hash});
+        let verified: VerifyDailyResultResponse =
+            serde_json::from_str(&verify_daily_result_json(&verify.to_string()).unwrap()).unwrap();
+        assert!(verified.valid);
+
+        // Same hash claimed for a different player does not verify
+        let mut other = verify.clone();
+        other["entry"]["player_id"] = json!("player_b");
+        let forged: VerifyDailyResultResponse =
+            serde_json::from_str(&verify_daily_result_json(&other.to_string()).unwrap()).unwrap();
+        assert!(!forged.valid);
+
+        // Over-strength squads are rejected
+        let stacked = entry_json(&challenge, challenge.constraints.max_average_overall + 1);
+        assert!(play_daily_challenge_json(&stacked.to_string()).unwrap_err().contains("cap"));
+    }
+}
@@ -0,0 +1,72 @@
+// Formation JSON API Layer
+// Per-slot formation waypoints and tactical heat overlays for the team editor /
+// custom viewers
+
+use crate::engine::team_phase::TeamPhase;
+use crate::tactics::tactical_heat::{DEFAULT_HEAT_COLS, DEFAULT_HEAT_ROWS};
+use crate::tactics::{get_formation_waypoints, get_tactical_heat_overlays, TeamInstructions};
+use serde::{Deserialize, Serialize};
+
+// ========== Request/Response Structures ==========
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FormationWaypointsRequest {
+    /// Formation code (e.g. "4-3-3")
+    pub formation: String,
+    #[serde(default)]
+    pub instructions: TeamInstructions,
+    #[serde(default)]
+    pub phase: TeamPhase,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TacticalHeatRequest {
+    /// Formation code (e.g. "4-3-3")
+    pub formation: String,
+    #[serde(default)]
+    pub instructions: TeamInstructions,
+    /// Grid columns across the pitch (clamped 4-48)
+    #[serde(default = "default_heat_cols")]
+    pub cols: usize,
+    /// Grid rows from own to opponent goal line (clamped 4-48)
+    #[serde(default = "default_heat_rows")]
+    pub rows: usize,
+}
+
+fn default_heat_cols() -> usize {
+    DEFAULT_HEAT_COLS
+}
+
+fn default_heat_rows() -> usize {
+    DEFAULT_HEAT_ROWS
+}
+
+// ========== API Functions ==========
+
+/// Per-slot target coordinates; returns `SlotWaypoint[]` JSON (team-relative, normalized)
+pub fn get_formation_waypoints_json(request_json: &str) -> Result<String, String> {
+    let request: FormationWaypointsRequest =
+        serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
+
+    let waypoints =
+        get_formation_waypoints(&request.formation, &request.instructions, request.phase)?;
+
+    serde_json::to_string(&waypoints)
+        .map_err(|e| format!("Failed to serialize formation waypoints: {}", e))
+}
+
+/// Per-slot coverage/pressure heat grids; returns `TacticalHeatOverlays` JSON
+pub fn get_tactical_heat_overlays_json(request_json: &str) -> Result<String, String> {
+    let request: TacticalHeatRequest =
+        serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
+
+    let overlays = get_tactical_heat_overlays(
+        &request.formation,
+        &request.instructions,
+        request.cols,
+        request.rows,
+    )?;
+
+    serde_json::to_string(&overlays)
+        .map_err(|e| format!("Failed to serialize tactical heat overlays: {}", e))
+}
@@ -1,14 +1,19 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_json;
 
+use crate::calibration::ParameterSet;
 use crate::data::resolve_person_by_player_uid;
-use crate::engine::{MatchEngine, MatchPlan};
+use crate::engine::{CancellationToken, MatchEngine, MatchPlan};
 use super::exp_config_env::apply_exp_config_from_env;
-use crate::fix01::{error_codes, is_valid_condition_level};
+use crate::fix01::{condition_level_from_fitness, error_codes, is_valid_condition_level};
 use crate::models::player::PlayerAttributes;
+use crate::models::skill::SpecialSkill;
 use crate::models::trait_system::{EquippedTrait, TraitId, TraitSlots, TraitTier};
-use crate::models::{Player, Team};
+use crate::models::{MatchResult, Player, Team};
+use crate::replay::ReplayDoc;
 use crate::player::instructions::PlayerInstructions;
+use crate::player::morale::{NEUTRAL_FORM, NEUTRAL_MORALE};
 use crate::player::personality::PersonalityArchetype;
 use crate::tactics::ai_profiles::AIDifficulty;
 use crate::tactics::team_instructions::TeamInstructions;
@@ -29,7 +34,7 @@ fn validate_condition_level(level: u8) -> Result<u8, String> {
     }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, JsonSchema)]
 pub struct MatchRequest {
     pub schema_version: u8,
     pub seed: u64,
@@ -43,16 +48,49 @@ pub struct MatchRequest {
     /// Enable position tracking for replay (increases data size ~1.4MB)
     #[serde(default)]
     pub enable_position_tracking: bool,
+    /// Optional pitch geometry (defaults to standard 105×68)
+    #[serde(default)]
+    pub pitch: Option<PitchRequest>,
+}
+
+/// Pitch selection for a match request.
+///
+/// Markings come from the `format` preset; `length_m` / `width_m` override
+/// the outer dimensions only.
+#[derive(Debug, Clone, Copy, Default, Deserialize, JsonSchema)]
+pub struct PitchRequest {
+    #[serde(default)]
+    pub format: crate::models::pitch::PitchFormat,
+    #[serde(default)]
+    pub length_m: Option<f32>,
+    #[serde(default)]
+    pub width_m: Option<f32>,
 }
 
-#[derive(Debug, Deserialize)]
+impl PitchRequest {
+    /// Resolve into a validated `PitchSpec`.
+    pub fn resolve(&self) -> Result<crate::models::pitch::PitchSpec, String> {
+        use crate::models::pitch::PitchSpec;
+
+        let preset = PitchSpec::for_format(self.format);
+        let pitch = PitchSpec::with_dimensions(
+            self.format,
+            self.length_m.unwrap_or(preset.length_m),
+            self.width_m.unwrap_or(preset.width_m),
+        );
+        pitch.validate().map_err(|e| format!("Invalid pitch: {}", e))?;
+        Ok(pitch)
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
 pub struct UserPlayerConfig {
     pub team: String, // "home" or "away"
     pub player_name: String,
     pub highlight_level: HighlightLevel,
 }
 
-#[derive(Debug, Deserialize, Clone, Copy)]
+#[derive(Debug, Deserialize, Clone, Copy, JsonSchema)]
 pub enum HighlightLevel {
     #[serde(rename = "skip")]
     Skip, // 스킵 - 바로 결과로
@@ -64,7 +102,7 @@ pub enum HighlightLevel {
     Full, // 전체 하이라이트
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, JsonSchema)]
 pub struct TeamData {
     pub name: String,
     pub formation: String,
@@ -74,7 +112,7 @@ pub struct TeamData {
         Option<std::collections::HashMap<String, crate::player::instructions::PlayerInstructions>>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, JsonSchema)]
 pub struct PlayerData {
     pub name: String,
     pub position: String,
@@ -87,12 +125,13 @@ pub struct PlayerData {
 // MatchRequest v2 (UID / PlayerLibrary-based) — schema_version = 2
 // ============================================================================
 
-#[derive(Debug, Deserialize)]
-pub struct MatchRequestV2 {
+/// Generic over the roster entry so schema v3 (`MatchRequestV3`) shares every other field
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct MatchRequestV2<R = RosterEntry> {
     pub schema_version: u8,
     pub seed: u64,
-    pub home_team: TeamDataV2,
-    pub away_team: TeamDataV2,
+    pub home_team: TeamDataV2<R>,
+    pub away_team: TeamDataV2<R>,
     pub user_player: Option<UserPlayerConfigV2>,
     #[serde(default)]
     pub home_instructions: Option<TeamInstructions>,
@@ -104,16 +143,62 @@ pub struct MatchRequestV2 {
     /// When true, use real names instead of pseudonyms for Player.name
     #[serde(default)]
     pub use_real_names: bool,
-    /// AI difficulty for home team: "Easy" | "Medium" | "Hard" | "Expert"
+    /// AI difficulty tier for home team: "Easy" | "Medium" | "Hard" | "Expert"
+    /// (case-insensitive). Drives decision noise, reaction delay and tactical adaptability.
     #[serde(default)]
     pub home_ai_difficulty: Option<String>,
-    /// AI difficulty for away team: "Easy" | "Medium" | "Hard" | "Expert"
+    /// AI difficulty tier for away team (same values as `home_ai_difficulty`)
     #[serde(default)]
     pub away_ai_difficulty: Option<String>,
+    /// Optional pitch geometry (defaults to standard 105×68)
+    #[serde(default)]
+    pub pitch: Option<PitchRequest>,
+    /// Squad chemistry from the squad screen (keyed by resolved player name)
+    #[serde(default)]
+    pub home_chemistry: Option<crate::models::chemistry::SquadChemistry>,
+    #[serde(default)]
+    pub away_chemistry: Option<crate::models::chemistry::SquadChemistry>,
+    /// Recent match load per player (fixture congestion lowers condition, raises injury risk)
+    #[serde(default)]
+    pub home_load: Option<crate::career::SquadLoad>,
+    #[serde(default)]
+    pub away_load: Option<crate::career::SquadLoad>,
+    /// Venue (overrides `pitch`; adds surface/width effects)
+    #[serde(default)]
+    pub stadium: Option<crate::models::stadium::Stadium>,
+    /// Use the home team's registered stadium when `stadium` is not given
+    #[serde(default)]
+    pub use_home_stadium: bool,
+    /// This fixture's crowd at the venue (overrides `stadium.attendance`; enables crowd pressure)
+    #[serde(default)]
+    pub attendance: Option<u32>,
+    /// Weather + pitch condition (None = clear weather, good pitch)
+    #[serde(default)]
+    pub conditions: Option<crate::models::weather::MatchConditions>,
+    /// Shootout kicking order, composure and sudden-death rule (Some = shootout after a draw)
+    #[serde(default)]
+    pub penalty_shootout: Option<crate::models::PenaltyShootoutConfig>,
+    /// Highlight reel tuning; when set, `best_moments` is filled in the result
+    #[serde(default)]
+    pub highlight_config: Option<crate::models::HighlightConfig>,
+    /// Match prep from training (fixture-specific; must name the opposing team)
+    #[serde(default)]
+    pub home_match_prep: Option<crate::training::MatchPrep>,
+    #[serde(default)]
+    pub away_match_prep: Option<crate::training::MatchPrep>,
+    /// Career day of the fixture; roster entries with `injured_until` after it are rejected
+    #[serde(default)]
+    pub match_day: Option<u32>,
+    /// Match official (strictness, card and advantage tendencies; None = neutral)
+    #[serde(default)]
+    pub referee: Option<crate::models::RefereeProfile>,
+    /// Derive a seeded referee when `referee` is not given
+    #[serde(default)]
+    pub referee_seed: Option<u64>,
 }
 
 /// Roster entry: either a UID string or embedded player data
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(untagged)]
 pub enum RosterEntry {
     /// UID reference (e.g. "csv:123")
@@ -125,15 +210,24 @@ pub enum RosterEntry {
 }
 
 /// UID roster entry with required FIX01 metadata.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct UidRosterEntry {
     pub uid: String,
     /// FIX01: ConditionLevel (1..=5)
     pub condition: u8,
+    /// Morale 0..=100 (None = neutral)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub morale: Option<u8>,
+    /// Form 0..=100 (None = neutral)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub form: Option<u8>,
+    /// Career day the player is fit again (None = fit)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub injured_until: Option<u32>,
 }
 
 /// Embedded player data for JSON v2 roster (MRQ0 v3 compatible)
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct EmbeddedPlayerData {
     pub name: String,
     pub position: String,
@@ -150,10 +244,19 @@ pub struct EmbeddedPlayerData {
     /// Equipped traits (max 4): [{id: "Sniper", tier: "Gold"}, ...]
     #[serde(default)]
     pub traits: Option<Vec<EmbeddedTrait>>,
+    /// Morale 0..=100 (None = neutral)
+    #[serde(default)]
+    pub morale: Option<u8>,
+    /// Form 0..=100 (None = neutral)
+    #[serde(default)]
+    pub form: Option<u8>,
+    /// Career day the player is fit again (None = fit)
+    #[serde(default)]
+    pub injured_until: Option<u32>,
 }
 
 /// Trait entry for embedded player data
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct EmbeddedTrait {
     /// Trait ID: "Sniper" | "Cannon" | "Finesse" | ... (30 total)
     pub id: String,
@@ -167,7 +270,7 @@ fn default_bronze() -> String {
 }
 
 /// Player attributes for embedded roster entries (36 fields, 0-100 scale)
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct EmbeddedPlayerAttributes {
     // Technical (14)
     #[serde(default = "default_50")]
@@ -250,18 +353,130 @@ fn default_50() -> u8 {
     50
 }
 
-#[derive(Debug, Deserialize)]
-pub struct TeamDataV2 {
+impl EmbeddedPlayerAttributes {
+    /// Number of attribute bytes in the MRQ0 v5 full attribute block
+    pub const FIELD_COUNT: usize = 36;
+
+    /// Build from values in declaration order (technical → mental → physical),
+    /// the layout of the MRQ0 v5 full attribute block.
+    pub fn from_ordered(v: [u8; Self::FIELD_COUNT]) -> Self {
+        Self {
+            corners: v[0],
+            crossing: v[1],
+            dribbling: v[2],
+            finishing: v[3],
+            first_touch: v[4],
+            free_kick_taking: v[5],
+            heading: v[6],
+            long_shots: v[7],
+            long_throws: v[8],
+            marking: v[9],
+            passing: v[10],
+            penalty_taking: v[11],
+            tackling: v[12],
+            technique: v[13],
+            aggression: v[14],
+            anticipation: v[15],
+            bravery: v[16],
+            composure: v[17],
+            concentration: v[18],
+            decisions: v[19],
+            determination: v[20],
+            flair: v[21],
+            leadership: v[22],
+            off_the_ball: v[23],
+            positioning: v[24],
+            teamwork: v[25],
+            vision: v[26],
+            work_rate: v[27],
+            acceleration: v[28],
+            agility: v[29],
+            balance: v[30],
+            jumping_reach: v[31],
+            natural_fitness: v[32],
+            pace: v[33],
+            stamina: v[34],
+            strength: v[35],
+        }
+    }
+
+    /// Engine attributes (GK attributes are not carried and default to 0)
+    pub fn to_player_attributes(&self) -> PlayerAttributes {
+        PlayerAttributes {
+            // Technical (14)
+            corners: self.corners,
+            crossing: self.crossing,
+            dribbling: self.dribbling,
+            finishing: self.finishing,
+            first_touch: self.first_touch,
+            free_kicks: self.free_kick_taking,
+            heading: self.heading,
+            long_shots: self.long_shots,
+            long_throws: self.long_throws,
+            marking: self.marking,
+            passing: self.passing,
+            penalty_taking: self.penalty_taking,
+            tackling: self.tackling,
+            technique: self.technique,
+            // Mental (14)
+            aggression: self.aggression,
+            anticipation: self.anticipation,
+            bravery: self.bravery,
+            composure: self.composure,
+            concentration: self.concentration,
+            decisions: self.decisions,
+            determination: self.determination,
+            flair: self.flair,
+            leadership: self.leadership,
+            off_the_ball: self.off_the_ball,
+            positioning: self.positioning,
+            teamwork: self.teamwork,
+            vision: self.vision,
+            work_rate: self.work_rate,
+            // Physical (8)
+            acceleration: self.acceleration,
+            agility: self.agility,
+            balance: self.balance,
+            jumping: self.jumping_reach,
+            natural_fitness: self.natural_fitness,
+            pace: self.pace,
+            stamina: self.stamina,
+            strength: self.strength,
+            // Goalkeeper (11) - embedded data doesn't include GK attrs, default 0
+            gk_aerial_reach: 0,
+            gk_command_of_area: 0,
+            gk_communication: 0,
+            gk_eccentricity: 0,
+            gk_handling: 0,
+            gk_kicking: 0,
+            gk_one_on_ones: 0,
+            gk_reflexes: 0,
+            gk_rushing_out: 0,
+            gk_punching: 0,
+            gk_throwing: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TeamDataV2<R = RosterEntry> {
     pub name: String,
     pub formation: String,
     /// 18 roster entries: either UID strings or embedded player data
-    pub roster: Vec<RosterEntry>,
+    pub roster: Vec<R>,
     /// Optional per-roster-slot instructions (slot index 0..17 encoded as JSON object keys)
     #[serde(default)]
     pub player_instructions: Option<HashMap<String, PlayerInstructions>>,
+    /// Formation editor layout: 11 anchors + role tags by starter slot.
+    /// `formation` still picks the preset roles; anchors replace its positions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_formation: Option<crate::tactics::FormationData>,
+    /// Designated takers by match track_id (home 0-10, away 11-21)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub set_piece_takers: Option<crate::tactics::SetPieceTakers>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, JsonSchema)]
 pub struct UserPlayerConfigV2 {
     pub team: String, // "home" or "away"
     pub highlight_level: HighlightLevel,
@@ -271,6 +486,281 @@ pub struct UserPlayerConfigV2 {
     pub roster_slot: Option<usize>,
 }
 
+/// Roster entry shape of a request schema (v2 `RosterEntry`, v3 `RosterEntryV3`)
+pub trait RosterSlot {
+    /// `schema_version` of requests whose rosters use this entry
+    const SCHEMA_VERSION: u8;
+
+    /// CSV/DB UID (duplicate check); None for inline players
+    fn uid(&self) -> Option<&str>;
+
+    /// `(label, injured_until)` when the entry carries an injury date
+    fn injury(&self) -> Option<(&str, u32)>;
+
+    /// Engine player and its UID key (`embedded:*` for inline players)
+    fn into_player(self, slot: usize) -> Result<(String, Player), String>;
+}
+
+impl RosterSlot for RosterEntry {
+    const SCHEMA_VERSION: u8 = 2;
+
+    fn uid(&self) -> Option<&str> {
+        match self {
+            RosterEntry::Uid(uid) => Some(uid),
+            RosterEntry::UidWithMeta(meta) => Some(&meta.uid),
+            RosterEntry::Embedded(_) => None,
+        }
+    }
+
+    fn injury(&self) -> Option<(&str, u32)> {
+        match self {
+            RosterEntry::Uid(_) => None,
+            RosterEntry::UidWithMeta(meta) => meta.injured_until.map(|d| (meta.uid.as_str(), d)),
+            RosterEntry::Embedded(player) => {
+                player.injured_until.map(|d| (player.name.as_str(), d))
+            }
+        }
+    }
+
+    fn into_player(self, slot: usize) -> Result<(String, Player), String> {
+        match self {
+            RosterEntry::Uid(uid) => {
+                // FIX01: condition is required for match-time determinism & CI proof.
+                Err(err_code(
+                    error_codes::INVALID_CONDITION_RANGE,
+                    format!(
+                        "missing condition for UID roster entry '{uid}' (use object form {{\"uid\":\"...\",\"condition\":3}})"
+                    ),
+                ))
+            }
+            RosterEntry::UidWithMeta(meta) => {
+                let mut player = resolve_uid_player(&meta.uid, meta.condition)?;
+                player.morale = meta.morale.map_or(player.morale, |m| m.min(100));
+                player.form = meta.form.map_or(player.form, |f| f.min(100));
+                Ok((meta.uid, player))
+            }
+            RosterEntry::Embedded(embedded) => {
+                // NEW: Embedded player data with full attributes (MRQ0 v3)
+                let position = parse_position(&embedded.position)
+                    .unwrap_or(crate::models::player::Position::MF);
+                let condition = validate_condition_level(embedded.condition)?;
+
+                // Build attributes from embedded data or derive from overall
+                let player_attributes = if let Some(ref attrs) = embedded.attributes {
+                    attrs.to_player_attributes()
+                } else {
+                    // Fallback: derive from overall
+                    PlayerAttributes::from_uniform(embedded.overall)
+                };
+
+                // Build trait slots from embedded traits
+                let trait_slots = build_trait_slots(embedded.traits.as_ref());
+
+                let uid_key = embedded
+                    .track_id
+                    .map(|id| format!("embedded:{id}"))
+                    .unwrap_or_else(|| format!("embedded:slot_{slot}"));
+
+                Ok((
+                    uid_key,
+                    Player {
+                        name: embedded.name,
+                        position,
+                        overall: embedded.overall,
+                        condition,
+                        attributes: Some(player_attributes),
+                        equipped_skills: Vec::new(),
+                        traits: trait_slots,
+                        personality: parse_personality(embedded.personality.as_deref()),
+                        morale: embedded.morale.map_or(NEUTRAL_MORALE, |m| m.min(100)),
+                        form: embedded.form.map_or(NEUTRAL_FORM, |f| f.min(100)),
+                    },
+                ))
+            }
+        }
+    }
+}
+
+/// Personality archetype name (unknown / absent = Steady)
+fn parse_personality(name: Option<&str>) -> PersonalityArchetype {
+    match name {
+        Some("Leader") => PersonalityArchetype::Leader,
+        Some("Genius") => PersonalityArchetype::Genius,
+        Some("Workhorse") => PersonalityArchetype::Workhorse,
+        Some("Rebel") => PersonalityArchetype::Rebel,
+        _ => PersonalityArchetype::Steady, // default
+    }
+}
+
+// ============================================================================
+// MatchRequest v3 (inline player payloads) — schema_version = 3
+// ============================================================================
+
+/// Schema v3: the v2 request with `RosterEntryV3` rosters
+pub type MatchRequestV3 = MatchRequestV2<RosterEntryV3>;
+
+/// Schema v3 team: the v2 team with `RosterEntryV3` rosters
+pub type TeamDataV3 = TeamDataV2<RosterEntryV3>;
+
+/// Schema v3 roster entry: full player data inline, for career systems without a save state
+///
+/// - with `uid`: the CSV/DB player is the base and every given field overrides it
+/// - without: fully inline; `name`, `position` and `overall` are required
+///   (`attributes` absent = uniform attributes from `overall`)
+///
+/// One of `condition` / `fitness` is required (FIX01: no implicit match-time condition).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct RosterEntryV3 {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uid: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub overall: Option<u8>,
+    /// FIX01: ConditionLevel (1..=5); wins over `fitness`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub condition: Option<u8>,
+    /// Match fitness 0..=100, mapped to a ConditionLevel when `condition` is absent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fitness: Option<u8>,
+    /// Full engine attributes (technical/mental/physical + goalkeeping)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<PlayerAttributes>,
+    /// Equipped traits (max 4)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub traits: Option<Vec<EmbeddedTrait>>,
+    /// Legacy special skills (max 5)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub equipped_skills: Option<Vec<SpecialSkill>>,
+    /// "Leader" | "Genius" | "Workhorse" | "Rebel" | "Steady"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub personality: Option<String>,
+    /// Morale 0..=100 (None = base / neutral)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub morale: Option<u8>,
+    /// Form 0..=100 (None = base / neutral)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub form: Option<u8>,
+    /// Career day the player is fit again (None = fit)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub injured_until: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub track_id: Option<u32>,
+}
+
+/// Max legacy skills per player (`Player::equipped_skills`)
+const MAX_EQUIPPED_SKILLS: usize = 5;
+
+impl RosterEntryV3 {
+    fn label(&self) -> &str {
+        self.uid.as_deref().or(self.name.as_deref()).unwrap_or("?")
+    }
+}
+
+impl RosterSlot for RosterEntryV3 {
+    const SCHEMA_VERSION: u8 = 3;
+
+    fn uid(&self) -> Option<&str> {
+        self.uid.as_deref()
+    }
+
+    fn injury(&self) -> Option<(&str, u32)> {
+        self.injured_until.map(|until| (self.label(), until))
+    }
+
+    fn into_player(self, slot: usize) -> Result<(String, Player), String> {
+        let condition = match (self.condition, self.fitness) {
+            (Some(level), _) => validate_condition_level(level)?,
+            (None, Some(fitness)) if fitness <= 100 => condition_level_from_fitness(fitness),
+            (None, Some(fitness)) => {
+                return Err(err_code(
+                    error_codes::INVALID_CONDITION_RANGE,
+                    format!("roster slot {slot}: fitness must be 0..=100, got {fitness}"),
+                ))
+            }
+            (None, None) => {
+                return Err(err_code(
+                    error_codes::INVALID_CONDITION_RANGE,
+                    format!(
+                        "roster slot {slot} ('{}'): condition or fitness is required",
+                        self.label()
+                    ),
+                ))
+            }
+        };
+        let position = self
+            .position
+            .as_deref()
+            .map(|p| parse_position(p).map_err(|e| format!("roster slot {slot}: {e}")))
+            .transpose()?;
+        if self.equipped_skills.as_ref().is_some_and(|s| s.len() > MAX_EQUIPPED_SKILLS) {
+            return Err(format!(
+                "roster slot {slot}: `equipped_skills` holds at most {MAX_EQUIPPED_SKILLS} skills"
+            ));
+        }
+
+        let (uid_key, mut player) = match self.uid {
+            Some(uid) => {
+                let mut player = resolve_uid_player(&uid, condition)?;
+                if let Some(name) = self.name {
+                    player.name = name;
+                }
+                if let Some(position) = position {
+                    player.position = position;
+                }
+                if let Some(overall) = self.overall {
+                    player.overall = overall;
+                }
+                if let Some(attributes) = self.attributes {
+                    player.attributes = Some(attributes);
+                }
+                (uid, player)
+            }
+            None => {
+                let missing =
+                    |field: &str| format!("roster slot {slot}: inline player needs `{field}`");
+                let name = self.name.ok_or_else(|| missing("name"))?;
+                let position = position.ok_or_else(|| missing("position"))?;
+                let overall = self.overall.ok_or_else(|| missing("overall"))?;
+                let attributes =
+                    self.attributes.unwrap_or_else(|| PlayerAttributes::from_uniform(overall));
+                let uid_key = self
+                    .track_id
+                    .map(|id| format!("embedded:{id}"))
+                    .unwrap_or_else(|| format!("embedded:slot_{slot}"));
+                let player = Player {
+                    name,
+                    position,
+                    overall,
+                    condition,
+                    attributes: Some(attributes),
+                    equipped_skills: Vec::new(),
+                    traits: TraitSlots::default(),
+                    personality: PersonalityArchetype::Steady,
+                    morale: NEUTRAL_MORALE,
+                    form: NEUTRAL_FORM,
+                };
+                (uid_key, player)
+            }
+        };
+
+        if let Some(traits) = &self.traits {
+            player.traits = build_trait_slots(Some(traits));
+        }
+        if let Some(skills) = self.equipped_skills {
+            player.equipped_skills = skills;
+        }
+        if self.personality.is_some() {
+            player.personality = parse_personality(self.personality.as_deref());
+        }
+        player.morale = self.morale.map_or(player.morale, |m| m.min(100));
+        player.form = self.form.map_or(player.form, |f| f.min(100));
+        Ok((uid_key, player))
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct MatchResponse {
     pub schema_version: u8,
@@ -297,7 +787,37 @@ pub fn match_plan_from_match_request_v2_json(
     let request: MatchRequestV2 =
         serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
 
-    if request.schema_version != 2 {
+    match_plan_from_match_request_v2(request)
+}
+
+/// Build a `MatchPlan` from an already-parsed MatchRequest v2.
+pub fn match_plan_from_match_request_v2(
+    request: MatchRequestV2,
+) -> Result<(MatchPlan, bool), String> {
+    match_plan_from_roster_request(request)
+}
+
+/// MatchRequest v3 JSON → (`MatchPlan`, `enable_position_tracking`)
+pub fn match_plan_from_match_request_v3_json(
+    request_json: &str,
+) -> Result<(MatchPlan, bool), String> {
+    let request: MatchRequestV3 =
+        serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
+    match_plan_from_match_request_v3(request)
+}
+
+/// Build a `MatchPlan` from an already-parsed MatchRequest v3.
+pub fn match_plan_from_match_request_v3(
+    request: MatchRequestV3,
+) -> Result<(MatchPlan, bool), String> {
+    match_plan_from_roster_request(request)
+}
+
+/// v2 / v3 plan builder; only roster entry resolution differs between the schemas
+fn match_plan_from_roster_request<R: RosterSlot>(
+    request: MatchRequestV2<R>,
+) -> Result<(MatchPlan, bool), String> {
+    if request.schema_version != R::SCHEMA_VERSION {
         return Err(format!("Unsupported schema version: {}", request.schema_version));
     }
 
@@ -312,17 +832,76 @@ pub fn match_plan_from_match_request_v2_json(
         use_real_names,
         home_ai_difficulty,
         away_ai_difficulty,
+        pitch,
+        home_chemistry,
+        away_chemistry,
+        home_load,
+        away_load,
+        stadium,
+        use_home_stadium,
+        attendance,
+        conditions,
+        penalty_shootout,
+        home_match_prep,
+        away_match_prep,
+        match_day,
+        referee,
+        referee_seed,
         ..
     } = request;
+    let pitch = pitch.map(|p| p.resolve()).transpose()?.unwrap_or_default();
+
+    reject_injured_selections(&home_team_data, match_day)?;
+    reject_injured_selections(&away_team_data, match_day)?;
+    let home_custom_formation = validate_custom_formation(&home_team_data)?;
+    let away_custom_formation = validate_custom_formation(&away_team_data)?;
+    let referee = referee.or_else(|| referee_seed.map(crate::models::RefereeProfile::from_seed));
+    let home_set_piece_takers = validate_set_piece_takers(&home_team_data, true)?;
+    let away_set_piece_takers = validate_set_piece_takers(&away_team_data, false)?;
 
-    let (home_team, home_uid_to_name, home_player_instructions) =
+    let (mut home_team, home_uid_to_name, home_player_instructions) =
         convert_team_v2(home_team_data, use_real_names)?;
-    let (away_team, away_uid_to_name, away_player_instructions) =
+    let (mut away_team, away_uid_to_name, away_player_instructions) =
         convert_team_v2(away_team_data, use_real_names)?;
 
     home_team.validate().map_err(|e| format!("Home team validation failed: {}", e))?;
     away_team.validate().map_err(|e| format!("Away team validation failed: {}", e))?;
 
+    use crate::engine::{ModifierSource, ModifierStack, ModifierStat};
+    let mut modifier_stack = ModifierStack::new();
+    if let Some(load) = &home_load {
+        let injury_risk = crate::career::apply_squad_load(&mut home_team, load, &home_uid_to_name);
+        modifier_stack.push(
+            true,
+            ModifierSource::SquadLoad,
+            "fixture congestion",
+            ModifierStat::InjuryRisk,
+            injury_risk,
+        );
+    }
+    if let Some(load) = &away_load {
+        let injury_risk = crate::career::apply_squad_load(&mut away_team, load, &away_uid_to_name);
+        modifier_stack.push(
+            false,
+            ModifierSource::SquadLoad,
+            "fixture congestion",
+            ModifierStat::InjuryRisk,
+            injury_risk,
+        );
+    }
+    for (prep, is_home, opponent) in
+        [(&home_match_prep, true, &away_team.name), (&away_match_prep, false, &home_team.name)]
+    {
+        let Some(prep) = prep else { continue };
+        if !prep.is_for(opponent) {
+            return Err(format!(
+                "Match prep was built for {}, not this fixture's opponent {}",
+                prep.opponent_name, opponent
+            ));
+        }
+        prep.push_to(&mut modifier_stack, is_home);
+    }
+
     let user_config = user_player
         .map(|up| {
             convert_user_player_v2(up, &home_team, &away_team, &home_uid_to_name, &away_uid_to_name)
@@ -330,8 +909,26 @@ pub fn match_plan_from_match_request_v2_json(
         .transpose()?;
 
     // Parse AI difficulty settings
-    let home_ai = parse_ai_difficulty(home_ai_difficulty.as_deref());
-    let away_ai = parse_ai_difficulty(away_ai_difficulty.as_deref());
+    let home_ai = parse_ai_difficulty(home_ai_difficulty.as_deref())?;
+    let away_ai = parse_ai_difficulty(away_ai_difficulty.as_deref())?;
+
+    let stadium = match stadium {
+        Some(stadium) => Some(stadium),
+        None if use_home_stadium => {
+            crate::state::get_state().stadium_registry.get(&home_team.name).cloned()
+        }
+        None => None,
+    };
+    let stadium = match (stadium, attendance) {
+        (Some(mut stadium), Some(attendance)) => {
+            stadium.attendance = Some(attendance);
+            Some(stadium)
+        }
+        (None, Some(_)) => {
+            return Err("`attendance` needs a venue (`stadium` or `use_home_stadium`)".to_string())
+        }
+        (stadium, None) => stadium,
+    };
 
     let plan = MatchPlan {
         home_team,
@@ -340,25 +937,36 @@ pub fn match_plan_from_match_request_v2_json(
         user_player: user_config,
         home_match_modifiers: crate::engine::TeamMatchModifiers::default(),
         away_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+        modifier_stack,
         home_instructions,
         away_instructions,
         home_player_instructions,
         away_player_instructions,
         home_ai_difficulty: home_ai,
         away_ai_difficulty: away_ai,
+        pitch,
+        home_chemistry,
+        away_chemistry,
+        stadium,
+        conditions,
+        penalty_shootout,
+        home_custom_formation,
+        away_custom_formation,
+        home_set_piece_takers,
+        away_set_piece_takers,
+        referee,
     };
 
     Ok((plan, enable_position_tracking))
 }
 
-/// Parse AI difficulty string to enum
-fn parse_ai_difficulty(s: Option<&str>) -> Option<AIDifficulty> {
-    match s? {
-        "Easy" => Some(AIDifficulty::Easy),
-        "Medium" => Some(AIDifficulty::Medium),
-        "Hard" => Some(AIDifficulty::Hard),
-        "Expert" => Some(AIDifficulty::Expert),
-        _ => None,
+/// Parse AI difficulty tier name (absent = no AI tier; unknown names are rejected)
+fn parse_ai_difficulty(s: Option<&str>) -> Result<Option<AIDifficulty>, String> {
+    match s {
+        None => Ok(None),
+        Some(name) => AIDifficulty::from_name(name)
+            .map(Some)
+            .ok_or_else(|| format!("Unknown AI difficulty: {} (Easy|Medium|Hard|Expert)", name)),
     }
 }
 
@@ -431,82 +1039,35 @@ fn build_trait_slots(traits: Option<&Vec<EmbeddedTrait>>) -> TraitSlots {
 
 /// Main entry point for JSON API - simulates a match from JSON request
 pub fn simulate_match_json(request_json: &str) -> Result<String, String> {
-    use std::io::{self, Write};
-    println!("🔴🔴🔴 [simulate_match_json] ENTRY POINT CALLED 🔴🔴🔴");
-    io::stdout().flush().unwrap();
+    simulate_match_json_cancellable(request_json, &CancellationToken::new())
+}
 
+/// `simulate_match_json` that stops at the next minute boundary once `cancel`
+/// fires; the partial result carries `determinism.cut_reason`.
+pub fn simulate_match_json_cancellable(
+    request_json: &str,
+    cancel: &CancellationToken,
+) -> Result<String, String> {
     // Parse request
     let request: MatchRequest =
         serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
 
-    // Validate schema version
-    if request.schema_version != 1 {
-        return Err(format!("Unsupported schema version: {}", request.schema_version));
-    }
-
-    let MatchRequest {
-        seed,
-        home_team: home_team_data,
-        away_team: away_team_data,
-        user_player,
-        home_instructions,
-        away_instructions,
-        enable_position_tracking,
-        ..
-    } = request;
+    let result = simulate_match_request(request, cancel)?;
 
-    // Extract player instructions before converting team data
-    let home_player_instructions = home_team_data.player_instructions.clone();
-    let away_player_instructions = away_team_data.player_instructions.clone();
-
-    // Convert to internal models
-    let home_team = convert_team(home_team_data)?;
-    let away_team = convert_team(away_team_data)?;
-
-    // Validate teams
-    home_team.validate().map_err(|e| format!("Home team validation failed: {}", e))?;
-    away_team.validate().map_err(|e| format!("Away team validation failed: {}", e))?;
-
-    // Create match plan with user config
-    let user_config = user_player.map(|up| {
-        let is_home = up.team == "home";
-        // C6: Resolve player_index from player_name
-        let players =
-            if is_home { home_team.get_starting_11() } else { away_team.get_starting_11() };
-        let base_idx = if is_home { 0 } else { 11 };
-        let player_index = players
-            .iter()
-            .position(|p| p.name == up.player_name)
-            .map(|i| base_idx + i)
-            .unwrap_or(base_idx + 9); // Fallback to first attacker
+    // Convert result to JSON
+    serde_json::to_string(&result).map_err(|e| format!("Failed to serialize result: {}", e))
+}
 
-        crate::engine::UserPlayerConfig {
-            is_home_team: is_home,
-            player_name: up.player_name,
-            player_index,
-            highlight_level: match up.highlight_level {
-                HighlightLevel::Skip => crate::engine::HighlightLevel::Skip,
-                HighlightLevel::Simple => crate::engine::HighlightLevel::Simple,
-                HighlightLevel::MyPlayer => crate::engine::HighlightLevel::MyPlayer,
-                HighlightLevel::Full => crate::engine::HighlightLevel::Full,
-            },
-        }
-    });
+/// Simulate an already-parsed MatchRequest v1 (shared by the JSON and MessagePack entrypoints)
+pub(crate) fn simulate_match_request(
+    request: MatchRequest,
+    cancel: &CancellationToken,
+) -> Result<MatchResult, String> {
+    use std::io::{self, Write};
+    println!("🔴🔴🔴 [simulate_match_json] ENTRY POINT CALLED 🔴🔴🔴");
+    io::stdout().flush().unwrap();
 
-    let plan = MatchPlan {
-        home_team,
-        away_team,
-        seed,
-        user_player: user_config,
-        home_match_modifiers: crate::engine::TeamMatchModifiers::default(),
-        away_match_modifiers: crate::engine::TeamMatchModifiers::default(),
-        home_instructions,
-        away_instructions,
-        home_player_instructions,
-        away_player_instructions,
-        home_ai_difficulty: None,
-        away_ai_difficulty: None,
-    };
+    let (plan, enable_position_tracking) = match_plan_from_match_request(request)?;
 
     // Run simulation
     let mut engine = MatchEngine::new(plan)?;
@@ -533,24 +1094,17 @@ pub fn simulate_match_json(request_json: &str) -> Result<String, String> {
 
     println!("🔴🔴🔴 [simulate_match_json] About to call engine.simulate() 🔴🔴🔴");
     io::stdout().flush().unwrap();
-    let result = engine.simulate();
+    let result = engine.simulate_with_cancellation(cancel);
     println!("🔴🔴🔴 [simulate_match_json] engine.simulate() returned 🔴🔴🔴");
     io::stdout().flush().unwrap();
 
-    // Convert result to JSON
-    let response_json =
-        serde_json::to_string(&result).map_err(|e| format!("Failed to serialize result: {}", e))?;
-
-    Ok(response_json)
+    Ok(result)
 }
 
-/// JSON API with replay recording - returns both match result and replay events
-/// Returns tuple: (result_json, replay_json)
-pub fn simulate_match_json_with_replay(request_json: &str) -> Result<(String, String), String> {
-    // Parse request
-    let request: MatchRequest =
-        serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
-
+/// MatchRequest v1 → MatchPlan (+ enable_position_tracking)
+pub(crate) fn match_plan_from_match_request(
+    request: MatchRequest,
+) -> Result<(MatchPlan, bool), String> {
     // Validate schema version
     if request.schema_version != 1 {
         return Err(format!("Unsupported schema version: {}", request.schema_version));
@@ -563,8 +1117,11 @@ pub fn simulate_match_json_with_replay(request_json: &str) -> Result<(String, St
         user_player,
         home_instructions,
         away_instructions,
+        enable_position_tracking,
+        pitch,
         ..
     } = request;
+    let pitch = pitch.map(|p| p.resolve()).transpose()?.unwrap_or_default();
 
     // Extract player instructions before converting team data
     let home_player_instructions = home_team_data.player_instructions.clone();
@@ -601,6 +1158,7 @@ pub fn simulate_match_json_with_replay(request_json: &str) -> Result<(String, St
                 HighlightLevel::MyPlayer => crate::engine::HighlightLevel::MyPlayer,
                 HighlightLevel::Full => crate::engine::HighlightLevel::Full,
             },
+            co_op_players: Vec::new(),
         }
     });
 
@@ -611,32 +1169,66 @@ pub fn simulate_match_json_with_replay(request_json: &str) -> Result<(String, St
         user_player: user_config,
         home_match_modifiers: crate::engine::TeamMatchModifiers::default(),
         away_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+        modifier_stack: Default::default(),
         home_instructions,
         away_instructions,
         home_player_instructions,
         away_player_instructions,
         home_ai_difficulty: None,
         away_ai_difficulty: None,
+        pitch,
+        home_chemistry: None,
+        away_chemistry: None,
+        stadium: None,
+        conditions: None,
+        penalty_shootout: None,
+        home_custom_formation: None,
+        away_custom_formation: None,
+        home_set_piece_takers: None,
+        away_set_piece_takers: None,
+        referee: None,
     };
 
+    Ok((plan, enable_position_tracking))
+}
+
+/// JSON API with replay recording - returns both match result and replay events
+/// Returns tuple: (result_json, replay_json)
+pub fn simulate_match_json_with_replay(request_json: &str) -> Result<(String, String), String> {
+    // Parse request
+    let request: MatchRequest =
+        serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
+
+    let (result, replay_doc) = simulate_match_request_with_replay(request)?;
+    result_and_replay_json(&result, replay_doc.as_ref())
+}
+
+/// MatchRequest v1 with position tracking + replay recording → (result, replay doc)
+pub(crate) fn simulate_match_request_with_replay(
+    request: MatchRequest,
+) -> Result<(MatchResult, Option<ReplayDoc>), String> {
+    let (plan, _enable_position_tracking) = match_plan_from_match_request(request)?;
+
     // Run simulation with position tracking and replay recording
     let mut engine = MatchEngine::new(plan)?;
     apply_exp_config_from_env(&mut engine)?;
     engine = engine.with_position_tracking().with_replay_recording();
 
     let result = engine.simulate();
+    Ok((result, engine.take_replay_doc()))
+}
 
-    // Get replay document
-    let replay_doc = engine.take_replay_doc();
-
-    // Convert result to JSON
+/// (result_json, replay_json); a missing replay doc serializes as `null`
+fn result_and_replay_json(
+    result: &MatchResult,
+    replay_doc: Option<&ReplayDoc>,
+) -> Result<(String, String), String> {
     let result_json =
-        serde_json::to_string(&result).map_err(|e| format!("Failed to serialize result: {}", e))?;
+        serde_json::to_string(result).map_err(|e| format!("Failed to serialize result: {}", e))?;
 
-    // Convert replay to JSON
     let replay_json = match replay_doc {
         Some(doc) => {
-            serde_json::to_string(&doc).map_err(|e| format!("Failed to serialize replay: {}", e))?
+            serde_json::to_string(doc).map_err(|e| format!("Failed to serialize replay: {}", e))?
         }
         None => "null".to_string(),
     };
@@ -645,42 +1237,102 @@ pub fn simulate_match_json_with_replay(request_json: &str) -> Result<(String, St
 }
 
 /// JSON API v2 - simulates a match from UID-based roster input (schema_version=2)
-pub fn simulate_match_v2_json(request_json: &str) -> Result<String, String> {   
-    let (plan, enable_position_tracking) = match_plan_from_match_request_v2_json(request_json)?;
+pub fn simulate_match_v2_json(request_json: &str) -> Result<String, String> {
+    let request: MatchRequestV2 =
+        serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
+    let result = simulate_match_v2(request)?;
+    serde_json::to_string(&result).map_err(|e| format!("Failed to serialize result: {}", e))
+}
+
+/// JSON API v2 with designer tunables - `parameters_json` is a (partial) `ParameterSet`.
+///
+/// Same request + same parameters → same result; `parameter_hash` in the result identifies
+/// the set so A/B runs can be reproduced.
+pub fn simulate_with_parameters_json(
+    request_json: &str,
+    parameters_json: &str,
+) -> Result<String, String> {
+    let request: MatchRequestV2 =
+        serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
+    let parameters = ParameterSet::from_json_str(parameters_json)?;
+    let result = simulate_match_v2_with_parameters(request, Some(parameters))?;
+    serde_json::to_string(&result).map_err(|e| format!("Failed to serialize result: {}", e))
+}
+
+/// Simulate an already-parsed MatchRequest v2 (shared by the single and batch entrypoints)
+pub(crate) fn simulate_match_v2(request: MatchRequestV2) -> Result<MatchResult, String> {
+    simulate_match_v2_with_parameters(request, None)
+}
+
+/// Simulate an already-parsed MatchRequest v3 (shared by the single and batch entrypoints)
+pub(crate) fn simulate_match_v3(request: MatchRequestV3) -> Result<MatchResult, String> {
+    simulate_match_v2_with_parameters(request, None)
+}
+
+fn simulate_match_v2_with_parameters<R: RosterSlot>(
+    mut request: MatchRequestV2<R>,
+    parameters: Option<ParameterSet>,
+) -> Result<MatchResult, String> {
+    let highlight_config = request.highlight_config.take();
+    let (plan, enable_position_tracking) = match_plan_from_roster_request(request)?;
 
     let mut engine = MatchEngine::new(plan)?;
     apply_exp_config_from_env(&mut engine)?;
+    if let Some(parameters) = parameters {
+        engine = engine.with_parameter_set(parameters);
+    }
     if enable_position_tracking {
         engine = engine.with_position_tracking();
     }
 
-    let result = engine.simulate();
-    serde_json::to_string(&result).map_err(|e| format!("Failed to serialize result: {}", e))
+    let mut result = engine.simulate();
+    if let Some(config) = &highlight_config {
+        result.generate_best_moments_with(config);
+    }
+    Ok(result)
 }
 
 /// JSON API v2 - simulates a match and returns (result_json, replay_json)
 pub fn simulate_match_v2_json_with_replay(request_json: &str) -> Result<(String, String), String> {
-    let (plan, _enable_position_tracking) = match_plan_from_match_request_v2_json(request_json)?;
+    let request: MatchRequestV2 =
+        serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
+    let (result, replay_doc) = simulate_match_v2_with_replay(request)?;
+    result_and_replay_json(&result, replay_doc.as_ref())
+}
+
+/// JSON API v3 - simulates a match from inline player payloads (schema_version=3)
+pub fn simulate_match_v3_json(request_json: &str) -> Result<String, String> {
+    let request: MatchRequestV3 =
+        serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
+    let result = simulate_match_v3(request)?;
+    serde_json::to_string(&result).map_err(|e| format!("Failed to serialize result: {}", e))
+}
+
+/// JSON API v3 - simulates a match and returns (result_json, replay_json)
+pub fn simulate_match_v3_json_with_replay(request_json: &str) -> Result<(String, String), String> {
+    let request: MatchRequestV3 =
+        serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
+    let (result, replay_doc) = simulate_match_v2_with_replay(request)?;
+    result_and_replay_json(&result, replay_doc.as_ref())
+}
+
+/// MatchRequest v2/v3 with position tracking + replay recording → (result, replay doc)
+pub(crate) fn simulate_match_v2_with_replay<R: RosterSlot>(
+    mut request: MatchRequestV2<R>,
+) -> Result<(MatchResult, Option<ReplayDoc>), String> {
+    let highlight_config = request.highlight_config.take();
+    let (plan, _enable_position_tracking) = match_plan_from_roster_request(request)?;
 
     // Mirror v1 behavior: with_replay always enables both position tracking + replay recording.
     let mut engine = MatchEngine::new(plan)?;
     apply_exp_config_from_env(&mut engine)?;
     engine = engine.with_position_tracking().with_replay_recording();
 
-    let result = engine.simulate();
-    let replay_doc = engine.take_replay_doc();
-
-    let result_json =
-        serde_json::to_string(&result).map_err(|e| format!("Failed to serialize result: {}", e))?;
-
-    let replay_json = match replay_doc {
-        Some(doc) => {
-            serde_json::to_string(&doc).map_err(|e| format!("Failed to serialize replay: {}", e))?
-        }
-        None => "null".to_string(),
-    };
-
-    Ok((result_json, replay_json))
+    let mut result = engine.simulate();
+    if let Some(config) = &highlight_config {
+        result.generate_best_moments_with(config);
+    }
+    Ok((result, engine.take_replay_doc()))
 }
 
 fn convert_user_player_v2(
@@ -740,14 +1392,55 @@ fn convert_user_player_v2(
             HighlightLevel::MyPlayer => crate::engine::HighlightLevel::MyPlayer,
             HighlightLevel::Full => crate::engine::HighlightLevel::Full,
         },
+        co_op_players: Vec::new(),
     })
 }
 
-fn convert_team_v2(
-    data: TeamDataV2,
+/// Custom formation anchors for a team (validated by `FormationData`)
+fn validate_custom_formation<R>(
+    data: &TeamDataV2<R>,
+) -> Result<Option<crate::tactics::FormationData>, String> {
+    let Some(custom) = &data.custom_formation else { return Ok(None) };
+    custom.validate().map_err(|e| {
+        err_code(error_codes::INVALID_CUSTOM_FORMATION, format!("{}: {}", data.name, e))
+    })?;
+    Ok(Some(custom.clone()))
+}
+
+fn validate_set_piece_takers<R>(
+    data: &TeamDataV2<R>,
+    is_home: bool,
+) -> Result<Option<crate::tactics::SetPieceTakers>, String> {
+    let Some(takers) = &data.set_piece_takers else { return Ok(None) };
+    takers.validate(is_home).map_err(|e| {
+        err_code(error_codes::INVALID_SET_PIECE_TAKER, format!("{}: {}", data.name, e))
+    })?;
+    Ok(Some(takers.clone()))
+}
+
+/// Injured players (`injured_until` after the match day) cannot be selected.
+/// Without a `match_day` any flagged entry counts as injured.
+fn reject_injured_selections<R: RosterSlot>(
+    data: &TeamDataV2<R>,
+    match_day: Option<u32>,
+) -> Result<(), String> {
+    for (slot, entry) in data.roster.iter().enumerate() {
+        let Some((label, until)) = entry.injury() else { continue };
+        if match_day.map_or(true, |day| day < until) {
+            return Err(err_code(
+                error_codes::PLAYER_INJURED,
+                format!("{} roster slot {slot} ('{label}') is injured until day {until}", data.name),
+            ));
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn convert_team_v2<R: RosterSlot>(
+    data: TeamDataV2<R>,
     _use_real_names: bool,
 ) -> Result<(Team, HashMap<String, String>, Option<HashMap<String, PlayerInstructions>>), String> {
-    let TeamDataV2 { name, formation: formation_str, roster, player_instructions } = data;
+    let TeamDataV2 { name, formation: formation_str, roster, player_instructions, .. } = data;
 
     let formation = parse_formation(&formation_str)?;
 
@@ -755,220 +1448,17 @@ fn convert_team_v2(
         return Err(format!("Team must have exactly 18 roster entries, found {}", roster.len()));
     }
 
-    // Duplicate check: use UID for Uid entries, slot index for Embedded entries
-    let mut seen_uids = HashSet::<String>::new();
-    for (i, entry) in roster.iter().enumerate() {
-        match entry {
-            RosterEntry::Uid(uid) => {
-                if !seen_uids.insert(uid.clone()) {
-                    return Err(format!("Duplicate player UID in roster: {uid}"));
-                }
-            }
-            RosterEntry::UidWithMeta(meta) => {
-                if !seen_uids.insert(meta.uid.clone()) {
-                    return Err(format!("Duplicate player UID in roster: {}", meta.uid));
-                }
-            }
-            RosterEntry::Embedded(_) => {
-                // For embedded entries, use slot index as unique key
-                seen_uids.insert(format!("__embedded_slot_{i}"));
-            }
+    // Duplicate check on UIDs (inline players are keyed by slot / track_id)
+    let mut seen_uids = HashSet::<&str>::new();
+    for uid in roster.iter().filter_map(RosterSlot::uid) {
+        if !seen_uids.insert(uid) {
+            return Err(format!("Duplicate player UID in roster: {uid}"));
         }
     }
 
     let mut resolved: Vec<(String, Player)> = Vec::with_capacity(18);
     for (slot_idx, entry) in roster.into_iter().enumerate() {
-        let (uid_key, player) = match entry {
-            RosterEntry::Uid(uid) => {
-                // FIX01: condition is required for match-time determinism & CI proof.
-                return Err(err_code(
-                    error_codes::INVALID_CONDITION_RANGE,
-                    format!(
-                        "missing condition for UID roster entry '{uid}' (use object form {{\"uid\":\"...\",\"condition\":3}})"
-                    ),
-                ));
-            }
-            RosterEntry::UidWithMeta(meta) => {
-                let condition = validate_condition_level(meta.condition)?;
-                let uid = meta.uid;
-
-                // Resolve from CSV/DB
-                let person = resolve_person_by_player_uid(&uid)?;
-                let name = person.name.clone();
-                let position_token = primary_position_token(&person.position);
-                let position = map_person_position(&position_token);
-                let overall = ca_to_overall(person.ca);
-
-                let fm_attrs = person.get_attributes_map();
-                let match_attrs = crate::data::ScaleConverter::fm_to_match_engine_attrs(&fm_attrs);
-
-                let player_attributes = PlayerAttributes {
-                    // Technical (14)
-                    corners: *match_attrs.get("corners").unwrap_or(&50),
-                    crossing: *match_attrs.get("crossing").unwrap_or(&50),
-                    dribbling: *match_attrs.get("dribbling").unwrap_or(&50),
-                    finishing: *match_attrs.get("finishing").unwrap_or(&50),
-                    first_touch: *match_attrs.get("first_touch").unwrap_or(&50),
-                    free_kicks: *match_attrs.get("free_kick_taking").unwrap_or(&50),
-                    heading: *match_attrs.get("heading").unwrap_or(&50),
-                    long_shots: *match_attrs.get("long_shots").unwrap_or(&50),
-                    long_throws: *match_attrs.get("long_throws").unwrap_or(&50),
-                    marking: *match_attrs.get("marking").unwrap_or(&50),
-                    passing: *match_attrs.get("passing").unwrap_or(&50),
-                    penalty_taking: *match_attrs.get("penalty_taking").unwrap_or(&50),
-                    tackling: *match_attrs.get("tackling").unwrap_or(&50),
-                    technique: *match_attrs.get("technique").unwrap_or(&50),
-                    // Mental (14)
-                    aggression: *match_attrs.get("aggression").unwrap_or(&50),
-                    anticipation: *match_attrs.get("anticipation").unwrap_or(&50),
-                    bravery: *match_attrs.get("bravery").unwrap_or(&50),
-                    composure: *match_attrs.get("composure").unwrap_or(&50),
-                    concentration: *match_attrs.get("concentration").unwrap_or(&50),
-                    decisions: *match_attrs.get("decisions").unwrap_or(&50),
-                    determination: *match_attrs.get("determination").unwrap_or(&50),
-                    flair: *match_attrs.get("flair").unwrap_or(&50),
-                    leadership: *match_attrs.get("leadership").unwrap_or(&50),
-                    off_the_ball: *match_attrs.get("off_the_ball").unwrap_or(&50),
-                    positioning: *match_attrs.get("positioning").unwrap_or(&50),
-                    teamwork: *match_attrs.get("teamwork").unwrap_or(&50),
-                    vision: *match_attrs.get("vision").unwrap_or(&50),
-                    work_rate: *match_attrs.get("work_rate").unwrap_or(&50),
-                    // Physical (8)
-                    acceleration: *match_attrs.get("acceleration").unwrap_or(&50),
-                    agility: *match_attrs.get("agility").unwrap_or(&50),
-                    balance: *match_attrs.get("balance").unwrap_or(&50),
-                    jumping: *match_attrs.get("jumping").unwrap_or(&50),
-                    natural_fitness: *match_attrs.get("natural_fitness").unwrap_or(&50),
-                    pace: *match_attrs.get("pace").unwrap_or(&50),
-                    stamina: *match_attrs.get("stamina").unwrap_or(&50),
-                    strength: *match_attrs.get("strength").unwrap_or(&50),
-                    // Goalkeeper (11) - v5 schema, FM scale (1-20) converted to match engine scale
-                    gk_aerial_reach: *match_attrs.get("gk_aerial_reach").unwrap_or(&0),
-                    gk_command_of_area: *match_attrs.get("gk_command_of_area").unwrap_or(&0),
-                    gk_communication: *match_attrs.get("gk_communication").unwrap_or(&0),
-                    gk_eccentricity: *match_attrs.get("gk_eccentricity").unwrap_or(&0),
-                    gk_handling: *match_attrs.get("gk_handling").unwrap_or(&0),
-                    gk_kicking: *match_attrs.get("gk_kicking").unwrap_or(&0),
-                    gk_one_on_ones: *match_attrs.get("gk_one_on_ones").unwrap_or(&0),
-                    gk_reflexes: *match_attrs.get("gk_reflexes").unwrap_or(&0),
-                    gk_rushing_out: *match_attrs.get("gk_rushing_out").unwrap_or(&0),
-                    gk_punching: *match_attrs.get("gk_punching").unwrap_or(&0),
-                    gk_throwing: *match_attrs.get("gk_throwing").unwrap_or(&0),
-                };
-
-                (
-                    uid,
-                    Player {
-                        name,
-                        position,
-                        overall,
-                        condition,
-                        attributes: Some(player_attributes),
-                        equipped_skills: Vec::new(),
-                        traits: Default::default(),
-                        personality: Default::default(),
-                    },
-                )
-            }
-            RosterEntry::Embedded(embedded) => {
-                // NEW: Embedded player data with full attributes (MRQ0 v3)
-                let position = parse_position(&embedded.position)
-                    .unwrap_or(crate::models::player::Position::MF);
-                let condition = validate_condition_level(embedded.condition)?;
-
-                // Build attributes from embedded data or derive from overall
-                let player_attributes = if let Some(ref attrs) = embedded.attributes {
-                    PlayerAttributes {
-                        // Technical (14)
-                        corners: attrs.corners,
-                        crossing: attrs.crossing,
-                        dribbling: attrs.dribbling,
-                        finishing: attrs.finishing,
-                        first_touch: attrs.first_touch,
-                        free_kicks: attrs.free_kick_taking,
-                        heading: attrs.heading,
-                        long_shots: attrs.long_shots,
-                        long_throws: attrs.long_throws,
-                        marking: attrs.marking,
-                        passing: attrs.passing,
-                        penalty_taking: attrs.penalty_taking,
-                        tackling: attrs.tackling,
-                        technique: attrs.technique,
-                        // Mental (14)
-                        aggression: attrs.aggression,
-                        anticipation: attrs.anticipation,
-                        bravery: attrs.bravery,
-                        composure: attrs.composure,
-                        concentration: attrs.concentration,
-                        decisions: attrs.decisions,
-                        determination: attrs.determination,
-                        flair: attrs.flair,
-                        leadership: attrs.leadership,
-                        off_the_ball: attrs.off_the_ball,
-                        positioning: attrs.positioning,
-                        teamwork: attrs.teamwork,
-                        vision: attrs.vision,
-                        work_rate: attrs.work_rate,
-                        // Physical (8)
-                        acceleration: attrs.acceleration,
-                        agility: attrs.agility,
-                        balance: attrs.balance,
-                        jumping: attrs.jumping_reach,
-                        natural_fitness: attrs.natural_fitness,
-                        pace: attrs.pace,
-                        stamina: attrs.stamina,
-                        strength: attrs.strength,
-                        // Goalkeeper (11) - embedded data doesn't include GK attrs, default 0
-                        gk_aerial_reach: 0,
-                        gk_command_of_area: 0,
-                        gk_communication: 0,
-                        gk_eccentricity: 0,
-                        gk_handling: 0,
-                        gk_kicking: 0,
-                        gk_one_on_ones: 0,
-                        gk_reflexes: 0,
-                        gk_rushing_out: 0,
-                        gk_punching: 0,
-                        gk_throwing: 0,
-                    }
-                } else {
-                    // Fallback: derive from overall
-                    PlayerAttributes::from_uniform(embedded.overall)
-                };
-
-                // Parse personality archetype
-                let personality = match embedded.personality.as_deref() {
-                    Some("Leader") => PersonalityArchetype::Leader,
-                    Some("Genius") => PersonalityArchetype::Genius,
-                    Some("Workhorse") => PersonalityArchetype::Workhorse,
-                    Some("Rebel") => PersonalityArchetype::Rebel,
-                    _ => PersonalityArchetype::Steady, // default
-                };
-
-                // Build trait slots from embedded traits
-                let trait_slots = build_trait_slots(embedded.traits.as_ref());
-
-                let uid_key = embedded
-                    .track_id
-                    .map(|id| format!("embedded:{id}"))
-                    .unwrap_or_else(|| format!("embedded:slot_{slot_idx}"));
-
-                (
-                    uid_key,
-                    Player {
-                        name: embedded.name,
-                        position,
-                        overall: embedded.overall,
-                        condition,
-                        attributes: Some(player_attributes),
-                        equipped_skills: Vec::new(),
-                        traits: trait_slots,
-                        personality,
-                    },
-                )
-            }
-        };
-        resolved.push((uid_key, player));
+        resolved.push(entry.into_player(slot_idx)?);
     }
 
     // Deterministic per-team name disambiguation (only if duplicates exist).
@@ -1018,7 +1508,92 @@ fn convert_team_v2(
     Ok((Team { name, formation, players }, uid_to_name, player_instructions_by_name))
 }
 
-fn ca_to_overall(ca: u8) -> u8 {
+/// Resolve a UID roster entry from CSV/DB into an engine player.
+///
+/// Shared by the JSON v2 roster and the MRQ0 v5 binary path so both produce
+/// identical players for the same UID.
+pub fn resolve_uid_player(uid: &str, condition: u8) -> Result<Player, String> {
+    let condition = validate_condition_level(condition)?;
+
+    // Resolve from CSV/DB
+    let person = resolve_person_by_player_uid(uid)?;
+    let name = person.name.clone();
+    let position_token = primary_position_token(&person.position);
+    let position = map_person_position(&position_token);
+    let overall = ca_to_overall(person.ca);
+
+    let fm_attrs = person.get_attributes_map();
+    let match_attrs = crate::data::ScaleConverter::fm_to_match_engine_attrs(&fm_attrs);
+
+    let player_attributes = PlayerAttributes {
+        // Technical (14)
+        corners: *match_attrs.get("corners").unwrap_or(&50),
+        crossing: *match_attrs.get("crossing").unwrap_or(&50),
+        dribbling: *match_attrs.get("dribbling").unwrap_or(&50),
+        finishing: *match_attrs.get("finishing").unwrap_or(&50),
+        first_touch: *match_attrs.get("first_touch").unwrap_or(&50),
+        free_kicks: *match_attrs.get("free_kick_taking").unwrap_or(&50),
+        heading: *match_attrs.get("heading").unwrap_or(&50),
+        long_shots: *match_attrs.get("long_shots").unwrap_or(&50),
+        long_throws: *match_attrs.get("long_throws").unwrap_or(&50),
+        marking: *match_attrs.get("marking").unwrap_or(&50),
+        passing: *match_attrs.get("passing").unwrap_or(&50),
+        penalty_taking: *match_attrs.get("penalty_taking").unwrap_or(&50),
+        tackling: *match_attrs.get("tackling").unwrap_or(&50),
+        technique: *match_attrs.get("technique").unwrap_or(&50),
+        // Mental (14)
+        aggression: *match_attrs.get("aggression").unwrap_or(&50),
+        anticipation: *match_attrs.get("anticipation").unwrap_or(&50),
+        bravery: *match_attrs.get("bravery").unwrap_or(&50),
+        composure: *match_attrs.get("composure").unwrap_or(&50),
+        concentration: *match_attrs.get("concentration").unwrap_or(&50),
+        decisions: *match_attrs.get("decisions").unwrap_or(&50),
+        determination: *match_attrs.get("determination").unwrap_or(&50),
+        flair: *match_attrs.get("flair").unwrap_or(&50),
+        leadership: *match_attrs.get("leadership").unwrap_or(&50),
+        off_the_ball: *match_attrs.get("off_the_ball").unwrap_or(&50),
+        positioning: *match_attrs.get("positioning").unwrap_or(&50),
+        teamwork: *match_attrs.get("teamwork").unwrap_or(&50),
+        vision: *match_attrs.get("vision").unwrap_or(&50),
+        work_rate: *match_attrs.get("work_rate").unwrap_or(&50),
+        // Physical (8)
+        acceleration: *match_attrs.get("acceleration").unwrap_or(&50),
+        agility: *match_attrs.get("agility").unwrap_or(&50),
+        balance: *match_attrs.get("balance").unwrap_or(&50),
+        jumping: *match_attrs.get("jumping").unwrap_or(&50),
+        natural_fitness: *match_attrs.get("natural_fitness").unwrap_or(&50),
+        pace: *match_attrs.get("pace").unwrap_or(&50),
+        stamina: *match_attrs.get("stamina").unwrap_or(&50),
+        strength: *match_attrs.get("strength").unwrap_or(&50),
+        // Goalkeeper (11) - v5 schema, FM scale (1-20) converted to match engine scale
+        gk_aerial_reach: *match_attrs.get("gk_aerial_reach").unwrap_or(&0),
+        gk_command_of_area: *match_attrs.get("gk_command_of_area").unwrap_or(&0),
+        gk_communication: *match_attrs.get("gk_communication").unwrap_or(&0),
+        gk_eccentricity: *match_attrs.get("gk_eccentricity").unwrap_or(&0),
+        gk_handling: *match_attrs.get("gk_handling").unwrap_or(&0),
+        gk_kicking: *match_attrs.get("gk_kicking").unwrap_or(&0),
+        gk_one_on_ones: *match_attrs.get("gk_one_on_ones").unwrap_or(&0),
+        gk_reflexes: *match_attrs.get("gk_reflexes").unwrap_or(&0),
+        gk_rushing_out: *match_attrs.get("gk_rushing_out").unwrap_or(&0),
+        gk_punching: *match_attrs.get("gk_punching").unwrap_or(&0),
+        gk_throwing: *match_attrs.get("gk_throwing").unwrap_or(&0),
+    };
+
+    Ok(Player {
+        name,
+        position,
+        overall,
+        condition,
+        attributes: Some(player_attributes),
+        equipped_skills: Vec::new(),
+        traits: Default::default(),
+        personality: Default::default(),
+        morale: NEUTRAL_MORALE,
+        form: NEUTRAL_FORM,
+    })
+}
+
+pub(crate) fn ca_to_overall(ca: u8) -> u8 {
     // Person.ca is 0..200, while engine Player.overall expects 0..100-ish.
     // round(ca / 2) == (ca + 1) / 2 for integer ca.
     let raw: u16 = (ca as u16).div_ceil(2);
@@ -1114,10 +1689,14 @@ fn convert_player(data: PlayerData) -> Result<Player, String> {
         equipped_skills: Vec::new(),
         traits: Default::default(),
         personality: Default::default(),
+        morale: NEUTRAL_MORALE,
+        form: NEUTRAL_FORM,
     })
 }
 
-fn parse_formation(formation_str: &str) -> Result<crate::models::team::Formation, String> {
+pub(crate) fn parse_formation(
+    formation_str: &str,
+) -> Result<crate::models::team::Formation, String> {
     use crate::models::team::Formation;
 
     match formation_str {
@@ -1142,7 +1721,9 @@ fn parse_formation(formation_str: &str) -> Result<crate::models::team::Formation
     }
 }
 
-fn parse_position(position_str: &str) -> Result<crate::models::player::Position, String> {
+pub(crate) fn parse_position(
+    position_str: &str,
+) -> Result<crate::models::player::Position, String> {
     use crate::models::player::Position;
 
     match position_str.to_uppercase().as_str() {
@@ -1187,4 +1768,259 @@ mod tests {
         assert_eq!(attrs.pace, 100);
         assert_eq!(attrs.finishing, 100);
     }
+
+    #[test]
+    fn match_request_pitch_is_optional() {
+        let base = r#"{"schema_version":1,"seed":1,
+            "home_team":{"name":"H","formation":"4-4-2","players":[]},
+            "away_team":{"name":"A","formation":"4-4-2","players":[]},
+            "user_player":null"#;
+
+        let request: MatchRequest = serde_json::from_str(&format!("{base}}}")).unwrap();
+        assert!(request.pitch.is_none());
+
+        let request: MatchRequest = serde_json::from_str(&format!(
+            r#"{base},"pitch":{{"format":"seven_a_side","width_m":35.0}}}}"#
+        ))
+        .unwrap();
+        let pitch = request.pitch.expect("pitch should be parsed").resolve().unwrap();
+        assert_eq!(pitch.format, crate::models::pitch::PitchFormat::SevenASide);
+        assert_eq!(pitch.length_m, 55.0);
+        assert_eq!(pitch.width_m, 35.0);
+
+        let too_small = PitchRequest { length_m: Some(60.0), ..Default::default() };
+        assert!(too_small.resolve().is_err());
+    }
+
+    #[test]
+    fn embedded_attributes_ordered_block_matches_json_fields() {
+        let mut values = [0u8; EmbeddedPlayerAttributes::FIELD_COUNT];
+        for (i, v) in values.iter_mut().enumerate() {
+            *v = 40 + i as u8;
+        }
+        let from_block = EmbeddedPlayerAttributes::from_ordered(values);
+
+        let json = r#"{"corners":40,"crossing":41,"dribbling":42,"finishing":43,
+            "first_touch":44,"free_kick_taking":45,"heading":46,"long_shots":47,
+            "long_throws":48,"marking":49,"passing":50,"penalty_taking":51,"tackling":52,
+            "technique":53,"aggression":54,"anticipation":55,"bravery":56,"composure":57,
+            "concentration":58,"decisions":59,"determination":60,"flair":61,"leadership":62,
+            "off_the_ball":63,"positioning":64,"teamwork":65,"vision":66,"work_rate":67,
+            "acceleration":68,"agility":69,"balance":70,"jumping_reach":71,
+            "natural_fitness":72,"pace":73,"stamina":74,"strength":75}"#;
+        let from_json: EmbeddedPlayerAttributes = serde_json::from_str(json).unwrap();
+
+        let attrs = from_block.to_player_attributes();
+        assert_eq!(attrs, from_json.to_player_attributes());
+        assert_eq!(attrs.free_kicks, 45);
+        assert_eq!(attrs.jumping, 71);
+        assert_eq!(attrs.strength, 75);
+        assert_eq!(attrs.gk_reflexes, 0);
+    }
+
+    #[test]
+    fn ai_difficulty_tier_names_are_validated() {
+        assert_eq!(parse_ai_difficulty(None), Ok(None));
+        assert_eq!(parse_ai_difficulty(Some("hard")), Ok(Some(AIDifficulty::Hard)));
+        assert_eq!(parse_ai_difficulty(Some("Expert")), Ok(Some(AIDifficulty::Expert)));
+        assert!(parse_ai_difficulty(Some("Nightmare")).is_err());
+    }
+    #[test]
+    fn set_piece_takers_are_validated_per_side() {
+        let team = |name: &str, takers: serde_json::Value| {
+            let positions = [
+                "GK", "LB", "CB", "CB", "RB", "LM", "CM", "CM", "RM", "ST", "ST", "GK", "CB", "CM",
+                "CM", "LW", "RW", "ST",
+            ];
+            let roster: Vec<_> = positions
+                .iter()
+                .map(|pos| serde_json::json!({"name": name, "position": pos, "overall": 70, "condition": 3}))
+                .collect();
+            serde_json::json!({"name": name, "formation": "4-4-2", "roster": roster,
+                "set_piece_takers": takers})
+        };
+        let request = |away_takers: serde_json::Value| -> MatchRequestV2 {
+            serde_json::from_value(serde_json::json!({
+                "schema_version": 2,
+                "seed": 5,
+                "home_team": team("H", serde_json::json!({"penalties": [9]})),
+                "away_team": team("A", away_takers)
+            }))
+            .unwrap()
+        };
+
+        let (plan, _) =
+            match_plan_from_match_request_v2(request(serde_json::json!({"corners_left": [20]})))
+                .unwrap();
+        assert_eq!(plan.home_set_piece_takers.unwrap().penalties, vec![9]);
+        assert_eq!(plan.away_set_piece_takers.unwrap().corners_left, vec![20]);
+
+        // Away takers must use away track_ids (11-21)
+        let err = match_plan_from_match_request_v2(request(serde_json::json!({"penalties": [3]})))
+            .unwrap_err();
+        assert!(err.contains(error_codes::INVALID_SET_PIECE_TAKER), "{}", err);
+    }
+
+    #[test]
+    fn referee_seed_resolves_to_seeded_profile() {
+        let team = |name: &str| {
+            let positions = [
+                "GK", "LB", "CB", "CB", "RB", "LM", "CM", "CM", "RM", "ST", "ST", "GK", "CB", "CM",
+                "CM", "LW", "RW", "ST",
+            ];
+            let roster: Vec<_> = positions
+                .iter()
+                .map(|pos| serde_json::json!({"name": name, "position": pos, "overall": 70, "condition": 3}))
+                .collect();
+            serde_json::json!({"name": name, "formation": "4-4-2", "roster": roster})
+        };
+        let request = |referee: serde_json::Value| -> MatchRequestV2 {
+            let mut request = serde_json::json!({
+                "schema_version": 2,
+                "seed": 5,
+                "home_team": team("H"),
+                "away_team": team("A"),
+                "referee_seed": 77
+            });
+            request["referee"] = referee;
+            serde_json::from_value(request).unwrap()
+        };
+
+        let (plan, _) = match_plan_from_match_request_v2(request(serde_json::Value::Null)).unwrap();
+        assert_eq!(plan.referee, Some(crate::models::RefereeProfile::from_seed(77)));
+
+        // An explicit profile wins over the seed; missing tendencies are neutral
+        let (plan, _) =
+            match_plan_from_match_request_v2(request(serde_json::json!({"name": "Explicit"})))
+                .unwrap();
+        let referee = plan.referee.expect("explicit referee");
+        assert_eq!(referee.name, "Explicit");
+        assert_eq!(referee.card_mult(), 1.0);
+    }
+
+    #[test]
+    fn simulate_with_parameters_records_hash_and_keeps_neutral_results() {
+        let team = |name: &str| {
+            let positions = [
+                "GK", "LB", "CB", "CB", "RB", "LM", "CM", "CM", "RM", "ST", "ST", "GK", "CB", "CM",
+                "CM", "LW", "RW", "ST",
+            ];
+            let roster: Vec<_> = positions
+                .iter()
+                .map(|pos| serde_json::json!({"name": name, "position": pos, "overall": 70, "condition": 3}))
+                .collect();
+            serde_json::json!({"name": name, "formation": "4-4-2", "roster": roster})
+        };
+        let request = serde_json::json!({
+            "schema_version": 2,
+            "seed": 21,
+            "home_team": team("H"),
+            "away_team": team("A")
+        })
+        .to_string();
+
+        let mut baseline: serde_json::Value =
+            serde_json::from_str(&simulate_match_v2_json(&request).unwrap()).unwrap();
+        let mut neutral: serde_json::Value =
+            serde_json::from_str(&simulate_with_parameters_json(&request, "{}").unwrap()).unwrap();
+        assert_eq!(neutral["parameter_hash"], ParameterSet::default().param_hash());
+        assert!(baseline.get("parameter_hash").is_none());
+
+        // Neutral parameters reproduce the shipped engine exactly
+        neutral.as_object_mut().unwrap().remove("parameter_hash");
+        baseline.as_object_mut().unwrap().remove("parameter_hash");
+        assert_eq!(neutral, baseline);
+
+        let err = simulate_with_parameters_json(&request, r#"{"shot_conversion": 10.0}"#);
+        assert!(err.unwrap_err().contains("shot_conversion"));
+    }
+
+    #[test]
+    fn match_request_v3_accepts_inline_attributes_and_fitness() {
+        let team = |name: &str, extra: serde_json::Value| {
+            let positions = [
+                "GK", "LB", "CB", "CB", "RB", "LM", "CM", "CM", "RM", "ST", "ST", "GK", "CB", "CM",
+                "CM", "LW", "RW", "ST",
+            ];
+            let mut roster: Vec<_> = positions
+                .iter()
+                .map(|pos| {
+                    serde_json::json!({"name": name, "position": pos, "overall": 70, "fitness": 80})
+                })
+                .collect();
+            for (key, value) in extra.as_object().unwrap() {
+                roster[9][key] = value.clone();
+            }
+            serde_json::json!({"name": name, "formation": "4-4-2", "roster": roster})
+        };
+        let request = |extra: serde_json::Value| {
+            serde_json::json!({
+                "schema_version": 3,
+                "seed": 9,
+                "home_team": team("H", extra),
+                "away_team": team("A", serde_json::json!({}))
+            })
+            .to_string()
+        };
+
+        let mut attributes = serde_json::to_value(PlayerAttributes::from_uniform(70)).unwrap();
+        attributes["finishing"] = serde_json::json!(95);
+        let ok = request(serde_json::json!({"attributes": attributes, "condition": 5}));
+        let (plan, _) = match_plan_from_match_request_v3_json(&ok).unwrap();
+        let striker = &plan.home_team.players[9];
+        assert_eq!(striker.attributes.as_ref().unwrap().finishing, 95);
+        assert_eq!(striker.condition, 5);
+        assert_eq!(plan.home_team.players[0].condition, condition_level_from_fitness(80));
+        assert!(simulate_match_v3_json(&ok).is_ok());
+
+        let no_condition = request(serde_json::json!({"fitness": null}));
+        let err = match_plan_from_match_request_v3_json(&no_condition).unwrap_err();
+        assert!(err.contains(error_codes::INVALID_CONDITION_RANGE), "{}", err);
+
+        let skills = serde_json::Value::from(vec!["Maestro"; 6]);
+        let err = match_plan_from_match_request_v3_json(&request(
+            serde_json::json!({"equipped_skills": skills}),
+        ))
+        .unwrap_err();
+        assert!(err.contains("equipped_skills"), "{}", err);
+    }
+
+    #[test]
+    fn attendance_adds_reported_crowd_pressure() {
+        let team = |name: &str| {
+            let positions = [
+                "GK", "LB", "CB", "CB", "RB", "LM", "CM", "CM", "RM", "ST", "ST", "GK", "CB", "CM",
+                "CM", "LW", "RW", "ST",
+            ];
+            let roster: Vec<_> = positions
+                .iter()
+                .map(|pos| serde_json::json!({"name": name, "position": pos, "overall": 70, "condition": 3}))
+                .collect();
+            serde_json::json!({"name": name, "formation": "4-4-2", "roster": roster})
+        };
+        let request = |stadium: serde_json::Value| {
+            serde_json::json!({
+                "schema_version": 2,
+                "seed": 11,
+                "home_team": team("H"),
+                "away_team": team("A"),
+                "stadium": stadium,
+                "attendance": 40_000
+            })
+            .to_string()
+        };
+        let ground = serde_json::json!({"name": "Home Park", "length_m": 105.0, "width_m": 68.0,
+            "capacity": 42_000});
+
+        let result: serde_json::Value =
+            serde_json::from_str(&simulate_match_v2_json(&request(ground)).unwrap()).unwrap();
+        assert_eq!(result["crowd"]["attendance"], 40_000);
+        assert_eq!(result["crowd"]["stadium"], "Home Park");
+        let entries = result["modifier_report"]["entries"].as_array().unwrap();
+        assert!(entries.iter().any(|e| e["source"] == "crowd" && e["stat"] == "card_rate"));
+
+        // No venue, no crowd
+        let err = simulate_match_v2_json(&request(serde_json::Value::Null)).unwrap_err();
+        assert!(err.contains("attendance"), "{}", err);
+    }
 }
@@ -77,12 +77,143 @@ fn env_truthy(name: &str) -> bool {
         .unwrap_or(false)
 }
 
-fn run_match_with_budget(
-    request_json: &str,
-    mut budget: SimBudget,
-) -> Result<BudgetRunResult, String> {
-    let start_time = Instant::now();
+/// Progress snapshot passed to the callback after every simulated minute
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct BudgetProgress {
+    /// Minutes simulated so far (across resumes)
+    pub minutes_simulated: u16,
+    /// Current match minute
+    pub match_minute: u8,
+    pub match_duration: u8,
+    pub events: usize,
+    /// Wall time spent in the current call
+    pub wall_time_ms: u64,
+    /// Full time reached (match_duration may include unplayed stoppage allowance)
+    pub finished: bool,
+}
 
+impl BudgetProgress {
+    /// Completed share of the match (0.0..=1.0), for progress bars
+    pub fn fraction(&self) -> f32 {
+        if self.finished || self.match_duration == 0 {
+            return 1.0;
+        }
+        (self.match_minute as f32 / self.match_duration as f32).clamp(0.0, 1.0)
+    }
+}
+
+/// In-memory state of a budgeted simulation cut by its budget.
+///
+/// Pass it to `resume_match_json_budget` to continue at the exact minute it stopped;
+/// the engine (RNG included) is kept as-is, so the finished match is identical to an
+/// uninterrupted run.
+#[derive(Clone)]
+pub struct BudgetResumeToken {
+    engine: Box<MatchEngine>,
+    home_strength: f32,
+    away_strength: f32,
+    possession_ratio: f32,
+    match_duration: u8,
+    minutes_simulated: u16,
+}
+
+impl BudgetResumeToken {
+    fn start(mut engine: MatchEngine) -> Self {
+        let (home_strength, away_strength, possession_ratio, match_duration) = engine.init();
+        Self {
+            engine: Box::new(engine),
+            home_strength,
+            away_strength,
+            possession_ratio,
+            match_duration,
+            minutes_simulated: 0,
+        }
+    }
+
+    /// Minutes simulated before the cut
+    pub fn minutes_simulated(&self) -> u16 {
+        self.minutes_simulated
+    }
+
+    /// Match minute the simulation resumes at
+    pub fn match_minute(&self) -> u8 {
+        self.engine.get_minute()
+    }
+
+    fn progress(&self, start_time: Instant) -> BudgetProgress {
+        BudgetProgress {
+            minutes_simulated: self.minutes_simulated,
+            match_minute: self.engine.get_minute(),
+            match_duration: self.match_duration,
+            events: self.engine.event_count(),
+            wall_time_ms: start_time.elapsed().as_millis() as u64,
+            finished: false,
+        }
+    }
+
+    /// Step minutes until the match ends or `budget` is exceeded.
+    /// Returns `Some(reason)` when the budget cut the run.
+    fn advance(
+        &mut self,
+        budget: &mut SimBudget,
+        start_time: Instant,
+        mut on_progress: impl FnMut(&BudgetProgress),
+    ) -> Option<String> {
+        // Step-based simulation with cooperative budget checking
+        loop {
+            // Check budget before each minute
+            if !budget.tick_minute() {
+                return Some(
+                    budget.get_exceeded_reason().unwrap_or_else(|| "Budget exceeded".to_string()),
+                );
+            }
+
+            // Check event count
+            if self.engine.event_count() > 0 && !budget.tick_event() {
+                return Some(
+                    budget
+                        .get_exceeded_reason()
+                        .unwrap_or_else(|| "Event budget exceeded".to_string()),
+                );
+            }
+
+            // Simulate one minute
+            let should_continue = self.engine.step(
+                self.home_strength,
+                self.away_strength,
+                self.possession_ratio,
+                self.match_duration,
+            );
+
+            // If match finished naturally, exit
+            if !should_continue {
+                return None;
+            }
+            self.minutes_simulated += 1;
+            on_progress(&self.progress(start_time));
+
+            // Double-check timeout (in case step() took too long)
+            if budget.is_exceeded() {
+                return Some(
+                    budget
+                        .get_exceeded_reason()
+                        .unwrap_or_else(|| "Budget exceeded during simulation".to_string()),
+                );
+            }
+        }
+    }
+}
+
+/// Response of a progress-reporting budgeted run
+pub struct BudgetedOutcome {
+    /// Same JSON as `simulate_match_json_budget` (partial or complete)
+    pub response_json: String,
+    pub progress: BudgetProgress,
+    /// Set when the budget cut the run; resume with `resume_match_json_budget`
+    pub resume: Option<BudgetResumeToken>,
+}
+
+fn build_budget_engine(request_json: &str) -> Result<MatchEngine, String> {
     // Parse request
     let request: MatchRequest =
         serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
@@ -128,6 +259,7 @@ fn run_match_with_budget(
             player_name: up.player_name,
             player_index,
             highlight_level: convert_highlight_level(up.highlight_level),
+            co_op_players: Vec::new(),
         }
     });
 
@@ -138,12 +270,24 @@ fn run_match_with_budget(
         user_player,
         home_match_modifiers: crate::engine::TeamMatchModifiers::default(),
         away_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+        modifier_stack: Default::default(),
         home_instructions,
         away_instructions,
         home_player_instructions,
         away_player_instructions,
         home_ai_difficulty: None,
         away_ai_difficulty: None,
+        pitch: crate::models::pitch::PitchSpec::default(),
+        home_chemistry: None,
+        away_chemistry: None,
+        stadium: None,
+        conditions: None,
+        penalty_shootout: None,
+        home_custom_formation: None,
+        away_custom_formation: None,
+        home_set_piece_takers: None,
+        away_set_piece_takers: None,
+        referee: None,
     };
 
     // Create engine and initialize
@@ -157,66 +301,86 @@ fn run_match_with_budget(
         engine = engine.with_position_tracking();
     }
     apply_exp_config_from_env(&mut engine)?;
-    let (home_strength, away_strength, possession_ratio, match_duration) = engine.init();
-
-    // Track simulation progress
-    let mut budget_exceeded = false;
-    let mut overflow_reason = String::new();
-
-    // Step-based simulation with cooperative budget checking
-    loop {
-        // Check budget before each minute
-        if !budget.tick_minute() {
-            budget_exceeded = true;
-            overflow_reason =
-                budget.get_exceeded_reason().unwrap_or_else(|| "Budget exceeded".to_string());
-            break;
-        }
-
-        // Check event count
-        if engine.event_count() > 0 && !budget.tick_event() {
-            budget_exceeded = true;
-            overflow_reason =
-                budget.get_exceeded_reason().unwrap_or_else(|| "Event budget exceeded".to_string());
-            break;
-        }
-
-        // Simulate one minute
-        let should_continue =
-            engine.step(home_strength, away_strength, possession_ratio, match_duration);
-
-        // If match finished naturally, exit
-        if !should_continue {
-            break;
-        }
-
-        // Double-check timeout (in case step() took too long)
-        if budget.is_exceeded() {
-            budget_exceeded = true;
-            overflow_reason = budget
-                .get_exceeded_reason()
-                .unwrap_or_else(|| "Budget exceeded during simulation".to_string());
-            break;
-        }
-    }
+    Ok(engine)
+}
 
-    // Finalize and get result
-    let mut result = engine.finalize(possession_ratio);
-    let wall_time_ms = start_time.elapsed().as_millis() as u64;
-    let (minutes_done, _events_done, _) = budget.get_progress();
+fn finish_budget_run(
+    mut result: MatchResult,
+    overflow: Option<String>,
+    minutes_simulated: u16,
+    wall_time_ms: u64,
+) -> BudgetRunResult {
+    let budget_exceeded = overflow.is_some();
+    let overflow_reason = overflow.unwrap_or_default();
 
     // FIX02: determinism/truncation metadata for budget path.
     result.determinism.mode = if budget_exceeded { DeterminismMode::Truncated } else { DeterminismMode::Budgeted };
     result.determinism.simulated_until_tick = result.statistics.total_ticks;
     result.determinism.cut_reason = if budget_exceeded { Some(overflow_reason.clone()) } else { None };
 
-    Ok(BudgetRunResult {
+    BudgetRunResult {
         result,
         budget_exceeded,
         overflow_reason,
-        minutes_simulated: minutes_done,
+        minutes_simulated,
         wall_time_ms,
-    })
+    }
+}
+
+fn run_match_with_budget(
+    request_json: &str,
+    mut budget: SimBudget,
+) -> Result<BudgetRunResult, String> {
+    let start_time = Instant::now();
+    let mut run = BudgetResumeToken::start(build_budget_engine(request_json)?);
+    let overflow = run.advance(&mut budget, start_time, |_| {});
+
+    // Finalize and get result
+    let result = run.engine.finalize(run.possession_ratio);
+    let wall_time_ms = start_time.elapsed().as_millis() as u64;
+    let (minutes_done, _events_done, _) = budget.get_progress();
+    Ok(finish_budget_run(result, overflow, minutes_done, wall_time_ms))
+}
+
+fn continue_budget_run(
+    mut run: BudgetResumeToken,
+    mut budget: SimBudget,
+    on_progress: impl FnMut(&BudgetProgress),
+) -> Result<BudgetedOutcome, String> {
+    let start_time = Instant::now();
+    let overflow = run.advance(&mut budget, start_time, on_progress);
+    let progress = BudgetProgress { finished: overflow.is_none(), ..run.progress(start_time) };
+
+    // A cut run finalizes a copy so the token can keep simulating from this minute.
+    let (result, resume) = if overflow.is_some() {
+        let mut partial = run.engine.as_ref().clone();
+        (partial.finalize(run.possession_ratio), Some(run))
+    } else {
+        (run.engine.finalize(run.possession_ratio), None)
+    };
+    let run_result =
+        finish_budget_run(result, overflow, progress.minutes_simulated, progress.wall_time_ms);
+    Ok(BudgetedOutcome { response_json: budget_response_json(run_result)?, progress, resume })
+}
+
+/// Budgeted simulation that reports progress after every minute and, when the budget
+/// cuts the run, returns a token to resume it at the exact minute it stopped.
+pub fn simulate_match_json_budget_with_progress(
+    request_json: &str,
+    budget: SimBudget,
+    on_progress: impl FnMut(&BudgetProgress),
+) -> Result<BudgetedOutcome, String> {
+    let run = BudgetResumeToken::start(build_budget_engine(request_json)?);
+    continue_budget_run(run, budget, on_progress)
+}
+
+/// Continue a budget-cut simulation under a fresh `budget` (deterministic).
+pub fn resume_match_json_budget(
+    token: BudgetResumeToken,
+    budget: SimBudget,
+    on_progress: impl FnMut(&BudgetProgress),
+) -> Result<BudgetedOutcome, String> {
+    continue_budget_run(token, budget, on_progress)
 }
 
 /// Simulate match with budget constraints using step-based API
@@ -224,8 +388,10 @@ pub fn simulate_match_json_budget(
     request_json: &str,
     budget: SimBudget,
 ) -> Result<String, String> {
-    let run = run_match_with_budget(request_json, budget)?;
+    budget_response_json(run_match_with_budget(request_json, budget)?)
+}
 
+fn budget_response_json(run: BudgetRunResult) -> Result<String, String> {
     // Return appropriate response based on budget status
     if run.budget_exceeded {
         let determinism = DeterminismMeta {
@@ -233,6 +399,8 @@ pub fn simulate_match_json_budget(
             simulated_until_tick: run.result.statistics.total_ticks,
             cut_reason: Some(run.overflow_reason.clone()),
             hash_algo: HashAlgorithm::FxHash, // FIX_2601/0123
+            seed: run.result.determinism.seed,
+            float_math: run.result.determinism.float_math,
         };
         let overflow_response = BudgetOverflowResponse {
             partial: true,
@@ -261,6 +429,8 @@ pub fn simulate_match_json_budget(
                 simulated_until_tick: run.result.statistics.total_ticks,
                 cut_reason: None,
                 hash_algo: HashAlgorithm::FxHash, // FIX_2601/0123
+                seed: run.result.determinism.seed,
+                float_math: run.result.determinism.float_math,
             },
             score_home: run.result.score_home,
             score_away: run.result.score_away,
@@ -304,6 +474,8 @@ pub fn simulate_match_json_budget_stats_only(
             simulated_until_tick: run.result.statistics.total_ticks,
             cut_reason: if run.budget_exceeded { Some(run.overflow_reason.clone()) } else { None },
             hash_algo: HashAlgorithm::FxHash, // FIX_2601/0123
+            seed: run.result.determinism.seed,
+            float_math: run.result.determinism.float_math,
         },
         score_home: run.result.score_home,
         score_away: run.result.score_away,
@@ -406,6 +578,8 @@ fn convert_team_internal(
                 equipped_skills: Vec::new(),
                 traits: Default::default(),
                 personality: Default::default(),
+                morale: crate::player::morale::NEUTRAL_MORALE,
+                form: crate::player::morale::NEUTRAL_FORM,
             })
         })
         .collect();
@@ -0,0 +1,148 @@
+// Medical JSON API Layer
+// Weekly injury recovery for the stored squad
+
+use crate::player::medical::{self, RecoveryTraining, WeeklyRecovery};
+use crate::player::CorePlayer;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+// ========== Request/Response Structures ==========
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct WeekPassedRequest {
+    /// Players to advance (None = whole squad)
+    #[serde(default)]
+    pub player_ids: Option<Vec<String>>,
+    /// Recovery training to set before the week is applied
+    #[serde(default)]
+    pub programs: HashMap<String, RecoveryTraining>,
+    /// Career day after the week has passed; fills `injured_until`
+    #[serde(default)]
+    pub today: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WeekPassedEntry {
+    #[serde(flatten)]
+    pub recovery: WeeklyRecovery,
+    /// Career day the player is available again (roster `injured_until`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub injured_until: Option<u32>,
+}
+
+// ========== API Functions ==========
+
+/// Advance one week of recovery for the stored squad; returns `WeekPassedEntry[]` JSON
+pub fn apply_week_passed_json(request_json: &str) -> Result<String, String> {
+    let request: WeekPassedRequest =
+        serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
+
+    let entries = apply_week_passed(&mut crate::state::get_state_mut().players, &request)?;
+
+    serde_json::to_string(&entries)
+        .map_err(|e| format!("Failed to serialize recovery report: {}", e))
+}
+
+fn apply_week_passed(
+    players: &mut [CorePlayer],
+    request: &WeekPassedRequest,
+) -> Result<Vec<WeekPassedEntry>, String> {
+    let known = |id: &String| players.iter().any(|p| &p.id == id);
+    if let Some(id) =
+        request.player_ids.iter().flatten().chain(request.programs.keys()).find(|id| !known(id))
+    {
+        return Err(format!("Player {} not found", id));
+    }
+
+    let entries = players
+        .iter_mut()
+        .filter(|p| request.player_ids.as_ref().is_none_or(|ids| ids.contains(&p.id)))
+        .map(|player| {
+            if let Some(program) = request.programs.get(&player.id) {
+                player.medical.recovery_training = *program;
+            }
+            let recovery = player.advance_medical_week();
+            let injured_until = request
+                .today
+                .and_then(|today| medical::injured_until(player.current_injury.as_ref(), today));
+            WeekPassedEntry { recovery, injured_until }
+        })
+        .collect();
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::player::Position;
+    use crate::training::{Injury, InjurySeverity, InjuryType};
+
+    #[test]
+    fn test_apply_week_passed() {
+        let mut fit = CorePlayer::create_average_player("Fit".to_string(), Position::ST, 1);
+        fit.id = "fit".to_string();
+        let mut hurt = CorePlayer::create_average_player("Hurt".to_string(), Position::CB, 2);
+        hurt.id = "hurt".to_string();
+        hurt.set_current_injury(Injury {
+            injury_type: InjuryType::Ligament,
+            severity: InjurySeverity::Serious,
+            affected_attributes: vec![],
+            recovery_days_total: 20,
+            recovery_days_remaining: 20,
+            occurred_date: "2026-03-01".to_string(),
+        });
+        let mut players = vec![fit, hurt];
+
+        let request: WeekPassedRequest =
+            serde_json::from_str(r#"{"programs":{"hurt":"physio"},"today":50}"#).unwrap();
+        let entries = apply_week_passed(&mut players, &request).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].injured_until, None);
+        assert_eq!(entries[1].recovery.weeks_out_after, 2);
+        assert_eq!(entries[1].injured_until, Some(50 + 11));
+
+        let unknown =
+            WeekPassedRequest { player_ids: Some(vec!["ghost".to_string()]), ..Default::default() };
+        assert!(apply_week_passed(&mut players, &unknown).is_err());
+    }
+
+    #[test]
+    fn test_match_request_rejects_injured_selection() {
+        use crate::api::json_api::match_plan_from_match_request_v2_json;
+        use serde_json::json;
+
+        let positions = [
+            "GK", "LB", "CB", "CB", "RB", "LM", "CM", "CM", "RM", "ST", "ST", "GK", "CB", "CM",
+            "CM", "LW", "RW", "ST",
+        ];
+        let team = |prefix: &str, injured_until: Option<u32>| {
+            let roster: Vec<_> = positions
+                .iter()
+                .enumerate()
+                .map(|(i, pos)| {
+                    let mut entry = json!({"name": format!("{prefix}{i}"), "position": pos, "overall": 70, "condition": 3});
+                    if i == 9 {
+                        entry["injured_until"] = json!(injured_until);
+                    }
+                    entry
+                })
+                .collect();
+            json!({"name": prefix, "formation": "4-4-2", "roster": roster})
+        };
+        let request = |match_day: Option<u32>| {
+            json!({
+                "schema_version": 2,
+                "seed": 1,
+                "home_team": team("H", Some(20)),
+                "away_team": team("A", None),
+                "match_day": match_day
+            })
+            .to_string()
+        };
+
+        let err = match_plan_from_match_request_v2_json(&request(Some(14))).unwrap_err();
+        assert!(err.starts_with("PLAYER_INJURED"), "{err}");
+        assert!(match_plan_from_match_request_v2_json(&request(None)).is_err());
+        assert!(match_plan_from_match_request_v2_json(&request(Some(20))).is_ok());
+    }
+}
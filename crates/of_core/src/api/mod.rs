@@ -1,29 +1,119 @@
+pub mod aging_json;
+pub mod attestation;
+pub mod audio_cues_json;
+pub mod batch_json;
 pub mod budget;
+pub mod career_json;
+pub mod challenge;
+pub mod chemistry_json;
 pub mod coach_json;
+pub mod commentary_json;
+pub mod daily_challenge;
+pub mod formation_json;
 pub mod json_api;
 pub mod json_api_budget;
+pub mod medical_json;
+pub mod msgpack_api;
+pub mod pass_network_json;
 pub mod player_json;
+pub mod prediction_json;
+pub mod replay_text_json;
+pub mod roster_validation;
+pub mod rl_env_json;
+pub mod schema;
+pub mod season_json;
+pub mod squad_import;
+pub mod stadium_json;
 pub mod story_json;
+pub mod substitution_json;
+pub mod tournament_json;
 pub mod training_json;
+pub mod what_if_json;
+pub mod youth_tournament_json;
 
 mod exp_config_env;
 
 #[cfg(test)]
 mod budget_test;
 
+pub use aging_json::{advance_season_json, AdvanceSeasonRequest, AdvanceSeasonResponse};
+pub use attestation::{
+    attest_result, simulate_match_v2_attested_json, verify_attestation,
+    verify_result_attestation_json, AttestationVerdict, ResultAttestation,
+};
+pub use audio_cues_json::{build_audio_cues_json, AudioCueTrackRequest};
+pub use batch_json::{
+    simulate_matches_parallel, simulate_matches_parallel_json, BatchAggregate, BatchMatchOutcome,
+    BatchTiming, ParallelBatchResponse,
+};
 pub use budget::SimBudget;
+pub use career_json::{
+    advise_rotation_json, compile_career_highlights_json, get_congestion_report_json,
+    get_ladder_leaderboard_json, record_fixture_lineup_json, record_ladder_match_json,
+    record_match_highlights_json, schedule_fixtures_json, CareerHighlightsRequest,
+    LeaderboardRequest, RecordMatchHighlightsRequest, RotationAdviceRequest,
+};
+pub use challenge::{
+    pack_challenge_json, simulate_challenge_json, verify_challenge_result_json, ChallengePayload,
+    ChallengeSide,
+};
+pub use chemistry_json::{
+    calculate_squad_chemistry_json, SquadChemistryRequest, SquadChemistryResponse,
+};
 pub use coach_json::{
     gacha_draw_10x_json, gacha_draw_single_json, get_card_inventory_json,
     get_gacha_statistics_json, load_deck_json, merge_cards_json, save_deck_json,
 };
+pub use commentary_json::generate_commentary_json;
+pub use daily_challenge::{
+    generate_daily_challenge, get_daily_challenge_json, play_daily_challenge_json,
+    verify_daily_result_json, DailyChallenge, DailyChallengeEntry, DailyChallengeResult,
+};
+pub use formation_json::{
+    get_formation_waypoints_json, get_tactical_heat_overlays_json, FormationWaypointsRequest,
+    TacticalHeatRequest,
+};
 pub use json_api::{
-    match_plan_from_match_request_v2_json, simulate_match_json, simulate_match_json_with_replay,
-    simulate_match_v2_json, simulate_match_v2_json_with_replay, MatchRequest, MatchRequestV2,
-    MatchResponse,
+    match_plan_from_match_request_v2_json, match_plan_from_match_request_v3_json,
+    resolve_uid_player, simulate_match_json, simulate_match_json_cancellable,
+    simulate_match_json_with_replay, simulate_match_v2_json, simulate_match_v2_json_with_replay,
+    simulate_match_v3_json, simulate_match_v3_json_with_replay, simulate_with_parameters_json,
+    EmbeddedPlayerAttributes, MatchRequest, MatchRequestV2, MatchRequestV3, MatchResponse,
+    RosterEntryV3,
 };
 pub use json_api_budget::{
-    simulate_match_json_budget, simulate_match_json_budget_stats_only, BudgetOverflowResponse,
-    StatsOnlyResponse,
+    resume_match_json_budget, simulate_match_json_budget, simulate_match_json_budget_stats_only,
+    simulate_match_json_budget_with_progress, BudgetOverflowResponse, BudgetProgress,
+    BudgetResumeToken, BudgetedOutcome, StatsOnlyResponse,
+};
+pub use medical_json::{apply_week_passed_json, WeekPassedEntry, WeekPassedRequest};
+pub use msgpack_api::{
+    msgpack_request_schema_version, simulate_match_msgpack, simulate_match_msgpack_with_replay,
+    simulate_match_v2_msgpack, simulate_match_v2_msgpack_with_replay,
+    simulate_matches_parallel_msgpack,
 };
+pub use pass_network_json::{get_pass_network_json, PassNetworkRequest};
 pub use player_json::*;
+pub use prediction_json::predict_match_json;
+pub use replay_text_json::{embed_replay_text_json, EmbedReplayTextRequest};
+pub use roster_validation::{
+    validate_roster, validate_roster_json, IssueSeverity, RosterIssue, RosterValidationReport,
+    SlotAssignment, SlotFit,
+};
+pub use schema::{
+    get_request_schema, validate_request, validate_request_json, FieldError, RequestKind,
+    ValidationReport,
+};
+pub use season_json::{simulate_matchday_json, SimulateMatchdayRequest, SimulateMatchdayResponse};
+pub use squad_import::{
+    import_squad, import_squad_json, map_loose_position, SquadAdjustment, SquadImportRequest,
+    SquadImportResult,
+};
+pub use stadium_json::{get_stadium_json, register_stadium_json, RegisterStadiumRequest};
+pub use substitution_json::{plan_substitutions_json, PlanSubstitutionsRequest};
+pub use tournament_json::{simulate_tournament_json, SimulateTournamentRequest};
 pub use training_json::{execute_training_json, TrainingRequest, TrainingResponse};
+pub use what_if_json::{simulate_what_if_json, WhatIfRequest, WhatIfResponse};
+pub use youth_tournament_json::{
+    generate_youth_bracket_json, generate_youth_opponent_json, YouthBracketRequest,
+};
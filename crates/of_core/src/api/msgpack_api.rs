@@ -0,0 +1,214 @@
+// MessagePack API Layer
+// Same requests and results as json_api / batch_json, encoded with rmp_serde instead of JSON.
+// Structs are written as maps (`to_vec_named`), so field names and optional-field rules match
+// the JSON schema exactly; only the wire encoding differs.
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use super::batch_json::simulate_matches_parallel;
+use super::json_api::{
+    simulate_match_request, simulate_match_request_with_replay, simulate_match_v2,
+    simulate_match_v2_with_replay, MatchRequest, MatchRequestV2,
+};
+use crate::engine::CancellationToken;
+
+fn decode_request<T: DeserializeOwned>(request: &[u8]) -> Result<T, String> {
+    rmp_serde::from_slice(request).map_err(|e| format!("Invalid MessagePack request: {}", e))
+}
+
+fn encode<T: Serialize + ?Sized>(value: &T, what: &str) -> Result<Vec<u8>, String> {
+    rmp_serde::to_vec_named(value).map_err(|e| format!("Failed to serialize {}: {}", what, e))
+}
+
+/// Only the version field, to route a request to the v1 or v2 entrypoint
+#[derive(Deserialize)]
+struct SchemaProbe {
+    schema_version: u8,
+}
+
+/// `schema_version` of a MessagePack request (1 = `MatchRequest`, 2 = `MatchRequestV2`)
+pub fn msgpack_request_schema_version(request: &[u8]) -> Result<u8, String> {
+    decode_request::<SchemaProbe>(request).map(|probe| probe.schema_version)
+}
+
+/// MessagePack `MatchRequest` (v1) → MessagePack `MatchResult`
+pub fn simulate_match_msgpack(request: &[u8]) -> Result<Vec<u8>, String> {
+    let request: MatchRequest = decode_request(request)?;
+    let result = simulate_match_request(request, &CancellationToken::new())?;
+    encode(&result, "result")
+}
+
+/// MessagePack `MatchRequestV2` → MessagePack `MatchResult`
+pub fn simulate_match_v2_msgpack(request: &[u8]) -> Result<Vec<u8>, String> {
+    let request: MatchRequestV2 = decode_request(request)?;
+    let result = simulate_match_v2(request)?;
+    encode(&result, "result")
+}
+
+/// v1 with replay recording → (result, replay doc); a missing replay is MessagePack `nil`
+pub fn simulate_match_msgpack_with_replay(request: &[u8]) -> Result<(Vec<u8>, Vec<u8>), String> {
+    let request: MatchRequest = decode_request(request)?;
+    let (result, replay_doc) = simulate_match_request_with_replay(request)?;
+    Ok((encode(&result, "result")?, encode(&replay_doc, "replay")?))
+}
+
+/// v2 with replay recording → (result, replay doc); a missing replay is MessagePack `nil`
+pub fn simulate_match_v2_msgpack_with_replay(request: &[u8]) -> Result<(Vec<u8>, Vec<u8>), String> {
+    let request: MatchRequestV2 = decode_request(request)?;
+    let (result, replay_doc) = simulate_match_v2_with_replay(request)?;
+    Ok((encode(&result, "result")?, encode(&replay_doc, "replay")?))
+}
+
+/// MessagePack array of `MatchRequestV2` → MessagePack `ParallelBatchResponse`
+///
+/// Same semantics as `simulate_matches_parallel_json` (`thread_count == 0` = one per CPU,
+/// per-request failures reported in their own slot).
+pub fn simulate_matches_parallel_msgpack(
+    requests: &[u8],
+    thread_count: usize,
+) -> Result<Vec<u8>, String> {
+    let requests: Vec<serde_json::Value> = decode_request(requests)?;
+    let response = simulate_matches_parallel(requests, thread_count)?;
+    encode(&response, "response")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::json_api::{simulate_match_json, simulate_match_v2_json};
+    use crate::models::MatchResult;
+    use crate::replay::ReplayDoc;
+    use serde_json::json;
+
+    fn roster(prefix: &str) -> Vec<serde_json::Value> {
+        let positions = [
+            "GK", "LB", "CB", "CB", "RB", "LM", "CM", "CM", "RM", "ST", "ST", "GK", "CB", "CM",
+            "CM", "LW", "RW", "ST",
+        ];
+        positions
+            .iter()
+            .enumerate()
+            .map(|(i, pos)| {
+                json!({"name": format!("{prefix}{i}"), "position": pos, "overall": 70, "condition": 3})
+            })
+            .collect()
+    }
+
+    fn v1_request(seed: u64) -> serde_json::Value {
+        let team =
+            |name: &str| json!({"name": name, "formation": "4-4-2", "players": roster(name)});
+        json!({"schema_version": 1, "seed": seed, "home_team": team("Home"), "away_team": team("Away")})
+    }
+
+    fn v2_request(seed: u64) -> serde_json::Value {
+        let team = |name: &str| json!({"name": name, "formation": "4-4-2", "roster": roster(name)});
+        json!({"schema_version": 2, "seed": seed, "home_team": team("Home"), "away_team": team("Away")})
+    }
+
+    /// Same keys, same values; floats may differ in the last ulp (JSON text parsing
+    /// is not exactly round-trip, MessagePack floats are)
+    fn assert_same_value(a: &serde_json::Value, b: &serde_json::Value, path: &str) {
+        use serde_json::Value;
+        match (a, b) {
+            (Value::Object(x), Value::Object(y)) => {
+                assert_eq!(x.len(), y.len(), "{path}: different keys");
+                for (key, value) in x {
+                    let other = y.get(key).unwrap_or_else(|| panic!("{path}.{key} missing"));
+                    assert_same_value(value, other, &format!("{path}.{key}"));
+                }
+            }
+            (Value::Array(x), Value::Array(y)) => {
+                assert_eq!(x.len(), y.len(), "{path}: different lengths");
+                for (i, (v, w)) in x.iter().zip(y).enumerate() {
+                    assert_same_value(v, w, &format!("{path}[{i}]"));
+                }
+            }
+            (Value::Number(x), Value::Number(y)) if x.is_f64() || y.is_f64() => {
+                let (x, y) = (x.as_f64().unwrap(), y.as_f64().unwrap());
+                assert!((x - y).abs() <= 1e-9 * x.abs().max(1.0), "{path}: {x} vs {y}");
+            }
+            _ => assert_eq!(a, b, "{path}"),
+        }
+    }
+
+    /// Both encodings must decode to the same `MatchResult`
+    /// (compared as `Value`, so HashMap order does not matter)
+    fn assert_json_parity(msgpack_result: &[u8], json_result: &str) {
+        let from_msgpack: MatchResult = rmp_serde::from_slice(msgpack_result).unwrap();
+        let from_json: MatchResult = serde_json::from_str(json_result).unwrap();
+        assert_same_value(
+            &serde_json::to_value(&from_msgpack).unwrap(),
+            &serde_json::to_value(&from_json).unwrap(),
+            "result",
+        );
+    }
+
+    #[test]
+    fn test_msgpack_results_match_json_schema() {
+        let v1 = v1_request(11);
+        let v1_bytes = rmp_serde::to_vec_named(&v1).unwrap();
+        assert_eq!(msgpack_request_schema_version(&v1_bytes), Ok(1));
+        assert_json_parity(
+            &simulate_match_msgpack(&v1_bytes).unwrap(),
+            &simulate_match_json(&v1.to_string()).unwrap(),
+        );
+
+        let v2 = v2_request(11);
+        let v2_bytes = rmp_serde::to_vec_named(&v2).unwrap();
+        assert_eq!(msgpack_request_schema_version(&v2_bytes), Ok(2));
+        assert_json_parity(
+            &simulate_match_v2_msgpack(&v2_bytes).unwrap(),
+            &simulate_match_v2_json(&v2.to_string()).unwrap(),
+        );
+
+        let (result, replay) = simulate_match_v2_msgpack_with_replay(&v2_bytes).unwrap();
+        let (result_json, replay_json) =
+            crate::api::simulate_match_v2_json_with_replay(&v2.to_string()).unwrap();
+        assert_json_parity(&result, &result_json);
+        let replay: Option<ReplayDoc> = rmp_serde::from_slice(&replay).unwrap();
+        let replay_from_json: Option<ReplayDoc> = serde_json::from_str(&replay_json).unwrap();
+        assert!(replay.is_some());
+        assert_same_value(
+            &serde_json::to_value(&replay).unwrap(),
+            &serde_json::to_value(&replay_from_json).unwrap(),
+            "replay",
+        );
+
+        assert!(simulate_match_v2_msgpack(&v1_bytes).is_err());
+        assert!(simulate_match_msgpack(b"\xc1")
+            .unwrap_err()
+            .starts_with("Invalid MessagePack request"));
+    }
+
+    // Result maps keep integer keys in MessagePack, so probe the response with typed structs.
+    #[derive(Deserialize)]
+    struct BatchProbe {
+        results: Vec<OutcomeProbe>,
+        aggregate: AggregateProbe,
+    }
+
+    #[derive(Deserialize)]
+    struct OutcomeProbe {
+        result: Option<serde::de::IgnoredAny>,
+        error: Option<String>,
+    }
+
+    #[derive(Deserialize)]
+    struct AggregateProbe {
+        failed: usize,
+    }
+
+    #[test]
+    fn test_msgpack_batch_keeps_per_request_errors() {
+        let requests = json!([v2_request(1), {"schema_version": 2}]);
+        let bytes =
+            simulate_matches_parallel_msgpack(&rmp_serde::to_vec_named(&requests).unwrap(), 2)
+                .unwrap();
+        let response: BatchProbe = rmp_serde::from_slice(&bytes).unwrap();
+        assert_eq!(response.results.len(), 2);
+        assert!(response.results[0].result.is_some());
+        assert!(response.results[1].error.is_some());
+        assert_eq!(response.aggregate.failed, 1);
+    }
+}
@@ -0,0 +1,25 @@
+// Pass Network JSON API Layer
+// Per-team pass adjacency, shot-ending possession chains and centrality for the tactics screen
+
+use crate::analysis::pass_network::compute_pass_network;
+use crate::models::MatchEvent;
+use serde::{Deserialize, Serialize};
+
+// ========== Request/Response Structures ==========
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PassNetworkRequest {
+    /// Match events so far (a finished match or a live session's log)
+    pub events: Vec<MatchEvent>,
+}
+
+// ========== API Functions ==========
+
+/// Build the pass network; returns `MatchPassNetwork` JSON
+pub fn get_pass_network_json(request_json: &str) -> Result<String, String> {
+    let request: PassNetworkRequest =
+        serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
+
+    let network = compute_pass_network(&request.events);
+    serde_json::to_string(&network).map_err(|e| format!("Failed to serialize pass network: {}", e))
+}
@@ -3,10 +3,13 @@
 //! This module provides JSON-based API endpoints for Godot integration,
 //! supporting player creation, updates, retrieval, and batch operations.
 
+use crate::data::name_pools::NameGenerator;
 use crate::models::player::{PlayerAttributes, Position};
+use crate::player::morale::{form_attribute_mult, morale_attribute_mult};
 use crate::player::{
     AttributeChange, AttributeGrowth, CACalculator, CorePlayer, GrowthCalculator, GrowthProfile,
-    HexagonStats, MonthlyGrowth, PersonAttributes, PlayerValidator, TrainingType, ValidationError,
+    HexagonCalculator, HexagonRadar, HexagonStats, MatchMoraleInput, MonthlyGrowth, MoraleChange,
+    PersonAttributes, PlayerValidator, TrainingType, ValidationError,
 };
 use crate::special_ability::{
     AbilityActivationContext, AbilityTier, ProcessingResult, SpecialAbility, SpecialAbilityType,
@@ -43,7 +46,12 @@ pub struct ApiError {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerCreationRequest {
     pub schema_version: Option<String>,
+    /// Empty = newgen name from the seeded name pools
+    #[serde(default)]
     pub name: String,
+    /// Name pool for a generated name (None = nationality-weighted)
+    #[serde(default)]
+    pub nationality: Option<String>,
     pub position: Position,
     pub age_months: f32,
     pub ca: Option<u8>,
@@ -352,9 +360,11 @@ impl PlayerCreationRequest {
     pub fn validate(&self) -> Result<(), ApiError> {
         // PlayerValidator has static methods only
 
-        // Validate name
-        if let Err(e) = PlayerValidator::validate_name(&self.name) {
-            return Err(ApiError::from_validation_error(e));
+        // Validate name (empty = generated later)
+        if !self.name.is_empty() {
+            if let Err(e) = PlayerValidator::validate_name(&self.name) {
+                return Err(ApiError::from_validation_error(e));
+            }
         }
 
         // Validate age
@@ -698,9 +708,23 @@ fn create_player_from_request(
     // Generate personality based on seed
     let personality = PersonAttributes::generate_random(seed_used + 1000);
 
+    // Newgen name when none was given
+    let name = if request.name.is_empty() {
+        let generator = NameGenerator::builtin();
+        match &request.nationality {
+            Some(code) => generator
+                .generate_with_nationality(code, seed_used, 0)
+                .map_err(|e| ApiError::new("INVALID_NATIONALITY", &e))?,
+            None => generator.generate(seed_used, 0),
+        }
+        .full_name
+    } else {
+        request.name.clone()
+    };
+
     // Create the player
     let player = CorePlayer::new(
-        request.name.clone(),
+        name,
         request.position,
         request.age_months,
         ca,
@@ -2385,6 +2409,210 @@ fn generate_comparison_summary(players: &[CorePlayer]) -> ComparisonSummary {
     }
 }
 
+// 📊 Hexagon Radar Export
+
+/// Hexagon radar export request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HexagonRadarRequest {
+    pub schema_version: Option<String>,
+    pub player_id: String,
+    /// Reference population (e.g. league roster). None = whole registry
+    #[serde(default)]
+    pub reference_player_ids: Option<Vec<String>>,
+    /// Compare only against the same generic position (GK/DF/MF/FW)
+    #[serde(default)]
+    pub same_position_only: Option<bool>,
+}
+
+/// Hexagon radar export response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HexagonRadarResponse {
+    pub player_id: String,
+    pub player_name: String,
+    pub position: Position,
+    pub radar: HexagonRadar,
+}
+
+/// Export ready-to-plot hexagon radar data with percentile baselines
+///
+/// # Arguments
+/// * `request_json` - JSON string containing HexagonRadarRequest
+/// * `players` - Reference to HashMap storing players by ID (registry)
+///
+/// # Returns
+/// JSON string containing ApiResponse<HexagonRadarResponse>
+pub fn export_hexagon_radar_json(
+    request_json: &str,
+    players: &HashMap<String, CorePlayer>,
+) -> String {
+    let request: HexagonRadarRequest = match serde_json::from_str(request_json) {
+        Ok(req) => req,
+        Err(e) => {
+            error!("Failed to parse HexagonRadarRequest: {}", e);
+            let error = ApiError::new("INVALID_JSON", &format!("Invalid JSON format: {}", e));
+            let response: ApiResponse<HexagonRadarResponse> = ApiResponse::error(error);
+            return serde_json::to_string(&response).unwrap_or_else(|_| "{}".to_string());
+        }
+    };
+
+    let Some(player) = players.get(&request.player_id) else {
+        let error = ApiError::new(
+            "PLAYER_NOT_FOUND",
+            &format!("Player with ID {} not found", request.player_id),
+        );
+        let response: ApiResponse<HexagonRadarResponse> = ApiResponse::error(error);
+        return serde_json::to_string(&response).unwrap_or_else(|_| "{}".to_string());
+    };
+
+    let mut population: Vec<&CorePlayer> = match &request.reference_player_ids {
+        Some(ids) => ids.iter().filter_map(|id| players.get(id)).collect(),
+        None => players.values().collect(),
+    };
+    if request.same_position_only.unwrap_or(false) {
+        let group = player.position.to_generic_position();
+        population.retain(|p| p.position.to_generic_position() == group);
+    }
+
+    let baseline = HexagonCalculator::build_baseline(population.iter().map(|p| &p.hexagon_stats));
+    let data = HexagonRadarResponse {
+        player_id: player.id.clone(),
+        player_name: player.name.clone(),
+        position: player.position,
+        radar: HexagonCalculator::export_radar(&player.hexagon_stats, &baseline),
+    };
+    serde_json::to_string(&ApiResponse::success(data)).unwrap_or_else(|_| "{}".to_string())
+}
+
+/// Player morale/form query request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerMoraleQuery {
+    pub schema_version: Option<String>,
+    pub player_id: String,
+}
+
+/// Player morale/form update request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerMoraleUpdateRequest {
+    pub schema_version: Option<String>,
+    pub player_id: String,
+    pub action: PlayerMoraleAction,
+}
+
+/// Player morale/form updates
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum PlayerMoraleAction {
+    /// Post-match update (result, minutes, rating, quest outcomes)
+    RecordMatch(MatchMoraleInput),
+    /// Story/event swing, clamped to 0..=100
+    AdjustMorale { delta: i16 },
+    /// Overwrite values (debug / save repair)
+    Set { morale: Option<u8>, form: Option<u8> },
+}
+
+/// Player morale/form response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerMoraleResponse {
+    pub player_id: String,
+    pub morale: u8,
+    pub form: u8,
+    /// Engine multiplier on technical attributes (from form)
+    pub technical_mult: f32,
+    /// Engine multiplier on mental attributes (from morale)
+    pub mental_mult: f32,
+    /// Before/after values (update only)
+    pub change: Option<MoraleChange>,
+}
+
+impl PlayerMoraleResponse {
+    fn new(player: &CorePlayer, change: Option<MoraleChange>) -> Self {
+        Self {
+            player_id: player.id.clone(),
+            morale: player.morale,
+            form: player.form,
+            technical_mult: form_attribute_mult(player.form),
+            mental_mult: morale_attribute_mult(player.morale),
+            change,
+        }
+    }
+}
+
+/// Query a player's morale and form (JSON API)
+pub fn get_player_morale_json(request_json: &str, players: &HashMap<String, CorePlayer>) -> String {
+    let request: PlayerMoraleQuery = match serde_json::from_str(request_json) {
+        Ok(req) => req,
+        Err(e) => {
+            let error = ApiError::new("INVALID_JSON", &format!("Invalid JSON format: {}", e));
+            let response: ApiResponse<PlayerMoraleResponse> = ApiResponse::error(error);
+            return serde_json::to_string(&response).unwrap_or_else(|_| "{}".to_string());
+        }
+    };
+
+    let Some(player) = players.get(&request.player_id) else {
+        let error = ApiError::new(
+            "PLAYER_NOT_FOUND",
+            &format!("Player with ID {} not found", request.player_id),
+        );
+        let response: ApiResponse<PlayerMoraleResponse> = ApiResponse::error(error);
+        return serde_json::to_string(&response).unwrap_or_else(|_| "{}".to_string());
+    };
+
+    let data = PlayerMoraleResponse::new(player, None);
+    serde_json::to_string(&ApiResponse::success(data)).unwrap_or_else(|_| "{}".to_string())
+}
+
+/// Update a player's morale and form (JSON API)
+pub fn update_player_morale_json(
+    request_json: &str,
+    players: &mut HashMap<String, CorePlayer>,
+) -> String {
+    let request: PlayerMoraleUpdateRequest = match serde_json::from_str(request_json) {
+        Ok(req) => req,
+        Err(e) => {
+            let error = ApiError::new("INVALID_JSON", &format!("Invalid JSON format: {}", e));
+            let response: ApiResponse<PlayerMoraleResponse> = ApiResponse::error(error);
+            return serde_json::to_string(&response).unwrap_or_else(|_| "{}".to_string());
+        }
+    };
+
+    let Some(player) = players.get_mut(&request.player_id) else {
+        let error = ApiError::new(
+            "PLAYER_NOT_FOUND",
+            &format!("Player with ID {} not found", request.player_id),
+        );
+        let response: ApiResponse<PlayerMoraleResponse> = ApiResponse::error(error);
+        return serde_json::to_string(&response).unwrap_or_else(|_| "{}".to_string());
+    };
+
+    let (morale_before, form_before) = (player.morale, player.form);
+    let change = match request.action {
+        PlayerMoraleAction::RecordMatch(input) => player.record_match_morale(&input),
+        PlayerMoraleAction::AdjustMorale { delta } => {
+            player.morale = (player.morale as i16).saturating_add(delta).clamp(0, 100) as u8;
+            player.touch();
+            MoraleChange {
+                morale_before,
+                morale_after: player.morale,
+                form_before,
+                form_after: player.form,
+            }
+        }
+        PlayerMoraleAction::Set { morale, form } => {
+            player.morale = morale.map_or(player.morale, |m| m.min(100));
+            player.form = form.map_or(player.form, |f| f.min(100));
+            player.touch();
+            MoraleChange {
+                morale_before,
+                morale_after: player.morale,
+                form_before,
+                form_after: player.form,
+            }
+        }
+    };
+
+    let data = PlayerMoraleResponse::new(player, Some(change));
+    serde_json::to_string(&ApiResponse::success(data)).unwrap_or_else(|_| "{}".to_string())
+}
+
 // 🌟 Special Ability API Functions
 
 /// Special ability management request
@@ -2773,6 +3001,23 @@ mod tests {
         assert_eq!(created_player.pa, 140);
     }
 
+    #[test]
+    fn test_create_player_json_generates_newgen_name() {
+        let request = serde_json::json!({
+            "position": "MF",
+            "age_months": 16.0,
+            "seed": 77,
+            "nationality": "BRA"
+        })
+        .to_string();
+        let result: ApiResponse<PlayerCreationResponse> =
+            serde_json::from_str(&create_player_json(&request)).unwrap();
+        let player = result.data.unwrap().player;
+        let expected =
+            NameGenerator::builtin().generate_with_nationality("BRA", 77, 0).unwrap().full_name;
+        assert_eq!(player.name, expected);
+    }
+
     #[test]
     fn test_update_player_json_workflow() {
         // Create a player first
@@ -2805,6 +3050,80 @@ mod tests {
         assert_eq!(update_result.data.unwrap().attribute_changes.len(), 2);
     }
 
+    #[test]
+    fn test_export_hexagon_radar_json() {
+        let mut players = HashMap::new();
+        let mut target_id = String::new();
+        for (i, position) in [Position::FW, Position::FW, Position::DF].into_iter().enumerate() {
+            let request = create_test_player_request_json("Radar", position, Some(100 + i as u64));
+            let result: ApiResponse<PlayerCreationResponse> =
+                serde_json::from_str(&create_player_json(&request)).unwrap();
+            let player = result.data.unwrap().player;
+            if i == 0 {
+                target_id = player.id.clone();
+            }
+            players.insert(player.id.clone(), player);
+        }
+
+        let request = serde_json::json!({
+            "player_id": target_id,
+            "same_position_only": true
+        })
+        .to_string();
+        let result: ApiResponse<HexagonRadarResponse> =
+            serde_json::from_str(&export_hexagon_radar_json(&request, &players)).unwrap();
+        assert!(result.success);
+        let radar = result.data.unwrap().radar;
+        assert_eq!(radar.population_size, 2);
+        assert_eq!(radar.axes.len(), 6);
+        assert!(radar.axes.iter().all(|a| (0.0..=100.0).contains(&a.percentile)));
+
+        let missing = serde_json::json!({ "player_id": "nope" }).to_string();
+        let result: ApiResponse<HexagonRadarResponse> =
+            serde_json::from_str(&export_hexagon_radar_json(&missing, &players)).unwrap();
+        assert!(!result.success);
+    }
+
+    #[test]
+    fn test_player_morale_json() {
+        let request = create_test_player_request_json("Morale", Position::FW, Some(7));
+        let result: ApiResponse<PlayerCreationResponse> =
+            serde_json::from_str(&create_player_json(&request)).unwrap();
+        let player = result.data.unwrap().player;
+        let player_id = player.id.clone();
+        let mut players = HashMap::from([(player_id.clone(), player)]);
+
+        let query = serde_json::json!({ "player_id": player_id }).to_string();
+        let result: ApiResponse<PlayerMoraleResponse> =
+            serde_json::from_str(&get_player_morale_json(&query, &players)).unwrap();
+        let data = result.data.unwrap();
+        assert_eq!((data.morale, data.form), (50, 50));
+        assert_eq!(data.technical_mult, 1.0);
+
+        let update = serde_json::json!({
+            "player_id": player_id,
+            "action": { "RecordMatch": {
+                "outcome": "win", "minutes_played": 90, "rating": 8.5, "quests_completed": 1
+            } }
+        })
+        .to_string();
+        let result: ApiResponse<PlayerMoraleResponse> =
+            serde_json::from_str(&update_player_morale_json(&update, &mut players)).unwrap();
+        let data = result.data.unwrap();
+        assert_eq!(data.morale, 50 + 5 + 2 + 3 + 4);
+        assert!(data.form > 50 && data.technical_mult > 1.0);
+        assert_eq!(players[&player_id].morale, data.morale);
+
+        let adjust = serde_json::json!({
+            "player_id": player_id,
+            "action": { "AdjustMorale": { "delta": -200 } }
+        })
+        .to_string();
+        let result: ApiResponse<PlayerMoraleResponse> =
+            serde_json::from_str(&update_player_morale_json(&adjust, &mut players)).unwrap();
+        assert_eq!(result.data.unwrap().morale, 0);
+    }
+
     #[test]
     fn test_get_player_json_workflow() {
         // Create a player first
@@ -2996,6 +3315,7 @@ mod tests {
         let valid_request = PlayerCreationRequest {
             schema_version: Some(API_VERSION.to_string()),
             name: "Test Player".to_string(),
+            nationality: None,
             position: Position::FW,
             age_months: 16.5,
             ca: Some(100),
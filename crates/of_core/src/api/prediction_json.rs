@@ -0,0 +1,78 @@
+// Match Prediction JSON API Layer
+// Pre-match win/draw/loss odds for a MatchRequest v2 (Monte Carlo or Poisson fast path)
+
+use super::json_api::match_plan_from_match_request_v2_json;
+use crate::engine::{predict_by_simulation, predict_statistical};
+
+// ========== API Functions ==========
+
+/// Predict the outcome of a `MatchRequestV2`; returns `MatchPrediction` JSON.
+///
+/// `n_samples` seeded simulations are run (seeds derived from the request seed);
+/// `n_samples == 0` selects the statistical fast path instead.
+pub fn predict_match_json(request_json: &str, n_samples: usize) -> Result<String, String> {
+    let (plan, _enable_position_tracking) = match_plan_from_match_request_v2_json(request_json)?;
+    let prediction = if n_samples == 0 {
+        predict_statistical(&plan)?
+    } else {
+        predict_by_simulation(&plan, n_samples)?
+    };
+    serde_json::to_string(&prediction).map_err(|e| format!("Failed to serialize prediction: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn team_json(prefix: &str, overall: u8) -> serde_json::Value {
+        let positions = [
+            "GK", "LB", "CB", "CB", "RB", "LM", "CM", "CM", "RM", "ST", "ST", "GK", "CB", "CM",
+            "CM", "LW", "RW", "ST",
+        ];
+        let roster: Vec<_> = positions
+            .iter()
+            .enumerate()
+            .map(|(i, pos)| {
+                json!({"name": format!("{prefix}{i}"), "position": pos, "overall": overall, "condition": 3})
+            })
+            .collect();
+        json!({"name": prefix, "formation": "4-4-2", "roster": roster})
+    }
+
+    fn request(home_overall: u8, away_overall: u8) -> String {
+        json!({
+            "schema_version": 2,
+            "seed": 77,
+            "home_team": team_json("Home", home_overall),
+            "away_team": team_json("Away", away_overall),
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn test_zero_samples_uses_statistical_fast_path() {
+        let prediction: serde_json::Value =
+            serde_json::from_str(&predict_match_json(&request(60, 80), 0).unwrap()).unwrap();
+        assert_eq!(prediction["method"], "statistical");
+        assert_eq!(prediction["samples"], 0);
+        assert!(
+            prediction["away_win"].as_f64().unwrap() > prediction["home_win"].as_f64().unwrap()
+        );
+        assert!(prediction["most_likely_scoreline"]["probability"].as_f64().unwrap() > 0.0);
+    }
+
+    #[test]
+    fn test_simulated_prediction_reports_sample_count() {
+        let prediction: serde_json::Value =
+            serde_json::from_str(&predict_match_json(&request(70, 70), 2).unwrap()).unwrap();
+        assert_eq!(prediction["method"], "simulation");
+        assert_eq!(prediction["samples"], 2);
+        assert_eq!(prediction["home_goals_distribution"].as_array().unwrap().len(), 7);
+    }
+
+    #[test]
+    fn test_rejects_invalid_request() {
+        assert!(predict_match_json("{}", 0).is_err());
+    }
+}
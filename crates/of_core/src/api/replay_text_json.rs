@@ -0,0 +1,34 @@
+// Replay Text JSON API Layer
+// Embeds resolved localized key-event strings into a replay doc at save time
+
+use std::collections::HashMap;
+
+use crate::replay::{embed_localized_text, ReplayDoc, ReplaySaveOptions};
+use serde::{Deserialize, Serialize};
+
+// ========== Request/Response Structures ==========
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EmbedReplayTextRequest {
+    pub replay: ReplayDoc,
+    /// Locales to embed (e.g. `["en-US", "ko-KR"]`)
+    pub locales: Vec<String>,
+    /// Optional content-pack FTL per locale (overrides built-in strings)
+    #[serde(default)]
+    pub content_packs: HashMap<String, String>,
+}
+
+// ========== API Functions ==========
+
+/// Embed localized key-event text; returns the updated `ReplayDoc` JSON
+pub fn embed_replay_text_json(request_json: &str) -> Result<String, String> {
+    let request: EmbedReplayTextRequest =
+        serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
+
+    let mut doc = request.replay;
+    let options =
+        ReplaySaveOptions { embed_locales: request.locales, content_packs: request.content_packs };
+    embed_localized_text(&mut doc, &options)?;
+
+    serde_json::to_string(&doc).map_err(|e| format!("Failed to serialize replay: {}", e))
+}
@@ -0,0 +1,360 @@
+//! RL Environment C ABI / JSON API
+//!
+//! 외부 트레이너(Python ctypes 등)용 `engine::rl_env::RlEnv` 바인딩.
+//!
+//! - JSON 모드: `rl_env_create_json` / `rl_env_reset_json` / `rl_env_step_json`
+//! - Raw 모드: `rl_env_reset_raw` / `rl_env_step_raw` (관측값을 f32 버퍼에 직접 기록)
+//!
+//! 환경은 `env_id`로 참조하고 `rl_env_destroy`로 해제한다.
+//! 반환된 문자열은 반드시 `rl_env_free_string`으로 해제한다.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+use serde_json::json;
+
+use super::json_api::{match_plan_from_match_request_v2, MatchRequestV2};
+use super::story_json::{error_response_c, safe_c_str_to_string, safe_string_to_c};
+use crate::engine::rl_env::{RlAction, RlEnv, RlEnvConfig, RlStep};
+
+/// Raw 모드 반환 코드: 진행 중
+pub const RL_ENV_OK: i32 = 0;
+/// Raw 모드 반환 코드: 에피소드 종료 (step)
+pub const RL_ENV_DONE: i32 = 1;
+/// 알 수 없는 env_id
+pub const RL_ENV_ERR_UNKNOWN_ENV: i32 = -1;
+/// 범위를 벗어난 action 인덱스
+pub const RL_ENV_ERR_INVALID_ACTION: i32 = -2;
+/// 관측 버퍼가 null이거나 `observation_space` 크기보다 작음
+pub const RL_ENV_ERR_BUFFER: i32 = -3;
+/// reset/step 실패 (reset 전 step, 종료 후 step 등)
+pub const RL_ENV_ERR_STEP: i32 = -4;
+
+/// 환경 생성 요청
+#[derive(Debug, Deserialize)]
+pub struct RlEnvCreateRequest {
+    pub schema_version: u8,
+    /// MatchRequest v2 (schema_version=2)
+    pub match_request: MatchRequestV2,
+    #[serde(default)]
+    pub config: RlEnvConfig,
+}
+
+/// 환경 레지스트리 (env별 Mutex: 병렬 환경이 서로 막지 않음)
+#[derive(Default)]
+struct RlEnvRegistry {
+    next_id: u64,
+    envs: HashMap<u64, Arc<Mutex<RlEnv>>>,
+}
+
+static RL_ENVS: Lazy<Mutex<RlEnvRegistry>> = Lazy::new(|| Mutex::new(RlEnvRegistry::default()));
+
+fn lookup_env(env_id: u64) -> Option<Arc<Mutex<RlEnv>>> {
+    RL_ENVS.lock().expect("RL_ENVS lock poisoned").envs.get(&env_id).cloned()
+}
+
+fn with_env<T>(env_id: u64, f: impl FnOnce(&mut RlEnv) -> T) -> Result<T, String> {
+    let env = lookup_env(env_id).ok_or_else(|| format!("Unknown env_id {}", env_id))?;
+    let mut env = env.lock().expect("RlEnv lock poisoned");
+    Ok(f(&mut env))
+}
+
+// ============================================================================
+// JSON API (Rust)
+// ============================================================================
+
+/// 환경 생성 → `{env_id, action_space, observation_space}`
+pub fn create_rl_env_json(request_json: &str) -> Result<String, String> {
+    let request: RlEnvCreateRequest =
+        serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
+    if request.schema_version != 1 {
+        return Err(format!("Unsupported schema version: {}", request.schema_version));
+    }
+
+    let (plan, _) = match_plan_from_match_request_v2(request.match_request)?;
+    let env = RlEnv::new(plan, request.config)?;
+    let (action_space, observation_space) = (env.action_space(), env.observation_space());
+
+    let mut registry = RL_ENVS.lock().expect("RL_ENVS lock poisoned");
+    registry.next_id += 1;
+    let env_id = registry.next_id;
+    registry.envs.insert(env_id, Arc::new(Mutex::new(env)));
+
+    Ok(json!({
+        "schema_version": 1,
+        "success": true,
+        "env_id": env_id,
+        "action_space": action_space,
+        "observation_space": observation_space,
+    })
+    .to_string())
+}
+
+/// 에피소드 시작 → `{observation}`
+pub fn reset_rl_env_json(env_id: u64, seed: Option<u64>) -> Result<String, String> {
+    let observation = with_env(env_id, |env| env.reset(seed))??;
+    Ok(json!({ "schema_version": 1, "success": true, "observation": observation }).to_string())
+}
+
+/// 1 step 진행 → `{observation, reward, done, info}`
+pub fn step_rl_env_json(env_id: u64, action: u32) -> Result<String, String> {
+    let step = with_env(env_id, |env| env.step_index(action))??;
+    let RlStep { observation, reward, done, info } = step;
+    Ok(json!({
+        "schema_version": 1,
+        "success": true,
+        "observation": observation,
+        "reward": reward,
+        "done": done,
+        "info": info,
+    })
+    .to_string())
+}
+
+/// 환경 해제 (존재했으면 true)
+pub fn destroy_rl_env(env_id: u64) -> bool {
+    RL_ENVS.lock().expect("RL_ENVS lock poisoned").envs.remove(&env_id).is_some()
+}
+
+// ============================================================================
+// FFI 함수
+// ============================================================================
+
+/// 환경 생성 (JSON API)
+#[no_mangle]
+pub extern "C" fn rl_env_create_json(
+    request_json: *const std::os::raw::c_char,
+) -> *mut std::os::raw::c_char {
+    let request_str = match safe_c_str_to_string(request_json) {
+        Some(s) => s,
+        None => return error_response_c("Invalid input: null pointer"),
+    };
+
+    match create_rl_env_json(&request_str) {
+        Ok(response) => safe_string_to_c(response),
+        Err(e) => error_response_c(&e),
+    }
+}
+
+/// 에피소드 시작 (JSON API)
+#[no_mangle]
+pub extern "C" fn rl_env_reset_json(env_id: u64, seed: u64) -> *mut std::os::raw::c_char {
+    match reset_rl_env_json(env_id, Some(seed)) {
+        Ok(response) => safe_string_to_c(response),
+        Err(e) => error_response_c(&e),
+    }
+}
+
+/// 1 step 진행 (JSON API)
+#[no_mangle]
+pub extern "C" fn rl_env_step_json(env_id: u64, action: u32) -> *mut std::os::raw::c_char {
+    match step_rl_env_json(env_id, action) {
+        Ok(response) => safe_string_to_c(response),
+        Err(e) => error_response_c(&e),
+    }
+}
+
+/// 에피소드 시작 (Raw API): 첫 관측값을 `obs_out`에 기록
+///
+/// # Safety
+/// `obs_out`은 `obs_len`개의 f32를 쓸 수 있는 유효한 버퍼여야 합니다.
+#[no_mangle]
+pub unsafe extern "C" fn rl_env_reset_raw(
+    env_id: u64,
+    seed: u64,
+    obs_out: *mut f32,
+    obs_len: usize,
+) -> i32 {
+    let result = with_env(env_id, |env| {
+        check_buffer(env, obs_out, obs_len)?;
+        env.reset(Some(seed)).map_err(|_| RL_ENV_ERR_STEP)
+    });
+    match result {
+        Ok(Ok(observation)) => write_observation(&observation, obs_out, obs_len),
+        Ok(Err(code)) => code,
+        Err(_) => RL_ENV_ERR_UNKNOWN_ENV,
+    }
+}
+
+/// 1 step 진행 (Raw API): 관측값은 `obs_out`, 보상은 `reward_out`에 기록
+///
+/// 반환값: `RL_ENV_OK`(진행 중) / `RL_ENV_DONE`(종료) / 음수 에러 코드
+///
+/// # Safety
+/// `obs_out`은 `obs_len`개의 f32를 쓸 수 있는 유효한 버퍼여야 하고,
+/// `reward_out`은 null이거나 유효한 f32 포인터여야 합니다.
+#[no_mangle]
+pub unsafe extern "C" fn rl_env_step_raw(
+    env_id: u64,
+    action: u32,
+    obs_out: *mut f32,
+    obs_len: usize,
+    reward_out: *mut f32,
+) -> i32 {
+    let Some(action) = RlAction::from_index(action) else {
+        return RL_ENV_ERR_INVALID_ACTION;
+    };
+    // 버퍼를 먼저 검사해 실패한 호출이 에피소드를 진행시키지 않도록 한다.
+    let result = with_env(env_id, |env| {
+        check_buffer(env, obs_out, obs_len)?;
+        env.step(action).map_err(|_| RL_ENV_ERR_STEP)
+    });
+    let step = match result {
+        Ok(Ok(step)) => step,
+        Ok(Err(code)) => return code,
+        Err(_) => return RL_ENV_ERR_UNKNOWN_ENV,
+    };
+
+    let code = write_observation(&step.observation, obs_out, obs_len);
+    if code != RL_ENV_OK {
+        return code;
+    }
+    if !reward_out.is_null() {
+        // SAFETY: 호출자가 reward_out 유효성을 보장
+        *reward_out = step.reward;
+    }
+    if step.done {
+        RL_ENV_DONE
+    } else {
+        RL_ENV_OK
+    }
+}
+
+/// 환경 해제 (존재했으면 1, 아니면 0)
+#[no_mangle]
+pub extern "C" fn rl_env_destroy(env_id: u64) -> i32 {
+    destroy_rl_env(env_id) as i32
+}
+
+/// 메모리 해제 함수
+///
+/// # Safety
+/// 이 함수는 `rl_env_*_json` 함수들이 반환한 포인터만 사용해야 합니다.
+/// 동일한 포인터를 두 번 해제하면 undefined behavior가 발생합니다.
+#[no_mangle]
+pub unsafe extern "C" fn rl_env_free_string(s: *mut std::os::raw::c_char) {
+    if s.is_null() {
+        return;
+    }
+    // SAFETY: 이 포인터는 safe_string_to_c()에서 CString::into_raw()로 생성됨
+    let _ = std::ffi::CString::from_raw(s);
+}
+
+fn check_buffer(env: &RlEnv, obs_out: *mut f32, obs_len: usize) -> Result<(), i32> {
+    if obs_out.is_null() || obs_len < env.observation_space().len() {
+        return Err(RL_ENV_ERR_BUFFER);
+    }
+    Ok(())
+}
+
+/// # Safety
+/// `obs_out`은 null이거나 `obs_len`개의 f32를 쓸 수 있어야 함
+unsafe fn write_observation(observation: &[f32], obs_out: *mut f32, obs_len: usize) -> i32 {
+    if obs_out.is_null() || obs_len < observation.len() {
+        return RL_ENV_ERR_BUFFER;
+    }
+    // SAFETY: null/길이 체크 완료, 버퍼 유효성은 호출자가 보장
+    std::ptr::copy_nonoverlapping(observation.as_ptr(), obs_out, observation.len());
+    RL_ENV_OK
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::SimpleVectorObservation;
+    use std::ffi::{CStr, CString};
+
+    fn create_request(config: serde_json::Value) -> String {
+        let positions = [
+            "GK", "LB", "CB", "CB", "RB", "LM", "CM", "CM", "RM", "ST", "ST", "GK", "CB", "CM",
+            "CM", "LW", "RW", "ST",
+        ];
+        let team = |prefix: &str| {
+            let roster: Vec<_> = positions
+                .iter()
+                .enumerate()
+                .map(|(i, pos)| {
+                    json!({"name": format!("{prefix}{i}"), "position": pos, "overall": 70, "condition": 3})
+                })
+                .collect();
+            json!({"name": prefix, "formation": "4-4-2", "roster": roster})
+        };
+        json!({
+            "schema_version": 1,
+            "match_request": {
+                "schema_version": 2,
+                "seed": 3,
+                "home_team": team("Home"),
+                "away_team": team("Away"),
+            },
+            "config": config,
+        })
+        .to_string()
+    }
+
+    fn call(ptr: *mut std::os::raw::c_char) -> serde_json::Value {
+        let text = unsafe { CStr::from_ptr(ptr) }.to_str().unwrap().to_string();
+        unsafe { rl_env_free_string(ptr) };
+        serde_json::from_str(&text).unwrap()
+    }
+
+    #[test]
+    fn test_c_abi_json_and_raw_round_trip() {
+        let request =
+            CString::new(create_request(json!({"reward": {"possession": 0.01}}))).unwrap();
+        let created = call(rl_env_create_json(request.as_ptr()));
+        assert_eq!(created["success"], true);
+        assert_eq!(created["action_space"]["n"], 12);
+        assert_eq!(
+            created["observation_space"]["shape"],
+            json!([SimpleVectorObservation::FLAT_SIZE])
+        );
+        let env_id = created["env_id"].as_u64().unwrap();
+
+        let reset = call(rl_env_reset_json(env_id, 9));
+        assert_eq!(
+            reset["observation"].as_array().unwrap().len(),
+            SimpleVectorObservation::FLAT_SIZE
+        );
+        let step = call(rl_env_step_json(env_id, 6));
+        assert_eq!(step["done"], false);
+        assert!(step["info"]["reward_breakdown"]["possession"].is_number());
+        assert_eq!(call(rl_env_step_json(env_id, 99))["success"], false);
+
+        let mut obs = vec![0.0f32; SimpleVectorObservation::FLAT_SIZE];
+        let mut reward = f32::NAN;
+        unsafe {
+            assert_eq!(rl_env_reset_raw(env_id, 9, obs.as_mut_ptr(), obs.len()), RL_ENV_OK);
+            assert_eq!(
+                obs,
+                reset["observation"]
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .map(|v| v.as_f64().unwrap() as f32)
+                    .collect::<Vec<_>>()
+            );
+            let code = rl_env_step_raw(env_id, 6, obs.as_mut_ptr(), obs.len(), &mut reward);
+            assert_eq!(code, RL_ENV_OK);
+            assert_eq!(reward, step["reward"].as_f64().unwrap() as f32);
+            let short = rl_env_step_raw(env_id, 0, obs.as_mut_ptr(), 10, std::ptr::null_mut());
+            assert_eq!(short, RL_ENV_ERR_BUFFER);
+            let bad = rl_env_step_raw(env_id, 12, obs.as_mut_ptr(), obs.len(), &mut reward);
+            assert_eq!(bad, RL_ENV_ERR_INVALID_ACTION);
+        }
+
+        assert_eq!(rl_env_destroy(env_id), 1);
+        assert_eq!(rl_env_destroy(env_id), 0);
+        assert_eq!(call(rl_env_reset_json(env_id, 1))["success"], false);
+    }
+
+    #[test]
+    fn test_create_rejects_invalid_config() {
+        let request = create_request(json!({"controlled_slot": 12}));
+        assert!(create_rl_env_json(&request).unwrap_err().contains("controlled_slot"));
+        assert!(create_rl_env_json(r#"{"schema_version": 1}"#).is_err());
+        assert_eq!(call(rl_env_create_json(std::ptr::null()))["success"], false);
+    }
+}
@@ -0,0 +1,380 @@
+// Roster Validation API Layer
+// Pre-match squad check for the squad screen: runs the same roster resolution and
+// formation-slot assignment the engine uses (`convert_team_v2` + `MatchSetup::from_team`)
+// but collects every problem instead of stopping at the first one.
+//
+// Errors block simulation; warnings are legal selections the UI should point out
+// (out-of-position starters, thin bench, injury flags).
+
+use std::collections::HashSet;
+
+use serde::Serialize;
+
+use super::json_api::{parse_formation, RosterEntry, RosterSlot, TeamDataV2, TeamDataV3};
+use crate::fix01::error_codes;
+use crate::models::match_setup::{assign_starter_slots, formation_slot_template, slot_accepts};
+use crate::models::player::{Player, Position};
+
+const STARTERS: usize = 11;
+const ROSTER_SIZE: usize = 18;
+
+/// Issue codes (engine `error_codes` are reused where the engine fails the same way)
+pub mod issue_codes {
+    pub const ROSTER_SIZE: &str = "ROSTER_SIZE";
+    pub const DUPLICATE_UID: &str = "DUPLICATE_UID";
+    pub const INVALID_ROSTER_ENTRY: &str = "INVALID_ROSTER_ENTRY";
+    pub const GOALKEEPER_COUNT: &str = "GOALKEEPER_COUNT";
+    pub const TOO_FEW_DEFENDERS: &str = "TOO_FEW_DEFENDERS";
+    pub const OUT_OF_POSITION: &str = "OUT_OF_POSITION";
+    pub const BENCH_NO_GOALKEEPER: &str = "BENCH_NO_GOALKEEPER";
+    pub const BENCH_LINE_UNCOVERED: &str = "BENCH_LINE_UNCOVERED";
+}
+
+// ========== Report Structures ==========
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueSeverity {
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RosterIssue {
+    pub severity: IssueSeverity,
+    pub code: String,
+    /// Roster index (0-10 starters, 11-17 bench) when the issue is about one entry
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub roster_slot: Option<usize>,
+    pub message: String,
+}
+
+/// How a starter fits the formation slot the engine assigns them to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SlotFit {
+    /// Natural position
+    Natural,
+    /// `Position::is_compatible_position` — allowed, flagged as out of position
+    Compatible,
+    /// The engine refuses this placement
+    Incompatible,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SlotAssignment {
+    /// Formation slot (0 = GK)
+    pub formation_slot: usize,
+    pub slot_position: Position,
+    pub roster_slot: usize,
+    pub name: String,
+    pub position: Position,
+    pub fit: SlotFit,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RosterValidationReport {
+    /// No errors (warnings allowed)
+    pub valid: bool,
+    pub formation: String,
+    /// Engine starter placement; empty when the starting XI could not be resolved
+    pub assignments: Vec<SlotAssignment>,
+    pub issues: Vec<RosterIssue>,
+}
+
+impl RosterValidationReport {
+    pub fn errors(&self) -> impl Iterator<Item = &RosterIssue> {
+        self.issues.iter().filter(|issue| issue.severity == IssueSeverity::Error)
+    }
+
+    pub fn warnings(&self) -> impl Iterator<Item = &RosterIssue> {
+        self.issues.iter().filter(|issue| issue.severity == IssueSeverity::Warning)
+    }
+}
+
+// ========== API Functions ==========
+
+/// Validate one team (v2 `TeamDataV2` or v3 `TeamDataV3` JSON); returns `RosterValidationReport`
+///
+/// Malformed JSON is an `Err`; roster problems are reported as issues.
+pub fn validate_roster_json(team_json: &str) -> Result<String, String> {
+    let value: serde_json::Value =
+        serde_json::from_str(team_json).map_err(|e| format!("Invalid JSON: {}", e))?;
+
+    // v2 entries first (UID strings are v2-only); v3 accepts fitness/attributes payloads
+    let report = match serde_json::from_value::<TeamDataV2<RosterEntry>>(value.clone()) {
+        Ok(team) => validate_roster(&team),
+        Err(_) => {
+            let team: TeamDataV3 =
+                serde_json::from_value(value).map_err(|e| format!("Invalid team JSON: {}", e))?;
+            validate_roster(&team)
+        }
+    };
+
+    serde_json::to_string(&report).map_err(|e| format!("Failed to serialize report: {}", e))
+}
+
+/// Check a team the way `convert_team_v2` + `MatchSetup::from_team` would
+pub fn validate_roster<R: RosterSlot + Clone>(team: &TeamDataV2<R>) -> RosterValidationReport {
+    let mut issues = Vec::new();
+    let mut push = |severity, code: &str, roster_slot, message: String| {
+        issues.push(RosterIssue { severity, code: code.to_string(), roster_slot, message });
+    };
+
+    if team.roster.len() != ROSTER_SIZE {
+        push(
+            IssueSeverity::Error,
+            issue_codes::ROSTER_SIZE,
+            None,
+            format!("roster must have exactly {ROSTER_SIZE} entries, found {}", team.roster.len()),
+        );
+    }
+
+    let mut seen_uids = HashSet::new();
+    for (slot, entry) in team.roster.iter().enumerate() {
+        if let Some(uid) = entry.uid() {
+            if !seen_uids.insert(uid) {
+                push(
+                    IssueSeverity::Error,
+                    issue_codes::DUPLICATE_UID,
+                    Some(slot),
+                    format!("duplicate player UID in roster: {uid}"),
+                );
+            }
+        }
+        if let Some((label, until)) = entry.injury() {
+            push(
+                IssueSeverity::Warning,
+                error_codes::PLAYER_INJURED,
+                Some(slot),
+                format!("'{label}' is injured until day {until}; rejected before that match day"),
+            );
+        }
+    }
+
+    let players: Vec<Option<Player>> = team
+        .roster
+        .iter()
+        .enumerate()
+        .map(|(slot, entry)| match entry.clone().into_player(slot) {
+            Ok((_, player)) => Some(player),
+            Err(err) => {
+                let (code, message) = split_error_code(&err);
+                push(IssueSeverity::Error, code, Some(slot), message.to_string());
+                None
+            }
+        })
+        .collect();
+
+    let formation = match parse_formation(&team.formation) {
+        Ok(formation) => Some(formation),
+        Err(err) => {
+            let (code, message) = split_error_code(&err);
+            push(IssueSeverity::Error, code, None, message.to_string());
+            None
+        }
+    };
+
+    let starters: Option<Vec<&Player>> =
+        players.iter().take(STARTERS).map(Option::as_ref).collect();
+    let starters = starters.filter(|starters| starters.len() == STARTERS);
+
+    if let Some(starters) = &starters {
+        let goalkeepers = starters.iter().filter(|p| p.position == Position::GK).count();
+        if goalkeepers != 1 {
+            push(
+                IssueSeverity::Error,
+                issue_codes::GOALKEEPER_COUNT,
+                None,
+                format!("starting XI must have exactly 1 GK, found {goalkeepers}"),
+            );
+        }
+    }
+
+    let mut assignments = Vec::new();
+    if let (Some(starters), Some(formation)) = (&starters, &formation) {
+        let template = formation_slot_template(formation);
+
+        let needed = template.iter().filter(|p| p.is_defender()).count();
+        let defenders = starters.iter().filter(|p| p.position.is_defender()).count();
+        if defenders < needed {
+            push(
+                IssueSeverity::Warning,
+                issue_codes::TOO_FEW_DEFENDERS,
+                None,
+                format!("{} needs {needed} defenders, starting XI has {defenders}", team.formation),
+            );
+        }
+
+        for (formation_slot, &roster_slot) in
+            assign_starter_slots(starters, formation).iter().enumerate()
+        {
+            let player = starters[roster_slot];
+            let slot_position = template[formation_slot];
+            let fit = if !slot_accepts(formation_slot, player.position, slot_position) {
+                SlotFit::Incompatible
+            } else if player.position == slot_position || formation_slot == 0 {
+                SlotFit::Natural
+            } else {
+                SlotFit::Compatible
+            };
+            match fit {
+                SlotFit::Natural => {}
+                SlotFit::Compatible => push(
+                    IssueSeverity::Warning,
+                    issue_codes::OUT_OF_POSITION,
+                    Some(roster_slot),
+                    format!(
+                        "'{}' ({:?}) plays out of position at {slot_position:?}",
+                        player.name, player.position
+                    ),
+                ),
+                SlotFit::Incompatible => push(
+                    IssueSeverity::Error,
+                    error_codes::UNSUPPORTED_POSITION_MAPPING,
+                    Some(roster_slot),
+                    format!(
+                        "cannot assign '{}' ({:?}) to slot {formation_slot} ({slot_position:?})",
+                        player.name, player.position
+                    ),
+                ),
+            }
+            assignments.push(SlotAssignment {
+                formation_slot,
+                slot_position,
+                roster_slot,
+                name: player.name.clone(),
+                position: player.position,
+                fit,
+            });
+        }
+    }
+
+    let bench: Vec<&Player> = players.iter().skip(STARTERS).flatten().collect();
+    if !bench.is_empty() {
+        if !bench.iter().any(|p| p.position.is_goalkeeper()) {
+            push(
+                IssueSeverity::Warning,
+                issue_codes::BENCH_NO_GOALKEEPER,
+                None,
+                "no substitute goalkeeper on the bench".to_string(),
+            );
+        }
+        let uncovered: Vec<&str> = [
+            ("defence", Position::is_defender as fn(&Position) -> bool),
+            ("midfield", Position::is_midfielder),
+            ("attack", Position::is_forward),
+        ]
+        .into_iter()
+        .filter(|(_, in_line)| !bench.iter().any(|p| in_line(&p.position)))
+        .map(|(line, _)| line)
+        .collect();
+        if !uncovered.is_empty() {
+            push(
+                IssueSeverity::Warning,
+                issue_codes::BENCH_LINE_UNCOVERED,
+                None,
+                format!("bench has no cover for: {}", uncovered.join(", ")),
+            );
+        }
+    }
+
+    let valid = !issues.iter().any(|issue| issue.severity == IssueSeverity::Error);
+    RosterValidationReport { valid, formation: team.formation.clone(), assignments, issues }
+}
+
+/// `"CODE: message"` (engine `err_code` format) → (code, message)
+fn split_error_code(err: &str) -> (&str, &str) {
+    match err.split_once(": ") {
+        Some((code, message))
+            if !code.is_empty() && code.chars().all(|c| c.is_ascii_uppercase() || c == '_') =>
+        {
+            (code, message)
+        }
+        _ => (issue_codes::INVALID_ROSTER_ENTRY, err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::json_api::convert_team_v2;
+
+    const POSITIONS: [&str; 18] = [
+        "GK", "LB", "CB", "CB", "RB", "LM", "CM", "CM", "RM", "ST", "ST", "GK", "CB", "CM", "CM",
+        "LW", "RW", "ST",
+    ];
+
+    fn team(positions: &[&str]) -> serde_json::Value {
+        let roster: Vec<_> = positions
+            .iter()
+            .enumerate()
+            .map(|(i, pos)| {
+                serde_json::json!({"name": format!("P{i}"), "position": pos, "overall": 70,
+                    "condition": 3})
+            })
+            .collect();
+        serde_json::json!({"name": "H", "formation": "4-4-2", "roster": roster})
+    }
+
+    fn report(team: serde_json::Value) -> RosterValidationReport {
+        let team: TeamDataV2 = serde_json::from_value(team).unwrap();
+        validate_roster(&team)
+    }
+
+    fn codes(report: &RosterValidationReport) -> Vec<&str> {
+        report.issues.iter().map(|issue| issue.code.as_str()).collect()
+    }
+
+    #[test]
+    fn valid_roster_matches_engine_assignment() {
+        let report = report(team(&POSITIONS));
+        assert!(report.valid);
+        assert!(report.issues.is_empty(), "{:?}", report.issues);
+        assert_eq!(report.assignments.len(), 11);
+        assert!(report.assignments.iter().all(|a| a.fit == SlotFit::Natural));
+
+        // Same players the engine would accept
+        let request: TeamDataV2 = serde_json::from_value(team(&POSITIONS)).unwrap();
+        let (engine_team, _, _) = convert_team_v2(request, false).unwrap();
+        assert!(crate::models::MatchSetup::from_teams(&engine_team, &engine_team).is_ok());
+    }
+
+    #[test]
+    fn out_of_position_and_bench_gaps_are_warnings() {
+        let mut positions = POSITIONS;
+        positions[1] = "LM"; // LB slot filled by a compatible LM
+        positions[11] = "CB"; // no bench GK
+        let report = report(team(&positions));
+        assert!(report.valid, "{:?}", report.issues);
+        let out = report.warnings().find(|i| i.code == issue_codes::OUT_OF_POSITION).unwrap();
+        // Either LM may take the LB slot; the other stays natural
+        assert!(matches!(out.roster_slot, Some(1) | Some(5)), "{:?}", out);
+        assert!(codes(&report).contains(&issue_codes::TOO_FEW_DEFENDERS));
+        assert!(codes(&report).contains(&issue_codes::BENCH_NO_GOALKEEPER));
+    }
+
+    #[test]
+    fn engine_blocking_problems_are_errors() {
+        let mut positions = POSITIONS;
+        positions[0] = "ST"; // no starting GK
+        let mut team = team(&positions);
+        team["roster"][12]["condition"] = serde_json::json!(9);
+        let report = report(team);
+        assert!(!report.valid);
+        let codes = codes(&report);
+        assert!(codes.contains(&issue_codes::GOALKEEPER_COUNT));
+        assert!(codes.contains(&error_codes::INVALID_CONDITION_RANGE));
+        assert_eq!(report.errors().filter(|i| i.roster_slot == Some(12)).count(), 1);
+        // The engine would refuse an outfielder in the GK slot
+        assert_eq!(report.assignments[0].fit, SlotFit::Incompatible);
+        assert!(codes.contains(&error_codes::UNSUPPORTED_POSITION_MAPPING));
+
+        let short = serde_json::json!({"name": "H", "formation": "9-9-9", "roster": []});
+        let json: serde_json::Value =
+            serde_json::from_str(&validate_roster_json(&short.to_string()).unwrap()).unwrap();
+        assert_eq!(json["valid"], false);
+        assert_eq!(json["issues"][0]["code"], issue_codes::ROSTER_SIZE);
+        assert_eq!(json["issues"][1]["code"], error_codes::UNSUPPORTED_FORMATION);
+    }
+}
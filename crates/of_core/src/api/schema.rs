@@ -0,0 +1,530 @@
+// Request JSON Schemas + pre-flight validation
+//
+// `get_request_schema(kind)` exports the JSON Schema (draft-07, generated by schemars from the
+// request structs themselves) for "match_v1", "match_v2", "match_v3", "training" and "replay".
+// `validate_request_json` checks a payload before it is simulated and lists every problem with
+// its field path, so frontends and modders can point at the broken field instead of a serde
+// line/column.
+//
+// Validation runs in three passes; a pass only runs when the previous one found nothing:
+// 1. schema walk (types, required fields, enums, integer ranges) — collects all errors
+// 2. typed decode (`serde_json::from_value`) + `schema_version` check
+// 3. match requests only: `MatchPlan` build (roster size, UIDs, formations, injuries)
+
+use serde::Serialize;
+use serde_json::Value;
+
+use super::json_api::{
+    match_plan_from_match_request, match_plan_from_match_request_v2,
+    match_plan_from_match_request_v3, MatchRequest, MatchRequestV2, MatchRequestV3,
+};
+use super::training_json::TrainingRequest;
+use crate::replay::ReplayDoc;
+
+/// Payload kinds with an exported schema
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RequestKind {
+    MatchV1,
+    MatchV2,
+    MatchV3,
+    Training,
+    Replay,
+}
+
+impl RequestKind {
+    pub const ALL: [RequestKind; 5] = [
+        RequestKind::MatchV1,
+        RequestKind::MatchV2,
+        RequestKind::MatchV3,
+        RequestKind::Training,
+        RequestKind::Replay,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            RequestKind::MatchV1 => "match_v1",
+            RequestKind::MatchV2 => "match_v2",
+            RequestKind::MatchV3 => "match_v3",
+            RequestKind::Training => "training",
+            RequestKind::Replay => "replay",
+        }
+    }
+
+    pub fn parse(kind: &str) -> Result<Self, String> {
+        Self::ALL.into_iter().find(|k| k.as_str() == kind).ok_or_else(|| {
+            format!(
+                "Unknown request schema '{}' (expected match_v1, match_v2, match_v3, training or replay)",
+                kind
+            )
+        })
+    }
+
+    /// Guess the kind of a request payload (replay docs must be named explicitly)
+    ///
+    /// `request_type` → training; otherwise `schema_version` 1/2/3 → match v1/v2/v3.
+    pub fn detect(request: &Value) -> Option<Self> {
+        if request.get("request_type").is_some() {
+            return Some(RequestKind::Training);
+        }
+        match request.get("schema_version").and_then(Value::as_u64) {
+            Some(1) => Some(RequestKind::MatchV1),
+            Some(2) => Some(RequestKind::MatchV2),
+            Some(3) => Some(RequestKind::MatchV3),
+            _ => None,
+        }
+    }
+
+    pub fn schema(self) -> Value {
+        let schema = match self {
+            RequestKind::MatchV1 => schemars::schema_for!(MatchRequest),
+            RequestKind::MatchV2 => schemars::schema_for!(MatchRequestV2),
+            RequestKind::MatchV3 => schemars::schema_for!(MatchRequestV3),
+            RequestKind::Training => schemars::schema_for!(TrainingRequest),
+            RequestKind::Replay => schemars::schema_for!(ReplayDoc),
+        };
+        serde_json::to_value(schema).unwrap_or_default()
+    }
+}
+
+/// JSON Schema of a request kind ("match_v1" | "match_v2" | "match_v3" | "training" | "replay")
+pub fn get_request_schema(kind: &str) -> Result<String, String> {
+    let schema = RequestKind::parse(kind)?.schema();
+    serde_json::to_string_pretty(&schema).map_err(|e| format!("Failed to serialize schema: {}", e))
+}
+
+/// One problem in a payload; `path` is `$` for the root, e.g. `$.home_team.roster[3].condition`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FieldError {
+    pub path: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ValidationReport {
+    /// Kind the payload was validated as (None = could not be detected)
+    pub kind: Option<&'static str>,
+    pub valid: bool,
+    pub errors: Vec<FieldError>,
+}
+
+/// Validate a request JSON; the kind is detected from `request_type` / `schema_version`
+///
+/// Returns a `ValidationReport` JSON; `Err` only when the report itself cannot be serialized.
+pub fn validate_request_json(request_json: &str) -> Result<String, String> {
+    let report = match serde_json::from_str::<Value>(request_json) {
+        Ok(request) => match RequestKind::detect(&request) {
+            Some(kind) => validate_request(kind, &request),
+            None => ValidationReport {
+                kind: None,
+                valid: false,
+                errors: vec![FieldError {
+                    path: "$".to_string(),
+                    message: "cannot detect request kind: expected `request_type` (training) or \
+                              `schema_version` 1, 2 or 3 (match)"
+                        .to_string(),
+                }],
+            },
+        },
+        Err(e) => ValidationReport {
+            kind: None,
+            valid: false,
+            errors: vec![FieldError {
+                path: "$".to_string(),
+                message: format!("Invalid JSON: {}", e),
+            }],
+        },
+    };
+    serde_json::to_string(&report).map_err(|e| format!("Failed to serialize report: {}", e))
+}
+
+/// Validate a parsed payload as `kind`
+pub fn validate_request(kind: RequestKind, request: &Value) -> ValidationReport {
+    let schema = kind.schema();
+    let mut errors = Vec::new();
+    SchemaWalker { root: &schema }.check(&schema, request, "$", &mut errors);
+    if errors.is_empty() {
+        if let Err(message) = check_typed(kind, request) {
+            errors.push(FieldError { path: "$".to_string(), message });
+        }
+    }
+    ValidationReport { kind: Some(kind.as_str()), valid: errors.is_empty(), errors }
+}
+
+/// Passes 2 and 3: what the simulate entrypoints would reject after parsing
+fn check_typed(kind: RequestKind, request: &Value) -> Result<(), String> {
+    let decode_error = |e: serde_json::Error| format!("Invalid request: {}", e);
+    match kind {
+        RequestKind::MatchV1 => {
+            let request: MatchRequest =
+                serde_json::from_value(request.clone()).map_err(decode_error)?;
+            match_plan_from_match_request(request).map(|_| ())
+        }
+        RequestKind::MatchV2 => {
+            let request: MatchRequestV2 =
+                serde_json::from_value(request.clone()).map_err(decode_error)?;
+            match_plan_from_match_request_v2(request).map(|_| ())
+        }
+        RequestKind::MatchV3 => {
+            let request: MatchRequestV3 =
+                serde_json::from_value(request.clone()).map_err(decode_error)?;
+            match_plan_from_match_request_v3(request).map(|_| ())
+        }
+        RequestKind::Training => {
+            let request: TrainingRequest =
+                serde_json::from_value(request.clone()).map_err(decode_error)?;
+            if request.schema_version != 1 {
+                return Err(format!("Unsupported schema version: {}", request.schema_version));
+            }
+            Ok(())
+        }
+        RequestKind::Replay => {
+            serde_json::from_value::<ReplayDoc>(request.clone()).map_err(decode_error)?;
+            Ok(())
+        }
+    }
+}
+
+const MISSING_FIELD: &str = "missing required field";
+
+/// Minimal draft-07 walker covering what schemars emits for our types
+struct SchemaWalker<'a> {
+    root: &'a Value,
+}
+
+impl<'a> SchemaWalker<'a> {
+    fn resolve(&self, schema: &'a Value) -> &'a Value {
+        let target = schema
+            .get("$ref")
+            .and_then(Value::as_str)
+            .and_then(|r| r.strip_prefix("#/definitions/"))
+            .and_then(|name| self.root.get("definitions")?.get(name));
+        match target {
+            Some(target) => self.resolve(target),
+            None => schema,
+        }
+    }
+
+    fn check(&self, schema: &'a Value, value: &Value, path: &str, errors: &mut Vec<FieldError>) {
+        let schema = self.resolve(schema);
+        let mut error = |message: String| {
+            errors.push(FieldError { path: path.to_string(), message });
+        };
+
+        if schema == &Value::Bool(false) {
+            error("not allowed here".to_string());
+            return;
+        }
+        let Some(schema) = schema.as_object() else {
+            return;
+        };
+
+        if let Some(types) = schema.get("type") {
+            let allowed: Vec<&str> = match types {
+                Value::String(t) => vec![t.as_str()],
+                Value::Array(ts) => ts.iter().filter_map(Value::as_str).collect(),
+                _ => Vec::new(),
+            };
+            if !allowed.is_empty() && !allowed.iter().any(|t| type_matches(t, value)) {
+                error(format!("expected {}, got {}", allowed.join(" or "), type_name(value)));
+                return;
+            }
+        }
+        if let Some(options) = schema.get("enum").and_then(Value::as_array) {
+            if !options.contains(value) {
+                error(format!("expected one of {}, got {}", Value::from(options.clone()), value));
+                return;
+            }
+        }
+        if let Some(expected) = schema.get("const") {
+            if expected != value {
+                error(format!("expected {}, got {}", expected, value));
+                return;
+            }
+        }
+        if let Some(number) = value.as_f64() {
+            if let Some(message) = range_error(schema, number) {
+                error(message);
+                return;
+            }
+        }
+
+        for sub in schema.get("allOf").and_then(Value::as_array).into_iter().flatten() {
+            self.check(sub, value, path, errors);
+        }
+        for key in ["anyOf", "oneOf"] {
+            if let Some(branches) = schema.get(key).and_then(Value::as_array) {
+                self.check_branches(branches, value, path, errors);
+            }
+        }
+
+        match value {
+            Value::Object(map) => {
+                for field in schema.get("required").and_then(Value::as_array).into_iter().flatten()
+                {
+                    if let Some(field) = field.as_str() {
+                        if !map.contains_key(field) {
+                            errors.push(FieldError {
+                                path: format!("{}.{}", path, field),
+                                message: MISSING_FIELD.to_string(),
+                            });
+                        }
+                    }
+                }
+                let properties = schema.get("properties").and_then(Value::as_object);
+                for (key, item) in map {
+                    let item_path = format!("{}.{}", path, key);
+                    match properties.and_then(|p| p.get(key)) {
+                        Some(sub) => self.check(sub, item, &item_path, errors),
+                        None => {
+                            if let Some(extra) = schema.get("additionalProperties") {
+                                self.check(extra, item, &item_path, errors);
+                            }
+                        }
+                    }
+                }
+            }
+            Value::Array(items) => {
+                if let Some(message) = length_error(schema, items.len()) {
+                    errors.push(FieldError { path: path.to_string(), message });
+                }
+                match schema.get("items") {
+                    Some(Value::Array(tuple)) => {
+                        for (i, (sub, item)) in tuple.iter().zip(items).enumerate() {
+                            self.check(sub, item, &format!("{}[{}]", path, i), errors);
+                        }
+                    }
+                    Some(sub) => {
+                        for (i, item) in items.iter().enumerate() {
+                            self.check(sub, item, &format!("{}[{}]", path, i), errors);
+                        }
+                    }
+                    None => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// anyOf / oneOf: fine if any branch matches; otherwise report the closest branch
+    ///
+    /// "Closest" = a branch that accepts the value's shape (no error at `path` itself), then
+    /// fewest missing fields, then fewest errors — so an untagged roster entry reports the bad
+    /// attribute, and a tagged enum with the right tag reports its fields instead of the tag.
+    fn check_branches(
+        &self,
+        branches: &'a [Value],
+        value: &Value,
+        path: &str,
+        errors: &mut Vec<FieldError>,
+    ) {
+        let rank = |errors: &[FieldError]| {
+            let missing = errors.iter().filter(|e| e.message == MISSING_FIELD).count();
+            (errors.iter().any(|e| e.path == path), missing, errors.len())
+        };
+        let mut closest: Option<Vec<FieldError>> = None;
+        for branch in branches {
+            let mut branch_errors = Vec::new();
+            self.check(branch, value, path, &mut branch_errors);
+            if branch_errors.is_empty() {
+                return;
+            }
+            if closest.as_ref().map_or(true, |c| rank(&branch_errors) < rank(c)) {
+                closest = Some(branch_errors);
+            }
+        }
+        errors.extend(closest.unwrap_or_default());
+    }
+}
+
+fn type_matches(schema_type: &str, value: &Value) -> bool {
+    match schema_type {
+        "null" => value.is_null(),
+        "boolean" => value.is_boolean(),
+        "string" => value.is_string(),
+        "array" => value.is_array(),
+        "object" => value.is_object(),
+        "number" => value.is_number(),
+        "integer" => {
+            value.is_i64() || value.is_u64() || value.as_f64().is_some_and(|n| n.fract() == 0.0)
+        }
+        _ => true,
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// `minimum` / `maximum`, plus the bounds implied by schemars integer formats (`uint8`, ...)
+fn range_error(schema: &serde_json::Map<String, Value>, number: f64) -> Option<String> {
+    let (format_min, format_max) = match schema.get("format").and_then(Value::as_str) {
+        Some("uint8") => (Some(0.0), Some(u8::MAX as f64)),
+        Some("uint16") => (Some(0.0), Some(u16::MAX as f64)),
+        Some("uint32") => (Some(0.0), Some(u32::MAX as f64)),
+        Some("uint64") | Some("uint") => (Some(0.0), None),
+        Some("int8") => (Some(i8::MIN as f64), Some(i8::MAX as f64)),
+        Some("int16") => (Some(i16::MIN as f64), Some(i16::MAX as f64)),
+        Some("int32") => (Some(i32::MIN as f64), Some(i32::MAX as f64)),
+        _ => (None, None),
+    };
+    let min = schema.get("minimum").and_then(Value::as_f64).or(format_min);
+    let max = schema.get("maximum").and_then(Value::as_f64).or(format_max);
+    match (min, max) {
+        (Some(min), _) if number < min => Some(format!("{} is below the minimum {}", number, min)),
+        (_, Some(max)) if number > max => Some(format!("{} is above the maximum {}", number, max)),
+        _ => None,
+    }
+}
+
+fn length_error(schema: &serde_json::Map<String, Value>, len: usize) -> Option<String> {
+    let min = schema.get("minItems").and_then(Value::as_u64);
+    let max = schema.get("maxItems").and_then(Value::as_u64);
+    match (min, max) {
+        (Some(min), _) if (len as u64) < min => {
+            Some(format!("expected at least {} items, got {}", min, len))
+        }
+        (_, Some(max)) if (len as u64) > max => {
+            Some(format!("expected at most {} items, got {}", max, len))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn v2_request() -> Value {
+        let positions = [
+            "GK", "LB", "CB", "CB", "RB", "LM", "CM", "CM", "RM", "ST", "ST", "GK", "CB", "CM",
+            "CM", "LW", "RW", "ST",
+        ];
+        let team = |name: &str| {
+            let roster: Vec<Value> = positions
+                .iter()
+                .enumerate()
+                .map(|(i, pos)| {
+                    json!({"name": format!("{name}{i}"), "position": pos, "overall": 70, "condition": 3})
+                })
+                .collect();
+            json!({"name": name, "formation": "4-4-2", "roster": roster})
+        };
+        json!({"schema_version": 2, "seed": 7, "home_team": team("Home"), "away_team": team("Away")})
+    }
+
+    fn json_report(request: &Value) -> Value {
+        serde_json::from_str(&validate_request_json(&request.to_string()).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_every_kind_exports_a_schema() {
+        for kind in RequestKind::ALL {
+            let schema: Value =
+                serde_json::from_str(&get_request_schema(kind.as_str()).unwrap()).unwrap();
+            assert_eq!(schema["type"], "object", "{}", kind.as_str());
+            assert!(schema["properties"].is_object(), "{}", kind.as_str());
+        }
+        let v2: Value = serde_json::from_str(&get_request_schema("match_v2").unwrap()).unwrap();
+        assert!(v2["definitions"]["TeamDataV2_for_RosterEntry"].is_object());
+        let v3: Value = serde_json::from_str(&get_request_schema("match_v3").unwrap()).unwrap();
+        assert!(v3["definitions"]["RosterEntryV3"]["properties"]["attributes"].is_object());
+        assert!(get_request_schema("match_v4").is_err());
+    }
+
+    #[test]
+    fn test_valid_requests_pass() {
+        let report = json_report(&v2_request());
+        assert_eq!(report["kind"], "match_v2");
+        assert_eq!(report["valid"], true, "{report}");
+
+        let training = json!({
+            "schema_version": 1,
+            "request_type": {"type": "ExecuteRest", "forced": false},
+            "player_id": "p1",
+            "seed": 1
+        });
+        let report = json_report(&training);
+        assert_eq!(report["kind"], "training");
+        assert_eq!(report["valid"], true, "{report}");
+    }
+
+    /// Whatever the engine serializes must pass its own schema
+    #[test]
+    fn test_serialized_models_pass_schema() {
+        let mut request = v2_request();
+        let value = |v: serde_json::Result<Value>| v.unwrap();
+        request["home_instructions"] =
+            value(serde_json::to_value(crate::tactics::TeamInstructions::default()));
+        request["conditions"] =
+            value(serde_json::to_value(crate::models::weather::MatchConditions::default()));
+        request["referee"] = value(serde_json::to_value(crate::models::RefereeProfile::default()));
+        request["highlight_config"] =
+            value(serde_json::to_value(crate::models::HighlightConfig::default()));
+        request["penalty_shootout"] =
+            value(serde_json::to_value(crate::models::PenaltyShootoutConfig::default()));
+        request["home_load"] = value(serde_json::to_value(crate::career::SquadLoad::default()));
+        let report = validate_request(RequestKind::MatchV2, &request);
+        assert!(report.valid, "{:?}", report.errors);
+
+        let (_, replay_json) =
+            crate::api::simulate_match_v2_json_with_replay(&v2_request().to_string()).unwrap();
+        let replay: Value = serde_json::from_str(&replay_json).unwrap();
+        let report = validate_request(RequestKind::Replay, &replay);
+        assert!(report.valid, "{:?}", &report.errors[..report.errors.len().min(5)]);
+    }
+
+    #[test]
+    fn test_field_errors_carry_paths() {
+        let mut request = v2_request();
+        request["home_team"]["roster"][3]["overall"] = json!(300);
+        request["away_team"]["formation"] = json!(442);
+        request.as_object_mut().unwrap().remove("seed");
+
+        let report = validate_request(RequestKind::MatchV2, &request);
+        assert!(!report.valid);
+        let paths: Vec<&str> = report.errors.iter().map(|e| e.path.as_str()).collect();
+        assert!(paths.contains(&"$.seed"), "{paths:?}");
+        assert!(paths.contains(&"$.away_team.formation"), "{paths:?}");
+        assert!(paths.contains(&"$.home_team.roster[3].overall"), "{paths:?}");
+    }
+
+    #[test]
+    fn test_tagged_enum_reports_the_matching_variant() {
+        let training = json!({
+            "schema_version": 1,
+            "request_type": {"type": "ExecuteRest", "forced": "yes"},
+            "player_id": "p1",
+            "seed": 1
+        });
+        let report = validate_request(RequestKind::Training, &training);
+        assert_eq!(
+            report.errors,
+            vec![FieldError {
+                path: "$.request_type.forced".to_string(),
+                message: "expected boolean, got string".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_semantic_errors_after_schema_pass() {
+        let mut request = v2_request();
+        request["home_team"]["roster"].as_array_mut().unwrap().truncate(5);
+        let report = validate_request(RequestKind::MatchV2, &request);
+        assert!(!report.valid);
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].path, "$");
+
+        let undetected = json_report(&json!({"seed": 1}));
+        assert_eq!(undetected["kind"], Value::Null);
+        assert_eq!(undetected["valid"], false);
+    }
+}
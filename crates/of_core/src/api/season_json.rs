@@ -0,0 +1,97 @@
+// Season JSON API Layer
+// Stateless league matchday simulation: the caller keeps the returned league and sends it back
+
+use super::json_api::{convert_team_v2, TeamDataV2};
+use crate::season::{FixtureResult, League, StandingsRow};
+use serde::{Deserialize, Serialize};
+
+// ========== Request/Response Structures ==========
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SimulateMatchdayRequest {
+    /// League state returned by the previous call (None = start a new season from `teams`)
+    #[serde(default)]
+    pub league: Option<League>,
+    #[serde(default)]
+    pub teams: Vec<TeamDataV2>,
+    #[serde(default)]
+    pub seed: u64,
+    #[serde(default = "default_true")]
+    pub double_round_robin: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SimulateMatchdayResponse {
+    pub matchday: u32,
+    pub results: Vec<FixtureResult>,
+    pub standings: Vec<StandingsRow>,
+    pub finished: bool,
+    pub league: League,
+}
+
+// ========== API Functions ==========
+
+/// Simulate the next matchday; returns `SimulateMatchdayResponse` JSON
+pub fn simulate_matchday_json(request_json: &str) -> Result<String, String> {
+    let request: SimulateMatchdayRequest =
+        serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
+
+    let mut league = match request.league {
+        Some(league) => league,
+        None => {
+            let teams = request
+                .teams
+                .into_iter()
+                .map(|data| convert_team_v2(data, false).map(|(team, _, _)| team))
+                .collect::<Result<Vec<_>, _>>()?;
+            League::new(teams, request.seed, request.double_round_robin)?
+        }
+    };
+
+    let matchday = league.next_matchday;
+    let results = league.simulate_next_matchday()?;
+    let response = SimulateMatchdayResponse {
+        matchday,
+        results,
+        standings: league.table(),
+        finished: league.is_finished(),
+        league,
+    };
+
+    serde_json::to_string(&response).map_err(|e| format!("Failed to serialize matchday: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::match_sim::test_fixtures::create_test_team_with_subs as create_test_team;
+
+    #[test]
+    fn test_league_state_round_trips_between_matchdays() {
+        let teams = vec![create_test_team("Home"), create_test_team("Away")];
+        let league = League::new(teams, 9, true).unwrap();
+        let request = serde_json::json!({ "league": league }).to_string();
+
+        let first: SimulateMatchdayResponse =
+            serde_json::from_str(&simulate_matchday_json(&request).unwrap()).unwrap();
+        assert_eq!(first.matchday, 0);
+        assert_eq!(first.results.len(), 1);
+        assert!(!first.finished);
+
+        let request = serde_json::json!({ "league": first.league }).to_string();
+        let second: SimulateMatchdayResponse =
+            serde_json::from_str(&simulate_matchday_json(&request).unwrap()).unwrap();
+        assert_eq!(second.matchday, 1);
+        assert!(second.finished);
+        assert_eq!(second.standings[0].played, 2);
+    }
+
+    #[test]
+    fn test_needs_two_teams() {
+        assert!(simulate_matchday_json(r#"{"teams": [], "seed": 1}"#).is_err());
+    }
+}
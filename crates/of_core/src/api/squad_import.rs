@@ -0,0 +1,488 @@
+// Squad Import API Layer
+// Forgiving MatchSetup roster importer: accepts 16-23 players, maps loose
+// position strings ("AMC", "D(C)", "Striker"), fills missing bench slots from the
+// stored squad and reports every adjustment made along the way.
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use super::json_api::{
+    ca_to_overall, parse_formation, parse_position, EmbeddedPlayerData, RosterEntry, TeamDataV2,
+    UidRosterEntry,
+};
+use crate::models::player::Position;
+use crate::player::CorePlayer;
+
+pub const MIN_IMPORT_SQUAD: usize = 16;
+pub const MAX_IMPORT_SQUAD: usize = 23;
+
+const STARTERS: usize = 11;
+const BENCH: usize = 7;
+const DEFAULT_FORMATION: &str = "4-4-2";
+/// FIX01 ConditionLevel used when the payload carries none
+const DEFAULT_CONDITION: u8 = 3;
+const DEFAULT_OVERALL: u8 = 50;
+
+// ========== Request/Response Structures ==========
+
+/// Roster entry as exported by Godot: an id string or a loose player dictionary
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum ImportEntry {
+    /// Stored player id or person UID (e.g. "csv:123")
+    Id(String),
+    Player(ImportPlayer),
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ImportPlayer {
+    #[serde(default)]
+    pub uid: Option<String>,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub position: Option<String>,
+    #[serde(default)]
+    pub overall: Option<u8>,
+    #[serde(default)]
+    pub condition: Option<u8>,
+}
+
+/// MatchSetupExporter team dictionary
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SquadImportRequest {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default, alias = "formation_id")]
+    pub formation: Option<String>,
+    #[serde(default)]
+    pub starting_xi: Vec<ImportEntry>,
+    #[serde(default)]
+    pub bench: Vec<ImportEntry>,
+    /// Flat list, starters first (used when `starting_xi` is empty)
+    #[serde(default)]
+    pub roster: Vec<ImportEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SquadAdjustment {
+    FormationDefaulted { from: String, to: String },
+    PositionMapped { player: String, from: String, to: Position },
+    ConditionDefaulted { player: String, condition: u8 },
+    OverallDefaulted { player: String, overall: u8 },
+    MovedToBench { player: String },
+    PromotedToStarter { player: String },
+    GoalkeeperMovedToSlot0 { player: String },
+    BenchTrimmed { player: String },
+    BenchFilled { player: String, player_id: String },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SquadImportResult {
+    /// 18-entry team ready for MatchRequestV2
+    pub team: TeamDataV2,
+    pub adjustments: Vec<SquadAdjustment>,
+}
+
+struct ImportedPlayer {
+    label: String,
+    /// Duplicate key: stored id, UID or embedded name
+    key: String,
+    /// None for UID references (position lives in the person DB)
+    position: Option<Position>,
+    entry: RosterEntry,
+}
+
+// ========== Position Mapping ==========
+
+/// Map a loose position string (engine code, FM code, FM "AM (L)" notation or
+/// plain word) to an engine position
+pub fn map_loose_position(raw: &str) -> Option<Position> {
+    let cleaned: String =
+        raw.chars().filter(|c| !c.is_whitespace() && *c != '"').collect::<String>().to_uppercase();
+    let token = cleaned.split([',', '/']).next().unwrap_or("");
+    match token.split_once('(') {
+        Some((base, side)) => side
+            .chars()
+            .next()
+            .and_then(|side| position_alias(&format!("{base}{side}")))
+            .or_else(|| position_alias(base)),
+        None => position_alias(token),
+    }
+}
+
+fn position_alias(token: &str) -> Option<Position> {
+    if let Ok(position) = parse_position(token) {
+        return Some(position);
+    }
+    Some(match token {
+        "G" | "GKP" | "GOALKEEPER" | "KEEPER" => Position::GK,
+        "DL" => Position::LB,
+        "DR" => Position::RB,
+        "DC" | "CD" => Position::CB,
+        "WBL" => Position::LWB,
+        "WBR" => Position::RWB,
+        "D" | "DEF" | "DEFENDER" => Position::DF,
+        "DM" | "DMC" => Position::CDM,
+        "MC" => Position::CM,
+        "AM" | "AMC" => Position::CAM,
+        "ML" => Position::LM,
+        "MR" => Position::RM,
+        "M" | "MID" | "MIDFIELDER" => Position::MF,
+        "AML" => Position::LW,
+        "AMR" => Position::RW,
+        "S" | "SS" | "STC" | "STRIKER" => Position::ST,
+        "F" | "FWD" | "FORWARD" => Position::FW,
+        _ => return None,
+    })
+}
+
+// ========== Core Functions ==========
+
+/// Import a team dictionary into an 18-entry TeamDataV2; `registry` is the
+/// stored squad used to resolve ids and fill missing bench slots
+pub fn import_squad(
+    request: &SquadImportRequest,
+    registry: &[CorePlayer],
+) -> Result<SquadImportResult, String> {
+    let (starters, bench) = if request.starting_xi.is_empty() {
+        request.roster.split_at(request.roster.len().min(STARTERS))
+    } else {
+        (request.starting_xi.as_slice(), request.bench.as_slice())
+    };
+    let total = starters.len() + bench.len();
+    if !(MIN_IMPORT_SQUAD..=MAX_IMPORT_SQUAD).contains(&total) {
+        return Err(format!(
+            "Squad must have {}-{} players, found {}",
+            MIN_IMPORT_SQUAD, MAX_IMPORT_SQUAD, total
+        ));
+    }
+
+    let mut adjustments = Vec::new();
+    let formation = import_formation(request.formation.as_deref(), &mut adjustments);
+    let mut starters = starters
+        .iter()
+        .map(|entry| import_entry(entry, registry, &mut adjustments))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut bench = bench
+        .iter()
+        .map(|entry| import_entry(entry, registry, &mut adjustments))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut seen = HashSet::new();
+    if let Some(dup) = starters.iter().chain(&bench).find(|p| !seen.insert(p.key.clone())) {
+        return Err(format!("Duplicate player in squad: {}", dup.label));
+    }
+
+    while starters.len() > STARTERS {
+        let player = starters.pop().expect("more than 11 starters");
+        adjustments.push(SquadAdjustment::MovedToBench { player: player.label.clone() });
+        bench.insert(0, player);
+    }
+    while starters.len() < STARTERS {
+        // total >= 16 leaves enough bench players to promote
+        let player = bench.remove(0);
+        adjustments.push(SquadAdjustment::PromotedToStarter { player: player.label.clone() });
+        starters.push(player);
+    }
+    if starters[0].position != Some(Position::GK) {
+        if let Some(idx) = starters.iter().position(|p| p.position == Some(Position::GK)) {
+            let keeper = starters.remove(idx);
+            adjustments
+                .push(SquadAdjustment::GoalkeeperMovedToSlot0 { player: keeper.label.clone() });
+            starters.insert(0, keeper);
+        }
+    }
+
+    while bench.len() > BENCH {
+        let player = bench.pop().expect("more than 7 substitutes");
+        adjustments.push(SquadAdjustment::BenchTrimmed { player: player.label });
+    }
+    fill_bench(&mut bench, registry, &seen, &mut adjustments)?;
+
+    Ok(SquadImportResult {
+        team: TeamDataV2 {
+            name: request.name.clone().unwrap_or_else(|| "Imported".to_string()),
+            formation,
+            roster: starters.into_iter().chain(bench).map(|p| p.entry).collect(),
+            player_instructions: None,
+            custom_formation: None,
+            set_piece_takers: None,
+        },
+        adjustments,
+    })
+}
+
+fn import_formation(raw: Option<&str>, adjustments: &mut Vec<SquadAdjustment>) -> String {
+    let raw = raw.unwrap_or("").trim();
+    // Godot ids ("T442") and bare digits ("4231")
+    let digits = raw.trim_start_matches(['T', 't']);
+    let normalized = if !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()) {
+        digits.chars().map(String::from).collect::<Vec<_>>().join("-")
+    } else {
+        raw.to_string()
+    };
+    if parse_formation(&normalized).is_ok() {
+        return normalized;
+    }
+    adjustments.push(SquadAdjustment::FormationDefaulted {
+        from: raw.to_string(),
+        to: DEFAULT_FORMATION.to_string(),
+    });
+    DEFAULT_FORMATION.to_string()
+}
+
+fn import_entry(
+    entry: &ImportEntry,
+    registry: &[CorePlayer],
+    adjustments: &mut Vec<SquadAdjustment>,
+) -> Result<ImportedPlayer, String> {
+    let player = match entry {
+        ImportEntry::Id(id) => {
+            let id = id.trim();
+            if id.is_empty() {
+                return Err("Empty roster entry".to_string());
+            }
+            if let Some(stored) = registry.iter().find(|p| p.id == id) {
+                return Ok(stored_player(stored, adjustments));
+            }
+            ImportPlayer { uid: Some(id.to_string()), ..Default::default() }
+        }
+        ImportEntry::Player(player) => player.clone(),
+    };
+
+    let label = player.name.clone().or_else(|| player.uid.clone()).unwrap_or_default();
+    let condition = player.condition.unwrap_or_else(|| {
+        adjustments.push(SquadAdjustment::ConditionDefaulted {
+            player: label.clone(),
+            condition: DEFAULT_CONDITION,
+        });
+        DEFAULT_CONDITION
+    });
+
+    if let Some(uid) = player.uid {
+        return Ok(ImportedPlayer {
+            label,
+            key: uid.clone(),
+            position: None,
+            entry: RosterEntry::UidWithMeta(UidRosterEntry {
+                uid,
+                condition,
+                morale: None,
+                form: None,
+                injured_until: None,
+            }),
+        });
+    }
+
+    let name = player
+        .name
+        .filter(|n| !n.trim().is_empty())
+        .ok_or_else(|| "Roster entry needs a uid or a name".to_string())?;
+    let raw_position = player.position.unwrap_or_default();
+    let position = map_loose_position(&raw_position).unwrap_or(Position::MF);
+    if format!("{:?}", position) != raw_position.trim() {
+        adjustments.push(SquadAdjustment::PositionMapped {
+            player: name.clone(),
+            from: raw_position,
+            to: position,
+        });
+    }
+    let overall = player.overall.unwrap_or_else(|| {
+        adjustments.push(SquadAdjustment::OverallDefaulted {
+            player: name.clone(),
+            overall: DEFAULT_OVERALL,
+        });
+        DEFAULT_OVERALL
+    });
+
+    Ok(ImportedPlayer {
+        label: name.clone(),
+        key: name.clone(),
+        position: Some(position),
+        entry: embedded_entry(name, position, overall, condition),
+    })
+}
+
+fn stored_player(player: &CorePlayer, adjustments: &mut Vec<SquadAdjustment>) -> ImportedPlayer {
+    adjustments.push(SquadAdjustment::ConditionDefaulted {
+        player: player.name.clone(),
+        condition: DEFAULT_CONDITION,
+    });
+    ImportedPlayer {
+        label: player.name.clone(),
+        key: player.id.clone(),
+        position: Some(player.position),
+        entry: embedded_entry(
+            player.name.clone(),
+            player.position,
+            ca_to_overall(player.ca),
+            DEFAULT_CONDITION,
+        ),
+    }
+}
+
+fn embedded_entry(name: String, position: Position, overall: u8, condition: u8) -> RosterEntry {
+    RosterEntry::Embedded(EmbeddedPlayerData {
+        name,
+        position: format!("{:?}", position),
+        overall,
+        condition,
+        attributes: None,
+        track_id: None,
+        personality: None,
+        traits: None,
+        morale: None,
+        form: None,
+        injured_until: None,
+    })
+}
+
+/// Fill the bench to 7 from fit stored players, best CA first; a backup
+/// keeper is taken first when the bench has none
+fn fill_bench(
+    bench: &mut Vec<ImportedPlayer>,
+    registry: &[CorePlayer],
+    seen: &HashSet<String>,
+    adjustments: &mut Vec<SquadAdjustment>,
+) -> Result<(), String> {
+    let mut candidates: Vec<&CorePlayer> = registry
+        .iter()
+        .filter(|p| p.current_injury.is_none())
+        .filter(|p| !seen.contains(&p.id) && !seen.contains(&p.name))
+        .collect();
+    candidates.sort_by(|a, b| b.ca.cmp(&a.ca).then_with(|| a.id.cmp(&b.id)));
+
+    while bench.len() < BENCH {
+        if candidates.is_empty() {
+            return Err(format!(
+                "Bench needs {} more players but no stored players are available",
+                BENCH - bench.len()
+            ));
+        }
+        // Unknown (UID) positions might be keepers; only force one when none can be
+        let needs_keeper = !bench.iter().any(|p| p.position.is_none_or(|pos| pos.is_goalkeeper()));
+        let idx = needs_keeper
+            .then(|| candidates.iter().position(|p| p.position.is_goalkeeper()))
+            .flatten()
+            .unwrap_or(0);
+        let player = candidates.remove(idx);
+        adjustments.push(SquadAdjustment::BenchFilled {
+            player: player.name.clone(),
+            player_id: player.id.clone(),
+        });
+        bench.push(ImportedPlayer {
+            label: player.name.clone(),
+            key: player.id.clone(),
+            position: Some(player.position),
+            entry: embedded_entry(
+                player.name.clone(),
+                player.position,
+                ca_to_overall(player.ca),
+                DEFAULT_CONDITION,
+            ),
+        });
+    }
+    Ok(())
+}
+
+// ========== API Functions ==========
+
+/// Import a Godot team dictionary against the stored squad; returns `SquadImportResult` JSON
+pub fn import_squad_json(request_json: &str) -> Result<String, String> {
+    let request: SquadImportRequest =
+        serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
+
+    let result = import_squad(&request, &crate::state::get_state().players)?;
+
+    serde_json::to_string(&result).map_err(|e| format!("Failed to serialize squad import: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_map_loose_position() {
+        assert_eq!(map_loose_position("AMC"), Some(Position::CAM));
+        assert_eq!(map_loose_position("DM"), Some(Position::CDM));
+        assert_eq!(map_loose_position("D (C)"), Some(Position::CB));
+        assert_eq!(map_loose_position("AM (L), ST (C)"), Some(Position::LW));
+        assert_eq!(map_loose_position("striker"), Some(Position::ST));
+        assert_eq!(map_loose_position("cb"), Some(Position::CB));
+        assert_eq!(map_loose_position("??"), None);
+    }
+
+    #[test]
+    fn test_import_squad_adjusts_and_fills_bench() {
+        let mut keeper =
+            CorePlayer::create_average_player("Reserve GK".to_string(), Position::GK, 1);
+        keeper.id = "gk2".to_string();
+        let mut veteran = CorePlayer::create_average_player("Veteran".to_string(), Position::CM, 2);
+        veteran.id = "vet".to_string();
+        let mut youngster =
+            CorePlayer::create_average_player("Youngster".to_string(), Position::ST, 3);
+        youngster.id = "kid".to_string();
+        let registry = vec![keeper, veteran, youngster];
+
+        // 12 starters (keeper listed 4th), 4 bench: 16 players
+        let positions = ["DC", "DC", "DL", "G", "DR", "DM", "MC", "AMC", "AM (R)", "ST", "Striker"];
+        let mut starting_xi: Vec<_> = positions
+            .iter()
+            .enumerate()
+            .map(|(i, pos)| json!({"name": format!("P{i}"), "position": pos, "overall": 70, "condition": 3}))
+            .collect();
+        starting_xi.push(json!("vet"));
+        let bench: Vec<_> = (0..4)
+            .map(|i| json!({"name": format!("B{i}"), "position": "MF", "overall": 60}))
+            .collect();
+        let request: SquadImportRequest = serde_json::from_value(json!({
+            "name": "Imported FC",
+            "formation_id": "T4231",
+            "starting_xi": starting_xi,
+            "bench": bench
+        }))
+        .unwrap();
+
+        let result = import_squad(&request, &registry).unwrap();
+        let adjustments = &result.adjustments;
+        assert_eq!(result.team.formation, "4-2-3-1");
+        assert_eq!(result.team.roster.len(), 18);
+        assert!(adjustments.contains(&SquadAdjustment::MovedToBench { player: "Veteran".into() }));
+        assert!(
+            adjustments.contains(&SquadAdjustment::GoalkeeperMovedToSlot0 { player: "P3".into() })
+        );
+        assert!(adjustments.contains(&SquadAdjustment::PositionMapped {
+            player: "P7".into(),
+            from: "AMC".into(),
+            to: Position::CAM,
+        }));
+        // Two open bench slots, only the reserve keeper to fill them
+        let err = import_squad(&request, &registry[..1]).unwrap_err();
+        assert!(err.contains("Bench needs"), "{err}");
+        assert!(adjustments.contains(&SquadAdjustment::BenchFilled {
+            player: "Reserve GK".into(),
+            player_id: "gk2".into(),
+        }));
+        match &result.team.roster[0] {
+            RosterEntry::Embedded(p) => {
+                assert_eq!((p.name.as_str(), p.position.as_str()), ("P3", "GK"))
+            }
+            other => panic!("unexpected slot 0: {other:?}"),
+        }
+
+        // The imported team is a valid MatchRequestV2 side
+        let team = serde_json::to_value(&result.team).unwrap();
+        let match_request =
+            json!({"schema_version": 2, "seed": 5, "home_team": team, "away_team": team});
+        crate::api::json_api::match_plan_from_match_request_v2_json(&match_request.to_string())
+            .unwrap();
+
+        let short: SquadImportRequest =
+            serde_json::from_value(json!({"roster": ["a", "b", "c"]})).unwrap();
+        assert!(import_squad(&short, &registry).is_err());
+    }
+}
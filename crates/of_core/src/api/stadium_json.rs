@@ -0,0 +1,40 @@
+// Stadium JSON API Layer
+// Per-team home stadium registry (pitch dimensions + surface)
+
+use crate::models::stadium::Stadium;
+use serde::{Deserialize, Serialize};
+
+// ========== Request/Response Structures ==========
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RegisterStadiumRequest {
+    pub team: String,
+    pub stadium: Stadium,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetStadiumRequest {
+    pub team: String,
+}
+
+// ========== API Functions ==========
+
+/// Register (or replace) a team's home stadium in the saved registry
+pub fn register_stadium_json(request_json: &str) -> Result<String, String> {
+    let request: RegisterStadiumRequest =
+        serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
+
+    crate::state::get_state_mut().stadium_registry.register(&request.team, request.stadium)?;
+
+    Ok(r#"{"success":true}"#.to_string())
+}
+
+/// A team's registered stadium; returns `Stadium` JSON or `null`
+pub fn get_stadium_json(request_json: &str) -> Result<String, String> {
+    let request: GetStadiumRequest =
+        serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
+
+    let stadium = crate::state::get_state().stadium_registry.get(&request.team).cloned();
+
+    serde_json::to_string(&stadium).map_err(|e| format!("Failed to serialize stadium: {}", e))
+}
@@ -149,7 +149,7 @@ static STORY_ENGINE: Lazy<Mutex<Option<StoryEngine>>> = Lazy::new(|| Mutex::new(
 /// # Safety
 /// - null 포인터 체크
 /// - UTF-8 변환 실패 시 lossy 변환 사용
-fn safe_c_str_to_string(ptr: *const std::os::raw::c_char) -> Option<String> {
+pub(crate) fn safe_c_str_to_string(ptr: *const std::os::raw::c_char) -> Option<String> {
     if ptr.is_null() {
         return None;
     }
@@ -161,7 +161,7 @@ fn safe_c_str_to_string(ptr: *const std::os::raw::c_char) -> Option<String> {
 /// Rust String을 C 문자열 포인터로 안전하게 변환
 ///
 /// NUL 바이트가 있으면 제거 후 변환 (패닉 방지)
-fn safe_string_to_c(s: String) -> *mut std::os::raw::c_char {
+pub(crate) fn safe_string_to_c(s: String) -> *mut std::os::raw::c_char {
     // NUL 바이트 제거 (CString::new() 패닉 방지)
     let cleaned = s.replace('\0', "");
     match std::ffi::CString::new(cleaned) {
@@ -174,7 +174,7 @@ fn safe_string_to_c(s: String) -> *mut std::os::raw::c_char {
 }
 
 /// 에러 응답 JSON을 C 문자열로 변환하는 헬퍼
-fn error_response_c(error_msg: &str) -> *mut std::os::raw::c_char {
+pub(crate) fn error_response_c(error_msg: &str) -> *mut std::os::raw::c_char {
     let response = json!({
         "schema_version": 1,
         "success": false,
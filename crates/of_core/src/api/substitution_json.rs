@@ -0,0 +1,30 @@
+// Substitution Planner JSON API Layer
+// Half-time substitution preview (recommendations only; apply via substitute())
+
+use crate::engine::substitution_planner::{
+    plan_substitutions, SubstitutionPolicy, SubstitutionSquadState,
+};
+use serde::{Deserialize, Serialize};
+
+// ========== Request/Response Structures ==========
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PlanSubstitutionsRequest {
+    /// Team snapshot (from a live session or a finished result)
+    pub state: SubstitutionSquadState,
+    #[serde(default)]
+    pub policy: SubstitutionPolicy,
+}
+
+// ========== API Functions ==========
+
+/// Recommend substitutions for one team; returns `SubstitutionPlan` JSON
+pub fn plan_substitutions_json(request_json: &str) -> Result<String, String> {
+    let request: PlanSubstitutionsRequest =
+        serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
+
+    let plan = plan_substitutions(&request.state, &request.policy);
+
+    serde_json::to_string(&plan)
+        .map_err(|e| format!("Failed to serialize substitution plan: {}", e))
+}
@@ -0,0 +1,36 @@
+// Tournament JSON API Layer
+// Whole-tournament simulation: bracket, results and top scorers in one call
+
+use super::json_api::{convert_team_v2, TeamDataV2};
+use crate::tournament::{simulate_tournament, TieRules, TournamentFormat};
+use serde::{Deserialize, Serialize};
+
+// ========== Request/Response Structures ==========
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SimulateTournamentRequest {
+    pub teams: Vec<TeamDataV2>,
+    #[serde(default)]
+    pub seed: u64,
+    #[serde(default)]
+    pub format: TournamentFormat,
+    #[serde(default)]
+    pub tie_rules: TieRules,
+}
+
+// ========== API Functions ==========
+
+/// Simulate a full tournament; returns `TournamentResult` JSON
+pub fn simulate_tournament_json(request_json: &str) -> Result<String, String> {
+    let request: SimulateTournamentRequest =
+        serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
+
+    let teams = request
+        .teams
+        .into_iter()
+        .map(|data| convert_team_v2(data, false).map(|(team, _, _)| team))
+        .collect::<Result<Vec<_>, _>>()?;
+    let result = simulate_tournament(&teams, request.format, request.seed, request.tie_rules)?;
+
+    serde_json::to_string(&result).map_err(|e| format!("Failed to serialize tournament: {}", e))
+}
@@ -1,4 +1,5 @@
 // 훈련 시스템 JSON API
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_json;
 
@@ -10,7 +11,7 @@ use crate::training::{
 };
 
 /// 훈련 요청 - Godot에서 전송
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, JsonSchema)]
 pub struct TrainingRequest {
     pub schema_version: u8,
     pub request_type: TrainingRequestType,
@@ -20,7 +21,7 @@ pub struct TrainingRequest {
     pub active_deck: Option<Deck>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, JsonSchema)]
 #[serde(tag = "type")]
 pub enum TrainingRequestType {
     /// 주간 계획 요청
@@ -66,13 +67,13 @@ pub enum TrainingRequestType {
     UpdateDaySlot { week_number: u16, day: String, slots: Vec<DaySlotInput> },
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, JsonSchema)]
 pub struct DayScheduleInput {
     pub day: String,
     pub slots: Vec<DaySlotInput>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, JsonSchema)]
 #[serde(tag = "type")]
 pub enum DaySlotInput {
     TeamTraining { target: String },
@@ -306,6 +307,9 @@ impl PlayerInput {
             current_injury: None,
             injury_history: Vec::new(),
             injury_proneness: 0.1, // 기본 10% 부상 성향
+            morale: crate::player::morale::NEUTRAL_MORALE,
+            form: crate::player::morale::NEUTRAL_FORM,
+            medical: Default::default(),
             created_at: now,
             updated_at: now,
             career_stats: PlayerCareerStats::new(),
@@ -0,0 +1,108 @@
+// What-If JSON API Layer
+// Resimulates the rest of a replayed match from a chosen moment under alternative seeds
+
+use super::json_api::{match_plan_from_match_request_v2, MatchRequestV2};
+use crate::engine::{
+    capture_keyframe, resimulate_from_keyframe, MatchStateSnapshot, WhatIfDistribution,
+};
+use serde::{Deserialize, Serialize};
+
+// ========== Request/Response Structures ==========
+
+#[derive(Debug, Deserialize)]
+pub struct WhatIfRequest {
+    /// Match request (incl. seed) that produced the replay
+    pub match_request: MatchRequestV2,
+    /// Replay moment to branch from (ms since kick-off)
+    pub timestamp_ms: u64,
+    pub seeds: Vec<u64>,
+    /// Replace the score at the branch point, e.g. `[1, 0]` for "that shot went in"
+    #[serde(default)]
+    pub score_override: Option<(u8, u8)>,
+    /// Keyframe returned by a previous call (skips re-streaming to `timestamp_ms`)
+    #[serde(default)]
+    pub keyframe: Option<MatchStateSnapshot>,
+    #[serde(default)]
+    pub include_keyframe: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WhatIfResponse {
+    #[serde(flatten)]
+    pub distribution: WhatIfDistribution,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keyframe: Option<MatchStateSnapshot>,
+}
+
+// ========== API Functions ==========
+
+/// Branch the replay at `timestamp_ms`; returns `WhatIfResponse` JSON
+pub fn simulate_what_if_json(request_json: &str) -> Result<String, String> {
+    let request: WhatIfRequest =
+        serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
+
+    let (plan, _enable_position_tracking) =
+        match_plan_from_match_request_v2(request.match_request)?;
+    let keyframe = match request.keyframe {
+        Some(keyframe) => keyframe,
+        None => capture_keyframe(&plan, request.timestamp_ms)?,
+    };
+    let distribution =
+        resimulate_from_keyframe(&plan, &keyframe, &request.seeds, request.score_override)?;
+
+    let response =
+        WhatIfResponse { distribution, keyframe: request.include_keyframe.then_some(keyframe) };
+    serde_json::to_string(&response).map_err(|e| format!("Failed to serialize response: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn team_json(prefix: &str) -> serde_json::Value {
+        let positions = [
+            "GK", "LB", "CB", "CB", "RB", "LM", "CM", "CM", "RM", "ST", "ST", "GK", "CB", "CM",
+            "CM", "LW", "RW", "ST",
+        ];
+        let roster: Vec<_> = positions
+            .iter()
+            .enumerate()
+            .map(|(i, pos)| {
+                json!({"name": format!("{prefix}{i}"), "position": pos, "overall": 70, "condition": 3})
+            })
+            .collect();
+        json!({"name": prefix, "formation": "4-4-2", "roster": roster})
+    }
+
+    #[test]
+    fn test_returned_keyframe_reproduces_branches() {
+        let match_request = json!({
+            "schema_version": 2,
+            "seed": 4,
+            "home_team": team_json("H"),
+            "away_team": team_json("A")
+        });
+        let request = json!({
+            "match_request": match_request,
+            "timestamp_ms": 89 * 60_000,
+            "seeds": [7, 8],
+            "include_keyframe": true
+        });
+        let first: serde_json::Value =
+            serde_json::from_str(&simulate_what_if_json(&request.to_string()).unwrap()).unwrap();
+        assert_eq!(first["branch_minute"], 89);
+        assert_eq!(first["branches"].as_array().unwrap().len(), 2);
+
+        let request = json!({
+            "match_request": match_request,
+            "timestamp_ms": 0,
+            "seeds": [7, 8],
+            "keyframe": first["keyframe"]
+        });
+        let second: serde_json::Value =
+            serde_json::from_str(&simulate_what_if_json(&request.to_string()).unwrap()).unwrap();
+        assert_eq!(first["branches"], second["branches"]);
+        assert!(second.get("keyframe").is_none());
+    }
+}
@@ -0,0 +1,39 @@
+// Youth Tournament JSON API Layer
+// Deterministic story-mode youth opponents keyed by chapter id
+
+use crate::story::youth_tournament::{
+    generate_youth_bracket, generate_youth_opponent, YouthAgeGroup, YouthOpponentRequest,
+};
+use serde::{Deserialize, Serialize};
+
+// ========== Request/Response Structures ==========
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct YouthBracketRequest {
+    pub chapter_id: String,
+    #[serde(default)]
+    pub age_group: YouthAgeGroup,
+    pub count: usize,
+}
+
+// ========== API Functions ==========
+
+/// Generate one youth opponent; returns `YouthOpponent` JSON
+pub fn generate_youth_opponent_json(request_json: &str) -> Result<String, String> {
+    let request: YouthOpponentRequest =
+        serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
+
+    let opponent = generate_youth_opponent(&request)?;
+
+    serde_json::to_string(&opponent).map_err(|e| format!("Failed to serialize opponent: {}", e))
+}
+
+/// Generate a chapter's bracket of opponents from distinct regions
+pub fn generate_youth_bracket_json(request_json: &str) -> Result<String, String> {
+    let request: YouthBracketRequest =
+        serde_json::from_str(request_json).map_err(|e| format!("Invalid JSON request: {}", e))?;
+
+    let bracket = generate_youth_bracket(&request.chapter_id, request.age_group, request.count)?;
+
+    serde_json::to_string(&bracket).map_err(|e| format!("Failed to serialize bracket: {}", e))
+}
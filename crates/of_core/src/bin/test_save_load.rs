@@ -138,6 +138,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             date: 1640995200 + (i as u64 * 86400), // timestamp starting from 2022-01-01
             week: ((i % 38) + 1) as u16,
             season: ((i / 200) + 1) as u16,
+            appearances: Vec::new(),
+            highlights: Vec::new(),
         });
     }
 
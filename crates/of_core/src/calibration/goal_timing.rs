@@ -0,0 +1,218 @@
+//! Goal Timing - Goals per 5-minute bucket
+//!
+//! Real league data shows goal frequency rising through a match, with
+//! spikes in the 40-45+ and 85-90+ buckets (fatigue, stoppage time and
+//! trailing teams throwing bodies forward). This module holds the anchor
+//! distribution and a histogram collected from simulated matches so late
+//! goal realism (and the DPER `desperation` mode) can be tuned against it.
+//!
+//! Stoppage-time goals fold into the last bucket of their half, and
+//! second-half minutes are re-based on the `HalfTime` event so first-half
+//! added time does not shift buckets.
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::{EventType, MatchResult};
+
+/// Number of 5-minute buckets over 90 minutes
+pub const GOAL_TIMING_BUCKETS: usize = 18;
+
+/// First bucket counted as "late" (85'+)
+pub const LATE_BUCKET: usize = 17;
+
+/// Target share of goals per 5-minute bucket (sums to 1.0)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GoalTimingTargets {
+    pub shares: [f32; GOAL_TIMING_BUCKETS],
+    /// Max absolute share deviation per bucket still considered in range
+    pub tolerance: f32,
+}
+
+impl GoalTimingTargets {
+    /// Top-flight European league average (multi-season, stoppage folded)
+    pub fn reference() -> Self {
+        Self {
+            shares: [
+                0.038, 0.045, 0.048, 0.049, 0.050, 0.051, 0.052, 0.053, 0.064, // 1H
+                0.052, 0.054, 0.055, 0.056, 0.057, 0.058, 0.060, 0.062, 0.096, // 2H
+            ],
+            tolerance: 0.02,
+        }
+    }
+
+    /// Target share of goals scored from 85' onward
+    pub fn late_share(&self) -> f32 {
+        self.shares[LATE_BUCKET..].iter().sum()
+    }
+
+    /// Target share of goals scored in the second half
+    pub fn second_half_share(&self) -> f32 {
+        self.shares[GOAL_TIMING_BUCKETS / 2..].iter().sum()
+    }
+}
+
+impl Default for GoalTimingTargets {
+    fn default() -> Self {
+        Self::reference()
+    }
+}
+
+/// Goal counts per 5-minute bucket
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GoalTimingHistogram {
+    pub counts: [u32; GOAL_TIMING_BUCKETS],
+    pub matches: u32,
+}
+
+impl GoalTimingHistogram {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Bucket index for a match-clock minute
+    ///
+    /// `half_time_minute` is the engine minute of the `HalfTime` event (first
+    /// half end incl. added time); pass `None` for already re-based minutes.
+    pub fn bucket_for(minute: u8, half_time_minute: Option<u8>) -> usize {
+        let minute = minute as usize;
+        match half_time_minute {
+            Some(ht) if minute < ht as usize => minute.min(44) / 5,
+            Some(ht) => (45 + (minute - ht as usize)).clamp(45, 89) / 5,
+            None => minute.min(89) / 5,
+        }
+    }
+
+    /// Record a goal at an already re-based minute (0-based, stoppage folded)
+    pub fn record_minute(&mut self, minute: u8) {
+        self.counts[Self::bucket_for(minute, None)] += 1;
+    }
+
+    /// Add all goals (incl. own goals) of a simulated match
+    pub fn add_match(&mut self, result: &MatchResult) {
+        let half_time =
+            result.events.iter().find(|e| e.event_type == EventType::HalfTime).map(|e| e.minute);
+        for event in &result.events {
+            if matches!(event.event_type, EventType::Goal | EventType::OwnGoal) {
+                self.counts[Self::bucket_for(event.minute, half_time)] += 1;
+            }
+        }
+        self.matches += 1;
+    }
+
+    pub fn from_results(results: &[MatchResult]) -> Self {
+        let mut histogram = Self::new();
+        for result in results {
+            histogram.add_match(result);
+        }
+        histogram
+    }
+
+    pub fn total_goals(&self) -> u32 {
+        self.counts.iter().sum()
+    }
+
+    /// Share of goals per bucket (all zero when no goals)
+    pub fn shares(&self) -> [f32; GOAL_TIMING_BUCKETS] {
+        let total = self.total_goals();
+        let mut shares = [0.0; GOAL_TIMING_BUCKETS];
+        if total > 0 {
+            for (share, &count) in shares.iter_mut().zip(self.counts.iter()) {
+                *share = count as f32 / total as f32;
+            }
+        }
+        shares
+    }
+
+    /// Share of goals from 85' onward
+    pub fn late_share(&self) -> f32 {
+        self.shares()[LATE_BUCKET..].iter().sum()
+    }
+
+    /// Compare against anchor targets
+    pub fn compare(&self, targets: &GoalTimingTargets) -> GoalTimingReport {
+        let shares = self.shares();
+        let deviations: Vec<f32> =
+            shares.iter().zip(targets.shares.iter()).map(|(s, t)| s - t).collect();
+        let max_abs_deviation = deviations.iter().fold(0.0f32, |acc, d| acc.max(d.abs()));
+        let out_of_range_buckets = deviations
+            .iter()
+            .enumerate()
+            .filter(|(_, d)| d.abs() > targets.tolerance)
+            .map(|(i, _)| i)
+            .collect();
+
+        GoalTimingReport {
+            total_goals: self.total_goals(),
+            matches: self.matches,
+            deviations,
+            max_abs_deviation,
+            out_of_range_buckets,
+            late_share: self.late_share(),
+            target_late_share: targets.late_share(),
+        }
+    }
+}
+
+/// Histogram vs. anchor comparison
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoalTimingReport {
+    pub total_goals: u32,
+    pub matches: u32,
+    /// Simulated minus target share, per bucket
+    pub deviations: Vec<f32>,
+    pub max_abs_deviation: f32,
+    /// Buckets whose deviation exceeds the tolerance
+    pub out_of_range_buckets: Vec<usize>,
+    pub late_share: f32,
+    pub target_late_share: f32,
+}
+
+impl GoalTimingReport {
+    pub fn is_within_tolerance(&self) -> bool {
+        self.out_of_range_buckets.is_empty()
+    }
+
+    /// Late (85'+) share shortfall; positive = too few late goals
+    pub fn late_share_gap(&self) -> f32 {
+        self.target_late_share - self.late_share
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reference_targets_sum_to_one() {
+        let targets = GoalTimingTargets::reference();
+        let total: f32 = targets.shares.iter().sum();
+        assert!((total - 1.0).abs() < 1e-3, "total {}", total);
+        assert!(targets.second_half_share() > 0.5);
+        assert!(targets.late_share() > targets.shares[9]);
+    }
+
+    #[test]
+    fn test_bucket_folds_stoppage_time() {
+        // 1H added time (HalfTime at engine minute 47)
+        assert_eq!(GoalTimingHistogram::bucket_for(46, Some(47)), 8);
+        // 2H minutes re-based: engine 47 == 45'
+        assert_eq!(GoalTimingHistogram::bucket_for(47, Some(47)), 9);
+        assert_eq!(GoalTimingHistogram::bucket_for(87, Some(47)), 17);
+        assert_eq!(GoalTimingHistogram::bucket_for(96, Some(47)), 17);
+        assert_eq!(GoalTimingHistogram::bucket_for(93, None), 17);
+    }
+
+    #[test]
+    fn test_compare_flags_missing_late_goals() {
+        let mut histogram = GoalTimingHistogram::new();
+        for minute in (0..85).step_by(5) {
+            histogram.record_minute(minute);
+        }
+        let report = histogram.compare(&GoalTimingTargets::reference());
+        assert_eq!(report.total_goals, 17);
+        assert_eq!(report.late_share, 0.0);
+        assert!(report.late_share_gap() > 0.09);
+        assert!(report.out_of_range_buckets.contains(&LATE_BUCKET));
+        assert!(!report.is_within_tolerance());
+    }
+}
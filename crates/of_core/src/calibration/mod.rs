@@ -9,6 +9,9 @@
 //! - StatSnapshot (collects per-match statistics)
 //! - PassClassifier (categorizes passes by type)
 //! - Scenarios (GRF-style micro-tests for bug reproduction)
+//! - GoalTiming (goals per 5-minute bucket, late-goal realism)
+//! - Suite (batch distribution validation against target ranges)
+//! - ParameterSet (designer-facing engine tunables for A/B runs)
 
 pub mod zone;
 pub mod anchor_table;
@@ -18,6 +21,9 @@ pub mod pass_classifier;
 pub mod scenarios;
 pub mod scenario_runner;
 pub mod symmetry_runner;
+pub mod goal_timing;
+pub mod suite;
+pub mod parameter_set;
 
 pub use zone::{
     ZoneId, ZoneSchema, pos_to_zone, pos_to_zone_for_team,
@@ -41,4 +47,10 @@ pub use stat_snapshot::{
 pub use pass_classifier::{PassType, classify_pass, classify_pass_detailed, PassClassification, NormPos, ClassifierThresholds};
 pub use scenarios::{TestScenario, ScenarioSetup, ScenarioResult, SuccessCondition, SymmetryVariant};
 pub use scenario_runner::ScenarioRunner;
+pub use goal_timing::{GoalTimingHistogram, GoalTimingReport, GoalTimingTargets};
 pub use symmetry_runner::{SymmetryMetaRunner, SymmetryReport, SymmetryViolation, SymmetryStats, ViolationType};
+pub use suite::{
+    run_calibration_suite, CalibrationReport, CalibrationSuiteConfig, CalibrationTargets,
+    MetricCheck, MetricRange,
+};
+pub use parameter_set::ParameterSet;
@@ -0,0 +1,114 @@
+//! ParameterSet - Designer-facing engine tunables
+//!
+//! Global multipliers (both teams) over a handful of engine constants so
+//! parameter sets can be A/B tested without recompiling:
+//! - pressing efficiency: tackle/interception success when winning the ball back
+//! - pass error: execution-error spread on the intended receiver's first touch
+//! - shot conversion: Goal weight in shot outcome selection
+//! - fatigue rate: per-tick and per-action stamina drain
+//!
+//! All 1.0 = shipped engine behaviour (bit-identical results). `param_hash()`
+//! is recorded in `MatchResult.parameter_hash` so a result can be reproduced.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Accepted range for every multiplier
+pub const PARAMETER_MIN: f32 = 0.25;
+pub const PARAMETER_MAX: f32 = 4.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ParameterSet {
+    /// Tackle/interception success multiplier (>1 = pressing wins the ball more)
+    pub pressing_efficiency: f32,
+    /// First-touch error sigma multiplier on pass reception (>1 = sloppier passing)
+    pub pass_error: f32,
+    /// Goal weight multiplier in shot outcome selection (>1 = more shots go in)
+    pub shot_conversion: f32,
+    /// Stamina drain multiplier (>1 = players tire faster)
+    pub fatigue_rate: f32,
+}
+
+impl Default for ParameterSet {
+    fn default() -> Self {
+        Self { pressing_efficiency: 1.0, pass_error: 1.0, shot_conversion: 1.0, fatigue_rate: 1.0 }
+    }
+}
+
+impl ParameterSet {
+    pub fn from_json_str(json: &str) -> Result<Self, String> {
+        let params: Self =
+            serde_json::from_str(json).map_err(|e| format!("Invalid parameter set: {}", e))?;
+        params.validate()?;
+        Ok(params)
+    }
+
+    /// Reject non-finite or out-of-range multipliers
+    pub fn validate(&self) -> Result<(), String> {
+        for (name, value) in self.named_values() {
+            if !value.is_finite() || !(PARAMETER_MIN..=PARAMETER_MAX).contains(&value) {
+                return Err(format!(
+                    "{} must be within [{}, {}], got {}",
+                    name, PARAMETER_MIN, PARAMETER_MAX, value
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// True when every multiplier is 1.0 (shipped behaviour)
+    pub fn is_neutral(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Stable sha256 over the multipliers (first 16 hex chars)
+    pub fn param_hash(&self) -> String {
+        let canonical = self
+            .named_values()
+            .iter()
+            .map(|(name, value)| format!("{}={:.6}", name, value))
+            .collect::<Vec<_>>()
+            .join(";");
+        let mut hasher = Sha256::new();
+        hasher.update(canonical.as_bytes());
+        let hex = format!("{:x}", hasher.finalize());
+        hex[..16].to_string()
+    }
+
+    fn named_values(&self) -> [(&'static str, f32); 4] {
+        [
+            ("pressing_efficiency", self.pressing_efficiency),
+            ("pass_error", self.pass_error),
+            ("shot_conversion", self.shot_conversion),
+            ("fatigue_rate", self.fatigue_rate),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partial_json_keeps_defaults_and_validates_range() {
+        let params = ParameterSet::from_json_str(r#"{"shot_conversion": 1.3}"#).unwrap();
+        assert_eq!(params.shot_conversion, 1.3);
+        assert_eq!(params.fatigue_rate, 1.0);
+        assert!(!params.is_neutral());
+        assert!(ParameterSet::default().is_neutral());
+
+        assert!(ParameterSet::from_json_str(r#"{"pass_error": 0.0}"#).is_err());
+        assert!(ParameterSet::from_json_str(r#"{"fatigue_rate": 9.0}"#).is_err());
+    }
+
+    #[test]
+    fn test_param_hash_is_stable_and_sensitive() {
+        let base = ParameterSet::default();
+        assert_eq!(base.param_hash(), ParameterSet::default().param_hash());
+        assert_eq!(base.param_hash().len(), 16);
+
+        let tweaked = ParameterSet { pressing_efficiency: 1.1, ..base };
+        assert_ne!(base.param_hash(), tweaked.param_hash());
+    }
+}
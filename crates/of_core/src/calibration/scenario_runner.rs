@@ -54,12 +54,24 @@ impl ScenarioRunner {
             user_player: None,
             home_match_modifiers: Default::default(),
             away_match_modifiers: Default::default(),
+            modifier_stack: Default::default(),
             home_instructions: None,
             away_instructions: None,
             home_player_instructions: None,
             away_player_instructions: None,
             home_ai_difficulty: None,
             away_ai_difficulty: None,
+            pitch: crate::models::pitch::PitchSpec::default(),
+            home_chemistry: None,
+            away_chemistry: None,
+            stadium: None,
+            conditions: None,
+            penalty_shootout: None,
+            home_custom_formation: None,
+            away_custom_formation: None,
+            home_set_piece_takers: None,
+            away_set_piece_takers: None,
+            referee: None,
         };
 
         // Create MatchEngine
@@ -176,6 +188,8 @@ impl ScenarioRunner {
                 equipped_skills: Vec::new(),
                 traits: Default::default(),
                 personality: Default::default(),
+                morale: crate::player::morale::NEUTRAL_MORALE,
+                form: crate::player::morale::NEUTRAL_FORM,
             });
         }
 
@@ -202,6 +216,8 @@ impl ScenarioRunner {
                 equipped_skills: Vec::new(),
                 traits: Default::default(),
                 personality: Default::default(),
+                morale: crate::player::morale::NEUTRAL_MORALE,
+                form: crate::player::morale::NEUTRAL_FORM,
             });
         }
 
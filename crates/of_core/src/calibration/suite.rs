@@ -0,0 +1,332 @@
+//! Calibration Suite - Distribution validation against target stats
+//!
+//! Simulates a batch of seeded matches and checks per-match averages and the
+//! total-goals distribution against configurable target ranges. Targets load
+//! from JSON (same shape as `CalibrationTargets`); the report serializes to
+//! JSON with pass/fail per metric so CI can gate on it.
+//!
+//! Same config → same report (matches run in parallel, tallied in seed order).
+
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::engine::{MatchEngine, MatchPlan};
+use crate::models::{MatchResult, Team};
+
+/// Total-goals buckets for the distribution check (last bucket is "5 or more")
+pub const GOAL_DISTRIBUTION_BUCKETS: usize = 6;
+
+/// Inclusive accepted range for one metric
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MetricRange {
+    pub min: f32,
+    pub max: f32,
+}
+
+impl MetricRange {
+    pub const fn new(min: f32, max: f32) -> Self {
+        Self { min, max }
+    }
+
+    pub fn contains(&self, value: f32) -> bool {
+        value >= self.min && value <= self.max
+    }
+}
+
+/// Target ranges per metric (per match, both teams); `None` skips the metric
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CalibrationTargets {
+    #[serde(default)]
+    pub goals_per_match: Option<MetricRange>,
+    /// Share of matches with 0, 1, .., 5+ total goals
+    #[serde(default)]
+    pub goal_distribution: Option<[MetricRange; GOAL_DISTRIBUTION_BUCKETS]>,
+    #[serde(default)]
+    pub shots_per_match: Option<MetricRange>,
+    #[serde(default)]
+    pub shots_on_target_per_match: Option<MetricRange>,
+    /// Mean |home - away| possession in percentage points
+    #[serde(default)]
+    pub possession_spread: Option<MetricRange>,
+    #[serde(default)]
+    pub fouls_per_match: Option<MetricRange>,
+    #[serde(default)]
+    pub yellow_cards_per_match: Option<MetricRange>,
+    #[serde(default)]
+    pub red_cards_per_match: Option<MetricRange>,
+}
+
+impl CalibrationTargets {
+    /// Top-5 European league ranges (qa_baseline.json, widened for sim variance)
+    pub fn reference() -> Self {
+        Self {
+            goals_per_match: Some(MetricRange::new(2.2, 3.4)),
+            goal_distribution: Some([
+                MetricRange::new(0.03, 0.13),
+                MetricRange::new(0.12, 0.24),
+                MetricRange::new(0.17, 0.29),
+                MetricRange::new(0.16, 0.28),
+                MetricRange::new(0.09, 0.21),
+                MetricRange::new(0.07, 0.21),
+            ]),
+            shots_per_match: Some(MetricRange::new(20.0, 30.0)),
+            shots_on_target_per_match: Some(MetricRange::new(7.0, 11.0)),
+            possession_spread: Some(MetricRange::new(5.0, 30.0)),
+            fouls_per_match: Some(MetricRange::new(18.0, 30.0)),
+            yellow_cards_per_match: Some(MetricRange::new(2.5, 5.5)),
+            red_cards_per_match: Some(MetricRange::new(0.0, 0.4)),
+        }
+    }
+
+    pub fn from_json_str(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| format!("Invalid calibration targets: {}", e))
+    }
+}
+
+impl Default for CalibrationTargets {
+    fn default() -> Self {
+        Self::reference()
+    }
+}
+
+/// What to simulate and what to compare against
+#[derive(Debug, Clone)]
+pub struct CalibrationSuiteConfig {
+    pub home_team: Team,
+    pub away_team: Team,
+    pub matches: u32,
+    /// Match `i` runs with seed `base_seed + i`
+    pub base_seed: u64,
+    pub targets: CalibrationTargets,
+}
+
+impl CalibrationSuiteConfig {
+    pub fn new(home_team: Team, away_team: Team) -> Self {
+        Self {
+            home_team,
+            away_team,
+            matches: 1000,
+            base_seed: 0,
+            targets: CalibrationTargets::reference(),
+        }
+    }
+}
+
+/// One metric vs. its target range
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MetricCheck {
+    pub metric: String,
+    pub value: f32,
+    pub target: MetricRange,
+    pub passed: bool,
+}
+
+/// Machine-readable suite outcome
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CalibrationReport {
+    pub matches: u32,
+    pub base_seed: u64,
+    pub checks: Vec<MetricCheck>,
+    pub passed: bool,
+}
+
+impl CalibrationReport {
+    pub fn failed_checks(&self) -> impl Iterator<Item = &MetricCheck> {
+        self.checks.iter().filter(|c| !c.passed)
+    }
+
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| format!("Failed to serialize report: {}", e))
+    }
+}
+
+/// Per-match stat totals (both teams)
+#[derive(Debug, Clone, Copy, Default)]
+struct MatchSample {
+    goals: u32,
+    shots: u32,
+    shots_on_target: u32,
+    possession_spread: f32,
+    fouls: u32,
+    yellow_cards: u32,
+    red_cards: u32,
+}
+
+impl MatchSample {
+    fn from_result(result: &MatchResult) -> Self {
+        let stats = &result.statistics;
+        Self {
+            goals: result.score_home as u32 + result.score_away as u32,
+            shots: stats.shots_home as u32 + stats.shots_away as u32,
+            shots_on_target: stats.shots_on_target_home as u32 + stats.shots_on_target_away as u32,
+            possession_spread: (stats.possession_home - stats.possession_away).abs(),
+            fouls: stats.fouls_home as u32 + stats.fouls_away as u32,
+            yellow_cards: stats.yellow_cards_home as u32 + stats.yellow_cards_away as u32,
+            red_cards: stats.red_cards_home as u32 + stats.red_cards_away as u32,
+        }
+    }
+}
+
+/// Simulate `config.matches` matches and check them against `config.targets`
+pub fn run_calibration_suite(config: &CalibrationSuiteConfig) -> Result<CalibrationReport, String> {
+    if config.matches == 0 {
+        return Err("Calibration suite needs at least one match".to_string());
+    }
+
+    let samples: Vec<MatchSample> = (0..config.matches as u64)
+        .into_par_iter()
+        .map(|i| {
+            let plan = match_plan(config, config.base_seed.wrapping_add(i));
+            let result = MatchEngine::new(plan)?.simulate();
+            Ok(MatchSample::from_result(&result))
+        })
+        .collect::<Result<_, String>>()?;
+
+    Ok(evaluate(&samples, config))
+}
+
+fn evaluate(samples: &[MatchSample], config: &CalibrationSuiteConfig) -> CalibrationReport {
+    let n = samples.len().max(1) as f32;
+    let mean = |f: fn(&MatchSample) -> f32| samples.iter().map(f).sum::<f32>() / n;
+    let targets = &config.targets;
+
+    let mut checks = Vec::new();
+    let mut check = |metric: &str, value: f32, target: Option<MetricRange>| {
+        if let Some(target) = target {
+            checks.push(MetricCheck {
+                metric: metric.to_string(),
+                value,
+                target,
+                passed: target.contains(value),
+            });
+        }
+    };
+
+    check("goals_per_match", mean(|s| s.goals as f32), targets.goals_per_match);
+    if let Some(ranges) = &targets.goal_distribution {
+        let mut counts = [0u32; GOAL_DISTRIBUTION_BUCKETS];
+        for s in samples {
+            counts[(s.goals as usize).min(GOAL_DISTRIBUTION_BUCKETS - 1)] += 1;
+        }
+        for (goals, (&count, &range)) in counts.iter().zip(ranges.iter()).enumerate() {
+            let label = if goals == GOAL_DISTRIBUTION_BUCKETS - 1 {
+                format!("goal_distribution.{}+", goals)
+            } else {
+                format!("goal_distribution.{}", goals)
+            };
+            check(&label, count as f32 / n, Some(range));
+        }
+    }
+    check("shots_per_match", mean(|s| s.shots as f32), targets.shots_per_match);
+    check(
+        "shots_on_target_per_match",
+        mean(|s| s.shots_on_target as f32),
+        targets.shots_on_target_per_match,
+    );
+    check("possession_spread", mean(|s| s.possession_spread), targets.possession_spread);
+    check("fouls_per_match", mean(|s| s.fouls as f32), targets.fouls_per_match);
+    check(
+        "yellow_cards_per_match",
+        mean(|s| s.yellow_cards as f32),
+        targets.yellow_cards_per_match,
+    );
+    check("red_cards_per_match", mean(|s| s.red_cards as f32), targets.red_cards_per_match);
+
+    let passed = checks.iter().all(|c| c.passed);
+    CalibrationReport { matches: samples.len() as u32, base_seed: config.base_seed, checks, passed }
+}
+
+fn match_plan(config: &CalibrationSuiteConfig, seed: u64) -> MatchPlan {
+    MatchPlan {
+        home_team: config.home_team.clone(),
+        away_team: config.away_team.clone(),
+        seed,
+        user_player: None,
+        home_match_modifiers: Default::default(),
+        away_match_modifiers: Default::default(),
+        modifier_stack: Default::default(),
+        home_instructions: None,
+        away_instructions: None,
+        home_player_instructions: None,
+        away_player_instructions: None,
+        home_ai_difficulty: None,
+        away_ai_difficulty: None,
+        pitch: crate::models::pitch::PitchSpec::default(),
+        home_chemistry: None,
+        away_chemistry: None,
+        stadium: None,
+        conditions: None,
+        penalty_shootout: None,
+        home_custom_formation: None,
+        away_custom_formation: None,
+        home_set_piece_takers: None,
+        away_set_piece_takers: None,
+        referee: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::match_sim::test_fixtures::create_test_team;
+
+    fn sample(goals: u32, shots: u32) -> MatchSample {
+        MatchSample { goals, shots, ..Default::default() }
+    }
+
+    #[test]
+    fn test_evaluate_flags_out_of_range_metrics() {
+        let mut config = CalibrationSuiteConfig::new(create_test_team("H"), create_test_team("A"));
+        config.targets = CalibrationTargets {
+            goals_per_match: Some(MetricRange::new(2.0, 3.0)),
+            shots_per_match: Some(MetricRange::new(20.0, 30.0)),
+            ..CalibrationTargets::from_json_str("{}").unwrap()
+        };
+        let report = evaluate(&[sample(1, 12), sample(5, 14)], &config);
+
+        assert_eq!(report.checks.len(), 2);
+        assert!(report.checks[0].passed, "mean goals 3.0 is inside [2, 3]");
+        assert_eq!(report.checks[1].metric, "shots_per_match");
+        assert!(!report.checks[1].passed);
+        assert!(!report.passed);
+        assert_eq!(report.failed_checks().count(), 1);
+    }
+
+    #[test]
+    fn test_goal_distribution_buckets_high_scoring_matches() {
+        let mut config = CalibrationSuiteConfig::new(create_test_team("H"), create_test_team("A"));
+        config.targets = CalibrationTargets::from_json_str(
+            r#"{"goal_distribution": [
+                {"min": 0.0, "max": 1.0}, {"min": 0.0, "max": 1.0}, {"min": 0.0, "max": 1.0},
+                {"min": 0.0, "max": 1.0}, {"min": 0.0, "max": 1.0}, {"min": 0.5, "max": 0.5}
+            ]}"#,
+        )
+        .unwrap();
+        let report = evaluate(&[sample(0, 0), sample(7, 0)], &config);
+
+        let last = report.checks.last().unwrap();
+        assert_eq!(last.metric, "goal_distribution.5+");
+        assert!((last.value - 0.5).abs() < 1e-6);
+        assert!(report.passed);
+    }
+
+    #[test]
+    fn test_suite_is_deterministic_and_report_serializes() {
+        let mut config = CalibrationSuiteConfig::new(create_test_team("H"), create_test_team("A"));
+        config.matches = 2;
+        config.base_seed = 5;
+
+        let a = run_calibration_suite(&config).unwrap();
+        let b = run_calibration_suite(&config).unwrap();
+        assert_eq!(a, b);
+        assert_eq!(a.matches, 2);
+        assert_eq!(a.checks.len(), 13);
+
+        let json: serde_json::Value = serde_json::from_str(&a.to_json().unwrap()).unwrap();
+        assert_eq!(json["checks"][0]["metric"], "goals_per_match");
+        assert!(json["passed"].is_boolean());
+
+        config.matches = 0;
+        assert!(run_calibration_suite(&config).is_err());
+    }
+}
@@ -0,0 +1,381 @@
+//! Fixture calendar and congestion effects
+//!
+//! Days are plain day indices on the career calendar (day 0 = season start).
+//! The calendar generates league fixture dates, records who played each
+//! fixture, and turns recent match load into a `CongestionEffect`:
+//! - fewer than `FULL_REST_DAYS` between matches lowers condition by one level
+//! - more than `CONGESTION_MATCH_LIMIT` matches in the last 14 days lowers it again
+//! - both raise in-match injury risk (`TeamMatchModifiers::injury_risk_mult`)
+
+use std::collections::{BTreeMap, HashMap};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::rotation::{FixtureImportance, UpcomingFixture};
+use crate::models::chemistry::MatchAppearance;
+use crate::models::Team;
+
+/// Rest days (match day to match day) needed to avoid a congestion penalty
+pub const FULL_REST_DAYS: u32 = 3;
+/// Window used to count recent matches
+pub const CONGESTION_WINDOW_DAYS: u32 = 14;
+/// Matches inside the window tolerated without penalty
+pub const CONGESTION_MATCH_LIMIT: usize = 3;
+/// Minutes on the pitch for an appearance to count as match load
+pub const LOAD_MIN_MINUTES: u32 = 30;
+/// Upper bound for the congestion injury multiplier
+pub const MAX_INJURY_RISK_MULT: f32 = 1.6;
+
+/// One dated fixture of the career calendar
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CalendarFixture {
+    pub id: u32,
+    pub day: u32,
+    pub opponent: String,
+    pub home: bool,
+    #[serde(default)]
+    pub importance: FixtureImportance,
+    #[serde(default)]
+    pub competition: String,
+    /// Lineup minutes once the fixture has been played
+    #[serde(default)]
+    pub appearances: Vec<MatchAppearance>,
+}
+
+impl CalendarFixture {
+    pub fn is_played(&self) -> bool {
+        !self.appearances.is_empty()
+    }
+}
+
+/// League schedule generation options
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScheduleConfig {
+    #[serde(default)]
+    pub start_day: u32,
+    /// Days between league rounds
+    #[serde(default = "default_round_interval")]
+    pub round_interval_days: u32,
+    /// Play every opponent home and away
+    #[serde(default = "default_true")]
+    pub double_round_robin: bool,
+    #[serde(default = "default_competition")]
+    pub competition: String,
+}
+
+fn default_round_interval() -> u32 {
+    7
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_competition() -> String {
+    "league".to_string()
+}
+
+impl Default for ScheduleConfig {
+    fn default() -> Self {
+        Self {
+            start_day: 0,
+            round_interval_days: default_round_interval(),
+            double_round_robin: true,
+            competition: default_competition(),
+        }
+    }
+}
+
+/// Career fixture list, kept sorted by day
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FixtureCalendar {
+    #[serde(default)]
+    pub fixtures: Vec<CalendarFixture>,
+}
+
+/// Days on which one player carried match load
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct PlayerLoad {
+    /// Roster UID or resolved player name
+    pub player: String,
+    pub match_days: Vec<u32>,
+}
+
+/// Squad match load as of `today` (MatchRequestV2 `home_load` / `away_load`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct SquadLoad {
+    pub today: u32,
+    #[serde(default)]
+    pub players: Vec<PlayerLoad>,
+}
+
+/// Congestion consequences for one player's next match
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CongestionEffect {
+    /// Days since the player's last match (None = no recent match)
+    pub rest_days: Option<u32>,
+    pub matches_in_window: usize,
+    /// Condition levels lost (0..=2)
+    pub condition_drop: u8,
+    pub injury_risk_mult: f32,
+}
+
+impl CongestionEffect {
+    pub fn none() -> Self {
+        Self { rest_days: None, matches_in_window: 0, condition_drop: 0, injury_risk_mult: 1.0 }
+    }
+
+    /// Lowered FIX01 condition level (never below 1)
+    pub fn apply_condition(&self, condition: u8) -> u8 {
+        condition.saturating_sub(self.condition_drop).max(1)
+    }
+}
+
+/// Congestion effect of playing on `today` after matches on `match_days`
+pub fn congestion_effect(match_days: &[u32], today: u32) -> CongestionEffect {
+    let past = match_days.iter().copied().filter(|&d| d < today);
+    let rest_days = past.clone().max().map(|last| today - last);
+    let matches_in_window = past.filter(|&d| today - d <= CONGESTION_WINDOW_DAYS).count();
+
+    let short_rest = rest_days.is_some_and(|r| r < FULL_REST_DAYS);
+    let overloaded = matches_in_window > CONGESTION_MATCH_LIMIT;
+    let condition_drop = short_rest as u8 + overloaded as u8;
+
+    let mut injury_risk_mult = 1.0;
+    if short_rest {
+        injury_risk_mult += 0.2;
+    }
+    injury_risk_mult += 0.15 * matches_in_window.saturating_sub(CONGESTION_MATCH_LIMIT - 1) as f32;
+
+    CongestionEffect {
+        rest_days,
+        matches_in_window,
+        condition_drop,
+        injury_risk_mult: injury_risk_mult.min(MAX_INJURY_RISK_MULT),
+    }
+}
+
+/// Lower condition of congested players in `team` and return the team
+/// injury multiplier (average over the starting XI).
+///
+/// Loads are matched by player name, or by roster UID through `uid_to_name`.
+pub fn apply_squad_load(
+    team: &mut Team,
+    load: &SquadLoad,
+    uid_to_name: &HashMap<String, String>,
+) -> f32 {
+    let mut days_by_name: HashMap<&str, &[u32]> = HashMap::new();
+    for entry in &load.players {
+        let name = uid_to_name.get(&entry.player).unwrap_or(&entry.player);
+        days_by_name.insert(name.as_str(), &entry.match_days);
+    }
+
+    let mut starters_mult = 0.0;
+    let mut starters = 0;
+    for (idx, player) in team.players.iter_mut().enumerate() {
+        let effect = days_by_name
+            .get(player.name.as_str())
+            .map(|days| congestion_effect(days, load.today))
+            .unwrap_or_else(CongestionEffect::none);
+        player.condition = effect.apply_condition(player.condition);
+        if idx < 11 {
+            starters_mult += effect.injury_risk_mult;
+            starters += 1;
+        }
+    }
+
+    if starters == 0 {
+        1.0
+    } else {
+        starters_mult / starters as f32
+    }
+}
+
+impl FixtureCalendar {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// League schedule for our club against `opponents` (home/away alternating)
+    pub fn generate(opponents: &[String], config: &ScheduleConfig) -> Result<Self, String> {
+        if opponents.is_empty() {
+            return Err("Schedule needs at least one opponent".to_string());
+        }
+        if config.round_interval_days == 0 {
+            return Err("round_interval_days must be at least 1".to_string());
+        }
+
+        let legs = if config.double_round_robin { 2 } else { 1 };
+        let mut calendar = Self::new();
+        for leg in 0..legs {
+            for (i, opponent) in opponents.iter().enumerate() {
+                let round = (leg * opponents.len() + i) as u32;
+                calendar.fixtures.push(CalendarFixture {
+                    id: round,
+                    day: config.start_day + round * config.round_interval_days,
+                    opponent: opponent.clone(),
+                    home: (i + leg) % 2 == 0,
+                    importance: FixtureImportance::Normal,
+                    competition: config.competition.clone(),
+                    appearances: Vec::new(),
+                });
+            }
+        }
+        Ok(calendar)
+    }
+
+    /// Insert an extra fixture (cup, friendly); returns its id
+    pub fn add_fixture(
+        &mut self,
+        day: u32,
+        opponent: &str,
+        home: bool,
+        importance: FixtureImportance,
+        competition: &str,
+    ) -> u32 {
+        let id = self.fixtures.iter().map(|f| f.id + 1).max().unwrap_or(0);
+        self.fixtures.push(CalendarFixture {
+            id,
+            day,
+            opponent: opponent.to_string(),
+            home,
+            importance,
+            competition: competition.to_string(),
+            appearances: Vec::new(),
+        });
+        self.fixtures.sort_by_key(|f| (f.day, f.id));
+        id
+    }
+
+    pub fn fixture(&self, id: u32) -> Option<&CalendarFixture> {
+        self.fixtures.iter().find(|f| f.id == id)
+    }
+
+    /// Days between a fixture and the one before it (None for the first)
+    pub fn rest_days_before(&self, id: u32) -> Option<u32> {
+        let idx = self.fixtures.iter().position(|f| f.id == id)?;
+        let prev = self.fixtures.get(idx.checked_sub(1)?)?;
+        Some(self.fixtures[idx].day - prev.day)
+    }
+
+    /// Store the lineup of a played fixture
+    pub fn record_lineup(
+        &mut self,
+        id: u32,
+        appearances: Vec<MatchAppearance>,
+    ) -> Result<(), String> {
+        let fixture = self
+            .fixtures
+            .iter_mut()
+            .find(|f| f.id == id)
+            .ok_or_else(|| format!("Unknown fixture id: {}", id))?;
+        fixture.appearances = appearances;
+        Ok(())
+    }
+
+    /// Fixtures from `today` onwards, in rotation advisor form
+    pub fn upcoming(&self, today: u32) -> Vec<UpcomingFixture> {
+        self.fixtures
+            .iter()
+            .filter(|f| f.day >= today && !f.is_played())
+            .map(|f| UpcomingFixture {
+                opponent: f.opponent.clone(),
+                days_until: (f.day - today).min(u16::MAX as u32) as u16,
+                importance: f.importance,
+            })
+            .collect()
+    }
+
+    /// Per-player match load from recorded lineups, as of `today`
+    pub fn squad_load(&self, today: u32) -> SquadLoad {
+        let mut days: BTreeMap<&str, Vec<u32>> = BTreeMap::new();
+        for fixture in self.fixtures.iter().filter(|f| f.day < today) {
+            for appearance in &fixture.appearances {
+                let minutes = appearance.minute_off.saturating_sub(appearance.minute_on) as u32;
+                if minutes >= LOAD_MIN_MINUTES {
+                    days.entry(appearance.name.as_str()).or_default().push(fixture.day);
+                }
+            }
+        }
+        SquadLoad {
+            today,
+            players: days
+                .into_iter()
+                .map(|(player, match_days)| PlayerLoad { player: player.to_string(), match_days })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Player, Position};
+
+    #[test]
+    fn test_generate_schedule_and_rest_days() {
+        let opponents: Vec<String> = ["A", "B", "C"].iter().map(|s| s.to_string()).collect();
+        let mut calendar =
+            FixtureCalendar::generate(&opponents, &ScheduleConfig::default()).unwrap();
+        assert_eq!(calendar.fixtures.len(), 6);
+        assert_eq!(calendar.fixtures[3].day, 21);
+        assert!(calendar.fixtures[0].home && !calendar.fixtures[3].home);
+
+        let cup = calendar.add_fixture(9, "Cup", false, FixtureImportance::High, "cup");
+        assert_eq!(calendar.rest_days_before(cup), Some(2));
+        assert_eq!(calendar.rest_days_before(2), Some(5));
+
+        let upcoming = calendar.upcoming(8);
+        assert_eq!(upcoming[0].opponent, "Cup");
+        assert_eq!(upcoming[0].days_until, 1);
+        assert!(FixtureCalendar::generate(&[], &ScheduleConfig::default()).is_err());
+    }
+
+    #[test]
+    fn test_congestion_effect() {
+        let rested = congestion_effect(&[0, 7], 14);
+        assert_eq!(rested.condition_drop, 0);
+        assert_eq!(rested.injury_risk_mult, 1.0);
+
+        let congested = congestion_effect(&[3, 6, 9, 11], 13);
+        assert_eq!(congested.rest_days, Some(2));
+        assert_eq!(congested.matches_in_window, 4);
+        assert_eq!(congested.condition_drop, 2);
+        assert!(congested.injury_risk_mult > 1.4);
+        assert_eq!(congested.apply_condition(2), 1);
+    }
+
+    #[test]
+    fn test_squad_load_lowers_team_condition() {
+        let mut calendar = FixtureCalendar::new();
+        for day in [0, 3, 6, 9] {
+            let id = calendar.add_fixture(day, "X", true, FixtureImportance::Normal, "league");
+            calendar.record_lineup(id, vec![MatchAppearance::full_match("Tired")]).unwrap();
+        }
+        let load = calendar.squad_load(11);
+        assert_eq!(load.players.len(), 1);
+
+        let player = |name: &str| Player {
+            name: name.to_string(),
+            position: Position::CM,
+            overall: 70,
+            condition: 4,
+            attributes: None,
+            equipped_skills: Vec::new(),
+            traits: Default::default(),
+            personality: Default::default(),
+            morale: 50,
+            form: 50,
+        };
+        let mut team = Team {
+            name: "T".to_string(),
+            formation: crate::models::team::Formation::F442,
+            players: vec![player("Tired"), player("Fresh")],
+        };
+        let mult = apply_squad_load(&mut team, &load, &HashMap::new());
+        assert_eq!(team.players[0].condition, 2);
+        assert_eq!(team.players[1].condition, 4);
+        assert!(mult > 1.0);
+    }
+}
@@ -0,0 +1,362 @@
+//! Career highlight compilation (end-of-season montage)
+//!
+//! When a match is recorded, the moments involving the squad's players
+//! (goals, assists, big saves) are extracted from the simulated result and
+//! stored on the `MatchRecord` with a clip reference into that match's replay.
+//! Replays carry player names only, so the caller supplies the UID → name
+//! mapping used for that match.
+//!
+//! A manifest for one player UID is then compiled by scanning the saved match
+//! history, optionally for a single season.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::{BestMoment, EventType, MatchEvent, MomentType};
+use crate::save::MatchRecord;
+
+/// xG of the saved shot needed for a save to count as a big save
+pub const BIG_SAVE_XG: f32 = 0.25;
+/// How far back a save looks for the shot it stopped
+const SAVE_SHOT_LOOKBACK_MS: u64 = 5_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CareerHighlightKind {
+    Goal,
+    Assist,
+    BigSave,
+}
+
+impl CareerHighlightKind {
+    /// Montage priority when a clip budget applies (higher = kept first)
+    fn priority(self) -> u8 {
+        match self {
+            CareerHighlightKind::Goal => 3,
+            CareerHighlightKind::BigSave => 2,
+            CareerHighlightKind::Assist => 1,
+        }
+    }
+}
+
+/// Window into a recorded match's replay
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HighlightClipRef {
+    /// `MatchRecord.id` of the match the replay belongs to
+    pub match_id: u32,
+    pub start_time_ms: u64,
+    pub end_time_ms: u64,
+}
+
+/// One player moment stored with a match record
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CareerHighlight {
+    pub kind: CareerHighlightKind,
+    pub player_uid: String,
+    pub player_name: String,
+    pub minute: u8,
+    pub is_home_team: bool,
+    /// Chance quality of the shot scored / assisted / saved, when known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub xg: Option<f32>,
+    pub clip: HighlightClipRef,
+}
+
+/// Manifest entry: a highlight plus the fixture it came from
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CareerHighlightEntry {
+    pub season: u16,
+    pub week: u16,
+    pub date: u64,
+    pub opponent: String,
+    #[serde(flatten)]
+    pub highlight: CareerHighlight,
+}
+
+/// Which highlights to compile
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CareerHighlightFilter {
+    /// Only this season (None = whole career)
+    pub season: Option<u16>,
+    /// Kinds to include (empty = all)
+    pub kinds: Vec<CareerHighlightKind>,
+    /// Clip budget; goals, then big saves, then assists are kept first
+    pub max_clips: Option<usize>,
+}
+
+/// Compiled montage for one player, in chronological order
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CareerHighlightManifest {
+    pub player_uid: String,
+    pub season: Option<u16>,
+    /// Matches the player has at least one highlight in
+    pub matches: u32,
+    /// Totals before the clip budget is applied
+    pub goals: u32,
+    pub assists: u32,
+    pub big_saves: u32,
+    pub clips: Vec<CareerHighlightEntry>,
+}
+
+/// Extract goals, assists and big saves for the listed players
+///
+/// `uid_to_name` maps player UIDs to the names used in this match's result;
+/// players not listed are ignored.
+pub fn extract_career_highlights(
+    result: &crate::models::MatchResult,
+    match_id: u32,
+    uid_to_name: &HashMap<String, String>,
+) -> Vec<CareerHighlight> {
+    let name_to_uid: HashMap<&str, &str> =
+        uid_to_name.iter().map(|(uid, name)| (name.as_str(), uid.as_str())).collect();
+
+    let mut events: Vec<&MatchEvent> = result.events.iter().collect();
+    events.sort_by_key(|e| e.timeline_ms());
+
+    let mut slot_names = starting_slot_names(result);
+    let mut highlights = Vec::new();
+    for (i, event) in events.iter().enumerate() {
+        let Some(track_id) = event.player_track_id.map(usize::from).filter(|&t| t < 22) else {
+            continue;
+        };
+
+        let mut push = |kind, name: &Option<String>, moment_type, xg| {
+            let Some(name) = name.as_deref() else {
+                return;
+            };
+            let Some(uid) = name_to_uid.get(name) else {
+                return;
+            };
+            let moment = BestMoment::from_event(event, moment_type);
+            highlights.push(CareerHighlight {
+                kind,
+                player_uid: uid.to_string(),
+                player_name: name.to_string(),
+                minute: event.minute,
+                is_home_team: event.is_home_team,
+                xg,
+                clip: HighlightClipRef {
+                    match_id,
+                    start_time_ms: moment.start_time_ms,
+                    end_time_ms: moment.end_time_ms,
+                },
+            });
+        };
+
+        match event.event_type {
+            EventType::Substitution => {
+                if let Some(sub) = event.details.as_ref().and_then(|d| d.substitution.as_ref()) {
+                    slot_names[track_id] = Some(sub.player_in_name.clone());
+                }
+            }
+            EventType::Goal => {
+                let xg = event.details.as_ref().and_then(|d| d.xg_value);
+                push(CareerHighlightKind::Goal, &slot_names[track_id], MomentType::Goal, xg);
+                if let Some(assist) =
+                    event.target_track_id.map(usize::from).filter(|&t| t < 22 && t != track_id)
+                {
+                    push(CareerHighlightKind::Assist, &slot_names[assist], MomentType::Goal, xg);
+                }
+            }
+            EventType::Save => {
+                let xg = saved_shot_xg(event, &events[..i]);
+                if xg.is_some_and(|xg| xg >= BIG_SAVE_XG) {
+                    push(CareerHighlightKind::BigSave, &slot_names[track_id], MomentType::Save, xg);
+                }
+            }
+            _ => {}
+        }
+    }
+    highlights
+}
+
+/// Compile one player's highlights from saved match history
+pub fn compile_career_highlights(
+    history: &[MatchRecord],
+    player_uid: &str,
+    filter: &CareerHighlightFilter,
+) -> CareerHighlightManifest {
+    let mut manifest = CareerHighlightManifest {
+        player_uid: player_uid.to_string(),
+        season: filter.season,
+        matches: 0,
+        goals: 0,
+        assists: 0,
+        big_saves: 0,
+        clips: Vec::new(),
+    };
+
+    for record in history {
+        if filter.season.is_some_and(|season| season != record.season) {
+            continue;
+        }
+        let mut in_match = false;
+        for highlight in record.highlights.iter().filter(|h| h.player_uid == player_uid) {
+            if !filter.kinds.is_empty() && !filter.kinds.contains(&highlight.kind) {
+                continue;
+            }
+            in_match = true;
+            match highlight.kind {
+                CareerHighlightKind::Goal => manifest.goals += 1,
+                CareerHighlightKind::Assist => manifest.assists += 1,
+                CareerHighlightKind::BigSave => manifest.big_saves += 1,
+            }
+            manifest.clips.push(CareerHighlightEntry {
+                season: record.season,
+                week: record.week,
+                date: record.date,
+                opponent: record.opponent.clone(),
+                highlight: highlight.clone(),
+            });
+        }
+        manifest.matches += in_match as u32;
+    }
+
+    let chronological = |a: &CareerHighlightEntry, b: &CareerHighlightEntry| {
+        (a.season, a.week, a.date, a.highlight.clip.match_id, a.highlight.clip.start_time_ms).cmp(
+            &(b.season, b.week, b.date, b.highlight.clip.match_id, b.highlight.clip.start_time_ms),
+        )
+    };
+    if let Some(max_clips) = filter.max_clips.filter(|&max| max < manifest.clips.len()) {
+        // Stable sort: equal priority keeps chronological (history) order.
+        manifest.clips.sort_by(chronological);
+        manifest.clips.sort_by_key(|c| std::cmp::Reverse(c.highlight.kind.priority()));
+        manifest.clips.truncate(max_clips);
+    }
+    manifest.clips.sort_by(chronological);
+    manifest
+}
+
+/// xG of the opposing shot this save stopped (most recent within the lookback)
+fn saved_shot_xg(save: &MatchEvent, earlier: &[&MatchEvent]) -> Option<f32> {
+    let save_ms = save.timeline_ms();
+    earlier
+        .iter()
+        .rev()
+        .take_while(|e| save_ms.saturating_sub(e.timeline_ms()) <= SAVE_SHOT_LOOKBACK_MS)
+        .find(|e| {
+            e.is_home_team != save.is_home_team
+                && matches!(e.event_type, EventType::ShotOnTarget | EventType::Shot)
+        })
+        .and_then(|shot| shot.details.as_ref()?.xg_value)
+}
+
+/// Pitch-slot occupant names at kick-off (substitutions update them later)
+fn starting_slot_names(result: &crate::models::MatchResult) -> Vec<Option<String>> {
+    let mut names: Vec<Option<String>> = vec![None; 22];
+    if let Some(setup) = result.match_setup.as_ref() {
+        for slot in &setup.player_slots {
+            if let Some(name) = names.get_mut(slot.track_id as usize) {
+                *name = Some(slot.name.clone());
+            }
+        }
+    }
+    for (offset, team) in [(0, result.home_team.as_ref()), (11, result.away_team.as_ref())] {
+        if let Some(team) = team {
+            for (i, player) in team.players.iter().take(11).enumerate() {
+                names[offset + i] = Some(player.name.clone());
+            }
+        }
+    }
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::team::Formation;
+    use crate::models::{EventDetails, Player, Position, SubstitutionDetails, Team};
+
+    fn team(prefix: &str) -> Team {
+        let players = (0..14)
+            .map(|i| Player {
+                name: format!("{prefix}{i}"),
+                position: if i == 0 { Position::GK } else { Position::CM },
+                overall: 70,
+                condition: 3,
+                attributes: None,
+                equipped_skills: Vec::new(),
+                traits: Default::default(),
+                personality: Default::default(),
+                morale: 50,
+                form: 50,
+            })
+            .collect();
+        Team { name: prefix.to_string(), formation: Formation::F442, players }
+    }
+
+    fn shot(minute: u8, ms: u64, is_home: bool, track_id: usize, xg: f32) -> MatchEvent {
+        MatchEvent::shot(minute, ms, is_home, track_id, true, xg)
+    }
+
+    #[test]
+    fn test_extract_and_compile_career_montage() {
+        let mut result = crate::models::MatchResult::new();
+        result.home_team = Some(team("H"));
+        result.away_team = Some(team("A"));
+        let mut sub = MatchEvent::substitution(60, 3_600_000, true, 9, 9);
+        sub.details = Some(EventDetails {
+            substitution: Some(SubstitutionDetails {
+                player_in_name: "H12".to_string(),
+                player_out_name: "H9".to_string(),
+                bench_slot: 1,
+            }),
+            ..Default::default()
+        });
+        result.events = vec![
+            MatchEvent::goal(10, 600_000, true, 9, Some(7)),
+            shot(20, 1_200_000, false, 19, 0.4),
+            MatchEvent::save(20, 1_201_000, true, 0),
+            shot(30, 1_800_000, false, 19, 0.05),
+            MatchEvent::save(30, 1_801_000, true, 0),
+            sub,
+            MatchEvent::goal(75, 4_500_000, true, 9, None),
+        ];
+        let uids: HashMap<String, String> =
+            [("u9", "H9"), ("u7", "H7"), ("u0", "H0"), ("u12", "H12")]
+                .into_iter()
+                .map(|(uid, name)| (uid.to_string(), name.to_string()))
+                .collect();
+
+        let highlights = extract_career_highlights(&result, 4, &uids);
+        let kinds: Vec<_> = highlights.iter().map(|h| (h.player_uid.as_str(), h.kind)).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("u9", CareerHighlightKind::Goal),
+                ("u7", CareerHighlightKind::Assist),
+                ("u0", CareerHighlightKind::BigSave),
+                ("u12", CareerHighlightKind::Goal),
+            ]
+        );
+        assert_eq!(
+            highlights[0].clip,
+            HighlightClipRef { match_id: 4, start_time_ms: 595_000, end_time_ms: 603_000 }
+        );
+
+        let record = |id, season, week| MatchRecord {
+            id,
+            season,
+            week,
+            opponent: "Rivals".to_string(),
+            highlights: extract_career_highlights(&result, id, &uids),
+            ..MatchRecord::default()
+        };
+        let history = vec![record(2, 1, 5), record(1, 1, 3), record(3, 2, 1)];
+
+        let season = compile_career_highlights(
+            &history,
+            "u9",
+            &CareerHighlightFilter { season: Some(1), ..Default::default() },
+        );
+        assert_eq!((season.matches, season.goals), (2, 2));
+        assert_eq!(season.clips[0].week, 3);
+
+        let filter = CareerHighlightFilter { max_clips: Some(3), ..Default::default() };
+        let career = compile_career_highlights(&history, "u0", &filter);
+        assert_eq!((career.matches, career.big_saves, career.clips.len()), (3, 3, 3));
+        assert!(compile_career_highlights(&history, "nobody", &filter).clips.is_empty());
+    }
+}
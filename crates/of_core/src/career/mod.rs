@@ -0,0 +1,23 @@
+// crates/of_core/src/career/mod.rs
+// Career mode helpers (fixture calendar, squad management, rating ladder, highlights)
+
+pub mod calendar;
+pub mod highlights;
+pub mod rating;
+pub mod rotation;
+
+pub use calendar::{
+    apply_squad_load, congestion_effect, CalendarFixture, CongestionEffect, FixtureCalendar,
+    PlayerLoad, ScheduleConfig, SquadLoad,
+};
+pub use highlights::{
+    compile_career_highlights, extract_career_highlights, CareerHighlight, CareerHighlightEntry,
+    CareerHighlightFilter, CareerHighlightKind, CareerHighlightManifest, HighlightClipRef,
+};
+pub use rating::{
+    LadderEntry, LadderKind, LadderMatch, LadderUpdate, LeaderboardRow, RatingLadder,
+};
+pub use rotation::{
+    advise_rotation, FixtureImportance, RotationAdvice, RotationCandidate, RotationPick,
+    UpcomingFixture,
+};
@@ -0,0 +1,312 @@
+//! Elo rating ladder (teams + players)
+//!
+//! Ratings move by `K × goal-difference weight × (actual − expected)`.
+//! `expected` is the classic Elo logistic curve (with a home bonus) unless the
+//! caller supplies its own prediction, e.g. a Monte Carlo estimate of
+//! `P(home win) + 0.5 × P(draw)`.
+//!
+//! Players ride along with their team: every listed player is rated against the
+//! opponent team's rating with the same actual result.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::save::{MatchRecord, MatchResult};
+
+/// Rating of a newly seen team/player
+pub const DEFAULT_RATING: f32 = 1500.0;
+/// Base step size per match
+pub const K_FACTOR: f32 = 20.0;
+/// Rating bonus given to the home side when computing the expected result
+pub const HOME_ADVANTAGE: f32 = 60.0;
+/// Player ratings move slower than team ratings
+pub const PLAYER_K_SCALE: f32 = 0.5;
+
+/// Ladder record for one team or player
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LadderEntry {
+    pub rating: f32,
+    pub peak: f32,
+    pub played: u32,
+    pub wins: u32,
+    pub draws: u32,
+    pub losses: u32,
+}
+
+impl Default for LadderEntry {
+    fn default() -> Self {
+        Self {
+            rating: DEFAULT_RATING,
+            peak: DEFAULT_RATING,
+            played: 0,
+            wins: 0,
+            draws: 0,
+            losses: 0,
+        }
+    }
+}
+
+impl LadderEntry {
+    fn apply(&mut self, delta: f32, actual: f32) {
+        self.rating += delta;
+        self.peak = self.peak.max(self.rating);
+        self.played += 1;
+        if actual > 0.5 {
+            self.wins += 1;
+        } else if actual < 0.5 {
+            self.losses += 1;
+        } else {
+            self.draws += 1;
+        }
+    }
+}
+
+/// One finished match fed into the ladder
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LadderMatch {
+    pub home: String,
+    pub away: String,
+    pub score_home: u8,
+    pub score_away: u8,
+    /// Externally predicted home expected score (0..=1), e.g. from Monte Carlo
+    #[serde(default)]
+    pub expected_home: Option<f32>,
+    /// Neutral venue (no home bonus)
+    #[serde(default)]
+    pub neutral: bool,
+    #[serde(default)]
+    pub home_players: Vec<String>,
+    #[serde(default)]
+    pub away_players: Vec<String>,
+}
+
+/// Rating change produced by one match
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LadderUpdate {
+    pub expected_home: f32,
+    pub home_delta: f32,
+    pub away_delta: f32,
+    pub home_rating: f32,
+    pub away_rating: f32,
+}
+
+/// Leaderboard selector
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum LadderKind {
+    #[default]
+    Team,
+    Player,
+}
+
+/// One leaderboard row (rank is 1-based)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LeaderboardRow {
+    pub rank: u32,
+    pub id: String,
+    #[serde(flatten)]
+    pub entry: LadderEntry,
+}
+
+/// Persisted team + player ladders
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RatingLadder {
+    #[serde(default)]
+    pub teams: BTreeMap<String, LadderEntry>,
+    #[serde(default)]
+    pub players: BTreeMap<String, LadderEntry>,
+}
+
+/// Classic Elo expected score of `rating` against `opponent`
+pub fn expected_score(rating: f32, opponent: f32) -> f32 {
+    1.0 / (1.0 + 10f32.powf((opponent - rating) / 400.0))
+}
+
+/// Goal-difference weight (1 goal: 1.0, 2 goals: 1.5, n ≥ 3: (11 + n) / 8)
+pub fn goal_difference_weight(score_home: u8, score_away: u8) -> f32 {
+    match score_home.abs_diff(score_away) {
+        0 | 1 => 1.0,
+        2 => 1.5,
+        n => (11.0 + n as f32) / 8.0,
+    }
+}
+
+impl RatingLadder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn team_rating(&self, team: &str) -> f32 {
+        self.teams.get(team).map_or(DEFAULT_RATING, |e| e.rating)
+    }
+
+    pub fn player_rating(&self, player: &str) -> f32 {
+        self.players.get(player).map_or(DEFAULT_RATING, |e| e.rating)
+    }
+
+    /// Apply one match result to both ladders
+    pub fn record(&mut self, m: &LadderMatch) -> LadderUpdate {
+        let home_rating = self.team_rating(&m.home);
+        let away_rating = self.team_rating(&m.away);
+        let bonus = if m.neutral { 0.0 } else { HOME_ADVANTAGE };
+
+        let expected_home = m
+            .expected_home
+            .filter(|p| p.is_finite())
+            .map(|p| p.clamp(0.0, 1.0))
+            .unwrap_or_else(|| expected_score(home_rating + bonus, away_rating));
+        let actual_home = match m.score_home.cmp(&m.score_away) {
+            std::cmp::Ordering::Greater => 1.0,
+            std::cmp::Ordering::Equal => 0.5,
+            std::cmp::Ordering::Less => 0.0,
+        };
+        let k = K_FACTOR * goal_difference_weight(m.score_home, m.score_away);
+        let home_delta = k * (actual_home - expected_home);
+
+        self.teams.entry(m.home.clone()).or_default().apply(home_delta, actual_home);
+        self.teams.entry(m.away.clone()).or_default().apply(-home_delta, 1.0 - actual_home);
+
+        let player_k = k * PLAYER_K_SCALE;
+        for name in &m.home_players {
+            let expected = expected_score(self.player_rating(name) + bonus, away_rating);
+            let delta = player_k * (actual_home - expected);
+            self.players.entry(name.clone()).or_default().apply(delta, actual_home);
+        }
+        for name in &m.away_players {
+            let expected = expected_score(self.player_rating(name), home_rating + bonus);
+            let delta = player_k * ((1.0 - actual_home) - expected);
+            self.players.entry(name.clone()).or_default().apply(delta, 1.0 - actual_home);
+        }
+
+        LadderUpdate {
+            expected_home,
+            home_delta,
+            away_delta: -home_delta,
+            home_rating: self.team_rating(&m.home),
+            away_rating: self.team_rating(&m.away),
+        }
+    }
+
+    /// Rebuild a ladder by replaying saved match history (`club` = our team id).
+    ///
+    /// Records store our goals in `score_home`; venue is not stored, so all
+    /// replayed matches are treated as neutral.
+    pub fn from_history(club: &str, history: &[MatchRecord]) -> Self {
+        let mut ladder = Self::new();
+        for record in history {
+            let (goals_for, goals_against) = match record.result {
+                // Trust the recorded result when the score disagrees (e.g. penalties)
+                MatchResult::Win if record.score_home <= record.score_away => (1, 0),
+                MatchResult::Loss if record.score_home >= record.score_away => (0, 1),
+                MatchResult::Draw if record.score_home != record.score_away => (0, 0),
+                _ => (record.score_home, record.score_away),
+            };
+            ladder.record(&LadderMatch {
+                home: club.to_string(),
+                away: record.opponent.clone(),
+                score_home: goals_for,
+                score_away: goals_against,
+                expected_home: None,
+                neutral: true,
+                home_players: record.appearances.iter().map(|a| a.name.clone()).collect(),
+                away_players: Vec::new(),
+            });
+        }
+        ladder
+    }
+
+    /// Ranked rows, highest rating first (ties broken by id); `limit` 0 = all
+    pub fn leaderboard(&self, kind: LadderKind, limit: usize) -> Vec<LeaderboardRow> {
+        let source = match kind {
+            LadderKind::Team => &self.teams,
+            LadderKind::Player => &self.players,
+        };
+        let mut rows: Vec<(&String, &LadderEntry)> = source.iter().collect();
+        rows.sort_by(|a, b| b.1.rating.total_cmp(&a.1.rating).then_with(|| a.0.cmp(b.0)));
+        if limit > 0 {
+            rows.truncate(limit);
+        }
+        rows.into_iter()
+            .enumerate()
+            .map(|(i, (id, entry))| LeaderboardRow {
+                rank: i as u32 + 1,
+                id: id.clone(),
+                entry: entry.clone(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ladder_match(home: &str, away: &str, score_home: u8, score_away: u8) -> LadderMatch {
+        LadderMatch {
+            home: home.to_string(),
+            away: away.to_string(),
+            score_home,
+            score_away,
+            expected_home: None,
+            neutral: false,
+            home_players: vec!["H1".to_string()],
+            away_players: vec!["A1".to_string()],
+        }
+    }
+
+    #[test]
+    fn test_record_is_zero_sum_and_respects_home_bonus() {
+        let mut ladder = RatingLadder::new();
+        let update = ladder.record(&ladder_match("A", "B", 1, 1));
+
+        // Home side was favoured, so a draw costs it rating
+        assert!(update.expected_home > 0.5);
+        assert!(update.home_delta < 0.0);
+        assert!(
+            (ladder.team_rating("A") + ladder.team_rating("B") - 2.0 * DEFAULT_RATING).abs() < 1e-3
+        );
+        assert_eq!(ladder.teams["A"].draws, 1);
+        assert!(ladder.player_rating("H1") < DEFAULT_RATING);
+        assert!(ladder.player_rating("A1") > DEFAULT_RATING);
+    }
+
+    #[test]
+    fn test_external_prediction_and_goal_margin() {
+        let mut predicted = RatingLadder::new();
+        let mut m = ladder_match("A", "B", 3, 0);
+        m.expected_home = Some(0.9);
+        let small = predicted.record(&m).home_delta;
+
+        let mut classic = RatingLadder::new();
+        let big = classic.record(&ladder_match("A", "B", 3, 0)).home_delta;
+
+        assert!(small > 0.0 && small < big);
+        assert_eq!(goal_difference_weight(4, 0), 15.0 / 8.0);
+    }
+
+    #[test]
+    fn test_from_history_and_leaderboard() {
+        let record = |opponent: &str, result, gf, ga| MatchRecord {
+            opponent: opponent.to_string(),
+            result,
+            score_home: gf,
+            score_away: ga,
+            ..MatchRecord::default()
+        };
+        let history = vec![
+            record("Rivals", MatchResult::Win, 2, 0),
+            record("Rivals", MatchResult::Win, 1, 0),
+            record("Giants", MatchResult::Loss, 0, 3),
+        ];
+        let ladder = RatingLadder::from_history("Club", &history);
+
+        let board = ladder.leaderboard(LadderKind::Team, 2);
+        assert_eq!(board.len(), 2);
+        assert_eq!(board[0].rank, 1);
+        assert_eq!(board[0].id, "Giants");
+        assert_eq!(ladder.teams["Club"].played, 3);
+        assert_eq!(ladder.teams["Rivals"].losses, 2);
+        assert_eq!(ladder.leaderboard(LadderKind::Team, 0).len(), 3);
+    }
+}
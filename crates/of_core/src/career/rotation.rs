@@ -0,0 +1,426 @@
+//! Bench and squad rotation advisor
+//!
+//! Suggests a starting XI + bench for the next fixture from the squad's
+//! availability (injury/suspension), condition, recent form and the upcoming
+//! fixture list. The result carries a MatchRequestV2-ready roster ordering
+//! (11 starters GK→DF→MF→FW, then 7 substitutes).
+
+use serde::{Deserialize, Serialize};
+
+use crate::api::json_api::UidRosterEntry;
+use crate::models::Position;
+
+/// Roster size expected by MatchRequestV2 (11 starters + 7 substitutes)
+pub const ROSTER_SIZE: usize = 18;
+/// Number of substitutes on the bench
+pub const BENCH_SIZE: usize = 7;
+
+/// Next fixture closer than this is considered congested (rotation pressure)
+pub const CONGESTION_DAYS: u16 = 3;
+
+/// Neutral average match rating used when no form data is available
+pub const NEUTRAL_FORM: f32 = 6.5;
+
+/// How much a fixture matters (drives who gets rested)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum FixtureImportance {
+    Low,
+    #[default]
+    Normal,
+    High,
+    Critical,
+}
+
+/// One entry of the career fixture list
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UpcomingFixture {
+    pub opponent: String,
+    /// Days from today (0 = the match being selected for)
+    pub days_until: u16,
+    #[serde(default)]
+    pub importance: FixtureImportance,
+}
+
+/// Squad member considered for selection
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RotationCandidate {
+    pub uid: String,
+    pub name: String,
+    pub position: Position,
+    pub overall: u8,
+    /// FIX01 condition level (1..=5)
+    pub condition: u8,
+    /// Average match rating over recent games (1.0..=10.0)
+    #[serde(default = "default_form")]
+    pub form: f32,
+    /// Remaining matches of suspension
+    #[serde(default)]
+    pub suspended_matches: u8,
+    #[serde(default)]
+    pub injured: bool,
+}
+
+fn default_form() -> f32 {
+    NEUTRAL_FORM
+}
+
+/// One suggested selection (or omission) with reasons
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RotationPick {
+    pub uid: String,
+    pub name: String,
+    pub position: Position,
+    pub score: f32,
+    pub reasons: Vec<String>,
+}
+
+/// Advisor output
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RotationAdvice {
+    pub formation: String,
+    pub starting_xi: Vec<RotationPick>,
+    pub bench: Vec<RotationPick>,
+    pub left_out: Vec<RotationPick>,
+    /// MatchRequestV2 `roster` (starters first, then bench)
+    pub roster: Vec<UidRosterEntry>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Line {
+    Goalkeeper,
+    Defender,
+    Midfielder,
+    Forward,
+}
+
+impl Line {
+    fn of(position: Position) -> Self {
+        if position.is_goalkeeper() {
+            Line::Goalkeeper
+        } else if position.is_defender() {
+            Line::Defender
+        } else if position.is_midfielder() {
+            Line::Midfielder
+        } else {
+            Line::Forward
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            Line::Goalkeeper => "GK",
+            Line::Defender => "DF",
+            Line::Midfielder => "MF",
+            Line::Forward => "FW",
+        }
+    }
+}
+
+struct Scored<'a> {
+    candidate: &'a RotationCandidate,
+    score: f32,
+    reasons: Vec<String>,
+    rested: bool,
+}
+
+fn condition_factor(condition: u8) -> f32 {
+    match condition {
+        0 | 1 => 0.80,
+        2 => 0.90,
+        3 => 1.00,
+        4 => 1.03,
+        _ => 1.06,
+    }
+}
+
+/// Suggest a starting XI and bench for `fixtures[0]`.
+///
+/// `fixtures` is the career fixture list from today onwards; the first entry
+/// is the match being selected for, later entries drive rotation pressure.
+pub fn advise_rotation(
+    formation: &str,
+    candidates: &[RotationCandidate],
+    fixtures: &[UpcomingFixture],
+) -> Result<RotationAdvice, String> {
+    let parsed_formation = crate::api::json_api::parse_formation(formation)?;
+    let (def_needed, mid_needed, fwd_needed) = parsed_formation.get_positions();
+
+    let current = fixtures.first();
+    let next = fixtures.get(1);
+    let current_importance = current.map(|f| f.importance).unwrap_or_default();
+    let congested = match (current, next) {
+        (Some(cur), Some(nxt)) => nxt.days_until.saturating_sub(cur.days_until) <= CONGESTION_DAYS,
+        _ => false,
+    };
+    let next_matters_more = next.is_some_and(|nxt| nxt.importance > current_importance);
+
+    let mut left_out = Vec::new();
+    let mut pool: Vec<Scored> = Vec::new();
+    for candidate in candidates {
+        if candidate.injured {
+            left_out.push(pick(candidate, 0.0, vec!["Unavailable: injured".to_string()]));
+            continue;
+        }
+        if candidate.suspended_matches > 0 {
+            left_out.push(pick(
+                candidate,
+                0.0,
+                vec![format!("Unavailable: suspended ({} match(es))", candidate.suspended_matches)],
+            ));
+            continue;
+        }
+
+        let mut reasons = Vec::new();
+        let form = if candidate.form.is_finite() { candidate.form } else { NEUTRAL_FORM };
+        let mut score = candidate.overall as f32 * condition_factor(candidate.condition)
+            + (form.clamp(1.0, 10.0) - NEUTRAL_FORM) * 3.0;
+
+        if candidate.condition <= 2 {
+            reasons.push(format!("Low condition ({}/5)", candidate.condition));
+        }
+        if form >= NEUTRAL_FORM + 1.0 {
+            reasons.push(format!("In form (avg rating {:.1})", form));
+        } else if form <= NEUTRAL_FORM - 1.0 {
+            reasons.push(format!("Out of form (avg rating {:.1})", form));
+        }
+
+        // Rotation pressure: rest tired players before a congested run, and
+        // protect regulars when the next fixture matters more than this one.
+        let mut rested = false;
+        if congested && candidate.condition <= 2 {
+            rested = true;
+            reasons.push(format!("Rested: next fixture within {} days", CONGESTION_DAYS));
+        } else if congested && next_matters_more && candidate.condition <= 3 {
+            rested = true;
+            reasons.push("Rested: saved for a more important fixture".to_string());
+        }
+        if rested {
+            score *= 0.75;
+        }
+
+        pool.push(Scored { candidate, score, reasons, rested });
+    }
+
+    if pool.len() < ROSTER_SIZE {
+        return Err(format!(
+            "Not enough available players: need {}, have {}",
+            ROSTER_SIZE,
+            pool.len()
+        ));
+    }
+
+    // Deterministic order: score desc, then overall desc, then uid.
+    pool.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then(b.candidate.overall.cmp(&a.candidate.overall))
+            .then(a.candidate.uid.cmp(&b.candidate.uid))
+    });
+
+    let needs = [
+        (Line::Goalkeeper, 1usize),
+        (Line::Defender, def_needed as usize),
+        (Line::Midfielder, mid_needed as usize),
+        (Line::Forward, fwd_needed as usize),
+    ];
+
+    let mut taken = vec![false; pool.len()];
+    let mut starters: Vec<(Line, usize)> = Vec::with_capacity(11);
+    for (line, count) in needs {
+        let mut filled = 0;
+        for (i, scored) in pool.iter().enumerate() {
+            if filled == count {
+                break;
+            }
+            if !taken[i] && Line::of(scored.candidate.position) == line {
+                taken[i] = true;
+                starters.push((line, i));
+                filled += 1;
+            }
+        }
+        // Shortage: best remaining outfield player covers the line.
+        while filled < count {
+            let Some(i) = (0..pool.len())
+                .find(|&i| !taken[i] && Line::of(pool[i].candidate.position) != Line::Goalkeeper)
+            else {
+                return Err(format!("Cannot fill {} slots for {}", line.label(), formation));
+            };
+            taken[i] = true;
+            pool[i].reasons.push(format!("Covering {} (out of position)", line.label()));
+            starters.push((line, i));
+            filled += 1;
+        }
+    }
+    if starters
+        .iter()
+        .any(|&(line, i)| line == Line::Goalkeeper && !pool[i].candidate.position.is_goalkeeper())
+    {
+        return Err("No available goalkeeper".to_string());
+    }
+
+    // Bench: backup goalkeeper first, then best remaining covering each line.
+    let mut bench: Vec<usize> = Vec::with_capacity(BENCH_SIZE);
+    if let Some(i) =
+        (0..pool.len()).find(|&i| !taken[i] && pool[i].candidate.position.is_goalkeeper())
+    {
+        taken[i] = true;
+        pool[i].reasons.push("Backup goalkeeper".to_string());
+        bench.push(i);
+    }
+    for line in [Line::Defender, Line::Midfielder, Line::Forward] {
+        if bench.len() == BENCH_SIZE {
+            break;
+        }
+        if let Some(i) =
+            (0..pool.len()).find(|&i| !taken[i] && Line::of(pool[i].candidate.position) == line)
+        {
+            taken[i] = true;
+            pool[i].reasons.push(format!("{} cover", line.label()));
+            bench.push(i);
+        }
+    }
+    for i in 0..pool.len() {
+        if bench.len() == BENCH_SIZE {
+            break;
+        }
+        if !taken[i] {
+            taken[i] = true;
+            bench.push(i);
+        }
+    }
+
+    let mut starting_xi = Vec::with_capacity(11);
+    for &(line, i) in &starters {
+        let scored = &mut pool[i];
+        scored
+            .reasons
+            .insert(0, format!("Best available {} (score {:.1})", line.label(), scored.score));
+        starting_xi.push(pick(scored.candidate, scored.score, scored.reasons.clone()));
+    }
+    let bench_picks: Vec<RotationPick> = bench
+        .iter()
+        .map(|&i| {
+            let scored = &pool[i];
+            pick(scored.candidate, scored.score, scored.reasons.clone())
+        })
+        .collect();
+    for (i, scored) in pool.iter().enumerate() {
+        if taken[i] {
+            continue;
+        }
+        let mut reasons = scored.reasons.clone();
+        if !scored.rested {
+            reasons.push("Not selected: squad depth".to_string());
+        }
+        left_out.push(pick(scored.candidate, scored.score, reasons));
+    }
+
+    let roster = starters
+        .iter()
+        .map(|&(_, i)| i)
+        .chain(bench.iter().copied())
+        .map(|i| UidRosterEntry {
+            uid: pool[i].candidate.uid.clone(),
+            condition: pool[i].candidate.condition,
+            morale: None,
+            form: None,
+            injured_until: None,
+        })
+        .collect();
+
+    Ok(RotationAdvice {
+        formation: parsed_formation.code().to_string(),
+        starting_xi,
+        bench: bench_picks,
+        left_out,
+        roster,
+    })
+}
+
+fn pick(candidate: &RotationCandidate, score: f32, reasons: Vec<String>) -> RotationPick {
+    RotationPick {
+        uid: candidate.uid.clone(),
+        name: candidate.name.clone(),
+        position: candidate.position,
+        score,
+        reasons,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(uid: &str, position: Position, overall: u8, condition: u8) -> RotationCandidate {
+        RotationCandidate {
+            uid: uid.to_string(),
+            name: uid.to_uppercase(),
+            position,
+            overall,
+            condition,
+            form: NEUTRAL_FORM,
+            suspended_matches: 0,
+            injured: false,
+        }
+    }
+
+    fn squad() -> Vec<RotationCandidate> {
+        let mut squad =
+            vec![candidate("gk1", Position::GK, 80, 3), candidate("gk2", Position::GK, 65, 3)];
+        for i in 0..6 {
+            squad.push(candidate(&format!("df{i}"), Position::CB, 70 + i as u8, 3));
+        }
+        for i in 0..6 {
+            squad.push(candidate(&format!("mf{i}"), Position::CM, 70 + i as u8, 3));
+        }
+        for i in 0..4 {
+            squad.push(candidate(&format!("fw{i}"), Position::ST, 70 + i as u8, 3));
+        }
+        squad
+    }
+
+    fn fixture(days_until: u16, importance: FixtureImportance) -> UpcomingFixture {
+        UpcomingFixture { opponent: "Rival".to_string(), days_until, importance }
+    }
+
+    #[test]
+    fn test_advice_builds_roster_in_formation_order() {
+        let advice =
+            advise_rotation("4-4-2", &squad(), &[fixture(0, FixtureImportance::Normal)]).unwrap();
+
+        assert_eq!(advice.starting_xi.len(), 11);
+        assert_eq!(advice.bench.len(), BENCH_SIZE);
+        assert_eq!(advice.roster.len(), ROSTER_SIZE);
+        assert_eq!(advice.starting_xi[0].uid, "gk1");
+        assert_eq!(advice.bench[0].uid, "gk2");
+        assert!(advice.starting_xi[1..5].iter().all(|p| p.position.is_defender()));
+        assert!(advice.starting_xi[9..].iter().all(|p| p.position.is_forward()));
+        assert_eq!(advice.roster[0].uid, "gk1");
+    }
+
+    #[test]
+    fn test_unavailable_and_tired_players_are_rotated() {
+        let mut squad = squad();
+        squad.push(candidate("fw_extra", Position::ST, 60, 3));
+        squad.iter_mut().find(|c| c.uid == "fw3").unwrap().suspended_matches = 1;
+        squad.iter_mut().find(|c| c.uid == "mf5").unwrap().condition = 1;
+
+        let fixtures =
+            [fixture(0, FixtureImportance::Normal), fixture(2, FixtureImportance::Normal)];
+        let advice = advise_rotation("4-4-2", &squad, &fixtures).unwrap();
+
+        assert!(advice.roster.iter().all(|e| e.uid != "fw3"));
+        assert!(advice.left_out.iter().any(|p| p.uid == "fw3"));
+        let mf5 = advice.bench.iter().chain(&advice.left_out).find(|p| p.uid == "mf5").unwrap();
+        assert!(mf5.reasons.iter().any(|r| r.starts_with("Rested")));
+        assert!(advice.starting_xi.iter().all(|p| p.uid != "mf5"));
+    }
+
+    #[test]
+    fn test_short_squad_is_rejected() {
+        let squad: Vec<_> = squad().into_iter().take(10).collect();
+        assert!(advise_rotation("4-4-2", &squad, &[]).is_err());
+        assert!(advise_rotation("9-9-9", &self::squad(), &[]).is_err());
+    }
+}
@@ -1,8 +1,9 @@
 // 코치 카드 데이터 구조
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// 카드 레어도 (⭐1~5)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub enum CardRarity {
     One = 1,   // ⭐ 일반 (50%)
     Two = 2,   // ⭐⭐ 고급 (25%)
@@ -47,7 +48,7 @@ impl CardRarity {
 }
 
 /// 카드 타입
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum CardType {
     Manager, // 감독
     Coach,   // 코치
@@ -55,7 +56,7 @@ pub enum CardType {
 }
 
 /// 전문 분야
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub enum Specialty {
     Speed,     // 스피드 (PACE 계열)
     Power,     // 피지컬 (POWER 계열)
@@ -91,7 +92,7 @@ impl Specialty {
 }
 
 /// 코치 카드
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CoachCard {
     /// 카드 고유 ID
     pub id: String,
@@ -116,7 +117,7 @@ pub struct CoachCard {
 }
 
 /// 특수 능력
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct SpecialAbility {
     pub name: String,
     pub description: String,
@@ -124,7 +125,7 @@ pub struct SpecialAbility {
     pub value: f32,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub enum EffectType {
     InjuryPrevention,     // 부상 방지
     GrowthBoost,          // 성장 가속
@@ -2,10 +2,11 @@
 use super::card::{create_default_coach, create_default_manager, CardType, CoachCard, Specialty};
 use super::tactics::{TacticalStyle, TacticsCard};
 use crate::training::{CoachBonusLog, TrainingTarget};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// 덱 구성 (감독 1 + 코치 3 + 전술 3 = 7칸)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Deck {
     /// 덱 이름
     pub name: String,
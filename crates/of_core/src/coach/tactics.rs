@@ -1,9 +1,10 @@
 // 전술 카드 시스템
 use super::card::{CardRarity, CardType};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// 전술 스타일 (match_engine의 TacticalStyle과 연동)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub enum TacticalStyle {
     Defensive,     // 수비적
     Balanced,      // 균형
@@ -76,7 +77,7 @@ impl TacticalStyle {
 }
 
 /// 전술 카드
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct TacticsCard {
     /// 카드 고유 ID
     pub id: String,
@@ -103,7 +104,7 @@ pub struct TacticsCard {
 }
 
 /// 전술 특수 효과
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct TacticalEffect {
     pub name: String,
     pub description: String,
@@ -112,7 +113,7 @@ pub struct TacticalEffect {
     pub condition: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub enum TacticalEffectType {
     FormationBonus,     // 포메이션 보너스
     WeatherAdaptation,  // 날씨 적응
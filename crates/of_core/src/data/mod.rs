@@ -5,10 +5,12 @@
 //! - Game balance (포지션별 밸런스)
 //! - Training efficiency (훈련 타입별 효율)
 //! - League configuration (리그 설정)
+//! - Name pools (국적별 이름 풀, 시드 기반 이름 생성)
 //! - Rules (IFAB Laws of the Game)
 //! - RuleBook UI Cards (구조화된 "왜?" 버튼 JSON payload)
 
 pub mod embedded;
+pub mod name_pools;
 pub mod person_cache;
 pub mod rules;
 pub mod rulebook_ui_cards;
@@ -19,9 +21,11 @@ pub use embedded::{
     GameBalance, LeagueConfig, LeagueTeam, TrainingEfficiency,
 };
 
+pub use name_pools::{GeneratedName, NameGenerator, NamePack, NamePool};
+
 pub use person_cache::{
-    get_person_by_uid, get_person_index, resolve_person_by_player_uid, PersonIndex,
-    DEFAULT_PERSON_CACHE_REL_PATH, PERSON_CACHE_ENV,
+    get_person_by_uid, get_person_index, loaded_person_index, resolve_person_by_player_uid,
+    PersonIndex, DEFAULT_PERSON_CACHE_REL_PATH, PERSON_CACHE_ENV,
 };
 
 pub use scale_conversion::ScaleConverter;
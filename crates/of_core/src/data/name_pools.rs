@@ -0,0 +1,384 @@
+//! 국적별 이름 풀 (Name / nationality pools)
+//!
+//! 신규 선수(newgen), 유스 상대팀, 테스트 팀 빌더가 공통으로 쓰는 시드 기반 이름 생성기.
+//! - 국적은 풀의 `weight` 비율로 선택
+//! - 데이터 팩(JSON)으로 풀을 추가/교체 가능 (`NamePack::from_json`, `NamePack::merge`)
+//!
+//! ## 안정성 보장
+//! 추첨은 `rand` 대신 SHA-256(도메인 + seed + index + 항목)으로 계산합니다.
+//! 같은 풀 + 같은 seed/index → 릴리스/의존성 버전과 무관하게 항상 같은 이름.
+//! 내장 풀의 항목 순서를 바꾸거나 중간에 삽입하면 결과가 바뀌므로, 새 이름은 목록 끝에만 추가합니다.
+
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// 추첨 해시 도메인 (바꾸면 모든 이름이 바뀜)
+const NAME_DRAW_DOMAIN: &[u8] = b"of_core/name_pools/v1";
+/// `generate_unique`에서 이름 하나당 최대 재시도 횟수
+const MAX_UNIQUE_ATTEMPTS: u32 = 64;
+
+/// 한 국적의 이름 풀
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NamePool {
+    /// 국적 코드 (예: "KOR", "ENG")
+    pub nationality: String,
+    /// 국적 선택 가중치 (0 = 명시적으로 요청할 때만 사용)
+    #[serde(default = "default_weight")]
+    pub weight: u32,
+    pub given_names: Vec<String>,
+    pub surnames: Vec<String>,
+    /// 성 먼저 표기 (예: "Kim Min-jun")
+    #[serde(default)]
+    pub surname_first: bool,
+}
+
+fn default_weight() -> u32 {
+    1
+}
+
+impl NamePool {
+    fn from_static(
+        nationality: &str,
+        weight: u32,
+        given_names: &[&str],
+        surnames: &[&str],
+        surname_first: bool,
+    ) -> Self {
+        Self {
+            nationality: nationality.to_string(),
+            weight,
+            given_names: given_names.iter().map(|s| s.to_string()).collect(),
+            surnames: surnames.iter().map(|s| s.to_string()).collect(),
+            surname_first,
+        }
+    }
+}
+
+/// 이름 풀 묶음 (데이터 팩 단위)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct NamePack {
+    #[serde(default)]
+    pub pools: Vec<NamePool>,
+}
+
+impl NamePack {
+    /// 바이너리 내장 기본 풀
+    pub fn builtin() -> Self {
+        Self {
+            pools: vec![
+                NamePool::from_static("KOR", 50, KOR_GIVEN, KOR_SURNAMES, true),
+                NamePool::from_static("ENG", 12, ENG_GIVEN, ENG_SURNAMES, false),
+                NamePool::from_static("ESP", 10, ESP_GIVEN, ESP_SURNAMES, false),
+                NamePool::from_static("BRA", 10, BRA_GIVEN, BRA_SURNAMES, false),
+                NamePool::from_static("JPN", 10, JPN_GIVEN, JPN_SURNAMES, false),
+                NamePool::from_static("FRA", 8, FRA_GIVEN, FRA_SURNAMES, false),
+            ],
+        }
+    }
+
+    /// 데이터 팩 JSON (`{"pools": [...]}`) 로드 + 검증
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let pack: NamePack =
+            serde_json::from_str(json).map_err(|e| format!("Invalid name pack JSON: {}", e))?;
+        pack.validate()?;
+        Ok(pack)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.pools.is_empty() {
+            return Err("Name pack has no pools".to_string());
+        }
+        let mut seen = HashSet::new();
+        for pool in &self.pools {
+            if pool.nationality.trim().is_empty() {
+                return Err("Name pool nationality must not be empty".to_string());
+            }
+            if !seen.insert(pool.nationality.as_str()) {
+                return Err(format!("Duplicate name pool: {}", pool.nationality));
+            }
+            if pool.given_names.is_empty() || pool.surnames.is_empty() {
+                return Err(format!(
+                    "Name pool {} needs given names and surnames",
+                    pool.nationality
+                ));
+            }
+        }
+        if self.pools.iter().all(|p| p.weight == 0) {
+            return Err("Name pack needs at least one pool with weight > 0".to_string());
+        }
+        Ok(())
+    }
+
+    /// 다른 팩을 덮어쓰기 병합 (같은 국적은 교체, 새 국적은 끝에 추가)
+    pub fn merge(&mut self, other: NamePack) {
+        for pool in other.pools {
+            match self.pools.iter_mut().find(|p| p.nationality == pool.nationality) {
+                Some(existing) => *existing = pool,
+                None => self.pools.push(pool),
+            }
+        }
+    }
+
+    pub fn pool(&self, nationality: &str) -> Option<&NamePool> {
+        self.pools.iter().find(|p| p.nationality.eq_ignore_ascii_case(nationality))
+    }
+}
+
+/// 생성된 이름
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GeneratedName {
+    pub nationality: String,
+    pub given_name: String,
+    pub surname: String,
+    pub full_name: String,
+}
+
+/// 시드 기반 이름 생성기
+#[derive(Debug, Clone)]
+pub struct NameGenerator {
+    pack: NamePack,
+}
+
+impl NameGenerator {
+    pub fn new(pack: NamePack) -> Result<Self, String> {
+        pack.validate()?;
+        Ok(Self { pack })
+    }
+
+    /// 내장 풀 생성기 (프로세스당 1회 생성)
+    pub fn builtin() -> &'static NameGenerator {
+        static BUILTIN: OnceLock<NameGenerator> = OnceLock::new();
+        BUILTIN.get_or_init(|| NameGenerator { pack: NamePack::builtin() })
+    }
+
+    pub fn pack(&self) -> &NamePack {
+        &self.pack
+    }
+
+    /// 국적 가중치로 풀을 고른 뒤 이름 생성 (`index` = 같은 seed 안의 n번째 이름)
+    pub fn generate(&self, seed: u64, index: u32) -> GeneratedName {
+        let total: u64 = self.pack.pools.iter().map(|p| p.weight as u64).sum();
+        let mut roll = draw(seed, index, b"nationality") % total.max(1);
+        let pool = self
+            .pack
+            .pools
+            .iter()
+            .find(|p| {
+                if roll < p.weight as u64 {
+                    true
+                } else {
+                    roll -= p.weight as u64;
+                    false
+                }
+            })
+            .unwrap_or(&self.pack.pools[0]);
+        name_from_pool(pool, seed, index)
+    }
+
+    /// 지정 국적 풀에서 이름 생성
+    pub fn generate_with_nationality(
+        &self,
+        nationality: &str,
+        seed: u64,
+        index: u32,
+    ) -> Result<GeneratedName, String> {
+        let pool = self
+            .pack
+            .pool(nationality)
+            .ok_or_else(|| format!("Unknown name pool nationality: {}", nationality))?;
+        Ok(name_from_pool(pool, seed, index))
+    }
+
+    /// 서로 다른 이름 `count`개 (스쿼드 생성용). `nationality` None = 가중치 선택
+    pub fn generate_unique(
+        &self,
+        seed: u64,
+        count: usize,
+        nationality: Option<&str>,
+    ) -> Result<Vec<GeneratedName>, String> {
+        let mut used = HashSet::with_capacity(count);
+        let mut names = Vec::with_capacity(count);
+        let mut index = 0u32;
+        while names.len() < count {
+            if index >= (count as u32 + 1).saturating_mul(MAX_UNIQUE_ATTEMPTS) {
+                return Err(format!("Name pool too small for {} unique names", count));
+            }
+            let name = match nationality {
+                Some(code) => self.generate_with_nationality(code, seed, index)?,
+                None => self.generate(seed, index),
+            };
+            index += 1;
+            if used.insert(name.full_name.clone()) {
+                names.push(name);
+            }
+        }
+        Ok(names)
+    }
+}
+
+fn name_from_pool(pool: &NamePool, seed: u64, index: u32) -> GeneratedName {
+    let given =
+        &pool.given_names[(draw(seed, index, b"given") % pool.given_names.len() as u64) as usize];
+    let surname =
+        &pool.surnames[(draw(seed, index, b"surname") % pool.surnames.len() as u64) as usize];
+    let full_name = if pool.surname_first {
+        format!("{} {}", surname, given)
+    } else {
+        format!("{} {}", given, surname)
+    };
+    GeneratedName {
+        nationality: pool.nationality.clone(),
+        given_name: given.clone(),
+        surname: surname.clone(),
+        full_name,
+    }
+}
+
+/// 버전 고정 추첨값 (SHA-256 앞 8바이트)
+fn draw(seed: u64, index: u32, field: &[u8]) -> u64 {
+    let mut hasher = Sha256::new();
+    hasher.update(NAME_DRAW_DOMAIN);
+    hasher.update(seed.to_le_bytes());
+    hasher.update(index.to_le_bytes());
+    hasher.update(field);
+    let digest = hasher.finalize();
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&digest[..8]);
+    u64::from_le_bytes(bytes)
+}
+
+// ============================================================================
+// 내장 풀 (순서 변경 금지 - 끝에만 추가)
+// ============================================================================
+
+const KOR_GIVEN: &[&str] = &[
+    "Min-jun",
+    "Seo-jun",
+    "Do-yun",
+    "Ha-jun",
+    "Ji-ho",
+    "Jun-woo",
+    "Hyun-woo",
+    "Ji-hoon",
+    "Woo-jin",
+    "Seung-min",
+    "Tae-yang",
+    "Dong-hyun",
+    "Jae-won",
+    "Sung-min",
+    "Yu-chan",
+    "Eun-woo",
+];
+const KOR_SURNAMES: &[&str] = &[
+    "Kim", "Lee", "Park", "Choi", "Jung", "Kang", "Cho", "Yoon", "Jang", "Lim", "Han", "Oh", "Seo",
+    "Shin", "Kwon", "Hwang", "Ahn", "Song",
+];
+const ENG_GIVEN: &[&str] = &[
+    "Jack", "Harry", "George", "Oliver", "Charlie", "James", "Thomas", "Alfie", "Joe", "Mason",
+    "Reece", "Callum",
+];
+const ENG_SURNAMES: &[&str] = &[
+    "Smith", "Jones", "Taylor", "Brown", "Walker", "Wright", "Hughes", "Wood", "Clarke", "Turner",
+    "Ward", "Barnes",
+];
+const ESP_GIVEN: &[&str] = &[
+    "Pablo",
+    "Alejandro",
+    "Daniel",
+    "Adrian",
+    "Javier",
+    "Sergio",
+    "Marcos",
+    "Alvaro",
+    "Iker",
+    "Mario",
+    "Raul",
+    "Hugo",
+];
+const ESP_SURNAMES: &[&str] = &[
+    "Garcia", "Martinez", "Lopez", "Sanchez", "Perez", "Gomez", "Ruiz", "Moreno", "Navarro",
+    "Torres", "Ramos", "Castillo",
+];
+const BRA_GIVEN: &[&str] = &[
+    "Gabriel", "Lucas", "Matheus", "Rafael", "Gustavo", "Felipe", "Thiago", "Vinicius", "Bruno",
+    "Rodrigo", "Caio", "Diego",
+];
+const BRA_SURNAMES: &[&str] = &[
+    "Silva", "Santos", "Oliveira", "Souza", "Costa", "Pereira", "Almeida", "Ribeiro", "Carvalho",
+    "Gomes", "Barbosa", "Araujo",
+];
+const JPN_GIVEN: &[&str] = &[
+    "Haruto", "Sota", "Yuto", "Riku", "Kaito", "Ren", "Daiki", "Takumi", "Kenta", "Shota", "Yuki",
+    "Hiroki",
+];
+const JPN_SURNAMES: &[&str] = &[
+    "Sato",
+    "Suzuki",
+    "Takahashi",
+    "Tanaka",
+    "Watanabe",
+    "Ito",
+    "Nakamura",
+    "Kobayashi",
+    "Kato",
+    "Yoshida",
+    "Yamada",
+    "Matsumoto",
+];
+const FRA_GIVEN: &[&str] = &[
+    "Louis", "Hugo", "Theo", "Lucas", "Nathan", "Mathis", "Enzo", "Antoine", "Maxime", "Clement",
+    "Julien", "Yanis",
+];
+const FRA_SURNAMES: &[&str] = &[
+    "Martin", "Bernard", "Dubois", "Thomas", "Robert", "Richard", "Petit", "Durand", "Leroy",
+    "Moreau", "Simon", "Laurent",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_seed_same_names() {
+        let generator = NameGenerator::builtin();
+        let a = generator.generate_unique(42, 18, None).unwrap();
+        let b =
+            NameGenerator::new(NamePack::builtin()).unwrap().generate_unique(42, 18, None).unwrap();
+        assert_eq!(a, b);
+        assert_eq!(a.iter().map(|n| &n.full_name).collect::<HashSet<_>>().len(), 18);
+        assert_ne!(a, generator.generate_unique(43, 18, None).unwrap());
+    }
+
+    #[test]
+    fn test_names_are_pinned_across_releases() {
+        // Golden values: if this fails, existing saves/stories would get different names.
+        let generator = NameGenerator::builtin();
+        assert_eq!(generator.generate(42, 0).full_name, "Yoon Ji-hoon");
+        assert_eq!(
+            generator.generate_with_nationality("KOR", 7, 3).unwrap().full_name,
+            "Seo Sung-min"
+        );
+    }
+
+    #[test]
+    fn test_data_pack_overrides_and_weights() {
+        let mut pack = NamePack::builtin();
+        pack.merge(
+            NamePack::from_json(
+                r#"{"pools": [{"nationality": "KOR", "weight": 0, "given_names": ["A"], "surnames": ["B"]},
+                              {"nationality": "NED", "weight": 5, "given_names": ["Daan"], "surnames": ["Bakker"]}]}"#,
+            )
+            .unwrap(),
+        );
+        let generator = NameGenerator::new(pack).unwrap();
+        assert_eq!(generator.generate_with_nationality("kor", 1, 0).unwrap().full_name, "A B");
+        assert!((0..200).all(|i| generator.generate(9, i).nationality != "KOR"));
+        assert!((0..200).any(|i| generator.generate(9, i).nationality == "NED"));
+
+        assert!(NamePack::from_json(r#"{"pools": []}"#).is_err());
+        assert!(generator.generate_unique(1, 5, Some("KOR")).is_err());
+    }
+}
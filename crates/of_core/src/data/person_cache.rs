@@ -95,6 +95,11 @@ pub fn get_person_index() -> Result<&'static PersonIndex, String> {
     })
 }
 
+/// Return the PersonIndex only if it has already been loaded (never triggers a load).
+pub fn loaded_person_index() -> Option<&'static PersonIndex> {
+    PERSON_INDEX.get()
+}
+
 /// Resolve a single CSV `Person` by uid (u32).
 pub fn get_person_by_uid(uid: u32) -> Result<Option<&'static Person>, String> {
     Ok(get_person_index()?.players.get(&uid))
@@ -10,8 +10,10 @@
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value as JsonValue};
 
-use crate::models::events::{EventType, MatchEvent};
-use crate::models::rules::{FoulSeverity, OffsideInvolvementType, RuleId};
+use crate::models::events::{EventType, HandballRestartType, MatchEvent, VarIncident, VarReviewOutcome};
+use crate::models::rules::{
+    AdvantageCard, AdvantageOutcome, FoulSeverity, OffsideInvolvementType, RuleId,
+};
 use crate::models::EventDetails;
 
 // =============================================================================
@@ -100,7 +102,14 @@ pub fn generate_ui_card(
         EventType::Freekick => generate_freekick_cards(&mut cards, details, use_korean),
         EventType::Penalty => generate_penalty_cards(&mut cards, use_korean),
         EventType::PostHit | EventType::BarHit => generate_woodwork_cards(&mut cards, event_type, use_korean),
-        EventType::VarReview => generate_var_cards(&mut cards, details, use_korean),
+        EventType::VarReview | EventType::VarReviewStarted | EventType::VarDecision => {
+            generate_var_cards(&mut cards, details, use_korean)
+        }
+        EventType::AdvantagePlayed => generate_advantage_cards(&mut cards, details, use_korean),
+        EventType::Handball => generate_handball_cards(&mut cards, details, use_korean),
+        EventType::BackPassOffence | EventType::GoalkeeperHolding => {
+            generate_goalkeeper_offence_cards(&mut cards, event_type, details, use_korean)
+        }
         _ => return None,
     }
 
@@ -265,6 +274,17 @@ fn generate_offside_cards(cards: &mut Vec<CardBlock>, details: Option<&EventDeta
                 ));
             }
 
+            if let Some(defender) = offside.defender_track_id {
+                lines.push(with_ref(
+                    line_kv(
+                        if use_korean { "두 번째 최종 수비수" } else { "Second-last defender" },
+                        json!(defender),
+                    ),
+                    "player_track_id",
+                    defender.to_string(),
+                ));
+            }
+
             if let Some(ref involvement) = offside.involvement_type {
                 let t = match involvement {
                     OffsideInvolvementType::InterferingWithPlay => {
@@ -389,6 +409,13 @@ fn generate_foul_cards(cards: &mut Vec<CardBlock>, details: Option<&EventDetails
                 }));
             }
         }
+        if d.advantage_played == Some(true) {
+            lines.push(line_note(if use_korean {
+                "어드밴티지 적용: 프리킥 없이 플레이가 계속되었습니다."
+            } else {
+                "Advantage played: play continued without a free kick."
+            }));
+        }
     }
 
     if lines.is_empty() {
@@ -624,34 +651,80 @@ fn generate_woodwork_cards(cards: &mut Vec<CardBlock>, event_type: &EventType, u
 }
 
 fn generate_var_cards(cards: &mut Vec<CardBlock>, details: Option<&EventDetails>, use_korean: bool) {
+    let var = details.and_then(|d| d.var_review.as_ref());
+    let summary = match var.and_then(|v| v.outcome.as_ref()) {
+        None if var.is_some() => {
+            if use_korean {
+                "VAR(비디오 판독)이 진행 중입니다."
+            } else {
+                "VAR (video) review in progress."
+            }
+        }
+        Some(VarReviewOutcome::Overturned) => {
+            if use_korean {
+                "VAR 판독으로 원심이 번복되었습니다."
+            } else {
+                "VAR overturned the on-field decision."
+            }
+        }
+        _ => {
+            if use_korean {
+                "VAR 판독 결과 원심이 유지되었습니다."
+            } else {
+                "VAR upheld the on-field decision."
+            }
+        }
+    };
     cards.push(CardBlock {
         level: 1,
-        title: if use_korean { "VAR" } else { "VAR" }.to_string(),
-        lines: vec![line_plain(if use_korean {
-            "VAR(비디오 판독)이 진행되었습니다."
-        } else {
-            "A VAR (video) review occurred."
-        })],
+        title: "VAR".to_string(),
+        lines: vec![line_plain(summary)],
     });
 
     let mut lines: Vec<CardLine> = Vec::new();
-    if let Some(d) = details {
-        if let Some(ref var) = d.var_review {
+    if let Some(var) = var {
+        lines.push(line_kv(
+            if use_korean { "대상 이벤트" } else { "Reviewed event" },
+            json!(format!("{:?}", var.reviewed_event_type)),
+        ));
+        if let Some(incident) = var.incident {
+            let label = match (incident, use_korean) {
+                (VarIncident::OffsideOnGoal, true) => "득점 장면 오프사이드",
+                (VarIncident::OffsideOnGoal, false) => "Offside in the goal build-up",
+                (VarIncident::Penalty, true) => "페널티킥 판정",
+                (VarIncident::Penalty, false) => "Penalty decision",
+                (VarIncident::RedCard, true) => "퇴장 판정",
+                (VarIncident::RedCard, false) => "Direct red card",
+            };
+            lines.push(line_kv(if use_korean { "판독 항목" } else { "Incident" }, json!(label)));
+        }
+        if let Some(ref outcome) = var.outcome {
             lines.push(line_kv(
-                if use_korean { "대상 이벤트" } else { "Reviewed event" },
-                json!(format!("{:?}", var.reviewed_event_type)),
+                if use_korean { "결과" } else { "Outcome" },
+                json!(format!("{:?}", outcome)),
             ));
+        }
+        if let Some(margin) = var.offside_margin_m {
             lines.push(line_kv(
-                if use_korean { "결과" } else { "Outcome" },
-                json!(format!("{:?}", var.outcome)),
+                if use_korean { "오프사이드 차이" } else { "Offside margin" },
+                json!(format!("{:.2}m", margin)),
+            ));
+        }
+        if let Some(ref restart) = var.overturned_restart {
+            lines.push(line_kv(if use_korean { "재개" } else { "Restart" }, json!(restart)));
+        }
+        if let Some(duration_s) = var.duration_s {
+            lines.push(line_kv(
+                if use_korean { "판독 시간" } else { "Review time" },
+                json!(format!("{}s", duration_s)),
             ));
         }
     }
     if lines.is_empty() {
         lines.push(line_note(if use_korean {
-            "현재 버전에서는 VAR이 판정을 번복하지 않습니다."
+            "판독 세부 정보가 없습니다."
         } else {
-            "In the current version, VAR does not overturn decisions."
+            "No review details recorded."
         }));
     }
 
@@ -665,9 +738,249 @@ fn generate_var_cards(cards: &mut Vec<CardBlock>, details: Option<&EventDetails>
         level: 3,
         title: if use_korean { "규칙 참조" } else { "Rule Reference" }.to_string(),
         lines: vec![line_plain(if use_korean {
-            "VAR은 판정의 정확성을 돕기 위한 보조 수단입니다."
+            "VAR은 득점, 페널티킥, 직접 퇴장에 대한 명백한 오심만 바로잡습니다. 판독 시간은 추가시간에 더해집니다."
         } else {
-            "VAR is a tool to assist the accuracy of match decisions."
+            "VAR only corrects clear and obvious errors on goals, penalties and direct red cards. Review time is added on."
+        })],
+    });
+}
+
+fn generate_advantage_cards(cards: &mut Vec<CardBlock>, details: Option<&EventDetails>, use_korean: bool) {
+    let advantage = details.and_then(|d| d.advantage.as_ref());
+    let outcome = advantage.map(|a| a.outcome).unwrap_or(AdvantageOutcome::Signalled);
+
+    // L1
+    cards.push(CardBlock {
+        level: 1,
+        title: if use_korean { "어드밴티지" } else { "Advantage" }.to_string(),
+        lines: vec![line_plain(match (outcome, use_korean) {
+            (AdvantageOutcome::Signalled, true) => "반칙이 있었지만 공격 팀에 유리하여 플레이를 계속합니다.",
+            (AdvantageOutcome::Signalled, false) => {
+                "A foul was committed, but play continues because the attacking team benefits."
+            }
+            (AdvantageOutcome::Accrued, true) => "어드밴티지가 성립했습니다. 원래 반칙에 대한 프리킥은 없습니다.",
+            (AdvantageOutcome::Accrued, false) => {
+                "The advantage accrued. No free kick is awarded for the original foul."
+            }
+            (AdvantageOutcome::CalledBack, true) => {
+                "예상한 이익이 발생하지 않아 원래 반칙 지점에서 프리킥을 줍니다."
+            }
+            (AdvantageOutcome::CalledBack, false) => {
+                "The anticipated advantage did not ensue, so the original foul is penalised."
+            }
+        })],
+    });
+
+    // L2
+    let mut lines: Vec<CardLine> = Vec::new();
+    if let Some(a) = advantage {
+        let severity = if use_korean { a.severity.name_ko() } else { a.severity.name_en() };
+        lines.push(line_kv(if use_korean { "원래 반칙" } else { "Original foul" }, json!(severity)));
+
+        if a.is_dogso {
+            lines.push(line_warning(if use_korean {
+                "명백한 득점 기회 저지(DOGSO): 어드밴티지 적용 시 퇴장 대신 경고입니다."
+            } else {
+                "DOGSO: with advantage the offender is cautioned instead of sent off."
+            }));
+        } else if a.stopped_promising_attack {
+            lines.push(line_note(if use_korean {
+                "유망한 공격 저지(SPA): 어드밴티지 적용 시 경고하지 않습니다."
+            } else {
+                "Stopping a promising attack: no caution when advantage is played."
+            }));
+        }
+
+        let card_label = |card: Option<AdvantageCard>| match (card, use_korean) {
+            (Some(AdvantageCard::Yellow), true) => "옐로카드",
+            (Some(AdvantageCard::Yellow), false) => "Yellow card",
+            (Some(AdvantageCard::Red), true) => "레드카드",
+            (Some(AdvantageCard::Red), false) => "Red card",
+            (None, true) => "없음",
+            (None, false) => "None",
+        };
+        lines.push(line_kv(
+            if use_korean { "정지 시 제재" } else { "Sanction if stopped" },
+            json!(card_label(a.card_if_stopped())),
+        ));
+        lines.push(line_kv(
+            if use_korean { "최종 제재" } else { "Sanction applied" },
+            json!(card_label(a.card())),
+        ));
+        if a.card().is_some() && outcome != AdvantageOutcome::CalledBack {
+            lines.push(line_note(if use_korean {
+                "카드는 다음 경기 정지 시 주어집니다."
+            } else {
+                "The card is shown when the ball is next out of play."
+            }));
+        }
+    }
+    if lines.is_empty() {
+        lines = if use_korean {
+            vec![
+                line_bullet("이익이 몇 초 안에 발생하지 않으면 원래 반칙을 처벌합니다."),
+                line_bullet("보류된 카드는 다음 정지 시 주어집니다."),
+            ]
+        } else {
+            vec![
+                line_bullet("If the advantage does not ensue within a few seconds, the original foul is penalised."),
+                line_bullet("Any withheld card is shown at the next stoppage."),
+            ]
+        };
+    }
+
+    cards.push(CardBlock {
+        level: 2,
+        title: if use_korean { "핵심 근거" } else { "Key Points" }.to_string(),
+        lines,
+    });
+
+    // L3
+    cards.push(CardBlock {
+        level: 3,
+        title: if use_korean { "규칙 참조" } else { "Rule Reference" }.to_string(),
+        lines: vec![line_plain(if use_korean {
+            "IFAB Law 12.3: 경고/퇴장 대상 반칙에 어드밴티지를 적용하면 다음 정지 시 제재합니다. DOGSO는 경고, SPA는 경고 없음."
+        } else {
+            "IFAB Law 12.3: When advantage is played, the caution/sending-off is issued at the next stoppage; DOGSO becomes a caution and SPA is not cautioned."
+        })],
+    });
+}
+
+fn generate_handball_cards(cards: &mut Vec<CardBlock>, details: Option<&EventDetails>, use_korean: bool) {
+    let handball = details.and_then(|d| d.handball_details.as_ref());
+
+    // L1
+    cards.push(CardBlock {
+        level: 1,
+        title: if use_korean { "핸드볼" } else { "Handball" }.to_string(),
+        lines: vec![line_plain(if use_korean {
+            "선수가 손이나 팔로 공을 처리하여 반칙이 선언되었습니다."
+        } else {
+            "A player handled the ball with the hand or arm."
+        })],
+    });
+
+    // L2
+    let mut lines: Vec<CardLine> = Vec::new();
+    if let Some(h) = handball {
+        let restart = match (h.restart_type, use_korean) {
+            (HandballRestartType::DirectFreeKick, true) => "직접 프리킥",
+            (HandballRestartType::DirectFreeKick, false) => "Direct free kick",
+            (HandballRestartType::Penalty, true) => "페널티킥",
+            (HandballRestartType::Penalty, false) => "Penalty kick",
+        };
+        lines.push(line_kv(if use_korean { "재시작" } else { "Restart" }, json!(restart)));
+        lines.push(line_kv(
+            if use_korean { "페널티 에어리어 안" } else { "Inside penalty area" },
+            json!(h.in_penalty_area),
+        ));
+        if h.deliberate {
+            lines.push(line_warning(if use_korean {
+                "의도적으로 손/팔을 공 쪽으로 움직였습니다."
+            } else {
+                "The hand/arm moved deliberately towards the ball."
+            }));
+        }
+    } else {
+        lines = if use_korean {
+            vec![
+                line_bullet("손/팔을 공 쪽으로 의도적으로 움직임"),
+                line_bullet("신체를 부자연스럽게 크게 만든 위치"),
+            ]
+        } else {
+            vec![
+                line_bullet("Deliberate movement of the hand/arm towards the ball"),
+                line_bullet("Hand/arm made the body unnaturally bigger"),
+            ]
+        };
+    }
+
+    cards.push(CardBlock {
+        level: 2,
+        title: if use_korean { "핵심 근거" } else { "Key Points" }.to_string(),
+        lines,
+    });
+
+    // L3
+    cards.push(CardBlock {
+        level: 3,
+        title: if use_korean { "규칙 참조" } else { "Rule Reference" }.to_string(),
+        lines: vec![line_plain(if use_korean {
+            "IFAB Law 12.1: 핸드볼은 직접 프리킥(자기 페널티 에어리어 안이면 페널티킥)으로 처벌합니다."
+        } else {
+            "IFAB Law 12.1: Handling is penalised with a direct free kick, or a penalty kick inside the offender's penalty area."
+        })],
+    });
+}
+
+fn generate_goalkeeper_offence_cards(
+    cards: &mut Vec<CardBlock>,
+    event_type: &EventType,
+    details: Option<&EventDetails>,
+    use_korean: bool,
+) {
+    let offence = details.and_then(|d| d.goalkeeper_offence.as_ref());
+    let holding = matches!(event_type, EventType::GoalkeeperHolding);
+
+    // L1
+    cards.push(CardBlock {
+        level: 1,
+        title: match (holding, use_korean) {
+            (true, true) => "골키퍼 6초 규정",
+            (true, false) => "Goalkeeper Holding",
+            (false, true) => "백패스 핸들링",
+            (false, false) => "Back-pass Handling",
+        }
+        .to_string(),
+        lines: vec![line_plain(match (holding, use_korean) {
+            (true, true) => "골키퍼가 공을 손에 6초 넘게 들고 있어 간접 프리킥이 선언되었습니다.",
+            (true, false) => "The goalkeeper held the ball in the hands for more than six seconds.",
+            (false, true) => "골키퍼가 팀 동료가 의도적으로 준 공을 손으로 처리했습니다.",
+            (false, false) => "The goalkeeper handled the ball after a team-mate deliberately played it back.",
+        })],
+    });
+
+    // L2
+    let mut lines: Vec<CardLine> = vec![line_kv(
+        if use_korean { "재시작" } else { "Restart" },
+        json!(if use_korean { "간접 프리킥" } else { "Indirect free kick" }),
+    )];
+    if let Some(o) = offence {
+        if let Some(held_ms) = o.held_ms {
+            let held_s = (held_ms as f64 / 100.0).round() / 10.0;
+            lines.push(line_kv(if use_korean { "소유 시간(초)" } else { "Held (s)" }, json!(held_s)));
+        }
+        if !holding {
+            let source = match (o.from_throw_in, use_korean) {
+                (true, true) => "팀 동료의 스로인",
+                (true, false) => "Team-mate's throw-in",
+                (false, true) => "팀 동료의 의도적 킥",
+                (false, false) => "Team-mate's deliberate kick",
+            };
+            lines.push(line_kv(if use_korean { "공의 출처" } else { "Ball came from" }, json!(source)));
+        }
+    }
+    lines.push(line_note(if use_korean {
+        "골키퍼의 핸들링 반칙은 카드 없이 간접 프리킥으로 처벌합니다."
+    } else {
+        "Goalkeeper handling offences are penalised with an indirect free kick, without a card."
+    }));
+
+    cards.push(CardBlock {
+        level: 2,
+        title: if use_korean { "핵심 근거" } else { "Key Points" }.to_string(),
+        lines,
+    });
+
+    // L3
+    cards.push(CardBlock {
+        level: 3,
+        title: if use_korean { "규칙 참조" } else { "Rule Reference" }.to_string(),
+        lines: vec![line_plain(if use_korean {
+            "IFAB Law 12.2: 골키퍼가 자기 페널티 에어리어 안에서 6초 넘게 공을 소유하거나, 팀 동료의 의도적 킥/스로인을 손으로 처리하면 간접 프리킥입니다."
+        } else {
+            "IFAB Law 12.2: An indirect free kick is awarded if a goalkeeper inside their penalty area holds the ball for more than six seconds, or handles it after a team-mate's deliberate kick or throw-in."
         })],
     });
 }
@@ -680,8 +993,8 @@ fn generate_var_cards(cards: &mut Vec<CardBlock>, details: Option<&EventDetails>
 mod tests {
     use super::*;
     use crate::models::rules::{
-        DeflectionContext, DefenderTouchType, FoulDetails, FoulType, OffsideDetails,
-        OffsideRestartContext, RestartType,
+        AdvantageDetails, DeflectionContext, DefenderTouchType, FoulDetails, FoulType,
+        OffsideDetails, OffsideRestartContext, RestartType,
     };
 
     #[test]
@@ -701,6 +1014,7 @@ mod tests {
                     last_touch_by_defender: DefenderTouchType::None,
                     resets_offside: false,
                 }),
+                ..Default::default()
             }),
             rule_id: Some(RuleId::OffsidePosition),
             ..Default::default()
@@ -748,4 +1062,67 @@ mod tests {
             Some("player_track_id")
         );
     }
+
+    #[test]
+    fn test_generate_advantage_card_explains_reduced_sanction() {
+        let details = EventDetails {
+            advantage: Some(AdvantageDetails {
+                outcome: AdvantageOutcome::Accrued,
+                severity: FoulSeverity::Careless,
+                is_dogso: true,
+                stopped_promising_attack: false,
+            }),
+            rule_id: Some(RuleId::Advantage),
+            ..Default::default()
+        };
+
+        let card = generate_ui_card(&EventType::AdvantagePlayed, Some(&details), false).unwrap();
+        assert_eq!(card.cards.len(), 3);
+        assert_eq!(card.rule.as_ref().map(|r| r.law_number), Some(5));
+        let applied = card.cards[1].lines.iter().find(|l| l.key.as_deref() == Some("Sanction applied"));
+        assert_eq!(applied.and_then(|l| l.value.clone()), Some(json!("Yellow card")));
+
+        // Schema-valid fallback without details
+        let bare = generate_ui_card(&EventType::AdvantagePlayed, None, true).unwrap();
+        assert_eq!(bare.cards.len(), 3);
+    }
+
+    #[test]
+    fn test_generate_goalkeeper_offence_cards_cite_law_12_2() {
+        let event = MatchEvent::back_pass_offence(20, 1_200_000, true, 0, Some(3), (8.0, 34.0, 0.0), true);
+        let card = generate_ui_card_from_match_event(&event, false).unwrap();
+        assert_eq!(card.cards.len(), 3);
+        assert_eq!(card.rule.as_ref().map(|r| r.rule_id.as_str()), Some("BACK_PASS"));
+        assert_eq!(card.rule.as_ref().map(|r| r.law_number), Some(12));
+        let source = card.cards[1].lines.iter().find(|l| l.key.as_deref() == Some("Ball came from"));
+        assert_eq!(source.and_then(|l| l.value.clone()), Some(json!("Team-mate's throw-in")));
+
+        let event = MatchEvent::goalkeeper_holding(55, 3_300_000, false, 11, (97.0, 34.0, 1.0), 6_500);
+        let card = generate_ui_card_from_match_event(&event, true).unwrap();
+        let held = card.cards[1].lines.iter().find(|l| l.key.as_deref() == Some("소유 시간(초)"));
+        assert_eq!(held.and_then(|l| l.value.clone()), Some(json!(6.5)));
+
+        let handball = generate_ui_card(&EventType::Handball, None, false).unwrap();
+        assert_eq!(handball.rule.as_ref().map(|r| r.rule_id.as_str()), Some("HANDBALL"));
+        assert_eq!(handball.cards.len(), 3);
+    }
+
+    #[test]
+    fn test_generate_var_decision_card_explains_overturn() {
+        let details = crate::models::VarReviewDetails {
+            reviewed_event_type: EventType::Goal,
+            outcome: Some(VarReviewOutcome::Overturned),
+            incident: Some(VarIncident::OffsideOnGoal),
+            overturn_probability: Some(1.0),
+            duration_s: Some(95),
+            offside_margin_m: Some(0.42),
+            overturned_restart: Some("indirect_free_kick".to_string()),
+        };
+        let event = MatchEvent::var_decision(70, 4_200_000, true, Some(9), details);
+        let card = generate_ui_card_from_match_event(&event, false).unwrap();
+        assert_eq!(card.cards.len(), 3);
+        assert_eq!(card.cards[0].lines[0].text, "VAR overturned the on-field decision.");
+        let margin = card.cards[1].lines.iter().find(|l| l.key.as_deref() == Some("Offside margin"));
+        assert_eq!(margin.and_then(|l| l.value.clone()), Some(json!("0.42m")));
+    }
 }
@@ -657,6 +657,7 @@ mod tests {
                 last_touch_by_defender: DefenderTouchType::None,
                 resets_offside: false,
             }),
+            ..Default::default()
         };
 
         let explanation = generate_offside_why_explanation(&details, "손흥민", true);
@@ -690,6 +691,7 @@ mod tests {
                 last_touch_by_defender: DefenderTouchType::None,
                 resets_offside: false,
             }),
+            ..Default::default()
         };
 
         let explanation = generate_offside_why_explanation(&details, "Son", false);
@@ -770,6 +772,7 @@ mod tests {
                 restart_context: None,
                 touch_reference: None,
                 deflection_context: None,
+                ..Default::default()
             }),
             ..Default::default()
         };
@@ -14,6 +14,7 @@
 //! pending → active (Approach → Commit → Resolve → Recover → Cooldown → Finished)
 //! ```
 
+use crate::engine::strict_math::StrictMath;
 use fxhash::FxHasher;
 use serde::{Deserialize, Serialize};
 use std::cmp::Reverse;
@@ -69,6 +70,7 @@ use super::types::coord10::{Coord10, Vel10};
 // Contract v1: OutcomeSet sampler
 use crate::engine::match_sim::decision_topology::{select_outcome_softmax, ShotOutcome};
 use crate::engine::weights::WeightBreakdown;
+use crate::engine::xg_model::{contextual_xg, ShotAssistType, ShotBodyPart, XgFeatures};
 
 #[cfg(debug_assertions)]
 #[derive(Default)]
@@ -1470,8 +1472,8 @@ pub enum ActionResult {
 
     /// 골키퍼 핸들링 위반 (핸드볼)
     ///
-    /// - `is_indirect=false`: 페널티 에어리어 밖에서 손으로 처리(직접 프리킥)
-    /// - `is_indirect=true`: 백패스/스로인 등으로 인한 핸들링(간접 프리킥, TODO)
+    /// - `is_indirect=false`: 페널티 에어리어 밖에서 손으로 처리(직접 프리킥, 핸드볼)
+    /// - `is_indirect=true`: 백패스/스로인 등으로 인한 핸들링(간접 프리킥, Law 12.2)
     ///
     /// NOTE: 실제 재개 위치/팀은 `BallState::OutOfPlay`에 의해 결정된다.
     GoalkeeperHandlingViolation {
@@ -1479,6 +1481,8 @@ pub enum ActionResult {
         /// 마지막으로 공을 터치한 선수(예: 슈터/패서). 없으면 None.
         last_touch_idx: Option<usize>,
         is_indirect: bool,
+        /// 팀 동료의 스로인을 손으로 처리 (`is_indirect=true`일 때만 의미)
+        from_throw_in: bool,
         /// 슛 상황이면 xG 포함 (없으면 None)
         xg: Option<f32>,
     },
@@ -1681,6 +1685,8 @@ pub struct ActionQueue {
     pub last_shot_xg: Option<f32>,
     /// 마지막 슈터 인덱스 (Save 액션에서 사용)
     pub last_shooter_idx: Option<usize>,
+    /// 마지막 슛의 xG 피처 (슈터 인덱스, 피처) - 슛/세이브/골 이벤트에 첨부
+    pub last_shot_features: Option<(usize, XgFeatures)>,
 
     // ========== FIX_2601/0102: Assist Candidate System ==========
     /// 마지막 패스한 선수 인덱스 (어시스트 추적용)
@@ -1690,6 +1696,8 @@ pub struct ActionQueue {
     pub last_pass_receiver_idx: Option<usize>,
     /// 마지막 패스 타입 (크로스/스루 통계용)
     pub last_pass_type: Option<PassType>,
+    /// 마지막 패스 수신 (수신자, 패스 타입) - 슛 어시스트 타입 판정용
+    pub last_reception: Option<(usize, PassType)>,
     /// 마지막 헤딩 결과 (헤딩 통계용)
     pub last_header_outcome: Option<HeaderOutcome>,
     /// In-flight origin marker (set-piece deliveries, etc.).
@@ -1729,6 +1737,24 @@ impl ActionQueue {
         self.last_pass_type = None;
     }
 
+    /// 패스 수신 기록 (슛 어시스트 타입 판정용)
+    ///
+    /// 트랩 처리에서 패스 타입이 초기화되기 전에 호출. 상대 패스를 받은 경우는 제외.
+    pub fn note_reception(&mut self, receiver_idx: usize) {
+        let same_team = self
+            .last_passer_idx
+            .is_some_and(|p| TeamSide::is_home(p) == TeamSide::is_home(receiver_idx));
+        self.last_reception = self.last_pass_type.filter(|_| same_team).map(|t| (receiver_idx, t));
+    }
+
+    /// 해당 슈터의 마지막 슛 xG 피처 (다른 선수의 슛이면 None)
+    pub fn last_shot_features_for(&self, shooter_idx: usize) -> Option<XgFeatures> {
+        self.last_shot_features
+            .as_ref()
+            .filter(|(idx, _)| *idx == shooter_idx)
+            .map(|(_, features)| features.clone())
+    }
+
     pub fn take_last_header_outcome(&mut self) -> Option<HeaderOutcome> {       
         self.last_header_outcome.take()
     }
@@ -2732,10 +2758,12 @@ impl ActionQueue {
             }
             ActionResult::TrapSuccess { player_idx } => {
                 self.ball_state = BallState::Controlled { owner_idx: *player_idx };
+                self.last_shot_features = None;
                 self.last_pass_type = None;
                 self.in_flight_origin = None;
             }
             ActionResult::TrapFailed { loose_ball_pos, .. } => {
+                self.last_reception = None;
                 self.last_passer_idx = None;
                 self.last_pass_receiver_idx = None;
                 self.last_pass_type = None;
@@ -2749,22 +2777,29 @@ impl ActionQueue {
                 self.ball_state = BallState::Controlled { owner_idx: *player_idx };
             }
             ActionResult::TakeOnComplete { player_idx, .. } => {
-                // 돌파 성공 - 드리블 통계에 기록됨
+                // 돌파 성공 - 드리블 통계에 기록됨 (슛 찬스는 본인이 만든 것)
+                self.last_reception = None;
                 self.ball_state = BallState::Controlled { owner_idx: *player_idx };
             }
             ActionResult::DribbleTackled { tackler_idx, .. } => {
                 self.ball_state = BallState::Controlled { owner_idx: *tackler_idx };
             }
             ActionResult::InterceptSuccess { player_idx } => {
+                self.last_reception = None;
+                self.last_shot_features = None;
                 self.last_passer_idx = None;
                 self.last_pass_receiver_idx = None;
                 self.last_pass_type = None;
                 self.ball_state = BallState::Controlled { owner_idx: *player_idx };
             }
             ActionResult::TackleSuccess { tackler_idx, .. } => {
+                self.last_reception = None;
+                self.last_shot_features = None;
                 self.ball_state = BallState::Controlled { owner_idx: *tackler_idx };
             }
             ActionResult::OutOfBounds { restart_type, position, home_team } => {
+                self.last_reception = None;
+                self.last_shot_features = None;
                 self.last_passer_idx = None;
                 self.last_pass_receiver_idx = None;
                 self.last_pass_type = None;
@@ -2820,6 +2855,8 @@ impl ActionQueue {
         self.last_passer_idx = None;
         self.last_pass_receiver_idx = None;
         self.last_pass_type = None;
+        self.last_reception = None;
+        self.last_shot_features = None;
         self.last_header_outcome = None;
         self.in_flight_origin = None;
     }
@@ -3093,9 +3130,11 @@ impl ActionQueue {
             current_tick: self.current_tick,
             last_shot_xg: self.last_shot_xg,
             last_shooter_idx: self.last_shooter_idx,
+            last_shot_features: self.last_shot_features.clone(),
             last_passer_idx: self.last_passer_idx,
             last_pass_receiver_idx: self.last_pass_receiver_idx,
             last_pass_type: self.last_pass_type,
+            last_reception: self.last_reception,
             last_header_outcome: self.last_header_outcome.clone(),
             in_flight_origin: self.in_flight_origin,
         }
@@ -3117,9 +3156,11 @@ impl ActionQueue {
             current_tick: snapshot.current_tick,
             last_shot_xg: snapshot.last_shot_xg,
             last_shooter_idx: snapshot.last_shooter_idx,
+            last_shot_features: snapshot.last_shot_features,
             last_passer_idx: snapshot.last_passer_idx,
             last_pass_receiver_idx: snapshot.last_pass_receiver_idx,
             last_pass_type: snapshot.last_pass_type,
+            last_reception: snapshot.last_reception,
             last_header_outcome: snapshot.last_header_outcome,
             in_flight_origin: snapshot.in_flight_origin,
         }
@@ -3151,6 +3192,8 @@ pub struct ExecutionContext {
     pub home_match_modifiers: super::TeamMatchModifiers,
     /// FIX_2601/0109: Sparse scalar modifiers for Away team (deck/coach, etc.).
     pub away_match_modifiers: super::TeamMatchModifiers,
+    /// Chemistry-based passer → receiver link-up multipliers (None = neutral).
+    pub pass_link_modifiers: Option<std::sync::Arc<super::PassLinkModifiers>>,
 
     /// Rulebook: optional non-GK handball triggers enabled (executor-only).
     pub rulebook_non_gk_handball_enabled: bool,
@@ -3158,6 +3201,14 @@ pub struct ExecutionContext {
     pub rulebook_non_gk_handball_prob_mult: f32,
     /// Rulebook: advantage play enabled (executor-only).
     pub rulebook_advantage_play_enabled: bool,
+    /// Referee: foul call frequency multiplier (1.0 = neutral official).
+    pub referee_foul_call_mult: f32,
+    /// Referee: probability of playing advantage when the situation allows it.
+    pub referee_advantage_rate: f32,
+    /// Referee: opponents tolerated near the foul when playing advantage.
+    pub referee_advantage_pressure_tolerance: usize,
+    /// Designer tunables (all 1.0 = shipped behaviour).
+    pub parameters: crate::calibration::ParameterSet,
 
     /// FIX_2601/1120: Current ball position (meters) for accurate InFlight origin.
     /// This prevents teleportation when starting passes/shots - the ball should start
@@ -3235,6 +3286,28 @@ fn calculate_pressure_simple(player_pos: (f32, f32), nearby_opponents: &[(f32, f
     (pressure / 2.0).min(1.0)
 }
 
+/// 상대 필드 플레이어 인덱스 (GK 제외)
+fn opponent_range_outfield(shooter_is_home: bool, gk_idx: usize) -> impl Iterator<Item = usize> {
+    let range = if shooter_is_home { 11..22 } else { 0..11 };
+    range.filter(move |&i| i != gk_idx)
+}
+
+/// 슈터가 공을 어떻게 받았는지 (xG 어시스트 타입)
+///
+/// 공중볼 슛(발리/헤딩)은 진행 중인 패스, 그 외에는 슈터가 마지막으로 받은 패스 기준.
+fn shot_assist_type(queue: &ActionQueue, shooter_idx: usize) -> ShotAssistType {
+    let pass_type = match queue.ball_state {
+        BallState::InFlight { .. } => queue.last_pass_type,
+        _ => queue.last_reception.filter(|(idx, _)| *idx == shooter_idx).map(|(_, t)| t),
+    };
+    match pass_type {
+        None => ShotAssistType::Unassisted,
+        Some(PassType::Cross) => ShotAssistType::Cross,
+        Some(PassType::ThroughBall) => ShotAssistType::ThroughBall,
+        Some(_) => ShotAssistType::Pass,
+    }
+}
+
 /// 패스 실행
 pub fn execute_pass(
     action: &ScheduledAction,
@@ -3410,7 +3483,14 @@ pub fn execute_pass(
 
     // 패스 속도 계산 (ActionModel 물리 적용)
     // physics.speed는 기술별 기본 속도 (m/s)
-    let ball_speed = physics.speed + (distance * 0.1).min(5.0); // 거리에 따라 약간 보정
+    // 날씨/피치 상태 보정 (ball_speed_mult)
+    let ball_speed_mult = if action.player_idx < 11 {
+        ctx.home_match_modifiers.ball_speed_mult
+    } else {
+        ctx.away_match_modifiers.ball_speed_mult
+    };
+    // 거리에 따라 약간 보정
+    let ball_speed = (physics.speed + (distance * 0.1).min(5.0)) * ball_speed_mult;
 
     // 비행 시간 계산 (틱 단위, 10 ticks/sec)
     let flight_ticks = ((distance / ball_speed) * TICKS_PER_SECOND as f32).ceil() as u64;
@@ -3638,6 +3718,7 @@ pub fn execute_trap(
                 goalkeeper_idx: action.player_idx,
                 last_touch_idx: last_passer_idx,
                 is_indirect: false,
+                from_throw_in: false,
                 xg: None,
             };
         }
@@ -3656,6 +3737,7 @@ pub fn execute_trap(
                 goalkeeper_idx: action.player_idx,
                 last_touch_idx: None,
                 is_indirect: true,
+                from_throw_in: true,
                 xg: None,
             };
         }
@@ -3676,6 +3758,7 @@ pub fn execute_trap(
                 goalkeeper_idx: action.player_idx,
                 last_touch_idx: last_passer_idx,
                 is_indirect: true,
+                from_throw_in: false,
                 xg: None,
             };
         }
@@ -3700,18 +3783,24 @@ pub fn execute_trap(
             ..
         } if *receiver_idx == action.player_idx
     );
+    // Chemistry: passer → receiver link-up (bounded, neutral without chemistry data).
+    let pass_link_mult = match (&ctx.pass_link_modifiers, queue.last_passer_idx) {
+        (Some(links), Some(passer_idx)) if is_pass_trap => links.get(passer_idx, action.player_idx),
+        _ => 1.0,
+    };
+    let pass_mult = match_modifiers.pass_success_mult * pass_link_mult;
     let first_touch = if is_pass_trap {
-        apply_attribute_multiplier(stats.first_touch, match_modifiers.pass_success_mult)
+        apply_attribute_multiplier(stats.first_touch, pass_mult)
     } else {
         stats.first_touch
     };
     let composure = if is_pass_trap {
-        apply_attribute_multiplier(stats.composure, match_modifiers.pass_success_mult)
+        apply_attribute_multiplier(stats.composure, pass_mult)
     } else {
         stats.composure
     };
     let anticipation = if is_pass_trap {
-        apply_attribute_multiplier(stats.anticipation, match_modifiers.pass_success_mult)
+        apply_attribute_multiplier(stats.anticipation, pass_mult)
     } else {
         stats.anticipation
     };
@@ -3813,6 +3902,11 @@ pub fn execute_trap(
         .with_stats(first_touch, composure, anticipation)
         .with_context(pressure, ball_difficulty, false)
         .with_decision_quality_mult(decision_quality_mult);
+    let err_ctx = if is_pass_trap {
+        err_ctx.with_sigma_mult(ctx.parameters.pass_error)
+    } else {
+        err_ctx
+    };
 
     // RNG로 ExecutionError 샘플링
     let mut rng = ChaCha8Rng::seed_from_u64(
@@ -4063,18 +4157,6 @@ pub fn execute_shot(
     let dy = goal_center_y - player_pos_m.1;
     let distance = (dx * dx + dy * dy).sqrt();
 
-    // xG 계산 using phase_action::calculate_xg_with_target for P0 Goal Contract compliance
-    // goal_x is explicitly determined by attacking team's goal
-    use crate::engine::phase_action::calculate_xg_with_target;
-    let xg = calculate_xg_with_target(
-        player_pos_m,
-        ShotType::Normal, // P0: ShotType is now local to action_queue
-        0,                // defenders_blocking (simplified)
-        false,            // is_one_on_one
-        stats.finishing,
-        Some(goal_x), // P0: 공격 골대 명시적 지정
-    );
-
     // GK 위치를 고려한 슛 타겟 결정
     // 슈터는 GK에서 먼 쪽 구석을 노림
     let gk_idx = TeamSide::opponent_gk(action.player_idx);
@@ -4148,10 +4230,36 @@ pub fn execute_shot(
         _ => (false, 0.0), // Ground ball
     };
 
+    // Contextual xG: 위치/각도/신체 부위/압박/어시스트 타입/GK 위치 (슈터 능력치 제외)
+    // goal_x is explicitly determined by attacking team's goal (P0 Goal Contract)
+    let body_part = if ball_height_m > 1.5 {
+        ShotBodyPart::Head
+    } else if ball_airborne {
+        ShotBodyPart::Volley
+    } else {
+        ShotBodyPart::Foot
+    };
+    let defenders_m: Vec<(f32, f32)> = opponent_range_outfield(shooter_is_home, gk_idx)
+        .map(|i| ctx.player_positions[i])
+        .collect();
+    let xg_features = XgFeatures::from_positions(
+        player_pos_m,
+        goal_x,
+        gk_pos_m,
+        &defenders_m,
+        pressure,
+        body_part,
+        shot_assist_type(queue, action.player_idx),
+    );
+    let xg = contextual_xg(&xg_features);
+    queue.last_shot_features = Some((action.player_idx, xg_features));
+    // 결정력은 기록 xG가 아닌 결과 가중치에만 반영 (max ±0.10)
+    let finishing_xg = (xg + (stats.finishing as f32 - 50.0) / 500.0).clamp(0.01, 0.85);
+
     // ActionModel Integration: 상황 기반 Intent/Technique 선택
     let shot_ctx = ShotContext {
         distance_to_goal: distance,
-        angle_to_goal: (dy.atan2(dx).to_degrees()).abs(),
+        angle_to_goal: (dy.det_atan2(dx).to_degrees()).abs(),
         defenders_ahead: nearby_opponents.len() as u8,
         gk_distance: ((gk_pos_m.0 - player_pos_m.0).powi(2)
             + (gk_pos_m.1 - player_pos_m.1).powi(2))
@@ -4194,7 +4302,8 @@ pub fn execute_shot(
     let skill = (stats.technique as f32 + stats.finishing as f32) / 200.0;
     let lift_ratio = compute_lift_ratio(lift_intent, skill, pressure);
 
-    let base_speed = (25.0 + power * 10.0) * match_modifiers.shot_power_mult;
+    let base_speed =
+        (25.0 + power * 10.0) * match_modifiers.shot_power_mult * match_modifiers.ball_speed_mult;
     let flight_time = if base_speed > 0.0 { distance / base_speed } else { 0.0 };
     let vz = height_profile.vz_cap_mps() * lift_ratio;
     let intended_height =
@@ -4298,8 +4407,11 @@ pub fn execute_shot(
         // FIX_2601/0116: 골 전환율 상향 (xG 대비 실제 골 30% → 95% 목표)
         // 기존: xG ~0.12 * 1.5 = 0.18 → 경쟁에서 밀림
         // 수정: xG ~0.12 * 3.0 = 0.36 → Goal 가중치 상향
-        w_goal.context *= (xg * 3.0).clamp(0.05, 1.0);
+        // Contextual xG: 슛당 평균 xG 상승 (0.095 → 0.16) → 3.0 → 1.8 (평균 가중치 유지)
+        w_goal.context *= (finishing_xg * 1.8).clamp(0.05, 1.0);
     }
+    // Designer tunable (calibration::ParameterSet)
+    w_goal.context *= ctx.parameters.shot_conversion;
 
     // FIX_2601/0109: GK 능력치 기반 세이브 확률 (통합 함수 사용)
     use crate::engine::match_sim::attribute_calc::calculate_gk_save_prob_unified;
@@ -4318,7 +4430,7 @@ pub fn execute_shot(
     // FIX_2601/0116: GK 세이브 가중치 하향 (골 전환율 상향)
     // 기존: gk_save_prob * (1.5 - xg * 0.5) → 0.4 * 1.44 = 0.58
     // 수정: gk_save_prob * (1.2 - xg * 0.5) → 0.4 * 1.14 = 0.46
-    w_saved.context *= gk_save_prob * (1.2 - xg * 0.5);
+    w_saved.context *= gk_save_prob * (1.2 - finishing_xg * 0.5);
     if actual_height > aerial::GK_CATCH_MAX_M {
         w_saved.context *= 0.3; // 높은 슛은 세이브 어려움
     }
@@ -4416,6 +4528,7 @@ pub fn execute_shot(
                     goalkeeper_idx: gk_idx,
                     last_touch_idx: Some(action.player_idx),
                     is_indirect: false,
+                    from_throw_in: false,
                     xg: Some(xg),
                 };
             }
@@ -4477,6 +4590,7 @@ pub fn execute_shot(
                     goalkeeper_idx: gk_idx,
                     last_touch_idx: Some(action.player_idx),
                     is_indirect: false,
+                    from_throw_in: false,
                     xg: Some(xg),
                 };
             }
@@ -4686,6 +4800,7 @@ pub fn execute_save(
                 goalkeeper_idx: action.player_idx,
                 last_touch_idx: Some(shooter_idx),
                 is_indirect: false,
+                from_throw_in: false,
                 xg: Some(shot_xg),
             };
         }
@@ -4755,8 +4870,8 @@ pub fn execute_save(
 
                 if fumble_roll < fumble_chance && !nearby_attackers.is_empty() {
                     // 펌블! 공이 루즈볼이 됨
-                    let fumble_dir_x = exec_error.dir_angle_deg.to_radians().sin() * 2.0;
-                    let fumble_dir_y = exec_error.dir_angle_deg.to_radians().cos() * 2.0;
+                    let fumble_dir_x = exec_error.dir_angle_deg.to_radians().det_sin() * 2.0;
+                    let fumble_dir_y = exec_error.dir_angle_deg.to_radians().det_cos() * 2.0;
                     let player_pos = ctx.player_positions[action.player_idx];
                     queue.ball_state = BallState::Loose {
                         position: Coord10::from_meters(player_pos.0, player_pos.1),
@@ -4780,8 +4895,8 @@ pub fn execute_save(
 
                 // 오차로 인해 패링 방향이 약간 벗어남
                 let rot_rad = exec_error.dir_angle_deg.to_radians() * 0.5; // 절반만 적용
-                let cos_r = rot_rad.cos();
-                let sin_r = rot_rad.sin();
+                let cos_r = rot_rad.det_cos();
+                let sin_r = rot_rad.det_sin();
                 let parry_x = base_parry_x * cos_r - base_parry_y * sin_r;
                 let parry_y = base_parry_x * sin_r + base_parry_y * cos_r;
 
@@ -4932,8 +5047,8 @@ pub fn execute_header(
         let random_angle = random2 * std::f32::consts::TAU;
         let random_dist = 5.0 + random2 * 10.0; // 5-15m
         let miss_target_m = (
-            (player_pos_m.0 + random_angle.cos() * random_dist).clamp(0.0, field::LENGTH_M),
-            (player_pos_m.1 + random_angle.sin() * random_dist).clamp(0.0, field::WIDTH_M),
+            (player_pos_m.0 + random_angle.det_cos() * random_dist).clamp(0.0, field::LENGTH_M),
+            (player_pos_m.1 + random_angle.det_sin() * random_dist).clamp(0.0, field::WIDTH_M),
         );
         let miss_target = Coord10::from_meters(miss_target_m.0, miss_target_m.1);
 
@@ -4985,17 +5100,22 @@ pub fn execute_header(
         let dy = goal_center_y - player_pos_m.1;
         let distance = (dx * dx + dy * dy).sqrt();
 
-        // xG calculation for headed shot (generally lower than regular shot)
-        let base_xg = if distance < 6.0 {
-            0.45 // Close header
-        } else if distance < 12.0 {
-            0.20 // Penalty box header
-        } else {
-            0.05 // Long range header
-        };
-
-        let header_mod = heading_skill * 0.3;
-        let xg = (base_xg * (1.0 + header_mod)).min(0.80);
+        // Contextual xG for headed shot (heading skill already decided the header duel)
+        let opp_gk_idx = TeamSide::opponent_gk(action.player_idx);
+        let defenders_m: Vec<(f32, f32)> = opponent_range_outfield(is_home, opp_gk_idx)
+            .map(|i| ctx.player_positions[i])
+            .collect();
+        let xg_features = XgFeatures::from_positions(
+            player_pos_m,
+            goal_x,
+            ctx.player_positions[opp_gk_idx],
+            &defenders_m,
+            calculate_pressure_simple(player_pos_m, &defenders_m),
+            ShotBodyPart::Head,
+            shot_assist_type(queue, action.player_idx),
+        );
+        let xg = contextual_xg(&xg_features);
+        queue.last_shot_features = Some((action.player_idx, xg_features));
 
         // Ball flight
         let ball_speed = 18.0; // Headers are generally slower than shots
@@ -5204,11 +5324,16 @@ pub fn execute_tackle(
     // v2: 0.18 + (1 - tackling/100) * 0.18 + aggression/100 * 0.12 = 18~48%
     let aggression_factor = tackler_stats.aggression as f32 / 100.0 * 0.12;
     let foul_rate = 0.18 + (1.0 - tackler_stats.tackling as f32 / 100.0) * 0.18 + aggression_factor;
+    // Referee strictness: how readily the contact is whistled (crowd lean per side).
+    let foul_rate =
+        (foul_rate * ctx.referee_foul_call_mult * match_modifiers.foul_call_mult).min(0.95);
 
     // Apply sparse team-wide modifier without changing foul probability.
     let max_success_rate = (1.0 - foul_rate).max(0.0);
-    success_rate =
-        (success_rate * match_modifiers.tackle_success_mult).clamp(0.0, max_success_rate);
+    success_rate = (success_rate
+        * match_modifiers.tackle_success_mult
+        * ctx.parameters.pressing_efficiency)
+        .clamp(0.0, max_success_rate);
 
     let random = simple_random(ctx.rng_seed, ctx.current_tick, action.player_idx + 300);
 
@@ -5267,12 +5392,12 @@ pub fn execute_tackle(
                             let dy = op.1 - foul_pos_m.1;
                             if dx * dx + dy * dy <= r2 {
                                 opponents_near += 1;
-                                if opponents_near >= 2 {
-                                    break;
-                                }
                             }
                         }
-                        opponents_near < 2
+                        // Referee tendency: tolerated pressure, then a deterministic roll.
+                        opponents_near <= ctx.referee_advantage_pressure_tolerance
+                            && simple_random(ctx.rng_seed, ctx.current_tick, action.player_idx + 310)
+                                < ctx.referee_advantage_rate
                     }
                 }
             }
@@ -5351,6 +5476,7 @@ pub fn execute_intercept(
         let distance_penalty = (distance / 5.0).min(0.5);
         (base_rate - distance_penalty).max(0.1)
     };
+    let success_rate = success_rate * ctx.parameters.pressing_efficiency;
 
     let random = simple_random(ctx.rng_seed, ctx.current_tick, action.player_idx + 400);
 
@@ -5531,7 +5657,7 @@ fn non_gk_handball_prob_on_aerial_trap_fail(pressure: f32, trapper_stats: &Playe
     let composure = (trapper_stats.composure as f32 / 100.0).clamp(0.0, 1.0);
     let first_touch = (trapper_stats.first_touch as f32 / 100.0).clamp(0.0, 1.0);
     let skill = 0.6 * composure + 0.4 * first_touch;
-    let pressure_factor = pressure.powf(1.2);
+    let pressure_factor = pressure.det_powf(1.2);
     let skill_factor = (1.0 - 0.75 * skill).clamp(0.25, 1.0);
 
     (NON_GK_HANDBALL_AERIAL_TRAP_BASE_PROB * pressure_factor * skill_factor).clamp(0.0, 0.02)
@@ -6369,9 +6495,14 @@ mod tests {
             away_ctx: DirectionContext::new(false), // Away attacks left
             home_match_modifiers: crate::engine::TeamMatchModifiers::default(),
             away_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+            pass_link_modifiers: None,
             rulebook_non_gk_handball_enabled: false,
             rulebook_non_gk_handball_prob_mult: 1.0,
             rulebook_advantage_play_enabled: false,
+            referee_foul_call_mult: 1.0,
+            referee_advantage_rate: 1.0,
+            referee_advantage_pressure_tolerance: 1,
+            parameters: Default::default(),
             // FIX_2601/1120: Default ball position for tests
             ball_position: (field::CENTER_X, field::CENTER_Y),
         }
@@ -6415,9 +6546,14 @@ mod tests {
                 away_ctx: DirectionContext::new(false),
                 home_match_modifiers: crate::engine::TeamMatchModifiers::default(),
                 away_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+                pass_link_modifiers: None,
                 rulebook_non_gk_handball_enabled: false,
                 rulebook_non_gk_handball_prob_mult: 1.0,
                 rulebook_advantage_play_enabled: advantage_enabled,
+                referee_foul_call_mult: 1.0,
+                referee_advantage_rate: 1.0,
+                referee_advantage_pressure_tolerance: 1,
+                parameters: Default::default(),
                 ball_position: (60.0, field::CENTER_Y), // FIX_2601/1120
             }
         }
@@ -6496,9 +6632,14 @@ mod tests {
                 away_ctx: DirectionContext::new(false),
                 home_match_modifiers: crate::engine::TeamMatchModifiers::default(),
                 away_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+                pass_link_modifiers: None,
                 rulebook_non_gk_handball_enabled: false,
                 rulebook_non_gk_handball_prob_mult: 1.0,
                 rulebook_advantage_play_enabled: advantage_enabled,
+                referee_foul_call_mult: 1.0,
+                referee_advantage_rate: 1.0,
+                referee_advantage_pressure_tolerance: 1,
+                parameters: Default::default(),
                 ball_position: (60.0, field::CENTER_Y), // FIX_2601/1120
             }
         }
@@ -6604,9 +6745,14 @@ mod tests {
                 away_ctx: DirectionContext::new(false),
                 home_match_modifiers: crate::engine::TeamMatchModifiers::default(),
                 away_match_modifiers,
+                pass_link_modifiers: None,
                 rulebook_non_gk_handball_enabled: false,
                 rulebook_non_gk_handball_prob_mult: 1.0,
                 rulebook_advantage_play_enabled: false,
+                referee_foul_call_mult: 1.0,
+                referee_advantage_rate: 1.0,
+                referee_advantage_pressure_tolerance: 1,
+                parameters: Default::default(),
                 ball_position: (field::CENTER_X, field::CENTER_Y), // FIX_2601/1120
             }
         }
@@ -7021,9 +7167,14 @@ mod tests {
                 away_ctx: DirectionContext::new(false),
                 home_match_modifiers: crate::engine::TeamMatchModifiers::default(),
                 away_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+                pass_link_modifiers: None,
                 rulebook_non_gk_handball_enabled: false,
                 rulebook_non_gk_handball_prob_mult: 1.0,
                 rulebook_advantage_play_enabled: false,
+                referee_foul_call_mult: 1.0,
+                referee_advantage_rate: 1.0,
+                referee_advantage_pressure_tolerance: 1,
+                parameters: Default::default(),
                 ball_position: (field::CENTER_X, field::CENTER_Y), // FIX_2601/1120
             };
 
@@ -7096,9 +7247,14 @@ mod tests {
             away_ctx: DirectionContext::new(false),
             home_match_modifiers: crate::engine::TeamMatchModifiers::default(),
             away_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+            pass_link_modifiers: None,
             rulebook_non_gk_handball_enabled: false,
             rulebook_non_gk_handball_prob_mult: 1.0,
             rulebook_advantage_play_enabled: false,
+            referee_foul_call_mult: 1.0,
+            referee_advantage_rate: 1.0,
+            referee_advantage_pressure_tolerance: 1,
+            parameters: Default::default(),
             ball_position: (field::CENTER_X, field::CENTER_Y), // FIX_2601/1120
         };
 
@@ -7117,6 +7273,7 @@ mod tests {
                 goalkeeper_idx: 0,
                 last_touch_idx: Some(20),
                 is_indirect: false,
+                from_throw_in: false,
                 xg: Some(_),
             }
         ));
@@ -7152,9 +7309,14 @@ mod tests {
             away_ctx: DirectionContext::new(false),
             home_match_modifiers: crate::engine::TeamMatchModifiers::default(),
             away_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+            pass_link_modifiers: None,
             rulebook_non_gk_handball_enabled: false,
             rulebook_non_gk_handball_prob_mult: 1.0,
             rulebook_advantage_play_enabled: false,
+            referee_foul_call_mult: 1.0,
+            referee_advantage_rate: 1.0,
+            referee_advantage_pressure_tolerance: 1,
+            parameters: Default::default(),
             ball_position: (field::CENTER_X, field::CENTER_Y), // FIX_2601/1120
         };
 
@@ -7173,6 +7335,7 @@ mod tests {
                 goalkeeper_idx: 0,
                 last_touch_idx: Some(5),
                 is_indirect: true,
+                from_throw_in: false,
                 xg: None,
             }
         ));
@@ -7208,9 +7371,14 @@ mod tests {
             away_ctx: DirectionContext::new(false),
             home_match_modifiers: crate::engine::TeamMatchModifiers::default(),
             away_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+            pass_link_modifiers: None,
             rulebook_non_gk_handball_enabled: false,
             rulebook_non_gk_handball_prob_mult: 1.0,
             rulebook_advantage_play_enabled: false,
+            referee_foul_call_mult: 1.0,
+            referee_advantage_rate: 1.0,
+            referee_advantage_pressure_tolerance: 1,
+            parameters: Default::default(),
             ball_position: (field::CENTER_X, field::CENTER_Y), // FIX_2601/1120
         };
 
@@ -7229,6 +7397,7 @@ mod tests {
                 goalkeeper_idx: 0,
                 last_touch_idx: Some(5),
                 is_indirect: false,
+                from_throw_in: false,
                 xg: None,
             }
         ));
@@ -7264,9 +7433,14 @@ mod tests {
             away_ctx: DirectionContext::new(false),
             home_match_modifiers: crate::engine::TeamMatchModifiers::default(),
             away_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+            pass_link_modifiers: None,
             rulebook_non_gk_handball_enabled: false,
             rulebook_non_gk_handball_prob_mult: 1.0,
             rulebook_advantage_play_enabled: false,
+            referee_foul_call_mult: 1.0,
+            referee_advantage_rate: 1.0,
+            referee_advantage_pressure_tolerance: 1,
+            parameters: Default::default(),
             ball_position: (field::CENTER_X, field::CENTER_Y), // FIX_2601/1120
         };
 
@@ -7304,9 +7478,14 @@ mod tests {
             away_ctx: DirectionContext::new(false),
             home_match_modifiers: crate::engine::TeamMatchModifiers::default(),
             away_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+            pass_link_modifiers: None,
             rulebook_non_gk_handball_enabled: false,
             rulebook_non_gk_handball_prob_mult: 1.0,
             rulebook_advantage_play_enabled: false,
+            referee_foul_call_mult: 1.0,
+            referee_advantage_rate: 1.0,
+            referee_advantage_pressure_tolerance: 1,
+            parameters: Default::default(),
             ball_position: (field::CENTER_X, field::CENTER_Y), // FIX_2601/1120
         };
 
@@ -7325,6 +7504,7 @@ mod tests {
                 goalkeeper_idx: 0,
                 last_touch_idx: None,
                 is_indirect: true,
+                from_throw_in: true,
                 xg: None,
             }
         ));
@@ -7363,9 +7543,14 @@ mod tests {
             away_ctx: DirectionContext::new(false),
             home_match_modifiers: crate::engine::TeamMatchModifiers::default(),
             away_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+            pass_link_modifiers: None,
             rulebook_non_gk_handball_enabled: false,
             rulebook_non_gk_handball_prob_mult: 1.0,
             rulebook_advantage_play_enabled: false,
+            referee_foul_call_mult: 1.0,
+            referee_advantage_rate: 1.0,
+            referee_advantage_pressure_tolerance: 1,
+            parameters: Default::default(),
             ball_position: (field::CENTER_X, field::CENTER_Y), // FIX_2601/1120
         };
 
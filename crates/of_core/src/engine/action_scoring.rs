@@ -1,5 +1,6 @@
 /// action_scoring.rs
 /// ACTION_SCORING_SSOT 공용 함수 (compute_score, apply_situational, map_peak, etc)
+use crate::engine::strict_math::StrictMath;
 use super::action_scoring_types::*;
 use crate::models::player::PlayerAttributes;
 use std::collections::HashMap;
@@ -54,7 +55,7 @@ pub fn compute_score(
 
     // 2) 감마 커브 (상위 체감)
     let gamma = score_spec.gamma.unwrap_or(default_gamma);
-    let q2 = q.powf(gamma);
+    let q2 = q.det_powf(gamma);
 
     // 3) 클램프
     q2.clamp(0.0, 1.0)
@@ -86,7 +87,7 @@ pub fn compute_score_combo(
     let q = if sum_w > EPS { acc / sum_w } else { 0.0 };
 
     let gamma = score_combo.gamma.unwrap_or(default_gamma);
-    let q2 = q.powf(gamma);
+    let q2 = q.det_powf(gamma);
 
     q2.clamp(0.0, 1.0)
 }
@@ -142,7 +143,7 @@ pub fn apply_situational(
 pub fn map_peak(peak_range: &PeakRange, quality: f32, default_gamma: f32) -> f32 {
     let gamma = peak_range.gamma.unwrap_or(default_gamma);
     let q = quality.clamp(0.0, 1.0);
-    let q2 = q.powf(gamma);
+    let q2 = q.det_powf(gamma);
 
     peak_range.min + q2 * (peak_range.max - peak_range.min)
 }
@@ -154,7 +155,7 @@ pub fn map_peak(peak_range: &PeakRange, quality: f32, default_gamma: f32) -> f32
 /// Quality → Probability (sigmoid)
 pub fn prob_link(prob_link_spec: &ProbLinkSpec, quality: f32) -> f32 {
     let x = prob_link_spec.steep * (quality - prob_link_spec.mid);
-    let p = 1.0 / (1.0 + (-x).exp());
+    let p = 1.0 / (1.0 + (-x).det_exp());
     p.clamp(0.0, 1.0)
 }
 
@@ -179,7 +180,7 @@ pub fn mix_quality(mix_weights: &HashMap<String, f32>, intent_q: f32, exec_q: f3
 
 /// Execution quality → Error scaling (낮을수록 오차 증가)
 pub fn error_scale(base_error: f32, execution_q: f32, error_link: &ErrorLinkSpec) -> f32 {
-    let scale = (1.0 - execution_q.clamp(0.0, 1.0)).powf(error_link.k);
+    let scale = (1.0 - execution_q.clamp(0.0, 1.0)).det_powf(error_link.k);
     base_error * scale
 }
 
@@ -0,0 +1,657 @@
+//! AI Match Manager (reactive formation / tactics / substitutions)
+//!
+//! CPU 팀 감독: 경기 흐름에 맞춰 포메이션·팀 지시·교체를 바꾼다.
+//! `AITacticalManager`(tactics/ai_profiles.rs)가 TeamInstructions 세부값만
+//! 조정한다면, 이 모듈은 감독 수준의 결정을 내린다.
+//!
+//! ## 반응 상황
+//! - **ChasingGame**: 지고 있음 → 공격적 포메이션 + 수비 자원 대신 공격수 투입
+//! - **ProtectingLead**: 근소하게 앞섬 → 4-5-1 + 공격수 대신 수비 자원 투입
+//! - **OwnRedCard**: 퇴장 → 10명 대형(4-5-1), 수비 라인이 비면 공격수 희생
+//! - **OpponentRedCard**: 상대 퇴장 + 비기거나 지는 중 → 4-3-3 압박 (Hard 이상)
+//! - 동점 복귀 시 원래 포메이션으로 되돌림 (Hard 이상)
+//!
+//! ## 난이도
+//! `MatchPlan::{home,away}_ai_difficulty`가 설정된 팀만 활성화된다.
+//! 난이도가 반응 시작 분, 분당 반응 확률, 전술 교체 수를 정한다.
+//!
+//! ## 결정론
+//! 반응 여부는 `deterministic_bool(seed, minute, side, AI_MANAGER_REACT, ..)`로
+//! 결정되고 선수 선택은 정렬 기준이 고정되어 있다. 엔진 RNG 스트림을 소비하지
+//! 않으므로 같은 시드 → 같은 경기, AI 미사용 경기의 결과는 바뀌지 않는다.
+
+use serde::{Deserialize, Serialize};
+
+use super::match_sim::deterministic::{deterministic_bool, subcase};
+use super::match_sim::MatchEngine;
+use super::substitution_planner::{
+    PlannerBenchPlayer, PlannerPitchPlayer, SubstitutionSquadState, MAX_SUBSTITUTIONS,
+};
+use crate::engine::player_state::PlayerState;
+use crate::models::player::Position;
+use crate::tactics::ai_profiles::AIDifficulty;
+use crate::tactics::team_instructions::{TacticalPreset, TeamInstructions};
+
+/// 감독이 대응 중인 경기 상황
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ManagerSituation {
+    #[default]
+    Neutral,
+    ChasingGame,
+    ProtectingLead,
+    OwnRedCard,
+    OpponentRedCard,
+}
+
+/// 난이도별 감독 성향
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AiManagerProfile {
+    /// 스코어 기반 대응을 시작하는 분
+    pub react_minute: u8,
+    /// 대응 조건 충족 시 분당 반응 확률 (미반응 시 다음 분에 재시도)
+    pub react_chance: f32,
+    /// 이 리드 이하일 때 지키기 모드 (2 → 1~2골 차)
+    pub protect_margin: i8,
+    /// 전술적 교체 최대 수 (피로/부상 교체와 별도)
+    pub max_tactical_subs: u8,
+    /// 퇴장 시 교체로 수비 라인 복구
+    pub red_card_subs: bool,
+    /// 상대 퇴장 시 공세 전환
+    pub exploit_advantage: bool,
+    /// 동점 복귀 시 원래 포메이션/전술로 복귀
+    pub revert_when_level: bool,
+}
+
+impl AiManagerProfile {
+    pub fn for_difficulty(difficulty: AIDifficulty) -> Self {
+        match difficulty {
+            AIDifficulty::Easy => Self {
+                react_minute: 80,
+                react_chance: 0.2,
+                protect_margin: 1,
+                max_tactical_subs: 1,
+                red_card_subs: false,
+                exploit_advantage: false,
+                revert_when_level: false,
+            },
+            AIDifficulty::Medium => Self {
+                react_minute: 72,
+                react_chance: 0.5,
+                protect_margin: 1,
+                max_tactical_subs: 2,
+                red_card_subs: true,
+                exploit_advantage: false,
+                revert_when_level: false,
+            },
+            AIDifficulty::Hard => Self {
+                react_minute: 65,
+                react_chance: 0.8,
+                protect_margin: 2,
+                max_tactical_subs: 3,
+                red_card_subs: true,
+                exploit_advantage: true,
+                revert_when_level: true,
+            },
+            AIDifficulty::Expert => Self {
+                react_minute: 60,
+                react_chance: 1.0,
+                protect_margin: 2,
+                max_tactical_subs: 3,
+                red_card_subs: true,
+                exploit_advantage: true,
+                revert_when_level: true,
+            },
+        }
+    }
+}
+
+/// 감독이 보는 한 팀의 현재 상황
+#[derive(Debug, Clone)]
+pub struct ManagerView<'a> {
+    /// 분, 득실차, 교체 수, 출전/벤치 명단
+    pub squad: &'a SubstitutionSquadState,
+    pub formation: &'a str,
+    pub own_sent_off: u8,
+    pub opponent_sent_off: u8,
+}
+
+/// 전술적 교체 (`execute_substitution` 인자)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ManagerSubstitution {
+    pub track_id: u8,
+    pub bench_slot: u8,
+}
+
+/// 한 번의 감독 결정
+#[derive(Debug, Clone, PartialEq)]
+pub struct ManagerDecision {
+    pub situation: ManagerSituation,
+    /// 바꿀 포메이션 (현재와 같으면 None)
+    pub formation: Option<String>,
+    pub preset: Option<TacticalPreset>,
+    pub substitution: Option<ManagerSubstitution>,
+}
+
+/// 적용된 결정 기록 (UI/리포트용)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ManagerChange {
+    pub minute: u8,
+    pub is_home: bool,
+    pub situation: ManagerSituation,
+    pub formation: Option<String>,
+    pub preset: Option<TacticalPreset>,
+    pub player_out: Option<String>,
+    pub player_in: Option<String>,
+}
+
+/// 팀 하나의 CPU 감독
+#[derive(Debug, Clone)]
+pub struct AiMatchManager {
+    is_home: bool,
+    difficulty: AIDifficulty,
+    profile: AiManagerProfile,
+    seed: u64,
+    situation: ManagerSituation,
+    /// 킥오프 포메이션 (동점 복귀 시 되돌릴 대상)
+    original_formation: Option<String>,
+    handled_own_red: u8,
+    handled_opponent_red: u8,
+    tactical_subs_made: u8,
+    last_minute: Option<u8>,
+    changes: Vec<ManagerChange>,
+}
+
+impl AiMatchManager {
+    pub fn new(is_home: bool, difficulty: AIDifficulty, seed: u64) -> Self {
+        Self {
+            is_home,
+            difficulty,
+            profile: AiManagerProfile::for_difficulty(difficulty),
+            seed,
+            situation: ManagerSituation::Neutral,
+            original_formation: None,
+            handled_own_red: 0,
+            handled_opponent_red: 0,
+            tactical_subs_made: 0,
+            last_minute: None,
+            changes: Vec::new(),
+        }
+    }
+
+    pub fn difficulty(&self) -> AIDifficulty {
+        self.difficulty
+    }
+
+    pub fn situation(&self) -> ManagerSituation {
+        self.situation
+    }
+
+    /// 지금까지 적용된 결정
+    pub fn changes(&self) -> &[ManagerChange] {
+        &self.changes
+    }
+
+    /// 이번 분에 아직 평가하지 않았는지 (분당 1회 평가)
+    pub fn needs_evaluation(&self, minute: u8) -> bool {
+        self.last_minute != Some(minute)
+    }
+
+    /// 현재 상황을 평가해 결정을 반환 (분당 최대 1회)
+    pub fn evaluate(&mut self, view: &ManagerView) -> Option<ManagerDecision> {
+        let minute = view.squad.minute;
+        if !self.needs_evaluation(minute) {
+            return None;
+        }
+        self.last_minute = Some(minute);
+        if self.original_formation.is_none() {
+            self.original_formation = Some(view.formation.to_string());
+        }
+
+        // 퇴장은 즉시 대응 (확률 없음)
+        if view.own_sent_off > self.handled_own_red {
+            self.handled_own_red = view.own_sent_off;
+            return Some(self.respond_to_own_red_card(view));
+        }
+        if view.opponent_sent_off > self.handled_opponent_red {
+            self.handled_opponent_red = view.opponent_sent_off;
+            if self.profile.exploit_advantage && view.squad.goal_diff <= 0 {
+                return Some(self.decide(view, ManagerSituation::OpponentRedCard));
+            }
+        }
+
+        if minute < self.profile.react_minute {
+            return None;
+        }
+        let goal_diff = view.squad.goal_diff;
+        let desired = if goal_diff < 0 {
+            ManagerSituation::ChasingGame
+        } else if goal_diff > 0 && goal_diff <= self.profile.protect_margin {
+            ManagerSituation::ProtectingLead
+        } else {
+            ManagerSituation::Neutral
+        };
+        if desired == self.situation {
+            return None;
+        }
+        let reverting = desired == ManagerSituation::Neutral;
+        if reverting {
+            // 퇴장 대형은 유지, 스코어 대응만 되돌림
+            let score_driven = matches!(
+                self.situation,
+                ManagerSituation::ChasingGame | ManagerSituation::ProtectingLead
+            );
+            if !(score_driven && self.profile.revert_when_level) {
+                return None;
+            }
+        }
+
+        let side = if self.is_home { 0 } else { 1 };
+        if !deterministic_bool(
+            self.seed,
+            minute as u64,
+            side,
+            subcase::AI_MANAGER_REACT,
+            self.profile.react_chance,
+        ) {
+            return None;
+        }
+        Some(self.decide(view, desired))
+    }
+
+    /// 결정이 실제로 적용된 뒤 기록
+    pub fn record(&mut self, change: ManagerChange) {
+        self.changes.push(change);
+    }
+
+    fn respond_to_own_red_card(&mut self, view: &ManagerView) -> ManagerDecision {
+        let chasing_late =
+            view.squad.goal_diff < 0 && view.squad.minute >= self.profile.react_minute;
+        if chasing_late {
+            return self.decide(view, ManagerSituation::ChasingGame);
+        }
+        self.situation = ManagerSituation::OwnRedCard;
+
+        let defenders_left =
+            view.squad.on_pitch.iter().filter(|p| p.available && p.position.is_defender()).count();
+        let substitution = if self.profile.red_card_subs && defenders_left < 4 {
+            self.pick_substitution(view.squad, is_attacker, Position::is_defender)
+        } else {
+            None
+        };
+        ManagerDecision {
+            situation: ManagerSituation::OwnRedCard,
+            formation: changed_formation(view.formation, "4-5-1"),
+            preset: Some(TacticalPreset::Counterattack),
+            substitution,
+        }
+    }
+
+    fn decide(&mut self, view: &ManagerView, situation: ManagerSituation) -> ManagerDecision {
+        self.situation = situation;
+        let short_handed = view.own_sent_off > 0;
+        let (target, preset, substitution) = match situation {
+            ManagerSituation::ChasingGame => {
+                let target = if short_handed {
+                    "4-4-2"
+                } else if view.squad.goal_diff <= -2 && view.squad.minute >= 80 {
+                    "3-4-3"
+                } else {
+                    "4-3-3"
+                };
+                let sub = self.pick_substitution(view.squad, is_holder, Position::is_forward);
+                (target, TacticalPreset::HighPressing, sub)
+            }
+            ManagerSituation::OpponentRedCard => {
+                let sub = self.pick_substitution(view.squad, is_holder, Position::is_forward);
+                ("4-3-3", TacticalPreset::HighPressing, sub)
+            }
+            ManagerSituation::ProtectingLead => {
+                let sub = self.pick_substitution(view.squad, is_attacker, |pos: &Position| {
+                    pos.is_defender() || *pos == Position::CDM
+                });
+                ("4-5-1", TacticalPreset::Defensive, sub)
+            }
+            ManagerSituation::OwnRedCard | ManagerSituation::Neutral => {
+                let original = self.original_formation.clone().unwrap_or_default();
+                return ManagerDecision {
+                    situation,
+                    formation: changed_formation(view.formation, &original),
+                    preset: Some(TacticalPreset::Balanced),
+                    substitution: None,
+                };
+            }
+        };
+        ManagerDecision {
+            situation,
+            formation: changed_formation(view.formation, target),
+            preset: Some(preset),
+            substitution,
+        }
+    }
+
+    /// 가장 지친 `out` 후보 ↔ 가장 능력치 높은 `in` 후보 (부상 대비 1장 남김)
+    fn pick_substitution(
+        &mut self,
+        squad: &SubstitutionSquadState,
+        out_filter: impl Fn(&Position) -> bool,
+        in_filter: impl Fn(&Position) -> bool,
+    ) -> Option<ManagerSubstitution> {
+        if self.tactical_subs_made >= self.profile.max_tactical_subs
+            || squad.substitutions_made + 1 >= MAX_SUBSTITUTIONS
+        {
+            return None;
+        }
+        let out: &PlannerPitchPlayer = squad
+            .on_pitch
+            .iter()
+            .filter(|p| p.available && !p.position.is_goalkeeper() && out_filter(&p.position))
+            .min_by(|a, b| a.stamina.total_cmp(&b.stamina))?;
+        let incoming: &PlannerBenchPlayer = squad
+            .bench
+            .iter()
+            .filter(|b| !b.used && in_filter(&b.position))
+            .max_by_key(|b| (b.overall, std::cmp::Reverse(b.bench_slot)))?;
+        self.tactical_subs_made += 1;
+        Some(ManagerSubstitution { track_id: out.track_id, bench_slot: incoming.bench_slot })
+    }
+}
+
+/// 수비수 또는 수비형 미드필더 (공격 전환 시 교체 대상)
+fn is_holder(pos: &Position) -> bool {
+    pos.is_defender() || *pos == Position::CDM
+}
+
+/// 공격수 또는 공격형 미드필더 (수비 전환 시 교체 대상)
+fn is_attacker(pos: &Position) -> bool {
+    pos.is_forward() || *pos == Position::CAM
+}
+
+fn changed_formation(current: &str, target: &str) -> Option<String> {
+    (!target.is_empty() && current != target).then(|| target.to_string())
+}
+
+impl MatchEngine {
+    /// CPU 감독 평가 (AI 팀만, 분당 1회)
+    pub(crate) fn update_ai_match_managers(&mut self) {
+        let minute = self.minute;
+        for side in 0..2 {
+            let is_home = side == 0;
+            match &self.ai_match_managers[side] {
+                Some(manager) if manager.needs_evaluation(minute) => {}
+                _ => continue,
+            }
+
+            let squad = self.substitution_squad_state(is_home);
+            let own_sent_off = self.sent_off_count(is_home);
+            let opponent_sent_off = self.sent_off_count(!is_home);
+            let formation =
+                if is_home { self.home_formation.clone() } else { self.away_formation.clone() };
+            let view = ManagerView {
+                squad: &squad,
+                formation: &formation,
+                own_sent_off,
+                opponent_sent_off,
+            };
+            let Some(manager) = self.ai_match_managers[side].as_mut() else { continue };
+            let Some(decision) = manager.evaluate(&view) else { continue };
+
+            let change = self.apply_manager_decision(is_home, decision, &squad);
+            if let Some(manager) = self.ai_match_managers[side].as_mut() {
+                manager.record(change);
+            }
+        }
+    }
+
+    /// CPU 감독 결정 기록 [home, away]
+    pub fn ai_manager_changes(&self) -> Vec<ManagerChange> {
+        self.ai_match_managers.iter().flatten().flat_map(|m| m.changes().iter().cloned()).collect()
+    }
+
+    fn sent_off_count(&self, is_home: bool) -> u8 {
+        let start = if is_home { 0 } else { 11 };
+        (start..start + 11)
+            .filter(|&idx| matches!(self.get_player_fsm_state(idx), Some(PlayerState::SentOff)))
+            .count() as u8
+    }
+
+    fn apply_manager_decision(
+        &mut self,
+        is_home: bool,
+        decision: ManagerDecision,
+        squad: &SubstitutionSquadState,
+    ) -> ManagerChange {
+        use crate::engine::tactical_context::TeamSide;
+
+        let team = if is_home { TeamSide::Home } else { TeamSide::Away };
+        let mut formation = None;
+        if let Some(target) = decision.formation {
+            if self.change_formation(team, &target).is_ok() {
+                formation = Some(target);
+            }
+        }
+
+        if let Some(preset) = decision.preset {
+            let current = if is_home { &self.home_instructions } else { &self.away_instructions };
+            let instructions = TeamInstructions {
+                set_pieces: current.set_pieces.clone(),
+                ..TeamInstructions::for_style(preset)
+            };
+            self.apply_tactic_change(team, instructions.clone());
+            self.reapply_opponent_adaptation(is_home);
+            self.log_tactical_change(if is_home { "Home" } else { "Away" }, &instructions);
+        }
+
+        let (mut player_out, mut player_in) = (None, None);
+        if let Some(sub) = decision.substitution {
+            let made_before = squad.substitutions_made;
+            self.execute_substitution(sub.track_id as usize, sub.bench_slot, is_home);
+            let made_after =
+                if is_home { self.substitutions_made.0 } else { self.substitutions_made.1 };
+            if made_after > made_before {
+                player_out = squad
+                    .on_pitch
+                    .iter()
+                    .find(|p| p.track_id == sub.track_id)
+                    .map(|p| p.name.clone());
+                player_in = squad
+                    .bench
+                    .iter()
+                    .find(|b| b.bench_slot == sub.bench_slot)
+                    .map(|b| b.name.clone());
+            }
+        }
+
+        ManagerChange {
+            minute: self.minute,
+            is_home,
+            situation: decision.situation,
+            formation,
+            preset: decision.preset,
+            player_out,
+            player_in,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn squad(minute: u8, goal_diff: i8) -> SubstitutionSquadState {
+        let positions = [
+            Position::GK,
+            Position::LB,
+            Position::CB,
+            Position::CB,
+            Position::RB,
+            Position::CDM,
+            Position::CM,
+            Position::CM,
+            Position::CAM,
+            Position::ST,
+            Position::ST,
+        ];
+        let on_pitch = positions
+            .iter()
+            .enumerate()
+            .map(|(i, &position)| PlannerPitchPlayer {
+                slot: i as u8,
+                track_id: 11 + i as u8,
+                name: format!("P{i}"),
+                position,
+                stamina: 1.0 - i as f32 * 0.05,
+                stamina_attr: 70,
+                available: true,
+                yellow_cards: 0,
+                position_suitability: 1.0,
+                slot_position: None,
+            })
+            .collect();
+        let bench = [Position::GK, Position::CB, Position::CM, Position::ST, Position::LW]
+            .iter()
+            .enumerate()
+            .map(|(i, &position)| PlannerBenchPlayer {
+                bench_slot: i as u8,
+                name: format!("B{i}"),
+                position,
+                overall: 60 + i as u8,
+                stamina_attr: 70,
+                used: false,
+            })
+            .collect();
+        SubstitutionSquadState {
+            is_home: false,
+            minute,
+            goal_diff,
+            substitutions_made: 0,
+            on_pitch,
+            bench,
+        }
+    }
+
+    fn view<'a>(squad: &'a SubstitutionSquadState, own: u8, opp: u8) -> ManagerView<'a> {
+        ManagerView { squad, formation: "4-4-2", own_sent_off: own, opponent_sent_off: opp }
+    }
+
+    #[test]
+    fn test_chasing_late_switches_to_attack() {
+        let mut manager = AiMatchManager::new(false, AIDifficulty::Expert, 7);
+        let early = squad(40, -1);
+        assert!(manager.evaluate(&view(&early, 0, 0)).is_none());
+
+        let late = squad(60, -1);
+        let decision = manager.evaluate(&view(&late, 0, 0)).expect("expert reacts at once");
+        assert_eq!(decision.situation, ManagerSituation::ChasingGame);
+        assert_eq!(decision.formation.as_deref(), Some("4-3-3"));
+        assert_eq!(decision.preset, Some(TacticalPreset::HighPressing));
+        // 가장 지친 수비 자원(CDM, slot 5) → 최고 능력치 공격수(LW, bench 4)
+        assert_eq!(
+            decision.substitution,
+            Some(ManagerSubstitution { track_id: 16, bench_slot: 4 })
+        );
+
+        // 같은 분 재평가 없음, 같은 상황 반복 없음
+        assert!(manager.evaluate(&view(&late, 0, 0)).is_none());
+        assert!(manager.evaluate(&view(&squad(61, -1), 0, 0)).is_none());
+    }
+
+    #[test]
+    fn test_protecting_lead_and_revert() {
+        let mut manager = AiMatchManager::new(false, AIDifficulty::Expert, 7);
+        let decision = manager.evaluate(&view(&squad(75, 1), 0, 0)).unwrap();
+        assert_eq!(decision.situation, ManagerSituation::ProtectingLead);
+        assert_eq!(decision.formation.as_deref(), Some("4-5-1"));
+        let sub = decision.substitution.unwrap();
+        assert_eq!(sub.track_id, 21, "most tired forward leaves");
+        assert_eq!(sub.bench_slot, 1, "defender comes on");
+
+        let level = squad(80, 0);
+        let view_level = ManagerView { formation: "4-5-1", ..view(&level, 0, 0) };
+        let revert = manager.evaluate(&view_level).unwrap();
+        assert_eq!(revert.situation, ManagerSituation::Neutral);
+        assert_eq!(revert.formation.as_deref(), Some("4-4-2"));
+        assert!(revert.substitution.is_none());
+    }
+
+    #[test]
+    fn test_red_card_response() {
+        let mut s = squad(30, 0);
+        s.on_pitch[2].available = false; // CB sent off
+        let mut manager = AiMatchManager::new(false, AIDifficulty::Medium, 3);
+        let decision = manager.evaluate(&view(&s, 1, 0)).unwrap();
+        assert_eq!(decision.situation, ManagerSituation::OwnRedCard);
+        assert_eq!(decision.formation.as_deref(), Some("4-5-1"));
+        let sub = decision.substitution.expect("back line restored");
+        assert_eq!(sub.bench_slot, 1);
+        assert!(manager.evaluate(&view(&squad(31, 0), 1, 0)).is_none(), "handled once");
+
+        // Medium은 상대 퇴장을 공략하지 않음, Hard는 공략
+        assert!(manager.evaluate(&view(&squad(32, 0), 1, 1)).is_none());
+        let mut hard = AiMatchManager::new(false, AIDifficulty::Hard, 3);
+        let exploit = hard.evaluate(&view(&squad(32, 0), 0, 1)).unwrap();
+        assert_eq!(exploit.situation, ManagerSituation::OpponentRedCard);
+        assert_eq!(exploit.formation.as_deref(), Some("4-3-3"));
+    }
+
+    #[test]
+    fn test_difficulty_gates_reaction() {
+        let mut easy = AiMatchManager::new(false, AIDifficulty::Easy, 1);
+        assert!(easy.evaluate(&view(&squad(75, -1), 0, 0)).is_none(), "easy reacts from 80'");
+
+        // 시드 고정 → 같은 반응 분
+        let first_reaction = |seed: u64| {
+            let mut m = AiMatchManager::new(false, AIDifficulty::Easy, seed);
+            (80..=90).find(|&minute| m.evaluate(&view(&squad(minute, -1), 0, 0)).is_some())
+        };
+        assert_eq!(first_reaction(99), first_reaction(99));
+    }
+
+    #[test]
+    fn test_substitution_budget() {
+        let mut s = squad(70, -1);
+        s.substitutions_made = 4;
+        let mut manager = AiMatchManager::new(false, AIDifficulty::Expert, 5);
+        let decision = manager.evaluate(&view(&s, 0, 0)).unwrap();
+        assert!(decision.substitution.is_none(), "last substitution kept for injuries");
+    }
+
+    #[test]
+    fn test_engine_manager_is_seeded() {
+        use crate::engine::match_sim::test_fixtures::create_test_team_with_subs;
+        use crate::engine::match_sim::MatchPlan;
+
+        let run = |seed: u64| {
+            let plan = MatchPlan {
+                home_team: create_test_team_with_subs("Home"),
+                away_team: create_test_team_with_subs("Away"),
+                seed,
+                user_player: None,
+                home_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+                away_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+                modifier_stack: Default::default(),
+                home_instructions: None,
+                away_instructions: None,
+                home_player_instructions: None,
+                away_player_instructions: None,
+                home_ai_difficulty: None,
+                away_ai_difficulty: Some(AIDifficulty::Expert),
+                pitch: crate::models::pitch::PitchSpec::default(),
+                home_chemistry: None,
+                away_chemistry: None,
+                stadium: None,
+                conditions: None,
+                penalty_shootout: None,
+                home_custom_formation: None,
+                away_custom_formation: None,
+                home_set_piece_takers: None,
+                away_set_piece_takers: None,
+                referee: None,
+            };
+            let mut engine = MatchEngine::new(plan).unwrap();
+            let result = engine.simulate();
+            (result.score_home, result.score_away, engine.ai_manager_changes())
+        };
+        assert_eq!(run(42), run(42));
+    }
+}
@@ -0,0 +1,180 @@
+//! Presentation audio cues (crowd / referee whistle)
+//!
+//! A separate, presentation-only channel derived from match events so the
+//! Godot audio layer is driven by the engine instead of re-inferring cues
+//! from the raw event stream. Same events → same cues (no RNG).
+//!
+//! Cues may carry a `duration_ms` for sustained beds (corner anticipation,
+//! penalty tension) that the audio layer fades out when the next cue lands.
+//!
+//! Analysis/presentation only: nothing here feeds back into the simulation.
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::{EventType, MatchEvent};
+
+/// Minute from which goals and chances get the "late drama" boost
+pub const LATE_DRAMA_MINUTE: u8 = 80;
+const LATE_DRAMA_BOOST: f32 = 0.2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AudioCueKind {
+    /// Referee whistles
+    KickoffWhistle,
+    HalfTimeWhistle,
+    FullTimeWhistle,
+    FoulWhistle,
+    OffsideWhistle,
+    /// Crowd beds / reactions
+    CornerAnticipation,
+    FreeKickAnticipation,
+    PenaltyTension,
+    ShotGasp,
+    NearMissGroan,
+    SaveApplause,
+    GoalRoar,
+    CardReaction,
+    VarMurmur,
+}
+
+impl AudioCueKind {
+    /// Base intensity (0..1) before late-drama scaling
+    pub fn base_intensity(&self) -> f32 {
+        match self {
+            Self::KickoffWhistle | Self::FoulWhistle | Self::OffsideWhistle => 0.5,
+            Self::HalfTimeWhistle | Self::FullTimeWhistle => 0.7,
+            Self::CornerAnticipation | Self::FreeKickAnticipation => 0.4,
+            Self::PenaltyTension => 0.8,
+            Self::ShotGasp => 0.5,
+            Self::NearMissGroan => 0.6,
+            Self::SaveApplause => 0.5,
+            Self::GoalRoar => 1.0,
+            Self::CardReaction => 0.45,
+            Self::VarMurmur => 0.35,
+        }
+    }
+
+    /// Sustained bed length; `None` for one-shot cues
+    pub fn duration_ms(&self) -> Option<u64> {
+        match self {
+            Self::CornerAnticipation => Some(6_000),
+            Self::FreeKickAnticipation => Some(5_000),
+            Self::PenaltyTension => Some(10_000),
+            Self::VarMurmur => Some(8_000),
+            _ => None,
+        }
+    }
+
+    fn gets_late_drama(&self) -> bool {
+        matches!(
+            self,
+            Self::GoalRoar | Self::NearMissGroan | Self::PenaltyTension | Self::ShotGasp
+        )
+    }
+}
+
+/// One presentation cue
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AudioCue {
+    pub t_ms: u64,
+    pub kind: AudioCueKind,
+    /// Team the triggering event belongs to (the home crowd cheers its own)
+    pub is_home_team: bool,
+    /// 0..1 loudness hint
+    pub intensity: f32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<u64>,
+}
+
+/// Cues triggered by one match event (usually zero or one).
+pub fn cues_for_event(event: &MatchEvent) -> Vec<AudioCue> {
+    let kinds: &[AudioCueKind] = match event.event_type {
+        EventType::KickOff => &[AudioCueKind::KickoffWhistle],
+        EventType::HalfTime => &[AudioCueKind::HalfTimeWhistle],
+        EventType::FullTime => &[AudioCueKind::FullTimeWhistle],
+        EventType::Foul
+        | EventType::Handball
+        | EventType::BackPassOffence
+        | EventType::GoalkeeperHolding => &[AudioCueKind::FoulWhistle],
+        EventType::Offside => &[AudioCueKind::OffsideWhistle],
+        EventType::Corner => &[AudioCueKind::CornerAnticipation],
+        EventType::Freekick => &[AudioCueKind::FreeKickAnticipation],
+        EventType::Penalty => &[AudioCueKind::FoulWhistle, AudioCueKind::PenaltyTension],
+        EventType::Shot | EventType::ShotOnTarget | EventType::ShotBlocked => {
+            &[AudioCueKind::ShotGasp]
+        }
+        EventType::ShotOffTarget | EventType::PostHit | EventType::BarHit => {
+            &[AudioCueKind::NearMissGroan]
+        }
+        EventType::Save => &[AudioCueKind::SaveApplause],
+        EventType::Goal | EventType::OwnGoal => &[AudioCueKind::GoalRoar],
+        EventType::YellowCard | EventType::RedCard => &[AudioCueKind::CardReaction],
+        EventType::VarReview | EventType::VarReviewStarted => &[AudioCueKind::VarMurmur],
+        _ => &[],
+    };
+
+    let t_ms = event.timeline_ms();
+    kinds
+        .iter()
+        .map(|&kind| {
+            let mut intensity = kind.base_intensity();
+            if kind.gets_late_drama() && event.minute >= LATE_DRAMA_MINUTE {
+                intensity += LATE_DRAMA_BOOST;
+            }
+            if event.event_type == EventType::RedCard {
+                intensity += 0.3;
+            }
+            AudioCue {
+                t_ms,
+                kind,
+                is_home_team: event.is_home_team,
+                intensity: intensity.min(1.0),
+                duration_ms: kind.duration_ms(),
+            }
+        })
+        .collect()
+}
+
+/// Full cue track for a finished event list (offline replay playback).
+pub fn build_audio_cue_track(events: &[MatchEvent]) -> Vec<AudioCue> {
+    events.iter().flat_map(cues_for_event).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cues_follow_events_deterministically() {
+        let events = vec![
+            MatchEvent::shot(30, 1_800_000, true, 9, false, 0.1),
+            MatchEvent::shot(88, 5_280_000, false, 20, true, 0.3),
+        ];
+        let track = build_audio_cue_track(&events);
+        assert_eq!(track, build_audio_cue_track(&events));
+
+        assert_eq!(track.len(), 2);
+        assert_eq!(track[0].kind, AudioCueKind::NearMissGroan);
+        assert_eq!(track[0].t_ms, 1_800_000);
+        assert!(track[0].is_home_team);
+        assert_eq!(track[1].kind, AudioCueKind::ShotGasp);
+        assert!((track[1].intensity - 0.7).abs() < 1e-6, "late chances are louder");
+    }
+
+    #[test]
+    fn test_set_piece_cues_carry_duration() {
+        let mut penalty = MatchEvent::shot(60, 3_600_000, true, 9, true, 0.8);
+        penalty.event_type = EventType::Penalty;
+        let cues = cues_for_event(&penalty);
+        assert_eq!(cues.len(), 2);
+        assert_eq!(cues[0].kind, AudioCueKind::FoulWhistle);
+        assert_eq!(cues[0].duration_ms, None);
+        assert_eq!(cues[1].kind, AudioCueKind::PenaltyTension);
+        assert_eq!(cues[1].duration_ms, Some(10_000));
+
+        let mut pass = penalty.clone();
+        pass.event_type = EventType::Pass;
+        assert!(cues_for_event(&pass).is_empty());
+    }
+}
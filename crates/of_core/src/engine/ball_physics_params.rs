@@ -12,6 +12,7 @@
 //! - Do not rewrite legacy ball physics (`phase_action/ball_physics.rs`).
 //! - Do not introduce new gameplay behavior yet; this module is an SSOT surface.
 
+use crate::engine::strict_math::StrictMath;
 use crate::engine::timestep;
 
 /// Decision tick duration (s). This is the authoritative sim tick for ActionQueue scheduling.
@@ -63,7 +64,7 @@ impl BallPhysicsParams {
     /// Convert `k_roll` to a per-step velocity multiplier.
     #[inline]
     pub fn roll_multiplier(&self, dt: f32) -> f32 {
-        (-self.k_roll_1ps * dt).exp()
+        (-self.k_roll_1ps * dt).det_exp()
     }
 
     /// Stop threshold in Vel10 units (0.1m/s).
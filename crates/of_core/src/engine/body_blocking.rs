@@ -13,6 +13,7 @@
 //! - INFLUENCE_RADIUS: 1.5m (압박 영향 반경)
 //! - INTERCEPT_RADIUS: 1.2m (패스 인터셉트 반경)
 
+use crate::engine::strict_math::StrictMath;
 use crate::engine::player_state::PlayerState;
 
 // ============================================================================
@@ -115,13 +116,13 @@ pub fn normalize(v: (f32, f32)) -> (f32, f32) {
 /// 각도(라디안)에서 방향 벡터
 #[inline]
 pub fn angle_to_direction(angle: f32) -> (f32, f32) {
-    (angle.cos(), angle.sin())
+    (angle.det_cos(), angle.det_sin())
 }
 
 /// 방향 벡터에서 각도(라디안)
 #[inline]
 pub fn direction_to_angle(dir: (f32, f32)) -> f32 {
-    dir.1.atan2(dir.0)
+    dir.1.det_atan2(dir.0)
 }
 
 /// 점에서 선분까지의 최단 거리
@@ -338,14 +339,14 @@ pub fn calculate_approach_angle(
     let to_tackler_normalized = (to_tackler.0 / len, to_tackler.1 / len);
 
     // 타겟의 전방 벡터
-    let target_forward = (target_facing.cos(), target_facing.sin());
+    let target_forward = (target_facing.det_cos(), target_facing.det_sin());
 
     // 내적으로 각도 계산
     let dot =
         to_tackler_normalized.0 * target_forward.0 + to_tackler_normalized.1 * target_forward.1;
 
     // acos의 결과는 라디안, 도로 변환
-    dot.clamp(-1.0, 1.0).acos().to_degrees()
+    dot.clamp(-1.0, 1.0).det_acos().to_degrees()
 }
 
 // ============================================================================
@@ -3,6 +3,8 @@
 ///
 /// Track player facing direction for pressure/threat calculations
 
+use crate::engine::strict_math::StrictMath;
+
 const EPS: f32 = 1e-6;
 
 /// Normalize a 2D vector
@@ -24,7 +26,7 @@ pub fn dot(a: (f32, f32), b: (f32, f32)) -> f32 {
 pub fn angle_deg(a: (f32, f32), b: (f32, f32)) -> f32 {
     let dot_product = dot(normalize(a), normalize(b));
     let clamped = dot_product.clamp(-1.0, 1.0);
-    clamped.acos().to_degrees()
+    clamped.det_acos().to_degrees()
 }
 
 /// How much the player is facing toward a target (0.0 = opposite, 1.0 = directly facing)
@@ -0,0 +1,80 @@
+//! Cooperative cancellation for in-flight simulations
+//!
+//! The host (Godot main thread) keeps a clone of the token and cancels it when
+//! the app is backgrounded or the user navigates away. Simulation loops poll it
+//! at minute boundaries and return a partial result flagged as
+//! `DeterminismMode::Truncated` with `cut_reason = CANCELLED_CUT_REASON`.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// `DeterminismMeta::cut_reason` of a result stopped by its host
+pub const CANCELLED_CUT_REASON: &str = "Cancelled by host";
+
+/// Shared cancel flag; clones observe the same state.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request cancellation (idempotent, callable from any thread)
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Release);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Acquire)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::match_sim::test_fixtures::create_test_team;
+    use crate::engine::match_sim::{MatchEngine, MatchPlan};
+    use crate::models::{DeterminismMode, EventType};
+
+    #[test]
+    fn test_cancelled_simulation_returns_flagged_partial() {
+        let plan = MatchPlan {
+            home_team: create_test_team("Home"),
+            away_team: create_test_team("Away"),
+            seed: 11,
+            user_player: None,
+            home_match_modifiers: Default::default(),
+            away_match_modifiers: Default::default(),
+            modifier_stack: Default::default(),
+            home_instructions: None,
+            away_instructions: None,
+            home_player_instructions: None,
+            away_player_instructions: None,
+            home_ai_difficulty: None,
+            away_ai_difficulty: None,
+            pitch: Default::default(),
+            home_chemistry: None,
+            away_chemistry: None,
+            stadium: None,
+            conditions: None,
+            penalty_shootout: None,
+            home_custom_formation: None,
+            away_custom_formation: None,
+            home_set_piece_takers: None,
+            away_set_piece_takers: None,
+            referee: None,
+        };
+        let token = CancellationToken::new();
+        token.clone().cancel();
+        assert!(token.is_cancelled());
+
+        let result = MatchEngine::new(plan).unwrap().simulate_with_cancellation(&token);
+        assert_eq!(result.determinism.mode, DeterminismMode::Truncated);
+        assert_eq!(result.determinism.cut_reason.as_deref(), Some(CANCELLED_CUT_REASON));
+        assert!(!result.events.iter().any(|e| e.event_type == EventType::FullTime));
+        assert!(result.penalty_shootout.is_none());
+    }
+}
@@ -14,6 +14,7 @@
 //!
 //! The conversion functions swap X/Y to bridge these two systems.
 
+use crate::engine::strict_math::StrictMath;
 use super::physics_constants::{field, goal};
 
 /// Position in normalized coordinates (0-1)
@@ -150,7 +151,7 @@ pub fn angle_to_goal(pos: NormalizedPos, attacks_right: bool) -> f32 {
     let dx = goal_x - pos_m.0;
     let dy = goal_y - pos_m.1;
 
-    dy.atan2(dx)
+    dy.det_atan2(dx)
 }
 
 /// Check if position is inside penalty area
@@ -169,7 +169,7 @@ pub struct ExecutionResultLog {
 // ========== Logger ==========
 
 /// Debug Logger (개발 모드 전용)
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct DebugLogger {
     /// Decision logs
     decision_logs: Vec<DecisionLog>,
@@ -7,6 +7,7 @@
 //! - Godot live DSA remains a lightweight runtime proxy; this module is the
 //!   authoritative, replay-stable version for reports/CI.
 
+use crate::engine::strict_math::StrictMath;
 use super::physics_constants::field;
 use crate::analysis::metrics::gini::gini_coefficient_f32;
 use crate::calibration::zone::pos_to_posplay_zone_meters;
@@ -256,7 +257,7 @@ fn analyze_from_position_data(
                 let dx = player_pos[pid].0 - ball_pos.0;
                 let dy = player_pos[pid].1 - ball_pos.1;
                 let d = (dx * dx + dy * dy).sqrt();
-                sum += (-d / PRESSURE_RADIUS_M).exp();
+                sum += (-d / PRESSURE_RADIUS_M).det_exp();
             }
             (sum / PRESSURE_NORM_CAP).clamp(0.0, 1.0)
         } else {
@@ -441,7 +442,7 @@ fn resolve_half_time_ms(events: &[MatchEvent]) -> u64 {
     events
         .iter()
         .find(|e| e.event_type == EventType::HalfTime)
-        .map(|e| e.timestamp_ms.unwrap_or(e.minute as u64 * 60_000))
+        .map(|e| e.timeline_ms())
         .unwrap_or(DEFAULT_HALF_TIME_MS)
 }
 
@@ -4,6 +4,7 @@ use rand_chacha::ChaCha8Rng;
 use super::HighlightLevel;
 use crate::models::{EventType, MatchEvent};
 
+#[derive(Clone)]
 pub struct EventGenerator {
     // Configuration for event generation
     pub min_events: usize,
@@ -9,6 +9,7 @@
 //! - 오차는 정규분포를 따르며, sigma는 상황에 따라 변동
 //! - 높은 능력치, 낮은 압박, 낮은 피로 → 작은 오차
 
+use crate::engine::strict_math::StrictMath;
 use rand::Rng;
 use rand_distr::{Distribution, StandardNormal};
 
@@ -77,6 +78,8 @@ pub struct ErrorContext {
     pub decision_quality_mult: f32,
     /// 약발 사용 여부
     pub weak_foot: bool,
+    /// 외부 튜닝 sigma 배율 (calibration::ParameterSet, 1.0 = neutral)
+    pub sigma_mult: f32,
 }
 
 impl ErrorContext {
@@ -92,6 +95,7 @@ impl ErrorContext {
             fatigue: 0.0,
             decision_quality_mult: 1.0,
             weak_foot: false,
+            sigma_mult: 1.0,
         }
     }
 
@@ -131,6 +135,11 @@ impl ErrorContext {
         self.decision_quality_mult = mult.clamp(0.5, 2.0);
         self
     }
+
+    pub fn with_sigma_mult(mut self, mult: f32) -> Self {
+        self.sigma_mult = mult;
+        self
+    }
 }
 
 // ========== Base Sigma Values (Tuning Points) ==========
@@ -229,19 +238,22 @@ pub fn sample_execution_error(ctx: &ErrorContext, rng: &mut impl Rng) -> Executi
             + 0.5 * weak_foot_factor)
         * (1.2 - 0.5 * calm_factor)
         * concentration_mult
-        * decision_sigma_mult;
+        * decision_sigma_mult
+        * ctx.sigma_mult;
 
     let dist_sigma = base_sigma::distance(ctx.action_kind)
         * (1.0 + 0.5 * skill_factor + 0.4 * pressure_factor + 0.3 * fatigue_factor)
         * (1.2 - 0.4 * calm_factor)
         * concentration_mult
-        * decision_sigma_mult;
+        * decision_sigma_mult
+        * ctx.sigma_mult;
 
     let height_sigma = base_sigma::height(ctx.action_kind)
         * (1.0 + 0.5 * skill_factor + 0.3 * pressure_factor)
         * (1.1 - 0.3 * calm_factor)
         * concentration_mult
-        * decision_sigma_mult;
+        * decision_sigma_mult
+        * ctx.sigma_mult;
 
     // 3. 정규분포에서 샘플링
     let n_angle: f32 = StandardNormal.sample(rng);
@@ -284,8 +296,8 @@ pub fn apply_error_to_target(
 
     // 방향 오차 회전
     let rot_rad = err.dir_angle_deg.to_radians();
-    let cos_r = rot_rad.cos();
-    let sin_r = rot_rad.sin();
+    let cos_r = rot_rad.det_cos();
+    let sin_r = rot_rad.det_sin();
     let rotated_x = norm_x * cos_r - norm_y * sin_r;
     let rotated_y = norm_x * sin_r + norm_y * cos_r;
 
@@ -356,8 +368,8 @@ pub fn apply_error_for_first_touch(
 
     // 방향 오차 회전
     let rot_rad = err.dir_angle_deg.to_radians();
-    let cos_r = rot_rad.cos();
-    let sin_r = rot_rad.sin();
+    let cos_r = rot_rad.det_cos();
+    let sin_r = rot_rad.det_sin();
     let bounce_x = norm_x * cos_r - norm_y * sin_r;
     let bounce_y = norm_x * sin_r + norm_y * cos_r;
 
@@ -15,6 +15,7 @@
 //! - **DecisionParams**: Shot xG threshold, pass risk tolerance, dribble bias
 //! - **AudacityParams**: Scale, losing boost, late game urgency
 //! - **StyleParams**: Tempo bias, width bias, directness bias
+//! - **DesperationParams**: Late all-out attack for trailing teams
 
 use serde::{Deserialize, Serialize};
 use std::fs;
@@ -40,6 +41,9 @@ pub struct ExpConfig {
     /// Rulebook / referee layer parameters (executor-only; no DecisionTopology coupling)
     #[serde(default)]
     pub rulebook: RulebookParams,
+    /// Late-game desperation mode (trailing team after ~85')
+    #[serde(default)]
+    pub desperation: DesperationParams,
 }
 
 // ========== Decision Parameters ==========
@@ -224,6 +228,12 @@ pub struct RulebookParams {
     /// DecisionTopology scoring.
     #[serde(default)]
     pub advantage_play_enabled: bool,
+
+    /// Enable VAR reviews of goals, penalties and direct red cards (default: false).
+    ///
+    /// `OF_ALLOW_VAR=1` enables it as well.
+    #[serde(default)]
+    pub var_enabled: bool,
 }
 
 impl Default for RulebookParams {
@@ -232,6 +242,73 @@ impl Default for RulebookParams {
             non_gk_handball_enabled: false,
             non_gk_handball_prob_mult: 1.0,
             advantage_play_enabled: false,
+            var_enabled: false,
+        }
+    }
+}
+
+// ========== Desperation Parameters ==========
+
+/// Late-game "all-out attack" for a trailing team.
+///
+/// Once the regulation clock passes `start_minute` and a team trails by
+/// 1..=`max_deficit` goals, it pushes its shape up, presses harder and
+/// shoots from lower-xG chances. Tuned against the goal-timing anchors in
+/// `calibration::goal_timing` (85'+ bucket).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DesperationParams {
+    /// Enable desperation mode (default: false)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Regulation minute the mode switches on (default: 85)
+    /// Stoppage-time offsets are handled by the engine clock.
+    #[serde(default = "default_desperation_start_minute")]
+    pub start_minute: u8,
+
+    /// Largest deficit that still triggers it (default: 2)
+    #[serde(default = "default_desperation_max_deficit")]
+    pub max_deficit: u8,
+
+    /// Extra forward shift of the team shape, normalized length (default: 0.06)
+    #[serde(default = "default_desperation_push_forward")]
+    pub push_forward: f32,
+
+    /// Pressing factor added while desperate (default: 0.15)
+    #[serde(default = "default_desperation_press_add")]
+    pub press_add: f32,
+
+    /// Multiplier on the shot xG threshold (default: 0.6)
+    /// < 1.0 = shoot from worse positions
+    #[serde(default = "default_desperation_shot_threshold_mult")]
+    pub shot_threshold_mult: f32,
+}
+
+fn default_desperation_start_minute() -> u8 {
+    85
+}
+fn default_desperation_max_deficit() -> u8 {
+    2
+}
+fn default_desperation_push_forward() -> f32 {
+    0.06
+}
+fn default_desperation_press_add() -> f32 {
+    0.15
+}
+fn default_desperation_shot_threshold_mult() -> f32 {
+    0.6
+}
+
+impl Default for DesperationParams {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            start_minute: 85,
+            max_deficit: 2,
+            push_forward: 0.06,
+            press_add: 0.15,
+            shot_threshold_mult: 0.6,
         }
     }
 }
@@ -248,6 +325,7 @@ impl Default for ExpConfig {
             audacity: AudacityParams::default(),
             style: StyleParams::default(),
             rulebook: RulebookParams::default(),
+            desperation: DesperationParams::default(),
         }
     }
 }
@@ -342,6 +420,37 @@ impl ExpConfig {
             )));
         }
 
+        // Desperation params bounds
+        if self.desperation.start_minute < 60 || self.desperation.start_minute > 90 {
+            return Err(ExpConfigError::ValidationError(format!(
+                "desperation.start_minute must be 60-90, got {}",
+                self.desperation.start_minute
+            )));
+        }
+        if self.desperation.max_deficit == 0 {
+            return Err(ExpConfigError::ValidationError(
+                "desperation.max_deficit must be at least 1".to_string()
+            ));
+        }
+        if self.desperation.push_forward < 0.0 || self.desperation.push_forward > 0.2 {
+            return Err(ExpConfigError::ValidationError(format!(
+                "desperation.push_forward must be 0.0-0.2, got {}",
+                self.desperation.push_forward
+            )));
+        }
+        if self.desperation.press_add < 0.0 || self.desperation.press_add > 0.5 {
+            return Err(ExpConfigError::ValidationError(format!(
+                "desperation.press_add must be 0.0-0.5, got {}",
+                self.desperation.press_add
+            )));
+        }
+        if self.desperation.shot_threshold_mult < 0.2 || self.desperation.shot_threshold_mult > 1.0 {
+            return Err(ExpConfigError::ValidationError(format!(
+                "desperation.shot_threshold_mult must be 0.2-1.0, got {}",
+                self.desperation.shot_threshold_mult
+            )));
+        }
+
         Ok(())
     }
 
@@ -370,6 +479,7 @@ impl ExpConfig {
                 directness_bias: 0.3,
             },
             rulebook: RulebookParams::default(),
+            desperation: DesperationParams::default(),
         }
     }
 
@@ -398,6 +508,7 @@ impl ExpConfig {
                 directness_bias: -0.4,
             },
             rulebook: RulebookParams::default(),
+            desperation: DesperationParams::default(),
         }
     }
 
@@ -427,6 +538,13 @@ impl ExpConfig {
         self.rulebook.non_gk_handball_enabled.hash(&mut hasher);
         format!("{:.6}", self.rulebook.non_gk_handball_prob_mult).hash(&mut hasher);
         self.rulebook.advantage_play_enabled.hash(&mut hasher);
+        self.rulebook.var_enabled.hash(&mut hasher);
+        self.desperation.enabled.hash(&mut hasher);
+        self.desperation.start_minute.hash(&mut hasher);
+        self.desperation.max_deficit.hash(&mut hasher);
+        format!("{:.6}", self.desperation.push_forward).hash(&mut hasher);
+        format!("{:.6}", self.desperation.press_add).hash(&mut hasher);
+        format!("{:.6}", self.desperation.shot_threshold_mult).hash(&mut hasher);
 
         format!("{:016x}", hasher.finish())
     }
@@ -497,6 +615,21 @@ pub struct RuntimeExpParams {
     pub non_gk_handball_prob_mult: f32,
     /// Rulebook: advantage play enabled
     pub advantage_play_enabled: bool,
+    /// Rulebook: VAR reviews enabled
+    pub var_enabled: bool,
+
+    /// Desperation mode enabled
+    pub desperation_enabled: bool,
+    /// Desperation start (regulation minute)
+    pub desperation_start_minute: u8,
+    /// Desperation max deficit
+    pub desperation_max_deficit: u8,
+    /// Desperation forward shift (normalized)
+    pub desperation_push_forward: f32,
+    /// Desperation pressing add
+    pub desperation_press_add: f32,
+    /// Desperation shot threshold multiplier
+    pub desperation_shot_threshold_mult: f32,
 }
 
 impl From<&ExpConfig> for RuntimeExpParams {
@@ -520,6 +653,13 @@ impl From<&ExpConfig> for RuntimeExpParams {
             non_gk_handball_enabled: config.rulebook.non_gk_handball_enabled,
             non_gk_handball_prob_mult: config.rulebook.non_gk_handball_prob_mult,
             advantage_play_enabled: config.rulebook.advantage_play_enabled,
+            var_enabled: config.rulebook.var_enabled,
+            desperation_enabled: config.desperation.enabled,
+            desperation_start_minute: config.desperation.start_minute,
+            desperation_max_deficit: config.desperation.max_deficit,
+            desperation_push_forward: config.desperation.push_forward,
+            desperation_press_add: config.desperation.press_add,
+            desperation_shot_threshold_mult: config.desperation.shot_threshold_mult,
         }
     }
 }
@@ -546,6 +686,13 @@ impl RuntimeExpParams {
             non_gk_handball_enabled: false,
             non_gk_handball_prob_mult: 1.0,
             advantage_play_enabled: false,
+            var_enabled: false,
+            desperation_enabled: false,
+            desperation_start_minute: 85,
+            desperation_max_deficit: 2,
+            desperation_push_forward: 0.06,
+            desperation_press_add: 0.15,
+            desperation_shot_threshold_mult: 0.6,
         }
     }
 }
@@ -743,6 +890,23 @@ mod tests {
         assert!(config.validate().is_ok());
     }
 
+    #[test]
+    fn test_desperation_params_default_off_and_validated() {
+        let config: ExpConfig = serde_json::from_str(
+            r#"{"exp_id":"x","name":"x","description":"x","decision":{},"audacity":{},"style":{}}"#,
+        )
+        .unwrap();
+        assert!(!config.desperation.enabled);
+        assert_eq!(config.desperation.start_minute, 85);
+
+        let mut config = ExpConfig::default();
+        config.desperation.enabled = true;
+        assert!(config.validate().is_ok());
+        assert_ne!(config.config_hash(), ExpConfig::default().config_hash());
+        config.desperation.start_minute = 30;
+        assert!(config.validate().is_err());
+    }
+
     #[test]
     fn test_conservative_preset() {
         let config = ExpConfig::conservative();
@@ -1,7 +1,7 @@
 //! field_board.rs
 //!
 //! A-Plan Board Layer:
-//! - Truth = meters (105x68)
+//! - Truth = meters (engine frame 105x68, mapped onto the match `PitchSpec`)
 //! - Board = view/query layer (cells)
 //! - Provides occupancy + pressure heatmaps for debug, decision hints, replay summary.
 //!
@@ -27,6 +27,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::engine::physics_constants::field;
 use crate::engine::xgzone_map::XGZoneMap;
+use crate::models::pitch::PitchSpec;
 
 pub const FIELD_LENGTH_M: f32 = field::LENGTH_M;
 pub const FIELD_WIDTH_M: f32 = field::WIDTH_M;
@@ -140,6 +141,11 @@ impl FieldBounds {
         Self { x_min: 0.0, x_max: FIELD_LENGTH_M, y_min: 0.0, y_max: FIELD_WIDTH_M }
     }
 
+    /// Bounds of a match pitch (pitch-frame meters)
+    pub fn for_pitch(pitch: &PitchSpec) -> Self {
+        Self { x_min: 0.0, x_max: pitch.length_m, y_min: 0.0, y_max: pitch.width_m }
+    }
+
     /// Validate a position against field bounds
     ///
     /// Returns Ok(()) if position is within bounds, Err with details otherwise
@@ -175,9 +181,16 @@ impl Default for FieldBounds {
 /// Board runtime storage:
 /// - occupancy (counts)
 /// - pressure maps (against home / against away)
+///
+/// Positions passed in/out are engine-frame meters; cell sizes and pressure
+/// distances are measured on the match pitch (`pitch`).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FieldBoard {
     pub spec: FieldBoardSpec,
+    /// Pitch geometry the board is laid over
+    #[serde(default)]
+    pub pitch: PitchSpec,
+    /// Cell size in pitch meters
     pub cell_w_m: f32,
     pub cell_h_m: f32,
 
@@ -193,12 +206,19 @@ pub struct FieldBoard {
 }
 
 impl FieldBoard {
+    /// Board over the standard 105×68 pitch.
     pub fn new(spec: FieldBoardSpec) -> Self {
-        let cell_w_m = FIELD_LENGTH_M / spec.cols as f32;
-        let cell_h_m = FIELD_WIDTH_M / spec.rows as f32;
+        Self::with_pitch(spec, PitchSpec::standard())
+    }
+
+    /// Board over an arbitrary match pitch.
+    pub fn with_pitch(spec: FieldBoardSpec, pitch: PitchSpec) -> Self {
+        let cell_w_m = pitch.length_m / spec.cols as f32;
+        let cell_h_m = pitch.width_m / spec.rows as f32;
         let len = (spec.cols as usize) * (spec.rows as usize);
         Self {
             spec,
+            pitch,
             cell_w_m,
             cell_h_m,
             occupancy_version_tick: 0,
@@ -206,7 +226,7 @@ impl FieldBoard {
             occupancy: vec![OccupancyCell::default(); len],
             pressure_against_home: HeatmapF32::new(spec.cols, spec.rows),
             pressure_against_away: HeatmapF32::new(spec.cols, spec.rows),
-            xgzone: XGZoneMap::new(spec.cols, spec.rows),
+            xgzone: XGZoneMap::with_pitch(spec.cols, spec.rows, &pitch),
         }
     }
 
@@ -225,11 +245,11 @@ impl FieldBoard {
         (pos_m.0.clamp(0.0, FIELD_LENGTH_M), pos_m.1.clamp(0.0, FIELD_WIDTH_M))
     }
 
-    /// Convert meter position to cell.
+    /// Convert engine-frame meter position to cell.
     /// This is a view mapping; it clamps out-of-bounds into nearest valid cell.
     #[inline]
     pub fn cell_of(&self, pos_m: (f32, f32)) -> CellIndex {
-        let (x, y) = Self::clamp_to_field(pos_m);
+        let (x, y) = self.pitch.clamp(self.pitch.engine_to_pitch(pos_m));
         let mut col = (x / self.cell_w_m).floor() as i32;
         let mut row = (y / self.cell_h_m).floor() as i32;
         // edge case: x == length => last col; y == width => last row
@@ -248,9 +268,15 @@ impl FieldBoard {
         CellIndex { col: col as u8, row: row as u8 }
     }
 
-    /// Cell center in meters.
+    /// Cell center in engine-frame meters.
     #[inline]
     pub fn cell_center(&self, cell: CellIndex) -> (f32, f32) {
+        self.pitch.pitch_to_engine(self.cell_center_pitch_m(cell))
+    }
+
+    /// Cell center in pitch meters.
+    #[inline]
+    pub fn cell_center_pitch_m(&self, cell: CellIndex) -> (f32, f32) {
         let cx = (cell.col as f32 + 0.5) * self.cell_w_m;
         let cy = (cell.row as f32 + 0.5) * self.cell_h_m;
         (cx, cy)
@@ -335,6 +361,7 @@ impl FieldBoard {
     /// - pressure_against_away: home defenders influence
     ///
     /// This is a "hint" map; it does not need perfect physical accuracy.
+    /// `influence_radius_m` is measured on the match pitch.
     pub fn update_pressure_from_positions_m(
         &mut self,
         current_tick: u64,
@@ -366,7 +393,7 @@ impl FieldBoard {
         for row in 0..rows {
             for col in 0..cols {
                 let cell = CellIndex { col, row };
-                let center = self.cell_center(cell);
+                let center = self.cell_center_pitch_m(cell);
 
                 let mut away_influence = 0.0_f32; // pressure against home
                 let mut home_influence = 0.0_f32; // pressure against away
@@ -377,7 +404,7 @@ impl FieldBoard {
                         continue;
                     }
 
-                    let p = player_positions_m[track_id];
+                    let p = self.pitch.engine_to_pitch(player_positions_m[track_id]);
                     let d2 = dist2(p, center);
                     if d2 >= r * r {
                         continue;
@@ -892,4 +919,29 @@ mod tests {
         assert_eq!(bounds.y_min, 0.0);
         assert_eq!(bounds.y_max, field::WIDTH_M);
     }
+
+    #[test]
+    fn test_small_sided_pitch_board() {
+        use crate::models::pitch::PitchFormat;
+
+        let pitch = PitchSpec::for_format(PitchFormat::SevenASide);
+        let board = FieldBoard::with_pitch(FieldBoardSpec::default(), pitch);
+        assert!((board.cell_w_m - pitch.length_m / 28.0).abs() < 1e-5);
+        assert!((board.cell_h_m - pitch.width_m / 18.0).abs() < 1e-5);
+
+        // Engine-frame inputs bin identically to the standard board
+        let standard = FieldBoard::new(FieldBoardSpec::default());
+        for pos in [(0.0, 0.0), (CX, CY), (80.0, 12.0), (field::LENGTH_M, field::WIDTH_M)] {
+            assert_eq!(board.cell_of(pos), standard.cell_of(pos));
+        }
+
+        // Cell centers come back in the engine frame
+        let cell = board.cell_of((CX, CY));
+        let center = board.cell_center(cell);
+        assert_eq!(board.cell_of(center), cell);
+
+        let bounds = FieldBounds::for_pitch(&pitch);
+        assert_eq!(bounds.x_max, pitch.length_m);
+        assert_eq!(bounds.y_max, pitch.width_m);
+    }
 }
@@ -7,6 +7,7 @@
 //! - 상대 선수로부터 반발 (Repel)
 //! - 측면 라인으로부터 반발 (Repel)
 
+use crate::engine::strict_math::StrictMath;
 use super::physics_constants::field;
 
 /// 힘의 유형
@@ -96,7 +97,7 @@ impl ForceSpot {
         let strength = match self.decay {
             DecayType::Constant => self.power,
             DecayType::Linear => self.power * (1.0 - dist / self.scale).max(0.0),
-            DecayType::Exponential => self.power * (-dist / self.scale).exp(),
+            DecayType::Exponential => self.power * (-dist / self.scale).det_exp(),
         };
 
         // 방향 벡터 (정규화)
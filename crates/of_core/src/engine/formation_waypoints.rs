@@ -10,7 +10,15 @@ use std::collections::HashMap;
 
 /// Get waypoints for a specific formation
 pub fn get_formation_waypoints(formation: &str) -> HashMap<PositionKey, PositionWaypoints> {
-    match formation {
+    try_get_formation_waypoints(formation)
+        .unwrap_or_else(|| panic!("UNSUPPORTED_FORMATION: get_formation_waypoints({formation})"))
+}
+
+/// Waypoints for a formation, or None when the formation is not supported
+pub fn try_get_formation_waypoints(
+    formation: &str,
+) -> Option<HashMap<PositionKey, PositionWaypoints>> {
+    let waypoints = match formation {
         "4-4-2" | "442" => get_442_waypoints(),
         "4-3-3" | "433" => get_433_waypoints(),
         "4-2-3-1" | "4231" => get_4231_waypoints(),
@@ -27,8 +35,29 @@ pub fn get_formation_waypoints(formation: &str) -> HashMap<PositionKey, Position
         "4-5-1" | "451" => get_451_waypoints(),
         "3-4-2-1" | "3421" => get_3421_waypoints(),
         "3-4-1-2" | "3412" => get_3412_waypoints(),
-        _ => panic!("UNSUPPORTED_FORMATION: get_formation_waypoints({formation})"),
-    }
+        _ => return None,
+    };
+    Some(waypoints)
+}
+
+/// Preset waypoints moved so their base sits on a custom anchor (formation
+/// editor); the tactical offsets around the base are kept.
+pub fn anchor_waypoints(
+    preset: Option<&PositionWaypoints>,
+    anchor: (f32, f32),
+) -> PositionWaypoints {
+    let Some(wp) = preset else {
+        return PositionWaypoints::from_base(anchor, 0.08, 0.10);
+    };
+    let (dx, dy) = (anchor.0 - wp.base.0, anchor.1 - wp.base.1);
+    let shift = |p: (f32, f32)| ((p.0 + dx).clamp(0.05, 0.95), (p.1 + dy).clamp(0.05, 0.95));
+    PositionWaypoints::new(
+        anchor,
+        shift(wp.defensive),
+        shift(wp.offensive),
+        shift(wp.left_shift),
+        shift(wp.right_shift),
+    )
 }
 
 /// 4-4-2 Formation
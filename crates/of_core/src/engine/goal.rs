@@ -13,6 +13,7 @@
 //! - Home 팀이 공격할 골대 = Away 골대 (x=105)
 //! - Away 팀이 공격할 골대 = Home 골대 (x=0)
 
+use crate::engine::strict_math::StrictMath;
 use super::coordinates::MeterPos;
 use super::physics_constants::{field, goal as goal_const};
 use super::tactical_context::TeamSide;
@@ -102,7 +103,7 @@ impl Goal {
     pub fn angle_from(&self, pos: MeterPos) -> f32 {
         let dx = self.center.0 - pos.0;
         let dy = self.center.1 - pos.1;
-        dy.atan2(dx)
+        dy.det_atan2(dx)
     }
 
     /// 슛 각도 계산 (골대 양쪽 포스트 사이의 시야각)
@@ -119,7 +120,7 @@ impl Goal {
         let mag2 = (to_post2.0 * to_post2.0 + to_post2.1 * to_post2.1).sqrt();
 
         if mag1 * mag2 > 0.0 {
-            (dot / (mag1 * mag2)).clamp(-1.0, 1.0).acos()
+            (dot / (mag1 * mag2)).clamp(-1.0, 1.0).det_acos()
         } else {
             0.0
         }
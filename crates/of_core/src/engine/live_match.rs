@@ -3,15 +3,33 @@
 //! Phase 7: Real-time match streaming support.
 //! Provides tick-by-tick simulation with user intervention capabilities.
 
+use crate::engine::audio_cues::{build_audio_cue_track, AudioCue};
 use crate::engine::field_board::FieldBoardSnapshotExport;
+use crate::engine::marking_manager::MarkingSnapshotExport;
 use crate::engine::match_sim::{
     MatchEngine, MatchPlan, MiniMapObservation, MiniMapSpec, SimpleVectorObservation, StickyAction,
-    StickyActions,
+    StickyActions, UserInputAction,
+};
+use crate::engine::memory_report::{vec_bytes, SessionMemoryUsage};
+use crate::engine::netcode::{
+    input_frames_hash, Desync, InputFrame, LockstepState, RemoteAction, RollbackBuffer,
+    RollbackSnapshot, TickChecksum,
+};
+use crate::engine::scenario_loader::ScenarioOverrides;
+use crate::engine::substitution_planner::{
+    advise_substitutions, plan_substitutions, SubstitutionAdvice, SubstitutionPlan,
+    SubstitutionPolicy,
 };
 use crate::engine::tactical_context::TeamSide;
-use crate::models::{MatchEvent, MatchResult};
+use crate::engine::tactical_problems::TacticalInsight;
+use crate::engine::win_probability::{WinProbability, WinProbabilityTracker};
+use crate::models::{
+    EventType, MatchEvent, MatchPositionData, MatchResult, TeamTalkDetails, TeamTalkTone,
+};
 use crate::models::replay::types::DecisionIntent;
-use crate::tactics::TeamInstructions;
+use crate::player::instructions::PlayerInstructions;
+use crate::tactics::{SetPieceTakers, TeamInstructions};
+use serde::{Deserialize, Serialize};
 
 /// Tick rate constants for live streaming API.
 ///
@@ -69,6 +87,14 @@ pub struct TickData {
     pub field_board_snapshot: Option<FieldBoardSnapshotExport>,
     /// Offside lines in meters (home, away)
     pub offside_lines: OffsideLineSnapshot,
+    /// Defending team's marking assignments (opt-in, see `set_marking_export_enabled`)
+    pub marking: Option<MarkingSnapshotExport>,
+    /// Recurring tactical weaknesses flagged this tick (clip-linked)
+    pub tactical_insights: Vec<TacticalInsight>,
+    /// Presentation audio cues for this tick's events (crowd / whistle)
+    pub audio_cues: Vec<AudioCue>,
+    /// Live win/draw/loss estimate (opt-in, see `set_win_probability_enabled`)
+    pub win_probability: Option<WinProbability>,
 }
 
 /// Player position data
@@ -91,6 +117,17 @@ pub struct OffsideLineSnapshot {
     pub away_x: f32,
 }
 
+/// What a team-view consumer is allowed to see.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ObservationProfile {
+    /// Full state for both teams (local play, training, debug)
+    #[default]
+    Full,
+    /// Spectator / async PvP: hidden info of the non-observed team
+    /// (stamina, decision intents) is redacted from tick output.
+    Observer,
+}
+
 /// Optional team-view observation config for live sessions.
 #[derive(Debug, Clone)]
 pub struct TeamViewObservationConfig {
@@ -98,12 +135,58 @@ pub struct TeamViewObservationConfig {
     pub include_simple: bool,
     pub include_minimap: bool,
     pub minimap_spec: MiniMapSpec,
+    pub profile: ObservationProfile,
 }
 
 impl TeamViewObservationConfig {
     pub fn is_enabled(&self) -> bool {
         self.include_simple || self.include_minimap
     }
+
+    /// True when the non-observed team's hidden info must be stripped.
+    pub fn redacts_opponent(&self) -> bool {
+        self.profile == ObservationProfile::Observer
+    }
+
+    /// True when `track_id` belongs to the team this view is hiding info for.
+    pub fn is_redacted_track(&self, track_id: usize) -> bool {
+        self.redacts_opponent() && (track_id < 11) != self.observer_is_home
+    }
+}
+
+/// Which events a live session streams in `TickData.events`.
+///
+/// Only trims the per-tick payload; the session still records every event
+/// for the full-time result and replay.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EventSubscription {
+    /// Event types to stream (empty = all types)
+    pub event_types: Vec<EventType>,
+    /// Only events whose actor or target is one of these track ids (empty = all players)
+    pub player_track_ids: Vec<u8>,
+}
+
+impl EventSubscription {
+    pub fn matches(&self, event: &MatchEvent) -> bool {
+        let type_ok = self.event_types.is_empty() || self.event_types.contains(&event.event_type);
+        let player_ok = self.player_track_ids.is_empty()
+            || [event.player_track_id, event.target_track_id]
+                .into_iter()
+                .flatten()
+                .any(|id| self.player_track_ids.contains(&id));
+        type_ok && player_ok
+    }
+}
+
+/// Live tactic change: team instructions plus optional designated takers
+#[derive(Debug, Clone, Deserialize)]
+pub struct LiveTacticChange {
+    #[serde(flatten)]
+    pub instructions: TeamInstructions,
+    /// Replaces the team's takers when present (track_ids, see `SetPieceTakers`)
+    #[serde(default)]
+    pub set_piece_takers: Option<SetPieceTakers>,
 }
 
 /// Data returned at half-time
@@ -126,6 +209,40 @@ pub struct FullTimeData {
     pub all_events: Vec<MatchEvent>,
 }
 
+/// Replay slice flushed by `LiveMatchSession::take_replay_chunk`
+#[derive(Debug, Clone, Serialize)]
+pub struct ReplayChunk {
+    /// 0-based chunk sequence number
+    pub seq: u32,
+    /// Session tick index of the first tick in this chunk
+    pub first_tick: u64,
+    /// Ticks covered (0 when nothing was played since the last chunk)
+    pub tick_count: u64,
+    /// Full (unfiltered) events since the previous chunk
+    pub events: Vec<MatchEvent>,
+    /// Ball/player positions recorded since the previous chunk
+    pub positions: MatchPositionData,
+    /// True once the match is finished (no further chunks carry data)
+    pub is_final: bool,
+}
+
+/// Result of a snapshot-free skip (`step_until_next_event` / `fast_forward_to_minute`)
+#[derive(Debug, Clone)]
+pub struct SkipResult {
+    /// Ticks simulated by this call
+    pub ticks_advanced: u64,
+    /// Match clock after the skip
+    pub timestamp_ms: u64,
+    pub minute: u8,
+    pub score: (u8, u8),
+    /// Session state after the skip (HalfTimeBreak/Finished when play paused)
+    pub state: MatchState,
+    /// True when the target (event / minute) was reached before pausing or `max_ms`
+    pub stopped_on_target: bool,
+    /// Displayed events accumulated over the skipped ticks
+    pub events: Vec<MatchEvent>,
+}
+
 // ============================================
 // MatchState: Current match state
 // ============================================
@@ -164,10 +281,26 @@ pub struct LiveMatchSession {
     position_tracking_enabled: bool,
     /// Cursor for incremental per-tick event streaming
     last_event_count: usize,
+    /// Cursor for incremental per-tick tactical insight streaming
+    last_insight_count: usize,
     /// Events from the entire match (for saving at end)
     all_events: Vec<MatchEvent>,
+    /// Ticks executed since kick-off
+    ticks_played: u64,
+    /// Streamed replay cursor: (next chunk seq, first unflushed tick, all_events index)
+    replay_chunk_cursor: (u32, u64, usize),
+    /// Half-time team talk given (home, away); one per team per match
+    team_talk_given: [bool; 2],
+    /// Running win-probability state (Some = attach estimate to each tick)
+    win_probability: Option<WinProbabilityTracker>,
     /// Optional team-view observation output
     team_view_observation: Option<TeamViewObservationConfig>,
+    /// Optional per-tick event filter (None = stream every event)
+    event_subscription: Option<EventSubscription>,
+    /// Lockstep multiplayer bookkeeping (None = single-client session)
+    lockstep: Option<LockstepState>,
+    /// Periodic rewind snapshots: (interval in ticks, ring of snapshots)
+    rewind: Option<(u64, RollbackBuffer)>,
     /// FIX_2601/0123 #12: Session TTL tracking
     /// Timestamp when the session was created
     created_at: std::time::Instant,
@@ -189,8 +322,16 @@ impl LiveMatchSession {
             match_duration: 90,
             position_tracking_enabled: true,
             last_event_count: 0,
+            last_insight_count: 0,
             all_events: Vec::new(),
+            ticks_played: 0,
+            replay_chunk_cursor: (0, 0, 0),
+            team_talk_given: [false; 2],
+            win_probability: None,
             team_view_observation: None,
+            event_subscription: None,
+            lockstep: None,
+            rewind: None,
             created_at: now,
             last_polled: now,
         })
@@ -244,6 +385,30 @@ impl LiveMatchSession {
         self.team_view_observation = Some(config);
     }
 
+    /// Stream only the subscribed events in each tick's `events` (None = all).
+    /// Full-time `all_events` and the replay are unaffected.
+    pub fn set_event_subscription(&mut self, subscription: Option<EventSubscription>) {
+        self.event_subscription = subscription;
+    }
+
+    /// Attach per-factor pass candidate evaluations to each tick's `decision_intents`.
+    /// Debug/tuning only (replay inspector); off by default.
+    pub fn set_pass_candidate_debug_enabled(&mut self, enabled: bool) {
+        self.engine.set_pass_candidate_debug(enabled);
+    }
+
+    /// Attach the defending team's marking assignments to each tick.
+    /// Debug/coaching overlay only; off by default.
+    pub fn set_marking_export_enabled(&mut self, enabled: bool) {
+        self.engine.set_marking_export(enabled);
+    }
+
+    /// Attach a live win/draw/loss estimate to each tick (chart overlay).
+    /// Must be set before `kick_off()` to take effect.
+    pub fn set_win_probability_enabled(&mut self, enabled: bool) {
+        self.win_probability = enabled.then(WinProbabilityTracker::new);
+    }
+
     /// Kick off the match (start first half).
     pub fn kick_off(&mut self) {
         if self.state != MatchState::NotStarted {
@@ -266,6 +431,35 @@ impl LiveMatchSession {
         self.last_event_count = self.engine.get_events_len();
     }
 
+    /// Kick off from a mid-match situation (scripted tutorials / challenges).
+    ///
+    /// Replaces `kick_off()`: the clock, scoreline and scenario placements are
+    /// applied before the first tick and the session resumes in the matching half.
+    pub fn kick_off_from_situation(
+        &mut self,
+        minute: u8,
+        score: (u8, u8),
+        overrides: &ScenarioOverrides,
+    ) -> Result<(), String> {
+        if self.state != MatchState::NotStarted {
+            return Err("Session already kicked off".to_string());
+        }
+        if minute >= self.match_duration {
+            return Err(format!("Start minute {} is not before full time", minute));
+        }
+
+        self.kick_off();
+        self.engine.seek_match_clock(minute, score.0, score.1);
+        self.engine.apply_scenario_overrides(overrides);
+        if let Some(tracker) = self.win_probability.as_mut() {
+            tracker.score = score;
+        }
+        if minute >= 45 {
+            self.state = MatchState::SecondHalf;
+        }
+        Ok(())
+    }
+
     /// Resume second half after half-time break.
     pub fn resume_second_half(&mut self) {
         if self.state != MatchState::HalfTimeBreak {
@@ -297,6 +491,36 @@ impl LiveMatchSession {
 
     /// Execute a single tick during play
     fn execute_tick(&mut self) -> StepResult {
+        let continues = self.advance_engine_tick();
+
+        // Build tick data
+        let mut tick_data = self.build_tick_data();
+
+        // Incremental event streaming for this tick only.
+        let new_events = self.consume_tick_events();
+
+        if let Some(tracker) = self.win_probability.as_ref() {
+            tick_data.win_probability =
+                Some(tracker.estimate(tick_data.minute, self.match_duration));
+        }
+
+        // Audio cues follow the full event stream (not the highlight-filtered one).
+        tick_data.audio_cues = build_audio_cue_track(&new_events);
+        tick_data.events = self.display_events(new_events);
+
+        let insights = self.engine.get_tactical_insights();
+        tick_data.tactical_insights = insights[self.last_insight_count..].to_vec();
+        self.last_insight_count = insights.len();
+
+        // The half-time whistle still rides on this (last) tick's cue channel.
+        let halftime_events = self.finish_tick(continues);
+        tick_data.audio_cues.extend(build_audio_cue_track(&halftime_events));
+
+        StepResult::Tick(tick_data)
+    }
+
+    /// Advance the engine by one decision tick (250ms). Returns false at full time.
+    fn advance_engine_tick(&mut self) -> bool {
         // Advance exactly one decision tick (250ms) using the tick-based Game OS loop.
         let continues = self.engine.step_decision_tick_streaming(
             self.home_strength,
@@ -304,36 +528,59 @@ impl LiveMatchSession {
             self.possession_ratio,
             self.match_duration,
         );
+        self.ticks_played += 1;
+        continues
+    }
 
-        // Build tick data
-        let mut tick_data = self.build_tick_data();
-
-        // Incremental event streaming for this tick only.
+    /// Move this tick's new engine events into the full record (and win-probability tracker).
+    fn consume_tick_events(&mut self) -> Vec<MatchEvent> {
         let new_events = self.engine.get_events_since(self.last_event_count);
         self.last_event_count += new_events.len();
         self.all_events.extend(new_events.clone());
 
+        if let Some(tracker) = self.win_probability.as_mut() {
+            for event in &new_events {
+                tracker.observe(event);
+            }
+        }
+        new_events
+    }
+
+    /// Events surfaced to the UI: highlight policy, then the event subscription.
+    fn display_events(&self, events: Vec<MatchEvent>) -> Vec<MatchEvent> {
         // Live 스트리밍에서도 HighlightLevel 정책을 적용해
         // 주인공/중요 이벤트만 tick.events 에 포함시킨다.
-        if let Some((level, player_track_id)) = self.engine.get_user_highlight_config() {
+        let mut events = if let Some((level, player_track_id)) =
+            self.engine.get_user_highlight_config()
+        {
             use crate::engine::events::filter_events_by_highlight_level;
-            tick_data.events =
-                filter_events_by_highlight_level(&new_events, level, Some(player_track_id));
+            filter_events_by_highlight_level(&events, level, Some(player_track_id))
         } else {
-            tick_data.events = new_events;
+            events
+        };
+        if let Some(subscription) = &self.event_subscription {
+            events.retain(|event| subscription.matches(event));
         }
+        events
+    }
+
+    /// Half/full-time transitions and lockstep/rewind bookkeeping at the end of a tick.
+    ///
+    /// Returns the half-time events consumed here (not surfaced as per-tick events).
+    fn finish_tick(&mut self, continues: bool) -> Vec<MatchEvent> {
+        let mut halftime_events = Vec::new();
 
         // Half-time boundary (45:00): prepare the engine state, then pause on the next call.
         // We apply the half-time transition AFTER building this tick's snapshot to avoid corrupting it.
         if self.state == MatchState::FirstHalf
-            && tick_data.timestamp_ms + MS_PER_TICK == 45 * 60_000
+            && self.engine.get_current_timestamp_ms() + MS_PER_TICK == 45 * 60_000
         {
             self.engine.apply_half_time_transition();
             // Consume the HalfTime event (timestamp=45:00) into the full event stream,
             // but do not surface it as a per-tick event payload.
-            let halftime_events = self.engine.get_events_since(self.last_event_count);
+            halftime_events = self.engine.get_events_since(self.last_event_count);
             self.last_event_count += halftime_events.len();
-            self.all_events.extend(halftime_events);
+            self.all_events.extend(halftime_events.iter().cloned());
             self.state = MatchState::HalfTimeBreak;
         }
 
@@ -342,7 +589,80 @@ impl LiveMatchSession {
             self.state = MatchState::Finished;
         }
 
-        StepResult::Tick(tick_data)
+        if self.lockstep.is_some() {
+            self.record_lockstep_tick();
+        }
+        if let Some((interval, _)) = self.rewind {
+            if self.ticks_played % interval == 0 {
+                let snapshot = self.capture_rollback_snapshot();
+                if let Some((_, buffer)) = self.rewind.as_mut() {
+                    buffer.push(snapshot);
+                }
+            }
+        }
+        halftime_events
+    }
+
+    /// Advance ticks without building per-tick snapshots until `stop` sees the
+    /// tick's displayed events (or play pauses at half/full time).
+    fn skip_ticks(
+        &mut self,
+        max_ms: u64,
+        mut stop: impl FnMut(&Self, &[MatchEvent]) -> bool,
+    ) -> Result<SkipResult, String> {
+        if !matches!(self.state, MatchState::FirstHalf | MatchState::SecondHalf) {
+            return Err(format!("Match is not in play ({:?})", self.state));
+        }
+        self.touch();
+
+        let start_ms = self.engine.get_current_timestamp_ms();
+        let mut events = Vec::new();
+        let mut ticks_advanced = 0;
+        let mut stopped = false;
+        while matches!(self.state, MatchState::FirstHalf | MatchState::SecondHalf)
+            && self.engine.get_current_timestamp_ms() < start_ms + max_ms
+        {
+            let continues = self.advance_engine_tick();
+            ticks_advanced += 1;
+            let new_events = self.consume_tick_events();
+            let shown = self.display_events(new_events);
+            self.last_insight_count = self.engine.get_tactical_insights().len();
+            stopped = stop(self, &shown);
+            events.extend(shown);
+            self.finish_tick(continues);
+            if stopped {
+                break;
+            }
+        }
+
+        Ok(SkipResult {
+            ticks_advanced,
+            timestamp_ms: self.engine.get_current_timestamp_ms(),
+            minute: self.engine.get_minute(),
+            score: self.engine.get_score(),
+            state: self.state,
+            stopped_on_target: stopped,
+            events,
+        })
+    }
+
+    /// Advance without per-tick snapshots until the next displayed event, at most
+    /// `max_ms` of match time. Stops early at half/full time.
+    pub fn step_until_next_event(&mut self, max_ms: u64) -> Result<SkipResult, String> {
+        self.skip_ticks(max_ms, |_, events| !events.is_empty())
+    }
+
+    /// Advance without per-tick snapshots until the match clock reaches `minute`.
+    /// Stops early at half/full time (resume the second half, then call again).
+    pub fn fast_forward_to_minute(&mut self, minute: u8) -> Result<SkipResult, String> {
+        let target_ms = minute as u64 * 60_000;
+        let now_ms = self.engine.get_current_timestamp_ms();
+        if target_ms <= now_ms {
+            return Err(format!("Minute {} is not ahead of the match clock", minute));
+        }
+        self.skip_ticks(target_ms - now_ms, |session, _| {
+            session.engine.get_current_timestamp_ms() >= target_ms
+        })
     }
 
     /// Build tick data snapshot
@@ -360,6 +680,15 @@ impl LiveMatchSession {
             player_positions.push(PlayerPosition { index: i, position: pos_m, state, stamina });
         }
 
+        let redaction = self.team_view_observation.as_ref().filter(|c| c.redacts_opponent());
+        if let Some(config) = redaction {
+            for player in &mut player_positions {
+                if config.is_redacted_track(player.index as usize) {
+                    player.stamina = SimpleVectorObservation::REDACTED_STAMINA;
+                }
+            }
+        }
+
         let (home_score, away_score) = self.engine.get_score();
         let minute = self.engine.get_minute();
         let timestamp_ms = self.engine.get_current_timestamp_ms();
@@ -367,7 +696,12 @@ impl LiveMatchSession {
         let (team_view_simple, team_view_minimap) = match &self.team_view_observation {
             Some(config) if config.is_enabled() => {
                 let simple = if config.include_simple {
-                    Some(self.engine.build_team_view_simple_observation(config.observer_is_home))
+                    let mut obs =
+                        self.engine.build_team_view_simple_observation(config.observer_is_home);
+                    if config.redacts_opponent() {
+                        obs.redact_opponent_hidden_info();
+                    }
+                    Some(obs)
                 } else {
                     None
                 };
@@ -384,13 +718,30 @@ impl LiveMatchSession {
             _ => (None, None),
         };
 
-        let decision_intents = self.engine.get_decision_intents().to_vec();
+        let decision_intents = self
+            .engine
+            .get_decision_intents()
+            .iter()
+            .filter(|intent| {
+                redaction.map_or(true, |config| !config.is_redacted_track(intent.player_id as usize))
+            })
+            .cloned()
+            .collect();
         let field_board_snapshot = self
             .engine
             .field_board
             .as_ref()
             .map(|board| board.to_snapshot_export());
         let (home_x, away_x) = self.engine.get_offside_lines_m();
+        // Observer views only see their own team's defensive organization.
+        let marking = self
+            .engine
+            .get_marking_snapshot()
+            .filter(|snapshot| {
+                let defender_track = if snapshot.defending_is_home { 0 } else { 11 };
+                redaction.map_or(true, |config| !config.is_redacted_track(defender_track))
+            })
+            .cloned();
 
         TickData {
             timestamp_ms,
@@ -406,6 +757,10 @@ impl LiveMatchSession {
             decision_intents,
             field_board_snapshot,
             offside_lines: OffsideLineSnapshot { home_x, away_x },
+            marking,
+            tactical_insights: Vec::new(),
+            audio_cues: Vec::new(),
+            win_probability: None,
         }
     }
 
@@ -447,6 +802,34 @@ impl LiveMatchSession {
         self.engine.apply_tactic_change(team, instructions);
     }
 
+    /// Change team tactic and designated set-piece takers during the match.
+    ///
+    /// Takers are validated first; nothing is applied on error.
+    pub fn apply_tactic_change(
+        &mut self,
+        team: TeamSide,
+        change: LiveTacticChange,
+    ) -> Result<(), String> {
+        if let Some(takers) = change.set_piece_takers {
+            self.engine.apply_set_piece_takers_change(team, takers)?;
+        }
+        self.engine.apply_tactic_change(team, change.instructions);
+        Ok(())
+    }
+
+    /// Replace one player's individual instructions during the match.
+    ///
+    /// * `track_id` - Pitch slot of the player (0-10 home, 11-21 away)
+    ///
+    /// Returns Err (nothing applied) for an invalid slot or man-mark target.
+    pub fn set_player_instructions(
+        &mut self,
+        track_id: usize,
+        instructions: PlayerInstructions,
+    ) -> Result<(), String> {
+        self.engine.apply_player_instructions_change(track_id, instructions)
+    }
+
     /// Change team formation during the match (Phase 5).
     ///
     /// Supported formations: 4-4-2, 4-3-3, 4-5-1, 3-4-3, 4-2-3-1, 3-5-2
@@ -476,11 +859,87 @@ impl LiveMatchSession {
         self.engine.substitute_player(team, out_idx, in_idx)
     }
 
+    /// Give the half-time team talk for `team` (only during the half-time break).
+    ///
+    /// The squad reaction (personality mix × scoreline) applies bounded
+    /// second-half modifiers and is recorded as a `TeamTalk` event.
+    pub fn give_team_talk(
+        &mut self,
+        team: TeamSide,
+        tone: TeamTalkTone,
+    ) -> Result<TeamTalkDetails, &'static str> {
+        if self.state != MatchState::HalfTimeBreak {
+            return Err("Team talk is only available at half-time");
+        }
+        let slot = if team == TeamSide::Home { 0 } else { 1 };
+        if self.team_talk_given[slot] {
+            return Err("Team talk already given");
+        }
+        self.team_talk_given[slot] = true;
+        Ok(self.engine.apply_team_talk(team, tone))
+    }
+
+    /// Preview recommended substitutions for `team` (does not change the roster).
+    pub fn plan_substitutions(
+        &self,
+        team: TeamSide,
+        policy: &SubstitutionPolicy,
+    ) -> SubstitutionPlan {
+        let state = self.engine.substitution_squad_state(team == TeamSide::Home);
+        plan_substitutions(&state, policy)
+    }
+
+    /// Ranked substitution advice for `team`: tired, booked and out-of-position
+    /// players with the best bench replacement (does not change the roster).
+    pub fn get_substitution_advice(
+        &self,
+        team: TeamSide,
+        policy: &SubstitutionPolicy,
+    ) -> SubstitutionAdvice {
+        let state = self.engine.substitution_squad_state(team == TeamSide::Home);
+        advise_substitutions(&state, policy)
+    }
+
+    /// Flush the replay recorded since the previous call (None before kick-off).
+    ///
+    /// Positions are moved out of the engine, so a session that streams its replay
+    /// never holds the whole match in memory. The full-time `MatchResult.position_data`
+    /// (and the heat maps / possession zones derived from it) then only covers ticks
+    /// after the last chunk; take one more chunk after full-time to get the tail.
+    pub fn take_replay_chunk(&mut self) -> Option<ReplayChunk> {
+        if self.state == MatchState::NotStarted {
+            return None;
+        }
+        let (seq, first_tick, event_cursor) = self.replay_chunk_cursor;
+        let chunk = ReplayChunk {
+            seq,
+            first_tick,
+            tick_count: self.ticks_played - first_tick,
+            events: self.all_events[event_cursor..].to_vec(),
+            positions: self.engine.take_position_data().unwrap_or_default(),
+            is_final: self.state == MatchState::Finished,
+        };
+        self.replay_chunk_cursor = (seq + 1, self.ticks_played, self.all_events.len());
+        Some(chunk)
+    }
+
     /// Get current match state.
     pub fn get_state(&self) -> MatchState {
         self.state
     }
 
+    /// Measure engine buffers plus the session's own event archive.
+    pub fn memory_usage(&self, session_id: Option<u64>) -> SessionMemoryUsage {
+        let engine = self.engine.memory_usage();
+        let session_event_bytes = vec_bytes(&self.all_events);
+        SessionMemoryUsage {
+            session_id,
+            engine,
+            session_event_bytes,
+            total_bytes: engine.total_bytes() + session_event_bytes,
+        }
+    }
+
     /// Get current minute.
     pub fn get_minute(&self) -> u8 {
         self.engine.get_minute()
@@ -550,6 +1009,235 @@ impl LiveMatchSession {
     pub fn get_sticky_actions(&self, track_id: usize) -> Option<StickyActions> {
         self.engine.get_sticky_actions(track_id)
     }
+
+    /// Drive the user's avatar directly; applied on every following tick.
+    /// See `MatchEngine::set_user_player_input`.
+    pub fn set_user_player_input(
+        &mut self,
+        track_id: usize,
+        move_dir: (f32, f32),
+        sprint: bool,
+        action: Option<UserInputAction>,
+    ) -> Result<(), &'static str> {
+        self.engine.set_user_player_input(track_id, move_dir, sprint, action)
+    }
+
+    // =========================================================================
+    // Lockstep multiplayer (see engine::netcode)
+    // =========================================================================
+
+    /// Enable lockstep bookkeeping: a rollback snapshot at every tick boundary (the last
+    /// `rollback_ticks` are kept) and a `TickChecksum` for every executed tick.
+    /// Each snapshot is a full engine clone, so keep the window small. Call after `kick_off()`.
+    pub fn enable_lockstep(&mut self, rollback_ticks: usize) {
+        let mut lockstep = LockstepState::new(rollback_ticks);
+        lockstep.rollback.push(self.capture_rollback_snapshot());
+        self.lockstep = Some(lockstep);
+    }
+
+    /// Drop lockstep bookkeeping (snapshots and checksums).
+    pub fn disable_lockstep(&mut self) {
+        self.lockstep = None;
+    }
+
+    /// Lockstep bookkeeping, if enabled.
+    pub fn lockstep(&self) -> Option<&LockstepState> {
+        self.lockstep.as_ref()
+    }
+
+    /// Ticks executed since kick-off (the tick the next input frame belongs to).
+    pub fn ticks_played(&self) -> u64 {
+        self.ticks_played
+    }
+
+    /// Apply one user input received from a peer (same path as local input).
+    pub fn apply_remote_action(&mut self, action: &RemoteAction) -> Result<(), String> {
+        action.validate()?;
+        match *action {
+            RemoteAction::UserInput { track_id, move_dir, sprint, action } => self
+                .set_user_player_input(track_id, move_dir, sprint, action)
+                .map_err(str::to_string),
+            RemoteAction::StickyAction { track_id, action, enabled } => {
+                self.set_sticky_action(track_id, action, enabled).map_err(str::to_string)
+            }
+            RemoteAction::ControlPlayer { track_id: Some(track_id) } => {
+                self.enable_controlled_mode(track_id);
+                Ok(())
+            }
+            RemoteAction::ControlPlayer { track_id: None } => {
+                self.disable_controlled_mode();
+                Ok(())
+            }
+        }
+    }
+
+    /// Apply every peer's frame for the next tick, in peer order, and return the
+    /// combined input hash (folded into that tick's checksum).
+    ///
+    /// Frames for another tick or with an invalid action are rejected before
+    /// anything is applied.
+    pub fn apply_input_frames(&mut self, frames: &[InputFrame]) -> Result<u32, String> {
+        for frame in frames {
+            if frame.tick != self.ticks_played {
+                return Err(format!(
+                    "Input frame for tick {} but next tick is {}",
+                    frame.tick, self.ticks_played
+                ));
+            }
+            for action in &frame.actions {
+                action.validate()?;
+            }
+        }
+
+        let mut ordered: Vec<&InputFrame> = frames.iter().collect();
+        ordered.sort_by_key(|frame| frame.peer_id);
+        for frame in ordered {
+            for action in &frame.actions {
+                self.apply_remote_action(action)?;
+            }
+        }
+
+        let hash = input_frames_hash(frames);
+        if let Some(lockstep) = self.lockstep.as_mut() {
+            lockstep.pending_input_hash = hash;
+        }
+        Ok(hash)
+    }
+
+    /// End-of-tick engine state checksum (the value the replay determinism trail records).
+    pub fn state_checksum(&self) -> u32 {
+        self.engine.determinism_checksum()
+    }
+
+    /// Checksum of the last executed tick (lockstep only).
+    pub fn last_tick_checksum(&self) -> Option<TickChecksum> {
+        self.lockstep.as_ref()?.checksums.latest()
+    }
+
+    /// Compare a peer's checksum with ours. Ticks we no longer hold (or lockstep off) pass.
+    pub fn verify_remote_checksum(&self, remote: &TickChecksum) -> Result<(), Desync> {
+        match &self.lockstep {
+            Some(lockstep) => lockstep.checksums.verify(remote),
+            None => Ok(()),
+        }
+    }
+
+    /// Restore the session to the boundary before `tick` (before that tick's inputs).
+    /// The caller re-applies frames from `tick` onwards and steps again.
+    pub fn rollback_to_tick(&mut self, tick: u64) -> Result<(), String> {
+        let lockstep = self.lockstep.as_mut().ok_or("Lockstep is not enabled")?;
+        let snapshot = lockstep
+            .rollback
+            .get(tick)
+            .cloned()
+            .ok_or_else(|| format!("Tick {} is outside the rollback window", tick))?;
+        lockstep.rollback.truncate_after(tick);
+        lockstep.pending_input_hash = 0;
+
+        self.restore_rollback_snapshot(snapshot);
+        Ok(())
+    }
+
+    // =========================================================================
+    // Rewind ("retry this highlight" / debugging rare events)
+    // =========================================================================
+
+    /// Keep a snapshot every `interval_ms` of match time (the newest `max_snapshots`).
+    /// Each snapshot is a full engine clone. Call after `kick_off()`.
+    pub fn enable_rewind(&mut self, interval_ms: u64, max_snapshots: usize) {
+        let interval = (interval_ms / MS_PER_TICK).max(1);
+        let mut buffer = RollbackBuffer::new(max_snapshots);
+        buffer.push(self.capture_rollback_snapshot());
+        self.rewind = Some((interval, buffer));
+    }
+
+    /// Drop rewind snapshots.
+    pub fn disable_rewind(&mut self) {
+        self.rewind = None;
+    }
+
+    /// Match-clock range (ms) that `rewind_to_ms` can reach.
+    pub fn rewind_window_ms(&self) -> Option<(u64, u64)> {
+        let (_, buffer) = self.rewind.as_ref()?;
+        Some((buffer.oldest_timestamp_ms()?, self.engine.current_timestamp_ms()))
+    }
+
+    /// Rewind to match time `timestamp_ms`: restore the nearest earlier snapshot and
+    /// re-simulate up to it. Returns the match clock landed on (tick-aligned).
+    ///
+    /// Inputs given after that snapshot (commands, tactic changes) are not replayed.
+    pub fn rewind_to_ms(&mut self, timestamp_ms: u64) -> Result<u64, String> {
+        let now_ms = self.engine.current_timestamp_ms();
+        if timestamp_ms > now_ms {
+            return Err(format!("Cannot rewind forward: {} ms > now {} ms", timestamp_ms, now_ms));
+        }
+        let (_, buffer) = self.rewind.as_mut().ok_or("Rewind is not enabled")?;
+        let snapshot = buffer
+            .latest_at_or_before_ms(timestamp_ms)
+            .cloned()
+            .ok_or_else(|| format!("{} ms is before the rewind window", timestamp_ms))?;
+        buffer.truncate_after(snapshot.tick);
+        if let Some(lockstep) = self.lockstep.as_mut() {
+            lockstep.rollback.truncate_after(snapshot.tick);
+            lockstep.pending_input_hash = 0;
+        }
+        self.restore_rollback_snapshot(snapshot);
+
+        // Deterministic re-simulation (crossing half-time if needed).
+        while self.engine.current_timestamp_ms() < timestamp_ms {
+            match self.state {
+                MatchState::FirstHalf | MatchState::SecondHalf => {
+                    self.execute_tick();
+                }
+                MatchState::HalfTimeBreak => self.resume_second_half(),
+                MatchState::NotStarted | MatchState::Finished => break,
+            }
+        }
+        Ok(self.engine.current_timestamp_ms())
+    }
+
+    fn restore_rollback_snapshot(&mut self, snapshot: RollbackSnapshot) {
+        self.engine = *snapshot.engine;
+        self.all_events.truncate(snapshot.all_event_count);
+        self.last_event_count = snapshot.last_event_count;
+        self.win_probability = snapshot.win_probability;
+        self.state = snapshot.session_state;
+        self.ticks_played = snapshot.tick;
+
+        // Streamed replay chunks are not rewound; re-simulated ticks are sent again.
+        let (seq, flushed_tick, flushed_events) = self.replay_chunk_cursor;
+        self.replay_chunk_cursor = (
+            seq,
+            flushed_tick.min(snapshot.tick),
+            flushed_events.min(snapshot.all_event_count),
+        );
+    }
+
+    fn capture_rollback_snapshot(&self) -> RollbackSnapshot {
+        RollbackSnapshot {
+            tick: self.ticks_played,
+            timestamp_ms: self.engine.current_timestamp_ms(),
+            engine: Box::new(self.engine.clone()),
+            session_state: self.state,
+            all_event_count: self.all_events.len(),
+            last_event_count: self.last_event_count,
+            win_probability: self.win_probability.clone(),
+        }
+    }
+
+    /// Record the checksum of the tick just executed and snapshot the next boundary.
+    fn record_lockstep_tick(&mut self) {
+        let state = self.state_checksum();
+        let snapshot = self.capture_rollback_snapshot();
+        if let Some(lockstep) = self.lockstep.as_mut() {
+            lockstep.checksums.record(TickChecksum {
+                tick: self.ticks_played - 1,
+                input_hash: std::mem::take(&mut lockstep.pending_input_hash),
+                state,
+            });
+            lockstep.rollback.push(snapshot);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -568,10 +1256,22 @@ mod tests {
             user_player: None,
             home_match_modifiers: crate::engine::TeamMatchModifiers::default(),
             away_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+            modifier_stack: Default::default(),
             home_player_instructions: None,
             away_player_instructions: None,
             home_ai_difficulty: None,
             away_ai_difficulty: None,
+            pitch: crate::models::pitch::PitchSpec::default(),
+            home_chemistry: None,
+            away_chemistry: None,
+            stadium: None,
+            conditions: None,
+            penalty_shootout: None,
+            home_custom_formation: None,
+            away_custom_formation: None,
+            home_set_piece_takers: None,
+            away_set_piece_takers: None,
+            referee: None,
         }
     }
 
@@ -592,6 +1292,25 @@ mod tests {
         assert_eq!(session.get_minute(), 0);
     }
 
+    #[test]
+    fn test_substitution_advice_flags_formation_mismatch() {
+        use crate::engine::substitution_planner::AdviceReason;
+
+        let mut session = LiveMatchSession::new(create_test_plan()).expect("live session init");
+        session.kick_off();
+        let policy = SubstitutionPolicy::default();
+        assert!(session.get_substitution_advice(TeamSide::Home, &policy).suggestions.is_empty());
+
+        // 4-3-3 puts the right midfielder in the left-wing slot
+        session.change_formation(TeamSide::Home, "4-3-3").unwrap();
+        let advice = session.get_substitution_advice(TeamSide::Home, &policy);
+        let first = &advice.suggestions[0];
+        assert_eq!(first.recommendation.out_idx, 8);
+        assert_eq!(first.reasons, vec![AdviceReason::TacticalMismatch]);
+        assert_eq!(first.recommendation.in_name, "Home Sub 6");
+        assert!(session.get_substitution_advice(TeamSide::Away, &policy).suggestions.is_empty());
+    }
+
     #[test]
     fn test_step_returns_tick_data() {
         let plan = create_test_plan();
@@ -624,6 +1343,7 @@ mod tests {
             include_simple: true,
             include_minimap: true,
             minimap_spec: MiniMapSpec::default(),
+            profile: ObservationProfile::Full,
         });
 
         session.kick_off();
@@ -638,6 +1358,336 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_observer_profile_redacts_opponent_hidden_info() {
+        let plan = create_test_plan();
+        let mut session = LiveMatchSession::new(plan).expect("live session init");
+
+        session.set_team_view_observation_config(TeamViewObservationConfig {
+            observer_is_home: true,
+            include_simple: true,
+            include_minimap: false,
+            minimap_spec: MiniMapSpec::default(),
+            profile: ObservationProfile::Observer,
+        });
+
+        session.kick_off();
+        for _ in 0..40 {
+            let StepResult::Tick(data) = session.step() else {
+                panic!("Expected StepResult::Tick");
+            };
+
+            for player in &data.player_positions {
+                if player.index < 11 {
+                    assert!(player.stamina >= 0.0, "observed team stamina stays visible");
+                } else {
+                    assert_eq!(player.stamina, SimpleVectorObservation::REDACTED_STAMINA);
+                }
+            }
+            assert!(data.decision_intents.iter().all(|intent| intent.player_id < 11));
+
+            let simple = data.team_view_simple.expect("simple observation");
+            for player in &simple.players {
+                let redacted = player.stamina == SimpleVectorObservation::REDACTED_STAMINA;
+                assert_eq!(redacted, player.team_id == 1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_marking_export_is_opt_in() {
+        let plan = create_test_plan();
+        let mut session = LiveMatchSession::new(plan).expect("live session init");
+        session.kick_off();
+        let StepResult::Tick(data) = session.step() else {
+            panic!("Expected StepResult::Tick");
+        };
+        assert!(data.marking.is_none());
+
+        let mut session = LiveMatchSession::new(create_test_plan()).expect("live session init");
+        session.set_marking_export_enabled(true);
+        session.kick_off();
+        let mut saw_presser = false;
+        for _ in 0..80 {
+            let StepResult::Tick(data) = session.step() else {
+                panic!("Expected StepResult::Tick");
+            };
+            let marking = data.marking.expect("marking snapshot");
+            assert_eq!(marking.assignments.len(), 11);
+            let defender_range = if marking.defending_is_home { 0..11 } else { 11..22 };
+            for assignment in &marking.assignments {
+                assert!(defender_range.contains(&assignment.defender_track_id));
+                if let Some(target) = assignment.target_track_id {
+                    assert_eq!(assignment.role, "marker");
+                    assert!(!defender_range.contains(&target));
+                }
+                saw_presser |= assignment.role == "presser_primary";
+            }
+            assert_eq!(marking.assignments[0].role, "goalkeeper");
+        }
+        assert!(saw_presser, "defending team should commit a primary presser");
+    }
+
+    #[test]
+    fn test_win_probability_is_opt_in() {
+        let mut session = LiveMatchSession::new(create_test_plan()).expect("live session init");
+        session.kick_off();
+        let StepResult::Tick(data) = session.step() else {
+            panic!("Expected StepResult::Tick");
+        };
+        assert!(data.win_probability.is_none());
+
+        let mut session = LiveMatchSession::new(create_test_plan()).expect("live session init");
+        session.set_win_probability_enabled(true);
+        session.kick_off();
+        for _ in 0..40 {
+            let StepResult::Tick(data) = session.step() else {
+                panic!("Expected StepResult::Tick");
+            };
+            let p = data.win_probability.expect("win probability");
+            assert_eq!(p.minute, data.minute);
+            assert!((p.home_win + p.draw + p.away_win - 1.0).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_event_subscription_matches_type_and_player() {
+        let goal = MatchEvent::goal(10, 600_000, true, 9, Some(7));
+        let booking = MatchEvent::yellow_card(12, 720_000, false, 15);
+
+        let all = EventSubscription::default();
+        assert!(all.matches(&goal) && all.matches(&booking));
+
+        let goals = EventSubscription { event_types: vec![EventType::Goal], ..Default::default() };
+        assert!(goals.matches(&goal) && !goals.matches(&booking));
+
+        let assister = EventSubscription { player_track_ids: vec![7], ..Default::default() };
+        assert!(assister.matches(&goal), "target track id counts");
+        assert!(!assister.matches(&booking));
+    }
+
+    #[test]
+    fn test_event_subscription_trims_ticks_but_keeps_full_record() {
+        let mut session = LiveMatchSession::new(create_test_plan()).expect("live session init");
+        session.set_event_subscription(Some(EventSubscription {
+            event_types: vec![EventType::Pass],
+            player_track_ids: vec![],
+        }));
+        session.kick_off();
+
+        let mut streamed = 0;
+        for _ in 0..960 {
+            let StepResult::Tick(data) = session.step() else {
+                panic!("Expected StepResult::Tick");
+            };
+            assert!(data.events.iter().all(|e| e.event_type == EventType::Pass));
+            streamed += data.events.len();
+        }
+        assert!(streamed > 0, "passes should still stream");
+        assert!(
+            session.all_events.iter().any(|e| e.event_type != EventType::Pass),
+            "the full record keeps unsubscribed events"
+        );
+    }
+
+    #[test]
+    fn test_replay_chunks_stream_ticks_since_last_call() {
+        let mut session = LiveMatchSession::new(create_test_plan()).expect("live session init");
+        assert!(session.take_replay_chunk().is_none(), "nothing to flush before kick-off");
+        session.kick_off();
+
+        let mut chunks = Vec::new();
+        for _ in 0..3 {
+            for _ in 0..240 {
+                assert!(matches!(session.step(), StepResult::Tick(_)));
+            }
+            chunks.push(session.take_replay_chunk().unwrap());
+        }
+
+        let seqs: Vec<u32> = chunks.iter().map(|c| c.seq).collect();
+        assert_eq!(seqs, vec![0, 1, 2]);
+        assert_eq!(chunks[1].first_tick, 240);
+        assert!(chunks.iter().all(|c| c.tick_count == 240 && !c.is_final));
+        // One position frame per tick, continuing where the previous chunk stopped.
+        assert!(chunks.iter().all(|c| c.positions.ball.len() == 240));
+        // Player tracks skip unchanged frames but each chunk opens with a keyframe.
+        assert!(chunks.iter().all(|c| !c.positions.players[7].is_empty()));
+        assert!(chunks[1].positions.ball[0].timestamp > chunks[0].positions.ball[239].timestamp);
+
+        // Chunks partition the event record without gaps or repeats.
+        let streamed: usize = chunks.iter().map(|c| c.events.len()).sum();
+        assert_eq!(streamed, session.all_events.len());
+
+        // The engine no longer holds flushed positions.
+        let empty = session.take_replay_chunk().unwrap();
+        assert_eq!((empty.tick_count, empty.positions.ball.len()), (0, 0));
+        assert!(empty.events.is_empty());
+    }
+
+    #[test]
+    fn test_user_player_input_drives_avatar_each_tick() {
+        let mut session = LiveMatchSession::new(create_test_plan()).expect("live session init");
+        session.kick_off();
+        for _ in 0..8 {
+            session.step();
+        }
+
+        // Home left-back runs down the touchline (+y) regardless of shape.
+        let track = 1;
+        let start = session.engine.get_player_position_by_index(track).to_meters();
+        session.set_user_player_input(track, (0.0, 2.0), true, None).unwrap();
+        assert!(session.engine.is_controlled(track));
+        assert!(session.get_sticky_actions(track).unwrap().sprint);
+        for _ in 0..12 {
+            session.step();
+        }
+        let moved = session.engine.get_player_position_by_index(track).to_meters();
+        assert!(
+            moved.1 - start.1 > 5.0,
+            "avatar should follow move_dir: {:?} -> {:?}",
+            start,
+            moved
+        );
+
+        // Zero direction holds position.
+        session.set_user_player_input(track, (0.0, 0.0), false, None).unwrap();
+        for _ in 0..8 {
+            session.step();
+        }
+        let before = session.engine.get_player_position_by_index(track).to_meters();
+        for _ in 0..4 {
+            session.step();
+        }
+        let after = session.engine.get_player_position_by_index(track).to_meters();
+        assert!((after.0 - before.0).abs() + (after.1 - before.1).abs() < 0.5);
+
+        assert!(session.set_user_player_input(22, (1.0, 0.0), false, None).is_err());
+        assert!(session.set_user_player_input(track, (f32::NAN, 0.0), false, None).is_err());
+    }
+
+    #[test]
+    fn test_lockstep_peers_stay_in_sync_and_detect_desync() {
+        use crate::engine::netcode::{InputFrame, RemoteAction};
+
+        let mut host = LiveMatchSession::new(create_test_plan()).expect("live session init");
+        let mut guest = LiveMatchSession::new(create_test_plan()).expect("live session init");
+        for session in [&mut host, &mut guest] {
+            session.kick_off();
+            session.enable_lockstep(8);
+        }
+
+        for _ in 0..12 {
+            let tick = host.ticks_played();
+            let mut frames = vec![InputFrame { tick, peer_id: 1, actions: Vec::new() }];
+            if tick == 4 {
+                frames.push(InputFrame {
+                    tick,
+                    peer_id: 0,
+                    actions: vec![RemoteAction::UserInput {
+                        track_id: 9,
+                        move_dir: (1.0, 0.0),
+                        sprint: true,
+                        action: None,
+                    }],
+                });
+            }
+            host.apply_input_frames(&frames).unwrap();
+            frames.reverse(); // arrival order must not matter
+            guest.apply_input_frames(&frames).unwrap();
+            host.step();
+            guest.step();
+
+            let remote = guest.last_tick_checksum().unwrap();
+            assert_eq!(remote.tick, tick);
+            assert!(host.verify_remote_checksum(&remote).is_ok());
+        }
+        assert!(host.engine.is_controlled(9));
+
+        // Guest applies an input the host never saw.
+        let tick = host.ticks_played();
+        let stray =
+            RemoteAction::StickyAction { track_id: 3, action: StickyAction::Press, enabled: true };
+        guest.apply_input_frames(&[InputFrame { tick, peer_id: 1, actions: vec![stray] }]).unwrap();
+        host.step();
+        guest.step();
+        let desync = host.verify_remote_checksum(&guest.last_tick_checksum().unwrap()).unwrap_err();
+        assert_eq!(desync.tick, tick);
+        assert!(desync.is_input_mismatch());
+
+        assert!(host.apply_input_frames(&[InputFrame { tick: 0, ..Default::default() }]).is_err());
+    }
+
+    #[test]
+    fn test_lockstep_rollback_resimulates_same_checksums() {
+        let mut session = LiveMatchSession::new(create_test_plan()).expect("live session init");
+        session.kick_off();
+        session.enable_lockstep(8);
+        for _ in 0..10 {
+            session.step();
+        }
+        let original: Vec<_> =
+            (6..10).map(|tick| session.lockstep().unwrap().checksums.get(tick).unwrap()).collect();
+        let events = session.all_events.len();
+
+        assert!(session.rollback_to_tick(1).is_err(), "outside the 8-tick window");
+        session.rollback_to_tick(6).unwrap();
+        assert_eq!(session.ticks_played(), 6);
+        for _ in 0..4 {
+            session.step();
+        }
+        let resimulated: Vec<_> =
+            (6..10).map(|tick| session.lockstep().unwrap().checksums.get(tick).unwrap()).collect();
+        assert_eq!(original, resimulated);
+        assert_eq!(session.all_events.len(), events);
+    }
+
+    #[test]
+    fn test_rewind_to_ms_replays_identical_state() {
+        let mut session = LiveMatchSession::new(create_test_plan()).expect("live session init");
+        session.kick_off();
+        session.enable_rewind(1_000, 8);
+        let mut history = Vec::new();
+        for _ in 0..20 {
+            session.step();
+            history.push((session.engine.current_timestamp_ms(), session.state_checksum()));
+        }
+        let (now_ms, final_checksum) = *history.last().unwrap();
+
+        assert!(session.rewind_to_ms(now_ms + MS_PER_TICK).is_err());
+        let (target_ms, target_checksum) = history[9];
+        assert_eq!(session.rewind_to_ms(target_ms).unwrap(), target_ms);
+        assert_eq!(session.state_checksum(), target_checksum);
+
+        while session.engine.current_timestamp_ms() < now_ms {
+            session.step();
+        }
+        assert_eq!(session.state_checksum(), final_checksum);
+    }
+
+    #[test]
+    fn test_fast_forward_matches_per_tick_stepping() {
+        let mut stepped = LiveMatchSession::new(create_test_plan()).expect("live session init");
+        let mut skipped = LiveMatchSession::new(create_test_plan()).expect("live session init");
+        stepped.kick_off();
+        skipped.kick_off();
+
+        assert!(skipped.fast_forward_to_minute(0).is_err());
+        let result = skipped.fast_forward_to_minute(3).unwrap();
+        assert!(result.stopped_on_target);
+        assert_eq!(result.minute, 3);
+        assert_eq!(result.timestamp_ms, 3 * 60_000);
+        while stepped.engine.get_current_timestamp_ms() < result.timestamp_ms {
+            stepped.step();
+        }
+        assert_eq!(skipped.ticks_played(), stepped.ticks_played());
+        assert_eq!(skipped.state_checksum(), stepped.state_checksum());
+        assert_eq!(skipped.all_events.len(), stepped.all_events.len());
+
+        let next = skipped.step_until_next_event(5 * 60_000).unwrap();
+        assert!(next.ticks_advanced > 0);
+        assert_eq!(next.stopped_on_target, !next.events.is_empty());
+    }
+
     /// Test that live engine runs to completion (full 90 minutes)
     /// Spec: test_live_engine_runs_to_completion
     #[test]
@@ -717,6 +1767,56 @@ mod tests {
         assert!(matches!(result, StepResult::Tick(_)));
     }
 
+    #[test]
+    fn test_team_talk_only_at_half_time_and_recorded_as_event() {
+        use crate::models::{EventType, TeamTalkTone};
+
+        let mut session = LiveMatchSession::new(create_test_plan()).expect("live session init");
+        session.kick_off();
+        assert!(session.give_team_talk(TeamSide::Home, TeamTalkTone::Calm).is_err());
+
+        while !matches!(session.step(), StepResult::HalfTime(_)) {}
+        let talk = session.give_team_talk(TeamSide::Home, TeamTalkTone::Encourage).unwrap();
+        assert_eq!(talk.tone, TeamTalkTone::Encourage);
+        assert!(session.give_team_talk(TeamSide::Home, TeamTalkTone::Demand).is_err());
+        assert!(session.give_team_talk(TeamSide::Away, TeamTalkTone::Demand).is_ok());
+
+        session.resume_second_half();
+        let StepResult::Tick(tick) = session.step() else { panic!("expected a tick") };
+        let talks: Vec<_> =
+            tick.events.iter().filter(|e| e.event_type == EventType::TeamTalk).collect();
+        assert_eq!(talks.len(), 2);
+        assert!(talks[0].is_home_team);
+        assert_eq!(talks[0].details.as_ref().unwrap().team_talk.as_ref(), Some(&talk));
+    }
+
+    #[test]
+    fn test_live_player_instructions_update() {
+        use crate::player::instructions::PlayerRole;
+        use crate::tactics::roles::role_profile;
+
+        let mut session = LiveMatchSession::new(create_test_plan()).expect("live session init");
+        session.kick_off();
+
+        let teammate = PlayerInstructions { man_mark: Some(3), ..Default::default() };
+        assert!(session.set_player_instructions(5, teammate).is_err());
+        assert!(session.set_player_instructions(22, PlayerInstructions::default()).is_err());
+        assert_eq!(session.engine.man_mark_target(5), None);
+
+        let marker = PlayerInstructions {
+            man_mark: Some(15),
+            role: Some(PlayerRole::BallWinning),
+            ..Default::default()
+        };
+        session.set_player_instructions(5, marker).unwrap();
+        assert_eq!(session.engine.man_mark_target(5), Some(4));
+        assert_eq!(session.engine.role_profile(5), role_profile(PlayerRole::BallWinning));
+
+        for _ in 0..20 {
+            session.step();
+        }
+    }
+
     /// Test that live simulation produces equivalent results to batch simulation
     /// Spec: test_live_vs_batch_result_equivalence
     ///
@@ -740,10 +1840,22 @@ mod tests {
             user_player: None,
             home_match_modifiers: crate::engine::TeamMatchModifiers::default(),
             away_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+            modifier_stack: Default::default(),
             home_player_instructions: None,
             away_player_instructions: None,
             home_ai_difficulty: None,
             away_ai_difficulty: None,
+            pitch: crate::models::pitch::PitchSpec::default(),
+            home_chemistry: None,
+            away_chemistry: None,
+            stadium: None,
+            conditions: None,
+            penalty_shootout: None,
+            home_custom_formation: None,
+            away_custom_formation: None,
+            home_set_piece_takers: None,
+            away_set_piece_takers: None,
+            referee: None,
         };
 
         let mut batch_engine = MatchEngine::new(batch_plan).expect("match engine init");
@@ -759,10 +1871,22 @@ mod tests {
             user_player: None,
             home_match_modifiers: crate::engine::TeamMatchModifiers::default(),
             away_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+            modifier_stack: Default::default(),
             home_player_instructions: None,
             away_player_instructions: None,
             home_ai_difficulty: None,
             away_ai_difficulty: None,
+            pitch: crate::models::pitch::PitchSpec::default(),
+            home_chemistry: None,
+            away_chemistry: None,
+            stadium: None,
+            conditions: None,
+            penalty_shootout: None,
+            home_custom_formation: None,
+            away_custom_formation: None,
+            home_set_piece_takers: None,
+            away_set_piece_takers: None,
+            referee: None,
         };
 
         let mut live_session = LiveMatchSession::new(live_plan).expect("live session init");
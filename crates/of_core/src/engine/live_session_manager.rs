@@ -0,0 +1,152 @@
+//! Keyed live match sessions
+//!
+//! Holds several `LiveMatchSession`s at once, addressed by a numeric session id,
+//! so other fixtures of the round can be stepped in the background while the
+//! player's own match is live. Ids are never reused within one manager.
+
+use std::collections::BTreeMap;
+
+use super::live_match::{LiveMatchSession, StepResult};
+use super::match_sim::MatchPlan;
+use super::memory_report::SessionMemoryUsage;
+
+#[derive(Default)]
+pub struct LiveSessionManager {
+    sessions: BTreeMap<u64, LiveMatchSession>,
+    next_id: u64,
+}
+
+impl LiveSessionManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a session from a match plan and return its id
+    pub fn create(&mut self, plan: MatchPlan) -> Result<u64, String> {
+        let session = LiveMatchSession::new(plan)?;
+        Ok(self.insert(session))
+    }
+
+    /// Take ownership of an already configured session and return its id
+    pub fn insert(&mut self, session: LiveMatchSession) -> u64 {
+        self.next_id += 1;
+        self.sessions.insert(self.next_id, session);
+        self.next_id
+    }
+
+    pub fn get(&self, session_id: u64) -> Option<&LiveMatchSession> {
+        self.sessions.get(&session_id)
+    }
+
+    pub fn get_mut(&mut self, session_id: u64) -> Option<&mut LiveMatchSession> {
+        self.sessions.get_mut(&session_id)
+    }
+
+    /// Step one session by a single tick (None = unknown id)
+    pub fn step(&mut self, session_id: u64) -> Option<StepResult> {
+        self.sessions.get_mut(&session_id).map(LiveMatchSession::step)
+    }
+
+    pub fn remove(&mut self, session_id: u64) -> Option<LiveMatchSession> {
+        self.sessions.remove(&session_id)
+    }
+
+    /// Drop sessions idle longer than `ttl_secs`; returns the removed ids
+    pub fn remove_stale(&mut self, ttl_secs: u64) -> Vec<u64> {
+        let stale: Vec<u64> = self
+            .sessions
+            .iter()
+            .filter(|(_, session)| session.is_stale_with_ttl(ttl_secs))
+            .map(|(&id, _)| id)
+            .collect();
+        for id in &stale {
+            self.sessions.remove(id);
+        }
+        stale
+    }
+
+    /// Per-session memory usage in id order
+    pub fn memory_usage(&self) -> Vec<SessionMemoryUsage> {
+        self.sessions.iter().map(|(&id, s)| s.memory_usage(Some(id))).collect()
+    }
+
+    /// Session ids in creation order
+    pub fn ids(&self) -> Vec<u64> {
+        self.sessions.keys().copied().collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.sessions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sessions.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::live_match::MatchState;
+    use crate::engine::match_sim::test_fixtures::create_test_team_with_subs as create_test_team;
+
+    fn plan(seed: u64) -> MatchPlan {
+        MatchPlan {
+            home_team: create_test_team("Home"),
+            away_team: create_test_team("Away"),
+            seed,
+            home_instructions: None,
+            away_instructions: None,
+            user_player: None,
+            home_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+            away_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+            modifier_stack: Default::default(),
+            home_player_instructions: None,
+            away_player_instructions: None,
+            home_ai_difficulty: None,
+            away_ai_difficulty: None,
+            pitch: crate::models::pitch::PitchSpec::default(),
+            home_chemistry: None,
+            away_chemistry: None,
+            stadium: None,
+            conditions: None,
+            penalty_shootout: None,
+            home_custom_formation: None,
+            away_custom_formation: None,
+            home_set_piece_takers: None,
+            away_set_piece_takers: None,
+            referee: None,
+        }
+    }
+
+    #[test]
+    fn test_sessions_step_independently() {
+        let mut manager = LiveSessionManager::new();
+        let first = manager.create(plan(1)).expect("session");
+        let second = manager.create(plan(2)).expect("session");
+        assert_ne!(first, second);
+        assert_eq!(manager.ids(), vec![first, second]);
+
+        manager.get_mut(first).unwrap().kick_off();
+        for _ in 0..10 {
+            assert!(matches!(manager.step(first), Some(StepResult::Tick(_))));
+        }
+        assert!(matches!(manager.step(second), Some(StepResult::NotStarted)));
+        assert_eq!(manager.get(second).unwrap().get_state(), MatchState::NotStarted);
+        assert!(manager.step(99).is_none());
+    }
+
+    #[test]
+    fn test_ids_are_not_reused_after_remove() {
+        let mut manager = LiveSessionManager::new();
+        let first = manager.create(plan(1)).expect("session");
+        assert!(manager.remove(first).is_some());
+        assert!(manager.is_empty());
+
+        let second = manager.create(plan(1)).expect("session");
+        assert_ne!(first, second);
+        assert!(manager.remove_stale(LiveMatchSession::DEFAULT_TTL_SECS).is_empty());
+        assert_eq!(manager.remove_stale(0), vec![second]);
+        assert_eq!(manager.len(), 0);
+    }
+}
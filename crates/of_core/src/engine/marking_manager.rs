@@ -1,5 +1,6 @@
 use crate::engine::RestartType;
 use crate::engine::TransitionState;
+use crate::engine::defensive_positioning::DefensiveRole;
 use crate::engine::physics_constants::field;
 use crate::models::TeamSide;
 /// marking_manager.rs
@@ -840,6 +841,70 @@ impl Default for MarkingManager {
     }
 }
 
+/// ============================================================================
+/// Marking Export - Tick snapshot for tactical debugging / coaching UI
+/// ============================================================================
+
+/// One defender's assignment as resolved this tick
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MarkingAssignmentExport {
+    /// Defender track_id (0-21)
+    pub defender_track_id: u8,
+    /// Resolved role: goalkeeper / presser_primary / presser_secondary / marker / cover
+    pub role: String,
+    /// Marked attacker track_id (0-21); only set for `marker`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_track_id: Option<u8>,
+    /// Zone responsibility
+    pub zone_role: ZoneRole,
+    /// Marking tightness
+    pub mode: MarkMode,
+}
+
+/// Defending team's marking organization for one tick
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MarkingSnapshotExport {
+    pub defending_is_home: bool,
+    pub assignments: Vec<MarkingAssignmentExport>,
+}
+
+impl MarkingSnapshotExport {
+    /// Build from the manager state and the final (post-positioning) roles.
+    ///
+    /// `roles` is local (0-10, GK first); track ids are offset by team.
+    pub fn from_roles(
+        manager: &MarkingManager,
+        roles: &[DefensiveRole],
+        defending_is_home: bool,
+    ) -> Self {
+        let (defending_start, attacking_start) = if defending_is_home { (0, 11) } else { (11, 0) };
+        let assignments = roles
+            .iter()
+            .zip(manager.states.iter())
+            .enumerate()
+            .map(|(local_idx, (role, state))| {
+                let (label, target_track_id) = match role {
+                    DefensiveRole::Goalkeeper => ("goalkeeper", None),
+                    DefensiveRole::PresserPrimary => ("presser_primary", None),
+                    DefensiveRole::PresserSecondary => ("presser_secondary", None),
+                    DefensiveRole::Marker { target_idx } => {
+                        ("marker", Some((attacking_start + target_idx) as u8))
+                    }
+                    DefensiveRole::Cover => ("cover", None),
+                };
+                MarkingAssignmentExport {
+                    defender_track_id: (defending_start + local_idx) as u8,
+                    role: label.to_string(),
+                    target_track_id,
+                    zone_role: state.zone_role,
+                    mode: state.mode,
+                }
+            })
+            .collect();
+        Self { defending_is_home, assignments }
+    }
+}
+
 /// ============================================================================
 /// Helper Functions
 /// ============================================================================
@@ -8,6 +8,7 @@
 //! 2. **Danger Timeline**: High-xG moments throughout match (xG ≥ 0.15)
 //! 3. **Attack Zone Distribution**: Analysis of attack origins (9-zone grid)
 //! 4. **Pressure Patterns**: High/low pressure periods by field thirds
+//! 5. **Momentum Curve**: Per-minute threat series (territory, pressure, shots, xG)
 //!
 //! ## Design Philosophy
 //! - **Memory Efficient**: Event-based reconstruction, no storage during simulation
@@ -27,6 +28,8 @@
 use super::physics_constants::field;
 use super::dsa_summary;
 use super::interpretation_v1;
+use super::momentum_curve::{self, MomentumPoint};
+use super::win_probability::{self, WinProbability};
 use crate::models::{EventType, MatchEvent, MatchResult};
 use serde::{Deserialize, Serialize};
 
@@ -115,6 +118,12 @@ pub struct MatchAnalysisReport {
     /// Interpretation layer v1 (Replay/Analytics meaning layer, post-match).
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub interpretation_v1: Option<interpretation_v1::MatchInterpretationV1>,
+    /// Per-minute win/draw/loss estimate (probability chart overlay)
+    #[serde(default)]
+    pub win_probability: Vec<WinProbability>,
+    /// Per-minute momentum series (momentum graph)
+    #[serde(default)]
+    pub momentum_curve: Vec<MomentumPoint>,
     /// Report generation timestamp
     pub generated_at_ms: u64,
 }
@@ -223,6 +232,12 @@ pub fn analyze_match(result: &MatchResult) -> MatchAnalysisReport {
             result,
             dsa.as_ref(),
         )),
+        win_probability: win_probability::win_probability_series(&result.events, duration_minutes),
+        // Results without tick samples (older saves) fall back to shots/xG only
+        momentum_curve: result
+            .momentum_curve
+            .clone()
+            .unwrap_or_else(|| momentum_curve::momentum_series_from_events(&result.events)),
         generated_at_ms: current_timestamp_ms(),
     }
 }
@@ -384,7 +399,7 @@ fn detect_danger_moments(events: &[MatchEvent]) -> Vec<DangerMoment> {
 
             Some(DangerMoment {
                 minute: event.minute,
-                timestamp_ms: event.timestamp_ms.unwrap_or((event.minute as u64) * 60_000),
+                timestamp_ms: event.timeline_ms(),
                 xg_value,
                 tier: DangerMomentTier::from_xg_value(xg_value),
                 event_type: format!("{:?}", event.event_type),
@@ -692,6 +707,14 @@ mod tests {
             penalty_shootout: None,
             best_moments: None,
             shot_opp_telemetry: None,
+            tactical_insights: None,
+            match_statistics: None,
+            player_ratings: None,
+            man_of_the_match: None,
+            momentum_curve: None,
+            modifier_report: None,
+            crowd: None,
+            parameter_hash: None,
         }
     }
 
@@ -1048,6 +1071,8 @@ mod tests {
         assert!(!report.danger_timeline.is_empty(), "Should have danger moments");
         assert_eq!(report.attack_zones.zones.len(), 9, "Should have 9 zones");
         assert_eq!(report.attack_zones.total_attacks, 3, "Should have 3 shots");
+        assert_eq!(report.momentum_curve.len(), 90, "Event fallback covers every minute");
+        assert!(report.momentum_curve[30].momentum < 0.0, "Away shot swings momentum");
         assert!(report.generated_at_ms > 0, "Should have valid timestamp");
     }
 
@@ -3,8 +3,19 @@
 //! This module is the SSOT for how external systems (deck/coach, events, etc.)
 //! inject small, deterministic scalar effects into match simulation without
 //! touching decision logic.
+//!
+//! `ModifierStack` keeps every contribution with its origin. The engine resolves
+//! the stack into one `TeamMatchModifiers` per team:
+//! - entries fold in `ModifierSource` order (plan → deck → ... → crowd → weather →
+//!   in-match), insertion order within a source
+//! - multiplicative stats multiply, additive stats (press, width) add
+//! - the folded value is clamped to `ModifierStat::cap()` and capped stats are
+//!   reported, so balance issues show up in `MatchResult.modifier_report`
+
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct TeamMatchModifiers {
     pub pass_success_mult: f32,
     pub shot_accuracy_mult: f32,
@@ -12,6 +23,20 @@ pub struct TeamMatchModifiers {
     pub tackle_success_mult: f32,
     pub press_intensity_add: f32,
     pub stamina_drain_mult: f32,
+    /// Foul injury chance multiplier (fixture congestion, not a deck mod)
+    pub injury_risk_mult: f32,
+    /// Wide-play bias added to the team width instruction (meters; stadium width)
+    pub width_bias_add_m: f32,
+    /// Aerial duel strength of defenders at corners (match prep drills)
+    pub set_piece_defense_mult: f32,
+    /// Ball speed off passes and shots (weather / pitch condition)
+    pub ball_speed_mult: f32,
+    /// How readily this side's challenges are whistled (crowd pressure on the referee)
+    pub foul_call_mult: f32,
+    /// How readily this side's fouls are booked (crowd pressure on the referee)
+    pub card_mult: f32,
+    /// Off-ball movement quality (squad chemistry)
+    pub off_ball_mult: f32,
 }
 
 impl Default for TeamMatchModifiers {
@@ -23,6 +48,13 @@ impl Default for TeamMatchModifiers {
             tackle_success_mult: 1.0,
             press_intensity_add: 0.0,
             stamina_drain_mult: 1.0,
+            injury_risk_mult: 1.0,
+            width_bias_add_m: 0.0,
+            set_piece_defense_mult: 1.0,
+            ball_speed_mult: 1.0,
+            foul_call_mult: 1.0,
+            card_mult: 1.0,
+            off_ball_mult: 1.0,
         }
     }
 }
@@ -47,6 +79,330 @@ impl TeamMatchModifiers {
     }
 }
 
+/// Where a modifier came from; also its resolution order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ModifierSource {
+    /// Untagged bundle passed in `MatchPlan.*_match_modifiers`
+    Plan,
+    /// Coach/manager deck (`apply_mod_list` ids)
+    Deck,
+    Ability,
+    Story,
+    /// Squad chemistry of the starting XI
+    Chemistry,
+    /// Fixture congestion (squad load)
+    SquadLoad,
+    MatchPrep,
+    Stadium,
+    /// Home crowd pressure (`CrowdPressure`)
+    Crowd,
+    Weather,
+    /// Half-time team talk (in-match)
+    TeamTalk,
+}
+
+/// One scalar in `TeamMatchModifiers`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ModifierStat {
+    PassSuccess,
+    ShotAccuracy,
+    ShotPower,
+    TackleSuccess,
+    PressIntensity,
+    StaminaDrain,
+    InjuryRisk,
+    WidthBias,
+    SetPieceDefense,
+    BallSpeed,
+    FoulCall,
+    CardRate,
+    OffBallMovement,
+}
+
+impl ModifierStat {
+    pub const ALL: [ModifierStat; 13] = [
+        Self::PassSuccess,
+        Self::ShotAccuracy,
+        Self::ShotPower,
+        Self::TackleSuccess,
+        Self::PressIntensity,
+        Self::StaminaDrain,
+        Self::InjuryRisk,
+        Self::WidthBias,
+        Self::SetPieceDefense,
+        Self::BallSpeed,
+        Self::FoulCall,
+        Self::CardRate,
+        Self::OffBallMovement,
+    ];
+
+    /// Additive stats sum their entries; the rest multiply
+    pub fn is_additive(self) -> bool {
+        matches!(self, Self::PressIntensity | Self::WidthBias)
+    }
+
+    pub fn neutral(self) -> f32 {
+        if self.is_additive() {
+            0.0
+        } else {
+            1.0
+        }
+    }
+
+    /// Allowed range of the resolved value (all sources combined)
+    pub fn cap(self) -> (f32, f32) {
+        match self {
+            Self::PassSuccess | Self::ShotAccuracy | Self::ShotPower | Self::TackleSuccess => {
+                (0.75, 1.35)
+            }
+            Self::PressIntensity => (0.0, 0.5),
+            Self::StaminaDrain => (0.75, 1.75),
+            Self::InjuryRisk => (0.5, 2.5),
+            Self::WidthBias => (-6.0, 6.0),
+            Self::SetPieceDefense => (0.8, 1.3),
+            Self::BallSpeed => (0.7, 1.25),
+            Self::FoulCall => (0.85, 1.2),
+            Self::CardRate => (0.8, 1.3),
+            Self::OffBallMovement => (0.85, 1.15),
+        }
+    }
+
+    pub fn get(self, modifiers: &TeamMatchModifiers) -> f32 {
+        match self {
+            Self::PassSuccess => modifiers.pass_success_mult,
+            Self::ShotAccuracy => modifiers.shot_accuracy_mult,
+            Self::ShotPower => modifiers.shot_power_mult,
+            Self::TackleSuccess => modifiers.tackle_success_mult,
+            Self::PressIntensity => modifiers.press_intensity_add,
+            Self::StaminaDrain => modifiers.stamina_drain_mult,
+            Self::InjuryRisk => modifiers.injury_risk_mult,
+            Self::WidthBias => modifiers.width_bias_add_m,
+            Self::SetPieceDefense => modifiers.set_piece_defense_mult,
+            Self::BallSpeed => modifiers.ball_speed_mult,
+            Self::FoulCall => modifiers.foul_call_mult,
+            Self::CardRate => modifiers.card_mult,
+            Self::OffBallMovement => modifiers.off_ball_mult,
+        }
+    }
+
+    fn slot(self, modifiers: &mut TeamMatchModifiers) -> &mut f32 {
+        match self {
+            Self::PassSuccess => &mut modifiers.pass_success_mult,
+            Self::ShotAccuracy => &mut modifiers.shot_accuracy_mult,
+            Self::ShotPower => &mut modifiers.shot_power_mult,
+            Self::TackleSuccess => &mut modifiers.tackle_success_mult,
+            Self::PressIntensity => &mut modifiers.press_intensity_add,
+            Self::StaminaDrain => &mut modifiers.stamina_drain_mult,
+            Self::InjuryRisk => &mut modifiers.injury_risk_mult,
+            Self::WidthBias => &mut modifiers.width_bias_add_m,
+            Self::SetPieceDefense => &mut modifiers.set_piece_defense_mult,
+            Self::BallSpeed => &mut modifiers.ball_speed_mult,
+            Self::FoulCall => &mut modifiers.foul_call_mult,
+            Self::CardRate => &mut modifiers.card_mult,
+            Self::OffBallMovement => &mut modifiers.off_ball_mult,
+        }
+    }
+}
+
+/// One active modifier and its origin
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModifierEntry {
+    pub is_home: bool,
+    pub source: ModifierSource,
+    /// Human-readable origin (card, focus, stadium name, ...)
+    pub label: String,
+    pub stat: ModifierStat,
+    /// Multiplier, or addend for additive stats
+    pub value: f32,
+    /// Match minute it became active (0 = pre-match)
+    #[serde(default)]
+    pub minute: u8,
+}
+
+/// A stat whose folded value hit its cap
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CappedStat {
+    pub is_home: bool,
+    pub stat: ModifierStat,
+    /// Folded value before clamping
+    pub uncapped: f32,
+    pub resolved: f32,
+}
+
+/// Every active modifier plus what it resolved to (debugging balance)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ModifierReport {
+    /// In resolution order
+    pub entries: Vec<ModifierEntry>,
+    pub home: TeamMatchModifiers,
+    pub away: TeamMatchModifiers,
+    pub capped: Vec<CappedStat>,
+}
+
+/// Modifier contributions for both teams, kept in resolution order
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ModifierStack {
+    entries: Vec<ModifierEntry>,
+}
+
+impl ModifierStack {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn entries(&self) -> &[ModifierEntry] {
+        &self.entries
+    }
+
+    /// Add one entry. Neutral and non-finite values are dropped.
+    pub fn push_entry(&mut self, entry: ModifierEntry) {
+        if !entry.value.is_finite() || entry.value == entry.stat.neutral() {
+            return;
+        }
+        // After every entry of the same or an earlier source (stable order)
+        let at = self.entries.partition_point(|e| e.source <= entry.source);
+        self.entries.insert(at, entry);
+    }
+
+    pub fn push(
+        &mut self,
+        is_home: bool,
+        source: ModifierSource,
+        label: impl Into<String>,
+        stat: ModifierStat,
+        value: f32,
+    ) {
+        self.push_entry(ModifierEntry {
+            is_home,
+            source,
+            label: label.into(),
+            stat,
+            value,
+            minute: 0,
+        });
+    }
+
+    /// Split a pre-folded bundle into one entry per non-neutral stat
+    pub fn push_bundle(
+        &mut self,
+        is_home: bool,
+        source: ModifierSource,
+        label: &str,
+        bundle: &TeamMatchModifiers,
+        minute: u8,
+    ) {
+        for stat in ModifierStat::ALL {
+            self.push_entry(ModifierEntry {
+                is_home,
+                source,
+                label: label.to_string(),
+                stat,
+                value: stat.get(bundle),
+                minute,
+            });
+        }
+    }
+
+    /// Deck-style `(mod_id, value)` list, clamped like `apply_mod_id`
+    pub fn push_mod_list(
+        &mut self,
+        is_home: bool,
+        source: ModifierSource,
+        label: &str,
+        mods: &[(u8, f32)],
+    ) {
+        let mut bundle = TeamMatchModifiers::default();
+        bundle.apply_mod_list(mods);
+        self.push_bundle(is_home, source, label, &bundle, 0);
+    }
+
+    /// Fold one team's entries and clamp to the stat caps
+    pub fn resolve(&self, is_home: bool) -> (TeamMatchModifiers, Vec<CappedStat>) {
+        let mut resolved = TeamMatchModifiers::default();
+        for entry in self.entries.iter().filter(|e| e.is_home == is_home) {
+            let slot = entry.stat.slot(&mut resolved);
+            if entry.stat.is_additive() {
+                *slot += entry.value;
+            } else {
+                *slot *= entry.value;
+            }
+        }
+
+        let mut capped = Vec::new();
+        for stat in ModifierStat::ALL {
+            let (min, max) = stat.cap();
+            let slot = stat.slot(&mut resolved);
+            let uncapped = *slot;
+            *slot = uncapped.clamp(min, max);
+            if *slot != uncapped {
+                capped.push(CappedStat { is_home, stat, uncapped, resolved: *slot });
+            }
+        }
+        (resolved, capped)
+    }
+
+    pub fn report(&self) -> ModifierReport {
+        let (home, mut capped) = self.resolve(true);
+        let (away, away_capped) = self.resolve(false);
+        capped.extend(away_capped);
+        ModifierReport { entries: self.entries.clone(), home, away, capped }
+    }
+}
+
+/// Pairwise passer → receiver link-up multipliers (track_id indexed, 22×22).
+///
+/// Built from squad chemistry; only same-team pairs are ever non-neutral.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PassLinkModifiers {
+    mults: Vec<f32>,
+}
+
+impl Default for PassLinkModifiers {
+    fn default() -> Self {
+        Self { mults: vec![1.0; 22 * 22] }
+    }
+}
+
+impl PassLinkModifiers {
+    pub const MIN_MULT: f32 = 0.95;
+    pub const MAX_MULT: f32 = 1.05;
+
+    pub fn get(&self, passer_idx: usize, receiver_idx: usize) -> f32 {
+        if passer_idx >= 22 || receiver_idx >= 22 {
+            return 1.0;
+        }
+        self.mults[passer_idx * 22 + receiver_idx]
+    }
+
+    pub fn set(&mut self, passer_idx: usize, receiver_idx: usize, value: f32) {
+        if passer_idx >= 22 || receiver_idx >= 22 {
+            return;
+        }
+        self.mults[passer_idx * 22 + receiver_idx] =
+            clamp_finite(value, Self::MIN_MULT, Self::MAX_MULT, 1.0);
+    }
+
+    /// Rebuild all links touching `track_id` (e.g. after a substitution).
+    pub fn rebuild_track<F>(&mut self, track_id: usize, mut link: F)
+    where
+        F: FnMut(usize, usize) -> f32,
+    {
+        if track_id >= 22 {
+            return;
+        }
+        let team_range = if track_id < 11 { 0..11 } else { 11..22 };
+        for other in team_range {
+            if other == track_id {
+                continue;
+            }
+            self.set(track_id, other, link(track_id, other));
+            self.set(other, track_id, link(other, track_id));
+        }
+    }
+}
+
 fn clamp_finite(value: f32, min: f32, max: f32, default: f32) -> f32 {
     if !value.is_finite() {
         return default;
@@ -54,3 +410,36 @@ fn clamp_finite(value: f32, min: f32, max: f32, default: f32) -> f32 {
     value.clamp(min, max)
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stack_orders_folds_and_caps() {
+        let mut stack = ModifierStack::new();
+        stack.push(true, ModifierSource::Weather, "rain", ModifierStat::PassSuccess, 0.96);
+        stack.push_mod_list(true, ModifierSource::Deck, "deck", &[(1, 1.1), (5, 0.2)]);
+        stack.push(true, ModifierSource::Plan, "plan", ModifierStat::PressIntensity, 0.4);
+        stack.push(false, ModifierSource::Stadium, "ground", ModifierStat::WidthBias, 0.0);
+
+        let order: Vec<ModifierSource> = stack.entries().iter().map(|e| e.source).collect();
+        assert_eq!(
+            order,
+            vec![
+                ModifierSource::Plan,
+                ModifierSource::Deck,
+                ModifierSource::Deck,
+                ModifierSource::Weather
+            ],
+            "neutral entries are dropped, sources fold in order"
+        );
+
+        let report = stack.report();
+        assert!((report.home.pass_success_mult - 1.1 * 0.96).abs() < 1e-6);
+        assert_eq!(report.home.press_intensity_add, 0.5, "0.4 + 0.2 capped at 0.5");
+        assert_eq!(report.away, TeamMatchModifiers::default());
+        assert_eq!(report.capped.len(), 1);
+        assert_eq!(report.capped[0].stat, ModifierStat::PressIntensity);
+        assert!((report.capped[0].uncapped - 0.6).abs() < 1e-6);
+    }
+}
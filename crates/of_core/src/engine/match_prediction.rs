@@ -0,0 +1,264 @@
+//! Pre-match outcome prediction (Monte Carlo or Poisson fast path)
+//!
+//! - Simulation: plays the plan N times under derived seeds and tallies final
+//!   scores. Faithful to the engine, cost grows linearly with N.
+//! - Statistical: independent Poisson goals per side from pre-match team
+//!   strength (ratings × instructions × home advantage). Instant, but blind to
+//!   everything the engine models beyond strength.
+//!
+//! Same plan + sample count → same prediction. Used for pre-match odds and
+//! difficulty tuning; nothing here feeds back into the simulation.
+
+use std::collections::BTreeMap;
+
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::match_sim::{MatchEngine, MatchPlan};
+use super::strict_math::StrictMath;
+use super::win_probability::{poisson_pmf, PRIOR_GOALS_PER_90};
+
+/// Upper bound on simulated samples per request (each one is a full match)
+pub const MAX_PREDICTION_SAMPLES: usize = 500;
+/// Goal-count histograms pool everything at or above this into the last bucket
+pub const GOALS_BUCKET_CAP: usize = 6;
+/// Scorelines listed in the prediction (most likely first)
+const TOP_SCORELINES: usize = 10;
+/// Scoring rate sensitivity to the strength ratio (fast path)
+const STRENGTH_EXPONENT: f32 = 3.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PredictionMethod {
+    Simulation,
+    Statistical,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PredictedScoreline {
+    pub home_goals: u8,
+    pub away_goals: u8,
+    pub probability: f32,
+}
+
+/// Outcome probabilities from the home side's perspective
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MatchPrediction {
+    pub method: PredictionMethod,
+    /// Simulated matches (0 for the statistical fast path)
+    pub samples: u32,
+    pub home_win: f32,
+    pub draw: f32,
+    pub away_win: f32,
+    pub expected_home_goals: f32,
+    pub expected_away_goals: f32,
+    /// P(home scores k) for k = 0..GOALS_BUCKET_CAP; the last entry is "k or more"
+    pub home_goals_distribution: Vec<f32>,
+    pub away_goals_distribution: Vec<f32>,
+    pub most_likely_scoreline: PredictedScoreline,
+    /// Most likely first, ties by score
+    pub scorelines: Vec<PredictedScoreline>,
+}
+
+impl MatchPrediction {
+    /// Summarise a joint scoreline distribution (weights need not be normalised)
+    fn from_joint(method: PredictionMethod, samples: u32, joint: &BTreeMap<(u8, u8), f32>) -> Self {
+        let total = joint.values().sum::<f32>().max(f32::EPSILON);
+        let (mut home_win, mut draw, mut away_win) = (0.0f32, 0.0f32, 0.0f32);
+        let (mut expected_home_goals, mut expected_away_goals) = (0.0f32, 0.0f32);
+        let mut home_goals_distribution = vec![0.0f32; GOALS_BUCKET_CAP + 1];
+        let mut away_goals_distribution = vec![0.0f32; GOALS_BUCKET_CAP + 1];
+
+        let mut scorelines = Vec::with_capacity(joint.len());
+        for (&(home_goals, away_goals), &weight) in joint {
+            let p = weight / total;
+            match home_goals.cmp(&away_goals) {
+                std::cmp::Ordering::Greater => home_win += p,
+                std::cmp::Ordering::Equal => draw += p,
+                std::cmp::Ordering::Less => away_win += p,
+            }
+            expected_home_goals += p * home_goals as f32;
+            expected_away_goals += p * away_goals as f32;
+            home_goals_distribution[(home_goals as usize).min(GOALS_BUCKET_CAP)] += p;
+            away_goals_distribution[(away_goals as usize).min(GOALS_BUCKET_CAP)] += p;
+            scorelines.push(PredictedScoreline { home_goals, away_goals, probability: p });
+        }
+        // BTreeMap order already breaks ties by score; stable sort keeps it.
+        scorelines.sort_by(|a, b| b.probability.total_cmp(&a.probability));
+        scorelines.truncate(TOP_SCORELINES);
+
+        let most_likely_scoreline = scorelines.first().cloned().unwrap_or(PredictedScoreline {
+            home_goals: 0,
+            away_goals: 0,
+            probability: 0.0,
+        });
+        Self {
+            method,
+            samples,
+            home_win,
+            draw,
+            away_win,
+            expected_home_goals,
+            expected_away_goals,
+            home_goals_distribution,
+            away_goals_distribution,
+            most_likely_scoreline,
+            scorelines,
+        }
+    }
+}
+
+/// Simulate the plan `n_samples` times (seeds derived from `plan.seed`)
+pub fn predict_by_simulation(
+    plan: &MatchPlan,
+    n_samples: usize,
+) -> Result<MatchPrediction, String> {
+    if n_samples == 0 {
+        return Err("At least one sample is required".to_string());
+    }
+    if n_samples > MAX_PREDICTION_SAMPLES {
+        return Err(format!("Too many samples: {} (max {})", n_samples, MAX_PREDICTION_SAMPLES));
+    }
+
+    // Samples are independent; collect() keeps index order, so the tally is thread-count agnostic.
+    let scores: Vec<(u8, u8)> = (0..n_samples as u64)
+        .into_par_iter()
+        .map(|i| {
+            let mut sample_plan = plan.clone();
+            sample_plan.seed = sample_seed(plan.seed, i);
+            let result = MatchEngine::new(sample_plan)?.simulate();
+            Ok((result.score_home, result.score_away))
+        })
+        .collect::<Result<_, String>>()?;
+
+    let mut joint: BTreeMap<(u8, u8), f32> = BTreeMap::new();
+    for score in scores {
+        *joint.entry(score).or_insert(0.0) += 1.0;
+    }
+    Ok(MatchPrediction::from_joint(PredictionMethod::Simulation, n_samples as u32, &joint))
+}
+
+/// Poisson fast path from pre-match team strength (no simulation)
+pub fn predict_statistical(plan: &MatchPlan) -> Result<MatchPrediction, String> {
+    let engine = MatchEngine::new(plan.clone())?;
+    let home_strength = engine.calculate_team_strength(&engine.home_team, true);
+    let away_strength = engine.calculate_team_strength(&engine.away_team, false);
+    let (home_rate, away_rate) = expected_goal_rates(home_strength, away_strength);
+
+    let home_pmf = poisson_pmf(home_rate);
+    let away_pmf = poisson_pmf(away_rate);
+    let mut joint: BTreeMap<(u8, u8), f32> = BTreeMap::new();
+    for (i, p_home) in home_pmf.iter().enumerate() {
+        for (j, p_away) in away_pmf.iter().enumerate() {
+            joint.insert((i as u8, j as u8), p_home * p_away);
+        }
+    }
+    Ok(MatchPrediction::from_joint(PredictionMethod::Statistical, 0, &joint))
+}
+
+/// Expected goals per side over 90 minutes: league prior scaled by the strength ratio
+pub fn expected_goal_rates(home_strength: f32, away_strength: f32) -> (f32, f32) {
+    let ratio = home_strength.max(1.0) / away_strength.max(1.0);
+    (
+        PRIOR_GOALS_PER_90 * ratio.det_powf(STRENGTH_EXPONENT),
+        PRIOR_GOALS_PER_90 * ratio.det_powf(-STRENGTH_EXPONENT),
+    )
+}
+
+/// Per-sample engine seed (SplitMix64 over base seed and sample index)
+fn sample_seed(seed: u64, index: u64) -> u64 {
+    let mut z = seed ^ index.wrapping_add(1).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::match_sim::test_fixtures::{
+        create_test_team, create_test_team_with_overall,
+    };
+    use crate::models::Team;
+
+    fn plan(home_team: Team, away_team: Team, seed: u64) -> MatchPlan {
+        MatchPlan {
+            home_team,
+            away_team,
+            seed,
+            user_player: None,
+            home_match_modifiers: Default::default(),
+            away_match_modifiers: Default::default(),
+            modifier_stack: Default::default(),
+            home_instructions: None,
+            away_instructions: None,
+            home_player_instructions: None,
+            away_player_instructions: None,
+            home_ai_difficulty: None,
+            away_ai_difficulty: None,
+            pitch: crate::models::pitch::PitchSpec::default(),
+            home_chemistry: None,
+            away_chemistry: None,
+            stadium: None,
+            conditions: None,
+            penalty_shootout: None,
+            home_custom_formation: None,
+            away_custom_formation: None,
+            home_set_piece_takers: None,
+            away_set_piece_takers: None,
+            referee: None,
+        }
+    }
+
+    #[test]
+    fn test_joint_summary_sums_to_one_and_buckets_high_scores() {
+        let joint = BTreeMap::from([((2, 1), 2.0), ((1, 1), 1.0), ((0, 7), 1.0)]);
+        let p = MatchPrediction::from_joint(PredictionMethod::Simulation, 4, &joint);
+
+        assert!((p.home_win - 0.5).abs() < 1e-6);
+        assert!((p.draw - 0.25).abs() < 1e-6);
+        assert!((p.away_win - 0.25).abs() < 1e-6);
+        assert!((p.expected_away_goals - 2.5).abs() < 1e-6);
+        assert_eq!(p.away_goals_distribution.len(), GOALS_BUCKET_CAP + 1);
+        assert!((p.away_goals_distribution[GOALS_BUCKET_CAP] - 0.25).abs() < 1e-6);
+        assert_eq!(
+            (p.most_likely_scoreline.home_goals, p.most_likely_scoreline.away_goals),
+            (2, 1)
+        );
+        // Equal probabilities fall back to score order.
+        assert_eq!((p.scorelines[1].home_goals, p.scorelines[1].away_goals), (0, 7));
+    }
+
+    #[test]
+    fn test_statistical_fast_path_favours_stronger_side() {
+        let even =
+            predict_statistical(&plan(create_test_team("H"), create_test_team("A"), 1)).unwrap();
+        let total = even.home_win + even.draw + even.away_win;
+        assert!((total - 1.0).abs() < 1e-4);
+        // Home advantage tips an even matchup.
+        assert!(even.home_win > even.away_win);
+        assert_eq!(even.samples, 0);
+
+        let mismatch = predict_statistical(&plan(
+            create_test_team_with_overall("Weak", 55),
+            create_test_team_with_overall("Strong", 85),
+            1,
+        ))
+        .unwrap();
+        assert!(mismatch.away_win > 0.6);
+        assert!(mismatch.expected_away_goals > mismatch.expected_home_goals);
+    }
+
+    #[test]
+    fn test_simulation_is_deterministic_and_validates_sample_count() {
+        let plan = plan(create_test_team("Home"), create_test_team("Away"), 42);
+        let a = predict_by_simulation(&plan, 3).unwrap();
+        let b = predict_by_simulation(&plan, 3).unwrap();
+        assert_eq!(a, b);
+        assert_eq!(a.samples, 3);
+        assert!((a.home_win + a.draw + a.away_win - 1.0).abs() < 1e-5);
+
+        assert!(predict_by_simulation(&plan, 0).is_err());
+        assert!(predict_by_simulation(&plan, MAX_PREDICTION_SAMPLES + 1).is_err());
+    }
+}
@@ -10,6 +10,7 @@
 // P18: calculate_pressure_context deprecated 경고 억제 (FieldBoard 마이그레이션 예정)
 #![allow(deprecated)]
 
+use crate::engine::strict_math::StrictMath;
 use super::MatchEngine;
 use super::RngCategory; // FIX_2601/0120: RNG tracking
 use crate::engine::body_blocking::{self, TackleAttemptResult};
@@ -421,7 +422,7 @@ impl MatchEngine {
 
         // A. 기본 xG (거리 기반)
         // 0m = 0.85, 10m = 0.20, 20m = 0.04, 30m = 0.01
-        let base_xg = (0.85 * (-0.12 * distance_m).exp()).clamp(0.01, 0.85);
+        let base_xg = (0.85 * (-0.12 * distance_m).det_exp()).clamp(0.01, 0.85);
 
         // B. 능력치 보정 (Finishing or Long Shots)
         let skill = if distance_m < 18.0 {
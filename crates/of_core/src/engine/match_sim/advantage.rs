@@ -0,0 +1,434 @@
+//! Advantage (IFAB Law 5.3 / Law 12.3)
+//!
+//! When the executor plays advantage on a foul (`ActionResult::TackleFoulAdvantage`),
+//! the referee's decision is held open for a short window:
+//! - the fouled team loses the ball inside the window → called back, free kick
+//!   (or penalty) at the foul spot and the original sanction;
+//! - otherwise the advantage accrues and any card is shown at the next stoppage,
+//!   reduced per Law 12.3 (DOGSO → caution, stopping a promising attack → none).
+
+use rand::Rng;
+
+use super::MatchEngine;
+use crate::engine::action_queue::{ActionResult, BallState, RestartType};
+use crate::engine::physics_constants::field;
+use crate::engine::player_state::PlayerState;
+use crate::engine::types::Coord10;
+use crate::models::rules::{AdvantageCard, AdvantageDetails, AdvantageOutcome, FoulSeverity};
+use crate::models::{MatchEvent, TeamSide};
+
+/// Ticks the referee waits for the advantage to materialise (~3s at 250ms/tick).
+pub(crate) const ADVANTAGE_WINDOW_TICKS: u64 = 12;
+
+/// DOGSO requires the foul to be this close to the opponent goal (meters).
+const DOGSO_MAX_DISTANCE_M: f32 = 25.0;
+
+/// Penalty-area half width (meters, standard 40.32m box + line).
+const PENALTY_AREA_HALF_WIDTH_M: f32 = 20.16;
+
+/// Foul held open while the fouled team plays on.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PendingAdvantage {
+    pub offender_idx: usize,
+    pub victim_idx: usize,
+    /// Foul spot (restart position if called back)
+    pub foul_position: Coord10,
+    pub in_penalty_area: bool,
+    pub signalled_tick: u64,
+    pub details: AdvantageDetails,
+}
+
+impl PendingAdvantage {
+    fn victim_is_home(&self) -> bool {
+        TeamSide::is_home(self.victim_idx)
+    }
+}
+
+/// Foul severity for a played-on foul; rough/aggressive players commit more reckless fouls.
+/// `card_mult` is the referee's card tendency (1.0 = neutral).
+pub(crate) fn foul_severity(aggression: u8, roll: f32, card_mult: f32) -> FoulSeverity {
+    let aggression = aggression.min(100) as f32 / 100.0;
+    let excessive = (0.01 + aggression * 0.02) * card_mult;
+    let reckless = excessive + (0.10 + aggression * 0.20) * card_mult;
+    if roll < excessive {
+        FoulSeverity::ExcessiveForce
+    } else if roll < reckless {
+        FoulSeverity::Reckless
+    } else {
+        FoulSeverity::Careless
+    }
+}
+
+/// DOGSO: close to goal with no outfield defender between the victim and the goal.
+pub(crate) fn is_obvious_goal_scoring_opportunity(
+    distance_to_goal_m: f32,
+    defenders_goal_side: usize,
+) -> bool {
+    distance_to_goal_m <= DOGSO_MAX_DISTANCE_M && defenders_goal_side == 0
+}
+
+impl MatchEngine {
+    /// Team-view meters (attacking toward x=105) for the given side.
+    fn advantage_team_view_m(&self, pos: Coord10, attacking_home: bool) -> (f32, f32) {
+        let (x, y) = pos.to_meters();
+        if self.attacks_right(attacking_home) {
+            (x, y)
+        } else {
+            (field::LENGTH_M - x, y)
+        }
+    }
+
+    /// Record a played-on foul: `AdvantagePlayed` event and an open decision window.
+    ///
+    /// Called after the foul itself has been emitted with `advantage_played`.
+    pub(crate) fn signal_advantage(&mut self, offender_idx: usize, victim_idx: usize) {
+        // A second foul inside the window closes the earlier one first.
+        if let Some(earlier) = self.pending_advantage {
+            if earlier.details.outcome == AdvantageOutcome::Signalled {
+                self.accrue_pending_advantage();
+            }
+            self.issue_pending_advantage_card();
+        }
+
+        let victim_is_home = TeamSide::is_home(victim_idx);
+        let foul_position = self.player_positions[victim_idx];
+        let (tv_x, tv_y) = self.advantage_team_view_m(foul_position, victim_is_home);
+        let distance_to_goal_m =
+            ((field::LENGTH_M - tv_x).powi(2) + (tv_y - field::CENTER_Y).powi(2)).sqrt();
+        let in_penalty_area = tv_x >= field::LENGTH_M - field::PENALTY_AREA_LENGTH_M
+            && (tv_y - field::CENTER_Y).abs() <= PENALTY_AREA_HALF_WIDTH_M;
+
+        let keeper_idx = TeamSide::opponent_gk(victim_idx);
+        let defenders_goal_side = TeamSide::opponent_range(victim_idx)
+            .filter(|&i| i != offender_idx && i != keeper_idx)
+            .filter(|&i| !matches!(self.player_states[i], PlayerState::SentOff))
+            .filter(|&i| {
+                self.advantage_team_view_m(self.player_positions[i], victim_is_home).0 > tv_x
+            })
+            .count();
+        let is_dogso = is_obvious_goal_scoring_opportunity(distance_to_goal_m, defenders_goal_side);
+
+        let aggression = self.get_player_attributes(offender_idx).aggression;
+        let severity = foul_severity(
+            aggression,
+            self.rng.gen::<f32>(),
+            self.referee_card_mult_for(offender_idx),
+        );
+
+        // Advantage is only played with a teammate ahead in the attacking half,
+        // so anything short of DOGSO stopped a promising attack.
+        let details = AdvantageDetails {
+            outcome: AdvantageOutcome::Signalled,
+            severity,
+            is_dogso,
+            stopped_promising_attack: !is_dogso,
+        };
+
+        self.emit_event(MatchEvent::advantage_played(
+            self.minute,
+            self.current_timestamp_ms(),
+            victim_is_home,
+            victim_idx,
+            offender_idx,
+            details,
+        ));
+
+        self.pending_advantage = Some(PendingAdvantage {
+            offender_idx,
+            victim_idx,
+            foul_position,
+            in_penalty_area,
+            signalled_tick: self.current_tick,
+            details,
+        });
+    }
+
+    /// Per-tick advantage resolution (after out-of-play and goal checks).
+    pub(crate) fn update_pending_advantage(&mut self) {
+        let Some(pending) = self.pending_advantage else { return };
+
+        let stoppage =
+            self.restart_occurred_this_tick || !self.action_queue.ball_state().is_in_play();
+        let open = pending.details.outcome == AdvantageOutcome::Signalled;
+
+        if stoppage {
+            // Play ran on until the ball went dead (goal, shot wide, ...).
+            if open {
+                self.accrue_pending_advantage();
+            }
+            self.issue_pending_advantage_card();
+            return;
+        }
+
+        if !open {
+            return;
+        }
+
+        let lost_ball = self
+            .ball
+            .current_owner
+            .is_some_and(|owner| TeamSide::is_home(owner) != pending.victim_is_home());
+        if lost_ball {
+            self.call_back_advantage();
+        } else if self.current_tick >= pending.signalled_tick + ADVANTAGE_WINDOW_TICKS {
+            self.accrue_pending_advantage();
+            // Nothing withheld: the decision is complete.
+            if pending.details.card_after_advantage().is_none() {
+                self.pending_advantage = None;
+            }
+        }
+    }
+
+    /// The fouled team kept the ball: no restart for the original foul.
+    fn accrue_pending_advantage(&mut self) {
+        let Some(pending) = self.pending_advantage.as_mut() else { return };
+        pending.details.outcome = AdvantageOutcome::Accrued;
+        let pending = *pending;
+
+        self.emit_event(MatchEvent::advantage_played(
+            self.minute,
+            self.current_timestamp_ms(),
+            pending.victim_is_home(),
+            pending.victim_idx,
+            pending.offender_idx,
+            pending.details,
+        ));
+    }
+
+    /// The advantage did not ensue: penalise the original foul at its spot.
+    fn call_back_advantage(&mut self) {
+        let Some(mut pending) = self.pending_advantage.take() else { return };
+        pending.details.outcome = AdvantageOutcome::CalledBack;
+        let victim_is_home = pending.victim_is_home();
+
+        self.emit_event(MatchEvent::advantage_played(
+            self.minute,
+            self.current_timestamp_ms(),
+            victim_is_home,
+            pending.victim_idx,
+            pending.offender_idx,
+            pending.details,
+        ));
+
+        if let Some(card) = pending.details.card() {
+            self.book_advantage_offender(pending.offender_idx, pending.victim_idx, card);
+        }
+
+        let restart_type =
+            if pending.in_penalty_area { RestartType::Penalty } else { RestartType::FreeKick };
+        self.action_queue.set_ball_state(BallState::OutOfPlay {
+            restart_type,
+            position: pending.foul_position,
+            home_team: victim_is_home,
+        });
+        self.handle_action_result(ActionResult::OutOfBounds {
+            restart_type,
+            position: pending.foul_position,
+            home_team: victim_is_home,
+        });
+    }
+
+    /// Next stoppage after an accrued advantage: show the withheld card.
+    fn issue_pending_advantage_card(&mut self) {
+        let Some(pending) = self.pending_advantage.take() else { return };
+        if let Some(card) = pending.details.card() {
+            self.book_advantage_offender(pending.offender_idx, pending.victim_idx, card);
+        }
+    }
+
+    fn book_advantage_offender(
+        &mut self,
+        offender_idx: usize,
+        victim_idx: usize,
+        card: AdvantageCard,
+    ) {
+        use super::momentum::events;
+
+        if matches!(self.player_states[offender_idx], PlayerState::SentOff) {
+            return;
+        }
+
+        // VAR checks a direct red card (not a second caution) before it is shown
+        let card = if card == AdvantageCard::Red && !self.var_confirms_red_card(offender_idx) {
+            AdvantageCard::Yellow
+        } else {
+            card
+        };
+
+        let is_home = TeamSide::is_home(offender_idx);
+        let already_booked = self
+            .result
+            .statistics
+            .discipline_for(offender_idx as u8)
+            .is_some_and(|d| d.yellow_cards > 0);
+
+        if card == AdvantageCard::Yellow {
+            self.emit_event(
+                MatchEvent::yellow_card(
+                    self.minute,
+                    self.current_timestamp_ms(),
+                    is_home,
+                    offender_idx,
+                )
+                .with_target_track_id(Some(victim_idx)),
+            );
+            if is_home {
+                self.result.statistics.yellow_cards_home += 1;
+                self.home_momentum.apply_event(events::YELLOW_CARD);
+            } else {
+                self.result.statistics.yellow_cards_away += 1;
+                self.away_momentum.apply_event(events::YELLOW_CARD);
+            }
+        }
+
+        if card == AdvantageCard::Red || already_booked {
+            self.emit_event(
+                MatchEvent::red_card(
+                    self.minute,
+                    self.current_timestamp_ms(),
+                    is_home,
+                    offender_idx,
+                )
+                .with_target_track_id(Some(victim_idx)),
+            );
+            if is_home {
+                self.result.statistics.red_cards_home += 1;
+                self.home_momentum.apply_event(events::RED_CARD);
+            } else {
+                self.result.statistics.red_cards_away += 1;
+                self.away_momentum.apply_event(events::RED_CARD);
+            }
+            self.send_off_player(offender_idx);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::match_sim::test_fixtures;
+    use crate::models::EventType;
+
+    fn advantage_outcomes(engine: &MatchEngine) -> Vec<AdvantageOutcome> {
+        engine
+            .result
+            .events
+            .iter()
+            .filter_map(|e| e.advantage_details().map(|a| a.outcome))
+            .collect()
+    }
+
+    fn played_on(engine: &mut MatchEngine, victim_idx: usize, details: AdvantageDetails) {
+        engine.pending_advantage = Some(PendingAdvantage {
+            offender_idx: 14,
+            victim_idx,
+            foul_position: engine.player_positions[victim_idx],
+            in_penalty_area: false,
+            signalled_tick: engine.current_tick,
+            details,
+        });
+        engine.ball.current_owner = Some(victim_idx);
+        engine.action_queue.set_ball_state(BallState::Controlled { owner_idx: victim_idx });
+        engine.restart_occurred_this_tick = false;
+    }
+
+    #[test]
+    fn advantage_is_called_back_when_the_ball_is_lost() {
+        let mut engine = test_fixtures::create_test_engine();
+        let details = AdvantageDetails {
+            outcome: AdvantageOutcome::Signalled,
+            severity: FoulSeverity::Careless,
+            is_dogso: false,
+            stopped_promising_attack: true,
+        };
+        played_on(&mut engine, 9, details);
+
+        // Opponent wins the ball inside the window
+        engine.current_tick += 2;
+        engine.ball.current_owner = Some(15);
+        engine.action_queue.set_ball_state(BallState::Controlled { owner_idx: 15 });
+        engine.update_pending_advantage();
+
+        assert_eq!(advantage_outcomes(&engine), vec![AdvantageOutcome::CalledBack]);
+        assert_eq!(engine.restart_type_this_tick, Some(RestartType::FreeKick));
+        assert!(engine.pending_advantage.is_none());
+        // Stopped for SPA: the original caution stands
+        let booking = engine.result.events.iter().find(|e| e.event_type == EventType::YellowCard);
+        assert_eq!(booking.and_then(|e| e.player_track_id), Some(14));
+    }
+
+    #[test]
+    fn accrued_dogso_is_cautioned_at_the_next_stoppage() {
+        let mut engine = test_fixtures::create_test_engine();
+        let details = AdvantageDetails {
+            outcome: AdvantageOutcome::Signalled,
+            severity: FoulSeverity::Careless,
+            is_dogso: true,
+            stopped_promising_attack: false,
+        };
+        played_on(&mut engine, 9, details);
+
+        engine.current_tick += ADVANTAGE_WINDOW_TICKS;
+        engine.update_pending_advantage();
+        assert_eq!(advantage_outcomes(&engine), vec![AdvantageOutcome::Accrued]);
+        assert!(!engine.result.events.iter().any(|e| e.event_type == EventType::YellowCard));
+
+        // Ball goes out: the withheld card is shown, reduced from red to yellow
+        engine.action_queue.set_ball_state(BallState::OutOfPlay {
+            restart_type: RestartType::ThrowIn,
+            position: engine.ball.position,
+            home_team: false,
+        });
+        engine.update_pending_advantage();
+
+        assert!(engine.pending_advantage.is_none());
+        let cards: Vec<_> = engine
+            .result
+            .events
+            .iter()
+            .filter(|e| matches!(e.event_type, EventType::YellowCard | EventType::RedCard))
+            .collect();
+        assert_eq!(cards.len(), 1);
+        assert_eq!(cards[0].event_type, EventType::YellowCard);
+        assert_eq!(cards[0].player_track_id, Some(14));
+    }
+
+    #[test]
+    fn full_match_resolves_every_advantage() {
+        let mut config = crate::engine::experimental::ExpConfig::default();
+        config.rulebook.advantage_play_enabled = true;
+        let mut engine = test_fixtures::create_test_engine().with_exp_config(&config);
+        let result = engine.simulate();
+
+        let outcomes: Vec<_> =
+            result.events.iter().filter_map(|e| e.advantage_details().map(|a| a.outcome)).collect();
+        let signalled = outcomes.iter().filter(|&&o| o == AdvantageOutcome::Signalled).count();
+        let resolved = outcomes.len() - signalled;
+        assert!(signalled > 0, "advantage should be played at least once");
+        // Only an advantage still open at the final whistle may be unresolved
+        assert!(resolved + 1 >= signalled && resolved <= signalled);
+    }
+
+    #[test]
+    fn aggressive_players_commit_more_reckless_fouls() {
+        // Same roll: calm player careless, aggressive player reckless
+        assert_eq!(foul_severity(10, 0.2, 1.0), FoulSeverity::Careless);
+        assert_eq!(foul_severity(90, 0.2, 1.0), FoulSeverity::Reckless);
+        assert_eq!(foul_severity(50, 0.0, 1.0), FoulSeverity::ExcessiveForce);
+        assert_eq!(foul_severity(100, 0.99, 1.0), FoulSeverity::Careless);
+    }
+
+    #[test]
+    fn card_happy_referee_books_more() {
+        // Same player and roll: lenient referee careless, strict referee reckless
+        assert_eq!(foul_severity(50, 0.2, 0.5), FoulSeverity::Careless);
+        assert_eq!(foul_severity(50, 0.2, 1.5), FoulSeverity::Reckless);
+    }
+
+    #[test]
+    fn dogso_needs_proximity_and_no_cover() {
+        assert!(is_obvious_goal_scoring_opportunity(18.0, 0));
+        assert!(!is_obvious_goal_scoring_opportunity(18.0, 1));
+        assert!(!is_obvious_goal_scoring_opportunity(40.0, 0));
+    }
+}
@@ -7,6 +7,7 @@
 //!
 //! Extracted from match_sim/mod.rs for better organization.
 
+use crate::engine::strict_math::StrictMath;
 use super::MatchEngine;
 use crate::engine::actions::{self, AerialDefender, AerialDuelContext};
 use crate::engine::ball::HeightProfile;
@@ -77,8 +78,8 @@ impl MatchEngine {
             let random_angle = self.rng.gen_range(0.0..std::f32::consts::TAU);
             let random_dist = self.rng.gen_range(2.0..5.0); // 2-5m
             let (ball_x, ball_y) = ball_pos.to_meters();
-            let target_x = (ball_x + random_angle.cos() * random_dist).clamp(-1.0, 106.0);
-            let target_y = (ball_y + random_angle.sin() * random_dist).clamp(-1.0, 69.0);
+            let target_x = (ball_x + random_angle.det_cos() * random_dist).clamp(-1.0, 106.0);
+            let target_y = (ball_y + random_angle.det_sin() * random_dist).clamp(-1.0, 69.0);
             let target = Coord10::from_meters(target_x, target_y);
             self.ball.start_flight(target, 2.0, None);
             self.ball.height_profile = HeightProfile::Arc;
@@ -21,12 +21,24 @@ mod tests {
             user_player: None,
             home_match_modifiers: crate::engine::TeamMatchModifiers::default(),
             away_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+            modifier_stack: Default::default(),
             home_instructions: None,
             away_instructions: None,
             home_player_instructions: None,
             away_player_instructions: None,
             home_ai_difficulty: Some(AIDifficulty::Expert),
             away_ai_difficulty: Some(AIDifficulty::Medium),
+            pitch: crate::models::pitch::PitchSpec::default(),
+            home_chemistry: None,
+            away_chemistry: None,
+            stadium: None,
+            conditions: None,
+            penalty_shootout: None,
+            home_custom_formation: None,
+            away_custom_formation: None,
+            home_set_piece_takers: None,
+            away_set_piece_takers: None,
+            referee: None,
         };
 
         let engine = MatchEngine::new(plan).expect("match engine init");
@@ -51,12 +63,24 @@ mod tests {
             user_player: None,
             home_match_modifiers: crate::engine::TeamMatchModifiers::default(),
             away_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+            modifier_stack: Default::default(),
             home_instructions: None,
             away_instructions: None,
             home_player_instructions: None,
             away_player_instructions: None,
             home_ai_difficulty: Some(AIDifficulty::Hard),
             away_ai_difficulty: None,
+            pitch: crate::models::pitch::PitchSpec::default(),
+            home_chemistry: None,
+            away_chemistry: None,
+            stadium: None,
+            conditions: None,
+            penalty_shootout: None,
+            home_custom_formation: None,
+            away_custom_formation: None,
+            home_set_piece_takers: None,
+            away_set_piece_takers: None,
+            referee: None,
         };
 
         let engine = MatchEngine::new(plan).expect("match engine init");
@@ -80,12 +104,24 @@ mod tests {
             user_player: None,
             home_match_modifiers: crate::engine::TeamMatchModifiers::default(),
             away_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+            modifier_stack: Default::default(),
             home_instructions: None,
             away_instructions: None,
             home_player_instructions: None,
             away_player_instructions: None,
             home_ai_difficulty: Some(AIDifficulty::Expert),
             away_ai_difficulty: Some(AIDifficulty::Expert),
+            pitch: crate::models::pitch::PitchSpec::default(),
+            home_chemistry: None,
+            away_chemistry: None,
+            stadium: None,
+            conditions: None,
+            penalty_shootout: None,
+            home_custom_formation: None,
+            away_custom_formation: None,
+            home_set_piece_takers: None,
+            away_set_piece_takers: None,
+            referee: None,
         };
 
         let mut engine = MatchEngine::new(plan).expect("match engine init");
@@ -117,12 +153,24 @@ mod tests {
                 user_player: None,
                 home_match_modifiers: crate::engine::TeamMatchModifiers::default(),
                 away_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+                modifier_stack: Default::default(),
                 home_instructions: None,
                 away_instructions: None,
                 home_player_instructions: None,
                 away_player_instructions: None,
                 home_ai_difficulty: Some(difficulty),
                 away_ai_difficulty: Some(difficulty),
+                pitch: crate::models::pitch::PitchSpec::default(),
+                home_chemistry: None,
+                away_chemistry: None,
+                stadium: None,
+                conditions: None,
+                penalty_shootout: None,
+                home_custom_formation: None,
+                away_custom_formation: None,
+                home_set_piece_takers: None,
+                away_set_piece_takers: None,
+                referee: None,
             };
 
             let engine = MatchEngine::new(plan).expect("match engine init");
@@ -133,4 +181,70 @@ mod tests {
 
         println!("✅ All difficulty levels initialized successfully!");
     }
+
+    /// 난이도 티어 vs 고정 베이스라인(AI 없음) 승점 (홈/원정 교대로 홈 어드밴티지 상쇄)
+    fn tier_points_vs_baseline(difficulty: AIDifficulty, matches: u64) -> u32 {
+        let mut points = 0;
+        for seed in 0..matches {
+            let tier_is_home = seed % 2 == 0;
+            let plan = MatchPlan {
+                home_team: create_test_team("Home"),
+                away_team: create_test_team("Away"),
+                seed: 9_000 + seed,
+                user_player: None,
+                home_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+                away_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+                modifier_stack: Default::default(),
+                home_instructions: None,
+                away_instructions: None,
+                home_player_instructions: None,
+                away_player_instructions: None,
+                home_ai_difficulty: tier_is_home.then_some(difficulty),
+                away_ai_difficulty: (!tier_is_home).then_some(difficulty),
+                pitch: crate::models::pitch::PitchSpec::default(),
+                home_chemistry: None,
+                away_chemistry: None,
+                stadium: None,
+                conditions: None,
+                penalty_shootout: None,
+                home_custom_formation: None,
+                away_custom_formation: None,
+                home_set_piece_takers: None,
+                away_set_piece_takers: None,
+                referee: None,
+            };
+            let result = MatchEngine::new(plan).expect("match engine init").simulate();
+            let (own, other) = if tier_is_home {
+                (result.score_home, result.score_away)
+            } else {
+                (result.score_away, result.score_home)
+            };
+            points += match own.cmp(&other) {
+                std::cmp::Ordering::Greater => 3,
+                std::cmp::Ordering::Equal => 1,
+                std::cmp::Ordering::Less => 0,
+            };
+        }
+        points
+    }
+
+    /// 난이도 캘리브레이션: 상위 티어가 같은 베이스라인 상대로 더 많은 승점을 얻어야 한다.
+    ///
+    /// 느림 (경기 수 × 4 티어). 실행:
+    /// `cargo test -p of_core --release --lib calibration_higher_tiers -- --ignored --nocapture`
+    #[test]
+    #[ignore]
+    fn test_calibration_higher_tiers_beat_baseline_more() {
+        const MATCHES: u64 = 40;
+        let tiers =
+            [AIDifficulty::Easy, AIDifficulty::Medium, AIDifficulty::Hard, AIDifficulty::Expert];
+        let points: Vec<u32> = tiers.iter().map(|&d| tier_points_vs_baseline(d, MATCHES)).collect();
+        for (tier, pts) in tiers.iter().zip(&points) {
+            println!("{:?}: {} pts / {} matches vs baseline", tier, pts, MATCHES);
+        }
+
+        assert!(points[3] > points[0], "Expert must out-score Easy: {:?}", points);
+        assert!(points[2] > points[0], "Hard must out-score Easy: {:?}", points);
+        assert!(points[3] >= points[1], "Expert must not trail Medium: {:?}", points);
+    }
 }
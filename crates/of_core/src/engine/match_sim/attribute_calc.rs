@@ -13,6 +13,7 @@
 //! - FM 스타일: 1-20 → `attribute::from_fm()`
 //! - OFB 스타일: 0-100 → `attribute::from_100()` 또는 `attr01()`
 
+use crate::engine::strict_math::StrictMath;
 use super::calculations::PressureLevel;
 use crate::engine::weights::{StackRule, WeightComposer};
 use crate::models::Position;
@@ -167,7 +168,7 @@ pub fn pass_distance_factor_v2(
     } else if ratio < 1.00 {
         // Near max range: exponential decay
         let over = ratio - 0.90;
-        0.70 - over.powf(1.5) * 2.50 // 0.70-0.45
+        0.70 - over.det_powf(1.5) * 2.50 // 0.70-0.45
     } else {
         // Beyond max range: severe penalty
         0.30
@@ -175,7 +176,7 @@ pub fn pass_distance_factor_v2(
 
     // Skill adjustment: reduces penalty by up to 35%
     let penalty = 1.0 - base_factor;
-    let penalty_reduction = skill.powf(1.2) * 0.35;
+    let penalty_reduction = skill.det_powf(1.2) * 0.35;
     let adjusted = 1.0 - penalty * (1.0 - penalty_reduction);
 
     adjusted.clamp(0.25, 0.98)
@@ -94,6 +94,34 @@ impl MatchEngine {
         AudacityContext { flair, audacity, desperation }
     }
 
+    /// Late-game desperation mode (DPER `desperation` params)
+    ///
+    /// True when enabled, the regulation clock is past `start_minute`
+    /// (stoppage-adjusted) and the team trails by 1..=`max_deficit`.
+    pub fn is_desperation_mode(&self, is_home: bool) -> bool {
+        let Some(params) = self.exp_params.as_ref() else {
+            return false;
+        };
+        if !params.desperation_enabled || !self.is_second_half {
+            return false;
+        }
+
+        // Second-half minutes are offset by first-half added time.
+        let trigger_minute = self
+            .regulation_end_minute()
+            .saturating_sub(90u8.saturating_sub(params.desperation_start_minute));
+        if self.minute < trigger_minute {
+            return false;
+        }
+
+        let (gf, ga) = if is_home {
+            (self.result.score_home as i32, self.result.score_away as i32)
+        } else {
+            (self.result.score_away as i32, self.result.score_home as i32)
+        };
+        (1..=params.desperation_max_deficit as i32).contains(&(ga - gf))
+    }
+
     /// Audacity 보정 적용
     ///
     /// rational_ev (P12)에 욕망/성향을 섞어서 최종 EV 반환
@@ -173,12 +201,24 @@ mod tests {
             user_player: None,
             home_match_modifiers: crate::engine::TeamMatchModifiers::default(),
             away_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+            modifier_stack: Default::default(),
             home_instructions: None,
             away_instructions: None,
             home_player_instructions: None,
             away_player_instructions: None,
             home_ai_difficulty: None,
             away_ai_difficulty: None,
+            pitch: crate::models::pitch::PitchSpec::default(),
+            home_chemistry: None,
+            away_chemistry: None,
+            stadium: None,
+            conditions: None,
+            penalty_shootout: None,
+            home_custom_formation: None,
+            away_custom_formation: None,
+            home_set_piece_takers: None,
+            away_set_piece_takers: None,
+            referee: None,
         };
 
         let mut engine = MatchEngine::new(plan).expect("match engine init");
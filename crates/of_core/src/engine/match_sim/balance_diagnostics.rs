@@ -18,7 +18,7 @@ use super::pitch_zone::{zone_of_position, PitchZone};
 use crate::engine::physics_constants::field;
 
 /// Diagnostic data collector for balance analysis
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct BalanceDiagnostics {
     /// Ball position samples (X coordinate in meters)
     ball_x_samples: Vec<f32>,
@@ -10,6 +10,7 @@
 //!
 //! Extracted from match_sim/mod.rs for better organization.
 
+use crate::engine::strict_math::StrictMath;
 use super::MatchEngine;
 use crate::engine::audit_gates;
 use crate::engine::ball::get_ball_position_3d;
@@ -163,7 +164,7 @@ impl MatchEngine {
         let multiplier = google_football::MAGNUS_MULTIPLIER;
 
         // Magnitude of Magnus force
-        let force_mag = c * speed.powf(p) * multiplier / 1000.0; // Scaled down for reasonable effect
+        let force_mag = c * speed.det_powf(p) * multiplier / 1000.0; // Scaled down for reasonable effect
 
         // Direction: perpendicular to velocity based on spin axis
         // sidespin (spin_y) creates horizontal deflection
@@ -188,6 +188,28 @@ mod tests {
                 .iter()
                 .any(|event| matches!(event.event_type, EventType::FullTime))
         );
+
+        // Structured statistics are attached with tracked distance covered
+        let stats = result.match_statistics.as_ref().expect("match_statistics generated");
+        assert_eq!(stats.goals_home, result.score_home);
+        assert!(stats.distance_covered_home_km > 0.0);
+        assert!(stats.distance_covered_away_km > 0.0);
+
+        let ratings = result.player_ratings.as_ref().expect("player_ratings generated");
+        assert!(ratings.len() >= 22);
+        let motm = result.man_of_the_match.expect("man of the match selected");
+        assert!(ratings.contains_key(&motm));
+
+        // Open-play shot outcomes carry the contextual xG feature vector behind xg_value
+        let mut with_features = 0;
+        for details in result.events.iter().filter_map(|e| e.details.as_ref()) {
+            let Some(features) = &details.xg_features else { continue };
+            with_features += 1;
+            if let Some(xg) = details.xg_value {
+                assert!((crate::engine::xg_model::contextual_xg(features) - xg).abs() < 1e-6);
+            }
+        }
+        assert!(with_features > 0, "no shot outcome event carries xg_features");
     }
 
     /// Validate that major events have actor_track_id set
@@ -310,12 +332,24 @@ mod tests {
             user_player: None,
             home_match_modifiers: crate::engine::TeamMatchModifiers::default(),
             away_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+            modifier_stack: Default::default(),
             home_instructions: None,
             away_instructions: None,
             home_player_instructions: None,
             away_player_instructions: None,
             home_ai_difficulty: None,
             away_ai_difficulty: None,
+            pitch: crate::models::pitch::PitchSpec::default(),
+            home_chemistry: None,
+            away_chemistry: None,
+            stadium: None,
+            conditions: None,
+            penalty_shootout: None,
+            home_custom_formation: None,
+            away_custom_formation: None,
+            home_set_piece_takers: None,
+            away_set_piece_takers: None,
+            referee: None,
         };
 
         let match_duration_min: u8 = 2;
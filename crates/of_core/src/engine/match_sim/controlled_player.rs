@@ -1,7 +1,10 @@
 //! Career Player Mode - Controlled Player State
 //!
 //! This module provides the state management for Career Player Mode,
-//! where a single player is controlled by the user during on-ball moments.
+//! where a single player is controlled by the user during on-ball moments
+//! or, with direct input, driven every tick (movement, pass, shot, tackle).
+
+use serde::{Deserialize, Serialize};
 
 /// Controlled player mode state
 #[derive(Debug, Clone, Default)]
@@ -17,12 +20,81 @@ pub struct ControlledPlayerMode {
 
     /// 입력 락 종료 틱
     pub lock_until_tick: u64,
+
+    /// 직접 조작 입력 (None = 온볼 명령만, 이동은 AI)
+    pub direct_input: Option<DirectInputState>,
+}
+
+/// 직접 조작 버튼 액션 (1회성)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UserInputAction {
+    Pass,
+    Shoot,
+    Tackle,
+}
+
+impl UserInputAction {
+    /// Godot 문자열 파싱 ("" / "none" = 액션 없음)
+    pub fn parse(action: &str) -> Result<Option<Self>, String> {
+        match action {
+            "" | "none" => Ok(None),
+            "pass" => Ok(Some(Self::Pass)),
+            "shoot" | "shot" => Ok(Some(Self::Shoot)),
+            "tackle" => Ok(Some(Self::Tackle)),
+            other => Err(format!("Unknown input action: {}", other)),
+        }
+    }
+}
+
+/// 직접 조작 입력 상태
+///
+/// 이동 방향은 다음 입력까지 매 틱 유지되고, 버튼 액션은 1회 소비된다.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct DirectInputState {
+    /// 이동 방향 (피치 미터 축, 단위 벡터; (0,0) = 정지)
+    pub move_dir: (f32, f32),
+    /// 대기 중인 버튼 액션과 만료 틱
+    pub pending_action: Option<(UserInputAction, u64)>,
+}
+
+impl DirectInputState {
+    /// 버튼 액션 유효 시간 (4틱 = 1초): 그 안에 실행 조건이 안 되면 버림
+    pub const ACTION_TTL_TICKS: u64 = 4;
+
+    /// 이동 목표 선행 거리 (m)
+    pub const MOVE_LOOKAHEAD_M: f32 = 5.0;
+
+    /// 아직 유효한 대기 액션
+    pub fn pending(&self, current_tick: u64) -> Option<UserInputAction> {
+        self.pending_action.filter(|(_, expires)| current_tick < *expires).map(|(a, _)| a)
+    }
+
+    /// 스틱 중립 (정지 입력)
+    pub fn is_idle(&self) -> bool {
+        self.move_dir.0 == 0.0 && self.move_dir.1 == 0.0
+    }
+
+    /// 이동 목표 위치 (현재 위치 + 방향 × 선행 거리, 피치 내로 클램프)
+    pub fn move_target_m(&self, pos_m: (f32, f32), pitch_m: (f32, f32)) -> (f32, f32) {
+        let (dx, dy) = self.move_dir;
+        (
+            (pos_m.0 + dx * Self::MOVE_LOOKAHEAD_M).clamp(0.0, pitch_m.0),
+            (pos_m.1 + dy * Self::MOVE_LOOKAHEAD_M).clamp(0.0, pitch_m.1),
+        )
+    }
 }
 
 impl ControlledPlayerMode {
     /// Create a new controlled player mode instance
     pub fn new(controlled_track_id: usize) -> Self {
-        Self { enabled: true, controlled_track_id, last_consumed_seq: 0, lock_until_tick: 0 }
+        Self {
+            enabled: true,
+            controlled_track_id,
+            last_consumed_seq: 0,
+            lock_until_tick: 0,
+            direct_input: None,
+        }
     }
 
     /// 해당 선수가 컨트롤 대상인지 확인
@@ -95,6 +167,21 @@ mod tests {
         assert_eq!(mode.remaining_lock_ticks(110), 0);
     }
 
+    #[test]
+    fn test_direct_input_action_expires_and_target_clamps() {
+        let input = DirectInputState {
+            move_dir: (1.0, 0.0),
+            pending_action: Some((UserInputAction::Pass, 10 + DirectInputState::ACTION_TTL_TICKS)),
+        };
+        assert_eq!(input.pending(10), Some(UserInputAction::Pass));
+        assert_eq!(input.pending(14), None);
+        assert_eq!(input.move_target_m((50.0, 30.0), (105.0, 68.0)), (55.0, 30.0));
+        assert_eq!(input.move_target_m((103.0, 30.0), (105.0, 68.0)), (105.0, 30.0));
+        assert_eq!(UserInputAction::parse("tackle"), Ok(Some(UserInputAction::Tackle)));
+        assert_eq!(UserInputAction::parse(""), Ok(None));
+        assert!(UserInputAction::parse("dive").is_err());
+    }
+
     #[test]
     fn test_default() {
         let mode = ControlledPlayerMode::default();
@@ -10,6 +10,7 @@
 //! - Gate C는 순수 실행 (Bias 없음)
 //! - EV 계산은 Gate B까지만 (이후에는 없음)
 
+use crate::engine::strict_math::{strict_sum, StrictMath};
 use rand::Rng;
 
 use super::cognitive_bias::CognitiveBias;
@@ -359,6 +360,8 @@ pub struct DecisionContext {
     ///
     /// Used to adjust softmax temperature (Gate B) deterministically.
     pub team_tempo_factor: f32,
+    /// AI difficulty decision noise (softmax temperature multiplier, 1.0 = neutral).
+    pub team_decision_noise: f32,
     /// Width bias from TeamInstructions (-5.0..+5.0 meters).
     ///
     /// Used to bias wide-play (Cross/SwitchPlay) without changing UI meaning.
@@ -476,6 +479,7 @@ impl Default for DecisionContext {
             event_mix_profile: None,
             team_pressing_factor: 0.6,
             team_tempo_factor: 0.6,
+            team_decision_noise: 1.0,
             team_width_bias_m: 0.0,
             team_risk_bias: 0.0,
             has_better_positioned_teammate: false,
@@ -531,6 +535,16 @@ pub fn apply_team_tempo_temperature(
     apply_team_tempo_temperature_factor(base_temperature, instructions.get_tempo_factor())
 }
 
+/// Apply AI difficulty decision noise to softmax temperature.
+///
+/// Neutral noise (1.0) leaves the temperature unchanged.
+pub fn apply_decision_noise(temperature: f32, decision_noise: f32) -> f32 {
+    if decision_noise == 1.0 {
+        return temperature;
+    }
+    (temperature * decision_noise).clamp(0.1, 1.5)
+}
+
 /// Apply team tempo factor (0.2..1.0) to softmax temperature.
 ///
 /// Higher tempo → higher temperature (more exploratory/risky decisions).
@@ -635,7 +649,7 @@ pub fn apply_team_instructions(
 #[inline]
 fn ln_weight(bd: &WeightBreakdown) -> f32 {
     // ln(0) 방지 포함
-    bd.to_weight().ln()
+    bd.to_weight().det_ln()
 }
 
 /// 액션 이력 (Budget Gate용)
@@ -1317,7 +1331,7 @@ fn event_mix_factor_for_action(action: CandidateAction, ctx: &DecisionContext) -
         base.mul(event_mix_profile_weights(profile)).mul(ofm_event_mix_weights(profile, zone));
     let normalized = tuned.normalize_by_mean();
     let weight = normalized.for_bucket(bucket).max(0.0001);
-    let factor = weight.powf(EVENT_MIX_STRENGTH).clamp(EVENT_MIX_CLAMP_MIN, EVENT_MIX_CLAMP_MAX);
+    let factor = weight.det_powf(EVENT_MIX_STRENGTH).clamp(EVENT_MIX_CLAMP_MIN, EVENT_MIX_CLAMP_MAX);
     Some(factor)
 }
 
@@ -3069,8 +3083,10 @@ pub fn decide_action_with_detail(
 
     // 2. Utility Selection (Gate B)
     let base_temperature = calculate_temperature(flair, decisions, concentration);
-    let temperature =
-        apply_team_tempo_temperature_factor(base_temperature, decision_ctx.team_tempo_factor);
+    let temperature = apply_decision_noise(
+        apply_team_tempo_temperature_factor(base_temperature, decision_ctx.team_tempo_factor),
+        decision_ctx.team_decision_noise,
+    );
     let (intent, utility, results) =
         select_best_intent(&soft_candidates, decision_ctx, bias, temperature, rng);
 
@@ -3306,6 +3322,7 @@ pub fn build_decision_intent(
         target_player_id: None,
         pass_targets: Vec::new(),
         nearby_opponents: Vec::new(),
+        pass_candidates: Vec::new(),
     }
 }
 
@@ -3319,9 +3336,9 @@ fn compute_softmax_probabilities(utilities: &[f32], temperature: f32) -> Vec<f32
     let max_u = utilities.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
 
     // Compute exp((u - max) / temp)
-    let exps: Vec<f32> = utilities.iter().map(|u| ((u - max_u) / temperature).exp()).collect();
+    let exps: Vec<f32> = utilities.iter().map(|u| ((u - max_u) / temperature).det_exp()).collect();
 
-    let sum: f32 = exps.iter().sum();
+    let sum = strict_sum(exps.iter().copied());
 
     if sum > 0.0 {
         exps.iter().map(|e| e / sum).collect()
@@ -100,6 +100,10 @@ pub mod subcase {
     // Intercept (0x09xx)
     /// 인터셉트 지점 조정
     pub const INTERCEPT_POINT_ADJUST: u32 = 0x0900;
+
+    // AI Manager (0x0Axx)
+    /// CPU 감독의 상황 대응 여부 (분 단위)
+    pub const AI_MANAGER_REACT: u32 = 0x0A00;
 }
 
 // ============================================================================
@@ -44,8 +44,9 @@
 // P18: calculate_pressure_context deprecated 경고 억제 (FieldBoard 마이그레이션 예정)
 #![allow(deprecated)]
 
+use crate::engine::strict_math::StrictMath;
 use super::attribute_calc;
-use super::MatchEngine;
+use super::{MatchEngine, UserInputAction};
 
 // FIX_2601/1124: Gate A 검증용 CandidateKey
 use super::candidate_key::CandidateKey;
@@ -59,7 +60,7 @@ use crate::engine::probability;
 use crate::engine::tactical_context::TeamSide;
 use crate::engine::types::Coord10; // FIX_2601
 use crate::models::TeamSide as MatchTeamSide;
-use crate::models::replay::types::{IntentTarget, MeterPos};
+use crate::models::replay::types::{IntentTarget, MeterPos, PassCandidateEval};
 // P9: ActionModel 통합
 use crate::engine::phase_action::{
     ActionModel, DribbleContext, DribbleIntent, DribbleModel, DribbleSkills, PassContext,
@@ -773,8 +774,8 @@ impl MatchEngine {
         for angle_deg in ANGLES_DEG {
             let angle_rad = angle_deg.to_radians();
             let dir = (
-                forward.0 * angle_rad.cos() - forward.1 * angle_rad.sin(),
-                forward.0 * angle_rad.sin() + forward.1 * angle_rad.cos(),
+                forward.0 * angle_rad.det_cos() - forward.1 * angle_rad.det_sin(),
+                forward.0 * angle_rad.det_sin() + forward.1 * angle_rad.det_cos(),
             );
             let target = (
                 player_pos_m.0 + dir.0 * SCAN_DISTANCE_M,
@@ -1686,6 +1687,27 @@ impl MatchEngine {
                 if controlled.is_controlled(player_idx) {
                     // 2) 엔진 입력 락 체크
                     if !controlled.is_locked(self.current_tick) {
+                        // 3-0) 직접 조작 패스/슈팅 버튼 (큐 명령보다 우선)
+                        let direct_on_ball = [UserInputAction::Pass, UserInputAction::Shoot];
+                        if let Some(action) =
+                            self.take_direct_input_action(player_idx, &direct_on_ball)
+                        {
+                            let payload = super::UserCommandPayload::OnBallAction {
+                                action: if action == UserInputAction::Pass {
+                                    super::OnBallAction::Pass
+                                } else {
+                                    super::OnBallAction::Shoot
+                                },
+                                variant: None,
+                                target_track_id: None,
+                            };
+                            let lock_duration = Self::lock_duration_for_user_cmd(&payload);
+                            if let Some(ref mut ctrl) = self.controlled_mode {
+                                ctrl.lock(self.current_tick, lock_duration);
+                            }
+                            return self.convert_user_cmd_to_player_action(player_idx, payload);
+                        }
+
                         // 3) 큐에서 유효한 명령 1개 소비
                         if let Some(cmd) = self.pop_latest_valid_cmd_for(player_idx) {
                             let lock_duration = Self::lock_duration_for_user_cmd(&cmd.payload);
@@ -1861,6 +1883,13 @@ impl MatchEngine {
                 .collect();
         }
 
+        if self.pass_candidate_debug && !elab_ctx.pass_targets.is_empty() {
+            let is_home = MatchTeamSide::is_home(player_idx);
+            let mut evals = Vec::new();
+            self.build_pass_targets_inner(player_idx, is_home, Some(&mut evals));
+            intent_log.pass_candidates = evals;
+        }
+
         if !elab_ctx.nearby_opponents.is_empty() {
             intent_log.nearby_opponents = elab_ctx
                 .nearby_opponents
@@ -2199,7 +2228,7 @@ impl MatchEngine {
         // teammates_in_box: Count teammates in attacking penalty box
         let teammates_in_box = self.count_teammates_in_box(player_idx, is_home, attacks_right);
 
-        let ctx = DecisionContext {
+        let mut ctx = DecisionContext {
             xg,
             distance_to_goal: distance_m,
             local_pressure,
@@ -2226,7 +2255,14 @@ impl MatchEngine {
             // Phase G v1: Team tactics knobs (deterministic, evidence-grade)
             team_pressing_factor: instructions.get_pressing_factor(),
             team_tempo_factor: instructions.get_tempo_factor(),
-            team_width_bias_m: instructions.get_width_bias_m(),
+            team_decision_noise: self.ai_difficulty_profiles[if is_home { 0 } else { 1 }]
+                .decision_noise,
+            team_width_bias_m: instructions.get_width_bias_m()
+                + if is_home {
+                    self.home_match_modifiers.width_bias_add_m
+                } else {
+                    self.away_match_modifiers.width_bias_add_m
+                },
             team_risk_bias: super::decision_topology::build_up_style_risk_delta(instructions),
             // FIX_2601/0105 Phase 2+4: Shot realism fields
             has_better_positioned_teammate,
@@ -2249,7 +2285,11 @@ impl MatchEngine {
             in_attacking_third,
             teammates_in_box,
             // DPER Framework: Experimental parameters
-            exp_shoot_xg_threshold: self.exp_shoot_xg_threshold(),
+            exp_shoot_xg_threshold: if self.is_desperation_mode(is_home) {
+                self.exp_shoot_xg_threshold() * self.exp_desperation_shot_threshold_mult()
+            } else {
+                self.exp_shoot_xg_threshold()
+            },
             exp_dribble_bias: self.exp_dribble_bias(),
             exp_through_ball_multiplier: self.exp_through_ball_multiplier(),
             exp_cross_multiplier: self.exp_cross_multiplier(),
@@ -2304,6 +2344,20 @@ impl MatchEngine {
             safe_pass_seq: self.safe_pass_seq,
         };
 
+        // Role × individual instructions (tactics::roles): scale on-ball action biases per player
+        let instructions = self.get_player_instructions(player_idx);
+        let utilities = self
+            .role_profile(player_idx)
+            .utilities
+            .combine(crate::tactics::roles::instruction_utilities(&instructions));
+        ctx.cal_shot_bias *= utilities.shot;
+        ctx.cal_dribble_bias *= utilities.take_on;
+        ctx.cal_cross_bias *= utilities.cross;
+        ctx.cal_through_ball_bias *= utilities.through_ball;
+        ctx.cal_progressive_pass_bias *= utilities.progressive_pass;
+        ctx.cal_safe_pass_bias *= utilities.safe_pass;
+        ctx.cal_long_pass_bias *= utilities.switch_play;
+
         // P18: Performance profiling + behavior validation output (every 100 ticks)
         #[cfg(debug_assertions)]
         {
@@ -2452,6 +2506,17 @@ impl MatchEngine {
         &self,
         player_idx: usize,
         is_home: bool,
+    ) -> Vec<(usize, (f32, f32), f32)> {
+        self.build_pass_targets_inner(player_idx, is_home, None)
+    }
+
+    /// `build_pass_targets` with optional per-factor capture for the debug overlay.
+    /// Capturing never changes the returned targets.
+    fn build_pass_targets_inner(
+        &self,
+        player_idx: usize,
+        is_home: bool,
+        mut evals: Option<&mut Vec<PassCandidateEval>>,
     ) -> Vec<(usize, (f32, f32), f32)> {
         let player_pos = self.get_player_position_by_index(player_idx);
         let player_pos_m = player_pos.to_meters();
@@ -2480,6 +2545,12 @@ impl MatchEngine {
                     attacks_right,
                 );
                 if is_forward && self.is_offside_position(idx, is_home) {
+                    if let Some(evals) = evals.as_deref_mut() {
+                        let mut eval = self.pass_candidate_eval(player_idx, idx, attacks_right);
+                        eval.is_forward = true;
+                        eval.excluded = Some("offside".to_string());
+                        evals.push(eval);
+                    }
                     continue;
                 }
 
@@ -2560,8 +2631,22 @@ impl MatchEngine {
                     quality = WIDE_PLAYER_QUALITY_FLOOR;
                 }
 
+                let eval = evals.as_ref().map(|_| {
+                    let mut eval = self.pass_candidate_eval(player_idx, idx, attacks_right);
+                    eval.distance_factor = base_quality;
+                    eval.zone_factor = zone_factor;
+                    eval.progression_factor = progression_bonus;
+                    eval.diversity_penalty = diversity_penalty;
+                    eval.is_forward = is_forward;
+                    eval
+                });
+
                 // GK 제외 (0, 11)
                 if idx == 0 || idx == 11 {
+                    if let (Some(evals), Some(mut eval)) = (evals.as_deref_mut(), eval) {
+                        eval.excluded = Some("goalkeeper".to_string());
+                        evals.push(eval);
+                    }
                     continue;
                 }
 
@@ -2573,9 +2658,16 @@ impl MatchEngine {
                 // FIX_2601/0123: Skip tactical threshold only for backward passes (safe options)
                 // Removed reciprocity skip to reduce A→B→A patterns
                 let skip_tactical = !is_forward;
-                if skip_tactical || target_tactical >= passer_tactical + tactical_threshold {
+                let tactical_ok = target_tactical >= passer_tactical + tactical_threshold;
+                if skip_tactical || tactical_ok {
                     targets.push(candidate);
                 }
+
+                if let (Some(evals), Some(mut eval)) = (evals.as_deref_mut(), eval) {
+                    eval.quality = quality;
+                    eval.tactical_ok = tactical_ok;
+                    evals.push(eval);
+                }
             }
         }
 
@@ -2598,6 +2690,47 @@ impl MatchEngine {
         targets
     }
 
+    /// Diagnostic pass factors shared by every candidate (pressure, lane, progression)
+    fn pass_candidate_eval(
+        &self,
+        passer_idx: usize,
+        receiver_idx: usize,
+        attacks_right: bool,
+    ) -> PassCandidateEval {
+        use crate::engine::body_blocking;
+
+        const PRESSURE_RADIUS_M: f32 = 10.0;
+        const LANE_CLEAR_M: f32 = 5.0;
+
+        let from_m = self.get_player_position_by_index(passer_idx).to_meters();
+        let to_m = self.get_player_position_by_index(receiver_idx).to_meters();
+
+        let pressure = self
+            .find_nearest_defender_for_ev(receiver_idx)
+            .map_or(0.0, |(_, dist)| (1.0 - dist / PRESSURE_RADIUS_M).clamp(0.0, 1.0));
+        let lane_dist = MatchTeamSide::opponent_range(passer_idx)
+            .filter_map(|opp| self.player_positions.get(opp))
+            .map(|pos| body_blocking::point_to_line_distance(pos.to_meters(), from_m, to_m))
+            .fold(f32::MAX, f32::min);
+        let progression_m = if attacks_right { to_m.0 - from_m.0 } else { from_m.0 - to_m.0 };
+
+        PassCandidateEval {
+            player_id: receiver_idx as u32,
+            pos: MeterPos { x: to_m.0 as f64, y: to_m.1 as f64 },
+            quality: 0.0,
+            distance_factor: 0.0,
+            zone_factor: 0.0,
+            progression_factor: 0.0,
+            diversity_penalty: 0.0,
+            pressure,
+            lane_openness: (lane_dist / LANE_CLEAR_M).clamp(0.0, 1.0),
+            progression_m,
+            is_forward: false,
+            tactical_ok: false,
+            excluded: None,
+        }
+    }
+
     /// Mindset 결정
     fn determine_player_mindset(
         &self,
@@ -4113,12 +4246,24 @@ mod tests {
             user_player: None,
             home_match_modifiers: crate::engine::TeamMatchModifiers::default(),
             away_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+            modifier_stack: Default::default(),
             home_instructions: None,
             away_instructions: None,
             home_player_instructions: None,
             away_player_instructions: None,
             home_ai_difficulty: None,
             away_ai_difficulty: None,
+            pitch: crate::models::pitch::PitchSpec::default(),
+            home_chemistry: None,
+            away_chemistry: None,
+            stadium: None,
+            conditions: None,
+            penalty_shootout: None,
+            home_custom_formation: None,
+            away_custom_formation: None,
+            home_set_piece_takers: None,
+            away_set_piece_takers: None,
+            referee: None,
         };
         let mut engine = MatchEngine::new(plan).expect("match engine init");
         engine.initialize_player_positions();
@@ -4440,4 +4585,25 @@ mod tests {
             "Clear lane pass should have lower risk than blocked lane pass"
         );
     }
+
+    #[test]
+    fn test_pass_candidate_capture_matches_targets() {
+        let engine = create_test_engine();
+        let passer = 6;
+
+        let targets = engine.build_pass_targets(passer, true);
+        let mut evals = Vec::new();
+        let captured = engine.build_pass_targets_inner(passer, true, Some(&mut evals));
+
+        assert_eq!(targets, captured, "capture must not change pass targets");
+        assert_eq!(evals.len(), 10, "every teammate is reported");
+        assert!(evals.iter().any(|e| e.player_id == 0 && e.excluded.as_deref() == Some("goalkeeper")));
+        for (idx, _, quality) in &targets {
+            let eval = evals.iter().find(|e| e.player_id == *idx as u32).unwrap();
+            assert!(eval.excluded.is_none());
+            assert_eq!(eval.quality, *quality);
+            assert!((0.0..=1.0).contains(&eval.pressure));
+            assert!((0.0..=1.0).contains(&eval.lane_openness));
+        }
+    }
 }
@@ -0,0 +1,169 @@
+//! Goalkeeper holding (IFAB Law 12.2)
+//!
+//! A goalkeeper who has caught the ball must release it within six seconds.
+//! Holding it longer is penalised with an indirect free kick to the opponents
+//! at the goalkeeper's position; inside the goal area the kick moves to the
+//! goal-area line (Law 13.2).
+
+use super::MatchEngine;
+use crate::engine::action_queue::{ActionResult, BallState, RestartType};
+use crate::engine::physics_constants::field;
+use crate::engine::types::Coord10;
+use crate::models::{MatchEvent, TeamSide};
+
+/// Six seconds at 250ms/tick.
+pub(crate) const GK_HOLDING_LIMIT_TICKS: u64 = 24;
+
+const TICK_MS: u64 = 250;
+
+/// Goal-area depth from the goal line (meters).
+const GOAL_AREA_DEPTH_M: f32 = 5.5;
+
+/// Goal-area half width (meters, 18.32m box).
+const GOAL_AREA_HALF_WIDTH_M: f32 = 9.16;
+
+/// Goalkeeper currently holding the ball in the hands.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct GoalkeeperInHands {
+    pub goalkeeper_idx: usize,
+    pub since_tick: u64,
+}
+
+/// Indirect free kick spot for an offence by the team defending the goal at x=0
+/// (`own_goal_at_zero`) or x=105: goal-area offences move to the goal-area line.
+pub(crate) fn indirect_free_kick_spot(pos_m: (f32, f32), own_goal_at_zero: bool) -> (f32, f32) {
+    let (x, y) = pos_m;
+    let depth = if own_goal_at_zero { x } else { field::LENGTH_M - x };
+    let in_goal_area =
+        depth < GOAL_AREA_DEPTH_M && (y - field::CENTER_Y).abs() <= GOAL_AREA_HALF_WIDTH_M;
+    if !in_goal_area {
+        return (x, y);
+    }
+    let x = if own_goal_at_zero { GOAL_AREA_DEPTH_M } else { field::LENGTH_M - GOAL_AREA_DEPTH_M };
+    (x, y)
+}
+
+impl MatchEngine {
+    /// Start the six-second count after a catch.
+    pub(crate) fn goalkeeper_took_ball_in_hands(&mut self, goalkeeper_idx: usize) {
+        self.goalkeeper_in_hands =
+            Some(GoalkeeperInHands { goalkeeper_idx, since_tick: self.current_tick });
+    }
+
+    /// Per-tick check: release or stoppage ends the count; over the limit → IFK.
+    pub(crate) fn update_goalkeeper_holding(&mut self) {
+        let Some(hold) = self.goalkeeper_in_hands else { return };
+
+        let still_holding = !self.restart_occurred_this_tick
+            && matches!(
+                self.action_queue.ball_state(),
+                BallState::Controlled { owner_idx } if *owner_idx == hold.goalkeeper_idx
+            );
+        if !still_holding {
+            self.goalkeeper_in_hands = None;
+            return;
+        }
+
+        let held_ticks = self.current_tick.saturating_sub(hold.since_tick);
+        if held_ticks > GK_HOLDING_LIMIT_TICKS {
+            self.goalkeeper_in_hands = None;
+            self.penalise_goalkeeper_holding(hold.goalkeeper_idx, held_ticks);
+        }
+    }
+
+    fn penalise_goalkeeper_holding(&mut self, goalkeeper_idx: usize, held_ticks: u64) {
+        let is_home = TeamSide::is_home(goalkeeper_idx);
+        let gk_pos_m = self.player_positions[goalkeeper_idx].to_meters();
+
+        self.emit_event(MatchEvent::goalkeeper_holding(
+            self.minute,
+            self.current_timestamp_ms(),
+            is_home,
+            goalkeeper_idx,
+            (gk_pos_m.0, gk_pos_m.1, self.ball.height_meters()),
+            (held_ticks * TICK_MS) as u32,
+        ));
+
+        let own_goal_at_zero = self.attacks_right(is_home);
+        let (x, y) = indirect_free_kick_spot(gk_pos_m, own_goal_at_zero);
+        self.pending_indirect_free_kick = true;
+        self.handle_action_result(ActionResult::OutOfBounds {
+            restart_type: RestartType::FreeKick,
+            position: Coord10::from_meters(x, y),
+            home_team: !is_home,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::match_sim::test_fixtures;
+    use crate::engine::phase_action::SetPieceType;
+    use crate::models::EventType;
+
+    fn catch_ball(engine: &mut MatchEngine, gk_idx: usize, pos_m: (f32, f32)) {
+        engine.player_positions[gk_idx] = Coord10::from_meters(pos_m.0, pos_m.1);
+        engine.ball.position = engine.player_positions[gk_idx];
+        engine.ball.current_owner = Some(gk_idx);
+        engine.action_queue.set_ball_state(BallState::Controlled { owner_idx: gk_idx });
+        engine.restart_occurred_this_tick = false;
+        engine.goalkeeper_took_ball_in_hands(gk_idx);
+    }
+
+    #[test]
+    fn holding_beyond_six_seconds_gives_indirect_free_kick() {
+        let mut engine = test_fixtures::create_test_engine();
+        catch_ball(&mut engine, 0, (12.0, 30.0));
+
+        engine.current_tick += GK_HOLDING_LIMIT_TICKS;
+        engine.update_goalkeeper_holding();
+        assert!(!engine.result.events.iter().any(|e| e.event_type == EventType::GoalkeeperHolding));
+
+        engine.current_tick += 1;
+        engine.update_goalkeeper_holding();
+
+        let offence = engine
+            .result
+            .events
+            .iter()
+            .find(|e| e.event_type == EventType::GoalkeeperHolding)
+            .expect("holding offence should be called");
+        assert_eq!(offence.player_track_id, Some(0));
+        assert_eq!(offence.goalkeeper_offence_details().and_then(|d| d.held_ms), Some(6_250));
+        assert!(engine.goalkeeper_in_hands.is_none());
+
+        let set_piece = engine.active_set_pieces.last().expect("indirect free kick");
+        assert_eq!(set_piece.set_piece_type, SetPieceType::FreeKickIndirect);
+        let kicker_idx = engine.ball.current_owner.expect("free kick should assign a kicker");
+        assert!(!TeamSide::is_home(kicker_idx), "away team should take the indirect free kick");
+    }
+
+    #[test]
+    fn releasing_the_ball_ends_the_count() {
+        let mut engine = test_fixtures::create_test_engine();
+        catch_ball(&mut engine, 11, (95.0, 34.0));
+
+        engine.current_tick += 10;
+        engine.ball.current_owner = Some(15);
+        engine.action_queue.set_ball_state(BallState::Controlled { owner_idx: 15 });
+        engine.update_goalkeeper_holding();
+        assert!(engine.goalkeeper_in_hands.is_none());
+
+        engine.current_tick += GK_HOLDING_LIMIT_TICKS;
+        engine.update_goalkeeper_holding();
+        assert!(!engine.result.events.iter().any(|e| e.event_type == EventType::GoalkeeperHolding));
+    }
+
+    #[test]
+    fn goal_area_offence_moves_to_goal_area_line() {
+        assert_eq!(indirect_free_kick_spot((2.0, 30.0), true), (GOAL_AREA_DEPTH_M, 30.0));
+        assert_eq!(
+            indirect_free_kick_spot((103.0, 38.0), false),
+            (field::LENGTH_M - GOAL_AREA_DEPTH_M, 38.0)
+        );
+        // Outside the goal area the spot is unchanged
+        assert_eq!(indirect_free_kick_spot((12.0, 30.0), true), (12.0, 30.0));
+        assert_eq!(indirect_free_kick_spot((2.0, 10.0), true), (2.0, 10.0));
+    }
+}
@@ -84,22 +84,18 @@ impl MatchEngine {
     }
 
     /// Filter events for display based on highlight level
+    ///
+    /// Co-op: 각 유저 슬롯의 하이라이트 레벨을 독립 적용하고, 어느 슬롯이든 허용한 이벤트를 남긴다.
     pub(crate) fn filter_events_for_display(&mut self) {
-        use crate::engine::events::filter_events_by_highlight_level;
-
-        let user_config = match &self.user_player {
-            Some(config) => config,
+        let configs = match &self.user_player {
+            Some(config) => config.highlight_configs(),
             None => return,
         };
 
         // 공통 HighlightLevel 정책에 따라 배치 결과 이벤트를 필터링한다.
-        let filtered = filter_events_by_highlight_level(
-            &self.result.events,
-            user_config.highlight_level,
-            Some(user_config.player_index as u8),
-        );
-
-        self.result.events = filtered;
+        self.result.events.retain(|event| {
+            configs.iter().any(|(level, track_id)| level.allows(event, Some(*track_id as u8)))
+        });
     }
 
     /// Get player position by index (uses dynamic position if available)
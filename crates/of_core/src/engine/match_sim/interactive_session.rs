@@ -9,6 +9,7 @@
 //! - `simulate_until_intervention()` - Run simulation until intervention
 //! - `execute_direct_pass_to()` - Execute user-directed pass
 //! - `resume_with_action()` - Resume after user action
+//! - `pending_user_decisions()` - Co-op: per-user-slot decision prompt queue
 
 use rand::Rng;
 
@@ -106,6 +107,7 @@ impl MatchEngine {
 
         UserDecisionContext {
             player_id,
+            user_slot: self.user_slot_of(player_idx).unwrap_or(0) as u8,
             time_seconds: self.current_timestamp_ms as f32 / 1000.0,
             position_m: pos_m,
             options: ActionOptions { shoot_prob, dribble_prob, pass_targets },
@@ -123,10 +125,11 @@ impl MatchEngine {
 
         let is_home = TeamSide::is_home(owner_idx);
 
-        // Only consider moments when the ball holder IS the configured "my player"
+        // Only consider moments when the ball holder IS one of the configured user players
         if !self.is_user_controlled_player(owner_idx, is_home) {
             return None;
         }
+        let slot = self.user_slot_of(owner_idx)?;
 
         // Basic cooldown: avoid pausing too frequently (e.g. every few seconds)
         const INTERVENTION_COOLDOWN_MS: u64 = 10_000;
@@ -136,9 +139,9 @@ impl MatchEngine {
             (self.minute as u64) * 60_000
         };
 
-        if self.last_intervention_ms != 0
-            && approx_now_ms < self.last_intervention_ms + INTERVENTION_COOLDOWN_MS
-        {
+        // Co-op: each user slot has its own cooldown
+        let last_ms = self.last_intervention_ms.get(slot).copied().unwrap_or(0);
+        if last_ms != 0 && approx_now_ms < last_ms + INTERVENTION_COOLDOWN_MS {
             return None;
         }
 
@@ -175,11 +178,26 @@ impl MatchEngine {
             return None;
         }
 
-        // Record last intervention timestamp and return context
-        self.last_intervention_ms = approx_now_ms;
+        // Record last intervention timestamp, queue the prompt for its slot and return it
+        if let Some(last) = self.last_intervention_ms.get_mut(slot) {
+            *last = approx_now_ms;
+        }
+        if let Some(queue) = self.user_decision_queues.get_mut(slot) {
+            queue.push_back(ctx.clone());
+        }
         Some(ctx)
     }
 
+    /// Co-op: unanswered decision prompts for a user slot (0 = primary user player).
+    pub fn pending_user_decisions(&self, slot: usize) -> Vec<UserDecisionContext> {
+        self.user_decision_queues.get(slot).map(|q| q.iter().cloned().collect()).unwrap_or_default()
+    }
+
+    /// Co-op: number of configured user slots (0 when no user player is set).
+    pub fn user_slot_count(&self) -> usize {
+        self.user_decision_queues.len()
+    }
+
     /// Phase E: experimental interactive simulation entry point.
     /// This method mirrors `simulate()` but is structured to allow
     /// insertion of intervention points. It uses the same init/step
@@ -336,6 +354,13 @@ impl MatchEngine {
         if let Some(owner_idx) = self.ball.current_owner {
             let is_home = TeamSide::is_home(owner_idx);
 
+            // The owner's oldest prompt is the one being answered.
+            if let Some(queue) =
+                self.user_slot_of(owner_idx).and_then(|slot| self.user_decision_queues.get_mut(slot))
+            {
+                queue.pop_front();
+            }
+
             match action {
                 UserAction::Shoot => {
                     // Use the same pattern as execute_dribble_action when it
@@ -382,3 +407,89 @@ impl MatchEngine {
         SimState::Finished(result)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_fixtures::create_test_team;
+    use super::super::{MatchEngine, MatchPlan};
+    use crate::engine::{CoOpUserPlayer, HighlightLevel, UserPlayerConfig};
+    use crate::models::{EventType, MatchEvent};
+
+    fn co_op_engine() -> MatchEngine {
+        let plan = MatchPlan {
+            home_team: create_test_team("Home"),
+            away_team: create_test_team("Away"),
+            seed: 12345,
+            user_player: Some(UserPlayerConfig {
+                is_home_team: true,
+                player_name: "Home Player 10".to_string(),
+                player_index: 9,
+                highlight_level: HighlightLevel::MyPlayer,
+                co_op_players: vec![CoOpUserPlayer {
+                    is_home_team: true,
+                    player_name: "Home Player 11".to_string(),
+                    player_index: 10,
+                    highlight_level: HighlightLevel::Skip,
+                }],
+            }),
+            home_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+            away_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+            modifier_stack: Default::default(),
+            home_instructions: None,
+            away_instructions: None,
+            home_player_instructions: None,
+            away_player_instructions: None,
+            home_ai_difficulty: None,
+            away_ai_difficulty: None,
+            pitch: crate::models::pitch::PitchSpec::default(),
+            home_chemistry: None,
+            away_chemistry: None,
+            stadium: None,
+            conditions: None,
+            penalty_shootout: None,
+            home_custom_formation: None,
+            away_custom_formation: None,
+            home_set_piece_takers: None,
+            away_set_piece_takers: None,
+            referee: None,
+        };
+        MatchEngine::new(plan).expect("match engine init")
+    }
+
+    #[test]
+    fn test_co_op_slots_and_independent_highlights() {
+        let mut engine = co_op_engine();
+        assert_eq!(engine.user_slot_count(), 2);
+        assert_eq!(engine.user_slot_of(9), Some(0));
+        assert_eq!(engine.user_slot_of(10), Some(1));
+        assert_eq!(engine.user_slot_of(8), None);
+        assert!(engine.is_user_player(10, true));
+        assert_eq!(engine.get_user_highlight_configs().len(), 2);
+
+        // Slot 0 (MyPlayer) keeps its own pass; slot 1 is on Skip, so its pass is dropped.
+        let pass_by = |track_id: usize| {
+            let mut event = MatchEvent::shot(10, 600_000, true, track_id, false, 0.05);
+            event.event_type = EventType::Pass;
+            event
+        };
+        engine.result.events = vec![pass_by(9), pass_by(10)];
+        engine.filter_events_for_display();
+        assert_eq!(engine.result.events.len(), 1);
+        assert_eq!(engine.result.events[0].player_track_id, Some(9));
+    }
+
+    #[test]
+    fn test_co_op_prompt_is_queued_for_owning_slot() {
+        let mut engine = co_op_engine();
+        let super::SimState::Paused(ctx) = engine.simulate_until_intervention() else {
+            panic!("expected an intervention prompt for a user player");
+        };
+        let slot = ctx.user_slot as usize;
+        assert_eq!(engine.user_slot_of(ctx.player_id as usize), Some(slot));
+        assert_eq!(engine.pending_user_decisions(slot).len(), 1);
+        assert!(engine.pending_user_decisions(1 - slot).is_empty());
+
+        engine.resume_with_action(super::UserAction::Dribble);
+        assert!(engine.pending_user_decisions(slot).len() <= 1);
+    }
+}
@@ -380,6 +380,7 @@ pub struct StateTransition {
 }
 
 /// State machine controller
+#[derive(Clone)]
 pub struct GameFlowMachine {
     current_state: GameFlowState,
     state_enter_tick: u64,
@@ -138,11 +138,11 @@ use std::io::{self, Write};
 
 use super::ball::Ball;
 use super::ball_prediction::BallPrediction; // FIX_2601/0106: Ball prediction system
-use super::formation_waypoints::get_formation_waypoints;
-use super::movement::{get_fallback_position, slot_to_position_key};
+use super::movement::get_fallback_position;
 use super::team_phase::TeamPhaseState;
 use super::types::{DirectionContext, GameState, PlayerReactionState};
 use super::{EventGenerator, StatsCalculator};
+use super::cancellation::{CancellationToken, CANCELLED_CUT_REASON};
 // P7: Phase-Based Action System imports
 use super::player_state::{default_player_states, PlayerState, PlayerStates};
 // P0: Goal Contract imports
@@ -168,8 +168,11 @@ use crate::replay::recorder::ReplayRecorder;
 use crate::replay::types::{PitchSpec, ReplayDoc, ReplayPlayer, ReplayRoster, ReplayRosters};
 use crate::tactics::team_instructions::{BuildUpStyle, TeamInstructions};
 // Phase 2: AI Tactical Manager Integration
-use crate::tactics::{AIDifficulty, AITacticalManager, MatchState};        
-use crate::calibration::{MatchStatSnapshot, CalibratorParams};
+use crate::tactics::{
+    AIDifficulty, AIDifficultyProfile, AITacticalManager, MatchState, OpponentAdaptation,
+};        
+use crate::calibration::{MatchStatSnapshot, CalibratorParams, ParameterSet};
+use crate::engine::ai_manager::AiMatchManager;
 
 // =============================================================================
 // FIX_2601/0106: Added Time (Stoppage Time, deterministic; v1.1)
@@ -194,11 +197,14 @@ pub mod deterministic; // FIX_2601/1123: Deterministic 선택 함수
 pub mod tactical_bias; // FIX_2601/1124: TacticalBias 시스템
 pub mod candidate_key; // FIX_2601/1124: CandidateKey + Gate A 검증
 pub mod attack_phase; // FIX_2601/1129: 팀 단위 공격 국면 (AttackPhase)
+mod advantage;
 mod aerial_duel;
 mod ball_helpers;
 mod ball_physics;
 mod calculations;
 mod cross_through;
+mod goalkeeper_holding;
+mod var;
 
 // Re-export pressure types for external use
 pub use balance_diagnostics::DiagnosticReport;
@@ -313,7 +319,7 @@ mod controlled_player;
 mod user_command;
 
 // Re-export Career Player Mode types
-pub use controlled_player::ControlledPlayerMode;
+pub use controlled_player::{ControlledPlayerMode, DirectInputState, UserInputAction};
 pub use user_command::{
     ControllerSlot, MultiAgentCommand, MultiAgentCommandBatch, OnBallAction, UserCommand,
     UserCommandPayload, UserCommandQueue,
@@ -808,6 +814,9 @@ pub struct MatchPlan {
     /// v1: keep deterministic + clamp ranges at injection boundaries.
     pub home_match_modifiers: super::TeamMatchModifiers,
     pub away_match_modifiers: super::TeamMatchModifiers,
+    /// Tagged modifiers (deck, prep, squad load, ...) folded on top of the
+    /// bundles above; origins are kept for `MatchResult.modifier_report`
+    pub modifier_stack: super::ModifierStack,
     pub home_instructions: Option<TeamInstructions>,
     pub away_instructions: Option<TeamInstructions>,
     pub home_player_instructions: Option<HashMap<String, PlayerInstructions>>,
@@ -815,8 +824,29 @@ pub struct MatchPlan {
     // Phase 2: AI Tactical Integration
     pub home_ai_difficulty: Option<AIDifficulty>,
     pub away_ai_difficulty: Option<AIDifficulty>,
+    /// Pitch geometry for this match (standard 105×68 by default)
+    pub pitch: crate::models::pitch::PitchSpec,
+    /// Squad chemistry (None = neutral pass link-up)
+    pub home_chemistry: Option<crate::models::chemistry::SquadChemistry>,
+    pub away_chemistry: Option<crate::models::chemistry::SquadChemistry>,
+    /// Home stadium (overrides `pitch` and adds surface/width effects)
+    pub stadium: Option<crate::models::stadium::Stadium>,
+    /// Weather + pitch condition (None = clear weather, good pitch)
+    pub conditions: Option<crate::models::weather::MatchConditions>,
+    /// Shootout kicking order/rules (Some = shootout after a drawn match)
+    pub penalty_shootout: Option<crate::models::PenaltyShootoutConfig>,
+    /// Formation editor anchors per slot; the team's `formation` still sets roles
+    pub home_custom_formation: Option<crate::tactics::FormationData>,
+    pub away_custom_formation: Option<crate::tactics::FormationData>,
+    /// Designated penalty / free-kick / corner takers (None = attribute-based)
+    pub home_set_piece_takers: Option<crate::tactics::SetPieceTakers>,
+    pub away_set_piece_takers: Option<crate::tactics::SetPieceTakers>,
+    /// Match referee (None = neutral official, calibrated baseline)
+    pub referee: Option<crate::models::RefereeProfile>,
 }
 
+// Clone: full copy for lockstep rollback snapshots (engine::netcode)
+#[derive(Clone)]
 pub struct MatchEngine {
     rng: ChaCha8Rng,
     /// Original seed for RNG restoration in snapshots
@@ -839,7 +869,7 @@ pub struct MatchEngine {
     pub(crate) home_ctx: DirectionContext,
     /// FIX_2601/0105: Explicit direction context for Away team
     pub(crate) away_ctx: DirectionContext,
-    result: MatchResult,
+    pub(crate) result: MatchResult,
     event_generator: EventGenerator,
     stats_calculator: StatsCalculator,
     /// Phase 0: Minimal balance diagnostics
@@ -861,10 +891,12 @@ pub struct MatchEngine {
     possession_owner_idx: Option<usize>,
     possession_owner_since_tick: u64,
     pub(crate) user_player: Option<super::UserPlayerConfig>,
-    home_instructions: TeamInstructions,
-    away_instructions: TeamInstructions,
+    pub(crate) home_instructions: TeamInstructions,
+    pub(crate) away_instructions: TeamInstructions,
     home_match_modifiers: super::TeamMatchModifiers,
     away_match_modifiers: super::TeamMatchModifiers,
+    /// Origins of the two bundles above (re-resolved when an in-match source changes)
+    modifier_stack: super::ModifierStack,
 
     // P0: Defensive Tuning (per-team tactical configuration)
     home_defensive_tuning: DefensiveTuning,
@@ -873,6 +905,12 @@ pub struct MatchEngine {
     // Phase D: ?�이?�인???�합
     pub home_formation: String,
     pub away_formation: String,
+    /// Custom formation anchors by slot (None = preset waypoints)
+    pub(crate) home_custom_anchors: Option<[(f32, f32); 11]>,
+    pub(crate) away_custom_anchors: Option<[(f32, f32); 11]>,
+    /// Designated set-piece takers by track_id
+    pub(crate) home_set_piece_takers: crate::tactics::SetPieceTakers,
+    pub(crate) away_set_piece_takers: crate::tactics::SetPieceTakers,
     ball: Ball,
     game_state: GameState,
     /// Optional override for player positions (used in tests)
@@ -886,16 +924,24 @@ pub struct MatchEngine {
     precomputed_away_strength: f32,
     precomputed_possession_ratio: f32,
     precomputed_match_duration: u8,
-    /// Phase E: last time (ms) we paused for interactive intervention
-    last_intervention_ms: u64,
+    /// Phase E: last time (ms) we paused for interactive intervention, per user slot
+    last_intervention_ms: Vec<u64>,
+    /// Co-op: unanswered decision prompts, one queue per user slot
+    user_decision_queues: Vec<VecDeque<super::types::UserDecisionContext>>,
     /// Dynamic player positions in Coord10 (0.1m precision), 22 players total
     /// FIX_2601 Phase 3.6: Changed from Vec<(f32, f32)> normalized to Vec<Coord10>
     pub(crate) player_positions: Vec<super::types::Coord10>,
+    /// 이동 거리 집계용 직전 틱 위치 (비어 있으면 다음 틱부터 집계)
+    distance_prev_positions: Vec<super::types::Coord10>,
+    /// 선수별 누적 이동 거리 (m, track_id 인덱스)
+    distance_covered_m: [f32; 22],
     /// ??A13: ?�수?반응 ?�태 (22?
     player_reaction_states: Vec<PlayerReactionState>,
     /// ??P1: 개인 ?�수 ?�술 지??(?�수 ?�름 ??Instructions)
     home_player_instructions: std::collections::HashMap<String, PlayerInstructions>,
     away_player_instructions: std::collections::HashMap<String, PlayerInstructions>,
+    /// 트랙별 엔진 역할 캐시 (PlayerInstructions.role, 교체 시 갱신)
+    pub(crate) player_roles: [Option<crate::player::instructions::PlayerRole>; 22],
     /// P3: 교체 횟수 (홈팀, 원정팀) - 최대 5회
     pub(crate) substitutions_made: (u8, u8),
     /// FIX_2601/0106 P2.1: First-half stoppage time accumulator (seconds).
@@ -991,6 +1037,14 @@ pub struct MatchEngine {
     /// Decision intent logs for the current tick (debug output)
     decision_intents: Vec<DecisionIntent>,
 
+    /// Attach full pass candidate evaluations to decision intents (debug only)
+    pass_candidate_debug: bool,
+
+    /// Capture the defending team's marking assignments each tick (debug only)
+    marking_export: bool,
+    /// Last captured marking snapshot (`marking_export` only)
+    marking_snapshot: Option<crate::engine::marking_manager::MarkingSnapshotExport>,
+
     /// Decision scheduler (DPQ) state.
     ///
     /// FIX_2601/0113 v1.1: routing layer only (no behavior change); cadence is
@@ -1072,6 +1126,30 @@ pub struct MatchEngine {
     /// AI 전술 관리자 (원정팀, CPU 팀만 Some)
     away_ai_manager: Option<AITacticalManager>,
 
+    /// 난이도별 행동 프로필 [home, away] (AI 팀이 아니면 NEUTRAL)
+    pub(crate) ai_difficulty_profiles: [AIDifficultyProfile; 2],
+
+    /// 상대 패턴 적응 [home, away] (AI 팀만 Some)
+    pub(crate) opponent_adaptation: [Option<OpponentAdaptation>; 2],
+
+    /// CPU 감독 (포메이션/전술/교체 대응) [home, away] (AI 팀만 Some)
+    pub(crate) ai_match_managers: [Option<AiMatchManager>; 2],
+
+    /// 경기 날씨/피치 상태 (효과는 match modifiers에 이미 반영됨)
+    match_conditions: Option<crate::models::weather::MatchConditions>,
+
+    /// 주심 성향 (None = 중립 주심, 배율 1.0)
+    referee: Option<crate::models::RefereeProfile>,
+
+    /// 무승부 시 승부차기 강제 (토너먼트 녹아웃; env 플래그와 무관)
+    force_penalty_shootout: bool,
+
+    /// 승부차기 키커 순서/침착성/서든데스 설정 (Some이면 무승부 시 승부차기 진행)
+    penalty_shootout_config: Option<crate::models::PenaltyShootoutConfig>,
+
+    /// 볼을 잃은 팀의 압박 재개 틱 [home, away] (reaction delay)
+    pub(crate) press_resume_tick: [u64; 2],
+
     /// 마지막 AI 업데이트 시간 (분)
     last_ai_update_minute: u32,
 
@@ -1129,6 +1207,12 @@ pub struct MatchEngine {
     restart_type_this_tick: Option<super::RestartType>,
     /// Offside restart needs indirect free kick (consumed when restart is applied)
     pending_indirect_free_kick: bool,
+    /// Foul played on under advantage, awaiting call-back or the next stoppage
+    pending_advantage: Option<advantage::PendingAdvantage>,
+    /// Goalkeeper holding the ball after a catch (Law 12.2 six-second count)
+    goalkeeper_in_hands: Option<goalkeeper_holding::GoalkeeperInHands>,
+    /// Tight offside let through by the on-field tolerance (checked by VAR if a goal follows)
+    var_offside_candidate: Option<var::VarOffsideCandidate>,
 
     // ========== P15: Player Inertia Physics System ==========
     /// 선수별 속도 벡터 (m/s) - 관성 물리 시스템
@@ -1153,6 +1237,23 @@ pub struct MatchEngine {
     /// 보드 레이어 (점유/압박 히트맵)
     /// Decision/Tactics의 보조 정보 제공
     pub field_board: Option<FieldBoard>,
+    /// Recurring-weakness insights (FieldBoard pressure × conceded chances)
+    tactical_problems: super::tactical_problems::TacticalProblemDetector,
+    /// Per-minute territory/pressure samples for the post-match momentum graph
+    momentum_curve: super::momentum_curve::MomentumTracker,
+
+    /// Match pitch geometry (FieldBoard/xG/observation layers map onto it)
+    pub(crate) pitch: crate::models::pitch::PitchSpec,
+    /// Home stadium (exported in the viewer MatchSetup)
+    pub(crate) stadium: Option<crate::models::stadium::Stadium>,
+    /// Home crowd (stadium with an attendance; reported in `MatchResult.crowd`)
+    pub(crate) crowd: Option<crate::models::CrowdPressure>,
+
+    /// Squad chemistry (source for pass link-up modifiers)
+    pub(crate) home_chemistry: Option<crate::models::chemistry::SquadChemistry>,
+    pub(crate) away_chemistry: Option<crate::models::chemistry::SquadChemistry>,
+    /// Passer → receiver link-up multipliers derived from chemistry (None = neutral)
+    pub(crate) pass_link_modifiers: Option<std::sync::Arc<super::PassLinkModifiers>>,
 
     // ========== FIX_2601/0106: Ball Prediction System ==========
     /// 공 예측 시스템 (10ms 간격, 최대 3초 예측)
@@ -1206,6 +1307,9 @@ pub struct MatchEngine {
     /// Calibrator parameters to apply (optional, affects action probabilities)
     pub(crate) calibrator_params: Option<CalibratorParams>,
 
+    /// Designer tunables (None = shipped constants; hash recorded in the result)
+    pub(crate) parameter_set: Option<ParameterSet>,
+
     // ========== FIX_2601/0115: Off-Ball Decision System v1 ==========
     /// Off-ball objectives for all 22 players (TTL-based)
     /// Updated each tick when offball_decisions_enabled is true in ExpConfig.
@@ -1230,10 +1334,12 @@ pub struct MatchEngine {
 }
 
 impl MatchEngine {
-    pub fn new(plan: MatchPlan) -> Result<Self, String> {
+    pub fn new(mut plan: MatchPlan) -> Result<Self, String> {
         let original_seed = plan.seed;
         let rng = ChaCha8Rng::seed_from_u64(original_seed);
 
+        let user_slot_count = plan.user_player.as_ref().map_or(0, |cfg| cfg.slot_count());
+
         // Configure event generator based on user player config
         let mut event_generator = EventGenerator::new();
         if let Some(ref user_config) = plan.user_player {
@@ -1249,7 +1355,87 @@ impl MatchEngine {
         Self::populate_default_player_instructions(&mut away_player_instructions, &plan.away_team);
 
         // P17: MatchSetup 생성 (team move 전에 참조로 생성)
-        let setup = MatchSetup::from_teams(&plan.home_team, &plan.away_team)?;
+        let mut setup = MatchSetup::from_teams(&plan.home_team, &plan.away_team)?;
+
+        // AI difficulty tiers: lower tiers play with dulled technique/mental attributes.
+        for (difficulty, team) in [
+            (plan.home_ai_difficulty, &mut setup.home),
+            (plan.away_ai_difficulty, &mut setup.away),
+        ] {
+            let Some(difficulty) = difficulty else { continue };
+            let scale = difficulty.profile().attribute_scale;
+            for player in team.starters.iter_mut().chain(team.substitutes.iter_mut()) {
+                player.attributes.apply_position_penalty(scale);
+            }
+        }
+
+        // Unified modifier stack: plan bundles, tagged plan entries, then venue/weather
+        use super::ModifierSource;
+        let mut modifier_stack = std::mem::take(&mut plan.modifier_stack);
+        for (is_home, bundle) in
+            [(true, &plan.home_match_modifiers), (false, &plan.away_match_modifiers)]
+        {
+            modifier_stack.push_bundle(is_home, ModifierSource::Plan, "match plan", bundle, 0);
+        }
+        if let Some(stadium) = &plan.stadium {
+            stadium.validate().map_err(|err| format!("Invalid stadium: {}", err))?;
+            plan.pitch = stadium.pitch_spec();
+            let (mut home, mut away) = Default::default();
+            stadium.apply_effects(&mut home, &mut away);
+            modifier_stack.push_bundle(true, ModifierSource::Stadium, &stadium.name, &home, 0);
+            modifier_stack.push_bundle(false, ModifierSource::Stadium, &stadium.name, &away, 0);
+        }
+        let crowd = plan
+            .stadium
+            .as_ref()
+            .and_then(|stadium| crate::models::CrowdPressure::from_stadium(stadium, plan.seed));
+        if let Some(crowd) = &crowd {
+            let (mut home, mut away) = Default::default();
+            crowd.apply_effects(&mut home, &mut away);
+            modifier_stack.push_bundle(true, ModifierSource::Crowd, &crowd.summary, &home, 0);
+            modifier_stack.push_bundle(false, ModifierSource::Crowd, &crowd.summary, &away, 0);
+            // Visitors lose some composure under the noise
+            let composure = crowd.away_composure_mult();
+            for player in setup.away.starters.iter_mut().chain(setup.away.substitutes.iter_mut()) {
+                let attributes = &mut player.attributes;
+                attributes.composure = (attributes.composure as f32 * composure) as u8;
+            }
+        }
+        for (is_home, chemistry) in
+            [(true, plan.home_chemistry.as_ref()), (false, plan.away_chemistry.as_ref())]
+        {
+            let Some(chemistry) = chemistry else {
+                continue;
+            };
+            let team = if is_home { &setup.home } else { &setup.away };
+            let names: Vec<&str> = team.starters.iter().map(|p| p.name.as_str()).collect();
+            let score = chemistry.lineup_score(&names);
+            let bundle = crate::models::chemistry::team_chemistry_modifiers(score);
+            let label = format!("starting XI chemistry {}", score);
+            modifier_stack.push_bundle(is_home, ModifierSource::Chemistry, &label, &bundle, 0);
+        }
+        if let Some(conditions) = &plan.conditions {
+            conditions.validate().map_err(|err| format!("Invalid conditions: {}", err))?;
+            let (mut home, mut away) = Default::default();
+            conditions.apply_effects(&mut home, &mut away);
+            let label = format!(
+                "{:?} ({:.0}%), {:?} pitch",
+                conditions.weather.kind,
+                conditions.weather.intensity * 100.0,
+                conditions.pitch_quality
+            );
+            modifier_stack.push_bundle(true, ModifierSource::Weather, &label, &home, 0);
+            modifier_stack.push_bundle(false, ModifierSource::Weather, &label, &away, 0);
+        }
+        plan.home_match_modifiers = modifier_stack.resolve(true).0;
+        plan.away_match_modifiers = modifier_stack.resolve(false).0;
+        if let Some(shootout) = &plan.penalty_shootout {
+            shootout.validate().map_err(|err| format!("Invalid penalty shootout: {}", err))?;
+        }
+        if let Some(referee) = &plan.referee {
+            referee.validate().map_err(|err| format!("Invalid referee: {}", err))?;
+        }
+        plan.pitch.validate().map_err(|err| format!("Invalid pitch: {}", err))?;
 
         // FIX_2512 Phase 0: Audit Gates - Validate match plan
         let home_formation_ref = plan.home_team.formation.code();
@@ -1259,6 +1445,14 @@ impl MatchEngine {
 
         let home_formation_code = home_formation_ref.to_string();
         let away_formation_code = away_formation_ref.to_string();
+        let home_custom_anchors =
+            Self::custom_anchors(plan.home_custom_formation.as_ref(), "home")?;
+        let away_custom_anchors =
+            Self::custom_anchors(plan.away_custom_formation.as_ref(), "away")?;
+        let home_set_piece_takers = plan.home_set_piece_takers.take().unwrap_or_default();
+        let away_set_piece_takers = plan.away_set_piece_takers.take().unwrap_or_default();
+        home_set_piece_takers.validate(true)?;
+        away_set_piece_takers.validate(false)?;
 
         // Phase 2: AI Tactical Manager 초기화
         let home_ai_manager = plan.home_ai_difficulty.map(|difficulty| {
@@ -1277,7 +1471,13 @@ impl MatchEngine {
         }
         let multi_agent_lock_until_tick = vec![0; 22];
 
-        Ok(Self {
+        let pass_link_modifiers = Self::build_pass_link_modifiers(
+            &setup,
+            plan.home_chemistry.as_ref(),
+            plan.away_chemistry.as_ref(),
+        );
+
+        let mut engine = Self {
             rng,
             original_seed,
             home_team: plan.home_team,
@@ -1305,6 +1505,7 @@ impl MatchEngine {
             away_instructions: plan.away_instructions.unwrap_or_default(),      
             home_match_modifiers: plan.home_match_modifiers,
             away_match_modifiers: plan.away_match_modifiers,
+            modifier_stack,
 
             // P0: Defensive Tuning (initialized to defaults, updated each tick)
             home_defensive_tuning: DefensiveTuning::default(),
@@ -1313,6 +1514,10 @@ impl MatchEngine {
             // Phase D 초기화
             home_formation: home_formation_code,
             away_formation: away_formation_code,
+            home_custom_anchors,
+            away_custom_anchors,
+            home_set_piece_takers,
+            away_set_piece_takers,
             ball: Ball::default(),
             game_state: GameState::default(),
             test_player_positions: None,
@@ -1322,11 +1527,15 @@ impl MatchEngine {
             precomputed_away_strength: 0.0,
             precomputed_possession_ratio: 0.0,
             precomputed_match_duration: MATCH_DURATION_CAP_MINUTES,
-            last_intervention_ms: 0,
+            last_intervention_ms: vec![0; user_slot_count],
+            user_decision_queues: vec![VecDeque::new(); user_slot_count],
             player_positions: Vec::new(), // Will be initialized in simulate()
+            distance_prev_positions: Vec::new(),
+            distance_covered_m: [0.0; 22],
             player_reaction_states: vec![PlayerReactionState::default(); 22],
             home_player_instructions,
             away_player_instructions,
+            player_roles: [None; 22],
             substitutions_made: (0, 0),
             stoppage_seconds_first_half: 0,
             stoppage_finalized_first_half: false,
@@ -1418,6 +1627,23 @@ impl MatchEngine {
             // Phase 2: AI Tactical Manager
             home_ai_manager,
             away_ai_manager,
+            ai_difficulty_profiles: [
+                plan.home_ai_difficulty.map_or(AIDifficultyProfile::NEUTRAL, |d| d.profile()),
+                plan.away_ai_difficulty.map_or(AIDifficultyProfile::NEUTRAL, |d| d.profile()),
+            ],
+            opponent_adaptation: [
+                plan.home_ai_difficulty.map(OpponentAdaptation::new),
+                plan.away_ai_difficulty.map(OpponentAdaptation::new),
+            ],
+            ai_match_managers: [
+                plan.home_ai_difficulty.map(|d| AiMatchManager::new(true, d, original_seed)),
+                plan.away_ai_difficulty.map(|d| AiMatchManager::new(false, d, original_seed)),
+            ],
+            match_conditions: plan.conditions,
+            referee: plan.referee,
+            force_penalty_shootout: false,
+            penalty_shootout_config: plan.penalty_shootout,
+            press_resume_tick: [0; 2],
             last_ai_update_minute: 0,
             previous_score_home: 0,
             previous_score_away: 0,
@@ -1442,6 +1668,9 @@ impl MatchEngine {
             restart_occurred_this_tick: false,
             restart_type_this_tick: None,
             pending_indirect_free_kick: false,
+            pending_advantage: None,
+            goalkeeper_in_hands: None,
+            var_offside_candidate: None,
 
             // P15: Player Inertia Physics System
             player_velocities: [(0.0, 0.0); 22], // 모두 정지 상태로 시작
@@ -1457,7 +1686,15 @@ impl MatchEngine {
             }),
 
             // P18: FieldBoard (A-Plan Board Layer)
-            field_board: Some(FieldBoard::new(FieldBoardSpec::default())),
+            field_board: Some(FieldBoard::with_pitch(FieldBoardSpec::default(), plan.pitch)),
+            tactical_problems: super::tactical_problems::TacticalProblemDetector::new(),
+            momentum_curve: super::momentum_curve::MomentumTracker::new(),
+            pitch: plan.pitch,
+            stadium: plan.stadium,
+            crowd,
+            home_chemistry: plan.home_chemistry,
+            away_chemistry: plan.away_chemistry,
+            pass_link_modifiers,
 
             // FIX_2601/0106: Ball Prediction System
             ball_prediction: BallPrediction::new(),
@@ -1473,6 +1710,9 @@ impl MatchEngine {
 
             // FIX_2601/0107: DecisionIntent 초기화
             decision_intents: Vec::new(),
+            pass_candidate_debug: false,
+            marking_export: false,
+            marking_snapshot: None,
 
             // FIX_2601/0113 v1.1: DPQ scheduler (routing layer only).
             decision_scheduler: crate::engine::decision_scheduler::DecisionScheduler::new(),
@@ -1494,6 +1734,7 @@ impl MatchEngine {
             home_stat_snapshot: MatchStatSnapshot::new(0, original_seed),
             away_stat_snapshot: MatchStatSnapshot::new(1, original_seed),
             calibrator_params: None,
+            parameter_set: None,
 
             // FIX_2601/0115: Off-Ball Decision System v1
             offball_objectives: [super::offball::OffBallObjective::default(); 22],
@@ -1509,7 +1750,83 @@ impl MatchEngine {
             } else {
                 None
             },
-        })
+        };
+        for track_id in 0..22 {
+            engine.refresh_player_role(track_id);
+        }
+        Ok(engine)
+    }
+
+    /// Chemistry pass link-up multiplier between two pitch slots (same team only).
+    fn chemistry_link_mult(
+        setup: &MatchSetup,
+        home_chemistry: Option<&crate::models::chemistry::SquadChemistry>,
+        away_chemistry: Option<&crate::models::chemistry::SquadChemistry>,
+        passer_idx: usize,
+        receiver_idx: usize,
+    ) -> f32 {
+        if TeamSide::is_home(passer_idx) != TeamSide::is_home(receiver_idx) {
+            return 1.0;
+        }
+        let chemistry =
+            if TeamSide::is_home(passer_idx) { home_chemistry } else { away_chemistry };
+        let Some(chemistry) = chemistry else {
+            return 1.0;
+        };
+        chemistry.pass_link_mult(
+            &setup.get_player(passer_idx).name,
+            &setup.get_player(receiver_idx).name,
+        )
+    }
+
+    /// Build the 22×22 pass link table from squad chemistry (None if no chemistry given).
+    fn build_pass_link_modifiers(
+        setup: &MatchSetup,
+        home_chemistry: Option<&crate::models::chemistry::SquadChemistry>,
+        away_chemistry: Option<&crate::models::chemistry::SquadChemistry>,
+    ) -> Option<std::sync::Arc<super::PassLinkModifiers>> {
+        if home_chemistry.is_none() && away_chemistry.is_none() {
+            return None;
+        }
+        let mut links = super::PassLinkModifiers::default();
+        for passer_idx in 0..22 {
+            for receiver_idx in 0..22 {
+                if passer_idx == receiver_idx {
+                    continue;
+                }
+                links.set(
+                    passer_idx,
+                    receiver_idx,
+                    Self::chemistry_link_mult(
+                        setup,
+                        home_chemistry,
+                        away_chemistry,
+                        passer_idx,
+                        receiver_idx,
+                    ),
+                );
+            }
+        }
+        Some(std::sync::Arc::new(links))
+    }
+
+    /// Recompute pass links for a pitch slot whose occupant changed.
+    fn refresh_pass_links_for_track_id(&mut self, track_id: usize) {
+        let Some(links) = self.pass_link_modifiers.as_mut() else {
+            return;
+        };
+        let setup = &self.setup;
+        let home_chemistry = self.home_chemistry.as_ref();
+        let away_chemistry = self.away_chemistry.as_ref();
+        std::sync::Arc::make_mut(links).rebuild_track(track_id, |passer_idx, receiver_idx| {
+            Self::chemistry_link_mult(
+                setup,
+                home_chemistry,
+                away_chemistry,
+                passer_idx,
+                receiver_idx,
+            )
+        });
     }
 
     /// P15: 선수별 이동 물리 파라미터 초기화 (경기 시작 시 호출)
@@ -1562,6 +1879,24 @@ impl MatchEngine {
         self.injured_players.retain(|&idx| idx != track_id);
 
         self.refresh_player_motion_params_for_track_id(track_id);
+        self.refresh_pass_links_for_track_id(track_id);
+        self.refresh_player_role(track_id);
+    }
+
+    /// Re-read the role of the player occupying a pitch slot.
+    fn refresh_player_role(&mut self, track_id: usize) {
+        let instructions_map = if TeamSide::is_home(track_id) {
+            &self.home_player_instructions
+        } else {
+            &self.away_player_instructions
+        };
+        let name = &self.get_match_player(track_id).name;
+        self.player_roles[track_id] = instructions_map.get(name).and_then(|pi| pi.role);
+    }
+
+    /// Engine effects of the role played in a pitch slot (neutral if none)
+    pub(crate) fn role_profile(&self, track_id: usize) -> crate::tactics::roles::RoleProfile {
+        crate::tactics::roles::profile_for(self.player_roles.get(track_id).copied().flatten())
     }
 
     /// Phase 2: 팀과 난이도에 따라 적절한 AI 프로필 선택
@@ -1653,6 +1988,7 @@ impl MatchEngine {
                     ai.update_tactics(&match_state, &self.away_instructions, &mut self.rng)
                 {
                     self.home_instructions = new_tactics.clone();
+                    self.reapply_opponent_adaptation(true);
                     self.log_tactical_change("Home", &new_tactics);
                 }
             }
@@ -1665,6 +2001,7 @@ impl MatchEngine {
                     ai.update_tactics(&match_state, &self.home_instructions, &mut self.rng)
                 {
                     self.away_instructions = new_tactics.clone();
+                    self.reapply_opponent_adaptation(false);
                     self.log_tactical_change("Away", &new_tactics);
                 }
             }
@@ -1676,8 +2013,72 @@ impl MatchEngine {
         self.previous_score_away = match_state.away_score as u32;
     }
 
+    /// 공격 방향 기준 볼 위치 (progress: 0=자기 골라인 → 1=상대 골라인, lateral: 0=공격팀 왼쪽)
+    fn attack_relative_ball(&self, attacker_is_home: bool) -> (f32, f32) {
+        use crate::engine::physics_constants::field;
+        let (x, y) = self.ball.position.to_meters();
+        let (x, y) = ((x / field::LENGTH_M).clamp(0.0, 1.0), (y / field::WIDTH_M).clamp(0.0, 1.0));
+        if self.attacks_right(attacker_is_home) {
+            (x, y)
+        } else {
+            (1.0 - x, 1.0 - y)
+        }
+    }
+
+    /// 상대 패턴 샘플링 + 체크포인트에서 적응 (AI 팀만)
+    fn update_opponent_adaptation(&mut self) {
+        let home_has_ball = self.home_phase_state.has_possession;
+        let minute = self.current_minute() as u32;
+        for side in 0..2 {
+            if self.opponent_adaptation[side].is_none() {
+                continue;
+            }
+            let adapting_is_home = side == 0;
+            let opponent_has_ball = home_has_ball != adapting_is_home;
+            let (progress, lateral) = self.attack_relative_ball(!adapting_is_home);
+            let Some(adaptation) = self.opponent_adaptation[side].as_mut() else { continue };
+            if opponent_has_ball {
+                adaptation.observe_possession(progress, lateral);
+            }
+            let Some(details) = adaptation.checkpoint(minute) else { continue };
+
+            self.reapply_opponent_adaptation(adapting_is_home);
+            self.emit_event(MatchEvent::tactical_adaptation(
+                self.minute,
+                self.current_timestamp_ms(),
+                adapting_is_home,
+                details,
+            ));
+        }
+    }
+
+    /// 상대가 볼을 되찾은 위치 기록 (possession change 시)
+    pub(crate) fn record_opponent_regain(&mut self, home_has_ball: bool) {
+        let adapting = if home_has_ball { 1 } else { 0 };
+        if self.opponent_adaptation[adapting].is_none() {
+            return;
+        }
+        let (progress, _) = self.attack_relative_ball(home_has_ball);
+        if let Some(adaptation) = self.opponent_adaptation[adapting].as_mut() {
+            adaptation.observe_regain(progress);
+        }
+    }
+
+    /// 활성 적응 응답을 현재 지시에 다시 적용 (AI 매니저가 지시를 덮어쓴 뒤)
+    pub(crate) fn reapply_opponent_adaptation(&mut self, is_home: bool) {
+        let side = if is_home { 0 } else { 1 };
+        let Some(response) =
+            self.opponent_adaptation[side].as_ref().and_then(|a| a.active_response())
+        else {
+            return;
+        };
+        let instructions =
+            if is_home { &mut self.home_instructions } else { &mut self.away_instructions };
+        crate::tactics::opponent_adaptation::apply_adaptation_response(response, instructions);
+    }
+
     /// 전술 변경 로그 기록
-    fn log_tactical_change(&self, team: &str, new_tactics: &TeamInstructions) {
+    pub(crate) fn log_tactical_change(&self, team: &str, new_tactics: &TeamInstructions) {
         println!(
             "[{}min] {} team changed tactics: Tempo={:?}, Pressing={:?}, Width={:?}",
             self.current_minute(),
@@ -1779,37 +2180,72 @@ impl MatchEngine {
 
         let event_with_timestamp = event_with_position.with_timestamp(self.current_timestamp_ms);
 
-        // VAR v0: emit an informational review event for high-impact decisions.
-        // (No overturn yet; review adds stoppage time.)
-        let var_payload = if Self::var_enabled()
-            && Self::should_trigger_var_review(&event_with_timestamp.event_type)
-        {
-            Some((
-                event_with_timestamp.minute,
-                event_with_timestamp.is_home_team,
-                event_with_timestamp.player_track_id,
-                event_with_timestamp.event_type.clone(),
-            ))
-        } else {
-            None
-        };
-
         self.maybe_accumulate_stoppage_time(&event_with_timestamp);
+        self.record_tactical_chance(&event_with_timestamp);
+        self.result.statistics.record_discipline_event(&event_with_timestamp);
         self.result.events.push(event_with_timestamp);
+    }
 
-        if let Some((minute, is_home_team, player_track_id, reviewed_event_type)) = var_payload {
-            let timestamp_ms = self.current_timestamp_ms;
-            self.emit_event(MatchEvent::var_review(
-                minute,
-                timestamp_ms,
-                is_home_team,
-                player_track_id,
-                reviewed_event_type,
-                crate::models::VarReviewOutcome::Upheld,
-            ));
+    fn attach_tactical_insights(&mut self) {
+        let insights = self.tactical_problems.insights();
+        if !insights.is_empty() {
+            self.result.tactical_insights = Some(insights.to_vec());
         }
     }
 
+    fn attach_momentum_curve(&mut self) {
+        let series = self.momentum_curve.series(&self.result.events);
+        if !series.is_empty() {
+            self.result.momentum_curve = Some(series);
+        }
+    }
+
+    /// Sample territory and pressure at the ball for the momentum curve (analysis only).
+    fn record_momentum_tick(&mut self) {
+        let owner_is_home = self.ball.current_owner.map(crate::models::TeamSide::is_home);
+        let (x, y) = self.ball.position_meters();
+        let (advance, pressure) = match owner_is_home {
+            Some(home) => {
+                let progress = (x / super::physics_constants::field::LENGTH_M).clamp(0.0, 1.0);
+                let advance = if self.attacks_right(home) { progress } else { 1.0 - progress };
+                let pressure = self.field_board.as_ref().map_or(0.0, |board| {
+                    let cell = board.cell_of((x, y));
+                    if home {
+                        board.pressure_against_home.get(cell)
+                    } else {
+                        board.pressure_against_away.get(cell)
+                    }
+                });
+                (advance, pressure)
+            }
+            None => (0.0, 0.0),
+        };
+        self.momentum_curve.record_tick(self.minute, owner_is_home, advance, pressure);
+    }
+
+    /// Feed a conceded chance into the tactical problem detector (analysis only).
+    fn record_tactical_chance(&mut self, event: &MatchEvent) {
+        use super::tactical_problems::TacticalProblemDetector;
+
+        if !TacticalProblemDetector::is_chance_event(&event.event_type) {
+            return;
+        }
+        let Some((x, y, _)) = event.details.as_ref().and_then(|d| d.ball_position) else {
+            return;
+        };
+        let pos_m = (x / 10.0, y / 10.0);
+        let conceding_attacks_right = self.attacks_right(!event.is_home_team);
+        let pressure = self.field_board.as_ref().map_or(0.0, |board| {
+            let cell = board.cell_of(pos_m);
+            if event.is_home_team {
+                board.pressure_against_home.get(cell)
+            } else {
+                board.pressure_against_away.get(cell)
+            }
+        });
+        self.tactical_problems.record_chance(event, pos_m, conceding_attacks_right, pressure);
+    }
+
     #[inline]
     fn regulation_end_minute(&self) -> u8 {
         self.first_half_end_minute.saturating_add(HALF_DURATION_MINUTES)
@@ -1823,13 +2259,16 @@ impl MatchEngine {
         }
     }
 
-    fn stoppage_seconds_for_event(event_type: &EventType) -> u16 {
-        match event_type {
+    fn stoppage_seconds_for_event(event: &MatchEvent) -> u16 {
+        match &event.event_type {
             EventType::Substitution => STOPPAGE_SECONDS_SUBSTITUTION,
             EventType::Injury => STOPPAGE_SECONDS_INJURY,
             EventType::Goal | EventType::OwnGoal => STOPPAGE_SECONDS_GOAL,
             EventType::YellowCard | EventType::RedCard => STOPPAGE_SECONDS_CARD,
             EventType::VarReview => STOPPAGE_SECONDS_VAR_REVIEW,
+            EventType::VarDecision => {
+                event.var_review_details().and_then(|d| d.duration_s).unwrap_or(STOPPAGE_SECONDS_VAR_REVIEW)
+            }
             _ => 0,
         }
     }
@@ -1844,7 +2283,7 @@ impl MatchEngine {
             return;
         }
 
-        let seconds = Self::stoppage_seconds_for_event(&event.event_type);
+        let seconds = Self::stoppage_seconds_for_event(event);
         if seconds == 0 {
             return;
         }
@@ -1863,7 +2302,7 @@ impl MatchEngine {
             return;
         }
 
-        let seconds = Self::stoppage_seconds_for_event(&event.event_type);
+        let seconds = Self::stoppage_seconds_for_event(event);
 
         if seconds == 0 {
             return;
@@ -1882,6 +2321,7 @@ impl MatchEngine {
 
         self.first_half_end_minute = HALF_DURATION_MINUTES.saturating_add(added_minutes);
         self.stoppage_finalized_first_half = true;
+        self.announce_added_time(1, added_minutes);
 
         // Until 2H added time is finalized, match_end_minute tracks regulation end.
         if !self.stoppage_finalized_second_half {
@@ -1900,22 +2340,31 @@ impl MatchEngine {
 
         self.match_end_minute = regulation_end_minute.saturating_add(added_minutes);
         self.stoppage_finalized_second_half = true;
+        self.announce_added_time(2, added_minutes);
+    }
+
+    /// Fourth-official board: `AddedTime` event at the start of the added minutes.
+    fn announce_added_time(&mut self, half: u8, minutes: u8) {
+        // Boundary event: tick-aligned timestamp (emit_event uses current_timestamp_ms SSOT).
+        self.current_timestamp_ms = self.minute as u64 * 60_000;
+        let timestamp_ms = self.current_timestamp_ms;
+        self.emit_event(MatchEvent::added_time(self.minute, timestamp_ms, half, minutes));
+        if let Some(recorder) = self.replay_recorder.as_mut() {
+            recorder.record_added_time(timestamp_ms as f64 / 1000.0, half, minutes);
+        }
     }
 
-    fn var_enabled() -> bool {
+    /// VAR reviews: `rulebook.var_enabled` or `OF_ALLOW_VAR=1`
+    pub(crate) fn var_enabled(&self) -> bool {
+        if self.exp_params.as_ref().is_some_and(|p| p.var_enabled) {
+            return true;
+        }
         let Ok(value) = std::env::var("OF_ALLOW_VAR") else {
             return false;
         };
         matches!(value.as_str(), "1" | "true" | "TRUE" | "True")
     }
 
-    fn should_trigger_var_review(event_type: &EventType) -> bool {
-        matches!(
-            event_type,
-            EventType::Goal | EventType::OwnGoal | EventType::Penalty | EventType::RedCard
-        )
-    }
-
     fn penalty_shootout_enabled() -> bool {
         let Ok(value) = std::env::var("OF_ALLOW_PENALTY_SHOOTOUT") else {
             return false;
@@ -1931,7 +2380,10 @@ impl MatchEngine {
         if self.result.score_home != self.result.score_away {
             return;
         }
-        if !Self::penalty_shootout_enabled() {
+        if !self.force_penalty_shootout
+            && self.penalty_shootout_config.is_none()
+            && !Self::penalty_shootout_enabled()
+        {
             return;
         }
 
@@ -1942,19 +2394,27 @@ impl MatchEngine {
         use rand::Rng;
         use crate::engine::player_state::PlayerState;
         use crate::models::match_result::PenaltyShootoutKick;
+        use crate::models::penalty_shootout::SHOOTOUT_ROUNDS;
 
-        let collect_kickers = |is_home: bool| -> Vec<usize> {
+        let config = self.penalty_shootout_config.clone().unwrap_or_default();
+        let collect_kickers = |is_home: bool| -> Vec<(usize, f32)> {
             let (start, end) = if is_home { (0, 11) } else { (11, 22) };
-            let mut kickers = Vec::new();
+            let mut eligible = Vec::new();
             for track_id in start..end {
                 match self.player_states.get(track_id) {
                     Some(PlayerState::SentOff) | Some(PlayerState::Injured) => continue,
-                    Some(_) => kickers.push(track_id),
+                    Some(_) => eligible.push((track_id - start) as u8),
                     None => {}
                 }
             }
+            let mut kickers: Vec<(usize, f32)> = config
+                .side(is_home)
+                .kicking_order(&eligible)
+                .into_iter()
+                .map(|(slot, composure)| (start + slot as usize, composure))
+                .collect();
             if kickers.is_empty() {
-                kickers.push(start);
+                kickers.push((start, 0.0));
             }
             kickers
         };
@@ -1986,7 +2446,7 @@ impl MatchEngine {
         let mut kicks: Vec<PenaltyShootoutKick> = Vec::new();
 
         let mut take_kick = |is_home_team: bool,
-                             kicker_track_id: usize,
+                             (kicker_track_id, composure): (usize, f32),
                              keeper_track_id: usize,
                              kick_log: &mut Vec<PenaltyShootoutKick>|
          -> bool {
@@ -1997,6 +2457,7 @@ impl MatchEngine {
             let keeper_overall = self.get_match_player(keeper_track_id).overall as i32;
             let diff = kicker_overall - keeper_overall;
             let p_goal = (0.75 + diff as f32 * 0.002).clamp(0.55, 0.92);
+            let p_goal = (p_goal + composure).clamp(0.05, 0.99);
             let scored = self.rng.gen::<f32>() < p_goal;
             let kick_index = (kick_log.len() + 1) as u8;
             kick_log.push(PenaltyShootoutKick {
@@ -2005,12 +2466,13 @@ impl MatchEngine {
                 kicker_track_id: kicker_track_id as u8,
                 kicker_name,
                 scored,
+                conversion_probability: p_goal,
             });
             scored
         };
 
         // Initial 5 kicks each (early termination allowed).
-        'initial: for _round in 0..5 {
+        'initial: for _round in 0..SHOOTOUT_ROUNDS {
             // Home kick
             kicks_home = kicks_home.saturating_add(1);
             let kicker = home_kickers[next_home % home_kickers.len()];
@@ -2019,7 +2481,7 @@ impl MatchEngine {
                 goals_home = goals_home.saturating_add(1);
             }
 
-            let away_remaining = 5u8.saturating_sub(kicks_away);
+            let away_remaining = SHOOTOUT_ROUNDS.saturating_sub(kicks_away);
             if goals_home > goals_away.saturating_add(away_remaining) {
                 break 'initial;
             }
@@ -2032,14 +2494,14 @@ impl MatchEngine {
                 goals_away = goals_away.saturating_add(1);
             }
 
-            let home_remaining = 5u8.saturating_sub(kicks_home);
+            let home_remaining = SHOOTOUT_ROUNDS.saturating_sub(kicks_home);
             if goals_away > goals_home.saturating_add(home_remaining) {
                 break 'initial;
             }
         }
 
         // Sudden death (cap rounds to avoid infinite loops).
-        if goals_home == goals_away {
+        if goals_home == goals_away && config.sudden_death {
             for _round in 0..10 {
                 kicks_home = kicks_home.saturating_add(1);
                 let kicker = home_kickers[next_home % home_kickers.len()];
@@ -2062,7 +2524,7 @@ impl MatchEngine {
         }
 
         let winner_is_home = if goals_home == goals_away {
-            // Should be extremely rare; break ties deterministically with RNG (seeded).
+            // Rare with sudden death; without it, draw lots deterministically with RNG (seeded).
             self.rng.gen::<bool>()
         } else {
             goals_home > goals_away
@@ -2144,22 +2606,24 @@ impl MatchEngine {
         self.result.ssot_proof = proof;
     }
 
+    /// Validated formation editor anchors for one side
+    fn custom_anchors(
+        custom: Option<&crate::tactics::FormationData>,
+        side: &str,
+    ) -> Result<Option<[(f32, f32); 11]>, String> {
+        let Some(custom) = custom else { return Ok(None) };
+        custom.validate().map_err(|err| format!("Invalid {} custom formation: {}", side, err))?;
+        Ok(Some(custom.anchors()))
+    }
+
     /// Get base formation position for player index (without dynamic adjustments)
     fn get_base_position_for_index(&self, idx: usize) -> (f32, f32) {
         let is_home = TeamSide::is_home(idx);
-        let (slot, formation) = if is_home {
-            (idx, &self.home_formation)
-        } else {
-            (TeamSide::local_idx(idx), &self.away_formation)
-        };
-
-        let waypoints = get_formation_waypoints(formation);
-        let position_key = slot_to_position_key(slot, formation);
+        let slot = if is_home { idx } else { TeamSide::local_idx(idx) };
 
-        let pos = if let Some(wp) = waypoints.get(&position_key) {
-            wp.base
-        } else {
-            get_fallback_position(slot)
+        let pos = match self.slot_waypoints(is_home, slot) {
+            Some(wp) => wp.base,
+            None => get_fallback_position(slot),
         };
 
         // FIX_2601/0110: Away team needs BOTH axes flipped (mirror across center)
@@ -2180,6 +2644,13 @@ impl MatchEngine {
         self
     }
 
+    /// Decide a drawn match with a penalty shootout (knockout ties).
+    /// The regulation score is unchanged; see `MatchResult::penalty_shootout`.
+    pub fn with_penalty_shootout(mut self) -> Self {
+        self.force_penalty_shootout = true;
+        self
+    }
+
     /// Enable replay recording for generating ReplayDoc with all events
     /// This creates a ReplayRecorder that captures events during simulation
     pub fn with_replay_recording(mut self) -> Self {
@@ -2195,7 +2666,11 @@ impl MatchEngine {
             width_m: field::LENGTH_M as f64,
             height_m: field::WIDTH_M as f64,
         };
-        self.replay_recorder = Some(ReplayRecorder::new(pitch, rosters));
+        let mut recorder = ReplayRecorder::new(pitch, rosters);
+        if let Some(conditions) = &self.match_conditions {
+            recorder.record_weather(0.0, conditions);
+        }
+        self.replay_recorder = Some(recorder);
         self
     }
 
@@ -2358,11 +2833,48 @@ impl MatchEngine {
         self.calibrator_params.as_ref()
     }
 
+    /// Apply designer tunables (builder pattern)
+    ///
+    /// The parameter hash is recorded in `MatchResult.parameter_hash`.
+    pub fn with_parameter_set(mut self, params: ParameterSet) -> Self {
+        self.parameter_set = Some(params);
+        self
+    }
+
+    /// Designer tunables in effect (defaults when none applied)
+    pub fn parameter_set(&self) -> ParameterSet {
+        self.parameter_set.unwrap_or_default()
+    }
+
     /// Get current experimental parameters (or baseline if none applied)
     pub fn get_exp_params(&self) -> crate::engine::experimental::RuntimeExpParams {
         self.exp_params.clone().unwrap_or_else(crate::engine::experimental::RuntimeExpParams::baseline)
     }
 
+    /// Referee assigned to this match (None = neutral official)
+    pub fn referee(&self) -> Option<&crate::models::RefereeProfile> {
+        self.referee.as_ref()
+    }
+
+    /// Referee card multiplier (1.0 for a neutral official)
+    pub(crate) fn referee_card_mult(&self) -> f32 {
+        self.referee.as_ref().map_or(1.0, |r| r.card_mult())
+    }
+
+    /// Resolved match modifiers of `track_id`'s side
+    pub(crate) fn match_modifiers_for(&self, track_id: usize) -> &super::TeamMatchModifiers {
+        if TeamSide::is_home(track_id) {
+            &self.home_match_modifiers
+        } else {
+            &self.away_match_modifiers
+        }
+    }
+
+    /// Card multiplier for `track_id`'s fouls: referee tendency × crowd lean on that side
+    pub(crate) fn referee_card_mult_for(&self, track_id: usize) -> f32 {
+        self.referee_card_mult() * self.match_modifiers_for(track_id).card_mult
+    }
+
     /// Get shoot xG threshold (from exp_config or default)
     #[inline]
     pub fn exp_shoot_xg_threshold(&self) -> f32 {
@@ -2429,6 +2941,24 @@ impl MatchEngine {
         self.exp_params.as_ref().map_or(0.0, |p| p.directness_bias)
     }
 
+    /// Get desperation forward shift (from exp_config or default)
+    #[inline]
+    pub fn exp_desperation_push_forward(&self) -> f32 {
+        self.exp_params.as_ref().map_or(0.06, |p| p.desperation_push_forward)
+    }
+
+    /// Get desperation pressing add (from exp_config or default)
+    #[inline]
+    pub fn exp_desperation_press_add(&self) -> f32 {
+        self.exp_params.as_ref().map_or(0.15, |p| p.desperation_press_add)
+    }
+
+    /// Get desperation shot threshold multiplier (from exp_config or default)
+    #[inline]
+    pub fn exp_desperation_shot_threshold_mult(&self) -> f32 {
+        self.exp_params.as_ref().map_or(0.6, |p| p.desperation_shot_threshold_mult)
+    }
+
     // ========== FIX_2601/0112: Calibration Bias Getters ==========
 
     /// Get progressive pass attempt bias from calibrator params
@@ -2523,6 +3053,11 @@ impl MatchEngine {
         &self.goals
     }
 
+    /// 경기장 규격 (PitchSpec) 참조 반환
+    pub fn pitch(&self) -> &crate::models::pitch::PitchSpec {
+        &self.pitch
+    }
+
     // ========== P17: MatchSetup Delegate Methods ==========
 
     /// P17: track_id로 선수 정보 조회 (MatchSetup delegate)
@@ -2577,12 +3112,34 @@ impl MatchEngine {
         instructions_map.get(player_name).cloned().unwrap_or_default()
     }
 
+    /// Opponent a player is instructed to man-mark, as the opponent's local index (0-10)
+    pub(crate) fn man_mark_target(&self, track_id: usize) -> Option<usize> {
+        let instructions_map = if TeamSide::is_home(track_id) {
+            &self.home_player_instructions
+        } else {
+            &self.away_player_instructions
+        };
+        let target = instructions_map.get(&self.get_match_player(track_id).name)?.man_mark?;
+        let target = target as usize;
+        (target < 22 && TeamSide::is_home(target) != TeamSide::is_home(track_id))
+            .then_some(target % 11)
+    }
+
     /// P2: player_idx로�???position 문자??추출
     fn get_position_string_by_idx(&self, player_idx: usize) -> String {
         self.get_position_string(&self.get_match_player(player_idx).position)
     }
 
     pub fn simulate(&mut self) -> MatchResult {
+        self.simulate_with_cancellation(&CancellationToken::new())
+    }
+
+    /// Run the full match, polling `cancel` at every minute boundary.
+    ///
+    /// A cancelled run stops where it is and returns the partial result: no
+    /// full-time event or shootout, `determinism.mode = Truncated` and
+    /// `cut_reason = CANCELLED_CUT_REASON`.
+    pub fn simulate_with_cancellation(&mut self, cancel: &CancellationToken) -> MatchResult {
         println!("[MatchEngine] simulate() CALLED - START");
         let _ = io::stdout().flush();
 
@@ -2628,8 +3185,13 @@ impl MatchEngine {
 
         // Use a stable upper bound; the actual end minute is decided at regulation boundaries.
         let match_duration = MATCH_DURATION_CAP_MINUTES;
+        let mut cancelled = false;
 
         for minute in 0..=match_duration {
+            if cancel.is_cancelled() {
+                cancelled = true;
+                break;
+            }
             self.minute = minute;
 
             // 1H added time is finalized at minute 45.
@@ -2659,7 +3221,9 @@ impl MatchEngine {
             // 레거시 record_positions_for_minute()는 위치를 재계산해서 엔진 결과를 덮어쓰는 문제가 있었음
         }
 
-        if !self.result.events.iter().any(|event| matches!(event.event_type, EventType::FullTime)) {
+        let has_full_time =
+            self.result.events.iter().any(|event| matches!(event.event_type, EventType::FullTime));
+        if !cancelled && !has_full_time {
             let match_end_minute = self.match_end_minute;
             let timestamp_ms = match_end_minute as u64 * 60_000;
             self.current_timestamp_ms = timestamp_ms;
@@ -2669,12 +3233,17 @@ impl MatchEngine {
         self.finalize_pass_sequences();
 
         // Calculate final statistics
+        self.record_distance_covered_stats();
         self.stats_calculator.finalize(&mut self.result, possession_ratio);
 
         // FIX02: Determinism SSOT metadata (full simulation path).
-        self.result.determinism.mode = crate::models::DeterminismMode::Full;
+        self.result.determinism.mode = if cancelled {
+            crate::models::DeterminismMode::Truncated
+        } else {
+            crate::models::DeterminismMode::Full
+        };
         self.result.determinism.simulated_until_tick = self.result.statistics.total_ticks;
-        self.result.determinism.cut_reason = None;
+        self.result.determinism.cut_reason = cancelled.then(|| CANCELLED_CUT_REASON.to_string());
 
         // Aggregate per-match stats for the configured user player (MyPlayer)
         if let Some(user_stats) = self.build_user_player_stats() {
@@ -2686,8 +3255,8 @@ impl MatchEngine {
             self.filter_events_for_display();
         }
 
-        // Sort events by minute
-        self.result.events.sort_by_key(|e| e.minute);
+        // Sort events by match time (ms); minute-only ordering is ambiguous within a minute
+        self.result.events.sort_by_key(|e| e.timeline_ms());
 
         // Convert to detailed replay events if tracking enabled
         println!("?��?��?�� [MatchEngine] DEBUG: track_positions={}, replay_events.is_some={}, events={} ?��?��?��",
@@ -2728,12 +3297,19 @@ impl MatchEngine {
             }
         }
 
+        // Match seed (seeded post-match presentation, e.g. commentary)
+        self.result.determinism.seed = Some(self.original_seed);
+
         // Store teams for roster information
         self.result.home_team = Some(self.home_team.clone());
         self.result.away_team = Some(self.away_team.clone());
 
         // P17 Phase 5: Store match setup for viewer (starting lineup snapshot).
-        self.result.match_setup = Some(self.setup.to_export_starting_lineup());
+        let mut match_setup = self.setup.to_export_starting_lineup();
+        match_setup.stadium = self.stadium.clone();
+        match_setup.conditions = self.match_conditions;
+        match_setup.referee = self.referee.clone();
+        self.result.match_setup = Some(match_setup);
 
         // Add debug info
         self.result.debug_info = Some(format!(
@@ -2743,16 +3319,32 @@ impl MatchEngine {
             self.result.events.len()
         ));
 
-        self.maybe_run_penalty_shootout();
+        if !cancelled {
+            self.maybe_run_penalty_shootout();
+        }
 
+        // Player ratings first: the summary's MVP falls back to the man of the match
+        self.result.generate_player_ratings();
         // Generate match summary for quick display on result screens
         self.result.generate_summary();
+        self.result.generate_match_statistics();
 
         // P18: Board summary (final snapshot of occupancy/pressure)
         if let Some(ref board) = self.field_board {
             self.result.board_summary = Some(board.to_summary_export(5));
         }
 
+        // Recurring weakness insights (clip-linked)
+        self.attach_tactical_insights();
+
+        // Active modifiers and their origins (balance debugging)
+        self.result.modifier_report = Some(self.modifier_stack.report());
+        self.result.crowd = self.crowd.clone();
+        self.result.parameter_hash = self.parameter_set.map(|p| p.param_hash());
+
+        // Per-minute momentum curve (post-match graph)
+        self.attach_momentum_curve();
+
         // Phase 0: Minimal diagnostics summary (single-run)
         self.balance_diagnostics.print_phase0_summary();
         if std::env::var("OF_BALANCE_REPORT").is_ok() {
@@ -3032,6 +3624,9 @@ impl MatchEngine {
 
         // 5. Emit KickOff event
         self.emit_event(crate::models::MatchEvent::kick_off(0, 0, home_kicks_off));
+        if let Some(conditions) = self.match_conditions {
+            self.emit_event(crate::models::MatchEvent::weather(0, 0, conditions));
+        }
 
         println!(
             "[MatchEngine] Initial kickoff state recorded: ball at center, {} owns ball (home_kicks_off={})",
@@ -3672,6 +4267,8 @@ impl MatchEngine {
 
         let mut goals: u32 = 0;
         let mut assists: u32 = 0;
+        let mut second_assists: u32 = 0;
+        let mut key_passes: u32 = 0;
         let mut shots: u32 = 0;
         let mut passes: u32 = 0;
         let mut tackles: u32 = 0;
@@ -3685,6 +4282,19 @@ impl MatchEngine {
             let is_hero_player =
                 event.player_track_id == Some(hero_track_id) && event.is_home_team == hero_is_home;
 
+            // 찬스 메이킹 크레딧 (슈팅/세이브/골 이벤트). 세이브 이벤트의 is_home_team은
+            // GK 팀이므로 track_id(팀 고유)로만 판정한다.
+            if let Some(creation) = event.chance_creation() {
+                if creation.key_pass_track_id == Some(hero_track_id) {
+                    key_passes += 1;
+                }
+                if event.event_type == EventType::Goal
+                    && creation.second_assist_track_id == Some(hero_track_id)
+                {
+                    second_assists += 1;
+                }
+            }
+
             match event.event_type {
                 EventType::Goal => {
                     if is_hero_player {
@@ -3718,7 +4328,7 @@ impl MatchEngine {
                         tackles += 1;
                     }
                 }
-                EventType::Foul => {
+                EventType::Foul | EventType::Handball => {
                     if is_hero_player {
                         fouls += 1;
                     }
@@ -3752,6 +4362,8 @@ impl MatchEngine {
         let mut rating = 6.0f32;
         rating += goals as f32 * 0.75;
         rating += assists as f32 * 0.5;
+        rating += second_assists as f32 * 0.2;
+        rating += (key_passes as f32 * 0.1).min(0.4);
         rating += (shots as f32 * 0.05).min(0.4);
         rating += (tackles as f32 * 0.08).min(0.4);
         rating += (passes as f32 * 0.01).min(0.3);
@@ -3770,6 +4382,8 @@ impl MatchEngine {
             player_name: player_name.clone(),
             goals,
             assists,
+            second_assists,
+            key_passes,
             shots,
             passes,
             tackles,
@@ -3831,6 +4445,7 @@ impl MatchEngine {
 
 
         // Calculate final statistics
+        self.record_distance_covered_stats();
         self.stats_calculator.finalize(&mut self.result, possession_ratio);
 
         // Aggregate per-match stats for the configured user player (MyPlayer)
@@ -3843,8 +4458,8 @@ impl MatchEngine {
             self.filter_events_for_display();
         }
 
-        // Sort events by minute
-        self.result.events.sort_by_key(|e| e.minute);
+        // Sort events by match time (ms); minute-only ordering is ambiguous within a minute
+        self.result.events.sort_by_key(|e| e.timeline_ms());
 
         // Convert to detailed replay events if tracking enabled
         println!("?��?��?�� [MatchEngine] DEBUG: track_positions={}, replay_events.is_some={}, events={} ?��?��?��",
@@ -3885,12 +4500,19 @@ impl MatchEngine {
             }
         }
 
+        // Match seed (seeded post-match presentation, e.g. commentary)
+        self.result.determinism.seed = Some(self.original_seed);
+
         // Store teams for roster information
         self.result.home_team = Some(self.home_team.clone());
         self.result.away_team = Some(self.away_team.clone());
 
         // P17 Phase 5: Store match setup for viewer (starting lineup snapshot).
-        self.result.match_setup = Some(self.setup.to_export_starting_lineup());
+        let mut match_setup = self.setup.to_export_starting_lineup();
+        match_setup.stadium = self.stadium.clone();
+        match_setup.conditions = self.match_conditions;
+        match_setup.referee = self.referee.clone();
+        self.result.match_setup = Some(match_setup);
 
         // Add debug info
         self.result.debug_info = Some(format!(
@@ -3902,14 +4524,28 @@ impl MatchEngine {
 
         self.maybe_run_penalty_shootout();
 
+        // Player ratings first: the summary's MVP falls back to the man of the match
+        self.result.generate_player_ratings();
         // Generate match summary for quick display on result screens
         self.result.generate_summary();
+        self.result.generate_match_statistics();
 
         // P18: Board summary (final snapshot of occupancy/pressure)
         if let Some(ref board) = self.field_board {
             self.result.board_summary = Some(board.to_summary_export(5));
         }
 
+        // Recurring weakness insights (clip-linked)
+        self.attach_tactical_insights();
+
+        // Active modifiers and their origins (balance debugging)
+        self.result.modifier_report = Some(self.modifier_stack.report());
+        self.result.crowd = self.crowd.clone();
+        self.result.parameter_hash = self.parameter_set.map(|p| p.param_hash());
+
+        // Per-minute momentum curve (post-match graph)
+        self.attach_momentum_curve();
+
         self.result.clone()
     }
 
@@ -4063,6 +4699,65 @@ impl MatchEngine {
         }
     }
 
+    /// Drive the controlled player directly (movement / sprint / button action).
+    ///
+    /// Switches Career Player Mode to `track_id` if needed. `move_dir` is in pitch
+    /// meter axes and persists until the next call ((0,0) = stand still); `action`
+    /// fires once within `DirectInputState::ACTION_TTL_TICKS`.
+    pub fn set_user_player_input(
+        &mut self,
+        track_id: usize,
+        move_dir: (f32, f32),
+        sprint: bool,
+        action: Option<UserInputAction>,
+    ) -> Result<(), &'static str> {
+        if track_id >= self.sticky_actions.len() {
+            return Err("invalid_track_id");
+        }
+        if !move_dir.0.is_finite() || !move_dir.1.is_finite() {
+            return Err("invalid_move_dir");
+        }
+        if !self.is_controlled(track_id) {
+            self.enable_controlled_mode(track_id);
+        }
+
+        let len = (move_dir.0 * move_dir.0 + move_dir.1 * move_dir.1).sqrt();
+        let move_dir = if len > 1.0 { (move_dir.0 / len, move_dir.1 / len) } else { move_dir };
+        let current_tick = self.current_tick;
+        if let Some(mode) = self.controlled_mode.as_mut() {
+            let input = mode.direct_input.get_or_insert_with(DirectInputState::default);
+            input.move_dir = move_dir;
+            if let Some(action) = action {
+                input.pending_action =
+                    Some((action, current_tick + DirectInputState::ACTION_TTL_TICKS));
+            }
+        }
+        self.sticky_actions[track_id].set(StickyAction::Sprint, sprint);
+        Ok(())
+    }
+
+    /// Direct input of `track_id` (None = not directly controlled).
+    pub(crate) fn direct_input_for(&self, track_id: usize) -> Option<&DirectInputState> {
+        self.controlled_mode
+            .as_ref()
+            .filter(|mode| mode.is_controlled(track_id))
+            .and_then(|mode| mode.direct_input.as_ref())
+    }
+
+    /// Take the pending button action of `track_id` if it is `wanted` and still live.
+    pub(crate) fn take_direct_input_action(
+        &mut self,
+        track_id: usize,
+        wanted: &[UserInputAction],
+    ) -> Option<UserInputAction> {
+        let current_tick = self.current_tick;
+        let mode = self.controlled_mode.as_mut().filter(|mode| mode.is_controlled(track_id))?;
+        let input = mode.direct_input.as_mut()?;
+        let action = input.pending(current_tick).filter(|a| wanted.contains(a))?;
+        input.pending_action = None;
+        Some(action)
+    }
+
     // ========== State Snapshot API ==========
 
     /// Capture complete match state as a snapshot
@@ -4241,9 +4936,11 @@ impl MatchEngine {
             current_tick: snapshot.current_tick,
             last_shot_xg: None,     // Reset
             last_shooter_idx: None, // Reset
+            last_shot_features: None,
             last_passer_idx: None,  // Reset
             last_pass_receiver_idx: None, // Reset
             last_pass_type: None,
+            last_reception: None,
             last_header_outcome: None,
             in_flight_origin: None, // Reset
         };
@@ -4514,6 +5211,29 @@ mod rulebook_stoppage_time_tests {
         // 90 seconds => ceil(90/60) = 2 minutes
         assert_eq!(engine.match_end_minute, 92);
         assert!(engine.stoppage_finalized_second_half);
+
+        let board = engine.result.events.last().unwrap();
+        assert_eq!(board.event_type, EventType::AddedTime);
+        assert_eq!(board.minute, 90);
+        assert_eq!(board.added_time_details().map(|d| (d.half, d.minutes)), Some((2, 2)));
+    }
+
+    #[test]
+    fn full_match_announces_added_time_and_plays_it() {
+        let mut engine = test_fixtures::create_test_engine();
+        let result = engine.simulate();
+
+        let boards: Vec<_> =
+            result.events.iter().filter(|e| e.event_type == EventType::AddedTime).collect();
+        assert_eq!(boards.len(), 2, "one announcement per half");
+        assert_eq!(boards[0].minute, HALF_DURATION_MINUTES);
+
+        let clock = crate::models::MatchClock::from_events(&result.events);
+        assert_eq!(boards[1].minute, clock.first_half_end_minute() + HALF_DURATION_MINUTES);
+        let half_time = result.events.iter().find(|e| e.event_type == EventType::HalfTime).unwrap();
+        assert_eq!(half_time.minute, clock.first_half_end_minute());
+        assert!(result.events.iter().all(|e| e.event_type == EventType::FullTime
+            || e.minute <= clock.match_end_minute()));
     }
 
     #[test]
@@ -4541,3 +5261,131 @@ mod rulebook_stoppage_time_tests {
 
 
 
+
+#[cfg(test)]
+mod penalty_shootout_tests {
+    use super::*;
+    use crate::models::{PenaltyKicker, PenaltyShootoutConfig, PenaltyShootoutSide};
+
+    #[test]
+    fn shootout_follows_configured_order_and_rules() {
+        let mut engine = test_fixtures::create_test_engine();
+        engine.penalty_shootout_config = Some(PenaltyShootoutConfig {
+            home: PenaltyShootoutSide {
+                kickers: vec![
+                    PenaltyKicker { slot: 9, composure: 0.25 },
+                    PenaltyKicker { slot: 0, composure: -0.25 },
+                ],
+            },
+            away: PenaltyShootoutSide { kickers: vec![PenaltyKicker { slot: 10, composure: 0.0 }] },
+            sudden_death: false,
+        });
+
+        // A configured shootout runs on a draw without the env flag.
+        engine.maybe_run_penalty_shootout();
+        let shootout = engine.result.penalty_shootout.clone().expect("shootout after draw");
+
+        let home: Vec<_> = shootout.kicks.iter().filter(|k| k.is_home_team).collect();
+        let away: Vec<_> = shootout.kicks.iter().filter(|k| !k.is_home_team).collect();
+        assert_eq!(home[0].kicker_track_id, 9);
+        assert_eq!(home[1].kicker_track_id, 0);
+        assert_eq!(away[0].kicker_track_id, 21);
+        assert!(home[0].conversion_probability > home[1].conversion_probability + 0.4);
+
+        // No sudden death: at most five kicks each.
+        assert!(shootout.kicks_taken_home <= 5 && shootout.kicks_taken_away <= 5);
+        let kicks_taken = shootout.kicks_taken_home + shootout.kicks_taken_away;
+        assert_eq!(shootout.kicks.len(), kicks_taken as usize);
+    }
+}
+
+#[cfg(test)]
+mod set_piece_routine_tests {
+    use super::*;
+    use crate::engine::phase_action::CornerTactic;
+    use crate::models::SetPieceRoutineKind;
+    use crate::tactics::CornerRoutine;
+
+    #[test]
+    fn corner_and_free_kick_honor_team_routines() {
+        let mut engine = test_fixtures::create_test_engine();
+        engine.home_instructions.set_pieces.corner = CornerRoutine::FarPost;
+        // Away track_id and a sent-off player are skipped
+        engine.home_instructions.set_pieces.corner_takers = vec![14, 4, 6];
+        engine.home_instructions.set_pieces.free_kick_takers = vec![8];
+        engine.away_instructions.set_pieces.wall_jump = true;
+        engine.send_off_player(4);
+
+        engine.start_corner_kick_fsm(true);
+        let corner = engine.result.events.last().unwrap();
+        assert_eq!(corner.player_track_id, Some(6));
+        let routine = corner.details.as_ref().unwrap().set_piece_routine.as_ref().unwrap();
+        assert_eq!(routine.routine, SetPieceRoutineKind::FarPost);
+        assert!(routine.designated_taker);
+        let ctx = engine.active_set_pieces[0].corner_context.as_ref().unwrap();
+        assert_eq!(ctx.tactic, CornerTactic::FarPost);
+
+        engine.start_free_kick_fsm((0.5, 0.8), true, false);
+        let free_kick = engine.result.events.last().unwrap();
+        assert_eq!(free_kick.player_track_id, Some(8));
+        let routine = free_kick.details.as_ref().unwrap().set_piece_routine.as_ref().unwrap();
+        assert_eq!(routine.wall_jump, Some(true));
+        assert!(engine.active_set_pieces[1].freekick_context.as_ref().unwrap().wall_jump);
+    }
+    #[test]
+    fn designated_takers_override_routines_and_attributes() {
+        use crate::tactics::SetPieceTakers;
+
+        let mut engine = test_fixtures::create_test_engine();
+        engine.home_instructions.set_pieces.corner_takers = vec![7];
+        engine.home_instructions.set_pieces.free_kick_takers = vec![7];
+        let takers = SetPieceTakers {
+            penalties: vec![3],
+            direct_free_kicks: vec![9],
+            corners_left: vec![2],
+            corners_right: vec![5],
+        };
+        engine.apply_set_piece_takers_change(TeamSide::Home, takers).unwrap();
+
+        engine.start_penalty_kick_fsm(true);
+        assert_eq!(engine.result.events.last().unwrap().player_track_id, Some(3));
+
+        engine.start_corner_kick_fsm(true);
+        let corner_taker = engine.result.events.last().unwrap().player_track_id;
+        assert!(matches!(corner_taker, Some(2) | Some(5)), "{:?}", corner_taker);
+
+        engine.start_free_kick_fsm((0.5, 0.8), true, false);
+        assert_eq!(engine.result.events.last().unwrap().player_track_id, Some(9));
+        // Indirect free kicks fall back to the routine list
+        engine.start_free_kick_fsm((0.5, 0.8), true, true);
+        assert_eq!(engine.result.events.last().unwrap().player_track_id, Some(7));
+
+        let wrong_side = SetPieceTakers { penalties: vec![14], ..Default::default() };
+        assert!(engine.apply_set_piece_takers_change(TeamSide::Home, wrong_side).is_err());
+        assert_eq!(engine.home_set_piece_takers.penalties, vec![3]);
+    }
+}
+
+#[cfg(test)]
+mod referee_tests {
+    use super::*;
+    use crate::models::{EventType, RefereeProfile};
+
+    fn fouls_with(strictness: f32) -> (usize, MatchResult) {
+        let mut engine = test_fixtures::create_test_engine();
+        engine.referee = Some(RefereeProfile { strictness, ..Default::default() });
+        let result = engine.simulate();
+        let fouls = result.events.iter().filter(|e| e.event_type == EventType::Foul).count();
+        (fouls, result)
+    }
+
+    #[test]
+    fn strict_referee_whistles_more_and_is_exported() {
+        let (lenient, _) = fouls_with(0.0);
+        let (strict, result) = fouls_with(1.0);
+        assert!(strict > lenient, "strict {} vs lenient {}", strict, lenient);
+
+        let referee = result.match_setup.and_then(|setup| setup.referee).expect("referee exported");
+        assert_eq!(referee.strictness, 1.0);
+    }
+}
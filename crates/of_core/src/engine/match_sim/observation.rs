@@ -1,7 +1,7 @@
 //! Observation Wrappers for ML/AI Pipelines
 //!
 //! Google Football-style observation formats:
-//! - `SimpleVectorObservation`: 115-float vector (positions, directions, game mode)
+//! - `SimpleVectorObservation`: 116-float vector (positions, directions, game mode)
 //! - `MiniMapObservation`: 72×96 spatial planes (self, opponent, ball, active)
 //!
 //! All observations use TeamView coordinates where:
@@ -125,7 +125,7 @@ pub struct TeamViewPlayerObservation {
 /// Contains all game state in a structured format that can be flattened
 /// to a fixed-size vector for neural network input.
 ///
-/// ## Flat Vector Layout (115 floats)
+/// ## Flat Vector Layout (116 floats)
 /// ```text
 /// [0-1]     Ball position (x, y) normalized to [0, 1]
 /// [2-3]     Ball direction (dx, dy) normalized
@@ -135,9 +135,9 @@ pub struct TeamViewPlayerObservation {
 /// [70-91]   Opponent team directions (11 × 2)
 /// [92-102]  Active player one-hot (11 elements)
 /// [103-105] Sticky actions (sprint, dribble, press)
-/// [106-112] Game mode one-hot (7 elements)
-/// [113]     Score difference (normalized -1 to 1)
-/// [114]     Time remaining (normalized 0 to 1)
+/// [106-113] Game mode one-hot (8 elements)
+/// [114]     Score difference (normalized -1 to 1)
+/// [115]     Time remaining (normalized 0 to 1)
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SimpleVectorObservation {
@@ -163,11 +163,23 @@ pub struct SimpleVectorObservation {
 
 impl SimpleVectorObservation {
     /// Total size of flat vector output
-    pub const FLAT_SIZE: usize = 115;
+    pub const FLAT_SIZE: usize = 116;
+
+    /// Stamina reported for players whose condition is hidden from the observer
+    pub const REDACTED_STAMINA: f32 = -1.0;
+
+    /// Hide opponent-only information (stamina) for spectator/observer views.
+    ///
+    /// The flat vector layout is unaffected (it carries no stamina).
+    pub fn redact_opponent_hidden_info(&mut self) {
+        for player in self.players.iter_mut().filter(|p| p.team_id != 0) {
+            player.stamina = Self::REDACTED_STAMINA;
+        }
+    }
 
     /// Convert to flat f32 vector for ML pipelines
     ///
-    /// ## Layout (115 floats)
+    /// ## Layout (116 floats)
     /// - `[0-1]`: Ball position (normalized 0-1)
     /// - `[2-3]`: Ball direction (unit vector)
     /// - `[4-25]`: Self team positions (11 × 2, normalized)
@@ -176,9 +188,9 @@ impl SimpleVectorObservation {
     /// - `[70-91]`: Opponent directions (11 × 2, unit vectors)
     /// - `[92-102]`: Active player one-hot (11 elements)
     /// - `[103-105]`: Sticky actions (sprint, dribble, press)
-    /// - `[106-112]`: Game mode one-hot (7 elements)
-    /// - `[113]`: Score difference (normalized -1 to 1)
-    /// - `[114]`: Time remaining (normalized 0 to 1)
+    /// - `[106-113]`: Game mode one-hot (8 elements)
+    /// - `[114]`: Score difference (normalized -1 to 1)
+    /// - `[115]`: Time remaining (normalized 0 to 1)
     pub fn to_flat_vector(&self) -> Vec<f32> {
         let mut v = Vec::with_capacity(Self::FLAT_SIZE);
 
@@ -250,14 +262,14 @@ impl SimpleVectorObservation {
         v.push(if self.sticky_actions.1 { 1.0 } else { 0.0 }); // dribble
         v.push(if self.sticky_actions.2 { 1.0 } else { 0.0 }); // press
 
-        // [106-112] Game mode one-hot
+        // [106-113] Game mode one-hot
         v.extend_from_slice(&self.game_mode.to_one_hot());
 
-        // [113] Score difference (normalized to -1..1, capped at ±5)
+        // [114] Score difference (normalized to -1..1, capped at ±5)
         let score_diff = self.score.0 as i32 - self.score.1 as i32;
         v.push((score_diff as f32 / 5.0).clamp(-1.0, 1.0));
 
-        // [114] Time remaining (normalized, 90 minutes = 1.0)
+        // [115] Time remaining (normalized, 90 minutes = 1.0)
         v.push(1.0 - (self.minute as f32 / 90.0).clamp(0.0, 1.0));
 
         debug_assert_eq!(v.len(), Self::FLAT_SIZE);
@@ -661,11 +673,11 @@ mod tests {
         // Opp directions: 11 * 2 = 22
         // Active one-hot: 11
         // Sticky: 3
-        // Game mode: 7
+        // Game mode: 8 (incl. DropBall)
         // Score diff: 1
         // Time remaining: 1
-        // Total: 4 + 22 + 22 + 22 + 22 + 11 + 3 + 7 + 1 + 1 = 115
-        assert_eq!(SimpleVectorObservation::FLAT_SIZE, 115);
+        // Total: 4 + 22 + 22 + 22 + 22 + 11 + 3 + 8 + 1 + 1 = 116
+        assert_eq!(SimpleVectorObservation::FLAT_SIZE, 116);
     }
 
     #[test]
@@ -14,6 +14,7 @@
 //!
 //! Extracted from match_sim/mod.rs for better organization.
 
+use crate::engine::strict_math::StrictMath;
 use super::MatchEngine;
 use crate::engine::action_queue::{ActionResult, RestartType};
 use crate::engine::coordinates;
@@ -384,7 +385,7 @@ impl MatchEngine {
         let diff = trap_score - break_score; // 범위: 약 -18 ~ +22
 
         // 시그모이드: diff=0 → 50%, diff=+10 → 73%, diff=-10 → 27%
-        let trap_prob = 1.0 / (1.0 + (-diff * offside_trap::SIGMOID_SCALE).exp());
+        let trap_prob = 1.0 / (1.0 + (-diff * offside_trap::SIGMOID_SCALE).det_exp());
 
         // ===== Phase 5: 상한/하한 적용 후 확률적 결과 =====
         // FIX_2601/0113: cohesion_score를 트랩 성공률에 반영
@@ -597,6 +598,54 @@ impl MatchEngine {
         is_offside
     }
 
+    /// Tight offside let through by the ~1m tolerance of `is_offside_pass`
+    ///
+    /// Same pass-moment checks, but returns the receiver's distance beyond the
+    /// second-last defender (meters) when it is within the tolerance. VAR uses
+    /// it to review goals scored from that attacking phase.
+    pub(crate) fn tight_offside_margin_m(
+        &self,
+        passer_idx: usize,
+        receiver_idx: usize,
+        attacking_is_home: bool,
+    ) -> Option<f32> {
+        use crate::engine::physics_constants::{field, offside};
+
+        let passer_pos = self.get_player_position_by_index(passer_idx);
+        let receiver_pos = self.get_player_position_by_index(receiver_idx);
+        let ball_pos = coordinates::to_normalized(self.ball.position.to_meters());
+        let attacks_right = self.attacks_right(attacking_is_home);
+
+        let is_advancing = coordinates::is_advancing(
+            passer_pos.to_normalized_legacy(),
+            receiver_pos.to_normalized_legacy(),
+            attacks_right,
+        );
+        let in_opponent_half =
+            coordinates::is_in_opponent_half(receiver_pos.to_normalized_legacy(), attacks_right);
+        if !is_advancing
+            || !in_opponent_half
+            || passer_pos.distance_to_m(&receiver_pos) < offside::MIN_PASS_DISTANCE_M
+        {
+            return None;
+        }
+
+        let receiver_length = coordinates::norm_length(receiver_pos.to_normalized_legacy());
+        let ball_length = coordinates::norm_length(ball_pos);
+        let offside_line = self.second_last_defender_line(!attacking_is_home, attacks_right);
+        let receiver_tv = if attacks_right { receiver_length } else { 1.0 - receiver_length };
+        let ball_tv = if attacks_right { ball_length } else { 1.0 - ball_length };
+        let line_tv = if attacks_right { offside_line } else { 1.0 - offside_line };
+
+        // Same ~1m tolerance as is_offside_pass: beyond it the offside is called on the field
+        let small_buffer = 0.01;
+        let margin_tv = receiver_tv - line_tv;
+        if margin_tv <= 0.0 || margin_tv > small_buffer || receiver_tv <= ball_tv {
+            return None;
+        }
+        Some(margin_tv * field::LENGTH_M)
+    }
+
     /// Calculate the second-last defender's position (offside line)
     ///
     /// The offside line is typically the second-last defender (GK is usually last).
@@ -608,36 +657,43 @@ impl MatchEngine {
         defending_is_home: bool,
         attacks_right: bool,
     ) -> f32 {
+        let Some((_, line_tv)) = self.second_last_defender(defending_is_home, attacks_right) else {
+            // Fallback: use halfway line
+            return 0.5;
+        };
+
+        if attacks_right { line_tv } else { 1.0 - line_tv }
+    }
+
+    /// The defender who sets the offside line, with the line in TeamView length
+    pub(crate) fn second_last_defender(
+        &self,
+        defending_is_home: bool,
+        attacks_right: bool,
+    ) -> Option<(usize, f32)> {
         let defender_range = if defending_is_home { 0..11 } else { 11..22 };
 
         // Collect all defender positions in length direction (excluding GK at index 0 or 11)
         let gk_idx = if defending_is_home { 0 } else { 11 };
-        let mut lengths: Vec<f32> = defender_range
+        let mut lengths: Vec<(usize, f32)> = defender_range
             .filter(|&idx| idx != gk_idx) // Exclude GK
             .map(|idx| {
                 let len = coordinates::norm_length(
                     self.get_player_position_by_index(idx).to_normalized_legacy(),
                 );
-                if attacks_right { len } else { 1.0 - len }
+                (idx, if attacks_right { len } else { 1.0 - len })
             })
             .collect();
 
-        if lengths.is_empty() {
-            // Fallback: use halfway line
-            return 0.5;
-        }
-
         // Sort by length position
-        lengths.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        lengths.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
 
         // TeamView: always advancing to +length, so the offside line is the second-highest defender length.
-        let line_tv = if lengths.len() >= 2 {
-            lengths[lengths.len() - 2]
-        } else {
-            lengths[0]
-        };
-
-        if attacks_right { line_tv } else { 1.0 - line_tv }
+        match lengths.len() {
+            0 => None,
+            1 => Some(lengths[0]),
+            n => Some(lengths[n - 2]),
+        }
     }
 
     /// Assign the ball to the nearest defender of the opposing team
@@ -749,6 +805,7 @@ impl MatchEngine {
 
         // 5. Get second-last defender line
         let offside_line_norm = self.second_last_defender_line(!attacking_is_home, attacks_right);
+        let line_defender = self.second_last_defender(!attacking_is_home, attacks_right);
 
         // 6. Calculate margin (receiver position relative to offside line)
         let receiver_length_tv = if attacks_right {
@@ -813,6 +870,11 @@ impl MatchEngine {
             restart_context: Some(restart_context),
             touch_reference: Some(touch_reference),
             deflection_context: Some(deflection_context),
+            attacker_pos_m: Some(receiver_pos.to_meters()),
+            defender_track_id: line_defender.map(|(idx, _)| idx as u8),
+            defender_pos_m: line_defender
+                .map(|(idx, _)| self.get_player_position_by_index(idx).to_meters()),
+            passer_pos_m: Some(passer_pos.to_meters()),
         })
     }
 
@@ -913,6 +975,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_offside_details_capture_pass_moment_snapshot() {
+        use super::super::test_fixtures::create_test_engine;
+        use crate::engine::types::Coord10;
+
+        let mut engine = create_test_engine();
+        engine.is_second_half = false; // Home attacks RIGHT (x=105)
+
+        let passer_idx = 6;
+        let receiver_idx = 9;
+
+        // Away back line: deepest outfielder at 86m, second-last (idx 13) at 84m.
+        for idx in 12..22 {
+            engine.player_positions[idx] = Coord10::from_meters(70.0, field::CENTER_Y);
+        }
+        engine.player_positions[12] = Coord10::from_meters(86.0, 20.0);
+        engine.player_positions[13] = Coord10::from_meters(84.0, 40.0);
+
+        let passer_pos = Coord10::from_meters(75.0, field::CENTER_Y);
+        engine.player_positions[passer_idx] = passer_pos;
+        engine.ball.position = passer_pos;
+        engine.player_positions[receiver_idx] = Coord10::from_meters(88.0, 30.0);
+
+        let details = engine
+            .check_offside_detailed(passer_idx, receiver_idx, true)
+            .expect("receiver is clearly offside");
+
+        assert!((details.offside_line_m - 84.0).abs() < 0.1);
+        assert_eq!(details.defender_track_id, Some(13));
+        assert_eq!(details.defender_pos_m, Some((84.0, 40.0)));
+        assert_eq!(details.attacker_pos_m, Some((88.0, 30.0)));
+        assert_eq!(details.passer_pos_m, Some((75.0, field::CENTER_Y)));
+    }
+
     #[test]
     fn test_offside_in_match_simulation() {
         use crate::engine::MatchPlan;
@@ -931,6 +1027,8 @@ mod tests {
                 equipped_skills: Vec::new(),
                 traits: Default::default(),
                 personality: PersonalityArchetype::Steady,
+                morale: 50,
+                form: 50,
             }
         }
 
@@ -974,12 +1072,24 @@ mod tests {
             user_player: None,
             home_match_modifiers: crate::engine::TeamMatchModifiers::default(),
             away_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+            modifier_stack: Default::default(),
             home_instructions: None,
             away_instructions: None,
             home_player_instructions: None,
             away_player_instructions: None,
             home_ai_difficulty: None,
             away_ai_difficulty: None,
+            pitch: crate::models::pitch::PitchSpec::default(),
+            home_chemistry: None,
+            away_chemistry: None,
+            stadium: None,
+            conditions: None,
+            penalty_shootout: None,
+            home_custom_formation: None,
+            away_custom_formation: None,
+            home_set_piece_takers: None,
+            away_set_piece_takers: None,
+            referee: None,
         };
 
         let mut engine = super::super::MatchEngine::new(plan).expect("match engine init");
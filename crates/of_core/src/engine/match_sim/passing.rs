@@ -8,6 +8,7 @@
 //!
 //! Extracted from match_sim/mod.rs for better organization.
 
+use crate::engine::strict_math::StrictMath;
 use super::attribute_calc::{calculate_max_pass_range, pass_distance_factor_v2};
 use super::buildup_phase::BuildupPhase;
 use super::pitch_zone::zone_of_position;
@@ -708,8 +709,8 @@ impl MatchEngine {
             let angle: f32 = rng.gen::<f32>() * std::f32::consts::PI * 2.0;
             let dist: f32 = 2.0 + rng.gen::<f32>() * 3.0;
             let loose_pos = (
-                receiver_pos.0 + angle.cos() * dist,
-                receiver_pos.1 + angle.sin() * dist,
+                receiver_pos.0 + angle.det_cos() * dist,
+                receiver_pos.1 + angle.det_sin() * dist,
             );
             PassFailureOutcome::Miscontrolled {
                 receiver_idx,
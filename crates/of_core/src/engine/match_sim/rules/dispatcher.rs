@@ -56,6 +56,7 @@ impl Default for RuleConfig {
 /// This struct coordinates all rule evaluation in a deterministic,
 /// team-neutral manner. It replaces the scattered rule checks
 /// throughout the codebase with a single evaluation point.
+#[derive(Clone)]
 pub struct RuleDispatcher {
     /// Field boundaries
     field_bounds: FieldBounds,
@@ -63,6 +63,11 @@ impl MatchEngine {
         // Without this flag, the same goal could be detected twice with different scorers
         self.goal_scored_this_tick = true;
 
+        // VAR goal check (same as open-play goals)
+        if self.var_disallows_goal(is_home) {
+            return;
+        }
+
         // ========== ATOMIC FIX: Score update FIRST ==========
         // Update score before event emission to prevent inconsistency
         // if budget exhaustion occurs between these operations.
@@ -100,8 +105,8 @@ impl MatchEngine {
 
     /// Handle other events (cards, injuries, substitutions)
     pub(crate) fn simulate_other_events(&mut self) {
-        // Yellow cards (1% chance per minute)
-        if self.rng.gen::<f32>() < 0.01 {
+        // Yellow cards (1% chance per minute, scaled by the referee's card tendency)
+        if self.rng.gen::<f32>() < 0.01 * self.referee_card_mult() {
             let is_home = self.rng.gen::<bool>();
             let player_idx = if is_home {
                 self.select_random_player_home()
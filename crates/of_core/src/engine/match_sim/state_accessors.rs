@@ -8,12 +8,17 @@
 //! - `get_ball_state()`, `get_player_state_string()`
 //! - `get_recent_events()`, `get_user_highlight_config()`
 //! - `get_possession_stats()`, `get_shot_stats()`, `get_shots_on_target_stats()`
-//! - `get_result()`
+//! - `get_result()`, `memory_usage()`
 //! - `enable_position_tracking()`, `update_positions_for_tick()`
-//! - `apply_tactic_change()`, `substitute_player()`
+//! - `apply_tactic_change()`, `apply_team_talk()`, `substitute_player()`
 
-use crate::models::MatchResult;
+use crate::engine::marking_manager::MarkingSnapshotExport;
+use crate::engine::memory_report::{deque_bytes, hash_map_bytes, vec_bytes, EngineMemoryUsage};
+use crate::engine::tactical_problems::TacticalInsight;
+use crate::models::{MatchPositionData, MatchResult};
 use crate::models::replay::types::DecisionIntent;
+use crate::replay::recorder::ReplayRecorder;
+use std::mem::size_of;
 use std::path::Path;
 
 use super::MatchEngine;
@@ -31,6 +36,13 @@ impl MatchEngine {
         }
     }
 
+    /// Move the recorded position history out, leaving an empty buffer that keeps recording.
+    ///
+    /// Returns None when position tracking is off.
+    pub fn take_position_data(&mut self) -> Option<MatchPositionData> {
+        self.result.position_data.as_mut().map(std::mem::take)
+    }
+
     /// Get current minute
     pub fn get_minute(&self) -> u8 {
         self.minute
@@ -52,6 +64,34 @@ impl MatchEngine {
         self.match_situation.update_score(score_home, score_away);
     }
 
+    /// Jump the match clock to `minute` with a preset scoreline (scripted scenarios).
+    ///
+    /// Call after `init()` and before the first tick. Starting at or after 45'
+    /// puts the engine in the second half (directions swapped, players mirrored)
+    /// without emitting a HalfTime event or a kickoff restart.
+    pub fn seek_match_clock(&mut self, minute: u8, score_home: u8, score_away: u8) {
+        use crate::engine::types::coord10::Coord10;
+
+        if minute >= super::HALF_DURATION_MINUTES && !self.is_second_half {
+            self.is_second_half = true;
+            self.stoppage_finalized_first_half = true;
+            self.first_half_end_minute = super::HALF_DURATION_MINUTES;
+            self.home_ctx.swap_for_second_half();
+            self.away_ctx.swap_for_second_half();
+            for pos in self.player_positions.iter_mut() {
+                pos.x = Coord10::FIELD_LENGTH_10 - pos.x;
+            }
+        }
+
+        self.minute = minute;
+        self.current_tick = minute as u64 * crate::engine::live_match::TICKS_PER_MINUTE;
+        self.current_timestamp_ms = minute as u64 * 60_000;
+        self.result.score_home = score_home;
+        self.result.score_away = score_away;
+        self.match_situation.update_minute(minute as u32);
+        self.match_situation.update_score(score_home, score_away);
+    }
+
     /// Get current timestamp in milliseconds
     pub fn get_current_timestamp_ms(&self) -> u64 {
         self.current_timestamp_ms
@@ -67,6 +107,11 @@ impl MatchEngine {
         self.result.events.get(start..).unwrap_or(&[]).to_vec()
     }
 
+    /// Tactical insights emitted so far (recurring conceded-chance zones).
+    pub fn get_tactical_insights(&self) -> &[TacticalInsight] {
+        self.tactical_problems.insights()
+    }
+
     /// Apply half-time transition (side swap + kickoff reset).
     ///
     /// This is primarily used by the session/streaming API which steps the match per tick.
@@ -87,6 +132,32 @@ impl MatchEngine {
         &self.decision_intents
     }
 
+    /// Attach per-factor pass candidate evaluations to decision intents.
+    ///
+    /// Debug-only: adds a full teammate scan per on-ball decision.
+    pub fn set_pass_candidate_debug(&mut self, enabled: bool) {
+        self.pass_candidate_debug = enabled;
+    }
+
+    pub fn pass_candidate_debug_enabled(&self) -> bool {
+        self.pass_candidate_debug
+    }
+
+    /// Capture the defending team's marking assignments every tick.
+    ///
+    /// Debug-only (tactical overlay / coaching UI); off by default.
+    pub fn set_marking_export(&mut self, enabled: bool) {
+        self.marking_export = enabled;
+        if !enabled {
+            self.marking_snapshot = None;
+        }
+    }
+
+    /// Marking assignments resolved in the latest tick (`set_marking_export` only).
+    pub fn get_marking_snapshot(&self) -> Option<&MarkingSnapshotExport> {
+        self.marking_snapshot.as_ref()
+    }
+
     /// Get current offside lines in meters (home, away).
     pub fn get_offside_lines_m(&self) -> (f32, f32) {
         (
@@ -136,6 +207,13 @@ impl MatchEngine {
         self.user_player.as_ref().map(|cfg| (cfg.highlight_level, cfg.player_index as u8))
     }
 
+    /// Co-op: highlight configuration (level + track_id) for every user slot, in slot order.
+    pub fn get_user_highlight_configs(&self) -> Vec<(super::super::HighlightLevel, u8)> {
+        self.user_player.as_ref().map_or_else(Vec::new, |cfg| {
+            cfg.highlight_configs().into_iter().map(|(level, idx)| (level, idx as u8)).collect()
+        })
+    }
+
     /// Get possession statistics (home%, away%)
     pub fn get_possession_stats(&self) -> (u8, u8) {
         // Simple approximation from possession_ratio
@@ -196,11 +274,55 @@ impl MatchEngine {
         (home_sot, away_sot)
     }
 
+    /// Get accumulated expected goals (home, away)
+    pub fn get_xg_stats(&self) -> (f32, f32) {
+        (self.result.statistics.xg_home, self.result.statistics.xg_away)
+    }
+
     /// Get the final match result
     pub fn get_result(&self) -> crate::models::MatchResult {
         self.result.clone()
     }
 
+    /// Measure the buffers this engine holds, per subsystem.
+    pub fn memory_usage(&self) -> EngineMemoryUsage {
+        let replay_buffer_bytes = self.replay_recorder.as_ref().map_or(0, |r| {
+            size_of::<ReplayRecorder>() + vec_bytes(&r.events) + vec_bytes(&r.timeline)
+        }) + self.result.replay_events.as_ref().map_or(0, vec_bytes);
+
+        let position_data_bytes = self.result.position_data.as_ref().map_or(0, |p| {
+            vec_bytes(&p.ball) + p.players.iter().map(vec_bytes).sum::<usize>()
+        });
+
+        let event_log_bytes = vec_bytes(&self.result.events)
+            + self.result.viewer_events.as_ref().map_or(0, vec_bytes);
+
+        let working_state_bytes = size_of::<MatchEngine>()
+            + deque_bytes(&self.recent_pass_pairs)
+            + self.test_player_positions.as_ref().map_or(0, vec_bytes)
+            + vec_bytes(&self.distance_prev_positions)
+            + vec_bytes(&self.player_reaction_states)
+            + hash_map_bytes(&self.home_player_instructions)
+            + hash_map_bytes(&self.away_player_instructions)
+            + vec_bytes(&self.player_objectives)
+            + vec_bytes(&self.decision_intents)
+            + self.defensive_roles.iter().map(vec_bytes).sum::<usize>()
+            + self.presser_movements.iter().map(vec_bytes).sum::<usize>()
+            + self.marker_movements.iter().map(vec_bytes).sum::<usize>()
+            + self.cover_movements.iter().map(vec_bytes).sum::<usize>()
+            + self.base_formations.iter().map(vec_bytes).sum::<usize>()
+            + vec_bytes(&self.active_set_pieces)
+            + vec_bytes(&self.home_formation_offsets)
+            + vec_bytes(&self.away_formation_offsets);
+
+        EngineMemoryUsage {
+            replay_buffer_bytes,
+            position_data_bytes,
+            event_log_bytes,
+            working_state_bytes,
+        }
+    }
+
     /// Phase0 diagnostics report (structured).
     pub fn get_diagnostics_report(&self) -> super::balance_diagnostics::DiagnosticReport {
         self.balance_diagnostics.generate_report()
@@ -249,6 +371,92 @@ impl MatchEngine {
         };
     }
 
+    /// Replace a team's designated set-piece takers (live tactic change)
+    pub fn apply_set_piece_takers_change(
+        &mut self,
+        team: crate::engine::tactical_context::TeamSide,
+        takers: crate::tactics::SetPieceTakers,
+    ) -> Result<(), String> {
+        use crate::engine::tactical_context::TeamSide;
+
+        takers.validate(team == TeamSide::Home)?;
+        match team {
+            TeamSide::Home => self.home_set_piece_takers = takers,
+            TeamSide::Away => self.away_set_piece_takers = takers,
+        };
+        Ok(())
+    }
+
+    /// Replace the individual instructions of the player in pitch slot `track_id`
+    /// (live update; takes effect from the next tick)
+    pub fn apply_player_instructions_change(
+        &mut self,
+        track_id: usize,
+        instructions: crate::player::instructions::PlayerInstructions,
+    ) -> Result<(), String> {
+        if track_id >= 22 {
+            return Err(format!("Invalid track_id: {}", track_id));
+        }
+        instructions.validate_for_track(track_id)?;
+
+        let name = self.get_match_player(track_id).name.clone();
+        let instructions_map = if crate::models::TeamSide::is_home(track_id) {
+            &mut self.home_player_instructions
+        } else {
+            &mut self.away_player_instructions
+        };
+        instructions_map.insert(name, instructions);
+        self.refresh_player_role(track_id);
+        Ok(())
+    }
+
+    /// Apply a half-time team talk: evaluate the squad reaction, fold the bounded
+    /// effects into the team's match modifiers and record a `TeamTalk` event.
+    pub fn apply_team_talk(
+        &mut self,
+        team: crate::engine::tactical_context::TeamSide,
+        tone: crate::models::TeamTalkTone,
+    ) -> crate::models::TeamTalkDetails {
+        use crate::engine::player_state::PlayerState;
+        use crate::engine::tactical_context::TeamSide;
+        use crate::engine::team_talk::{apply_team_talk_modifiers, evaluate_team_talk};
+
+        let is_home = team == TeamSide::Home;
+        let start_idx = if is_home { 0 } else { 11 };
+        // Only players still on the pitch hear the talk.
+        let personalities: Vec<_> = (start_idx..start_idx + 11)
+            .filter(|&idx| !matches!(self.get_player_fsm_state(idx), Some(PlayerState::SentOff)))
+            .map(|idx| self.get_match_player(idx).personality)
+            .collect();
+        let (home, away) = self.get_score();
+        let goal_diff = if is_home { home as i16 - away as i16 } else { away as i16 - home as i16 };
+
+        let talk = evaluate_team_talk(tone, &personalities, goal_diff.clamp(-127, 127) as i8);
+        let mut talk_modifiers = crate::engine::TeamMatchModifiers::default();
+        apply_team_talk_modifiers(&mut talk_modifiers, &talk);
+        self.modifier_stack.push_bundle(
+            is_home,
+            crate::engine::ModifierSource::TeamTalk,
+            &format!("team talk: {:?}", tone),
+            &talk_modifiers,
+            self.minute,
+        );
+        let (resolved, _) = self.modifier_stack.resolve(is_home);
+        if is_home {
+            self.home_match_modifiers = resolved;
+        } else {
+            self.away_match_modifiers = resolved;
+        }
+
+        self.emit_event(crate::models::MatchEvent::team_talk(
+            self.minute,
+            self.current_timestamp_ms(),
+            is_home,
+            talk.clone(),
+        ));
+        talk
+    }
+
     /// Change formation during the match (Phase 5)
     ///
     /// Supported formations: 4-4-2, 4-3-3, 4-5-1, 3-4-3, 4-2-3-1, 3-5-2
@@ -275,6 +483,7 @@ impl MatchEngine {
                     self.minute, self.home_formation, formation
                 );
                 self.home_formation = formation.to_string();
+                self.home_custom_anchors = None;
             }
             TeamSide::Away => {
                 println!(
@@ -282,6 +491,7 @@ impl MatchEngine {
                     self.minute, self.away_formation, formation
                 );
                 self.away_formation = formation.to_string();
+                self.away_custom_anchors = None;
             }
         }
 
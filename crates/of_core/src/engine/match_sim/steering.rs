@@ -8,6 +8,7 @@
 //! - Movement patterns (DirectRun, DiagonalRun, ChannelRun, DriftWide, CheckToFeet, OppositeMovement)
 //! - Skill-based speed adjustments
 
+use crate::engine::strict_math::StrictMath;
 use crate::engine::types::Coord10;
 
 /// Steering behavior types for player movement
@@ -219,8 +220,8 @@ fn wander(current: Coord10, center: Coord10, radius: i32, max_speed: i32) -> Coo
         let move_dist = (max_speed / 4) as f32;
 
         Coord10 {
-            x: (current.x + (angle.cos() * move_dist) as i32).clamp(0, Coord10::FIELD_LENGTH_10),
-            y: (current.y + (angle.sin() * move_dist) as i32).clamp(0, Coord10::FIELD_WIDTH_10),
+            x: (current.x + (angle.det_cos() * move_dist) as i32).clamp(0, Coord10::FIELD_LENGTH_10),
+            y: (current.y + (angle.det_sin() * move_dist) as i32).clamp(0, Coord10::FIELD_WIDTH_10),
             z: 0,
         }
     }
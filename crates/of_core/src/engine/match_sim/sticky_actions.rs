@@ -1,5 +1,7 @@
 //! Sticky action toggles (sprint/dribble/press).
 
+use serde::{Deserialize, Serialize};
+
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub struct StickyActions {
     pub sprint: bool,
@@ -17,7 +19,8 @@ impl StickyActions {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum StickyAction {
     Sprint,
     Dribble,
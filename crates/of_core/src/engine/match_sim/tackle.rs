@@ -354,7 +354,13 @@ impl MatchEngine {
         let aggression_factor = aggression * 0.03; // Up to +3% from aggression
         let fatigue_factor = fatigue * 0.04; // Up to +4% from fatigue
 
-        let injury_chance = base_chance + aggression_factor + fatigue_factor;
+        let congestion_mult = if TeamSide::is_home(victim_idx) {
+            self.home_match_modifiers.injury_risk_mult
+        } else {
+            self.away_match_modifiers.injury_risk_mult
+        };
+
+        let injury_chance = (base_chance + aggression_factor + fatigue_factor) * congestion_mult;
 
         if self.rng.gen::<f32>() < injury_chance {
             // Injury occurred!
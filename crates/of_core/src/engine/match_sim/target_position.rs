@@ -9,11 +9,12 @@
 //!
 //! Extracted from match_sim/mod.rs for better organization.
 
+use crate::engine::strict_math::StrictMath;
 use super::channel_finder::get_opponent_defenders;
 use super::MatchEngine;
 // Note: offside_trap_state is accessed via self.offside_trap_state (MatchEngine field)
-use crate::engine::formation_waypoints::get_formation_waypoints;
 use crate::engine::debug_flags::match_debug_enabled;
+use crate::engine::formation_waypoints::{anchor_waypoints, get_formation_waypoints};
 use crate::engine::movement::{
     // FIX_2601/0105: Use EXPLICIT direction functions - NO Y-flip!
     calculate_defensive_target_explicit,
@@ -26,6 +27,7 @@ use crate::engine::movement::{
     PositionRole,
 };
 use crate::engine::physics_constants::google_football; // FIX_2601/0112: Microfocus constants
+use crate::engine::positioning::PositionWaypoints;
 use crate::engine::types::{Coord10, DirectionContext};
 
 /// FIX_2601/0112: Microfocus sin_curve - Google Football 스타일 거리 기반 attraction
@@ -42,7 +44,7 @@ fn microfocus_sin_curve(distance: f32, peak: f32, width: f32) -> f32 {
     }
     let normalized = distance / width;
     // sin curve: peaks at distance=0, falls to 0 at distance=width
-    ((1.0 - normalized) * std::f32::consts::FRAC_PI_2).sin().powi(2) * peak
+    ((1.0 - normalized) * std::f32::consts::FRAC_PI_2).det_sin().powi(2) * peak
 }
 
 impl MatchEngine {
@@ -50,6 +52,22 @@ impl MatchEngine {
     // Target Position System (5-Layer)
     // ===========================================
 
+    /// Waypoints a slot steers from: the formation preset, moved onto the
+    /// custom formation anchor when the team uses one
+    pub(crate) fn slot_waypoints(&self, is_home: bool, slot: usize) -> Option<PositionWaypoints> {
+        let (formation, anchors) = if is_home {
+            (&self.home_formation, &self.home_custom_anchors)
+        } else {
+            (&self.away_formation, &self.away_custom_anchors)
+        };
+        let waypoints = get_formation_waypoints(formation);
+        let preset = waypoints.get(&slot_to_position_key(slot, formation));
+        match anchors.as_ref().and_then(|a| a.get(slot)) {
+            Some(&anchor) => Some(anchor_waypoints(preset, anchor)),
+            None => preset.cloned(),
+        }
+    }
+
     /// Calculate target position for a player based on game state
     ///
     /// FIX_2601/0105: Changed from `is_home: bool` to `ctx: &DirectionContext`
@@ -69,7 +87,6 @@ impl MatchEngine {
             (player_idx - 11, &self.away_formation)
         };
 
-        let waypoints = get_formation_waypoints(formation);
         let position_key = slot_to_position_key(slot, formation);
 
         // FIX_2601/0116: Debug - check if GK is processed
@@ -83,9 +100,10 @@ impl MatchEngine {
             );
         }
 
-        let Some(wp) = waypoints.get(&position_key) else {
+        let Some(wp) = self.slot_waypoints(is_home, slot) else {
             return get_fallback_position(slot);
         };
+        let wp = &wp;
 
         // Determine if this player's team is attacking
         let team_attacking = (is_home && home_has_possession) || (!is_home && !home_has_possession);
@@ -155,7 +173,10 @@ impl MatchEngine {
         if self.minute >= 75 {
             if effective_score < 0 {
                 // Losing - push forward more (toward opponent goal)
-                let urgency = ((self.minute - 75) as f32 / 15.0) * 0.08;
+                let mut urgency = ((self.minute - 75) as f32 / 15.0) * 0.08;
+                if self.is_desperation_mode(is_home) {
+                    urgency += self.exp_desperation_push_forward();
+                }
                 target.1 = (target.1 + urgency * forward).clamp(0.05, 0.95);
             } else if effective_score > 0 && self.minute >= 85 {
                 // Winning late - drop back (toward own goal)
@@ -206,6 +227,15 @@ impl MatchEngine {
             }
         }
 
+        // Role shifts (tactics::roles): tuck in / push up per phase
+        let role_shift = self.role_profile(player_idx).positioning;
+        if team_attacking {
+            target.0 += (0.5 - target.0) * role_shift.inside_attack;
+            target.1 = (target.1 + role_shift.depth_attack * forward).clamp(0.08, 0.92);
+        } else {
+            target.1 = (target.1 + role_shift.depth_defense * forward).clamp(0.08, 0.92);
+        }
+
         // ===== LAYER 2: Microfocus Ball Attraction =====
         // FIX_2601/0112: Google Football 스타일 Microfocus 시스템
         // sin_curve 기반 - 공에 가까울수록 강한 attraction, 멀면 0
@@ -457,3 +487,91 @@ impl MatchEngine {
         Coord10::from_normalized_legacy(target)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::engine::match_sim::test_fixtures::create_test_team;
+    use crate::engine::match_sim::{MatchEngine, MatchPlan};
+    use crate::tactics::{FormationData, MatchTacticType};
+
+    fn plan(custom: Option<FormationData>) -> MatchPlan {
+        MatchPlan {
+            home_team: create_test_team("Home"),
+            away_team: create_test_team("Away"),
+            seed: 7,
+            user_player: None,
+            home_match_modifiers: Default::default(),
+            away_match_modifiers: Default::default(),
+            modifier_stack: Default::default(),
+            home_instructions: None,
+            away_instructions: None,
+            home_player_instructions: None,
+            away_player_instructions: None,
+            home_ai_difficulty: None,
+            away_ai_difficulty: None,
+            pitch: Default::default(),
+            home_chemistry: None,
+            away_chemistry: None,
+            stadium: None,
+            conditions: None,
+            penalty_shootout: None,
+            home_custom_formation: custom,
+            away_custom_formation: None,
+            home_set_piece_takers: None,
+            away_set_piece_takers: None,
+            referee: None,
+        }
+    }
+
+    #[test]
+    fn test_custom_formation_anchors_drive_base_positions() {
+        let mut positions = FormationData::for_tactic(MatchTacticType::T442).positions;
+        positions[9].x = 0.5;
+        positions[9].y = 0.9;
+        let custom = FormationData::custom("Target man", MatchTacticType::T442, positions).unwrap();
+
+        let engine = MatchEngine::new(plan(Some(custom.clone()))).unwrap();
+        let preset = MatchEngine::new(plan(None)).unwrap();
+        assert_eq!(engine.get_base_position_for_index(9), (0.5, 0.9));
+        assert_eq!(
+            engine.get_base_position_for_index(0),
+            (custom.positions[0].x, custom.positions[0].y)
+        );
+        // Away side keeps its preset
+        assert_eq!(engine.get_base_position_for_index(20), preset.get_base_position_for_index(20));
+
+        let mut invalid = custom;
+        invalid.positions[4].slot = 3;
+        let err = MatchEngine::new(plan(Some(invalid))).err().expect("duplicate slot rejected");
+        assert!(err.contains("custom formation"), "{err}");
+    }
+
+    #[test]
+    fn test_player_role_shifts_position_and_attributes() {
+        use crate::player::instructions::PlayerRole;
+        use std::collections::HashMap;
+
+        let name = MatchEngine::new(plan(None)).unwrap().get_match_player(9).name.clone();
+        let with_role = |role: Option<PlayerRole>| {
+            let mut instructions = PlayerRole::Poacher.default_instructions();
+            instructions.role = role;
+            let mut plan = plan(None);
+            plan.home_player_instructions = Some(HashMap::from([(name.clone(), instructions)]));
+            let mut engine = MatchEngine::new(plan).unwrap();
+            engine.init();
+            engine
+        };
+        let poacher = with_role(Some(PlayerRole::Poacher));
+        let plain = with_role(None);
+
+        let ratio = poacher.get_player_finishing(9) / plain.get_player_finishing(9);
+        assert!((ratio - 1.08).abs() < 1e-3, "{ratio}");
+
+        // Out of possession the poacher stays higher up the pitch
+        let ctx = poacher.home_ctx;
+        let forward = ctx.forward_sign() as f32;
+        let shifted = poacher.calculate_target_position(9, &ctx, false, 0).1;
+        let base = plain.calculate_target_position(9, &ctx, false, 0).1;
+        assert!((shifted - base) * forward > 0.02, "{shifted} vs {base}");
+    }
+}
@@ -9,6 +9,7 @@
 //! use crate::engine::match_sim::test_fixtures::*;
 //! ```
 
+use crate::data::name_pools::NameGenerator;
 use crate::models::player::PlayerAttributes;
 use crate::models::team::Formation;
 use crate::models::{Player, Position, Team};
@@ -44,6 +45,8 @@ pub fn create_test_team_with_overall(name: &str, overall: u8) -> Team {
             equipped_skills: Vec::new(),
             traits: Default::default(),
             personality: PersonalityArchetype::default(),
+            morale: 50,
+            form: 50,
         });
     }
 
@@ -72,6 +75,8 @@ pub fn create_test_team_with_mental(name: &str, flair: u8, aggression: u8, decis
             equipped_skills: Vec::new(),
             traits: Default::default(),
             personality: PersonalityArchetype::default(),
+            morale: 50,
+            form: 50,
         });
     }
 
@@ -96,6 +101,8 @@ pub fn create_test_team_with_subs(name: &str) -> Team {
             equipped_skills: Vec::new(),
             traits: Default::default(),
             personality: PersonalityArchetype::default(),
+            morale: 50,
+            form: 50,
         });
     }
 
@@ -119,12 +126,27 @@ pub fn create_test_team_with_subs(name: &str) -> Team {
             equipped_skills: Vec::new(),
             traits: Default::default(),
             personality: PersonalityArchetype::default(),
+            morale: 50,
+            form: 50,
         });
     }
 
     Team { name: name.to_string(), formation: Formation::F442, players }
 }
 
+/// Same as `create_test_team_with_subs`, with seeded realistic player names
+/// (distinct, nationality-weighted; same seed → same names).
+pub fn create_named_test_team(name: &str, seed: u64) -> Team {
+    let mut team = create_test_team_with_subs(name);
+    let names = NameGenerator::builtin()
+        .generate_unique(seed, team.players.len(), None)
+        .expect("builtin name pools cover a full squad");
+    for (player, generated) in team.players.iter_mut().zip(names) {
+        player.name = generated.full_name;
+    }
+    team
+}
+
 // =============================================================================
 // Engine Creation Helpers
 // =============================================================================
@@ -152,12 +174,24 @@ pub fn create_test_engine_uninit() -> super::MatchEngine {
         user_player: None,
         home_match_modifiers: crate::engine::TeamMatchModifiers::default(),
         away_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+        modifier_stack: Default::default(),
         home_instructions: None,
         away_instructions: None,
         home_player_instructions: None,
         away_player_instructions: None,
         home_ai_difficulty: None,
         away_ai_difficulty: None,
+        pitch: crate::models::pitch::PitchSpec::default(),
+        home_chemistry: None,
+        away_chemistry: None,
+        stadium: None,
+        conditions: None,
+        penalty_shootout: None,
+        home_custom_formation: None,
+        away_custom_formation: None,
+        home_set_piece_takers: None,
+        away_set_piece_takers: None,
+        referee: None,
     };
 
     super::MatchEngine::new(plan).expect("match engine init")
@@ -179,12 +213,24 @@ pub fn create_test_engine_with_mental(
         user_player: None,
         home_match_modifiers: crate::engine::TeamMatchModifiers::default(),
         away_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+        modifier_stack: Default::default(),
         home_instructions: None,
         away_instructions: None,
         home_player_instructions: None,
         away_player_instructions: None,
         home_ai_difficulty: None,
         away_ai_difficulty: None,
+        pitch: crate::models::pitch::PitchSpec::default(),
+        home_chemistry: None,
+        away_chemistry: None,
+        stadium: None,
+        conditions: None,
+        penalty_shootout: None,
+        home_custom_formation: None,
+        away_custom_formation: None,
+        home_set_piece_takers: None,
+        away_set_piece_takers: None,
+        referee: None,
     };
 
     let mut engine = super::MatchEngine::new(plan).expect("match engine init");
@@ -213,12 +259,24 @@ pub fn create_test_engine_with_player_attrs(
         user_player: None,
         home_match_modifiers: crate::engine::TeamMatchModifiers::default(),
         away_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+        modifier_stack: Default::default(),
         home_instructions: None,
         away_instructions: None,
         home_player_instructions: None,
         away_player_instructions: None,
         home_ai_difficulty: None,
         away_ai_difficulty: None,
+        pitch: crate::models::pitch::PitchSpec::default(),
+        home_chemistry: None,
+        away_chemistry: None,
+        stadium: None,
+        conditions: None,
+        penalty_shootout: None,
+        home_custom_formation: None,
+        away_custom_formation: None,
+        home_set_piece_takers: None,
+        away_set_piece_takers: None,
+        referee: None,
     };
 
     let mut engine = super::MatchEngine::new(plan).expect("match engine init");
@@ -292,6 +350,14 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_create_named_test_team_is_seeded() {
+        let team = create_named_test_team("Test", 7);
+        assert_eq!(team.players.len(), 18);
+        assert_eq!(team.players, create_named_test_team("Test", 7).players);
+        assert!(!team.players[0].name.starts_with("Test"));
+    }
+
     #[test]
     fn test_create_test_engine_initializes() {
         let engine = create_test_engine();
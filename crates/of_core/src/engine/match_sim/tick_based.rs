@@ -112,7 +112,7 @@
 
 use rand::Rng;
 
-use super::MatchEngine;
+use super::{MatchEngine, UserInputAction};
 use super::match_state::{
     GameFlowState, MatchPlayerId, MatchPosition, TeamId, TransitionTrigger,
 };
@@ -137,6 +137,7 @@ use crate::engine::action_queue::{
     ExecutionContext,
     PlayerStats,
     RestartType,
+    SaveType,
     ScheduledAction,
     TackleEvent,
     // P0: Core types moved to action_queue
@@ -161,7 +162,7 @@ use crate::engine::player_objective::PlayerObjective;
 use crate::engine::player_objective::{assign_objective, ObjectiveContext};
 use crate::engine::player_state::PlayerState;
 use crate::engine::types::{Coord10, DirectionContext, TeamViewCoord10, Vel10}; // FIX_2512 Phase 4 - TASK_09
-use crate::models::MatchEvent;
+use crate::models::{ChanceCreationDetails, HandballDetails, HandballRestartType, MatchEvent};
 use crate::models::TeamSide;
 use crate::replay::types::{MeterPos, PossessionChangeType};
 // FIX_2601/0112: Statistical Anchor Calibration
@@ -212,9 +213,17 @@ impl MatchEngine {
             away_ctx: self.away_ctx,
             home_match_modifiers: self.home_match_modifiers,
             away_match_modifiers: self.away_match_modifiers,
+            pass_link_modifiers: self.pass_link_modifiers.clone(),
             rulebook_non_gk_handball_enabled: exp.non_gk_handball_enabled,
             rulebook_non_gk_handball_prob_mult: exp.non_gk_handball_prob_mult,
             rulebook_advantage_play_enabled: exp.advantage_play_enabled,
+            referee_foul_call_mult: self.referee.as_ref().map_or(1.0, |r| r.foul_call_mult()),
+            referee_advantage_rate: self.referee.as_ref().map_or(1.0, |r| r.advantage_rate()),
+            referee_advantage_pressure_tolerance: self
+                .referee
+                .as_ref()
+                .map_or(1, |r| r.advantage_pressure_tolerance()),
+            parameters: self.parameter_set.unwrap_or_default(),
             // FIX_2601/1120: Pass actual ball position to prevent InFlight origin teleportation
             ball_position: self.ball.position.to_meters(),
         }
@@ -433,6 +442,8 @@ impl MatchEngine {
                     // self.result.statistics.debug_shot_taken_count += 1;
                 }
 
+                let creation = self.chance_creation_for(*shooter_idx);
+                self.result.statistics.record_chance_creation(&creation, false);
                 let ball_pos_m = self.ball.position_meters();
                 self.emit_event(
                     MatchEvent::shot(
@@ -447,7 +458,9 @@ impl MatchEngine {
                         ball_pos_m.0,
                         ball_pos_m.1,
                         self.ball.height_meters(),
-                    )),
+                    ))
+                    .with_xg_features(self.action_queue.last_shot_features_for(*shooter_idx))
+                    .with_chance_creation(creation),
                 );
 
                 // Balance diagnostics: shot attempt (on-target path)
@@ -623,9 +636,18 @@ impl MatchEngine {
                 if let Some(ref mut recorder) = self.replay_recorder {
                     recorder.record_foul(t_seconds, team_id, *tackler_idx as u32, at_m);
                 }
+
+                // Law 12.3: card decision deferred until call-back or next stoppage.
+                self.signal_advantage(*tackler_idx, *target_idx);
             }
 
-            ActionResult::GoalkeeperHandlingViolation { goalkeeper_idx, last_touch_idx, is_indirect, xg } => {
+            ActionResult::GoalkeeperHandlingViolation {
+                goalkeeper_idx,
+                last_touch_idx,
+                is_indirect,
+                from_throw_in,
+                xg,
+            } => {
                 self.finalize_pass_sequences();
                 // Restart pulse (set piece will follow)
                 self.restart_occurred_this_tick = true;
@@ -656,17 +678,36 @@ impl MatchEngine {
                     _ => self.player_positions[*goalkeeper_idx],
                 };
                 let foul_pos_m = foul_pos.to_meters();
+                let ball_pos = (foul_pos_m.0, foul_pos_m.1, self.ball.height_meters());
 
-                self.emit_event(
-                    MatchEvent::foul(
+                // Law 12.2: back-pass/throw-in handling is a technical offence (IFK);
+                // handling outside the own penalty area is a direct-FK handball.
+                let event = if *is_indirect {
+                    MatchEvent::back_pass_offence(
                         self.minute,
                         self.current_timestamp_ms(),
                         is_home,
                         *goalkeeper_idx,
-                        (foul_pos_m.0, foul_pos_m.1, self.ball.height_meters()),
+                        *last_touch_idx,
+                        ball_pos,
+                        *from_throw_in,
                     )
-                    .with_target_track_id(*last_touch_idx),
-                );
+                } else {
+                    MatchEvent::handball(
+                        self.minute,
+                        self.current_timestamp_ms(),
+                        is_home,
+                        *goalkeeper_idx,
+                        ball_pos,
+                        HandballDetails {
+                            deliberate: true,
+                            restart_type: HandballRestartType::DirectFreeKick,
+                            in_penalty_area: false,
+                        },
+                    )
+                    .with_target_track_id(*last_touch_idx)
+                };
+                self.emit_event(event);
                 if is_home {
                     self.result.statistics.fouls_home += 1;
                 } else {
@@ -764,13 +805,23 @@ impl MatchEngine {
                     // so we mainly track statistics here for bias monitoring.
                 }
 
+                let is_penalty = restart_type == RestartType::Penalty;
                 self.emit_event(
-                    MatchEvent::foul(
+                    MatchEvent::handball(
                         self.minute,
                         self.current_timestamp_ms(),
                         is_home,
                         *offender_idx,
                         (foul_pos_m.0, foul_pos_m.1, self.ball.height_meters()),
+                        HandballDetails {
+                            deliberate: is_penalty,
+                            restart_type: if is_penalty {
+                                HandballRestartType::Penalty
+                            } else {
+                                HandballRestartType::DirectFreeKick
+                            },
+                            in_penalty_area: is_penalty,
+                        },
                     )
                     .with_target_track_id(*last_touch_idx),
                 );
@@ -869,7 +920,7 @@ impl MatchEngine {
                 }
             }
 
-            ActionResult::SaveMade { goalkeeper_idx, shooter_idx, xg, .. } => {
+            ActionResult::SaveMade { goalkeeper_idx, save_type, shooter_idx, xg } => {
                 self.finalize_pass_sequences();
                 let is_home = TeamSide::is_home(*goalkeeper_idx);
                 let team_id = if is_home { 0 } else { 1 };
@@ -891,6 +942,8 @@ impl MatchEngine {
                     self.result.statistics.xg_away += xg;
                 }
                 // NOTE: Shot budget tracking done in record_shot_attempt() only
+                let creation = self.chance_creation_for(*shooter_idx);
+                self.result.statistics.record_chance_creation(&creation, false);
 
                 // C6: Use goalkeeper_idx directly as track_id
                 self.emit_event(
@@ -900,7 +953,9 @@ impl MatchEngine {
                         is_home,
                         *goalkeeper_idx,
                     )
-                    .with_target_track_id(Some(*shooter_idx)),
+                    .with_target_track_id(Some(*shooter_idx))
+                    .with_xg_features(self.action_queue.last_shot_features_for(*shooter_idx))
+                    .with_chance_creation(creation),
                 );
 
                 // P6: Record to ReplayRecorder
@@ -934,6 +989,10 @@ impl MatchEngine {
                 self.ball.height = 0;
                 // 4. BallState도 Controlled로 변경
                 self.action_queue.set_ball_state(BallState::Controlled { owner_idx: *goalkeeper_idx });
+                // 5. 캐치면 6초 카운트 시작 (Law 12.2)
+                if matches!(save_type, SaveType::Catch) {
+                    self.goalkeeper_took_ball_in_hands(*goalkeeper_idx);
+                }
             }
 
             ActionResult::ShotMissed { shooter_idx, xg } => {
@@ -954,6 +1013,8 @@ impl MatchEngine {
                 // FIX_2601/0112: Record to calibration snapshot (missed = not on target, not goal)
                 self.record_shot_for_calibration(*shooter_idx, false, false, *xg);
                 // NOTE: Shot budget tracking done in record_shot_attempt() only
+                let creation = self.chance_creation_for(*shooter_idx);
+                self.result.statistics.record_chance_creation(&creation, false);
                 if let BallState::OutOfPlay {
                     restart_type,
                     position,
@@ -1057,6 +1118,7 @@ impl MatchEngine {
 
             // FIX_2601/0102: 트랩 성공 시 어시스트 후보 업데이트
             ActionResult::TrapSuccess { player_idx } => {
+                self.action_queue.note_reception(*player_idx);
                 // 패스로 인한 트랩인 경우 어시스트 후보 저장
                 let prev_owner_id = self.action_queue.last_passer_idx.take();
                 let prev_target_id = self.action_queue.last_pass_receiver_idx.take();
@@ -1070,12 +1132,15 @@ impl MatchEngine {
                         // FIX_2601/1128: Record pass pair for reciprocity bonus
                         self.record_pass_pair(passer_idx, target_idx);
                         let receiver_team = TeamSide::from_player_idx(*player_idx);
-                        self.assist_candidate = Some(super::super::types::AssistCandidate::new(
-                            passer_idx,
-                            *player_idx,
-                            receiver_team,
-                            self.current_tick as u32,
-                        ));
+                        self.assist_candidate = Some(
+                            super::super::types::AssistCandidate::new(
+                                passer_idx,
+                                *player_idx,
+                                receiver_team,
+                                self.current_tick as u32,
+                            )
+                            .chained(self.assist_candidate),
+                        );
                     }
                 }
 
@@ -1169,6 +1234,13 @@ impl MatchEngine {
         record_boundary: bool,
         t_seconds: f64,
     ) {
+        // VAR checks a penalty before it is taken (may become a free kick or dropped ball)
+        let (restart_type, position, restart_is_home) = if restart_type == RestartType::Penalty {
+            self.var_review_penalty(position, restart_is_home)
+        } else {
+            (restart_type, position, restart_is_home)
+        };
+
         // Restart pulse (set piece will follow)
         self.restart_occurred_this_tick = true;
         self.restart_type_this_tick = Some(restart_type);
@@ -1328,6 +1400,8 @@ impl MatchEngine {
                 }
                 self.apply_offside_restart(is_home, receiver_pos);
                 offside_called = true;
+            } else {
+                self.note_var_offside_candidate(*passer_idx, *receiver_idx);
             }
         }
 
@@ -1361,6 +1435,36 @@ impl MatchEngine {
         );
     }
 
+    /// End-of-tick state checksum for cross-platform determinism checks.
+    ///
+    /// Integer positions catch divergence once it moves a player; stamina and ball flight
+    /// are hashed by bit pattern so float drift shows up on the tick it happens. Every value
+    /// is written at a fixed width (FxHasher64), so 32-bit targets hash the same bytes.
+    pub(crate) fn determinism_checksum(&self) -> u32 {
+        use std::hash::Hasher;
+
+        let mut h = fxhash::FxHasher64::default();
+        h.write_u64(self.current_tick);
+        for pos in &self.player_positions {
+            h.write_i32(pos.x);
+            h.write_i32(pos.y);
+        }
+        h.write_i32(self.ball.position.x);
+        h.write_i32(self.ball.position.y);
+        h.write_i16(self.ball.height);
+        h.write_u32(self.ball.current_owner.map_or(u32::MAX, |idx| idx as u32));
+        h.write_u8(self.ball.is_in_flight as u8);
+        h.write_u32(self.ball.flight_progress.to_bits());
+        for stamina in &self.stamina {
+            h.write_u32(stamina.to_bits());
+        }
+        h.write_u8(self.result.score_home);
+        h.write_u8(self.result.score_away);
+
+        let full = h.finish();
+        (full ^ (full >> 32)) as u32
+    }
+
     /// FIX_2601/1128: intended_target_pos 파라미터 추가
     /// 선택 시점의 타겟 위치를 사용하여 forward_pass_rate를 정확하게 측정
     fn record_phase0_pass(
@@ -1468,6 +1572,8 @@ impl MatchEngine {
 
         // Phase 2: AI 전술 업데이트 (점수 변화 또는 시간 경과 시)
         self.update_ai_tactics_if_needed();
+        self.update_opponent_adaptation();
+        self.update_ai_match_managers();
 
         // P0: Update defensive tuning from TeamInstructions
         let mut home_mindset = crate::engine::mindset::MindsetContext::default();
@@ -1493,6 +1599,24 @@ impl MatchEngine {
             + self.away_match_modifiers.press_intensity_add)
             .clamp(0.2, 1.0);
 
+        // AI difficulty reaction delay: no counter-press until the team has reacted.
+        if self.current_tick < self.press_resume_tick[0] {
+            self.home_defensive_tuning.pressing_factor = 0.2;
+        }
+        if self.current_tick < self.press_resume_tick[1] {
+            self.away_defensive_tuning.pressing_factor = 0.2;
+        }
+
+        // Late-game desperation: trailing side presses higher (DPER, opt-in).
+        if self.is_desperation_mode(true) {
+            let press = self.home_defensive_tuning.pressing_factor + self.exp_desperation_press_add();
+            self.home_defensive_tuning.pressing_factor = press.clamp(0.2, 1.0);
+        }
+        if self.is_desperation_mode(false) {
+            let press = self.away_defensive_tuning.pressing_factor + self.exp_desperation_press_add();
+            self.away_defensive_tuning.pressing_factor = press.clamp(0.2, 1.0);
+        }
+
         // P0-W1: Update elastic tactics from TeamInstructions
         self.elastic_home_tactics =
             crate::engine::elastic_band::team_instructions_to_elastic_tactics(  
@@ -1537,10 +1661,18 @@ impl MatchEngine {
 
             let owner_has_action = self.action_queue.is_player_active(owner_idx)
                 || self.action_queue.has_pending_for_player(owner_idx);
+            // Direct input: the user carries the ball (get_player_target_position_m);
+            // the decision runs only on a pass/shot press.
+            let owner_user_carrying = self.direct_input_for(owner_idx).is_some_and(|input| {
+                !matches!(
+                    input.pending(self.current_tick),
+                    Some(UserInputAction::Pass | UserInputAction::Shoot)
+                )
+            });
             if owner_has_action {
                 self.result.statistics.owner_action_blocked_ticks =
                     self.result.statistics.owner_action_blocked_ticks.saturating_add(1);
-            } else {
+            } else if !owner_user_carrying {
                 let exp = self.get_exp_params();
                 if exp.dpq_enabled {
                     if self.decision_scheduler.is_due(owner_idx, self.current_tick) {
@@ -1581,6 +1713,7 @@ impl MatchEngine {
         self.update_active_set_pieces();
 
         // 8. P7: 수비수 태클 결정 (FSM 기반)
+        self.apply_user_tackle_input();
         self.decide_defender_tackles();
 
         // 8.5. 쿨다운 틱 감소
@@ -1636,6 +1769,13 @@ impl MatchEngine {
         // 11.6. P0: 공 위치 기반 골 체크 (Goal Contract)
         self.check_goals_from_ball_position();
 
+        // 11.65. Advantage window: call back, accrue, or book at the stoppage
+        self.update_pending_advantage();
+        // 11.66. Goalkeeper six-second count (Law 12.2)
+        self.update_goalkeeper_holding();
+        // 11.67. VAR: attacking phase with a tight offside ends on turnover/restart
+        self.update_var_offside_candidate();
+
         // 11.7. P18: FieldBoard 업데이트 (occupancy 매틱, pressure 3틱마다)
         self.update_field_board_tick();
 
@@ -1648,9 +1788,11 @@ impl MatchEngine {
 
         // Phase 0: Minimal diagnostics (ball position + possession)
         self.record_phase0_tick();
+        self.record_momentum_tick();
 
         // 12. 위치 기록 (엔진이 계산한 실제 위치를 기록)
         self.record_positions_for_tick();
+        self.accumulate_distance_covered_tick();
 
         // 13. P10-13: Stamina 업데이트
         self.update_all_sprint_states();
@@ -1659,6 +1801,14 @@ impl MatchEngine {
         // 14. FIX_2601/0123: Momentum tick (gradual decay toward neutral)
         self.home_momentum.tick();
         self.away_momentum.tick();
+
+        // 15. Determinism trail: end-of-tick state checksum (replay recording only)
+        if self.replay_recorder.is_some() {
+            let checksum = self.determinism_checksum();
+            if let Some(ref mut recorder) = self.replay_recorder {
+                recorder.record_tick_checksum(self.current_tick, checksum);
+            }
+        }
     }
 
     /// 새로운 틱 기반 시뮬레이션
@@ -2099,6 +2249,11 @@ impl MatchEngine {
         self.possession_changed_this_tick = home_has_ball != prev_home_has_ball;
         if self.possession_changed_this_tick {
             self.record_possession_change(home_has_ball);
+            self.record_opponent_regain(home_has_ball);
+            // AI difficulty reaction delay: the side that lost the ball is slow to re-press.
+            let loser = if home_has_ball { 1 } else { 0 };
+            self.press_resume_tick[loser] = self.current_tick
+                + self.ai_difficulty_profiles[loser].reaction_delay_ticks as u64;
         }
         self.transition_system.update(self.possession_changed_this_tick, prev_home_has_ball);
 
@@ -2489,6 +2644,24 @@ impl MatchEngine {
     ///
     /// 점수 증가 + 골 이벤트 발생 + 킥오프 재시작 예약
     /// 자책골 판정: 공을 마지막에 터치한 선수의 팀 ≠ 득점 팀
+    /// 슈팅 시점의 찬스 메이킹 크레딧 (키 패스 + 세컨드 어시스트)
+    ///
+    /// 어시스트 후보가 슈터에게 간 유효한 패스일 때만 인정한다.
+    fn chance_creation_for(&self, shooter_idx: usize) -> ChanceCreationDetails {
+        let shooter_team = TeamSide::from_player_idx(shooter_idx);
+        let Some(candidate) = self
+            .assist_candidate
+            .filter(|c| c.is_for_team(shooter_team))
+            .filter(|c| c.is_valid(self.current_tick as u32, shooter_idx))
+        else {
+            return ChanceCreationDetails::default();
+        };
+        ChanceCreationDetails {
+            key_pass_track_id: Some(candidate.passer_idx as u8),
+            second_assist_track_id: candidate.second_assist_for(shooter_idx).map(|idx| idx as u8),
+        }
+    }
+
     fn on_goal_scored(&mut self, scoring_team: super::super::TeamSide) {
         use crate::engine::tactical_context::TeamSide;
 
@@ -2496,6 +2669,11 @@ impl MatchEngine {
 
         // 중복 골 방지 플래그 설정
         self.goal_scored_this_tick = true;
+
+        // VAR goal check: offside in the attacking phase disallows the goal
+        if self.var_disallows_goal(matches!(scoring_team, TeamSide::Home)) {
+            return;
+        }
         // Restart pulse (kickoff state is applied immediately)
         self.restart_occurred_this_tick = true;
         self.restart_type_this_tick = Some(RestartType::KickOff);
@@ -2582,25 +2760,32 @@ impl MatchEngine {
             // 일반 골
             // FIX_2601/0102: Assist Candidate System
             // 10초 윈도우 내 유효한 패스 어시스트 확인
-            let assist_idx = self
-                .assist_candidate
-                .filter(|c| c.is_for_team(scoring_team))
-                .filter(|c| c.is_valid(self.current_tick as u32, last_touch_idx))
-                .map(|c| c.passer_idx);
+            let creation = self.chance_creation_for(last_touch_idx);
+            let assist_idx = creation.key_pass_track_id.map(usize::from);
+            self.result.statistics.record_chance_creation(&creation, true);
 
             // 어시스트 후보 초기화 (사용 후)
             self.assist_candidate = None;
 
+            // 득점자의 슛이면 xG 피처 첨부
+            let xg_features = self.action_queue.last_shot_features_for(last_touch_idx);
+
             // C6: Use last_touch_idx and assist_idx directly as track_id
-            self.emit_event(MatchEvent::goal_with_position(
-                self.minute,
-                self.current_timestamp_ms(),
-                is_home,
-                last_touch_idx,
-                assist_idx,
-                ball_position,
-            ));
+            self.emit_event(
+                MatchEvent::goal_with_position(
+                    self.minute,
+                    self.current_timestamp_ms(),
+                    is_home,
+                    last_touch_idx,
+                    assist_idx,
+                    ball_position,
+                )
+                .with_xg_features(xg_features)
+                .with_chance_creation(creation),
+            );
         }
+        // 슛 피처는 이 골로 소진 (다음 골로 이월 방지)
+        self.action_queue.last_shot_features = None;
 
         // 5. P6: ReplayRecorder 기록
         let t_seconds = self.current_tick as f64 * 0.25; // 4 ticks/sec
@@ -4370,6 +4555,19 @@ impl MatchEngine {
                         );
                     }
                 }
+                // Direct input: 스틱 중립이면 드래그 활주 대신 감속으로 제동
+                let vel = match self.direct_input_for(player_idx) {
+                    Some(input) if input.is_idle() => {
+                        let speed = (vel.0 * vel.0 + vel.1 * vel.1).sqrt();
+                        let keep = if speed > 0.0 {
+                            (1.0 - params.decel * SUBSTEP_DT / speed).max(0.0)
+                        } else {
+                            0.0
+                        };
+                        (vel.0 * keep, vel.1 * keep)
+                    }
+                    _ => vel,
+                };
                 let (new_pos_m, new_vel) = update_player_motion(
                     pos_m,
                     vel,
@@ -4427,6 +4625,11 @@ impl MatchEngine {
 
     /// P15: 선수의 목표 위치 (미터 단위) 가져오기
     fn get_player_target_position_m(&self, player_idx: usize) -> (f32, f32) {
+        if let Some(input) = self.direct_input_for(player_idx) {
+            let pos_m = self.player_positions[player_idx].to_meters();
+            return input.move_target_m(pos_m, (field::LENGTH_M, field::WIDTH_M));
+        }
+
         // PositioningEngine에서 계산된 목표 위치 사용
         // FIX_2601 Phase 3.3: Coord10 → meters 변환
         let target = if let Some(state) = self.positioning_engine.get_player_state(player_idx) {
@@ -4487,6 +4690,7 @@ impl MatchEngine {
         use crate::engine::defensive_positioning::{
             update_defensive_positioning, DefensiveRole, TeamSide,
         };
+        use crate::engine::marking_manager::MarkingSnapshotExport;
         use crate::engine::threat_model;
 
         if self.player_positions.is_empty() {
@@ -4568,6 +4772,10 @@ impl MatchEngine {
             0.0
         };
 
+        // 개인 지시 대인 마크 (PlayerInstructions.man_mark, 상대 local idx)
+        let man_marks: [Option<usize>; 11] =
+            std::array::from_fn(|i| self.man_mark_target(defending_start + i));
+
         // 1. 역할 재할당 (MarkingManager → DefensiveRole)
         let manager = if defending_team == 0 {
             &mut self.home_marking_manager
@@ -4603,6 +4811,8 @@ impl MatchEngine {
 
             let role = if state.is_emergency_presser {
                 DefensiveRole::PresserPrimary
+            } else if let Some(target_idx) = man_marks[local_idx] {
+                DefensiveRole::Marker { target_idx }
             } else if state.is_cover {
                 DefensiveRole::PresserSecondary
             } else if state.primary_mark_id >= 0 {
@@ -4680,6 +4890,19 @@ impl MatchEngine {
                     Coord10::from_meters(pos_m.0, pos_m.1).clamp_in_bounds();
             }
         }
+
+        if self.marking_export {
+            let manager = if defending_is_home {
+                &self.home_marking_manager
+            } else {
+                &self.away_marking_manager
+            };
+            self.marking_snapshot = Some(MarkingSnapshotExport::from_roles(
+                manager,
+                &self.defensive_roles[defending_team],
+                defending_is_home,
+            ));
+        }
     }
 
     /// [DEPRECATED - P7] 기존 ActionQueue 기반 태클 체크
@@ -5142,6 +5365,41 @@ impl MatchEngine {
         self.hero_xp_bucket.apply_training_synergy(trained_attrs, bonus_rate);
     }
 
+    // ========== Distance Covered ==========
+
+    /// 틱 간 선수 이동 거리 누적 (결정 틱 단위 샘플링)
+    ///
+    /// 킥오프/세트피스 재배치 같은 순간이동은 사람이 한 틱(250ms)에 갈 수 없는
+    /// 거리이므로 집계에서 제외한다.
+    fn accumulate_distance_covered_tick(&mut self) {
+        /// 한 틱 최대 인정 이동 거리 (m) ≈ 12 m/s 전력질주
+        const MAX_TICK_DISPLACEMENT_M: f32 = 3.0;
+
+        if self.distance_prev_positions.len() == self.player_positions.len() {
+            for (idx, (prev, cur)) in
+                self.distance_prev_positions.iter().zip(&self.player_positions).enumerate()
+            {
+                let moved = prev.distance_to_m(cur);
+                if idx < self.distance_covered_m.len() && moved <= MAX_TICK_DISPLACEMENT_M {
+                    self.distance_covered_m[idx] += moved;
+                }
+            }
+        }
+        self.distance_prev_positions.clone_from(&self.player_positions);
+    }
+
+    /// 선수별 이동 거리를 팀 합계로 통계에 반영
+    pub(crate) fn record_distance_covered_stats(&mut self) {
+        let stats = &mut self.result.statistics;
+        stats.distance_covered_home_m = self.distance_covered_m[..11].iter().sum();
+        stats.distance_covered_away_m = self.distance_covered_m[11..].iter().sum();
+    }
+
+    /// 선수별 누적 이동 거리 (m, track_id 인덱스)
+    pub fn get_distance_covered_m(&self) -> &[f32; 22] {
+        &self.distance_covered_m
+    }
+
     // ========== Position Recording for Tick-Based Simulation ==========
 
     /// 2025-12-11: 현재 틱의 위치를 기록
@@ -5551,6 +5809,24 @@ impl MatchEngine {
     ///
     /// 공 소유자 근처의 수비수들이 태클을 시도할지 결정
     /// FIX_2601/0110: 2-phase update 패턴 적용 (index order bias 제거)
+    /// Direct input: start the user's tackle on the opposing ball carrier in range.
+    ///
+    /// The press stays pending (until its TTL) while the carrier is out of reach.
+    fn apply_user_tackle_input(&mut self) {
+        let Some(mode) = self.controlled_mode.as_ref() else { return };
+        let tackler = mode.controlled_track_id;
+        let Some(ball_owner) = self.ball.current_owner else { return };
+        if TeamSide::is_home(ball_owner) == TeamSide::is_home(tackler) {
+            return;
+        }
+        let wants_tackle = self
+            .direct_input_for(tackler)
+            .is_some_and(|input| input.pending(self.current_tick) == Some(UserInputAction::Tackle));
+        if wants_tackle && self.start_tackle_fsm(tackler, ball_owner, TackleType::Standing) {
+            self.take_direct_input_action(tackler, &[UserInputAction::Tackle]);
+        }
+    }
+
     pub(crate) fn decide_defender_tackles(&mut self) {
         // 공 소유자가 없으면 무시
         let Some(ball_owner) = self.ball.current_owner else { return };
@@ -5784,6 +6060,32 @@ impl MatchEngine {
         !self.active_set_pieces.is_empty()
     }
 
+    /// 팀 세트피스 루틴 (TeamInstructions.set_pieces)
+    fn set_piece_routines(&self, is_home: bool) -> &crate::tactics::SetPieceRoutines {
+        if is_home {
+            &self.home_instructions.set_pieces
+        } else {
+            &self.away_instructions.set_pieces
+        }
+    }
+
+    /// 경기 지정 키커 (MatchPlan.*_set_piece_takers)
+    fn set_piece_takers(&self, is_home: bool) -> &crate::tactics::SetPieceTakers {
+        if is_home {
+            &self.home_set_piece_takers
+        } else {
+            &self.away_set_piece_takers
+        }
+    }
+
+    /// 지정 키커 목록에서 경기장에 있는 첫 번째 선수 (track_id)
+    fn designated_set_piece_taker(&self, takers: &[usize], is_home: bool) -> Option<usize> {
+        let team_range = if is_home { 0..11 } else { 11..22 };
+        takers.iter().copied().find(|&idx| {
+            team_range.contains(&idx) && !matches!(self.player_states[idx], PlayerState::SentOff)
+        })
+    }
+
     /// P9: 코너킥 FSM 시작
     ///
     /// 기존 `execute_corner_kick()`을 FSM 기반으로 대체
@@ -5793,6 +6095,19 @@ impl MatchEngine {
             AerialDefender, AerialTarget, CornerKickContext, CornerTactic, SetPieceAction,
         };
         use crate::models::trait_system::TraitId;
+        use crate::models::{SetPieceRoutineDetails, SetPieceRoutineKind};
+        use crate::tactics::CornerRoutine;
+
+        // 코너 방향 먼저 결정 (좌/우 지정 키커 목록 선택)
+        let is_left_corner = self.rng.gen_bool(0.5);
+        let takers = self.set_piece_takers(is_home_attacking);
+        let side_takers = if is_left_corner { &takers.corners_left } else { &takers.corners_right };
+        let routines = self.set_piece_routines(is_home_attacking);
+        let corner_routine = routines.corner;
+        let designated =
+            self.designated_set_piece_taker(side_takers, is_home_attacking).or_else(|| {
+                self.designated_set_piece_taker(&routines.corner_takers, is_home_attacking)
+            });
 
         // 1. 코너킥 키커 선택 (corners + crossing 스킬 기준)
         // FIX_2601/0123: corners 속성을 주요 기준으로 사용, crossing은 보조
@@ -5810,15 +6125,32 @@ impl MatchEngine {
                 best_kicker = idx;
             }
         }
+        if let Some(idx) = designated {
+            best_kicker = idx;
+        }
+
+        let (tactic, routine) = match corner_routine {
+            CornerRoutine::Mixed => (CornerTactic::Inswing, SetPieceRoutineKind::Inswing),
+            CornerRoutine::NearPost => (CornerTactic::NearPost, SetPieceRoutineKind::NearPost),
+            CornerRoutine::FarPost => (CornerTactic::FarPost, SetPieceRoutineKind::FarPost),
+            CornerRoutine::Short => (CornerTactic::Short, SetPieceRoutineKind::ShortCorner),
+        };
 
         // 2. 코너킥 이벤트 발생
         // C6: Use best_kicker directly as track_id
-        self.emit_event(MatchEvent::corner(
-            self.minute,
-            self.current_timestamp_ms(),
-            is_home_attacking,
-            best_kicker,
-        ));
+        self.emit_event(
+            MatchEvent::corner(
+                self.minute,
+                self.current_timestamp_ms(),
+                is_home_attacking,
+                best_kicker,
+            )
+            .with_set_piece_routine(SetPieceRoutineDetails {
+                routine,
+                designated_taker: designated.is_some(),
+                wall_jump: None,
+            }),
+        );
 
         // For CornerKickContext (not Event SSOT)
         let kicker_name = self.get_player_name(best_kicker);
@@ -5828,7 +6160,6 @@ impl MatchEngine {
         // Corner is at opponent's goal line: if attacking right, corner at x=1.0
         let ctx = team_view_context(self, is_home_attacking);
         let attacks_right = ctx.attacks_right;
-        let is_left_corner = self.rng.gen_bool(0.5);
         let corner_tv = TeamViewCoord10 {
             x: Coord10::FIELD_LENGTH_10,
             y: if is_left_corner { 0 } else { Coord10::FIELD_WIDTH_10 },
@@ -5912,7 +6243,7 @@ impl MatchEngine {
             kicker_idx: best_kicker,
             kicker_name,
             is_left_corner,
-            tactic: CornerTactic::Inswing, // TODO: 전술 설정에서 가져오기
+            tactic,
             corners: self.get_player_corners(best_kicker) as u8,  // FIX_2601/0123: 코너킥 전용 속성
             crossing: self.get_player_crossing(best_kicker) as u8,
             technique: self.get_player_technique(best_kicker) as u8,
@@ -5920,6 +6251,11 @@ impl MatchEngine {
             curve: self.get_player_technique(best_kicker) as u8, // use technique as curve proxy
             header_targets,
             defenders,
+            defender_aerial_mult: if is_home_attacking {
+                self.away_match_modifiers.set_piece_defense_mult
+            } else {
+                self.home_match_modifiers.set_piece_defense_mult
+            },
         };
 
         // 7. FSM 생성 및 등록
@@ -5944,6 +6280,22 @@ impl MatchEngine {
         use crate::engine::coordinates;
         use crate::engine::phase_action::{FreeKickContext, FreeKickTactic, SetPieceAction};
         use crate::models::trait_system::TraitId;
+        use crate::models::{SetPieceRoutineDetails, SetPieceRoutineKind};
+
+        let direct_takers: &[usize] = if is_indirect {
+            &[]
+        } else {
+            &self.set_piece_takers(is_home_attacking).direct_free_kicks
+        };
+        let designated =
+            self.designated_set_piece_taker(direct_takers, is_home_attacking).or_else(|| {
+                self.designated_set_piece_taker(
+                    &self.set_piece_routines(is_home_attacking).free_kick_takers,
+                    is_home_attacking,
+                )
+            });
+        // 수비팀 벽 점프 (직접 프리킥만)
+        let wall_jump = !is_indirect && self.set_piece_routines(!is_home_attacking).wall_jump;
 
         // 1. 프리킥 키커 선택 (free_kicks 스킬 기준)
         let (start_idx, end_idx) = if is_home_attacking { (0, 11) } else { (11, 22) };
@@ -5959,6 +6311,10 @@ impl MatchEngine {
                 }
             }
         }
+        if let Some(idx) = designated {
+            best_kicker = idx;
+            best_freekick = self.get_player(idx).map_or(0, |p| p.attributes.free_kicks);
+        }
 
         // 2. 거리 계산
         // FIX_2601/0116: Use attacks_right for distance calculation (not is_home_attacking)
@@ -5966,23 +6322,7 @@ impl MatchEngine {
         let distance_to_goal = coordinates::distance_to_goal_m(position, attacks_right);
         let can_shoot_direct = !is_indirect && distance_to_goal <= 30.0;
 
-        // 3. 프리킥 이벤트 발생
-        // C6: Use best_kicker directly as track_id
-        self.emit_event(MatchEvent::freekick(
-            self.minute,
-            self.current_timestamp_ms(),
-            is_home_attacking,
-            best_kicker,
-            (position.0, position.1, 0.0),
-        ));
-
-        // 4. 공 위치 설정
-        // FIX_2601/0104: position is in NormalizedPos (width, length) format
-        // Use from_normalized_legacy() which properly swaps axes
-        self.ball.position = Coord10::from_normalized_legacy(position);
-        self.ball.current_owner = Some(best_kicker);
-
-        // 5. 택틱 결정: 직접슛 가능 거리면 직접슛, 아니면 크로스
+        // 3. 택틱 결정: 직접슛 가능 거리면 직접슛, 아니면 크로스
         let tactic = if is_indirect {
             if self.rng.gen::<f32>() < 0.6 {
                 FreeKickTactic::ShortPass
@@ -5995,6 +6335,35 @@ impl MatchEngine {
             FreeKickTactic::Cross
         };
 
+        let routine = match tactic {
+            FreeKickTactic::DirectShot => SetPieceRoutineKind::DirectShot,
+            FreeKickTactic::Cross | FreeKickTactic::LobBehind => SetPieceRoutineKind::Cross,
+            FreeKickTactic::ShortPass => SetPieceRoutineKind::ShortPass,
+        };
+
+        // 4. 프리킥 이벤트 발생
+        // C6: Use best_kicker directly as track_id
+        self.emit_event(
+            MatchEvent::freekick(
+                self.minute,
+                self.current_timestamp_ms(),
+                is_home_attacking,
+                best_kicker,
+                (position.0, position.1, 0.0),
+            )
+            .with_set_piece_routine(SetPieceRoutineDetails {
+                routine,
+                designated_taker: designated.is_some(),
+                wall_jump: (!is_indirect).then_some(wall_jump),
+            }),
+        );
+
+        // 5. 공 위치 설정
+        // FIX_2601/0104: position is in NormalizedPos (width, length) format
+        // Use from_normalized_legacy() which properly swaps axes
+        self.ball.position = Coord10::from_normalized_legacy(position);
+        self.ball.current_owner = Some(best_kicker);
+
         // 6. Context 생성
         let gk_idx = if is_home_attacking { 11 } else { 0 };
         let _gk_name = self.get_player_name(gk_idx);
@@ -6020,6 +6389,7 @@ impl MatchEngine {
             gk_idx,
             gk_reflexes: self.get_player_gk_reflexes(gk_idx) as u8,
             gk_positioning: self.get_player_positioning(gk_idx) as u8,
+            wall_jump,
         };
 
         // 7. FSM 생성 및 등록
@@ -6052,6 +6422,13 @@ impl MatchEngine {
                 best_kicker = idx;
             }
         }
+        if let Some(idx) = self.designated_set_piece_taker(
+            &self.set_piece_takers(is_home_attacking).penalties,
+            is_home_attacking,
+        ) {
+            best_kicker = idx;
+            best_penalty = self.get_player_penalty_taking(idx);
+        }
 
         // 2. 페널티 이벤트 발생
         // C6: Use best_kicker directly as track_id
@@ -6179,12 +6556,14 @@ impl MatchEngine {
         // FIX_2601/0106 P5: 휴식 시 회복 상수
         const BASE_RECOVERY_RATE: f32 = 0.0003; // 기본 회복률 (per tick)
         const REST_DECAY_MULT: f32 = 0.2; // 휴식 중 감소율 (걷기 = 20%)
+        // Designer tunable (calibration::ParameterSet), folded into every decay term
+        let fatigue_rate = self.parameter_set().fatigue_rate;
 
         for i in 0..22 {
             let stamina_attr = self.get_player_stamina_attr(i) as f32 / 100.0; // 0~1 범위
             let condition_mult = crate::fix01::condition_drain_mult(
                 self.setup.get_player(i).condition_level,
-            );
+            ) * fatigue_rate;
 
             // P0-S1: Get tempo multiplier from team instructions
             let is_home = i < 11;
@@ -6295,7 +6674,12 @@ impl MatchEngine {
         } else {
             self.away_match_modifiers.stamina_drain_mult
         };
-        let cost = base_cost * cost_mult * pressing_mult * stamina_drain_mult * condition_mult;
+        let cost = base_cost
+            * cost_mult
+            * pressing_mult
+            * stamina_drain_mult
+            * condition_mult
+            * self.parameter_set().fatigue_rate;
         self.stamina[player_idx] = (self.stamina[player_idx] - cost).max(0.0);  
     }
 
@@ -7443,16 +7827,244 @@ mod tests {
             user_player: None,
             home_match_modifiers: crate::engine::TeamMatchModifiers::default(),
             away_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+            modifier_stack: Default::default(),
             home_instructions: None,
             away_instructions: None,
             home_player_instructions: None,
             away_player_instructions: None,
             home_ai_difficulty: None,
             away_ai_difficulty: None,
+            pitch: crate::models::pitch::PitchSpec::default(),
+            home_chemistry: None,
+            away_chemistry: None,
+            stadium: None,
+            conditions: None,
+            penalty_shootout: None,
+            home_custom_formation: None,
+            away_custom_formation: None,
+            home_set_piece_takers: None,
+            away_set_piece_takers: None,
+            referee: None,
         };
         MatchEngine::new(plan).expect("match engine init")
     }
 
+    #[test]
+    fn test_home_chemistry_builds_pass_links() {
+        use crate::models::chemistry::{
+            ChemistryProfile, MatchAppearance, SharedMinutes, SquadChemistry,
+        };
+
+        let home_team = create_test_team("Home");
+        let profiles: Vec<ChemistryProfile> = home_team
+            .players
+            .iter()
+            .map(|p| ChemistryProfile {
+                name: p.name.clone(),
+                nationality: Some("KOR".to_string()),
+                personality: p.personality,
+                clubs: vec![],
+            })
+            .collect();
+        let lineup: Vec<MatchAppearance> =
+            home_team.players.iter().map(|p| MatchAppearance::full_match(p.name.clone())).collect();
+        let shared = SharedMinutes::from_matches(std::iter::repeat(lineup.as_slice()).take(20));
+
+        let plan = MatchPlan {
+            home_team,
+            away_team: create_test_team("Away"),
+            seed: 12345,
+            user_player: None,
+            home_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+            away_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+            modifier_stack: Default::default(),
+            home_instructions: None,
+            away_instructions: None,
+            home_player_instructions: None,
+            away_player_instructions: None,
+            home_ai_difficulty: None,
+            away_ai_difficulty: None,
+            pitch: crate::models::pitch::PitchSpec::default(),
+            home_chemistry: Some(SquadChemistry::compute(&profiles, &shared)),
+            away_chemistry: None,
+            stadium: None,
+            conditions: None,
+            penalty_shootout: None,
+            home_custom_formation: None,
+            away_custom_formation: None,
+            home_set_piece_takers: None,
+            away_set_piece_takers: None,
+            referee: None,
+        };
+        let engine = MatchEngine::new(plan).expect("match engine init");
+
+        let links = engine.pass_link_modifiers.clone().expect("chemistry should build pass links");
+        assert!(links.get(9, 10) > 1.0, "shared history should boost home link-up");
+        assert_eq!(links.get(19, 20), 1.0, "away side has no chemistry data");
+        assert_eq!(links.get(9, 20), 1.0, "cross-team links stay neutral");
+        assert!(engine.build_execution_context().pass_link_modifiers.is_some());
+
+        // Team chemistry lifts the home side only
+        assert!(engine.home_match_modifiers.pass_success_mult > 1.0);
+        assert!(engine.home_match_modifiers.off_ball_mult > 1.0);
+        assert_eq!(engine.away_match_modifiers.off_ball_mult, 1.0);
+        assert!(engine.get_player_off_the_ball(9) > create_test_engine().get_player_off_the_ball(9));
+
+        assert!(create_test_engine().pass_link_modifiers.is_none());
+    }
+
+    #[test]
+    fn test_stadium_sets_pitch_and_modifiers() {
+        use crate::models::stadium::{PitchSurface, Stadium};
+
+        let stadium = Stadium {
+            name: "Old Ground".to_string(),
+            length_m: 100.0,
+            width_m: 64.0,
+            surface: PitchSurface::Worn,
+            ..Stadium::standard("Old Ground")
+        };
+        let plan = MatchPlan {
+            home_team: create_test_team("Home"),
+            away_team: create_test_team("Away"),
+            seed: 12345,
+            user_player: None,
+            home_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+            away_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+            modifier_stack: Default::default(),
+            home_instructions: None,
+            away_instructions: None,
+            home_player_instructions: None,
+            away_player_instructions: None,
+            home_ai_difficulty: None,
+            away_ai_difficulty: None,
+            pitch: crate::models::pitch::PitchSpec::default(),
+            home_chemistry: None,
+            away_chemistry: None,
+            stadium: Some(stadium.clone()),
+            conditions: None,
+            penalty_shootout: None,
+            home_custom_formation: None,
+            away_custom_formation: None,
+            home_set_piece_takers: None,
+            away_set_piece_takers: None,
+            referee: None,
+        };
+        let engine = MatchEngine::new(plan).expect("match engine init");
+
+        assert_eq!(engine.pitch().length_m, 100.0);
+        assert_eq!(engine.pitch().width_m, 64.0);
+        assert!(engine.away_match_modifiers.pass_success_mult < 1.0);
+        assert!(
+            engine.home_match_modifiers.pass_success_mult
+                > engine.away_match_modifiers.pass_success_mult
+        );
+        assert_eq!(engine.home_match_modifiers.width_bias_add_m, -1.0);
+        assert_eq!(engine.stadium, Some(stadium));
+    }
+
+    #[test]
+    fn test_match_conditions_apply_to_both_sides() {
+        use crate::models::weather::{MatchConditions, PitchQuality, WeatherCondition, WeatherKind};
+
+        let make_plan = |intensity: f32| MatchPlan {
+            home_team: create_test_team("Home"),
+            away_team: create_test_team("Away"),
+            seed: 12345,
+            user_player: None,
+            home_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+            away_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+            modifier_stack: Default::default(),
+            home_instructions: None,
+            away_instructions: None,
+            home_player_instructions: None,
+            away_player_instructions: None,
+            home_ai_difficulty: None,
+            away_ai_difficulty: None,
+            pitch: crate::models::pitch::PitchSpec::default(),
+            home_chemistry: None,
+            away_chemistry: None,
+            stadium: None,
+            conditions: Some(MatchConditions {
+                weather: WeatherCondition::new(WeatherKind::Snow, intensity),
+                pitch_quality: PitchQuality::Frozen,
+            }),
+            penalty_shootout: None,
+            home_custom_formation: None,
+            away_custom_formation: None,
+            home_set_piece_takers: None,
+            away_set_piece_takers: None,
+            referee: None,
+        };
+        let engine = MatchEngine::new(make_plan(1.0)).expect("match engine init");
+
+        assert_eq!(engine.home_match_modifiers, engine.away_match_modifiers);
+        assert!(engine.home_match_modifiers.pass_success_mult < 1.0);
+        assert!(engine.home_match_modifiers.injury_risk_mult > 1.3);
+
+        let doc = engine.with_replay_recording().take_replay_doc().expect("replay doc");
+        assert!(matches!(
+            doc.events.first(),
+            Some(crate::replay::types::ReplayEvent::Weather { weather: WeatherKind::Snow, .. })
+        ));
+
+        assert!(MatchEngine::new(make_plan(2.0)).is_err());
+    }
+
+    #[test]
+    fn test_desperation_mode_late_and_trailing_only() {
+        let plan = MatchPlan {
+            home_team: create_test_team("Home"),
+            away_team: create_test_team("Away"),
+            seed: 12345,
+            user_player: None,
+            home_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+            away_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+            modifier_stack: Default::default(),
+            home_instructions: None,
+            away_instructions: None,
+            home_player_instructions: None,
+            away_player_instructions: None,
+            home_ai_difficulty: None,
+            away_ai_difficulty: None,
+            pitch: crate::models::pitch::PitchSpec::default(),
+            home_chemistry: None,
+            away_chemistry: None,
+            stadium: None,
+            conditions: None,
+            penalty_shootout: None,
+            home_custom_formation: None,
+            away_custom_formation: None,
+            home_set_piece_takers: None,
+            away_set_piece_takers: None,
+            referee: None,
+        };
+        let mut engine = MatchEngine::new(plan).expect("match engine init");
+        engine.is_second_half = true;
+        engine.first_half_end_minute = 47; // 2 min of 1H added time
+        engine.result.score_home = 0;
+        engine.result.score_away = 1;
+        engine.minute = 88; // 86' on the regulation clock
+
+        // Opt-in only
+        assert!(!engine.is_desperation_mode(true));
+
+        let mut config = crate::engine::experimental::ExpConfig::default();
+        config.desperation.enabled = true;
+        engine.apply_exp_config(&config);
+        assert!(engine.is_desperation_mode(true));
+        assert!(!engine.is_desperation_mode(false));
+
+        // 84' on the regulation clock
+        engine.minute = 86;
+        assert!(!engine.is_desperation_mode(true));
+
+        // Deficit beyond max_deficit
+        engine.minute = 90;
+        engine.result.score_away = 3;
+        assert!(!engine.is_desperation_mode(true));
+    }
+
     #[test]
     fn test_derive_home_has_ball_for_phases_is_stable_during_flight() {
         let mut ball = super::super::Ball::default();
@@ -7513,6 +8125,7 @@ mod tests {
             goalkeeper_idx: 0,
             last_touch_idx: Some(20), // away shooter
             is_indirect: false,
+            from_throw_in: false,
             xg: Some(0.01),
         });
 
@@ -7521,8 +8134,8 @@ mod tests {
                 .result
                 .events
                 .iter()
-                .any(|e| matches!(e.event_type, EventType::Foul) && e.player_track_id == Some(0)),
-            "expected a foul event for GK track_id=0"
+                .any(|e| matches!(e.event_type, EventType::Handball) && e.player_track_id == Some(0)),
+            "expected a handball event for GK track_id=0"
         );
         assert!(
             engine
@@ -7541,6 +8154,7 @@ mod tests {
     fn test_goalkeeper_handling_violation_indirect_starts_indirect_free_kick_fsm() {
         use crate::engine::action_queue::{ActionResult, BallState, RestartType};
         use crate::engine::phase_action::SetPieceType;
+        use crate::models::EventType;
 
         let mut engine = create_test_engine();
         engine.initialize_player_positions();
@@ -7562,9 +8176,20 @@ mod tests {
             goalkeeper_idx: 0,
             last_touch_idx: Some(5), // teammate passer (for event target linkage)
             is_indirect: true,
+            from_throw_in: false,
             xg: None,
         });
 
+        let offence = engine
+            .result
+            .events
+            .iter()
+            .find(|e| e.event_type == EventType::BackPassOffence)
+            .expect("expected a back-pass offence event");
+        assert_eq!(offence.player_track_id, Some(0));
+        assert_eq!(offence.target_track_id, Some(5));
+        assert!(!engine.result.events.iter().any(|e| e.event_type == EventType::Foul));
+
         let last_set_piece = engine
             .active_set_pieces
             .last()
@@ -7800,6 +8425,48 @@ mod tests {
         }
     }
 
+    // ========== Chance creation (assist / second assist / key pass) ==========
+
+    #[test]
+    fn test_chance_creation_follows_pass_chain() {
+        use crate::engine::types::AssistCandidate;
+
+        let mut engine = create_test_engine();
+        engine.current_tick = 1_000;
+        let first = AssistCandidate::new(4, 6, TeamSide::Home, 900);
+        engine.assist_candidate =
+            Some(AssistCandidate::new(6, 9, TeamSide::Home, 950).chained(Some(first)));
+
+        let creation = engine.chance_creation_for(9);
+        assert_eq!(creation.key_pass_track_id, Some(6));
+        assert_eq!(creation.second_assist_track_id, Some(4));
+        // 다른 선수의 슛 / 상대 팀 슛에는 크레딧 없음
+        assert!(engine.chance_creation_for(10).is_empty());
+        assert!(engine.chance_creation_for(20).is_empty());
+    }
+
+    #[test]
+    fn test_goal_assists_match_chance_creation() {
+        use crate::models::events::EventType;
+
+        let mut engine = crate::engine::match_sim::test_fixtures::create_test_engine();
+        let result = engine.simulate();
+
+        let mut assists = 0u16;
+        for goal in result.events.iter().filter(|e| e.event_type == EventType::Goal) {
+            let Some(key_pass) = goal.chance_creation().and_then(|c| c.key_pass_track_id) else {
+                continue;
+            };
+            assert_eq!(goal.target_track_id, Some(key_pass), "goal assist must be the key pass");
+            assists += 1;
+        }
+        let stats = &result.statistics;
+        let credited: u16 = stats.player_creative.iter().map(|p| p.assists).sum();
+        let key_passes: u16 = stats.player_creative.iter().map(|p| p.key_passes).sum();
+        assert_eq!(credited, assists);
+        assert!(key_passes >= credited);
+    }
+
     // ========== Phase 5: Hero Growth Tests ==========
 
     #[test]
@@ -7877,6 +8544,47 @@ mod tests {
         assert!(engine.stamina[0] > 0.99); // 한 틱에 많이 감소하지 않음
     }
 
+    #[test]
+    fn test_replay_records_one_determinism_checksum_per_tick() {
+        let run = || {
+            let mut engine = create_test_engine().with_replay_recording();
+            engine.simulate();
+            engine.take_replay_doc().and_then(|doc| doc.determinism).expect("determinism trail")
+        };
+        let a = run();
+        let b = run();
+        assert_eq!(a.first_divergence(&b), None);
+
+        // One entry per simulated tick, in order, and the state actually moves
+        assert!(a.checksums.len() >= 90 * TICKS_PER_MINUTE as usize);
+        assert!(a.checksums.windows(2).all(|w| w[0].0 < w[1].0));
+        assert!(a.checksums.windows(2).any(|w| w[0].1 != w[1].1));
+
+        // Checksums only cost anything when a replay is being recorded
+        let mut plain = create_test_engine();
+        plain.simulate();
+        assert!(plain.take_replay_doc().is_none());
+    }
+
+    #[test]
+    fn test_fatigue_rate_parameter_scales_decay() {
+        let mut base = create_test_engine();
+        base.initialize_player_positions();
+        let mut tired = create_test_engine().with_parameter_set(
+            crate::calibration::ParameterSet { fatigue_rate: 2.0, ..Default::default() },
+        );
+        tired.initialize_player_positions();
+
+        for _ in 0..100 {
+            base.decay_stamina_tick();
+            tired.decay_stamina_tick();
+        }
+
+        let base_drop = 1.0 - base.stamina[0];
+        let tired_drop = 1.0 - tired.stamina[0];
+        assert!((tired_drop / base_drop - 2.0).abs() < 0.01, "{} vs {}", tired_drop, base_drop);
+    }
+
     #[test]
     fn test_stamina_decay_90min_no_sprint() {
         let mut engine = create_test_engine();
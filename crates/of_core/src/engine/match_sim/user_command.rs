@@ -72,7 +72,7 @@ pub enum OnBallAction {
 }
 
 /// User command queue (FIFO)
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct UserCommandQueue {
     queue: VecDeque<UserCommand>,
 }
@@ -8,6 +8,7 @@
 //! - **Gate B 전용**: 이 모듈은 의사결정 단계에서만 사용
 //! - **Softmax Selection**: Temperature 기반 확률적 선택
 
+use crate::engine::strict_math::{strict_sum, StrictMath};
 use super::cognitive_bias::CognitiveBias;
 use rand::Rng;
 
@@ -19,22 +20,22 @@ use rand::Rng;
 #[inline]
 pub fn logit(p: f32) -> f32 {
     let p_clamped = p.clamp(0.001, 0.999);
-    (p_clamped / (1.0 - p_clamped)).ln()
+    (p_clamped / (1.0 - p_clamped)).det_ln()
 }
 
 /// Sigmoid 역변환: 로그오즈 → 확률
 #[inline]
 pub fn sigmoid(x: f32) -> f32 {
-    1.0 / (1.0 + (-x).exp())
+    1.0 / (1.0 + (-x).det_exp())
 }
 
 /// Box-Muller 정규분포 (mean=0, std=1) [v2.1]
 pub fn normal01(rng: &mut impl Rng) -> f32 {
     let u1 = rng.gen::<f32>().max(1e-6);
     let u2 = rng.gen::<f32>();
-    let r = (-2.0 * u1.ln()).sqrt();
+    let r = (-2.0 * u1.det_ln()).sqrt();
     let theta = 2.0 * std::f32::consts::PI * u2;
-    r * theta.cos()
+    r * theta.det_cos()
 }
 
 // ============================================================================
@@ -268,9 +269,9 @@ pub fn softmax_select(utilities: &[f32], temperature: f32, rng: &mut impl Rng) -
 
     // Utility를 지수로 변환 (overflow 방지를 위해 max 빼기)
     let max_u = utilities.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
-    let weights: Vec<f32> = utilities.iter().map(|u| ((u - max_u) / t).exp()).collect();
+    let weights: Vec<f32> = utilities.iter().map(|u| ((u - max_u) / t).det_exp()).collect();
 
-    let total: f32 = weights.iter().sum();
+    let total = strict_sum(weights.iter().copied());
     if total <= 0.0 || !total.is_finite() {
         return 0;
     }
@@ -295,9 +296,9 @@ pub fn softmax_probabilities(utilities: &[f32], temperature: f32) -> Vec<f32> {
 
     let t = temperature.max(1e-3);
     let max_u = utilities.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
-    let weights: Vec<f32> = utilities.iter().map(|u| ((u - max_u) / t).exp()).collect();
+    let weights: Vec<f32> = utilities.iter().map(|u| ((u - max_u) / t).det_exp()).collect();
 
-    let total: f32 = weights.iter().sum();
+    let total = strict_sum(weights.iter().copied());
     if total <= 0.0 || !total.is_finite() {
         let n = utilities.len();
         return vec![1.0 / n as f32; n];
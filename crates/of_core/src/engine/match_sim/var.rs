@@ -0,0 +1,500 @@
+//! VAR reviews (IFAB VAR protocol)
+//!
+//! Reviewable match-changing incidents:
+//! - goals: offside in the attacking phase that the ~1m on-field tolerance let through
+//! - penalties: foul location (inside/outside the area) and whether there was a foul
+//! - direct red cards: serious foul play downgraded to a caution
+//!
+//! Each review has a deterministic overturn probability; the roll is hashed from
+//! the match seed and tick so a review never shifts the match RNG stream. The
+//! timeline gets a `VarReviewStarted` → `VarDecision` pair per review, and the
+//! review duration is added to stoppage time.
+
+use super::match_state::{TransitionTrigger, VarReviewType};
+use super::MatchEngine;
+use crate::engine::action_queue::{BallState, RestartType};
+use crate::engine::physics_constants::field;
+use crate::engine::types::Coord10;
+use crate::models::{
+    EventType, MatchEvent, TeamSide, VarIncident, VarReviewDetails, VarReviewOutcome,
+};
+
+/// Attacker this far beyond the line is clearly offside on the calibrated lines.
+const OFFSIDE_CLEAR_MARGIN_M: f32 = 0.3;
+
+/// Fouls this close to the penalty-area line get a location check.
+const PENALTY_LOCATION_TIGHT_M: f32 = 1.0;
+const PENALTY_LOCATION_OVERTURN_MAX: f32 = 0.5;
+
+/// Soft penalty overturn chance; strict referees award more soft penalties.
+const PENALTY_SOFT_BASE: f32 = 0.04;
+const PENALTY_SOFT_PER_STRICTNESS: f32 = 0.12;
+
+/// Red card downgrade chance; card-happy referees are corrected more often.
+const RED_CARD_OVERTURN_BASE: f32 = 0.06;
+const RED_CARD_OVERTURN_PER_CARD_TENDENCY: f32 = 0.12;
+
+/// Penalty area half width (meters, 40.32m box).
+const PENALTY_AREA_HALF_WIDTH_M: f32 = 20.16;
+
+/// Free kick spot distance outside the area line when VAR moves a penalty out.
+const OUTSIDE_AREA_STEP_M: f32 = 0.5;
+
+/// Review durations (seconds): (base, random spread)
+const GOAL_CHECK_DURATION_S: (u16, u16) = (30, 30);
+const OFFSIDE_CHECK_DURATION_S: (u16, u16) = (60, 60);
+const FOUL_REVIEW_DURATION_S: (u16, u16) = (60, 60);
+/// On-field review at the monitor before a subjective decision is overturned.
+const ON_FIELD_REVIEW_S: u16 = 60;
+
+/// Tight offside the on-field tolerance let through (reviewed if a goal follows).
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct VarOffsideCandidate {
+    pub attacker_idx: usize,
+    pub attacking_home: bool,
+    pub margin_m: f32,
+    /// Attacker position at the pass (indirect free kick spot if the goal is disallowed)
+    pub position: Coord10,
+    pub noted_tick: u64,
+}
+
+/// What VAR checks on an awarded penalty
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PenaltyCheck {
+    /// Tight call at the area line: overturn → direct free kick outside the area
+    Location,
+    /// Clearly inside: overturn → no foul, dropped ball to the defending team
+    Foul,
+}
+
+struct VarReview {
+    incident: VarIncident,
+    reviewed_event_type: EventType,
+    is_home_team: bool,
+    player_idx: Option<usize>,
+    overturn_probability: f32,
+    offside_margin_m: Option<f32>,
+    overturned_restart: Option<&'static str>,
+}
+
+/// Deterministic roll in [0, 1) from the match seed, tick and salt (splitmix64).
+fn var_roll(seed: u64, tick: u64, salt: u64) -> f32 {
+    let mut z =
+        seed ^ tick.wrapping_mul(0x9E37_79B9_7F4A_7C15) ^ salt.wrapping_mul(0xD1B5_4A32_D192_ED03);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+    (z >> 40) as f32 / (1u64 << 24) as f32
+}
+
+/// Offside on a goal: the clearer the margin, the surer the overturn.
+pub(crate) fn offside_overturn_probability(margin_m: f32) -> f32 {
+    (margin_m / OFFSIDE_CLEAR_MARGIN_M).clamp(0.0, 1.0)
+}
+
+/// Distance inside the penalty area (meters) from the nearest area line.
+pub(crate) fn penalty_area_inside_margin_m(pos_m: (f32, f32), goal_at_zero: bool) -> f32 {
+    let depth = if goal_at_zero { pos_m.0 } else { field::LENGTH_M - pos_m.0 };
+    let front = field::PENALTY_AREA_LENGTH_M - depth;
+    let side = PENALTY_AREA_HALF_WIDTH_M - (pos_m.1 - field::CENTER_Y).abs();
+    front.min(side)
+}
+
+/// Penalty check and overturn chance (`strictness` = referee tendency, 0.5 neutral).
+pub(crate) fn penalty_overturn_probability(
+    inside_margin_m: f32,
+    strictness: f32,
+) -> (f32, PenaltyCheck) {
+    if inside_margin_m < PENALTY_LOCATION_TIGHT_M {
+        let tightness = 1.0 - inside_margin_m.max(0.0) / PENALTY_LOCATION_TIGHT_M;
+        (PENALTY_LOCATION_OVERTURN_MAX * tightness, PenaltyCheck::Location)
+    } else {
+        let strictness = strictness.clamp(0.0, 1.0);
+        (PENALTY_SOFT_BASE + PENALTY_SOFT_PER_STRICTNESS * strictness, PenaltyCheck::Foul)
+    }
+}
+
+/// Direct red card downgrade chance (`card_tendency` = referee tendency, 0.5 neutral).
+pub(crate) fn red_card_overturn_probability(card_tendency: f32) -> f32 {
+    RED_CARD_OVERTURN_BASE + RED_CARD_OVERTURN_PER_CARD_TENDENCY * card_tendency.clamp(0.0, 1.0)
+}
+
+/// Free kick spot just outside the nearest penalty-area line.
+fn outside_area_spot(pos_m: (f32, f32), goal_at_zero: bool) -> (f32, f32) {
+    let depth = if goal_at_zero { pos_m.0 } else { field::LENGTH_M - pos_m.0 };
+    let front = field::PENALTY_AREA_LENGTH_M - depth;
+    let side = PENALTY_AREA_HALF_WIDTH_M - (pos_m.1 - field::CENTER_Y).abs();
+    if front <= side {
+        let depth = field::PENALTY_AREA_LENGTH_M + OUTSIDE_AREA_STEP_M;
+        let x = if goal_at_zero { depth } else { field::LENGTH_M - depth };
+        (x, pos_m.1)
+    } else {
+        let offset = PENALTY_AREA_HALF_WIDTH_M + OUTSIDE_AREA_STEP_M;
+        let y = if pos_m.1 >= field::CENTER_Y {
+            field::CENTER_Y + offset
+        } else {
+            field::CENTER_Y - offset
+        };
+        (pos_m.0, y)
+    }
+}
+
+fn review_duration_s(review: &VarReview, overturned: bool, roll: f32) -> u16 {
+    let (base, spread) = match review.incident {
+        VarIncident::OffsideOnGoal if review.offside_margin_m.is_none() => GOAL_CHECK_DURATION_S,
+        VarIncident::OffsideOnGoal => OFFSIDE_CHECK_DURATION_S,
+        VarIncident::Penalty | VarIncident::RedCard => FOUL_REVIEW_DURATION_S,
+    };
+    // Factual offside decisions are overturned without an on-field review
+    let on_field_review = overturned && review.incident != VarIncident::OffsideOnGoal;
+    base + (spread as f32 * roll) as u16 + if on_field_review { ON_FIELD_REVIEW_S } else { 0 }
+}
+
+impl MatchEngine {
+    /// Pass with the flag down: remember a tight offside for the goal check.
+    pub(crate) fn note_var_offside_candidate(&mut self, passer_idx: usize, receiver_idx: usize) {
+        // First offence of the attacking phase counts
+        if self.var_offside_candidate.is_some() || !self.var_enabled() {
+            return;
+        }
+        let attacking_home = TeamSide::is_home(passer_idx);
+        let Some(margin_m) = self.tight_offside_margin_m(passer_idx, receiver_idx, attacking_home)
+        else {
+            return;
+        };
+        self.var_offside_candidate = Some(VarOffsideCandidate {
+            attacker_idx: receiver_idx,
+            attacking_home,
+            margin_m,
+            position: self.player_positions[receiver_idx],
+            noted_tick: self.current_tick,
+        });
+    }
+
+    /// Per tick: the attacking phase ends when the defenders win the ball or play restarts.
+    pub(crate) fn update_var_offside_candidate(&mut self) {
+        let Some(candidate) = self.var_offside_candidate else { return };
+        let defenders_have_ball = matches!(
+            self.action_queue.ball_state(),
+            BallState::Controlled { owner_idx }
+                if TeamSide::is_home(*owner_idx) != candidate.attacking_home
+        );
+        let restarted = self.restart_occurred_this_tick && self.current_tick > candidate.noted_tick;
+        if defenders_have_ball || restarted {
+            self.var_offside_candidate = None;
+        }
+    }
+
+    /// Goal check; true when VAR disallows the goal (offside restart already applied).
+    pub(crate) fn var_disallows_goal(&mut self, scoring_home: bool) -> bool {
+        let candidate = self.var_offside_candidate.take();
+        if !self.var_enabled() {
+            return false;
+        }
+        let candidate = candidate.filter(|c| c.attacking_home == scoring_home);
+        let overturned = self.run_var_review(VarReview {
+            incident: VarIncident::OffsideOnGoal,
+            reviewed_event_type: EventType::Goal,
+            is_home_team: scoring_home,
+            player_idx: self.ball.current_owner,
+            overturn_probability: candidate
+                .map_or(0.0, |c| offside_overturn_probability(c.margin_m)),
+            offside_margin_m: candidate.map(|c| c.margin_m),
+            overturned_restart: Some("indirect_free_kick"),
+        });
+        let Some(candidate) = candidate.filter(|_| overturned) else {
+            return false;
+        };
+
+        self.emit_event(MatchEvent::offside(
+            self.minute,
+            self.current_timestamp_ms(),
+            candidate.attacking_home,
+            candidate.attacker_idx,
+        ));
+        if candidate.attacking_home {
+            self.offside_count_home += 1;
+        } else {
+            self.offside_count_away += 1;
+        }
+        self.apply_offside_restart(candidate.attacking_home, candidate.position);
+        true
+    }
+
+    /// Penalty check before the kick; returns the restart to apply instead.
+    pub(crate) fn var_review_penalty(
+        &mut self,
+        position: Coord10,
+        attacking_home: bool,
+    ) -> (RestartType, Coord10, bool) {
+        if !self.var_enabled() {
+            return (RestartType::Penalty, position, attacking_home);
+        }
+
+        let goal_at_zero = !self.attacks_right(attacking_home);
+        let pos_m = position.to_meters();
+        let strictness = self.referee.as_ref().map_or(0.5, |r| r.strictness);
+        let (probability, check) = penalty_overturn_probability(
+            penalty_area_inside_margin_m(pos_m, goal_at_zero),
+            strictness,
+        );
+        let (overturned_restart, restart) = match check {
+            PenaltyCheck::Location => {
+                let (x, y) = outside_area_spot(pos_m, goal_at_zero);
+                ("free_kick", (RestartType::FreeKick, Coord10::from_meters(x, y), attacking_home))
+            }
+            PenaltyCheck::Foul => ("drop_ball", (RestartType::DropBall, position, !attacking_home)),
+        };
+
+        let overturned = self.run_var_review(VarReview {
+            incident: VarIncident::Penalty,
+            reviewed_event_type: EventType::Penalty,
+            is_home_team: attacking_home,
+            player_idx: None,
+            overturn_probability: probability,
+            offside_margin_m: None,
+            overturned_restart: Some(overturned_restart),
+        });
+        if overturned {
+            restart
+        } else {
+            (RestartType::Penalty, position, attacking_home)
+        }
+    }
+
+    /// Direct red card check; false when VAR downgrades it to a caution.
+    pub(crate) fn var_confirms_red_card(&mut self, offender_idx: usize) -> bool {
+        if !self.var_enabled() {
+            return true;
+        }
+        let card_tendency = self.referee.as_ref().map_or(0.5, |r| r.card_tendency);
+        !self.run_var_review(VarReview {
+            incident: VarIncident::RedCard,
+            reviewed_event_type: EventType::RedCard,
+            is_home_team: TeamSide::is_home(offender_idx),
+            player_idx: Some(offender_idx),
+            overturn_probability: red_card_overturn_probability(card_tendency),
+            offside_margin_m: None,
+            overturned_restart: None,
+        })
+    }
+
+    /// Emit the review timeline; returns whether the decision was overturned.
+    fn run_var_review(&mut self, review: VarReview) -> bool {
+        let salt = review.incident as u64 + 1;
+        let overturned =
+            var_roll(self.original_seed, self.current_tick, salt) < review.overturn_probability;
+        let duration_s = review_duration_s(
+            &review,
+            overturned,
+            var_roll(self.original_seed, self.current_tick, salt + 16),
+        );
+
+        let review_type = match review.incident {
+            VarIncident::OffsideOnGoal => VarReviewType::Goal,
+            VarIncident::Penalty => VarReviewType::Penalty,
+            VarIncident::RedCard => VarReviewType::RedCard,
+        };
+        self.game_flow_machine.try_transition(
+            TransitionTrigger::VarCheckInitiated { review_type },
+            self.current_tick,
+        );
+
+        let timestamp_ms = self.current_timestamp_ms();
+        self.emit_event(MatchEvent::var_review_started(
+            self.minute,
+            timestamp_ms,
+            review.is_home_team,
+            review.player_idx,
+            review.incident,
+            review.reviewed_event_type.clone(),
+        ));
+        self.emit_event(MatchEvent::var_decision(
+            self.minute,
+            timestamp_ms,
+            review.is_home_team,
+            review.player_idx,
+            VarReviewDetails {
+                reviewed_event_type: review.reviewed_event_type,
+                outcome: Some(if overturned {
+                    VarReviewOutcome::Overturned
+                } else {
+                    VarReviewOutcome::Upheld
+                }),
+                incident: Some(review.incident),
+                overturn_probability: Some(review.overturn_probability),
+                duration_s: Some(duration_s),
+                offside_margin_m: review.offside_margin_m,
+                overturned_restart: review
+                    .overturned_restart
+                    .filter(|_| overturned)
+                    .map(str::to_string),
+            },
+        ));
+
+        self.game_flow_machine.try_transition(
+            TransitionTrigger::VarDecision { upheld: !overturned },
+            self.current_tick,
+        );
+        overturned
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::match_sim::test_fixtures;
+
+    fn var_engine() -> MatchEngine {
+        let mut config = crate::engine::experimental::ExpConfig::default();
+        config.rulebook.var_enabled = true;
+        test_fixtures::create_test_engine().with_exp_config(&config)
+    }
+
+    fn decisions(engine: &MatchEngine) -> Vec<&VarReviewDetails> {
+        engine
+            .result
+            .events
+            .iter()
+            .filter(|e| e.event_type == EventType::VarDecision)
+            .filter_map(|e| e.var_review_details())
+            .collect()
+    }
+
+    #[test]
+    fn overturn_probabilities_follow_the_incident() {
+        assert_eq!(offside_overturn_probability(0.6), 1.0);
+        assert!((offside_overturn_probability(0.15) - 0.5).abs() < 1e-6);
+
+        // Tight location call vs clear foul inside the area
+        let (p, check) = penalty_overturn_probability(0.2, 0.5);
+        assert_eq!(check, PenaltyCheck::Location);
+        assert!((p - 0.4).abs() < 1e-6);
+        let (lenient, check) = penalty_overturn_probability(6.0, 0.0);
+        assert_eq!(check, PenaltyCheck::Foul);
+        let (strict, _) = penalty_overturn_probability(6.0, 1.0);
+        assert!(strict > lenient);
+
+        assert!(red_card_overturn_probability(1.0) > red_card_overturn_probability(0.0));
+    }
+
+    #[test]
+    fn penalty_area_geometry() {
+        // Goal at x=0: 16.0m out in the middle is 0.5m inside the front line
+        assert!((penalty_area_inside_margin_m((16.0, 34.0), true) - 0.5).abs() < 1e-4);
+        assert!((penalty_area_inside_margin_m((89.0, 34.0), false) - 0.5).abs() < 1e-4);
+        assert!(penalty_area_inside_margin_m((18.0, 34.0), true) < 0.0);
+        assert_eq!(outside_area_spot((16.0, 34.0), true), (17.0, 34.0));
+        let (x, y) = outside_area_spot((5.0, 54.0), true);
+        assert_eq!(x, 5.0);
+        assert!(
+            (y - (field::CENTER_Y + PENALTY_AREA_HALF_WIDTH_M + OUTSIDE_AREA_STEP_M)).abs() < 1e-4
+        );
+    }
+
+    #[test]
+    fn clear_offside_goal_is_disallowed_with_indirect_free_kick() {
+        let mut engine = var_engine();
+        engine.var_offside_candidate = Some(VarOffsideCandidate {
+            attacker_idx: 9,
+            attacking_home: true,
+            margin_m: 0.8,
+            position: Coord10::from_meters(80.0, 30.0),
+            noted_tick: engine.current_tick,
+        });
+        engine.ball.current_owner = Some(9);
+
+        assert!(engine.var_disallows_goal(true));
+
+        let types: Vec<_> = engine.result.events.iter().map(|e| e.event_type.clone()).collect();
+        let started = types.iter().position(|t| *t == EventType::VarReviewStarted).unwrap();
+        assert_eq!(types[started + 1], EventType::VarDecision);
+        assert!(types.contains(&EventType::Offside));
+        let decision = decisions(&engine)[0];
+        assert_eq!(decision.outcome, Some(VarReviewOutcome::Overturned));
+        assert_eq!(decision.incident, Some(VarIncident::OffsideOnGoal));
+        assert_eq!(decision.offside_margin_m, Some(0.8));
+        assert!(engine.var_offside_candidate.is_none());
+        assert_eq!(engine.result.score_home, 0);
+    }
+
+    #[test]
+    fn goal_without_offside_is_checked_and_stands() {
+        let mut engine = var_engine();
+        engine.ball.current_owner = Some(20);
+        assert!(!engine.var_disallows_goal(false));
+        let decision = decisions(&engine)[0];
+        assert_eq!(decision.outcome, Some(VarReviewOutcome::Upheld));
+        assert_eq!(decision.overturn_probability, Some(0.0));
+        let duration = decision.duration_s.unwrap();
+        assert!((30..=60).contains(&duration), "{}", duration);
+    }
+
+    #[test]
+    fn reviews_are_off_without_var() {
+        let mut engine = test_fixtures::create_test_engine();
+        let position = Coord10::from_meters(95.0, 34.0);
+        assert_eq!(
+            engine.var_review_penalty(position, true),
+            (RestartType::Penalty, position, true)
+        );
+        assert!(engine.var_confirms_red_card(3));
+        assert!(!engine.var_disallows_goal(true));
+        assert!(decisions(&engine).is_empty());
+    }
+
+    #[test]
+    fn tight_penalty_location_can_be_moved_outside_the_area() {
+        // Same seed/tick → same roll; find a tick where the tight call is overturned
+        let mut moved = None;
+        for tick in 0..200 {
+            let mut engine = var_engine();
+            engine.current_tick = tick;
+            let goal_at_zero = !engine.attacks_right(true);
+            let x = if goal_at_zero { 16.4 } else { field::LENGTH_M - 16.4 };
+            let position = Coord10::from_meters(x, 34.0);
+            let (restart, spot, home) = engine.var_review_penalty(position, true);
+            if restart != RestartType::Penalty {
+                moved = Some((restart, spot, home, engine));
+                break;
+            }
+        }
+        let (restart, spot, home, engine) = moved.expect("a 0.1m-inside call is overturned often");
+        assert_eq!(restart, RestartType::FreeKick);
+        assert!(home, "the attacking team keeps the free kick");
+        assert!(penalty_area_inside_margin_m(spot.to_meters(), !engine.attacks_right(true)) < 0.0);
+        let decision = decisions(&engine)[0];
+        assert_eq!(decision.overturned_restart.as_deref(), Some("free_kick"));
+        assert!(decision.duration_s.unwrap() >= FOUL_REVIEW_DURATION_S.0 + ON_FIELD_REVIEW_S);
+    }
+
+    #[test]
+    fn reviews_are_deterministic_and_add_stoppage_time() {
+        let mut config = crate::engine::experimental::ExpConfig::default();
+        config.rulebook.var_enabled = true;
+        config.rulebook.advantage_play_enabled = true;
+        let run = || {
+            let mut engine = test_fixtures::create_test_engine().with_exp_config(&config);
+            engine.simulate()
+        };
+        let a = run();
+        let b = run();
+        let timeline = |r: &crate::models::MatchResult| -> Vec<_> {
+            r.events
+                .iter()
+                .filter(|e| {
+                    matches!(e.event_type, EventType::VarReviewStarted | EventType::VarDecision)
+                })
+                .map(|e| (e.minute, e.event_type.clone(), e.var_review_details().cloned()))
+                .collect()
+        };
+        assert_eq!(timeline(&a), timeline(&b));
+
+        let started =
+            a.events.iter().filter(|e| e.event_type == EventType::VarReviewStarted).count();
+        let decided = a.events.iter().filter(|e| e.event_type == EventType::VarDecision).count();
+        assert_eq!(started, decided);
+        let goals = a.events.iter().filter(|e| e.event_type == EventType::Goal).count();
+        assert!(started >= goals, "every goal is checked: {} reviews, {} goals", started, goals);
+    }
+}
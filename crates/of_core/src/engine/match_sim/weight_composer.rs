@@ -6,6 +6,8 @@
 //! ## 핵심 공식
 //! ln(W_total) = ln(W_base) + Σ ln(factor_i)
 
+use crate::engine::strict_math::StrictMath;
+
 /// 가중치 합성 규칙
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum StackRule {
@@ -48,7 +50,7 @@ impl WeightComposer {
 
     /// 최종 가중치 계산
     pub fn compose(&self) -> f32 {
-        let mut ln_total = self.base_weight.ln();
+        let mut ln_total = self.base_weight.det_ln();
 
         // 규칙별로 그룹화하여 처리
         let mut add_ln_sum = 0.0;
@@ -59,7 +61,7 @@ impl WeightComposer {
         let mut has_max_only = false;
 
         for f in &self.factors {
-            let ln_f = f.factor.ln();
+            let ln_f = f.factor.det_ln();
             match f.rule {
                 StackRule::AddLn => {
                     add_ln_sum += ln_f;
@@ -88,7 +90,7 @@ impl WeightComposer {
             ln_total += ln_f;
         }
 
-        ln_total.exp().clamp(0.05, 20.0)
+        ln_total.det_exp().clamp(0.05, 20.0)
     }
 
     /// 상세 내역 로깅용
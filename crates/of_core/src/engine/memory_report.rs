@@ -0,0 +1,205 @@
+//! Memory accounting for engine state
+//!
+//! Mobile builds budget memory per subsystem, so the bridge reports what the
+//! engine actually holds instead of a single opaque number.
+//!
+//! Sizes are computed from container capacities (`capacity × size_of::<T>()`),
+//! which is what the allocator has handed out for each buffer. Heap owned by
+//! the elements themselves (strings inside events, nested vectors) is not
+//! followed, so totals are a lower bound that tracks growth faithfully.
+
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
+use std::mem::size_of;
+
+use crate::data::person_cache::loaded_person_index;
+use crate::engine::live_match::LiveMatchSession;
+use crate::engine::live_session_manager::LiveSessionManager;
+use crate::engine::match_sim::MatchEngine;
+
+/// Bytes reserved by a `Vec` buffer.
+pub fn vec_bytes<T>(v: &Vec<T>) -> usize {
+    v.capacity() * size_of::<T>()
+}
+
+/// Bytes reserved by a `VecDeque` buffer.
+pub fn deque_bytes<T>(v: &VecDeque<T>) -> usize {
+    v.capacity() * size_of::<T>()
+}
+
+/// Bytes reserved by a `HashMap` table (entries plus one control byte each).
+pub fn hash_map_bytes<K, V, S>(m: &HashMap<K, V, S>) -> usize {
+    m.capacity() * (size_of::<(K, V)>() + 1)
+}
+
+/// Memory held by a single `MatchEngine`, broken down by subsystem.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct EngineMemoryUsage {
+    /// Replay recorder buffers plus replay events attached to the result
+    pub replay_buffer_bytes: usize,
+    /// Ball and per-player position history (`MatchResult.position_data`)
+    pub position_data_bytes: usize,
+    /// Match events and viewer events accumulated in the result
+    pub event_log_bytes: usize,
+    /// Engine struct plus per-tick working buffers (AI roles, formations, intents)
+    pub working_state_bytes: usize,
+}
+
+impl EngineMemoryUsage {
+    pub fn total_bytes(&self) -> usize {
+        self.replay_buffer_bytes
+            + self.position_data_bytes
+            + self.event_log_bytes
+            + self.working_state_bytes
+    }
+}
+
+/// Memory held by one live session (engine + session-side event archive).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct SessionMemoryUsage {
+    /// Background session id; `None` for the foreground session
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<u64>,
+    pub engine: EngineMemoryUsage,
+    /// Session-level copy of all events (kept for saving at full time)
+    pub session_event_bytes: usize,
+    pub total_bytes: usize,
+}
+
+/// Process-wide caches loaded on demand.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct CacheMemoryUsage {
+    /// Entries in the person index (0 until first lookup loads it)
+    pub person_cache_entries: usize,
+    pub person_cache_bytes: usize,
+}
+
+impl CacheMemoryUsage {
+    /// Measure caches without forcing any of them to load.
+    pub fn collect() -> Self {
+        match loaded_person_index() {
+            Some(index) => Self {
+                person_cache_entries: index.players.len(),
+                person_cache_bytes: size_of_val(index) + hash_map_bytes(&index.players),
+            },
+            None => Self::default(),
+        }
+    }
+
+    pub fn total_bytes(&self) -> usize {
+        self.person_cache_bytes
+    }
+}
+
+/// Full memory report across every engine instance the caller holds.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct MemoryReport {
+    /// Interactive (non-session) engine, if one is loaded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interactive_engine: Option<EngineMemoryUsage>,
+    /// Foreground live session, if one is running
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub live_session: Option<SessionMemoryUsage>,
+    /// Background sessions in id order
+    pub background_sessions: Vec<SessionMemoryUsage>,
+    pub caches: CacheMemoryUsage,
+    pub active_simulations: usize,
+    pub total_bytes: usize,
+}
+
+impl MemoryReport {
+    pub fn collect(
+        interactive_engine: Option<&MatchEngine>,
+        live_session: Option<&LiveMatchSession>,
+        background_sessions: &LiveSessionManager,
+    ) -> Self {
+        Self::from_parts(
+            interactive_engine.map(MatchEngine::memory_usage),
+            live_session.map(|s| s.memory_usage(None)),
+            background_sessions.memory_usage(),
+        )
+    }
+
+    /// Build from measurements taken separately (callers that cannot hold
+    /// every engine borrow at once).
+    pub fn from_parts(
+        interactive_engine: Option<EngineMemoryUsage>,
+        live_session: Option<SessionMemoryUsage>,
+        background_sessions: Vec<SessionMemoryUsage>,
+    ) -> Self {
+        let caches = CacheMemoryUsage::collect();
+        let active_simulations = usize::from(interactive_engine.is_some())
+            + usize::from(live_session.is_some())
+            + background_sessions.len();
+        let total_bytes = interactive_engine.map_or(0, |e| e.total_bytes())
+            + live_session.map_or(0, |s| s.total_bytes)
+            + background_sessions.iter().map(|s| s.total_bytes).sum::<usize>()
+            + caches.total_bytes();
+
+        Self {
+            interactive_engine,
+            live_session,
+            background_sessions,
+            caches,
+            active_simulations,
+            total_bytes,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::match_sim::test_fixtures::create_test_team;
+    use crate::engine::match_sim::MatchPlan;
+
+    fn test_plan() -> MatchPlan {
+        MatchPlan {
+            home_team: create_test_team("Home"),
+            away_team: create_test_team("Away"),
+            seed: 7,
+            user_player: None,
+            home_match_modifiers: Default::default(),
+            away_match_modifiers: Default::default(),
+            modifier_stack: Default::default(),
+            home_instructions: None,
+            away_instructions: None,
+            home_player_instructions: None,
+            away_player_instructions: None,
+            home_ai_difficulty: None,
+            away_ai_difficulty: None,
+            pitch: Default::default(),
+            home_chemistry: None,
+            away_chemistry: None,
+            stadium: None,
+            conditions: None,
+            penalty_shootout: None,
+            home_custom_formation: None,
+            away_custom_formation: None,
+            home_set_piece_takers: None,
+            away_set_piece_takers: None,
+            referee: None,
+        }
+    }
+
+    #[test]
+    fn test_report_grows_with_simulation() {
+        let mut manager = LiveSessionManager::new();
+        let id = manager.create(test_plan()).unwrap();
+        let before = MemoryReport::collect(None, None, &manager);
+        assert_eq!(before.active_simulations, 1);
+        assert_eq!(before.background_sessions[0].session_id, Some(id));
+        assert!(before.background_sessions[0].engine.working_state_bytes > 0);
+
+        manager.get_mut(id).unwrap().kick_off();
+        for _ in 0..2_000 {
+            manager.step(id);
+        }
+        let after = MemoryReport::collect(None, None, &manager);
+        let (b, a) = (&before.background_sessions[0], &after.background_sessions[0]);
+        assert!(a.engine.event_log_bytes > b.engine.event_log_bytes);
+        assert!(a.session_event_bytes > b.session_event_bytes);
+        assert!(after.total_bytes > before.total_bytes);
+        assert_eq!(after.total_bytes, a.total_bytes + after.caches.total_bytes());
+    }
+}
@@ -219,6 +219,14 @@ mod tests {
             penalty_shootout: None,
             best_moments: None,
             shot_opp_telemetry: None,
+            tactical_insights: None,
+            match_statistics: None,
+            player_ratings: None,
+            man_of_the_match: None,
+            momentum_curve: None,
+            modifier_report: None,
+            crowd: None,
+            parameter_hash: None,
         }
     }
 
@@ -6,7 +6,9 @@ pub mod action_queue; // NEW: Phase 3.1 - Action scheduling system
 pub mod action_scoring; // NEW: Phase 1.0.12 - ACTION_SCORING_SSOT core functions
 pub mod action_scoring_types; // NEW: Phase 1.0.12 - YAML structures
 pub mod actions;
+pub mod ai_manager; // CPU manager: reactive formation/tactics/substitutions for AI teams
 pub mod actor_state_validator; // NEW: P2.1-B - Actor State FSM Validation
+pub mod audio_cues; // Presentation-only crowd/whistle cue channel (derived from events)
 pub mod audit_gates; // NEW: FIX_2512 Phase 0 - Audit Gates (coordinate/state/ID validation)
 pub mod ball;
 pub mod ball_physics_params; // NEW: FIX_2601/0109 - Ball physics params SSOT for contract path
@@ -15,6 +17,7 @@ pub mod ball_flight_resolver; // NEW: FIX_2601/0106 - Physics-based shot resolut
 pub mod ball_prediction; // NEW: FIX_2601/0112 - Ball Prediction System (Google Football style)
 pub mod body_blocking; // NEW: P7 Phase 6 - Body Blocking & Physical Interference
 pub mod body_orientation; // NEW: Phase 1.1 - Body Orientation Model
+pub mod cancellation; // Cooperative cancellation for in-flight simulations
 pub mod config; // NEW: P10-13 Phase 6 - Tuning Configuration
 pub mod coordinate_contract; // FIX_2601: Coordinate contract SSOT
 pub mod coordinates;
@@ -36,19 +39,25 @@ pub mod growth; // NEW: Phase 5 - Hero Growth System
 pub mod intent_arbiter; // FIX_2601/0117 - Intent conflict resolution (Arbiter)
 pub mod intent_log; // NEW: FIX_2601 - Intent logging for CI gates and analysis
 pub mod live_match;
+pub mod live_session_manager; // Keyed concurrent live sessions (background fixtures)
 pub mod marking_manager; // NEW: Phase 1.3 - MarkingManager (Budget Enforcement)
 pub mod match_analysis; // NEW: Match OS v1.2 Priority 5 - Post-Match Pattern Detection
 pub mod interpretation_v1; // FIX_2601/0115 - Replay/Analytics Interpretation Layer v1 (post-match)
 pub mod match_modifiers; // NEW: FIX_2601/0109 - Sparse scalar modifiers (deck/coach effects)
+pub mod match_prediction; // Pre-match win/draw/loss odds (Monte Carlo or Poisson fast path)
 pub mod match_sim;
+pub mod memory_report; // Per-subsystem memory accounting for mobile memory budgets
 pub mod mindset; // NEW: P14 - Player Mindset System
+pub mod momentum_curve; // Per-minute momentum/threat series for the post-match graph
 pub mod movement;
+pub mod netcode; // Lockstep multiplayer sync: input hashing, tick checksums, rollback
 pub mod observation; // FIX_2601 Phase 4: SSOT-compliant Observation Builders
 pub mod offball; // FIX_2601/0115 - Off-Ball Decision System v1
 pub mod opponent_analysis;
 pub mod pep_grid; // NEW: Phase 3.4 - 5-channel positioning
 pub mod phase_action; // NEW: P7 - Phase-Based Action System
 pub mod reward; // NEW: FIX_2601 - RewardFunction (Google Football style AI training)
+pub mod rl_env; // Gym-style reset/step training env (C ABI in api::rl_env_json)
 pub mod physics_constants;
 pub mod plan_builder; // NEW: Phase 1.0.5 - build_plan_window() (prepared for full integration)
 pub mod plan_window; // NEW: Phase 1.0.2 - PlanWindow structure
@@ -63,16 +72,21 @@ pub mod positioning_engine; // NEW: Phase 3.4 - Off-the-Ball movement
 pub mod probability;
 pub mod scenario_builder; // NEW: FIX_2601 - ScenarioBuilder (Google Football style declarative API)
 pub mod scenario_loader; // FIX_2601/0106 - Scenario test harness
+pub mod scripted_scenario; // Mid-match tutorial/challenge situations with pass/fail objectives
 pub mod set_pieces;
 pub mod snapshot; // State Snapshot API for checkpoint/restore
 pub mod sort_keys; // FIX_2601/0123 PR #9-1: Stable sort tie-breaker keys
 pub mod stats;
+pub mod strict_math; // Cross-platform strict float determinism (libm + fixed-point sums)
 pub mod steering; // P3a: Steering behaviors (seek, arrive, pursuit, separation)
 pub mod substep_runner; // NEW: Phase 1.0.5 - exec_substep() (prepared for full integration)
+pub mod substitution_planner; // Substitution preview (advisory, no roster change)
 pub mod substitutions; // NEW: Extracted from match_sim.rs
 pub mod tactical_brain;
 pub mod tactical_context;
+pub mod tactical_problems; // In-match recurring weakness insights (pressure × conceded chances)
 pub mod team_phase; // NEW: Phase 3.0 - Team phase state machine
+pub mod team_talk; // Half-time team talk (tone × personality mix × scoreline → bounded modifiers)
 pub mod threat_model; // NEW: Phase 1.2 - ThreatModel (CarrierFreeScore)
 pub mod tick_snapshot; // FIX_2601/0117 - Snapshot-based 2-Phase decision system
 pub mod timestep; // NEW: Phase 1.0.1 - Dual timestep constants
@@ -80,6 +94,9 @@ pub mod trace_dump; // FIX_2601/0106 - Trace dump utilities
 pub mod transition_system; // NEW: Phase 1.4 - TransitionSystem (3s possession-change window)
 pub mod types;
 pub mod weights;
+pub mod what_if; // Multi-seed resimulation from a match moment (keyframe branching)
+pub mod win_probability; // Live win/draw/loss estimate (score × time × xG pace × red cards)
+pub mod xg_model; // Contextual per-shot xG (location, body part, pressure, assist, GK position)
 pub mod xgzone_map; // NEW: Match OS v1.2 - XGZone Map (Spatial xG Awareness)
 
 pub use action_queue::{
@@ -188,6 +205,7 @@ pub use body_blocking::{
     INFLUENCE_RADIUS,
     INTERCEPT_RADIUS,
 }; // P7 Phase 6: Body Blocking
+pub use cancellation::{CancellationToken, CANCELLED_CUT_REASON};
 pub use config::{AudacityConfig, DecisionConfig, EngineConfig, ExecutionConfig, StaminaConfig}; // P10-13 Phase 6: Tuning Configuration
 pub use debug_logger::{
     ActionEvaluation, DebugLogger, DecisionContext, DecisionLog, EvaluationBreakdown, ExecutionLog,
@@ -287,15 +305,43 @@ pub use field_board::{
     FIELD_WIDTH_M,
 }; // P18: FieldBoard (A-Plan Board Layer)
 pub use force_field::{calculate_dribble_direction, DecayType, ForceSpot, ForceType}; // FIX_2601/0112: Force Field Navigation
-pub use formation_waypoints::get_formation_waypoints;
+pub use formation_waypoints::{
+    anchor_waypoints, get_formation_waypoints, try_get_formation_waypoints,
+};
 pub use goal::{Goal, Goals}; // P0: Goal Contract
 pub use growth::{
     calculate_dribble_difficulty, calculate_pass_difficulty, calculate_pressure, calculate_xp,
     growth_threshold, HeroActionTag, HeroMatchGrowth, HeroXpBucket, HeroXpEvent, PlayerAttribute,
 }; // Phase 5: Hero Growth
 pub use live_match::{
-    FullTimeData, HalfTimeData, LiveMatchSession, MatchState, PlayerPosition, StepResult, TickData,
+    EventSubscription, FullTimeData, HalfTimeData, LiveMatchSession, LiveTacticChange,
+    MatchState, ObservationProfile, PlayerPosition, ReplayChunk, SkipResult, StepResult,
+    TickData,
+};
+pub use live_session_manager::LiveSessionManager;
+pub use match_prediction::{
+    expected_goal_rates, predict_by_simulation, predict_statistical, MatchPrediction,
+    PredictedScoreline, PredictionMethod, MAX_PREDICTION_SAMPLES,
+};
+pub use netcode::{
+    Desync, InputFrame, LockstepState, RemoteAction, RollbackBuffer, TickChecksum,
+};
+pub use memory_report::{CacheMemoryUsage, EngineMemoryUsage, MemoryReport, SessionMemoryUsage};
+pub use momentum_curve::{momentum_series_from_events, MomentumPoint, MomentumTracker};
+pub use scripted_scenario::{
+    ObjectiveReport, ObjectiveStatus, ObjectiveTracker, ScenarioObjective, ScriptedScenario,
+    ScriptedSession,
+};
+pub use what_if::{
+    capture_keyframe, resimulate_from_keyframe, what_if_from_moment, WhatIfBranch,
+    WhatIfDistribution, WhatIfScoreline, MAX_WHAT_IF_SEEDS,
 };
+pub use rl_env::{
+    ActionSpace, ObservationSpace, RewardBreakdown, RewardShaping, RlAction, RlEnv, RlEnvConfig,
+    RlObservationKind, RlStep, RlStepInfo,
+};
+pub use win_probability::{win_probability_series, WinProbability, WinProbabilityTracker};
+pub use xg_model::{contextual_xg, ShotAssistType, ShotBodyPart, XgFeatures};
 pub use match_analysis::{
     // Functions
     analyze_match,
@@ -321,7 +367,10 @@ pub use match_sim::{
     MatchEngine, MatchPlan, MiniMapObservation, MiniMapSpec, SimpleVectorObservation,
     TeamViewBallObservation, TeamViewPlayerObservation,
 };
-pub use match_modifiers::TeamMatchModifiers;
+pub use match_modifiers::{
+    CappedStat, ModifierEntry, ModifierReport, ModifierSource, ModifierStack, ModifierStat,
+    PassLinkModifiers, TeamMatchModifiers,
+};
 pub use mindset::{
     build_candidates,
     // Functions
@@ -470,6 +519,42 @@ pub struct UserPlayerConfig {
     pub player_name: String, // C7: Will be removed
     pub player_index: usize, // C6: Engine-confirmed track_id (0-21)
     pub highlight_level: HighlightLevel,
+    /// Co-op: 같은 커리어를 공유하는 추가 유저 선수 (슬롯 1..)
+    pub co_op_players: Vec<CoOpUserPlayer>,
+}
+
+/// Co-op 추가 유저 선수 (슬롯별 독립 하이라이트 레벨)
+#[derive(Debug, Clone)]
+pub struct CoOpUserPlayer {
+    pub is_home_team: bool,
+    pub player_name: String,
+    pub player_index: usize, // Engine-confirmed track_id (0-21)
+    pub highlight_level: HighlightLevel,
+}
+
+impl UserPlayerConfig {
+    /// 유저 슬롯 수 (슬롯 0 = 주 선수, 1.. = co-op)
+    pub fn slot_count(&self) -> usize {
+        1 + self.co_op_players.len()
+    }
+
+    /// 팀 + 선수 이름으로 유저 슬롯 찾기
+    pub fn slot_for(&self, is_home: bool, player_name: &str) -> Option<usize> {
+        if self.is_home_team == is_home && self.player_name == player_name {
+            return Some(0);
+        }
+        self.co_op_players
+            .iter()
+            .position(|p| p.is_home_team == is_home && p.player_name == player_name)
+            .map(|i| i + 1)
+    }
+
+    /// 슬롯 순서대로 (하이라이트 레벨, track_id)
+    pub fn highlight_configs(&self) -> Vec<(HighlightLevel, usize)> {
+        std::iter::once((self.highlight_level, self.player_index))
+            .chain(self.co_op_players.iter().map(|p| (p.highlight_level, p.player_index)))
+            .collect()
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -0,0 +1,192 @@
+//! Momentum curve (per-minute threat series)
+//!
+//! Samples field position and defensive pressure every decision tick, adds
+//! per-minute shots and xG from the event log, and produces the classic
+//! momentum graph series for the post-match UI.
+//!
+//! - **Territory**: ball progress toward the goal the owning team attacks
+//!   (0 = own goal line, 1 = opponent goal line), averaged over the minute.
+//!   Uses the engine's attack direction, so it survives the half-time swap.
+//! - **Pressure**: FieldBoard pressure the team applies at the ball while the
+//!   opponent has it (normalised 0..1, same scale as pass-risk scoring).
+//! - **Threat** = weighted sum of territory, pressure, shots and xG, then an
+//!   exponential moving average so single events read as swings, not spikes.
+//! - **Momentum** = `tanh(home_threat - away_threat)`, in -1..1 (home positive).
+//!
+//! Analysis only: nothing here feeds back into the simulation (the in-play
+//! team momentum modifier lives in `match_sim::momentum`).
+
+use crate::engine::strict_math::StrictMath;
+use serde::{Deserialize, Serialize};
+
+use crate::models::{EventType, MatchEvent};
+
+pub const TERRITORY_WEIGHT: f32 = 1.0;
+pub const PRESSURE_WEIGHT: f32 = 0.5;
+pub const SHOT_WEIGHT: f32 = 0.3;
+pub const XG_WEIGHT: f32 = 2.0;
+/// EMA weight of the current minute (1.0 = no smoothing)
+pub const SMOOTHING: f32 = 0.4;
+/// FieldBoard pressure value mapped to 1.0
+const PRESSURE_SCALE: f32 = 3.0;
+
+/// One minute of the momentum graph
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct MomentumPoint {
+    pub minute: u8,
+    /// Smoothed threat (≥ 0)
+    pub home_threat: f32,
+    pub away_threat: f32,
+    /// -1 (away dominant) .. 1 (home dominant)
+    pub momentum: f32,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct MinuteSample {
+    ticks: u32,
+    /// Summed per tick, [home, away]
+    territory: [f32; 2],
+    pressure: [f32; 2],
+}
+
+/// Per-tick field-position / pressure accumulator (one entry per minute)
+#[derive(Debug, Clone, Default)]
+pub struct MomentumTracker {
+    minutes: Vec<MinuteSample>,
+}
+
+impl MomentumTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record one decision tick.
+    ///
+    /// - `owner_is_home`: team in possession (`None` = loose ball, counted as neutral)
+    /// - `advance`: ball progress toward the owner's target goal (0..1)
+    /// - `pressure_at_ball`: raw FieldBoard pressure against the owner at the ball
+    pub fn record_tick(
+        &mut self,
+        minute: u8,
+        owner_is_home: Option<bool>,
+        advance: f32,
+        pressure_at_ball: f32,
+    ) {
+        let idx = minute as usize;
+        if self.minutes.len() <= idx {
+            self.minutes.resize(idx + 1, MinuteSample::default());
+        }
+        let sample = &mut self.minutes[idx];
+        sample.ticks += 1;
+        if let Some(home) = owner_is_home {
+            let (owner, defender) = if home { (0, 1) } else { (1, 0) };
+            sample.territory[owner] += advance.clamp(0.0, 1.0);
+            sample.pressure[defender] += (pressure_at_ball / PRESSURE_SCALE).clamp(0.0, 1.0);
+        }
+    }
+
+    /// Build the momentum series from the tick samples plus shot events.
+    pub fn series(&self, events: &[MatchEvent]) -> Vec<MomentumPoint> {
+        let per_minute = |minute: usize| {
+            self.minutes.get(minute).filter(|s| s.ticks > 0).map_or([0.0; 2], |s| {
+                let ticks = s.ticks as f32;
+                [0, 1].map(|t| {
+                    TERRITORY_WEIGHT * s.territory[t] / ticks
+                        + PRESSURE_WEIGHT * s.pressure[t] / ticks
+                })
+            })
+        };
+        build_series(events, self.minutes.len(), per_minute)
+    }
+}
+
+/// Event-only momentum series for results recorded without tick samples
+/// (shots and xG only).
+pub fn momentum_series_from_events(events: &[MatchEvent]) -> Vec<MomentumPoint> {
+    build_series(events, 0, |_| [0.0; 2])
+}
+
+fn is_shot_attempt(event_type: &EventType) -> bool {
+    // Goal follows its ShotOnTarget event, so it is not a separate attempt
+    matches!(
+        event_type,
+        EventType::Shot
+            | EventType::ShotOnTarget
+            | EventType::ShotOffTarget
+            | EventType::ShotBlocked
+            | EventType::PostHit
+            | EventType::BarHit
+    )
+}
+
+fn build_series(
+    events: &[MatchEvent],
+    sampled_minutes: usize,
+    field_threat: impl Fn(usize) -> [f32; 2],
+) -> Vec<MomentumPoint> {
+    let last_event_minute = events.iter().map(|e| e.minute as usize + 1).max().unwrap_or(0);
+    let minutes = sampled_minutes.max(last_event_minute);
+
+    let mut chances = vec![[0.0f32; 2]; minutes];
+    for event in events.iter().filter(|e| is_shot_attempt(&e.event_type)) {
+        let team = usize::from(!event.is_home_team);
+        let xg = event.details.as_ref().and_then(|d| d.xg_value).unwrap_or(0.0);
+        chances[event.minute as usize][team] += SHOT_WEIGHT + XG_WEIGHT * xg;
+    }
+
+    let mut smoothed = [0.0f32; 2];
+    (0..minutes)
+        .map(|minute| {
+            let field = field_threat(minute);
+            for team in 0..2 {
+                let raw = field[team] + chances[minute][team];
+                smoothed[team] = if minute == 0 {
+                    raw
+                } else {
+                    SMOOTHING * raw + (1.0 - SMOOTHING) * smoothed[team]
+                };
+            }
+            MomentumPoint {
+                minute: minute as u8,
+                home_threat: smoothed[0],
+                away_threat: smoothed[1],
+                momentum: (smoothed[0] - smoothed[1]).det_tanh(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_momentum_follows_territory_and_chances() {
+        let mut tracker = MomentumTracker::new();
+        // Minutes 0-9: home camped in the away half and pressing
+        for minute in 0..10 {
+            for _ in 0..240 {
+                tracker.record_tick(minute, Some(true), 0.8, 0.0);
+            }
+        }
+        // Minutes 10-19: away in control with heavy home pressure on the ball
+        for minute in 10..20 {
+            for _ in 0..240 {
+                tracker.record_tick(minute, Some(false), 0.7, 3.0);
+            }
+        }
+        let events = vec![MatchEvent::shot(15, 900_000, false, 20, true, 0.4)];
+        let series = tracker.series(&events);
+
+        assert_eq!(series.len(), 20);
+        assert!(series[9].momentum > 0.5);
+        assert!(series[19].momentum < 0.0);
+        // The away shot shows as a swing toward away in its minute
+        assert!(series[15].away_threat > series[14].away_threat);
+        assert!(series.iter().all(|p| (-1.0..=1.0).contains(&p.momentum)));
+
+        let fallback = momentum_series_from_events(&events);
+        assert_eq!(fallback.len(), 16);
+        assert!(fallback[15].momentum < 0.0);
+    }
+}
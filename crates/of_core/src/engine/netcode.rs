@@ -0,0 +1,364 @@
+//! Lockstep multiplayer synchronization primitives
+//!
+//! Two clients run the same `LiveMatchSession` (same plan + seed) and exchange only
+//! per-tick input frames. Each side hashes the frames it applied and the end-of-tick
+//! engine state; comparing `TickChecksum`s detects a desync on the tick it happens.
+//! `RollbackBuffer` keeps the last N tick-boundary snapshots so a late remote frame
+//! can be re-simulated from the tick it belongs to.
+//!
+//! Session wiring lives in `live_match.rs` (`enable_lockstep`, `apply_input_frames`,
+//! `apply_remote_action`, `rollback_to_tick`).
+
+use std::collections::VecDeque;
+use std::hash::Hasher;
+
+use serde::{Deserialize, Serialize};
+
+use super::live_match::MatchState;
+use super::match_sim::{MatchEngine, StickyAction, UserInputAction};
+use super::win_probability::WinProbabilityTracker;
+
+/// Default rollback window (ticks). 8 ticks = 2s of match time at 250ms/tick.
+pub const DEFAULT_ROLLBACK_TICKS: usize = 8;
+
+/// Checksums kept for late comparison against the remote peer.
+pub const CHECKSUM_HISTORY_TICKS: usize = 240;
+
+// ============================================
+// Input frames
+// ============================================
+
+/// One user input that must be applied identically on every peer.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RemoteAction {
+    /// `LiveMatchSession::set_user_player_input`
+    UserInput {
+        track_id: usize,
+        move_dir: (f32, f32),
+        sprint: bool,
+        #[serde(default)]
+        action: Option<UserInputAction>,
+    },
+    /// `LiveMatchSession::set_sticky_action`
+    StickyAction { track_id: usize, action: StickyAction, enabled: bool },
+    /// Take over (Some) or release (None) the controlled player
+    ControlPlayer { track_id: Option<usize> },
+}
+
+impl RemoteAction {
+    /// Reject malformed actions before any of a frame is applied.
+    pub fn validate(&self) -> Result<(), String> {
+        let track_id = match self {
+            RemoteAction::UserInput { track_id, move_dir, .. } => {
+                if !move_dir.0.is_finite() || !move_dir.1.is_finite() {
+                    return Err("move_dir must be finite".to_string());
+                }
+                Some(*track_id)
+            }
+            RemoteAction::StickyAction { track_id, .. } => Some(*track_id),
+            RemoteAction::ControlPlayer { track_id } => *track_id,
+        };
+        match track_id {
+            Some(id) if id >= 22 => Err(format!("Invalid track_id: {}", id)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Fixed-width hash input (floats by bit pattern), same on every target.
+    fn write_hash(&self, h: &mut impl Hasher) {
+        match self {
+            RemoteAction::UserInput { track_id, move_dir, sprint, action } => {
+                h.write_u8(0);
+                h.write_u32(*track_id as u32);
+                h.write_u32(move_dir.0.to_bits());
+                h.write_u32(move_dir.1.to_bits());
+                h.write_u8(*sprint as u8);
+                h.write_u8(action.map_or(0, |a| a as u8 + 1));
+            }
+            RemoteAction::StickyAction { track_id, action, enabled } => {
+                h.write_u8(1);
+                h.write_u32(*track_id as u32);
+                h.write_u8(*action as u8);
+                h.write_u8(*enabled as u8);
+            }
+            RemoteAction::ControlPlayer { track_id } => {
+                h.write_u8(2);
+                h.write_u32(track_id.map_or(u32::MAX, |id| id as u32));
+            }
+        }
+    }
+}
+
+/// All actions one peer submits for one tick.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct InputFrame {
+    /// Tick the actions apply before (the session's `ticks_played` at that point)
+    pub tick: u64,
+    /// Sending peer (0 = host)
+    pub peer_id: u8,
+    #[serde(default)]
+    pub actions: Vec<RemoteAction>,
+}
+
+impl InputFrame {
+    /// Deterministic frame hash (FxHasher64 over fixed-width fields).
+    pub fn hash(&self) -> u64 {
+        let mut h = fxhash::FxHasher64::default();
+        h.write_u64(self.tick);
+        h.write_u8(self.peer_id);
+        h.write_u32(self.actions.len() as u32);
+        for action in &self.actions {
+            action.write_hash(&mut h);
+        }
+        h.finish()
+    }
+}
+
+/// Combined hash of every frame applied for one tick, independent of arrival order.
+///
+/// Folded to 32 bits like the state checksum, so it survives Godot's float JSON numbers.
+pub fn input_frames_hash(frames: &[InputFrame]) -> u32 {
+    let mut ordered: Vec<&InputFrame> = frames.iter().collect();
+    ordered.sort_by_key(|frame| frame.peer_id);
+
+    let mut h = fxhash::FxHasher64::default();
+    for frame in ordered {
+        h.write_u64(frame.hash());
+    }
+    let full = h.finish();
+    (full ^ (full >> 32)) as u32
+}
+
+// ============================================
+// Checksums / desync detection
+// ============================================
+
+/// Per-tick sync record exchanged between peers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TickChecksum {
+    /// Executed tick (0-based, same numbering as `InputFrame::tick`)
+    pub tick: u64,
+    /// `input_frames_hash` of the frames applied before this tick (0 = none)
+    pub input_hash: u32,
+    /// End-of-tick engine state checksum
+    pub state: u32,
+}
+
+/// Mismatch between the local and remote record of one tick.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Desync {
+    pub tick: u64,
+    pub local: TickChecksum,
+    pub remote: TickChecksum,
+}
+
+impl Desync {
+    /// Peers applied different inputs (vs. same inputs, different simulation).
+    pub fn is_input_mismatch(&self) -> bool {
+        self.local.input_hash != self.remote.input_hash
+    }
+}
+
+impl std::fmt::Display for Desync {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kind = if self.is_input_mismatch() { "input" } else { "state" };
+        write!(
+            f,
+            "desync at tick {} ({} mismatch): local {:08x} vs remote {:08x}",
+            self.tick, kind, self.local.state, self.remote.state
+        )
+    }
+}
+
+/// Ring of recent local checksums.
+#[derive(Debug, Clone)]
+pub struct ChecksumLog {
+    capacity: usize,
+    entries: VecDeque<TickChecksum>,
+}
+
+impl ChecksumLog {
+    pub fn new(capacity: usize) -> Self {
+        Self { capacity: capacity.max(1), entries: VecDeque::new() }
+    }
+
+    pub fn record(&mut self, checksum: TickChecksum) {
+        // Re-simulated ticks (after rollback) replace their old records.
+        while self.entries.back().is_some_and(|c| c.tick >= checksum.tick) {
+            self.entries.pop_back();
+        }
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(checksum);
+    }
+
+    pub fn get(&self, tick: u64) -> Option<TickChecksum> {
+        self.entries.iter().rev().find(|c| c.tick == tick).copied()
+    }
+
+    pub fn latest(&self) -> Option<TickChecksum> {
+        self.entries.back().copied()
+    }
+
+    /// Compare with the remote record. Ticks not (or no longer) held locally pass.
+    pub fn verify(&self, remote: &TickChecksum) -> Result<(), Desync> {
+        match self.get(remote.tick) {
+            Some(local) if local != *remote => {
+                Err(Desync { tick: remote.tick, local, remote: *remote })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+// ============================================
+// Rollback snapshots
+// ============================================
+
+/// Session state at a tick boundary (before that tick's inputs are applied).
+///
+/// Holds a full engine clone: `MatchEngine::get_state()` leaves out AI/positioning
+/// internals, and re-simulating from it does not reproduce the original checksums.
+#[derive(Clone)]
+pub struct RollbackSnapshot {
+    pub tick: u64,
+    /// Match clock at the boundary
+    pub timestamp_ms: u64,
+    pub(crate) engine: Box<MatchEngine>,
+    pub(crate) session_state: MatchState,
+    pub(crate) all_event_count: usize,
+    pub(crate) last_event_count: usize,
+    pub(crate) win_probability: Option<WinProbabilityTracker>,
+}
+
+/// Last N tick-boundary snapshots, oldest first.
+#[derive(Clone)]
+pub struct RollbackBuffer {
+    capacity: usize,
+    snapshots: VecDeque<RollbackSnapshot>,
+}
+
+impl RollbackBuffer {
+    pub fn new(capacity: usize) -> Self {
+        Self { capacity: capacity.max(1), snapshots: VecDeque::new() }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.snapshots.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.snapshots.is_empty()
+    }
+
+    /// Oldest tick that can still be rolled back to.
+    pub fn oldest_tick(&self) -> Option<u64> {
+        self.snapshots.front().map(|s| s.tick)
+    }
+
+    pub fn push(&mut self, snapshot: RollbackSnapshot) {
+        while self.snapshots.back().is_some_and(|s| s.tick >= snapshot.tick) {
+            self.snapshots.pop_back();
+        }
+        if self.snapshots.len() == self.capacity {
+            self.snapshots.pop_front();
+        }
+        self.snapshots.push_back(snapshot);
+    }
+
+    pub fn get(&self, tick: u64) -> Option<&RollbackSnapshot> {
+        self.snapshots.iter().find(|s| s.tick == tick)
+    }
+
+    /// Newest snapshot whose match clock is at or before `timestamp_ms`.
+    pub fn latest_at_or_before_ms(&self, timestamp_ms: u64) -> Option<&RollbackSnapshot> {
+        self.snapshots.iter().rev().find(|s| s.timestamp_ms <= timestamp_ms)
+    }
+
+    /// Match clock of the oldest snapshot held.
+    pub fn oldest_timestamp_ms(&self) -> Option<u64> {
+        self.snapshots.front().map(|s| s.timestamp_ms)
+    }
+
+    /// Drop snapshots newer than `tick` (they are re-captured during re-simulation).
+    pub fn truncate_after(&mut self, tick: u64) {
+        while self.snapshots.back().is_some_and(|s| s.tick > tick) {
+            self.snapshots.pop_back();
+        }
+    }
+}
+
+/// Lockstep bookkeeping owned by a `LiveMatchSession`.
+#[derive(Clone)]
+pub struct LockstepState {
+    pub rollback: RollbackBuffer,
+    pub checksums: ChecksumLog,
+    /// Input hash for the tick about to run (reset after each tick)
+    pub(crate) pending_input_hash: u32,
+}
+
+impl LockstepState {
+    pub fn new(rollback_ticks: usize) -> Self {
+        Self {
+            rollback: RollbackBuffer::new(rollback_ticks),
+            checksums: ChecksumLog::new(CHECKSUM_HISTORY_TICKS),
+            pending_input_hash: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(peer_id: u8, dir: (f32, f32)) -> InputFrame {
+        InputFrame {
+            tick: 12,
+            peer_id,
+            actions: vec![RemoteAction::UserInput {
+                track_id: 9,
+                move_dir: dir,
+                sprint: true,
+                action: Some(UserInputAction::Pass),
+            }],
+        }
+    }
+
+    #[test]
+    fn test_frame_hash_is_stable_and_order_independent() {
+        let a = frame(0, (1.0, 0.0));
+        let b = frame(1, (0.0, -1.0));
+        assert_eq!(a.hash(), frame(0, (1.0, 0.0)).hash());
+        assert_ne!(a.hash(), frame(0, (1.0, 0.0001)).hash());
+        assert_eq!(input_frames_hash(&[a.clone(), b.clone()]), input_frames_hash(&[b, a]));
+
+        let json = serde_json::to_string(&frame(0, (1.0, 0.0))).unwrap();
+        let back: InputFrame = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.hash(), frame(0, (1.0, 0.0)).hash());
+    }
+
+    #[test]
+    fn test_checksum_log_detects_desync_and_replaces_resimulated_ticks() {
+        let mut log = ChecksumLog::new(3);
+        for tick in 0..5 {
+            log.record(TickChecksum { tick, input_hash: 0, state: tick as u32 });
+        }
+        assert!(log.get(1).is_none(), "older than capacity");
+
+        let remote = TickChecksum { tick: 3, input_hash: 0, state: 99 };
+        let desync = log.verify(&remote).unwrap_err();
+        assert!(!desync.is_input_mismatch());
+        assert!(log.verify(&TickChecksum { tick: 3, input_hash: 0, state: 3 }).is_ok());
+
+        // Re-simulating tick 3 drops the stale record for tick 4.
+        log.record(TickChecksum { tick: 3, input_hash: 7, state: 30 });
+        assert_eq!(log.latest().map(|c| c.tick), Some(3));
+        assert_eq!(log.get(3).map(|c| c.state), Some(30));
+    }
+}
@@ -1,5 +1,6 @@
 //! Candidate generation for off-ball decisions
 
+use crate::engine::strict_math::StrictMath;
 use super::types::*;
 
 /// Field dimensions
@@ -313,8 +314,8 @@ fn generate_press_support(ctx: &OffBallContext) -> Option<OffBallCandidate> {
     // Direction depends on which side of the ball we're on
     let angle_sign = if ctx.player_y < ctx.ball_y { 1.0 } else { -1.0 };
 
-    let offset_x = -ctx.attack_direction * OFFSET_DIST * angle.cos();
-    let offset_y = angle_sign * OFFSET_DIST * angle.sin();
+    let offset_x = -ctx.attack_direction * OFFSET_DIST * angle.det_cos();
+    let offset_y = angle_sign * OFFSET_DIST * angle.det_sin();
 
     let target_x = ctx.ball_x + offset_x;
     let target_y = ctx.ball_y + offset_y;
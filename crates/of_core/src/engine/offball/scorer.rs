@@ -1,5 +1,6 @@
 //! Score6 evaluation for off-ball candidates
 
+use crate::engine::strict_math::{strict_sum, StrictMath};
 use super::types::*;
 
 /// Evaluate a candidate and return Score6
@@ -47,8 +48,8 @@ fn softmax_select(scores: &[Score6], temperature: f32, seed: u64) -> Option<usiz
 
     // Stable softmax
     let max_t = totals.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
-    let exps: Vec<f32> = totals.iter().map(|t| (t - max_t).exp()).collect();
-    let sum: f32 = exps.iter().sum();
+    let exps: Vec<f32> = totals.iter().map(|t| (t - max_t).det_exp()).collect();
+    let sum = strict_sum(exps.iter().copied());
 
     if sum <= 0.0 {
         return Some(0);
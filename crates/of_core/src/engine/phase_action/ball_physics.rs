@@ -13,6 +13,7 @@
 //!      └──→ OutOfPlay (아웃)
 //! ```
 
+use crate::engine::strict_math::StrictMath;
 use serde::{Deserialize, Serialize};
 // P0: Core types moved to action_queue
 use super::super::action_queue::{PassType, ShotType};
@@ -370,7 +371,7 @@ impl BallPhysics {
 
         // 공 위치 = 선수 위치 + 전방 오프셋
         let effective_angle = owner_facing + offset_angle;
-        let (dx, dy) = (effective_angle.cos() * *separation, effective_angle.sin() * *separation);
+        let (dx, dy) = (effective_angle.det_cos() * *separation, effective_angle.det_sin() * *separation);
 
         self.position = (owner_pos.0 + dx, owner_pos.1 + dy);
         self.height = 0.0;
@@ -408,7 +409,7 @@ impl BallPhysics {
         let owner_facing = player_facings[owner_idx];
 
         let effective_angle = owner_facing + offset_angle;
-        let (dx, dy) = (effective_angle.cos() * *separation, effective_angle.sin() * *separation);
+        let (dx, dy) = (effective_angle.det_cos() * *separation, effective_angle.det_sin() * *separation);
 
         self.position = (owner_pos.0 + dx, owner_pos.1 + dy);
         self.height = 0.0;
@@ -565,7 +566,7 @@ impl BallPhysics {
         self.position.1 += velocity.1 * TICK_DT;
         self.height = 0.0;
 
-        let friction_per_tick = GRASS_FRICTION.powf(TICK_DT);
+        let friction_per_tick = GRASS_FRICTION.det_powf(TICK_DT);
         velocity.0 *= friction_per_tick;
         velocity.1 *= friction_per_tick;
 
@@ -649,7 +650,7 @@ impl BallPhysics {
         if let BallPhysicsState::Controlled { separation, offset_angle, .. } = &mut self.state {
             // 터치하면 공이 1.5m 앞으로
             *separation = TOUCH_SEPARATION;
-            *offset_angle = touch_direction.1.atan2(touch_direction.0);
+            *offset_angle = touch_direction.1.det_atan2(touch_direction.0);
         }
     }
 
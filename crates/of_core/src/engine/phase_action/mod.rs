@@ -49,9 +49,9 @@ pub use pass::{
 };
 pub use set_piece::{
     calculate_aerial_score, calculate_cross_accuracy, resolve_aerial_duel, resolve_direct_freekick,
-    resolve_penalty, AerialDefender, AerialTarget, CornerKickContext, CornerTactic,
-    FreeKickContext, FreeKickTactic, PenaltyContext, SetPieceAction, SetPiecePhase, SetPieceResult,
-    SetPieceType,
+    resolve_penalty, wall_jump_factor, AerialDefender, AerialTarget, CornerKickContext,
+    CornerTactic, FreeKickContext, FreeKickTactic, PenaltyContext, SetPieceAction, SetPiecePhase,
+    SetPieceResult, SetPieceType,
 };
 pub use shot::{
     calculate_save_probability, calculate_xg, calculate_xg_with_target, choose_shot_technique,
@@ -143,6 +143,8 @@ pub struct CornerKickContext {
     pub header_targets: Vec<AerialTarget>,
     /// 수비수들
     pub defenders: Vec<AerialDefender>,
+    /// 수비팀 세트피스 수비 보정 (1.0 = 기본)
+    pub defender_aerial_mult: f32,
 }
 
 /// 공중볼 타겟 (공격수)
@@ -209,6 +211,8 @@ pub struct FreeKickContext {
     pub gk_idx: usize,
     pub gk_reflexes: u8,
     pub gk_positioning: u8,
+    /// 수비벽 점프 (SetPieceRoutines.wall_jump)
+    pub wall_jump: bool,
 }
 
 // ============================================================================
@@ -637,7 +641,8 @@ pub fn resolve_aerial_duel<R: Rng>(ctx: &CornerKickContext, rng: &mut R) -> SetP
 
     // 경합 결과
     let roll: f32 = rng.gen();
-    let attack_advantage = best_attack_score - best_defend_score * 0.8; // 공격 약간 유리
+    let defend_score = best_defend_score * ctx.defender_aerial_mult;
+    let attack_advantage = best_attack_score - defend_score * 0.8; // 공격 약간 유리
 
     if roll < (0.4 + attack_advantage).clamp(0.1, 0.7) {
         // 공격수 승리 - 헤딩
@@ -709,6 +714,7 @@ pub fn resolve_direct_freekick<R: Rng>(ctx: &FreeKickContext, rng: &mut R) -> Se
     // Gold DeadBall 보너스
     let goal_chance =
         if ctx.has_deadball_gold { (base_chance * 1.8).min(0.25) } else { base_chance.min(0.15) };
+    let goal_chance = goal_chance * wall_jump_factor(ctx.wall_jump, tech);
 
     // GK 세이브 확률
     let gk_reflexes = skills::normalize(ctx.gk_reflexes as f32);
@@ -739,6 +745,15 @@ pub fn resolve_direct_freekick<R: Rng>(ctx: &FreeKickContext, rng: &mut R) -> Se
     }
 }
 
+/// 수비벽 점프 보정: 벽을 넘기는 슛은 막지만 기술 좋은 킥커는 벽 아래로 찬다
+pub fn wall_jump_factor(wall_jump: bool, technique: f32) -> f32 {
+    if wall_jump {
+        0.8 + 0.25 * technique
+    } else {
+        1.0
+    }
+}
+
 /// 페널티킥 해결
 pub fn resolve_penalty<R: Rng>(ctx: &PenaltyContext, rng: &mut R) -> SetPieceResult {
     let pk = skills::normalize(ctx.penalty_taking as f32);
@@ -932,6 +947,7 @@ mod tests {
             gk_idx: 11,
             gk_reflexes: 14,
             gk_positioning: 13,
+            wall_jump: false,
         };
 
         let mut rng = make_test_rng();
@@ -949,6 +965,14 @@ mod tests {
         assert!(rate > 0.05, "Close FK should have some goal chance: {}", rate);
     }
 
+    #[test]
+    fn test_wall_jump_factor() {
+        assert_eq!(wall_jump_factor(false, 0.2), 1.0);
+        // Jumping wall blocks average kickers, elite technique goes under it
+        assert!(wall_jump_factor(true, 0.5) < 1.0);
+        assert!(wall_jump_factor(true, 1.0) > 1.0);
+    }
+
     #[test]
     fn test_corner_kick_fsm_phases() {
         let ctx = CornerKickContext {
@@ -985,6 +1009,7 @@ mod tests {
                 marking_target: Some(4),
                 position: (0.9, 0.5),
             }],
+            defender_aerial_mult: 1.0,
         };
 
         let mut action = SetPieceAction::new_corner(1, 0, ctx);
@@ -14,6 +14,7 @@
 //! - ShotTechnique: 어떻게 차는가 (Normal, Power, OneTouch, Volley, Header, Chip)
 //! - ShotPhysicsParams: 물리 파라미터 (speed, accuracy, spin)
 
+use crate::engine::strict_math::StrictMath;
 use super::action_common::{
     clamp01, lerp as common_lerp, skill01, weighted_choice_index, ActionModel,
 };
@@ -312,7 +313,7 @@ pub fn shot_execution_error(technique: ShotTechnique, base_prob: f32, rng: f32)
     let angle = rng * std::f32::consts::TAU;
     let magnitude = rng * error_scale;
 
-    (angle.cos() * magnitude, angle.sin() * magnitude)
+    (angle.det_cos() * magnitude, angle.det_sin() * magnitude)
 }
 
 /// 선형 보간
@@ -1155,7 +1156,7 @@ pub fn calculate_xg_with_target(
         0.16 + (5.0 - dist) * 0.016
     } else if dist < 35.0 {
         // Exponential decay for realistic distance-based xG
-        0.20 * (-0.14 * dist).exp()
+        0.20 * (-0.14 * dist).det_exp()
     } else {
         // 35m+: negligible
         0.003
@@ -1206,7 +1207,7 @@ fn calculate_goal_angle(shooter_pos: (f32, f32), goal_x: f32) -> f32 {
     }
 
     let cos_angle = (dot / (mag1 * mag2)).clamp(-1.0, 1.0);
-    cos_angle.acos().to_degrees()
+    cos_angle.det_acos().to_degrees()
 }
 
 // ============================================================================
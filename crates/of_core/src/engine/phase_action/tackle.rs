@@ -8,6 +8,7 @@
 //! Outcome → Recovery (0.25~1s) → Cooldown (4s) → Finished
 //! ```
 
+use crate::engine::strict_math::StrictMath;
 use serde::{Deserialize, Serialize};
 // P0: Core types moved to action_queue
 use super::super::action_queue::{
@@ -498,12 +499,12 @@ pub fn calculate_approach_angle(
     let to_tackler_norm = (to_tackler.0 / len, to_tackler.1 / len);
 
     // 타겟의 전방 벡터
-    let target_forward = (target_facing.cos(), target_facing.sin());
+    let target_forward = (target_facing.det_cos(), target_facing.det_sin());
 
     // 내적으로 각도 계산
     let dot = to_tackler_norm.0 * target_forward.0 + to_tackler_norm.1 * target_forward.1;
 
-    dot.clamp(-1.0, 1.0).acos().to_degrees()
+    dot.clamp(-1.0, 1.0).det_acos().to_degrees()
 }
 
 // ============================================================================
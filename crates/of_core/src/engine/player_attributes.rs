@@ -89,8 +89,10 @@ impl MatchEngine {
         self.get_player_attribute(player_idx, |attrs| attrs.concentration as f32, 10.0)
     }
 
+    /// Off the ball, scaled by the side's squad chemistry (`off_ball_mult`)
     pub(crate) fn get_player_off_the_ball(&self, player_idx: usize) -> f32 {
         self.get_player_attribute(player_idx, |attrs| attrs.off_the_ball as f32, 10.0)
+            * self.match_modifiers_for(player_idx).off_ball_mult
     }
 
     pub(crate) fn get_player_positioning(&self, player_idx: usize) -> f32 {
@@ -443,29 +445,25 @@ impl MatchEngine {
     // User Player Helpers
     // ========================================================================
 
-    /// 주인공 선수인지 확인하는 헬퍼 함수
+    /// 주인공 선수인지 확인하는 헬퍼 함수 (co-op 선수 포함)
     pub(crate) fn is_user_player(&self, player_idx: usize, is_home: bool) -> bool {
-        let user_config = match &self.user_player {
-            Some(config) => config,
-            None => return false,
-        };
-
-        // 팀이 맞는지 확인
-        if user_config.is_home_team != is_home {
+        // Safety: callers should pass a consistent (player_idx, is_home) pair.
+        if player_idx < 22 && TeamSide::is_home(player_idx) != is_home {
             return false;
         }
+        self.user_slot_of(player_idx).is_some()
+    }
 
-        // 선수 이름이 맞는지 확인 (SSOT: MatchSetup assignment-aware)
+    /// 유저 슬롯 조회 (0 = 주 선수, 1.. = co-op). 유저 선수가 아니면 None
+    pub(crate) fn user_slot_of(&self, player_idx: usize) -> Option<usize> {
+        let user_config = self.user_player.as_ref()?;
         if player_idx >= 22 {
-            return false;
-        }
-
-        // Safety: callers should pass a consistent (player_idx, is_home) pair.
-        if TeamSide::is_home(player_idx) != is_home {
-            return false;
+            return None;
         }
 
-        self.get_match_player(player_idx).name == user_config.player_name
+        // 선수 이름이 맞는지 확인 (SSOT: MatchSetup assignment-aware)
+        let is_home = TeamSide::is_home(player_idx);
+        user_config.slot_for(is_home, &self.get_match_player(player_idx).name)
     }
 
     /// 선수 객체 직접 접근 헬퍼
@@ -545,7 +543,10 @@ impl MatchEngine {
             0.0
         };
 
-        let final_value = (base + trait_bonus) * (1.0 - fatigue_penalty);
+        // 역할 가중치 (tactics::roles, 역할 없으면 1.0)
+        let role_weight = self.role_profile(player_idx).attribute_weight(stat_type);
+
+        let final_value = (base + trait_bonus) * role_weight * (1.0 - fatigue_penalty);
         final_value.max(1.0) // 최소값 보장
     }
 
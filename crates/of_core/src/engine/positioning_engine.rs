@@ -14,6 +14,7 @@
 //! - Stretch: 넓이 확보 (측면 선수)
 //! - Recycle: 후방에서 빌드업 참여
 
+use crate::engine::strict_math::StrictMath;
 use serde::{Deserialize, Serialize};
 
 use super::offball::types::{OffBallIntent, OffBallObjective, ShapeBias, TacticalPreset};
@@ -512,8 +513,8 @@ impl PositioningEngine {
                     // FIX_2601/0109: Use attacks_right for correct half-aware positioning
                     let support_distance = 12.0;
                     let angle = (i as f32 * 0.7) - 0.35; // 선수마다 다른 각도
-                    let base_dx = angle.cos() * support_distance;
-                    let dy = angle.sin() * support_distance;
+                    let base_dx = angle.det_cos() * support_distance;
+                    let dy = angle.det_sin() * support_distance;
 
                     // Attack direction bias: +5m toward opponent goal
                     let attack_bias = if attacks_right { 5.0 } else { -5.0 };
@@ -0,0 +1,600 @@
+//! RL Environment - Gym-style training wrapper over `MatchEngine`
+//!
+//! One agent controls one player of one team; every other player is driven
+//! by the engine AI. The episode is a full match.
+//!
+//! - `reset(seed)` → observation
+//! - `step(action)` → (observation, reward, done, info)
+//! - Observations reuse the team-view `SimpleVectorObservation` (flat vector)
+//!   or `MiniMapObservation` (CHW planes).
+//! - Rewards are shaped from goals, xG delta and possession (`RewardShaping`).
+//!
+//! The C ABI / JSON mode for Python trainers lives in `api::rl_env_json`.
+//!
+//! ## Usage
+//!
+//! ```rust,ignore
+//! use of_core::engine::rl_env::{RlAction, RlEnv, RlEnvConfig};
+//!
+//! let mut env = RlEnv::new(plan, RlEnvConfig::default())?;
+//! let mut obs = env.reset(Some(42))?;
+//! loop {
+//!     let step = env.step(RlAction::Shoot)?;
+//!     obs = step.observation;
+//!     if step.done {
+//!         break;
+//!     }
+//! }
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use super::live_match::{LiveMatchSession, MatchState};
+use super::match_sim::{
+    MatchPlan, MiniMapObservation, MiniMapSpec, OnBallAction, SimpleVectorObservation,
+    StickyAction, UserCommand, UserCommandPayload,
+};
+use super::reward::{EpisodeMetrics, TerminationReason};
+use crate::models::{EventType, MatchEvent};
+
+// ============================================================================
+// Configuration
+// ============================================================================
+
+/// Observation encoding returned by `reset`/`step`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RlObservationKind {
+    /// `SimpleVectorObservation::to_flat_vector()` (`FLAT_SIZE` floats)
+    #[default]
+    SimpleVector,
+    /// `MiniMapObservation::to_flat_chw()` (4 × height × width floats)
+    MiniMap,
+}
+
+/// Reward weights, all from the agent team's perspective.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RewardShaping {
+    /// Per goal scored (+) / conceded (-)
+    pub goal: f32,
+    /// Per unit of xG created (+) / allowed (-)
+    pub xg_delta: f32,
+    /// Per tick with the ball owned (+) / owned by the opponent (-)
+    pub possession: f32,
+}
+
+impl Default for RewardShaping {
+    /// Scoring-only reward (Google Football `scoring`).
+    fn default() -> Self {
+        Self { goal: 1.0, xg_delta: 0.0, possession: 0.0 }
+    }
+}
+
+/// Environment configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RlEnvConfig {
+    /// Agent plays for the home team
+    pub agent_is_home: bool,
+    /// Controlled player slot within the agent team (0-10)
+    pub controlled_slot: u8,
+    /// Observation encoding
+    pub observation: RlObservationKind,
+    /// Grid size for `RlObservationKind::MiniMap`
+    pub minimap_spec: MiniMapSpec,
+    /// Engine ticks (250ms) advanced per `step` (frame skip)
+    pub ticks_per_step: u32,
+    /// Reward weights
+    pub reward: RewardShaping,
+}
+
+impl Default for RlEnvConfig {
+    fn default() -> Self {
+        Self {
+            agent_is_home: true,
+            controlled_slot: 9,
+            observation: RlObservationKind::SimpleVector,
+            minimap_spec: MiniMapSpec::default(),
+            ticks_per_step: 1,
+            reward: RewardShaping::default(),
+        }
+    }
+}
+
+// ============================================================================
+// Spaces
+// ============================================================================
+
+/// Discrete action set for the controlled player.
+///
+/// On-ball actions only take effect while the controlled player owns the ball;
+/// sticky toggles (sprint/dribble/press) apply immediately.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RlAction {
+    /// Leave the decision to the engine AI
+    Idle,
+    Pass,
+    Shoot,
+    Carry,
+    TakeOn,
+    Hold,
+    Sprint,
+    ReleaseSprint,
+    Dribble,
+    ReleaseDribble,
+    Press,
+    ReleasePress,
+}
+
+impl RlAction {
+    /// All actions in index order
+    pub const ALL: [RlAction; 12] = [
+        RlAction::Idle,
+        RlAction::Pass,
+        RlAction::Shoot,
+        RlAction::Carry,
+        RlAction::TakeOn,
+        RlAction::Hold,
+        RlAction::Sprint,
+        RlAction::ReleaseSprint,
+        RlAction::Dribble,
+        RlAction::ReleaseDribble,
+        RlAction::Press,
+        RlAction::ReleasePress,
+    ];
+
+    /// Action for a discrete index (None = out of range)
+    pub fn from_index(index: u32) -> Option<Self> {
+        Self::ALL.get(index as usize).copied()
+    }
+
+    /// Stable label (matches the serde name)
+    pub fn label(self) -> &'static str {
+        match self {
+            RlAction::Idle => "idle",
+            RlAction::Pass => "pass",
+            RlAction::Shoot => "shoot",
+            RlAction::Carry => "carry",
+            RlAction::TakeOn => "take_on",
+            RlAction::Hold => "hold",
+            RlAction::Sprint => "sprint",
+            RlAction::ReleaseSprint => "release_sprint",
+            RlAction::Dribble => "dribble",
+            RlAction::ReleaseDribble => "release_dribble",
+            RlAction::Press => "press",
+            RlAction::ReleasePress => "release_press",
+        }
+    }
+
+    fn on_ball_action(self) -> Option<OnBallAction> {
+        match self {
+            RlAction::Pass => Some(OnBallAction::Pass),
+            RlAction::Shoot => Some(OnBallAction::Shoot),
+            RlAction::Carry => Some(OnBallAction::Carry),
+            RlAction::TakeOn => Some(OnBallAction::TakeOn),
+            RlAction::Hold => Some(OnBallAction::Hold),
+            _ => None,
+        }
+    }
+
+    fn sticky_toggle(self) -> Option<(StickyAction, bool)> {
+        match self {
+            RlAction::Sprint => Some((StickyAction::Sprint, true)),
+            RlAction::ReleaseSprint => Some((StickyAction::Sprint, false)),
+            RlAction::Dribble => Some((StickyAction::Dribble, true)),
+            RlAction::ReleaseDribble => Some((StickyAction::Dribble, false)),
+            RlAction::Press => Some((StickyAction::Press, true)),
+            RlAction::ReleasePress => Some((StickyAction::Press, false)),
+            _ => None,
+        }
+    }
+}
+
+/// Discrete action space descriptor (`gym.spaces.Discrete`).
+#[derive(Debug, Clone, Serialize)]
+pub struct ActionSpace {
+    pub n: usize,
+    pub actions: Vec<&'static str>,
+}
+
+/// Box observation space descriptor (`gym.spaces.Box`).
+#[derive(Debug, Clone, Serialize)]
+pub struct ObservationSpace {
+    pub kind: RlObservationKind,
+    pub shape: Vec<usize>,
+    pub low: f32,
+    pub high: f32,
+}
+
+impl ObservationSpace {
+    /// Number of floats in one observation
+    pub fn len(&self) -> usize {
+        self.shape.iter().product()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+// ============================================================================
+// Step output
+// ============================================================================
+
+/// Reward split by shaping term (already weighted).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+pub struct RewardBreakdown {
+    pub goal: f32,
+    pub xg_delta: f32,
+    pub possession: f32,
+}
+
+impl RewardBreakdown {
+    pub fn total(&self) -> f32 {
+        self.goal + self.xg_delta + self.possession
+    }
+}
+
+/// Auxiliary per-step information.
+#[derive(Debug, Clone, Serialize)]
+pub struct RlStepInfo {
+    pub tick: u64,
+    pub minute: u8,
+    /// Score (home, away)
+    pub score: (u8, u8),
+    /// Controlled player currently owns the ball
+    pub has_ball: bool,
+    pub reward_breakdown: RewardBreakdown,
+    /// Match events produced during this step
+    pub events: Vec<MatchEvent>,
+    /// Episode summary (only on the final step)
+    pub episode: Option<EpisodeMetrics>,
+}
+
+/// Result of one `step`.
+#[derive(Debug, Clone, Serialize)]
+pub struct RlStep {
+    pub observation: Vec<f32>,
+    pub reward: f32,
+    pub done: bool,
+    pub info: RlStepInfo,
+}
+
+// ============================================================================
+// RlEnv
+// ============================================================================
+
+/// Single-agent training environment over a `LiveMatchSession`.
+pub struct RlEnv {
+    plan: MatchPlan,
+    config: RlEnvConfig,
+    session: Option<LiveMatchSession>,
+    /// Engine event cursor
+    event_cursor: usize,
+    /// xG already rewarded (agent, opponent)
+    xg_seen: (f32, f32),
+    /// Last ball-owning side (true = home)
+    last_owner_home: Option<bool>,
+    command_seq: u32,
+    ticks: u64,
+    metrics: EpisodeMetrics,
+}
+
+impl RlEnv {
+    /// Create an environment; call `reset` before the first `step`.
+    pub fn new(plan: MatchPlan, config: RlEnvConfig) -> Result<Self, String> {
+        if config.controlled_slot > 10 {
+            return Err(format!("controlled_slot {} out of range (0-10)", config.controlled_slot));
+        }
+        if config.ticks_per_step == 0 {
+            return Err("ticks_per_step must be at least 1".to_string());
+        }
+        Ok(Self {
+            plan,
+            config,
+            session: None,
+            event_cursor: 0,
+            xg_seen: (0.0, 0.0),
+            last_owner_home: None,
+            command_seq: 0,
+            ticks: 0,
+            metrics: EpisodeMetrics::new(),
+        })
+    }
+
+    pub fn config(&self) -> &RlEnvConfig {
+        &self.config
+    }
+
+    /// Engine track_id of the controlled player (0-21)
+    pub fn controlled_track_id(&self) -> usize {
+        let offset = if self.config.agent_is_home { 0 } else { 11 };
+        offset + self.config.controlled_slot as usize
+    }
+
+    pub fn action_space(&self) -> ActionSpace {
+        ActionSpace {
+            n: RlAction::ALL.len(),
+            actions: RlAction::ALL.iter().map(|a| a.label()).collect(),
+        }
+    }
+
+    pub fn observation_space(&self) -> ObservationSpace {
+        match self.config.observation {
+            RlObservationKind::SimpleVector => ObservationSpace {
+                kind: RlObservationKind::SimpleVector,
+                shape: vec![SimpleVectorObservation::FLAT_SIZE],
+                low: -1.0,
+                high: 1.0,
+            },
+            RlObservationKind::MiniMap => {
+                let spec = self.config.minimap_spec;
+                ObservationSpace {
+                    kind: RlObservationKind::MiniMap,
+                    shape: vec![
+                        MiniMapObservation::PLANE_COUNT,
+                        spec.height.max(1),
+                        spec.width.max(1),
+                    ],
+                    low: 0.0,
+                    high: 1.0,
+                }
+            }
+        }
+    }
+
+    /// Start a new episode (kick-off) and return the first observation.
+    ///
+    /// `seed` overrides the plan seed; `None` replays the plan seed.
+    pub fn reset(&mut self, seed: Option<u64>) -> Result<Vec<f32>, String> {
+        let mut plan = self.plan.clone();
+        if let Some(seed) = seed {
+            plan.seed = seed;
+        }
+
+        let mut session = LiveMatchSession::new(plan)?;
+        session.set_position_tracking_enabled(false);
+        session.enable_controlled_mode(self.controlled_track_id());
+        session.kick_off();
+
+        self.event_cursor = session.engine.get_events_len();
+        self.xg_seen = (0.0, 0.0);
+        self.last_owner_home = None;
+        self.command_seq = 0;
+        self.ticks = 0;
+        self.metrics.reset();
+
+        let observation = self.observe(&session);
+        self.session = Some(session);
+        Ok(observation)
+    }
+
+    /// Apply `action`, advance `ticks_per_step` ticks and score the transition.
+    pub fn step(&mut self, action: RlAction) -> Result<RlStep, String> {
+        let track_id = self.controlled_track_id();
+        let agent_is_home = self.config.agent_is_home;
+        let shaping = self.config.reward;
+
+        let mut session = self.session.take().ok_or("Environment not reset")?;
+        if session.get_state() == MatchState::Finished {
+            self.session = Some(session);
+            return Err("Episode finished; call reset".to_string());
+        }
+
+        if let Some((sticky, enabled)) = action.sticky_toggle() {
+            session.set_sticky_action(track_id, sticky, enabled)?;
+        }
+        if let Some(on_ball) = action.on_ball_action() {
+            // Queue only when it can be consumed; stale commands would fire later.
+            if session.engine.get_ball_owner() == Some(track_id) {
+                self.command_seq += 1;
+                session.submit_user_command(UserCommand {
+                    seq: self.command_seq,
+                    controlled_track_id: track_id,
+                    payload: UserCommandPayload::OnBallAction {
+                        action: on_ball,
+                        variant: None,
+                        target_track_id: None,
+                    },
+                });
+            }
+        }
+
+        let mut breakdown = RewardBreakdown::default();
+        let mut events = Vec::new();
+        for _ in 0..self.config.ticks_per_step {
+            session.step();
+            self.ticks += 1;
+            if session.get_state() == MatchState::HalfTimeBreak {
+                session.resume_second_half();
+            }
+
+            let mut tick_reward = RewardBreakdown::default();
+
+            let new_events = session.engine.get_events_since(self.event_cursor);
+            self.event_cursor += new_events.len();
+            for event in &new_events {
+                if matches!(event.event_type, EventType::Goal | EventType::OwnGoal) {
+                    // is_home_team = side credited with the goal (own goals included)
+                    self.metrics.record_goal(event.is_home_team);
+                    let sign = if event.is_home_team == agent_is_home { 1.0 } else { -1.0 };
+                    tick_reward.goal += sign * shaping.goal;
+                }
+            }
+            events.extend(new_events);
+
+            let (xg_home, xg_away) = session.engine.get_xg_stats();
+            let xg = if agent_is_home { (xg_home, xg_away) } else { (xg_away, xg_home) };
+            let created = (xg.0 - self.xg_seen.0) - (xg.1 - self.xg_seen.1);
+            tick_reward.xg_delta = created * shaping.xg_delta;
+            self.xg_seen = xg;
+
+            if let Some(owner) = session.engine.get_ball_owner() {
+                let owner_home = owner < 11;
+                self.metrics.record_possession(owner_home);
+                if self.last_owner_home.is_some_and(|last| last != owner_home) {
+                    self.metrics.record_possession_change();
+                }
+                self.last_owner_home = Some(owner_home);
+                let sign = if owner_home == agent_is_home { 1.0 } else { -1.0 };
+                tick_reward.possession = sign * shaping.possession;
+            }
+
+            self.metrics.record_tick(tick_reward.total());
+            breakdown.goal += tick_reward.goal;
+            breakdown.xg_delta += tick_reward.xg_delta;
+            breakdown.possession += tick_reward.possession;
+
+            if session.get_state() == MatchState::Finished {
+                break;
+            }
+        }
+
+        let done = session.get_state() == MatchState::Finished;
+        let episode = done.then(|| {
+            self.metrics.set_termination(TerminationReason::TimeUp);
+            self.metrics.clone()
+        });
+        let info = RlStepInfo {
+            tick: self.ticks,
+            minute: session.get_minute(),
+            score: session.get_score(),
+            has_ball: session.engine.get_ball_owner() == Some(track_id),
+            reward_breakdown: breakdown,
+            events,
+            episode,
+        };
+        let observation = self.observe(&session);
+        self.session = Some(session);
+
+        Ok(RlStep { observation, reward: breakdown.total(), done, info })
+    }
+
+    /// `step` with a discrete action index.
+    pub fn step_index(&mut self, action: u32) -> Result<RlStep, String> {
+        let action = RlAction::from_index(action)
+            .ok_or_else(|| format!("Invalid action {} (n={})", action, RlAction::ALL.len()))?;
+        self.step(action)
+    }
+
+    fn observe(&self, session: &LiveMatchSession) -> Vec<f32> {
+        let is_home = self.config.agent_is_home;
+        match self.config.observation {
+            RlObservationKind::SimpleVector => {
+                session.engine.build_team_view_simple_observation(is_home).to_flat_vector()
+            }
+            RlObservationKind::MiniMap => session
+                .engine
+                .build_team_view_minimap_observation(is_home, self.config.minimap_spec)
+                .to_flat_chw(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::match_plan_from_match_request_v2_json;
+    use serde_json::json;
+
+    fn test_plan() -> MatchPlan {
+        let positions = [
+            "GK", "LB", "CB", "CB", "RB", "LM", "CM", "CM", "RM", "ST", "ST", "GK", "CB", "CM",
+            "CM", "LW", "RW", "ST",
+        ];
+        let team = |prefix: &str| {
+            let roster: Vec<_> = positions
+                .iter()
+                .enumerate()
+                .map(|(i, pos)| {
+                    json!({"name": format!("{prefix}{i}"), "position": pos, "overall": 70, "condition": 3})
+                })
+                .collect();
+            json!({"name": prefix, "formation": "4-4-2", "roster": roster})
+        };
+        let request = json!({
+            "schema_version": 2,
+            "seed": 77,
+            "home_team": team("Home"),
+            "away_team": team("Away"),
+        });
+        match_plan_from_match_request_v2_json(&request.to_string()).unwrap().0
+    }
+
+    #[test]
+    fn test_spaces_match_observation_lengths() {
+        let mut env = RlEnv::new(test_plan(), RlEnvConfig::default()).unwrap();
+        assert_eq!(env.action_space().n, 12);
+        assert_eq!(env.action_space().actions[4], "take_on");
+        assert_eq!(env.reset(Some(1)).unwrap().len(), env.observation_space().len());
+
+        let config = RlEnvConfig {
+            agent_is_home: false,
+            observation: RlObservationKind::MiniMap,
+            minimap_spec: MiniMapSpec { width: 24, height: 16 },
+            ..Default::default()
+        };
+        let mut env = RlEnv::new(test_plan(), config).unwrap();
+        assert_eq!(env.controlled_track_id(), 20);
+        assert_eq!(env.observation_space().shape, vec![4, 16, 24]);
+        let step = env.step(RlAction::Sprint);
+        assert!(step.is_err(), "step before reset must fail");
+        env.reset(None).unwrap();
+        assert_eq!(env.step(RlAction::Sprint).unwrap().observation.len(), 4 * 16 * 24);
+        assert!(RlEnv::new(test_plan(), RlEnvConfig { controlled_slot: 11, ..Default::default() })
+            .is_err());
+    }
+
+    #[test]
+    fn test_same_seed_and_actions_replay_identically() {
+        let config = RlEnvConfig {
+            reward: RewardShaping { goal: 1.0, xg_delta: 0.5, possession: 0.001 },
+            ..Default::default()
+        };
+        let run = || {
+            let mut env = RlEnv::new(test_plan(), config.clone()).unwrap();
+            let mut trace = vec![env.reset(Some(5)).unwrap()];
+            for i in 0..120 {
+                let step = env.step_index(i % 12).unwrap();
+                trace.push(step.observation);
+                trace.push(vec![step.reward]);
+            }
+            trace
+        };
+        assert_eq!(run(), run());
+    }
+
+    #[test]
+    fn test_full_episode_rewards_match_scoreline() {
+        let config = RlEnvConfig {
+            ticks_per_step: 240,
+            reward: RewardShaping { goal: 1.0, xg_delta: 1.0, possession: 0.0 },
+            ..Default::default()
+        };
+        let mut env = RlEnv::new(test_plan(), config).unwrap();
+        env.reset(Some(2024)).unwrap();
+
+        let mut total = RewardBreakdown::default();
+        let last = loop {
+            let step = env.step(RlAction::Idle).unwrap();
+            total.goal += step.info.reward_breakdown.goal;
+            total.xg_delta += step.info.reward_breakdown.xg_delta;
+            if step.done {
+                break step;
+            }
+        };
+
+        let (home, away) = last.info.score;
+        assert_eq!(total.goal, home as f32 - away as f32);
+        let xg = env.session.as_ref().unwrap().engine.get_xg_stats();
+        assert!((total.xg_delta - (xg.0 - xg.1)).abs() < 1e-3);
+
+        let episode = last.info.episode.expect("episode summary on final step");
+        assert_eq!(episode.termination_reason, TerminationReason::TimeUp);
+        assert_eq!(episode.final_score, (home as u32, away as u32));
+        assert_eq!(episode.total_ticks, last.info.tick);
+        assert!(env.step(RlAction::Idle).is_err());
+    }
+}
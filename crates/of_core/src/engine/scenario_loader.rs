@@ -221,12 +221,24 @@ impl ScenarioSpec {
             user_player: None,
             home_match_modifiers: crate::engine::TeamMatchModifiers::default(),
             away_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+            modifier_stack: Default::default(),
             home_instructions: None,
             away_instructions: None,
             home_player_instructions: None,
             away_player_instructions: None,
             home_ai_difficulty: home_spec.difficulty.map(map_ai_difficulty),
             away_ai_difficulty: away_spec.difficulty.map(map_ai_difficulty),
+            pitch: crate::models::pitch::PitchSpec::default(),
+            home_chemistry: None,
+            away_chemistry: None,
+            stadium: None,
+            conditions: None,
+            penalty_shootout: None,
+            home_custom_formation: None,
+            away_custom_formation: None,
+            home_set_piece_takers: None,
+            away_set_piece_takers: None,
+            referee: None,
         };
 
         let mut player_positions = Vec::new();
@@ -528,6 +540,8 @@ fn make_player(
         equipped_skills: Vec::new(),
         traits: TraitSlots::default(),
         personality: PersonalityArchetype::default(),
+        morale: crate::player::morale::NEUTRAL_MORALE,
+        form: crate::player::morale::NEUTRAL_FORM,
     })
 }
 
@@ -0,0 +1,386 @@
+//! Scripted match situations (tutorials and challenge modes)
+//!
+//! A `ScriptedScenario` starts a `LiveMatchSession` mid-match: scoreline and
+//! minute come from the scenario, teams / ball / player placements reuse the
+//! `scenario_loader` format. Objectives ("hold the lead for 10 minutes") are
+//! evaluated from the streamed ticks, so pass/fail is decided in of_core and
+//! the UI only reads the `ObjectiveReport`.
+//!
+//! Placements are absolute pitch meters. Attack direction follows the half
+//! (home attacks right before 45'), so `home_attacks_right` is ignored here.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use super::live_match::{LiveMatchSession, StepResult};
+use super::scenario_loader::{ScenarioSide, ScenarioSpec};
+
+/// Upper bound on objectives per scenario (HUD checklist size)
+pub const MAX_SCENARIO_OBJECTIVES: usize = 8;
+
+/// Goal of a scripted situation, seen from the user's side
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ScenarioObjective {
+    /// Still ahead after `minutes` (fails as soon as the lead is gone)
+    HoldLead { minutes: u8 },
+    /// Score `goals` more within `within_minutes` (None = until full time)
+    ScoreGoals {
+        goals: u8,
+        #[serde(default)]
+        within_minutes: Option<u8>,
+    },
+    /// Concede nothing for `minutes` (None = until full time)
+    KeepCleanSheet {
+        #[serde(default)]
+        minutes: Option<u8>,
+    },
+    /// Ahead at full time
+    WinMatch,
+    /// Not behind at full time
+    AvoidDefeat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ObjectiveStatus {
+    Pending,
+    Passed,
+    Failed,
+}
+
+/// One objective with its current status
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ObjectiveProgress {
+    pub objective: ScenarioObjective,
+    pub status: ObjectiveStatus,
+    /// Match time the status was decided
+    pub decided_at_ms: Option<u64>,
+}
+
+/// Scenario checklist: passed once every objective passed, failed on the first failure
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ObjectiveReport {
+    pub scenario_id: String,
+    pub outcome: ObjectiveStatus,
+    pub objectives: Vec<ObjectiveProgress>,
+}
+
+/// Mid-match scenario definition (JSON)
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScriptedScenario {
+    /// Teams, ball and placements (`actions` / `assertions` are not used here)
+    #[serde(flatten)]
+    pub setup: ScenarioSpec,
+    #[serde(default)]
+    pub title: Option<String>,
+    pub start_minute: u8,
+    /// Scoreline at `start_minute` (home, away)
+    #[serde(default)]
+    pub score: (u8, u8),
+    #[serde(default = "default_user_side")]
+    pub user_side: ScenarioSide,
+    pub objectives: Vec<ScenarioObjective>,
+}
+
+fn default_user_side() -> ScenarioSide {
+    ScenarioSide::Home
+}
+
+impl ScriptedScenario {
+    pub fn load_from_path(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let raw = std::fs::read_to_string(path)
+            .map_err(|err| format!("Failed to read scenario {}: {}", path.display(), err))?;
+        Self::from_json(&raw)
+            .map_err(|err| format!("Failed to parse scenario {}: {}", path.display(), err))
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let scenario: Self = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        scenario.validate()?;
+        Ok(scenario)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.start_minute >= 90 {
+            return Err(format!("Start minute {} must be before 90", self.start_minute));
+        }
+        if self.objectives.is_empty() {
+            return Err("Scenario needs at least one objective".to_string());
+        }
+        if self.objectives.len() > MAX_SCENARIO_OBJECTIVES {
+            return Err(format!(
+                "Too many objectives: {} (max {})",
+                self.objectives.len(),
+                MAX_SCENARIO_OBJECTIVES
+            ));
+        }
+        let (ours, theirs) = user_score(self.score, self.user_side.is_home());
+        for objective in &self.objectives {
+            match objective {
+                ScenarioObjective::HoldLead { minutes: 0 } => {
+                    return Err("hold_lead needs at least one minute".to_string());
+                }
+                ScenarioObjective::HoldLead { .. } if ours <= theirs => {
+                    return Err("hold_lead requires the user side to start ahead".to_string());
+                }
+                ScenarioObjective::ScoreGoals { goals: 0, .. } => {
+                    return Err("score_goals needs at least one goal".to_string());
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Build the live session at the scripted moment, ready to `step()`
+    pub fn start(&self) -> Result<ScriptedSession, String> {
+        self.validate()?;
+        let scenario_plan = self.setup.to_plan()?;
+        let mut overrides = scenario_plan.overrides;
+        overrides.home_attacks_right = None;
+
+        let mut session = LiveMatchSession::new(scenario_plan.plan)?;
+        session.kick_off_from_situation(self.start_minute, self.score, &overrides)?;
+
+        Ok(ScriptedSession {
+            session,
+            tracker: ObjectiveTracker::new(
+                &self.setup.id,
+                &self.objectives,
+                self.start_minute,
+                self.score,
+                self.user_side.is_home(),
+            ),
+        })
+    }
+}
+
+/// Live session plus objective evaluation
+pub struct ScriptedSession {
+    pub session: LiveMatchSession,
+    tracker: ObjectiveTracker,
+}
+
+impl ScriptedSession {
+    /// Step the session and update objectives from the result
+    pub fn step(&mut self) -> StepResult {
+        let result = self.session.step();
+        match &result {
+            StepResult::Tick(tick) => self.tracker.observe(tick.timestamp_ms, tick.score),
+            StepResult::FullTime(full_time) => self.tracker.finish(
+                full_time.result.score_home,
+                full_time.result.score_away,
+                self.session.engine.get_current_timestamp_ms(),
+            ),
+            StepResult::NotStarted | StepResult::HalfTime(_) => {}
+        }
+        result
+    }
+
+    pub fn report(&self) -> ObjectiveReport {
+        self.tracker.report()
+    }
+
+    /// Scenario decided (UI can stop stepping)
+    pub fn is_decided(&self) -> bool {
+        self.tracker.outcome() != ObjectiveStatus::Pending
+    }
+}
+
+/// Evaluates objectives against the running score and clock
+#[derive(Debug, Clone)]
+pub struct ObjectiveTracker {
+    scenario_id: String,
+    progress: Vec<ObjectiveProgress>,
+    start_ms: u64,
+    /// User side's (scored, conceded) at the start
+    start_score: (u8, u8),
+    user_is_home: bool,
+}
+
+impl ObjectiveTracker {
+    pub fn new(
+        scenario_id: &str,
+        objectives: &[ScenarioObjective],
+        start_minute: u8,
+        start_score: (u8, u8),
+        user_is_home: bool,
+    ) -> Self {
+        Self {
+            scenario_id: scenario_id.to_string(),
+            progress: objectives
+                .iter()
+                .map(|objective| ObjectiveProgress {
+                    objective: objective.clone(),
+                    status: ObjectiveStatus::Pending,
+                    decided_at_ms: None,
+                })
+                .collect(),
+            start_ms: start_minute as u64 * 60_000,
+            start_score: user_score(start_score, user_is_home),
+            user_is_home,
+        }
+    }
+
+    /// Fold one tick (match time, home/away score) into pending objectives
+    pub fn observe(&mut self, timestamp_ms: u64, score: (u8, u8)) {
+        let (ours, theirs) = user_score(score, self.user_is_home);
+        let scored = ours.saturating_sub(self.start_score.0);
+        let conceded = theirs.saturating_sub(self.start_score.1);
+        let start_ms = self.start_ms;
+        let deadline = |minutes: u8| start_ms + minutes as u64 * 60_000;
+
+        for entry in self.progress.iter_mut().filter(|p| p.status == ObjectiveStatus::Pending) {
+            let status = match entry.objective {
+                ScenarioObjective::HoldLead { .. } if ours <= theirs => ObjectiveStatus::Failed,
+                ScenarioObjective::HoldLead { minutes } if timestamp_ms >= deadline(minutes) => {
+                    ObjectiveStatus::Passed
+                }
+                ScenarioObjective::ScoreGoals { goals, .. } if scored >= goals => {
+                    ObjectiveStatus::Passed
+                }
+                ScenarioObjective::ScoreGoals { within_minutes: Some(minutes), .. }
+                    if timestamp_ms >= deadline(minutes) =>
+                {
+                    ObjectiveStatus::Failed
+                }
+                ScenarioObjective::KeepCleanSheet { .. } if conceded > 0 => ObjectiveStatus::Failed,
+                ScenarioObjective::KeepCleanSheet { minutes: Some(minutes) }
+                    if timestamp_ms >= deadline(minutes) =>
+                {
+                    ObjectiveStatus::Passed
+                }
+                _ => ObjectiveStatus::Pending,
+            };
+            if status != ObjectiveStatus::Pending {
+                entry.status = status;
+                entry.decided_at_ms = Some(timestamp_ms);
+            }
+        }
+    }
+
+    /// Resolve everything still pending at full time
+    pub fn finish(&mut self, score_home: u8, score_away: u8, timestamp_ms: u64) {
+        self.observe(timestamp_ms, (score_home, score_away));
+        let (ours, theirs) = user_score((score_home, score_away), self.user_is_home);
+
+        for entry in self.progress.iter_mut().filter(|p| p.status == ObjectiveStatus::Pending) {
+            // Time-boxed objectives still pending here ran out of match before their deadline.
+            let passed = match entry.objective {
+                ScenarioObjective::HoldLead { .. } | ScenarioObjective::WinMatch => ours > theirs,
+                ScenarioObjective::ScoreGoals { .. } => false,
+                ScenarioObjective::KeepCleanSheet { .. } => true,
+                ScenarioObjective::AvoidDefeat => ours >= theirs,
+            };
+            entry.status = if passed { ObjectiveStatus::Passed } else { ObjectiveStatus::Failed };
+            entry.decided_at_ms = Some(timestamp_ms);
+        }
+    }
+
+    pub fn outcome(&self) -> ObjectiveStatus {
+        if self.progress.iter().any(|p| p.status == ObjectiveStatus::Failed) {
+            ObjectiveStatus::Failed
+        } else if self.progress.iter().all(|p| p.status == ObjectiveStatus::Passed) {
+            ObjectiveStatus::Passed
+        } else {
+            ObjectiveStatus::Pending
+        }
+    }
+
+    pub fn report(&self) -> ObjectiveReport {
+        ObjectiveReport {
+            scenario_id: self.scenario_id.clone(),
+            outcome: self.outcome(),
+            objectives: self.progress.clone(),
+        }
+    }
+}
+
+/// (user, opponent) goals from a (home, away) score
+fn user_score(score: (u8, u8), user_is_home: bool) -> (u8, u8) {
+    if user_is_home {
+        score
+    } else {
+        (score.1, score.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::live_match::MatchState;
+
+    const MINUTE_MS: u64 = 60_000;
+
+    #[test]
+    fn test_objective_tracker_decides_on_score_and_clock() {
+        let objectives = [
+            ScenarioObjective::HoldLead { minutes: 10 },
+            ScenarioObjective::ScoreGoals { goals: 1, within_minutes: Some(5) },
+            ScenarioObjective::KeepCleanSheet { minutes: None },
+        ];
+        // User is away, leading 1-0 at 80'.
+        let mut tracker = ObjectiveTracker::new("t", &objectives, 80, (0, 1), false);
+
+        tracker.observe(82 * MINUTE_MS, (0, 2));
+        let report = tracker.report();
+        assert_eq!(report.objectives[1].status, ObjectiveStatus::Passed);
+        assert_eq!(report.objectives[1].decided_at_ms, Some(82 * MINUTE_MS));
+        assert_eq!(report.outcome, ObjectiveStatus::Pending);
+
+        tracker.observe(90 * MINUTE_MS, (0, 2));
+        assert_eq!(tracker.report().objectives[0].status, ObjectiveStatus::Passed);
+
+        tracker.finish(0, 2, 93 * MINUTE_MS);
+        assert_eq!(tracker.outcome(), ObjectiveStatus::Passed);
+
+        let hold = [ScenarioObjective::HoldLead { minutes: 10 }];
+        let mut lost_lead = ObjectiveTracker::new("t", &hold, 80, (1, 0), true);
+        lost_lead.observe(84 * MINUTE_MS, (1, 1));
+        assert_eq!(lost_lead.outcome(), ObjectiveStatus::Failed);
+    }
+
+    fn team_json(side: &str, mirror: bool) -> serde_json::Value {
+        const ROLES: [&str; 11] =
+            ["GK", "LB", "CB", "CB", "RB", "LM", "CM", "CM", "RM", "ST", "ST"];
+        let players: Vec<_> = ROLES
+            .iter()
+            .enumerate()
+            .map(|(slot, role)| {
+                let x = 5.0 + slot as f32 * 4.0;
+                let x = if mirror { 105.0 - x } else { x };
+                serde_json::json!({ "role": role, "slot": slot, "pos_m": [x, 10.0 + slot as f32 * 4.0] })
+            })
+            .collect();
+        serde_json::json!({ "side": side, "players": players })
+    }
+
+    #[test]
+    fn test_scripted_scenario_starts_mid_match() {
+        let mut json = serde_json::json!({
+            "id": "hold_the_lead",
+            "seed": 7,
+            "teams": [team_json("home", true), team_json("away", false)],
+            "start_minute": 80,
+            "score": [1, 0],
+            "objectives": [{ "type": "hold_lead", "minutes": 2 }, { "type": "avoid_defeat" }]
+        });
+        let scenario = ScriptedScenario::from_json(&json.to_string()).unwrap();
+        let mut scripted = scenario.start().unwrap();
+        assert_eq!(scripted.session.get_state(), MatchState::SecondHalf);
+        assert_eq!(scripted.session.get_minute(), 80);
+        assert_eq!(scripted.session.get_score(), (1, 0));
+
+        while !matches!(scripted.step(), StepResult::FullTime(_)) {}
+        let report = scripted.report();
+        assert_eq!(report.scenario_id, "hold_the_lead");
+        assert_ne!(report.outcome, ObjectiveStatus::Pending);
+        assert!(scripted.is_decided());
+
+        json["score"] = serde_json::json!([0, 0]);
+        assert!(ScriptedScenario::from_json(&json.to_string()).is_err());
+    }
+}
@@ -12,6 +12,7 @@
 //! - Pressing triggers (support + zone + opponent space quality)
 //! - Zone escape (when current zone exceeds density threshold)
 
+use crate::engine::strict_math::StrictMath;
 use crate::engine::core_context::CoreContext;
 use crate::engine::match_sim::quality_metrics::FieldZone;
 use crate::engine::operations::{PlayerDistanceMatrix, SpaceAnalysis};
@@ -997,8 +998,8 @@ mod tests {
             let angle = (i as f32) * 0.3;
             let dist = 1.0 + (i as f32 * 0.1); // 1.0 - 3.1m
             positions[i] = Coord10::from_meters(
-                center_x + dist * angle.cos(),
-                center_y + dist * angle.sin(),
+                center_x + dist * angle.det_cos(),
+                center_y + dist * angle.det_sin(),
             );
         }
 
@@ -1102,8 +1103,8 @@ mod tests {
             let angle = (i as f32) * 0.3;
             let dist = 1.0 + (i as f32 * 0.1); // 1.0 - 3.1m
             positions[i] = Coord10::from_meters(
-                center_x + dist * angle.cos(),
-                center_y + dist * angle.sin(),
+                center_x + dist * angle.det_cos(),
+                center_y + dist * angle.det_sin(),
             );
         }
 
@@ -203,6 +203,9 @@ pub struct ActionQueueSnapshot {
     pub last_shot_xg: Option<f32>,
     /// Last shooter index
     pub last_shooter_idx: Option<usize>,
+    /// Last shot xG features (shooter index, features)
+    #[serde(default)]
+    pub last_shot_features: Option<(usize, crate::engine::xg_model::XgFeatures)>,
     /// Last passer index (for assist tracking)
     pub last_passer_idx: Option<usize>,
     /// Last pass receiver index (for pass success tracking)
@@ -210,6 +213,9 @@ pub struct ActionQueueSnapshot {
     /// Last pass type (for cross/through tracking)
     #[serde(default)]
     pub last_pass_type: Option<crate::engine::action_queue::PassType>,
+    /// Last pass reception (receiver index, pass type) for shot assist typing
+    #[serde(default)]
+    pub last_reception: Option<(usize, crate::engine::action_queue::PassType)>,
     /// Last header outcome (ephemeral)
     #[serde(default)]
     pub last_header_outcome: Option<crate::engine::action_queue::HeaderOutcome>,
@@ -1,6 +1,7 @@
 use crate::engine::physics_constants::field;
 use crate::models::{HeatMapPoint, MatchPositionData, MatchResult, Statistics};
 
+#[derive(Clone)]
 pub struct StatsCalculator {
     // Configuration for statistics
     pub base_passes_per_minute: f32,
@@ -157,6 +158,17 @@ impl StatsCalculator {
             }
         }
 
+        // Ground duels: a tackle or take-on attempt is won by exactly one side
+        let stats = &mut result.statistics;
+        let tackles_lost_home = stats.tackle_attempts_home.saturating_sub(stats.tackles_home);
+        let tackles_lost_away = stats.tackle_attempts_away.saturating_sub(stats.tackles_away);
+        let take_ons_lost_home = stats.take_on_attempts_home.saturating_sub(stats.take_ons_home);
+        let take_ons_lost_away = stats.take_on_attempts_away.saturating_sub(stats.take_ons_away);
+        stats.duels_won_home =
+            stats.tackles_home + stats.take_ons_home + tackles_lost_away + take_ons_lost_away;
+        stats.duels_won_away =
+            stats.tackles_away + stats.take_ons_away + tackles_lost_home + take_ons_lost_home;
+
         // Ensure possession sums to 100
         let total_poss = result.statistics.possession_home + result.statistics.possession_away;
         if (total_poss - 100.0).abs() > 0.1 {
@@ -223,10 +235,11 @@ impl StatsCalculator {
     }
 
     fn calculate_heat_maps(&self, stats: &mut Statistics, pos_data: &MatchPositionData) {
-        use std::collections::HashMap;
+        use std::collections::BTreeMap;
 
-        let mut position_counts_home: HashMap<(i32, i32), u32> = HashMap::new();
-        let mut position_counts_away: HashMap<(i32, i32), u32> = HashMap::new();
+        // BTreeMap: heat map points come out in grid order, identical across runs
+        let mut position_counts_home: BTreeMap<(i32, i32), u32> = BTreeMap::new();
+        let mut position_counts_away: BTreeMap<(i32, i32), u32> = BTreeMap::new();
 
         // Grid resolution: 5m x 5m
         const GRID_SIZE: f32 = 5.0;
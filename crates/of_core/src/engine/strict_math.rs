@@ -0,0 +1,258 @@
+//! Strict cross-platform float determinism
+//!
+//! IEEE 754 fixes the result of `+ - * /` and `sqrt`, but not of transcendental
+//! functions: `f32::sin`/`exp`/`ln`/`powf`/... call the platform libm (glibc on
+//! x86_64 Linux, Apple libm on ARM macOS/iOS, the JS engine on WASM), which can
+//! differ in the last ulp. Over a 90-minute match one ulp in a softmax weight is
+//! enough to flip a decision, so the same seed can produce different matches on
+//! different devices.
+//!
+//! With the `strict_determinism` feature:
+//! - [`StrictMath`] routes trig/exp/ln/pow/sqrt through the pure-Rust `libm`
+//!   crate (same code, same bits on every target)
+//! - [`FixedAccum`] / [`strict_sum`] accumulate probability mass in fixed point,
+//!   so roulette-wheel totals do not depend on float rounding
+//!
+//! Without the feature both fall through to `std` (no cost).
+//!
+//! Recorded replays carry a per-tick state checksum trail
+//! (`ReplayDoc.determinism`); `ReplayDeterminism::first_divergence` names the
+//! first tick at which two builds disagree.
+//!
+//! ## Usage
+//! ```ignore
+//! use crate::engine::strict_math::StrictMath;
+//!
+//! let w = (score / t).det_exp();
+//! let angle = dy.det_atan2(dx);
+//! ```
+
+/// True when built with the `strict_determinism` feature
+pub const STRICT_DETERMINISM: bool = cfg!(feature = "strict_determinism");
+
+/// Target tag recorded next to golden hashes (`x86_64`, `aarch64`, `wasm32`, ...)
+pub fn platform_tag() -> &'static str {
+    std::env::consts::ARCH
+}
+
+// ============================================================================
+// Transcendental functions
+// ============================================================================
+
+/// Float functions that are bit-identical across platforms in strict mode.
+///
+/// Method names mirror `std` with a `det_` prefix; call sites on engine
+/// sensitive paths use these instead of the inherent methods.
+pub trait StrictMath: Copy {
+    fn det_sin(self) -> Self;
+    fn det_cos(self) -> Self;
+    fn det_atan(self) -> Self;
+    fn det_atan2(self, other: Self) -> Self;
+    fn det_acos(self) -> Self;
+    fn det_exp(self) -> Self;
+    fn det_ln(self) -> Self;
+    fn det_powf(self, n: Self) -> Self;
+    fn det_tanh(self) -> Self;
+    fn det_hypot(self, other: Self) -> Self;
+    fn det_sqrt(self) -> Self;
+}
+
+macro_rules! impl_strict_math {
+    ($t:ty, { $($name:ident => $std:ident / $libm:ident ($($arg:ident),*);)* }) => {
+        impl StrictMath for $t {
+            $(
+                #[inline]
+                fn $name(self $(, $arg: Self)*) -> Self {
+                    #[cfg(feature = "strict_determinism")]
+                    {
+                        libm::$libm(self $(, $arg)*)
+                    }
+                    #[cfg(not(feature = "strict_determinism"))]
+                    {
+                        <$t>::$std(self $(, $arg)*)
+                    }
+                }
+            )*
+        }
+    };
+}
+
+impl_strict_math!(f32, {
+    det_sin => sin / sinf();
+    det_cos => cos / cosf();
+    det_atan => atan / atanf();
+    det_atan2 => atan2 / atan2f(other);
+    det_acos => acos / acosf();
+    det_exp => exp / expf();
+    det_ln => ln / logf();
+    det_powf => powf / powf(n);
+    det_tanh => tanh / tanhf();
+    det_hypot => hypot / hypotf(other);
+    det_sqrt => sqrt / sqrtf();
+});
+
+impl_strict_math!(f64, {
+    det_sin => sin / sin();
+    det_cos => cos / cos();
+    det_atan => atan / atan();
+    det_atan2 => atan2 / atan2(other);
+    det_acos => acos / acos();
+    det_exp => exp / exp();
+    det_ln => ln / log();
+    det_powf => powf / pow(n);
+    det_tanh => tanh / tanh();
+    det_hypot => hypot / hypot(other);
+    det_sqrt => sqrt / sqrt();
+});
+
+// ============================================================================
+// Fixed-point accumulation
+// ============================================================================
+
+/// Q40.24 fixed-point accumulator.
+///
+/// 24 fractional bits match the f32 mantissa; the integer part covers the
+/// weight/utility ranges used in the engine (|sum| < 5e11).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FixedAccum {
+    raw: i64,
+}
+
+impl FixedAccum {
+    pub const FRAC_BITS: u32 = 24;
+    const SCALE: f64 = (1u64 << Self::FRAC_BITS) as f64;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a value (rounded to the nearest 2^-24; NaN adds nothing)
+    #[inline]
+    pub fn add(&mut self, value: f32) {
+        let raw = (value as f64 * Self::SCALE).round() as i64;
+        self.raw = self.raw.saturating_add(raw);
+    }
+
+    #[inline]
+    pub fn to_f32(self) -> f32 {
+        (self.raw as f64 / Self::SCALE) as f32
+    }
+}
+
+/// Sum of `values`: fixed point in strict mode, plain f32 sum otherwise
+pub fn strict_sum<I: IntoIterator<Item = f32>>(values: I) -> f32 {
+    if STRICT_DETERMINISM {
+        let mut acc = FixedAccum::new();
+        for v in values {
+            acc.add(v);
+        }
+        acc.to_f32()
+    } else {
+        values.into_iter().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strict_math_matches_std_closely() {
+        for &x in &[-3.0f32, -0.5, 0.0, 0.25, 1.0, 2.5, 7.0] {
+            assert!((x.det_sin() - x.sin()).abs() < 1e-6);
+            assert!((x.det_cos() - x.cos()).abs() < 1e-6);
+            assert!((x.det_exp() - x.exp()).abs() <= x.exp() * 1e-6);
+            assert!((x.det_tanh() - x.tanh()).abs() < 1e-6);
+            assert!((x.det_atan2(1.5) - x.atan2(1.5)).abs() < 1e-6);
+        }
+        assert!((2.0f32.det_ln() - std::f32::consts::LN_2).abs() < 1e-6);
+        assert_eq!(9.0f32.det_sqrt(), 3.0);
+        assert!((0.3f64.det_powf(1.2) - 0.3f64.powf(1.2)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_fixed_accum_is_order_independent() {
+        let values = [0.1f32, 1e-4, 0.7, 3.25, 1e-3, 0.333];
+        let forward = strict_sum(values.iter().copied());
+        let backward = strict_sum(values.iter().rev().copied());
+        assert!((forward - 4.3841).abs() < 1e-4, "{forward}");
+
+        let mut a = FixedAccum::new();
+        let mut b = FixedAccum::new();
+        values.iter().for_each(|&v| a.add(v));
+        values.iter().rev().for_each(|&v| b.add(v));
+        assert_eq!(a, b);
+        if STRICT_DETERMINISM {
+            assert_eq!(forward.to_bits(), backward.to_bits());
+        }
+    }
+
+    /// Golden `ResultAttestation::result_hash` per (platform, seed) in strict mode.
+    ///
+    /// Strict mode promises identical hashes on every target, so a platform
+    /// without its own rows is checked against the rows of the others. Add rows
+    /// from each target's CI run (`cargo test --features strict_determinism`);
+    /// re-bless all rows when engine behaviour changes on purpose.
+    const GOLDEN_RESULT_HASHES: &[(&str, u64, &str)] = &[
+        ("x86_64", 11, "b17800b9c21ea54ed220adb82daf2e16e51944a67efd8f8463d61bb51b3abe61"),
+        ("x86_64", 2026, "dc242745ef1c1e76f44a9f19a41487370e6d320387bf9a6f29231ec477781675"),
+    ];
+
+    fn golden_request(seed: u64) -> serde_json::Value {
+        let positions = [
+            "GK", "LB", "CB", "CB", "RB", "LM", "CM", "CM", "RM", "ST", "ST", "GK", "CB", "CM",
+            "CM", "LW", "RW", "ST",
+        ];
+        let team = |prefix: &str| {
+            let roster: Vec<_> = positions
+                .iter()
+                .enumerate()
+                .map(|(i, pos)| {
+                    serde_json::json!({"name": format!("{prefix}{i}"), "position": pos, "overall": 65 + i % 10, "condition": 3})
+                })
+                .collect();
+            serde_json::json!({"name": prefix, "formation": "4-4-2", "roster": roster})
+        };
+        serde_json::json!({
+            "schema_version": 2,
+            "seed": seed,
+            "home_team": team("H"),
+            "away_team": team("A")
+        })
+    }
+
+    fn result_hash(seed: u64) -> String {
+        use crate::api::attestation::{attest_result, simulate_for_attestation};
+
+        let request = golden_request(seed);
+        let result = simulate_for_attestation(&request).unwrap();
+        assert_eq!(result.determinism.float_math, crate::models::FloatMathMode::current());
+        attest_result(&request, &result).unwrap().result_hash
+    }
+
+    #[test]
+    #[cfg(feature = "strict_determinism")]
+    fn test_strict_mode_matches_golden_hashes() {
+        let seeds: std::collections::BTreeSet<u64> =
+            GOLDEN_RESULT_HASHES.iter().map(|&(_, seed, _)| seed).collect();
+        for seed in seeds {
+            let rows: Vec<_> =
+                GOLDEN_RESULT_HASHES.iter().filter(|&&(_, s, _)| s == seed).collect();
+            assert!(
+                rows.windows(2).all(|w| w[0].2 == w[1].2),
+                "golden hashes disagree across platforms for seed {seed}: {rows:?}"
+            );
+            let expected = rows
+                .iter()
+                .find(|&&&(platform, _, _)| platform == platform_tag())
+                .unwrap_or(&rows[0])
+                .2;
+            assert_eq!(result_hash(seed), expected, "seed {seed} on {}", platform_tag());
+        }
+    }
+
+    #[test]
+    fn test_same_seed_same_hash() {
+        assert_eq!(result_hash(11), result_hash(11));
+    }
+}
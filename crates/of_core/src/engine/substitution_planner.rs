@@ -0,0 +1,597 @@
+//! Substitution planner (preview only)
+//!
+//! Given a snapshot of one team's on-pitch stamina and bench, recommends
+//! substitutions with the expected stamina impact and a short tactical
+//! reason, so the UI can present options at half-time before the user
+//! commits via `substitute()`.
+//!
+//! `advise_substitutions` widens the net beyond fatigue: booked players
+//! (second-yellow risk) and players out of shape for their slot are ranked
+//! alongside tired ones, so the UI can coach without its own heuristics.
+//!
+//! Nothing here mutates the match: the plan is advisory and deterministic
+//! (same snapshot + policy → same plan).
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::player::Position;
+
+/// Substitutions allowed per team (matches `substitute_player`)
+pub const MAX_SUBSTITUTIONS: u8 = 5;
+/// Assumed regulation length when projecting stamina to full time
+const FULL_TIME_MINUTE: f32 = 90.0;
+/// Minute from which a trailing/leading scoreline changes the advice
+const GAME_STATE_MINUTE: u8 = 55;
+/// Position suitability below which a player counts as out of position
+const MISMATCH_SUITABILITY: f32 = 0.8;
+/// Urgency of a booked player (defensive roles tackle more, so risk more)
+const CARD_RISK: f32 = 0.4;
+const CARD_RISK_DEFENSIVE: f32 = 0.15;
+/// Urgency floor when the formation slot asks for an incompatible position
+const SLOT_MISMATCH: f32 = 0.5;
+
+/// How aggressively to recommend changes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SubstitutionPolicy {
+    /// Maximum recommendations returned
+    pub max_suggestions: u8,
+    /// Fatigue (1 - stamina) above which a player is considered for replacement
+    pub fatigue_threshold: f32,
+    /// Keep one substitution in reserve for injuries
+    pub keep_injury_reserve: bool,
+    /// React to the scoreline (attacking subs when trailing, defensive when leading)
+    pub use_game_state: bool,
+}
+
+impl Default for SubstitutionPolicy {
+    fn default() -> Self {
+        Self {
+            max_suggestions: 3,
+            fatigue_threshold: 0.35,
+            keep_injury_reserve: true,
+            use_game_state: true,
+        }
+    }
+}
+
+/// On-pitch player as seen by the planner
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlannerPitchPlayer {
+    /// Team-local slot (0-10); `out_idx` for `substitute()`
+    pub slot: u8,
+    pub track_id: u8,
+    pub name: String,
+    pub position: Position,
+    /// Current stamina (0..1, 1 = fresh)
+    pub stamina: f32,
+    /// Stamina attribute (0-100)
+    pub stamina_attr: u8,
+    /// Sent off / injured players cannot be chosen
+    #[serde(default = "default_true")]
+    pub available: bool,
+    /// Yellow cards shown so far this match
+    #[serde(default)]
+    pub yellow_cards: u8,
+    /// Fit for the slot played (1.0 = natural position)
+    #[serde(default = "default_suitability")]
+    pub position_suitability: f32,
+    /// Position the current formation asks of this slot
+    #[serde(default)]
+    pub slot_position: Option<Position>,
+}
+
+/// Bench player as seen by the planner
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlannerBenchPlayer {
+    /// Bench slot (0-6); `in_idx = 11 + bench_slot` for `substitute()`
+    pub bench_slot: u8,
+    pub name: String,
+    pub position: Position,
+    pub overall: u8,
+    /// Stamina attribute (0-100)
+    pub stamina_attr: u8,
+    /// Already used (cannot re-enter)
+    #[serde(default)]
+    pub used: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_suitability() -> f32 {
+    1.0
+}
+
+/// Planner input: one team's situation at the current minute
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubstitutionSquadState {
+    pub is_home: bool,
+    pub minute: u8,
+    /// Own goals minus opponent goals
+    pub goal_diff: i8,
+    pub substitutions_made: u8,
+    pub on_pitch: Vec<PlannerPitchPlayer>,
+    pub bench: Vec<PlannerBenchPlayer>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PositionFit {
+    Exact,
+    SameLine,
+    OutOfPosition,
+}
+
+/// One recommended change
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubstitutionRecommendation {
+    /// `substitute()` arguments
+    pub out_idx: u8,
+    pub in_idx: u8,
+    pub out_track_id: u8,
+    pub out_name: String,
+    pub in_name: String,
+    pub out_stamina: f32,
+    /// Projected stamina at full time if the player stays on
+    pub projected_stamina_if_kept: f32,
+    /// Projected stamina of the substitute at full time
+    pub projected_stamina_of_sub: f32,
+    pub position_fit: PositionFit,
+    /// e.g. "Chasing the game: fresh forward for a tiring midfielder"
+    pub reasoning: String,
+}
+
+/// Planner output
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubstitutionPlan {
+    pub minute: u8,
+    pub substitutions_remaining: u8,
+    pub recommendations: Vec<SubstitutionRecommendation>,
+}
+
+/// Why a player is worth replacing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AdviceReason {
+    Fatigue,
+    /// Booked: a second yellow would leave the team short
+    CardRisk,
+    /// Out of position for the slot (low suitability or formation change)
+    TacticalMismatch,
+}
+
+/// One ranked suggestion from `advise_substitutions`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubstitutionSuggestion {
+    /// 1 = most urgent
+    pub rank: u8,
+    /// Combined weight of the reasons (higher = more urgent)
+    pub urgency: f32,
+    /// Strongest reason first
+    pub reasons: Vec<AdviceReason>,
+    #[serde(flatten)]
+    pub recommendation: SubstitutionRecommendation,
+}
+
+/// Advisor output
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubstitutionAdvice {
+    pub minute: u8,
+    pub substitutions_remaining: u8,
+    pub suggestions: Vec<SubstitutionSuggestion>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GameState {
+    Neutral,
+    Chasing,
+    Protecting,
+}
+
+/// Recommend substitutions for `state` under `policy`.
+pub fn plan_substitutions(
+    state: &SubstitutionSquadState,
+    policy: &SubstitutionPolicy,
+) -> SubstitutionPlan {
+    let (remaining, budget) = budget(state, policy);
+    let game_state = game_state(state, policy);
+
+    // Most tired first; GK only when exhausted. Ties break on slot for determinism.
+    let mut candidates: Vec<&PlannerPitchPlayer> = state
+        .on_pitch
+        .iter()
+        .filter(|p| p.available && 1.0 - p.stamina >= policy.fatigue_threshold)
+        .filter(|p| !p.position.is_goalkeeper() || p.stamina < 0.2)
+        .collect();
+    candidates.sort_by(|a, b| a.stamina.total_cmp(&b.stamina).then(a.slot.cmp(&b.slot)));
+
+    let mut bench_used: Vec<u8> = Vec::new();
+    let mut recommendations = Vec::new();
+    for out in candidates {
+        if recommendations.len() >= budget {
+            break;
+        }
+        let Some((sub, fit)) = pick_substitute(state, out.position, game_state, &bench_used) else {
+            continue;
+        };
+        bench_used.push(sub.bench_slot);
+
+        let (kept, fresh) = project_stamina(state.minute, out, sub);
+        recommendations.push(SubstitutionRecommendation {
+            out_idx: out.slot,
+            in_idx: 11 + sub.bench_slot,
+            out_track_id: out.track_id,
+            out_name: out.name.clone(),
+            in_name: sub.name.clone(),
+            out_stamina: out.stamina,
+            projected_stamina_if_kept: kept,
+            projected_stamina_of_sub: fresh,
+            position_fit: fit,
+            reasoning: reasoning(game_state, out, sub, fit),
+        });
+    }
+
+    SubstitutionPlan { minute: state.minute, substitutions_remaining: remaining, recommendations }
+}
+
+/// Rank replacements for tired, booked and out-of-position players.
+///
+/// Same budget and bench rules as `plan_substitutions`; a mismatched player
+/// is replaced by someone who fits the slot rather than the player.
+pub fn advise_substitutions(
+    state: &SubstitutionSquadState,
+    policy: &SubstitutionPolicy,
+) -> SubstitutionAdvice {
+    let (remaining, budget) = budget(state, policy);
+    let game_state = game_state(state, policy);
+
+    let mut candidates: Vec<(&PlannerPitchPlayer, f32, Vec<AdviceReason>)> = state
+        .on_pitch
+        .iter()
+        .filter(|p| p.available)
+        .filter(|p| !p.position.is_goalkeeper() || p.stamina < 0.2)
+        .filter_map(|p| {
+            let mut weighted: Vec<(AdviceReason, f32)> = Vec::new();
+            let fatigue = 1.0 - p.stamina;
+            if fatigue >= policy.fatigue_threshold {
+                weighted.push((AdviceReason::Fatigue, fatigue));
+            }
+            if p.yellow_cards > 0 {
+                let defensive = p.position.is_defender() || p.position == Position::CDM;
+                let risk = CARD_RISK + if defensive { CARD_RISK_DEFENSIVE } else { 0.0 };
+                weighted.push((AdviceReason::CardRisk, risk));
+            }
+            let mismatch = mismatch_weight(p);
+            if mismatch > 0.0 {
+                weighted.push((AdviceReason::TacticalMismatch, mismatch));
+            }
+            if weighted.is_empty() {
+                return None;
+            }
+            // Stable sort keeps declaration order on equal weights.
+            weighted.sort_by(|a, b| b.1.total_cmp(&a.1));
+            let urgency = weighted.iter().map(|(_, w)| w).sum();
+            Some((p, urgency, weighted.into_iter().map(|(r, _)| r).collect()))
+        })
+        .collect();
+    candidates.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.slot.cmp(&b.0.slot)));
+
+    let mut bench_used: Vec<u8> = Vec::new();
+    let mut suggestions = Vec::new();
+    for (out, urgency, reasons) in candidates {
+        if suggestions.len() >= budget {
+            break;
+        }
+        let target = match out.slot_position {
+            Some(slot) if reasons.contains(&AdviceReason::TacticalMismatch) => slot,
+            _ => out.position,
+        };
+        let Some((sub, fit)) = pick_substitute(state, target, game_state, &bench_used) else {
+            continue;
+        };
+        bench_used.push(sub.bench_slot);
+
+        let (kept, fresh) = project_stamina(state.minute, out, sub);
+        let reasoning = match reasons[0] {
+            AdviceReason::Fatigue => reasoning(game_state, out, sub, fit),
+            AdviceReason::CardRisk => format!(
+                "Card risk: {} is on a yellow, {} removes the chance of going down to ten",
+                out.name, sub.name
+            ),
+            AdviceReason::TacticalMismatch => format!(
+                "Tactical fit: {} is out of shape as {:?}, {} suits the role",
+                out.name, target, sub.name
+            ),
+        };
+        suggestions.push(SubstitutionSuggestion {
+            rank: suggestions.len() as u8 + 1,
+            urgency,
+            reasons,
+            recommendation: SubstitutionRecommendation {
+                out_idx: out.slot,
+                in_idx: 11 + sub.bench_slot,
+                out_track_id: out.track_id,
+                out_name: out.name.clone(),
+                in_name: sub.name.clone(),
+                out_stamina: out.stamina,
+                projected_stamina_if_kept: kept,
+                projected_stamina_of_sub: fresh,
+                position_fit: fit,
+                reasoning,
+            },
+        });
+    }
+
+    SubstitutionAdvice { minute: state.minute, substitutions_remaining: remaining, suggestions }
+}
+
+/// (substitutions remaining, suggestions allowed under the policy)
+fn budget(state: &SubstitutionSquadState, policy: &SubstitutionPolicy) -> (u8, usize) {
+    let remaining = MAX_SUBSTITUTIONS.saturating_sub(state.substitutions_made);
+    let usable = if policy.keep_injury_reserve { remaining.saturating_sub(1) } else { remaining };
+    (remaining, usable.min(policy.max_suggestions) as usize)
+}
+
+fn game_state(state: &SubstitutionSquadState, policy: &SubstitutionPolicy) -> GameState {
+    if !policy.use_game_state || state.minute < GAME_STATE_MINUTE {
+        GameState::Neutral
+    } else if state.goal_diff < 0 {
+        GameState::Chasing
+    } else if state.goal_diff > 0 {
+        GameState::Protecting
+    } else {
+        GameState::Neutral
+    }
+}
+
+/// 0 when the player suits the slot; otherwise how badly they don't.
+fn mismatch_weight(p: &PlannerPitchPlayer) -> f32 {
+    let suitability_gap = if p.position_suitability < MISMATCH_SUITABILITY {
+        1.0 - p.position_suitability
+    } else {
+        0.0
+    };
+    let slot_gap = match p.slot_position {
+        Some(slot) if !p.position.is_compatible_position(slot) => SLOT_MISMATCH,
+        _ => 0.0,
+    };
+    suitability_gap.max(slot_gap)
+}
+
+fn same_line(a: Position, b: Position) -> bool {
+    (a.is_goalkeeper() && b.is_goalkeeper())
+        || (a.is_defender() && b.is_defender())
+        || (a.is_midfielder() && b.is_midfielder())
+        || (a.is_forward() && b.is_forward())
+}
+
+fn position_fit(out: Position, sub: Position) -> PositionFit {
+    if out == sub {
+        PositionFit::Exact
+    } else if same_line(out, sub) {
+        PositionFit::SameLine
+    } else {
+        PositionFit::OutOfPosition
+    }
+}
+
+/// Best bench player for `target`: game-state preference first, then fit, then overall.
+fn pick_substitute<'a>(
+    state: &'a SubstitutionSquadState,
+    target: Position,
+    game_state: GameState,
+    bench_used: &[u8],
+) -> Option<(&'a PlannerBenchPlayer, PositionFit)> {
+    let prefers = |sub: &PlannerBenchPlayer| match game_state {
+        // Swap a tiring midfielder/defender for a forward when chasing
+        GameState::Chasing => sub.position.is_forward() && !target.is_goalkeeper(),
+        // Shore up with a defender/holding midfielder when protecting
+        GameState::Protecting => sub.position.is_defender() || sub.position == Position::CDM,
+        GameState::Neutral => false,
+    };
+    let fit_rank = |fit: PositionFit| match fit {
+        PositionFit::Exact => 2,
+        PositionFit::SameLine => 1,
+        PositionFit::OutOfPosition => 0,
+    };
+
+    state
+        .bench
+        .iter()
+        .filter(|sub| !sub.used && !bench_used.contains(&sub.bench_slot))
+        .filter(|sub| target.is_goalkeeper() == sub.position.is_goalkeeper())
+        .map(|sub| (sub, position_fit(target, sub.position)))
+        .max_by(|(a, fa), (b, fb)| {
+            (prefers(a), fit_rank(*fa), a.overall)
+                .cmp(&(prefers(b), fit_rank(*fb), b.overall))
+                // Lower bench slot wins ties (max_by keeps the last max).
+                .then(b.bench_slot.cmp(&a.bench_slot))
+        })
+}
+
+/// Linear projection to full time from the observed drain rate.
+fn project_stamina(minute: u8, out: &PlannerPitchPlayer, sub: &PlannerBenchPlayer) -> (f32, f32) {
+    let played = (minute as f32).max(1.0);
+    let remaining = (FULL_TIME_MINUTE - minute as f32).max(0.0);
+    let out_rate = (1.0 - out.stamina).max(0.0) / played;
+    // Higher stamina attribute drains slower (same shape as the fatigue model).
+    let drain_factor = |attr: u8| 1.0 - (attr as f32 / 100.0).min(0.8) * 0.5;
+    let sub_rate = out_rate * drain_factor(sub.stamina_attr) / drain_factor(out.stamina_attr);
+
+    let kept = (out.stamina - out_rate * remaining).clamp(0.0, 1.0);
+    let fresh = (1.0 - sub_rate * remaining).clamp(0.0, 1.0);
+    (kept, fresh)
+}
+
+fn reasoning(
+    game_state: GameState,
+    out: &PlannerPitchPlayer,
+    sub: &PlannerBenchPlayer,
+    fit: PositionFit,
+) -> String {
+    let fatigue_pct = ((1.0 - out.stamina) * 100.0).round() as u32;
+    match game_state {
+        GameState::Chasing if sub.position.is_forward() => format!(
+            "Chasing the game: {} adds attacking threat for {} ({}% fatigued)",
+            sub.name, out.name, fatigue_pct
+        ),
+        GameState::Protecting if !sub.position.is_forward() => format!(
+            "Protecting the lead: {} shores up the shape for {} ({}% fatigued)",
+            sub.name, out.name, fatigue_pct
+        ),
+        _ if fit == PositionFit::OutOfPosition => format!(
+            "Fresh legs: {} ({}% fatigued) off, {} would play out of position",
+            out.name, fatigue_pct, sub.name
+        ),
+        _ => format!(
+            "Fresh legs: {} for {} ({}% fatigued), like-for-like",
+            sub.name, out.name, fatigue_pct
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pitch(slot: u8, position: Position, stamina: f32) -> PlannerPitchPlayer {
+        PlannerPitchPlayer {
+            slot,
+            track_id: slot,
+            name: format!("P{}", slot),
+            position,
+            stamina,
+            stamina_attr: 60,
+            available: true,
+            yellow_cards: 0,
+            position_suitability: 1.0,
+            slot_position: None,
+        }
+    }
+
+    fn bench(bench_slot: u8, position: Position, overall: u8) -> PlannerBenchPlayer {
+        PlannerBenchPlayer {
+            bench_slot,
+            name: format!("B{}", bench_slot),
+            position,
+            overall,
+            stamina_attr: 60,
+            used: false,
+        }
+    }
+
+    fn state(minute: u8, goal_diff: i8) -> SubstitutionSquadState {
+        SubstitutionSquadState {
+            is_home: true,
+            minute,
+            goal_diff,
+            substitutions_made: 0,
+            on_pitch: vec![
+                pitch(0, Position::GK, 0.7),
+                pitch(3, Position::CB, 0.9),
+                pitch(6, Position::CM, 0.5),
+                pitch(7, Position::LM, 0.6),
+                pitch(9, Position::ST, 0.95),
+            ],
+            bench: vec![
+                bench(0, Position::GK, 60),
+                bench(1, Position::CM, 65),
+                bench(2, Position::ST, 70),
+                bench(3, Position::CB, 62),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_tired_players_get_like_for_like_fresh_legs() {
+        let plan = plan_substitutions(&state(45, 0), &SubstitutionPolicy::default());
+        assert_eq!(plan.substitutions_remaining, 5);
+        assert_eq!(plan.recommendations.len(), 2, "only CM and LM exceed the threshold");
+
+        let first = &plan.recommendations[0];
+        assert_eq!((first.out_idx, first.in_idx), (6, 12));
+        assert_eq!(first.position_fit, PositionFit::Exact);
+        assert!(first.projected_stamina_of_sub > first.projected_stamina_if_kept);
+        assert!(first.reasoning.starts_with("Fresh legs"));
+
+        // The CM is taken, so the LM gets the next-best midfielder or better.
+        assert_ne!(plan.recommendations[1].in_idx, first.in_idx);
+        assert_eq!(plan, plan_substitutions(&state(45, 0), &SubstitutionPolicy::default()));
+    }
+
+    #[test]
+    fn test_game_state_and_reserve_shape_the_plan() {
+        let chasing = plan_substitutions(&state(70, -1), &SubstitutionPolicy::default());
+        assert_eq!(chasing.recommendations[0].in_name, "B2");
+        assert!(chasing.recommendations[0].reasoning.starts_with("Chasing the game"));
+
+        let mut late = state(70, 1);
+        late.substitutions_made = 4;
+        let plan = plan_substitutions(&late, &SubstitutionPolicy::default());
+        assert!(plan.recommendations.is_empty(), "last sub is kept for injuries");
+
+        let policy = SubstitutionPolicy { keep_injury_reserve: false, ..Default::default() };
+        let plan = plan_substitutions(&late, &policy);
+        assert_eq!(plan.recommendations.len(), 1);
+        assert!(plan.recommendations[0].reasoning.starts_with("Protecting the lead"));
+    }
+
+    #[test]
+    fn test_advice_ranks_card_risk_and_mismatch_with_fatigue() {
+        let mut squad = state(30, 0);
+        squad.bench.push(bench(4, Position::LM, 64));
+        // Winger left in a centre-back slot after a formation change
+        squad.on_pitch[1].position = Position::LW;
+        squad.on_pitch[1].slot_position = Some(Position::CB);
+        // Booked striker, otherwise fresh
+        squad.on_pitch[4].yellow_cards = 1;
+
+        let policy = SubstitutionPolicy { max_suggestions: 5, ..Default::default() };
+        let advice = advise_substitutions(&squad, &policy);
+        let by_slot = |slot: u8| {
+            advice.suggestions.iter().find(|s| s.recommendation.out_idx == slot).unwrap()
+        };
+
+        assert_eq!(advice.suggestions.len(), 4, "mismatched slot, CM, LM and the booked ST");
+        assert!(advice.suggestions.windows(2).all(|w| w[0].urgency >= w[1].urgency));
+        assert_eq!(advice.suggestions.iter().map(|s| s.rank).collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+
+        // The replacement fits the slot, not the player
+        let mismatch = by_slot(3);
+        assert_eq!(mismatch.reasons, vec![AdviceReason::TacticalMismatch]);
+        assert_eq!(mismatch.recommendation.in_name, "B3");
+        assert_eq!(mismatch.recommendation.position_fit, PositionFit::Exact);
+        assert!(mismatch.recommendation.reasoning.starts_with("Tactical fit"));
+
+        let booked = by_slot(9);
+        assert_eq!(booked.reasons, vec![AdviceReason::CardRisk]);
+        assert_eq!(booked.recommendation.in_name, "B2");
+        assert!(booked.recommendation.reasoning.starts_with("Card risk"));
+
+        assert_eq!(by_slot(6).reasons, vec![AdviceReason::Fatigue]);
+        assert_eq!(advice, advise_substitutions(&squad, &policy));
+    }
+
+    #[test]
+    fn test_advice_respects_budget_and_skips_settled_players() {
+        let mut squad = state(30, 0);
+        for p in &mut squad.on_pitch {
+            p.stamina = 0.9;
+        }
+        assert!(advise_substitutions(&squad, &SubstitutionPolicy::default())
+            .suggestions
+            .is_empty());
+
+        squad.on_pitch[2].position_suitability = 0.3;
+        squad.on_pitch[2].yellow_cards = 1;
+        squad.substitutions_made = 3;
+        let advice = advise_substitutions(&squad, &SubstitutionPolicy::default());
+        assert_eq!(advice.substitutions_remaining, 2);
+        assert_eq!(advice.suggestions.len(), 1, "one sub held back for injuries");
+        let first = &advice.suggestions[0];
+        assert_eq!(first.reasons, vec![AdviceReason::TacticalMismatch, AdviceReason::CardRisk]);
+        assert!((first.urgency - 1.1).abs() < 1e-6);
+    }
+}
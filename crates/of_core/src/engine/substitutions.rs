@@ -7,6 +7,7 @@
 //! - find_substitution_candidate: 교체 후보 선수 찾기
 //! - execute_substitution: 교체 실행
 //! - force_injury_substitution: 부상으로 인한 강제 교체
+//! - substitution_squad_state: 교체 플래너 입력 스냅샷 (읽기 전용)
 //!
 //! FIX_2601/0106 P1: **Roster swap (SSOT)**
 //! - on-pitch 선수 접근은 `MatchSetup` assignment를 통해 수행
@@ -14,7 +15,11 @@
 //! - 벤치 선수는 재투입 불가(`sub_used`), 레드카드 퇴장은 교체 불가
 
 use super::match_sim::MatchEngine;
+use super::substitution_planner::{PlannerBenchPlayer, PlannerPitchPlayer, SubstitutionSquadState};
+use crate::engine::movement::slot_to_position_key;
 use crate::engine::player_state::PlayerState;
+use crate::engine::positioning::PositionKey;
+use crate::models::player::Position;
 use crate::models::{EventDetails, EventType, MatchEvent, SubstitutionDetails, TeamSide};
 
 impl MatchEngine {
@@ -46,6 +51,69 @@ impl MatchEngine {
         }
     }
 
+    /// 교체 플래너 입력 스냅샷 (현재 스태미나/벤치/스코어, 경기 상태는 변경하지 않음)
+    pub fn substitution_squad_state(&self, is_home: bool) -> SubstitutionSquadState {
+        let team = if is_home { TeamSide::Home } else { TeamSide::Away };
+        let start_idx = if is_home { 0 } else { 11 };
+        let formation = if is_home { &self.home_formation } else { &self.away_formation };
+
+        let on_pitch = (start_idx..start_idx + 11)
+            .map(|idx| {
+                let player = self.get_match_player(idx);
+                let available = !self.injured_players.contains(&idx)
+                    && !matches!(self.get_player_fsm_state(idx), Some(PlayerState::SentOff));
+                let slot = idx - start_idx;
+                PlannerPitchPlayer {
+                    slot: slot as u8,
+                    track_id: idx as u8,
+                    name: player.name.clone(),
+                    position: player.position,
+                    stamina: self.stamina[idx],
+                    stamina_attr: player.attributes.stamina,
+                    available,
+                    yellow_cards: self
+                        .result
+                        .statistics
+                        .discipline_for(idx as u8)
+                        .map_or(0, |d| d.yellow_cards),
+                    position_suitability: player.position_suitability,
+                    slot_position: Some(slot_position(slot_to_position_key(slot, formation))),
+                }
+            })
+            .collect();
+
+        let bench =
+            if is_home { &self.setup.home.substitutes } else { &self.setup.away.substitutes };
+        let bench = bench
+            .iter()
+            .enumerate()
+            .map(|(slot, sub)| PlannerBenchPlayer {
+                bench_slot: slot as u8,
+                name: sub.name.clone(),
+                position: sub.position,
+                overall: sub.overall,
+                stamina_attr: sub.attributes.stamina,
+                used: self.setup.is_sub_used(team, slot as u8),
+            })
+            .collect();
+
+        let (home, away) = self.get_score();
+        let (own, other) = if is_home { (home, away) } else { (away, home) };
+
+        SubstitutionSquadState {
+            is_home,
+            minute: self.minute,
+            goal_diff: (own as i16 - other as i16).clamp(i8::MIN as i16, i8::MAX as i16) as i8,
+            substitutions_made: if is_home {
+                self.substitutions_made.0
+            } else {
+                self.substitutions_made.1
+            },
+            on_pitch,
+            bench,
+        }
+    }
+
     /// 교체 후보 찾기 - 피로도가 높은 선수와 적합한 교체 선수 반환
     pub(crate) fn find_substitution_candidate(&self, is_home: bool) -> Option<(usize, u8)> {
         let (start_idx, end_idx) = if is_home { (0, 11) } else { (11, 22) };
@@ -207,3 +275,23 @@ impl MatchEngine {
     }
 }
 
+/// 포메이션 슬롯 키 → 플래너용 대표 포지션 (좌/우 구분은 라인 단위로 접음)
+fn slot_position(key: PositionKey) -> Position {
+    match key {
+        PositionKey::GK => Position::GK,
+        PositionKey::LB => Position::LB,
+        PositionKey::LCB | PositionKey::CB | PositionKey::RCB => Position::CB,
+        PositionKey::RB => Position::RB,
+        PositionKey::LWB => Position::LWB,
+        PositionKey::RWB => Position::RWB,
+        PositionKey::CDM | PositionKey::LDM | PositionKey::RDM => Position::CDM,
+        PositionKey::LM => Position::LM,
+        PositionKey::LCM | PositionKey::CM | PositionKey::RCM => Position::CM,
+        PositionKey::RM => Position::RM,
+        PositionKey::LAM | PositionKey::CAM | PositionKey::RAM => Position::CAM,
+        PositionKey::LW => Position::LW,
+        PositionKey::RW => Position::RW,
+        PositionKey::LF | PositionKey::CF | PositionKey::RF => Position::CF,
+        PositionKey::ST => Position::ST,
+    }
+}
@@ -680,6 +680,8 @@ mod tests {
             equipped_skills: Vec::new(),
             traits: TraitSlots::new(),
             personality: PersonalityArchetype::Steady,
+            morale: 50,
+            form: 50,
         };
 
         // 공격적인 공격수
@@ -705,6 +707,8 @@ mod tests {
             equipped_skills: Vec::new(),
             traits: TraitSlots::new(),
             personality: PersonalityArchetype::Steady,
+            morale: 50,
+            form: 50,
         };
 
         // 침착한 수비수
@@ -730,6 +734,8 @@ mod tests {
             equipped_skills: Vec::new(),
             traits: TraitSlots::new(),
             personality: PersonalityArchetype::Steady,
+            morale: 50,
+            form: 50,
         };
 
         // 전방 압박형 공격수
@@ -0,0 +1,264 @@
+//! Tactical problem highlights (in-match)
+//!
+//! Combines FieldBoard pressure maps with conceded-chance locations to flag
+//! recurring structural weaknesses, e.g. "space behind RB exploited 4 times".
+//!
+//! - Chances are bucketed into [`WeaknessZone`]s in the *conceding* team's
+//!   frame (its own goal, its own left/right), so the zone survives the
+//!   half-time side swap.
+//! - Once a zone has been exploited [`RECURRENCE_THRESHOLD`] times an insight
+//!   is emitted, then again on every further occurrence.
+//! - Each insight links the underlying chances as clip windows (`t0_ms..t1_ms`).
+//!
+//! Analysis only: nothing here feeds back into the simulation.
+
+use serde::{Deserialize, Serialize};
+
+use super::physics_constants::field;
+use crate::models::{EventType, MatchEvent};
+
+/// Chances in the same zone before an insight is emitted
+pub const RECURRENCE_THRESHOLD: u8 = 3;
+/// Pressure-map value below which the chance is counted as "unpressured space"
+pub const LOW_PRESSURE_THRESHOLD: f32 = 0.25;
+/// Clip context before / after the chance
+pub const CLIP_PRE_MS: u64 = 5_000;
+pub const CLIP_POST_MS: u64 = 3_000;
+/// Chances closer together than this belong to the same attack (rebounds, shot → goal)
+pub const SAME_ATTACK_MS: u64 = 3_000;
+
+/// Half-width of the central channel (≈ penalty-area width minus a few meters)
+const CENTRAL_HALF_WIDTH_M: f32 = 12.0;
+const BOX_DEPTH_M: f32 = 18.0;
+const ZONE14_DEPTH_M: f32 = 30.0;
+
+/// Where a chance was conceded, seen from the conceding team
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WeaknessZone {
+    BehindLeftBack,
+    BehindRightBack,
+    CentralBox,
+    Zone14,
+    LongRange,
+}
+
+impl WeaknessZone {
+    /// Classify an engine-frame position (meters) for the conceding team.
+    ///
+    /// `conceding_attacks_right`: the conceding team attacks toward x=105,
+    /// i.e. defends the goal at x=0 (its left flank is low y).
+    pub fn classify(pos_m: (f32, f32), conceding_attacks_right: bool) -> Self {
+        let (depth, right_offset) = if conceding_attacks_right {
+            (pos_m.0, pos_m.1 - field::CENTER_Y)
+        } else {
+            (field::LENGTH_M - pos_m.0, field::CENTER_Y - pos_m.1)
+        };
+
+        if depth > ZONE14_DEPTH_M {
+            Self::LongRange
+        } else if right_offset >= CENTRAL_HALF_WIDTH_M {
+            Self::BehindRightBack
+        } else if right_offset <= -CENTRAL_HALF_WIDTH_M {
+            Self::BehindLeftBack
+        } else if depth <= BOX_DEPTH_M {
+            Self::CentralBox
+        } else {
+            Self::Zone14
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::BehindLeftBack => "space behind LB",
+            Self::BehindRightBack => "space behind RB",
+            Self::CentralBox => "central box",
+            Self::Zone14 => "zone 14",
+            Self::LongRange => "long-range shooting room",
+        }
+    }
+}
+
+/// Clip window for one conceded chance
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InsightClip {
+    pub t0_ms: u64,
+    pub t1_ms: u64,
+    pub minute: u8,
+    #[serde(rename = "type")]
+    pub event_type: EventType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub player_track_id: Option<u8>,
+}
+
+/// Recurring weakness flagged during the match
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TacticalInsight {
+    /// Minute / timestamp of the chance that triggered this insight
+    pub minute: u8,
+    pub timestamp_ms: u64,
+    /// Team whose structure is being exploited
+    pub conceding_is_home: bool,
+    pub zone: WeaknessZone,
+    /// Chances conceded in this zone so far
+    pub occurrences: u8,
+    /// How many of those were taken under low pressure
+    pub unpressured: u8,
+    /// Mean FieldBoard pressure on the attacker at the chance location
+    pub avg_pressure: f32,
+    /// e.g. "space behind RB exploited 4 times"
+    pub message: String,
+    /// One clip per chance, oldest first
+    pub clips: Vec<InsightClip>,
+}
+
+#[derive(Debug, Clone)]
+struct ChanceRecord {
+    t_ms: u64,
+    zone: WeaknessZone,
+    pressure: f32,
+    clip: InsightClip,
+}
+
+/// Per-match accumulator; owned by the engine, fed from `emit_event`
+#[derive(Debug, Clone, Default)]
+pub struct TacticalProblemDetector {
+    /// Conceded chances per team (0 = home, 1 = away)
+    chances: [Vec<ChanceRecord>; 2],
+    insights: Vec<TacticalInsight>,
+}
+
+impl TacticalProblemDetector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Events that count as a conceded chance for the other team
+    pub fn is_chance_event(event_type: &EventType) -> bool {
+        matches!(
+            event_type,
+            EventType::Shot
+                | EventType::ShotOnTarget
+                | EventType::ShotOffTarget
+                | EventType::ShotBlocked
+                | EventType::Goal
+        )
+    }
+
+    /// Record a chance created by `event.is_home_team` at `pos_m`.
+    ///
+    /// `pressure` is the pressure-map value against the attacking team at
+    /// the chance location. Returns the insight if this chance triggered one.
+    pub fn record_chance(
+        &mut self,
+        event: &MatchEvent,
+        pos_m: (f32, f32),
+        conceding_attacks_right: bool,
+        pressure: f32,
+    ) -> Option<&TacticalInsight> {
+        if !Self::is_chance_event(&event.event_type) {
+            return None;
+        }
+        let conceding_is_home = !event.is_home_team;
+        let t_ms = event.timeline_ms();
+        let records = &mut self.chances[if conceding_is_home { 0 } else { 1 }];
+
+        if records.last().is_some_and(|last| t_ms.saturating_sub(last.t_ms) < SAME_ATTACK_MS) {
+            return None;
+        }
+
+        let zone = WeaknessZone::classify(pos_m, conceding_attacks_right);
+        records.push(ChanceRecord {
+            t_ms,
+            zone,
+            pressure,
+            clip: InsightClip {
+                t0_ms: t_ms.saturating_sub(CLIP_PRE_MS),
+                t1_ms: t_ms + CLIP_POST_MS,
+                minute: event.minute,
+                event_type: event.event_type.clone(),
+                player_track_id: event.player_track_id,
+            },
+        });
+
+        let in_zone: Vec<&ChanceRecord> = records.iter().filter(|r| r.zone == zone).collect();
+        let occurrences = in_zone.len() as u8;
+        if occurrences < RECURRENCE_THRESHOLD {
+            return None;
+        }
+
+        let unpressured =
+            in_zone.iter().filter(|r| r.pressure < LOW_PRESSURE_THRESHOLD).count() as u8;
+        let avg_pressure = in_zone.iter().map(|r| r.pressure).sum::<f32>() / occurrences as f32;
+        self.insights.push(TacticalInsight {
+            minute: event.minute,
+            timestamp_ms: t_ms,
+            conceding_is_home,
+            zone,
+            occurrences,
+            unpressured,
+            avg_pressure,
+            message: format!("{} exploited {} times", zone.label(), occurrences),
+            clips: in_zone.iter().map(|r| r.clip.clone()).collect(),
+        });
+        self.insights.last()
+    }
+
+    /// All insights emitted so far, in emission order
+    pub fn insights(&self) -> &[TacticalInsight] {
+        &self.insights
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shot(is_home_team: bool, t_ms: u64) -> MatchEvent {
+        MatchEvent::shot((t_ms / 60_000) as u8, t_ms, is_home_team, 9, true, 0.1)
+    }
+
+    #[test]
+    fn test_zone_classification_follows_conceding_frame() {
+        // Home defends x=0; its right flank is high y.
+        assert_eq!(WeaknessZone::classify((10.0, 60.0), true), WeaknessZone::BehindRightBack);
+        assert_eq!(WeaknessZone::classify((10.0, 8.0), true), WeaknessZone::BehindLeftBack);
+        assert_eq!(WeaknessZone::classify((10.0, 34.0), true), WeaknessZone::CentralBox);
+        assert_eq!(WeaknessZone::classify((24.0, 30.0), true), WeaknessZone::Zone14);
+        assert_eq!(WeaknessZone::classify((40.0, 34.0), true), WeaknessZone::LongRange);
+
+        // Same spot mirrored for a team defending x=105.
+        assert_eq!(WeaknessZone::classify((95.0, 8.0), false), WeaknessZone::BehindRightBack);
+    }
+
+    #[test]
+    fn test_recurring_weakness_emits_insight_with_clips() {
+        let mut detector = TacticalProblemDetector::new();
+
+        // Away creates chances behind the home RB; home defends x=0.
+        for i in 0..2 {
+            let event = shot(false, 600_000 + i * 120_000);
+            assert!(detector.record_chance(&event, (12.0, 60.0), true, 0.1).is_none());
+        }
+        // Rebound within the same attack is not a new occurrence.
+        let rebound = shot(false, 721_000);
+        assert!(detector.record_chance(&rebound, (12.0, 60.0), true, 0.1).is_none());
+
+        let event = shot(false, 900_000);
+        let insight = detector.record_chance(&event, (14.0, 58.0), true, 0.6).unwrap();
+        assert!(insight.conceding_is_home);
+        assert_eq!(insight.zone, WeaknessZone::BehindRightBack);
+        assert_eq!(insight.occurrences, 3);
+        assert_eq!(insight.unpressured, 2);
+        assert_eq!(insight.message, "space behind RB exploited 3 times");
+        assert_eq!(insight.clips.len(), 3);
+        assert_eq!(insight.clips[2].t0_ms, 900_000 - CLIP_PRE_MS);
+
+        // Chances in other zones / by the other team do not count toward it.
+        let central = shot(false, 1_000_000);
+        assert!(detector.record_chance(&central, (8.0, 34.0), true, 0.0).is_none());
+        let home_shot = shot(true, 1_100_000);
+        assert!(detector.record_chance(&home_shot, (95.0, 60.0), false, 0.0).is_none());
+        assert_eq!(detector.insights().len(), 1);
+    }
+}
@@ -0,0 +1,182 @@
+//! Half-time team talk
+//!
+//! The user picks a [`TeamTalkTone`] during the half-time break; the squad's
+//! reaction depends on the personality mix of the players on the pitch and
+//! the current scoreline. The reaction maps to small, bounded second-half
+//! [`TeamMatchModifiers`] adjustments.
+//!
+//! Deterministic: same tone + personalities + scoreline → same outcome (no RNG).
+
+use crate::engine::TeamMatchModifiers;
+use crate::models::{TeamTalkDetails, TeamTalkReaction, TeamTalkTone};
+use crate::player::personality::PersonalityArchetype;
+
+/// Max ± change of pass/shot/tackle multipliers from a team talk
+pub const MAX_TECHNIQUE_SWING: f32 = 0.03;
+/// Max pressing intensity added by an aggressive talk
+pub const MAX_PRESS_ADD: f32 = 0.05;
+/// Receptiveness at/above which the squad is fired up
+const FIRED_THRESHOLD: f32 = 0.25;
+/// Receptiveness at/below which the squad is deflated
+const DEFLATED_THRESHOLD: f32 = -0.05;
+
+/// How one personality archetype takes a given tone (-1..1)
+fn archetype_response(archetype: PersonalityArchetype, tone: TeamTalkTone) -> f32 {
+    use PersonalityArchetype::*;
+    use TeamTalkTone::*;
+    match (tone, archetype) {
+        (Calm, Steady) => 0.4,
+        (Calm, Leader | Genius) => 0.2,
+        (Calm, Workhorse) => 0.1,
+        (Calm, Rebel) => 0.0,
+        (Encourage, Leader) => 0.2,
+        (Encourage, Rebel) => 0.1,
+        (Encourage, _) => 0.3,
+        (Praise, Genius) => 0.4,
+        (Praise, Rebel | Steady) => 0.2,
+        (Praise, Leader | Workhorse) => 0.1,
+        (Demand, Leader | Workhorse) => 0.4,
+        (Demand, Steady) => 0.1,
+        (Demand, Genius) => -0.2,
+        (Demand, Rebel) => -0.3,
+        (Criticize, Workhorse) => 0.2,
+        (Criticize, Leader) => 0.1,
+        (Criticize, Steady) => -0.1,
+        (Criticize, Genius) => -0.4,
+        (Criticize, Rebel) => -0.5,
+    }
+}
+
+/// How well the tone suits the scoreline (goal_diff = own - opponent)
+fn scoreline_fit(tone: TeamTalkTone, goal_diff: i8) -> f32 {
+    match tone {
+        TeamTalkTone::Calm => match goal_diff {
+            d if d > 0 => 0.2,
+            0 => 0.1,
+            _ => -0.2,
+        },
+        TeamTalkTone::Encourage => match goal_diff {
+            d if d > 0 => 0.0,
+            _ => 0.15,
+        },
+        // Praising a losing side breeds complacency.
+        TeamTalkTone::Praise => match goal_diff {
+            d if d > 0 => 0.3,
+            0 => 0.0,
+            _ => -0.3,
+        },
+        TeamTalkTone::Demand => match goal_diff {
+            d if d > 0 => -0.1,
+            0 => 0.1,
+            _ => 0.2,
+        },
+        // A rollicking only lands when the half went badly.
+        TeamTalkTone::Criticize => match goal_diff {
+            d if d <= -2 => 0.2,
+            -1 => 0.0,
+            0 => -0.1,
+            _ => -0.4,
+        },
+    }
+}
+
+/// Squad receptiveness to `tone` (-1..1).
+///
+/// Empty `personalities` (e.g. every player sent off) only uses the scoreline.
+pub fn team_talk_receptiveness(
+    tone: TeamTalkTone,
+    personalities: &[PersonalityArchetype],
+    goal_diff: i8,
+) -> f32 {
+    let mix = if personalities.is_empty() {
+        0.0
+    } else {
+        personalities.iter().map(|p| archetype_response(*p, tone)).sum::<f32>()
+            / personalities.len() as f32
+    };
+    (mix + scoreline_fit(tone, goal_diff)).clamp(-1.0, 1.0)
+}
+
+/// Evaluate a team talk into the reaction and bounded second-half effects.
+pub fn evaluate_team_talk(
+    tone: TeamTalkTone,
+    personalities: &[PersonalityArchetype],
+    goal_diff: i8,
+) -> TeamTalkDetails {
+    let receptiveness = team_talk_receptiveness(tone, personalities, goal_diff);
+    let reaction = if receptiveness >= FIRED_THRESHOLD {
+        TeamTalkReaction::Fired
+    } else if receptiveness <= DEFLATED_THRESHOLD {
+        TeamTalkReaction::Deflated
+    } else {
+        TeamTalkReaction::Neutral
+    };
+
+    // Aggressive tones turn a good reaction into intensity rather than composure.
+    let aggressive = matches!(tone, TeamTalkTone::Demand | TeamTalkTone::Criticize);
+    let press_intensity_add =
+        if aggressive { (receptiveness * MAX_PRESS_ADD).max(0.0) } else { 0.0 };
+
+    TeamTalkDetails {
+        tone,
+        reaction,
+        receptiveness,
+        technique_mult: 1.0 + receptiveness * MAX_TECHNIQUE_SWING,
+        press_intensity_add,
+    }
+}
+
+/// Fold a team talk outcome into the team's match modifiers.
+pub fn apply_team_talk_modifiers(modifiers: &mut TeamMatchModifiers, talk: &TeamTalkDetails) {
+    let technique = talk.technique_mult.clamp(1.0 - MAX_TECHNIQUE_SWING, 1.0 + MAX_TECHNIQUE_SWING);
+    modifiers.pass_success_mult *= technique;
+    modifiers.shot_accuracy_mult *= technique;
+    modifiers.tackle_success_mult *= technique;
+    modifiers.press_intensity_add += talk.press_intensity_add.clamp(0.0, MAX_PRESS_ADD);
+    // Extra intensity costs legs.
+    modifiers.stamina_drain_mult *= 1.0 + talk.press_intensity_add.clamp(0.0, MAX_PRESS_ADD);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use PersonalityArchetype::*;
+
+    #[test]
+    fn test_reaction_depends_on_personality_and_scoreline() {
+        let grafters = [Leader, Workhorse, Workhorse, Steady];
+        let divas = [Genius, Rebel, Genius, Rebel];
+
+        let demand = evaluate_team_talk(TeamTalkTone::Demand, &grafters, -1);
+        assert_eq!(demand.reaction, TeamTalkReaction::Fired);
+        assert!(demand.press_intensity_add > 0.0);
+        let criticize = evaluate_team_talk(TeamTalkTone::Criticize, &divas, -1);
+        assert_eq!(criticize.reaction, TeamTalkReaction::Deflated);
+
+        // Same squad, different scoreline.
+        assert_eq!(
+            evaluate_team_talk(TeamTalkTone::Praise, &divas, 1).reaction,
+            TeamTalkReaction::Fired
+        );
+        assert_eq!(
+            evaluate_team_talk(TeamTalkTone::Praise, &divas, -1).reaction,
+            TeamTalkReaction::Neutral
+        );
+
+        assert_eq!(demand, evaluate_team_talk(TeamTalkTone::Demand, &grafters, -1));
+    }
+
+    #[test]
+    fn test_modifiers_stay_bounded() {
+        let rebels = [Rebel; 11];
+        let talk = evaluate_team_talk(TeamTalkTone::Criticize, &rebels, 3);
+        assert!((talk.receptiveness + 0.9).abs() < 1e-6);
+
+        let mut modifiers = TeamMatchModifiers::default();
+        apply_team_talk_modifiers(&mut modifiers, &talk);
+        assert!(modifiers.pass_success_mult >= 1.0 - MAX_TECHNIQUE_SWING);
+        assert!(modifiers.pass_success_mult < 1.0);
+        assert_eq!(modifiers.press_intensity_add, 0.0, "a deflated squad does not press harder");
+        assert_eq!(modifiers.stamina_drain_mult, 1.0);
+    }
+}
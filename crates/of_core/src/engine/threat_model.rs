@@ -9,6 +9,7 @@
 ///
 /// Emergency Presser Trigger:
 /// - If free_score >= threshold → Assign emergency presser (ignores cooldown)
+use crate::engine::strict_math::StrictMath;
 use crate::engine::body_orientation::{
     facing_to_goal, facing_to_progress, is_frontal_pressure, is_rear_pressure, pressure_angle,
 };
@@ -58,7 +59,7 @@ pub fn calculate_space_term(carrier_pos: (f32, f32), defender_positions: &[(f32,
     // Log scaling (asymptotic to 1.0)
     // k chosen so that with MAX_DIST=20m, f(5m)=0.5 (=> k=ln(16)).
     const K: f32 = 4.0 * std::f32::consts::LN_2;
-    1.0 - (-K * normalized_dist).exp()
+    1.0 - (-K * normalized_dist).det_exp()
 }
 
 /// Calculate time term (time to intercept in seconds)
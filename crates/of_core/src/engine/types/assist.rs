@@ -21,6 +21,8 @@ pub struct AssistCandidate {
     pub team_side: TeamSide,
     /// 패스 시점 (tick)
     pub tick: u32,
+    /// 직전 패스를 passer에게 연결한 선수 (세컨드 어시스트 후보)
+    pub previous_passer_idx: Option<usize>,
 }
 
 impl AssistCandidate {
@@ -29,7 +31,27 @@ impl AssistCandidate {
 
     /// 새 어시스트 후보 생성
     pub fn new(passer_idx: usize, receiver_idx: usize, team_side: TeamSide, tick: u32) -> Self {
-        Self { passer_idx, receiver_idx, team_side, tick }
+        Self { passer_idx, receiver_idx, team_side, tick, previous_passer_idx: None }
+    }
+
+    /// 이전 후보가 이 패스의 passer에게 간 패스였다면 세컨드 어시스트 후보로 연결
+    ///
+    /// - 같은 팀, 이전 receiver == 이번 passer
+    /// - 이전 패스가 윈도우 내
+    /// - 원투 패스로 받는 선수 본인이 되돌려받는 경우는 제외
+    pub fn chained(mut self, previous: Option<AssistCandidate>) -> Self {
+        self.previous_passer_idx = previous
+            .filter(|p| p.team_side == self.team_side)
+            .filter(|p| p.receiver_idx == self.passer_idx)
+            .filter(|p| self.tick.saturating_sub(p.tick) <= Self::WINDOW_TICKS)
+            .map(|p| p.passer_idx)
+            .filter(|&idx| idx != self.receiver_idx);
+        self
+    }
+
+    /// 세컨드 어시스트 후보 (득점/슈팅 선수 본인 제외)
+    pub fn second_assist_for(&self, shooter_idx: usize) -> Option<usize> {
+        self.previous_passer_idx.filter(|&idx| idx != shooter_idx)
     }
 
     /// 어시스트 유효성 검증
@@ -96,6 +118,26 @@ mod tests {
         assert!(!candidate.is_for_team(TeamSide::Away));
     }
 
+    #[test]
+    fn test_assist_candidate_chain() {
+        let first = AssistCandidate::new(3, 5, TeamSide::Home, 1000);
+        let key = AssistCandidate::new(5, 9, TeamSide::Home, 1100).chained(Some(first));
+        assert_eq!(key.second_assist_for(9), Some(3));
+
+        // 체인 끊김: 다른 선수에게 간 패스 / 윈도우 밖 / 원투 패스
+        let other = AssistCandidate::new(3, 7, TeamSide::Home, 1000);
+        assert_eq!(
+            AssistCandidate::new(5, 9, TeamSide::Home, 1100)
+                .chained(Some(other))
+                .previous_passer_idx,
+            None
+        );
+        let stale = AssistCandidate::new(5, 9, TeamSide::Home, 1300).chained(Some(first));
+        assert_eq!(stale.previous_passer_idx, None);
+        let one_two = AssistCandidate::new(5, 3, TeamSide::Home, 1100).chained(Some(first));
+        assert_eq!(one_two.previous_passer_idx, None);
+    }
+
     #[test]
     fn test_assist_candidate_expired() {
         let candidate = AssistCandidate::new(5, 9, TeamSide::Home, 1000);
@@ -39,6 +39,8 @@ pub struct ActionOptions {
 #[derive(Debug, Clone)]
 pub struct UserDecisionContext {
     pub player_id: u32,
+    /// Co-op: which user slot must answer (0 = primary user player)
+    pub user_slot: u8,
     pub time_seconds: f32,
     pub position_m: (f32, f32),
     pub options: ActionOptions,
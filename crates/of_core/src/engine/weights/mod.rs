@@ -2,6 +2,8 @@ pub mod budget;
 pub mod gate;
 pub mod softmax;
 
+use crate::engine::strict_math::{strict_sum, StrictMath};
+
 pub const W_MIN: f32 = 0.05;
 pub const W_MAX: f32 = 20.0;
 
@@ -42,17 +44,17 @@ impl WeightBreakdown {
 
     pub fn ln_sum(&self) -> f32 {
         // ln(0) 방지: 최소값 clamp
-        let b = self.base.max(0.0001).ln();
-        let a = self.attr.max(0.0001).ln();
-        let t = self.tactics.max(0.0001).ln();
-        let p = self.personality.max(0.0001).ln();
-        let c = self.cards.max(0.0001).ln();
-        let x = self.context.max(0.0001).ln();
-        b + a + t + p + c + x
+        let b = self.base.max(0.0001).det_ln();
+        let a = self.attr.max(0.0001).det_ln();
+        let t = self.tactics.max(0.0001).det_ln();
+        let p = self.personality.max(0.0001).det_ln();
+        let c = self.cards.max(0.0001).det_ln();
+        let x = self.context.max(0.0001).det_ln();
+        strict_sum([b, a, t, p, c, x])
     }
 
     pub fn to_weight(&self) -> f32 {
-        self.ln_sum().exp().clamp(W_MIN, W_MAX)
+        self.ln_sum().det_exp().clamp(W_MIN, W_MAX)
     }
 }
 
@@ -1,3 +1,4 @@
+use crate::engine::strict_math::{strict_sum, StrictMath};
 use rand::Rng;
 
 pub fn sample_softmax_ln<R: Rng, T: Copy>(
@@ -13,15 +14,15 @@ pub fn sample_softmax_ln<R: Rng, T: Copy>(
     // scores = ln(W)/T
     let mut scores: Vec<f32> = cands
         .iter()
-        .map(|c| (c.w.to_weight().max(crate::engine::weights::W_MIN)).ln() / t)
+        .map(|c| (c.w.to_weight().max(crate::engine::weights::W_MIN)).det_ln() / t)
         .collect();
 
     // stable softmax
     let maxv = scores.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
     for s in &mut scores {
-        *s = (*s - maxv).exp();
+        *s = (*s - maxv).det_exp();
     }
-    let sum: f32 = scores.iter().sum();
+    let sum = strict_sum(scores.iter().copied());
     if sum <= 0.0 {
         return Some(cands[0].clone());
     }
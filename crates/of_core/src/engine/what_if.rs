@@ -0,0 +1,264 @@
+//! "What if" resimulation from a match moment
+//!
+//! Replays carry no engine state, so the moment is rebuilt deterministically:
+//! the original plan is streamed tick-by-tick to the requested timestamp and
+//! captured as a `MatchStateSnapshot` keyframe. Each alternative seed then
+//! restores that keyframe with a fresh RNG stream and plays out the remainder,
+//! giving a distribution of final scores ("what if that shot went in?").
+//!
+//! Same plan + timestamp + seeds → same distribution.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::live_match::MS_PER_TICK;
+use super::match_sim::{MatchEngine, MatchPlan};
+use super::snapshot::MatchStateSnapshot;
+
+/// Upper bound on alternative seeds per request (each one plays out a full remainder)
+pub const MAX_WHAT_IF_SEEDS: usize = 64;
+
+/// Final score of one alternative timeline
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WhatIfBranch {
+    pub seed: u64,
+    pub home_goals: u8,
+    pub away_goals: u8,
+}
+
+/// Final score with how many branches ended on it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WhatIfScoreline {
+    pub home_goals: u8,
+    pub away_goals: u8,
+    pub count: u32,
+    pub probability: f32,
+}
+
+/// Outcome distribution across all alternative seeds
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WhatIfDistribution {
+    /// Keyframe time (tick-aligned)
+    pub branch_timestamp_ms: u64,
+    pub branch_minute: u8,
+    /// Score the branches start from (after any override)
+    pub branch_score: (u8, u8),
+    pub branches: Vec<WhatIfBranch>,
+    pub home_win: f32,
+    pub draw: f32,
+    pub away_win: f32,
+    pub avg_home_goals: f32,
+    pub avg_away_goals: f32,
+    /// Most frequent first, ties by score
+    pub scorelines: Vec<WhatIfScoreline>,
+}
+
+impl WhatIfDistribution {
+    /// Aggregate branch results into probabilities and a scoreline histogram
+    pub fn from_branches(
+        branch_timestamp_ms: u64,
+        branch_minute: u8,
+        branch_score: (u8, u8),
+        branches: Vec<WhatIfBranch>,
+    ) -> Self {
+        let n = branches.len().max(1) as f32;
+        let mut wins = [0u32; 3];
+        let mut goals = (0u32, 0u32);
+        let mut histogram: BTreeMap<(u8, u8), u32> = BTreeMap::new();
+        for b in &branches {
+            let slot = match b.home_goals.cmp(&b.away_goals) {
+                std::cmp::Ordering::Greater => 0,
+                std::cmp::Ordering::Equal => 1,
+                std::cmp::Ordering::Less => 2,
+            };
+            wins[slot] += 1;
+            goals.0 += b.home_goals as u32;
+            goals.1 += b.away_goals as u32;
+            *histogram.entry((b.home_goals, b.away_goals)).or_insert(0) += 1;
+        }
+
+        let mut scorelines: Vec<WhatIfScoreline> = histogram
+            .into_iter()
+            .map(|((home_goals, away_goals), count)| WhatIfScoreline {
+                home_goals,
+                away_goals,
+                count,
+                probability: count as f32 / n,
+            })
+            .collect();
+        // BTreeMap order already breaks ties by score; stable sort keeps it.
+        scorelines.sort_by_key(|s| std::cmp::Reverse(s.count));
+
+        Self {
+            branch_timestamp_ms,
+            branch_minute,
+            branch_score,
+            home_win: wins[0] as f32 / n,
+            draw: wins[1] as f32 / n,
+            away_win: wins[2] as f32 / n,
+            avg_home_goals: goals.0 as f32 / n,
+            avg_away_goals: goals.1 as f32 / n,
+            branches,
+            scorelines,
+        }
+    }
+}
+
+/// Stream the original match to `timestamp_ms` and capture the keyframe there
+///
+/// The timestamp is rounded down to a decision tick. Fails if the match ends
+/// before the requested moment.
+pub fn capture_keyframe(plan: &MatchPlan, timestamp_ms: u64) -> Result<MatchStateSnapshot, String> {
+    let mut engine = MatchEngine::new(plan.clone())?;
+    let (home_strength, away_strength, possession_ratio, match_duration) = engine.init();
+
+    let target_ticks = timestamp_ms / MS_PER_TICK;
+    for _ in 0..target_ticks {
+        let continues = engine.step_decision_tick_streaming(
+            home_strength,
+            away_strength,
+            possession_ratio,
+            match_duration,
+        );
+        if !continues {
+            return Err(format!("Timestamp {}ms is after full time", timestamp_ms));
+        }
+    }
+
+    Ok(engine.get_state())
+}
+
+/// Play out the remainder of the match from `keyframe` once per seed
+///
+/// Each branch restores the keyframe, swaps in a fresh RNG stream for its seed
+/// and streams to full time. `score_override` replaces the keyframe score
+/// (e.g. to count a shot that was saved).
+pub fn resimulate_from_keyframe(
+    plan: &MatchPlan,
+    keyframe: &MatchStateSnapshot,
+    seeds: &[u64],
+    score_override: Option<(u8, u8)>,
+) -> Result<WhatIfDistribution, String> {
+    if seeds.is_empty() {
+        return Err("At least one alternative seed is required".to_string());
+    }
+    if seeds.len() > MAX_WHAT_IF_SEEDS {
+        return Err(format!("Too many seeds: {} (max {})", seeds.len(), MAX_WHAT_IF_SEEDS));
+    }
+
+    let branch_score = score_override.unwrap_or((keyframe.score_home, keyframe.score_away));
+    let mut branches = Vec::with_capacity(seeds.len());
+    for &seed in seeds {
+        let mut engine = MatchEngine::new(plan.clone())?;
+        let (home_strength, away_strength, possession_ratio, match_duration) = engine.init();
+
+        let mut state = keyframe.clone();
+        state.rng_seed = seed;
+        state.rng_word_pos = 0;
+        state.score_home = branch_score.0;
+        state.score_away = branch_score.1;
+        engine.set_state(state).map_err(|e| format!("Failed to restore keyframe: {}", e))?;
+
+        while engine.step_decision_tick_streaming(
+            home_strength,
+            away_strength,
+            possession_ratio,
+            match_duration,
+        ) {}
+
+        let (home_goals, away_goals) = engine.get_score();
+        branches.push(WhatIfBranch { seed, home_goals, away_goals });
+    }
+
+    // `keyframe.minute` still holds the last simulated tick's minute; derive from the clock.
+    let branch_timestamp_ms = keyframe.current_tick * MS_PER_TICK;
+    let branch_minute = (branch_timestamp_ms / 60_000) as u8;
+    Ok(WhatIfDistribution::from_branches(
+        branch_timestamp_ms,
+        branch_minute,
+        branch_score,
+        branches,
+    ))
+}
+
+/// Capture the keyframe at `timestamp_ms` and resimulate the remainder per seed
+pub fn what_if_from_moment(
+    plan: &MatchPlan,
+    timestamp_ms: u64,
+    seeds: &[u64],
+    score_override: Option<(u8, u8)>,
+) -> Result<WhatIfDistribution, String> {
+    let keyframe = capture_keyframe(plan, timestamp_ms)?;
+    resimulate_from_keyframe(plan, &keyframe, seeds, score_override)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::match_sim::test_fixtures::create_test_team;
+
+    fn branch(seed: u64, home_goals: u8, away_goals: u8) -> WhatIfBranch {
+        WhatIfBranch { seed, home_goals, away_goals }
+    }
+
+    #[test]
+    fn test_distribution_aggregates_branches() {
+        let branches = vec![branch(1, 2, 1), branch(2, 1, 1), branch(3, 2, 1), branch(4, 0, 3)];
+        let dist = WhatIfDistribution::from_branches(60_000, 1, (1, 1), branches);
+
+        assert!((dist.home_win - 0.5).abs() < 1e-6);
+        assert!((dist.draw - 0.25).abs() < 1e-6);
+        assert!((dist.away_win - 0.25).abs() < 1e-6);
+        assert!((dist.avg_home_goals - 1.25).abs() < 1e-6);
+        assert_eq!(dist.scorelines[0].count, 2);
+        assert_eq!((dist.scorelines[0].home_goals, dist.scorelines[0].away_goals), (2, 1));
+        // Ties in count fall back to score order.
+        assert_eq!((dist.scorelines[1].home_goals, dist.scorelines[1].away_goals), (0, 3));
+    }
+
+    #[test]
+    fn test_resimulation_is_deterministic_per_seed() {
+        let plan = MatchPlan {
+            home_team: create_test_team("Home"),
+            away_team: create_test_team("Away"),
+            seed: 11,
+            user_player: None,
+            home_match_modifiers: Default::default(),
+            away_match_modifiers: Default::default(),
+            modifier_stack: Default::default(),
+            home_instructions: None,
+            away_instructions: None,
+            home_player_instructions: None,
+            away_player_instructions: None,
+            home_ai_difficulty: None,
+            away_ai_difficulty: None,
+            pitch: crate::models::pitch::PitchSpec::default(),
+            home_chemistry: None,
+            away_chemistry: None,
+            stadium: None,
+            conditions: None,
+            penalty_shootout: None,
+            home_custom_formation: None,
+            away_custom_formation: None,
+            home_set_piece_takers: None,
+            away_set_piece_takers: None,
+            referee: None,
+        };
+
+        let keyframe = capture_keyframe(&plan, 88 * 60_000).unwrap();
+        assert_eq!(keyframe.current_tick * MS_PER_TICK, 88 * 60_000);
+
+        let dist = resimulate_from_keyframe(&plan, &keyframe, &[5, 5, 9], Some((3, 0))).unwrap();
+        assert_eq!(dist.branch_score, (3, 0));
+        assert_eq!(dist.branch_minute, 88);
+        assert_eq!(dist.branches.len(), 3);
+        assert_eq!(dist.branches[0], dist.branches[1]);
+        for b in &dist.branches {
+            assert!(b.home_goals >= 3);
+        }
+
+        assert!(capture_keyframe(&plan, 200 * 60_000).is_err());
+        assert!(resimulate_from_keyframe(&plan, &keyframe, &[], None).is_err());
+    }
+}
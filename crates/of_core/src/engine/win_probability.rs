@@ -0,0 +1,199 @@
+//! Live win-probability model
+//!
+//! Estimates home win / draw / away win from the match state alone:
+//! - current score
+//! - time remaining
+//! - xG pace (blended with a league-average prior early in the match)
+//! - red cards (a man down scores less and concedes more)
+//!
+//! Remaining goals per side are modelled as independent Poisson draws.
+//! Same events → same probabilities (no RNG).
+//!
+//! Analysis/presentation only: nothing here feeds back into the simulation.
+
+use crate::engine::strict_math::StrictMath;
+use serde::{Deserialize, Serialize};
+
+use crate::models::{EventType, MatchEvent};
+
+/// League-average goals per team per 90 minutes (prior scoring rate)
+pub(crate) const PRIOR_GOALS_PER_90: f32 = 1.35;
+/// Minutes of play at which xG pace and the prior carry equal weight
+const PRIOR_WEIGHT_MINUTES: f32 = 30.0;
+/// Scoring-rate multiplier per own red card
+const OWN_RED_CARD_MULT: f32 = 0.8;
+/// Scoring-rate multiplier per opponent red card
+const OPPONENT_RED_CARD_MULT: f32 = 1.15;
+/// Remaining goals per side considered (Poisson tail beyond is negligible)
+const MAX_REMAINING_GOALS: usize = 10;
+
+/// Win/draw/loss estimate at one point of the match (home perspective)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct WinProbability {
+    pub minute: u8,
+    pub home_win: f32,
+    pub draw: f32,
+    pub away_win: f32,
+}
+
+/// Running match state for the model, fed event by event
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WinProbabilityTracker {
+    pub score: (u8, u8),
+    pub xg: (f32, f32),
+    pub red_cards: (u8, u8),
+}
+
+impl WinProbabilityTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fold one match event into the running state
+    pub fn observe(&mut self, event: &MatchEvent) {
+        let home = event.is_home_team;
+        match event.event_type {
+            // Own goals are credited to the benefiting side (`is_home_team`).
+            EventType::Goal | EventType::OwnGoal => bump(&mut self.score, home, 1),
+            EventType::RedCard => bump(&mut self.red_cards, home, 1),
+            EventType::Shot
+            | EventType::ShotOnTarget
+            | EventType::ShotOffTarget
+            | EventType::ShotBlocked => {
+                let xg = event.details.as_ref().and_then(|d| d.xg_value).unwrap_or(0.0);
+                if home {
+                    self.xg.0 += xg;
+                } else {
+                    self.xg.1 += xg;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Estimate at `minute` of a `duration`-minute match
+    pub fn estimate(&self, minute: u8, duration: u8) -> WinProbability {
+        let played = minute as f32;
+        let remaining = duration.saturating_sub(minute) as f32;
+        let (home_reds, away_reds) = (self.red_cards.0 as i32, self.red_cards.1 as i32);
+        let home_rate = scoring_rate(self.xg.0, played)
+            * OWN_RED_CARD_MULT.powi(home_reds)
+            * OPPONENT_RED_CARD_MULT.powi(away_reds);
+        let away_rate = scoring_rate(self.xg.1, played)
+            * OWN_RED_CARD_MULT.powi(away_reds)
+            * OPPONENT_RED_CARD_MULT.powi(home_reds);
+
+        let home_pmf = poisson_pmf(home_rate * remaining);
+        let away_pmf = poisson_pmf(away_rate * remaining);
+        let lead = self.score.0 as i32 - self.score.1 as i32;
+
+        let (mut home_win, mut draw, mut away_win) = (0.0f32, 0.0f32, 0.0f32);
+        for (i, p_home) in home_pmf.iter().enumerate() {
+            for (j, p_away) in away_pmf.iter().enumerate() {
+                let p = p_home * p_away;
+                match (lead + i as i32 - j as i32).signum() {
+                    1 => home_win += p,
+                    0 => draw += p,
+                    _ => away_win += p,
+                }
+            }
+        }
+        let total = (home_win + draw + away_win).max(f32::EPSILON);
+        WinProbability {
+            minute,
+            home_win: home_win / total,
+            draw: draw / total,
+            away_win: away_win / total,
+        }
+    }
+}
+
+/// Per-minute series for a finished match (minutes 0..=duration).
+///
+/// The point at minute `m` reflects events before `m`; the last point
+/// includes stoppage-time events, so it matches the final score.
+pub fn win_probability_series(events: &[MatchEvent], duration: u8) -> Vec<WinProbability> {
+    let mut sorted: Vec<&MatchEvent> = events.iter().collect();
+    sorted.sort_by_key(|e| e.timeline_ms());
+
+    let mut tracker = WinProbabilityTracker::new();
+    let mut pending = sorted.into_iter().peekable();
+    let mut series = Vec::with_capacity(duration as usize + 1);
+    for minute in 0..=duration {
+        while let Some(event) = pending.next_if(|e| e.minute < minute || minute == duration) {
+            tracker.observe(event);
+        }
+        series.push(tracker.estimate(minute, duration));
+    }
+    series
+}
+
+fn bump(pair: &mut (u8, u8), home: bool, by: u8) {
+    if home {
+        pair.0 = pair.0.saturating_add(by);
+    } else {
+        pair.1 = pair.1.saturating_add(by);
+    }
+}
+
+/// Goals per minute: prior early on, xG pace as the sample grows
+fn scoring_rate(xg: f32, played_minutes: f32) -> f32 {
+    let prior = PRIOR_GOALS_PER_90 / 90.0;
+    if played_minutes <= 0.0 {
+        return prior;
+    }
+    let weight = played_minutes / (played_minutes + PRIOR_WEIGHT_MINUTES);
+    (1.0 - weight) * prior + weight * (xg.max(0.0) / played_minutes)
+}
+
+pub(crate) fn poisson_pmf(lambda: f32) -> [f32; MAX_REMAINING_GOALS + 1] {
+    let mut pmf = [0.0; MAX_REMAINING_GOALS + 1];
+    let mut p = (-lambda).det_exp();
+    for (k, slot) in pmf.iter_mut().enumerate() {
+        if k > 0 {
+            p *= lambda / k as f32;
+        }
+        *slot = p;
+    }
+    pmf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kickoff_is_balanced_and_sums_to_one() {
+        let p = WinProbabilityTracker::new().estimate(0, 90);
+        assert!((p.home_win - p.away_win).abs() < 1e-6);
+        assert!(p.draw > 0.2 && p.draw < 0.35);
+        assert!((p.home_win + p.draw + p.away_win - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_lead_grows_safer_with_time_and_red_cards_matter() {
+        let mut tracker = WinProbabilityTracker::new();
+        tracker.observe(&MatchEvent::goal(20, 1_200_000, true, 9, None));
+        let early = tracker.estimate(25, 90);
+        let late = tracker.estimate(85, 90);
+        assert!(late.home_win > early.home_win);
+        assert!(tracker.estimate(90, 90).home_win > 0.999);
+
+        let mut down_to_ten = tracker.clone();
+        down_to_ten.observe(&MatchEvent::red_card(30, 1_800_000, true, 4));
+        assert!(down_to_ten.estimate(40, 90).home_win < tracker.estimate(40, 90).home_win);
+    }
+
+    #[test]
+    fn test_series_follows_xg_pace_and_final_score() {
+        let events = vec![
+            MatchEvent::shot(10, 600_000, false, 18, true, 0.6),
+            MatchEvent::shot(25, 1_500_000, false, 19, false, 0.5),
+            MatchEvent::goal(91, 5_460_000, false, 19, None),
+        ];
+        let series = win_probability_series(&events, 90);
+        assert_eq!(series.len(), 91);
+        assert!(series[60].away_win > series[60].home_win);
+        assert!(series[90].away_win > 0.999);
+    }
+}
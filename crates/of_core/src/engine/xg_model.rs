@@ -0,0 +1,286 @@
+//! Contextual expected-goals (xG) model
+//!
+//! Replaces the distance/angle-only estimate with a per-shot feature vector:
+//! - shot location (distance, goal-mouth opening angle)
+//! - body part (foot / header / volley)
+//! - defender pressure and bodies in the shooting cone
+//! - assist type (cross / through ball / other pass)
+//! - goalkeeper positioning (off the shot line, off the goal line)
+//!
+//! The features are recorded on the shot events (`EventDetails.xg_features`)
+//! so analysts can re-score every shot and validate the model offline.
+//! Shooter ability is deliberately NOT a feature: xG describes the chance,
+//! not the finisher.
+
+use crate::engine::strict_math::StrictMath;
+use serde::{Deserialize, Serialize};
+
+use crate::engine::physics_constants::{field, goal};
+
+/// Lower bound of a single shot's xG
+const XG_MIN: f32 = 0.01;
+/// Upper bound of a single shot's xG (open-play penalty-spot equivalents stay below this)
+const XG_MAX: f32 = 0.85;
+/// Header multiplier (headers convert far less often than footed shots)
+const HEAD_MULT: f32 = 0.6;
+/// Volley multiplier
+const VOLLEY_MULT: f32 = 0.8;
+/// Cross-assisted multiplier (ball arriving across the shooter's body)
+const CROSS_MULT: f32 = 0.85;
+/// Through-ball multiplier (shooter typically running onto the ball behind the line)
+const THROUGH_BALL_MULT: f32 = 1.3;
+/// xG lost at full pressure (pressure = 1.0)
+const PRESSURE_PENALTY: f32 = 0.35;
+/// Multiplier per outfield defender standing inside the shooting cone
+const CONE_DEFENDER_MULT: f32 = 0.8;
+/// Keeper shot-line offset (m) still considered "set"
+const GK_SET_OFFSET_M: f32 = 1.0;
+/// Keeper shot-line offset (m) beyond which the bonus is maxed
+const GK_BEATEN_OFFSET_M: f32 = 4.0;
+/// Maximum bonus for a keeper caught out of position
+const GK_OUT_OF_POSITION_BONUS: f32 = 0.6;
+/// One-on-one multiplier (every outfield defender beaten)
+const ONE_ON_ONE_MULT: f32 = 1.5;
+/// Max shot distance (m) for a one-on-one
+const ONE_ON_ONE_MAX_DISTANCE_M: f32 = 20.0;
+
+/// Body part used for the shot
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ShotBodyPart {
+    Foot,
+    Head,
+    Volley,
+}
+
+/// How the shooter received the ball
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ShotAssistType {
+    /// Self-created (dribble, loose ball, rebound)
+    Unassisted,
+    /// Ground / lofted / back pass
+    Pass,
+    Cross,
+    ThroughBall,
+}
+
+/// Per-shot feature vector fed to [`contextual_xg`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct XgFeatures {
+    /// Distance to the goal centre (m)
+    pub distance_m: f32,
+    /// Goal-mouth opening angle seen from the shot location (degrees)
+    pub angle_deg: f32,
+    pub body_part: ShotBodyPart,
+    pub assist_type: ShotAssistType,
+    /// Outfield defenders inside the triangle shooter → both posts
+    pub defenders_in_cone: u8,
+    /// Closest outfield defender to the shooter (m, pitch length if none)
+    pub nearest_defender_m: f32,
+    /// Outfield defenders closer to the goal than the shooter
+    pub defenders_goal_side: u8,
+    /// Defender pressure on the shooter (0.0-1.0)
+    pub pressure: f32,
+    /// Keeper distance from the shooter (m)
+    pub gk_distance_m: f32,
+    /// Keeper distance from the shooter → goal-centre line (m)
+    pub gk_offset_m: f32,
+    /// Keeper distance off the goal line (m)
+    pub gk_off_line_m: f32,
+    pub is_one_on_one: bool,
+}
+
+impl XgFeatures {
+    /// Build the feature vector from pitch positions (meters)
+    ///
+    /// `defenders` are the outfield defenders only (keeper excluded).
+    pub fn from_positions(
+        shooter: (f32, f32),
+        goal_x: f32,
+        gk: (f32, f32),
+        defenders: &[(f32, f32)],
+        pressure: f32,
+        body_part: ShotBodyPart,
+        assist_type: ShotAssistType,
+    ) -> Self {
+        let goal_center = (goal_x, field::CENTER_Y);
+        let post_a = (goal_x, goal::Y_MIN);
+        let post_b = (goal_x, goal::Y_MAX);
+
+        let distance_m = dist(shooter, goal_center);
+        let angle_a = (post_a.1 - shooter.1).det_atan2(post_a.0 - shooter.0);
+        let angle_b = (post_b.1 - shooter.1).det_atan2(post_b.0 - shooter.0);
+        let mut angle_deg = (angle_a - angle_b).abs().to_degrees();
+        if angle_deg > 180.0 {
+            angle_deg = 360.0 - angle_deg;
+        }
+
+        let defenders_in_cone =
+            defenders.iter().filter(|&&d| in_triangle(d, shooter, post_a, post_b)).count() as u8;
+        let nearest_defender_m =
+            defenders.iter().map(|&d| dist(shooter, d)).fold(field::LENGTH_M, f32::min);
+
+        let gk_offset_m = distance_to_segment(gk, shooter, goal_center);
+        let gk_off_line_m = (gk.0 - goal_x).abs();
+
+        let defenders_goal_side =
+            defenders.iter().filter(|&&d| dist(d, goal_center) < distance_m).count() as u8;
+
+        let is_one_on_one = defenders_goal_side == 0 && distance_m < ONE_ON_ONE_MAX_DISTANCE_M;
+
+        Self {
+            distance_m,
+            angle_deg,
+            body_part,
+            assist_type,
+            defenders_in_cone,
+            nearest_defender_m,
+            defenders_goal_side,
+            pressure: pressure.clamp(0.0, 1.0),
+            gk_distance_m: dist(shooter, gk),
+            gk_offset_m,
+            gk_off_line_m,
+            is_one_on_one,
+        }
+    }
+}
+
+/// Contextual xG of a single shot (0.01-0.85)
+pub fn contextual_xg(f: &XgFeatures) -> f32 {
+    // Distance decay (real-world open play: 5m ≈ 0.30, 10m ≈ 0.06, 20m ≈ 0.015)
+    let base = if f.distance_m < 5.0 {
+        0.16 + (5.0 - f.distance_m) * 0.016
+    } else if f.distance_m < 35.0 {
+        0.20 * (-0.14 * f.distance_m).det_exp()
+    } else {
+        0.003
+    };
+    let angle_mult = (f.angle_deg / 45.0).clamp(0.6, 1.0);
+
+    let body_mult = match f.body_part {
+        ShotBodyPart::Foot => 1.0,
+        ShotBodyPart::Head => HEAD_MULT,
+        ShotBodyPart::Volley => VOLLEY_MULT,
+    };
+    let assist_mult = match f.assist_type {
+        ShotAssistType::Unassisted | ShotAssistType::Pass => 1.0,
+        ShotAssistType::Cross => CROSS_MULT,
+        ShotAssistType::ThroughBall => THROUGH_BALL_MULT,
+    };
+    let pressure_mult = 1.0 - PRESSURE_PENALTY * f.pressure.clamp(0.0, 1.0);
+    let cone_mult = CONE_DEFENDER_MULT.powi(f.defenders_in_cone as i32);
+
+    let gk_beaten = ((f.gk_offset_m - GK_SET_OFFSET_M) / (GK_BEATEN_OFFSET_M - GK_SET_OFFSET_M))
+        .clamp(0.0, 1.0);
+    let gk_mult = 1.0 + GK_OUT_OF_POSITION_BONUS * gk_beaten;
+
+    let one_on_one_mult = if f.is_one_on_one { ONE_ON_ONE_MULT } else { 1.0 };
+
+    (base * angle_mult
+        * body_mult
+        * assist_mult
+        * pressure_mult
+        * cone_mult
+        * gk_mult
+        * one_on_one_mult)
+        .clamp(XG_MIN, XG_MAX)
+}
+
+fn dist(a: (f32, f32), b: (f32, f32)) -> f32 {
+    ((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)).sqrt()
+}
+
+fn in_triangle(p: (f32, f32), a: (f32, f32), b: (f32, f32), c: (f32, f32)) -> bool {
+    let cross = |o: (f32, f32), u: (f32, f32), v: (f32, f32)| {
+        (u.0 - o.0) * (v.1 - o.1) - (u.1 - o.1) * (v.0 - o.0)
+    };
+    let d1 = cross(a, b, p);
+    let d2 = cross(b, c, p);
+    let d3 = cross(c, a, p);
+    let has_neg = d1 < 0.0 || d2 < 0.0 || d3 < 0.0;
+    let has_pos = d1 > 0.0 || d2 > 0.0 || d3 > 0.0;
+    !(has_neg && has_pos)
+}
+
+fn distance_to_segment(p: (f32, f32), a: (f32, f32), b: (f32, f32)) -> f32 {
+    let ab = (b.0 - a.0, b.1 - a.1);
+    let len_sq = ab.0 * ab.0 + ab.1 * ab.1;
+    if len_sq <= f32::EPSILON {
+        return dist(p, a);
+    }
+    let t = (((p.0 - a.0) * ab.0 + (p.1 - a.1) * ab.1) / len_sq).clamp(0.0, 1.0);
+    dist(p, (a.0 + ab.0 * t, a.1 + ab.1 * t))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GOAL_X: f32 = field::LENGTH_M;
+
+    fn features(shooter: (f32, f32), defenders: &[(f32, f32)]) -> XgFeatures {
+        XgFeatures::from_positions(
+            shooter,
+            GOAL_X,
+            (GOAL_X - 0.5, field::CENTER_Y),
+            defenders,
+            0.0,
+            ShotBodyPart::Foot,
+            ShotAssistType::Pass,
+        )
+    }
+
+    #[test]
+    fn test_contextual_xg_responds_to_shot_context() {
+        let marker = (GOAL_X - 9.0, field::CENTER_Y + 3.0);
+        let central = features((GOAL_X - 11.0, field::CENTER_Y), &[marker]);
+        let far = features((GOAL_X - 25.0, field::CENTER_Y), &[marker]);
+        let tight_angle = features((GOAL_X - 6.0, field::CENTER_Y + 15.0), &[marker]);
+        assert!(central.angle_deg > tight_angle.angle_deg);
+        assert!(contextual_xg(&central) > contextual_xg(&far));
+        assert!(contextual_xg(&central) > contextual_xg(&tight_angle));
+
+        // Blocker in the cone + close pressure lowers the chance
+        let blocker = (GOAL_X - 8.0, field::CENTER_Y);
+        let mut blocked = features((GOAL_X - 11.0, field::CENTER_Y), &[blocker]);
+        assert_eq!(blocked.defenders_in_cone, 1);
+        assert!(contextual_xg(&blocked) < contextual_xg(&central));
+        blocked.pressure = 1.0;
+        let pressured = contextual_xg(&blocked);
+        blocked.pressure = 0.0;
+        assert!(pressured < contextual_xg(&blocked));
+
+        // Header < foot; through ball > cross
+        let mut header = central.clone();
+        header.body_part = ShotBodyPart::Head;
+        assert!(contextual_xg(&header) < contextual_xg(&central));
+        let (mut through, mut cross) = (central.clone(), central.clone());
+        through.assist_type = ShotAssistType::ThroughBall;
+        cross.assist_type = ShotAssistType::Cross;
+        assert!(contextual_xg(&through) > contextual_xg(&cross));
+
+        // Keeper pulled off the shot line leaves the goal open
+        let gk_beaten = XgFeatures::from_positions(
+            (GOAL_X - 11.0, field::CENTER_Y),
+            GOAL_X,
+            (GOAL_X - 2.0, field::CENTER_Y + 5.0),
+            &[marker],
+            0.0,
+            ShotBodyPart::Foot,
+            ShotAssistType::Pass,
+        );
+        assert!(gk_beaten.gk_offset_m > GK_BEATEN_OFFSET_M);
+        assert!(contextual_xg(&gk_beaten) > contextual_xg(&central));
+
+        // Nothing between shooter and keeper → one-on-one
+        let one_on_one = features((GOAL_X - 11.0, field::CENTER_Y), &[]);
+        assert!(one_on_one.is_one_on_one && !central.is_one_on_one);
+        assert!(contextual_xg(&one_on_one) > contextual_xg(&central));
+
+        for f in [&central, &far, &tight_angle, &header, &through, &gk_beaten, &one_on_one] {
+            let xg = contextual_xg(f);
+            assert!((XG_MIN..=XG_MAX).contains(&xg), "xg {} out of bounds", xg);
+        }
+    }
+}
@@ -1,10 +1,12 @@
+use crate::engine::strict_math::StrictMath;
 use crate::engine::field_board::HeatmapF32;
+use crate::models::pitch::PitchSpec;
 use serde::{Deserialize, Serialize};
 
 // Constants for xG zone classification
 pub const XG_THRESHOLD_RED: f32 = 0.15;
 pub const XG_THRESHOLD_YELLOW: f32 = 0.05;
-const GOAL_WIDTH: f32 = 7.32; // meters
+const STANDARD_GOAL_WIDTH_M: f32 = 7.32;
 const UPDATE_INTERVAL_TICKS: u64 = 10;
 
 /// XGZoneMap provides spatial expected goals (xG) calculation across the FieldBoard grid.
@@ -15,12 +17,28 @@ const UPDATE_INTERVAL_TICKS: u64 = 10;
 pub struct XGZoneMap {
     cells: HeatmapF32,
     last_update_tick: u64,
+    /// Goal width of the match pitch (meters)
+    #[serde(default = "default_goal_width_m")]
+    goal_width_m: f32,
+}
+
+fn default_goal_width_m() -> f32 {
+    STANDARD_GOAL_WIDTH_M
 }
 
 impl XGZoneMap {
-    /// Creates a new XGZoneMap and initializes xG values for all cells.
+    /// Creates a new XGZoneMap for the standard pitch and initializes xG values for all cells.
     pub fn new(cols: u8, rows: u8) -> Self {
-        let mut map = Self { cells: HeatmapF32::new(cols, rows), last_update_tick: 0 };
+        Self::with_pitch(cols, rows, &PitchSpec::standard())
+    }
+
+    /// Creates a new XGZoneMap using the goal geometry of `pitch`.
+    pub fn with_pitch(cols: u8, rows: u8, pitch: &PitchSpec) -> Self {
+        let mut map = Self {
+            cells: HeatmapF32::new(cols, rows),
+            last_update_tick: 0,
+            goal_width_m: pitch.goal_width_m,
+        };
         map.initialize_zones(cols, rows);
         map
     }
@@ -84,10 +102,10 @@ impl XGZoneMap {
         }
 
         // Angle factor: wider angle to goal = better shooting opportunity
-        let angle_factor = ((GOAL_WIDTH / 2.0) / dist).atan().sin();
+        let angle_factor = ((self.goal_width_m / 2.0) / dist).det_atan().det_sin();
 
         // Distance decay: exponential falloff with distance
-        let dist_factor = (-2.0 * dist).exp();
+        let dist_factor = (-2.0 * dist).det_exp();
 
         // Defensive zone penalty: own half (y < 0.4) has reduced xG
         let zone_penalty = if pos.1 < 0.4 { 0.3 } else { 1.0 };
@@ -213,4 +231,11 @@ mod tests {
             xg_right_near, xg_right_far
         );
     }
+
+    #[test]
+    fn test_with_pitch_standard_matches_new() {
+        let a = XGZoneMap::new(28, 18);
+        let b = XGZoneMap::with_pitch(28, 18, &PitchSpec::standard());
+        assert_eq!(a.get_cells().values, b.get_cells().values);
+    }
 }
@@ -14,6 +14,9 @@ pub mod error_codes {
     pub const INPUT_NOT_APPLIED_FORMATION: &str = "INPUT_NOT_APPLIED_FORMATION";
     pub const INPUT_NOT_APPLIED_POSITION: &str = "INPUT_NOT_APPLIED_POSITION";
     pub const INPUT_NOT_APPLIED_CONDITION: &str = "INPUT_NOT_APPLIED_CONDITION";
+    pub const PLAYER_INJURED: &str = "PLAYER_INJURED";
+    pub const INVALID_CUSTOM_FORMATION: &str = "INVALID_CUSTOM_FORMATION";
+    pub const INVALID_SET_PIECE_TAKER: &str = "INVALID_SET_PIECE_TAKER";
 }
 
 pub const CONDITION_MODEL_ID: &str = "cond_v1_5step";
@@ -24,6 +27,18 @@ pub fn is_valid_condition_level(level: u8) -> bool {
     (1..=5).contains(&level)
 }
 
+/// FIX01 C1: ConditionLevel for a 0..=100 match fitness (external career systems)
+#[inline]
+pub fn condition_level_from_fitness(fitness: u8) -> u8 {
+    match fitness {
+        90.. => 5,
+        75..=89 => 4,
+        55..=74 => 3,
+        35..=54 => 2,
+        _ => 1,
+    }
+}
+
 /// FIX01 C1: stamina drain multiplier (lower condition => faster drain).
 #[inline]
 pub fn condition_drain_mult(level: u8) -> f32 {
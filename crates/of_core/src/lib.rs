@@ -31,6 +31,7 @@
 pub mod analysis;
 pub mod api;
 pub mod calibration;
+pub mod career;
 pub mod coach;
 pub mod data;
 pub mod engine;
@@ -41,10 +42,12 @@ pub mod player;
 pub mod quest;
 pub mod replay;
 pub mod save;
+pub mod season;
 pub mod special_ability;
 pub mod state;
 pub mod story;
 pub mod tactics;
+pub mod tournament;
 pub mod training;
 
 // Re-export main API functions
@@ -55,7 +58,8 @@ pub use api::player_json::{
 pub use api::{execute_training_json, TrainingRequest, TrainingResponse};
 pub use api::{
     simulate_match_json, simulate_match_json_with_replay, simulate_match_v2_json,
-    simulate_match_v2_json_with_replay, MatchRequest, MatchRequestV2, MatchResponse,
+    simulate_match_v2_json_with_replay, simulate_match_v3_json, simulate_match_v3_json_with_replay,
+    MatchRequest, MatchRequestV2, MatchRequestV3, MatchResponse,
 };
 pub use error::{MatchError, Result};
 
@@ -338,6 +342,8 @@ mod tests {
                 equipped_skills: Vec::new(),
                 traits: Default::default(),
                 personality: PersonalityArchetype::Steady,
+                morale: 50,
+                form: 50,
             }
         }
 
@@ -381,12 +387,24 @@ mod tests {
             user_player: None,
             home_match_modifiers: crate::engine::TeamMatchModifiers::default(),
             away_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+            modifier_stack: Default::default(),
             home_instructions: None,
             away_instructions: None,
             home_player_instructions: None,
             away_player_instructions: None,
             home_ai_difficulty: None,
             away_ai_difficulty: None,
+            pitch: crate::models::pitch::PitchSpec::default(),
+            home_chemistry: None,
+            away_chemistry: None,
+            stadium: None,
+            conditions: None,
+            penalty_shootout: None,
+            home_custom_formation: None,
+            away_custom_formation: None,
+            home_set_piece_takers: None,
+            away_set_piece_takers: None,
+            referee: None,
         };
 
         // Run simulation with position tracking enabled
@@ -488,6 +506,8 @@ mod tests {
                 equipped_skills: Vec::new(),
                 traits: Default::default(),
                 personality: PersonalityArchetype::Steady,
+                morale: 50,
+                form: 50,
             }
         }
 
@@ -531,12 +551,24 @@ mod tests {
             user_player: None,
             home_match_modifiers: crate::engine::TeamMatchModifiers::default(),
             away_match_modifiers: crate::engine::TeamMatchModifiers::default(),
+            modifier_stack: Default::default(),
             home_instructions: None,
             away_instructions: None,
             home_player_instructions: None,
             away_player_instructions: None,
             home_ai_difficulty: None,
             away_ai_difficulty: None,
+            pitch: crate::models::pitch::PitchSpec::default(),
+            home_chemistry: None,
+            away_chemistry: None,
+            stadium: None,
+            conditions: None,
+            penalty_shootout: None,
+            home_custom_formation: None,
+            away_custom_formation: None,
+            home_set_piece_takers: None,
+            away_set_piece_takers: None,
+            referee: None,
         };
 
         // Run simulation with replay recording enabled
@@ -0,0 +1,433 @@
+//! Team chemistry (shared history + player affinities)
+//!
+//! Chemistry is a symmetric pair score (0..=100, 50 = neutral) built from:
+//! - minutes two players have spent on the pitch together (match history)
+//! - shared nationality
+//! - a shared club in the players' career history (save data)
+//! - personality archetype affinity
+//!
+//! The engine consumes it twice:
+//! - `pass_link_mult`, a bounded multiplier on passer → receiver link-up
+//!   (see `engine::PassLinkModifiers`)
+//! - `team_chemistry_modifiers`, a pass success / off-ball movement bundle from
+//!   the starting XI's average link score (`ModifierSource::Chemistry`)
+
+use std::collections::BTreeMap;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::engine::TeamMatchModifiers;
+use crate::player::personality::PersonalityArchetype;
+
+/// Minutes together needed to max out the history component (~20 full matches)
+pub const CHEMISTRY_FULL_MINUTES: u32 = 1800;
+
+/// Score of a pair with no history, no shared nationality and neutral personalities
+pub const CHEMISTRY_BASE_SCORE: i32 = 40;
+
+/// Max score contributed by shared minutes
+pub const CHEMISTRY_HISTORY_MAX: i32 = 40;
+
+/// Score bonus for shared nationality
+pub const CHEMISTRY_NATIONALITY_BONUS: i32 = 10;
+
+/// Score bonus for having played for the same club
+pub const CHEMISTRY_CLUB_BONUS: i32 = 8;
+
+/// Neutral pair score (pass link multiplier = 1.0)
+pub const CHEMISTRY_NEUTRAL_SCORE: u8 = 50;
+
+/// Max pass link-up effect at score 0 / 100 (±5%)
+pub const PASS_LINK_MAX_EFFECT: f32 = 0.05;
+
+/// Max team pass success effect at lineup score 0 / 100 (±3%)
+pub const TEAM_PASS_SUCCESS_MAX_EFFECT: f32 = 0.03;
+
+/// Max team off-ball movement effect at lineup score 0 / 100 (±8%)
+pub const TEAM_OFF_BALL_MAX_EFFECT: f32 = 0.08;
+
+/// One player's time on the pitch in a single match
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatchAppearance {
+    pub name: String,
+    /// Minute the player came on (0 for starters)
+    #[serde(default)]
+    pub minute_on: u8,
+    /// Minute the player went off (90 if played to the end)
+    #[serde(default = "default_minute_off")]
+    pub minute_off: u8,
+}
+
+fn default_minute_off() -> u8 {
+    90
+}
+
+impl MatchAppearance {
+    /// Starter who played the whole match
+    pub fn full_match(name: impl Into<String>) -> Self {
+        Self { name: name.into(), minute_on: 0, minute_off: 90 }
+    }
+
+    /// Minutes both players were on the pitch
+    pub fn overlap_minutes(&self, other: &MatchAppearance) -> u32 {
+        let start = self.minute_on.max(other.minute_on);
+        let end = self.minute_off.min(other.minute_off);
+        end.saturating_sub(start) as u32
+    }
+}
+
+/// Accumulated minutes played together, per player pair
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SharedMinutes {
+    pairs: BTreeMap<(String, String), u32>,
+}
+
+impl SharedMinutes {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build from a list of past match lineups
+    pub fn from_matches<'a, I>(matches: I) -> Self
+    where
+        I: IntoIterator<Item = &'a [MatchAppearance]>,
+    {
+        let mut shared = Self::new();
+        for appearances in matches {
+            shared.record_match(appearances);
+        }
+        shared
+    }
+
+    /// Add one match worth of pairwise overlap
+    pub fn record_match(&mut self, appearances: &[MatchAppearance]) {
+        for (i, a) in appearances.iter().enumerate() {
+            for b in &appearances[i + 1..] {
+                if a.name == b.name {
+                    continue;
+                }
+                let minutes = a.overlap_minutes(b);
+                if minutes > 0 {
+                    *self.pairs.entry(pair_key(&a.name, &b.name)).or_insert(0) += minutes;
+                }
+            }
+        }
+    }
+
+    /// Minutes `a` and `b` have played together
+    pub fn minutes_together(&self, a: &str, b: &str) -> u32 {
+        self.pairs.get(&pair_key(a, b)).copied().unwrap_or(0)
+    }
+}
+
+fn pair_key(a: &str, b: &str) -> (String, String) {
+    if a <= b {
+        (a.to_string(), b.to_string())
+    } else {
+        (b.to_string(), a.to_string())
+    }
+}
+
+/// Per-player chemistry inputs
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChemistryProfile {
+    pub name: String,
+    #[serde(default)]
+    pub nationality: Option<String>,
+    #[serde(default)]
+    pub personality: PersonalityArchetype,
+    /// Clubs played for (career history from save data)
+    #[serde(default)]
+    pub clubs: Vec<String>,
+}
+
+/// Why a pair scores what it does (squad UI breakdown)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChemistryLink {
+    pub a: String,
+    pub b: String,
+    /// Final pair score (0..=100)
+    pub score: u8,
+    /// From minutes played together
+    pub history: i32,
+    pub nationality: i32,
+    pub club: i32,
+    pub personality: i32,
+    /// First club both players have played for
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shared_club: Option<String>,
+}
+
+/// Personality affinity between two archetypes (symmetric, -10..=10)
+pub fn personality_affinity(a: PersonalityArchetype, b: PersonalityArchetype) -> i32 {
+    use PersonalityArchetype::*;
+    match (a, b) {
+        (Leader, Workhorse) | (Workhorse, Leader) => 10,
+        (Leader, Steady) | (Steady, Leader) => 5,
+        (Leader, Genius) | (Genius, Leader) => 5,
+        (Steady, Steady) | (Workhorse, Workhorse) => 5,
+        (Workhorse, Steady) | (Steady, Workhorse) => 5,
+        (Leader, Leader) => -5,
+        (Rebel, Steady) | (Steady, Rebel) => -5,
+        (Genius, Rebel) | (Rebel, Genius) => -10,
+        (Rebel, Rebel) => -5,
+        _ => 0,
+    }
+}
+
+/// Squad chemistry matrix (row/column order = `players`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct SquadChemistry {
+    pub players: Vec<String>,
+    /// Symmetric pair scores (0..=100); diagonal is 100
+    pub matrix: Vec<Vec<u8>>,
+}
+
+impl SquadChemistry {
+    /// Compute chemistry for a squad from profiles and shared history
+    pub fn compute(profiles: &[ChemistryProfile], shared: &SharedMinutes) -> Self {
+        let n = profiles.len();
+        let mut matrix = vec![vec![100u8; n]; n];
+
+        for i in 0..n {
+            for j in (i + 1)..n {
+                let score = chemistry_link(&profiles[i], &profiles[j], shared).score;
+                matrix[i][j] = score;
+                matrix[j][i] = score;
+            }
+        }
+
+        Self { players: profiles.iter().map(|p| p.name.clone()).collect(), matrix }
+    }
+
+    fn index_of(&self, name: &str) -> Option<usize> {
+        self.players.iter().position(|p| p == name)
+    }
+
+    /// Pair score by player name (None if either player is unknown)
+    pub fn pair(&self, a: &str, b: &str) -> Option<u8> {
+        let i = self.index_of(a)?;
+        let j = self.index_of(b)?;
+        self.matrix.get(i)?.get(j).copied()
+    }
+
+    /// Average pair score of one player against the rest of the squad
+    pub fn player_score(&self, index: usize) -> u8 {
+        let Some(row) = self.matrix.get(index) else {
+            return CHEMISTRY_NEUTRAL_SCORE;
+        };
+        let others: Vec<u32> =
+            row.iter().enumerate().filter(|(j, _)| *j != index).map(|(_, v)| *v as u32).collect();
+        if others.is_empty() {
+            return CHEMISTRY_NEUTRAL_SCORE;
+        }
+        (others.iter().sum::<u32>() / others.len() as u32) as u8
+    }
+
+    /// Average of all off-diagonal pair scores
+    pub fn team_score(&self) -> u8 {
+        let n = self.players.len();
+        if n < 2 {
+            return CHEMISTRY_NEUTRAL_SCORE;
+        }
+        let mut total = 0u32;
+        let mut count = 0u32;
+        for i in 0..n {
+            for j in (i + 1)..n {
+                total += self.matrix[i][j] as u32;
+                count += 1;
+            }
+        }
+        (total / count) as u8
+    }
+
+    /// Average pair score among the named players (unknown names are skipped)
+    pub fn lineup_score(&self, names: &[&str]) -> u8 {
+        let indices: Vec<usize> = names.iter().filter_map(|name| self.index_of(name)).collect();
+        let mut total = 0u32;
+        let mut count = 0u32;
+        for (k, &i) in indices.iter().enumerate() {
+            for &j in &indices[k + 1..] {
+                if i != j {
+                    total += self.matrix[i][j] as u32;
+                    count += 1;
+                }
+            }
+        }
+        if count == 0 {
+            return CHEMISTRY_NEUTRAL_SCORE;
+        }
+        (total / count) as u8
+    }
+
+    /// Pass link-up multiplier for passer → receiver (1.0 when unknown)
+    pub fn pass_link_mult(&self, passer: &str, receiver: &str) -> f32 {
+        self.pair(passer, receiver).map(pass_link_mult_for_score).unwrap_or(1.0)
+    }
+}
+
+/// Map a pair score to a bounded pass link-up multiplier (0.95..=1.05)
+pub fn pass_link_mult_for_score(score: u8) -> f32 {
+    let t =
+        (score.min(100) as f32 - CHEMISTRY_NEUTRAL_SCORE as f32) / CHEMISTRY_NEUTRAL_SCORE as f32;
+    1.0 + t * PASS_LINK_MAX_EFFECT
+}
+
+/// Team-wide effect of a lineup chemistry score (neutral at 50)
+pub fn team_chemistry_modifiers(score: u8) -> TeamMatchModifiers {
+    let t =
+        (score.min(100) as f32 - CHEMISTRY_NEUTRAL_SCORE as f32) / CHEMISTRY_NEUTRAL_SCORE as f32;
+    TeamMatchModifiers {
+        pass_success_mult: 1.0 + t * TEAM_PASS_SUCCESS_MAX_EFFECT,
+        off_ball_mult: 1.0 + t * TEAM_OFF_BALL_MAX_EFFECT,
+        ..TeamMatchModifiers::default()
+    }
+}
+
+/// Every pair in the squad with its score breakdown (same order as the matrix rows)
+pub fn chemistry_links(
+    profiles: &[ChemistryProfile],
+    shared: &SharedMinutes,
+) -> Vec<ChemistryLink> {
+    let mut links = Vec::new();
+    for (i, a) in profiles.iter().enumerate() {
+        for b in &profiles[i + 1..] {
+            links.push(chemistry_link(a, b, shared));
+        }
+    }
+    links
+}
+
+/// Score breakdown for one pair
+pub fn chemistry_link(
+    a: &ChemistryProfile,
+    b: &ChemistryProfile,
+    shared: &SharedMinutes,
+) -> ChemistryLink {
+    let minutes = shared.minutes_together(&a.name, &b.name).min(CHEMISTRY_FULL_MINUTES);
+    let history =
+        (minutes as i64 * CHEMISTRY_HISTORY_MAX as i64 / CHEMISTRY_FULL_MINUTES as i64) as i32;
+
+    let nationality = match (&a.nationality, &b.nationality) {
+        (Some(na), Some(nb)) if !na.is_empty() && na.eq_ignore_ascii_case(nb) => {
+            CHEMISTRY_NATIONALITY_BONUS
+        }
+        _ => 0,
+    };
+
+    let shared_club = a
+        .clubs
+        .iter()
+        .find(|club| {
+            !club.is_empty() && b.clubs.iter().any(|other| other.eq_ignore_ascii_case(club))
+        })
+        .cloned();
+    let club = if shared_club.is_some() { CHEMISTRY_CLUB_BONUS } else { 0 };
+    let personality = personality_affinity(a.personality, b.personality);
+
+    let score = CHEMISTRY_BASE_SCORE + history + nationality + club + personality;
+    ChemistryLink {
+        a: a.name.clone(),
+        b: b.name.clone(),
+        score: score.clamp(0, 100) as u8,
+        history,
+        nationality,
+        club,
+        personality,
+        shared_club,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile(
+        name: &str,
+        nationality: Option<&str>,
+        personality: PersonalityArchetype,
+    ) -> ChemistryProfile {
+        ChemistryProfile {
+            name: name.to_string(),
+            nationality: nationality.map(str::to_string),
+            personality,
+            clubs: vec![],
+        }
+    }
+
+    #[test]
+    fn test_shared_minutes_overlap() {
+        let lineup = vec![
+            MatchAppearance::full_match("A"),
+            MatchAppearance { name: "B".to_string(), minute_on: 0, minute_off: 60 },
+            MatchAppearance { name: "C".to_string(), minute_on: 60, minute_off: 90 },
+        ];
+        let shared = SharedMinutes::from_matches([lineup.as_slice(), lineup.as_slice()]);
+
+        assert_eq!(shared.minutes_together("A", "B"), 120);
+        assert_eq!(shared.minutes_together("B", "A"), 120);
+        assert_eq!(shared.minutes_together("A", "C"), 60);
+        assert_eq!(shared.minutes_together("B", "C"), 0);
+    }
+
+    #[test]
+    fn test_history_and_affinity_raise_score() {
+        let profiles = vec![
+            profile("A", Some("KOR"), PersonalityArchetype::Leader),
+            profile("B", Some("kor"), PersonalityArchetype::Workhorse),
+            profile("C", Some("BRA"), PersonalityArchetype::Steady),
+        ];
+        let lineup: Vec<_> = ["A", "B"].iter().map(|n| MatchAppearance::full_match(*n)).collect();
+        let mut shared = SharedMinutes::new();
+        for _ in 0..20 {
+            shared.record_match(&lineup);
+        }
+
+        let chem = SquadChemistry::compute(&profiles, &shared);
+        assert_eq!(chem.pair("A", "B"), Some(100));
+        assert_eq!(chem.pair("A", "C"), Some(45));
+        assert_eq!(chem.pair("A", "B"), chem.pair("B", "A"));
+        assert_eq!(chem.pair("A", "Z"), None);
+        assert!(chem.player_score(0) > chem.player_score(2));
+    }
+
+    #[test]
+    fn test_pass_link_mult_is_bounded() {
+        assert_eq!(pass_link_mult_for_score(CHEMISTRY_NEUTRAL_SCORE), 1.0);
+        assert!((pass_link_mult_for_score(100) - 1.05).abs() < 1e-6);
+        assert!((pass_link_mult_for_score(0) - 0.95).abs() < 1e-6);
+        assert!((pass_link_mult_for_score(255) - 1.05).abs() < 1e-6);
+
+        let chem = SquadChemistry::compute(&[], &SharedMinutes::new());
+        assert_eq!(chem.pass_link_mult("A", "B"), 1.0);
+        assert_eq!(chem.team_score(), CHEMISTRY_NEUTRAL_SCORE);
+    }
+
+    #[test]
+    fn test_shared_club_and_lineup_score() {
+        let mut a = profile("A", None, PersonalityArchetype::Steady);
+        let mut b = profile("B", None, PersonalityArchetype::Genius);
+        a.clubs = vec!["Suwon".to_string(), "Ulsan".to_string()];
+        b.clubs = vec!["ulsan".to_string()];
+        let c = profile("C", None, PersonalityArchetype::Genius);
+        let profiles = vec![a, b, c];
+
+        let links = chemistry_links(&profiles, &SharedMinutes::new());
+        assert_eq!(links.len(), 3);
+        assert_eq!(links[0].club, CHEMISTRY_CLUB_BONUS);
+        assert_eq!(links[0].shared_club.as_deref(), Some("Ulsan"));
+        assert_eq!(links[1].club, 0);
+
+        let chem = SquadChemistry::compute(&profiles, &SharedMinutes::new());
+        assert_eq!(chem.pair("A", "B"), Some(links[0].score));
+        assert_eq!(chem.lineup_score(&["A", "B", "Z"]), links[0].score);
+        assert_eq!(chem.lineup_score(&["A"]), CHEMISTRY_NEUTRAL_SCORE);
+
+        let neutral = team_chemistry_modifiers(CHEMISTRY_NEUTRAL_SCORE);
+        assert_eq!(neutral, TeamMatchModifiers::default());
+        let strong = team_chemistry_modifiers(100);
+        assert!((strong.pass_success_mult - 1.03).abs() < 1e-6);
+        assert!((strong.off_ball_mult - 1.08).abs() < 1e-6);
+    }
+}
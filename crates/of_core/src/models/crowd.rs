@@ -0,0 +1,199 @@
+//! Crowd pressure (home advantage from the stands)
+//!
+//! A stadium with an `attendance` gets a `CrowdPressure` for the fixture. Its
+//! intensity grows with how full the ground is and how big the crowd is, plus
+//! a seeded "mood of the day", and it leans on the visitors:
+//! - referee: away challenges are whistled and booked a little more readily,
+//!   home ones a little less (`foul_call_mult` / `card_mult` per side)
+//! - away composure is scaled down before kickoff
+//!
+//! `from_stadium` derives the mood from the match seed with its own salt, so
+//! it never consumes the match RNG stream. An empty ground is neutral.
+
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::stadium::Stadium;
+use crate::engine::TeamMatchModifiers;
+
+/// Salt so the crowd mood does not mirror match or referee rolls.
+const CROWD_SEED_SALT: u64 = 0x0C20_D0F5_A25B_00E5;
+
+/// Crowd size at which the noise stops growing
+pub const FULL_VOLUME_ATTENDANCE: u32 = 60_000;
+/// Max swing of the seeded mood of the day (± intensity)
+const MOOD_SWING: f32 = 0.1;
+
+/// Full-intensity effects
+const MAX_AWAY_COMPOSURE_LOSS: f32 = 0.10;
+const MAX_AWAY_FOUL_CALL: f32 = 0.08;
+const MAX_HOME_FOUL_CALL: f32 = -0.04;
+const MAX_AWAY_CARD: f32 = 0.12;
+const MAX_HOME_CARD: f32 = -0.06;
+
+/// How the ground sounds on the day (narrative flavor)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CrowdMood {
+    Subdued,
+    Lively,
+    Raucous,
+    Hostile,
+}
+
+impl CrowdMood {
+    fn from_intensity(intensity: f32) -> Self {
+        match intensity {
+            i if i < 0.25 => CrowdMood::Subdued,
+            i if i < 0.5 => CrowdMood::Lively,
+            i if i < 0.75 => CrowdMood::Raucous,
+            _ => CrowdMood::Hostile,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            CrowdMood::Subdued => "subdued",
+            CrowdMood::Lively => "lively",
+            CrowdMood::Raucous => "raucous",
+            CrowdMood::Hostile => "hostile",
+        }
+    }
+}
+
+/// Home crowd for one fixture (reported in `MatchResult.crowd`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct CrowdPressure {
+    pub stadium: String,
+    pub attendance: u32,
+    /// attendance / capacity (1.0 when the capacity is unknown)
+    pub fill_ratio: f32,
+    /// 0.0 (silent) .. 1.0 (cauldron)
+    pub intensity: f32,
+    pub mood: CrowdMood,
+    /// e.g. "A hostile crowd of 52,000 at Anfield (96% full)"
+    pub summary: String,
+}
+
+impl CrowdPressure {
+    /// Crowd for `stadium` (None when it has no attendance). Same seed → same mood.
+    pub fn from_stadium(stadium: &Stadium, seed: u64) -> Option<Self> {
+        let attendance = stadium.attendance?;
+        let fill_ratio = if attendance == 0 {
+            0.0
+        } else if stadium.capacity == 0 {
+            1.0
+        } else {
+            (attendance as f32 / stadium.capacity as f32).min(1.0)
+        };
+        let volume = (attendance as f32 / FULL_VOLUME_ATTENDANCE as f32).min(1.0);
+
+        let mut rng = ChaCha8Rng::seed_from_u64(seed ^ CROWD_SEED_SALT);
+        let mood_of_the_day = rng.gen_range(-MOOD_SWING..=MOOD_SWING);
+        let intensity = if attendance == 0 {
+            0.0
+        } else {
+            (0.6 * fill_ratio + 0.4 * volume + mood_of_the_day).clamp(0.0, 1.0)
+        };
+        let mood = CrowdMood::from_intensity(intensity);
+
+        let summary = format!(
+            "A {} crowd of {} at {} ({:.0}% full)",
+            mood.label(),
+            thousands(attendance),
+            stadium.name,
+            fill_ratio * 100.0
+        );
+        Some(Self {
+            stadium: stadium.name.clone(),
+            attendance,
+            fill_ratio,
+            intensity,
+            mood,
+            summary,
+        })
+    }
+
+    /// Composure multiplier for the visiting players (1.0 = unaffected)
+    pub fn away_composure_mult(&self) -> f32 {
+        1.0 - MAX_AWAY_COMPOSURE_LOSS * self.intensity
+    }
+
+    /// Referee foul-call multiplier for one side's challenges
+    pub fn foul_call_mult(&self, is_home: bool) -> f32 {
+        let full = if is_home { MAX_HOME_FOUL_CALL } else { MAX_AWAY_FOUL_CALL };
+        1.0 + full * self.intensity
+    }
+
+    /// Referee card multiplier for one side's fouls
+    pub fn card_mult(&self, is_home: bool) -> f32 {
+        let full = if is_home { MAX_HOME_CARD } else { MAX_AWAY_CARD };
+        1.0 + full * self.intensity
+    }
+
+    /// Fold the referee lean into both teams' match modifiers
+    pub fn apply_effects(&self, home: &mut TeamMatchModifiers, away: &mut TeamMatchModifiers) {
+        for (is_home, modifiers) in [(true, home), (false, away)] {
+            modifiers.foul_call_mult *= self.foul_call_mult(is_home);
+            modifiers.card_mult *= self.card_mult(is_home);
+        }
+    }
+}
+
+/// 52000 → "52,000"
+fn thousands(value: u32) -> String {
+    let digits = value.to_string();
+    let mut out = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            out.push(',');
+        }
+        out.push(c);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ground(capacity: u32, attendance: Option<u32>) -> Stadium {
+        Stadium { capacity, attendance, ..Stadium::standard("Anfield") }
+    }
+
+    #[test]
+    fn test_crowd_is_seeded_and_scales_with_the_ground() {
+        assert!(CrowdPressure::from_stadium(&ground(54_000, None), 7).is_none());
+
+        let full = CrowdPressure::from_stadium(&ground(54_000, Some(52_000)), 7).unwrap();
+        assert_eq!(full, CrowdPressure::from_stadium(&ground(54_000, Some(52_000)), 7).unwrap());
+        assert!(full.intensity > 0.6, "{}", full.intensity);
+        assert!(full.summary.contains("52,000 at Anfield (96% full)"), "{}", full.summary);
+
+        let sparse = CrowdPressure::from_stadium(&ground(54_000, Some(6_000)), 7).unwrap();
+        assert!(sparse.intensity < full.intensity);
+
+        let empty = CrowdPressure::from_stadium(&ground(54_000, Some(0)), 7).unwrap();
+        assert_eq!(empty.intensity, 0.0);
+        assert_eq!(empty.away_composure_mult(), 1.0);
+        assert_eq!(empty.foul_call_mult(false), 1.0);
+    }
+
+    #[test]
+    fn test_crowd_leans_on_the_visitors() {
+        let crowd = CrowdPressure::from_stadium(&ground(0, Some(60_000)), 3).unwrap();
+        assert_eq!(crowd.fill_ratio, 1.0);
+
+        let mut home = TeamMatchModifiers::default();
+        let mut away = TeamMatchModifiers::default();
+        crowd.apply_effects(&mut home, &mut away);
+        assert!(away.foul_call_mult > 1.0 && home.foul_call_mult < 1.0);
+        assert!(away.card_mult > 1.0 && home.card_mult < 1.0);
+        assert!(crowd.away_composure_mult() < 1.0);
+        // Only the referee stats move
+        assert_eq!(home.pass_success_mult, 1.0);
+        assert_eq!(away.stamina_drain_mult, 1.0);
+    }
+}
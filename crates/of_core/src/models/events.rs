@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 
-use super::rules::{FoulDetails, FoulSeverity, OffsideDetails, RuleId};
+use super::rules::{AdvantageDetails, FoulDetails, FoulSeverity, OffsideDetails, RuleId};
+use crate::engine::xg_model::XgFeatures;
+use super::weather::MatchConditions;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct MatchEvent {
@@ -64,8 +66,26 @@ pub enum EventType {
     HalfTime,
     /// Full-time whistle
     FullTime,
-    /// VAR review (v0: informational, no overturn yet)
+    /// VAR review (v0: informational; superseded by `VarReviewStarted`/`VarDecision`)
     VarReview,
+    /// Half-time team talk (tone + squad reaction)
+    TeamTalk,
+    /// AI opponent adapted its instructions to a detected pattern
+    TacticalAdaptation,
+    /// Match-day weather and pitch condition (emitted at kickoff)
+    Weather,
+    /// Stoppage time announced by the fourth official (end of each half)
+    AddedTime,
+    /// Referee played advantage after a foul (signalled, accrued or called back)
+    AdvantagePlayed,
+    /// Goalkeeper handled a team-mate's deliberate kick or throw-in (indirect free kick)
+    BackPassOffence,
+    /// Goalkeeper held the ball in the hands beyond six seconds (indirect free kick)
+    GoalkeeperHolding,
+    /// VAR started reviewing an incident (goal, penalty or direct red card)
+    VarReviewStarted,
+    /// VAR review finished: decision upheld or overturned (duration added to stoppage time)
+    VarDecision,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
@@ -76,6 +96,9 @@ pub struct EventDetails {
     // - own_goal_by: Use player_track_id on parent MatchEvent
     #[serde(skip_serializing_if = "Option::is_none")]
     pub xg_value: Option<f32>,
+    /// Feature vector behind `xg_value` (contextual xG model inputs)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub xg_features: Option<XgFeatures>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub injury_severity: Option<InjurySeverity>,
     /// Ball position when event occurred (Coord10 units + height)
@@ -109,12 +132,33 @@ pub struct EventDetails {
     /// VAR review metadata (reviewed event type, outcome).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub var_review: Option<VarReviewDetails>,
+    /// Half-time team talk metadata (tone, reaction, applied modifiers).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team_talk: Option<TeamTalkDetails>,
+    /// AI opponent adaptation metadata (detected pattern, response).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tactical_adaptation: Option<TacticalAdaptationDetails>,
+    /// Weather / pitch condition metadata (kickoff only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weather: Option<MatchConditions>,
+    /// Corner / free-kick routine taken (taker, delivery, wall).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub set_piece_routine: Option<SetPieceRoutineDetails>,
+    /// Announced stoppage time (added-time events only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub added_time: Option<AddedTimeDetails>,
+    /// Passes credited with creating the shot (shot / save / goal events).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chance_creation: Option<ChanceCreationDetails>,
     /// Whether advantage was played on a foul (play continues without restart).
     ///
     /// This is set only when advantage is played (`true`). When absent, treat
     /// as "no advantage" (default).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub advantage_played: Option<bool>,
+    /// Advantage decision and deferred card (advantage events only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub advantage: Option<AdvantageDetails>,
 
     // =========================================================================
     // RuleBook System (IFAB Laws of the Game)
@@ -137,6 +181,10 @@ pub struct EventDetails {
     /// FIX_2601/0123 Phase 6: 핸드볼 상세 정보
     #[serde(skip_serializing_if = "Option::is_none")]
     pub handball_details: Option<HandballDetails>,
+
+    /// 골키퍼 핸들링 반칙 상세 (Law 12.2, 간접 프리킥)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub goalkeeper_offence: Option<GoalkeeperOffenceDetails>,
 }
 
 /// Handball event details (FIX_2601/0123 Phase 6)
@@ -160,6 +208,98 @@ pub enum HandballRestartType {
     Penalty,
 }
 
+/// Goalkeeper handling offence details (Law 12.2, indirect free kick)
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct GoalkeeperOffenceDetails {
+    /// Back-pass came from a team-mate's throw-in rather than a deliberate kick
+    #[serde(default)]
+    pub from_throw_in: bool,
+    /// Time the ball was held in the hands (holding offence only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub held_ms: Option<u32>,
+}
+
+/// Chance-creation credit, captured by the engine when the shot is struck
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ChanceCreationDetails {
+    /// Last completed pass to the shooter (key pass; the assist if scored)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key_pass_track_id: Option<u8>,
+    /// Pass that set up the key pass (second assist if scored)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub second_assist_track_id: Option<u8>,
+}
+
+impl ChanceCreationDetails {
+    pub fn is_empty(&self) -> bool {
+        self.key_pass_track_id.is_none() && self.second_assist_track_id.is_none()
+    }
+}
+
+/// Stoppage time shown on the board at the end of a half
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AddedTimeDetails {
+    /// 1 = first half, 2 = second half
+    pub half: u8,
+    /// Announced minutes (whole minutes, rounded up from accumulated stoppages)
+    pub minutes: u8,
+}
+
+/// Broadcast clock labels ("45+2'", "90+4'") for the engine's running minute.
+///
+/// Engine minutes run on without a reset: the second half kicks off at
+/// `45 + first_half_added`, so a raw minute needs the announced first-half
+/// added time to map back to the printed clock.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MatchClock {
+    pub first_half_added: u8,
+    pub second_half_added: u8,
+}
+
+impl MatchClock {
+    /// Read the announced added time from `AddedTime` events (0 when absent).
+    pub fn from_events(events: &[MatchEvent]) -> Self {
+        let mut clock = Self::default();
+        for details in events.iter().filter_map(MatchEvent::added_time_details) {
+            match details.half {
+                1 => clock.first_half_added = details.minutes,
+                2 => clock.second_half_added = details.minutes,
+                _ => {}
+            }
+        }
+        clock
+    }
+
+    /// Engine minute at which the second half kicks off
+    pub fn first_half_end_minute(&self) -> u8 {
+        45u8.saturating_add(self.first_half_added)
+    }
+
+    /// Engine minute after which the match is over
+    pub fn match_end_minute(&self) -> u8 {
+        self.first_half_end_minute().saturating_add(45).saturating_add(self.second_half_added)
+    }
+
+    /// Printed clock for an engine minute, e.g. 47 → "45+2'" in a 3-minute first-half stoppage.
+    pub fn label(&self, minute: u8) -> String {
+        let first_half_end = self.first_half_end_minute();
+        if minute < first_half_end {
+            if minute > 45 {
+                format!("45+{}'", minute - 45)
+            } else {
+                format!("{}'", minute)
+            }
+        } else {
+            let clock = minute as u16 - first_half_end as u16 + 45;
+            if clock > 90 {
+                format!("90+{}'", clock - 90)
+            } else {
+                format!("{}'", clock)
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct SubstitutionDetails {
     pub player_in_name: String,
@@ -181,10 +321,127 @@ pub enum VarReviewOutcome {
     Overturned,
 }
 
+/// Incident checked by VAR
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum VarIncident {
+    /// Goal checked for offside in the attacking phase
+    OffsideOnGoal,
+    /// Penalty checked for foul location and whether there was a foul
+    Penalty,
+    /// Direct red card checked for serious foul play
+    RedCard,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct VarReviewDetails {
     pub reviewed_event_type: EventType,
-    pub outcome: VarReviewOutcome,
+    /// None while the review is in progress (`VarReviewStarted`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub outcome: Option<VarReviewOutcome>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub incident: Option<VarIncident>,
+    /// Chance the on-field decision is overturned (decision events only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub overturn_probability: Option<f32>,
+    /// Review length added to stoppage time (decision events only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_s: Option<u16>,
+    /// Attacker's distance beyond the offside line (offside checks only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offside_margin_m: Option<f32>,
+    /// Restart after an overturned penalty (free kick outside the area / dropped ball)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub overturned_restart: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum TeamTalkTone {
+    Calm,
+    Encourage,
+    Praise,
+    Demand,
+    Criticize,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum TeamTalkReaction {
+    Fired,
+    Neutral,
+    Deflated,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TeamTalkDetails {
+    pub tone: TeamTalkTone,
+    pub reaction: TeamTalkReaction,
+    /// Squad receptiveness (-1..1) that drove the reaction
+    pub receptiveness: f32,
+    /// Second-half pass/shot multiplier applied (bounded around 1.0)
+    pub technique_mult: f32,
+    /// Second-half pressing intensity added
+    pub press_intensity_add: f32,
+}
+
+/// Dominant pattern detected in the opponent's play since the last checkpoint
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum AdaptationPattern {
+    /// Most final-third possession down the attacker's left
+    LeftFlankOverload,
+    /// Most final-third possession down the attacker's right
+    RightFlankOverload,
+    /// Most final-third possession through the middle
+    CentralOverload,
+    /// Frequent ball recoveries in the adapting team's half
+    HighPress,
+}
+
+/// Instruction change the adapting team made in response
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum AdaptationResponse {
+    /// Wider shape to double up on the overloaded flank
+    WidenShape,
+    /// Compact shape to crowd the middle
+    NarrowShape,
+    /// Go direct to bypass the press
+    PlayOverPress,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TacticalAdaptationDetails {
+    pub pattern: AdaptationPattern,
+    pub response: AdaptationResponse,
+    /// Share of the sampled window that showed the pattern (0..1)
+    pub share: f32,
+    /// Samples in the window the decision was based on
+    pub samples: u32,
+}
+
+/// Routine chosen for a corner or free kick
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SetPieceRoutineKind {
+    Inswing,
+    NearPost,
+    FarPost,
+    ShortCorner,
+    DirectShot,
+    Cross,
+    ShortPass,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SetPieceRoutineDetails {
+    pub routine: SetPieceRoutineKind,
+    /// Taker came from the team's designated list
+    pub designated_taker: bool,
+    /// Defending wall jumped (direct free kicks only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wall_jump: Option<bool>,
 }
 
 impl MatchEvent {
@@ -465,12 +722,72 @@ impl MatchEvent {
         self
     }
 
+    /// Attach the contextual xG feature vector (shot / save / goal events)
+    pub fn with_xg_features(mut self, features: Option<XgFeatures>) -> Self {
+        if let Some(features) = features {
+            if let Some(ref mut details) = self.details {
+                details.xg_features = Some(features);
+            } else {
+                self.details =
+                    Some(EventDetails { xg_features: Some(features), ..Default::default() });
+            }
+        }
+        self
+    }
+
+    /// Attach chance-creation credit (shot / save / goal events; no-op when empty)
+    pub fn with_chance_creation(mut self, creation: ChanceCreationDetails) -> Self {
+        if creation.is_empty() {
+            return self;
+        }
+        if let Some(ref mut details) = self.details {
+            details.chance_creation = Some(creation);
+        } else {
+            self.details =
+                Some(EventDetails { chance_creation: Some(creation), ..Default::default() });
+        }
+        self
+    }
+
+    /// Chance-creation credit carried by this event, if any
+    pub fn chance_creation(&self) -> Option<&ChanceCreationDetails> {
+        self.details.as_ref().and_then(|d| d.chance_creation.as_ref())
+    }
+
+    /// Announced stoppage time carried by an `AddedTime` event
+    pub fn added_time_details(&self) -> Option<&AddedTimeDetails> {
+        self.details.as_ref().and_then(|d| d.added_time.as_ref())
+    }
+
+    /// VAR review metadata (VAR events only)
+    pub fn var_review_details(&self) -> Option<&VarReviewDetails> {
+        self.details.as_ref().and_then(|d| d.var_review.as_ref())
+    }
+
+    /// Goalkeeper handling offence (back-pass / holding events only)
+    pub fn goalkeeper_offence_details(&self) -> Option<&GoalkeeperOffenceDetails> {
+        self.details.as_ref().and_then(|d| d.goalkeeper_offence.as_ref())
+    }
+
+    /// Advantage decision (advantage events only)
+    pub fn advantage_details(&self) -> Option<&AdvantageDetails> {
+        self.details.as_ref().and_then(|d| d.advantage.as_ref())
+    }
+
     /// Set timestamp_ms for position_data synchronization
     pub fn with_timestamp(mut self, timestamp_ms: u64) -> Self {
         self.timestamp_ms = Some(timestamp_ms);
         self
     }
 
+    /// Millisecond match time used for ordering (falls back to minute start).
+    ///
+    /// Prefer this over `minute` for sorting: minute-only ordering cannot
+    /// distinguish events within the same minute.
+    pub fn timeline_ms(&self) -> u64 {
+        self.timestamp_ms.unwrap_or(self.minute as u64 * 60_000)
+    }
+
     /// Set Event SSOT target track_id (secondary actor).
     /// Examples: pass receiver, foul victim, tackle target, shot shooter on save events.
     pub fn with_target_track_id(mut self, target_track_id: Option<usize>) -> Self {
@@ -587,6 +904,12 @@ impl MatchEvent {
         self
     }
 
+    /// Attach the set-piece routine taken to a corner / free-kick event
+    pub fn with_set_piece_routine(mut self, routine: SetPieceRoutineDetails) -> Self {
+        self.details.get_or_insert_with(EventDetails::default).set_piece_routine = Some(routine);
+        self
+    }
+
     /// Create a corner kick event
     /// C5: timestamp_ms is now engine-confirmed
     /// C6: track_id is now engine-confirmed (0-21)
@@ -741,6 +1064,60 @@ impl MatchEvent {
         }
     }
 
+    /// Create a back-pass offence (player = goalkeeper, target = team-mate who played it)
+    pub fn back_pass_offence(
+        minute: u8,
+        timestamp_ms: u64,
+        is_home_team: bool,
+        goalkeeper_track_id: usize,
+        passer_track_id: Option<usize>,
+        ball_position: (f32, f32, f32),
+        from_throw_in: bool,
+    ) -> Self {
+        Self {
+            minute,
+            timestamp_ms: Some(timestamp_ms),
+            event_type: EventType::BackPassOffence,
+            is_home_team,
+            player_track_id: Some(goalkeeper_track_id as u8),
+            target_track_id: passer_track_id.map(|idx| idx as u8),
+            details: Some(EventDetails {
+                ball_position: Some(ball_position),
+                rule_id: Some(RuleId::BackPass),
+                goalkeeper_offence: Some(GoalkeeperOffenceDetails { from_throw_in, held_ms: None }),
+                ..Default::default()
+            }),
+        }
+    }
+
+    /// Create a six-second holding offence (player = goalkeeper)
+    pub fn goalkeeper_holding(
+        minute: u8,
+        timestamp_ms: u64,
+        is_home_team: bool,
+        goalkeeper_track_id: usize,
+        ball_position: (f32, f32, f32),
+        held_ms: u32,
+    ) -> Self {
+        Self {
+            minute,
+            timestamp_ms: Some(timestamp_ms),
+            event_type: EventType::GoalkeeperHolding,
+            is_home_team,
+            player_track_id: Some(goalkeeper_track_id as u8),
+            target_track_id: None,
+            details: Some(EventDetails {
+                ball_position: Some(ball_position),
+                rule_id: Some(RuleId::GoalkeeperHolding),
+                goalkeeper_offence: Some(GoalkeeperOffenceDetails {
+                    from_throw_in: false,
+                    held_ms: Some(held_ms),
+                }),
+                ..Default::default()
+            }),
+        }
+    }
+
     /// Create an offside event
     /// C5: timestamp_ms is now engine-confirmed
     /// C6: track_id is now engine-confirmed (0-21)
@@ -922,10 +1299,154 @@ impl MatchEvent {
             details: Some(EventDetails {
                 var_review: Some(VarReviewDetails {
                     reviewed_event_type,
-                    outcome,
+                    outcome: Some(outcome),
+                    incident: None,
+                    overturn_probability: None,
+                    duration_s: None,
+                    offside_margin_m: None,
+                    overturned_restart: None,
                 }),
                 ..Default::default()
             }),
         }
     }
+
+    /// Create a VAR review start event (player = player whose incident is checked)
+    pub fn var_review_started(
+        minute: u8,
+        timestamp_ms: u64,
+        is_home_team: bool,
+        player_track_id: Option<usize>,
+        incident: VarIncident,
+        reviewed_event_type: EventType,
+    ) -> Self {
+        Self {
+            minute,
+            timestamp_ms: Some(timestamp_ms),
+            event_type: EventType::VarReviewStarted,
+            is_home_team,
+            player_track_id: player_track_id.map(|idx| idx as u8),
+            target_track_id: None,
+            details: Some(EventDetails {
+                var_review: Some(VarReviewDetails {
+                    reviewed_event_type,
+                    outcome: None,
+                    incident: Some(incident),
+                    overturn_probability: None,
+                    duration_s: None,
+                    offside_margin_m: None,
+                    overturned_restart: None,
+                }),
+                ..Default::default()
+            }),
+        }
+    }
+
+    /// Create a VAR decision event (`details.outcome` is set)
+    pub fn var_decision(
+        minute: u8,
+        timestamp_ms: u64,
+        is_home_team: bool,
+        player_track_id: Option<usize>,
+        details: VarReviewDetails,
+    ) -> Self {
+        Self {
+            minute,
+            timestamp_ms: Some(timestamp_ms),
+            event_type: EventType::VarDecision,
+            is_home_team,
+            player_track_id: player_track_id.map(|idx| idx as u8),
+            target_track_id: None,
+            details: Some(EventDetails { var_review: Some(details), ..Default::default() }),
+        }
+    }
+
+    /// Create a half-time team talk event (team-level, no player).
+    pub fn team_talk(
+        minute: u8,
+        timestamp_ms: u64,
+        is_home_team: bool,
+        details: TeamTalkDetails,
+    ) -> Self {
+        Self {
+            minute,
+            timestamp_ms: Some(timestamp_ms),
+            event_type: EventType::TeamTalk,
+            is_home_team,
+            player_track_id: None,
+            target_track_id: None,
+            details: Some(EventDetails { team_talk: Some(details), ..Default::default() }),
+        }
+    }
+
+    /// Create an AI tactical adaptation event (team-level, no player).
+    pub fn tactical_adaptation(
+        minute: u8,
+        timestamp_ms: u64,
+        is_home_team: bool,
+        details: TacticalAdaptationDetails,
+    ) -> Self {
+        Self {
+            minute,
+            timestamp_ms: Some(timestamp_ms),
+            event_type: EventType::TacticalAdaptation,
+            is_home_team,
+            player_track_id: None,
+            target_track_id: None,
+            details: Some(EventDetails { tactical_adaptation: Some(details), ..Default::default() }),
+        }
+    }
+
+    /// Create an added-time announcement (match-level, no team or player).
+    pub fn added_time(minute: u8, timestamp_ms: u64, half: u8, minutes: u8) -> Self {
+        Self {
+            minute,
+            timestamp_ms: Some(timestamp_ms),
+            event_type: EventType::AddedTime,
+            is_home_team: true,
+            player_track_id: None,
+            target_track_id: None,
+            details: Some(EventDetails {
+                added_time: Some(AddedTimeDetails { half, minutes }),
+                ..Default::default()
+            }),
+        }
+    }
+
+    /// Create an advantage event (team = side playing on, player = victim, target = offender).
+    pub fn advantage_played(
+        minute: u8,
+        timestamp_ms: u64,
+        is_home_team: bool,
+        victim_track_id: usize,
+        offender_track_id: usize,
+        advantage: AdvantageDetails,
+    ) -> Self {
+        Self {
+            minute,
+            timestamp_ms: Some(timestamp_ms),
+            event_type: EventType::AdvantagePlayed,
+            is_home_team,
+            player_track_id: Some(victim_track_id as u8),
+            target_track_id: Some(offender_track_id as u8),
+            details: Some(EventDetails {
+                advantage: Some(advantage),
+                rule_id: Some(RuleId::Advantage),
+                ..Default::default()
+            }),
+        }
+    }
+
+    /// Create a weather event (match-level, no team or player).
+    pub fn weather(minute: u8, timestamp_ms: u64, conditions: MatchConditions) -> Self {
+        Self {
+            minute,
+            timestamp_ms: Some(timestamp_ms),
+            event_type: EventType::Weather,
+            is_home_team: true,
+            player_track_id: None,
+            target_track_id: None,
+            details: Some(EventDetails { weather: Some(conditions), ..Default::default() }),
+        }
+    }
 }
@@ -80,23 +80,28 @@
 //! be blocked or it will replace real positions with (0,0,0).
 
 use super::match_setup::MatchSetupExport;
+use super::match_statistics::MatchStatistics;
+use crate::analysis::ratings::{
+    compute_player_ratings, select_man_of_the_match, PlayerMatchRating,
+};
 use super::replay;
-use super::{EventType, MatchEvent, Team};
+use super::{ChanceCreationDetails, EventType, MatchClock, MatchEvent, Team};
 use crate::engine::field_board::BoardSummaryExport;
 use crate::engine::coordinate_contract::{
     COORD_CONTRACT_VERSION, COORD_SYSTEM_LEGACY_AXIS_SWAP, COORD_SYSTEM_METERS_V2,
 };
 // P0: Core types moved to action_queue
 use crate::engine::action_queue::ViewerEvent;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 // ============================================
 // Best Moments (Highlight System)
 // ============================================
 
 /// Type of highlight moment
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum MomentType {
     /// Goal scored
@@ -208,6 +213,9 @@ pub struct PenaltyShootoutKick {
     pub kicker_track_id: u8,
     pub kicker_name: String,
     pub scored: bool,
+    /// Pre-kick conversion probability (0..1, for UI display)
+    #[serde(default)]
+    pub conversion_probability: f32,
 }
 
 /// Penalty shootout outcome (does not mutate regulation score).
@@ -222,8 +230,58 @@ pub struct PenaltyShootoutResult {
     pub kicks: Vec<PenaltyShootoutKick>,
 }
 
+/// Highlight reel tuning (thresholds, reel length, clip windows, categories)
+///
+/// Defaults reproduce the fixed reel: every detected moment, default windows.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct HighlightConfig {
+    /// Minimum priority for "highlight" clips
+    pub highlight_threshold: u8,
+    /// Minimum priority for "key_moment" clips
+    pub key_moment_threshold: u8,
+    /// Reel length cap, highest priority first (None = every qualifying moment)
+    pub max_clips: Option<usize>,
+    /// Extra lead time added before each moment's default window (ms)
+    pub padding_before_ms: u64,
+    /// Extra trail time added after each moment's default window (ms)
+    pub padding_after_ms: u64,
+    /// Only keep these moment types (empty = all)
+    pub categories: Vec<MomentType>,
+}
+
+impl Default for HighlightConfig {
+    fn default() -> Self {
+        Self {
+            highlight_threshold: 40,
+            key_moment_threshold: 70,
+            max_clips: None,
+            padding_before_ms: 0,
+            padding_after_ms: 0,
+            categories: Vec::new(),
+        }
+    }
+}
+
+impl HighlightConfig {
+    /// Same config with the reel threshold raised to `key_moment_threshold`
+    pub fn for_key_moments(&self) -> Self {
+        Self {
+            highlight_threshold: self.highlight_threshold.max(self.key_moment_threshold),
+            ..self.clone()
+        }
+    }
+
+    fn includes(&self, moment_type: MomentType) -> bool {
+        self.categories.is_empty() || self.categories.contains(&moment_type)
+    }
+}
+
 /// Generate best moments from match events
-pub fn generate_best_moments(events: &[MatchEvent]) -> Vec<BestMoment> {        
+///
+/// Moments below `config.highlight_threshold` or outside `config.categories`
+/// are dropped before overlapping windows are merged.
+pub fn generate_best_moments(events: &[MatchEvent], config: &HighlightConfig) -> Vec<BestMoment> {
     let mut moments: Vec<BestMoment> = Vec::new();
 
     for event in events {
@@ -247,7 +305,12 @@ pub fn generate_best_moments(events: &[MatchEvent]) -> Vec<BestMoment> {
             _ => None,
         };
 
-        if let Some(m) = moment {
+        if let Some(mut m) = moment {
+            if m.priority < config.highlight_threshold || !config.includes(m.moment_type) {
+                continue;
+            }
+            m.start_time_ms = m.start_time_ms.saturating_sub(config.padding_before_ms);
+            m.end_time_ms += config.padding_after_ms;
             moments.push(m);
         }
     }
@@ -260,6 +323,10 @@ pub fn generate_best_moments(events: &[MatchEvent]) -> Vec<BestMoment> {
     // Merge overlapping moments (keep higher priority)
     merge_overlapping_moments(&mut moments);
 
+    if let Some(max_clips) = config.max_clips {
+        moments.truncate(max_clips);
+    }
+
     moments
 }
 
@@ -358,7 +425,8 @@ impl MatchSummary {
         }
 
         let mut events_sorted: Vec<&MatchEvent> = result.events.iter().collect();
-        events_sorted.sort_by_key(|e| e.timestamp_ms.unwrap_or(e.minute as u64 * 60_000));
+        events_sorted.sort_by_key(|e| e.timeline_ms());
+        let clock = MatchClock::from_events(&result.events);
 
         for event in events_sorted {
             if event.event_type == EventType::Substitution {
@@ -383,9 +451,9 @@ impl MatchSummary {
                     if event.event_type == EventType::OwnGoal {
                         player_name = format!("{} (OG)", player_name);
                     }
-                    format!("{} {}'", player_name, event.minute)
+                    format!("{} {}", player_name, clock.label(event.minute))
                 } else {
-                    format!("{}'", event.minute)
+                    clock.label(event.minute)
                 };
 
                 if event.is_home_team {
@@ -397,8 +465,13 @@ impl MatchSummary {
         }
 
         // Determine MVP (player with highest rating if available)
+        let motm = result
+            .man_of_the_match
+            .and_then(|track_id| result.player_ratings.as_ref()?.get(&track_id));
         let (mvp_player, mvp_rating) = if let Some(ref my_stats) = stats.my_player_stats {
             (Some(my_stats.player_name.clone()), Some(my_stats.rating))
+        } else if let Some(motm) = motm {
+            (Some(motm.name.clone()), Some(motm.rating))
         } else {
             (None, None)
         };
@@ -800,6 +873,38 @@ pub struct MatchResult {
     /// FIX_2601: Shot opportunity telemetry for bias detection (env-gated: OF_DEBUG_SHOT_OPP=1)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub shot_opp_telemetry: Option<crate::engine::match_sim::ShotOppTelemetry>,
+
+    /// Recurring tactical weaknesses flagged during the match (clip-linked)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tactical_insights: Option<Vec<crate::engine::tactical_problems::TacticalInsight>>,
+
+    /// Typed per-team statistics for UI panels (generated after simulation)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub match_statistics: Option<MatchStatistics>,
+
+    /// Post-match player ratings keyed by track_id (generated after simulation)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub player_ratings: Option<BTreeMap<u8, PlayerMatchRating>>,
+
+    /// track_id of the man of the match
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub man_of_the_match: Option<u8>,
+
+    /// Per-minute momentum/threat series sampled during simulation
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub momentum_curve: Option<Vec<crate::engine::momentum_curve::MomentumPoint>>,
+
+    /// Every active match modifier with its origin, plus the resolved values
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub modifier_report: Option<crate::engine::match_modifiers::ModifierReport>,
+
+    /// Home crowd for this fixture (stadium with an `attendance`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub crowd: Option<crate::models::crowd::CrowdPressure>,
+
+    /// `ParameterSet::param_hash()` when designer tunables were applied
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parameter_hash: Option<String>,
 }
 
 // ============================================================================
@@ -835,6 +940,28 @@ impl Default for HashAlgorithm {
     }
 }
 
+/// Float math used by the engine (`strict_determinism` feature)
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum FloatMathMode {
+    /// Platform libm: bit-identical only on the same target
+    #[default]
+    Native,
+    /// Software libm + fixed-point sums: bit-identical across x86/ARM/WASM
+    Strict,
+}
+
+impl FloatMathMode {
+    /// Mode this build was compiled with
+    pub fn current() -> Self {
+        if crate::engine::strict_math::STRICT_DETERMINISM {
+            FloatMathMode::Strict
+        } else {
+            FloatMathMode::Native
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct DeterminismMeta {
     pub mode: DeterminismMode,
@@ -845,6 +972,12 @@ pub struct DeterminismMeta {
     /// Legacy replays may use DefaultHasher which is NOT stable across Rust versions
     #[serde(default)]
     pub hash_algo: HashAlgorithm,
+    /// Match seed (drives seeded presentation such as commentary variation)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+    /// Float math mode; results only replay bit-exactly across platforms in `Strict`
+    #[serde(default)]
+    pub float_math: FloatMathMode,
 }
 
 impl Default for DeterminismMeta {
@@ -854,6 +987,8 @@ impl Default for DeterminismMeta {
             simulated_until_tick: 0,
             cut_reason: None,
             hash_algo: HashAlgorithm::FxHash, // FIX_2601/0123: Use stable hash
+            seed: None,
+            float_math: FloatMathMode::current(),
         }
     }
 }
@@ -864,6 +999,12 @@ pub struct MyPlayerStats {
     pub player_name: String,
     pub goals: u32,
     pub assists: u32,
+    /// Pass before the assist on a goal
+    #[serde(default)]
+    pub second_assists: u32,
+    /// Completed passes that led directly to a shot
+    #[serde(default)]
+    pub key_passes: u32,
     pub shots: u32,
     pub passes: u32,
     pub tackles: u32,
@@ -875,6 +1016,37 @@ pub struct MyPlayerStats {
     pub rating: f32,
 }
 
+/// Per-player disciplinary / foul counters, keyed by track_id (0..21).
+///
+/// Accumulated as events are emitted (before highlight filtering), so callers
+/// don't need to rescan `MatchResult.events`. A substitute inherits the track
+/// slot of the player they replaced.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PlayerDisciplineStats {
+    pub track_id: u8,
+    /// Fouls committed (includes handballs)
+    pub fouls_committed: u16,
+    pub fouls_suffered: u16,
+    pub handballs: u16,
+    pub yellow_cards: u8,
+    pub red_cards: u8,
+    pub offsides: u16,
+}
+
+/// Per-player chance creation, keyed by track_id (0..21).
+///
+/// Credited by the engine when a shot is resolved (goal, save, miss), from the
+/// pass chain leading to the shooter. Same slot semantics as
+/// [`PlayerDisciplineStats`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PlayerCreativeStats {
+    pub track_id: u8,
+    /// Passes that led directly to a shot (includes assists)
+    pub key_passes: u16,
+    pub assists: u16,
+    pub second_assists: u16,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HeatMapPoint {
     pub x: f32,
@@ -1007,6 +1179,16 @@ pub struct Statistics {
     pub corners_away: u8,
     pub offsides_home: u8,
     pub offsides_away: u8,
+    /// Ground duels won (tackles won + take-ons beaten, from either side of the duel)
+    #[serde(default)]
+    pub duels_won_home: u16,
+    #[serde(default)]
+    pub duels_won_away: u16,
+    /// Team distance covered in metres (sampled per decision tick)
+    #[serde(default)]
+    pub distance_covered_home_m: f32,
+    #[serde(default)]
+    pub distance_covered_away_m: f32,
 
     // Telemetry / pacing diagnostics
     #[serde(default)]
@@ -1072,6 +1254,14 @@ pub struct Statistics {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub my_player_stats: Option<MyPlayerStats>,
 
+    /// Per-player fouls / cards / offsides (indexed by track_id once populated)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub player_discipline: Vec<PlayerDisciplineStats>,
+
+    /// Per-player key passes / assists / second assists (indexed by track_id once populated)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub player_creative: Vec<PlayerCreativeStats>,
+
     // Phase E: Advanced Analytics
     #[serde(default)]
     pub possession_zones_home: Vec<f32>, // 18 zones possession percentage
@@ -1158,6 +1348,10 @@ impl Default for Statistics {
             corners_away: 0,
             offsides_home: 0,
             offsides_away: 0,
+            duels_won_home: 0,
+            duels_won_away: 0,
+            distance_covered_home_m: 0.0,
+            distance_covered_away_m: 0.0,
             total_ticks: 0,
             ball_in_play_ticks: 0,
             ball_in_flight_ticks: 0,
@@ -1193,10 +1387,80 @@ impl Default for Statistics {
             heat_map_data_home: Vec::new(),
             heat_map_data_away: Vec::new(),
             my_player_stats: None,
+            player_discipline: Vec::new(),
+            player_creative: Vec::new(),
         }
     }
 }
 
+impl Statistics {
+    /// Update per-player discipline counters from one emitted event.
+    pub fn record_discipline_event(&mut self, event: &MatchEvent) {
+        let Some(track_id) = event.player_track_id.filter(|&id| id < 22) else {
+            return;
+        };
+        if self.player_discipline.is_empty() {
+            self.player_discipline = (0..22)
+                .map(|track_id| PlayerDisciplineStats { track_id, ..Default::default() })
+                .collect();
+        }
+
+        let stats = &mut self.player_discipline[track_id as usize];
+        match event.event_type {
+            EventType::Foul => stats.fouls_committed += 1,
+            EventType::Handball => {
+                stats.fouls_committed += 1;
+                stats.handballs += 1;
+            }
+            EventType::YellowCard => stats.yellow_cards += 1,
+            EventType::RedCard => stats.red_cards += 1,
+            EventType::Offside => stats.offsides += 1,
+            _ => return,
+        }
+
+        if event.event_type == EventType::Foul {
+            if let Some(victim) = event.target_track_id.filter(|&id| id < 22) {
+                self.player_discipline[victim as usize].fouls_suffered += 1;
+            }
+        }
+    }
+
+    /// Discipline counters for one track_id (None before any discipline event)
+    pub fn discipline_for(&self, track_id: u8) -> Option<&PlayerDisciplineStats> {
+        self.player_discipline.get(track_id as usize)
+    }
+
+    /// Credit the passes behind one resolved shot (`scored` adds assist credit).
+    pub fn record_chance_creation(&mut self, creation: &ChanceCreationDetails, scored: bool) {
+        if creation.is_empty() {
+            return;
+        }
+        if self.player_creative.is_empty() {
+            self.player_creative = (0..22)
+                .map(|track_id| PlayerCreativeStats { track_id, ..Default::default() })
+                .collect();
+        }
+
+        if let Some(key) = creation.key_pass_track_id.filter(|&id| id < 22) {
+            let stats = &mut self.player_creative[key as usize];
+            stats.key_passes += 1;
+            if scored {
+                stats.assists += 1;
+            }
+        }
+        if scored {
+            if let Some(second) = creation.second_assist_track_id.filter(|&id| id < 22) {
+                self.player_creative[second as usize].second_assists += 1;
+            }
+        }
+    }
+
+    /// Chance-creation counters for one track_id (None before any credited shot)
+    pub fn creative_for(&self, track_id: u8) -> Option<&PlayerCreativeStats> {
+        self.player_creative.get(track_id as usize)
+    }
+}
+
 impl Default for MatchResult {
     fn default() -> Self {
         Self::new()
@@ -1227,6 +1491,14 @@ impl MatchResult {
             penalty_shootout: None,
             best_moments: None,
             shot_opp_telemetry: None,
+            tactical_insights: None,
+            match_statistics: None,
+            player_ratings: None,
+            man_of_the_match: None,
+            momentum_curve: None,
+            modifier_report: None,
+            crowd: None,
+            parameter_hash: None,
         }
     }
 
@@ -1254,6 +1526,14 @@ impl MatchResult {
             penalty_shootout: None,
             best_moments: None,
             shot_opp_telemetry: None,
+            tactical_insights: None,
+            match_statistics: None,
+            player_ratings: None,
+            man_of_the_match: None,
+            momentum_curve: None,
+            modifier_report: None,
+            crowd: None,
+            parameter_hash: None,
         }
     }
 
@@ -1281,6 +1561,14 @@ impl MatchResult {
             penalty_shootout: None,
             best_moments: None,
             shot_opp_telemetry: None,
+            tactical_insights: None,
+            match_statistics: None,
+            player_ratings: None,
+            man_of_the_match: None,
+            momentum_curve: None,
+            modifier_report: None,
+            crowd: None,
+            parameter_hash: None,
         }
     }
 
@@ -1308,6 +1596,14 @@ impl MatchResult {
             penalty_shootout: None,
             best_moments: None,
             shot_opp_telemetry: None,
+            tactical_insights: None,
+            match_statistics: None,
+            player_ratings: None,
+            man_of_the_match: None,
+            momentum_curve: None,
+            modifier_report: None,
+            crowd: None,
+            parameter_hash: None,
         }
     }
 
@@ -1359,9 +1655,27 @@ impl MatchResult {
         self.summary = Some(MatchSummary::from_result(self));
     }
 
+    /// Generate and set player ratings and the man of the match
+    pub fn generate_player_ratings(&mut self) {
+        let ratings = compute_player_ratings(self);
+        self.man_of_the_match =
+            select_man_of_the_match(&ratings, self.score_home, self.score_away);
+        self.player_ratings = Some(ratings);
+    }
+
+    /// Generate and set typed match statistics from the finalized result
+    pub fn generate_match_statistics(&mut self) {
+        self.match_statistics = Some(MatchStatistics::from_result(self));
+    }
+
     /// Generate and set best moments from events
     pub fn generate_best_moments(&mut self) {
-        let moments = generate_best_moments(&self.events);
+        self.generate_best_moments_with(&HighlightConfig::default());
+    }
+
+    /// Generate and set best moments with a tuned highlight config
+    pub fn generate_best_moments_with(&mut self, config: &HighlightConfig) {
+        let moments = generate_best_moments(&self.events, config);
         if !moments.is_empty() {
             self.best_moments = Some(moments);
         }
@@ -1423,6 +1737,8 @@ mod tests {
                 equipped_skills: Vec::new(),
                 traits: Default::default(),
                 personality: Default::default(),
+                morale: 50,
+                form: 50,
             });
         }
         Team {
@@ -1597,4 +1913,103 @@ mod tests {
         let summary = MatchSummary::from_result(&result);
         assert_eq!(summary.goal_scorers_home, vec!["Home Sub 1 71'".to_string()]);
     }
+
+    #[test]
+    fn match_summary_labels_goals_in_added_time() {
+        let mut result = MatchResult::new();
+        result.home_team = Some(make_team_with_starters("Home"));
+        // 45+3 in the first half, so the second half kicks off at engine minute 48.
+        result.events.push(MatchEvent::added_time(45, 2_700_000, 1, 3));
+        for minute in [46u8, 60, 95] {
+            result.events.push(MatchEvent::goal(minute, minute as u64 * 60_000, true, 9, None));
+        }
+        result.events.push(MatchEvent::added_time(93, 5_580_000, 2, 4));
+
+        let clock = MatchClock::from_events(&result.events);
+        assert_eq!((clock.first_half_end_minute(), clock.match_end_minute()), (48, 97));
+        assert_eq!(clock.label(45), "45'");
+        assert_eq!(clock.label(48), "45'");
+
+        let summary = MatchSummary::from_result(&result);
+        let scorer = &result.home_team.as_ref().unwrap().players[9].name;
+        assert_eq!(
+            summary.goal_scorers_home,
+            vec![
+                format!("{} 45+1'", scorer),
+                format!("{} 57'", scorer),
+                format!("{} 90+2'", scorer)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_discipline_stats_accumulate_per_track_id() {
+        let mut stats = Statistics::default();
+        assert!(stats.discipline_for(4).is_none());
+
+        let foul = MatchEvent::foul(10, 600_000, true, 4, (0.5, 0.5, 0.0))
+            .with_target_track_id(Some(15));
+        stats.record_discipline_event(&foul);
+        stats.record_discipline_event(&foul);
+        stats.record_discipline_event(&MatchEvent::yellow_card(11, 660_000, true, 4));
+        stats.record_discipline_event(&MatchEvent::offside(20, 1_200_000, false, 20));
+        // Non-disciplinary events are ignored.
+        stats.record_discipline_event(&MatchEvent::shot(30, 1_800_000, true, 4, true, 0.2));
+
+        assert_eq!(stats.player_discipline.len(), 22);
+        let fouler = stats.discipline_for(4).unwrap();
+        assert_eq!(fouler.fouls_committed, 2);
+        assert_eq!(fouler.yellow_cards, 1);
+        assert_eq!(stats.discipline_for(15).unwrap().fouls_suffered, 2);
+        assert_eq!(stats.discipline_for(20).unwrap().offsides, 1);
+    }
+
+    #[test]
+    fn test_creative_stats_credit_key_pass_and_assists() {
+        let mut stats = Statistics::default();
+        stats.record_chance_creation(&ChanceCreationDetails::default(), true);
+        assert!(stats.creative_for(6).is_none());
+
+        let creation = ChanceCreationDetails {
+            key_pass_track_id: Some(6),
+            second_assist_track_id: Some(2),
+        };
+        stats.record_chance_creation(&creation, false);
+        stats.record_chance_creation(&creation, true);
+
+        let creator = stats.creative_for(6).unwrap();
+        assert_eq!((creator.key_passes, creator.assists), (2, 1));
+        let second = stats.creative_for(2).unwrap();
+        assert_eq!((second.key_passes, second.second_assists), (0, 1));
+    }
+
+    #[test]
+    fn test_highlight_config_tunes_reel() {
+        let events = vec![
+            MatchEvent::goal(10, 600_000, true, 9, None),
+            MatchEvent::save(30, 1_800_000, false, 11),
+            MatchEvent::shot(50, 3_000_000, true, 7, true, 0.3),
+            MatchEvent::red_card(70, 4_200_000, false, 15),
+        ];
+
+        let all = generate_best_moments(&events, &HighlightConfig::default());
+        assert_eq!(all.len(), 4);
+        assert_eq!(all[0].moment_type, MomentType::Goal);
+
+        let key = generate_best_moments(&events, &HighlightConfig::default().for_key_moments());
+        assert_eq!(key.len(), 2);
+
+        let config = HighlightConfig {
+            max_clips: Some(1),
+            padding_before_ms: 2_000,
+            padding_after_ms: 1_000,
+            categories: vec![MomentType::Save, MomentType::ShotOnTarget],
+            ..Default::default()
+        };
+        let reel = generate_best_moments(&events, &config);
+        assert_eq!(reel.len(), 1);
+        assert_eq!(reel[0].moment_type, MomentType::Save);
+        assert_eq!(reel[0].start_time_ms, 1_800_000 - 5_000 - 2_000);
+        assert_eq!(reel[0].end_time_ms, 1_800_000 + 3_000 + 1_000);
+    }
 }
@@ -382,6 +382,7 @@ impl MatchPlayer {
         };
 
         attributes.apply_position_penalty(position_suitability);
+        crate::player::morale::apply_form_modifiers(&mut attributes, player.form, player.morale);
 
         Self {
             name: player.name.clone(),
@@ -542,57 +543,13 @@ impl TeamSetup {
             ));
         }
 
-        // Hungarian assignment (11 players × 11 slots) with deterministic tie-breaks.
-        use pathfinding::kuhn_munkres::kuhn_munkres_min;
-        use pathfinding::matrix::Matrix;
-
-        const COST_INCOMPATIBLE: i64 = 1_000_000;
-
-        let costs = Matrix::from_fn(11, 11, |(player_idx, slot_idx)| {
-            let player = starters_slice[player_idx];
-            let expected = slot_template[slot_idx];
-
-            // Enforce GK slot (0) strictly.
-            let base_cost = if slot_idx == 0 {
-                if player.position == Position::GK {
-                    0
-                } else {
-                    COST_INCOMPATIBLE
-                }
-            } else if player.position == Position::GK {
-                COST_INCOMPATIBLE
-            } else if player.position == expected {
-                0
-            } else if player.position.is_compatible_position(expected) {
-                10
-            } else {
-                COST_INCOMPATIBLE
-            };
-
-            // Deterministic tie-break (prefer earlier roster order).
-            base_cost + player_idx as i64
-        });
-
-        let (_, assignments) = kuhn_munkres_min(&costs);
-
-        // assignments[player_idx] = slot_idx
-        let mut slot_to_player_idx = [0usize; 11];
-        for (player_idx, slot_idx) in assignments.iter().enumerate() {
-            slot_to_player_idx[*slot_idx] = player_idx;
-        }
+        let slot_to_player_idx = assign_starter_slots(&starters_slice, &team.formation);
 
         // Validate assignment: no incompatible placements.
-        for slot_idx in 0..11 {
-            let player_idx = slot_to_player_idx[slot_idx];
+        for (slot_idx, &player_idx) in slot_to_player_idx.iter().enumerate() {
             let player = starters_slice[player_idx];
             let expected = slot_template[slot_idx];
-            let ok = if slot_idx == 0 {
-                player.position == Position::GK
-            } else {
-                player.position != Position::GK
-                    && (player.position == expected || player.position.is_compatible_position(expected))
-            };
-            if !ok {
+            if !slot_accepts(slot_idx, player.position, expected) {
                 return Err(format!(
                     "{}: cannot assign '{}' ({:?}) to slot {} ({:?}) for formation {}",
                     error_codes::UNSUPPORTED_POSITION_MAPPING,
@@ -658,7 +615,54 @@ impl TeamSetup {
     }
 }
 
-fn formation_slot_template(formation: &Formation) -> [Position; 11] {
+/// Whether a player at `position` may fill formation slot `slot_idx` (slot 0 = GK only)
+pub(crate) fn slot_accepts(slot_idx: usize, position: Position, expected: Position) -> bool {
+    if slot_idx == 0 {
+        position == Position::GK
+    } else {
+        position != Position::GK
+            && (position == expected || position.is_compatible_position(expected))
+    }
+}
+
+/// Formation slot → starter index, via Hungarian assignment (11 players × 11 slots).
+///
+/// Exact positions cost nothing, compatible ones a little; ties prefer earlier
+/// roster order so the result is deterministic. Incompatible placements are
+/// only chosen when nothing else fits — callers check them with `slot_accepts`.
+pub(crate) fn assign_starter_slots(starters: &[&Player], formation: &Formation) -> [usize; 11] {
+    use pathfinding::kuhn_munkres::kuhn_munkres_min;
+    use pathfinding::matrix::Matrix;
+
+    const COST_INCOMPATIBLE: i64 = 1_000_000;
+
+    let slot_template = formation_slot_template(formation);
+    let costs = Matrix::from_fn(11, 11, |(player_idx, slot_idx)| {
+        let position = starters[player_idx].position;
+        let expected = slot_template[slot_idx];
+        let base_cost = if !slot_accepts(slot_idx, position, expected) {
+            COST_INCOMPATIBLE
+        } else if position == expected || slot_idx == 0 {
+            0
+        } else {
+            10
+        };
+
+        // Deterministic tie-break (prefer earlier roster order).
+        base_cost + player_idx as i64
+    });
+
+    let (_, assignments) = kuhn_munkres_min(&costs);
+
+    // assignments[player_idx] = slot_idx
+    let mut slot_to_player_idx = [0usize; 11];
+    for (player_idx, slot_idx) in assignments.iter().enumerate() {
+        slot_to_player_idx[*slot_idx] = player_idx;
+    }
+    slot_to_player_idx
+}
+
+pub(crate) fn formation_slot_template(formation: &Formation) -> [Position; 11] {
     use Position::*;
     match formation {
         Formation::F442 => [GK, LB, CB, CB, RB, LM, CM, CM, RM, ST, ST],
@@ -789,6 +793,8 @@ mod tests {
             equipped_skills: Vec::new(),
             traits: Default::default(),
             personality: Default::default(),
+            morale: 50,
+            form: 50,
         };
 
         // Create person with MC rating = 20 (natural position)
@@ -844,6 +850,8 @@ mod tests {
             equipped_skills: Vec::new(),
             traits: Default::default(),
             personality: Default::default(),
+            morale: 50,
+            form: 50,
         };
 
         // Create person with GK rating = 1 (cannot play)
@@ -904,6 +912,8 @@ mod tests {
             equipped_skills: Vec::new(),
             traits: Default::default(),
             personality: Default::default(),
+            morale: 50,
+            form: 50,
         };
 
         // Create person with DM rating = 8 (adequate)
@@ -951,6 +961,8 @@ mod tests {
             equipped_skills: Vec::new(),
             traits: Default::default(),
             personality: Default::default(),
+            morale: 50,
+            form: 50,
         };
 
         // Test: No Person data → no penalty
@@ -1114,6 +1126,8 @@ mod tests {
             equipped_skills: Vec::new(),
             traits: TraitSlots::default(),
             personality: PersonalityArchetype::default(),
+            morale: 50,
+            form: 50,
         }
     }
 
@@ -1399,6 +1413,8 @@ mod tests {
                 equipped_skills: Vec::new(),
                 traits: TraitSlots::default(),
                 personality: PersonalityArchetype::default(),
+                morale: 50,
+                form: 50,
             });
         }
 
@@ -1680,6 +1696,15 @@ pub struct MatchSetupExport {
     pub away: TeamSetupExport,
     /// 22명 선수 슬롯 정보
     pub player_slots: Vec<PlayerSlotExport>,
+    /// Home ground (pitch dimensions + surface), when the match has one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stadium: Option<super::stadium::Stadium>,
+    /// Weather + pitch condition, when set for the match
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<super::weather::MatchConditions>,
+    /// Match referee, when one was assigned (name + tendencies)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub referee: Option<super::referee::RefereeProfile>,
 }
 
 /// 팀 셋업 내보내기
@@ -1750,6 +1775,9 @@ impl MatchSetup {
                 formation: format!("{:?}", self.away.formation),
             },
             player_slots,
+            stadium: None,
+            conditions: None,
+            referee: None,
         }
     }
 
@@ -1800,6 +1828,9 @@ impl MatchSetup {
                 formation: format!("{:?}", self.away.formation),
             },
             player_slots,
+            stadium: None,
+            conditions: None,
+            referee: None,
         }
     }
 }
@@ -25,6 +25,8 @@ mod ci_gates {
             equipped_skills: Vec::new(),
             traits: Default::default(),
             personality: Default::default(),
+            morale: 50,
+            form: 50,
         };
 
         // In strict mode, this must panic
@@ -44,6 +46,8 @@ mod ci_gates {
             equipped_skills: Vec::new(),
             traits: Default::default(),
             personality: Default::default(),
+            morale: 50,
+            form: 50,
         };
 
         // Should not panic
@@ -156,6 +160,8 @@ mod ci_gates {
                 equipped_skills: Vec::new(),
                 traits: Default::default(),
                 personality: Default::default(),
+                morale: 50,
+                form: 50,
             });
         }
 
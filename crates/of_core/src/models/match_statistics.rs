@@ -1,5 +1,8 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::BTreeMap;
+
+use super::match_result::MatchResult;
+use super::EventType;
 
 /// Aggregated statistics for a simulated match.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -23,19 +26,44 @@ pub struct MatchStatistics {
     pub pass_accuracy_away: f32,
     pub corners_home: u8,
     pub corners_away: u8,
-    pub fouls_home: u8,
-    pub fouls_away: u8,
+    pub fouls_home: u16,
+    pub fouls_away: u16,
+    #[serde(default)]
+    pub offsides_home: u8,
+    #[serde(default)]
+    pub offsides_away: u8,
+    #[serde(default)]
+    pub yellow_cards_home: u8,
+    #[serde(default)]
+    pub yellow_cards_away: u8,
+    #[serde(default)]
+    pub red_cards_home: u8,
+    #[serde(default)]
+    pub red_cards_away: u8,
+    #[serde(default)]
+    pub tackles_home: u16,
+    #[serde(default)]
+    pub tackles_away: u16,
+    #[serde(default)]
+    pub duels_won_home: u16,
+    #[serde(default)]
+    pub duels_won_away: u16,
+    /// Team distance covered in kilometres
+    #[serde(default)]
+    pub distance_covered_home_km: f32,
+    #[serde(default)]
+    pub distance_covered_away_km: f32,
     /// Shot-oriented events (includes goals, shots on/off target).
     pub shot_events: Vec<ShotEvent>,
     /// All timeline events relevant to UI (mirrors `shot_events` by default).
     pub events: Vec<ShotEvent>,
     /// Player ratings keyed by roster identifier.
-    pub player_ratings: HashMap<String, f32>,
+    pub player_ratings: BTreeMap<String, f32>,
     /// Ordered roster identifiers for UI panels.
     pub roster_home: Vec<String>,
     pub roster_away: Vec<String>,
-    #[serde(skip_serializing_if = "HashMap::is_empty", default)]
-    pub metadata: HashMap<String, String>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    pub metadata: BTreeMap<String, String>,
 }
 
 impl MatchStatistics {
@@ -43,6 +71,106 @@ impl MatchStatistics {
     pub fn empty(match_id: impl Into<String>) -> Self {
         Self { match_id: match_id.into(), ..Default::default() }
     }
+
+    /// Build the typed payload from a finalized match result.
+    ///
+    /// Team stats come from `result.statistics`; names and rosters from
+    /// `result.match_setup` and player ratings from `result.player_ratings`
+    /// when present.
+    pub fn from_result(result: &MatchResult) -> Self {
+        let stats = &result.statistics;
+        let setup = result.match_setup.as_ref();
+
+        let player_name = |track_id: Option<u8>| -> Option<String> {
+            let track_id = track_id? as u32;
+            setup?.player_slots.iter().find(|p| p.track_id == track_id).map(|p| p.name.clone())
+        };
+        let roster = |team: &str| -> Vec<String> {
+            setup
+                .map(|s| {
+                    s.player_slots.iter().filter(|p| p.team == team).map(|p| p.name.clone()).collect()
+                })
+                .unwrap_or_default()
+        };
+
+        let shot_events: Vec<ShotEvent> = result
+            .events
+            .iter()
+            .filter_map(|event| {
+                let (event_type, outcome) = match event.event_type {
+                    EventType::Goal => ("goal", "goal"),
+                    EventType::ShotOnTarget => ("shot", "on_target"),
+                    EventType::ShotOffTarget => ("shot", "off_target"),
+                    EventType::ShotBlocked => ("shot", "blocked"),
+                    EventType::PostHit => ("shot", "post"),
+                    EventType::BarHit => ("shot", "bar"),
+                    _ => return None,
+                };
+                let details = event.details.as_ref();
+                // ball_position is in Coord10 (0.1m) units
+                let coordinates = details
+                    .and_then(|d| d.ball_position)
+                    .map(|(x, y, _)| EventCoordinates { x: x / 10.0, y: y / 10.0 })
+                    .unwrap_or_default();
+                Some(ShotEvent {
+                    event_type: event_type.to_string(),
+                    minute: event.minute,
+                    player: player_name(event.player_track_id),
+                    team: if event.is_home_team { "home" } else { "away" }.to_string(),
+                    coordinates,
+                    outcome: outcome.to_string(),
+                    xg: details.and_then(|d| d.xg_value),
+                })
+            })
+            .collect();
+
+        Self {
+            match_id: String::new(),
+            home_team: setup.map(|s| s.home.name.clone()).unwrap_or_default(),
+            away_team: setup.map(|s| s.away.name.clone()).unwrap_or_default(),
+            goals_home: result.score_home,
+            goals_away: result.score_away,
+            possession_home: stats.possession_home,
+            possession_away: stats.possession_away,
+            shots_home: stats.shots_home,
+            shots_away: stats.shots_away,
+            shots_on_target_home: stats.shots_on_target_home,
+            shots_on_target_away: stats.shots_on_target_away,
+            xg_home: stats.xg_home,
+            xg_away: stats.xg_away,
+            passes_home: stats.passes_home,
+            passes_away: stats.passes_away,
+            pass_accuracy_home: stats.pass_accuracy_home,
+            pass_accuracy_away: stats.pass_accuracy_away,
+            corners_home: stats.corners_home,
+            corners_away: stats.corners_away,
+            fouls_home: stats.fouls_home,
+            fouls_away: stats.fouls_away,
+            offsides_home: stats.offsides_home,
+            offsides_away: stats.offsides_away,
+            yellow_cards_home: stats.yellow_cards_home,
+            yellow_cards_away: stats.yellow_cards_away,
+            red_cards_home: stats.red_cards_home,
+            red_cards_away: stats.red_cards_away,
+            tackles_home: stats.tackles_home,
+            tackles_away: stats.tackles_away,
+            duels_won_home: stats.duels_won_home,
+            duels_won_away: stats.duels_won_away,
+            distance_covered_home_km: stats.distance_covered_home_m / 1000.0,
+            distance_covered_away_km: stats.distance_covered_away_m / 1000.0,
+            events: shot_events.clone(),
+            shot_events,
+            player_ratings: result
+                .player_ratings
+                .iter()
+                .flat_map(|ratings| ratings.values())
+                .map(|p| (p.name.clone(), p.rating))
+                .collect(),
+            roster_home: roster("home"),
+            roster_away: roster("away"),
+            metadata: BTreeMap::new(),
+        }
+    }
 }
 
 /// Simplified event payload exposed to Godot layers.
@@ -65,3 +193,46 @@ pub struct EventCoordinates {
     pub x: f32,
     pub y: f32,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::MatchEvent;
+
+    #[test]
+    fn test_from_result_maps_team_stats_and_shots() {
+        let mut result = MatchResult::new();
+        result.score_home = 1;
+        result.statistics.shots_home = 2;
+        result.statistics.duels_won_away = 14;
+        result.statistics.distance_covered_home_m = 104_500.0;
+        result.events.push(MatchEvent::goal(12, 720_000, true, 9, None));
+        result.events.push(MatchEvent::shot_with_position(
+            30,
+            1_800_000,
+            false,
+            18,
+            false,
+            0.08,
+            (850.0, 340.0, 0.0),
+        ));
+        result.events.push(MatchEvent::yellow_card(40, 2_400_000, true, 4));
+        result.generate_match_statistics();
+
+        let stats = result.match_statistics.as_ref().unwrap();
+        assert_eq!(stats.goals_home, 1);
+        assert_eq!(stats.shots_home, 2);
+        assert_eq!(stats.duels_won_away, 14);
+        assert!((stats.distance_covered_home_km - 104.5).abs() < 1e-3);
+        assert_eq!(stats.shot_events.len(), 2);
+        assert_eq!(stats.shot_events[0].outcome, "goal");
+        let miss = &stats.shot_events[1];
+        assert_eq!((miss.team.as_str(), miss.outcome.as_str()), ("away", "off_target"));
+        assert!((miss.coordinates.x - 85.0).abs() < 1e-3);
+        assert_eq!(miss.xg, Some(0.08));
+
+        let json = serde_json::to_string(&result).unwrap();
+        let back: MatchResult = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.match_statistics.unwrap().duels_won_away, 14);
+    }
+}
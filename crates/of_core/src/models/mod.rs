@@ -1,36 +1,49 @@
+pub mod chemistry;
+pub mod crowd;
 pub mod events;
 pub mod match_result;
 pub mod match_setup;
 pub mod match_statistics;
 pub mod oracle;
+pub mod penalty_shootout;
 pub mod person;
+pub mod pitch;
 pub mod player;
+pub mod referee;
 pub mod replay;
 pub mod rules;
 pub mod skill;
+pub mod stadium;
 pub mod team;
 pub mod trait_balance;
 pub mod trait_system;
+pub mod weather;
 
 #[cfg(test)]
 mod match_setup_contracts_test;
 
+pub use crowd::{CrowdMood, CrowdPressure};
 pub use events::{
-    EventDetails, EventType, InjurySeverity, MatchEvent, SubstitutionDetails, VarReviewDetails,
-    VarReviewOutcome,
+    AdaptationPattern, AdaptationResponse, AddedTimeDetails, ChanceCreationDetails, EventDetails, EventType,
+    GoalkeeperOffenceDetails, HandballDetails, HandballRestartType, InjurySeverity, MatchClock, MatchEvent,
+    SetPieceRoutineDetails, SetPieceRoutineKind, SubstitutionDetails, TacticalAdaptationDetails,
+    TeamTalkDetails, TeamTalkReaction, TeamTalkTone, VarIncident, VarReviewDetails, VarReviewOutcome,
 };
 pub use match_result::{
-    generate_best_moments, BestMoment, DeterminismMeta, DeterminismMode, HashAlgorithm, HeatMapPoint,
-    MatchPositionData, MatchResult, MatchSummary, MomentType, MyPlayerStats, PenaltyShootoutResult,
-    PlayerState, Statistics,
+    generate_best_moments, BestMoment, DeterminismMeta, DeterminismMode, FloatMathMode, HashAlgorithm,
+    HeatMapPoint, HighlightConfig, MatchPositionData, MatchResult, MatchSummary, MomentType, MyPlayerStats,
+    PenaltyShootoutResult, PlayerCreativeStats, PlayerDisciplineStats, PlayerState, Statistics,
 };
 pub use match_statistics::{EventCoordinates, MatchStatistics, ShotEvent};
 pub use oracle::{EventCounts, FixtureInfo, Invariants, MatchResultSnapshot, OracleSnapshot};
+pub use penalty_shootout::{PenaltyKicker, PenaltyShootoutConfig, PenaltyShootoutSide};
 pub use person::Person;
 pub use player::{Player, Position};
+pub use referee::RefereeProfile;
 pub use replay::*;
 pub use skill::{ActionType, SkillContext, SpecialSkill};
 pub use team::{Formation, Team};
+pub use weather::{MatchConditions, PitchQuality, WeatherCondition, WeatherKind};
 pub use trait_system::{
     ActionType as TraitActionType, EquippedTrait, StatType, TraitCategory, TraitError, TraitId,
     TraitSlots, TraitTier,
@@ -51,6 +64,8 @@ pub use rules::{
     DefenderTouchType, DeflectionContext,
     // Fouls (Law 12)
     FoulDetails, FoulSanction, FoulSeverity, FoulType,
+    // Advantage (Law 5.3 / 12.3)
+    AdvantageCard, AdvantageDetails, AdvantageOutcome,
     // YAML data structures
     FoulsRuleData, OffsideRuleData,
 };
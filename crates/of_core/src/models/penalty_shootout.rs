@@ -0,0 +1,136 @@
+//! Penalty shootout customization
+//!
+//! Callers can fix each side's kicking order (team-local pitch slots 0-10),
+//! give individual kickers a composure modifier, and choose whether a level
+//! shootout goes to sudden death after the first five kicks each.
+//!
+//! Listed kickers who are no longer on the pitch (sent off / injured) are
+//! skipped; everyone not listed follows in the engine's default order.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Largest composure adjustment to a kick's conversion probability
+pub const MAX_COMPOSURE_MODIFIER: f32 = 0.25;
+/// Kicks each side takes before sudden death
+pub const SHOOTOUT_ROUNDS: u8 = 5;
+
+/// One entry in a side's kicking order
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct PenaltyKicker {
+    /// Team-local pitch slot (0-10)
+    pub slot: u8,
+    /// Added to the conversion probability (clamped to ±`MAX_COMPOSURE_MODIFIER`)
+    #[serde(default)]
+    pub composure: f32,
+}
+
+/// Kicking order for one team
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct PenaltyShootoutSide {
+    #[serde(default)]
+    pub kickers: Vec<PenaltyKicker>,
+}
+
+impl PenaltyShootoutSide {
+    /// Resolve the kicking order over the `eligible` slots (in default order)
+    ///
+    /// Returns `(slot, composure)` pairs: listed kickers first, then the rest.
+    pub fn kicking_order(&self, eligible: &[u8]) -> Vec<(u8, f32)> {
+        let mut order: Vec<(u8, f32)> = Vec::with_capacity(eligible.len());
+        for kicker in &self.kickers {
+            if eligible.contains(&kicker.slot) && !order.iter().any(|(s, _)| *s == kicker.slot) {
+                let composure =
+                    kicker.composure.clamp(-MAX_COMPOSURE_MODIFIER, MAX_COMPOSURE_MODIFIER);
+                order.push((kicker.slot, composure));
+            }
+        }
+        for &slot in eligible {
+            if !order.iter().any(|(s, _)| *s == slot) {
+                order.push((slot, 0.0));
+            }
+        }
+        order
+    }
+
+    fn validate(&self, side: &str) -> Result<(), String> {
+        for kicker in &self.kickers {
+            if kicker.slot > 10 {
+                return Err(format!("{} kicker slot out of range (0-10): {}", side, kicker.slot));
+            }
+            if !kicker.composure.is_finite() {
+                return Err(format!("{} kicker composure must be finite", side));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Shootout rules for a match (supplying one also enables the shootout on a draw)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct PenaltyShootoutConfig {
+    pub home: PenaltyShootoutSide,
+    pub away: PenaltyShootoutSide,
+    /// Level after five kicks each: continue kick-for-kick (true) or draw lots (false)
+    pub sudden_death: bool,
+}
+
+impl Default for PenaltyShootoutConfig {
+    fn default() -> Self {
+        Self {
+            home: PenaltyShootoutSide::default(),
+            away: PenaltyShootoutSide::default(),
+            sudden_death: true,
+        }
+    }
+}
+
+impl PenaltyShootoutConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        self.home.validate("home")?;
+        self.away.validate("away")
+    }
+
+    pub fn side(&self, is_home: bool) -> &PenaltyShootoutSide {
+        if is_home {
+            &self.home
+        } else {
+            &self.away
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kicking_order_puts_listed_kickers_first() {
+        let side = PenaltyShootoutSide {
+            kickers: vec![
+                PenaltyKicker { slot: 9, composure: 0.1 },
+                // Sent off: skipped
+                PenaltyKicker { slot: 4, composure: 0.0 },
+                PenaltyKicker { slot: 7, composure: -0.9 },
+                PenaltyKicker { slot: 9, composure: 0.2 },
+            ],
+        };
+        let eligible = [0, 1, 2, 3, 5, 6, 7, 8, 9, 10];
+        let order = side.kicking_order(&eligible);
+
+        assert_eq!(order.len(), eligible.len());
+        assert_eq!(order[0], (9, 0.1));
+        assert_eq!(order[1], (7, -MAX_COMPOSURE_MODIFIER));
+        assert_eq!(order[2], (0, 0.0));
+        assert!(!order.iter().any(|(slot, _)| *slot == 4));
+    }
+
+    #[test]
+    fn test_validate_rejects_bench_slots() {
+        let mut config = PenaltyShootoutConfig::default();
+        assert!(config.validate().is_ok());
+        config.away.kickers.push(PenaltyKicker { slot: 11, composure: 0.0 });
+        assert!(config.validate().is_err());
+    }
+}
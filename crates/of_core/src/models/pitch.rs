@@ -0,0 +1,376 @@
+//! Pitch geometry (per-match SSOT)
+//!
+//! `PitchSpec` describes the real playing surface for a match: outer
+//! dimensions, goal size and the markings that rules/analysis depend on.
+//!
+//! ## Engine frame vs pitch frame
+//!
+//! The tick engine always simulates in the canonical 105m × 68m frame
+//! (`engine::physics_constants::field`). A `PitchSpec` maps that frame onto
+//! the actual pitch by independent X/Y scaling, so geometric layers
+//! (FieldBoard cells, xG zones, observation normalisation, waypoint → meter
+//! conversion, viewer coordinate info) stay consistent for small-sided and
+//! non-standard pitches instead of assuming 105×68 everywhere.
+//!
+//! For the standard pitch the mapping is the identity, so existing results
+//! are unchanged.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::engine::physics_constants::field;
+
+/// Canonical engine frame length (meters)
+pub const ENGINE_LENGTH_M: f32 = field::LENGTH_M;
+/// Canonical engine frame width (meters)
+pub const ENGINE_WIDTH_M: f32 = field::WIDTH_M;
+
+/// Match format the pitch is marked for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PitchFormat {
+    /// 11-a-side (IFAB Law 1)
+    #[default]
+    ElevenASide,
+    /// 9-a-side youth pitch
+    NineASide,
+    /// 7-a-side youth pitch
+    SevenASide,
+    /// 5-a-side / futsal-sized pitch
+    FiveASide,
+}
+
+impl PitchFormat {
+    /// Legal (min, max) length and width ranges in meters for this format.
+    pub fn legal_ranges(&self) -> ((f32, f32), (f32, f32)) {
+        match self {
+            PitchFormat::ElevenASide => ((90.0, 120.0), (45.0, 90.0)),
+            PitchFormat::NineASide => ((55.0, 75.0), (35.0, 55.0)),
+            PitchFormat::SevenASide => ((45.0, 65.0), (27.0, 46.0)),
+            PitchFormat::FiveASide => ((25.0, 42.0), (15.0, 25.0)),
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_ascii_lowercase().replace(['-', ' '], "_").as_str() {
+            "eleven_a_side" | "11v11" | "11" | "standard" => Some(PitchFormat::ElevenASide),
+            "nine_a_side" | "9v9" | "9" => Some(PitchFormat::NineASide),
+            "seven_a_side" | "7v7" | "7" => Some(PitchFormat::SevenASide),
+            "five_a_side" | "5v5" | "5" | "futsal" => Some(PitchFormat::FiveASide),
+            _ => None,
+        }
+    }
+}
+
+/// Pitch geometry for a single match
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PitchSpec {
+    pub format: PitchFormat,
+    /// Goal line to goal line (meters)
+    pub length_m: f32,
+    /// Touchline to touchline (meters)
+    pub width_m: f32,
+    pub goal_width_m: f32,
+    pub goal_height_m: f32,
+    pub penalty_area_length_m: f32,
+    pub penalty_area_width_m: f32,
+    pub goal_area_length_m: f32,
+    pub goal_area_width_m: f32,
+    /// Penalty spot distance from the goal line (meters)
+    pub penalty_spot_m: f32,
+    pub center_circle_radius_m: f32,
+}
+
+impl Default for PitchSpec {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+impl PitchSpec {
+    /// Standard 105m × 68m 11-a-side pitch (engine canonical frame)
+    pub fn standard() -> Self {
+        Self {
+            format: PitchFormat::ElevenASide,
+            length_m: field::LENGTH_M,
+            width_m: field::WIDTH_M,
+            goal_width_m: 7.32,
+            goal_height_m: 2.44,
+            penalty_area_length_m: field::PENALTY_AREA_LENGTH_M,
+            penalty_area_width_m: 40.32,
+            goal_area_length_m: 5.5,
+            goal_area_width_m: 18.32,
+            penalty_spot_m: field::PENALTY_SPOT_M,
+            center_circle_radius_m: field::CENTER_CIRCLE_RADIUS_M,
+        }
+    }
+
+    /// Typical pitch for the given match format
+    pub fn for_format(format: PitchFormat) -> Self {
+        match format {
+            PitchFormat::ElevenASide => Self::standard(),
+            PitchFormat::NineASide => Self {
+                format,
+                length_m: 64.0,
+                width_m: 45.0,
+                goal_width_m: 6.4,
+                goal_height_m: 2.13,
+                penalty_area_length_m: 13.0,
+                penalty_area_width_m: 32.0,
+                goal_area_length_m: 4.0,
+                goal_area_width_m: 14.6,
+                penalty_spot_m: 9.0,
+                center_circle_radius_m: 7.0,
+            },
+            PitchFormat::SevenASide => Self {
+                format,
+                length_m: 55.0,
+                width_m: 37.0,
+                goal_width_m: 5.0,
+                goal_height_m: 2.0,
+                penalty_area_length_m: 10.0,
+                penalty_area_width_m: 26.0,
+                goal_area_length_m: 3.0,
+                goal_area_width_m: 11.0,
+                penalty_spot_m: 8.0,
+                center_circle_radius_m: 6.0,
+            },
+            PitchFormat::FiveASide => Self {
+                format,
+                length_m: 40.0,
+                width_m: 20.0,
+                goal_width_m: 3.0,
+                goal_height_m: 2.0,
+                penalty_area_length_m: 6.0,
+                penalty_area_width_m: 15.0,
+                goal_area_length_m: 0.0,
+                goal_area_width_m: 0.0,
+                penalty_spot_m: 6.0,
+                center_circle_radius_m: 3.0,
+            },
+        }
+    }
+
+    /// Format defaults with custom outer dimensions (markings kept as-is)
+    pub fn with_dimensions(format: PitchFormat, length_m: f32, width_m: f32) -> Self {
+        Self { length_m, width_m, ..Self::for_format(format) }
+    }
+
+    /// True when this pitch maps 1:1 onto the engine frame
+    pub fn is_standard(&self) -> bool {
+        self.length_m == ENGINE_LENGTH_M && self.width_m == ENGINE_WIDTH_M
+    }
+
+    /// Validate dimensions against the legal ranges of `format`.
+    pub fn validate(&self) -> Result<(), String> {
+        let values = [
+            self.length_m,
+            self.width_m,
+            self.goal_width_m,
+            self.goal_height_m,
+            self.penalty_area_length_m,
+            self.penalty_area_width_m,
+            self.goal_area_length_m,
+            self.goal_area_width_m,
+            self.penalty_spot_m,
+            self.center_circle_radius_m,
+        ];
+        if values.iter().any(|v| !v.is_finite() || *v < 0.0) {
+            return Err("pitch dimensions must be finite and non-negative".to_string());
+        }
+
+        let ((len_min, len_max), (wid_min, wid_max)) = self.format.legal_ranges();
+        if !(len_min..=len_max).contains(&self.length_m) {
+            return Err(format!(
+                "length_m={:.1} outside {:?} range [{:.0}, {:.0}]",
+                self.length_m, self.format, len_min, len_max
+            ));
+        }
+        if !(wid_min..=wid_max).contains(&self.width_m) {
+            return Err(format!(
+                "width_m={:.1} outside {:?} range [{:.0}, {:.0}]",
+                self.width_m, self.format, wid_min, wid_max
+            ));
+        }
+        if self.width_m >= self.length_m {
+            return Err("width_m must be smaller than length_m".to_string());
+        }
+        if self.goal_width_m <= 0.0 || self.goal_width_m >= self.width_m {
+            return Err(format!("goal_width_m={:.2} invalid for pitch", self.goal_width_m));
+        }
+        if self.penalty_area_width_m > self.width_m
+            || self.penalty_area_length_m * 2.0 >= self.length_m
+        {
+            return Err("penalty area does not fit inside the pitch".to_string());
+        }
+        Ok(())
+    }
+
+    /// Pitch center (meters, pitch frame)
+    #[inline]
+    pub fn center(&self) -> (f32, f32) {
+        (self.length_m * 0.5, self.width_m * 0.5)
+    }
+
+    #[inline]
+    pub fn goal_half_width_m(&self) -> f32 {
+        self.goal_width_m * 0.5
+    }
+
+    /// Goal mouth Y range (meters, pitch frame)
+    #[inline]
+    pub fn goal_y_range(&self) -> (f32, f32) {
+        let cy = self.width_m * 0.5;
+        (cy - self.goal_half_width_m(), cy + self.goal_half_width_m())
+    }
+
+    /// Pitch-frame meters per engine-frame meter on each axis
+    #[inline]
+    pub fn scale_from_engine(&self) -> (f32, f32) {
+        (self.length_m / ENGINE_LENGTH_M, self.width_m / ENGINE_WIDTH_M)
+    }
+
+    /// Map an engine-frame position (105×68) onto this pitch.
+    #[inline]
+    pub fn engine_to_pitch(&self, pos_m: (f32, f32)) -> (f32, f32) {
+        let (sx, sy) = self.scale_from_engine();
+        (pos_m.0 * sx, pos_m.1 * sy)
+    }
+
+    /// Map a pitch-frame position back into the engine frame.
+    #[inline]
+    pub fn pitch_to_engine(&self, pos_m: (f32, f32)) -> (f32, f32) {
+        let (sx, sy) = self.scale_from_engine();
+        (pos_m.0 / sx, pos_m.1 / sy)
+    }
+
+    /// Pitch-frame meters → normalized (0..1, 0..1)
+    #[inline]
+    pub fn normalize(&self, pos_m: (f32, f32)) -> (f32, f32) {
+        (pos_m.0 / self.length_m, pos_m.1 / self.width_m)
+    }
+
+    /// Normalized (0..1, 0..1) → pitch-frame meters
+    #[inline]
+    pub fn denormalize(&self, pos: (f32, f32)) -> (f32, f32) {
+        (pos.0 * self.length_m, pos.1 * self.width_m)
+    }
+
+    /// Clamp a pitch-frame position onto the field of play
+    #[inline]
+    pub fn clamp(&self, pos_m: (f32, f32)) -> (f32, f32) {
+        (pos_m.0.clamp(0.0, self.length_m), pos_m.1.clamp(0.0, self.width_m))
+    }
+
+    #[inline]
+    pub fn contains(&self, pos_m: (f32, f32)) -> bool {
+        (0.0..=self.length_m).contains(&pos_m.0) && (0.0..=self.width_m).contains(&pos_m.1)
+    }
+
+    /// Viewer-facing geometry summary (bridge `get_field_coordinate_info`)
+    pub fn coordinate_info(&self) -> PitchCoordinateInfo {
+        PitchCoordinateInfo {
+            format: self.format,
+            pitch_length_m: self.length_m,
+            pitch_width_m: self.width_m,
+            goal_width_m: self.goal_width_m,
+            goal_height_m: self.goal_height_m,
+            penalty_area_length_m: self.penalty_area_length_m,
+            penalty_area_width_m: self.penalty_area_width_m,
+            goal_area_length_m: self.goal_area_length_m,
+            goal_area_width_m: self.goal_area_width_m,
+            penalty_spot_m: self.penalty_spot_m,
+            center_circle_radius_m: self.center_circle_radius_m,
+            engine_frame_m: (ENGINE_LENGTH_M, ENGINE_WIDTH_M),
+            coordinate_system: "normalized".to_string(),
+            x_range: (0.0, 1.0),
+            y_range: (0.0, 1.0),
+        }
+    }
+}
+
+/// Serialized pitch geometry for viewers/UI
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PitchCoordinateInfo {
+    pub format: PitchFormat,
+    pub pitch_length_m: f32,
+    pub pitch_width_m: f32,
+    pub goal_width_m: f32,
+    pub goal_height_m: f32,
+    pub penalty_area_length_m: f32,
+    pub penalty_area_width_m: f32,
+    pub goal_area_length_m: f32,
+    pub goal_area_width_m: f32,
+    pub penalty_spot_m: f32,
+    pub center_circle_radius_m: f32,
+    /// Engine simulation frame (positions in MatchPositionData use this frame)
+    pub engine_frame_m: (f32, f32),
+    pub coordinate_system: String,
+    pub x_range: (f32, f32),
+    pub y_range: (f32, f32),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_standard_is_identity() {
+        let pitch = PitchSpec::standard();
+        assert!(pitch.is_standard());
+        assert!(pitch.validate().is_ok());
+        assert_eq!(pitch.engine_to_pitch((80.0, 20.0)), (80.0, 20.0));
+        assert_eq!(pitch.pitch_to_engine((80.0, 20.0)), (80.0, 20.0));
+    }
+
+    #[test]
+    fn test_format_presets_are_legal() {
+        for format in [
+            PitchFormat::ElevenASide,
+            PitchFormat::NineASide,
+            PitchFormat::SevenASide,
+            PitchFormat::FiveASide,
+        ] {
+            let pitch = PitchSpec::for_format(format);
+            assert!(pitch.validate().is_ok(), "{:?}: {:?}", format, pitch.validate());
+        }
+    }
+
+    #[test]
+    fn test_engine_mapping_round_trip() {
+        let pitch = PitchSpec::for_format(PitchFormat::SevenASide);
+        let engine_center = (ENGINE_LENGTH_M * 0.5, ENGINE_WIDTH_M * 0.5);
+        let c = pitch.engine_to_pitch(engine_center);
+        assert!((c.0 - pitch.center().0).abs() < 1e-4);
+        assert!((c.1 - pitch.center().1).abs() < 1e-4);
+
+        let back = pitch.pitch_to_engine(c);
+        assert!((back.0 - engine_center.0).abs() < 1e-3);
+        assert!((back.1 - engine_center.1).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_validate_rejects_out_of_range() {
+        let pitch = PitchSpec::with_dimensions(PitchFormat::ElevenASide, 140.0, 68.0);
+        assert!(pitch.validate().is_err());
+
+        let pitch = PitchSpec::with_dimensions(PitchFormat::SevenASide, 55.0, 60.0);
+        assert!(pitch.validate().is_err());
+    }
+
+    #[test]
+    fn test_partial_json_uses_standard_defaults() {
+        let pitch: PitchSpec = serde_json::from_str(r#"{"length_m": 100.0}"#).unwrap();
+        assert_eq!(pitch.length_m, 100.0);
+        assert_eq!(pitch.width_m, 68.0);
+        assert_eq!(pitch.format, PitchFormat::ElevenASide);
+    }
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!(PitchFormat::parse("7v7"), Some(PitchFormat::SevenASide));
+        assert_eq!(PitchFormat::parse("five-a-side"), Some(PitchFormat::FiveASide));
+        assert_eq!(PitchFormat::parse("rugby"), None);
+    }
+}
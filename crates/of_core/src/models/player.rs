@@ -3,6 +3,7 @@ use super::trait_system::{TraitId, TraitSlots};
 use crate::player::personality::{DecisionModifiers, PersonalityArchetype};
 use rand::{rngs::StdRng, Rng, SeedableRng};
 use serde::{Deserialize, Serialize};
+use schemars::JsonSchema;
 use std::str::FromStr;
 
 /// Player data for match simulation engine.
@@ -44,12 +45,28 @@ pub struct Player {
     /// Personality archetype (drives tactical decision modifiers)
     #[serde(default)]
     pub personality: PersonalityArchetype,
+
+    /// Morale 0..=100 (50 = neutral); scales mental attributes in the engine
+    #[serde(default = "default_morale")]
+    pub morale: u8,
+
+    /// Recent form 0..=100 (50 = neutral); scales technical attributes in the engine
+    #[serde(default = "default_form")]
+    pub form: u8,
 }
 
 fn default_condition_level() -> u8 {
     3
 }
 
+fn default_morale() -> u8 {
+    crate::player::morale::NEUTRAL_MORALE
+}
+
+fn default_form() -> u8 {
+    crate::player::morale::NEUTRAL_FORM
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum Position {
@@ -382,7 +399,7 @@ impl FromStr for Position {
 
 /// Open-Football original player skills structure (36 attributes)
 /// Technical (14) + Mental (14) + Physical (8) + Goalkeeper (11) = 47 fields total
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
 pub struct PlayerAttributes {
     // Technical attributes (14) - exact match with Open-Football original
     pub corners: u8,
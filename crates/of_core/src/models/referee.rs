@@ -0,0 +1,159 @@
+//! Match referee profile
+//!
+//! `RefereeProfile` describes how the match official applies the Laws:
+//! - `strictness`: how readily contact is whistled as a foul
+//! - `card_tendency`: how readily a foul becomes a caution / sending-off
+//! - `advantage_tendency`: how often play is allowed to continue after a foul
+//!
+//! All three are 0.0..1.0 with 0.5 as the calibrated baseline (every
+//! multiplier is exactly 1.0), so a neutral referee leaves the match untouched.
+//! `from_seed` derives a profile deterministically without touching the
+//! match RNG stream.
+
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Salt so a referee seed equal to the match seed does not mirror match rolls.
+const REFEREE_SEED_SALT: u64 = 0x5EF_E5EE_D0C0_FFEE;
+
+/// Tendency range for seeded referees (extremes are rare in real officiating).
+const SEEDED_MIN: f32 = 0.15;
+const SEEDED_MAX: f32 = 0.85;
+
+const REFEREE_NAMES: [&str; 12] = [
+    "J. Park",
+    "M. Kessler",
+    "A. Romano",
+    "D. Hughes",
+    "S. Kim",
+    "L. Moreau",
+    "R. Alvarez",
+    "T. Lindqvist",
+    "K. Mensah",
+    "H. Tanaka",
+    "P. Novak",
+    "E. Costa",
+];
+
+/// Referee assigned to a match
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct RefereeProfile {
+    pub name: String,
+    /// 0.0 (lets contact go) .. 1.0 (whistles everything)
+    #[serde(default = "neutral_tendency")]
+    pub strictness: f32,
+    /// 0.0 (reluctant to book) .. 1.0 (quick to show cards)
+    #[serde(default = "neutral_tendency")]
+    pub card_tendency: f32,
+    /// 0.0 (stops play for every foul) .. 1.0 (plays advantage whenever possible)
+    #[serde(default = "neutral_tendency")]
+    pub advantage_tendency: f32,
+}
+
+fn neutral_tendency() -> f32 {
+    0.5
+}
+
+impl Default for RefereeProfile {
+    fn default() -> Self {
+        Self {
+            name: "Referee".to_string(),
+            strictness: neutral_tendency(),
+            card_tendency: neutral_tendency(),
+            advantage_tendency: neutral_tendency(),
+        }
+    }
+}
+
+impl RefereeProfile {
+    /// Deterministic referee for `seed` (same seed → same name and tendencies)
+    pub fn from_seed(seed: u64) -> Self {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed ^ REFEREE_SEED_SALT);
+        let name = REFEREE_NAMES[rng.gen_range(0..REFEREE_NAMES.len())].to_string();
+        let mut tendency = || SEEDED_MIN + rng.gen::<f32>() * (SEEDED_MAX - SEEDED_MIN);
+        Self {
+            name,
+            strictness: tendency(),
+            card_tendency: tendency(),
+            advantage_tendency: tendency(),
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("referee name must not be empty".to_string());
+        }
+        for (label, value) in [
+            ("strictness", self.strictness),
+            ("card_tendency", self.card_tendency),
+            ("advantage_tendency", self.advantage_tendency),
+        ] {
+            if !value.is_finite() || !(0.0..=1.0).contains(&value) {
+                return Err(format!("referee {} must be within 0..1, got {}", label, value));
+            }
+        }
+        Ok(())
+    }
+
+    /// Foul call frequency multiplier (0.75..1.25)
+    pub fn foul_call_mult(&self) -> f32 {
+        0.75 + self.strictness.clamp(0.0, 1.0) * 0.5
+    }
+
+    /// Card probability multiplier; >1 lowers the threshold for a caution (0.5..1.5)
+    pub fn card_mult(&self) -> f32 {
+        0.5 + self.card_tendency.clamp(0.0, 1.0)
+    }
+
+    /// Probability of playing advantage when the situation allows it (0.5..1.0)
+    pub fn advantage_rate(&self) -> f32 {
+        (0.5 + self.advantage_tendency.clamp(0.0, 1.0)).min(1.0)
+    }
+
+    /// Opponents tolerated near the foul before the referee stops play
+    pub fn advantage_pressure_tolerance(&self) -> usize {
+        if self.advantage_tendency >= 0.75 {
+            2
+        } else {
+            1
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_neutral_referee_is_calibrated_baseline() {
+        let referee = RefereeProfile::default();
+        assert_eq!(referee.foul_call_mult(), 1.0);
+        assert_eq!(referee.card_mult(), 1.0);
+        assert_eq!(referee.advantage_rate(), 1.0);
+        assert_eq!(referee.advantage_pressure_tolerance(), 1);
+        assert!(referee.validate().is_ok());
+    }
+
+    #[test]
+    fn test_seeded_referee_is_deterministic() {
+        let a = RefereeProfile::from_seed(2026);
+        assert_eq!(a, RefereeProfile::from_seed(2026));
+        assert!(a.validate().is_ok());
+        for value in [a.strictness, a.card_tendency, a.advantage_tendency] {
+            assert!((SEEDED_MIN..=SEEDED_MAX).contains(&value));
+        }
+
+        let distinct = (0..20u64).map(RefereeProfile::from_seed).filter(|r| *r != a).count();
+        assert!(distinct >= 19);
+    }
+
+    #[test]
+    fn test_validate_rejects_out_of_range() {
+        let referee = RefereeProfile { strictness: 1.5, ..Default::default() };
+        assert!(referee.validate().is_err());
+        let referee = RefereeProfile { name: " ".to_string(), ..Default::default() };
+        assert!(referee.validate().is_err());
+    }
+}
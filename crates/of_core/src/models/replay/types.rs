@@ -158,6 +158,10 @@ pub struct DecisionIntent {
     pub pass_targets: Vec<IntentTarget>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub nearby_opponents: Vec<MeterPos>,
+    /// Full pass candidate evaluations (debug flag only, see
+    /// `MatchEngine::set_pass_candidate_debug`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pass_candidates: Vec<PassCandidateEval>,
 }
 
 /// Alternative action that was considered but not chosen
@@ -177,6 +181,39 @@ pub struct IntentTarget {
     pub quality: f32,
 }
 
+/// Per-factor breakdown of one pass candidate (debug overlay)
+///
+/// `quality = clamp(distance_factor * zone_factor * progression_factor) - diversity_penalty`,
+/// then raised to a floor for safe/wide options. `pressure` and `lane_openness`
+/// are diagnostic only (not part of `quality`).
+#[derive(Serialize, Deserialize, Clone, Debug, JsonSchema)]
+pub struct PassCandidateEval {
+    pub player_id: u32,
+    pub pos: MeterPos,
+    /// Final ranking quality (0.0 when excluded)
+    pub quality: f32,
+    /// Distance term (shorter = higher)
+    pub distance_factor: f32,
+    /// Zone transition term (team style)
+    pub zone_factor: f32,
+    /// Attack sub-phase forward/circulation weight
+    pub progression_factor: f32,
+    /// Frequent-receiver penalty
+    pub diversity_penalty: f32,
+    /// Receiver pressure (0.0 = free, 1.0 = opponent within touching distance)
+    pub pressure: f32,
+    /// Lane openness (0.0 = opponent on the line, 1.0 = >= 5m clear)
+    pub lane_openness: f32,
+    /// Meters gained toward the opponent goal (negative = backward)
+    pub progression_m: f32,
+    pub is_forward: bool,
+    /// Passed the tactical improvement gate (forward passes only)
+    pub tactical_ok: bool,
+    /// Why the candidate was dropped ("offside", "goalkeeper")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub excluded: Option<String>,
+}
+
 /// Context snapshot at decision time
 #[derive(Serialize, Deserialize, Clone, Debug, JsonSchema, Default)]
 pub struct IntentContext {
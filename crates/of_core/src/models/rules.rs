@@ -3,6 +3,7 @@
 //! Rule-as-Data 시스템을 위한 Rust 타입 정의.
 //! YAML 데이터 파일과 매핑되어 규칙 설명/분류를 제공합니다.
 //!
+//! - Law 5: The Referee (Advantage)
 //! - Law 7: Duration of Match
 //! - Law 8: Start and Restart of Play
 //! - Law 9: Ball In and Out of Play
@@ -31,6 +32,10 @@ use super::events::EventType;
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum RuleId {
+    // Law 5: The Referee
+    /// 어드밴티지 (반칙 후 플레이 계속)
+    Advantage,
+
     // Law 7: Duration of Match
     /// 경기 시간
     Duration,
@@ -75,6 +80,12 @@ pub enum RuleId {
     /// 시뮬레이션
     Simulation,
 
+    // Law 12.2: Goalkeeper offences (indirect free kick)
+    /// 골키퍼가 팀 동료의 의도적 킥/스로인을 손으로 처리
+    BackPass,
+    /// 골키퍼가 공을 손에 6초 넘게 소유
+    GoalkeeperHolding,
+
     // Law 13: Free Kicks
     /// 직접 프리킥
     DirectFreeKick,
@@ -102,6 +113,7 @@ impl RuleId {
     /// IFAB Law 번호 반환
     pub fn law_number(&self) -> u8 {
         match self {
+            RuleId::Advantage => 5,
             RuleId::Duration => 7,
             RuleId::KickOff => 8,
             RuleId::BallInOut => 9,
@@ -118,7 +130,9 @@ impl RuleId {
             | RuleId::SeriousFoulPlay
             | RuleId::ViolentConduct
             | RuleId::Handball
-            | RuleId::Simulation => 12,
+            | RuleId::Simulation
+            | RuleId::BackPass
+            | RuleId::GoalkeeperHolding => 12,
 
             RuleId::DirectFreeKick | RuleId::IndirectFreeKick => 13,
             RuleId::PenaltyKick => 14,
@@ -131,6 +145,7 @@ impl RuleId {
     /// 규칙 이름 (한국어)
     pub fn name_ko(&self) -> &'static str {
         match self {
+            RuleId::Advantage => "어드밴티지",
             RuleId::Duration => "경기 시간",
             RuleId::KickOff => "킥오프",
             RuleId::BallInOut => "볼 인/아웃",
@@ -146,6 +161,8 @@ impl RuleId {
             RuleId::ViolentConduct => "난폭 행위",
             RuleId::Handball => "핸드볼",
             RuleId::Simulation => "시뮬레이션",
+            RuleId::BackPass => "백패스 핸들링",
+            RuleId::GoalkeeperHolding => "골키퍼 6초 규정",
             RuleId::DirectFreeKick => "직접 프리킥",
             RuleId::IndirectFreeKick => "간접 프리킥",
             RuleId::PenaltyKick => "페널티킥",
@@ -158,6 +175,7 @@ impl RuleId {
     /// 규칙 이름 (영어)
     pub fn name_en(&self) -> &'static str {
         match self {
+            RuleId::Advantage => "Advantage",
             RuleId::Duration => "Duration of the Match",
             RuleId::KickOff => "Kick-off",
             RuleId::BallInOut => "Ball In and Out of Play",
@@ -173,6 +191,8 @@ impl RuleId {
             RuleId::ViolentConduct => "Violent conduct",
             RuleId::Handball => "Handball",
             RuleId::Simulation => "Simulation",
+            RuleId::BackPass => "Goalkeeper handling a back-pass",
+            RuleId::GoalkeeperHolding => "Goalkeeper holding the ball",
             RuleId::DirectFreeKick => "Direct free kick",
             RuleId::IndirectFreeKick => "Indirect free kick",
             RuleId::PenaltyKick => "Penalty kick",
@@ -193,6 +213,9 @@ impl RuleId {
             EventType::Offside => Some(RuleId::OffsidePosition),
             EventType::Foul => Some(RuleId::FoulCareless), // 기본값, FoulDetails에서 세분화
             EventType::Handball => Some(RuleId::Handball), // FIX_2601/0123 Phase 6
+            EventType::BackPassOffence => Some(RuleId::BackPass),
+            EventType::GoalkeeperHolding => Some(RuleId::GoalkeeperHolding),
+            EventType::AdvantagePlayed => Some(RuleId::Advantage),
             EventType::YellowCard => Some(RuleId::FoulReckless),
             EventType::RedCard => Some(RuleId::FoulExcessiveForce),
             EventType::ThrowIn => Some(RuleId::ThrowIn),
@@ -215,7 +238,13 @@ impl RuleId {
             | EventType::KeyChance
             | EventType::Substitution
             | EventType::Injury
-            | EventType::VarReview => None,
+            | EventType::VarReview
+            | EventType::VarReviewStarted
+            | EventType::VarDecision
+            | EventType::TeamTalk
+            | EventType::TacticalAdaptation
+            | EventType::Weather
+            | EventType::AddedTime => None,
         }
     }
 
@@ -225,6 +254,9 @@ impl RuleId {
             event_type,
             EventType::Offside
                 | EventType::Foul
+                | EventType::Handball
+                | EventType::BackPassOffence
+                | EventType::GoalkeeperHolding
                 | EventType::YellowCard
                 | EventType::RedCard
                 | EventType::ThrowIn
@@ -237,6 +269,8 @@ impl RuleId {
                 | EventType::PostHit
                 | EventType::BarHit
                 | EventType::VarReview
+                | EventType::VarDecision
+                | EventType::AdvantagePlayed
         )
     }
 }
@@ -249,6 +283,9 @@ impl FromStr for RuleId {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
+            // Law 5: The Referee
+            "ADVANTAGE" | "LAW_05_ADVANTAGE" => Ok(RuleId::Advantage),
+
             // Law 7: Duration
             "DURATION" | "LAW_07_DURATION" => Ok(RuleId::Duration),
 
@@ -277,6 +314,8 @@ impl FromStr for RuleId {
             "VIOLENT_CONDUCT" => Ok(RuleId::ViolentConduct),
             "HANDBALL" => Ok(RuleId::Handball),
             "SIMULATION" => Ok(RuleId::Simulation),
+            "BACK_PASS" | "LAW_12_BACK_PASS" => Ok(RuleId::BackPass),
+            "GOALKEEPER_HOLDING" | "LAW_12_GOALKEEPER_HOLDING" => Ok(RuleId::GoalkeeperHolding),
 
             // Law 13: Free Kicks
             "DIRECT_FREE_KICK" | "LAW_13_FREE_KICK" => Ok(RuleId::DirectFreeKick),
@@ -376,7 +415,7 @@ impl RestartType {
 ///
 /// 오프사이드 판정의 근거를 설명하기 위한 데이터.
 /// "Why?" 질문에 답할 수 있도록 마진, 라인 위치 등을 포함합니다.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct OffsideDetails {
     /// 오프사이드 마진 (미터 단위)
     /// 양수: 오프사이드, 음수: 온사이드
@@ -405,6 +444,26 @@ pub struct OffsideDetails {
     /// 굴절/세이브 컨텍스트 (deliberate play 판단용)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub deflection_context: Option<DeflectionContext>,
+
+    // -------------------------------------------------------------------------
+    // 패스 순간 스냅샷 (Why? 카드 / 리플레이에서 실제 라인을 그리기 위함)
+    // 좌표는 월드 미터 (x: 0-105 골라인 방향, y: 0-68 폭 방향)
+    // -------------------------------------------------------------------------
+    /// 패스 순간 공격수(수신자) 위치
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attacker_pos_m: Option<(f32, f32)>,
+
+    /// 오프사이드 라인을 만든 두 번째 최종 수비수의 track_id
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub defender_track_id: Option<u8>,
+
+    /// 패스 순간 두 번째 최종 수비수 위치
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub defender_pos_m: Option<(f32, f32)>,
+
+    /// 패스 순간 패서 위치
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub passer_pos_m: Option<(f32, f32)>,
 }
 
 /// 오프사이드 재시작 컨텍스트
@@ -826,6 +885,83 @@ impl FoulDetails {
     }
 }
 
+// =============================================================================
+// Advantage (Law 5.3 / Law 12.3)
+// =============================================================================
+
+/// 어드밴티지 진행 단계
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AdvantageOutcome {
+    /// 어드밴티지 선언 - 플레이 계속
+    Signalled,
+    /// 이익 발생 - 반칙에 대한 재개 없음, 보류된 카드는 다음 정지 시 집행
+    Accrued,
+    /// 이익이 발생하지 않음 - 원래 반칙 지점에서 프리킥
+    CalledBack,
+}
+
+/// 어드밴티지로 보류된 카드
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AdvantageCard {
+    Yellow,
+    Red,
+}
+
+/// 어드밴티지 상세 정보
+///
+/// 반칙 정보와 진행 단계를 담고, 카드 판정은 Law 12.3에 따라 계산합니다.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AdvantageDetails {
+    /// 어드밴티지 진행 단계
+    pub outcome: AdvantageOutcome,
+
+    /// 원래 반칙의 심각도
+    pub severity: FoulSeverity,
+
+    /// 명백한 득점 기회 저지 (DOGSO) 여부
+    #[serde(default)]
+    pub is_dogso: bool,
+
+    /// 유망한 공격 저지 (SPA) 여부
+    #[serde(default)]
+    pub stopped_promising_attack: bool,
+}
+
+impl AdvantageDetails {
+    /// 경기를 멈췄다면 주어졌을 카드
+    pub fn card_if_stopped(&self) -> Option<AdvantageCard> {
+        match self.severity {
+            FoulSeverity::ExcessiveForce => Some(AdvantageCard::Red),
+            _ if self.is_dogso => Some(AdvantageCard::Red),
+            FoulSeverity::Reckless => Some(AdvantageCard::Yellow),
+            FoulSeverity::Careless if self.stopped_promising_attack => Some(AdvantageCard::Yellow),
+            FoulSeverity::Careless => None,
+        }
+    }
+
+    /// 어드밴티지 적용 후 다음 정지 시 집행할 카드
+    ///
+    /// Law 12.3: DOGSO는 경고로 감경, SPA 경고는 취소, 무모함/과도한 힘은 그대로.
+    pub fn card_after_advantage(&self) -> Option<AdvantageCard> {
+        match self.severity {
+            FoulSeverity::ExcessiveForce => Some(AdvantageCard::Red),
+            FoulSeverity::Reckless => Some(AdvantageCard::Yellow),
+            FoulSeverity::Careless if self.is_dogso => Some(AdvantageCard::Yellow),
+            FoulSeverity::Careless => None,
+        }
+    }
+
+    /// 진행 단계 기준 최종 카드 (되돌린 경우 원래 제재)
+    pub fn card(&self) -> Option<AdvantageCard> {
+        match self.outcome {
+            AdvantageOutcome::CalledBack => self.card_if_stopped(),
+            AdvantageOutcome::Signalled | AdvantageOutcome::Accrued => self.card_after_advantage(),
+        }
+    }
+}
+
 // =============================================================================
 // YAML Data Structures (for serde deserialization)
 // =============================================================================
@@ -1026,6 +1162,8 @@ mod tests {
         assert_eq!(RuleId::OffsidePosition.law_number(), 11);
         assert_eq!(RuleId::FoulCareless.law_number(), 12);
         assert_eq!(RuleId::Dogso.law_number(), 12);
+        assert_eq!(RuleId::GoalkeeperHolding.law_number(), 12);
+        assert_eq!("BACK_PASS".parse::<RuleId>(), Ok(RuleId::BackPass));
     }
 
     #[test]
@@ -1077,4 +1215,29 @@ mod tests {
         // DOGSO without ball attempt = red card
         assert_eq!(foul.expected_sanction(), FoulSanction::RedCardAndPenalty);
     }
+
+    #[test]
+    fn test_advantage_card_follows_law_12_3() {
+        let dogso = AdvantageDetails {
+            outcome: AdvantageOutcome::Accrued,
+            severity: FoulSeverity::Careless,
+            is_dogso: true,
+            stopped_promising_attack: false,
+        };
+        // DOGSO played on = caution instead of sending-off
+        assert_eq!(dogso.card_if_stopped(), Some(AdvantageCard::Red));
+        assert_eq!(dogso.card(), Some(AdvantageCard::Yellow));
+
+        let spa = AdvantageDetails { is_dogso: false, stopped_promising_attack: true, ..dogso };
+        // SPA played on = no caution
+        assert_eq!(spa.card_if_stopped(), Some(AdvantageCard::Yellow));
+        assert_eq!(spa.card(), None);
+
+        // Called back = the original sanction stands
+        let called_back = AdvantageDetails { outcome: AdvantageOutcome::CalledBack, ..spa };
+        assert_eq!(called_back.card(), Some(AdvantageCard::Yellow));
+
+        let reckless = AdvantageDetails { severity: FoulSeverity::Reckless, ..spa };
+        assert_eq!(reckless.card(), Some(AdvantageCard::Yellow));
+    }
 }
@@ -3,6 +3,7 @@
 //! RPG-style special skills that trigger based on attribute combinations
 
 use serde::{Deserialize, Serialize};
+use schemars::JsonSchema;
 
 /// 스킬 발동 액션 타입 (match_sim.rs에서 공유)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -37,7 +38,7 @@ pub struct SkillContext {
 }
 
 /// Special skills that can be equipped by players
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub enum SpecialSkill {
     // === 멘탈/창의성 기반 스킬 (Flair-Based) ===
     /// 앵클 브레이커: 드리블 시 수비수 Frozen 확률 1.5배
@@ -0,0 +1,226 @@
+//! Home stadium definitions
+//!
+//! A `Stadium` fixes the pitch dimensions (within the 11-a-side legal range)
+//! and the surface quality of a team's home ground. Effects are deliberately
+//! small and apply to both teams, since both play on the same grass:
+//! - width shifts the wide-play bias (wide pitch → more crosses/switches)
+//! - surface quality scales pass success; the home side, used to its own
+//!   pitch, feels only half of any penalty
+//! - altitude above 1000m tires the visitors faster (the hosts are acclimatised)
+//!
+//! `capacity` and the fixture's `attendance` feed the crowd model
+//! (`crowd::CrowdPressure`); a stadium without an attendance has no crowd effect.
+//!
+//! `StadiumRegistry` maps team names to their home ground and is persisted
+//! in the save.
+
+use std::collections::BTreeMap;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::pitch::{PitchFormat, PitchSpec, ENGINE_WIDTH_M};
+use crate::engine::TeamMatchModifiers;
+
+/// Wide-play bias per meter of width above/below 68m
+pub const WIDTH_BIAS_PER_M: f32 = 0.25;
+/// Max absolute wide-play bias from pitch width (meters)
+pub const MAX_WIDTH_BIAS_M: f32 = 2.5;
+/// Altitude the visitors start to feel (meters above sea level)
+pub const ALTITUDE_THRESHOLD_M: f32 = 1000.0;
+/// Visitor stamina drain per 1000m above the threshold
+pub const ALTITUDE_DRAIN_PER_KM: f32 = 0.05;
+/// Max visitor stamina drain from altitude
+pub const MAX_ALTITUDE_DRAIN: f32 = 0.12;
+/// Highest ground accepted (La Paz is ~3640m)
+pub const MAX_ALTITUDE_M: f32 = 4500.0;
+
+/// Playing surface condition
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PitchSurface {
+    Pristine,
+    #[default]
+    Good,
+    Worn,
+    Poor,
+}
+
+impl PitchSurface {
+    /// Pass success multiplier for a visiting side
+    pub fn pass_success_mult(&self) -> f32 {
+        match self {
+            PitchSurface::Pristine => 1.02,
+            PitchSurface::Good => 1.0,
+            PitchSurface::Worn => 0.98,
+            PitchSurface::Poor => 0.95,
+        }
+    }
+}
+
+/// A team's home ground
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct Stadium {
+    pub name: String,
+    pub length_m: f32,
+    pub width_m: f32,
+    #[serde(default)]
+    pub surface: PitchSurface,
+    /// Seats (0 = unknown; any attendance then counts as a full house)
+    #[serde(default)]
+    pub capacity: u32,
+    /// Meters above sea level
+    #[serde(default)]
+    pub altitude_m: f32,
+    /// This fixture's crowd (None = no crowd effect, e.g. behind closed doors)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attendance: Option<u32>,
+}
+
+impl Stadium {
+    /// Standard 105×68 ground with a good surface
+    pub fn standard(name: impl Into<String>) -> Self {
+        let pitch = PitchSpec::standard();
+        Self {
+            name: name.into(),
+            length_m: pitch.length_m,
+            width_m: pitch.width_m,
+            surface: PitchSurface::Good,
+            capacity: 0,
+            altitude_m: 0.0,
+            attendance: None,
+        }
+    }
+
+    /// 11-a-side pitch with this ground's dimensions
+    pub fn pitch_spec(&self) -> PitchSpec {
+        PitchSpec::with_dimensions(PitchFormat::ElevenASide, self.length_m, self.width_m)
+    }
+
+    /// Validate dimensions against the 11-a-side legal ranges
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("stadium name must not be empty".to_string());
+        }
+        if !self.altitude_m.is_finite() || !(-500.0..=MAX_ALTITUDE_M).contains(&self.altitude_m)
+        {
+            return Err(format!(
+                "stadium altitude must be within -500..{MAX_ALTITUDE_M}m, got {}",
+                self.altitude_m
+            ));
+        }
+        if let Some(attendance) = self.attendance {
+            if self.capacity > 0 && attendance > self.capacity {
+                return Err(format!(
+                    "attendance {attendance} exceeds stadium capacity {}",
+                    self.capacity
+                ));
+            }
+        }
+        self.pitch_spec().validate()
+    }
+
+    /// Wide-play bias (meters) from pitch width
+    pub fn width_bias_m(&self) -> f32 {
+        ((self.width_m - ENGINE_WIDTH_M) * WIDTH_BIAS_PER_M)
+            .clamp(-MAX_WIDTH_BIAS_M, MAX_WIDTH_BIAS_M)
+    }
+
+    /// Visitor stamina drain multiplier from altitude (1.0 below the threshold)
+    pub fn altitude_drain_mult(&self) -> f32 {
+        let km_above = (self.altitude_m - ALTITUDE_THRESHOLD_M).max(0.0) / 1000.0;
+        1.0 + (km_above * ALTITUDE_DRAIN_PER_KM).min(MAX_ALTITUDE_DRAIN)
+    }
+
+    /// Apply stadium effects to both teams (host = home side)
+    pub fn apply_effects(&self, home: &mut TeamMatchModifiers, away: &mut TeamMatchModifiers) {
+        let surface = self.surface.pass_success_mult();
+        let home_surface = if surface < 1.0 { 1.0 - (1.0 - surface) * 0.5 } else { surface };
+        home.pass_success_mult *= home_surface;
+        away.pass_success_mult *= surface;
+
+        let width_bias = self.width_bias_m();
+        home.width_bias_add_m += width_bias;
+        away.width_bias_add_m += width_bias;
+
+        away.stamina_drain_mult *= self.altitude_drain_mult();
+    }
+}
+
+/// Team name → home stadium
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StadiumRegistry {
+    #[serde(default)]
+    pub stadiums: BTreeMap<String, Stadium>,
+}
+
+impl StadiumRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register (or replace) a team's home ground
+    pub fn register(&mut self, team: &str, stadium: Stadium) -> Result<(), String> {
+        stadium.validate()?;
+        self.stadiums.insert(team.to_string(), stadium);
+        Ok(())
+    }
+
+    pub fn get(&self, team: &str) -> Option<&Stadium> {
+        self.stadiums.get(team)
+    }
+
+    pub fn remove(&mut self, team: &str) -> Option<Stadium> {
+        self.stadiums.remove(team)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stadium_validation_uses_legal_ranges() {
+        assert!(Stadium::standard("Home Park").validate().is_ok());
+
+        let tiny = Stadium { width_m: 40.0, ..Stadium::standard("Tiny") };
+        assert!(tiny.validate().is_err());
+        let long = Stadium { length_m: 125.0, ..Stadium::standard("Long") };
+        assert!(long.validate().is_err());
+
+        let mut registry = StadiumRegistry::new();
+        assert!(registry.register("FC", tiny).is_err());
+        registry.register("FC", Stadium::standard("Home Park")).unwrap();
+        assert_eq!(registry.get("FC").unwrap().name, "Home Park");
+    }
+
+    #[test]
+    fn test_stadium_effects() {
+        let wide_poor =
+            Stadium { width_m: 75.0, surface: PitchSurface::Poor, ..Stadium::standard("Mud") };
+        let mut home = TeamMatchModifiers::default();
+        let mut away = TeamMatchModifiers::default();
+        wide_poor.apply_effects(&mut home, &mut away);
+
+        assert!((home.width_bias_add_m - 1.75).abs() < 1e-6);
+        assert_eq!(home.width_bias_add_m, away.width_bias_add_m);
+        assert!((away.pass_success_mult - 0.95).abs() < 1e-6);
+        assert!((home.pass_success_mult - 0.975).abs() < 1e-6);
+
+        let narrow = Stadium { width_m: 60.0, ..Stadium::standard("Box") };
+        assert_eq!(narrow.width_bias_m(), -2.0);
+
+        // Altitude tires only the visitors
+        let high = Stadium { altitude_m: 3600.0, ..Stadium::standard("Hernando Siles") };
+        let mut home = TeamMatchModifiers::default();
+        let mut away = TeamMatchModifiers::default();
+        high.apply_effects(&mut home, &mut away);
+        assert_eq!(home.stamina_drain_mult, 1.0);
+        assert!((away.stamina_drain_mult - 1.12).abs() < 1e-6);
+        assert_eq!(Stadium::standard("Sea Level").altitude_drain_mult(), 1.0);
+
+        let oversold =
+            Stadium { capacity: 20_000, attendance: Some(25_000), ..Stadium::standard("Box") };
+        assert!(oversold.validate().is_err());
+    }
+}
@@ -0,0 +1,181 @@
+//! Match-day weather and pitch condition
+//!
+//! `MatchConditions` combines the weather and the state of the grass on the
+//! day. Both teams play in the same conditions, so effects are symmetric and
+//! folded into each side's `TeamMatchModifiers` before kickoff:
+//! - pass accuracy (`pass_success_mult`)
+//! - ball speed off passes and shots (`ball_speed_mult`)
+//! - stamina drain (`stamina_drain_mult`)
+//! - injury probability (`injury_risk_mult`)
+//!
+//! Deterministic: no RNG, effects scale linearly with weather intensity.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::engine::TeamMatchModifiers;
+
+/// Weather type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WeatherKind {
+    #[default]
+    Clear,
+    Rain,
+    Snow,
+    Wind,
+    Heat,
+}
+
+impl WeatherKind {
+    /// Full-intensity effect: (pass, ball speed, stamina drain, injury risk)
+    fn full_effect(&self) -> (f32, f32, f32, f32) {
+        match self {
+            WeatherKind::Clear => (1.0, 1.0, 1.0, 1.0),
+            // Wet ball skids on: quicker passes, harder to control.
+            WeatherKind::Rain => (0.96, 1.05, 1.03, 1.10),
+            WeatherKind::Snow => (0.93, 0.85, 1.08, 1.15),
+            WeatherKind::Wind => (0.95, 0.97, 1.02, 1.0),
+            WeatherKind::Heat => (0.99, 1.0, 1.15, 1.05),
+        }
+    }
+}
+
+/// Weather on the day
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default, JsonSchema)]
+pub struct WeatherCondition {
+    pub kind: WeatherKind,
+    /// 0.0 (barely noticeable) .. 1.0 (severe)
+    #[serde(default = "default_intensity")]
+    pub intensity: f32,
+}
+
+fn default_intensity() -> f32 {
+    0.5
+}
+
+impl WeatherCondition {
+    pub fn new(kind: WeatherKind, intensity: f32) -> Self {
+        Self { kind, intensity }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if !self.intensity.is_finite() || !(0.0..=1.0).contains(&self.intensity) {
+            return Err(format!("weather intensity must be within 0..1, got {}", self.intensity));
+        }
+        Ok(())
+    }
+}
+
+/// State of the grass on the day
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PitchQuality {
+    Excellent,
+    #[default]
+    Good,
+    /// Bobbly, patchy surface
+    Worn,
+    /// Muddy / waterlogged: ball holds up, legs get heavy
+    Heavy,
+    /// Hard, frozen ground: fast bounce, more knocks
+    Frozen,
+}
+
+impl PitchQuality {
+    /// Effect: (pass, ball speed, stamina drain, injury risk)
+    fn effect(&self) -> (f32, f32, f32, f32) {
+        match self {
+            PitchQuality::Excellent => (1.02, 1.02, 1.0, 1.0),
+            PitchQuality::Good => (1.0, 1.0, 1.0, 1.0),
+            PitchQuality::Worn => (0.98, 0.98, 1.0, 1.05),
+            PitchQuality::Heavy => (0.95, 0.88, 1.06, 1.05),
+            PitchQuality::Frozen => (0.96, 1.06, 1.0, 1.20),
+        }
+    }
+}
+
+/// Weather + pitch condition for one match
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default, JsonSchema)]
+pub struct MatchConditions {
+    #[serde(default)]
+    pub weather: WeatherCondition,
+    #[serde(default)]
+    pub pitch_quality: PitchQuality,
+}
+
+impl MatchConditions {
+    pub fn validate(&self) -> Result<(), String> {
+        self.weather.validate()
+    }
+
+    /// Combined multipliers: (pass, ball speed, stamina drain, injury risk)
+    pub fn multipliers(&self) -> (f32, f32, f32, f32) {
+        let t = self.weather.intensity.clamp(0.0, 1.0);
+        let lerp = |full: f32| 1.0 + (full - 1.0) * t;
+        let (wp, ws, wd, wi) = self.weather.kind.full_effect();
+        let (pp, ps, pd, pi) = self.pitch_quality.effect();
+        (lerp(wp) * pp, lerp(ws) * ps, lerp(wd) * pd, lerp(wi) * pi)
+    }
+
+    /// Fold the conditions into both teams' match modifiers
+    pub fn apply_effects(&self, home: &mut TeamMatchModifiers, away: &mut TeamMatchModifiers) {
+        let (pass, speed, drain, injury) = self.multipliers();
+        for modifiers in [home, away] {
+            modifiers.pass_success_mult *= pass;
+            modifiers.ball_speed_mult *= speed;
+            modifiers.stamina_drain_mult *= drain;
+            modifiers.injury_risk_mult *= injury;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clear_good_is_neutral() {
+        let mut home = TeamMatchModifiers::default();
+        let mut away = TeamMatchModifiers::default();
+        MatchConditions::default().apply_effects(&mut home, &mut away);
+        assert_eq!(home, TeamMatchModifiers::default());
+        assert_eq!(away, TeamMatchModifiers::default());
+    }
+
+    #[test]
+    fn test_heavy_rain_effects_are_symmetric_and_scaled() {
+        let conditions = MatchConditions {
+            weather: WeatherCondition::new(WeatherKind::Rain, 1.0),
+            pitch_quality: PitchQuality::Heavy,
+        };
+        let mut home = TeamMatchModifiers::default();
+        let mut away = TeamMatchModifiers::default();
+        conditions.apply_effects(&mut home, &mut away);
+        assert_eq!(home, away);
+        assert!(home.pass_success_mult < 0.93);
+        assert!(home.injury_risk_mult > 1.1);
+
+        let light = MatchConditions {
+            weather: WeatherCondition::new(WeatherKind::Rain, 0.5),
+            pitch_quality: PitchQuality::Good,
+        };
+        let (pass, _, _, _) = light.multipliers();
+        assert!((pass - 0.98).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_heat_drains_stamina_and_snow_slows_ball() {
+        let heat = MatchConditions {
+            weather: WeatherCondition::new(WeatherKind::Heat, 1.0),
+            ..Default::default()
+        };
+        assert!(heat.multipliers().2 > 1.1);
+        let snow = MatchConditions {
+            weather: WeatherCondition::new(WeatherKind::Snow, 1.0),
+            ..Default::default()
+        };
+        assert!(snow.multipliers().1 < 0.9);
+        assert!(WeatherCondition::new(WeatherKind::Wind, 1.5).validate().is_err());
+    }
+}
@@ -0,0 +1,384 @@
+//! Player aging: yearly growth, decline and retirement
+//!
+//! `advance_season` ages a player by one year at season end. Each attribute
+//! group follows a curve around the position's peak age:
+//! - **developing** (before the peak): growth, scaled by `GrowthProfile`
+//!   (`growth_rate`, `training_response`) and by the CA → PA headroom
+//! - **peak**: technical/physical hold, mental keeps growing with experience
+//! - **declining**: physical goes first, technical later, mental last;
+//!   `natural_fitness` slows and `injury_prone` speeds up the physical drop
+//!
+//! Retirement is rolled after aging from age, ability and a serious injury.
+//! `CorePlayer.age_months` holds the age in years (see `GrowthCalculator`).
+//! Rolls are seeded per player (seed + player id), so the same season seed
+//! always ages a squad the same way.
+
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::models::player::{PlayerAttributes, Position};
+use crate::player::CorePlayer;
+use crate::training::InjurySeverity;
+
+/// Full-growth years before the peak (developing players)
+const EARLY_GROWTH_YEARS: f32 = 6.0;
+const LATE_GROWTH_YEARS: f32 = 3.0;
+/// Yearly attribute points at full growth rate
+const EARLY_GROWTH: f32 = 3.0;
+const LATE_GROWTH: f32 = 2.0;
+const PRE_PEAK_GROWTH: f32 = 1.0;
+/// CA gap (share of PA) at which growth is no longer held back
+const FULL_HEADROOM: f32 = 0.25;
+/// Mental attributes keep improving this many years past the peak
+const MENTAL_EXPERIENCE_YEARS: f32 = 4.0;
+const MENTAL_EXPERIENCE: f32 = 1.0;
+
+/// Years after the peak each group starts to decline
+const PHYSICAL_DECLINE_OFFSET: f32 = 2.0;
+const TECHNICAL_DECLINE_OFFSET: f32 = 4.0;
+const MENTAL_DECLINE_OFFSET: f32 = 8.0;
+
+/// Years past the peak before retirement is considered
+const RETIREMENT_OFFSET: f32 = 5.0;
+/// Nobody plays on past this age
+pub const MAX_PLAYING_AGE: f32 = 40.0;
+/// Below this CA a veteran is more likely to call it a day
+const DECLINE_RETIREMENT_CA: u8 = 60;
+
+/// Career stage at the player's new age
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AgingPhase {
+    Developing,
+    Peak,
+    Declining,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RetirementReason {
+    Age,
+    DecliningAbility,
+    Injury,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AttributeGroup {
+    Technical,
+    Mental,
+    Physical,
+    Goalkeeping,
+}
+
+/// One player's season-end aging
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SeasonAging {
+    pub player_id: String,
+    pub name: String,
+    pub age_before: f32,
+    pub age_after: f32,
+    pub phase: AgingPhase,
+    pub ca_before: u8,
+    pub ca_after: u8,
+    /// Net attribute points gained (+) or lost (-) per group
+    pub technical_change: i16,
+    pub mental_change: i16,
+    pub physical_change: i16,
+    pub goalkeeping_change: i16,
+    pub retires: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retirement_reason: Option<RetirementReason>,
+}
+
+/// Age at which technical and physical attributes stop improving
+pub fn peak_age(position: Position) -> f32 {
+    if position.is_goalkeeper() {
+        31.0
+    } else if position.is_defender() {
+        28.0
+    } else if position.is_midfielder() {
+        27.0
+    } else {
+        26.0
+    }
+}
+
+/// Career stage at `age`
+pub fn aging_phase(age: f32, position: Position) -> AgingPhase {
+    let peak = peak_age(position);
+    if age < peak {
+        AgingPhase::Developing
+    } else if age <= peak + PHYSICAL_DECLINE_OFFSET {
+        AgingPhase::Peak
+    } else {
+        AgingPhase::Declining
+    }
+}
+
+/// Per-player seed for one season's rolls
+pub fn aging_seed(seed: u64, player_id: &str) -> u64 {
+    let mut hasher = Sha256::new();
+    hasher.update(b"of_core/aging/v1");
+    hasher.update(seed.to_le_bytes());
+    hasher.update(player_id.as_bytes());
+    let digest = hasher.finalize();
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&digest[..8]);
+    u64::from_le_bytes(bytes)
+}
+
+/// Expected yearly points for one attribute of `group` (before the per-attribute roll)
+///
+/// `headroom` is 0.0 (at PA) .. 1.0 (plenty of room to grow).
+fn group_change(player: &CorePlayer, age: f32, group: AttributeGroup, headroom: f32) -> f32 {
+    let peak = peak_age(player.position);
+    let profile = &player.growth_profile;
+    let response = &profile.training_response;
+
+    if age < peak && group != AttributeGroup::Mental {
+        let years_to_peak = peak - age;
+        let base = if years_to_peak > EARLY_GROWTH_YEARS {
+            EARLY_GROWTH
+        } else if years_to_peak > LATE_GROWTH_YEARS {
+            LATE_GROWTH
+        } else {
+            PRE_PEAK_GROWTH
+        };
+        let mult = match group {
+            AttributeGroup::Physical => response.physical_multiplier,
+            _ => response.technical_multiplier,
+        };
+        return base * profile.growth_rate * mult * headroom;
+    }
+
+    let decline_start = peak
+        + match group {
+            AttributeGroup::Physical => PHYSICAL_DECLINE_OFFSET,
+            AttributeGroup::Technical | AttributeGroup::Goalkeeping => TECHNICAL_DECLINE_OFFSET,
+            AttributeGroup::Mental => MENTAL_DECLINE_OFFSET,
+        };
+    if age <= decline_start {
+        return match group {
+            AttributeGroup::Mental if age <= peak + MENTAL_EXPERIENCE_YEARS => {
+                MENTAL_EXPERIENCE * response.mental_multiplier
+            }
+            _ => 0.0,
+        };
+    }
+
+    let years = age - decline_start;
+    match group {
+        AttributeGroup::Physical => {
+            let fitness = player.detailed_stats.natural_fitness.min(100) as f32 / 100.0;
+            let durability = (1.2 - 0.4 * fitness) * (1.0 + 0.5 * profile.injury_prone);
+            -(1.0 + 0.6 * years) * durability
+        }
+        AttributeGroup::Technical | AttributeGroup::Goalkeeping => -(0.5 + 0.5 * years),
+        AttributeGroup::Mental => -(0.5 * years),
+    }
+}
+
+fn group_attributes(attributes: &mut PlayerAttributes, group: AttributeGroup) -> Vec<&mut u8> {
+    match group {
+        AttributeGroup::Technical => vec![
+            &mut attributes.corners,
+            &mut attributes.crossing,
+            &mut attributes.dribbling,
+            &mut attributes.finishing,
+            &mut attributes.first_touch,
+            &mut attributes.free_kicks,
+            &mut attributes.heading,
+            &mut attributes.long_shots,
+            &mut attributes.long_throws,
+            &mut attributes.marking,
+            &mut attributes.passing,
+            &mut attributes.penalty_taking,
+            &mut attributes.tackling,
+            &mut attributes.technique,
+        ],
+        AttributeGroup::Mental => vec![
+            &mut attributes.aggression,
+            &mut attributes.anticipation,
+            &mut attributes.bravery,
+            &mut attributes.composure,
+            &mut attributes.concentration,
+            &mut attributes.decisions,
+            &mut attributes.determination,
+            &mut attributes.flair,
+            &mut attributes.leadership,
+            &mut attributes.off_the_ball,
+            &mut attributes.positioning,
+            &mut attributes.teamwork,
+            &mut attributes.vision,
+            &mut attributes.work_rate,
+        ],
+        AttributeGroup::Physical => vec![
+            &mut attributes.acceleration,
+            &mut attributes.agility,
+            &mut attributes.balance,
+            &mut attributes.jumping,
+            &mut attributes.natural_fitness,
+            &mut attributes.pace,
+            &mut attributes.stamina,
+            &mut attributes.strength,
+        ],
+        AttributeGroup::Goalkeeping => vec![
+            &mut attributes.gk_aerial_reach,
+            &mut attributes.gk_command_of_area,
+            &mut attributes.gk_communication,
+            &mut attributes.gk_eccentricity,
+            &mut attributes.gk_handling,
+            &mut attributes.gk_kicking,
+            &mut attributes.gk_one_on_ones,
+            &mut attributes.gk_reflexes,
+            &mut attributes.gk_rushing_out,
+            &mut attributes.gk_punching,
+            &mut attributes.gk_throwing,
+        ],
+    }
+}
+
+/// Chance (0.0-1.0) that the player retires at their current age
+pub fn retirement_chance(player: &CorePlayer) -> f32 {
+    let age = player.age_months;
+    if age >= MAX_PLAYING_AGE {
+        return 1.0;
+    }
+    let start = peak_age(player.position) + RETIREMENT_OFFSET;
+    if age < start {
+        return 0.0;
+    }
+    let mut chance = 0.2 + 0.2 * (age - start);
+    if player.ca < DECLINE_RETIREMENT_CA {
+        chance += 0.1;
+    }
+    if has_serious_injury(player) {
+        chance += 0.15;
+    }
+    chance.clamp(0.0, 1.0)
+}
+
+fn has_serious_injury(player: &CorePlayer) -> bool {
+    player.current_injury.as_ref().is_some_and(|injury| {
+        matches!(injury.severity, InjurySeverity::Serious | InjurySeverity::Severe)
+    })
+}
+
+/// Age `player` by one season and roll for retirement (deterministic per seed)
+pub fn advance_season(player: &mut CorePlayer, seed: u64) -> SeasonAging {
+    let mut rng = ChaCha8Rng::seed_from_u64(aging_seed(seed, &player.id));
+    let age_before = player.age_months;
+    let ca_before = player.ca;
+    let age = age_before + 1.0;
+
+    let headroom = if player.pa == 0 || player.ca >= player.pa {
+        0.0
+    } else {
+        ((player.pa - player.ca) as f32 / player.pa as f32 / FULL_HEADROOM).min(1.0)
+    };
+
+    let mut changes = [0i16; 4];
+    let groups = [
+        AttributeGroup::Technical,
+        AttributeGroup::Mental,
+        AttributeGroup::Physical,
+        AttributeGroup::Goalkeeping,
+    ];
+    for (slot, group) in groups.into_iter().enumerate() {
+        if group == AttributeGroup::Goalkeeping && !player.position.is_goalkeeper() {
+            continue;
+        }
+        let expected = group_change(player, age, group, headroom);
+        if expected == 0.0 {
+            continue;
+        }
+        for value in group_attributes(&mut player.detailed_stats, group) {
+            let delta = (expected * rng.gen_range(0.5..1.5)).round() as i16;
+            let next = (*value as i16 + delta).clamp(1, 100);
+            changes[slot] += next - *value as i16;
+            *value = next as u8;
+        }
+    }
+
+    player.age_months = age;
+    player.recalculate_all();
+
+    let retires = rng.gen::<f32>() < retirement_chance(player);
+    let retirement_reason = retires.then(|| {
+        if has_serious_injury(player) {
+            RetirementReason::Injury
+        } else if player.ca < DECLINE_RETIREMENT_CA {
+            RetirementReason::DecliningAbility
+        } else {
+            RetirementReason::Age
+        }
+    });
+
+    SeasonAging {
+        player_id: player.id.clone(),
+        name: player.name.clone(),
+        age_before,
+        age_after: age,
+        phase: aging_phase(age, player.position),
+        ca_before,
+        ca_after: player.ca,
+        technical_change: changes[0],
+        mental_change: changes[1],
+        physical_change: changes[2],
+        goalkeeping_change: changes[3],
+        retires,
+        retirement_reason,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn player_aged(age: f32, position: Position) -> CorePlayer {
+        let mut player = CorePlayer::create_average_player("Veteran".to_string(), position, 11);
+        player.id = "veteran".to_string();
+        player.age_months = age;
+        player
+    }
+
+    #[test]
+    fn test_growth_then_decline() {
+        let mut young = player_aged(17.0, Position::CM);
+        young.pa = 200;
+        let report = advance_season(&mut young, 1);
+        assert_eq!(report.age_after, 18.0);
+        assert_eq!(report.phase, AgingPhase::Developing);
+        assert!(report.technical_change > 0 && report.physical_change > 0);
+        assert!(!report.retires);
+
+        let mut old = player_aged(33.0, Position::ST);
+        let report = advance_season(&mut old, 1);
+        assert_eq!(report.phase, AgingPhase::Declining);
+        // Physical goes first (per attribute: 8 physical, 14 technical)
+        assert!((report.physical_change as f32 / 8.0) < (report.technical_change as f32 / 14.0));
+        assert!(report.physical_change < 0);
+        assert!(report.ca_after <= report.ca_before);
+        assert_eq!(report.goalkeeping_change, 0, "outfield players skip GK attributes");
+
+        // Same seed, same player → same season
+        let mut replay = player_aged(33.0, Position::ST);
+        assert_eq!(advance_season(&mut replay, 1), report);
+    }
+
+    #[test]
+    fn test_retirement_decisions() {
+        assert_eq!(retirement_chance(&player_aged(30.0, Position::ST)), 0.0);
+        assert!(
+            retirement_chance(&player_aged(33.0, Position::GK))
+                < retirement_chance(&player_aged(33.0, Position::ST))
+        );
+
+        let mut veteran = player_aged(39.0, Position::CB);
+        let report = advance_season(&mut veteran, 5);
+        assert!(report.retires);
+        assert!(report.retirement_reason.is_some());
+    }
+}
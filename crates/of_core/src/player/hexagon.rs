@@ -3,9 +3,17 @@
 //! Handles the conversion from 42 detailed attributes to 6-sided hexagon stats
 //! for visual representation and simplified player comparison.
 
+use serde::{Deserialize, Serialize};
+
 use crate::models::player::{PlayerAttributes, Position};
 use crate::player::types::HexagonStats;
 
+/// Radar axis order (matches `HexagonStats::as_array`)
+pub const HEXAGON_AXES: [&str; 6] =
+    ["pace", "power", "technical", "shooting", "passing", "defending"];
+/// Upper bound of a hexagon stat
+pub const HEXAGON_MAX: u8 = 20;
+
 /// Calculator for hexagon statistics
 pub struct HexagonCalculator;
 
@@ -112,6 +120,117 @@ impl HexagonCalculator {
             defending_diff: stats1.defending as i16 - stats2.defending as i16,
         }
     }
+
+    /// Build per-axis baselines from a reference population (registry, league roster, ...)
+    pub fn build_baseline<'a, I>(population: I) -> HexagonBaseline
+    where
+        I: IntoIterator<Item = &'a HexagonStats>,
+    {
+        let mut samples: [Vec<u8>; 6] = Default::default();
+        for stats in population {
+            for (axis, value) in stats.as_array().into_iter().enumerate() {
+                samples[axis].push(value.min(HEXAGON_MAX));
+            }
+        }
+        for axis in samples.iter_mut() {
+            axis.sort_unstable();
+        }
+        HexagonBaseline { samples }
+    }
+
+    /// Ready-to-plot radar for a player card, ranked against `baseline`
+    pub fn export_radar(stats: &HexagonStats, baseline: &HexagonBaseline) -> HexagonRadar {
+        let axes = stats
+            .as_array()
+            .into_iter()
+            .enumerate()
+            .map(|(axis, value)| {
+                let value = value.min(HEXAGON_MAX);
+                let p25 = baseline.quantile(axis, 0.25);
+                let median = baseline.quantile(axis, 0.5);
+                let p75 = baseline.quantile(axis, 0.75);
+                HexagonRadarAxis {
+                    key: HEXAGON_AXES[axis].to_string(),
+                    value,
+                    normalized: normalize(value as f32),
+                    percentile: baseline.percentile(axis, value),
+                    baseline_p25: normalize(p25),
+                    baseline_median: normalize(median),
+                    baseline_p75: normalize(p75),
+                }
+            })
+            .collect();
+        HexagonRadar {
+            axes,
+            max_value: HEXAGON_MAX,
+            population_size: baseline.population_size(),
+            total: stats.total(),
+        }
+    }
+}
+
+fn normalize(value: f32) -> f32 {
+    (value / HEXAGON_MAX as f32).clamp(0.0, 1.0)
+}
+
+/// Sorted per-axis samples of a reference population
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HexagonBaseline {
+    samples: [Vec<u8>; 6],
+}
+
+impl HexagonBaseline {
+    pub fn population_size(&self) -> usize {
+        self.samples[0].len()
+    }
+
+    /// Percentile rank (0-100) of `value` on `axis`; ties count half.
+    /// Empty population → 50 (no information).
+    pub fn percentile(&self, axis: usize, value: u8) -> f32 {
+        let sorted = &self.samples[axis];
+        if sorted.is_empty() {
+            return 50.0;
+        }
+        let below = sorted.partition_point(|&v| v < value);
+        let equal = sorted[below..].partition_point(|&v| v == value);
+        (below as f32 + equal as f32 * 0.5) / sorted.len() as f32 * 100.0
+    }
+
+    /// Linear-interpolated quantile `q` (0-1) on `axis`.
+    /// Empty population → scale midpoint.
+    pub fn quantile(&self, axis: usize, q: f32) -> f32 {
+        let sorted = &self.samples[axis];
+        if sorted.is_empty() {
+            return HEXAGON_MAX as f32 / 2.0;
+        }
+        let pos = q.clamp(0.0, 1.0) * (sorted.len() - 1) as f32;
+        let lo = pos.floor() as usize;
+        let hi = pos.ceil() as usize;
+        let t = pos - lo as f32;
+        sorted[lo] as f32 * (1.0 - t) + sorted[hi] as f32 * t
+    }
+}
+
+/// One radar axis (normalized values are 0-1 of `HEXAGON_MAX`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HexagonRadarAxis {
+    pub key: String,
+    pub value: u8,
+    pub normalized: f32,
+    /// Percentile rank within the reference population (0-100)
+    pub percentile: f32,
+    pub baseline_p25: f32,
+    pub baseline_median: f32,
+    pub baseline_p75: f32,
+}
+
+/// Radar chart data for a player card (axes in `HEXAGON_AXES` order)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HexagonRadar {
+    pub axes: Vec<HexagonRadarAxis>,
+    pub max_value: u8,
+    pub population_size: usize,
+    pub total: u16,
 }
 
 /// Difference between two hexagon stats (for comparison UI)
@@ -364,4 +483,31 @@ mod tests {
         let array = hexagon.as_array();
         assert_eq!(array, [15, 12, 18, 10, 14, 8]);
     }
+
+    #[test]
+    fn test_radar_export_against_population() {
+        let population: Vec<HexagonStats> = (0..=20).map(HexagonStats::new).collect();
+        let baseline = HexagonCalculator::build_baseline(&population);
+        assert_eq!(baseline.population_size(), 21);
+
+        let star = HexagonStats { pace: 20, defending: 0, ..HexagonStats::new(10) };
+        let radar = HexagonCalculator::export_radar(&star, &baseline);
+
+        assert_eq!(radar.axes.len(), 6);
+        assert_eq!(radar.axes[0].key, "pace");
+        assert!(radar.axes[0].percentile > 95.0);
+        assert!(radar.axes[5].percentile < 5.0);
+        assert!((radar.axes[1].percentile - 50.0).abs() < 1e-4);
+        assert!((radar.axes[1].baseline_median - 0.5).abs() < 1e-4);
+        assert!((radar.axes[1].baseline_p25 - 0.25).abs() < 1e-4);
+        assert_eq!(radar.axes[0].normalized, 1.0);
+    }
+
+    #[test]
+    fn test_radar_with_empty_population_is_neutral() {
+        let baseline = HexagonCalculator::build_baseline(&[]);
+        let radar = HexagonCalculator::export_radar(&HexagonStats::new(15), &baseline);
+        assert_eq!(radar.population_size, 0);
+        assert!(radar.axes.iter().all(|a| a.percentile == 50.0 && a.baseline_median == 0.5));
+    }
 }
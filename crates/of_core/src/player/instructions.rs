@@ -6,11 +6,12 @@
 
 use crate::models::player::PlayerAttributes;
 use crate::models::Position;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
 /// 개인 선수 전술 지시 (8개 주요 항목)
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
 pub struct PlayerInstructions {
     /// 공격 성향 (얼마나 전진하는가)
     pub mentality: Mentality,
@@ -35,6 +36,14 @@ pub struct PlayerInstructions {
 
     /// 압박 강도
     pub pressing: PressingIntensity,
+
+    /// 엔진 역할 (포지셔닝/의사결정/능력치 가중치, `tactics::roles`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<PlayerRole>,
+
+    /// 대인 마크 대상 (상대 선수 track_id 0-21, 수비 시 Marker 역할 고정)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub man_mark: Option<u8>,
 }
 
 impl Default for PlayerInstructions {
@@ -48,12 +57,35 @@ impl Default for PlayerInstructions {
             shooting: ShootingTendency::Normal,
             defensive_work: DefensiveWork::Normal,
             pressing: PressingIntensity::Medium,
+            role: None,
+            man_mark: None,
         }
     }
 }
 
+impl PlayerInstructions {
+    /// 슬롯(track_id 0-21)의 선수에게 적용 가능한지 검사 (man_mark 대상은 상대 선수)
+    pub fn validate_for_track(&self, track_id: usize) -> Result<(), String> {
+        if let Some(target) = self.man_mark {
+            let target = target as usize;
+            if target >= 22 {
+                return Err(format!("man_mark target out of range: {}", target));
+            }
+            if (target < 11) == (track_id < 11) {
+                return Err(format!("man_mark target {} is a teammate of track {}", target, track_id));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// `PlayerInstructions` JSON schema (에디터 폼 / 요청 검증용)
+pub fn player_instructions_schema() -> serde_json::Value {
+    serde_json::to_value(schemars::schema_for!(PlayerInstructions)).unwrap_or_default()
+}
+
 /// 공격 성향 (전진 빈도)
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, JsonSchema)]
 pub enum Mentality {
     #[serde(rename = "conservative")]
     Conservative, // 20% 전진, 수비 중시
@@ -64,7 +96,7 @@ pub enum Mentality {
 }
 
 /// 좌우 포지셔닝
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, JsonSchema)]
 pub enum Width {
     #[serde(rename = "stay_wide")]
     StayWide, // 측면 유지
@@ -77,7 +109,7 @@ pub enum Width {
 }
 
 /// 전후 포지셔닝
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, JsonSchema)]
 pub enum Depth {
     #[serde(rename = "stay_back")]
     StayBack, // 후방 유지
@@ -88,7 +120,7 @@ pub enum Depth {
 }
 
 /// 패싱 스타일
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, JsonSchema)]
 pub enum PassingStyle {
     #[serde(rename = "short")]
     Short, // 짧은 패스 위주
@@ -99,7 +131,7 @@ pub enum PassingStyle {
 }
 
 /// 드리블 빈도
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, JsonSchema)]
 pub enum DribblingFrequency {
     #[serde(rename = "rarely")]
     Rarely, // 거의 안함
@@ -110,7 +142,7 @@ pub enum DribblingFrequency {
 }
 
 /// 슈팅 성향
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, JsonSchema)]
 pub enum ShootingTendency {
     #[serde(rename = "conservative")]
     Conservative, // 신중하게
@@ -121,7 +153,7 @@ pub enum ShootingTendency {
 }
 
 /// 수비 기여도
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, JsonSchema)]
 pub enum DefensiveWork {
     #[serde(rename = "minimal")]
     Minimal, // 최소한
@@ -132,7 +164,7 @@ pub enum DefensiveWork {
 }
 
 /// 압박 강도
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, JsonSchema)]
 pub enum PressingIntensity {
     #[serde(rename = "low")]
     Low, // 낮음
@@ -143,7 +175,7 @@ pub enum PressingIntensity {
 }
 
 /// Role = Instructions의 Preset
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, JsonSchema)]
 pub enum PlayerRole {
     // ===== Forward Roles =====
     #[serde(rename = "target_man")]
@@ -152,6 +184,8 @@ pub enum PlayerRole {
     Poacher, // 포처: 골만 노리는 순수 스트라이커
     #[serde(rename = "complete_forward")]
     CompleteForward, // 컴플리트 포워드: 균형잡힌 공격수
+    #[serde(rename = "inverted_winger")]
+    InvertedWinger, // 인버티드 윙어: 안쪽으로 파고들어 슈팅
 
     // ===== Midfielder Roles =====
     #[serde(rename = "playmaker")]
@@ -184,6 +218,8 @@ impl PlayerRole {
                 shooting: ShootingTendency::ShootOnSight,
                 defensive_work: DefensiveWork::Minimal,
                 pressing: PressingIntensity::Low,
+                role: Some(*self),
+                man_mark: None,
             },
 
             PlayerRole::Poacher => PlayerInstructions {
@@ -195,6 +231,8 @@ impl PlayerRole {
                 shooting: ShootingTendency::ShootOnSight,
                 defensive_work: DefensiveWork::Minimal,
                 pressing: PressingIntensity::Low,
+                role: Some(*self),
+                man_mark: None,
             },
 
             PlayerRole::CompleteForward => PlayerInstructions {
@@ -206,6 +244,21 @@ impl PlayerRole {
                 shooting: ShootingTendency::Normal,
                 defensive_work: DefensiveWork::Normal,
                 pressing: PressingIntensity::Medium,
+                role: Some(*self),
+                man_mark: None,
+            },
+
+            PlayerRole::InvertedWinger => PlayerInstructions {
+                mentality: Mentality::Aggressive,
+                width: Width::CutInside,
+                depth: Depth::GetForward,
+                passing: PassingStyle::Short,
+                dribbling: DribblingFrequency::Often,
+                shooting: ShootingTendency::ShootOnSight,
+                defensive_work: DefensiveWork::Normal,
+                pressing: PressingIntensity::Medium,
+                role: Some(*self),
+                man_mark: None,
             },
 
             // ===== Midfielder Roles =====
@@ -218,6 +271,8 @@ impl PlayerRole {
                 shooting: ShootingTendency::Conservative,
                 defensive_work: DefensiveWork::Normal,
                 pressing: PressingIntensity::Medium,
+                role: Some(*self),
+                man_mark: None,
             },
 
             PlayerRole::BoxToBox => PlayerInstructions {
@@ -229,6 +284,8 @@ impl PlayerRole {
                 shooting: ShootingTendency::Normal,
                 defensive_work: DefensiveWork::High,
                 pressing: PressingIntensity::High,
+                role: Some(*self),
+                man_mark: None,
             },
 
             PlayerRole::BallWinning => PlayerInstructions {
@@ -240,6 +297,8 @@ impl PlayerRole {
                 shooting: ShootingTendency::Conservative,
                 defensive_work: DefensiveWork::High,
                 pressing: PressingIntensity::High,
+                role: Some(*self),
+                man_mark: None,
             },
 
             // ===== Defender Roles =====
@@ -252,6 +311,8 @@ impl PlayerRole {
                 shooting: ShootingTendency::Conservative,
                 defensive_work: DefensiveWork::High,
                 pressing: PressingIntensity::Medium,
+                role: Some(*self),
+                man_mark: None,
             },
 
             PlayerRole::Stopper => PlayerInstructions {
@@ -263,6 +324,8 @@ impl PlayerRole {
                 shooting: ShootingTendency::Conservative,
                 defensive_work: DefensiveWork::High,
                 pressing: PressingIntensity::High,
+                role: Some(*self),
+                man_mark: None,
             },
 
             PlayerRole::CoveringDefender => PlayerInstructions {
@@ -274,6 +337,8 @@ impl PlayerRole {
                 shooting: ShootingTendency::Conservative,
                 defensive_work: DefensiveWork::High,
                 pressing: PressingIntensity::Low, // 커버링 중심
+                role: Some(*self),
+                man_mark: None,
             },
         }
     }
@@ -284,6 +349,7 @@ impl PlayerRole {
             PlayerRole::TargetMan => "타겟맨",
             PlayerRole::Poacher => "포처",
             PlayerRole::CompleteForward => "컴플리트 포워드",
+            PlayerRole::InvertedWinger => "인버티드 윙어",
             PlayerRole::Playmaker => "플레이메이커",
             PlayerRole::BoxToBox => "박스투박스",
             PlayerRole::BallWinning => "볼위닝 미드필더",
@@ -299,6 +365,7 @@ impl PlayerRole {
             PlayerRole::TargetMan => "강력한 피지컬과 헤딩으로 공을 받아 연결하는 타겟맨",
             PlayerRole::Poacher => "골 결정력에 집중하는 순수 스트라이커",
             PlayerRole::CompleteForward => "공격의 모든 면에서 균형잡힌 포워드",
+            PlayerRole::InvertedWinger => "측면에서 안쪽으로 파고들어 슈팅을 노리는 윙어",
             PlayerRole::Playmaker => "패싱과 비전으로 공격을 조율하는 플레이메이커",
             PlayerRole::BoxToBox => "공수 양쪽에서 활약하는 만능 미드필더",
             PlayerRole::BallWinning => "수비에 집중하며 볼을 탈취하는 미드필더",
@@ -315,6 +382,9 @@ impl PlayerRole {
             PlayerRole::TargetMan | PlayerRole::Poacher | PlayerRole::CompleteForward => {
                 vec![Position::ST]
             }
+            PlayerRole::InvertedWinger => {
+                vec![Position::LW, Position::RW, Position::LM, Position::RM]
+            }
 
             // Midfielder roles - 미드필더용
             PlayerRole::Playmaker => {
@@ -347,6 +417,7 @@ impl PlayerRole {
             PlayerRole::TargetMan,
             PlayerRole::Poacher,
             PlayerRole::CompleteForward,
+            PlayerRole::InvertedWinger,
             PlayerRole::Playmaker,
             PlayerRole::BoxToBox,
             PlayerRole::BallWinning,
@@ -614,6 +685,29 @@ mod tests {
         assert_eq!(modified.positioning, 9); // -1
     }
 
+    #[test]
+    fn test_man_mark_validation_and_schema() {
+        let marking = PlayerInstructions { man_mark: Some(14), ..Default::default() };
+        assert!(marking.validate_for_track(3).is_ok());
+        assert!(marking.validate_for_track(12).unwrap_err().contains("teammate"));
+        let out_of_range = PlayerInstructions { man_mark: Some(22), ..Default::default() };
+        assert!(out_of_range.validate_for_track(3).is_err());
+
+        let parsed: PlayerInstructions = serde_json::from_str(
+            r#"{"mentality":"balanced","width":"stay_wide","depth":"stay_back","passing":"mixed",
+                "dribbling":"normal","shooting":"shoot_on_sight","defensive_work":"normal",
+                "pressing":"medium","man_mark":14}"#,
+        )
+        .unwrap();
+        assert_eq!(parsed.man_mark, Some(14));
+        assert_eq!(parsed.role, None);
+
+        let schema = player_instructions_schema();
+        let props = &schema["properties"];
+        assert!(props.get("man_mark").is_some());
+        assert!(props.get("shooting").is_some());
+    }
+
     #[test]
     fn test_role_names() {
         assert_eq!(PlayerRole::TargetMan.display_name_ko(), "타겟맨");
@@ -0,0 +1,241 @@
+//! Medical: injury lifecycle across matches
+//!
+//! `CorePlayer.current_injury` holds the active injury (day-based, from
+//! training or matches). The medical record adds what happens between
+//! fixtures:
+//! - **recovery training**: rest, physio or accelerated rehab (faster return,
+//!   higher re-injury risk)
+//! - **weekly progression**: `advance_week` moves recovery on by a week
+//! - **re-injury risk**: raised on return to fitness, decays week by week
+//!
+//! Roster entries carry `injured_until` (career day) derived from the active
+//! injury; match requests reject injured selections.
+
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::player::CorePlayer;
+use crate::training::{Injury, InjurySeverity, InjuryType};
+
+pub const DAYS_PER_WEEK: u8 = 7;
+
+/// Weekly decay of the post-return re-injury risk
+const REINJURY_DECAY: f32 = 0.6;
+/// Risk below this is cleared (player fully back)
+const REINJURY_FLOOR: f32 = 0.005;
+/// Recurrences come back as this severity, at least
+const RECURRENCE_SEVERITY: InjurySeverity = InjurySeverity::Moderate;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecoveryTraining {
+    /// Normal pace, baseline re-injury risk
+    #[default]
+    Rest,
+    /// Slightly faster, lower re-injury risk
+    Physio,
+    /// Fastest return, clearly higher re-injury risk
+    AcceleratedRehab,
+}
+
+impl RecoveryTraining {
+    /// Recovery days credited per week
+    pub fn days_per_week(self) -> u8 {
+        match self {
+            Self::Rest => 7,
+            Self::Physio => 9,
+            Self::AcceleratedRehab => 11,
+        }
+    }
+
+    /// Multiplier on the re-injury risk set at return to fitness
+    pub fn reinjury_mult(self) -> f32 {
+        match self {
+            Self::Rest => 1.0,
+            Self::Physio => 0.75,
+            Self::AcceleratedRehab => 1.6,
+        }
+    }
+}
+
+/// Per-player medical state kept between matches
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MedicalRecord {
+    pub recovery_training: RecoveryTraining,
+    /// Chance (0.0-1.0) of a recurrence per match appearance
+    pub reinjury_risk: f32,
+    /// Weeks since the last return to fitness (None = no recent injury)
+    pub weeks_since_return: Option<u8>,
+}
+
+/// One player's week in the medical room
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WeeklyRecovery {
+    pub player_id: String,
+    pub injury_type: Option<InjuryType>,
+    pub weeks_out_before: u8,
+    pub weeks_out_after: u8,
+    /// Returned to fitness this week
+    pub recovered: bool,
+    pub reinjury_risk: f32,
+}
+
+/// Whole weeks until fit (rounded up)
+pub fn weeks_out(injury: &Injury) -> u8 {
+    injury.recovery_days_remaining.div_ceil(DAYS_PER_WEEK)
+}
+
+/// Career day the player is available again (None = fit)
+pub fn injured_until(injury: Option<&Injury>, today: u32) -> Option<u32> {
+    injury
+        .filter(|injury| !injury.is_recovered())
+        .map(|injury| today + injury.recovery_days_remaining as u32)
+}
+
+/// Re-injury risk right after returning from an injury of this severity
+pub fn base_reinjury_risk(severity: &InjurySeverity) -> f32 {
+    match severity {
+        InjurySeverity::Minor => 0.03,
+        InjurySeverity::Moderate => 0.06,
+        InjurySeverity::Serious => 0.12,
+        InjurySeverity::Severe => 0.2,
+    }
+}
+
+/// Advance recovery by one week under the player's recovery training
+pub fn advance_week(player: &mut CorePlayer) -> WeeklyRecovery {
+    let injury_type = player.current_injury.as_ref().map(|i| i.injury_type.clone());
+    let weeks_out_before = player.current_injury.as_ref().map_or(0, weeks_out);
+    let program = player.medical.recovery_training;
+
+    // Returned players regain confidence in the joint/muscle week by week.
+    if let Some(weeks) = player.medical.weeks_since_return.as_mut() {
+        *weeks = weeks.saturating_add(1);
+        player.medical.reinjury_risk *= REINJURY_DECAY;
+        if player.medical.reinjury_risk < REINJURY_FLOOR {
+            player.medical.reinjury_risk = 0.0;
+            player.medical.weeks_since_return = None;
+        }
+    }
+
+    let mut recovered = false;
+    if player.current_injury.is_some() {
+        for _ in 0..program.days_per_week() {
+            if let Some(healed) = player.advance_recovery() {
+                player.medical.reinjury_risk =
+                    (base_reinjury_risk(&healed.severity) * program.reinjury_mult()).min(1.0);
+                player.medical.weeks_since_return = Some(0);
+                // Back to normal training once fit.
+                player.medical.recovery_training = RecoveryTraining::Rest;
+                recovered = true;
+                break;
+            }
+        }
+    }
+    player.touch();
+
+    WeeklyRecovery {
+        player_id: player.id.clone(),
+        injury_type,
+        weeks_out_before,
+        weeks_out_after: player.current_injury.as_ref().map_or(0, weeks_out),
+        recovered,
+        reinjury_risk: player.medical.reinjury_risk,
+    }
+}
+
+/// Roll for a recurrence after a match appearance (deterministic per seed)
+///
+/// A recurrence brings back the last injury's type at Moderate severity or
+/// worse and clears the post-return window.
+pub fn roll_reinjury(player: &mut CorePlayer, occurred_date: &str, seed: u64) -> Option<Injury> {
+    if player.current_injury.is_some() || player.medical.reinjury_risk <= 0.0 {
+        return None;
+    }
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    if rng.gen::<f32>() >= player.medical.reinjury_risk {
+        return None;
+    }
+
+    let last = player.injury_history.last()?;
+    let severity = match last.severity {
+        InjurySeverity::Minor | InjurySeverity::Moderate => RECURRENCE_SEVERITY,
+        ref worse => worse.clone(),
+    };
+    let (min_days, max_days) = severity.recovery_range();
+    let days = rng.gen_range(min_days..=max_days);
+    let injury = Injury {
+        injury_type: last.injury_type.clone(),
+        severity,
+        affected_attributes: last.affected_attributes.clone(),
+        recovery_days_total: days,
+        recovery_days_remaining: days,
+        occurred_date: occurred_date.to_string(),
+    };
+
+    player.medical = MedicalRecord::default();
+    player.set_current_injury(injury.clone());
+    Some(injury)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::player::Position;
+
+    fn injured_player(severity: InjurySeverity, days: u8) -> CorePlayer {
+        let mut player = CorePlayer::create_average_player("Medic".to_string(), Position::CM, 3);
+        player.set_current_injury(Injury {
+            injury_type: InjuryType::Muscle,
+            severity,
+            affected_attributes: vec!["pace".to_string()],
+            recovery_days_total: days,
+            recovery_days_remaining: days,
+            occurred_date: "2026-03-01".to_string(),
+        });
+        player
+    }
+
+    #[test]
+    fn test_weekly_recovery_and_reinjury_window() {
+        let mut player = injured_player(InjurySeverity::Serious, 15);
+        assert_eq!(weeks_out(player.current_injury.as_ref().unwrap()), 3);
+        assert_eq!(injured_until(player.current_injury.as_ref(), 100), Some(115));
+
+        let week = advance_week(&mut player);
+        assert_eq!((week.weeks_out_before, week.weeks_out_after), (3, 2));
+        assert!(!week.recovered);
+
+        player.medical.recovery_training = RecoveryTraining::AcceleratedRehab;
+        let week = advance_week(&mut player);
+        assert!(week.recovered);
+        assert!(player.can_play_match());
+        assert_eq!(player.injury_history.len(), 1);
+        let risk = player.medical.reinjury_risk;
+        assert!((risk - 0.12 * 1.6).abs() < 1e-6);
+        assert_eq!(player.medical.recovery_training, RecoveryTraining::Rest);
+
+        let week = advance_week(&mut player);
+        assert!((week.reinjury_risk - risk * REINJURY_DECAY).abs() < 1e-6);
+        assert_eq!(player.medical.weeks_since_return, Some(1));
+    }
+
+    #[test]
+    fn test_roll_reinjury_is_seeded() {
+        let mut player = injured_player(InjurySeverity::Minor, 2);
+        advance_week(&mut player);
+        player.medical.reinjury_risk = 1.0;
+
+        let mut replay = player.clone();
+        let injury = roll_reinjury(&mut player, "2026-03-20", 9).expect("certain recurrence");
+        assert_eq!(injury.severity, InjurySeverity::Moderate);
+        assert_eq!(injury.injury_type, InjuryType::Muscle);
+        assert!(!player.can_play_match());
+        assert_eq!(roll_reinjury(&mut replay, "2026-03-20", 9), Some(injury));
+
+        // Already injured: no double roll.
+        assert_eq!(roll_reinjury(&mut player, "2026-03-21", 9), None);
+    }
+}
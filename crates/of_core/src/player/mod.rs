@@ -4,15 +4,19 @@
 //! - CorePlayer struct with CA/PA system
 //! - HexagonStats for 6-sided ability visualization
 //! - Growth profiles and progression system
+//! - Season-end aging, decline and retirement
 //! - Position-specific weighting and calculations
 //! - Validation and error handling
 
+pub mod aging;
 pub mod ca_model;
 pub mod ca_weights;
 pub mod calculator;
 pub mod growth_calculator;
 pub mod hexagon;
 pub mod instructions;
+pub mod medical;
+pub mod morale;
 pub mod optimization;
 pub mod personality;
 pub mod position_weights;
@@ -26,12 +30,21 @@ pub mod ca_calculator {
 }
 
 // Re-export main types
+pub use aging::{AgingPhase, RetirementReason, SeasonAging};
 pub use calculator::{CACalculationDetails, CACalculator};
 pub use growth_calculator::GrowthCalculator;
-pub use hexagon::HexagonCalculator;
+pub use hexagon::{
+    HexagonBaseline, HexagonCalculator, HexagonRadar, HexagonRadarAxis, HEXAGON_AXES, HEXAGON_MAX,
+};
 pub use instructions::{
-    apply_instructions_modifiers, DefensiveWork, Depth, DribblingFrequency, Mentality,
-    PassingStyle, PlayerInstructions, PlayerRole, PressingIntensity, ShootingTendency, Width,
+    apply_instructions_modifiers, player_instructions_schema, DefensiveWork, Depth,
+    DribblingFrequency, Mentality, PassingStyle, PlayerInstructions, PlayerRole, PressingIntensity,
+    ShootingTendency, Width,
+};
+pub use medical::{MedicalRecord, RecoveryTraining, WeeklyRecovery};
+pub use morale::{
+    apply_form_modifiers, update_after_match, MatchMoraleInput, MatchOutcome, MoraleChange,
+    NEUTRAL_FORM, NEUTRAL_MORALE,
 };
 pub use optimization::{
     BatchProcessingResult, BatchProcessor, BulkOperation, BulkOperationResult, CachedCalculations,
@@ -0,0 +1,238 @@
+//! Player morale and form
+//!
+//! Both values live on a 0-100 scale with 50 as neutral:
+//! - **morale**: how the player feels about the club (results, playing time,
+//!   personal performance, quest outcomes)
+//! - **form**: a moving average of recent match ratings
+//!
+//! They are updated once per match with `update_after_match` and feed the
+//! engine through `apply_form_modifiers` (small, deterministic attribute
+//! scaling in `MatchPlayer::from_player`). Neutral values leave attributes
+//! untouched.
+
+use serde::{Deserialize, Serialize};
+
+use crate::analysis::ratings::PlayerMatchRating;
+use crate::models::player::PlayerAttributes;
+
+pub const NEUTRAL_MORALE: u8 = 50;
+pub const NEUTRAL_FORM: u8 = 50;
+
+/// Max technical attribute shift at form 0 / 100
+pub const FORM_ATTRIBUTE_SHIFT: f32 = 0.05;
+/// Max mental attribute shift at morale 0 / 100
+pub const MORALE_ATTRIBUTE_SHIFT: f32 = 0.04;
+
+const WIN: i16 = 5;
+const LOSS: i16 = -5;
+const UNUSED: i16 = -3;
+/// Minutes that count as a proper run-out
+const REGULAR_MINUTES: u8 = 60;
+const REGULAR: i16 = 2;
+const GOOD_RATING: f32 = 7.5;
+const POOR_RATING: f32 = 5.5;
+const RATING_SWING: i16 = 3;
+const QUEST_COMPLETED: i16 = 4;
+const QUEST_FAILED: i16 = -3;
+
+/// Weight of a full 90-minute rating in the form average
+const FORM_WEIGHT: f32 = 0.35;
+/// Drift back towards neutral after a match without a rating
+const FORM_DECAY: f32 = 0.1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MatchOutcome {
+    Win,
+    Draw,
+    Loss,
+}
+
+impl MatchOutcome {
+    pub fn from_score(goals_for: u8, goals_against: u8) -> Self {
+        match goals_for.cmp(&goals_against) {
+            std::cmp::Ordering::Greater => Self::Win,
+            std::cmp::Ordering::Equal => Self::Draw,
+            std::cmp::Ordering::Less => Self::Loss,
+        }
+    }
+}
+
+/// What one match meant for one player
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MatchMoraleInput {
+    pub outcome: MatchOutcome,
+    #[serde(default)]
+    pub minutes_played: u8,
+    /// Post-match rating (0.0-10.0); None = did not play
+    #[serde(default)]
+    pub rating: Option<f32>,
+    #[serde(default)]
+    pub quests_completed: u8,
+    #[serde(default)]
+    pub quests_failed: u8,
+}
+
+impl MatchMoraleInput {
+    /// Build from the engine's post-match rating for the player
+    pub fn from_match_rating(rating: &PlayerMatchRating, outcome: MatchOutcome) -> Self {
+        Self {
+            outcome,
+            minutes_played: rating.minutes,
+            rating: (rating.minutes > 0).then_some(rating.rating),
+            quests_completed: 0,
+            quests_failed: 0,
+        }
+    }
+}
+
+/// Morale/form before and after one update
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MoraleChange {
+    pub morale_before: u8,
+    pub morale_after: u8,
+    pub form_before: u8,
+    pub form_after: u8,
+}
+
+/// Morale swing from a single match
+pub fn morale_delta(input: &MatchMoraleInput) -> i16 {
+    let mut delta = match input.outcome {
+        MatchOutcome::Win => WIN,
+        MatchOutcome::Draw => 0,
+        MatchOutcome::Loss => LOSS,
+    };
+    if input.minutes_played == 0 {
+        delta += UNUSED;
+    } else if input.minutes_played >= REGULAR_MINUTES {
+        delta += REGULAR;
+    }
+    match input.rating {
+        Some(r) if r >= GOOD_RATING => delta += RATING_SWING,
+        Some(r) if r < POOR_RATING => delta -= RATING_SWING,
+        _ => {}
+    }
+    delta += input.quests_completed as i16 * QUEST_COMPLETED;
+    delta += input.quests_failed as i16 * QUEST_FAILED;
+    delta
+}
+
+/// Form after a match: ratings pull it towards their 0-100 equivalent
+/// (6.0 = 50, 9.0+ = 100), weighted by minutes played
+pub fn next_form(form: u8, input: &MatchMoraleInput) -> u8 {
+    let form = form.min(100) as f32;
+    let next = match input.rating.filter(|r| r.is_finite() && input.minutes_played > 0) {
+        Some(rating) => {
+            let target = ((rating - 3.0) / 6.0 * 100.0).clamp(0.0, 100.0);
+            let weight = FORM_WEIGHT * (input.minutes_played.min(90) as f32 / 90.0);
+            form + (target - form) * weight
+        }
+        None => form + (NEUTRAL_FORM as f32 - form) * FORM_DECAY,
+    };
+    next.round().clamp(0.0, 100.0) as u8
+}
+
+/// Apply one match to a player's morale and form
+pub fn update_after_match(
+    morale: &mut u8,
+    form: &mut u8,
+    input: &MatchMoraleInput,
+) -> MoraleChange {
+    let (morale_before, form_before) = (*morale, *form);
+    *morale = (*morale as i16 + morale_delta(input)).clamp(0, 100) as u8;
+    *form = next_form(*form, input);
+    MoraleChange { morale_before, morale_after: *morale, form_before, form_after: *form }
+}
+
+/// Technical attribute multiplier from form (0.95..=1.05)
+pub fn form_attribute_mult(form: u8) -> f32 {
+    1.0 + (form.min(100) as f32 - NEUTRAL_FORM as f32) / 50.0 * FORM_ATTRIBUTE_SHIFT
+}
+
+/// Mental attribute multiplier from morale (0.96..=1.04)
+pub fn morale_attribute_mult(morale: u8) -> f32 {
+    1.0 + (morale.min(100) as f32 - NEUTRAL_MORALE as f32) / 50.0 * MORALE_ATTRIBUTE_SHIFT
+}
+
+/// Scale match attributes by current form (technical execution) and morale
+/// (mental). Physical and goalkeeping attributes are untouched.
+pub fn apply_form_modifiers(attributes: &mut PlayerAttributes, form: u8, morale: u8) {
+    fn scale(value: &mut u8, mult: f32) {
+        *value = (*value as f32 * mult).round().clamp(1.0, 100.0) as u8;
+    }
+
+    let technical = form_attribute_mult(form);
+    if technical != 1.0 {
+        for value in [
+            &mut attributes.crossing,
+            &mut attributes.dribbling,
+            &mut attributes.finishing,
+            &mut attributes.first_touch,
+            &mut attributes.long_shots,
+            &mut attributes.passing,
+            &mut attributes.technique,
+        ] {
+            scale(value, technical);
+        }
+    }
+
+    let mental = morale_attribute_mult(morale);
+    if mental != 1.0 {
+        for value in [
+            &mut attributes.composure,
+            &mut attributes.concentration,
+            &mut attributes.decisions,
+            &mut attributes.determination,
+            &mut attributes.work_rate,
+        ] {
+            scale(value, mental);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input(outcome: MatchOutcome, minutes: u8, rating: Option<f32>) -> MatchMoraleInput {
+        MatchMoraleInput {
+            outcome,
+            minutes_played: minutes,
+            rating,
+            quests_completed: 0,
+            quests_failed: 0,
+        }
+    }
+
+    #[test]
+    fn test_update_after_match() {
+        let (mut morale, mut form) = (NEUTRAL_MORALE, NEUTRAL_FORM);
+        let change =
+            update_after_match(&mut morale, &mut form, &input(MatchOutcome::Win, 90, Some(8.0)));
+        assert_eq!(change.morale_after, 50 + 5 + 2 + 3);
+        assert!(change.form_after > NEUTRAL_FORM);
+
+        let mut quest = input(MatchOutcome::Loss, 0, None);
+        quest.quests_failed = 1;
+        assert_eq!(morale_delta(&quest), -5 - 3 - 3);
+
+        // Unrated matches drift form back towards neutral
+        assert_eq!(next_form(90, &input(MatchOutcome::Draw, 0, None)), 86);
+        assert_eq!(next_form(NEUTRAL_FORM, &input(MatchOutcome::Draw, 90, Some(6.0))), 50);
+    }
+
+    #[test]
+    fn test_apply_form_modifiers() {
+        let base = PlayerAttributes::from_uniform(60);
+
+        let mut neutral = base.clone();
+        apply_form_modifiers(&mut neutral, NEUTRAL_FORM, NEUTRAL_MORALE);
+        assert_eq!(neutral, base);
+
+        let mut hot = base.clone();
+        apply_form_modifiers(&mut hot, 100, 0);
+        assert_eq!(hot.finishing, 63);
+        assert_eq!(hot.composure, 58);
+        assert_eq!(hot.pace, 60, "physical attributes are untouched");
+    }
+}
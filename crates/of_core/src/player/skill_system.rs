@@ -153,6 +153,8 @@ mod tests {
             equipped_skills: Vec::new(),
             traits: TraitSlots::new(),
             personality: Default::default(),
+            morale: 50,
+            form: 50,
         };
 
         // 앵클 브레이커 조건 설정 (Flair 18, Technique 16, Dribbling 16)
@@ -180,6 +182,8 @@ mod tests {
             equipped_skills: Vec::new(),
             traits: TraitSlots::new(),
             personality: Default::default(),
+            morale: 50,
+            form: 50,
         };
 
         // 여러 스킬 습득 조건 설정
@@ -209,6 +213,8 @@ mod tests {
             equipped_skills: Vec::new(),
             traits: TraitSlots::new(),
             personality: Default::default(),
+            morale: 50,
+            form: 50,
         };
 
         let score = player.get_skill_score(SpecialSkill::AnkleBreaker);
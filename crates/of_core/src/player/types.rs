@@ -95,6 +95,18 @@ pub struct CorePlayer {
     /// Injury proneness factor 0.0-1.0 (added v1.2)
     #[serde(default = "default_injury_proneness")]
     pub injury_proneness: f32,
+
+    /// Morale 0-100, 50 = neutral (added v1.3)
+    #[serde(default = "default_morale")]
+    pub morale: u8,
+
+    /// Recent form 0-100, 50 = neutral (added v1.3)
+    #[serde(default = "default_form")]
+    pub form: u8,
+
+    /// Recovery programme and re-injury risk between matches (added v1.3)
+    #[serde(default)]
+    pub medical: crate::player::medical::MedicalRecord,
 }
 
 /// Default injury proneness value for migration (low risk)
@@ -102,6 +114,14 @@ fn default_injury_proneness() -> f32 {
     0.1
 }
 
+fn default_morale() -> u8 {
+    crate::player::morale::NEUTRAL_MORALE
+}
+
+fn default_form() -> u8 {
+    crate::player::morale::NEUTRAL_FORM
+}
+
 /// Result of an attribute modification operation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AttributeChange {
@@ -233,6 +253,9 @@ pub struct PlayerCareerStats {
     pub current_season: SeasonStats,
     /// 관계 점수 (0.0-1.0, 모든 관계의 평균)
     pub relationship_score: f32,
+    /// Clubs played for, oldest first (feeds squad chemistry)
+    #[serde(default)]
+    pub clubs: Vec<String>,
 }
 
 impl PlayerCareerStats {
@@ -306,6 +329,9 @@ impl CorePlayer {
             current_injury: None,
             injury_history: Vec::new(),
             injury_proneness: 0.1,
+            morale: crate::player::morale::NEUTRAL_MORALE,
+            form: crate::player::morale::NEUTRAL_FORM,
+            medical: Default::default(),
             created_at: now,
             updated_at: now,
         }
@@ -316,6 +342,27 @@ impl CorePlayer {
         self.updated_at = Utc::now();
     }
 
+    /// Update morale and form after a match
+    pub fn record_match_morale(
+        &mut self,
+        input: &crate::player::morale::MatchMoraleInput,
+    ) -> crate::player::morale::MoraleChange {
+        let change =
+            crate::player::morale::update_after_match(&mut self.morale, &mut self.form, input);
+        self.touch();
+        change
+    }
+
+    /// Advance injury recovery by one week under the current recovery training
+    pub fn advance_medical_week(&mut self) -> crate::player::medical::WeeklyRecovery {
+        crate::player::medical::advance_week(self)
+    }
+
+    /// Age one season (growth/decline) and roll for retirement
+    pub fn advance_season(&mut self, seed: u64) -> crate::player::aging::SeasonAging {
+        crate::player::aging::advance_season(self, seed)
+    }
+
     /// Recalculate hexagon stats from detailed attributes
     pub fn recalculate_hexagon_stats(&mut self) {
         self.hexagon_stats =
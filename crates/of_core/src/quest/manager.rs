@@ -1,5 +1,6 @@
 use super::types::*;
 use crate::error::CoreError;
+use crate::models::MyPlayerStats;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -258,6 +259,21 @@ impl QuestManager {
         completed_quests
     }
 
+    /// Apply one match's creative contributions (assists / key passes)
+    ///
+    /// Returns IDs of quests completed by this update.
+    pub fn record_match_contributions(&mut self, stats: &MyPlayerStats) -> Vec<String> {
+        let mut completed = Vec::new();
+        for (objective_type, value) in
+            [(ObjectiveType::Assist, stats.assists), (ObjectiveType::KeyPass, stats.key_passes)]
+        {
+            if value > 0 {
+                completed.extend(self.update_all_active_objectives(objective_type, value as i32));
+            }
+        }
+        completed
+    }
+
     /// Complete a quest
     fn complete_quest(&mut self, quest_id: &str) -> QuestResult<()> {
         let quest = self
@@ -454,6 +470,43 @@ mod tests {
         assert_eq!(manager.get_quest("test2").unwrap().status, QuestStatus::Completed);
     }
 
+    #[test]
+    fn test_record_match_contributions() {
+        let mut manager = QuestManager::new();
+        let mut quest = create_test_quest("playmaker", QuestType::Side);
+        quest.objectives = vec![
+            Objective::new("Provide 2 assists".to_string(), 2, ObjectiveType::Assist),
+            Objective::new("Make 5 key passes".to_string(), 5, ObjectiveType::KeyPass),
+        ];
+        quest.status = QuestStatus::Active;
+        quest.started_at = Some(0);
+        manager.add_quest(quest);
+        manager.state.active_quest_ids.push("playmaker".to_string());
+
+        let mut stats = MyPlayerStats {
+            player_id: "p".to_string(),
+            player_name: "p".to_string(),
+            goals: 0,
+            assists: 1,
+            second_assists: 1,
+            key_passes: 3,
+            shots: 0,
+            passes: 40,
+            tackles: 0,
+            fouls: 0,
+            saves: 0,
+            yellow_cards: 0,
+            red_cards: 0,
+            rating: 7.0,
+        };
+        assert!(manager.record_match_contributions(&stats).is_empty());
+        let objectives = &manager.get_quest("playmaker").unwrap().objectives;
+        assert_eq!((objectives[0].current_value, objectives[1].current_value), (1, 3));
+
+        stats.key_passes = 2;
+        assert_eq!(manager.record_match_contributions(&stats), vec!["playmaker".to_string()]);
+    }
+
     #[test]
     fn test_time_limit_check() {
         let mut manager = QuestManager::new();
@@ -21,10 +21,12 @@ pub enum QuestStatus {
 /// Objective type for quest progression tracking
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ObjectiveType {
-    Win,   // Win N matches
-    Train, // Complete N training sessions
-    Stat,  // Achieve stat threshold
-    Event, // Trigger specific events
+    Win,     // Win N matches
+    Train,   // Complete N training sessions
+    Stat,    // Achieve stat threshold
+    Event,   // Trigger specific events
+    Assist,  // Provide N assists
+    KeyPass, // Make N passes that lead to a shot
 }
 
 /// Squad level for unlock conditions
@@ -0,0 +1,995 @@
+//! MRB0 바이너리 리플레이 포맷 (encode/decode)
+//!
+//! Godot 브리지(`simulate_match_from_binary`)가 만들던 바이너리 리플레이를
+//! of_core로 옮긴 SSOT. CLI 등 다른 호스트도 같은 파일을 읽을 수 있다.
+//!
+//! ## 레이아웃 (little-endian)
+//! ```text
+//! u32  magic = 0x3042524D ("MRB0")
+//! u8   version (5)
+//! u32  header_len
+//! [u8] header (UTF-8 JSON: 메타/팀/이벤트/타임라인)
+//! u32  index_len                                   (v4+)
+//! index:                                           (v4+)
+//!   u32  bucket_seconds (60), u32 bucket_count (B)
+//!   u64  ball_offsets[B+1]
+//!   u32  player_count
+//!     { u32 player_id, u64 offsets[B+1] }
+//! body:
+//!   u8   format_version (5)
+//!   f32  duration_seconds
+//!   u8   score_home, u8 score_away
+//!   u32  event_count
+//!     { u8 minute, u8 event_code, u8 is_home, str16 player, str16 assist }
+//!   u64  ball_frame_count
+//!     v3/v4: { f32 t, x, y, z, vx, vy }
+//!     v5:    u32 chunk_count (B), { u32 chunk_len, [u8] chunk }
+//!   u64  player_count
+//!     { u32 player_id, u64 frame_count,
+//!       v3/v4: { f32 t, x, y, vx, vy }
+//!       v5:    u32 chunk_count (B), { u32 chunk_len, [u8] chunk } }
+//! ```
+//! `str16` = u16 길이 + UTF-8 바이트. C7 이후 player/assist는 항상 빈 문자열.
+//!
+//! 인덱스 오프셋은 body 시작 기준 바이트 위치: `offsets[b]` = b분 이후 첫 프레임,
+//! `offsets[B]` = 해당 트랙 프레임 끝. `ReplayReader`가 이걸로 필요한 구간만 디코딩한다.
+//! v5에서는 버킷 하나 = LZ4 청크 하나이고 오프셋은 청크(`chunk_len`) 시작을 가리킨다.
+//! 청크 내부(cm 양자화 + 델타)는 `binary_delta` 참고. v5 프레임은 손실(cm/ms) 압축이다.
+//! v3(인덱스 없음)/v4(f32 원본 프레임)도 계속 디코딩·인코딩된다.
+
+use serde::{Deserialize, Serialize};
+
+use super::binary_delta::{
+    decode_ball_chunk, decode_player_chunk, encode_ball_chunk, encode_player_chunk,
+};
+use crate::models::{EventType, MatchResult};
+
+/// "MRB0"
+pub const MRB0_MAGIC: u32 = 0x3042524D;
+/// 현재 포맷 버전 (외부 버전 바이트 = body format_version)
+pub const MRB0_FORMAT_VERSION: u8 = 5;
+/// 인덱스 + f32 원본 프레임 (무손실)
+pub const MRB0_INDEXED_VERSION: u8 = 4;
+/// 프레임 인덱스가 없는 이전 버전 (디코딩만 지원)
+pub const MRB0_LEGACY_VERSION: u8 = 3;
+/// 프레임 인덱스 버킷 크기 (초)
+pub const MRB0_INDEX_BUCKET_SECONDS: u32 = 60;
+
+pub(super) const BALL_FRAME_BYTES: usize = 24;
+pub(super) const PLAYER_FRAME_BYTES: usize = 20;
+
+/// 디코딩된 MRB0 리플레이
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BinaryReplay {
+    pub format_version: u8,
+    /// 헤더 JSON (match_id, seed, teams, match_setup, events, timeline ...)
+    pub header: serde_json::Value,
+    pub duration_seconds: f32,
+    pub score_home: u8,
+    pub score_away: u8,
+    pub events: Vec<BinaryEvent>,
+    pub ball_frames: Vec<BinaryBallFrame>,
+    pub players: Vec<BinaryPlayerTrack>,
+}
+
+/// body 이벤트 레코드
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BinaryEvent {
+    pub minute: u8,
+    pub event_type: EventType,
+    pub is_home_team: bool,
+    /// C7: 항상 빈 문자열 (track_id는 헤더 이벤트 참조)
+    pub player: String,
+    pub assist: String,
+}
+
+/// 공 프레임 (초, 미터, m/s)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BinaryBallFrame {
+    pub t: f32,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub vx: f32,
+    pub vy: f32,
+}
+
+/// 선수 프레임 (초, 미터, m/s)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BinaryPlayerFrame {
+    pub t: f32,
+    pub x: f32,
+    pub y: f32,
+    pub vx: f32,
+    pub vy: f32,
+}
+
+/// 선수 한 명의 프레임 목록
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BinaryPlayerTrack {
+    /// track_id (0-21)
+    pub player_id: u32,
+    pub frames: Vec<BinaryPlayerFrame>,
+}
+
+/// v4+ 프레임 인덱스 (분 → body 기준 바이트 오프셋)
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FrameIndex {
+    pub bucket_seconds: u32,
+    /// 버킷 b의 첫 공 프레임 오프셋 (마지막 원소 = 공 프레임 끝)
+    pub ball: Vec<u64>,
+    pub players: Vec<TrackIndex>,
+}
+
+/// 선수 한 명의 프레임 오프셋
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackIndex {
+    pub player_id: u32,
+    pub offsets: Vec<u64>,
+}
+
+impl FrameIndex {
+    /// 인덱스 버킷 수 (오프셋 배열 길이 - 1)
+    pub fn bucket_count(&self) -> usize {
+        self.ball.len().saturating_sub(1)
+    }
+
+    /// 시각(ms)이 속한 버킷 (범위를 넘으면 마지막 경계로 clamp)
+    pub fn bucket_for(&self, t_ms: u64) -> usize {
+        let bucket_ms = self.bucket_seconds.max(1) as u64 * 1000;
+        ((t_ms / bucket_ms) as usize).min(self.bucket_count())
+    }
+}
+
+/// 이벤트 타입 → MRB0 코드
+pub fn mrb0_event_code(event_type: &EventType) -> u8 {
+    match event_type {
+        EventType::Goal => 0,
+        EventType::Shot => 1,
+        EventType::ShotOnTarget => 2,
+        EventType::ShotOffTarget => 3,
+        EventType::ShotBlocked => 4,
+        EventType::Save => 5,
+        EventType::YellowCard => 6,
+        EventType::RedCard => 7,
+        EventType::Substitution => 8,
+        EventType::Injury => 9,
+        EventType::Corner => 10,
+        EventType::Freekick => 11,
+        EventType::Penalty => 12,
+        EventType::Offside => 13,
+        EventType::Foul => 14,
+        EventType::KeyChance => 15,
+        EventType::Pass => 16,
+        EventType::Tackle => 17,
+        EventType::Dribble => 18,
+        EventType::KickOff => 19,
+        EventType::PostHit => 20,
+        EventType::BarHit => 21,
+        EventType::GoalKick => 22,
+        EventType::ThrowIn => 23,
+        EventType::HalfTime => 24,
+        EventType::FullTime => 25,
+        EventType::OwnGoal => 26,
+        EventType::VarReview => 27,
+        EventType::Handball => 28,
+        EventType::TeamTalk => 29,
+        EventType::TacticalAdaptation => 30,
+        EventType::Weather => 31,
+        EventType::AddedTime => 32,
+        EventType::AdvantagePlayed => 33,
+        EventType::BackPassOffence => 34,
+        EventType::GoalkeeperHolding => 35,
+        EventType::VarReviewStarted => 36,
+        EventType::VarDecision => 37,
+    }
+}
+
+/// MRB0 코드 → 이벤트 타입 (알 수 없는 코드는 None)
+pub fn mrb0_event_type(code: u8) -> Option<EventType> {
+    let event_type = match code {
+        0 => EventType::Goal,
+        1 => EventType::Shot,
+        2 => EventType::ShotOnTarget,
+        3 => EventType::ShotOffTarget,
+        4 => EventType::ShotBlocked,
+        5 => EventType::Save,
+        6 => EventType::YellowCard,
+        7 => EventType::RedCard,
+        8 => EventType::Substitution,
+        9 => EventType::Injury,
+        10 => EventType::Corner,
+        11 => EventType::Freekick,
+        12 => EventType::Penalty,
+        13 => EventType::Offside,
+        14 => EventType::Foul,
+        15 => EventType::KeyChance,
+        16 => EventType::Pass,
+        17 => EventType::Tackle,
+        18 => EventType::Dribble,
+        19 => EventType::KickOff,
+        20 => EventType::PostHit,
+        21 => EventType::BarHit,
+        22 => EventType::GoalKick,
+        23 => EventType::ThrowIn,
+        24 => EventType::HalfTime,
+        25 => EventType::FullTime,
+        26 => EventType::OwnGoal,
+        27 => EventType::VarReview,
+        28 => EventType::Handball,
+        29 => EventType::TeamTalk,
+        30 => EventType::TacticalAdaptation,
+        31 => EventType::Weather,
+        32 => EventType::AddedTime,
+        33 => EventType::AdvantagePlayed,
+        34 => EventType::BackPassOffence,
+        35 => EventType::GoalkeeperHolding,
+        36 => EventType::VarReviewStarted,
+        37 => EventType::VarDecision,
+        _ => return None,
+    };
+    Some(event_type)
+}
+
+/// 헤더 JSON에 쓰는 이벤트 타입 문자열
+pub fn mrb0_event_label(event_type: &EventType) -> &'static str {
+    match event_type {
+        EventType::Goal => "goal",
+        EventType::OwnGoal => "own_goal",
+        EventType::Shot => "shot",
+        EventType::ShotOnTarget => "shot_on_target",
+        EventType::ShotOffTarget => "shot_off_target",
+        EventType::ShotBlocked => "shot_blocked",
+        EventType::Save => "save",
+        EventType::YellowCard => "yellow_card",
+        EventType::RedCard => "red_card",
+        EventType::Substitution => "substitution",
+        EventType::Injury => "injury",
+        EventType::Corner => "corner",
+        EventType::Freekick => "freekick",
+        EventType::Penalty => "penalty",
+        EventType::Offside => "offside",
+        EventType::Foul => "foul",
+        EventType::Handball => "handball",
+        EventType::KeyChance => "key_chance",
+        EventType::Pass => "pass",
+        EventType::Tackle => "tackle",
+        EventType::Dribble => "dribble",
+        EventType::KickOff => "kick_off",
+        EventType::PostHit => "post_hit",
+        EventType::BarHit => "bar_hit",
+        EventType::GoalKick => "goal_kick",
+        EventType::ThrowIn => "throw_in",
+        EventType::HalfTime => "half_time",
+        EventType::FullTime => "full_time",
+        EventType::VarReview => "var_review",
+        EventType::TeamTalk => "team_talk",
+        EventType::TacticalAdaptation => "tactical_adaptation",
+        EventType::Weather => "weather",
+        EventType::AddedTime => "added_time",
+        EventType::AdvantagePlayed => "advantage_played",
+        EventType::BackPassOffence => "back_pass_offence",
+        EventType::GoalkeeperHolding => "goalkeeper_holding",
+        EventType::VarReviewStarted => "var_review_started",
+        EventType::VarDecision => "var_decision",
+    }
+}
+
+impl BinaryReplay {
+    /// 경기 결과(position_data 포함)로부터 MRB0 리플레이 구성
+    pub fn from_match_result(result: &MatchResult, seed: u64) -> Self {
+        let mut duration_seconds: f32 = 5400.0; // fallback: 90min
+        let mut ball_frames = Vec::new();
+        let mut players = Vec::new();
+
+        if let Some(ref pos_data) = result.position_data {
+            if let Some(last) = pos_data.ball.last() {
+                duration_seconds = (last.timestamp as f32) / 1000.0;
+            }
+
+            ball_frames = pos_data
+                .ball
+                .iter()
+                .map(|item| {
+                    let (vx, vy) = item.velocity.unwrap_or((0.0, 0.0));
+                    BinaryBallFrame {
+                        t: (item.timestamp as f32) / 1000.0,
+                        x: item.position.0,
+                        y: item.position.1,
+                        z: item.height.unwrap_or(0.0),
+                        vx,
+                        vy,
+                    }
+                })
+                .collect();
+
+            // track_id 순서로 고정 (HashMap 순회 순서에 의존하지 않음)
+            players = pos_data
+                .players
+                .iter()
+                .enumerate()
+                .map(|(player_idx, frames)| BinaryPlayerTrack {
+                    player_id: player_idx as u32,
+                    frames: frames
+                        .iter()
+                        .map(|item| {
+                            let (vx, vy) = item.velocity.unwrap_or((0.0, 0.0));
+                            BinaryPlayerFrame {
+                                t: (item.timestamp as f32) / 1000.0,
+                                x: item.position.0,
+                                y: item.position.1,
+                                vx,
+                                vy,
+                            }
+                        })
+                        .collect(),
+                })
+                .collect();
+        }
+
+        let events = result
+            .events
+            .iter()
+            .map(|event| BinaryEvent {
+                minute: event.minute,
+                event_type: event.event_type.clone(),
+                is_home_team: event.is_home_team,
+                player: String::new(),
+                assist: String::new(),
+            })
+            .collect();
+
+        Self {
+            format_version: MRB0_FORMAT_VERSION,
+            header: build_header(result, seed, duration_seconds),
+            duration_seconds,
+            score_home: result.score_home,
+            score_away: result.score_away,
+            events,
+            ball_frames,
+            players,
+        }
+    }
+}
+
+/// 헤더 JSON (events는 ReplayLoader용, timeline은 UI용으로 중복 수록)
+fn build_header(result: &MatchResult, seed: u64, duration_seconds: f32) -> serde_json::Value {
+    let events_json: Vec<_> = result
+        .events
+        .iter()
+        .map(|e| {
+            let event_type_str = mrb0_event_label(&e.event_type);
+            serde_json::json!({
+                "minute": e.minute,
+                "timestamp_ms": e.timeline_ms(),
+                "t": e.timeline_ms() as f64 / 1000.0,
+                "type": event_type_str,
+                "etype": event_type_str,
+                "team": if e.is_home_team { "home" } else { "away" },
+                "is_home_team": e.is_home_team,
+                // C7: player/player_id 제거 - player_track_id 사용
+                "player_track_id": e.player_track_id,
+                "target_track_id": e.target_track_id,
+                "details": e.details.as_ref().map(|d| {
+                    let injury_severity = d.injury_severity.as_ref().map(|inj| serde_json::json!({
+                        "weeks_out": inj.weeks_out,
+                        "description": &inj.description,
+                    }));
+                    let ball_position = d.ball_position.map(|(x, y, z)| serde_json::json!({
+                        "x": x,
+                        "y": y,
+                        "z": z,
+                    }));
+                    let substitution = d.substitution.as_ref().map(|sub| serde_json::json!({
+                        "player_in_name": &sub.player_in_name,
+                        "player_out_name": &sub.player_out_name,
+                        "bench_slot": sub.bench_slot,
+                    }));
+                    let var_review = d.var_review.as_ref().map(|vr| serde_json::json!({
+                        "reviewed_event_type":
+                            format!("{:?}", vr.reviewed_event_type).to_lowercase(),
+                        "outcome": vr.outcome.as_ref().map(|o| format!("{:?}", o).to_lowercase()),
+                        "incident": vr.incident,
+                        "overturn_probability": vr.overturn_probability,
+                        "duration_s": vr.duration_s,
+                        "offside_margin_m": vr.offside_margin_m,
+                        "overturned_restart": vr.overturned_restart,
+                    }));
+                    serde_json::json!({
+                        "xg_value": d.xg_value,
+                        "injury_severity": injury_severity,
+                        "ball_position": ball_position,
+                        "substitution": substitution,
+                        "var_review": var_review,
+                    })
+                })
+            })
+        })
+        .collect();
+
+    let team_json = |team: Option<&crate::models::Team>, fallback: &str| {
+        serde_json::json!({
+            "name": team.map(|t| t.name.clone()).unwrap_or_else(|| fallback.to_string()),
+            "formation": team
+                .map(|t| format!("{:?}", t.formation))
+                .unwrap_or_else(|| "4-4-2".to_string()),
+        })
+    };
+
+    let timeline: Vec<_> = events_json
+        .iter()
+        .map(|e| {
+            serde_json::json!({
+                "t": e.get("t").unwrap_or(&serde_json::json!(0.0)),
+                "label": e.get("type").unwrap_or(&serde_json::json!("unknown")),
+                "team_id": if e.get("is_home_team").and_then(|v| v.as_bool()).unwrap_or(true) {
+                    0
+                } else {
+                    1
+                },
+                "player": e.get("player")
+            })
+        })
+        .collect();
+
+    serde_json::json!({
+        "format_version": MRB0_FORMAT_VERSION,
+        "match_id": format!("binary-{}", seed),
+        "seed": seed,
+        "duration_seconds": duration_seconds,
+        "score": { "home": result.score_home, "away": result.score_away },
+        "penalty_shootout": result.penalty_shootout.as_ref().map(|ps| {
+            serde_json::json!({
+                "goals_home": ps.goals_home,
+                "goals_away": ps.goals_away,
+                "kicks_taken_home": ps.kicks_taken_home,
+                "kicks_taken_away": ps.kicks_taken_away,
+                "winner_is_home": ps.winner_is_home,
+                "kicks": ps.kicks.iter().map(|kick| {
+                    serde_json::json!({
+                        "kick_index": kick.kick_index,
+                        "is_home_team": kick.is_home_team,
+                        "kicker_track_id": kick.kicker_track_id,
+                        "kicker_name": &kick.kicker_name,
+                        "scored": kick.scored,
+                    })
+                }).collect::<Vec<_>>(),
+            })
+        }),
+        "teams": {
+            "home": team_json(result.home_team.as_ref(), "Home"),
+            "away": team_json(result.away_team.as_ref(), "Away"),
+        },
+        "match_setup": result.match_setup.as_ref().map(|ms| {
+            serde_json::json!({
+                "home": { "name": &ms.home.name, "formation": &ms.home.formation },
+                "away": { "name": &ms.away.name, "formation": &ms.away.formation },
+                "player_slots": ms.player_slots.iter().map(|slot| {
+                    serde_json::json!({
+                        "track_id": slot.track_id,
+                        "team": &slot.team,
+                        "name": &slot.name,
+                        "position": &slot.position,
+                        "overall": slot.overall,
+                        "slot": slot.slot
+                    })
+                }).collect::<Vec<_>>()
+            })
+        }),
+        "events": events_json,
+        "timeline": timeline,
+    })
+}
+
+// ============================================================================
+// Encode
+// ============================================================================
+
+/// MRB0 바이트로 인코딩
+pub fn encode_mrb0(replay: &BinaryReplay) -> Vec<u8> {
+    let mut body: Vec<u8> = Vec::new();
+    body.push(replay.format_version);
+    body.extend_from_slice(&replay.duration_seconds.to_le_bytes());
+    body.push(replay.score_home);
+    body.push(replay.score_away);
+
+    body.extend_from_slice(&(replay.events.len() as u32).to_le_bytes());
+    for event in &replay.events {
+        body.push(event.minute);
+        body.push(mrb0_event_code(&event.event_type));
+        body.push(if event.is_home_team { 1 } else { 0 });
+        write_str16(&mut body, &event.player);
+        write_str16(&mut body, &event.assist);
+    }
+
+    let buckets =
+        (replay.duration_seconds.max(0.0) / MRB0_INDEX_BUCKET_SECONDS as f32) as usize + 1;
+    let mut index = FrameIndex { bucket_seconds: MRB0_INDEX_BUCKET_SECONDS, ..Default::default() };
+
+    let chunked = replay.format_version >= MRB0_FORMAT_VERSION;
+
+    body.extend_from_slice(&(replay.ball_frames.len() as u64).to_le_bytes());
+    let times: Vec<f32> = replay.ball_frames.iter().map(|f| f.t).collect();
+    let bounds = bucket_bounds(&times, buckets);
+    if chunked {
+        index.ball =
+            write_chunks(&mut body, &bounds, |range| encode_ball_chunk(&replay.ball_frames[range]));
+    } else {
+        index.ball = raw_offsets(&bounds, body.len(), BALL_FRAME_BYTES);
+        for f in &replay.ball_frames {
+            for v in [f.t, f.x, f.y, f.z, f.vx, f.vy] {
+                body.extend_from_slice(&v.to_le_bytes());
+            }
+        }
+    }
+
+    body.extend_from_slice(&(replay.players.len() as u64).to_le_bytes());
+    for track in &replay.players {
+        body.extend_from_slice(&track.player_id.to_le_bytes());
+        body.extend_from_slice(&(track.frames.len() as u64).to_le_bytes());
+        let times: Vec<f32> = track.frames.iter().map(|f| f.t).collect();
+        let bounds = bucket_bounds(&times, buckets);
+        let offsets = if chunked {
+            write_chunks(&mut body, &bounds, |range| encode_player_chunk(&track.frames[range]))
+        } else {
+            let offsets = raw_offsets(&bounds, body.len(), PLAYER_FRAME_BYTES);
+            for f in &track.frames {
+                for v in [f.t, f.x, f.y, f.vx, f.vy] {
+                    body.extend_from_slice(&v.to_le_bytes());
+                }
+            }
+            offsets
+        };
+        index.players.push(TrackIndex { player_id: track.player_id, offsets });
+    }
+
+    let header_bytes = serde_json::to_vec(&replay.header).unwrap_or_else(|_| b"{}".to_vec());
+    let index_bytes =
+        if replay.format_version > MRB0_LEGACY_VERSION { encode_index(&index) } else { Vec::new() };
+
+    let mut out: Vec<u8> =
+        Vec::with_capacity(13 + header_bytes.len() + index_bytes.len() + body.len());
+    out.extend_from_slice(&MRB0_MAGIC.to_le_bytes());
+    out.push(replay.format_version);
+    out.extend_from_slice(&(header_bytes.len() as u32).to_le_bytes());
+    out.extend_from_slice(&header_bytes);
+    if replay.format_version > MRB0_LEGACY_VERSION {
+        out.extend_from_slice(&(index_bytes.len() as u32).to_le_bytes());
+        out.extend_from_slice(&index_bytes);
+    }
+    out.extend_from_slice(&body);
+    out
+}
+
+/// 버킷 경계별 첫 프레임 인덱스 (프레임은 시간순, 마지막 원소 = 프레임 수)
+fn bucket_bounds(times: &[f32], buckets: usize) -> Vec<usize> {
+    (0..=buckets)
+        .map(|b| {
+            if b == buckets {
+                times.len()
+            } else {
+                let bound = (b as u32 * MRB0_INDEX_BUCKET_SECONDS) as f32;
+                times.partition_point(|&t| t < bound)
+            }
+        })
+        .collect()
+}
+
+/// v4: 고정 크기 프레임의 버킷 오프셋
+fn raw_offsets(bounds: &[usize], block_start: usize, frame_bytes: usize) -> Vec<u64> {
+    bounds.iter().map(|&frames| (block_start + frames * frame_bytes) as u64).collect()
+}
+
+/// v5: 버킷마다 청크 하나를 쓰고 청크 시작 오프셋 반환
+fn write_chunks(
+    body: &mut Vec<u8>,
+    bounds: &[usize],
+    encode: impl Fn(std::ops::Range<usize>) -> Vec<u8>,
+) -> Vec<u64> {
+    let buckets = bounds.len() - 1;
+    body.extend_from_slice(&(buckets as u32).to_le_bytes());
+    let mut offsets = Vec::with_capacity(bounds.len());
+    for b in 0..buckets {
+        offsets.push(body.len() as u64);
+        let chunk = encode(bounds[b]..bounds[b + 1]);
+        body.extend_from_slice(&(chunk.len() as u32).to_le_bytes());
+        body.extend_from_slice(&chunk);
+    }
+    offsets.push(body.len() as u64);
+    offsets
+}
+
+fn encode_index(index: &FrameIndex) -> Vec<u8> {
+    let mut buf = Vec::new();
+    buf.extend_from_slice(&index.bucket_seconds.to_le_bytes());
+    buf.extend_from_slice(&(index.bucket_count() as u32).to_le_bytes());
+    for offset in &index.ball {
+        buf.extend_from_slice(&offset.to_le_bytes());
+    }
+    buf.extend_from_slice(&(index.players.len() as u32).to_le_bytes());
+    for track in &index.players {
+        buf.extend_from_slice(&track.player_id.to_le_bytes());
+        for offset in &track.offsets {
+            buf.extend_from_slice(&offset.to_le_bytes());
+        }
+    }
+    buf
+}
+
+fn write_str16(buf: &mut Vec<u8>, s: &str) {
+    let bytes = s.as_bytes();
+    let len = bytes.len().min(u16::MAX as usize);
+    buf.extend_from_slice(&(len as u16).to_le_bytes());
+    buf.extend_from_slice(&bytes[..len]);
+}
+
+// ============================================================================
+// Decode
+// ============================================================================
+
+/// MRB0 바이트 디코딩
+pub fn decode_mrb0(data: &[u8]) -> Result<BinaryReplay, String> {
+    let mut r = Reader::new(data);
+
+    let magic = r.u32()?;
+    if magic != MRB0_MAGIC {
+        return Err(format!("invalid MRB0 magic: {:#x}", magic));
+    }
+    let (header, _index) = decode_preamble(&mut r)?;
+    let mut replay = decode_body_preamble(&mut r, header)?;
+
+    let chunked = replay.format_version >= MRB0_FORMAT_VERSION;
+
+    let ball_count = r.u64()? as usize;
+    let ball_frames = if chunked {
+        read_chunks(&mut r, ball_count, decode_ball_chunk)?
+    } else {
+        let mut frames = Vec::with_capacity(ball_count.min(r.remaining() / BALL_FRAME_BYTES));
+        for _ in 0..ball_count {
+            frames.push(read_ball_frame(&mut r)?);
+        }
+        frames
+    };
+
+    let player_count = r.u64()? as usize;
+    let mut players = Vec::with_capacity(player_count.min(22));
+    for _ in 0..player_count {
+        let player_id = r.u32()?;
+        let frame_count = r.u64()? as usize;
+        let frames = if chunked {
+            read_chunks(&mut r, frame_count, decode_player_chunk)?
+        } else {
+            let mut frames =
+                Vec::with_capacity(frame_count.min(r.remaining() / PLAYER_FRAME_BYTES));
+            for _ in 0..frame_count {
+                frames.push(read_player_frame(&mut r)?);
+            }
+            frames
+        };
+        players.push(BinaryPlayerTrack { player_id, frames });
+    }
+
+    replay.ball_frames = ball_frames;
+    replay.players = players;
+    Ok(replay)
+}
+
+/// v5 청크 목록 전체 디코딩 (프레임 수가 선언과 다르면 에러)
+fn read_chunks<T>(
+    r: &mut Reader<'_>,
+    frame_count: usize,
+    decode: fn(&[u8]) -> Result<Vec<T>, String>,
+) -> Result<Vec<T>, String> {
+    let chunk_count = r.u32()? as usize;
+    let mut frames = Vec::with_capacity(frame_count.min(r.remaining()));
+    for _ in 0..chunk_count {
+        frames.extend(decode(read_chunk(r)?)?);
+    }
+    if frames.len() != frame_count {
+        return Err(format!("MRB0 frame count mismatch: {} != {}", frames.len(), frame_count));
+    }
+    Ok(frames)
+}
+
+/// v5 청크 하나 (`u32 chunk_len` + 바이트)
+pub(super) fn read_chunk<'a>(r: &mut Reader<'a>) -> Result<&'a [u8], String> {
+    let len = r.u32()? as usize;
+    r.bytes(len)
+}
+
+/// version/header/index까지 읽기 (v3는 인덱스 None). 끝나면 `r`은 body 시작에 위치.
+pub(super) fn decode_preamble(
+    r: &mut Reader<'_>,
+) -> Result<(serde_json::Value, Option<FrameIndex>), String> {
+    let version = r.u8()?;
+    if !(MRB0_LEGACY_VERSION..=MRB0_FORMAT_VERSION).contains(&version) {
+        return Err(format!("unsupported MRB0 version: {}", version));
+    }
+
+    let header_len = r.u32()? as usize;
+    let header_bytes = r.bytes(header_len)?;
+    let header: serde_json::Value = serde_json::from_slice(header_bytes)
+        .map_err(|e| format!("invalid MRB0 header JSON: {}", e))?;
+
+    let index = if version > MRB0_LEGACY_VERSION {
+        let index_len = r.u32()? as usize;
+        let mut ir = Reader::new(r.bytes(index_len)?);
+        Some(decode_index(&mut ir)?)
+    } else {
+        None
+    };
+    Ok((header, index))
+}
+
+fn decode_index(r: &mut Reader<'_>) -> Result<FrameIndex, String> {
+    let bucket_seconds = r.u32()?;
+    let bucket_count = r.u32()? as usize;
+    let offsets = |r: &mut Reader<'_>| -> Result<Vec<u64>, String> {
+        if (bucket_count + 1) * 8 > r.remaining() {
+            return Err("MRB0 frame index truncated".to_string());
+        }
+        (0..=bucket_count).map(|_| r.u64()).collect()
+    };
+    let ball = offsets(r)?;
+    let player_count = r.u32()? as usize;
+    let mut players = Vec::with_capacity(player_count.min(22));
+    for _ in 0..player_count {
+        let player_id = r.u32()?;
+        players.push(TrackIndex { player_id, offsets: offsets(r)? });
+    }
+    Ok(FrameIndex { bucket_seconds, ball, players })
+}
+
+/// body의 메타 + 이벤트 (프레임 목록은 비어 있음)
+pub(super) fn decode_body_preamble(
+    r: &mut Reader<'_>,
+    header: serde_json::Value,
+) -> Result<BinaryReplay, String> {
+    let format_version = r.u8()?;
+    let duration_seconds = r.f32()?;
+    let score_home = r.u8()?;
+    let score_away = r.u8()?;
+
+    let event_count = r.u32()? as usize;
+    let mut events = Vec::with_capacity(event_count.min(r.remaining()));
+    for _ in 0..event_count {
+        let minute = r.u8()?;
+        let code = r.u8()?;
+        let event_type =
+            mrb0_event_type(code).ok_or_else(|| format!("unknown MRB0 event code: {}", code))?;
+        let is_home_team = r.u8()? != 0;
+        let player = r.str16()?;
+        let assist = r.str16()?;
+        events.push(BinaryEvent { minute, event_type, is_home_team, player, assist });
+    }
+
+    Ok(BinaryReplay {
+        format_version,
+        header,
+        duration_seconds,
+        score_home,
+        score_away,
+        events,
+        ball_frames: Vec::new(),
+        players: Vec::new(),
+    })
+}
+
+pub(super) fn read_ball_frame(r: &mut Reader<'_>) -> Result<BinaryBallFrame, String> {
+    Ok(BinaryBallFrame {
+        t: r.f32()?,
+        x: r.f32()?,
+        y: r.f32()?,
+        z: r.f32()?,
+        vx: r.f32()?,
+        vy: r.f32()?,
+    })
+}
+
+pub(super) fn read_player_frame(r: &mut Reader<'_>) -> Result<BinaryPlayerFrame, String> {
+    Ok(BinaryPlayerFrame { t: r.f32()?, x: r.f32()?, y: r.f32()?, vx: r.f32()?, vy: r.f32()? })
+}
+
+pub(super) struct Reader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub(super) fn new(data: &'a [u8]) -> Self {
+        Self { data, offset: 0 }
+    }
+
+    pub(super) fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub(super) fn remaining(&self) -> usize {
+        self.data.len() - self.offset
+    }
+
+    pub(super) fn bytes(&mut self, len: usize) -> Result<&'a [u8], String> {
+        if len > self.remaining() {
+            return Err(format!("MRB0 truncated at offset {}", self.offset));
+        }
+        let slice = &self.data[self.offset..self.offset + len];
+        self.offset += len;
+        Ok(slice)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], String> {
+        let mut buf = [0u8; N];
+        buf.copy_from_slice(self.bytes(N)?);
+        Ok(buf)
+    }
+
+    pub(super) fn u8(&mut self) -> Result<u8, String> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, String> {
+        self.array().map(u16::from_le_bytes)
+    }
+
+    pub(super) fn u32(&mut self) -> Result<u32, String> {
+        self.array().map(u32::from_le_bytes)
+    }
+
+    pub(super) fn u64(&mut self) -> Result<u64, String> {
+        self.array().map(u64::from_le_bytes)
+    }
+
+    pub(super) fn f32(&mut self) -> Result<f32, String> {
+        self.array().map(f32::from_le_bytes)
+    }
+
+    fn str16(&mut self) -> Result<String, String> {
+        let len = self.u16()? as usize;
+        String::from_utf8(self.bytes(len)?.to_vec())
+            .map_err(|_| format!("invalid UTF-8 string at offset {}", self.offset))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::match_result::{MatchPositionData, PositionDataItem};
+    use crate::models::MatchEvent;
+
+    fn sample_result() -> MatchResult {
+        let mut result = MatchResult::new();
+        result.score_home = 2;
+        result.score_away = 1;
+        result.events = vec![
+            MatchEvent::shot(12, 720_000, true, 9, true, 0.4),
+            MatchEvent::shot(70, 4_200_000, false, 20, false, 0.1),
+        ];
+        result.events[0].event_type = EventType::Goal;
+        result.events[1].event_type = EventType::Handball;
+
+        let mut pos = MatchPositionData::new();
+        pos.ball.push(PositionDataItem::with_height(0, (52.5, 34.0), 0.0));
+        pos.ball.push(PositionDataItem {
+            velocity: Some((3.0, -1.5)),
+            ..PositionDataItem::with_height(5_400_000, (60.0, 30.0), 1.2)
+        });
+        pos.players[3].push(PositionDataItem::with_velocity(50, (20.0, 10.0), (1.0, 0.5)));
+        result.position_data = Some(pos);
+        result
+    }
+
+    #[test]
+    fn test_mrb0_round_trip() {
+        let replay = BinaryReplay::from_match_result(&sample_result(), 42);
+        assert_eq!(replay.duration_seconds, 5400.0);
+        assert_eq!(replay.players.len(), 22);
+        assert_eq!(replay.header["match_id"], "binary-42");
+        assert_eq!(replay.header["events"][1]["type"], "handball");
+        assert_eq!(replay.header["events"][0]["timestamp_ms"], 720_000);
+        assert_eq!(replay.header["timeline"][1]["t"], 4_200.0);
+
+        let bytes = encode_mrb0(&replay);
+        assert_eq!(&bytes[0..4], b"MRB0");
+        assert_eq!(bytes[4], MRB0_FORMAT_VERSION);
+
+        let decoded = decode_mrb0(&bytes).unwrap();
+        assert_eq!(decoded, replay);
+        assert_eq!(decoded.events[0].event_type, EventType::Goal);
+        assert_eq!(decoded.ball_frames[1].vx, 3.0);
+        assert_eq!(decoded.players[3].frames[0].x, 20.0);
+
+        // Same result → same bytes (player tracks are ordered by track_id).
+        assert_eq!(encode_mrb0(&BinaryReplay::from_match_result(&sample_result(), 42)), bytes);
+    }
+
+    /// 90분 10Hz: 선수 22명 + 공, 부드러운 이동
+    fn tracked_replay(format_version: u8) -> BinaryReplay {
+        let ticks = 54_000;
+        let ball_frames = (0..=ticks)
+            .map(|i| {
+                let t = i as f32 * 0.1;
+                BinaryBallFrame {
+                    t,
+                    x: 52.5 + (t * 0.05).sin() * 40.0,
+                    y: 34.0 + (t * 0.07).cos() * 25.0,
+                    z: ((t * 0.3).sin() * 2.0).max(0.0),
+                    vx: (t * 0.05).cos() * 2.0,
+                    vy: -(t * 0.07).sin() * 1.75,
+                }
+            })
+            .collect();
+        let players = (0..22u32)
+            .map(|id| BinaryPlayerTrack {
+                player_id: id,
+                frames: (0..=ticks)
+                    .map(|i| {
+                        let t = i as f32 * 0.1;
+                        let phase = id as f32 * 0.3;
+                        BinaryPlayerFrame {
+                            t,
+                            x: 10.0 + id as f32 * 4.0 + (t * 0.02 + phase).sin() * 8.0,
+                            y: 34.0 + (t * 0.03 + phase).cos() * 12.0,
+                            vx: (t * 0.02 + phase).cos() * 0.16,
+                            vy: -(t * 0.03 + phase).sin() * 0.36,
+                        }
+                    })
+                    .collect(),
+            })
+            .collect();
+        BinaryReplay {
+            format_version,
+            header: serde_json::json!({"match_id": "tracked"}),
+            duration_seconds: 5400.0,
+            score_home: 0,
+            score_away: 0,
+            events: Vec::new(),
+            ball_frames,
+            players,
+        }
+    }
+
+    #[test]
+    fn test_mrb0_v5_compresses_full_match_within_centimetres() {
+        let raw = tracked_replay(MRB0_INDEXED_VERSION);
+        let raw_bytes = encode_mrb0(&raw);
+        assert_eq!(decode_mrb0(&raw_bytes).unwrap(), raw);
+
+        let packed = tracked_replay(MRB0_FORMAT_VERSION);
+        let packed_bytes = encode_mrb0(&packed);
+        assert!(
+            packed_bytes.len() * 3 < raw_bytes.len(),
+            "v5 {} bytes vs v4 {} bytes",
+            packed_bytes.len(),
+            raw_bytes.len()
+        );
+
+        let decoded = decode_mrb0(&packed_bytes).unwrap();
+        assert_eq!(decoded.ball_frames.len(), packed.ball_frames.len());
+        for (a, b) in packed.players.iter().zip(&decoded.players) {
+            assert_eq!(a.frames.len(), b.frames.len());
+            for (fa, fb) in a.frames.iter().zip(&b.frames) {
+                assert!((fa.t - fb.t).abs() < 1e-3);
+                assert!((fa.x - fb.x).abs() <= 0.0051 && (fa.y - fb.y).abs() <= 0.0051);
+            }
+        }
+        for (fa, fb) in packed.ball_frames.iter().zip(&decoded.ball_frames) {
+            assert!((fa.z - fb.z).abs() <= 0.0051 && (fa.vx - fb.vx).abs() <= 0.0051);
+        }
+    }
+
+    #[test]
+    fn test_mrb0_rejects_bad_input() {
+        let bytes = encode_mrb0(&BinaryReplay::from_match_result(&sample_result(), 1));
+        assert!(decode_mrb0(&bytes[..bytes.len() - 3]).is_err());
+
+        let mut bad_magic = bytes.clone();
+        bad_magic[0] = b'X';
+        assert!(decode_mrb0(&bad_magic).is_err());
+
+        for code in 0..=37 {
+            let event_type = mrb0_event_type(code).unwrap();
+            assert_eq!(mrb0_event_code(&event_type), code);
+        }
+        assert!(mrb0_event_type(38).is_none());
+    }
+}
@@ -0,0 +1,219 @@
+//! MRB0 v5 프레임 청크 코덱 (양자화 + 델타 + LZ4)
+//!
+//! 한 청크 = 트랙 하나의 인덱스 버킷(1분) 분량 프레임. 청크마다 독립적으로
+//! 풀 수 있어서 `ReplayReader`가 필요한 분만 해제한다.
+//!
+//! ```text
+//! lz4(size-prepended) {
+//!   varint frame_count
+//!   { zigzag-varint delta[N] }   // 직전 프레임 대비, 첫 프레임은 0 기준
+//! }
+//! ```
+//! 양자화: t = ms, 위치/높이 = cm, 속도 = cm/s (i32). 디코딩 값은 `q / scale`.
+
+use lz4_flex::{compress_prepend_size, decompress_size_prepended};
+
+use super::binary::{BinaryBallFrame, BinaryPlayerFrame};
+
+/// t: 초 → ms
+const TIME_SCALE: f64 = 1000.0;
+/// 위치/속도: m → cm
+const SPACE_SCALE: f64 = 100.0;
+
+/// LZ4 최대 압축률 (~255:1) 초과 선언은 손상으로 간주
+const LZ4_MAX_RATIO: usize = 255;
+
+fn quantize(v: f32, scale: f64) -> i32 {
+    (v as f64 * scale).round().clamp(i32::MIN as f64, i32::MAX as f64) as i32
+}
+
+fn dequantize(q: i32, scale: f64) -> f32 {
+    q as f32 / scale as f32
+}
+
+fn ball_fields(f: &BinaryBallFrame) -> [i32; 6] {
+    [
+        quantize(f.t, TIME_SCALE),
+        quantize(f.x, SPACE_SCALE),
+        quantize(f.y, SPACE_SCALE),
+        quantize(f.z, SPACE_SCALE),
+        quantize(f.vx, SPACE_SCALE),
+        quantize(f.vy, SPACE_SCALE),
+    ]
+}
+
+fn player_fields(f: &BinaryPlayerFrame) -> [i32; 5] {
+    [
+        quantize(f.t, TIME_SCALE),
+        quantize(f.x, SPACE_SCALE),
+        quantize(f.y, SPACE_SCALE),
+        quantize(f.vx, SPACE_SCALE),
+        quantize(f.vy, SPACE_SCALE),
+    ]
+}
+
+pub(super) fn encode_ball_chunk(frames: &[BinaryBallFrame]) -> Vec<u8> {
+    encode_chunk(frames.iter().map(ball_fields), frames.len())
+}
+
+pub(super) fn encode_player_chunk(frames: &[BinaryPlayerFrame]) -> Vec<u8> {
+    encode_chunk(frames.iter().map(player_fields), frames.len())
+}
+
+pub(super) fn decode_ball_chunk(chunk: &[u8]) -> Result<Vec<BinaryBallFrame>, String> {
+    Ok(decode_chunk::<6>(chunk)?
+        .into_iter()
+        .map(|[t, x, y, z, vx, vy]| BinaryBallFrame {
+            t: dequantize(t, TIME_SCALE),
+            x: dequantize(x, SPACE_SCALE),
+            y: dequantize(y, SPACE_SCALE),
+            z: dequantize(z, SPACE_SCALE),
+            vx: dequantize(vx, SPACE_SCALE),
+            vy: dequantize(vy, SPACE_SCALE),
+        })
+        .collect())
+}
+
+pub(super) fn decode_player_chunk(chunk: &[u8]) -> Result<Vec<BinaryPlayerFrame>, String> {
+    Ok(decode_chunk::<5>(chunk)?
+        .into_iter()
+        .map(|[t, x, y, vx, vy]| BinaryPlayerFrame {
+            t: dequantize(t, TIME_SCALE),
+            x: dequantize(x, SPACE_SCALE),
+            y: dequantize(y, SPACE_SCALE),
+            vx: dequantize(vx, SPACE_SCALE),
+            vy: dequantize(vy, SPACE_SCALE),
+        })
+        .collect())
+}
+
+fn encode_chunk<const N: usize>(frames: impl Iterator<Item = [i32; N]>, count: usize) -> Vec<u8> {
+    let mut raw = Vec::with_capacity(4 + count * N * 2);
+    write_varint(&mut raw, count as u64);
+    let mut prev = [0i32; N];
+    for cur in frames {
+        for i in 0..N {
+            write_varint(&mut raw, zigzag(cur[i] as i64 - prev[i] as i64));
+        }
+        prev = cur;
+    }
+    compress_prepend_size(&raw)
+}
+
+fn decode_chunk<const N: usize>(chunk: &[u8]) -> Result<Vec<[i32; N]>, String> {
+    let declared = chunk
+        .get(..4)
+        .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]) as usize)
+        .ok_or_else(|| "MRB0 frame chunk truncated".to_string())?;
+    if declared > chunk.len().saturating_mul(LZ4_MAX_RATIO) + 16 {
+        return Err(format!("MRB0 frame chunk declares {} bytes (corrupt)", declared));
+    }
+    let raw = decompress_size_prepended(chunk)
+        .map_err(|e| format!("MRB0 frame chunk decompress failed: {}", e))?;
+
+    let mut pos = 0;
+    let count = read_varint(&raw, &mut pos)? as usize;
+    // 프레임당 최소 N바이트 (varint 1바이트 × N)
+    let mut frames = Vec::with_capacity(count.min(raw.len() / N));
+    let mut prev = [0i32; N];
+    for _ in 0..count {
+        let mut cur = [0i32; N];
+        for i in 0..N {
+            let value = prev[i] as i64 + unzigzag(read_varint(&raw, &mut pos)?);
+            cur[i] = i32::try_from(value)
+                .map_err(|_| format!("MRB0 frame delta overflow at byte {}", pos))?;
+        }
+        frames.push(cur);
+        prev = cur;
+    }
+    if pos != raw.len() {
+        return Err(format!("MRB0 frame chunk has {} trailing bytes", raw.len() - pos));
+    }
+    Ok(frames)
+}
+
+fn zigzag(v: i64) -> u64 {
+    ((v << 1) ^ (v >> 63)) as u64
+}
+
+fn unzigzag(v: u64) -> i64 {
+    (v >> 1) as i64 ^ -((v & 1) as i64)
+}
+
+fn write_varint(buf: &mut Vec<u8>, mut v: u64) {
+    while v >= 0x80 {
+        buf.push((v as u8) | 0x80);
+        v >>= 7;
+    }
+    buf.push(v as u8);
+}
+
+fn read_varint(buf: &[u8], pos: &mut usize) -> Result<u64, String> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let byte = *buf.get(*pos).ok_or_else(|| "MRB0 frame chunk truncated".to_string())?;
+        *pos += 1;
+        value |= ((byte & 0x7F) as u64) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err(format!("MRB0 varint too long at byte {}", *pos))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zigzag_varint_round_trip() {
+        for v in [0i64, 1, -1, 63, -64, 300, -300, i32::MAX as i64 * 2, i32::MIN as i64 * 2] {
+            let mut buf = Vec::new();
+            write_varint(&mut buf, zigzag(v));
+            let mut pos = 0;
+            assert_eq!(unzigzag(read_varint(&buf, &mut pos).unwrap()), v);
+            assert_eq!(pos, buf.len());
+        }
+    }
+
+    #[test]
+    fn test_chunk_quantizes_to_centimetres() {
+        let frames: Vec<BinaryPlayerFrame> = (0..600)
+            .map(|i| BinaryPlayerFrame {
+                t: i as f32 * 0.1,
+                x: 30.0 + (i as f32 * 0.013).sin() * 5.0,
+                y: 34.0 - i as f32 * 0.004,
+                vx: 1.234_567,
+                vy: -0.5,
+            })
+            .collect();
+        let chunk = encode_player_chunk(&frames);
+        let decoded = decode_player_chunk(&chunk).unwrap();
+
+        assert_eq!(decoded.len(), frames.len());
+        for (a, b) in frames.iter().zip(&decoded) {
+            assert!((a.t - b.t).abs() <= 0.0005);
+            assert!((a.x - b.x).abs() <= 0.005 + 1e-5);
+            assert!((a.y - b.y).abs() <= 0.005 + 1e-5);
+            assert_eq!(b.vx, 1.23);
+        }
+        // 600 frames × 20 raw bytes
+        assert!(chunk.len() * 4 < frames.len() * 20, "chunk {} bytes", chunk.len());
+    }
+
+    #[test]
+    fn test_chunk_rejects_corruption() {
+        let chunk = encode_ball_chunk(&[BinaryBallFrame {
+            t: 1.0,
+            x: 2.0,
+            y: 3.0,
+            z: 0.5,
+            vx: 0.0,
+            vy: 0.0,
+        }]);
+        assert_eq!(decode_ball_chunk(&chunk).unwrap()[0].z, 0.5);
+        assert!(decode_ball_chunk(&chunk[..chunk.len() - 1]).is_err());
+        assert!(decode_ball_chunk(&[0xFF, 0xFF, 0xFF, 0x7F, 0]).is_err());
+        assert!(decode_ball_chunk(&[]).is_err());
+    }
+}
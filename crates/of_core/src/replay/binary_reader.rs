@@ -0,0 +1,253 @@
+//! ReplayReader - MRB0 v4 부분 디코딩 (시간 구간 탐색)
+//!
+//! 헤더/인덱스/이벤트만 먼저 읽고, 프레임은 요청 구간에 걸친 분 버킷만
+//! 디코딩한다 (v5는 해당 버킷의 LZ4 청크만 해제). 90분 리플레이 전체를 풀지 않고 하이라이트·스크럽 구간을 꺼낼 때 사용.
+
+use super::binary::{
+    decode_body_preamble, decode_preamble, encode_mrb0, read_ball_frame, read_chunk,
+    read_player_frame, BinaryPlayerTrack, BinaryReplay, FrameIndex, Reader, BALL_FRAME_BYTES,
+    MRB0_FORMAT_VERSION, MRB0_MAGIC, PLAYER_FRAME_BYTES,
+};
+use super::binary_delta::{decode_ball_chunk, decode_player_chunk};
+
+/// 인덱스 기반 MRB0 리더 (데이터를 복사하지 않음)
+#[derive(Debug)]
+pub struct ReplayReader<'a> {
+    /// body 바이트 (인덱스 오프셋 기준점)
+    body: &'a [u8],
+    index: FrameIndex,
+    /// 메타 + 이벤트 (프레임 목록은 비어 있음)
+    meta: BinaryReplay,
+}
+
+impl<'a> ReplayReader<'a> {
+    /// 헤더·인덱스·이벤트만 디코딩 (프레임은 `read_window`에서)
+    pub fn open(data: &'a [u8]) -> Result<Self, String> {
+        let mut r = Reader::new(data);
+        let magic = r.u32()?;
+        if magic != MRB0_MAGIC {
+            return Err(format!("invalid MRB0 magic: {:#x}", magic));
+        }
+        let (header, index) = decode_preamble(&mut r)?;
+        let index = index.ok_or_else(|| {
+            "MRB0 v3 has no frame index (decode it fully with decode_mrb0)".to_string()
+        })?;
+
+        let body = &data[r.offset()..];
+        let meta = decode_body_preamble(&mut Reader::new(body), header)?;
+        Ok(Self { body, index, meta })
+    }
+
+    pub fn index(&self) -> &FrameIndex {
+        &self.index
+    }
+
+    pub fn duration_ms(&self) -> u64 {
+        (self.meta.duration_seconds.max(0.0) * 1000.0) as u64
+    }
+
+    /// `t_ms`가 속한 버킷의 공 프레임(v5: 청크) body 오프셋
+    pub fn seek(&self, t_ms: u64) -> u64 {
+        self.index.ball[self.index.bucket_for(t_ms)]
+    }
+
+    /// [start_ms, end_ms] 구간만 디코딩한 리플레이 (헤더/점수는 원본 그대로)
+    pub fn read_window(&self, start_ms: u64, end_ms: u64) -> Result<BinaryReplay, String> {
+        if start_ms > end_ms {
+            return Err(format!("invalid replay window: {}ms > {}ms", start_ms, end_ms));
+        }
+        let first = self.index.bucket_for(start_ms);
+        let last = (self.index.bucket_for(end_ms) + 1).min(self.index.bucket_count());
+        let in_window = |t: f32| {
+            let t_ms = (t.max(0.0) * 1000.0).round() as u64;
+            (start_ms..=end_ms).contains(&t_ms)
+        };
+
+        let chunked = self.meta.format_version >= MRB0_FORMAT_VERSION;
+
+        let mut ball_frames = Vec::new();
+        let mut r = self.slice(&self.index.ball, first, last, BALL_FRAME_BYTES, chunked)?;
+        while r.remaining() > 0 {
+            if chunked {
+                ball_frames.extend(decode_ball_chunk(read_chunk(&mut r)?)?);
+            } else {
+                ball_frames.push(read_ball_frame(&mut r)?);
+            }
+        }
+        ball_frames.retain(|f| in_window(f.t));
+
+        let mut players = Vec::with_capacity(self.index.players.len());
+        for track in &self.index.players {
+            let mut r = self.slice(&track.offsets, first, last, PLAYER_FRAME_BYTES, chunked)?;
+            let mut frames = Vec::new();
+            while r.remaining() > 0 {
+                if chunked {
+                    frames.extend(decode_player_chunk(read_chunk(&mut r)?)?);
+                } else {
+                    frames.push(read_player_frame(&mut r)?);
+                }
+            }
+            frames.retain(|f| in_window(f.t));
+            players.push(BinaryPlayerTrack { player_id: track.player_id, frames });
+        }
+
+        let (start_minute, end_minute) = (start_ms / 60_000, end_ms / 60_000);
+        let events = self
+            .meta
+            .events
+            .iter()
+            .filter(|e| (start_minute..=end_minute).contains(&(e.minute as u64)))
+            .cloned()
+            .collect();
+
+        Ok(BinaryReplay { events, ball_frames, players, ..self.meta.clone() })
+    }
+
+    /// 버킷 [first, last) 구간의 프레임(v5: 청크) 바이트
+    fn slice(
+        &self,
+        offsets: &[u64],
+        first: usize,
+        last: usize,
+        frame_bytes: usize,
+        chunked: bool,
+    ) -> Result<Reader<'a>, String> {
+        let (start, end) = (offsets[first] as usize, offsets[last] as usize);
+        if start > end || end > self.body.len() || (!chunked && (end - start) % frame_bytes != 0) {
+            return Err(format!("MRB0 frame index out of range: {}..{}", start, end));
+        }
+        Ok(Reader::new(&self.body[start..end]))
+    }
+}
+
+/// MRB0 v4에서 [start_ms, end_ms] 구간만 꺼내 다시 MRB0로 인코딩
+pub fn read_replay_window(data: &[u8], start_ms: u64, end_ms: u64) -> Result<Vec<u8>, String> {
+    let window = ReplayReader::open(data)?.read_window(start_ms, end_ms)?;
+    Ok(encode_mrb0(&window))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::EventType;
+    use crate::replay::binary::{
+        decode_mrb0, BinaryBallFrame, BinaryEvent, BinaryPlayerFrame, MRB0_INDEXED_VERSION,
+        MRB0_LEGACY_VERSION,
+    };
+
+    /// 90분, 공 1Hz / 선수 0.5Hz 프레임
+    fn sample_replay() -> BinaryReplay {
+        let ball_frames = (0..=5400)
+            .map(|s| BinaryBallFrame {
+                t: s as f32,
+                x: s as f32 / 100.0,
+                y: 34.0,
+                z: 0.0,
+                vx: 0.0,
+                vy: 0.0,
+            })
+            .collect();
+        let players = (0..22)
+            .map(|id| BinaryPlayerTrack {
+                player_id: id,
+                frames: (0..=2700)
+                    .map(|s| BinaryPlayerFrame {
+                        t: s as f32 * 2.0,
+                        x: id as f32,
+                        y: 0.0,
+                        vx: 0.0,
+                        vy: 0.0,
+                    })
+                    .collect(),
+            })
+            .collect();
+        let event = |minute, event_type| BinaryEvent {
+            minute,
+            event_type,
+            is_home_team: true,
+            player: String::new(),
+            assist: String::new(),
+        };
+        BinaryReplay {
+            format_version: MRB0_FORMAT_VERSION,
+            header: serde_json::json!({"match_id": "window"}),
+            duration_seconds: 5400.0,
+            score_home: 1,
+            score_away: 0,
+            events: vec![event(10, EventType::Shot), event(31, EventType::Goal)],
+            ball_frames,
+            players,
+        }
+    }
+
+    #[test]
+    fn test_index_points_at_minute_boundaries() {
+        let indexed = BinaryReplay { format_version: MRB0_INDEXED_VERSION, ..sample_replay() };
+        let bytes = encode_mrb0(&indexed);
+        let reader = ReplayReader::open(&bytes).unwrap();
+        assert_eq!(reader.index().bucket_count(), 91);
+        assert_eq!(reader.index().players.len(), 22);
+        assert_eq!(reader.duration_ms(), 5_400_000);
+
+        // Minute 30 starts 1800 ball frames into the ball block.
+        assert_eq!(
+            reader.seek(30 * 60_000 + 5_000) - reader.seek(0),
+            1800 * BALL_FRAME_BYTES as u64
+        );
+        assert_eq!(reader.seek(u64::MAX), *reader.index().ball.last().unwrap());
+
+        // Full decode still works and skips the index.
+        assert_eq!(decode_mrb0(&bytes).unwrap(), indexed);
+
+        // v5 offsets point at one chunk per minute instead.
+        let bytes = encode_mrb0(&sample_replay());
+        let reader = ReplayReader::open(&bytes).unwrap();
+        assert_eq!(reader.index().bucket_count(), 91);
+        assert!(reader.index().ball.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(decode_mrb0(&bytes).unwrap(), sample_replay());
+    }
+
+    #[test]
+    fn test_window_decodes_only_requested_range() {
+        let bytes = encode_mrb0(&sample_replay());
+        let window = ReplayReader::open(&bytes).unwrap().read_window(1_800_500, 1_900_000).unwrap();
+
+        assert_eq!(window.ball_frames.first().unwrap().t, 1801.0);
+        assert_eq!(window.ball_frames.last().unwrap().t, 1900.0);
+        assert_eq!(window.ball_frames.len(), 100);
+        assert_eq!(window.players[5].frames.len(), 50);
+        assert_eq!(window.players[5].frames[0].x, 5.0);
+        assert_eq!(window.events.len(), 1);
+        assert_eq!(window.events[0].event_type, EventType::Goal);
+        assert_eq!(window.header["match_id"], "window");
+
+        // The window round-trips as a standalone MRB0 file.
+        let window_bytes = read_replay_window(&bytes, 1_800_500, 1_900_000).unwrap();
+        assert_eq!(decode_mrb0(&window_bytes).unwrap(), window);
+        assert!(window_bytes.len() < bytes.len() / 2);
+
+        // Raw-frame v4 yields the same window.
+        let indexed = BinaryReplay { format_version: MRB0_INDEXED_VERSION, ..sample_replay() };
+        let indexed_window = ReplayReader::open(&encode_mrb0(&indexed))
+            .unwrap()
+            .read_window(1_800_500, 1_900_000)
+            .unwrap();
+        assert_eq!(indexed_window.ball_frames, window.ball_frames);
+        assert_eq!(indexed_window.players, window.players);
+    }
+
+    #[test]
+    fn test_reader_rejects_legacy_and_bad_windows() {
+        let legacy = BinaryReplay { format_version: MRB0_LEGACY_VERSION, ..sample_replay() };
+        let legacy_bytes = encode_mrb0(&legacy);
+        assert_eq!(decode_mrb0(&legacy_bytes).unwrap(), legacy);
+        assert!(ReplayReader::open(&legacy_bytes).unwrap_err().contains("no frame index"));
+
+        let bytes = encode_mrb0(&sample_replay());
+        let reader = ReplayReader::open(&bytes).unwrap();
+        assert!(reader.read_window(10_000, 5_000).is_err());
+        // Past the end: empty but valid.
+        assert!(reader.read_window(9_000_000, 9_500_000).unwrap().ball_frames.is_empty());
+        assert!(ReplayReader::open(&bytes[..40]).is_err());
+    }
+}
@@ -286,7 +286,8 @@ impl ReplayConverter {
             }
 
             // FIX_2601/0123 Phase 6: Handball events map to Foul for replay
-            EventType::Handball => {
+            // Goalkeeper handling offences (Law 12.2) replay the same way.
+            EventType::Handball | EventType::BackPassOffence | EventType::GoalkeeperHolding => {
                 let opponent_id =
                     format!("{}{}", if event.is_home_team { "A" } else { "H" }, player_idx);
 
@@ -418,7 +419,16 @@ impl ReplayConverter {
                 }))
             }
 
-            EventType::HalfTime | EventType::FullTime | EventType::VarReview => {
+            EventType::HalfTime
+            | EventType::FullTime
+            | EventType::VarReview
+            | EventType::VarReviewStarted
+            | EventType::VarDecision
+            | EventType::TeamTalk
+            | EventType::TacticalAdaptation
+            | EventType::Weather
+            | EventType::AddedTime
+            | EventType::AdvantagePlayed => {
                 // Match phase events - map to a minimal pass event (no actual ball movement)
                 let end_pos = pos.clone();
                 let ball = self.create_ball_state(&pos, &end_pos, 0.0);
@@ -0,0 +1,328 @@
+//! Replay diff - 두 리플레이 비교 리포트 (회귀 테스트/모더용)
+//!
+//! - 결정성 체크섬 트레일이 양쪽에 있으면 첫 분기 틱
+//! - 이벤트는 인덱스 순으로 비교해 달라진 필드를 나열 (`base.t`, `at`, `xg` ...)
+//! - 같은 인덱스·같은 종류 이벤트의 기준 좌표(at/from/spot/to) 거리 통계
+//!
+//! 골든 리플레이를 통합 테스트에서 비교하거나, 엔진 수정 전후 리플레이를
+//! `diff_replays_json`으로 비교하는 용도.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::types::{ReplayDoc, ReplayEvent};
+
+/// 좌표 필드 우선순위 (이벤트 기준점)
+const ANCHOR_FIELDS: [&str; 4] = ["at", "from", "spot", "to"];
+
+/// 비교 허용 오차
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReplayDiffOptions {
+    /// 좌표 필드 허용 오차 (m)
+    pub position_tolerance_m: f64,
+    /// `base.t` 허용 오차 (초)
+    pub time_tolerance_s: f64,
+    /// 리포트에 나열할 최대 이벤트 차이 수
+    pub max_listed_events: usize,
+}
+
+impl Default for ReplayDiffOptions {
+    fn default() -> Self {
+        Self { position_tolerance_m: 0.01, time_tolerance_s: 0.001, max_listed_events: 50 }
+    }
+}
+
+/// 인덱스 하나의 이벤트 차이
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventDiff {
+    pub index: usize,
+    /// 이벤트 종류 (없으면 해당 쪽에 이벤트 없음)
+    pub kind_a: Option<String>,
+    pub kind_b: Option<String>,
+    pub t_a: Option<f64>,
+    pub t_b: Option<f64>,
+    /// 달라진 필드 (중첩은 `base.player_id` 형식)
+    pub changed_fields: Vec<String>,
+}
+
+/// 기준 좌표 거리 통계
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PositionDeltaStats {
+    /// 양쪽 모두 좌표가 있는 이벤트 쌍 수
+    pub compared: usize,
+    /// 허용 오차를 넘은 쌍 수
+    pub over_tolerance: usize,
+    pub max_delta_m: f64,
+    pub mean_delta_m: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_delta_event_index: Option<usize>,
+}
+
+/// 리플레이 비교 결과
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReplayDiffReport {
+    pub identical: bool,
+    /// 양쪽 모두 체크섬 트레일이 있을 때만 true
+    pub checksums_compared: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_divergent_tick: Option<u32>,
+    pub event_count_a: usize,
+    pub event_count_b: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_divergent_event: Option<usize>,
+    pub differing_event_count: usize,
+    /// 앞에서부터 최대 `max_listed_events`개
+    pub event_diffs: Vec<EventDiff>,
+    pub position_deltas: PositionDeltaStats,
+}
+
+/// 기본 허용 오차로 비교
+pub fn diff(replay_a: &ReplayDoc, replay_b: &ReplayDoc) -> ReplayDiffReport {
+    diff_with_options(replay_a, replay_b, &ReplayDiffOptions::default())
+}
+
+pub fn diff_with_options(
+    replay_a: &ReplayDoc,
+    replay_b: &ReplayDoc,
+    options: &ReplayDiffOptions,
+) -> ReplayDiffReport {
+    let (checksums_compared, first_divergent_tick) =
+        match (&replay_a.determinism, &replay_b.determinism) {
+            (Some(a), Some(b)) => (true, a.first_divergence(b)),
+            _ => (false, None),
+        };
+
+    let event_count = replay_a.events.len().max(replay_b.events.len());
+    let mut event_diffs = Vec::new();
+    let mut differing_event_count = 0;
+    let mut first_divergent_event = None;
+    let mut deltas: Vec<(usize, f64)> = Vec::new();
+
+    for index in 0..event_count {
+        let a = replay_a.events.get(index).map(event_value);
+        let b = replay_b.events.get(index).map(event_value);
+
+        if let (Some(a), Some(b)) = (&a, &b) {
+            if a.get("kind") == b.get("kind") {
+                if let (Some(pa), Some(pb)) = (anchor(a), anchor(b)) {
+                    deltas.push((index, ((pa.0 - pb.0).powi(2) + (pa.1 - pb.1).powi(2)).sqrt()));
+                }
+            }
+        }
+
+        let changed_fields = match (&a, &b) {
+            (Some(a), Some(b)) => changed_fields(a, b, "", options),
+            _ => vec!["kind".to_string()],
+        };
+        if changed_fields.is_empty() {
+            continue;
+        }
+
+        differing_event_count += 1;
+        first_divergent_event.get_or_insert(index);
+        if event_diffs.len() < options.max_listed_events {
+            event_diffs.push(EventDiff {
+                index,
+                kind_a: a.as_ref().and_then(kind_of),
+                kind_b: b.as_ref().and_then(kind_of),
+                t_a: replay_a.events.get(index).map(|e| e.base().t),
+                t_b: replay_b.events.get(index).map(|e| e.base().t),
+                changed_fields,
+            });
+        }
+    }
+
+    let position_deltas = position_stats(&deltas, options.position_tolerance_m);
+    ReplayDiffReport {
+        identical: first_divergent_tick.is_none() && differing_event_count == 0,
+        checksums_compared,
+        first_divergent_tick,
+        event_count_a: replay_a.events.len(),
+        event_count_b: replay_b.events.len(),
+        first_divergent_event,
+        differing_event_count,
+        event_diffs,
+        position_deltas,
+    }
+}
+
+/// JSON API: 리플레이 JSON 두 개 (+ 선택 옵션 JSON) → 리포트 JSON
+pub fn diff_replays_json(
+    replay_a_json: &str,
+    replay_b_json: &str,
+    options_json: Option<&str>,
+) -> Result<String, String> {
+    let parse = |json: &str, label: &str| -> Result<ReplayDoc, String> {
+        serde_json::from_str(json).map_err(|e| format!("Invalid replay {} JSON: {}", label, e))
+    };
+    let replay_a = parse(replay_a_json, "A")?;
+    let replay_b = parse(replay_b_json, "B")?;
+    let options = match options_json.map(str::trim).filter(|s| !s.is_empty()) {
+        Some(json) => {
+            serde_json::from_str(json).map_err(|e| format!("Invalid diff options: {}", e))?
+        }
+        None => ReplayDiffOptions::default(),
+    };
+
+    let report = diff_with_options(&replay_a, &replay_b, &options);
+    serde_json::to_string(&report).map_err(|e| format!("Failed to serialize diff report: {}", e))
+}
+
+fn event_value(event: &ReplayEvent) -> Value {
+    serde_json::to_value(event).unwrap_or(Value::Null)
+}
+
+fn kind_of(value: &Value) -> Option<String> {
+    value.get("kind").and_then(Value::as_str).map(str::to_string)
+}
+
+/// 이벤트 기준 좌표 (x, y)
+fn anchor(value: &Value) -> Option<(f64, f64)> {
+    ANCHOR_FIELDS.iter().find_map(|field| meter_pos(value.get(*field)?))
+}
+
+fn meter_pos(value: &Value) -> Option<(f64, f64)> {
+    Some((value.get("x")?.as_f64()?, value.get("y")?.as_f64()?))
+}
+
+/// 허용 오차를 적용한 필드별 비교 (중첩 객체는 하위 경로로 나열)
+fn changed_fields(a: &Value, b: &Value, prefix: &str, options: &ReplayDiffOptions) -> Vec<String> {
+    let (Some(a_obj), Some(b_obj)) = (a.as_object(), b.as_object()) else {
+        return if a == b { Vec::new() } else { vec![prefix.to_string()] };
+    };
+
+    let mut keys: Vec<&String> = a_obj.keys().chain(b_obj.keys()).collect();
+    keys.sort();
+    keys.dedup();
+
+    let mut changed = Vec::new();
+    for key in keys {
+        let path = if prefix.is_empty() { key.clone() } else { format!("{}.{}", prefix, key) };
+        let (Some(va), Some(vb)) = (a_obj.get(key), b_obj.get(key)) else {
+            changed.push(path);
+            continue;
+        };
+        if let (Some(pa), Some(pb)) = (meter_pos(va), meter_pos(vb)) {
+            let tolerance = options.position_tolerance_m;
+            if (pa.0 - pb.0).abs() > tolerance || (pa.1 - pb.1).abs() > tolerance {
+                changed.push(path);
+            }
+        } else if va.is_object() && vb.is_object() {
+            changed.extend(changed_fields(va, vb, &path, options));
+        } else if path == "base.t" {
+            let dt = va.as_f64().zip(vb.as_f64()).map(|(ta, tb)| (ta - tb).abs());
+            if dt.map_or(va != vb, |dt| dt > options.time_tolerance_s) {
+                changed.push(path);
+            }
+        } else if va != vb {
+            changed.push(path);
+        }
+    }
+    changed
+}
+
+fn position_stats(deltas: &[(usize, f64)], tolerance_m: f64) -> PositionDeltaStats {
+    if deltas.is_empty() {
+        return PositionDeltaStats::default();
+    }
+    let (max_index, max_delta) = deltas.iter().copied().fold(
+        (deltas[0].0, 0.0_f64),
+        |acc, (i, d)| {
+            if d > acc.1 {
+                (i, d)
+            } else {
+                acc
+            }
+        },
+    );
+    PositionDeltaStats {
+        compared: deltas.len(),
+        over_tolerance: deltas.iter().filter(|(_, d)| *d > tolerance_m).count(),
+        max_delta_m: max_delta,
+        mean_delta_m: deltas.iter().map(|(_, d)| d).sum::<f64>() / deltas.len() as f64,
+        max_delta_event_index: (max_delta > 0.0).then_some(max_index),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::replay::recorder::ReplayRecorder;
+    use crate::replay::types::{MeterPos, PitchSpec, ReplayRosters};
+
+    fn pos(x: f64, y: f64) -> MeterPos {
+        MeterPos { x, y }
+    }
+
+    fn sample_doc(shot_x: f64, scorer: u32, diverge_at: u32) -> ReplayDoc {
+        let mut rec = ReplayRecorder::new(
+            PitchSpec { width_m: 105.0, height_m: 68.0 },
+            ReplayRosters::default(),
+        );
+        rec.record_kickoff(0.0, 0, 9);
+        rec.record_shot(300.0, 0, 9, pos(shot_x, 30.0), pos(105.0, 34.0), true, Some(0.2));
+        rec.record_goal(301.0, 0, scorer, pos(105.0, 34.0), None);
+        rec.record_full_time(5_400.0);
+        for tick in 0..200u64 {
+            let checksum =
+                if tick as u32 >= diverge_at { tick as u32 ^ 0xFFFF } else { tick as u32 };
+            rec.record_tick_checksum(tick, checksum);
+        }
+        rec.into_doc(1)
+    }
+
+    #[test]
+    fn test_identical_replays_report_no_divergence() {
+        let report = diff(&sample_doc(88.0, 10, u32::MAX), &sample_doc(88.0, 10, u32::MAX));
+        assert!(report.identical);
+        assert!(report.checksums_compared);
+        assert_eq!(report.first_divergent_event, None);
+        assert_eq!(report.position_deltas.compared, 2, "kick-off has no anchor");
+        assert_eq!(report.position_deltas.max_delta_m, 0.0);
+    }
+
+    #[test]
+    fn test_diff_reports_tick_events_and_position_deltas() {
+        let a = sample_doc(88.0, 10, u32::MAX);
+        let mut b = sample_doc(88.5, 11, 120);
+        b.events.push(ReplayEvent::test_goal(
+            crate::replay::types::EventBase { t: 5_401.0, player_id: None, team_id: None },
+            pos(0.0, 34.0),
+        ));
+
+        let report = diff(&a, &b);
+        assert!(!report.identical);
+        assert_eq!(report.first_divergent_tick, Some(120));
+        assert_eq!((report.event_count_a, report.event_count_b), (4, 5));
+        assert_eq!(report.first_divergent_event, Some(1));
+        assert_eq!(report.differing_event_count, 3);
+
+        assert_eq!(report.event_diffs[0].changed_fields, vec!["from".to_string()]);
+        assert_eq!(report.event_diffs[1].changed_fields, vec!["base.player_id".to_string()]);
+        assert_eq!(report.event_diffs[2].kind_a, None);
+        assert_eq!(report.event_diffs[2].kind_b.as_deref(), Some("goal"));
+
+        assert_eq!(report.position_deltas.over_tolerance, 1);
+        assert!((report.position_deltas.max_delta_m - 0.5).abs() < 1e-9);
+        assert_eq!(report.position_deltas.max_delta_event_index, Some(1));
+
+        // A looser tolerance hides the 0.5m shot move.
+        let loose = ReplayDiffOptions { position_tolerance_m: 1.0, ..Default::default() };
+        assert_eq!(diff_with_options(&a, &b, &loose).differing_event_count, 2);
+    }
+
+    #[test]
+    fn test_diff_json_api() {
+        let a = serde_json::to_string(&sample_doc(88.0, 10, u32::MAX)).unwrap();
+        let b = serde_json::to_string(&sample_doc(88.0, 10, 5)).unwrap();
+        let report: ReplayDiffReport =
+            serde_json::from_str(&diff_replays_json(&a, &b, None).unwrap()).unwrap();
+        assert_eq!(report.first_divergent_tick, Some(5));
+        assert_eq!(report.differing_event_count, 0);
+
+        let capped = diff_replays_json(&a, &b, Some(r#"{"max_listed_events": 0}"#)).unwrap();
+        assert!(capped.contains("\"event_diffs\":[]"));
+        assert!(diff_replays_json("{}", &b, None).unwrap_err().contains("replay A"));
+    }
+}
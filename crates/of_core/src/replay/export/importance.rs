@@ -52,6 +52,8 @@ impl ImportanceCalculator for StandardImportanceCalculator {
             ReplayEvent::KickOff { .. } => 0.0,
             ReplayEvent::HalfTime { .. } => 0.0,
             ReplayEvent::FullTime { .. } => 0.0,
+            ReplayEvent::Weather { .. } => 0.0,
+            ReplayEvent::AddedTime { .. } => 0.0,
             ReplayEvent::Substitution { .. } => 0.2,
 
             // 0108: Possession events (low importance - for analytics)
@@ -139,6 +139,9 @@ pub fn arb_replay_doc() -> impl Strategy<Value = ReplayDoc> {
             rosters: ReplayRosters::default(),
             timeline: Vec::new(),
             tactics: ReplayTeamsTactics::default(),
+            localized_text: Vec::new(),
+            determinism: None,
+            integrity: None,
         })
 }
 
@@ -166,6 +169,9 @@ pub fn generate_sample_replay() -> ReplayDoc {
         rosters: ReplayRosters::default(),
         timeline: Vec::new(),
         tactics: ReplayTeamsTactics::default(),
+        localized_text: Vec::new(),
+        determinism: None,
+        integrity: None,
     }
 }
 
@@ -0,0 +1,407 @@
+//! Highlight Editor - Replay → edited highlight reel definition
+//!
+//! Picks the moments worth showing from a `ReplayDoc` and lays them out as an
+//! ordered reel the client can play back directly:
+//! - candidates: goals, penalties, shots (xG ≥ HIGHLIGHT_THRESHOLD), saves, red cards
+//! - each clip gets lead-in/lead-out around its trigger and a camera focus track_id
+//! - goals get a slow-motion marker over the build-up to the ball crossing the line
+//! - overlapping clips merge (MERGE_GAP_MS), then the highest-scoring clips are kept
+//!   until the playback budget (slow-motion included) is spent
+//!
+//! Clip scores share the ChanceScore scale with `clip_reducer`.
+
+use serde::{Deserialize, Serialize};
+
+use super::clip_reducer::{AUTO_INCLUDE_THRESHOLD, HIGHLIGHT_THRESHOLD, MERGE_GAP_MS};
+use super::types::{CardType, ReplayDoc, ReplayEvent};
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Default reel budget (milliseconds of playback)
+pub const DEFAULT_HIGHLIGHT_BUDGET_MS: u64 = 90_000;
+
+/// Score for a missed penalty (scored penalties auto-include)
+const MISSED_PENALTY_SCORE: f32 = 0.8;
+
+/// Red cards change the match, but rank below chances
+const RED_CARD_SCORE: f32 = 0.5;
+
+/// Save base score, plus `save_difficulty` × SAVE_DIFFICULTY_WEIGHT
+const SAVE_BASE_SCORE: f32 = 0.3;
+const SAVE_DIFFICULTY_WEIGHT: f32 = 0.3;
+
+/// On-target shots rank slightly above misses with the same xG
+const ON_TARGET_BONUS: f32 = 0.05;
+
+// ============================================================================
+// Data Structures
+// ============================================================================
+
+/// Editing options (all fields optional in JSON)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HighlightReelOptions {
+    /// Total playback budget, slow-motion included
+    pub budget_ms: u64,
+    /// Time shown before the trigger event
+    pub lead_in_ms: u64,
+    /// Time shown after the trigger event
+    pub lead_out_ms: u64,
+    /// Extra lead-out after goals (celebration)
+    pub goal_lead_out_ms: u64,
+    /// Slow-motion span ending at the goal event
+    pub goal_slow_motion_ms: u64,
+    /// Playback rate inside the slow-motion span (0.5 = half speed)
+    pub slow_motion_rate: f32,
+    /// Minimum clip score to be a candidate
+    pub min_score: f32,
+}
+
+impl Default for HighlightReelOptions {
+    fn default() -> Self {
+        Self {
+            budget_ms: DEFAULT_HIGHLIGHT_BUDGET_MS,
+            lead_in_ms: 6_000,
+            lead_out_ms: 3_000,
+            goal_lead_out_ms: 5_000,
+            goal_slow_motion_ms: 3_000,
+            slow_motion_rate: 0.5,
+            min_score: HIGHLIGHT_THRESHOLD,
+        }
+    }
+}
+
+impl HighlightReelOptions {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.budget_ms == 0 {
+            return Err("budget_ms must be > 0".to_string());
+        }
+        if !(self.slow_motion_rate > 0.0 && self.slow_motion_rate <= 1.0) {
+            return Err(format!(
+                "slow_motion_rate must be within (0, 1], got {}",
+                self.slow_motion_rate
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Why a clip is in the reel
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HighlightKind {
+    Goal,
+    Penalty,
+    Shot,
+    Save,
+    RedCard,
+}
+
+/// Slow-motion span inside a clip (match time)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SlowMotionMarker {
+    pub start_ms: u64,
+    pub end_ms: u64,
+    /// Playback rate (0.5 = half speed)
+    pub rate: f32,
+}
+
+/// One clip of the reel
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HighlightClip {
+    pub id: String,
+    pub kind: HighlightKind,
+    /// Clip range (milliseconds from match start)
+    pub start_ms: u64,
+    pub end_ms: u64,
+    /// Trigger event time
+    pub trigger_ms: u64,
+    /// Index of trigger event in `ReplayDoc.events`
+    pub trigger_event_idx: usize,
+    /// Camera focus (trigger player's track_id)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub focus_track_id: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<u32>,
+    /// ChanceScore-scale importance
+    pub score: f32,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub slow_motion: Vec<SlowMotionMarker>,
+    /// Playback length including slow-motion
+    pub playback_ms: u64,
+}
+
+/// Edited highlight reel (clips in match order)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HighlightReel {
+    pub budget_ms: u64,
+    /// Sum of `playback_ms` (≤ budget_ms)
+    pub total_duration_ms: u64,
+    pub clips: Vec<HighlightClip>,
+    /// Candidate clips left out to stay within budget
+    pub dropped_clips: usize,
+}
+
+// ============================================================================
+// Editor
+// ============================================================================
+
+/// Build a highlight reel from a replay
+pub fn build_highlight_reel(doc: &ReplayDoc, options: &HighlightReelOptions) -> HighlightReel {
+    let match_end_ms = doc.events.iter().map(|e| to_ms(e.base().t)).max().unwrap_or(0);
+
+    let mut candidates: Vec<HighlightClip> = doc
+        .events
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, event)| candidate_clip(idx, event, options, match_end_ms))
+        .filter(|clip| clip.score >= options.min_score)
+        .collect();
+    candidates.sort_by_key(|c| (c.start_ms, c.trigger_ms));
+
+    let mut merged = merge_candidates(candidates);
+    for clip in &mut merged {
+        clip.playback_ms = playback_ms(clip);
+    }
+
+    // Spend the budget on the best clips first, then restore match order.
+    let mut by_score: Vec<usize> = (0..merged.len()).collect();
+    by_score.sort_by(|&a, &b| {
+        merged[b]
+            .score
+            .total_cmp(&merged[a].score)
+            .then(merged[a].start_ms.cmp(&merged[b].start_ms))
+    });
+    let mut keep = vec![false; merged.len()];
+    let mut total_duration_ms = 0;
+    for idx in by_score {
+        if total_duration_ms + merged[idx].playback_ms <= options.budget_ms {
+            total_duration_ms += merged[idx].playback_ms;
+            keep[idx] = true;
+        }
+    }
+
+    let candidate_count = merged.len();
+    let clips: Vec<HighlightClip> =
+        merged.into_iter().zip(keep).filter(|(_, kept)| *kept).map(|(clip, _)| clip).collect();
+    HighlightReel {
+        budget_ms: options.budget_ms,
+        total_duration_ms,
+        dropped_clips: candidate_count - clips.len(),
+        clips,
+    }
+}
+
+/// JSON entry point: replay doc JSON (+ optional options JSON) → reel JSON
+pub fn build_highlight_reel_json(
+    replay_json: &str,
+    options_json: Option<&str>,
+) -> Result<String, String> {
+    let doc: ReplayDoc =
+        serde_json::from_str(replay_json).map_err(|e| format!("Invalid replay JSON: {}", e))?;
+    let options = match options_json.map(str::trim).filter(|s| !s.is_empty()) {
+        Some(json) => {
+            serde_json::from_str(json).map_err(|e| format!("Invalid highlight options: {}", e))?
+        }
+        None => HighlightReelOptions::default(),
+    };
+    options.validate()?;
+
+    let reel = build_highlight_reel(&doc, &options);
+    serde_json::to_string(&reel).map_err(|e| format!("Failed to serialize highlight reel: {}", e))
+}
+
+/// Score and frame one event (None = not highlight material)
+fn candidate_clip(
+    idx: usize,
+    event: &ReplayEvent,
+    options: &HighlightReelOptions,
+    match_end_ms: u64,
+) -> Option<HighlightClip> {
+    let (kind, score) = match event {
+        ReplayEvent::Goal { .. } => (HighlightKind::Goal, AUTO_INCLUDE_THRESHOLD),
+        ReplayEvent::Penalty { scored, .. } => (
+            HighlightKind::Penalty,
+            if *scored { AUTO_INCLUDE_THRESHOLD } else { MISSED_PENALTY_SCORE },
+        ),
+        ReplayEvent::Shot { xg, on_target, .. } => {
+            let bonus = if *on_target { ON_TARGET_BONUS } else { 0.0 };
+            (HighlightKind::Shot, xg.unwrap_or(0.0) as f32 + bonus)
+        }
+        ReplayEvent::Save { save_difficulty, .. } => (
+            HighlightKind::Save,
+            SAVE_BASE_SCORE + save_difficulty.unwrap_or(0.0) * SAVE_DIFFICULTY_WEIGHT,
+        ),
+        ReplayEvent::Card { card_type: CardType::Red, .. } => {
+            (HighlightKind::RedCard, RED_CARD_SCORE)
+        }
+        _ => return None,
+    };
+
+    let base = event.base();
+    let trigger_ms = to_ms(base.t);
+    let lead_out_ms =
+        if kind == HighlightKind::Goal { options.goal_lead_out_ms } else { options.lead_out_ms };
+    let start_ms = trigger_ms.saturating_sub(options.lead_in_ms);
+    let end_ms = (trigger_ms + lead_out_ms).min(match_end_ms).max(trigger_ms);
+
+    let mut slow_motion = Vec::new();
+    if kind == HighlightKind::Goal && options.goal_slow_motion_ms > 0 {
+        slow_motion.push(SlowMotionMarker {
+            start_ms: trigger_ms.saturating_sub(options.goal_slow_motion_ms).max(start_ms),
+            end_ms: trigger_ms,
+            rate: options.slow_motion_rate,
+        });
+    }
+
+    Some(HighlightClip {
+        id: format!("hl_{}", trigger_ms),
+        kind,
+        start_ms,
+        end_ms,
+        trigger_ms,
+        trigger_event_idx: idx,
+        focus_track_id: base.player_id,
+        team_id: base.team_id,
+        score,
+        slow_motion,
+        playback_ms: 0,
+    })
+}
+
+/// Merge clips with gap ≤ MERGE_GAP_MS; the higher-scoring trigger drives kind/focus
+fn merge_candidates(candidates: Vec<HighlightClip>) -> Vec<HighlightClip> {
+    let mut merged: Vec<HighlightClip> = Vec::with_capacity(candidates.len());
+    for clip in candidates {
+        match merged.last_mut() {
+            Some(current) if clip.start_ms <= current.end_ms + MERGE_GAP_MS => {
+                current.end_ms = current.end_ms.max(clip.end_ms);
+                current.slow_motion.extend(clip.slow_motion);
+                if clip.score > current.score {
+                    current.id = clip.id;
+                    current.kind = clip.kind;
+                    current.trigger_ms = clip.trigger_ms;
+                    current.trigger_event_idx = clip.trigger_event_idx;
+                    current.focus_track_id = clip.focus_track_id;
+                    current.team_id = clip.team_id;
+                    current.score = clip.score;
+                }
+            }
+            _ => merged.push(clip),
+        }
+    }
+    merged
+}
+
+/// Playback length: real time, plus the stretch added by slow-motion spans
+fn playback_ms(clip: &HighlightClip) -> u64 {
+    let stretch: f64 = clip
+        .slow_motion
+        .iter()
+        .map(|m| (m.end_ms - m.start_ms) as f64 * (1.0 / m.rate as f64 - 1.0))
+        .sum();
+    (clip.end_ms - clip.start_ms) + stretch.round() as u64
+}
+
+fn to_ms(t_seconds: f64) -> u64 {
+    (t_seconds.max(0.0) * 1000.0).round() as u64
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::replay::recorder::ReplayRecorder;
+    use crate::replay::types::{MeterPos, PitchSpec, ReplayRosters};
+
+    fn pos(x: f64, y: f64) -> MeterPos {
+        MeterPos { x, y }
+    }
+
+    fn sample_doc() -> ReplayDoc {
+        let mut rec = ReplayRecorder::new(
+            PitchSpec { width_m: 105.0, height_m: 68.0 },
+            ReplayRosters::default(),
+        );
+        rec.record_kickoff(0.0, 0, 9);
+        rec.record_shot(300.0, 0, 9, pos(88.0, 30.0), pos(105.0, 34.0), false, Some(0.05));
+        rec.record_card(900.0, 1, 15, true);
+        rec.record_shot(1_200.0, 1, 20, pos(15.0, 34.0), pos(0.0, 34.0), true, Some(0.3));
+        rec.record_save(1_200.8, 0, 0, pos(1.0, 34.0));
+        rec.record_shot(2_000.0, 0, 10, pos(94.0, 36.0), pos(105.0, 35.0), true, Some(0.4));
+        rec.record_goal(2_001.0, 0, 10, pos(105.0, 35.0), Some(9));
+        rec.record_shot(4_000.0, 1, 18, pos(80.0, 20.0), pos(105.0, 30.0), false, Some(0.12));
+        rec.record_full_time(5_400.0);
+        rec.into_doc(1)
+    }
+
+    #[test]
+    fn test_reel_orders_clips_and_marks_goal_slow_motion() {
+        let reel = build_highlight_reel(&sample_doc(), &HighlightReelOptions::default());
+
+        // The low-xG shot is filtered; shot + save merge; shot + goal merge.
+        let kinds: Vec<HighlightKind> = reel.clips.iter().map(|c| c.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                HighlightKind::RedCard,
+                HighlightKind::Shot,
+                HighlightKind::Goal,
+                HighlightKind::Shot
+            ]
+        );
+        assert_eq!(reel.dropped_clips, 0);
+        assert!(reel.clips.windows(2).all(|w| w[0].end_ms < w[1].start_ms));
+
+        let goal = &reel.clips[2];
+        assert_eq!(goal.focus_track_id, Some(10));
+        assert_eq!(goal.trigger_ms, 2_001_000);
+        assert_eq!((goal.start_ms, goal.end_ms), (1_994_000, 2_006_000));
+        assert_eq!(
+            goal.slow_motion,
+            vec![SlowMotionMarker { start_ms: 1_998_000, end_ms: 2_001_000, rate: 0.5 }]
+        );
+        // 12s real time + 3s of half-speed stretch
+        assert_eq!(goal.playback_ms, 15_000);
+
+        let saved_shot = &reel.clips[1];
+        assert_eq!(saved_shot.focus_track_id, Some(20));
+        assert_eq!(saved_shot.end_ms, 1_203_800, "merged save extends the clip");
+
+        assert_eq!(reel.total_duration_ms, reel.clips.iter().map(|c| c.playback_ms).sum::<u64>());
+    }
+
+    #[test]
+    fn test_budget_keeps_highest_scoring_clips() {
+        let options = HighlightReelOptions { budget_ms: 25_000, ..Default::default() };
+        let reel = build_highlight_reel(&sample_doc(), &options);
+
+        let kinds: Vec<HighlightKind> = reel.clips.iter().map(|c| c.kind).collect();
+        assert_eq!(kinds, vec![HighlightKind::RedCard, HighlightKind::Goal]);
+        assert_eq!(reel.dropped_clips, 2);
+        assert!(reel.total_duration_ms <= 25_000);
+    }
+
+    #[test]
+    fn test_reel_json_round_trip_and_option_errors() {
+        let replay_json = serde_json::to_string(&sample_doc()).unwrap();
+        let json =
+            build_highlight_reel_json(&replay_json, Some(r#"{"slow_motion_rate": 0.25}"#)).unwrap();
+        let reel: HighlightReel = serde_json::from_str(&json).unwrap();
+        assert_eq!(reel.budget_ms, DEFAULT_HIGHLIGHT_BUDGET_MS);
+        let goal = reel.clips.iter().find(|c| c.kind == HighlightKind::Goal).unwrap();
+        assert_eq!(goal.playback_ms, 12_000 + 9_000);
+
+        assert!(build_highlight_reel_json(&replay_json, Some("  ")).is_ok());
+        assert!(build_highlight_reel_json(&replay_json, Some(r#"{"budget_ms": 0}"#)).is_err());
+        assert!(
+            build_highlight_reel_json(&replay_json, Some(r#"{"slow_motion_rate": 2}"#)).is_err()
+        );
+        assert!(build_highlight_reel_json("{}", None).is_err());
+    }
+}
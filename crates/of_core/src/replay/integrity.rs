@@ -0,0 +1,367 @@
+//! Replay integrity - chunk checksums and a final match hash
+//!
+//! Events are hashed in fixed-size chunks (sha256 over canonical JSON) and a
+//! match hash binds the chunk hashes to rosters, tactics, timeline and the
+//! per-tick determinism trail. `verify_replay` recomputes both, which catches
+//! edits to a stored replay; `verify_replay_json` can also re-simulate the
+//! original request and compare match hashes, which proves the replay is the
+//! engine's output and not merely self-consistent.
+//!
+//! Embedded localized text is presentation only and is not covered.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::types::{
+    PitchSpec, ReplayDeterminism, ReplayDoc, ReplayEvent, ReplayRosters, ReplayTeamsTactics,
+    ReplayTimelineEntry,
+};
+
+/// Integrity section format version
+pub const INTEGRITY_VERSION: u8 = 1;
+/// Events per hashed chunk (a tampered event is located to within one chunk)
+pub const REPLAY_CHUNK_EVENTS: usize = 256;
+
+const CHUNK_DOMAIN: &[u8] = b"of_core/replay_chunk/v1";
+const MATCH_DOMAIN: &[u8] = b"of_core/replay_match/v1";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ReplayIntegrity {
+    pub version: u8,
+    pub chunk_events: u32,
+    /// sha256 per event chunk (hex), in event order
+    pub chunk_hashes: Vec<String>,
+    /// sha256 over the chunk hashes and the non-event replay sections (hex)
+    pub match_hash: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReplayVerdict {
+    pub valid: bool,
+    /// Why verification failed (None = valid)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Event chunks whose contents no longer match their hash
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tampered_chunks: Vec<usize>,
+    /// True when the request was re-simulated (false = hashes only)
+    pub resimulated: bool,
+    /// First tick at which the re-simulation's determinism trail differs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_divergent_tick: Option<u32>,
+}
+
+impl ReplayVerdict {
+    fn reject(reason: impl Into<String>) -> Self {
+        Self {
+            valid: false,
+            reason: Some(reason.into()),
+            tampered_chunks: Vec::new(),
+            resimulated: false,
+            first_divergent_tick: None,
+        }
+    }
+
+    fn accept() -> Self {
+        Self {
+            valid: true,
+            reason: None,
+            tampered_chunks: Vec::new(),
+            resimulated: false,
+            first_divergent_tick: None,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct MatchBody<'a> {
+    version: u32,
+    pitch_m: &'a PitchSpec,
+    rosters: &'a ReplayRosters,
+    timeline: &'a [ReplayTimelineEntry],
+    tactics: &'a ReplayTeamsTactics,
+    determinism: &'a Option<ReplayDeterminism>,
+    chunk_events: u32,
+    chunk_hashes: &'a [String],
+}
+
+// ========== Core Functions ==========
+
+/// Hash `doc` (its `integrity` section is ignored)
+pub fn compute_integrity(doc: &ReplayDoc) -> Result<ReplayIntegrity, String> {
+    let chunk_hashes = chunk_hashes(&doc.events, REPLAY_CHUNK_EVENTS)?;
+    let match_hash = match_hash(doc, REPLAY_CHUNK_EVENTS as u32, &chunk_hashes)?;
+    Ok(ReplayIntegrity {
+        version: INTEGRITY_VERSION,
+        chunk_events: REPLAY_CHUNK_EVENTS as u32,
+        chunk_hashes,
+        match_hash,
+    })
+}
+
+/// Attach a fresh integrity section
+pub fn seal_replay(doc: &mut ReplayDoc) -> Result<(), String> {
+    doc.integrity = Some(compute_integrity(doc)?);
+    Ok(())
+}
+
+/// Recompute chunk and match hashes against the replay's own integrity section
+pub fn verify_replay(doc: &ReplayDoc) -> Result<ReplayVerdict, String> {
+    let Some(claimed) = &doc.integrity else {
+        return Ok(ReplayVerdict::reject("Replay has no integrity section"));
+    };
+    if claimed.version != INTEGRITY_VERSION {
+        return Ok(ReplayVerdict::reject(format!(
+            "Unsupported integrity version: {}",
+            claimed.version
+        )));
+    }
+    if claimed.chunk_events == 0 {
+        return Ok(ReplayVerdict::reject("Integrity chunk size must be > 0"));
+    }
+
+    let actual = chunk_hashes(&doc.events, claimed.chunk_events as usize)?;
+    let chunks = actual.len().max(claimed.chunk_hashes.len());
+    let tampered_chunks: Vec<usize> =
+        (0..chunks).filter(|&i| actual.get(i) != claimed.chunk_hashes.get(i)).collect();
+    if !tampered_chunks.is_empty() {
+        return Ok(ReplayVerdict {
+            tampered_chunks,
+            ..ReplayVerdict::reject("Replay events were modified")
+        });
+    }
+
+    if match_hash(doc, claimed.chunk_events, &actual)? != claimed.match_hash {
+        return Ok(ReplayVerdict::reject("Match hash mismatch"));
+    }
+    Ok(ReplayVerdict::accept())
+}
+
+/// `verify_replay`, then re-simulate `request` (MatchRequestV2 JSON) and compare match hashes.
+///
+/// Re-simulation never applies env exp-config, so replays recorded with experimental
+/// parameters only verify by hash.
+pub fn verify_replay_against_request(
+    doc: &ReplayDoc,
+    request: &serde_json::Value,
+) -> Result<ReplayVerdict, String> {
+    let verdict = verify_replay(doc)?;
+    if !verdict.valid {
+        return Ok(verdict);
+    }
+
+    let expected = resimulate(request)?;
+    let expected_hash = expected.integrity.as_ref().map(|i| i.match_hash.as_str());
+    let claimed_hash = doc.integrity.as_ref().map(|i| i.match_hash.as_str());
+    if expected_hash == claimed_hash {
+        return Ok(ReplayVerdict { resimulated: true, ..ReplayVerdict::accept() });
+    }
+
+    let first_divergent_tick = match (&doc.determinism, &expected.determinism) {
+        (Some(claimed), Some(expected)) => claimed.first_divergence(expected),
+        _ => None,
+    };
+    Ok(ReplayVerdict {
+        resimulated: true,
+        first_divergent_tick,
+        ..ReplayVerdict::reject("Replay does not match re-simulation")
+    })
+}
+
+fn resimulate(request: &serde_json::Value) -> Result<ReplayDoc, String> {
+    use crate::api::json_api::{match_plan_from_match_request_v2, MatchRequestV2};
+    use crate::engine::MatchEngine;
+
+    let request: MatchRequestV2 = serde_json::from_value(request.clone())
+        .map_err(|e| format!("Invalid JSON request: {}", e))?;
+    let (plan, _) = match_plan_from_match_request_v2(request)?;
+    let mut engine = MatchEngine::new(plan)?.with_position_tracking().with_replay_recording();
+    engine.simulate();
+    engine.take_replay_doc().ok_or_else(|| "Re-simulation produced no replay".to_string())
+}
+
+fn chunk_hashes(events: &[ReplayEvent], chunk_events: usize) -> Result<Vec<String>, String> {
+    events
+        .chunks(chunk_events)
+        .enumerate()
+        .map(|(index, chunk)| sha256_json(CHUNK_DOMAIN, &(index, chunk)))
+        .collect()
+}
+
+fn match_hash(
+    doc: &ReplayDoc,
+    chunk_events: u32,
+    chunk_hashes: &[String],
+) -> Result<String, String> {
+    sha256_json(
+        MATCH_DOMAIN,
+        &MatchBody {
+            version: doc.version,
+            pitch_m: &doc.pitch_m,
+            rosters: &doc.rosters,
+            timeline: &doc.timeline,
+            tactics: &doc.tactics,
+            determinism: &doc.determinism,
+            chunk_events,
+            chunk_hashes,
+        },
+    )
+}
+
+/// sha256 over JSON with object keys sorted and floats fixed to 6 decimals
+fn sha256_json<T: Serialize>(domain: &[u8], value: &T) -> Result<String, String> {
+    // serde_json::Value objects are BTreeMap-backed, so re-serializing sorts keys.
+    let mut value =
+        serde_json::to_value(value).map_err(|e| format!("Failed to serialize replay: {}", e))?;
+    quantize_floats(&mut value);
+    let bytes =
+        serde_json::to_vec(&value).map_err(|e| format!("Failed to serialize replay: {}", e))?;
+
+    let mut hasher = Sha256::new();
+    hasher.update(domain);
+    hasher.update(&bytes);
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// JSON float parsing may land one ulp off the written value (serde_json's default parser,
+/// Godot), so hashed floats are fixed-precision strings rather than shortest round-trip.
+fn quantize_floats(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Number(n) if n.is_f64() => {
+            let f = n.as_f64().unwrap_or_default();
+            *value = serde_json::Value::String(format!("{:.6}", f));
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(quantize_floats),
+        serde_json::Value::Object(map) => map.values_mut().for_each(quantize_floats),
+        _ => {}
+    }
+}
+
+// ========== API Functions ==========
+
+/// Verify a replay JSON; with `request_json` (the MatchRequestV2 that produced it) the
+/// match is also re-simulated. Returns `ReplayVerdict` JSON.
+pub fn verify_replay_json(replay_json: &str, request_json: Option<&str>) -> Result<String, String> {
+    let doc: ReplayDoc =
+        serde_json::from_str(replay_json).map_err(|e| format!("Invalid replay JSON: {}", e))?;
+
+    let verdict = match request_json {
+        Some(request_json) => {
+            let request: serde_json::Value = serde_json::from_str(request_json)
+                .map_err(|e| format!("Invalid JSON request: {}", e))?;
+            verify_replay_against_request(&doc, &request)?
+        }
+        None => verify_replay(&doc)?,
+    };
+
+    serde_json::to_string(&verdict).map_err(|e| format!("Failed to serialize verdict: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::replay::recorder::ReplayRecorder;
+    use crate::replay::types::{EventBase, MeterPos};
+
+    fn sealed_doc(events: usize) -> ReplayDoc {
+        let pitch = PitchSpec { width_m: 105.0, height_m: 68.0 };
+        let mut recorder = ReplayRecorder::new(pitch, ReplayRosters::default());
+        for i in 0..events {
+            recorder.record_decision(
+                i as f64,
+                0,
+                (i % 11) as u32,
+                MeterPos { x: 50.0, y: 34.0 },
+                "pass".to_string(),
+                Some(0.1),
+            );
+        }
+        recorder.into_doc(1)
+    }
+
+    fn request_json(seed: u64) -> String {
+        let positions = [
+            "GK", "LB", "CB", "CB", "RB", "LM", "CM", "CM", "RM", "ST", "ST", "GK", "CB", "CM",
+            "CM", "LW", "RW", "ST",
+        ];
+        let team = |name: &str| {
+            let roster: Vec<_> = positions
+                .iter()
+                .map(|pos| serde_json::json!({"name": name, "position": pos, "overall": 70, "condition": 3}))
+                .collect();
+            serde_json::json!({"name": name, "formation": "4-4-2", "roster": roster})
+        };
+        serde_json::json!({"schema_version": 2, "seed": seed, "home_team": team("H"), "away_team": team("A")})
+            .to_string()
+    }
+
+    #[test]
+    fn test_sealed_replay_verifies_and_locates_tampered_chunk() {
+        let doc = sealed_doc(REPLAY_CHUNK_EVENTS * 2 + 10);
+        let integrity = doc.integrity.as_ref().expect("recorder seals the replay");
+        assert_eq!(integrity.chunk_hashes.len(), 3);
+        assert!(verify_replay(&doc).unwrap().valid);
+
+        let mut edited = doc.clone();
+        if let ReplayEvent::Decision { utility, .. } = &mut edited.events[REPLAY_CHUNK_EVENTS + 3] {
+            *utility = Some(0.9);
+        }
+        let verdict = verify_replay(&edited).unwrap();
+        assert!(!verdict.valid);
+        assert_eq!(verdict.tampered_chunks, vec![1]);
+
+        let mut truncated = doc.clone();
+        truncated.events.truncate(REPLAY_CHUNK_EVENTS);
+        assert_eq!(verify_replay(&truncated).unwrap().tampered_chunks, vec![1, 2]);
+
+        let mut renamed = doc.clone();
+        renamed.rosters.home.name = "Other".to_string();
+        assert_eq!(verify_replay(&renamed).unwrap().reason.as_deref(), Some("Match hash mismatch"));
+
+        let mut unsealed = doc;
+        unsealed.integrity = None;
+        assert!(!verify_replay(&unsealed).unwrap().valid);
+    }
+
+    #[test]
+    fn test_integrity_survives_json_round_trip_and_ignores_localized_text() {
+        let mut doc = sealed_doc(5);
+        doc.events.push(ReplayEvent::KickOff {
+            base: EventBase { t: 6.0, player_id: None, team_id: Some(1) },
+        });
+        seal_replay(&mut doc).unwrap();
+        doc.localized_text.push(crate::replay::LocalizedTextPack {
+            locale: "en".to_string(),
+            entries: Vec::new(),
+        });
+
+        let json = serde_json::to_string(&doc).unwrap();
+        let verdict: ReplayVerdict =
+            serde_json::from_str(&verify_replay_json(&json, None).unwrap()).unwrap();
+        assert!(verdict.valid, "{:?}", verdict.reason);
+        assert!(!verdict.resimulated);
+
+        assert!(verify_replay_json("not json", None).is_err());
+    }
+
+    #[test]
+    fn test_resimulation_confirms_original_and_rejects_other_seed() {
+        let request = request_json(31);
+        let (_, replay_json) = crate::api::simulate_match_v2_json_with_replay(&request).unwrap();
+
+        let verdict: ReplayVerdict =
+            serde_json::from_str(&verify_replay_json(&replay_json, Some(&request)).unwrap())
+                .unwrap();
+        assert!(verdict.valid, "{:?}", verdict.reason);
+        assert!(verdict.resimulated);
+
+        let other: ReplayVerdict = serde_json::from_str(
+            &verify_replay_json(&replay_json, Some(&request_json(32))).unwrap(),
+        )
+        .unwrap();
+        assert!(!other.valid);
+        assert!(other.resimulated);
+        assert!(other.first_divergent_tick.is_some());
+    }
+}
@@ -1,3 +1,4 @@
+use super::localized_text::{embed_localized_text, ReplaySaveOptions};
 use super::types::ReplayDoc;
 use std::fs;
 use std::path::Path;
@@ -9,6 +10,20 @@ pub fn save_replay_json<P: AsRef<Path>>(doc: &ReplayDoc, path: P) -> anyhow::Res
     Ok(())
 }
 
+/// 리플레이 JSON 저장 (옵션: 요청 언어의 주요 이벤트 텍스트 임베드)
+pub fn save_replay_json_with_options<P: AsRef<Path>>(
+    doc: &ReplayDoc,
+    path: P,
+    options: &ReplaySaveOptions,
+) -> anyhow::Result<()> {
+    if options.embed_locales.is_empty() {
+        return save_replay_json(doc, path);
+    }
+    let mut doc = doc.clone();
+    embed_localized_text(&mut doc, options).map_err(anyhow::Error::msg)?;
+    save_replay_json(&doc, path)
+}
+
 /// 리플레이 JSON 로드
 pub fn load_replay_json<P: AsRef<Path>>(path: P) -> anyhow::Result<ReplayDoc> {
     let data = fs::read_to_string(path)?;
@@ -38,6 +53,9 @@ mod tests {
             rosters: ReplayRosters::default(),
             timeline: Vec::new(),
             tactics: ReplayTeamsTactics::default(),
+            localized_text: Vec::new(),
+            determinism: None,
+            integrity: None,
         };
 
         // Save
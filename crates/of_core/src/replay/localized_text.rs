@@ -0,0 +1,295 @@
+//! 리플레이 현지화 텍스트 임베드
+//!
+//! 저장 시점에 주요 이벤트(골/카드/교체/PK/전후반 종료) 문자열을 요청한 언어로
+//! 미리 해석해 `ReplayDoc.localized_text`에 넣는다. 원본 콘텐츠 팩이 없는
+//! 클라이언트도 공유된 리플레이를 그대로 렌더링할 수 있다.
+//!
+//! 문자열 우선순위: 호출자가 넘긴 콘텐츠 팩 FTL → 내장 기본 FTL (en-US/ko-KR/ja-JP)
+
+use std::collections::HashMap;
+
+use fluent::{FluentArgs, FluentBundle, FluentResource};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use unic_langid::LanguageIdentifier;
+
+use super::types::{CardType, ReplayDoc, ReplayEvent, ReplayRosters};
+
+/// 한 언어로 해석된 주요 이벤트 텍스트 묶음
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
+pub struct LocalizedTextPack {
+    pub locale: String,
+    pub entries: Vec<LocalizedEventText>,
+}
+
+/// 이벤트 하나의 해석된 텍스트
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
+pub struct LocalizedEventText {
+    /// `ReplayDoc.events` 인덱스
+    pub event_index: u32,
+    /// 경과 시간(초)
+    pub t: f64,
+    /// 메시지 키 (예: `replay-goal`)
+    pub key: String,
+    pub text: String,
+}
+
+/// 리플레이 저장 옵션
+#[derive(Debug, Clone, Default)]
+pub struct ReplaySaveOptions {
+    /// 텍스트를 임베드할 언어 (비어 있으면 임베드하지 않음)
+    pub embed_locales: Vec<String>,
+    /// 언어별 콘텐츠 팩 FTL (기본 문자열보다 우선)
+    pub content_packs: HashMap<String, String>,
+}
+
+/// 현지화 대상 이벤트의 메시지 키 (대상이 아니면 None)
+pub fn replay_text_key(event: &ReplayEvent) -> Option<&'static str> {
+    match event {
+        ReplayEvent::Goal { .. } => Some("replay-goal"),
+        ReplayEvent::Card { card_type: CardType::Yellow, .. } => Some("replay-yellow-card"),
+        ReplayEvent::Card { card_type: CardType::Red, .. } => Some("replay-red-card"),
+        ReplayEvent::Substitution { .. } => Some("replay-substitution"),
+        ReplayEvent::Penalty { scored: true, .. } => Some("replay-penalty-scored"),
+        ReplayEvent::Penalty { scored: false, .. } => Some("replay-penalty-missed"),
+        ReplayEvent::HalfTime { .. } => Some("replay-half-time"),
+        ReplayEvent::FullTime { .. } => Some("replay-full-time"),
+        _ => None,
+    }
+}
+
+/// 요청한 언어들로 주요 이벤트 텍스트를 해석해 `doc.localized_text`를 채운다.
+///
+/// 같은 언어의 기존 묶음은 교체된다. 콘텐츠 팩도 기본 문자열도 없는 언어는 에러.
+pub fn embed_localized_text(
+    doc: &mut ReplayDoc,
+    options: &ReplaySaveOptions,
+) -> Result<(), String> {
+    for locale in &options.embed_locales {
+        let bundle = build_bundle(locale, options.content_packs.get(locale))?;
+        let entries = doc
+            .events
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, event)| {
+                let key = replay_text_key(event)?;
+                let text = format_event(&bundle, key, event, &doc.rosters)?;
+                Some(LocalizedEventText {
+                    event_index: idx as u32,
+                    t: event.base().t,
+                    key: key.to_string(),
+                    text,
+                })
+            })
+            .collect();
+
+        doc.localized_text.retain(|pack| &pack.locale != locale);
+        doc.localized_text.push(LocalizedTextPack { locale: locale.clone(), entries });
+    }
+    Ok(())
+}
+
+fn build_bundle(
+    locale: &str,
+    content_pack: Option<&String>,
+) -> Result<FluentBundle<FluentResource>, String> {
+    let lang_id: LanguageIdentifier =
+        locale.parse().map_err(|_| format!("Invalid locale: {}", locale))?;
+    let default_ftl = default_replay_ftl().get(locale).copied();
+    if content_pack.is_none() && default_ftl.is_none() {
+        return Err(format!("No replay text available for locale {}", locale));
+    }
+
+    let mut bundle = FluentBundle::new(vec![lang_id]);
+    // 공유 텍스트는 평문으로 렌더링되므로 bidi 격리 문자를 넣지 않는다.
+    bundle.set_use_isolating(false);
+
+    // 먼저 넣은 리소스가 우선한다 (중복 키는 무시됨).
+    for ftl in content_pack.map(String::as_str).into_iter().chain(default_ftl) {
+        let resource = FluentResource::try_new(ftl.to_string())
+            .map_err(|_| format!("Failed to parse replay FTL for {}", locale))?;
+        let _ = bundle.add_resource(resource);
+    }
+    Ok(bundle)
+}
+
+fn format_event(
+    bundle: &FluentBundle<FluentResource>,
+    key: &str,
+    event: &ReplayEvent,
+    rosters: &ReplayRosters,
+) -> Option<String> {
+    let pattern = bundle.get_message(key)?.value()?;
+    let base = event.base();
+
+    let mut args = FluentArgs::new();
+    args.set("minute", (base.t / 60.0).floor() as i64);
+    args.set("player", player_name(rosters, base.player_id));
+    args.set("team", team_name(rosters, base.team_id));
+    if let ReplayEvent::Substitution { in_player_id, .. } = event {
+        args.set("player_in", player_name(rosters, *in_player_id));
+    }
+
+    let mut errors = vec![];
+    Some(bundle.format_pattern(pattern, Some(&args), &mut errors).to_string())
+}
+
+/// track_id(0-21) → 로스터 이름 (없으면 `#id`)
+fn player_name(rosters: &ReplayRosters, track_id: Option<u32>) -> String {
+    let Some(track_id) = track_id else {
+        return String::new();
+    };
+    let (roster, local_id) =
+        if track_id < 11 { (&rosters.home, track_id) } else { (&rosters.away, track_id - 11) };
+    roster
+        .players
+        .iter()
+        .find(|p| p.id == local_id)
+        .map(|p| p.name.clone())
+        .unwrap_or_else(|| format!("#{}", track_id))
+}
+
+fn team_name(rosters: &ReplayRosters, team_id: Option<u32>) -> String {
+    match team_id {
+        Some(0) => rosters.home.name.clone(),
+        Some(1) => rosters.away.name.clone(),
+        _ => String::new(),
+    }
+}
+
+/// 내장 기본 FTL (콘텐츠 팩이 없을 때)
+pub fn default_replay_ftl() -> HashMap<&'static str, &'static str> {
+    let mut ftl = HashMap::new();
+
+    ftl.insert(
+        "en-US",
+        r#"
+replay-goal = { $minute }' GOAL! { $player } ({ $team })
+replay-yellow-card = { $minute }' Yellow card: { $player } ({ $team })
+replay-red-card = { $minute }' Red card: { $player } ({ $team })
+replay-substitution = { $minute }' Substitution ({ $team }): { $player_in } replaces { $player }
+replay-penalty-scored = { $minute }' Penalty scored by { $player } ({ $team })
+replay-penalty-missed = { $minute }' Penalty missed by { $player } ({ $team })
+replay-half-time = Half-time
+replay-full-time = Full-time
+"#,
+    );
+
+    ftl.insert(
+        "ko-KR",
+        r#"
+replay-goal = { $minute }' 골! { $player } ({ $team })
+replay-yellow-card = { $minute }' 경고: { $player } ({ $team })
+replay-red-card = { $minute }' 퇴장: { $player } ({ $team })
+replay-substitution = { $minute }' 교체 ({ $team }): { $player } → { $player_in }
+replay-penalty-scored = { $minute }' PK 성공: { $player } ({ $team })
+replay-penalty-missed = { $minute }' PK 실패: { $player } ({ $team })
+replay-half-time = 전반 종료
+replay-full-time = 경기 종료
+"#,
+    );
+
+    ftl.insert(
+        "ja-JP",
+        r#"
+replay-goal = { $minute }' ゴール！ { $player } ({ $team })
+replay-yellow-card = { $minute }' 警告: { $player } ({ $team })
+replay-red-card = { $minute }' 退場: { $player } ({ $team })
+replay-substitution = { $minute }' 交代 ({ $team }): { $player } → { $player_in }
+replay-penalty-scored = { $minute }' PK成功: { $player } ({ $team })
+replay-penalty-missed = { $minute }' PK失敗: { $player } ({ $team })
+replay-half-time = 前半終了
+replay-full-time = 試合終了
+"#,
+    );
+
+    ftl
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::replay::{EventBase, MeterPos, PitchSpec, ReplayPlayer, ReplayRoster};
+
+    fn sample_doc() -> ReplayDoc {
+        let player = |id: u32, name: &str| ReplayPlayer {
+            id,
+            name: name.to_string(),
+            position: "ST".to_string(),
+            ca: 100,
+            condition: 1.0,
+            appearance: None,
+        };
+        ReplayDoc {
+            version: 1,
+            pitch_m: PitchSpec { width_m: 105.0, height_m: 68.0 },
+            events: vec![
+                ReplayEvent::KickOff {
+                    base: EventBase { t: 0.0, player_id: None, team_id: Some(0) },
+                },
+                ReplayEvent::Goal {
+                    base: EventBase { t: 1_230.0, player_id: Some(20), team_id: Some(1) },
+                    at: MeterPos { x: 5.0, y: 34.0 },
+                    assist_player_id: None,
+                },
+                ReplayEvent::FullTime {
+                    base: EventBase { t: 5_400.0, player_id: None, team_id: None },
+                },
+            ],
+            rosters: ReplayRosters {
+                home: ReplayRoster { name: "Home FC".to_string(), players: vec![] },
+                away: ReplayRoster {
+                    name: "Away United".to_string(),
+                    players: vec![player(9, "Kim")],
+                },
+            },
+            timeline: Vec::new(),
+            tactics: Default::default(),
+            localized_text: Vec::new(),
+            determinism: None,
+            integrity: None,
+        }
+    }
+
+    #[test]
+    fn test_embed_resolves_key_events_per_locale() {
+        let mut doc = sample_doc();
+        let options = ReplaySaveOptions {
+            embed_locales: vec!["en-US".to_string(), "ko-KR".to_string()],
+            ..Default::default()
+        };
+        embed_localized_text(&mut doc, &options).unwrap();
+
+        assert_eq!(doc.localized_text.len(), 2);
+        let en = &doc.localized_text[0];
+        assert_eq!(en.entries.len(), 2, "kick-off is not a key event");
+        assert_eq!(en.entries[0].event_index, 1);
+        assert_eq!(en.entries[0].text, "20' GOAL! Kim (Away United)");
+        assert_eq!(doc.localized_text[1].entries[1].text, "경기 종료");
+
+        // Re-embedding replaces the locale's pack instead of duplicating it.
+        embed_localized_text(&mut doc, &options).unwrap();
+        assert_eq!(doc.localized_text.len(), 2);
+
+        let json = serde_json::to_string(&doc).unwrap();
+        let loaded: ReplayDoc = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded, doc);
+    }
+
+    #[test]
+    fn test_content_pack_overrides_defaults_and_unknown_locale_errors() {
+        let mut doc = sample_doc();
+        let mut options =
+            ReplaySaveOptions { embed_locales: vec!["en-US".to_string()], ..Default::default() };
+        options
+            .content_packs
+            .insert("en-US".to_string(), "replay-goal = Goal for { $team }!\n".to_string());
+        embed_localized_text(&mut doc, &options).unwrap();
+        let entries = &doc.localized_text[0].entries;
+        assert_eq!(entries[0].text, "Goal for Away United!");
+        assert_eq!(entries[1].text, "Full-time", "missing keys fall back to defaults");
+
+        options.embed_locales = vec!["fr-FR".to_string()];
+        assert!(embed_localized_text(&mut doc, &options).is_err());
+    }
+}
@@ -1,10 +1,17 @@
+pub mod binary;
+mod binary_delta; // MRB0 v5 frame chunk codec
+pub mod binary_reader;
 pub mod clip_reducer;
 pub mod controller;
 pub mod converter;
+pub mod diff;
 pub mod export;
 pub mod format_v2; // FIX_2512 Phase 2: Replay v2 Format
 pub mod gen;
+pub mod highlight_editor;
+pub mod integrity;
 pub mod io;
+pub mod localized_text;
 pub mod position_tracker;
 pub mod reader_v2; // FIX_2512 Phase 3: Replay v2 Reader
 pub mod recorder;
@@ -14,11 +21,17 @@ pub mod validate;
 pub mod writer_v2; // FIX_2512 Phase 3: Replay v2 Writer
 
 // Re-export main types for convenience
+pub use binary::*;
+pub use binary_reader::*;
 pub use clip_reducer::*;
 pub use controller::*;
 pub use converter::*;
+pub use diff::*;
 pub use format_v2::*; // FIX_2512 Phase 2
+pub use highlight_editor::*;
+pub use integrity::*;
 pub use io::*;
+pub use localized_text::*;
 pub use position_tracker::*;
 pub use reader_v2::*; // FIX_2512 Phase 3
 pub use recorder::*;
@@ -4,9 +4,10 @@
 //! tick-based simulation engine into ReplayEvent types for replay visualization.
 
 use super::types::*;
+use crate::models::weather::MatchConditions;
 
 /// Bridge between tick_based engine and ReplayEvent/ReplayDoc
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ReplayRecorder {
     /// Pitch dimensions
     pub pitch: PitchSpec,
@@ -18,6 +19,8 @@ pub struct ReplayRecorder {
     pub rosters: ReplayRosters,
     /// Team tactics info
     pub tactics: ReplayTeamsTactics,
+    /// Per-tick determinism checksums
+    pub tick_checksums: Vec<TickChecksum>,
 }
 
 impl Default for ReplayRecorder {
@@ -28,6 +31,7 @@ impl Default for ReplayRecorder {
             timeline: Vec::new(),
             rosters: ReplayRosters::default(),
             tactics: ReplayTeamsTactics::default(),
+            tick_checksums: Vec::new(),
         }
     }
 }
@@ -41,6 +45,7 @@ impl ReplayRecorder {
             timeline: Vec::with_capacity(50),
             rosters,
             tactics: ReplayTeamsTactics::default(),
+            tick_checksums: Vec::with_capacity(22_000), // 90 min × 240 ticks + stoppage
         }
     }
 
@@ -401,6 +406,31 @@ impl ReplayRecorder {
         });
     }
 
+    /// Record the added-time announcement for `half` (1 or 2)
+    pub fn record_added_time(&mut self, t_seconds: f64, half: u8, minutes: u8) {
+        self.events.push(ReplayEvent::AddedTime {
+            base: self.make_base(t_seconds, None, None),
+            half,
+            minutes,
+        });
+        self.timeline.push(ReplayTimelineEntry {
+            t: t_seconds,
+            label: format!("ADDED TIME +{}", minutes),
+            team_id: None,
+            player_id: None,
+        });
+    }
+
+    /// Record match weather / pitch condition (once, at kickoff)
+    pub fn record_weather(&mut self, t_seconds: f64, conditions: &MatchConditions) {
+        self.events.push(ReplayEvent::Weather {
+            base: self.make_base(t_seconds, None, None),
+            weather: conditions.weather.kind,
+            intensity: conditions.weather.intensity,
+            pitch_quality: conditions.pitch_quality,
+        });
+    }
+
     /// Record offside event
     pub fn record_offside(&mut self, t_seconds: f64, team_id: u32, player_id: u32, at: MeterPos) {
         self.events.push(ReplayEvent::Offside {
@@ -480,20 +510,36 @@ impl ReplayRecorder {
         });
     }
 
+    /// Record the engine state checksum at the end of a tick
+    pub fn record_tick_checksum(&mut self, tick: u64, checksum: u32) {
+        self.tick_checksums.push(TickChecksum(tick as u32, checksum));
+    }
+
     // ========================================
     // Conversion Methods
     // ========================================
 
     /// Convert to ReplayDoc (consuming self)
     pub fn into_doc(self, version: u32) -> ReplayDoc {
-        ReplayDoc {
+        let determinism = (!self.tick_checksums.is_empty()).then(|| ReplayDeterminism {
+            float_math: crate::models::FloatMathMode::current(),
+            platform: crate::engine::strict_math::platform_tag().to_string(),
+            checksums: self.tick_checksums,
+        });
+        let mut doc = ReplayDoc {
             pitch_m: self.pitch,
             events: self.events,
             version,
             rosters: self.rosters,
             timeline: self.timeline,
             tactics: self.tactics,
-        }
+            localized_text: Vec::new(),
+            determinism,
+            integrity: None,
+        };
+        // Serializing replay types cannot fail; an unsealed doc just fails verification.
+        let _ = super::integrity::seal_replay(&mut doc);
+        doc
     }
 
     /// Get event count
@@ -587,6 +633,36 @@ mod tests {
         assert_eq!(doc.pitch_m.width_m, 105.0);
     }
 
+    #[test]
+    fn test_tick_checksums_become_determinism_trail() {
+        let pitch = PitchSpec { width_m: 105.0, height_m: 68.0 };
+        assert!(ReplayRecorder::new(pitch.clone(), ReplayRosters::default())
+            .into_doc(1)
+            .determinism
+            .is_none());
+
+        let record = |checksums: &[u32]| {
+            let mut recorder = ReplayRecorder::new(pitch.clone(), ReplayRosters::default());
+            for (tick, &checksum) in checksums.iter().enumerate() {
+                recorder.record_tick_checksum(tick as u64, checksum);
+            }
+            recorder.into_doc(1).determinism.expect("determinism trail")
+        };
+
+        let base = record(&[7, 8, 9, 10]);
+        assert_eq!(base.checksums[2], TickChecksum(2, 9));
+        assert_eq!(base.float_math, crate::models::FloatMathMode::current());
+        assert!(!base.platform.is_empty());
+
+        assert_eq!(base.first_divergence(&record(&[7, 8, 9, 10])), None);
+        assert_eq!(base.first_divergence(&record(&[7, 8, 1, 10])), Some(2));
+        assert_eq!(base.first_divergence(&record(&[7, 8, 9])), Some(3));
+        assert_eq!(record(&[7]).first_divergence(&base), Some(1));
+
+        let json = serde_json::to_value(&base).unwrap();
+        assert_eq!(json["checksums"][1], serde_json::json!([1, 8]));
+    }
+
     #[test]
     fn test_tackle_recording() {
         let pitch = PitchSpec { width_m: 105.0, height_m: 68.0 };
@@ -625,4 +701,15 @@ mod tests {
         assert!(recorder.timeline.iter().any(|e| e.label == "YELLOW CARD"));
         assert!(recorder.timeline.iter().any(|e| e.label == "RED CARD"));
     }
+
+    #[test]
+    fn test_added_time_recording() {
+        let pitch = PitchSpec { width_m: 105.0, height_m: 68.0 };
+        let mut recorder = ReplayRecorder::new(pitch, ReplayRosters::default());
+
+        recorder.record_added_time(45.0 * 60.0, 1, 2);
+
+        assert!(matches!(recorder.events[0], ReplayEvent::AddedTime { half: 1, minutes: 2, .. }));
+        assert_eq!(recorder.timeline[0].label, "ADDED TIME +2");
+    }
 }
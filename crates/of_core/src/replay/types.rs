@@ -1,21 +1,24 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::models::weather::{PitchQuality, WeatherKind};
+
 /// 축구장 좌표(미터) - FIFA 105x68 기준
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, JsonSchema)]
 pub struct MeterPos {
     pub x: f64, // 0.0..=105.0 권장
     pub y: f64, // 0.0..=68.0 권장
 }
 
 /// 필드 상의 방향/벡터 (단위: 미터 비율)
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, JsonSchema)]
 pub struct FieldVector {
     pub x: f64,
     pub y: f64,
 }
 
 /// 공통 이벤트 메타
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
 pub struct EventBase {
     /// 경과 시간(초)
     pub t: f64,
@@ -28,7 +31,7 @@ pub struct EventBase {
 }
 
 /// 카드 타입
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
 pub enum CardType {
     Yellow,
     Red,
@@ -39,7 +42,7 @@ fn default_false() -> bool {
 }
 
 /// 이벤트 타입 - 포괄적인 축구 경기 이벤트
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
 #[serde(tag = "kind", rename_all = "snake_case")]
 pub enum ReplayEvent {
     // 기본 경기장 이벤트
@@ -236,6 +239,20 @@ pub enum ReplayEvent {
     FullTime {
         base: EventBase,
     },
+    /// 추가시간 발표 (전반/후반 종료 시 1회)
+    AddedTime {
+        base: EventBase,
+        /// 1 = 전반, 2 = 후반
+        half: u8,
+        minutes: u8,
+    },
+    /// 경기 날씨/피치 상태 (킥오프 시 1회)
+    Weather {
+        base: EventBase,
+        weather: WeatherKind,
+        intensity: f32,
+        pitch_quality: PitchQuality,
+    },
     Offside {
         base: EventBase,
         at: MeterPos,
@@ -350,7 +367,7 @@ pub enum ReplayEvent {
 }
 
 /// How the possession was gained or lost (0108: Open-Football Integration)
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum PossessionChangeType {
     // Gaining possession
@@ -386,7 +403,7 @@ pub enum PossessionChangeType {
     Restart,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum PassOutcome {
     Complete,
@@ -394,7 +411,7 @@ pub enum PassOutcome {
     Out,
 }
 /// ���÷��� ���� ��Ʈ
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
 pub struct ReplayDoc {
     /// FIFA �԰�: width=105, height=68 (����)
     pub pitch_m: PitchSpec,
@@ -411,28 +428,68 @@ pub struct ReplayDoc {
     /// 팀 전술 정보 (홈/원정)
     #[serde(default)]
     pub tactics: ReplayTeamsTactics,
+    /// 저장 시 임베드된 주요 이벤트 현지화 텍스트 (콘텐츠 팩 없는 클라이언트용)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub localized_text: Vec<super::localized_text::LocalizedTextPack>,
+    /// 틱별 상태 체크섬 (크로스 플랫폼 결정성 검증용)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub determinism: Option<ReplayDeterminism>,
+    /// 청크 체크섬 + 최종 경기 해시 (변조 검증용)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integrity: Option<super::integrity::ReplayIntegrity>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+/// One tick's state checksum, serialized as `[tick, checksum]`.
+///
+/// Both halves are u32 so they survive JSON parsers that read numbers as f64 (Godot, JS).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+pub struct TickChecksum(pub u32, pub u32);
+
+/// Determinism trail recorded alongside the events: one checksum per simulated tick.
+///
+/// Replays of the same request agree on every checksum when the builds are bit-identical
+/// (same target, or `strict_determinism` everywhere); `first_divergence` finds where two
+/// runs drifted apart (network sync desync reports, cross-platform replay checks).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default, JsonSchema)]
+pub struct ReplayDeterminism {
+    pub float_math: crate::models::FloatMathMode,
+    /// Target the replay was recorded on (`x86_64`, `aarch64`, `wasm32`, ...)
+    pub platform: String,
+    pub checksums: Vec<TickChecksum>,
+}
+
+impl ReplayDeterminism {
+    /// First tick whose checksum differs (or that only one side recorded); `None` = identical
+    pub fn first_divergence(&self, other: &ReplayDeterminism) -> Option<u32> {
+        let mismatch = self.checksums.iter().zip(&other.checksums).find(|(a, b)| a != b);
+        if let Some((a, b)) = mismatch {
+            return Some(a.0.min(b.0));
+        }
+        let shorter = self.checksums.len().min(other.checksums.len());
+        self.checksums.get(shorter).or_else(|| other.checksums.get(shorter)).map(|c| c.0)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
 pub struct PitchSpec {
     pub width_m: f64,  // ���� 105.0
     pub height_m: f64, // ���� 68.0
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default, JsonSchema)]
 pub struct ReplayRosters {
     pub home: ReplayRoster,
     pub away: ReplayRoster,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default, JsonSchema)]
 pub struct ReplayRoster {
     pub name: String,
     #[serde(default)]
     pub players: Vec<ReplayPlayer>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default, JsonSchema)]
 pub struct ReplayPlayer {
     pub id: u32,
     pub name: String,
@@ -445,7 +502,7 @@ pub struct ReplayPlayer {
 }
 
 /// Player appearance data for replay visualization (kit colors, pattern)
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default, JsonSchema)]
 pub struct PlayerAppearanceData {
     /// Hair color type: "black", "blonde", "redhead", "other"
     pub hair_color: String,
@@ -457,7 +514,7 @@ pub struct PlayerAppearanceData {
     pub kit_pattern: u8,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default, JsonSchema)]
 pub struct ReplayTimelineEntry {
     pub t: f64,
     pub label: String,
@@ -468,7 +525,7 @@ pub struct ReplayTimelineEntry {
 }
 
 /// 팀 전술 정보 (포메이션/스타일)
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default, JsonSchema)]
 pub struct ReplayTeamsTactics {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub home: Option<ReplayTeamTactics>,
@@ -476,7 +533,7 @@ pub struct ReplayTeamsTactics {
     pub away: Option<ReplayTeamTactics>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
 pub struct ReplayTeamTactics {
     /// 전술 타입 (예: "T442", "T433" 등)
     pub tactic_type: String,
@@ -538,6 +595,8 @@ impl ReplayEvent {
             | ReplayEvent::Substitution { base, .. }
             | ReplayEvent::HalfTime { base }
             | ReplayEvent::FullTime { base }
+            | ReplayEvent::AddedTime { base, .. }
+            | ReplayEvent::Weather { base, .. }
             | ReplayEvent::Offside { base, .. }
             | ReplayEvent::Save { base, .. }
             | ReplayEvent::Throw { base, .. }
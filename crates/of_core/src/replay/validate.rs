@@ -1,5 +1,8 @@
 use super::types::*;
 
+/// Most stoppage time the engine announces per half (minutes)
+const MAX_ADDED_MINUTES: u8 = 8;
+
 /// Validates a replay document for consistency and correctness
 pub fn validate_replay(doc: &ReplayDoc) -> Result<(), String> {
     if doc.version == 0 {
@@ -56,6 +59,79 @@ pub fn validate_replay(doc: &ReplayDoc) -> Result<(), String> {
         }
     }
 
+    validate_added_time(doc)?;
+
+    Ok(())
+}
+
+/// Added-time announcements: at most one per half, in order, within the cap,
+/// and the half/full-time whistles must not run past the announced time.
+fn validate_added_time(doc: &ReplayDoc) -> Result<(), String> {
+    let mut announced: [Option<u8>; 2] = [None, None];
+    let mut half_time_seen = false;
+
+    for (i, event) in doc.events.iter().enumerate() {
+        match event {
+            ReplayEvent::AddedTime { half, minutes, .. } => {
+                if !(1..=2).contains(half) {
+                    return Err(format!(
+                        "Event {} announces added time for invalid half {}",
+                        i, half
+                    ));
+                }
+                if *minutes > MAX_ADDED_MINUTES {
+                    return Err(format!(
+                        "Event {} announces {} added minutes (max {})",
+                        i, minutes, MAX_ADDED_MINUTES
+                    ));
+                }
+                let idx = *half as usize - 1;
+                if announced[idx].is_some() {
+                    return Err(format!("Event {} repeats the half-{} added time", i, half));
+                }
+                let in_order = if *half == 1 {
+                    !half_time_seen
+                } else {
+                    half_time_seen || announced[0].is_some()
+                };
+                if !in_order {
+                    return Err(format!(
+                        "Event {} announces half-{} added time out of order",
+                        i, half
+                    ));
+                }
+                announced[idx] = Some(*minutes);
+            }
+            ReplayEvent::HalfTime { base } => {
+                half_time_seen = true;
+                if let Some(added) = announced[0] {
+                    let limit = (45 + added as u32) as f64 * 60.0;
+                    if base.t > limit {
+                        return Err(format!(
+                            "Half-time at {}s runs past 45+{} ({}s)",
+                            base.t, added, limit
+                        ));
+                    }
+                }
+            }
+            ReplayEvent::FullTime { base } => {
+                if let (Some(first), Some(second)) = (announced[0], announced[1]) {
+                    let limit = (90 + first as u32 + second as u32) as f64 * 60.0;
+                    // Engine minutes are 0-based: the last added minute ends one minute later.
+                    if base.t > limit + 60.0 {
+                        return Err(format!(
+                            "Full-time at {}s runs past 90+{} ({}s)",
+                            base.t,
+                            second,
+                            limit + 60.0
+                        ));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
     Ok(())
 }
 
@@ -111,6 +187,9 @@ mod tests {
             rosters: ReplayRosters::default(),
             timeline: Vec::new(),
             tactics: ReplayTeamsTactics::default(),
+            localized_text: Vec::new(),
+            determinism: None,
+            integrity: None,
         }
     }
 
@@ -166,4 +245,48 @@ mod tests {
         });
         assert!(validate_replay(&replay).is_err());
     }
+
+    #[test]
+    fn test_added_time_validation() {
+        let announce = |t: f64, half: u8, minutes: u8| ReplayEvent::AddedTime {
+            base: EventBase { t, player_id: None, team_id: None },
+            half,
+            minutes,
+        };
+        let whistle = |t: f64, full: bool| {
+            let base = EventBase { t, player_id: None, team_id: None };
+            if full {
+                ReplayEvent::FullTime { base }
+            } else {
+                ReplayEvent::HalfTime { base }
+            }
+        };
+
+        let mut replay = create_test_replay();
+        replay.events.extend([
+            announce(2700.0, 1, 2),
+            whistle(2820.0, false),
+            announce(5520.0, 2, 4),
+            whistle(5820.0, true),
+        ]);
+        assert!(validate_replay(&replay).is_ok());
+
+        // Half-time after the announced 45+2
+        let mut late = create_test_replay();
+        late.events.extend([announce(2700.0, 1, 2), whistle(2900.0, false)]);
+        assert!(validate_replay(&late).is_err());
+
+        // Second-half board before the first half is over
+        let mut early = create_test_replay();
+        early.events.push(announce(2700.0, 2, 3));
+        assert!(validate_replay(&early).is_err());
+
+        let mut capped = create_test_replay();
+        capped.events.push(announce(2700.0, 1, MAX_ADDED_MINUTES + 1));
+        assert!(validate_replay(&capped).is_err());
+
+        let mut repeated = create_test_replay();
+        repeated.events.extend([announce(2700.0, 1, 1), announce(2710.0, 1, 2)]);
+        assert!(validate_replay(&repeated).is_err());
+    }
 }
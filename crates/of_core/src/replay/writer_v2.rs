@@ -8,6 +8,7 @@ use crate::replay::format_v2::*;
 ///
 /// MatchEngine에서 50ms 틱마다 엔티티 상태를 기록하여
 /// ReplayV2 포맷으로 저장합니다.
+#[derive(Clone)]
 pub struct ReplayWriterV2 {
     /// 메타데이터
     meta: ReplayMetaV2,
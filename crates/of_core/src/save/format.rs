@@ -1,6 +1,8 @@
 use super::error::SaveError;
 use super::SAVE_VERSION;
+use crate::career::{FixtureCalendar, RatingLadder};
 use crate::coach::{CardInventory, Deck};
+use crate::models::stadium::StadiumRegistry;
 use crate::player::types::CorePlayer;
 use crate::quest::QuestManagerState;
 use crate::training::session::TrainingManager;
@@ -50,6 +52,18 @@ pub struct GameSave {
     /// Avatar appearance configuration (kit colors, pattern, etc.)
     #[serde(default)]
     pub player_appearance: Option<PlayerAppearance>,
+
+    /// Elo rating ladder (teams + players)
+    #[serde(default)]
+    pub rating_ladder: RatingLadder,
+
+    /// Career fixture calendar (dates + played lineups)
+    #[serde(default)]
+    pub fixture_calendar: FixtureCalendar,
+
+    /// Team home stadiums
+    #[serde(default)]
+    pub stadium_registry: StadiumRegistry,
 }
 
 impl Default for GameSave {
@@ -73,6 +87,9 @@ impl GameSave {
             game_settings: GameSettings::default(),
             quest_manager: QuestManagerState::default(),
             player_appearance: None,
+            rating_ladder: RatingLadder::new(),
+            fixture_calendar: FixtureCalendar::new(),
+            stadium_registry: StadiumRegistry::new(),
         }
     }
 
@@ -112,6 +129,12 @@ pub struct MatchRecord {
     pub date: u64, // timestamp
     pub week: u16,
     pub season: u16,
+    /// Our lineup minutes (feeds squad chemistry)
+    #[serde(default)]
+    pub appearances: Vec<crate::models::chemistry::MatchAppearance>,
+    /// Squad player moments with replay clip references (career montage)
+    #[serde(default)]
+    pub highlights: Vec<crate::career::CareerHighlight>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
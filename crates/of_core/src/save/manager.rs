@@ -1,9 +1,10 @@
 use super::error::SaveError;
 use super::format::{decompress_and_deserialize, serialize_and_compress, GameSave};
 use super::migration::migrate_save;
+use super::retention::{plan_retention, summarize_matches, RetentionPolicy, RetentionReport};
 
 use once_cell::sync::Lazy;
-use std::fs::{remove_file, rename, File};
+use std::fs::{read_dir, remove_file, rename, File};
 use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
 use std::sync::Mutex;
@@ -167,8 +168,103 @@ impl SaveManager {
         slots
     }
 
+    /// Store a match replay next to the saves, keyed by `MatchRecord.id`
+    pub fn save_replay(match_id: u32, data: &[u8]) -> Result<(), SaveError> {
+        let path = Self::get_replay_path(&Self::get_replay_dir(), match_id);
+        Self::write_atomic(&path, data)?;
+
+        log::debug!("Stored replay for match {} ({} bytes)", match_id, data.len());
+        Ok(())
+    }
+
+    /// Load a stored match replay
+    pub fn load_replay(match_id: u32) -> Result<Vec<u8>, SaveError> {
+        let path = Self::get_replay_path(&Self::get_replay_dir(), match_id);
+        if !path.exists() {
+            return Err(SaveError::FileNotFound { path: path.display().to_string() });
+        }
+
+        let mut data = Vec::new();
+        File::open(&path)?.read_to_end(&mut data)?;
+        Ok(data)
+    }
+
+    /// Apply a retention policy to the live match history and stored replays
+    ///
+    /// With `dry_run` nothing is changed; the report lists what would be pruned.
+    pub fn apply_retention(
+        policy: &RetentionPolicy,
+        dry_run: bool,
+    ) -> Result<RetentionReport, SaveError> {
+        use crate::state::GAME_STATE;
+
+        let mut state = GAME_STATE.write().unwrap();
+        let report = Self::apply_retention_in(
+            &Self::get_replay_dir(),
+            &mut state.match_history,
+            policy,
+            dry_run,
+        )?;
+
+        if !dry_run {
+            if let Some(current) = CURRENT_GAME_STATE.lock().unwrap().as_mut() {
+                summarize_matches(&mut current.match_history, &report);
+            }
+        }
+        Ok(report)
+    }
+
     // Private helper methods
 
+    fn apply_retention_in(
+        replay_dir: &Path,
+        history: &mut [super::format::MatchRecord],
+        policy: &RetentionPolicy,
+        dry_run: bool,
+    ) -> Result<RetentionReport, SaveError> {
+        let replays = Self::list_replays(replay_dir)?;
+        let mut report = plan_retention(history, &replays, policy);
+        if dry_run {
+            return Ok(report);
+        }
+
+        for &match_id in &report.deleted_replays {
+            remove_file(Self::get_replay_path(replay_dir, match_id))?;
+        }
+        summarize_matches(history, &report);
+        report.dry_run = false;
+
+        log::info!(
+            "Retention: summarized {} matches, deleted {} replays ({} bytes)",
+            report.summarized_matches.len(),
+            report.deleted_replays.len(),
+            report.bytes_freed
+        );
+        Ok(report)
+    }
+
+    /// Stored replays as (match id, size in bytes)
+    fn list_replays(replay_dir: &Path) -> Result<Vec<(u32, u64)>, SaveError> {
+        if !replay_dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut replays = Vec::new();
+        for entry in read_dir(replay_dir)? {
+            let entry = entry?;
+            let name = entry.file_name();
+            let match_id = name
+                .to_str()
+                .and_then(|n| n.strip_prefix("match_"))
+                .and_then(|n| n.strip_suffix(".replay"))
+                .and_then(|id| id.parse::<u32>().ok());
+            if let Some(match_id) = match_id {
+                replays.push((match_id, entry.metadata()?.len()));
+            }
+        }
+        Ok(replays)
+    }
+
     fn validate_slot(slot: u8) -> Result<(), SaveError> {
         if slot >= 3 {
             return Err(SaveError::InvalidSlot { slot: slot as i64 });
@@ -184,6 +280,14 @@ impl SaveManager {
         Self::get_save_dir().join("auto_save.dat")
     }
 
+    fn get_replay_dir() -> PathBuf {
+        Self::get_save_dir().join("replays")
+    }
+
+    fn get_replay_path(replay_dir: &Path, match_id: u32) -> PathBuf {
+        replay_dir.join(format!("match_{}.replay", match_id))
+    }
+
     fn get_save_dir() -> PathBuf {
         // In real implementation, this would use Godot's user:// path
         // For now, use a local directory
@@ -191,20 +295,26 @@ impl SaveManager {
     }
 
     fn save_to_path(path: &Path, save: &GameSave) -> Result<(), SaveError> {
-        // Ensure save directory exists
+        // Serialize and compress
+        let data = serialize_and_compress(save)?;
+        Self::write_atomic(path, &data)?;
+
+        log::debug!("Saved {} bytes to {:?}", data.len(), path);
+        Ok(())
+    }
+
+    fn write_atomic(path: &Path, data: &[u8]) -> Result<(), SaveError> {
+        // Ensure target directory exists
         if let Some(parent) = path.parent() {
             std::fs::create_dir_all(parent)?;
         }
 
-        // Serialize and compress
-        let data = serialize_and_compress(save)?;
-
         // Atomic save: write to temp file, then rename
         let temp_path = path.with_extension("tmp");
 
         {
             let mut file = File::create(&temp_path)?;
-            file.write_all(&data)?;
+            file.write_all(data)?;
             file.flush()?;
 
             // sync_all ensures data is written to disk (portable fsync)
@@ -213,8 +323,6 @@ impl SaveManager {
 
         // Atomic rename
         rename(&temp_path, path)?;
-
-        log::debug!("Saved {} bytes to {:?}", data.len(), path);
         Ok(())
     }
 
@@ -309,6 +417,34 @@ mod tests {
         assert!(!temp_path.exists());
     }
 
+    #[test]
+    fn test_retention_dry_run_then_prune() {
+        use crate::save::format::MatchRecord;
+
+        let temp_dir = TempDir::new().unwrap();
+        let replay_dir = temp_dir.path().join("replays");
+        for match_id in 1..=3 {
+            let path = SaveManager::get_replay_path(&replay_dir, match_id);
+            SaveManager::write_atomic(&path, &[0u8; 64]).unwrap();
+        }
+        let mut history: Vec<MatchRecord> =
+            (1..=3).map(|id| MatchRecord { id, ..MatchRecord::default() }).collect();
+        let policy = RetentionPolicy { keep_full_replays: 1, keep_detailed_matches: 3 };
+
+        let report =
+            SaveManager::apply_retention_in(&replay_dir, &mut history, &policy, true).unwrap();
+        assert!(report.dry_run);
+        assert_eq!(report.deleted_replays, vec![1, 2]);
+        assert_eq!(report.bytes_freed, 128);
+        assert!(SaveManager::get_replay_path(&replay_dir, 1).exists());
+
+        let report =
+            SaveManager::apply_retention_in(&replay_dir, &mut history, &policy, false).unwrap();
+        assert!(!report.dry_run);
+        assert!(!SaveManager::get_replay_path(&replay_dir, 1).exists());
+        assert_eq!(SaveManager::list_replays(&replay_dir).unwrap(), vec![(3, 64)]);
+    }
+
     #[test]
     fn test_slot_validation() {
         assert!(SaveManager::validate_slot(0).is_ok());
@@ -5,6 +5,7 @@ pub mod error;
 pub mod format;
 pub mod manager;
 pub mod migration;
+pub mod retention;
 
 pub use error::SaveError;
 pub use format::{
@@ -13,6 +14,7 @@ pub use format::{
 };
 pub use manager::SaveManager;
 pub use migration::migrate_save;
+pub use retention::{plan_retention, summarize_matches, RetentionPolicy, RetentionReport};
 
 pub const SAVE_VERSION: u32 = 1;
 pub const SETTINGS_VERSION: u32 = 1;
@@ -0,0 +1,120 @@
+// Match history / replay retention
+// Keeps long career saves bounded: only the most recent matches keep their full
+// replay file and per-match detail (lineup minutes, highlight clips). Older
+// records are summarized in place, so the result, score, date and stats stay intact.
+
+use serde::{Deserialize, Serialize};
+
+use super::format::MatchRecord;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RetentionPolicy {
+    /// Most recent matches whose replay file is kept
+    pub keep_full_replays: usize,
+    /// Most recent matches that keep appearances and highlights
+    pub keep_detailed_matches: usize,
+}
+
+impl Default for RetentionPolicy {
+    fn default() -> Self {
+        Self { keep_full_replays: 20, keep_detailed_matches: 100 }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RetentionReport {
+    /// True if nothing was changed
+    pub dry_run: bool,
+    /// Records summarized by this run (match ids)
+    pub summarized_matches: Vec<u32>,
+    pub appearances_dropped: usize,
+    pub highlights_dropped: usize,
+    /// Replay files removed by this run (match ids)
+    pub deleted_replays: Vec<u32>,
+    /// Bytes of the removed replay files
+    pub bytes_freed: u64,
+}
+
+/// Work a policy would do on `history` (recorded in play order)
+///
+/// `replays` lists the stored replay files as (match id, size in bytes).
+/// Replays of matches no longer in the history are removed as well.
+pub fn plan_retention(
+    history: &[MatchRecord],
+    replays: &[(u32, u64)],
+    policy: &RetentionPolicy,
+) -> RetentionReport {
+    let recent_ids = |keep: usize| -> Vec<u32> {
+        history.iter().rev().take(keep).map(|record| record.id).collect()
+    };
+    let replay_ids = recent_ids(policy.keep_full_replays);
+    let detailed_ids = recent_ids(policy.keep_detailed_matches);
+
+    let mut report = RetentionReport { dry_run: true, ..Default::default() };
+    for record in history.iter().filter(|r| !detailed_ids.contains(&r.id)) {
+        if record.appearances.is_empty() && record.highlights.is_empty() {
+            continue;
+        }
+        report.summarized_matches.push(record.id);
+        report.appearances_dropped += record.appearances.len();
+        report.highlights_dropped += record.highlights.len();
+    }
+    for &(id, bytes) in replays.iter().filter(|(id, _)| !replay_ids.contains(id)) {
+        report.deleted_replays.push(id);
+        report.bytes_freed += bytes;
+    }
+    report.deleted_replays.sort_unstable();
+    report
+}
+
+/// Summarize the records listed in `report`
+pub fn summarize_matches(history: &mut [MatchRecord], report: &RetentionReport) {
+    for record in history.iter_mut().filter(|r| report.summarized_matches.contains(&r.id)) {
+        record.appearances.clear();
+        record.highlights.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::chemistry::MatchAppearance;
+
+    #[test]
+    fn test_plan_keeps_recent_matches_and_replays() {
+        let history: Vec<MatchRecord> = (1..=5)
+            .map(|id| MatchRecord {
+                id,
+                appearances: vec![
+                    MatchAppearance {
+                        name: "P".to_string(),
+                        minute_on: 0,
+                        minute_off: 90
+                    };
+                    11
+                ],
+                ..MatchRecord::default()
+            })
+            .collect();
+        let replays = [(1, 100), (3, 300), (4, 400), (5, 500), (9, 900)];
+        let policy = RetentionPolicy { keep_full_replays: 2, keep_detailed_matches: 3 };
+
+        let report = plan_retention(&history, &replays, &policy);
+        assert_eq!(report.summarized_matches, vec![1, 2]);
+        assert_eq!(report.appearances_dropped, 22);
+        // Orphaned replay 9 goes too
+        assert_eq!(report.deleted_replays, vec![1, 3, 9]);
+        assert_eq!(report.bytes_freed, 1300);
+
+        let mut pruned = history.clone();
+        summarize_matches(&mut pruned, &report);
+        assert!(pruned[1].appearances.is_empty());
+        assert_eq!(pruned[2].appearances.len(), 11);
+        assert_eq!(pruned.len(), history.len());
+
+        // Already summarized records are not reported again
+        let again = plan_retention(&pruned, &[], &policy);
+        assert!(again.summarized_matches.is_empty());
+    }
+}
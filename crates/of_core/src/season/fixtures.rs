@@ -0,0 +1,105 @@
+//! Round-robin fixture generation
+//!
+//! Circle method: one team stays fixed while the others rotate, so every
+//! pair meets exactly once per leg and nobody plays twice on a matchday.
+//! With an odd team count one team rests (bye) each matchday.
+//! The second leg repeats the first with home/away swapped.
+
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+
+/// One scheduled match (teams are indices into the league's team list)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Fixture {
+    /// 0-based matchday
+    pub matchday: u32,
+    pub home: usize,
+    pub away: usize,
+}
+
+pub struct FixtureGenerator;
+
+impl FixtureGenerator {
+    /// Matchdays per leg for `team_count` teams
+    pub fn matchdays_per_leg(team_count: usize) -> u32 {
+        (team_count + team_count % 2).saturating_sub(1) as u32
+    }
+
+    /// Round-robin schedule; `seed` shuffles the rotation order (same seed → same fixtures)
+    pub fn round_robin(
+        team_count: usize,
+        double_round_robin: bool,
+        seed: u64,
+    ) -> Result<Vec<Fixture>, String> {
+        if team_count < 2 {
+            return Err(format!("League needs at least 2 teams, found {}", team_count));
+        }
+
+        let mut order: Vec<Option<usize>> = (0..team_count).map(Some).collect();
+        order.shuffle(&mut ChaCha8Rng::seed_from_u64(seed));
+        if team_count % 2 == 1 {
+            order.push(None); // bye
+        }
+
+        let slots = order.len();
+        let rounds = Self::matchdays_per_leg(team_count);
+        let mut fixtures = Vec::with_capacity(rounds as usize * slots / 2);
+        for round in 0..rounds {
+            for i in 0..slots / 2 {
+                if let (Some(a), Some(b)) = (order[i], order[slots - 1 - i]) {
+                    let (home, away) = if (round as usize + i) % 2 == 0 { (a, b) } else { (b, a) };
+                    fixtures.push(Fixture { matchday: round, home, away });
+                }
+            }
+            order[1..].rotate_right(1);
+        }
+
+        if double_round_robin {
+            let second_leg: Vec<Fixture> = fixtures
+                .iter()
+                .map(|f| Fixture { matchday: f.matchday + rounds, home: f.away, away: f.home })
+                .collect();
+            fixtures.extend(second_leg);
+        }
+        Ok(fixtures)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_every_pair_meets_once_per_leg() {
+        for team_count in [4usize, 5, 6] {
+            let fixtures = FixtureGenerator::round_robin(team_count, false, 7).unwrap();
+            let rounds = FixtureGenerator::matchdays_per_leg(team_count);
+
+            let pairs: HashSet<(usize, usize)> =
+                fixtures.iter().map(|f| (f.home.min(f.away), f.home.max(f.away))).collect();
+            assert_eq!(pairs.len(), team_count * (team_count - 1) / 2);
+            assert_eq!(fixtures.len(), pairs.len());
+
+            for matchday in 0..rounds {
+                let mut seen = HashSet::new();
+                for f in fixtures.iter().filter(|f| f.matchday == matchday) {
+                    assert!(seen.insert(f.home) && seen.insert(f.away));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_double_round_robin_mirrors_home_and_away() {
+        let fixtures = FixtureGenerator::round_robin(6, true, 3).unwrap();
+        assert_eq!(fixtures.len(), 30);
+        for team in 0..6 {
+            assert_eq!(fixtures.iter().filter(|f| f.home == team).count(), 5);
+        }
+        assert_eq!(fixtures, FixtureGenerator::round_robin(6, true, 3).unwrap());
+        assert!(FixtureGenerator::round_robin(1, true, 3).is_err());
+    }
+}
@@ -0,0 +1,156 @@
+//! League season state
+//!
+//! Teams, the full fixture list and the results so far. Each matchday is
+//! simulated with the regular match engine; fixture seeds are derived from the
+//! league seed, matchday and fixture slot, so a season replays identically and
+//! matchdays do not depend on the order they were requested in.
+
+use serde::{Deserialize, Serialize};
+
+use super::fixtures::{Fixture, FixtureGenerator};
+use super::standings::{FixtureResult, Standings, StandingsRow};
+use crate::engine::{MatchEngine, MatchPlan, TeamMatchModifiers};
+use crate::models::Team;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct League {
+    pub seed: u64,
+    pub teams: Vec<Team>,
+    pub fixtures: Vec<Fixture>,
+    pub standings: Standings,
+    /// Next matchday to simulate (0-based)
+    #[serde(default)]
+    pub next_matchday: u32,
+}
+
+impl League {
+    pub fn new(teams: Vec<Team>, seed: u64, double_round_robin: bool) -> Result<Self, String> {
+        let fixtures = FixtureGenerator::round_robin(teams.len(), double_round_robin, seed)?;
+        let standings = Standings::new(teams.iter().map(|t| t.name.clone()).collect());
+        Ok(Self { seed, teams, fixtures, standings, next_matchday: 0 })
+    }
+
+    pub fn matchday_count(&self) -> u32 {
+        self.fixtures.iter().map(|f| f.matchday + 1).max().unwrap_or(0)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.next_matchday >= self.matchday_count()
+    }
+
+    pub fn fixtures_for(&self, matchday: u32) -> impl Iterator<Item = &Fixture> {
+        self.fixtures.iter().filter(move |f| f.matchday == matchday)
+    }
+
+    /// Simulate the next matchday, record the results and return them
+    pub fn simulate_next_matchday(&mut self) -> Result<Vec<FixtureResult>, String> {
+        if self.is_finished() {
+            return Err("Season is already finished".to_string());
+        }
+        let matchday = self.next_matchday;
+        let fixtures: Vec<Fixture> = self.fixtures_for(matchday).copied().collect();
+
+        let mut results = Vec::with_capacity(fixtures.len());
+        for (slot, fixture) in fixtures.iter().enumerate() {
+            let (home_goals, away_goals) =
+                self.play(fixture, fixture_seed(self.seed, matchday, slot))?;
+            results.push(FixtureResult {
+                matchday,
+                home: fixture.home,
+                away: fixture.away,
+                home_goals,
+                away_goals,
+            });
+        }
+        for result in &results {
+            self.standings.record(*result)?;
+        }
+        self.next_matchday += 1;
+        Ok(results)
+    }
+
+    pub fn table(&self) -> Vec<StandingsRow> {
+        self.standings.table()
+    }
+
+    fn play(&self, fixture: &Fixture, seed: u64) -> Result<(u8, u8), String> {
+        let team = |idx: usize| {
+            self.teams.get(idx).cloned().ok_or_else(|| format!("Unknown team index: {}", idx))
+        };
+        let plan = MatchPlan {
+            home_team: team(fixture.home)?,
+            away_team: team(fixture.away)?,
+            seed,
+            user_player: None,
+            home_match_modifiers: TeamMatchModifiers::default(),
+            away_match_modifiers: TeamMatchModifiers::default(),
+            modifier_stack: Default::default(),
+            home_instructions: None,
+            away_instructions: None,
+            home_player_instructions: None,
+            away_player_instructions: None,
+            home_ai_difficulty: None,
+            away_ai_difficulty: None,
+            pitch: crate::models::pitch::PitchSpec::default(),
+            home_chemistry: None,
+            away_chemistry: None,
+            stadium: None,
+            conditions: None,
+            penalty_shootout: None,
+            home_custom_formation: None,
+            away_custom_formation: None,
+            home_set_piece_takers: None,
+            away_set_piece_takers: None,
+            referee: None,
+        };
+        let result = MatchEngine::new(plan)?.simulate();
+        Ok((result.score_home, result.score_away))
+    }
+}
+
+/// Per-fixture engine seed (SplitMix64 finalizer over league seed, matchday and slot)
+fn fixture_seed(seed: u64, matchday: u32, slot: usize) -> u64 {
+    let mut z = seed ^ ((matchday as u64) << 32 | slot as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::match_sim::test_fixtures::create_test_team_with_subs as create_test_team;
+
+    fn league(seed: u64) -> League {
+        let teams = ["North", "South", "East", "West"].map(create_test_team).to_vec();
+        League::new(teams, seed, false).unwrap()
+    }
+
+    #[test]
+    fn test_matchdays_are_deterministic_and_update_table() {
+        let mut a = league(11);
+        let mut b = league(11);
+        assert_eq!(a.matchday_count(), 3);
+
+        let first = a.simulate_next_matchday().unwrap();
+        assert_eq!(first.len(), 2);
+        assert_eq!(first, b.simulate_next_matchday().unwrap());
+
+        let table = a.table();
+        assert_eq!(table.iter().map(|r| r.played).sum::<u32>(), 4);
+        let points: u32 = table.iter().map(|r| r.points).sum();
+        assert!((4..=6).contains(&points));
+    }
+
+    #[test]
+    fn test_season_with_bye_finishes() {
+        let teams = ["North", "South", "East"].map(create_test_team).to_vec();
+        let mut league = League::new(teams, 5, false).unwrap();
+        assert_eq!(league.matchday_count(), 3);
+        while !league.is_finished() {
+            assert_eq!(league.simulate_next_matchday().unwrap().len(), 1);
+        }
+        assert_eq!(league.standings.results.len(), 3);
+        assert!(league.simulate_next_matchday().is_err());
+    }
+}
@@ -0,0 +1,10 @@
+// crates/of_core/src/season/mod.rs
+// League season simulation (round-robin fixtures, matchday simulation, standings)
+
+pub mod fixtures;
+pub mod league;
+pub mod standings;
+
+pub use fixtures::{Fixture, FixtureGenerator};
+pub use league::League;
+pub use standings::{FixtureResult, Standings, StandingsRow};
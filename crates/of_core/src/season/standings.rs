@@ -0,0 +1,172 @@
+//! League table
+//!
+//! Built from recorded results. Ordering:
+//! 1. points (3 / 1 / 0)
+//! 2. goal difference
+//! 3. goals scored
+//! 4. head-to-head among the teams still level (points, goal difference, goals)
+//! 5. team name (stable, deterministic)
+
+use std::cmp::Reverse;
+
+use serde::{Deserialize, Serialize};
+
+pub const POINTS_WIN: u32 = 3;
+pub const POINTS_DRAW: u32 = 1;
+
+/// One played fixture (teams are indices into the league's team list)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FixtureResult {
+    pub matchday: u32,
+    pub home: usize,
+    pub away: usize,
+    pub home_goals: u8,
+    pub away_goals: u8,
+}
+
+/// One table row
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StandingsRow {
+    /// 1-based table position
+    pub position: u32,
+    pub team: usize,
+    pub name: String,
+    pub played: u32,
+    pub won: u32,
+    pub drawn: u32,
+    pub lost: u32,
+    pub goals_for: u32,
+    pub goals_against: u32,
+    pub goal_difference: i32,
+    pub points: u32,
+}
+
+impl StandingsRow {
+    fn add(&mut self, scored: u8, conceded: u8) {
+        self.played += 1;
+        self.goals_for += scored as u32;
+        self.goals_against += conceded as u32;
+        self.goal_difference = self.goals_for as i32 - self.goals_against as i32;
+        match scored.cmp(&conceded) {
+            std::cmp::Ordering::Greater => {
+                self.won += 1;
+                self.points += POINTS_WIN;
+            }
+            std::cmp::Ordering::Equal => {
+                self.drawn += 1;
+                self.points += POINTS_DRAW;
+            }
+            std::cmp::Ordering::Less => self.lost += 1,
+        }
+    }
+
+    fn sort_key(&self) -> (u32, i32, u32) {
+        (self.points, self.goal_difference, self.goals_for)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Standings {
+    pub team_names: Vec<String>,
+    #[serde(default)]
+    pub results: Vec<FixtureResult>,
+}
+
+impl Standings {
+    pub fn new(team_names: Vec<String>) -> Self {
+        Self { team_names, results: Vec::new() }
+    }
+
+    pub fn record(&mut self, result: FixtureResult) -> Result<(), String> {
+        let teams = self.team_names.len();
+        if result.home >= teams || result.away >= teams || result.home == result.away {
+            return Err(format!("Invalid fixture: {} vs {}", result.home, result.away));
+        }
+        self.results.push(result);
+        Ok(())
+    }
+
+    /// Sorted league table
+    pub fn table(&self) -> Vec<StandingsRow> {
+        let mut rows = self.rows_for(|_| true);
+        rows.sort_by_key(|r| Reverse(r.sort_key()));
+
+        // Head-to-head inside each group still level on points/GD/goals
+        let mut start = 0;
+        while start < rows.len() {
+            let key = rows[start].sort_key();
+            let end = start + rows[start..].iter().take_while(|r| r.sort_key() == key).count();
+            if end - start > 1 {
+                let group: Vec<usize> = rows[start..end].iter().map(|r| r.team).collect();
+                let h2h = self.rows_for(|r| group.contains(&r.home) && group.contains(&r.away));
+                rows[start..end].sort_by(|a, b| {
+                    Reverse(h2h[a.team].sort_key())
+                        .cmp(&Reverse(h2h[b.team].sort_key()))
+                        .then_with(|| a.name.cmp(&b.name))
+                });
+            }
+            start = end;
+        }
+
+        for (idx, row) in rows.iter_mut().enumerate() {
+            row.position = idx as u32 + 1;
+        }
+        rows
+    }
+
+    /// Unsorted rows (indexed by team) over the results matching `filter`
+    fn rows_for(&self, filter: impl Fn(&FixtureResult) -> bool) -> Vec<StandingsRow> {
+        let mut rows: Vec<StandingsRow> = self
+            .team_names
+            .iter()
+            .enumerate()
+            .map(|(team, name)| StandingsRow { team, name: name.clone(), ..Default::default() })
+            .collect();
+        for result in self.results.iter().filter(|r| filter(r)) {
+            rows[result.home].add(result.home_goals, result.away_goals);
+            rows[result.away].add(result.away_goals, result.home_goals);
+        }
+        rows
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(home: usize, away: usize, home_goals: u8, away_goals: u8) -> FixtureResult {
+        FixtureResult { matchday: 0, home, away, home_goals, away_goals }
+    }
+
+    #[test]
+    fn test_points_then_goal_difference() {
+        let mut standings = Standings::new(vec!["A".into(), "B".into(), "C".into()]);
+        standings.record(result(0, 1, 3, 0)).unwrap();
+        standings.record(result(2, 1, 1, 0)).unwrap();
+        standings.record(result(0, 2, 1, 1)).unwrap();
+
+        let table = standings.table();
+        assert_eq!(table[0].name, "A");
+        assert_eq!((table[0].points, table[0].goal_difference), (4, 3));
+        assert_eq!(table[1].name, "C");
+        assert_eq!(table[2].name, "B");
+        assert_eq!(table[2].position, 3);
+        assert!(standings.record(result(1, 1, 0, 0)).is_err());
+    }
+
+    #[test]
+    fn test_head_to_head_breaks_full_tie() {
+        // "Zed" and "Ace" finish level on points, GD and goals; Zed won their meeting.
+        let mut standings = Standings::new(vec!["Zed".into(), "Ace".into(), "Mid".into()]);
+        standings.record(result(0, 1, 1, 0)).unwrap();
+        standings.record(result(1, 2, 1, 0)).unwrap();
+        standings.record(result(2, 0, 1, 0)).unwrap();
+        standings.record(result(1, 0, 0, 0)).unwrap();
+
+        let table = standings.table();
+        let zed = table.iter().position(|r| r.name == "Zed").unwrap();
+        let ace = table.iter().position(|r| r.name == "Ace").unwrap();
+        assert_eq!(table[zed].sort_key(), table[ace].sort_key());
+        assert!(zed < ace);
+    }
+}
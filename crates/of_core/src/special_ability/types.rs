@@ -1,8 +1,9 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// 🌟 특수능력 7단계 티어 시스템
 /// 파워풀 프로야구 스타일 등급 체계
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub enum AbilityTier {
     // 긍정적 능력 (5단계)
     Bronze,  // 🟤 동특 - 아주 조금 향상 (+1~2)
@@ -46,7 +47,7 @@ impl AbilityTier {
 
 /// 🎯 12개 특수능력 체계
 /// Technical, Mental, Physical 각 4개씩
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub enum SpecialAbilityType {
     // Technical 계열 (4개) - 기술적 특성
     DribblingMaster,    // 드리블 마스터
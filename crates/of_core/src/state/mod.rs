@@ -7,7 +7,9 @@
 use once_cell::sync::Lazy;
 use std::sync::{Arc, RwLock};
 
+use crate::career::{FixtureCalendar, LadderMatch, LadderUpdate, RatingLadder, SquadLoad};
 use crate::coach::{CardInventory, Deck};
+use crate::models::stadium::StadiumRegistry;
 use crate::player::CorePlayer;
 use crate::quest::QuestManagerState;
 use crate::save::{GameProgress, GameSave, GameSettings, MatchRecord};
@@ -49,6 +51,15 @@ pub struct GameState {
 
     /// Quest system state
     pub quest_manager: QuestManagerState,
+
+    /// Elo rating ladder (teams + players)
+    pub rating_ladder: RatingLadder,
+
+    /// Career fixture calendar (dates + played lineups)
+    pub fixture_calendar: FixtureCalendar,
+
+    /// Team home stadiums
+    pub stadium_registry: StadiumRegistry,
 }
 
 impl Default for GameState {
@@ -70,6 +81,9 @@ impl GameState {
             progress: GameProgress::default(),
             game_settings: GameSettings::default(),
             quest_manager: QuestManagerState::default(),
+            rating_ladder: RatingLadder::new(),
+            fixture_calendar: FixtureCalendar::new(),
+            stadium_registry: StadiumRegistry::new(),
         }
     }
 
@@ -88,6 +102,9 @@ impl GameState {
             game_settings: self.game_settings.clone(),
             quest_manager: self.quest_manager.clone(),
             player_appearance: None,
+            rating_ladder: self.rating_ladder.clone(),
+            fixture_calendar: self.fixture_calendar.clone(),
+            stadium_registry: self.stadium_registry.clone(),
         }
     }
 
@@ -103,6 +120,9 @@ impl GameState {
             progress: save.progress.clone(),
             game_settings: save.game_settings.clone(),
             quest_manager: save.quest_manager.clone(),
+            rating_ladder: save.rating_ladder.clone(),
+            fixture_calendar: save.fixture_calendar.clone(),
+            stadium_registry: save.stadium_registry.clone(),
         }
     }
 
@@ -182,6 +202,37 @@ impl GameState {
         self.progress.stats.goals_against += score_away as u32;
     }
 
+    /// Minutes played together per player pair, from recorded lineups
+    pub fn shared_minutes(&self) -> crate::models::chemistry::SharedMinutes {
+        crate::models::chemistry::SharedMinutes::from_matches(
+            self.match_history.iter().map(|record| record.appearances.as_slice()),
+        )
+    }
+
+    /// Career clubs of the saved player named `name` (empty if unknown)
+    pub fn career_clubs(&self, name: &str) -> Vec<String> {
+        self.players
+            .iter()
+            .find(|player| player.name == name)
+            .map(|player| player.career_stats.clubs.clone())
+            .unwrap_or_default()
+    }
+
+    /// Apply a finished match to the rating ladder
+    pub fn record_ladder_match(&mut self, ladder_match: &LadderMatch) -> LadderUpdate {
+        self.rating_ladder.record(ladder_match)
+    }
+
+    /// Replace the rating ladder with one replayed from saved match history
+    pub fn rebuild_rating_ladder(&mut self, club: &str) {
+        self.rating_ladder = RatingLadder::from_history(club, &self.match_history);
+    }
+
+    /// Per-player match load from the fixture calendar, as of `today`
+    pub fn squad_load(&self, today: u32) -> SquadLoad {
+        self.fixture_calendar.squad_load(today)
+    }
+
     // ========================
     // Progress Management
     // ========================
@@ -246,6 +297,16 @@ mod tests {
         state.progress.current_week = 10;
         state.progress.current_season = 2;
         state.active_deck_id = Some("test_deck".to_string());
+        state.record_ladder_match(&crate::career::LadderMatch {
+            home: "Club".to_string(),
+            away: "Rivals".to_string(),
+            score_home: 2,
+            score_away: 0,
+            expected_home: None,
+            neutral: false,
+            home_players: Vec::new(),
+            away_players: Vec::new(),
+        });
 
         // Convert to save
         let save = state.to_save();
@@ -256,6 +317,8 @@ mod tests {
         assert_eq!(restored.progress.current_week, 10);
         assert_eq!(restored.progress.current_season, 2);
         assert_eq!(restored.active_deck_id, Some("test_deck".to_string()));
+        assert_eq!(restored.rating_ladder, state.rating_ladder);
+        assert!(restored.rating_ladder.team_rating("Club") > crate::career::rating::DEFAULT_RATING);
     }
 
     #[test]
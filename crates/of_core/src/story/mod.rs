@@ -10,12 +10,17 @@ pub mod events;
 pub mod localization;
 pub mod serialization;
 pub mod types;
+pub mod youth_tournament;
 
 pub use branching::*;
 pub use conditions::*;
 pub use effects::*;
 pub use events::*;
 pub use types::*;
+pub use youth_tournament::{
+    generate_youth_bracket, generate_youth_opponent, YouthAgeGroup, YouthOpponent,
+    YouthOpponentRequest,
+};
 
 use crate::error::CoreError;
 
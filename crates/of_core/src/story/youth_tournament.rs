@@ -0,0 +1,421 @@
+//! Youth tournament opponent generator
+//!
+//! Story chapters reference opponents by chapter id instead of shipping
+//! hand-authored 18-player rosters. The same chapter id (and fixture index)
+//! always produces the same squad: a regional youth team with an age-group
+//! appropriate overall range, a regional playing style (formation +
+//! attribute emphasis) and MatchRequestV2-ready embedded roster entries.
+//!
+//! Trailing digits in the chapter id (e.g. `youth_ch3`) raise opponent
+//! strength slightly, so later chapters field tougher teams.
+
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::api::json_api::{EmbeddedPlayerAttributes, EmbeddedPlayerData, RosterEntry, TeamDataV2};
+use crate::data::name_pools::{GeneratedName, NameGenerator, NamePack, NamePool};
+
+/// Overall bonus per chapter number
+pub const CHAPTER_STRENGTH_STEP: i32 = 2;
+/// Max overall bonus from chapter progression
+pub const MAX_CHAPTER_BONUS: i32 = 10;
+
+/// Youth age group (drives the overall range)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum YouthAgeGroup {
+    U15,
+    #[default]
+    U17,
+    U19,
+}
+
+impl YouthAgeGroup {
+    /// (min, max) starter overall before chapter/strength adjustments
+    pub fn overall_range(&self) -> (i32, i32) {
+        match self {
+            YouthAgeGroup::U15 => (35, 52),
+            YouthAgeGroup::U17 => (42, 60),
+            YouthAgeGroup::U19 => (48, 67),
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            YouthAgeGroup::U15 => "U15",
+            YouthAgeGroup::U17 => "U17",
+            YouthAgeGroup::U19 => "U19",
+        }
+    }
+}
+
+/// Regional playing style
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RegionalStyle {
+    /// Short passing, technique and vision
+    Technical,
+    /// Strength, stamina and aerial play
+    Physical,
+    /// Work rate, pace and aggression
+    Pressing,
+    /// Pace and finishing on the break
+    Direct,
+}
+
+impl RegionalStyle {
+    fn formation(&self) -> &'static str {
+        match self {
+            RegionalStyle::Technical => "4-3-3",
+            RegionalStyle::Physical => "4-4-2",
+            RegionalStyle::Pressing => "4-2-3-1",
+            RegionalStyle::Direct => "3-5-2",
+        }
+    }
+
+    fn personality(&self) -> &'static str {
+        match self {
+            RegionalStyle::Technical => "Genius",
+            RegionalStyle::Physical => "Steady",
+            RegionalStyle::Pressing => "Workhorse",
+            RegionalStyle::Direct => "Rebel",
+        }
+    }
+}
+
+struct YouthRegion {
+    name: &'static str,
+    style: RegionalStyle,
+    surnames: &'static [&'static str],
+}
+
+const YOUTH_REGIONS: &[YouthRegion] = &[
+    YouthRegion {
+        name: "Seoul",
+        style: RegionalStyle::Technical,
+        surnames: &["Kim", "Lee", "Park", "Choi", "Jung", "Kang"],
+    },
+    YouthRegion {
+        name: "Busan",
+        style: RegionalStyle::Physical,
+        surnames: &["Cho", "Yoon", "Jang", "Lim", "Han", "Oh"],
+    },
+    YouthRegion {
+        name: "Gwangju",
+        style: RegionalStyle::Pressing,
+        surnames: &["Seo", "Shin", "Kwon", "Hwang", "Ahn", "Song"],
+    },
+    YouthRegion {
+        name: "Daegu",
+        style: RegionalStyle::Direct,
+        surnames: &["Jeon", "Hong", "Ko", "Moon", "Yang", "Son"],
+    },
+    YouthRegion {
+        name: "Incheon",
+        style: RegionalStyle::Technical,
+        surnames: &["Bae", "Baek", "Heo", "Yoo", "Nam", "Noh"],
+    },
+    YouthRegion {
+        name: "Jeju",
+        style: RegionalStyle::Direct,
+        surnames: &["Ha", "Kwak", "Sung", "Cha", "Joo", "Woo"],
+    },
+];
+
+const GIVEN_NAMES: &[&str] = &[
+    "Min-jun",
+    "Seo-jun",
+    "Do-yun",
+    "Ha-jun",
+    "Ji-ho",
+    "Jun-woo",
+    "Hyun-woo",
+    "Ji-hoon",
+    "Woo-jin",
+    "Seung-min",
+    "Tae-yang",
+    "Dong-hyun",
+    "Jae-won",
+    "Sung-min",
+    "Yu-chan",
+    "Eun-woo",
+];
+
+/// Generator input
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct YouthOpponentRequest {
+    pub chapter_id: String,
+    #[serde(default)]
+    pub age_group: YouthAgeGroup,
+    /// Fixture within the chapter (different opponents, same chapter)
+    #[serde(default)]
+    pub fixture_index: u32,
+    /// Extra overall adjustment chosen by the story (-20..=20)
+    #[serde(default)]
+    pub strength_offset: i8,
+}
+
+/// Generated opponent squad
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct YouthOpponent {
+    pub region: String,
+    pub style: RegionalStyle,
+    pub average_overall: u8,
+    /// MatchRequestV2 `home_team` / `away_team`
+    pub team: TeamDataV2,
+}
+
+/// Trailing number of a chapter id ("youth_ch3" → 3)
+fn chapter_number(chapter_id: &str) -> i32 {
+    let digits: String = chapter_id
+        .chars()
+        .rev()
+        .take_while(|c| c.is_ascii_digit())
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+        .collect();
+    digits.parse::<i32>().unwrap_or(0)
+}
+
+fn chapter_seed(chapter_id: &str, fixture_index: u32) -> u64 {
+    let mut hasher = Sha256::new();
+    hasher.update(b"of_core/youth_tournament/v1");
+    hasher.update(chapter_id.as_bytes());
+    hasher.update(fixture_index.to_le_bytes());
+    let digest = hasher.finalize();
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&digest[..8]);
+    u64::from_le_bytes(bytes)
+}
+
+/// 18 distinct regional names (surname first), stable per chapter seed
+fn region_names(region: &YouthRegion, seed: u64) -> Result<Vec<GeneratedName>, String> {
+    let pool = NamePool {
+        nationality: "KOR".to_string(),
+        weight: 1,
+        given_names: GIVEN_NAMES.iter().map(|s| s.to_string()).collect(),
+        surnames: region.surnames.iter().map(|s| s.to_string()).collect(),
+        surname_first: true,
+    };
+    NameGenerator::new(NamePack { pools: vec![pool] })?.generate_unique(seed, 18, None)
+}
+
+/// Starter positions per formation (GK first), then 7 substitutes
+fn roster_positions(formation: &str) -> [&'static str; 18] {
+    let starters: [&str; 11] = match formation {
+        "4-3-3" => ["GK", "LB", "CB", "CB", "RB", "CM", "CM", "CM", "LW", "ST", "RW"],
+        "4-2-3-1" => ["GK", "LB", "CB", "CB", "RB", "CDM", "CDM", "LM", "CAM", "RM", "ST"],
+        "3-5-2" => ["GK", "CB", "CB", "CB", "LM", "CM", "CDM", "CM", "RM", "ST", "ST"],
+        _ => ["GK", "LB", "CB", "CB", "RB", "LM", "CM", "CM", "RM", "ST", "ST"],
+    };
+    let bench = ["GK", "CB", "RB", "CM", "CM", "LM", "ST"];
+    let mut positions = [""; 18];
+    positions[..11].copy_from_slice(&starters);
+    positions[11..].copy_from_slice(&bench);
+    positions
+}
+
+/// Generate a themed youth opponent for a story chapter (deterministic)
+pub fn generate_youth_opponent(request: &YouthOpponentRequest) -> Result<YouthOpponent, String> {
+    if request.chapter_id.trim().is_empty() {
+        return Err("chapter_id must not be empty".to_string());
+    }
+    if !(-20..=20).contains(&request.strength_offset) {
+        return Err(format!("strength_offset {} outside -20..=20", request.strength_offset));
+    }
+
+    let seed = chapter_seed(&request.chapter_id, request.fixture_index);
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let region = &YOUTH_REGIONS[rng.gen_range(0..YOUTH_REGIONS.len())];
+
+    let bonus = (chapter_number(&request.chapter_id) * CHAPTER_STRENGTH_STEP)
+        .min(MAX_CHAPTER_BONUS)
+        + request.strength_offset as i32;
+    let (min, max) = request.age_group.overall_range();
+    let (min, max) = ((min + bonus).clamp(20, 90), (max + bonus).clamp(25, 95));
+
+    let formation = region.style.formation();
+    let mut roster = Vec::with_capacity(18);
+    let mut total = 0u32;
+    let mut names = region_names(region, seed)?.into_iter();
+    for (slot, position) in roster_positions(formation).iter().enumerate() {
+        // Substitutes sit a few points below the starters
+        let overall =
+            if slot < 11 { rng.gen_range(min..=max) } else { rng.gen_range(min..=max) - 4 }
+                .clamp(20, 95) as u8;
+        if slot < 11 {
+            total += overall as u32;
+        }
+
+        // GK attributes are not carried by embedded data; let them derive from overall
+        let attributes =
+            (*position != "GK").then(|| styled_attributes(&mut rng, overall, region.style));
+
+        roster.push(RosterEntry::Embedded(EmbeddedPlayerData {
+            name: names.next().map(|n| n.full_name).unwrap_or_default(),
+            position: position.to_string(),
+            overall,
+            condition: 3,
+            attributes,
+            track_id: None,
+            personality: (slot % 3 == 0).then(|| region.style.personality().to_string()),
+            traits: None,
+            morale: None,
+            form: None,
+            injured_until: None,
+        }));
+    }
+
+    Ok(YouthOpponent {
+        region: region.name.to_string(),
+        style: region.style,
+        average_overall: (total / 11) as u8,
+        team: TeamDataV2 {
+            name: format!("{} {}", region.name, request.age_group.label()),
+            formation: formation.to_string(),
+            roster,
+            player_instructions: None,
+            custom_formation: None,
+            set_piece_takers: None,
+        },
+    })
+}
+
+/// Distinct-region opponents for a chapter's tournament bracket
+pub fn generate_youth_bracket(
+    chapter_id: &str,
+    age_group: YouthAgeGroup,
+    count: usize,
+) -> Result<Vec<YouthOpponent>, String> {
+    if count > YOUTH_REGIONS.len() {
+        return Err(format!("bracket size {} exceeds {} regions", count, YOUTH_REGIONS.len()));
+    }
+
+    let mut opponents: Vec<YouthOpponent> = Vec::with_capacity(count);
+    let mut fixture_index = 0;
+    while opponents.len() < count {
+        let opponent = generate_youth_opponent(&YouthOpponentRequest {
+            chapter_id: chapter_id.to_string(),
+            age_group,
+            fixture_index,
+            strength_offset: 0,
+        })?;
+        if opponents.iter().all(|o| o.region != opponent.region) {
+            opponents.push(opponent);
+        }
+        fixture_index += 1;
+    }
+    Ok(opponents)
+}
+
+fn styled_attributes(
+    rng: &mut ChaCha8Rng,
+    overall: u8,
+    style: RegionalStyle,
+) -> EmbeddedPlayerAttributes {
+    let mut base = |emphasis: bool| -> u8 {
+        let jitter = rng.gen_range(-5..=5);
+        let bonus = if emphasis { 6 } else { 0 };
+        (overall as i32 + jitter + bonus).clamp(1, 99) as u8
+    };
+    let technical = style == RegionalStyle::Technical;
+    let physical = style == RegionalStyle::Physical;
+    let pressing = style == RegionalStyle::Pressing;
+    let direct = style == RegionalStyle::Direct;
+
+    EmbeddedPlayerAttributes {
+        corners: base(false),
+        crossing: base(physical),
+        dribbling: base(technical),
+        finishing: base(direct),
+        first_touch: base(technical),
+        free_kick_taking: base(false),
+        heading: base(physical),
+        long_shots: base(direct),
+        long_throws: base(false),
+        marking: base(pressing),
+        passing: base(technical),
+        penalty_taking: base(false),
+        tackling: base(pressing),
+        technique: base(technical),
+        aggression: base(pressing),
+        anticipation: base(false),
+        bravery: base(physical),
+        composure: base(technical),
+        concentration: base(false),
+        decisions: base(false),
+        determination: base(false),
+        flair: base(direct),
+        leadership: base(false),
+        off_the_ball: base(direct),
+        positioning: base(false),
+        teamwork: base(pressing),
+        vision: base(technical),
+        work_rate: base(pressing),
+        acceleration: base(direct),
+        agility: base(false),
+        balance: base(false),
+        jumping_reach: base(physical),
+        natural_fitness: base(false),
+        pace: base(direct || pressing),
+        stamina: base(pressing || physical),
+        strength: base(physical),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(chapter_id: &str, fixture_index: u32) -> YouthOpponentRequest {
+        YouthOpponentRequest {
+            chapter_id: chapter_id.to_string(),
+            age_group: YouthAgeGroup::U17,
+            fixture_index,
+            strength_offset: 0,
+        }
+    }
+
+    #[test]
+    fn test_generation_is_deterministic_and_valid() {
+        let a = generate_youth_opponent(&request("youth_ch1", 0)).unwrap();
+        let b = generate_youth_opponent(&request("youth_ch1", 0)).unwrap();
+        assert_eq!(serde_json::to_string(&a).unwrap(), serde_json::to_string(&b).unwrap());
+        assert_eq!(a.team.roster.len(), 18);
+        let other = generate_youth_opponent(&request("youth_ch1", 1)).unwrap();
+        assert_ne!(serde_json::to_string(&a).unwrap(), serde_json::to_string(&other).unwrap());
+
+        // Roster converts into a valid engine team
+        let mut request_v2 = serde_json::json!({
+            "schema_version": 2,
+            "seed": 1,
+            "home_team": a.team,
+            "away_team": b.team,
+        });
+        request_v2["away_team"]["name"] = serde_json::json!("Other");
+        let plan = crate::api::match_plan_from_match_request_v2_json(&request_v2.to_string());
+        assert!(plan.is_ok(), "{:?}", plan.err());
+    }
+
+    #[test]
+    fn test_chapter_progression_raises_strength() {
+        let early = generate_youth_opponent(&request("youth_ch0", 0)).unwrap();
+        let late = generate_youth_opponent(&request("youth_ch5", 0)).unwrap();
+        let (min, max) = YouthAgeGroup::U17.overall_range();
+        assert!((min..=max).contains(&(early.average_overall as i32)));
+        assert!(late.average_overall as i32 >= min + MAX_CHAPTER_BONUS);
+        assert_eq!(chapter_number("youth_ch12"), 12);
+        assert_eq!(chapter_number("prologue"), 0);
+    }
+
+    #[test]
+    fn test_bracket_regions_are_distinct() {
+        let bracket = generate_youth_bracket("cup_ch2", YouthAgeGroup::U15, 4).unwrap();
+        assert_eq!(bracket.len(), 4);
+        for (i, a) in bracket.iter().enumerate() {
+            assert!(bracket[i + 1..].iter().all(|b| b.region != a.region));
+        }
+        assert!(generate_youth_bracket("cup_ch2", YouthAgeGroup::U15, 99).is_err());
+    }
+}
@@ -90,6 +90,63 @@ impl AIDifficulty {
             Self::Expert => 3,  // Every 3 minutes
         }
     }
+
+    /// In-match behavior profile for this tier
+    pub fn profile(&self) -> AIDifficultyProfile {
+        let (decision_noise, reaction_delay_ticks, attribute_scale) = match self {
+            Self::Easy => (1.6, 6, 0.85),
+            Self::Medium => (1.25, 3, 0.92),
+            Self::Hard => (1.0, 1, 0.97),
+            Self::Expert => (0.8, 0, 1.0),
+        };
+        AIDifficultyProfile {
+            decision_noise,
+            reaction_delay_ticks,
+            attribute_scale,
+            tactical_adaptability: self.change_probability(),
+            review_interval_minutes: self.update_frequency(),
+        }
+    }
+
+    /// Parse a tier name ("Easy" | "Medium" | "Hard" | "Expert", case-insensitive)
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "easy" => Some(Self::Easy),
+            "medium" => Some(Self::Medium),
+            "hard" => Some(Self::Hard),
+            "expert" => Some(Self::Expert),
+            _ => None,
+        }
+    }
+}
+
+/// Per-tier behavior knobs applied by the match engine
+///
+/// - `decision_noise`: softmax temperature multiplier for on-ball decisions
+///   (>1 = more random choices, <1 = greedier)
+/// - `reaction_delay_ticks`: decision ticks (250ms) before the team starts
+///   pressing again after losing the ball
+/// - `attribute_scale`: technical/mental attribute scale (1.0 = squad as selected)
+/// - `tactical_adaptability`: probability a tactical review changes tactics
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AIDifficultyProfile {
+    pub decision_noise: f32,
+    pub reaction_delay_ticks: u8,
+    pub attribute_scale: f32,
+    pub tactical_adaptability: f32,
+    /// Minutes between tactical reviews
+    pub review_interval_minutes: u32,
+}
+
+impl AIDifficultyProfile {
+    /// Human-controlled / no-tier teams: engine behavior unchanged
+    pub const NEUTRAL: Self = Self {
+        decision_noise: 1.0,
+        reaction_delay_ticks: 0,
+        attribute_scale: 1.0,
+        tactical_adaptability: 0.0,
+        review_interval_minutes: 999,
+    };
 }
 
 // ============================================================================
@@ -213,6 +270,7 @@ pub const AGGRESSIVE_AI: AITacticalProfile = AITacticalProfile {
         build_up_style: BuildUpStyle::Direct,
         defensive_line: DefensiveLine::High,
         use_offside_trap: true,
+        set_pieces: SetPieceRoutines::DEFAULT,
     },
     situation_rules: vec![],
     counter_rules: vec![],
@@ -233,6 +291,7 @@ pub const BALANCED_AI: AITacticalProfile = AITacticalProfile {
         build_up_style: BuildUpStyle::Short,
         defensive_line: DefensiveLine::Normal,
         use_offside_trap: false,
+        set_pieces: SetPieceRoutines::DEFAULT,
     },
     situation_rules: vec![],
     counter_rules: vec![],
@@ -253,6 +312,7 @@ pub const DEFENSIVE_AI: AITacticalProfile = AITacticalProfile {
         build_up_style: BuildUpStyle::Direct,
         defensive_line: DefensiveLine::Deep,
         use_offside_trap: false,
+        set_pieces: SetPieceRoutines::DEFAULT,
     },
     situation_rules: vec![],
     counter_rules: vec![],
@@ -273,6 +333,7 @@ pub const ADAPTIVE_AI: AITacticalProfile = AITacticalProfile {
         build_up_style: BuildUpStyle::Short,
         defensive_line: DefensiveLine::Normal,
         use_offside_trap: false,
+        set_pieces: SetPieceRoutines::DEFAULT,
     },
     situation_rules: vec![],
     counter_rules: vec![],
@@ -293,6 +354,7 @@ pub const COUNTER_AI: AITacticalProfile = AITacticalProfile {
         build_up_style: BuildUpStyle::Direct,
         defensive_line: DefensiveLine::Deep,
         use_offside_trap: false,
+        set_pieces: SetPieceRoutines::DEFAULT,
     },
     situation_rules: vec![],
     counter_rules: vec![],
@@ -309,6 +371,7 @@ pub const AI_PROFILES: &[AITacticalProfile] =
 /// AI Tactical Manager
 ///
 /// Manages AI tactical decisions based on profile and difficulty
+#[derive(Clone)]
 pub struct AITacticalManager {
     /// AI profile being used
     profile: AITacticalProfile,
@@ -669,6 +732,22 @@ mod tests {
         assert!(late.is_final_minutes());
     }
 
+    #[test]
+    fn test_difficulty_profiles_are_monotonic() {
+        let tiers =
+            [AIDifficulty::Easy, AIDifficulty::Medium, AIDifficulty::Hard, AIDifficulty::Expert];
+        for pair in tiers.windows(2) {
+            let (lower, higher) = (pair[0].profile(), pair[1].profile());
+            assert!(higher.decision_noise < lower.decision_noise);
+            assert!(higher.reaction_delay_ticks < lower.reaction_delay_ticks);
+            assert!(higher.attribute_scale > lower.attribute_scale);
+            assert!(higher.tactical_adaptability > lower.tactical_adaptability);
+        }
+        assert_eq!(AIDifficulty::from_name("expert"), Some(AIDifficulty::Expert));
+        assert_eq!(AIDifficulty::from_name("Medium"), Some(AIDifficulty::Medium));
+        assert_eq!(AIDifficulty::from_name("godlike"), None);
+    }
+
     #[test]
     fn test_ai_difficulty_probabilities() {
         assert_eq!(AIDifficulty::Easy.change_probability(), 0.0);
@@ -79,6 +79,7 @@ pub const TIKI_TAKA: FamousTactics = FamousTactics {
         build_up_style: BuildUpStyle::Short,
         defensive_line: DefensiveLine::Normal,
         use_offside_trap: false,
+        set_pieces: SetPieceRoutines::DEFAULT,
     },
     style: TacticalStyle::Balanced,
 };
@@ -96,6 +97,7 @@ pub const GEGENPRESSING: FamousTactics = FamousTactics {
         build_up_style: BuildUpStyle::Direct,
         defensive_line: DefensiveLine::VeryHigh,
         use_offside_trap: true,
+        set_pieces: SetPieceRoutines::DEFAULT,
     },
     style: TacticalStyle::VeryAttacking,
 };
@@ -113,6 +115,7 @@ pub const CATENACCIO: FamousTactics = FamousTactics {
         build_up_style: BuildUpStyle::Short,
         defensive_line: DefensiveLine::VeryDeep,
         use_offside_trap: false,
+        set_pieces: SetPieceRoutines::DEFAULT,
     },
     style: TacticalStyle::VeryDefensive,
 };
@@ -130,6 +133,7 @@ pub const TOTAL_FOOTBALL: FamousTactics = FamousTactics {
         build_up_style: BuildUpStyle::Direct,
         defensive_line: DefensiveLine::VeryHigh,
         use_offside_trap: true,
+        set_pieces: SetPieceRoutines::DEFAULT,
     },
     style: TacticalStyle::VeryAttacking,
 };
@@ -147,6 +151,7 @@ pub const COUNTER_ATTACK: FamousTactics = FamousTactics {
         build_up_style: BuildUpStyle::Direct,
         defensive_line: DefensiveLine::Deep,
         use_offside_trap: false,
+        set_pieces: SetPieceRoutines::DEFAULT,
     },
     style: TacticalStyle::Defensive,
 };
@@ -164,6 +169,7 @@ pub const PARK_THE_BUS: FamousTactics = FamousTactics {
         build_up_style: BuildUpStyle::Short,
         defensive_line: DefensiveLine::VeryDeep,
         use_offside_trap: false,
+        set_pieces: SetPieceRoutines::DEFAULT,
     },
     style: TacticalStyle::VeryDefensive,
 };
@@ -0,0 +1,124 @@
+//! Public formation waypoint preview
+//!
+//! Resolves the engine's internal formation waypoints into per-slot target
+//! coordinates for a given set of team instructions and team phase, so the
+//! team editor and external viewers draw the same anchors the engine steers
+//! players from (before ball-dependent adjustments).
+//!
+//! Coordinates are team-relative and normalized (0-1), matching
+//! `engine::formation_waypoints`:
+//! - x: 0 = left touchline, 1 = right touchline
+//! - y: 0 = own goal line, 1 = opponent goal line
+
+use serde::{Deserialize, Serialize};
+
+use crate::engine::elastic_band::team_instructions_to_elastic_tactics;
+use crate::engine::formation_waypoints::try_get_formation_waypoints;
+use crate::engine::movement::{get_fallback_position, slot_to_position_key};
+use crate::engine::physics_constants::field;
+use crate::engine::positioning::PositionKey;
+use crate::engine::team_phase::TeamPhase;
+use crate::tactics::team_instructions::TeamInstructions;
+
+/// Vertical shift of the outfield block between the deepest and highest line (normalized)
+const LINE_HEIGHT_RANGE: f32 = 0.10;
+/// Same clamp the engine applies to target positions
+const FIELD_MARGIN: f32 = 0.05;
+
+/// One slot's target position
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SlotWaypoint {
+    /// Formation slot (0 = GK .. 10)
+    pub slot: u8,
+    /// Position label (e.g. "LCB", "ST")
+    pub position: String,
+    pub x: f32,
+    pub y: f32,
+}
+
+/// Per-slot target coordinates for a formation under the given instructions and phase.
+///
+/// - Phase picks the waypoint: Attack → offensive, Defense → defensive,
+///   transitions → base position.
+/// - Defensive line shifts the outfield block up/down the pitch.
+/// - Team width spreads/narrows the outfield block by the engine's width bias (m).
+pub fn get_formation_waypoints(
+    formation: &str,
+    instructions: &TeamInstructions,
+    phase: TeamPhase,
+) -> Result<Vec<SlotWaypoint>, String> {
+    let waypoints = try_get_formation_waypoints(formation)
+        .ok_or_else(|| format!("Unsupported formation: {}", formation))?;
+
+    let tactics = team_instructions_to_elastic_tactics(instructions);
+    let line_shift = (tactics.defensive_line_height - 0.5) * LINE_HEIGHT_RANGE;
+    let width_scale = 1.0 + 2.0 * instructions.get_width_bias_m() / field::WIDTH_M;
+
+    let slots = (0..11usize)
+        .map(|slot| {
+            let key = slot_to_position_key(slot, formation);
+            let (x, y) = match waypoints.get(&key) {
+                Some(wp) => match phase {
+                    TeamPhase::Attack => wp.offensive,
+                    TeamPhase::Defense => wp.defensive,
+                    TeamPhase::TransitionAttack | TeamPhase::TransitionDefense => wp.base,
+                },
+                None => get_fallback_position(slot),
+            };
+            let (x, y) = if key == PositionKey::GK {
+                (x, y)
+            } else {
+                (0.5 + (x - 0.5) * width_scale, y + line_shift)
+            };
+            SlotWaypoint {
+                slot: slot as u8,
+                position: format!("{:?}", key),
+                x: x.clamp(FIELD_MARGIN, 1.0 - FIELD_MARGIN),
+                y: y.clamp(FIELD_MARGIN, 1.0 - FIELD_MARGIN),
+            }
+        })
+        .collect();
+    Ok(slots)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tactics::team_instructions::{DefensiveLine, TeamWidth};
+
+    #[test]
+    fn test_phase_moves_block_and_gk_stays_home() {
+        let instructions = TeamInstructions::default();
+        let attack = get_formation_waypoints("4-3-3", &instructions, TeamPhase::Attack).unwrap();
+        let defense = get_formation_waypoints("4-3-3", &instructions, TeamPhase::Defense).unwrap();
+        assert_eq!(attack.len(), 11);
+        assert_eq!(attack[0].position, "GK");
+        assert_eq!(attack[9].position, "ST");
+        assert!(attack[9].y > defense[9].y);
+        assert!(attack[0].y < 0.1);
+    }
+
+    #[test]
+    fn test_instructions_shift_line_and_width() {
+        let base = TeamInstructions::default();
+        let high_wide = TeamInstructions {
+            defensive_line: DefensiveLine::VeryHigh,
+            team_width: TeamWidth::VeryWide,
+            ..Default::default()
+        };
+        let normal = get_formation_waypoints("4-4-2", &base, TeamPhase::Defense).unwrap();
+        let pushed = get_formation_waypoints("4-4-2", &high_wide, TeamPhase::Defense).unwrap();
+
+        // LCB higher, LM wider, GK untouched
+        assert!(pushed[2].y > normal[2].y);
+        assert!(pushed[5].x < normal[5].x);
+        assert_eq!(pushed[0], normal[0]);
+    }
+
+    #[test]
+    fn test_unknown_formation_is_an_error() {
+        let result =
+            get_formation_waypoints("2-3-5", &TeamInstructions::default(), TeamPhase::Attack);
+        assert!(result.is_err());
+    }
+}
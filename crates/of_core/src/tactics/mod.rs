@@ -3,7 +3,11 @@
 
 pub mod ai_profiles;
 pub mod famous_tactics;
+pub mod formation_waypoints;
 pub mod openfootball_bridge;
+pub mod opponent_adaptation;
+pub mod roles;
+pub mod tactical_heat;
 pub mod team_instructions;
 
 // Re-export main types
@@ -12,8 +16,8 @@ pub use openfootball_bridge::{
 };
 
 pub use team_instructions::{
-    BuildUpStyle, DefensiveLine, TacticalPreset, TeamInstructions, TeamPressing, TeamTempo,
-    TeamWidth,
+    BuildUpStyle, CornerRoutine, DefensiveLine, SetPieceRoutines, SetPieceTakers,
+    TacticalPreset, TeamInstructions, TeamPressing, TeamTempo, TeamWidth,
 };
 
 // Famous tactics presets
@@ -21,6 +25,16 @@ pub use famous_tactics::{FamousTactics, TacticalStyle as FamousTacticsStyle};
 
 // AI tactical profiles
 pub use ai_profiles::{
-    AIDifficulty, AITacticalManager, AITacticalProfile, MatchState, ADAPTIVE_AI, AGGRESSIVE_AI,
-    BALANCED_AI, COUNTER_AI, DEFENSIVE_AI,
+    AIDifficulty, AIDifficultyProfile, AITacticalManager, AITacticalProfile, MatchState,
+    ADAPTIVE_AI, AGGRESSIVE_AI, BALANCED_AI, COUNTER_AI, DEFENSIVE_AI,
 };
+
+// Formation waypoint preview (team editor / external viewers)
+pub use formation_waypoints::{get_formation_waypoints, SlotWaypoint};
+pub use tactical_heat::{get_tactical_heat_overlays, SlotHeatOverlay, TacticalHeatOverlays};
+
+// Player role effects (positioning / decision utilities / attribute weights)
+pub use roles::{instruction_utilities, role_profile, RoleProfile, RolePositioning, RoleUtilities};
+
+// AI opponent adaptation
+pub use opponent_adaptation::{adaptation_checkpoints, OpponentAdaptation};
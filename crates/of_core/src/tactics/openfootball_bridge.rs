@@ -2,6 +2,7 @@
 // Bridge between OpenFootball tactical system and our Godot integration
 // Provides 14 formations with position mappings and Korean translations
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Re-export OpenFootball types for convenience
@@ -9,7 +10,7 @@ use serde::{Deserialize, Serialize};
 /// For now, we define simplified versions that match OpenFootball's structure
 
 /// OpenFootball's position types (22 positions)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub enum PlayerPositionType {
     Goalkeeper,
     Sweeper,
@@ -102,7 +103,7 @@ impl PlayerPositionType {
 }
 
 /// OpenFootball's formation types (14 formations)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub enum MatchTacticType {
     T442,
     T433,
@@ -366,7 +367,7 @@ impl MatchTacticType {
 }
 
 /// Position with visualization coordinates
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct PositionWithCoords {
     pub slot: usize,
     pub position_type: PlayerPositionType,
@@ -380,16 +381,91 @@ impl PositionWithCoords {
     }
 }
 
+/// Min distance between two custom anchors (normalized)
+pub const MIN_CUSTOM_ANCHOR_SPACING: f32 = 0.04;
+/// Custom goalkeeper anchor must stay this close to the own goal line
+pub const MAX_CUSTOM_GK_Y: f32 = 0.2;
+
 /// Complete formation data with positions and coordinates
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct FormationData {
+    /// Preset (for custom formations: the preset the editor started from)
     pub formation_type: MatchTacticType,
     pub positions: Vec<PositionWithCoords>,
+    /// Name of an editor-made formation (None = preset)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_name: Option<String>,
 }
 
 impl FormationData {
     pub fn new(formation_type: MatchTacticType, positions: Vec<PositionWithCoords>) -> Self {
-        Self { formation_type, positions }
+        Self { formation_type, positions, custom_name: None }
+    }
+
+    /// Editor-made formation: 11 anchors with role tags, validated
+    pub fn custom(
+        name: &str,
+        base: MatchTacticType,
+        positions: Vec<PositionWithCoords>,
+    ) -> Result<FormationData, String> {
+        let formation =
+            Self { formation_type: base, positions, custom_name: Some(name.to_string()) };
+        formation.validate()?;
+        Ok(formation)
+    }
+
+    pub fn is_custom(&self) -> bool {
+        self.custom_name.is_some()
+    }
+
+    /// Check slots 0..=10 appear once, slot 0 is the only goalkeeper and stays
+    /// near goal, coordinates are normalized and no two anchors overlap
+    pub fn validate(&self) -> Result<(), String> {
+        if self.positions.len() != 11 {
+            return Err(format!("Formation needs 11 positions, got {}", self.positions.len()));
+        }
+        let mut seen = [false; 11];
+        for pos in &self.positions {
+            if pos.slot > 10 || std::mem::replace(&mut seen[pos.slot], true) {
+                return Err(format!("Formation slot {} is out of range or duplicated", pos.slot));
+            }
+            let in_range = |v: f32| v.is_finite() && (0.0..=1.0).contains(&v);
+            if !in_range(pos.x) || !in_range(pos.y) {
+                return Err(format!(
+                    "Formation slot {} anchor ({}, {}) is outside 0..=1",
+                    pos.slot, pos.x, pos.y
+                ));
+            }
+            let is_gk = pos.position_type == PlayerPositionType::Goalkeeper;
+            if is_gk != (pos.slot == 0) {
+                return Err(format!(
+                    "Formation slot {} has role {}; only slot 0 is the goalkeeper",
+                    pos.slot,
+                    pos.position_type.short_name()
+                ));
+            }
+            if is_gk && pos.y > MAX_CUSTOM_GK_Y {
+                return Err(format!("Goalkeeper anchor y {} is beyond {}", pos.y, MAX_CUSTOM_GK_Y));
+            }
+        }
+        for (i, a) in self.positions.iter().enumerate() {
+            for b in &self.positions[i + 1..] {
+                if (a.x - b.x).hypot(a.y - b.y) < MIN_CUSTOM_ANCHOR_SPACING {
+                    return Err(format!("Formation slots {} and {} overlap", a.slot, b.slot));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Anchors indexed by slot (x: 0 = left touchline, y: 0 = own goal).
+    /// Call on validated data; missing slots fall back to the pitch centre.
+    pub fn anchors(&self) -> [(f32, f32); 11] {
+        let mut anchors = [(0.5, 0.5); 11];
+        for pos in self.positions.iter().filter(|p| p.slot < 11) {
+            anchors[pos.slot] = (pos.x, pos.y);
+        }
+        anchors
     }
 
     /// Get formation data for all 14 formations
@@ -775,6 +851,33 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_custom_formation_validation() {
+        let mut positions = FormationData::for_tactic(MatchTacticType::T442).positions;
+        positions[9].y = 0.95;
+        positions[9].x = 0.5;
+        let custom = FormationData::custom("Lone target", MatchTacticType::T442, positions.clone())
+            .expect("valid custom formation");
+        assert!(custom.is_custom());
+        assert_eq!(custom.anchors()[9], (0.5, 0.95));
+        for preset in FormationData::all_formations() {
+            assert!(preset.validate().is_ok(), "{:?}", preset.formation_type);
+        }
+
+        let mut stacked = positions.clone();
+        stacked[10].x = stacked[9].x;
+        stacked[10].y = stacked[9].y;
+        assert!(FormationData::custom("Stacked", MatchTacticType::T442, stacked).is_err());
+
+        let mut two_keepers = positions.clone();
+        two_keepers[1].position_type = PlayerPositionType::Goalkeeper;
+        assert!(FormationData::custom("Keepers", MatchTacticType::T442, two_keepers).is_err());
+
+        let mut off_pitch = positions;
+        off_pitch[5].x = 1.2;
+        assert!(FormationData::custom("Wide", MatchTacticType::T442, off_pitch).is_err());
+    }
+
     #[test]
     fn test_formation_counts() {
         assert_eq!(MatchTacticType::T442.defender_count(), 4);
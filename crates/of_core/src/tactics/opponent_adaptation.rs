@@ -0,0 +1,218 @@
+//! Opponent Adaptation Layer
+//!
+//! Lets an AI team read how its opponent is playing and answer it mid-match:
+//! - Samples where the opponent holds the ball in the final third (left / centre / right)
+//! - Counts where the opponent wins the ball back (high press detection)
+//! - At fixed checkpoints, picks the dominant pattern and a matching instruction change
+//!
+//! Deterministic: same samples → same adaptation (no RNG). Checkpoints depend on
+//! the AI difficulty tier, so lower tiers adapt later (or never).
+
+use crate::models::{AdaptationPattern, AdaptationResponse, TacticalAdaptationDetails};
+use crate::tactics::ai_profiles::AIDifficulty;
+use crate::tactics::team_instructions::{BuildUpStyle, TeamInstructions, TeamWidth};
+
+/// Attack progress (0 = own goal line, 1 = opponent goal line) where the final third starts
+const FINAL_THIRD_START: f32 = 2.0 / 3.0;
+/// Minimum final-third samples (decision ticks) before a lane pattern is trusted
+const MIN_LANE_SAMPLES: u32 = 40;
+/// Lane share at/above which the lane counts as overloaded (uniform = 0.33)
+const LANE_OVERLOAD_SHARE: f32 = 0.5;
+/// Minimum regains before a press pattern is trusted
+const MIN_REGAIN_SAMPLES: u32 = 8;
+/// Share of regains in the adapting team's half that counts as a high press
+const HIGH_PRESS_SHARE: f32 = 0.45;
+
+/// Match minutes at which a tier reviews the opponent's patterns
+pub fn adaptation_checkpoints(difficulty: AIDifficulty) -> &'static [u32] {
+    match difficulty {
+        AIDifficulty::Easy => &[],
+        AIDifficulty::Medium => &[60],
+        AIDifficulty::Hard => &[30, 60],
+        AIDifficulty::Expert => &[30, 60, 75],
+    }
+}
+
+/// Apply an adaptation response on top of the team's current instructions
+pub fn apply_adaptation_response(
+    response: AdaptationResponse,
+    instructions: &mut TeamInstructions,
+) {
+    match response {
+        AdaptationResponse::WidenShape => {
+            instructions.team_width = match instructions.team_width {
+                TeamWidth::Wide | TeamWidth::VeryWide => TeamWidth::VeryWide,
+                _ => TeamWidth::Wide,
+            };
+        }
+        AdaptationResponse::NarrowShape => {
+            instructions.team_width = match instructions.team_width {
+                TeamWidth::Narrow | TeamWidth::VeryNarrow => TeamWidth::VeryNarrow,
+                _ => TeamWidth::Narrow,
+            };
+        }
+        AdaptationResponse::PlayOverPress => {
+            instructions.build_up_style = BuildUpStyle::Direct;
+        }
+    }
+}
+
+/// Tracks one opponent's patterns on behalf of an AI team
+#[derive(Debug, Clone)]
+pub struct OpponentAdaptation {
+    checkpoints: &'static [u32],
+    next_checkpoint: usize,
+    /// Final-third possession samples [left, centre, right] (attacker's view)
+    lane_samples: [u32; 3],
+    /// Opponent ball recoveries in the adapting team's half
+    high_regains: u32,
+    /// All opponent ball recoveries
+    regains: u32,
+    /// Last response applied (re-applied when the AI manager rewrites instructions)
+    active_response: Option<AdaptationResponse>,
+}
+
+impl OpponentAdaptation {
+    pub fn new(difficulty: AIDifficulty) -> Self {
+        Self {
+            checkpoints: adaptation_checkpoints(difficulty),
+            next_checkpoint: 0,
+            lane_samples: [0; 3],
+            high_regains: 0,
+            regains: 0,
+            active_response: None,
+        }
+    }
+
+    /// Record one decision tick of opponent possession.
+    ///
+    /// `progress` and `lateral` are attack-relative (0..1): progress 1 = adapting
+    /// team's goal line, lateral 0 = the attacker's left touchline.
+    pub fn observe_possession(&mut self, progress: f32, lateral: f32) {
+        if progress < FINAL_THIRD_START {
+            return;
+        }
+        let lane = ((lateral.clamp(0.0, 1.0) * 3.0) as usize).min(2);
+        self.lane_samples[lane] += 1;
+    }
+
+    /// Record the opponent winning the ball at attack-relative `progress`.
+    pub fn observe_regain(&mut self, progress: f32) {
+        self.regains += 1;
+        if progress >= 0.5 {
+            self.high_regains += 1;
+        }
+    }
+
+    pub fn active_response(&self) -> Option<AdaptationResponse> {
+        self.active_response
+    }
+
+    /// Review the window at the next due checkpoint.
+    ///
+    /// Returns the adaptation when a checkpoint is reached and a pattern is
+    /// dominant; the sample window resets at every checkpoint either way.
+    pub fn checkpoint(&mut self, minute: u32) -> Option<TacticalAdaptationDetails> {
+        let due = *self.checkpoints.get(self.next_checkpoint)?;
+        if minute < due {
+            return None;
+        }
+        self.next_checkpoint += 1;
+
+        let details = self.dominant_pattern();
+        self.lane_samples = [0; 3];
+        self.high_regains = 0;
+        self.regains = 0;
+
+        let details = details?;
+        if self.active_response == Some(details.response) {
+            return None;
+        }
+        self.active_response = Some(details.response);
+        Some(details)
+    }
+
+    fn dominant_pattern(&self) -> Option<TacticalAdaptationDetails> {
+        if self.regains >= MIN_REGAIN_SAMPLES {
+            let share = self.high_regains as f32 / self.regains as f32;
+            if share >= HIGH_PRESS_SHARE {
+                return Some(TacticalAdaptationDetails {
+                    pattern: AdaptationPattern::HighPress,
+                    response: AdaptationResponse::PlayOverPress,
+                    share,
+                    samples: self.regains,
+                });
+            }
+        }
+
+        let total: u32 = self.lane_samples.iter().sum();
+        if total < MIN_LANE_SAMPLES {
+            return None;
+        }
+        let (lane, &count) = self
+            .lane_samples
+            .iter()
+            .enumerate()
+            .max_by_key(|&(idx, &count)| (count, std::cmp::Reverse(idx)))?;
+        let share = count as f32 / total as f32;
+        if share < LANE_OVERLOAD_SHARE {
+            return None;
+        }
+        let (pattern, response) = match lane {
+            0 => (AdaptationPattern::LeftFlankOverload, AdaptationResponse::WidenShape),
+            2 => (AdaptationPattern::RightFlankOverload, AdaptationResponse::WidenShape),
+            _ => (AdaptationPattern::CentralOverload, AdaptationResponse::NarrowShape),
+        };
+        Some(TacticalAdaptationDetails { pattern, response, share, samples: total })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_left_flank_overload_widens_shape() {
+        let mut adaptation = OpponentAdaptation::new(AIDifficulty::Expert);
+        for i in 0..60 {
+            adaptation.observe_possession(0.8, if i % 4 == 0 { 0.5 } else { 0.1 });
+        }
+        assert!(adaptation.checkpoint(29).is_none());
+
+        let details = adaptation.checkpoint(30).expect("adaptation at 30'");
+        assert_eq!(details.pattern, AdaptationPattern::LeftFlankOverload);
+        assert_eq!(details.response, AdaptationResponse::WidenShape);
+        assert_eq!(details.samples, 60);
+
+        let mut instructions = TeamInstructions::default();
+        apply_adaptation_response(details.response, &mut instructions);
+        assert_eq!(instructions.team_width, TeamWidth::Wide);
+    }
+
+    #[test]
+    fn test_high_press_and_window_reset() {
+        let mut adaptation = OpponentAdaptation::new(AIDifficulty::Hard);
+        for i in 0..10 {
+            adaptation.observe_regain(if i < 6 { 0.7 } else { 0.2 });
+        }
+        let details = adaptation.checkpoint(31).unwrap();
+        assert_eq!(details.pattern, AdaptationPattern::HighPress);
+        assert_eq!(adaptation.active_response(), Some(AdaptationResponse::PlayOverPress));
+
+        // Window was reset: nothing to react to at the next checkpoint.
+        assert!(adaptation.checkpoint(60).is_none());
+        assert!(adaptation.checkpoint(90).is_none());
+    }
+
+    #[test]
+    fn test_easy_tier_never_adapts_and_own_half_ignored() {
+        let mut easy = OpponentAdaptation::new(AIDifficulty::Easy);
+        let mut medium = OpponentAdaptation::new(AIDifficulty::Medium);
+        for _ in 0..100 {
+            easy.observe_possession(0.9, 0.5);
+            medium.observe_possession(0.3, 0.5);
+        }
+        assert!(easy.checkpoint(90).is_none());
+        assert!(medium.checkpoint(60).is_none());
+    }
+}
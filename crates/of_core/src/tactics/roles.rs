@@ -0,0 +1,287 @@
+//! Player role effects in the match engine
+//!
+//! A `PlayerRole` set per player through `MatchPlan.*_player_instructions`
+//! (`PlayerInstructions.role`) shapes three things in `match_sim`:
+//! - positioning: shifts from the formation waypoint, by team phase
+//! - decision utilities: multipliers on the on-ball action biases
+//! - attribute weights: small multipliers on the attributes the role leans on
+//!
+//! Players without a role use `RoleProfile::NEUTRAL` (no effect).
+//!
+//! The individual instructions themselves (shooting, dribbling, width,
+//! passing) scale the same decision utilities through `instruction_utilities`.
+
+use crate::models::trait_system::StatType;
+use crate::player::instructions::{
+    DribblingFrequency, PassingStyle, PlayerInstructions, PlayerRole, ShootingTendency, Width,
+};
+
+/// Waypoint shifts (normalized, team-relative)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RolePositioning {
+    /// Fraction of the distance to the pitch centre line moved inward in
+    /// possession (negative = toward the touchline)
+    pub inside_attack: f32,
+    /// Forward shift in possession (+ = toward the opponent goal)
+    pub depth_attack: f32,
+    /// Forward shift out of possession
+    pub depth_defense: f32,
+}
+
+/// Multipliers on the on-ball action calibration biases (1.0 = unchanged)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RoleUtilities {
+    pub shot: f32,
+    pub take_on: f32,
+    pub cross: f32,
+    pub through_ball: f32,
+    pub progressive_pass: f32,
+    pub safe_pass: f32,
+    pub switch_play: f32,
+}
+
+impl RoleUtilities {
+    pub const NEUTRAL: Self = Self {
+        shot: 1.0,
+        take_on: 1.0,
+        cross: 1.0,
+        through_ball: 1.0,
+        progressive_pass: 1.0,
+        safe_pass: 1.0,
+        switch_play: 1.0,
+    };
+
+    /// Element-wise product (role × instructions)
+    pub fn combine(self, other: Self) -> Self {
+        Self {
+            shot: self.shot * other.shot,
+            take_on: self.take_on * other.take_on,
+            cross: self.cross * other.cross,
+            through_ball: self.through_ball * other.through_ball,
+            progressive_pass: self.progressive_pass * other.progressive_pass,
+            safe_pass: self.safe_pass * other.safe_pass,
+            switch_play: self.switch_play * other.switch_play,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RoleProfile {
+    pub positioning: RolePositioning,
+    pub utilities: RoleUtilities,
+    /// Attribute multipliers (unlisted stats = 1.0)
+    pub attribute_weights: &'static [(StatType, f32)],
+}
+
+impl RoleProfile {
+    pub const NEUTRAL: Self = Self {
+        positioning: RolePositioning { inside_attack: 0.0, depth_attack: 0.0, depth_defense: 0.0 },
+        utilities: RoleUtilities::NEUTRAL,
+        attribute_weights: &[],
+    };
+
+    pub fn attribute_weight(&self, stat: StatType) -> f32 {
+        self.attribute_weights.iter().find(|(s, _)| *s == stat).map_or(1.0, |&(_, weight)| weight)
+    }
+}
+
+const fn positioning(inside_attack: f32, depth_attack: f32, depth_defense: f32) -> RolePositioning {
+    RolePositioning { inside_attack, depth_attack, depth_defense }
+}
+
+/// Engine effects of a role
+pub fn role_profile(role: PlayerRole) -> RoleProfile {
+    use StatType::*;
+
+    let neutral = RoleUtilities::NEUTRAL;
+    match role {
+        // ===== Forward Roles =====
+        PlayerRole::TargetMan => RoleProfile {
+            positioning: positioning(0.15, 0.03, 0.0),
+            utilities: RoleUtilities {
+                shot: 1.05,
+                take_on: 0.7,
+                cross: 0.8,
+                through_ball: 0.9,
+                safe_pass: 1.15,
+                ..neutral
+            },
+            attribute_weights: &[(Heading, 1.10), (Strength, 1.08), (Jumping, 1.05)],
+        },
+        PlayerRole::Poacher => RoleProfile {
+            positioning: positioning(0.10, 0.06, 0.05),
+            utilities: RoleUtilities {
+                shot: 1.25,
+                take_on: 0.8,
+                cross: 0.7,
+                through_ball: 0.8,
+                progressive_pass: 0.85,
+                safe_pass: 0.9,
+                switch_play: 0.7,
+            },
+            attribute_weights: &[(Finishing, 1.08), (Anticipation, 1.06), (Composure, 1.04)],
+        },
+        PlayerRole::CompleteForward => RoleProfile {
+            positioning: positioning(0.0, 0.02, 0.0),
+            utilities: RoleUtilities { shot: 1.05, take_on: 1.05, ..neutral },
+            attribute_weights: &[(Finishing, 1.03), (Passing, 1.03), (Dribbling, 1.03)],
+        },
+        PlayerRole::InvertedWinger => RoleProfile {
+            positioning: positioning(0.30, 0.02, 0.0),
+            utilities: RoleUtilities {
+                shot: 1.2,
+                take_on: 1.2,
+                cross: 0.6,
+                through_ball: 1.1,
+                safe_pass: 0.9,
+                switch_play: 0.9,
+                ..neutral
+            },
+            attribute_weights: &[(Dribbling, 1.06), (LongShots, 1.08), (Finishing, 1.03)],
+        },
+
+        // ===== Midfielder Roles =====
+        PlayerRole::Playmaker => RoleProfile {
+            positioning: positioning(0.10, -0.03, 0.0),
+            utilities: RoleUtilities {
+                shot: 0.85,
+                take_on: 0.9,
+                cross: 0.9,
+                through_ball: 1.3,
+                progressive_pass: 1.2,
+                switch_play: 1.2,
+                ..neutral
+            },
+            attribute_weights: &[(Passing, 1.08), (Vision, 1.08)],
+        },
+        PlayerRole::BoxToBox => RoleProfile {
+            positioning: positioning(0.0, 0.05, -0.02),
+            utilities: RoleUtilities { shot: 1.1, progressive_pass: 1.05, ..neutral },
+            attribute_weights: &[(Stamina, 1.08), (WorkRate, 1.05)],
+        },
+        PlayerRole::BallWinning => RoleProfile {
+            positioning: positioning(0.0, -0.05, 0.03),
+            utilities: RoleUtilities {
+                shot: 0.7,
+                take_on: 0.7,
+                through_ball: 0.8,
+                progressive_pass: 0.9,
+                safe_pass: 1.25,
+                switch_play: 0.9,
+                ..neutral
+            },
+            attribute_weights: &[(Tackling, 1.06), (Aggression, 1.06), (WorkRate, 1.04)],
+        },
+
+        // ===== Defender Roles =====
+        PlayerRole::BallPlayingDefender => RoleProfile {
+            positioning: positioning(0.0, 0.02, 0.0),
+            utilities: RoleUtilities {
+                shot: 0.8,
+                take_on: 0.9,
+                through_ball: 1.1,
+                progressive_pass: 1.25,
+                switch_play: 1.25,
+                ..neutral
+            },
+            attribute_weights: &[(Passing, 1.06), (Vision, 1.06), (Composure, 1.04)],
+        },
+        PlayerRole::Stopper => RoleProfile {
+            positioning: positioning(0.0, 0.0, 0.04),
+            utilities: RoleUtilities {
+                take_on: 0.6,
+                progressive_pass: 0.8,
+                safe_pass: 1.2,
+                ..neutral
+            },
+            attribute_weights: &[(Tackling, 1.08), (Strength, 1.05), (Heading, 1.05)],
+        },
+        PlayerRole::CoveringDefender => RoleProfile {
+            positioning: positioning(0.0, 0.0, -0.04),
+            utilities: RoleUtilities { take_on: 0.7, safe_pass: 1.2, ..neutral },
+            attribute_weights: &[(Positioning, 1.06), (Anticipation, 1.06), (Pace, 1.03)],
+        },
+    }
+}
+
+/// Decision utilities of individual instructions (default instructions = neutral)
+pub fn instruction_utilities(instructions: &PlayerInstructions) -> RoleUtilities {
+    let mut u = RoleUtilities::NEUTRAL;
+
+    match instructions.shooting {
+        ShootingTendency::ShootOnSight => u.shot *= 1.3,
+        ShootingTendency::Conservative => u.shot *= 0.75,
+        ShootingTendency::Normal => {}
+    }
+    match instructions.dribbling {
+        DribblingFrequency::Often => u.take_on *= 1.25,
+        DribblingFrequency::Rarely => u.take_on *= 0.7,
+        DribblingFrequency::Normal => {}
+    }
+    match instructions.width {
+        Width::StayWide => u.cross *= 1.3,
+        Width::CutInside => {
+            u.cross *= 0.7;
+            u.take_on *= 1.1;
+            u.shot *= 1.1;
+        }
+        Width::Roam | Width::Normal => {}
+    }
+    match instructions.passing {
+        PassingStyle::Short => {
+            u.safe_pass *= 1.15;
+            u.switch_play *= 0.8;
+        }
+        PassingStyle::Direct => {
+            u.through_ball *= 1.15;
+            u.switch_play *= 1.2;
+            u.safe_pass *= 0.9;
+        }
+        PassingStyle::Mixed => {}
+    }
+    u
+}
+
+/// Profile for an optional role (`None` = neutral)
+pub fn profile_for(role: Option<PlayerRole>) -> RoleProfile {
+    role.map_or(RoleProfile::NEUTRAL, role_profile)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_role_profiles_lean_on_their_strengths() {
+        let poacher = role_profile(PlayerRole::Poacher);
+        assert!(poacher.utilities.shot > 1.0);
+        assert!(poacher.positioning.depth_defense > 0.0);
+        assert_eq!(poacher.attribute_weight(StatType::Finishing), 1.08);
+        assert_eq!(poacher.attribute_weight(StatType::Tackling), 1.0);
+
+        let winger = role_profile(PlayerRole::InvertedWinger);
+        assert!(winger.positioning.inside_attack > 0.0);
+        assert!(winger.utilities.cross < 1.0);
+
+        let bpd = role_profile(PlayerRole::BallPlayingDefender);
+        assert!(bpd.utilities.progressive_pass > bpd.utilities.safe_pass);
+
+        assert_eq!(profile_for(None), RoleProfile::NEUTRAL);
+    }
+
+    #[test]
+    fn test_instruction_utilities() {
+        assert_eq!(instruction_utilities(&PlayerInstructions::default()), RoleUtilities::NEUTRAL);
+
+        let wide = PlayerInstructions {
+            width: Width::StayWide,
+            shooting: ShootingTendency::ShootOnSight,
+            ..Default::default()
+        };
+        let u = instruction_utilities(&wide);
+        assert!(u.cross > 1.0 && u.shot > 1.0);
+
+        let combined = role_profile(PlayerRole::Poacher).utilities.combine(u);
+        assert!((combined.shot - 1.25 * 1.3).abs() < 1e-6);
+    }
+}
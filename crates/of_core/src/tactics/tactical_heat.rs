@@ -0,0 +1,210 @@
+//! Tactical heat overlays for the team editor
+//!
+//! Previews how a formation + instructions spread each slot over the pitch
+//! before simulating:
+//! - coverage: the corridor a slot patrols between its defensive, base and
+//!   offensive waypoints (from `get_formation_waypoints`)
+//! - pressure: where the slot closes down out of possession, stepped up and
+//!   widened by pressing intensity
+//!
+//! Grids use the same team-relative frame as `SlotWaypoint` and are row-major
+//! (`cells[row * cols + col]`): columns run across the pitch (x), rows from
+//! the own goal line (row 0) to the opponent goal line (y).
+
+use serde::{Deserialize, Serialize};
+
+use crate::engine::physics_constants::field;
+use crate::engine::team_phase::TeamPhase;
+use crate::tactics::formation_waypoints::{get_formation_waypoints, SlotWaypoint};
+use crate::tactics::team_instructions::TeamInstructions;
+
+pub const DEFAULT_HEAT_COLS: usize = 12;
+pub const DEFAULT_HEAT_ROWS: usize = 16;
+const MIN_GRID: usize = 4;
+const MAX_GRID: usize = 48;
+
+/// Spread of a slot's coverage around its waypoint corridor (m)
+const COVERAGE_SIGMA_M: f32 = 9.0;
+/// Corridor samples between defensive → base → offensive waypoints
+const CORRIDOR_SAMPLES: usize = 9;
+/// Pressing radius at pressing factor 0 / 1 (m)
+const PRESS_RADIUS_MIN_M: f32 = 5.0;
+const PRESS_RADIUS_MAX_M: f32 = 14.0;
+/// Forward step of the pressing point at pressing factor 1 (normalized)
+const PRESS_STEP_UP: f32 = 0.08;
+
+/// One slot's overlays
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SlotHeatOverlay {
+    pub slot: u8,
+    pub position: String,
+    /// Coverage weight per cell (0-1, peak = 1)
+    pub coverage: Vec<f32>,
+    /// Pressure intensity per cell (0-1, peak = pressing factor; GK = 0)
+    pub pressure: Vec<f32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TacticalHeatOverlays {
+    pub cols: usize,
+    pub rows: usize,
+    pub slots: Vec<SlotHeatOverlay>,
+    /// Per-cell max over all slots
+    pub team_coverage: Vec<f32>,
+    pub team_pressure: Vec<f32>,
+}
+
+fn gaussian(dx_m: f32, dy_m: f32, sigma_m: f32) -> f32 {
+    (-(dx_m * dx_m + dy_m * dy_m) / (2.0 * sigma_m * sigma_m)).exp()
+}
+
+fn lerp(a: (f32, f32), b: (f32, f32), t: f32) -> (f32, f32) {
+    (a.0 + (b.0 - a.0) * t, a.1 + (b.1 - a.1) * t)
+}
+
+/// Cell centers in normalized team-relative coordinates
+fn cell_centers(cols: usize, rows: usize) -> Vec<(f32, f32)> {
+    (0..rows)
+        .flat_map(|row| {
+            (0..cols).map(move |col| {
+                ((col as f32 + 0.5) / cols as f32, (row as f32 + 0.5) / rows as f32)
+            })
+        })
+        .collect()
+}
+
+/// Max of `sigma`-wide bumps around `points`, scaled by `peak`
+fn heat(centers: &[(f32, f32)], points: &[(f32, f32)], sigma_m: f32, peak: f32) -> Vec<f32> {
+    centers
+        .iter()
+        .map(|&(cx, cy)| {
+            let best = points
+                .iter()
+                .map(|&(px, py)| {
+                    gaussian((cx - px) * field::WIDTH_M, (cy - py) * field::LENGTH_M, sigma_m)
+                })
+                .fold(0.0f32, f32::max);
+            best * peak
+        })
+        .collect()
+}
+
+fn team_max(
+    slots: &[SlotHeatOverlay],
+    cells: usize,
+    layer: fn(&SlotHeatOverlay) -> &[f32],
+) -> Vec<f32> {
+    (0..cells).map(|i| slots.iter().map(|s| layer(s)[i]).fold(0.0f32, f32::max)).collect()
+}
+
+/// Coverage/pressure overlays per slot for a formation under the given instructions.
+///
+/// Grid size is clamped to 4-48 cells per axis.
+pub fn get_tactical_heat_overlays(
+    formation: &str,
+    instructions: &TeamInstructions,
+    cols: usize,
+    rows: usize,
+) -> Result<TacticalHeatOverlays, String> {
+    let cols = cols.clamp(MIN_GRID, MAX_GRID);
+    let rows = rows.clamp(MIN_GRID, MAX_GRID);
+    let waypoints = |phase| get_formation_waypoints(formation, instructions, phase);
+    let defense = waypoints(TeamPhase::Defense)?;
+    let base = waypoints(TeamPhase::TransitionDefense)?;
+    let attack = waypoints(TeamPhase::Attack)?;
+
+    let pressing = instructions.get_pressing_factor();
+    let press_radius = PRESS_RADIUS_MIN_M + (PRESS_RADIUS_MAX_M - PRESS_RADIUS_MIN_M) * pressing;
+    let centers = cell_centers(cols, rows);
+    let at = |wp: &SlotWaypoint| (wp.x, wp.y);
+
+    let slots: Vec<SlotHeatOverlay> = (0..defense.len())
+        .map(|i| {
+            let (d, b, a) = (at(&defense[i]), at(&base[i]), at(&attack[i]));
+            let corridor: Vec<(f32, f32)> = (0..CORRIDOR_SAMPLES)
+                .map(|s| {
+                    let t = s as f32 / (CORRIDOR_SAMPLES - 1) as f32 * 2.0;
+                    if t <= 1.0 {
+                        lerp(d, b, t)
+                    } else {
+                        lerp(b, a, t - 1.0)
+                    }
+                })
+                .collect();
+            let coverage = heat(&centers, &corridor, COVERAGE_SIGMA_M, 1.0);
+
+            let pressure = if defense[i].position == "GK" {
+                vec![0.0; centers.len()]
+            } else {
+                let press_point = (d.0, (d.1 + PRESS_STEP_UP * pressing).min(1.0));
+                heat(&centers, &[press_point], press_radius, pressing)
+            };
+
+            SlotHeatOverlay {
+                slot: defense[i].slot,
+                position: defense[i].position.clone(),
+                coverage,
+                pressure,
+            }
+        })
+        .collect();
+
+    let team_coverage = team_max(&slots, centers.len(), |s| &s.coverage);
+    let team_pressure = team_max(&slots, centers.len(), |s| &s.pressure);
+    Ok(TacticalHeatOverlays { cols, rows, slots, team_coverage, team_pressure })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tactics::team_instructions::{DefensiveLine, TeamPressing};
+
+    fn weighted_row(cells: &[f32], cols: usize) -> f32 {
+        let total: f32 = cells.iter().sum();
+        cells.iter().enumerate().map(|(i, v)| (i / cols) as f32 * v).sum::<f32>() / total
+    }
+
+    #[test]
+    fn test_overlay_shape_and_gk_never_presses() {
+        let overlays =
+            get_tactical_heat_overlays("4-3-3", &TeamInstructions::default(), 10, 100).unwrap();
+        assert_eq!((overlays.cols, overlays.rows), (10, MAX_GRID));
+        assert_eq!(overlays.slots.len(), 11);
+        let cells = overlays.cols * overlays.rows;
+        for slot in &overlays.slots {
+            assert_eq!(slot.coverage.len(), cells);
+            assert!(slot.coverage.iter().all(|v| (0.0..=1.0).contains(v)));
+        }
+        assert!(overlays.slots[0].pressure.iter().all(|&v| v == 0.0));
+        // GK covers its own box, the striker the opponent half
+        let gk_row = weighted_row(&overlays.slots[0].coverage, overlays.cols);
+        let st_row = weighted_row(&overlays.slots[9].coverage, overlays.cols);
+        assert!(gk_row < st_row);
+        assert!(get_tactical_heat_overlays("2-3-5", &TeamInstructions::default(), 8, 8).is_err());
+    }
+
+    #[test]
+    fn test_high_press_raises_and_widens_pressure() {
+        let low = TeamInstructions {
+            pressing_intensity: TeamPressing::VeryLow,
+            defensive_line: DefensiveLine::Deep,
+            ..Default::default()
+        };
+        let high = TeamInstructions {
+            pressing_intensity: TeamPressing::VeryHigh,
+            defensive_line: DefensiveLine::High,
+            ..Default::default()
+        };
+        let low = get_tactical_heat_overlays("4-4-2", &low, 12, 16).unwrap();
+        let high = get_tactical_heat_overlays("4-4-2", &high, 12, 16).unwrap();
+
+        let sum = |cells: &[f32]| cells.iter().sum::<f32>();
+        assert!(sum(&high.team_pressure) > sum(&low.team_pressure));
+        assert!(
+            weighted_row(&high.slots[2].pressure, 12) > weighted_row(&low.slots[2].pressure, 12)
+        );
+        assert!(
+            weighted_row(&high.slots[2].coverage, 12) > weighted_row(&low.slots[2].coverage, 12)
+        );
+    }
+}
@@ -3,10 +3,11 @@
 //! Provides team-level tactical settings that affect all players' behavior in matches.
 //! This extends beyond individual player instructions to shape overall team strategy.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Team-wide tactical instructions that affect overall team behavior
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
 pub struct TeamInstructions {
     /// Defensive line height/depth
     pub defensive_line: DefensiveLine,
@@ -25,6 +26,9 @@ pub struct TeamInstructions {
     /// Use offside trap tactic
     #[serde(default)]
     pub use_offside_trap: bool,
+    /// Corner / free-kick routines
+    #[serde(default)]
+    pub set_pieces: SetPieceRoutines,
 }
 
 impl Default for TeamInstructions {
@@ -36,6 +40,7 @@ impl Default for TeamInstructions {
             pressing_intensity: TeamPressing::Medium,
             build_up_style: BuildUpStyle::Mixed,
             use_offside_trap: false,
+            set_pieces: SetPieceRoutines::DEFAULT,
         }
     }
 }
@@ -116,6 +121,7 @@ impl TeamInstructions {
                 pressing_intensity: TeamPressing::VeryHigh,
                 build_up_style: BuildUpStyle::Short,
                 use_offside_trap: true, // 높은 라인 + 트랩
+                set_pieces: SetPieceRoutines::DEFAULT,
             },
             TacticalPreset::Counterattack => Self {
                 defensive_line: DefensiveLine::Deep,
@@ -124,6 +130,7 @@ impl TeamInstructions {
                 pressing_intensity: TeamPressing::Low,
                 build_up_style: BuildUpStyle::Direct,
                 use_offside_trap: false, // 낮은 라인은 트랩 안함
+                set_pieces: SetPieceRoutines::DEFAULT,
             },
             TacticalPreset::Possession => Self {
                 defensive_line: DefensiveLine::High,
@@ -132,6 +139,7 @@ impl TeamInstructions {
                 pressing_intensity: TeamPressing::Medium,
                 build_up_style: BuildUpStyle::Short,
                 use_offside_trap: true, // 높은 라인 + 트랩
+                set_pieces: SetPieceRoutines::DEFAULT,
             },
             TacticalPreset::Balanced => Self::default(),
             TacticalPreset::Defensive => Self {
@@ -141,6 +149,7 @@ impl TeamInstructions {
                 pressing_intensity: TeamPressing::Low,
                 build_up_style: BuildUpStyle::Direct,
                 use_offside_trap: false, // 낮은 라인은 트랩 안함
+                set_pieces: SetPieceRoutines::DEFAULT,
             },
         }
     }
@@ -158,8 +167,90 @@ impl TeamInstructions {
     }
 }
 
+/// Corner and free-kick routines, including designated takers
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
+pub struct SetPieceRoutines {
+    /// Attacking corner delivery
+    #[serde(default)]
+    pub corner: CornerRoutine,
+    /// Defensive wall jumps on direct free kicks
+    #[serde(default)]
+    pub wall_jump: bool,
+    /// Corner takers by track_id, in priority order (empty = best corners attribute)
+    #[serde(default)]
+    pub corner_takers: Vec<usize>,
+    /// Free-kick takers by track_id, in priority order (empty = best free_kicks attribute)
+    #[serde(default)]
+    pub free_kick_takers: Vec<usize>,
+}
+
+impl SetPieceRoutines {
+    /// Engine defaults: mixed corners, standing wall, attribute-picked takers
+    pub const DEFAULT: Self = Self {
+        corner: CornerRoutine::Mixed,
+        wall_jump: false,
+        corner_takers: Vec::new(),
+        free_kick_takers: Vec::new(),
+    };
+}
+
+impl Default for SetPieceRoutines {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Designated taker priority lists by track_id (0-10 home, 11-21 away).
+/// Checked before the `SetPieceRoutines` lists; unavailable entries fall back
+/// to attribute-based selection.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, JsonSchema)]
+pub struct SetPieceTakers {
+    #[serde(default)]
+    pub penalties: Vec<usize>,
+    #[serde(default)]
+    pub direct_free_kicks: Vec<usize>,
+    /// Corners taken from the team-view y = 0 touchline
+    #[serde(default)]
+    pub corners_left: Vec<usize>,
+    #[serde(default)]
+    pub corners_right: Vec<usize>,
+}
+
+impl SetPieceTakers {
+    /// Every listed track_id must belong to the team's pitch slots
+    pub fn validate(&self, is_home: bool) -> Result<(), String> {
+        let (team_range, side) = if is_home { (0..11, "home") } else { (11..22, "away") };
+        let lists = [
+            ("penalties", &self.penalties),
+            ("direct_free_kicks", &self.direct_free_kicks),
+            ("corners_left", &self.corners_left),
+            ("corners_right", &self.corners_right),
+        ];
+        for (name, takers) in lists {
+            if let Some(id) = takers.iter().find(|&&id| !team_range.contains(&id)) {
+                return Err(format!("{} taker track_id {} is not a {} pitch slot", name, id, side));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Attacking corner routine
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+pub enum CornerRoutine {
+    /// Inswinging cross to the penalty spot
+    #[default]
+    Mixed,
+    /// Driven ball to the near post
+    NearPost,
+    /// Lofted ball to the far post
+    FarPost,
+    /// Short pass to a nearby teammate
+    Short,
+}
+
 /// Defensive line height - affects offside trap potential and space behind defense
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
 pub enum DefensiveLine {
     /// Very high line - constant offside trap, aggressive pressing
     VeryHigh,
@@ -232,7 +323,7 @@ impl DefensiveLine {
 }
 
 /// Team width - affects spacing between players and pitch coverage
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
 pub enum TeamWidth {
     /// Very wide - maximize pitch width, stretch opposition
     VeryWide,
@@ -291,7 +382,7 @@ impl TeamWidth {
 }
 
 /// Team tempo - affects speed of transitions and build-up
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
 pub enum TeamTempo {
     /// Very fast tempo - rapid transitions
     VeryFast,
@@ -361,7 +452,7 @@ impl TeamTempo {
 }
 
 /// Team-wide pressing intensity
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
 pub enum TeamPressing {
     /// Very high pressing - constant pressure
     VeryHigh,
@@ -430,7 +521,7 @@ impl TeamPressing {
 }
 
 /// Build-up style from defense
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
 pub enum BuildUpStyle {
     /// Short passing from back
     #[serde(alias = "ShortPassing")]
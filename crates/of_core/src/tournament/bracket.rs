@@ -0,0 +1,499 @@
+//! Tournament brackets
+//!
+//! Teams are seeded by a seeded shuffle, then played through one of:
+//! - single elimination (byes go to the top seeds; seeds 1 and 2 can only meet in the final)
+//! - double elimination (winners/losers brackets, grand final with reset)
+//! - group stage (single round robin) + single-elimination knockout
+//!
+//! Every tie gets its own seed derived from the tournament seed and the tie's
+//! order of play, so a tournament replays identically.
+
+use std::collections::BTreeMap;
+
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+
+use super::play::{play_with_engine, MatchOutcome, TieRules};
+use crate::models::Team;
+use crate::season::{FixtureGenerator, FixtureResult, Standings, StandingsRow};
+
+/// Entries in `TournamentResult::top_scorers`
+pub const TOP_SCORERS_LIMIT: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TournamentFormat {
+    #[default]
+    SingleElimination,
+    DoubleElimination,
+    /// Groups of `group_size` (last groups may be one smaller); the top
+    /// `advance_per_group` of each group enter a single-elimination knockout
+    GroupsThenKnockout {
+        group_size: usize,
+        advance_per_group: usize,
+    },
+}
+
+/// One played tie (teams are indices into the entry list)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TournamentMatch {
+    pub home: usize,
+    pub away: usize,
+    pub outcome: MatchOutcome,
+    /// None = drawn group match
+    pub winner: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TournamentRound {
+    pub name: String,
+    pub matches: Vec<TournamentMatch>,
+    /// Teams that advanced without playing this round
+    #[serde(default)]
+    pub byes: Vec<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GroupTable {
+    pub name: String,
+    /// Rows use tournament team indices
+    pub standings: Vec<StandingsRow>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TopScorer {
+    pub player: String,
+    pub team: usize,
+    pub team_name: String,
+    pub goals: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TournamentResult {
+    pub format: TournamentFormat,
+    /// Team indices in seed order (seed 1 first)
+    pub seeding: Vec<usize>,
+    #[serde(default)]
+    pub groups: Vec<GroupTable>,
+    pub rounds: Vec<TournamentRound>,
+    pub champion: usize,
+    pub champion_name: String,
+    pub runner_up: usize,
+    pub top_scorers: Vec<TopScorer>,
+}
+
+/// Simulate a whole tournament with the match engine
+pub fn simulate_tournament(
+    teams: &[Team],
+    format: TournamentFormat,
+    seed: u64,
+    rules: TieRules,
+) -> Result<TournamentResult, String> {
+    simulate_tournament_with(teams, format, seed, |home, away, tie_seed, knockout| {
+        play_with_engine(home, away, tie_seed, knockout, &rules)
+    })
+}
+
+/// Simulate with a custom tie resolver `(home, away, tie_seed, knockout) → outcome`
+pub fn simulate_tournament_with<F>(
+    teams: &[Team],
+    format: TournamentFormat,
+    seed: u64,
+    play: F,
+) -> Result<TournamentResult, String>
+where
+    F: FnMut(&Team, &Team, u64, bool) -> Result<MatchOutcome, String>,
+{
+    if teams.len() < 2 {
+        return Err(format!("Tournament needs at least 2 teams, found {}", teams.len()));
+    }
+    let mut seeding: Vec<usize> = (0..teams.len()).collect();
+    seeding.shuffle(&mut ChaCha8Rng::seed_from_u64(seed));
+
+    let mut sim = BracketSim { teams, seed, play, ties_played: 0, rounds: Vec::new() };
+    let mut groups = Vec::new();
+    let (champion, runner_up) = match format {
+        TournamentFormat::SingleElimination => sim.single_elimination(&seeding)?,
+        TournamentFormat::DoubleElimination => sim.double_elimination(&seeding)?,
+        TournamentFormat::GroupsThenKnockout { group_size, advance_per_group } => {
+            let qualifiers;
+            (groups, qualifiers) = sim.group_stage(&seeding, group_size, advance_per_group)?;
+            sim.single_elimination(&qualifiers)?
+        }
+    };
+
+    let top_scorers = top_scorers(teams, &sim.rounds);
+    Ok(TournamentResult {
+        format,
+        seeding,
+        groups,
+        rounds: sim.rounds,
+        champion,
+        champion_name: teams[champion].name.clone(),
+        runner_up,
+        top_scorers,
+    })
+}
+
+struct BracketSim<'a, F> {
+    teams: &'a [Team],
+    seed: u64,
+    play: F,
+    ties_played: u64,
+    rounds: Vec<TournamentRound>,
+}
+
+impl<F> BracketSim<'_, F>
+where
+    F: FnMut(&Team, &Team, u64, bool) -> Result<MatchOutcome, String>,
+{
+    fn tie(&mut self, home: usize, away: usize, knockout: bool) -> Result<TournamentMatch, String> {
+        let tie_seed = tie_seed(self.seed, self.ties_played);
+        self.ties_played += 1;
+        let outcome = (self.play)(&self.teams[home], &self.teams[away], tie_seed, knockout)?;
+        let winner = outcome.home_won().map(|home_won| if home_won { home } else { away });
+        if knockout && winner.is_none() {
+            return Err(format!(
+                "Knockout tie {} vs {} ended without a winner",
+                self.teams[home].name, self.teams[away].name
+            ));
+        }
+        Ok(TournamentMatch { home, away, outcome, winner })
+    }
+
+    /// Returns (champion, runner-up)
+    fn single_elimination(&mut self, seeds: &[usize]) -> Result<(usize, usize), String> {
+        let size = seeds.len().next_power_of_two();
+        let mut slots: Vec<Option<usize>> =
+            bracket_positions(size).into_iter().map(|p| seeds.get(p).copied()).collect();
+        let mut runner_up = None;
+
+        while slots.len() > 1 {
+            let mut round =
+                TournamentRound { name: round_name(slots.len()), matches: vec![], byes: vec![] };
+            let mut next = Vec::with_capacity(slots.len() / 2);
+            for pair in slots.chunks(2) {
+                match (pair[0], pair[1]) {
+                    (Some(home), Some(away)) => {
+                        let tie = self.tie(home, away, true)?;
+                        let winner = tie.winner.unwrap_or(home);
+                        if slots.len() == 2 {
+                            runner_up = Some(if winner == home { away } else { home });
+                        }
+                        next.push(Some(winner));
+                        round.matches.push(tie);
+                    }
+                    (Some(team), None) | (None, Some(team)) => {
+                        round.byes.push(team);
+                        next.push(Some(team));
+                    }
+                    (None, None) => next.push(None),
+                }
+            }
+            self.rounds.push(round);
+            slots = next;
+        }
+
+        let champion = slots[0].ok_or("Bracket finished without a champion")?;
+        Ok((champion, runner_up.ok_or("Bracket finished without a final")?))
+    }
+
+    /// Two losses eliminate; winners/losers bracket rounds alternate
+    fn double_elimination(&mut self, seeds: &[usize]) -> Result<(usize, usize), String> {
+        let mut winners = seeds.to_vec();
+        let mut losers: Vec<usize> = Vec::new();
+        let mut round_no = 1;
+
+        while winners.len() > 1 || losers.len() > 1 {
+            if winners.len() > 1 {
+                let (advanced, dropped) =
+                    self.elimination_round(&winners, format!("Winners round {}", round_no))?;
+                winners = advanced;
+                losers.extend(dropped);
+            }
+            if losers.len() > 1 {
+                let (advanced, _) =
+                    self.elimination_round(&losers, format!("Losers round {}", round_no))?;
+                losers = advanced;
+            }
+            round_no += 1;
+        }
+
+        let (upper, lower) = (winners[0], losers[0]);
+        let final_tie = self.tie(upper, lower, true)?;
+        let lower_won = final_tie.winner == Some(lower);
+        self.rounds.push(TournamentRound {
+            name: "Grand final".to_string(),
+            matches: vec![final_tie],
+            byes: vec![],
+        });
+        if !lower_won {
+            return Ok((upper, lower));
+        }
+
+        // Both teams now have one loss: play the reset
+        let reset = self.tie(lower, upper, true)?;
+        let champion = reset.winner.unwrap_or(lower);
+        self.rounds.push(TournamentRound {
+            name: "Grand final reset".to_string(),
+            matches: vec![reset],
+            byes: vec![],
+        });
+        Ok((champion, if champion == upper { lower } else { upper }))
+    }
+
+    /// Top half vs bottom half (1 v n, 2 v n-1, ...); odd team out gets a bye.
+    /// Returns (advanced, eliminated) in bracket order.
+    fn elimination_round(
+        &mut self,
+        teams: &[usize],
+        name: String,
+    ) -> Result<(Vec<usize>, Vec<usize>), String> {
+        let mut round = TournamentRound { name, matches: vec![], byes: vec![] };
+        let (mut advanced, mut eliminated) = (Vec::new(), Vec::new());
+        for i in 0..teams.len() / 2 {
+            let (home, away) = (teams[i], teams[teams.len() - 1 - i]);
+            let tie = self.tie(home, away, true)?;
+            let winner = tie.winner.unwrap_or(home);
+            advanced.push(winner);
+            eliminated.push(if winner == home { away } else { home });
+            round.matches.push(tie);
+        }
+        if teams.len() % 2 == 1 {
+            let bye = teams[teams.len() / 2];
+            round.byes.push(bye);
+            advanced.push(bye);
+        }
+        self.rounds.push(round);
+        Ok((advanced, eliminated))
+    }
+
+    /// Snake-seeded groups; returns the tables and the knockout seeds
+    /// (all group winners, then all runners-up, ...)
+    fn group_stage(
+        &mut self,
+        seeds: &[usize],
+        group_size: usize,
+        advance_per_group: usize,
+    ) -> Result<(Vec<GroupTable>, Vec<usize>), String> {
+        if group_size < 2 {
+            return Err(format!("group_size must be at least 2, got {}", group_size));
+        }
+        let group_count = seeds.len().div_ceil(group_size);
+        let mut members: Vec<Vec<usize>> = vec![Vec::new(); group_count];
+        for (i, &team) in seeds.iter().enumerate() {
+            let col = i % group_count;
+            let group = if (i / group_count) % 2 == 0 { col } else { group_count - 1 - col };
+            members[group].push(team);
+        }
+        let smallest = members.iter().map(Vec::len).min().unwrap_or(0);
+        if smallest < 2 {
+            return Err("Every group needs at least 2 teams".to_string());
+        }
+        if advance_per_group == 0 || advance_per_group > smallest {
+            return Err(format!(
+                "advance_per_group must be 1..={} for these groups, got {}",
+                smallest, advance_per_group
+            ));
+        }
+        if advance_per_group * group_count < 2 {
+            return Err("Knockout stage needs at least 2 qualifiers".to_string());
+        }
+
+        let mut tables = Vec::with_capacity(group_count);
+        let mut placed: Vec<Vec<usize>> = Vec::with_capacity(group_count);
+        for (g, group) in members.iter().enumerate() {
+            let name = format!("Group {}", (b'A' + (g % 26) as u8) as char);
+            let fixtures =
+                FixtureGenerator::round_robin(group.len(), false, tie_seed(self.seed, g as u64))?;
+            let mut standings =
+                Standings::new(group.iter().map(|&t| self.teams[t].name.clone()).collect());
+            let mut round = TournamentRound { name: name.clone(), matches: vec![], byes: vec![] };
+            for fixture in fixtures {
+                let tie = self.tie(group[fixture.home], group[fixture.away], false)?;
+                let (home_goals, away_goals) = tie.outcome.total();
+                standings.record(FixtureResult {
+                    matchday: fixture.matchday,
+                    home: fixture.home,
+                    away: fixture.away,
+                    home_goals,
+                    away_goals,
+                })?;
+                round.matches.push(tie);
+            }
+            self.rounds.push(round);
+
+            let mut rows = standings.table();
+            for row in rows.iter_mut() {
+                row.team = group[row.team];
+            }
+            placed.push(rows.iter().map(|r| r.team).collect());
+            tables.push(GroupTable { name, standings: rows });
+        }
+
+        let qualifiers = (0..advance_per_group)
+            .flat_map(|place| placed.iter().map(move |order| order[place]))
+            .collect();
+        Ok((tables, qualifiers))
+    }
+}
+
+/// Bracket slot → seed index, e.g. size 8 → [0, 7, 3, 4, 1, 6, 2, 5]
+fn bracket_positions(size: usize) -> Vec<usize> {
+    let mut positions = vec![0];
+    while positions.len() < size {
+        let n = positions.len() * 2;
+        positions = positions.iter().flat_map(|&s| [s, n - 1 - s]).collect();
+    }
+    positions
+}
+
+fn round_name(slots: usize) -> String {
+    match slots {
+        2 => "Final".to_string(),
+        4 => "Semi-finals".to_string(),
+        8 => "Quarter-finals".to_string(),
+        n => format!("Round of {}", n),
+    }
+}
+
+/// Per-tie engine seed (SplitMix64 over tournament seed and tie order)
+fn tie_seed(seed: u64, index: u64) -> u64 {
+    let mut z = seed ^ index.wrapping_add(1).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+fn top_scorers(teams: &[Team], rounds: &[TournamentRound]) -> Vec<TopScorer> {
+    let mut goals: BTreeMap<(usize, &str), u32> = BTreeMap::new();
+    for tie in rounds.iter().flat_map(|r| &r.matches) {
+        for goal in &tie.outcome.scorers {
+            let team = if goal.is_home { tie.home } else { tie.away };
+            *goals.entry((team, goal.player.as_str())).or_default() += 1;
+        }
+    }
+    let mut scorers: Vec<TopScorer> = goals
+        .into_iter()
+        .map(|((team, player), goals)| TopScorer {
+            player: player.to_string(),
+            team,
+            team_name: teams[team].name.clone(),
+            goals,
+        })
+        .collect();
+    scorers.sort_by(|a, b| b.goals.cmp(&a.goals).then_with(|| a.player.cmp(&b.player)));
+    scorers.truncate(TOP_SCORERS_LIMIT);
+    scorers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::match_sim::test_fixtures::create_test_team_with_subs as create_test_team;
+    use crate::tournament::play::GoalRecord;
+
+    fn teams(n: usize) -> Vec<Team> {
+        (0..n).map(|i| create_test_team(&format!("T{}", i))).collect()
+    }
+
+    /// Stronger = lower index; group matches between neighbours are drawn
+    fn fake_play(
+        home: &Team,
+        away: &Team,
+        _seed: u64,
+        knockout: bool,
+    ) -> Result<MatchOutcome, String> {
+        let rank = |t: &Team| t.name[1..].parse::<i32>().unwrap();
+        let (h, a) = (rank(home), rank(away));
+        let (home_goals, away_goals) = if !knockout && (h - a).abs() == 1 {
+            (1, 1)
+        } else if h < a {
+            (2, 0)
+        } else {
+            (0, 2)
+        };
+        let scorer = if h < a { home } else { away };
+        Ok(MatchOutcome {
+            home_goals,
+            away_goals,
+            scorers: vec![GoalRecord {
+                is_home: h < a,
+                player: scorer.players[9].name.clone(),
+                minute: 10,
+            }],
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_bracket_positions_keep_top_seeds_apart() {
+        assert_eq!(bracket_positions(8), vec![0, 7, 3, 4, 1, 6, 2, 5]);
+        assert_eq!(round_name(16), "Round of 16");
+    }
+
+    #[test]
+    fn test_single_elimination_with_byes() {
+        let teams = teams(6);
+        let result =
+            simulate_tournament_with(&teams, TournamentFormat::SingleElimination, 4, fake_play)
+                .unwrap();
+        assert_eq!(result.champion, 0);
+        assert_ne!(result.runner_up, 0);
+        assert_eq!(result.rounds.len(), 3);
+        assert_eq!(result.rounds[0].byes.len(), 2);
+        assert_eq!(result.rounds[2].name, "Final");
+        assert_eq!(result.top_scorers[0].team, 0);
+        assert!(result.top_scorers[0].goals >= 2);
+    }
+
+    #[test]
+    fn test_double_elimination_needs_two_losses() {
+        let teams = teams(5);
+        let result =
+            simulate_tournament_with(&teams, TournamentFormat::DoubleElimination, 9, fake_play)
+                .unwrap();
+        assert_eq!(result.champion, 0);
+        assert_eq!(result.runner_up, 1);
+
+        let mut losses = [0u32; 5];
+        for tie in result.rounds.iter().flat_map(|r| &r.matches) {
+            let loser = if tie.winner == Some(tie.home) { tie.away } else { tie.home };
+            losses[loser] += 1;
+        }
+        assert_eq!(losses[0], 0);
+        assert!(losses[1..].iter().all(|&l| l == 2));
+    }
+
+    #[test]
+    fn test_groups_feed_knockout() {
+        let teams = teams(8);
+        let format = TournamentFormat::GroupsThenKnockout { group_size: 4, advance_per_group: 2 };
+        let result = simulate_tournament_with(&teams, format, 2, fake_play).unwrap();
+        assert_eq!(result.groups.len(), 2);
+        assert!(result.groups.iter().all(|g| g.standings.len() == 4));
+        assert_eq!(result.rounds.iter().filter(|r| r.name.starts_with("Group")).count(), 2);
+        assert_eq!(result.rounds.last().unwrap().name, "Final");
+        assert_eq!(result.champion, 0);
+
+        let bad = TournamentFormat::GroupsThenKnockout { group_size: 4, advance_per_group: 5 };
+        assert!(simulate_tournament_with(&teams, bad, 2, fake_play).is_err());
+    }
+
+    #[test]
+    fn test_engine_knockout_always_has_a_winner() {
+        let teams = teams(2);
+        let result = simulate_tournament(
+            &teams,
+            TournamentFormat::SingleElimination,
+            21,
+            TieRules::default(),
+        )
+        .unwrap();
+        let final_tie = &result.rounds[0].matches[0];
+        assert_eq!(final_tie.winner, Some(result.champion));
+        assert_ne!(result.champion, result.runner_up);
+    }
+}
@@ -0,0 +1,11 @@
+// crates/of_core/src/tournament/mod.rs
+// Cup tournaments (seeded brackets, group stage + knockout, extra time / penalties)
+
+pub mod bracket;
+pub mod play;
+
+pub use bracket::{
+    simulate_tournament, simulate_tournament_with, GroupTable, TopScorer, TournamentFormat,
+    TournamentMatch, TournamentResult, TournamentRound,
+};
+pub use play::{play_with_engine, GoalRecord, MatchOutcome, TieRules, EXTRA_TIME_MINUTES};
@@ -0,0 +1,225 @@
+//! Tie resolution with the match engine
+//!
+//! - Regulation: a normal engine match.
+//! - Knockout draw → extra time: goals from the first `EXTRA_TIME_MINUTES` of a
+//!   second, independently seeded engine run (reported as minutes 91-120).
+//! - Still level → the engine's penalty shootout from the regulation run.
+//! - Extra time and penalties both disabled → drawing of lots (seed parity).
+//!
+//! Same teams + seed → same outcome.
+
+use serde::{Deserialize, Serialize};
+
+use crate::engine::{MatchEngine, MatchPlan, TeamMatchModifiers};
+use crate::models::{EventType, MatchEvent, Team};
+
+/// Length of extra time (two 15-minute halves)
+pub const EXTRA_TIME_MINUTES: u8 = 30;
+/// Minute offset for extra-time goals
+const REGULATION_MINUTES: u8 = 90;
+
+/// How drawn knockout ties are settled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TieRules {
+    #[serde(default = "default_true")]
+    pub extra_time: bool,
+    #[serde(default = "default_true")]
+    pub penalties: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl Default for TieRules {
+    fn default() -> Self {
+        Self { extra_time: true, penalties: true }
+    }
+}
+
+/// One goal (own goals are not credited to a scorer)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GoalRecord {
+    pub is_home: bool,
+    pub player: String,
+    pub minute: u8,
+}
+
+/// Result of one tie
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatchOutcome {
+    /// Regulation score
+    pub home_goals: u8,
+    pub away_goals: u8,
+    /// Goals scored in extra time only
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extra_time: Option<(u8, u8)>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub penalties: Option<(u8, u8)>,
+    /// Drawn knockout tie settled by lot
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lot_winner_is_home: Option<bool>,
+    #[serde(default)]
+    pub scorers: Vec<GoalRecord>,
+}
+
+impl MatchOutcome {
+    /// Score after extra time
+    pub fn total(&self) -> (u8, u8) {
+        let (et_home, et_away) = self.extra_time.unwrap_or((0, 0));
+        (self.home_goals + et_home, self.away_goals + et_away)
+    }
+
+    /// Some(true) = home through, Some(false) = away through, None = draw
+    pub fn home_won(&self) -> Option<bool> {
+        let (home, away) = self.total();
+        if home != away {
+            return Some(home > away);
+        }
+        match self.penalties {
+            Some((home, away)) if home != away => Some(home > away),
+            _ => self.lot_winner_is_home,
+        }
+    }
+}
+
+/// Play one tie; `knockout` ties always produce a winner
+pub fn play_with_engine(
+    home: &Team,
+    away: &Team,
+    seed: u64,
+    knockout: bool,
+    rules: &TieRules,
+) -> Result<MatchOutcome, String> {
+    let mut engine = MatchEngine::new(match_plan(home, away, seed))?;
+    if knockout && rules.penalties {
+        engine = engine.with_penalty_shootout();
+    }
+    let result = engine.simulate();
+
+    let mut outcome = MatchOutcome {
+        home_goals: result.score_home,
+        away_goals: result.score_away,
+        scorers: goal_records(&result.events, home, away, 0, u8::MAX),
+        ..Default::default()
+    };
+    if !knockout || outcome.home_goals != outcome.away_goals {
+        return Ok(outcome);
+    }
+
+    if rules.extra_time {
+        let extra =
+            MatchEngine::new(match_plan(home, away, seed.rotate_left(32) ^ 0xE7))?.simulate();
+        let extra_goals = |is_home: bool| {
+            extra
+                .events
+                .iter()
+                .filter(|e| is_goal(e) && e.is_home_team == is_home)
+                .filter(|e| e.minute < EXTRA_TIME_MINUTES)
+                .count() as u8
+        };
+        outcome.extra_time = Some((extra_goals(true), extra_goals(false)));
+        outcome.scorers.extend(goal_records(
+            &extra.events,
+            home,
+            away,
+            REGULATION_MINUTES,
+            EXTRA_TIME_MINUTES,
+        ));
+    }
+    if outcome.home_won().is_none() {
+        outcome.penalties =
+            result.penalty_shootout.as_ref().map(|ps| (ps.goals_home, ps.goals_away));
+    }
+    if outcome.home_won().is_none() {
+        outcome.lot_winner_is_home = Some(seed & 1 == 0);
+    }
+    Ok(outcome)
+}
+
+fn match_plan(home: &Team, away: &Team, seed: u64) -> MatchPlan {
+    MatchPlan {
+        home_team: home.clone(),
+        away_team: away.clone(),
+        seed,
+        user_player: None,
+        home_match_modifiers: TeamMatchModifiers::default(),
+        away_match_modifiers: TeamMatchModifiers::default(),
+        modifier_stack: Default::default(),
+        home_instructions: None,
+        away_instructions: None,
+        home_player_instructions: None,
+        away_player_instructions: None,
+        home_ai_difficulty: None,
+        away_ai_difficulty: None,
+        pitch: crate::models::pitch::PitchSpec::default(),
+        home_chemistry: None,
+        away_chemistry: None,
+        stadium: None,
+        conditions: None,
+        penalty_shootout: None,
+        home_custom_formation: None,
+        away_custom_formation: None,
+        home_set_piece_takers: None,
+        away_set_piece_takers: None,
+        referee: None,
+    }
+}
+
+fn is_goal(event: &MatchEvent) -> bool {
+    matches!(event.event_type, EventType::Goal | EventType::OwnGoal)
+}
+
+/// Credited goals before `limit` (minutes shifted by `offset`).
+///
+/// Scorers are named from the roster slot of their track id (0-10 home,
+/// 11-21 away), i.e. the player who started in that slot.
+fn goal_records(
+    events: &[MatchEvent],
+    home: &Team,
+    away: &Team,
+    offset: u8,
+    limit: u8,
+) -> Vec<GoalRecord> {
+    events
+        .iter()
+        .filter(|e| e.event_type == EventType::Goal && e.minute < limit)
+        .filter_map(|e| {
+            let track = e.player_track_id? as usize;
+            let (team, slot) = if track < 11 { (home, track) } else { (away, track - 11) };
+            let player = team
+                .players
+                .get(slot)
+                .map(|p| p.name.clone())
+                .unwrap_or_else(|| format!("#{}", track));
+            Some(GoalRecord {
+                is_home: e.is_home_team,
+                player,
+                minute: e.minute.saturating_add(offset),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_winner_order_score_then_penalties_then_lot() {
+        let mut outcome = MatchOutcome { home_goals: 1, away_goals: 1, ..Default::default() };
+        assert_eq!(outcome.home_won(), None);
+
+        outcome.extra_time = Some((0, 1));
+        assert_eq!(outcome.total(), (1, 2));
+        assert_eq!(outcome.home_won(), Some(false));
+
+        outcome.extra_time = Some((0, 0));
+        outcome.penalties = Some((4, 3));
+        assert_eq!(outcome.home_won(), Some(true));
+
+        outcome.penalties = None;
+        outcome.lot_winner_is_home = Some(false);
+        assert_eq!(outcome.home_won(), Some(false));
+    }
+}
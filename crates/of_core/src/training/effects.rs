@@ -426,6 +426,9 @@ mod tests {
             current_injury: None,
             injury_history: Vec::new(),
             injury_proneness: 0.3,
+            morale: 50,
+            form: 50,
+            medical: Default::default(),
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
             career_stats: crate::player::PlayerCareerStats::new(),
@@ -0,0 +1,194 @@
+// 경기 준비 훈련 (Match Prep)
+//
+// Turns a scouting report on the next opponent into fixture-specific
+// `TeamMatchModifiers` (e.g. set-piece defence drills vs a set-piece side).
+// The prep is stored on the `TrainingManager` and consumed by the next match
+// against that opponent only.
+use crate::analysis::scout::{ScoutLevel, StyleTag, TeamScoutReport};
+use crate::engine::{ModifierSource, ModifierStack, TeamMatchModifiers};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Max number of focuses a single prep week can drill
+pub const MAX_PREP_FOCUSES: usize = 2;
+
+/// 경기 준비 초점 (상대 스타일 대응)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+pub enum MatchPrepFocus {
+    /// Zonal marking drills vs a dangerous set-piece side
+    SetPieceDefense,
+    /// Composure on the ball vs a high press
+    PressResistance,
+    /// Rest-defence vs counter-attacks
+    CounterProtection,
+    /// Full-back support vs a wing-focused side
+    FlankDefense,
+    /// Switch play vs a narrow block
+    WidePlay,
+    /// Finishing patterns vs a low block
+    ChanceCreation,
+    /// Press triggers vs a patient build-up
+    PressTriggers,
+}
+
+impl MatchPrepFocus {
+    /// Focus that answers an opponent style tag (None = nothing to drill)
+    pub fn for_style_tag(tag: StyleTag) -> Option<Self> {
+        match tag {
+            StyleTag::SetPieceStrength => Some(Self::SetPieceDefense),
+            StyleTag::HighPress => Some(Self::PressResistance),
+            StyleTag::CounterAttacking => Some(Self::CounterProtection),
+            StyleTag::WingFocused => Some(Self::FlankDefense),
+            StyleTag::CentralCongestion => Some(Self::WidePlay),
+            StyleTag::LowBlock => Some(Self::ChanceCreation),
+            StyleTag::PossessionBased => Some(Self::PressTriggers),
+            _ => None,
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::SetPieceDefense => "세트피스 수비",
+            Self::PressResistance => "압박 탈출",
+            Self::CounterProtection => "역습 대비",
+            Self::FlankDefense => "측면 수비",
+            Self::WidePlay => "측면 전개",
+            Self::ChanceCreation => "밀집 수비 공략",
+            Self::PressTriggers => "압박 트리거",
+        }
+    }
+
+    /// Fold this focus into match modifiers at the given strength (0..1)
+    fn apply(&self, modifiers: &mut TeamMatchModifiers, strength: f32) {
+        match self {
+            Self::SetPieceDefense => modifiers.set_piece_defense_mult *= 1.0 + 0.10 * strength,
+            Self::PressResistance => modifiers.pass_success_mult *= 1.0 + 0.03 * strength,
+            Self::CounterProtection => modifiers.tackle_success_mult *= 1.0 + 0.03 * strength,
+            Self::FlankDefense => modifiers.width_bias_add_m += 1.5 * strength,
+            Self::WidePlay => modifiers.width_bias_add_m += 2.5 * strength,
+            Self::ChanceCreation => modifiers.shot_accuracy_mult *= 1.0 + 0.03 * strength,
+            Self::PressTriggers => modifiers.press_intensity_add += 0.05 * strength,
+        }
+    }
+}
+
+/// Fixture-specific preparation for one upcoming opponent
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct MatchPrep {
+    /// Opponent the prep was built for (must match the fixture's opponent)
+    pub opponent_name: String,
+    pub focuses: Vec<MatchPrepFocus>,
+    /// Effect strength from scouting accuracy (0.5 rumor .. 1.0 detailed)
+    pub strength: f32,
+}
+
+impl MatchPrep {
+    /// Build a prep plan from a scouting report (highest-priority tags first)
+    pub fn from_scout_report(report: &TeamScoutReport) -> Self {
+        let mut focuses = Vec::with_capacity(MAX_PREP_FOCUSES);
+        let candidates =
+            report.style_tags.iter().filter_map(|&tag| MatchPrepFocus::for_style_tag(tag));
+        for focus in candidates {
+            if focuses.len() == MAX_PREP_FOCUSES {
+                break;
+            }
+            if !focuses.contains(&focus) {
+                focuses.push(focus);
+            }
+        }
+        Self {
+            opponent_name: report.team_name.clone(),
+            focuses,
+            strength: scout_level_strength(report.scout_level),
+        }
+    }
+
+    pub fn is_for(&self, opponent_name: &str) -> bool {
+        self.opponent_name == opponent_name
+    }
+
+    /// Apply the prep to a team's match modifiers
+    pub fn apply_to(&self, modifiers: &mut TeamMatchModifiers) {
+        let strength = self.strength.clamp(0.0, 1.0);
+        for focus in self.focuses.iter().take(MAX_PREP_FOCUSES) {
+            focus.apply(modifiers, strength);
+        }
+    }
+
+    /// Add the prep to a modifier stack, one labelled entry set per focus
+    pub fn push_to(&self, stack: &mut ModifierStack, is_home: bool) {
+        let strength = self.strength.clamp(0.0, 1.0);
+        for focus in self.focuses.iter().take(MAX_PREP_FOCUSES) {
+            let mut modifiers = TeamMatchModifiers::default();
+            focus.apply(&mut modifiers, strength);
+            let label = focus.display_name();
+            stack.push_bundle(is_home, ModifierSource::MatchPrep, label, &modifiers, 0);
+        }
+    }
+}
+
+/// Vague scouting → vaguer preparation
+fn scout_level_strength(level: ScoutLevel) -> f32 {
+    match level {
+        ScoutLevel::Rumor => 0.5,
+        ScoutLevel::Basic => 0.7,
+        ScoutLevel::Report => 0.85,
+        ScoutLevel::Detail | ScoutLevel::Elite => 1.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::scout::ScoutReportBuilder;
+
+    fn report(tags: Vec<StyleTag>, level: ScoutLevel) -> TeamScoutReport {
+        ScoutReportBuilder::new()
+            .team(7, "Rivals FC")
+            .level(level)
+            .style_tags(tags)
+            .build()
+            .expect("report")
+    }
+
+    #[test]
+    fn test_prep_from_report_picks_top_focuses() {
+        let prep = MatchPrep::from_scout_report(&report(
+            vec![
+                StyleTag::SetPieceStrength,
+                StyleTag::Systematic,
+                StyleTag::HighPress,
+                StyleTag::LowBlock,
+            ],
+            ScoutLevel::Detail,
+        ));
+        assert_eq!(
+            prep.focuses,
+            vec![MatchPrepFocus::SetPieceDefense, MatchPrepFocus::PressResistance]
+        );
+        assert!(prep.is_for("Rivals FC"));
+
+        let mut modifiers = TeamMatchModifiers::default();
+        prep.apply_to(&mut modifiers);
+        assert!((modifiers.set_piece_defense_mult - 1.10).abs() < 1e-6);
+        assert!((modifiers.pass_success_mult - 1.03).abs() < 1e-6);
+        assert_eq!(modifiers.shot_accuracy_mult, 1.0);
+
+        let mut stack = ModifierStack::new();
+        prep.push_to(&mut stack, true);
+        assert_eq!(stack.entries().len(), 2);
+        assert_eq!(stack.entries()[0].label, "세트피스 수비");
+        assert_eq!(stack.resolve(true).0, modifiers);
+    }
+
+    #[test]
+    fn test_rumor_level_prep_is_weaker() {
+        let rumor = MatchPrep::from_scout_report(&report(
+            vec![StyleTag::SetPieceStrength],
+            ScoutLevel::Rumor,
+        ));
+        let mut modifiers = TeamMatchModifiers::default();
+        rumor.apply_to(&mut modifiers);
+        assert!((modifiers.set_piece_defense_mult - 1.05).abs() < 1e-6);
+    }
+}
@@ -3,6 +3,7 @@
 
 pub mod condition;
 pub mod effects;
+pub mod match_prep;
 pub mod session;
 pub mod stamina;
 pub mod types;
@@ -10,6 +11,7 @@ pub mod weekly_plan;
 
 pub use condition::*;
 pub use effects::*;
+pub use match_prep::*;
 pub use session::*;
 pub use stamina::*;
 pub use types::*;
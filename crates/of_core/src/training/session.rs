@@ -2,6 +2,7 @@
 use crate::player::types::CorePlayer;
 use crate::training::condition::Condition;
 use crate::training::effects::TrainingEffectEngine;
+use crate::training::match_prep::MatchPrep;
 use crate::training::stamina::{StaminaSystem, TrainingIntensity};
 use crate::training::types::{
     CoachBonusLog, TrainingResult, TrainingSession, TrainingTarget, TrainingType,
@@ -46,6 +47,9 @@ pub struct TrainingManager {
     /// 현재 주차
     #[serde(default = "default_training_week")]
     pub current_week: u16,
+    /// 다음 경기용 경기 준비 (해당 상대와의 다음 경기에서만 소비)
+    #[serde(default)]
+    pub pending_match_prep: Option<MatchPrep>,
 }
 
 fn default_training_week() -> u16 {
@@ -66,6 +70,7 @@ impl TrainingManager {
             training_load: PlayerTrainingLoad::new(),
             weekly_plans: HashMap::new(),
             current_week: 1,
+            pending_match_prep: None,
         }
     }
 
@@ -353,6 +358,42 @@ impl TrainingManager {
         }
     }
 
+    /// 경기 준비 훈련 실행 (스카우트 리포트 → 다음 경기 전용 보정)
+    ///
+    /// Costs stamina like a normal session but grants no attribute growth.
+    /// Replaces any prep still pending for a different opponent.
+    pub fn execute_match_prep(
+        &mut self,
+        report: &crate::analysis::scout::TeamScoutReport,
+        intensity: TrainingIntensity,
+    ) -> Result<MatchPrep, String> {
+        let session =
+            TrainingSession::new(TrainingType::MatchPrep, TrainingTarget::Mental, intensity);
+        if !self.stamina_system.can_train(session.stamina_cost) {
+            return Err(format!("체력 부족: {} 필요", session.stamina_cost));
+        }
+        if self.stamina_system.consume(session.stamina_cost).is_err() {
+            return Err("체력 소모 실패".to_string());
+        }
+
+        self.consecutive_training_days += 1;
+        self.consecutive_rest_days = 0;
+        self.record_training_load(&session);
+
+        let prep = MatchPrep::from_scout_report(report);
+        self.pending_match_prep = Some(prep.clone());
+        Ok(prep)
+    }
+
+    /// 다음 경기 상대에 맞는 경기 준비를 꺼냄 (소비됨; 상대가 다르면 None)
+    pub fn take_match_prep(&mut self, opponent_name: &str) -> Option<MatchPrep> {
+        if self.pending_match_prep.as_ref()?.is_for(opponent_name) {
+            self.pending_match_prep.take()
+        } else {
+            None
+        }
+    }
+
     /// 휴식 실행
     pub fn execute_rest(&mut self, forced: bool, seed: u64) -> DailyActivityResult {
         // 체력 회복
@@ -386,6 +427,9 @@ impl TrainingManager {
         self.consecutive_training_days = 0;
         self.consecutive_rest_days = 0;
 
+        // 경기 준비는 다음 경기 한 번만 유효
+        self.pending_match_prep = None;
+
         DailyActivityResult::MatchPlayed {
             stamina_consumed: 30,
             new_stamina: self.stamina_system.current(),
@@ -617,6 +661,7 @@ impl Default for TrainingManager {
 mod tests {
     use super::*;
     use crate::models::player::PlayerAttributes;
+    use crate::training::match_prep::MatchPrepFocus;
 
     #[test]
     fn test_training_manager_creation() {
@@ -648,6 +693,9 @@ mod tests {
             current_injury: None,
             injury_history: Vec::new(),
             injury_proneness: 0.3,
+            morale: 50,
+            form: 50,
+            medical: Default::default(),
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
             career_stats: crate::player::PlayerCareerStats::new(),
@@ -686,4 +734,25 @@ mod tests {
         manager.execute_rest(false, 12);
         assert_eq!(manager.stamina_system.current(), 80); // +30
     }
+
+    #[test]
+    fn test_match_prep_is_consumed_by_next_fixture_only() {
+        use crate::analysis::scout::{ScoutLevel, ScoutReportBuilder, StyleTag};
+
+        let mut manager = TrainingManager::new();
+        let report = ScoutReportBuilder::new()
+            .team(3, "Long Throw United")
+            .level(ScoutLevel::Report)
+            .style_tags(vec![StyleTag::SetPieceStrength])
+            .build()
+            .unwrap();
+
+        let prep = manager.execute_match_prep(&report, TrainingIntensity::Normal).unwrap();
+        assert_eq!(prep.focuses, vec![MatchPrepFocus::SetPieceDefense]);
+        assert!(manager.stamina_system.current() < 100);
+
+        assert!(manager.take_match_prep("Someone Else").is_none());
+        assert_eq!(manager.take_match_prep("Long Throw United"), Some(prep));
+        assert!(manager.take_match_prep("Long Throw United").is_none());
+    }
 }
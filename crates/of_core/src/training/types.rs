@@ -10,6 +10,8 @@ pub enum TrainingType {
     Individual,
     /// 특별 훈련 (이벤트)
     Special,
+    /// 경기 준비 훈련 (다음 상대 대비, 능력치 성장 없음)
+    MatchPrep,
 }
 
 /// 훈련 대상 능력치 (6각형)
@@ -166,6 +168,7 @@ impl TrainingSession {
             TrainingType::Team => 0.8,       // 팀훈련은 효율 낮지만 안정적
             TrainingType::Individual => 1.2, // 개인훈련은 효율 높음
             TrainingType::Special => 1.5,    // 특별훈련은 매우 효율적
+            TrainingType::MatchPrep => 0.0,  // 경기 준비는 성장 대신 경기 보정
         };
 
         Self {
@@ -0,0 +1,60 @@
+/*
+ * of_core C ABI (crates/of_core_ffi)
+ *
+ * Strings are NUL-terminated UTF-8. Every returned char* is a JSON document;
+ * release it with of_free_string. Byte buffers (MRB0 replays) are released
+ * with of_free_buffer. int32_t results are OF_FFI_* codes; of_last_error()
+ * describes the last failure on the calling thread.
+ */
+#ifndef OF_CORE_H
+#define OF_CORE_H
+
+#include <stdint.h>
+#include <stddef.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define OF_FFI_ABI_VERSION 1
+
+#define OF_FFI_OK 0
+#define OF_FFI_ERR_NULL (-1)
+#define OF_FFI_ERR_UNKNOWN_SESSION (-2)
+#define OF_FFI_ERR_INVALID_INPUT (-3)
+#define OF_FFI_ERR_ENGINE (-4)
+
+typedef struct OfBuffer {
+    uint8_t *data;
+    size_t len;
+} OfBuffer;
+
+/* Library */
+uint32_t of_ffi_abi_version(void);
+char *of_last_error(void);
+void of_free_string(char *s);
+void of_free_buffer(OfBuffer buffer);
+
+/* One-shot simulation */
+char *of_simulate_match_json(const char *request_json);
+char *of_simulate_match_v2_json(const char *request_json);
+int32_t of_simulate_match_v2_replay(const char *request_json, OfBuffer *out);
+
+/* Replay decode (MRB0) */
+char *of_replay_decode_json(const uint8_t *data, size_t len);
+int32_t of_replay_window(const uint8_t *data, size_t len, uint64_t start_ms, uint64_t end_ms,
+                         OfBuffer *out);
+
+/* Live session stepping */
+char *of_session_create_json(const char *request_json);
+int32_t of_session_kick_off(uint64_t session_id);
+int32_t of_session_resume_second_half(uint64_t session_id);
+char *of_session_step_json(uint64_t session_id);
+char *of_session_fast_forward_json(uint64_t session_id, uint8_t minute);
+int32_t of_session_destroy(uint64_t session_id);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* OF_CORE_H */
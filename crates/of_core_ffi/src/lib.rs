@@ -0,0 +1,588 @@
+//! of_core C ABI
+//!
+//! Stable `extern "C"` surface for engines other than Godot (Unity, Unreal, custom).
+//! The matching declarations live in `include/of_core.h`.
+//!
+//! Conventions:
+//! - Strings in and out are NUL-terminated UTF-8. Every returned `char*` is a JSON
+//!   document and must be released with `of_free_string`.
+//! - Byte buffers (MRB0 replays) are returned through `OfBuffer` out-params and
+//!   released with `of_free_buffer`.
+//! - Functions returning `int32_t` use the `OF_FFI_*` codes; details of the last
+//!   failure on the calling thread are available from `of_last_error`.
+//! - Live sessions are referenced by `session_id` and released with `of_session_destroy`.
+//! - Panics never cross the boundary; they surface as errors.
+//!
+//! The `wasm` feature adds a `wasm-bindgen` JS API (see [`wasm`]).
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::{Arc, Mutex};
+
+use once_cell::sync::Lazy;
+use serde_json::{json, Value};
+
+use of_core::api::json_api::match_plan_from_match_request_v2_json;
+use of_core::engine::{LiveMatchSession, MatchEngine, MatchState, StepResult};
+use of_core::replay::{decode_mrb0, encode_mrb0, read_replay_window, BinaryReplay};
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+/// Bumped on any breaking change to the exported signatures or JSON envelopes
+pub const OF_FFI_ABI_VERSION: u32 = 1;
+
+/// Success
+pub const OF_FFI_OK: i32 = 0;
+/// A required pointer argument was null
+pub const OF_FFI_ERR_NULL: i32 = -1;
+/// Unknown `session_id`
+pub const OF_FFI_ERR_UNKNOWN_SESSION: i32 = -2;
+/// Invalid request or replay bytes
+pub const OF_FFI_ERR_INVALID_INPUT: i32 = -3;
+/// Engine failure (including a caught panic)
+pub const OF_FFI_ERR_ENGINE: i32 = -4;
+
+/// Byte buffer owned by the library; release with `of_free_buffer`
+#[repr(C)]
+pub struct OfBuffer {
+    pub data: *mut u8,
+    pub len: usize,
+}
+
+impl OfBuffer {
+    fn empty() -> Self {
+        Self {
+            data: std::ptr::null_mut(),
+            len: 0,
+        }
+    }
+
+    fn from_vec(bytes: Vec<u8>) -> Self {
+        let len = bytes.len();
+        let data = Box::into_raw(bytes.into_boxed_slice()) as *mut u8;
+        Self { data, len }
+    }
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+fn set_last_error(message: impl Into<String>) {
+    LAST_ERROR.with(|e| *e.borrow_mut() = Some(message.into()));
+}
+
+/// Live sessions (per-session Mutex: parallel sessions do not block each other)
+#[derive(Default)]
+struct SessionRegistry {
+    next_id: u64,
+    sessions: HashMap<u64, Arc<Mutex<LiveMatchSession>>>,
+}
+
+static SESSIONS: Lazy<Mutex<SessionRegistry>> =
+    Lazy::new(|| Mutex::new(SessionRegistry::default()));
+
+fn registry() -> std::sync::MutexGuard<'static, SessionRegistry> {
+    SESSIONS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn with_session<T>(
+    session_id: u64,
+    f: impl FnOnce(&mut LiveMatchSession) -> T,
+) -> Result<T, String> {
+    let session = registry()
+        .sessions
+        .get(&session_id)
+        .cloned()
+        .ok_or_else(|| format!("Unknown session_id {}", session_id))?;
+    let mut session = session
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    Ok(f(&mut session))
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// # Safety
+/// `ptr` must be null or a valid NUL-terminated string
+unsafe fn c_str_arg(ptr: *const c_char) -> Option<String> {
+    if ptr.is_null() {
+        return None;
+    }
+    Some(CStr::from_ptr(ptr).to_string_lossy().into_owned())
+}
+
+/// # Safety
+/// `data` must be null or point to `len` readable bytes
+unsafe fn bytes_arg<'a>(data: *const u8, len: usize) -> Option<&'a [u8]> {
+    if data.is_null() {
+        return None;
+    }
+    Some(std::slice::from_raw_parts(data, len))
+}
+
+fn string_to_c(s: String) -> *mut c_char {
+    // Interior NULs cannot cross a C string; drop them instead of failing.
+    let cleaned = if s.contains('\0') {
+        s.replace('\0', "")
+    } else {
+        s
+    };
+    CString::new(cleaned).unwrap_or_default().into_raw()
+}
+
+fn error_json(message: &str) -> *mut c_char {
+    set_last_error(message);
+    string_to_c(json!({ "schema_version": 1, "success": false, "error": message }).to_string())
+}
+
+fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+    let detail = payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_default();
+    format!("Engine panicked: {}", detail)
+}
+
+/// Run `f` and return its JSON (or an error envelope), never unwinding into C
+fn json_call(f: impl FnOnce() -> Result<String, String>) -> *mut c_char {
+    match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(response)) => string_to_c(response),
+        Ok(Err(e)) => error_json(&e),
+        Err(payload) => error_json(&panic_message(payload)),
+    }
+}
+
+/// Run `f` and map it to an `OF_FFI_*` code, never unwinding into C
+fn code_call(f: impl FnOnce() -> Result<(), (i32, String)>) -> i32 {
+    match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(())) => OF_FFI_OK,
+        Ok(Err((code, message))) => {
+            set_last_error(message);
+            code
+        }
+        Err(payload) => {
+            set_last_error(panic_message(payload));
+            OF_FFI_ERR_ENGINE
+        }
+    }
+}
+
+fn session_code(session_id: u64, f: impl FnOnce(&mut LiveMatchSession)) -> i32 {
+    code_call(|| with_session(session_id, f).map_err(|e| (OF_FFI_ERR_UNKNOWN_SESSION, e)))
+}
+
+fn state_name(state: MatchState) -> &'static str {
+    match state {
+        MatchState::NotStarted => "not_started",
+        MatchState::FirstHalf => "first_half",
+        MatchState::HalfTimeBreak => "half_time",
+        MatchState::SecondHalf => "second_half",
+        MatchState::Finished => "finished",
+    }
+}
+
+fn step_result_json(result: StepResult) -> Value {
+    match result {
+        StepResult::NotStarted => json!({ "result_type": "not_started" }),
+        StepResult::Tick(data) => {
+            let players: Vec<Value> = data
+                .player_positions
+                .iter()
+                .map(|p| {
+                    json!({
+                        "index": p.index,
+                        "x": p.position.0,
+                        "y": p.position.1,
+                        "stamina": p.stamina,
+                        "state": p.state
+                    })
+                })
+                .collect();
+            json!({
+                "result_type": "tick",
+                "timestamp_ms": data.timestamp_ms,
+                "minute": data.minute,
+                "ball": {
+                    "x": data.ball_position.0,
+                    "y": data.ball_position.1,
+                    "height": data.ball_height,
+                    "owner": data.ball_owner_idx
+                },
+                "players": players,
+                "events": data.events,
+                "score": { "home": data.score.0, "away": data.score.1 }
+            })
+        }
+        StepResult::HalfTime(data) => json!({
+            "result_type": "half_time",
+            "score": { "home": data.score.0, "away": data.score.1 },
+            "possession": { "home": data.possession.0, "away": data.possession.1 },
+            "shots": { "home": data.shots.0, "away": data.shots.1 },
+            "shots_on_target": { "home": data.shots_on_target.0, "away": data.shots_on_target.1 }
+        }),
+        StepResult::FullTime(data) => json!({
+            "result_type": "full_time",
+            "score": { "home": data.result.score_home, "away": data.result.score_away },
+            "result": data.result,
+        }),
+    }
+}
+
+fn simulate_v2_replay(request_json: &str) -> Result<Vec<u8>, String> {
+    let (plan, _) = match_plan_from_match_request_v2_json(request_json)?;
+    let seed = plan.seed;
+    let result = MatchEngine::new(plan)?.with_position_tracking().simulate();
+    Ok(encode_mrb0(&BinaryReplay::from_match_result(&result, seed)))
+}
+
+// ============================================================================
+// Library
+// ============================================================================
+
+/// ABI version (`OF_FFI_ABI_VERSION`)
+#[no_mangle]
+pub extern "C" fn of_ffi_abi_version() -> u32 {
+    OF_FFI_ABI_VERSION
+}
+
+/// Last error message on the calling thread (null if none); free with `of_free_string`
+#[no_mangle]
+pub extern "C" fn of_last_error() -> *mut c_char {
+    LAST_ERROR
+        .with(|e| e.borrow().clone())
+        .map_or(std::ptr::null_mut(), string_to_c)
+}
+
+/// Release a string returned by this library
+///
+/// # Safety
+/// `s` must be null or a pointer returned by an `of_*` function, released once.
+#[no_mangle]
+pub unsafe extern "C" fn of_free_string(s: *mut c_char) {
+    if s.is_null() {
+        return;
+    }
+    // SAFETY: created by CString::into_raw in string_to_c
+    drop(CString::from_raw(s));
+}
+
+/// Release a buffer returned by this library
+///
+/// # Safety
+/// `buffer` must come from an `of_*` out-param, released once.
+#[no_mangle]
+pub unsafe extern "C" fn of_free_buffer(buffer: OfBuffer) {
+    if buffer.data.is_null() {
+        return;
+    }
+    // SAFETY: created by Box::into_raw of a boxed slice of exactly `len` bytes
+    drop(Box::from_raw(std::ptr::slice_from_raw_parts_mut(
+        buffer.data,
+        buffer.len,
+    )));
+}
+
+// ============================================================================
+// One-shot simulation
+// ============================================================================
+
+/// Simulate a full match from a schema v1 `MatchRequest` → `MatchResponse` JSON
+///
+/// # Safety
+/// `request_json` must be null or a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn of_simulate_match_json(request_json: *const c_char) -> *mut c_char {
+    let Some(request) = c_str_arg(request_json) else {
+        return error_json("Invalid input: null pointer");
+    };
+    json_call(|| of_core::simulate_match_json(&request))
+}
+
+/// Simulate a full match from a schema v2 `MatchRequestV2` → result JSON
+///
+/// # Safety
+/// `request_json` must be null or a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn of_simulate_match_v2_json(request_json: *const c_char) -> *mut c_char {
+    let Some(request) = c_str_arg(request_json) else {
+        return error_json("Invalid input: null pointer");
+    };
+    json_call(|| of_core::simulate_match_v2_json(&request))
+}
+
+/// Simulate a schema v2 request and write the MRB0 replay bytes to `out`
+///
+/// # Safety
+/// `request_json` must be null or a valid NUL-terminated string; `out` must be
+/// null or writable.
+#[no_mangle]
+pub unsafe extern "C" fn of_simulate_match_v2_replay(
+    request_json: *const c_char,
+    out: *mut OfBuffer,
+) -> i32 {
+    let (Some(request), false) = (c_str_arg(request_json), out.is_null()) else {
+        set_last_error("Invalid input: null pointer");
+        return OF_FFI_ERR_NULL;
+    };
+    *out = OfBuffer::empty();
+    code_call(|| {
+        let bytes = simulate_v2_replay(&request).map_err(|e| (OF_FFI_ERR_INVALID_INPUT, e))?;
+        // SAFETY: checked non-null above
+        *out = OfBuffer::from_vec(bytes);
+        Ok(())
+    })
+}
+
+// ============================================================================
+// Replay decode
+// ============================================================================
+
+/// Decode MRB0 replay bytes → `{success, replay}` JSON
+///
+/// # Safety
+/// `data` must be null or point to `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn of_replay_decode_json(data: *const u8, len: usize) -> *mut c_char {
+    let Some(bytes) = bytes_arg(data, len) else {
+        return error_json("Invalid input: null pointer");
+    };
+    json_call(|| {
+        let replay = decode_mrb0(bytes)?;
+        Ok(json!({ "schema_version": 1, "success": true, "replay": replay }).to_string())
+    })
+}
+
+/// Cut `[start_ms, end_ms]` out of an MRB0 v4+ replay into a standalone replay in `out`
+///
+/// # Safety
+/// `data` must be null or point to `len` readable bytes; `out` must be null or writable.
+#[no_mangle]
+pub unsafe extern "C" fn of_replay_window(
+    data: *const u8,
+    len: usize,
+    start_ms: u64,
+    end_ms: u64,
+    out: *mut OfBuffer,
+) -> i32 {
+    let (Some(bytes), false) = (bytes_arg(data, len), out.is_null()) else {
+        set_last_error("Invalid input: null pointer");
+        return OF_FFI_ERR_NULL;
+    };
+    *out = OfBuffer::empty();
+    code_call(|| {
+        let window = read_replay_window(bytes, start_ms, end_ms)
+            .map_err(|e| (OF_FFI_ERR_INVALID_INPUT, e))?;
+        // SAFETY: checked non-null above
+        *out = OfBuffer::from_vec(window);
+        Ok(())
+    })
+}
+
+// ============================================================================
+// Live session stepping
+// ============================================================================
+
+/// Create a live session from a schema v2 request → `{success, session_id}`
+///
+/// # Safety
+/// `request_json` must be null or a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn of_session_create_json(request_json: *const c_char) -> *mut c_char {
+    let Some(request) = c_str_arg(request_json) else {
+        return error_json("Invalid input: null pointer");
+    };
+    json_call(|| {
+        let (plan, _) = match_plan_from_match_request_v2_json(&request)?;
+        let session = LiveMatchSession::new(plan)?;
+        let mut registry = registry();
+        registry.next_id += 1;
+        let session_id = registry.next_id;
+        registry
+            .sessions
+            .insert(session_id, Arc::new(Mutex::new(session)));
+        Ok(json!({ "schema_version": 1, "success": true, "session_id": session_id }).to_string())
+    })
+}
+
+/// Kick off (first half starts)
+#[no_mangle]
+pub extern "C" fn of_session_kick_off(session_id: u64) -> i32 {
+    session_code(session_id, |s| s.kick_off())
+}
+
+/// Start the second half after the half-time break
+#[no_mangle]
+pub extern "C" fn of_session_resume_second_half(session_id: u64) -> i32 {
+    session_code(session_id, |s| s.resume_second_half())
+}
+
+/// Advance one 250ms tick → tick / half_time / full_time JSON (`result_type`)
+#[no_mangle]
+pub extern "C" fn of_session_step_json(session_id: u64) -> *mut c_char {
+    json_call(|| {
+        let result = with_session(session_id, |s| s.step())?;
+        let mut payload = step_result_json(result);
+        payload["success"] = json!(true);
+        Ok(payload.to_string())
+    })
+}
+
+/// Skip to `minute` without per-tick snapshots → `{minute, score, state, events}`
+/// (stops early at half/full time)
+#[no_mangle]
+pub extern "C" fn of_session_fast_forward_json(session_id: u64, minute: u8) -> *mut c_char {
+    json_call(|| {
+        let skip = with_session(session_id, |s| s.fast_forward_to_minute(minute))??;
+        Ok(json!({
+            "schema_version": 1,
+            "success": true,
+            "ticks_advanced": skip.ticks_advanced,
+            "timestamp_ms": skip.timestamp_ms,
+            "minute": skip.minute,
+            "score": { "home": skip.score.0, "away": skip.score.1 },
+            "state": state_name(skip.state),
+            "events": skip.events,
+        })
+        .to_string())
+    })
+}
+
+/// Release a session (1 if it existed, 0 otherwise)
+#[no_mangle]
+pub extern "C" fn of_session_destroy(session_id: u64) -> i32 {
+    registry().sessions.remove(&session_id).is_some() as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v2_request(seed: u64) -> CString {
+        let positions = [
+            "GK", "LB", "CB", "CB", "RB", "LM", "CM", "CM", "RM", "ST", "ST", "GK", "CB", "CM",
+            "CM", "LW", "RW", "ST",
+        ];
+        let team = |prefix: &str| {
+            let roster: Vec<_> = positions
+                .iter()
+                .enumerate()
+                .map(|(i, pos)| {
+                    json!({"name": format!("{prefix}{i}"), "position": pos, "overall": 70, "condition": 3})
+                })
+                .collect();
+            json!({"name": prefix, "formation": "4-4-2", "roster": roster})
+        };
+        let request = json!({
+            "schema_version": 2,
+            "seed": seed,
+            "home_team": team("Home"),
+            "away_team": team("Away"),
+        });
+        CString::new(request.to_string()).unwrap()
+    }
+
+    fn call(ptr: *mut c_char) -> Value {
+        assert!(!ptr.is_null());
+        let text = unsafe { CStr::from_ptr(ptr) }.to_str().unwrap().to_string();
+        unsafe { of_free_string(ptr) };
+        serde_json::from_str(&text).unwrap()
+    }
+
+    fn last_error() -> String {
+        call_text(of_last_error())
+    }
+
+    fn call_text(ptr: *mut c_char) -> String {
+        let text = unsafe { CStr::from_ptr(ptr) }.to_str().unwrap().to_string();
+        unsafe { of_free_string(ptr) };
+        text
+    }
+
+    #[test]
+    fn test_session_stepping_round_trip() {
+        let created = call(unsafe { of_session_create_json(v2_request(5).as_ptr()) });
+        assert_eq!(created["success"], true);
+        let session_id = created["session_id"].as_u64().unwrap();
+
+        assert_eq!(
+            call(of_session_step_json(session_id))["result_type"],
+            "not_started"
+        );
+        assert_eq!(of_session_kick_off(session_id), OF_FFI_OK);
+        let tick = call(of_session_step_json(session_id));
+        assert_eq!(tick["result_type"], "tick");
+        assert_eq!(tick["players"].as_array().unwrap().len(), 22);
+
+        let skipped = call(of_session_fast_forward_json(session_id, 2));
+        assert_eq!(skipped["minute"], 2);
+        assert_eq!(skipped["state"], "first_half");
+        assert_eq!(
+            call(of_session_fast_forward_json(session_id, 1))["success"],
+            false
+        );
+
+        assert_eq!(of_session_destroy(session_id), 1);
+        assert_eq!(of_session_destroy(session_id), 0);
+        assert_eq!(of_session_kick_off(session_id), OF_FFI_ERR_UNKNOWN_SESSION);
+        assert!(last_error().contains("Unknown session_id"));
+    }
+
+    #[test]
+    fn test_replay_bytes_decode_and_window() {
+        let mut replay = OfBuffer::empty();
+        let code = unsafe { of_simulate_match_v2_replay(v2_request(8).as_ptr(), &mut replay) };
+        assert_eq!(code, OF_FFI_OK);
+        assert!(replay.len > 0);
+
+        let decoded = call(unsafe { of_replay_decode_json(replay.data, replay.len) });
+        assert_eq!(decoded["success"], true);
+        assert!(!decoded["replay"]["ball_frames"]
+            .as_array()
+            .unwrap()
+            .is_empty());
+
+        let mut window = OfBuffer::empty();
+        let code = unsafe { of_replay_window(replay.data, replay.len, 0, 60_000, &mut window) };
+        assert_eq!(code, OF_FFI_OK);
+        assert!(window.len > 0 && window.len < replay.len);
+        unsafe {
+            of_free_buffer(window);
+            of_free_buffer(replay);
+        }
+
+        let garbage = [0u8; 8];
+        let bad = call(unsafe { of_replay_decode_json(garbage.as_ptr(), garbage.len()) });
+        assert_eq!(bad["success"], false);
+    }
+
+    #[test]
+    fn test_null_and_invalid_inputs_return_errors() {
+        assert_eq!(
+            call(unsafe { of_simulate_match_json(std::ptr::null()) })["success"],
+            false
+        );
+        let bad = CString::new("{not json").unwrap();
+        assert_eq!(
+            call(unsafe { of_simulate_match_v2_json(bad.as_ptr()) })["success"],
+            false
+        );
+        let code = unsafe { of_simulate_match_v2_replay(bad.as_ptr(), std::ptr::null_mut()) };
+        assert_eq!(code, OF_FFI_ERR_NULL);
+        let mut out = OfBuffer::empty();
+        let code = unsafe { of_simulate_match_v2_replay(bad.as_ptr(), &mut out) };
+        assert_eq!(code, OF_FFI_ERR_INVALID_INPUT);
+        assert!(out.data.is_null());
+        assert!(last_error().contains("Invalid JSON"));
+        assert_eq!(of_ffi_abi_version(), OF_FFI_ABI_VERSION);
+    }
+}
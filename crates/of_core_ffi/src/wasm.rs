@@ -0,0 +1,118 @@
+//! Browser (wasm-bindgen) API
+//!
+//! Stateless JSON-in / JSON-out functions for the web match viewer and balance
+//! dashboard. Nothing here touches the filesystem, threads or the session
+//! registry: every call owns its inputs and returns a JSON string (`JSON.parse` on
+//! the JS side). Errors are thrown as JS strings.
+//!
+//! Live sessions are not exposed: they use a wall clock (`Instant`) that
+//! `wasm32-unknown-unknown` does not provide.
+
+use serde_json::{json, Value};
+use wasm_bindgen::prelude::*;
+
+use of_core::replay::{decode_mrb0, read_replay_window};
+
+fn js_err(message: impl std::fmt::Display) -> JsValue {
+    JsValue::from_str(&message.to_string())
+}
+
+/// ABI version shared with the C API
+#[wasm_bindgen(js_name = abiVersion)]
+pub fn abi_version() -> u32 {
+    crate::OF_FFI_ABI_VERSION
+}
+
+/// Simulate a full match from a schema v1 `MatchRequest` → `MatchResponse` JSON
+#[wasm_bindgen(js_name = simulateMatchJson)]
+pub fn simulate_match_json(request_json: &str) -> Result<String, JsValue> {
+    of_core::simulate_match_json(request_json).map_err(js_err)
+}
+
+/// Simulate a full match from a schema v2 `MatchRequestV2` → result JSON
+#[wasm_bindgen(js_name = simulateMatchV2Json)]
+pub fn simulate_match_v2_json(request_json: &str) -> Result<String, JsValue> {
+    of_core::simulate_match_v2_json(request_json).map_err(js_err)
+}
+
+/// Predict one `MatchRequestV2` (`samples == 0` = statistical fast path)
+#[wasm_bindgen(js_name = predictMatchJson)]
+pub fn predict_match_json(request_json: &str, samples: usize) -> Result<String, JsValue> {
+    of_core::api::predict_match_json(request_json, samples).map_err(js_err)
+}
+
+/// Predict a JSON array of `MatchRequestV2` fixtures, in order.
+///
+/// Returns `[{index, prediction} | {index, error}]`; one bad fixture does not fail
+/// the batch. Runs on the calling thread (no worker pool in the browser).
+#[wasm_bindgen(js_name = predictMatchesJson)]
+pub fn predict_matches_json(requests_json: &str, samples: usize) -> Result<String, JsValue> {
+    let requests: Vec<Value> = serde_json::from_str(requests_json)
+        .map_err(|e| js_err(format!("Invalid JSON request array: {}", e)))?;
+    let results: Vec<Value> = requests
+        .iter()
+        .enumerate()
+        .map(|(index, request)| {
+            match of_core::api::predict_match_json(&request.to_string(), samples)
+                .and_then(|p| serde_json::from_str::<Value>(&p).map_err(|e| e.to_string()))
+            {
+                Ok(prediction) => json!({ "index": index, "prediction": prediction }),
+                Err(error) => json!({ "index": index, "error": error }),
+            }
+        })
+        .collect();
+    Ok(Value::Array(results).to_string())
+}
+
+/// Decode MRB0 replay bytes (`Uint8Array`) → `BinaryReplay` JSON
+#[wasm_bindgen(js_name = decodeReplay)]
+pub fn decode_replay(bytes: &[u8]) -> Result<String, JsValue> {
+    let replay = decode_mrb0(bytes).map_err(js_err)?;
+    serde_json::to_string(&replay).map_err(js_err)
+}
+
+/// Cut `[start_ms, end_ms]` out of an MRB0 v4+ replay → standalone MRB0 bytes
+#[wasm_bindgen(js_name = replayWindow)]
+pub fn replay_window(bytes: &[u8], start_ms: f64, end_ms: f64) -> Result<Vec<u8>, JsValue> {
+    // JS numbers are f64; negative / NaN clamp to 0.
+    read_replay_window(bytes, start_ms.max(0.0) as u64, end_ms.max(0.0) as u64).map_err(js_err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v2_request(seed: u64) -> Value {
+        let positions = [
+            "GK", "LB", "CB", "CB", "RB", "LM", "CM", "CM", "RM", "ST", "ST", "GK", "CB", "CM",
+            "CM", "LW", "RW", "ST",
+        ];
+        let team = |prefix: &str| {
+            let roster: Vec<_> = positions
+                .iter()
+                .enumerate()
+                .map(|(i, pos)| {
+                    json!({"name": format!("{prefix}{i}"), "position": pos, "overall": 70, "condition": 3})
+                })
+                .collect();
+            json!({"name": prefix, "formation": "4-4-2", "roster": roster})
+        };
+        json!({
+            "schema_version": 2,
+            "seed": seed,
+            "home_team": team("Home"),
+            "away_team": team("Away"),
+        })
+    }
+
+    #[test]
+    fn test_batch_prediction_keeps_per_fixture_errors() {
+        let batch = json!([v2_request(1), {"schema_version": 2}, v2_request(2)]).to_string();
+        let results: Vec<Value> =
+            serde_json::from_str(&predict_matches_json(&batch, 0).unwrap()).unwrap();
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0]["prediction"]["method"], "statistical");
+        assert!(results[1]["error"].is_string());
+        assert_eq!(results[2]["index"], 2);
+    }
+}
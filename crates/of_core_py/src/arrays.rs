@@ -0,0 +1,109 @@
+//! Replay → flat row-major `f32` tables (numpy/pandas friendly)
+
+use of_core::replay::BinaryReplay;
+
+/// Column order of the ball table
+pub const BALL_COLUMNS: [&str; 6] = ["t", "x", "y", "z", "vx", "vy"];
+/// Column order of the player table (long format, one row per player frame)
+pub const PLAYER_COLUMNS: [&str; 6] = ["player_id", "t", "x", "y", "vx", "vy"];
+
+/// Row-major table; `data.len() == rows * columns.len()`
+#[derive(Debug, Clone, PartialEq)]
+pub struct FrameTable {
+    pub columns: &'static [&'static str],
+    pub rows: usize,
+    pub data: Vec<f32>,
+}
+
+impl FrameTable {
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.columns.len())
+    }
+}
+
+/// Ball frames in `BALL_COLUMNS` order
+pub fn ball_table(replay: &BinaryReplay) -> FrameTable {
+    let data = replay
+        .ball_frames
+        .iter()
+        .flat_map(|f| [f.t, f.x, f.y, f.z, f.vx, f.vy])
+        .collect();
+    FrameTable {
+        columns: &BALL_COLUMNS,
+        rows: replay.ball_frames.len(),
+        data,
+    }
+}
+
+/// Player frames of every track in `PLAYER_COLUMNS` order (tracks in replay order)
+pub fn player_table(replay: &BinaryReplay) -> FrameTable {
+    let rows = replay.players.iter().map(|track| track.frames.len()).sum();
+    let mut data = Vec::with_capacity(rows * PLAYER_COLUMNS.len());
+    for track in &replay.players {
+        let id = track.player_id as f32;
+        for f in &track.frames {
+            data.extend_from_slice(&[id, f.t, f.x, f.y, f.vx, f.vy]);
+        }
+    }
+    FrameTable {
+        columns: &PLAYER_COLUMNS,
+        rows,
+        data,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use of_core::replay::{BinaryBallFrame, BinaryPlayerFrame, BinaryPlayerTrack};
+
+    fn frame(t: f32) -> BinaryPlayerFrame {
+        BinaryPlayerFrame {
+            t,
+            x: t + 1.0,
+            y: t + 2.0,
+            vx: 0.5,
+            vy: -0.5,
+        }
+    }
+
+    #[test]
+    fn test_tables_are_row_major_with_track_ids() {
+        let replay = BinaryReplay {
+            format_version: 4,
+            header: serde_json::json!({}),
+            duration_seconds: 1.0,
+            score_home: 0,
+            score_away: 0,
+            events: Vec::new(),
+            ball_frames: vec![BinaryBallFrame {
+                t: 0.5,
+                x: 52.5,
+                y: 34.0,
+                z: 0.1,
+                vx: 1.0,
+                vy: 2.0,
+            }],
+            players: vec![
+                BinaryPlayerTrack {
+                    player_id: 3,
+                    frames: vec![frame(0.0), frame(0.25)],
+                },
+                BinaryPlayerTrack {
+                    player_id: 14,
+                    frames: vec![frame(0.0)],
+                },
+            ],
+        };
+
+        let ball = ball_table(&replay);
+        assert_eq!(ball.shape(), (1, 6));
+        assert_eq!(ball.data, vec![0.5, 52.5, 34.0, 0.1, 1.0, 2.0]);
+
+        let players = player_table(&replay);
+        assert_eq!(players.shape(), (3, 6));
+        assert_eq!(players.data.len(), 18);
+        assert_eq!(&players.data[6..12], &[3.0, 0.25, 1.25, 2.25, 0.5, -0.5]);
+        assert_eq!(players.data[12], 14.0);
+    }
+}
@@ -0,0 +1,172 @@
+//! of_core Python bindings
+//!
+//! PyO3 extension (`import of_core`) for calibration and analytics notebooks:
+//! single and batch match simulation, the calibration suite, and MRB0 replay
+//! parsing into numpy arrays. Build with `maturin develop --release` from this
+//! directory; the crate is kept out of the Cargo workspace so engine builds do
+//! not need a Python toolchain.
+//!
+//! Conventions:
+//! - Requests and reports are the same JSON documents as the Godot / C APIs.
+//! - Engine errors raise `ValueError`.
+//! - Simulation releases the GIL, so Python threads can run matches concurrently.
+
+use numpy::ndarray::Array2;
+use numpy::IntoPyArray;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict};
+
+use of_core::api::json_api::match_plan_from_match_request_v2_json;
+use of_core::calibration::{run_calibration_suite, CalibrationSuiteConfig, CalibrationTargets};
+use of_core::engine::MatchEngine;
+use of_core::replay::{decode_mrb0, encode_mrb0, read_replay_window, BinaryReplay};
+
+pub mod arrays;
+
+use arrays::{ball_table, player_table, FrameTable};
+
+fn value_error(message: String) -> PyErr {
+    PyValueError::new_err(message)
+}
+
+fn json_loads<'py>(py: Python<'py>, json: &str) -> PyResult<Bound<'py, PyAny>> {
+    py.import("json")?.call_method1("loads", (json,))
+}
+
+fn table_to_numpy<'py>(py: Python<'py>, table: FrameTable) -> PyResult<Bound<'py, PyAny>> {
+    let array = Array2::from_shape_vec(table.shape(), table.data)
+        .map_err(|e| value_error(format!("Invalid frame table: {}", e)))?;
+    Ok(array.into_pyarray(py).into_any())
+}
+
+// ============================================================================
+// Simulation
+// ============================================================================
+
+/// Simulate a schema v1 `MatchRequest` → `MatchResponse` JSON
+#[pyfunction]
+fn simulate_match_json(py: Python<'_>, request_json: &str) -> PyResult<String> {
+    py.detach(|| of_core::simulate_match_json(request_json))
+        .map_err(value_error)
+}
+
+/// Simulate a schema v2 `MatchRequestV2` → result JSON
+#[pyfunction]
+fn simulate_match_v2_json(py: Python<'_>, request_json: &str) -> PyResult<String> {
+    py.detach(|| of_core::simulate_match_v2_json(request_json))
+        .map_err(value_error)
+}
+
+/// Simulate a JSON array of `MatchRequestV2` in parallel → `ParallelBatchResponse` JSON
+///
+/// `threads == 0` uses one thread per logical CPU.
+#[pyfunction]
+#[pyo3(signature = (requests_json, threads = 0))]
+fn simulate_matches_json(py: Python<'_>, requests_json: &str, threads: usize) -> PyResult<String> {
+    py.detach(|| of_core::api::simulate_matches_parallel_json(requests_json, threads))
+        .map_err(value_error)
+}
+
+/// Simulate a schema v2 request with position tracking → MRB0 replay bytes
+#[pyfunction]
+fn simulate_match_v2_replay<'py>(
+    py: Python<'py>,
+    request_json: &str,
+) -> PyResult<Bound<'py, PyBytes>> {
+    let bytes = py
+        .detach(|| -> Result<Vec<u8>, String> {
+            let (plan, _) = match_plan_from_match_request_v2_json(request_json)?;
+            let seed = plan.seed;
+            let result = MatchEngine::new(plan)?.with_position_tracking().simulate();
+            Ok(encode_mrb0(&BinaryReplay::from_match_result(&result, seed)))
+        })
+        .map_err(value_error)?;
+    Ok(PyBytes::new(py, &bytes))
+}
+
+// ============================================================================
+// Calibration
+// ============================================================================
+
+/// Run the calibration suite on the teams of a `MatchRequestV2` → `CalibrationReport` JSON
+///
+/// Match `i` uses seed `base_seed + i` (the request's own seed is ignored).
+/// `targets_json` is a (partial) `CalibrationTargets`; `None` = reference ranges.
+#[pyfunction]
+#[pyo3(signature = (request_json, matches = 1000, base_seed = 0, targets_json = None))]
+fn run_calibration_suite_json(
+    py: Python<'_>,
+    request_json: &str,
+    matches: u32,
+    base_seed: u64,
+    targets_json: Option<&str>,
+) -> PyResult<String> {
+    py.detach(|| -> Result<String, String> {
+        let (plan, _) = match_plan_from_match_request_v2_json(request_json)?;
+        let mut config = CalibrationSuiteConfig::new(plan.home_team, plan.away_team);
+        config.matches = matches;
+        config.base_seed = base_seed;
+        if let Some(targets) = targets_json {
+            config.targets = CalibrationTargets::from_json_str(targets)?;
+        }
+        run_calibration_suite(&config)?.to_json()
+    })
+    .map_err(value_error)
+}
+
+// ============================================================================
+// Replays
+// ============================================================================
+
+/// Decode MRB0 bytes into a dict of numpy arrays
+///
+/// Keys: `format_version`, `duration_seconds`, `score` (home, away), `header`
+/// (parsed JSON), `events` (list of dicts), `ball` (`float32[N, 6]`, columns in
+/// `ball_columns`) and `players` (`float32[M, 6]`, long format, columns in
+/// `player_columns`).
+#[pyfunction]
+fn decode_replay<'py>(py: Python<'py>, data: &[u8]) -> PyResult<Bound<'py, PyDict>> {
+    let replay = py.detach(|| decode_mrb0(data)).map_err(value_error)?;
+    let events = serde_json::to_string(&replay.events)
+        .map_err(|e| value_error(format!("Failed to serialize events: {}", e)))?;
+
+    let out = PyDict::new(py);
+    out.set_item("format_version", replay.format_version)?;
+    out.set_item("duration_seconds", replay.duration_seconds)?;
+    out.set_item("score", (replay.score_home, replay.score_away))?;
+    out.set_item("header", json_loads(py, &replay.header.to_string())?)?;
+    out.set_item("events", json_loads(py, &events)?)?;
+    let ball = ball_table(&replay);
+    out.set_item("ball_columns", ball.columns.to_vec())?;
+    out.set_item("ball", table_to_numpy(py, ball)?)?;
+    let players = player_table(&replay);
+    out.set_item("player_columns", players.columns.to_vec())?;
+    out.set_item("players", table_to_numpy(py, players)?)?;
+    Ok(out)
+}
+
+/// Cut `[start_ms, end_ms]` out of an MRB0 v4+ replay → standalone MRB0 bytes
+#[pyfunction]
+fn replay_window<'py>(
+    py: Python<'py>,
+    data: &[u8],
+    start_ms: u64,
+    end_ms: u64,
+) -> PyResult<Bound<'py, PyBytes>> {
+    let bytes = read_replay_window(data, start_ms, end_ms).map_err(value_error)?;
+    Ok(PyBytes::new(py, &bytes))
+}
+
+#[pymodule(name = "of_core")]
+fn of_core_py(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add("__version__", env!("CARGO_PKG_VERSION"))?;
+    m.add_function(wrap_pyfunction!(simulate_match_json, m)?)?;
+    m.add_function(wrap_pyfunction!(simulate_match_v2_json, m)?)?;
+    m.add_function(wrap_pyfunction!(simulate_matches_json, m)?)?;
+    m.add_function(wrap_pyfunction!(simulate_match_v2_replay, m)?)?;
+    m.add_function(wrap_pyfunction!(run_calibration_suite_json, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_replay, m)?)?;
+    m.add_function(wrap_pyfunction!(replay_window, m)?)?;
+    Ok(())
+}
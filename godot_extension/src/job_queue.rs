@@ -0,0 +1,312 @@
+//! Worker-thread job queue for background simulations
+//!
+//! `start_simulation` hands the match to a small worker pool through a channel and
+//! returns a job id right away; workers report back through a second channel that
+//! `status`/`take_result` drain. A 100-match league day therefore never blocks the
+//! Godot main thread.
+
+use std::collections::HashMap;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+
+use of_core::engine::{CancellationToken, CANCELLED_CUT_REASON};
+
+use crate::sync_state::MutexExt;
+
+/// Upper bound on worker threads (the main thread keeps one core)
+const MAX_WORKERS: usize = 8;
+
+/// Work run on a worker thread; should stop early once the token is cancelled
+pub(crate) type JobFn = Box<dyn FnOnce(&CancellationToken) -> Result<String, String> + Send>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum JobStatus {
+    Queued,
+    Running,
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+impl JobStatus {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            JobStatus::Queued => "queued",
+            JobStatus::Running => "running",
+            JobStatus::Completed => "completed",
+            JobStatus::Failed => "failed",
+            JobStatus::Cancelled => "cancelled",
+        }
+    }
+
+    pub(crate) fn is_finished(self) -> bool {
+        matches!(
+            self,
+            JobStatus::Completed | JobStatus::Failed | JobStatus::Cancelled
+        )
+    }
+}
+
+struct Job {
+    id: u64,
+    cancel: CancellationToken,
+    work: JobFn,
+}
+
+enum JobEvent {
+    Started(u64),
+    Finished(u64, Result<String, String>),
+}
+
+struct JobEntry {
+    status: JobStatus,
+    cancel: CancellationToken,
+    /// Set once finished (a cancelled job may still carry a partial result)
+    result: Option<Result<String, String>>,
+}
+
+/// Channel-backed worker pool; threads are spawned on the first submit.
+pub(crate) struct JobQueue {
+    worker_count: usize,
+    job_tx: Option<Sender<Job>>,
+    event_rx: Option<Receiver<JobEvent>>,
+    workers: Vec<JoinHandle<()>>,
+    jobs: HashMap<u64, JobEntry>,
+    next_id: u64,
+}
+
+impl JobQueue {
+    pub(crate) fn new() -> Self {
+        let cores = std::thread::available_parallelism().map_or(2, |n| n.get());
+        Self::with_workers(cores.saturating_sub(1).clamp(1, MAX_WORKERS))
+    }
+
+    pub(crate) fn with_workers(worker_count: usize) -> Self {
+        Self {
+            worker_count: worker_count.max(1),
+            job_tx: None,
+            event_rx: None,
+            workers: Vec::new(),
+            jobs: HashMap::new(),
+            next_id: 1,
+        }
+    }
+
+    /// Queue `work`; returns its job id
+    pub(crate) fn submit(&mut self, work: JobFn) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        let cancel = CancellationToken::new();
+        self.jobs.insert(
+            id,
+            JobEntry {
+                status: JobStatus::Queued,
+                cancel: cancel.clone(),
+                result: None,
+            },
+        );
+
+        let job = Job { id, cancel, work };
+        if let Err(mpsc::SendError(job)) = self.sender().send(job) {
+            // Every worker is gone (should not happen); fail the job instead of losing it.
+            self.finish(job.id, Err("Job queue workers stopped".to_string()));
+        }
+        id
+    }
+
+    /// Current status (None = unknown or already collected)
+    pub(crate) fn status(&mut self, id: u64) -> Option<JobStatus> {
+        self.drain_events();
+        self.jobs.get(&id).map(|entry| entry.status)
+    }
+
+    /// Request cancellation; a queued job is dropped, a running one stops early
+    pub(crate) fn cancel(&mut self, id: u64) -> Option<JobStatus> {
+        self.drain_events();
+        let entry = self.jobs.get_mut(&id)?;
+        entry.cancel.cancel();
+        if entry.status == JobStatus::Queued {
+            entry.status = JobStatus::Cancelled;
+            entry.result = Some(Err(CANCELLED_CUT_REASON.to_string()));
+        }
+        Some(entry.status)
+    }
+
+    /// Cancel every job not yet finished
+    pub(crate) fn cancel_all(&mut self) {
+        let ids: Vec<u64> = self.jobs.keys().copied().collect();
+        for id in ids {
+            self.cancel(id);
+        }
+    }
+
+    /// Remove and return a finished job's result (None while queued/running)
+    pub(crate) fn take_result(&mut self, id: u64) -> Option<(JobStatus, Result<String, String>)> {
+        self.drain_events();
+        if !self.jobs.get(&id)?.status.is_finished() {
+            return None;
+        }
+        let entry = self.jobs.remove(&id)?;
+        let result = entry
+            .result
+            .unwrap_or_else(|| Err("Job produced no result".to_string()));
+        Some((entry.status, result))
+    }
+
+    /// (queued, running) job counts
+    pub(crate) fn pending_counts(&mut self) -> (usize, usize) {
+        self.drain_events();
+        let count = |status| self.jobs.values().filter(|e| e.status == status).count();
+        (count(JobStatus::Queued), count(JobStatus::Running))
+    }
+
+    /// Apply worker reports received so far (non-blocking)
+    pub(crate) fn drain_events(&mut self) {
+        let Some(rx) = self.event_rx.as_ref() else {
+            return;
+        };
+        let events: Vec<JobEvent> = rx.try_iter().collect();
+        for event in events {
+            match event {
+                JobEvent::Started(id) => {
+                    if let Some(entry) = self.jobs.get_mut(&id) {
+                        if entry.status == JobStatus::Queued {
+                            entry.status = JobStatus::Running;
+                        }
+                    }
+                }
+                JobEvent::Finished(id, result) => self.finish(id, result),
+            }
+        }
+    }
+
+    fn finish(&mut self, id: u64, result: Result<String, String>) {
+        let Some(entry) = self.jobs.get_mut(&id) else {
+            return;
+        };
+        if entry.status == JobStatus::Cancelled && entry.result.is_some() {
+            return; // cancelled while queued; the worker only skipped it
+        }
+        entry.status = if entry.cancel.is_cancelled() {
+            JobStatus::Cancelled
+        } else if result.is_ok() {
+            JobStatus::Completed
+        } else {
+            JobStatus::Failed
+        };
+        entry.result = Some(result);
+    }
+
+    fn sender(&mut self) -> Sender<Job> {
+        if let Some(tx) = &self.job_tx {
+            return tx.clone();
+        }
+        let (job_tx, job_rx) = mpsc::channel::<Job>();
+        let (event_tx, event_rx) = mpsc::channel();
+        let job_rx = Arc::new(Mutex::new(job_rx));
+        for index in 0..self.worker_count {
+            let job_rx = Arc::clone(&job_rx);
+            let event_tx = event_tx.clone();
+            let handle = std::thread::Builder::new()
+                .name(format!("of-sim-worker-{}", index))
+                .spawn(move || worker_loop(&job_rx, &event_tx));
+            match handle {
+                Ok(handle) => self.workers.push(handle),
+                Err(e) => log::error!("[JobQueue] failed to spawn worker {}: {}", index, e),
+            }
+        }
+        self.event_rx = Some(event_rx);
+        self.job_tx = Some(job_tx.clone());
+        job_tx
+    }
+}
+
+impl Drop for JobQueue {
+    fn drop(&mut self) {
+        self.cancel_all();
+        // Closing the channel ends each worker once its current job returns.
+        self.job_tx = None;
+        for handle in self.workers.drain(..) {
+            let _ = handle.join();
+        }
+    }
+}
+
+fn worker_loop(job_rx: &Mutex<Receiver<Job>>, event_tx: &Sender<JobEvent>) {
+    loop {
+        let job = job_rx.lock_or_recover().recv();
+        let Ok(job) = job else {
+            return; // queue dropped
+        };
+        if job.cancel.is_cancelled() {
+            let _ = event_tx.send(JobEvent::Finished(
+                job.id,
+                Err(CANCELLED_CUT_REASON.to_string()),
+            ));
+            continue;
+        }
+        let _ = event_tx.send(JobEvent::Started(job.id));
+        let Job { id, cancel, work } = job;
+        let result = catch_unwind(AssertUnwindSafe(|| work(&cancel)))
+            .unwrap_or_else(|_| Err("Simulation panicked".to_string()));
+        if event_tx.send(JobEvent::Finished(id, result)).is_err() {
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    fn wait_until(queue: &mut JobQueue, id: u64, done: impl Fn(JobStatus) -> bool) -> JobStatus {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        loop {
+            let status = queue.status(id).expect("known job");
+            if done(status) || Instant::now() > deadline {
+                return status;
+            }
+            std::thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    #[test]
+    fn test_jobs_run_off_thread_and_cancel() {
+        let mut queue = JobQueue::with_workers(1);
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+        let blocker = queue.submit(Box::new(move |cancel| {
+            let _ = release_rx.recv();
+            Ok(format!("cancelled={}", cancel.is_cancelled()))
+        }));
+        let queued = queue.submit(Box::new(|_| Ok("never runs".to_string())));
+        let failing = queue.submit(Box::new(|_| Err("bad request".to_string())));
+
+        // submit returned while the only worker is still blocked.
+        assert_eq!(
+            wait_until(&mut queue, blocker, |s| s != JobStatus::Queued),
+            JobStatus::Running
+        );
+        assert_eq!(queue.take_result(blocker), None);
+        assert_eq!(queue.cancel(queued), Some(JobStatus::Cancelled));
+        assert_eq!(queue.cancel(blocker), Some(JobStatus::Running));
+        release_tx.send(()).unwrap();
+
+        assert_eq!(
+            wait_until(&mut queue, blocker, JobStatus::is_finished),
+            JobStatus::Cancelled
+        );
+        assert_eq!(
+            queue.take_result(blocker),
+            Some((JobStatus::Cancelled, Ok("cancelled=true".to_string())))
+        );
+        assert_eq!(
+            wait_until(&mut queue, failing, JobStatus::is_finished),
+            JobStatus::Failed
+        );
+        assert_eq!(queue.status(queued), Some(JobStatus::Cancelled));
+        assert_eq!(queue.status(blocker), None, "collected results are removed");
+    }
+}
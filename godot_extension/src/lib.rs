@@ -3,14 +3,19 @@
 #![allow(unused_must_use)]
 
 use godot::prelude::*;
-use of_core::api::{simulate_match_json_budget, SimBudget};
+use of_core::api::{
+    msgpack_request_schema_version, resume_match_json_budget, simulate_match_json_budget,
+    simulate_match_json_budget_with_progress, simulate_match_json_cancellable,
+    simulate_match_msgpack, simulate_match_v2_msgpack, BudgetResumeToken, BudgetedOutcome,
+    SimBudget,
+};
 use of_core::models::Team;
-use of_core::simulate_match_json;
 use of_core::simulate_match_json_with_replay;
 use of_core::simulate_match_v2_json;
 use of_core::simulate_match_v2_json_with_replay;
-use std::cell::RefCell;
+use of_core::simulate_match_v3_json;
 use std::collections::{BTreeMap, HashSet};
+use std::sync::{Mutex, RwLock};
 
 // Gacha/Deck SSOT (FIX_2601/0109)
 use of_core::coach::{
@@ -23,16 +28,22 @@ use of_core::tactics::TeamInstructions;
                                         // Import tactical context
 use lz4_flex::{compress_prepend_size, decompress_size_prepended};
 use of_core::engine::{
-    live_match::TeamViewObservationConfig,
+    live_match::{
+        EventSubscription, LiveTacticChange, ObservationProfile, TeamViewObservationConfig,
+    },
     match_sim::{
         MatchEngine as OfMatchEngine, MatchPlan as OfMatchPlan, MiniMapObservation, MiniMapSpec,
         SimpleVectorObservation, StickyAction,
     },
     HighlightLevel as CoreHighlightLevel,
+    CancellationToken,
+    CoOpUserPlayer as CoreCoOpUserPlayer,
     // Phase 7: Match session stepping
     LiveMatchSession,
+    LiveSessionManager,
     MatchState as LiveMatchState,
     SimState as OfSimState,
+    SkipResult,
     StepResult,
     TeamSide,
     UserAction as OfUserAction,
@@ -63,6 +74,12 @@ pub use quest_bridge::QuestBridge;
 mod data_cache;
 pub use data_cache::DataCacheStore;
 
+mod sync_state;
+use sync_state::{MutexExt, RwLockExt};
+
+mod job_queue;
+use job_queue::{JobQueue, JobStatus};
+
 /// P2-10: Helper function to convert MatchEvent to Godot Dictionary.
 /// Reduces code duplication and properly handles EventDetails.
 fn convert_event_to_dict(event: &MatchEvent) -> Dictionary {
@@ -236,6 +253,25 @@ fn parse_event_type(event_type_str: &str) -> Option<EventType> {
         "HalfTime" | "half_time" | "halftime" => Some(EventType::HalfTime),
         "FullTime" | "full_time" | "fulltime" => Some(EventType::FullTime),
         "VarReview" | "var_review" | "varreview" => Some(EventType::VarReview),
+        "TeamTalk" | "team_talk" | "teamtalk" => Some(EventType::TeamTalk),
+        "TacticalAdaptation" | "tactical_adaptation" | "tacticaladaptation" => {
+            Some(EventType::TacticalAdaptation)
+        }
+        "Weather" | "weather" => Some(EventType::Weather),
+        "AddedTime" | "added_time" | "addedtime" => Some(EventType::AddedTime),
+        "AdvantagePlayed" | "advantage_played" | "advantageplayed" => {
+            Some(EventType::AdvantagePlayed)
+        }
+        "BackPassOffence" | "back_pass_offence" | "backpassoffence" => {
+            Some(EventType::BackPassOffence)
+        }
+        "GoalkeeperHolding" | "goalkeeper_holding" | "goalkeeperholding" => {
+            Some(EventType::GoalkeeperHolding)
+        }
+        "VarReviewStarted" | "var_review_started" | "varreviewstarted" => {
+            Some(EventType::VarReviewStarted)
+        }
+        "VarDecision" | "var_decision" | "vardecision" => Some(EventType::VarDecision),
         _ => None,
     }
 }
@@ -300,6 +336,50 @@ fn convert_decision_intent_to_dict(intent: &DecisionIntent) -> Dictionary {
         }
         dict.set("nearby_opponents", opponents);
     }
+    if !intent.pass_candidates.is_empty() {
+        let mut candidates = Array::<Variant>::new();
+        for eval in &intent.pass_candidates {
+            let mut c_dict = Dictionary::new();
+            c_dict.set("player_id", eval.player_id as i32);
+            c_dict.set("pos", meter_pos_to_dict(&eval.pos));
+            c_dict.set("quality", eval.quality);
+            c_dict.set("distance_factor", eval.distance_factor);
+            c_dict.set("zone_factor", eval.zone_factor);
+            c_dict.set("progression_factor", eval.progression_factor);
+            c_dict.set("diversity_penalty", eval.diversity_penalty);
+            c_dict.set("pressure", eval.pressure);
+            c_dict.set("lane_openness", eval.lane_openness);
+            c_dict.set("progression_m", eval.progression_m);
+            c_dict.set("is_forward", eval.is_forward);
+            c_dict.set("tactical_ok", eval.tactical_ok);
+            if let Some(reason) = &eval.excluded {
+                c_dict.set("excluded", GString::from(reason.as_str()));
+            }
+            candidates.push(&c_dict.to_variant());
+        }
+        dict.set("pass_candidates", candidates);
+    }
+
+    dict
+}
+
+fn convert_marking_snapshot_to_dict(
+    marking: &of_core::engine::marking_manager::MarkingSnapshotExport,
+) -> Dictionary {
+    let mut dict = Dictionary::new();
+    dict.set("defending_is_home", marking.defending_is_home);
+
+    let mut assignments = Array::<Variant>::new();
+    for assignment in &marking.assignments {
+        let mut a_dict = Dictionary::new();
+        a_dict.set("defender_id", assignment.defender_track_id as i32);
+        a_dict.set("role", GString::from(assignment.role.as_str()));
+        a_dict.set("target_id", assignment.target_track_id.map(|id| id as i32).unwrap_or(-1));
+        a_dict.set("zone_role", GString::from(format!("{:?}", assignment.zone_role)));
+        a_dict.set("mode", GString::from(format!("{:?}", assignment.mode)));
+        assignments.push(&a_dict.to_variant());
+    }
+    dict.set("assignments", assignments);
 
     dict
 }
@@ -427,7 +507,13 @@ fn parse_team_view_observation_config(value: &JsonValue) -> Option<TeamViewObser
         .and_then(|v| v.as_bool())
         .unwrap_or(false);
 
-    if !include_simple && !include_minimap {
+    // "observer" = spectator / async PvP view (opponent hidden info redacted)
+    let profile = match obs.get("profile").and_then(|v| v.as_str()) {
+        Some("observer" | "spectator") => ObservationProfile::Observer,
+        _ => ObservationProfile::Full,
+    };
+
+    if !include_simple && !include_minimap && profile == ObservationProfile::Full {
         return None;
     }
 
@@ -448,6 +534,7 @@ fn parse_team_view_observation_config(value: &JsonValue) -> Option<TeamViewObser
             width: width as usize,
             height: height as usize,
         },
+        profile,
     })
 }
 
@@ -852,22 +939,47 @@ struct CoachSaveState {
 }
 
 /// Football Match Simulator - GDExtension wrapper for of_core
+///
+/// # Threading
+///
+/// Interior state sits behind `Mutex`/`RwLock` (poison-tolerant, see `sync_state`),
+/// so every `&self` method is safe to call from Godot worker threads
+/// (`WorkerThreadPool`, `Thread`): match simulation, what-if / analysis / statistics
+/// JSON calls, gacha, coach inventory and deck calls.
+///
+/// `&mut self` methods (live session lifecycle: `create_live_session`,
+/// `kick_off_live_match`, `step_live_*`, `poll_live_match`, `end_live_session`, ...)
+/// require Godot's exclusive bind; drive them from one thread (normally the main
+/// thread). `Dictionary`/`Array` results built on a worker must be handed back to
+/// the main thread rather than shared.
+///
+/// Lock order when several are held: `gacha_system` → `coach_inventory` →
+/// `saved_decks` → `active_deck_id`. Session locks are never held together, and no
+/// guard is held across a call that re-acquires the same lock.
 #[derive(GodotClass)]
 #[class(base=RefCounted)]
 pub struct FootballMatchSimulator {
     base: Base<RefCounted>,
     /// Phase E: interactive session state for of_core engine
-    interactive_engine: RefCell<Option<OfMatchEngine>>,
+    interactive_engine: Mutex<Option<OfMatchEngine>>,
     /// Phase 7: Match session state (step-based simulation)
-    live_session: RefCell<Option<LiveMatchSession>>,
+    live_session: Mutex<Option<LiveMatchSession>>,
+    /// Keyed background sessions (other fixtures simulated alongside the player's match)
+    background_sessions: Mutex<LiveSessionManager>,
     /// Issue #4: Gacha System state
-    gacha_system: RefCell<GachaSystem>,
+    gacha_system: RwLock<GachaSystem>,
     /// FIX_2601/0109: Coach inventory state (cards + collection)
-    coach_inventory: RefCell<InventoryManager>,
+    coach_inventory: RwLock<InventoryManager>,
     /// FIX_2601/0109: Saved decks (id -> deck definition)
-    saved_decks: RefCell<BTreeMap<String, SavedDeck>>,
+    saved_decks: RwLock<BTreeMap<String, SavedDeck>>,
     /// FIX_2601/0109: Active deck id
-    active_deck_id: RefCell<Option<String>>,
+    active_deck_id: RwLock<Option<String>>,
+    /// Abort flag shared with in-flight simulations; replaced after each cancel
+    cancel_token: Mutex<CancellationToken>,
+    /// Budget-cut simulation waiting for `resume_budgeted_simulation`
+    budget_resume: Mutex<Option<BudgetResumeToken>>,
+    /// Background simulation jobs (`start_simulation` / `poll_job` / `get_result`)
+    jobs: Mutex<JobQueue>,
 }
 
 // Interactive Match Request Structs
@@ -910,6 +1022,27 @@ struct InteractiveUserPlayer {
     team: String,
     player_name: String,
     highlight_level: InteractiveHighlightLevel,
+    /// Co-op: additional user players sharing the career (user slots 1..)
+    #[serde(default)]
+    co_op_players: Vec<InteractiveCoOpPlayer>,
+}
+
+#[derive(serde::Deserialize)]
+struct InteractiveCoOpPlayer {
+    team: String,
+    player_name: String,
+    highlight_level: InteractiveHighlightLevel,
+}
+
+impl InteractiveHighlightLevel {
+    fn to_core(self) -> CoreHighlightLevel {
+        match self {
+            InteractiveHighlightLevel::Skip => CoreHighlightLevel::Skip,
+            InteractiveHighlightLevel::Simple => CoreHighlightLevel::Simple,
+            InteractiveHighlightLevel::MyPlayer => CoreHighlightLevel::MyPlayer,
+            InteractiveHighlightLevel::Full => CoreHighlightLevel::Full,
+        }
+    }
 }
 
 #[derive(serde::Deserialize, Clone, Copy)]
@@ -993,6 +1126,8 @@ fn create_of_core_engine_from_interactive_request(
                 equipped_skills: Vec::new(),
                 traits: Default::default(),
                 personality: Default::default(),
+                morale: of_core::player::NEUTRAL_MORALE,
+                form: of_core::player::NEUTRAL_FORM,
             });
         }
 
@@ -1006,28 +1141,39 @@ fn create_of_core_engine_from_interactive_request(
     let home_team = convert_team(req.home_team)?;
     let away_team = convert_team(req.away_team)?;
 
-    let user_config = req.user_player.map(|up| {
-        let is_home = up.team == "home";
-        // C6: Resolve player_index from player_name
+    // C6: Resolve player_index from player_name
+    let resolve_index = |is_home: bool, player_name: &str| {
         let team = if is_home { &home_team } else { &away_team };
         let base_idx = if is_home { 0 } else { 11 };
-        let player_index = team
-            .players
+        team.players
             .iter()
-            .position(|p| p.name == up.player_name)
+            .position(|p| p.name == player_name)
             .map(|i| base_idx + i)
-            .unwrap_or(base_idx + 9); // Fallback to first attacker (idx 9/20)
+            .unwrap_or(base_idx + 9) // Fallback to first attacker (idx 9/20)
+    };
+
+    let user_config = req.user_player.map(|up| {
+        let is_home = up.team == "home";
+        let co_op_players = up
+            .co_op_players
+            .into_iter()
+            .map(|co| {
+                let co_home = co.team == "home";
+                CoreCoOpUserPlayer {
+                    is_home_team: co_home,
+                    player_index: resolve_index(co_home, &co.player_name),
+                    player_name: co.player_name,
+                    highlight_level: co.highlight_level.to_core(),
+                }
+            })
+            .collect();
 
         CoreUserPlayerConfig {
             is_home_team: is_home,
+            player_index: resolve_index(is_home, &up.player_name),
             player_name: up.player_name,
-            player_index,
-            highlight_level: match up.highlight_level {
-                InteractiveHighlightLevel::Skip => CoreHighlightLevel::Skip,
-                InteractiveHighlightLevel::Simple => CoreHighlightLevel::Simple,
-                InteractiveHighlightLevel::MyPlayer => CoreHighlightLevel::MyPlayer,
-                InteractiveHighlightLevel::Full => CoreHighlightLevel::Full,
-            },
+            highlight_level: up.highlight_level.to_core(),
+            co_op_players,
         }
     });
 
@@ -1038,12 +1184,24 @@ fn create_of_core_engine_from_interactive_request(
         user_player: user_config,
         home_match_modifiers: of_core::engine::TeamMatchModifiers::default(),
         away_match_modifiers: of_core::engine::TeamMatchModifiers::default(),
+        modifier_stack: Default::default(),
         home_instructions: req.home_instructions,
         away_instructions: req.away_instructions,
         home_player_instructions: None,
         away_player_instructions: None,
         home_ai_difficulty: None,
         away_ai_difficulty: None,
+        pitch: of_core::models::pitch::PitchSpec::default(),
+        home_chemistry: None,
+        away_chemistry: None,
+        stadium: None,
+        conditions: None,
+        penalty_shootout: None,
+        home_custom_formation: None,
+        away_custom_formation: None,
+        home_set_piece_takers: None,
+        away_set_piece_takers: None,
+        referee: None,
     };
 
     // Enable replay recording so the Finished payload can include a replay doc
@@ -1088,6 +1246,9 @@ fn encode_user_decision_context(ctx: &OfUserDecisionContext, out: &mut Vec<u8>)
         write_f32_le(out, target.success_prob);
         write_u8(out, if target.is_key_pass { 1 } else { 0 });
     }
+
+    // Co-op: trailing user slot byte (older readers stop after the pass targets)
+    write_u8(out, ctx.user_slot);
 }
 
 fn encode_interactive_state_binary(state: &OfSimState) -> PackedByteArray {
@@ -1176,12 +1337,16 @@ impl IRefCounted for FootballMatchSimulator {
 
         Self {
             base,
-            interactive_engine: RefCell::new(None),
-            live_session: RefCell::new(None),
-            gacha_system: RefCell::new(GachaSystem::default()),
-            coach_inventory: RefCell::new(coach_inventory),
-            saved_decks: RefCell::new(BTreeMap::new()),
-            active_deck_id: RefCell::new(None),
+            interactive_engine: Mutex::new(None),
+            live_session: Mutex::new(None),
+            background_sessions: Mutex::new(LiveSessionManager::new()),
+            gacha_system: RwLock::new(GachaSystem::default()),
+            coach_inventory: RwLock::new(coach_inventory),
+            saved_decks: RwLock::new(BTreeMap::new()),
+            active_deck_id: RwLock::new(None),
+            cancel_token: Mutex::new(CancellationToken::new()),
+            budget_resume: Mutex::new(None),
+            jobs: Mutex::new(JobQueue::new()),
         }
     }
 }
@@ -1334,7 +1499,8 @@ impl FootballMatchSimulator {
             } else {
                 500
             },
-        );
+        )
+        .with_cancellation(self.current_cancel_token());
 
         match simulate_match_json_budget(&request_str, budget) {
             Ok(result_json) => GString::from(result_json),
@@ -1345,6 +1511,75 @@ impl FootballMatchSimulator {
         }
     }
 
+    /// Resumable budgeted simulation for progress bars: run until the budget cuts it,
+    /// then call `resume_budgeted_simulation` (same minute, deterministic) until done.
+    /// Returns { result, progress, resumable }.
+    #[func]
+    pub fn start_budgeted_simulation(
+        &self,
+        match_request_json: GString,
+        max_wall_ms: i64,
+        max_minutes: i64,
+        max_events: i64,
+    ) -> GString {
+        let request_str = match_request_json.to_string();
+        if request_str.trim().is_empty() {
+            return self.create_error_response("Empty match request", "EMPTY_REQUEST");
+        }
+        let budget = self.budget_from_args(max_wall_ms, max_minutes, max_events);
+        let outcome = simulate_match_json_budget_with_progress(&request_str, budget, |_| {});
+        self.budgeted_outcome_response(outcome)
+    }
+
+    /// Continue the simulation cut by the last `start/resume_budgeted_simulation` call.
+    #[func]
+    pub fn resume_budgeted_simulation(
+        &self,
+        max_wall_ms: i64,
+        max_minutes: i64,
+        max_events: i64,
+    ) -> GString {
+        let Some(token) = self.budget_resume.lock_or_recover().take() else {
+            return self.create_error_response("No budgeted simulation to resume", "NO_SESSION");
+        };
+        let budget = self.budget_from_args(max_wall_ms, max_minutes, max_events);
+        let outcome = resume_match_json_budget(token, budget, |_| {});
+        self.budgeted_outcome_response(outcome)
+    }
+
+    fn budget_from_args(&self, max_wall_ms: i64, max_minutes: i64, max_events: i64) -> SimBudget {
+        SimBudget::new(
+            if max_wall_ms > 0 { max_wall_ms as u64 } else { 50 },
+            if max_minutes > 0 { max_minutes as u16 } else { 120 },
+            if max_events > 0 { max_events as usize } else { 500 },
+        )
+        .with_cancellation(self.current_cancel_token())
+    }
+
+    fn budgeted_outcome_response(&self, outcome: Result<BudgetedOutcome, String>) -> GString {
+        match outcome {
+            Ok(outcome) => {
+                let resumable = outcome.resume.is_some();
+                *self.budget_resume.lock_or_recover() = outcome.resume;
+                let result: JsonValue =
+                    serde_json::from_str(&outcome.response_json).unwrap_or(JsonValue::Null);
+                GString::from(
+                    json!({
+                        "result": result,
+                        "progress": outcome.progress,
+                        "fraction": outcome.progress.fraction(),
+                        "resumable": resumable
+                    })
+                    .to_string(),
+                )
+            }
+            Err(err) => self.create_error_response(
+                &format!("Budgeted simulation failed: {}", err),
+                "SIMULATION_BUDGET_ERROR",
+            ),
+        }
+    }
+
     /// P6: Simulate match with full replay event recording
     /// Returns Dictionary with "result_json" and "replay_json" keys
     #[func]
@@ -1395,6 +1630,24 @@ impl FootballMatchSimulator {
         }
     }
 
+    /// MatchRequest v3: roster entries carry full attributes, traits, skills, fitness and
+    /// morale inline (schema_version=3; external career systems).
+    #[func]
+    pub fn simulate_match_v3_json(&self, match_request_json: GString) -> GString {
+        let request_str = match_request_json.to_string();
+        if request_str.trim().is_empty() {
+            return self.create_error_response("Empty match request", "EMPTY_REQUEST");
+        }
+
+        match simulate_match_v3_json(&request_str) {
+            Ok(result_json) => GString::from(result_json),
+            Err(err) => self.create_error_response(
+                &format!("v3 simulation failed: {}", err),
+                "SIMULATION_V3_ERROR",
+            ),
+        }
+    }
+
     /// MatchRequest v2: Simulate match with full replay event recording.
     /// Returns Dictionary with "result_json" and "replay_json" keys.
     #[func]
@@ -1553,11 +1806,11 @@ impl FootballMatchSimulator {
                             None => "null".to_string(),
                         };
 
-                        *self.interactive_engine.borrow_mut() = None;
+                        *self.interactive_engine.lock_or_recover() = None;
                         encode_interactive_finished_binary(&result_json, &replay_json)
                     }
                     _ => {
-                        *self.interactive_engine.borrow_mut() = Some(engine);
+                        *self.interactive_engine.lock_or_recover() = Some(engine);
                         encode_interactive_state_binary(&state)
                     }
                 }
@@ -1580,7 +1833,7 @@ impl FootballMatchSimulator {
             None => return PackedByteArray::new(),
         };
 
-        let mut engine_cell = self.interactive_engine.borrow_mut();
+        let mut engine_cell = self.interactive_engine.lock_or_recover();
         let engine = match engine_cell.as_mut() {
             Some(e) => e,
             None => return PackedByteArray::new(),
@@ -1746,7 +1999,8 @@ impl FootballMatchSimulator {
         let magic = read_u32_le(data, &mut offset).unwrap_or(0);
         let version = read_u32_le(data, &mut offset).unwrap_or(0);
         // 2025-12-10: Support v1..v4 (Tactical Instructions + match-only v4 cleanup).
-        if magic != 0x3051514D || !(1..=4).contains(&version) {
+        // v5: per-player condition, UID and optional full attribute block.
+        if magic != 0x3051514D || !(1..=5).contains(&version) {
             return Err(format!(
                 "invalid header (magic={:#x}, version={})",
                 magic, version
@@ -1768,22 +2022,71 @@ impl FootballMatchSimulator {
             data: &[u8],
             offset: &mut usize,
             seed: u64,
-        ) -> Option<of_core::models::Team> {
+            version: u32,
+        ) -> Result<of_core::models::Team, String> {
+            use of_core::api::{resolve_uid_player, EmbeddedPlayerAttributes};
             use of_core::models::team::Formation;
 
-            let name = read_string(data, offset)?;
-            let formation_str = read_string(data, offset)?;
-            let player_count = read_u8(data, offset)? as usize;
+            let truncated = || "truncated team block".to_string();
+
+            let name = read_string(data, offset).ok_or_else(truncated)?;
+            let formation_str = read_string(data, offset).ok_or_else(truncated)?;
+            let player_count = read_u8(data, offset).ok_or_else(truncated)? as usize;
 
             let mut players: Vec<OfPlayer> = Vec::with_capacity(player_count);
 
             for _ in 0..player_count {
-                let pname = read_string(data, offset)?;
-                let pos_code = read_u8(data, offset)?;
-                let overall = read_u8(data, offset)?;
+                let pname = read_string(data, offset).ok_or_else(truncated)?;
+                let pos_code = read_u8(data, offset).ok_or_else(truncated)?;
+                let overall = read_u8(data, offset).ok_or_else(truncated)?;
 
                 let position = OfPosition::from_code(pos_code).unwrap_or(OfPosition::CM);
 
+                // v5: condition(u8) + uid(str, empty = none) + attr_flags(u8)
+                //     [+ 36 attribute bytes when bit0 is set]
+                if version >= 5 {
+                    let condition = read_u8(data, offset).ok_or_else(truncated)?;
+                    let uid = read_string(data, offset).ok_or_else(truncated)?;
+                    let attr_flags = read_u8(data, offset).ok_or_else(truncated)?;
+                    let full_attrs = if (attr_flags & 0b0000_0001) != 0 {
+                        let end = *offset + EmbeddedPlayerAttributes::FIELD_COUNT;
+                        let block = data.get(*offset..end).ok_or_else(truncated)?;
+                        *offset = end;
+                        let mut values = [0u8; EmbeddedPlayerAttributes::FIELD_COUNT];
+                        values.copy_from_slice(block);
+                        Some(EmbeddedPlayerAttributes::from_ordered(values))
+                    } else {
+                        None
+                    };
+
+                    // Same builders as the JSON v2 roster (UID / embedded attributes).
+                    if !uid.is_empty() {
+                        players.push(resolve_uid_player(&uid, condition)?);
+                        continue;
+                    }
+                    if let Some(attrs) = full_attrs {
+                        if !of_core::fix01::is_valid_condition_level(condition) {
+                            return Err(format!(
+                                "{}: condition must be 1..=5, got {condition}",
+                                of_core::fix01::error_codes::INVALID_CONDITION_RANGE
+                            ));
+                        }
+                        players.push(OfPlayer {
+                            name: pname,
+                            position,
+                            overall,
+                            condition,
+                            attributes: Some(attrs.to_player_attributes()),
+                            equipped_skills: Vec::new(),
+                            traits: Default::default(),
+                            personality: Default::default(),
+                            morale: of_core::player::NEUTRAL_MORALE,
+                            form: of_core::player::NEUTRAL_FORM,
+                        });
+                        continue;
+                    }
+                }
+
                 // MRQ0 hotpath: derive attributes deterministically from overall/position/seed
                 let attrs = OfPlayerAttributes::derive_from_proxy(
                     overall as u8,
@@ -1800,6 +2103,8 @@ impl FootballMatchSimulator {
                     equipped_skills: Vec::new(),
                     traits: Default::default(),
                     personality: Default::default(),
+                    morale: of_core::player::NEUTRAL_MORALE,
+                    form: of_core::player::NEUTRAL_FORM,
                 });
             }
 
@@ -1815,7 +2120,7 @@ impl FootballMatchSimulator {
                 _ => Formation::F442,
             };
 
-            Some(of_core::models::Team {
+            Ok(of_core::models::Team {
                 name,
                 formation,
                 players,
@@ -1875,11 +2180,12 @@ impl FootballMatchSimulator {
                 pressing_intensity: pressing,
                 build_up_style: build_up,
                 use_offside_trap: offside_trap,
+                set_pieces: Default::default(),
             })
         }
 
-        let home_team = decode_team_from_binary(data, &mut offset, seed)
-            .ok_or_else(|| "failed to decode home_team".to_string())?;
+        let home_team = decode_team_from_binary(data, &mut offset, seed, version)
+            .map_err(|e| format!("failed to decode home_team: {e}"))?;
 
         let home_instructions = if version >= 3 {
             decode_instructions_from_binary(data, &mut offset)
@@ -1891,8 +2197,9 @@ impl FootballMatchSimulator {
             data,
             &mut offset,
             seed.wrapping_add(0x9E3779B97F4A7C15),
+            version,
         )
-        .ok_or_else(|| "failed to decode away_team".to_string())?;
+        .map_err(|e| format!("failed to decode away_team: {e}"))?;
 
         let away_instructions = if version >= 3 {
             decode_instructions_from_binary(data, &mut offset)
@@ -1909,20 +2216,43 @@ impl FootballMatchSimulator {
                     of_core::engine::TeamMatchModifiers::default(),
                 )
             };
+        // Deck effects are tagged so `MatchResult.modifier_report` shows their origin
+        let mut modifier_stack = of_core::engine::ModifierStack::new();
+        for (is_home, bundle) in [(true, &home_match_modifiers), (false, &away_match_modifiers)] {
+            modifier_stack.push_bundle(
+                is_home,
+                of_core::engine::ModifierSource::Deck,
+                "coach deck",
+                bundle,
+                0,
+            );
+        }
 
         Ok(OfMatchPlan {
             home_team,
             away_team,
             seed,
             user_player: None,
-            home_match_modifiers,
-            away_match_modifiers,
+            home_match_modifiers: of_core::engine::TeamMatchModifiers::default(),
+            away_match_modifiers: of_core::engine::TeamMatchModifiers::default(),
+            modifier_stack,
             home_instructions,
             away_instructions,
             home_player_instructions: None,
             away_player_instructions: None,
             home_ai_difficulty: None,
             away_ai_difficulty: None,
+            pitch: of_core::models::pitch::PitchSpec::default(),
+            home_chemistry: None,
+            away_chemistry: None,
+            stadium: None,
+            conditions: None,
+            penalty_shootout: None,
+            home_custom_formation: None,
+            away_custom_formation: None,
+            home_set_piece_takers: None,
+            away_set_piece_takers: None,
+            referee: None,
         })
     }
 
@@ -1951,291 +2281,59 @@ impl FootballMatchSimulator {
         };
         let result = engine.simulate();
 
-        // 4) Convert MatchResult.position_data -> ReplayDataV3
-        // 2025-12-11 P2: Expanded ball_frames to include velocity (t, x, y, z, vx, vy)
-        let mut ball_frames: Vec<(f32, f32, f32, f32, f32, f32)> = Vec::new();
-        // Use nested structure: HashMap<player_id, Vec<(t, x, y, vx, vy)>>
-        // 2025-12-11: Added vx, vy for player velocity
-        let mut player_data: std::collections::HashMap<u32, Vec<(f32, f32, f32, f32, f32)>> =
-            std::collections::HashMap::new();
-        let mut duration: f32 = 5400.0; // fallback: 90min
-
-        if let Some(ref pos_data) = result.position_data {
-            if let Some(last) = pos_data.ball.last() {
-                duration = (last.timestamp as f32) / 1000.0;
-            }
-
-            // 2025-12-11 P2: Include ball velocity (vx, vy) in ball_frames
-            for item in &pos_data.ball {
-                let (vx, vy) = item.velocity.unwrap_or((0.0, 0.0));
-                ball_frames.push((
-                    (item.timestamp as f32) / 1000.0,
-                    item.position.0,
-                    item.position.1,
-                    item.height.unwrap_or(0.0), // 2025-12-11: Use actual height instead of hardcoded 0.0
-                    vx,
-                    vy,
-                ));
-            }
-
-            // Keep player frames grouped by player_id (nested structure)
-            // 2025-12-11: Include velocity (vx, vy) for accurate direction tracking
-            // FIX_2601/0109: players changed from HashMap to [Vec; 22]
-            for (player_idx, frames) in pos_data.players.iter().enumerate() {
-                let pid = player_idx as u32;
-                let player_frames: Vec<(f32, f32, f32, f32, f32)> = frames
-                    .iter()
-                    .map(|item| {
-                        let (vx, vy) = item.velocity.unwrap_or((0.0, 0.0));
-                        (
-                            (item.timestamp as f32) / 1000.0,
-                            item.position.0,
-                            item.position.1,
-                            vx,
-                            vy,
-                        )
-                    })
-                    .collect();
-                player_data.insert(pid, player_frames);
-            }
-        }
-
-        // Body bytes (v3 layout, backward-compatible with v2 structure)
-        let mut body: Vec<u8> = Vec::new();
-        body.push(3u8); // format_version = 3
-        body.extend_from_slice(&duration.to_le_bytes());
-        body.push(result.score_home);
-        body.push(result.score_away);
-
-        let event_count = result.events.len() as u32;
-        body.extend_from_slice(&event_count.to_le_bytes());
-        for event in &result.events {
-            body.push(event.minute);
-            let event_type_u8: u8 = match event.event_type {
-                of_core::models::EventType::Goal => 0,
-                of_core::models::EventType::OwnGoal => 26,
-                of_core::models::EventType::Shot => 1,
-                of_core::models::EventType::ShotOnTarget => 2,
-                of_core::models::EventType::ShotOffTarget => 3,
-                of_core::models::EventType::ShotBlocked => 4,
-                of_core::models::EventType::Save => 5,
-                of_core::models::EventType::YellowCard => 6,
-                of_core::models::EventType::RedCard => 7,
-                of_core::models::EventType::Substitution => 8,
-                of_core::models::EventType::Injury => 9,
-                of_core::models::EventType::Corner => 10,
-                of_core::models::EventType::Freekick => 11,
-                of_core::models::EventType::Penalty => 12,
-                of_core::models::EventType::Offside => 13,
-                of_core::models::EventType::Foul => 14,
-                of_core::models::EventType::KeyChance => 15,
-                of_core::models::EventType::Pass => 16,
-                of_core::models::EventType::Tackle => 17,
-                of_core::models::EventType::Dribble => 18,
-                of_core::models::EventType::KickOff => 19,
-                of_core::models::EventType::PostHit => 20,
-                of_core::models::EventType::BarHit => 21,
-                of_core::models::EventType::GoalKick => 22,
-                of_core::models::EventType::ThrowIn => 23,
-                of_core::models::EventType::HalfTime => 24,
-                of_core::models::EventType::FullTime => 25,
-                of_core::models::EventType::VarReview => 27,
-            };
-            body.push(event_type_u8);
-            body.push(if event.is_home_team { 1 } else { 0 });
-
-            // C7: player name removed - serialize empty string (binary format deprecated)
-            let player_bytes: &[u8] = &[];
-            body.extend_from_slice(&(player_bytes.len() as u16).to_le_bytes());
-            body.extend_from_slice(player_bytes);
-
-            // C7: assist_by removed - serialize empty string (binary format deprecated)
-            let assist_bytes: &[u8] = &[];
-            body.extend_from_slice(&(assist_bytes.len() as u16).to_le_bytes());
-            body.extend_from_slice(assist_bytes);
-        }
-
-        body.extend_from_slice(&(ball_frames.len() as u64).to_le_bytes());
-        // 2025-12-11 P2: Serialize ball frames with velocity (t, x, y, z, vx, vy)
-        for frame in &ball_frames {
-            body.extend_from_slice(&frame.0.to_le_bytes()); // t
-            body.extend_from_slice(&frame.1.to_le_bytes()); // x
-            body.extend_from_slice(&frame.2.to_le_bytes()); // y
-            body.extend_from_slice(&frame.3.to_le_bytes()); // z (height)
-            body.extend_from_slice(&frame.4.to_le_bytes()); // vx
-            body.extend_from_slice(&frame.5.to_le_bytes()); // vy
-        }
-
-        // Write player data in nested structure: player_count, then per-player (id, frame_count, frames)
-        // 2025-12-11: Now includes vx, vy for velocity (format_version bump recommended if strict compat needed)
-        body.extend_from_slice(&(player_data.len() as u64).to_le_bytes());
-        for (player_id, frames) in &player_data {
-            body.extend_from_slice(&player_id.to_le_bytes()); // u32
-            body.extend_from_slice(&(frames.len() as u64).to_le_bytes()); // frame_count
-            for frame in frames {
-                body.extend_from_slice(&frame.0.to_le_bytes()); // t
-                body.extend_from_slice(&frame.1.to_le_bytes()); // x
-                body.extend_from_slice(&frame.2.to_le_bytes()); // y
-                body.extend_from_slice(&frame.3.to_le_bytes()); // vx (2025-12-11)
-                body.extend_from_slice(&frame.4.to_le_bytes()); // vy (2025-12-11)
-            }
-        }
-
-        // Header JSON (UTF-8) for meta
-        // Events are included both as 'events' (for ReplayLoader) and 'timeline' (for UI)
-        let events_json: Vec<_> = result
-            .events
-            .iter()
-            .map(|e| {
-                let event_type_str = match e.event_type {
-                    of_core::models::EventType::Goal => "goal",
-                    of_core::models::EventType::OwnGoal => "own_goal",
-                    of_core::models::EventType::Shot => "shot",
-                    of_core::models::EventType::ShotOnTarget => "shot_on_target",
-                    of_core::models::EventType::ShotOffTarget => "shot_off_target",
-                    of_core::models::EventType::ShotBlocked => "shot_blocked",
-                    of_core::models::EventType::Save => "save",
-                    of_core::models::EventType::YellowCard => "yellow_card",
-                    of_core::models::EventType::RedCard => "red_card",
-                    of_core::models::EventType::Substitution => "substitution",
-                    of_core::models::EventType::Injury => "injury",
-                    of_core::models::EventType::Corner => "corner",
-                    of_core::models::EventType::Freekick => "freekick",
-                    of_core::models::EventType::Penalty => "penalty",
-                    of_core::models::EventType::Offside => "offside",
-                    of_core::models::EventType::Foul => "foul",
-                    of_core::models::EventType::KeyChance => "key_chance",
-                    of_core::models::EventType::Pass => "pass",
-                    of_core::models::EventType::Tackle => "tackle",
-                    of_core::models::EventType::Dribble => "dribble",
-                    of_core::models::EventType::KickOff => "kick_off",
-                    of_core::models::EventType::PostHit => "post_hit",
-                    of_core::models::EventType::BarHit => "bar_hit",
-                    of_core::models::EventType::GoalKick => "goal_kick",
-                    of_core::models::EventType::ThrowIn => "throw_in",
-                    of_core::models::EventType::HalfTime => "half_time",        
-                    of_core::models::EventType::FullTime => "full_time",        
-                    of_core::models::EventType::VarReview => "var_review",
-                };
-                serde_json::json!({
-                    "minute": e.minute,
-                    "t": (e.minute as f64) * 60.0,
-                    "type": event_type_str,
-                    "etype": event_type_str,
-                    "team": if e.is_home_team { "home" } else { "away" },
-                    "is_home_team": e.is_home_team,
-                    // C7: Removed player/player_id - use player_track_id instead
-                    "player_track_id": e.player_track_id,
-                    "target_track_id": e.target_track_id,
-                    "details": e.details.as_ref().map(|d| {
-                        let injury_severity = d.injury_severity.as_ref().map(|inj| serde_json::json!({
-                            "weeks_out": inj.weeks_out,
-                            "description": &inj.description,
-                        }));
-                        let ball_position = d.ball_position.map(|(x, y, z)| serde_json::json!({
-                            "x": x,
-                            "y": y,
-                            "z": z,
-                        }));
-                        let substitution = d.substitution.as_ref().map(|sub| serde_json::json!({
-                            "player_in_name": &sub.player_in_name,
-                            "player_out_name": &sub.player_out_name,
-                            "bench_slot": sub.bench_slot,
-                        }));
-                        let var_review = d.var_review.as_ref().map(|vr| serde_json::json!({
-                            "reviewed_event_type": format!("{:?}", vr.reviewed_event_type).to_lowercase(),
-                            "outcome": format!("{:?}", vr.outcome).to_lowercase(),
-                        }));
-                        serde_json::json!({
-                            "xg_value": d.xg_value,
-                            "injury_severity": injury_severity,
-                            "ball_position": ball_position,
-                            "substitution": substitution,
-                            "var_review": var_review,
-                        })
-                    })
-                })
-            })
-            .collect();
-
-        let header_json = serde_json::json!({
-            "format_version": 3,
-            "match_id": format!("binary-{}", seed),
-            "seed": seed,
-            "duration_seconds": duration,
-            "score": { "home": result.score_home, "away": result.score_away },
-            "penalty_shootout": result.penalty_shootout.as_ref().map(|ps| {     
-                serde_json::json!({
-                    "goals_home": ps.goals_home,
-                    "goals_away": ps.goals_away,
-                    "kicks_taken_home": ps.kicks_taken_home,
-                    "kicks_taken_away": ps.kicks_taken_away,
-                    "winner_is_home": ps.winner_is_home,
-                    "kicks": ps.kicks.iter().map(|kick| {
-                        serde_json::json!({
-                            "kick_index": kick.kick_index,
-                            "is_home_team": kick.is_home_team,
-                            "kicker_track_id": kick.kicker_track_id,
-                            "kicker_name": &kick.kicker_name,
-                            "scored": kick.scored,
-                        })
-                    }).collect::<Vec<_>>(),
-                })
-            }),
-            "teams": {
-                "home": {
-                    "name": result.home_team.as_ref().map(|t| t.name.clone()).unwrap_or_else(|| "Home".to_string()),
-                    "formation": result.home_team.as_ref().map(|t| format!("{:?}", t.formation)).unwrap_or_else(|| "4-4-2".to_string()),
-                },
-                "away": {
-                    "name": result.away_team.as_ref().map(|t| t.name.clone()).unwrap_or_else(|| "Away".to_string()),
-                    "formation": result.away_team.as_ref().map(|t| format!("{:?}", t.formation)).unwrap_or_else(|| "4-4-2".to_string()),
-                },
-            },
-            "match_setup": result.match_setup.as_ref().map(|ms| {
-                serde_json::json!({
-                    "home": {
-                        "name": &ms.home.name,
-                        "formation": &ms.home.formation
-                    },
-                    "away": {
-                        "name": &ms.away.name,
-                        "formation": &ms.away.formation
-                    },
-                    "player_slots": ms.player_slots.iter().map(|slot| {
-                        serde_json::json!({
-                            "track_id": slot.track_id,
-                            "team": &slot.team,
-                            "name": &slot.name,
-                            "position": &slot.position,
-                            "overall": slot.overall,
-                            "slot": slot.slot
-                        })
-                    }).collect::<Vec<_>>()
-                })
-            }),
-            "events": events_json.clone(),
-            "timeline": events_json.iter().map(|e| serde_json::json!({
-                "t": e.get("t").unwrap_or(&serde_json::json!(0.0)),
-                "label": e.get("type").unwrap_or(&serde_json::json!("unknown")),
-                "team_id": if e.get("is_home_team").and_then(|v| v.as_bool()).unwrap_or(true) { 0 } else { 1 },
-                "player": e.get("player")
-            })).collect::<Vec<_>>()
-        });
-        let header_bytes = serde_json::to_vec(&header_json).unwrap_or_else(|_| b"{}".to_vec());
-        let header_len = header_bytes.len() as u32;
-
-        // Final buffer: magic "MRB0" + version byte(3) + header_len + header + body
-        let mut out: Vec<u8> = Vec::new();
-        out.extend_from_slice(&0x3042524Du32.to_le_bytes()); // "MRB0"
-        out.push(3u8); // format version
-        out.extend_from_slice(&header_len.to_le_bytes());
-        out.extend_from_slice(&header_bytes);
-        out.extend_from_slice(&body);
+        // 4) Encode MRB0 (layout + decoder: of_core::replay::binary)
+        let replay = of_core::replay::binary::BinaryReplay::from_match_result(&result, seed);
+        let out = of_core::replay::binary::encode_mrb0(&replay);
 
         PackedByteArray::from(out.as_slice())
     }
 
+    /// MessagePack request → MessagePack `MatchResult` (v1 or v2 by `schema_version`)
+    ///
+    /// Same schema as `simulate_match_json` / `simulate_match_v2_json` without the JSON
+    /// parse/serialize cost; empty array on error.
+    #[func]
+    pub fn simulate_match_from_msgpack(&self, request_bytes: PackedByteArray) -> PackedByteArray {
+        let data: Vec<u8> = request_bytes.to_vec();
+        let result = match msgpack_request_schema_version(&data) {
+            Ok(2) => simulate_match_v2_msgpack(&data),
+            Ok(_) => simulate_match_msgpack(&data),
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok(bytes) => PackedByteArray::from(bytes.as_slice()),
+            Err(msg) => {
+                godot_error!("simulate_match_from_msgpack: {}", msg);
+                PackedByteArray::new()
+            }
+        }
+    }
+
+    /// Cut `[start_ms, end_ms]` out of an MRB0 v4+ replay without decoding the whole match.
+    ///
+    /// Returns a standalone MRB0 replay holding only that window's frames and events
+    /// (header and score unchanged); empty array on error.
+    #[func]
+    pub fn get_replay_window_binary(
+        &self,
+        replay_bytes: PackedByteArray,
+        start_ms: i64,
+        end_ms: i64,
+    ) -> PackedByteArray {
+        let data: Vec<u8> = replay_bytes.to_vec();
+        match of_core::replay::read_replay_window(
+            &data,
+            start_ms.max(0) as u64,
+            end_ms.max(0) as u64,
+        ) {
+            Ok(out) => PackedByteArray::from(out.as_slice()),
+            Err(msg) => {
+                godot_error!("get_replay_window_binary: {}", msg);
+                PackedByteArray::new()
+            }
+        }
+    }
+
     #[func]
     pub fn get_version(&self) -> GString {
         let v = env!("CARGO_PKG_VERSION");
@@ -2269,7 +2367,7 @@ impl FootballMatchSimulator {
     /// Returns Dictionary: { cards: Array<CardDict>, is_new: Array<bool>, summary: String }
     #[func]
     pub fn gacha_pull_single(&self, seed: i64) -> Dictionary {
-        let mut gacha = self.gacha_system.borrow_mut();
+        let mut gacha = self.gacha_system.write_or_recover();
         let mut result = gacha.pull_single(seed as u64);
         let pity_counter = gacha.pity_counter;
         drop(gacha);
@@ -2282,7 +2380,7 @@ impl FootballMatchSimulator {
     /// Returns Dictionary: { cards: Array<CardDict>, is_new: Array<bool>, summary: String }
     #[func]
     pub fn gacha_pull_ten(&self, seed: i64) -> Dictionary {
-        let mut gacha = self.gacha_system.borrow_mut();
+        let mut gacha = self.gacha_system.write_or_recover();
         let mut result = gacha.pull_ten(seed as u64);
         let pity_counter = gacha.pity_counter;
         drop(gacha);
@@ -2294,11 +2392,11 @@ impl FootballMatchSimulator {
     /// Get current pity counter.
     #[func]
     pub fn gacha_get_pity_count(&self) -> i32 {
-        self.gacha_system.borrow().pity_counter as i32
+        self.gacha_system.read_or_recover().pity_counter as i32
     }
 
     fn apply_gacha_result_to_inventory(&self, result: &mut GachaResult) {
-        let mut inventory = self.coach_inventory.borrow_mut();
+        let mut inventory = self.coach_inventory.write_or_recover();
         let collection = build_collection_set(&inventory);
         result.check_new_cards(&collection);
 
@@ -2516,13 +2614,13 @@ impl FootballMatchSimulator {
     #[func]
     pub fn coach_export_state(&self) -> Dictionary {
         let (pity_counter, pity_threshold) = {
-            let gacha = self.gacha_system.borrow();
+            let gacha = self.gacha_system.read_or_recover();
             (gacha.pity_counter, gacha.pity_threshold)
         };
 
-        let card_inventory = self.coach_inventory.borrow().clone();
-        let saved_decks = self.saved_decks.borrow().clone();
-        let active_deck_id = self.active_deck_id.borrow().clone();
+        let card_inventory = self.coach_inventory.read_or_recover().clone();
+        let saved_decks = self.saved_decks.read_or_recover().clone();
+        let active_deck_id = self.active_deck_id.read_or_recover().clone();
 
         let state = CoachSaveState {
             schema_version: coach_save_state_schema_version(),
@@ -2558,15 +2656,15 @@ impl FootballMatchSimulator {
         };
 
         {
-            let mut gacha = self.gacha_system.borrow_mut();
+            let mut gacha = self.gacha_system.write_or_recover();
             gacha.pity_counter = parsed.pity_counter;
             gacha.pity_threshold = parsed.pity_threshold.max(1);
         }
 
-        *self.coach_inventory.borrow_mut() = parsed.card_inventory;
+        *self.coach_inventory.write_or_recover() = parsed.card_inventory;
 
-        let mut decks_cell = self.saved_decks.borrow_mut();
-        let mut active_id_cell = self.active_deck_id.borrow_mut();
+        let mut decks_cell = self.saved_decks.write_or_recover();
+        let mut active_id_cell = self.active_deck_id.write_or_recover();
         *decks_cell = parsed.saved_decks;
         *active_id_cell = parsed.active_deck_id;
 
@@ -2585,17 +2683,17 @@ impl FootballMatchSimulator {
     /// to avoid leaking in-memory coach state across loads.
     #[func]
     pub fn coach_reset_state(&self) -> Dictionary {
-        *self.gacha_system.borrow_mut() = GachaSystem::default();
+        *self.gacha_system.write_or_recover() = GachaSystem::default();
 
         let mut inventory = InventoryManager::new();
         // Keep the same runtime capacities as init() (migration-safe defaults).
         inventory.manager_inventory.max_capacity = 500;
         inventory.coach_inventory.max_capacity = 1000;
         inventory.tactics_inventory.max_capacity = 500;
-        *self.coach_inventory.borrow_mut() = inventory;
+        *self.coach_inventory.write_or_recover() = inventory;
 
-        *self.saved_decks.borrow_mut() = BTreeMap::new();
-        *self.active_deck_id.borrow_mut() = None;
+        *self.saved_decks.write_or_recover() = BTreeMap::new();
+        *self.active_deck_id.write_or_recover() = None;
 
         Self::api_ok()
     }
@@ -2841,7 +2939,7 @@ impl FootballMatchSimulator {
     }
 
     fn deck_to_response_dict(&self, saved: &SavedDeck) -> Dictionary {
-        let inventory = self.coach_inventory.borrow();
+        let inventory = self.coach_inventory.read_or_recover();
         let mut deck_dict = Dictionary::new();
         deck_dict.set("id", GString::from(saved.id.as_str()));
         deck_dict.set("name", GString::from(saved.name.as_str()));
@@ -2927,8 +3025,8 @@ impl FootballMatchSimulator {
     }
 
     fn get_active_saved_deck(&self) -> Option<SavedDeck> {
-        let active_id = self.active_deck_id.borrow().clone();
-        let decks = self.saved_decks.borrow();
+        let active_id = self.active_deck_id.read_or_recover().clone();
+        let decks = self.saved_decks.read_or_recover();
         active_id.and_then(|id| decks.get(&id).cloned())
     }
 
@@ -2952,7 +3050,7 @@ impl FootballMatchSimulator {
             .map(|s| s.trim().to_lowercase())
             .filter(|s| !s.is_empty());
 
-        let inventory = self.coach_inventory.borrow();
+        let inventory = self.coach_inventory.read_or_recover();
         let collection_count = build_collection_set(&inventory).len() as i32;
 
         let mut cards_arr = Array::<Variant>::new();
@@ -3038,7 +3136,7 @@ impl FootballMatchSimulator {
         }
 
         let candidates: Vec<GachaCard> = {
-            let gacha = self.gacha_system.borrow();
+            let gacha = self.gacha_system.read_or_recover();
             gacha.pool
                 .regular_cards
                 .iter()
@@ -3047,7 +3145,7 @@ impl FootballMatchSimulator {
                 .collect()
         };
 
-        let mut inventory = self.coach_inventory.borrow_mut();
+        let mut inventory = self.coach_inventory.write_or_recover();
         let mut collection = build_collection_set(&inventory);
 
         let mut added_cards = Array::<Variant>::new();
@@ -3081,8 +3179,12 @@ impl FootballMatchSimulator {
             Err(e) => return Self::api_error(e, "INVALID_DECK_SCHEMA"),
         };
 
-        let inventory = self.coach_inventory.borrow();
-        if let Err(e) = self.build_runtime_deck(&saved, &inventory) {
+        // Release the inventory guard before `deck_to_response_dict` re-acquires it.
+        let validation = {
+            let inventory = self.coach_inventory.read_or_recover();
+            self.build_runtime_deck(&saved, &inventory).map(|_| ())
+        };
+        if let Err(e) = validation {
             let mut out = Self::api_error(e, "DECK_INVALID");
             out.set("deck", self.deck_to_response_dict(&saved));
             return out;
@@ -3102,23 +3204,22 @@ impl FootballMatchSimulator {
 
         saved.last_used_unix_ms = Some(Self::now_unix_ms());
 
-        {
-            let inventory = self.coach_inventory.borrow();
-            if let Err(e) = self.build_runtime_deck(&saved, &inventory) {
-                let mut out = Self::api_error(e, "DECK_INVALID");
-                out.set("deck", self.deck_to_response_dict(&saved));
-                return out;
-            }
+        let validation = {
+            let inventory = self.coach_inventory.read_or_recover();
+            self.build_runtime_deck(&saved, &inventory).map(|_| ())
+        };
+        if let Err(e) = validation {
+            let mut out = Self::api_error(e, "DECK_INVALID");
+            out.set("deck", self.deck_to_response_dict(&saved));
+            return out;
         }
 
         self.saved_decks
-            .borrow_mut()
+            .write_or_recover()
             .insert(saved.id.clone(), saved.clone());
 
         // Default behavior: if no active deck, set this as active.
-        if self.active_deck_id.borrow().is_none() {
-            *self.active_deck_id.borrow_mut() = Some(saved.id.clone());
-        }
+        self.active_deck_id.write_or_recover().get_or_insert_with(|| saved.id.clone());
 
         let mut out = Self::api_ok();
         out.set("deck_id", GString::from(saved.id.as_str()));
@@ -3133,13 +3234,14 @@ impl FootballMatchSimulator {
             return Self::api_error("Empty deck_id", "EMPTY_DECK_ID");
         }
 
-        let removed = self.saved_decks.borrow_mut().remove(&id);
+        let removed = self.saved_decks.write_or_recover().remove(&id);
         if removed.is_none() {
             return Self::api_error(format!("Deck not found: {}", id), "DECK_NOT_FOUND");
         }
 
-        if self.active_deck_id.borrow().as_deref() == Some(&id) {
-            *self.active_deck_id.borrow_mut() = None;
+        let mut active_id = self.active_deck_id.write_or_recover();
+        if active_id.as_deref() == Some(&id) {
+            *active_id = None;
         }
 
         Self::api_ok()
@@ -3152,11 +3254,11 @@ impl FootballMatchSimulator {
             return Self::api_error("Empty deck_id", "EMPTY_DECK_ID");
         }
 
-        if !self.saved_decks.borrow().contains_key(&id) {
+        if !self.saved_decks.read_or_recover().contains_key(&id) {
             return Self::api_error(format!("Deck not found: {}", id), "DECK_NOT_FOUND");
         }
 
-        *self.active_deck_id.borrow_mut() = Some(id.clone());
+        *self.active_deck_id.write_or_recover() = Some(id.clone());
         let mut out = Self::api_ok();
         out.set("deck_id", GString::from(id.as_str()));
         out
@@ -3166,7 +3268,7 @@ impl FootballMatchSimulator {
     pub fn deck_get_active(&self) -> Dictionary {
         let saved = self
             .get_active_saved_deck()
-            .or_else(|| self.saved_decks.borrow().get("default").cloned())
+            .or_else(|| self.saved_decks.read_or_recover().get("default").cloned())
             .unwrap_or_else(|| SavedDeck {
                 id: "default".to_string(),
                 name: "새 덱".to_string(),
@@ -3199,7 +3301,7 @@ impl FootballMatchSimulator {
             Err(e) => return Self::api_error(e, "INVALID_DECK_SCHEMA"),
         };
 
-        let inventory = self.coach_inventory.borrow();
+        let inventory = self.coach_inventory.read_or_recover();
         let runtime_deck = match self.build_runtime_deck(&saved, &inventory) {
             Ok(v) => v,
             Err(e) => return Self::api_error(e, "DECK_INVALID"),
@@ -3252,7 +3354,7 @@ impl FootballMatchSimulator {
             Err(e) => return Self::api_error(e, "INVALID_DECK_SCHEMA"),
         };
 
-        let inventory = self.coach_inventory.borrow();
+        let inventory = self.coach_inventory.read_or_recover();
         let runtime_deck = match self.build_runtime_deck(&saved, &inventory) {
             Ok(v) => v,
             Err(e) => return Self::api_error(e, "DECK_INVALID"),
@@ -3606,72 +3708,110 @@ impl FootballMatchSimulator {
     // Async Simulation API (for background processing)
     // ============================================================================
 
-    /// Start async simulation, returns job_id
+    /// Queue a simulation on a worker thread; returns { job_id, status } immediately.
+    /// Poll with `poll_job`, collect with `get_result`.
     #[func]
     pub fn start_simulation(&mut self, request_json: GString) -> GString {
-        // For now, run synchronously and return a dummy job_id
-        let job_id = format!(
-            "job_{}",
-            std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .unwrap_or_default()
-                .as_millis()
-        );
-
-        // Store result for later retrieval
-        let result = self.simulate_match(request_json);
-
-        // In a real async implementation, we'd spawn a thread here
-        // For now, we store the result immediately
-        GString::from(
-            json!({
-                "job_id": job_id,
-                "status": "completed",
-                "result": result.to_string()
-            })
-            .to_string(),
-        )
+        let request = request_json.to_string();
+        let job_id = self.jobs.lock_or_recover().submit(Box::new(move |cancel| {
+            simulate_match_json_cancellable(&request, cancel)
+        }));
+        Self::job_response(job_id, JobStatus::Queued)
     }
 
-    /// Start async simulation with time budget
+    /// Queue a budgeted simulation (wall-clock `budget_ms`) on a worker thread
     #[func]
     pub fn start_simulation_budget(&mut self, request_json: GString, budget_ms: i32) -> GString {
-        let job_id = format!(
-            "job_{}",
-            std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .unwrap_or_default()
-                .as_millis()
-        );
+        let request = request_json.to_string();
+        let budget_ms = if budget_ms > 0 { budget_ms as u64 } else { 50 };
+        let job_id = self.jobs.lock_or_recover().submit(Box::new(move |cancel| {
+            let budget = SimBudget::new(budget_ms, 120, 500).with_cancellation(cancel.clone());
+            simulate_match_json_budget(&request, budget)
+        }));
+        Self::job_response(job_id, JobStatus::Queued)
+    }
 
-        let result = self.simulate_match_with_budget(
-            request_json,
-            budget_ms as i64,
-            120, // max_minutes
-            500, // max_events
-        );
+    /// Status of a background job: queued / running / completed / failed / cancelled
+    #[func]
+    pub fn poll_job(&self, job_id: GString) -> GString {
+        let Some(id) = Self::parse_job_id(&job_id) else {
+            return self.create_error_response("Invalid job_id", "INVALID_JOB_ID");
+        };
+        match self.jobs.lock_or_recover().status(id) {
+            Some(status) => Self::job_response(id, status),
+            None => self.create_error_response("Unknown job_id", "UNKNOWN_JOB"),
+        }
+    }
 
-        GString::from(
-            json!({
-                "job_id": job_id,
-                "status": "completed",
-                "result": result.to_string()
-            })
-            .to_string(),
-        )
+    /// Cancel a background job (a running match stops early with a partial result)
+    #[func]
+    pub fn cancel_job(&self, job_id: GString) -> GString {
+        let Some(id) = Self::parse_job_id(&job_id) else {
+            return self.create_error_response("Invalid job_id", "INVALID_JOB_ID");
+        };
+        match self.jobs.lock_or_recover().cancel(id) {
+            Some(status) => Self::job_response(id, status),
+            None => self.create_error_response("Unknown job_id", "UNKNOWN_JOB"),
+        }
     }
 
-    /// Poll for async simulation progress (currently no-op as we run sync)
+    /// Apply finished-job reports from the workers (poll_job/get_result do this too)
     #[func]
     pub fn poll_simulation(&mut self) {
-        // No-op for synchronous implementation
+        self.jobs.lock_or_recover().drain_events();
+    }
+
+    /// Number of background jobs not yet finished: { queued, running }
+    #[func]
+    pub fn get_pending_jobs(&self) -> GString {
+        let (queued, running) = self.jobs.lock_or_recover().pending_counts();
+        GString::from(json!({ "queued": queued, "running": running }).to_string())
     }
 
-    /// Get result of completed simulation by job_id
+    /// Result of a finished job (removed once returned); only the status while pending
     #[func]
-    pub fn get_result(&self, _job_id: GString) -> GString {
-        // For sync implementation, return empty (result was already in start_simulation response)
-        GString::from("{}")
+    pub fn get_result(&self, job_id: GString) -> GString {
+        let Some(id) = Self::parse_job_id(&job_id) else {
+            return self.create_error_response("Invalid job_id", "INVALID_JOB_ID");
+        };
+        let mut jobs = self.jobs.lock_or_recover();
+        let Some(status) = jobs.status(id) else {
+            return self.create_error_response("Unknown job_id", "UNKNOWN_JOB");
+        };
+        match jobs.take_result(id) {
+            Some((status, Ok(result))) => GString::from(
+                json!({
+                    "job_id": Self::format_job_id(id),
+                    "status": status.as_str(),
+                    "result": result
+                })
+                .to_string(),
+            ),
+            Some((status, Err(e))) => GString::from(
+                json!({
+                    "job_id": Self::format_job_id(id),
+                    "status": status.as_str(),
+                    "error": true,
+                    "message": e
+                })
+                .to_string(),
+            ),
+            None => Self::job_response(id, status),
+        }
+    }
+
+    fn job_response(id: u64, status: JobStatus) -> GString {
+        GString::from(
+            json!({ "job_id": Self::format_job_id(id), "status": status.as_str() }).to_string(),
+        )
+    }
+
+    fn format_job_id(id: u64) -> String {
+        format!("job_{}", id)
+    }
+
+    fn parse_job_id(job_id: &GString) -> Option<u64> {
+        job_id.to_string().strip_prefix("job_")?.parse().ok()
     }
 
     // ============================================================================
@@ -3754,6 +3894,102 @@ impl FootballMatchSimulator {
         )
     }
 
+    /// Verify replay chunk checksums and match hash.
+    ///
+    /// With a non-empty `request_json` (the MatchRequestV2 that produced the replay) the
+    /// match is re-simulated as well. Response: `{ "valid", "reason"?, "tampered_chunks"?,
+    /// "resimulated", "first_divergent_tick"? }`
+    #[func]
+    pub fn verify_replay(&self, replay_json: GString, request_json: GString) -> GString {
+        use of_core::replay::verify_replay_json;
+
+        let request = request_json.to_string();
+        let request = (!request.is_empty()).then_some(request.as_str());
+        match verify_replay_json(&replay_json.to_string(), request) {
+            Ok(result) => GString::from(result),
+            Err(e) => self.create_error_response(
+                &format!("Replay verification failed: {}", e),
+                "REPLAY_VERIFY_ERROR",
+            ),
+        }
+    }
+
+    /// JSON Schema for "match_v1" | "match_v2" | "training" | "replay" payloads
+    #[func]
+    pub fn get_request_schema(&self, kind: GString) -> GString {
+        match of_core::api::get_request_schema(&kind.to_string()) {
+            Ok(schema) => GString::from(schema),
+            Err(e) => self.create_error_response(&e, "SCHEMA_ERROR"),
+        }
+    }
+
+    /// Pre-flight check of a match/training request (kind from `request_type` /
+    /// `schema_version`). Response: `{ "kind", "valid", "errors": [{ "path", "message" }] }`
+    #[func]
+    pub fn validate_request_json(&self, request_json: GString) -> GString {
+        match of_core::api::validate_request_json(&request_json.to_string()) {
+            Ok(report) => GString::from(report),
+            Err(e) => self.create_error_response(&e, "VALIDATION_ERROR"),
+        }
+    }
+
+    /// Squad screen check of one team (v2/v3 `TeamData` JSON). Response:
+    /// `{ "valid", "formation", "assignments": [{ "formation_slot", "roster_slot", "fit", ... }],
+    /// "issues": [{ "severity", "code", "roster_slot"?, "message" }] }`
+    #[func]
+    pub fn validate_roster_json(&self, team_json: GString) -> GString {
+        match of_core::api::validate_roster_json(&team_json.to_string()) {
+            Ok(report) => GString::from(report),
+            Err(e) => self.create_error_response(&e, "ROSTER_VALIDATION_ERROR"),
+        }
+    }
+
+    /// Edit a replay doc into a highlight reel definition.
+    ///
+    /// `options_json` may be empty (90s budget, default lead-in/out). Response:
+    /// `{ "budget_ms", "total_duration_ms", "clips": [{ "kind", "start_ms", "end_ms",
+    /// "focus_track_id"?, "slow_motion"?, "playback_ms", ... }], "dropped_clips" }`
+    #[func]
+    pub fn build_highlight_reel(&self, replay_json: GString, options_json: GString) -> GString {
+        use of_core::replay::build_highlight_reel_json;
+
+        let options = options_json.to_string();
+        match build_highlight_reel_json(&replay_json.to_string(), Some(options.as_str())) {
+            Ok(result) => GString::from(result),
+            Err(e) => self.create_error_response(
+                &format!("Highlight reel build failed: {}", e),
+                "HIGHLIGHT_ERROR",
+            ),
+        }
+    }
+
+    /// Compare two replay docs (e.g. golden vs. current engine build).
+    ///
+    /// `options_json` may be empty. Response: `{ "identical", "first_divergent_tick"?,
+    /// "first_divergent_event"?, "event_diffs": [...], "position_deltas": {...}, ... }`
+    #[func]
+    pub fn diff_replays(
+        &self,
+        replay_a_json: GString,
+        replay_b_json: GString,
+        options_json: GString,
+    ) -> GString {
+        use of_core::replay::diff_replays_json;
+
+        let options = options_json.to_string();
+        match diff_replays_json(
+            &replay_a_json.to_string(),
+            &replay_b_json.to_string(),
+            Some(options.as_str()),
+        ) {
+            Ok(result) => GString::from(result),
+            Err(e) => self.create_error_response(
+                &format!("Replay diff failed: {}", e),
+                "REPLAY_DIFF_ERROR",
+            ),
+        }
+    }
+
     /// Create test replay for debugging
     #[func]
     pub fn create_test_replay(&self) -> GString {
@@ -3807,24 +4043,56 @@ impl FootballMatchSimulator {
         match_result_json: GString,
         mode: GString,
     ) -> GString {
-        use of_core::models::MatchResult;
+        self.build_match_clips(&match_result_json.to_string(), &mode.to_string(), None)
+    }
 
-        let mode_str = mode.to_string();
+    /// Same as `get_match_clips_from_result`, tuned by a `HighlightConfig` JSON:
+    /// `{ "highlight_threshold": 40, "key_moment_threshold": 70, "max_clips": 8,
+    ///    "padding_before_ms": 2000, "padding_after_ms": 1000, "categories": ["goal", "save"] }`
+    ///
+    /// Clips are regenerated from events (stored `best_moments` are ignored).
+    #[func]
+    pub fn get_match_clips_from_result_with_config(
+        &self,
+        match_result_json: GString,
+        mode: GString,
+        config_json: GString,
+    ) -> GString {
+        use of_core::models::HighlightConfig;
 
-        let match_result: MatchResult = match serde_json::from_str(&match_result_json.to_string())
-        {
-            Ok(result) => result,
+        let config: HighlightConfig = match serde_json::from_str(&config_json.to_string()) {
+            Ok(config) => config,
             Err(e) => {
                 return self.create_error_response(
-                    &format!("Invalid match_result JSON: {e}"),
-                    "INVALID_MATCH_RESULT",
+                    &format!("Invalid highlight config JSON: {e}"),
+                    "INVALID_HIGHLIGHT_CONFIG",
                 );
             }
         };
+        self.build_match_clips(&match_result_json.to_string(), &mode.to_string(), Some(config))
+    }
 
-        // Full match mode: no clips needed (entire match playback)
-        if mode_str == "full" {
-            return GString::from(json!([]).to_string());
+    fn build_match_clips(
+        &self,
+        match_result_json: &str,
+        mode_str: &str,
+        config: Option<of_core::models::HighlightConfig>,
+    ) -> GString {
+        use of_core::models::MatchResult;
+
+        let match_result: MatchResult = match serde_json::from_str(match_result_json) {
+            Ok(result) => result,
+            Err(e) => {
+                return self.create_error_response(
+                    &format!("Invalid match_result JSON: {e}"),
+                    "INVALID_MATCH_RESULT",
+                );
+            }
+        };
+
+        // Full match mode: no clips needed (entire match playback)
+        if mode_str == "full" {
+            return GString::from(json!([]).to_string());
         }
 
         if mode_str != "highlight" && mode_str != "key_moment" {
@@ -3835,19 +4103,22 @@ impl FootballMatchSimulator {
         }
 
         // Source: BestMoment windows (event-only, deterministic).
+        let has_config = config.is_some();
+        let mut config = config.unwrap_or_default();
+        if mode_str == "key_moment" {
+            config = config.for_key_moments();
+        }
         let moments = match &match_result.best_moments {
-            Some(moments) => moments.clone(),
-            None => of_core::models::generate_best_moments(&match_result.events),
+            Some(moments) if !has_config => moments
+                .iter()
+                .filter(|m| m.priority >= config.highlight_threshold)
+                .cloned()
+                .collect(),
+            _ => of_core::models::generate_best_moments(&match_result.events, &config),
         };
 
-        let min_priority: u8 = if mode_str == "key_moment" { 70 } else { 40 };
-
         let mut clips: Vec<serde_json::Value> = Vec::new();
         for moment in moments {
-            if moment.priority < min_priority {
-                continue;
-            }
-
             let start_ms = moment.start_time_ms as i64;
             let end_ms = moment.end_time_ms as i64;
             let chance_score = (moment.priority as f64 / 100.0).clamp(0.0, 1.0);
@@ -3869,22 +4140,21 @@ impl FootballMatchSimulator {
     }
 
     /// Get field coordinate info
+    ///
+    /// Reflects the active live session's pitch (standard 105×68 when idle).
     #[func]
     pub fn get_field_coordinate_info(&self) -> GString {
-        GString::from(
-            json!({
-                "pitch_length_m": 105.0,
-                "pitch_width_m": 68.0,
-                "goal_width_m": 7.32,
-                "penalty_area_length_m": 16.5,
-                "penalty_area_width_m": 40.3,
-                "center_circle_radius_m": 9.15,
-                "coordinate_system": "normalized",
-                "x_range": [0.0, 1.0],
-                "y_range": [0.0, 1.0]
-            })
-            .to_string(),
-        )
+        let pitch = self
+            .live_session
+            .lock_or_recover()
+            .as_ref()
+            .map(|session| *session.engine.pitch())
+            .unwrap_or_default();
+
+        match serde_json::to_string(&pitch.coordinate_info()) {
+            Ok(json) => GString::from(json),
+            Err(e) => GString::from(json!({ "error": e.to_string() }).to_string()),
+        }
     }
 
     // ============================================================================
@@ -3999,7 +4269,7 @@ impl FootballMatchSimulator {
             return self.create_error_response("in_idx must be 11..17", "INVALID_IN_IDX");
         }
 
-        let mut session = self.live_session.borrow_mut();
+        let mut session = self.live_session.lock_or_recover();
         match session.as_mut() {
             Some(s) => match s.substitute(team_side, out_idx as usize, in_idx as usize) {
                 Ok(()) => GString::from(
@@ -4018,24 +4288,35 @@ impl FootballMatchSimulator {
         }
     }
 
-    /// Get match statistics
+    /// Get structured match statistics from a MatchResult JSON
+    ///
+    /// Uses the result's precomputed `match_statistics` when present, otherwise
+    /// derives them from the result's team statistics and events.
     #[func]
-    pub fn get_match_statistics_json(&self, _match_id: GString) -> GString {
-        GString::from(
-            json!({
-                "possession": { "home": 50, "away": 50 },
-                "shots": { "home": 10, "away": 8 },
-                "shots_on_target": { "home": 5, "away": 4 },
-                "corners": { "home": 5, "away": 3 },
-                "fouls": { "home": 12, "away": 10 },
-                "yellow_cards": { "home": 1, "away": 2 },
-                "red_cards": { "home": 0, "away": 0 },
-                "offsides": { "home": 2, "away": 3 },
-                "passes": { "home": 450, "away": 400 },
-                "pass_accuracy": { "home": 85.0, "away": 82.0 }
-            })
-            .to_string(),
-        )
+    pub fn get_match_statistics_json(&self, match_result_json: GString) -> GString {
+        use of_core::models::{MatchResult, MatchStatistics};
+
+        let match_result: MatchResult = match serde_json::from_str(&match_result_json.to_string()) {
+            Ok(result) => result,
+            Err(e) => {
+                return self.create_error_response(
+                    &format!("Invalid match result JSON: {}", e),
+                    "INVALID_MATCH_RESULT",
+                );
+            }
+        };
+
+        let statistics = match_result
+            .match_statistics
+            .clone()
+            .unwrap_or_else(|| MatchStatistics::from_result(&match_result));
+        match serde_json::to_string(&statistics) {
+            Ok(json) => GString::from(json),
+            Err(e) => self.create_error_response(
+                &format!("Failed to serialize match statistics: {}", e),
+                "SERIALIZATION_ERROR",
+            ),
+        }
     }
 
     /// Simulate match with tactical instructions
@@ -4057,17 +4338,23 @@ impl FootballMatchSimulator {
             Ok(reqs) => {
                 let mut results = Vec::new();
                 let limit = (batch_size as usize).min(reqs.len());
+                let cancel = self.current_cancel_token();
 
                 for req in reqs.into_iter().take(limit) {
+                    if cancel.is_cancelled() {
+                        break;
+                    }
                     let req_json = GString::from(req.to_string());
-                    let result = self.simulate_match(req_json);
+                    let result = self.simulate_match_cancellable(req_json, &cancel);
                     results.push(result.to_string());
                 }
 
+                // On cancel the last result (if any) is itself a truncated partial
                 GString::from(
                     json!({
                         "success": true,
                         "count": results.len(),
+                        "cancelled": cancel.is_cancelled(),
                         "results": results
                     })
                     .to_string(),
@@ -4079,6 +4366,50 @@ impl FootballMatchSimulator {
         }
     }
 
+    /// Simulate independent seeded matches in parallel (rayon pool).
+    ///
+    /// Request: `[MatchRequestV2...]`; `thread_count <= 0` uses one thread per logical CPU.
+    /// Response: `{ "results": [...], "aggregate": {...}, "timing": { "wall_clock_ms", ... } }`
+    #[func]
+    pub fn simulate_matches_parallel(&self, requests_json: GString, thread_count: i32) -> GString {
+        use of_core::api::simulate_matches_parallel_json;
+
+        match simulate_matches_parallel_json(
+            &requests_json.to_string(),
+            thread_count.max(0) as usize,
+        ) {
+            Ok(result) => GString::from(result),
+            Err(e) => self.create_error_response(
+                &format!("Parallel batch simulation failed: {}", e),
+                "BATCH_ERROR",
+            ),
+        }
+    }
+
+    /// Simulate a MatchRequestV2 under a designer ParameterSet (A/B tuning, no recompile).
+    ///
+    /// Parameters: `{ "pressing_efficiency", "pass_error", "shot_conversion", "fatigue_rate" }`
+    /// (omitted = 1.0). The result carries `parameter_hash` for reproducibility.
+    #[func]
+    pub fn simulate_with_parameters(
+        &self,
+        request_json: GString,
+        parameters_json: GString,
+    ) -> GString {
+        use of_core::api::simulate_with_parameters_json;
+
+        match simulate_with_parameters_json(
+            &request_json.to_string(),
+            &parameters_json.to_string(),
+        ) {
+            Ok(result) => GString::from(result),
+            Err(e) => self.create_error_response(
+                &format!("Parameterised simulation failed: {}", e),
+                "PARAMETER_ERROR",
+            ),
+        }
+    }
+
     // ============================================================================
     // Formation API
     // ============================================================================
@@ -4150,6 +4481,41 @@ impl FootballMatchSimulator {
         )
     }
 
+    /// Engine formation waypoints per slot for given instructions + phase.
+    ///
+    /// Request: `{ "formation": "4-3-3", "instructions": {..}, "phase": "Defense" }`.
+    /// Returns `[{slot, position, x, y}]` (team-relative, normalized 0-1).
+    #[func]
+    pub fn get_formation_waypoints(&self, request_json: GString) -> GString {
+        use of_core::api::get_formation_waypoints_json;
+
+        match get_formation_waypoints_json(&request_json.to_string()) {
+            Ok(result) => GString::from(result),
+            Err(e) => self.create_error_response(
+                &format!("Formation waypoints failed: {}", e),
+                "FORMATION_ERROR",
+            ),
+        }
+    }
+
+    /// Per-slot coverage/pressure heat overlays for the tactics editor preview.
+    ///
+    /// Request: `{ "formation": "4-3-3", "instructions": {..}, "cols": 12, "rows": 16 }`.
+    /// Returns `{cols, rows, slots: [{slot, position, coverage, pressure}], team_coverage,
+    /// team_pressure}` (row-major grids, row 0 = own goal line).
+    #[func]
+    pub fn get_tactical_heat_overlays(&self, request_json: GString) -> GString {
+        use of_core::api::get_tactical_heat_overlays_json;
+
+        match get_tactical_heat_overlays_json(&request_json.to_string()) {
+            Ok(result) => GString::from(result),
+            Err(e) => self.create_error_response(
+                &format!("Tactical heat overlays failed: {}", e),
+                "FORMATION_ERROR",
+            ),
+        }
+    }
+
     /// Recommend formations based on player roster
     #[func]
     pub fn recommend_formations(&self, _players_json: GString) -> GString {
@@ -4395,17 +4761,45 @@ impl FootballMatchSimulator {
         )
     }
 
-    /// Set player role
+    /// Set player role.
+    ///
+    /// Returns the role's preset instructions (with `role` set) to store in
+    /// `home_player_instructions` / `away_player_instructions` under the player's
+    /// name, plus the engine attribute weights the role applies in matches.
+    /// `player_json` may carry `position` to check role suitability.
     #[func]
     pub fn set_player_role(&self, player_json: GString, role_name: GString) -> GString {
-        let _player = player_json.to_string();
-        let role = role_name.to_string();
+        use of_core::player::instructions::PlayerRole;
+
+        let role_str = role_name.to_string();
+        let role: PlayerRole = match serde_json::from_value(json!(role_str)) {
+            Ok(role) => role,
+            Err(_) => {
+                return self.create_error_response(
+                    &format!("Unknown player role: {}", role_str),
+                    "INVALID_ROLE",
+                )
+            }
+        };
+
+        let position = serde_json::from_str::<JsonValue>(&player_json.to_string())
+            .ok()
+            .and_then(|player| player.get("position").cloned())
+            .and_then(|pos| serde_json::from_value::<of_core::models::Position>(pos).ok());
+        let attribute_modifiers: serde_json::Map<String, JsonValue> =
+            of_core::tactics::role_profile(role)
+                .attribute_weights
+                .iter()
+                .map(|(stat, weight)| (format!("{:?}", stat), json!(weight)))
+                .collect();
 
         GString::from(
             json!({
                 "success": true,
-                "role": role,
-                "attribute_modifiers": {}
+                "role": role_str,
+                "suitable": position.map(|pos| role.is_suitable_for(&pos)),
+                "instructions": role.default_instructions(),
+                "attribute_modifiers": attribute_modifiers
             })
             .to_string(),
         )
@@ -4485,6 +4879,414 @@ impl FootballMatchSimulator {
         }
     }
 
+    // ============================================================================
+    // Squad Rotation API
+    // ============================================================================
+
+    /// Suggest starting XI + bench for the next career fixture.
+    ///
+    /// Returns `RotationAdvice` JSON; `roster` is ready for MatchRequestV2.
+    #[func]
+    pub fn advise_squad_rotation(&self, request_json: GString) -> GString {
+        use of_core::api::advise_rotation_json;
+
+        match advise_rotation_json(&request_json.to_string()) {
+            Ok(result) => GString::from(result),
+            Err(e) => self
+                .create_error_response(&format!("Rotation advice failed: {}", e), "ROTATION_ERROR"),
+        }
+    }
+
+    // ============================================================================
+    // Youth Tournament API
+    // ============================================================================
+
+    /// Generate a deterministic youth opponent for a story chapter.
+    ///
+    /// Returns `YouthOpponent` JSON; `team` plugs into MatchRequestV2.
+    #[func]
+    pub fn generate_youth_opponent(&self, request_json: GString) -> GString {
+        use of_core::api::generate_youth_opponent_json;
+
+        match generate_youth_opponent_json(&request_json.to_string()) {
+            Ok(result) => GString::from(result),
+            Err(e) => self.create_error_response(
+                &format!("Youth opponent generation failed: {}", e),
+                "YOUTH_TOURNAMENT_ERROR",
+            ),
+        }
+    }
+
+    /// Generate a chapter's youth bracket (distinct regions).
+    #[func]
+    pub fn generate_youth_bracket(&self, request_json: GString) -> GString {
+        use of_core::api::generate_youth_bracket_json;
+
+        match generate_youth_bracket_json(&request_json.to_string()) {
+            Ok(result) => GString::from(result),
+            Err(e) => self.create_error_response(
+                &format!("Youth bracket generation failed: {}", e),
+                "YOUTH_TOURNAMENT_ERROR",
+            ),
+        }
+    }
+
+    // ============================================================================
+    // Season API
+    // ============================================================================
+
+    /// Simulate the next league matchday.
+    ///
+    /// Request: `{ "teams": [TeamDataV2...], "seed": u64 }` for a new season, or
+    /// `{ "league": <league from the previous response> }` to continue it.
+    #[func]
+    pub fn simulate_matchday(&self, request_json: GString) -> GString {
+        use of_core::api::simulate_matchday_json;
+
+        match simulate_matchday_json(&request_json.to_string()) {
+            Ok(result) => GString::from(result),
+            Err(e) => self.create_error_response(
+                &format!("Matchday simulation failed: {}", e),
+                "SEASON_ERROR",
+            ),
+        }
+    }
+
+    /// Simulate a whole cup tournament (bracket, results, top scorers).
+    ///
+    /// Request: `{ "teams": [TeamDataV2...], "seed": u64, "format": {"type": "single_elimination"},
+    /// "tie_rules": {"extra_time": true, "penalties": true} }`
+    #[func]
+    pub fn simulate_tournament(&self, request_json: GString) -> GString {
+        use of_core::api::simulate_tournament_json;
+
+        match simulate_tournament_json(&request_json.to_string()) {
+            Ok(result) => GString::from(result),
+            Err(e) => self.create_error_response(
+                &format!("Tournament simulation failed: {}", e),
+                "TOURNAMENT_ERROR",
+            ),
+        }
+    }
+
+    // ============================================================================
+    // Replay Text API
+    // ============================================================================
+
+    /// Embed localized key-event text into a replay doc before saving/sharing.
+    ///
+    /// Request: `{ "replay": ReplayDoc, "locales": ["en-US", ...], "content_packs": {...} }`
+    #[func]
+    pub fn embed_replay_text(&self, request_json: GString) -> GString {
+        use of_core::api::embed_replay_text_json;
+
+        match embed_replay_text_json(&request_json.to_string()) {
+            Ok(result) => GString::from(result),
+            Err(e) => self.create_error_response(
+                &format!("Replay text embed failed: {}", e),
+                "REPLAY_TEXT_ERROR",
+            ),
+        }
+    }
+
+    /// Replay "what if" from a moment: resimulate the remainder under alternative seeds.
+    ///
+    /// Request: `{ "match_request": MatchRequestV2, "timestamp_ms": u64, "seeds": [u64...],
+    /// "score_override": [home, away]?, "keyframe": MatchStateSnapshot?, "include_keyframe": bool }`
+    #[func]
+    pub fn simulate_what_if(&self, request_json: GString) -> GString {
+        use of_core::api::simulate_what_if_json;
+
+        match simulate_what_if_json(&request_json.to_string()) {
+            Ok(result) => GString::from(result),
+            Err(e) => self.create_error_response(
+                &format!("What-if resimulation failed: {}", e),
+                "WHAT_IF_ERROR",
+            ),
+        }
+    }
+
+    /// Pre-match odds: win/draw/loss probabilities, goal distributions, likeliest scoreline.
+    ///
+    /// Request: `MatchRequestV2`; `n_samples` seeded simulations, or `0` for the instant
+    /// statistical (Poisson) estimate.
+    #[func]
+    pub fn predict_match(&self, request_json: GString, n_samples: i32) -> GString {
+        use of_core::api::predict_match_json;
+
+        match predict_match_json(&request_json.to_string(), n_samples.max(0) as usize) {
+            Ok(result) => GString::from(result),
+            Err(e) => self.create_error_response(
+                &format!("Match prediction failed: {}", e),
+                "PREDICTION_ERROR",
+            ),
+        }
+    }
+
+    // ============================================================================
+    // Audio Cue API
+    // ============================================================================
+
+    /// Build the crowd/whistle cue track for an event list (replay playback).
+    ///
+    /// Live sessions already stream cues per tick as `audio_cues`.
+    #[func]
+    pub fn build_audio_cues(&self, request_json: GString) -> GString {
+        use of_core::api::build_audio_cues_json;
+
+        match build_audio_cues_json(&request_json.to_string()) {
+            Ok(result) => GString::from(result),
+            Err(e) => self.create_error_response(
+                &format!("Audio cue build failed: {}", e),
+                "AUDIO_CUE_ERROR",
+            ),
+        }
+    }
+
+    // ============================================================================
+    // Stadium API
+    // ============================================================================
+
+    /// Register a team's home stadium (pitch dimensions + surface) in the save.
+    ///
+    /// MatchRequestV2 picks it up with `"use_home_stadium": true`.
+    #[func]
+    pub fn register_stadium(&self, request_json: GString) -> GString {
+        use of_core::api::register_stadium_json;
+
+        match register_stadium_json(&request_json.to_string()) {
+            Ok(result) => GString::from(result),
+            Err(e) => self
+                .create_error_response(&format!("Stadium register failed: {}", e), "STADIUM_ERROR"),
+        }
+    }
+
+    /// Get a team's registered stadium (`null` when none).
+    #[func]
+    pub fn get_stadium(&self, request_json: GString) -> GString {
+        use of_core::api::get_stadium_json;
+
+        match get_stadium_json(&request_json.to_string()) {
+            Ok(result) => GString::from(result),
+            Err(e) => self
+                .create_error_response(&format!("Stadium lookup failed: {}", e), "STADIUM_ERROR"),
+        }
+    }
+
+    // ============================================================================
+    // Fixture Calendar API
+    // ============================================================================
+
+    /// Generate and store the career fixture calendar.
+    ///
+    /// Returns `FixtureCalendar` JSON (fixture ids + days).
+    #[func]
+    pub fn schedule_fixtures(&self, request_json: GString) -> GString {
+        use of_core::api::schedule_fixtures_json;
+
+        match schedule_fixtures_json(&request_json.to_string()) {
+            Ok(result) => GString::from(result),
+            Err(e) => self
+                .create_error_response(&format!("Scheduling failed: {}", e), "CALENDAR_ERROR"),
+        }
+    }
+
+    /// Record who played a calendar fixture (drives rest-day tracking).
+    #[func]
+    pub fn record_fixture_lineup(&self, request_json: GString) -> GString {
+        use of_core::api::record_fixture_lineup_json;
+
+        match record_fixture_lineup_json(&request_json.to_string()) {
+            Ok(result) => GString::from(result),
+            Err(e) => self
+                .create_error_response(&format!("Lineup record failed: {}", e), "CALENDAR_ERROR"),
+        }
+    }
+
+    /// Congestion report for `today`: upcoming fixtures, squad load and per-player effects.
+    ///
+    /// `load` goes into MatchRequestV2 `home_load`; `upcoming` into rotation advice.
+    #[func]
+    pub fn get_congestion_report(&self, request_json: GString) -> GString {
+        use of_core::api::get_congestion_report_json;
+
+        match get_congestion_report_json(&request_json.to_string()) {
+            Ok(result) => GString::from(result),
+            Err(e) => self.create_error_response(
+                &format!("Congestion report failed: {}", e),
+                "CALENDAR_ERROR",
+            ),
+        }
+    }
+
+    // ============================================================================
+    // Rating Ladder API
+    // ============================================================================
+
+    /// Apply a finished match to the saved Elo ladder.
+    ///
+    /// Returns `LadderUpdate` JSON (expected result, rating deltas).
+    #[func]
+    pub fn record_ladder_match(&self, request_json: GString) -> GString {
+        use of_core::api::record_ladder_match_json;
+
+        match record_ladder_match_json(&request_json.to_string()) {
+            Ok(result) => GString::from(result),
+            Err(e) => self
+                .create_error_response(&format!("Ladder update failed: {}", e), "LADDER_ERROR"),
+        }
+    }
+
+    /// Team or player leaderboard from the saved ladder (or replayed match history).
+    #[func]
+    pub fn get_ladder_leaderboard(&self, request_json: GString) -> GString {
+        use of_core::api::get_ladder_leaderboard_json;
+
+        match get_ladder_leaderboard_json(&request_json.to_string()) {
+            Ok(result) => GString::from(result),
+            Err(e) => self
+                .create_error_response(&format!("Leaderboard failed: {}", e), "LADDER_ERROR"),
+        }
+    }
+
+    // ============================================================================
+    // Career Highlights API
+    // ============================================================================
+
+    /// Store squad player goals/assists/big saves (with replay clip windows)
+    /// on a saved match record.
+    ///
+    /// Request: `{ match_id, match_result, players: { uid: name } }`.
+    #[func]
+    pub fn record_match_highlights(&self, request_json: GString) -> GString {
+        use of_core::api::record_match_highlights_json;
+
+        match record_match_highlights_json(&request_json.to_string()) {
+            Ok(result) => GString::from(result),
+            Err(e) => self.create_error_response(
+                &format!("Highlight record failed: {}", e),
+                "HIGHLIGHTS_ERROR",
+            ),
+        }
+    }
+
+    /// Compile one player's season/career montage from saved match history.
+    ///
+    /// Request: `{ player_uid, season?, kinds?, max_clips? }`; returns
+    /// `CareerHighlightManifest` JSON (chronological clip references).
+    #[func]
+    pub fn compile_career_highlights(&self, request_json: GString) -> GString {
+        use of_core::api::compile_career_highlights_json;
+
+        match compile_career_highlights_json(&request_json.to_string()) {
+            Ok(result) => GString::from(result),
+            Err(e) => self.create_error_response(
+                &format!("Highlight compilation failed: {}", e),
+                "HIGHLIGHTS_ERROR",
+            ),
+        }
+    }
+
+    // ============================================================================
+    // Pass Network API
+    // ============================================================================
+
+    /// Pass adjacency, shot-ending possession chains and centrality per team
+    /// for the tactics screen.
+    ///
+    /// Request: `{ events }`; returns `MatchPassNetwork` JSON.
+    #[func]
+    pub fn get_pass_network_json(&self, request_json: GString) -> GString {
+        use of_core::api::get_pass_network_json;
+
+        match get_pass_network_json(&request_json.to_string()) {
+            Ok(result) => GString::from(result),
+            Err(e) => self.create_error_response(
+                &format!("Pass network failed: {}", e),
+                "PASS_NETWORK_ERROR",
+            ),
+        }
+    }
+
+    // ============================================================================
+    // Commentary API
+    // ============================================================================
+
+    /// Localized commentary lines for a finished match.
+    ///
+    /// `lang`: `"ko"` or `"en"`. Returns `MatchCommentary` JSON
+    /// (`{ locale, seed, lines: [{ minute, intensity, key, text, ... }] }`).
+    #[func]
+    pub fn generate_commentary_json(&self, match_result_json: GString, lang: GString) -> GString {
+        use of_core::api::generate_commentary_json;
+
+        match generate_commentary_json(&match_result_json.to_string(), &lang.to_string()) {
+            Ok(result) => GString::from(result),
+            Err(e) => self
+                .create_error_response(&format!("Commentary failed: {}", e), "COMMENTARY_ERROR"),
+        }
+    }
+
+    // ============================================================================
+    // Async PvP Challenge API
+    // ============================================================================
+
+    /// Pack squad + tactics + deck into a signed challenge payload.
+    ///
+    /// Returns `ChallengePayload` JSON to send to the opponent.
+    #[func]
+    pub fn pack_pvp_challenge(&self, request_json: GString) -> GString {
+        use of_core::api::pack_challenge_json;
+
+        match pack_challenge_json(&request_json.to_string()) {
+            Ok(result) => GString::from(result),
+            Err(e) => self
+                .create_error_response(&format!("Challenge pack failed: {}", e), "CHALLENGE_ERROR"),
+        }
+    }
+
+    /// Simulate a received challenge locally (responder plays at home).
+    ///
+    /// Returns `ChallengeOutcome` JSON: seed, score and `result_hash`.
+    #[func]
+    pub fn simulate_pvp_challenge(&self, request_json: GString) -> GString {
+        use of_core::api::simulate_challenge_json;
+
+        match simulate_challenge_json(&request_json.to_string()) {
+            Ok(result) => GString::from(result),
+            Err(e) => self.create_error_response(
+                &format!("Challenge simulation failed: {}", e),
+                "CHALLENGE_ERROR",
+            ),
+        }
+    }
+
+    /// Re-simulate a challenge and check the opponent's claimed result hash.
+    #[func]
+    pub fn verify_pvp_challenge_result(&self, request_json: GString) -> GString {
+        use of_core::api::verify_challenge_result_json;
+
+        match verify_challenge_result_json(&request_json.to_string()) {
+            Ok(result) => GString::from(result),
+            Err(e) => self.create_error_response(
+                &format!("Challenge verification failed: {}", e),
+                "CHALLENGE_ERROR",
+            ),
+        }
+    }
+
+    // ============================================================================
+    // Squad Chemistry API
+    // ============================================================================
+
+    /// Compute squad chemistry matrix (players + optional lineup history)
+    #[func]
+    pub fn calculate_squad_chemistry(&self, request_json: GString) -> GString {
+        use of_core::api::calculate_squad_chemistry_json;
+
+        GString::from(calculate_squad_chemistry_json(&request_json.to_string()))
+    }
+
     // ============================================================================
     // Personality System API
     // ============================================================================
@@ -4570,6 +5372,20 @@ impl FootballMatchSimulator {
         }).to_string())
     }
 
+    /// Abort every in-flight `simulate_match*` / batch / budget call.
+    ///
+    /// Running simulations stop at their next minute boundary and return a
+    /// partial result (`determinism.mode = "truncated"`, `cut_reason` set); batch
+    /// calls skip their remaining matches. Queued background jobs are dropped.
+    /// Calls started afterwards run normally.
+    #[func]
+    pub fn cancel_simulations(&self) {
+        let mut token = self.cancel_token.lock_or_recover();
+        token.cancel();
+        *token = CancellationToken::new();
+        self.jobs.lock_or_recover().cancel_all();
+    }
+
     /// Suggest memory cleanup
     #[func]
     pub fn suggest_memory_cleanup(&self) {
@@ -4578,14 +5394,26 @@ impl FootballMatchSimulator {
     }
 
     /// Get memory statistics
+    ///
+    /// Per-subsystem byte counts for every engine this simulator holds (interactive
+    /// engine, live session, background sessions) plus loaded caches.
     #[func]
     pub fn get_memory_stats(&self) -> GString {
+        use of_core::engine::MemoryReport;
+
+        // Session locks are never held together: measure each under its own lock.
+        let interactive =
+            self.interactive_engine.lock_or_recover().as_ref().map(|e| e.memory_usage());
+        let live = self.live_session.lock_or_recover().as_ref().map(|s| s.memory_usage(None));
+        let background = self.background_sessions.lock_or_recover().memory_usage();
+        let report = MemoryReport::from_parts(interactive, live, background);
+
         GString::from(
             json!({
-                "heap_used_bytes": 0,
-                "cache_entries": 0,
-                "active_simulations": 0,
-                "note": "Memory stats not available in this build"
+                "heap_used_bytes": report.total_bytes,
+                "cache_entries": report.caches.person_cache_entries,
+                "active_simulations": report.active_simulations,
+                "breakdown": report,
             })
             .to_string(),
         )
@@ -4604,7 +5432,7 @@ impl FootballMatchSimulator {
     /// This is called automatically when creating a new session to prevent
     /// memory leaks from abandoned sessions.
     fn cleanup_stale_session(&mut self) {
-        let mut session = self.live_session.borrow_mut();
+        let mut session = self.live_session.lock_or_recover();
         if let Some(ref s) = *session {
             if s.is_stale() {
                 // Log cleanup for debugging
@@ -4616,19 +5444,21 @@ impl FootballMatchSimulator {
                 *session = None;
             }
         }
+        drop(session);
+        self.background_sessions.lock_or_recover().remove_stale(LiveMatchSession::DEFAULT_TTL_SECS);
     }
 
     /// Check if there is an active (non-stale) session.
     #[func]
     pub fn has_active_session(&self) -> bool {
-        let session = self.live_session.borrow();
+        let session = self.live_session.lock_or_recover();
         session.as_ref().map(|s| !s.is_stale()).unwrap_or(false)
     }
 
     /// Get session info including TTL status.
     #[func]
     pub fn get_session_info(&self) -> GString {
-        let session = self.live_session.borrow();
+        let session = self.live_session.lock_or_recover();
         match session.as_ref() {
             Some(s) => {
                 GString::from(
@@ -4681,6 +5511,27 @@ impl FootballMatchSimulator {
         let team_view_config = request_value
             .as_ref()
             .and_then(parse_team_view_observation_config);
+        let debug_pass_candidates = request_value
+            .as_ref()
+            .and_then(|v| v.get("debug_pass_candidates").and_then(|x| x.as_bool()))
+            .unwrap_or(false);
+        let debug_marking = request_value
+            .as_ref()
+            .and_then(|v| v.get("debug_marking").and_then(|x| x.as_bool()))
+            .unwrap_or(false);
+        let win_probability = request_value
+            .as_ref()
+            .and_then(|v| v.get("win_probability").and_then(|x| x.as_bool()))
+            .unwrap_or(false);
+        let background = request_value
+            .as_ref()
+            .and_then(|v| v.get("background").and_then(|x| x.as_bool()))
+            .unwrap_or(false);
+        // Minimal HUDs: only convert the subscribed events into per-tick Dictionaries
+        let event_subscription = request_value
+            .as_ref()
+            .and_then(|v| v.get("event_subscription"))
+            .and_then(|x| serde_json::from_value::<EventSubscription>(x.clone()).ok());
 
         match schema_version {
             // MatchRequest v2 (UID roster-only): preferred for Phase23.5 session compliance.
@@ -4699,7 +5550,24 @@ impl FootballMatchSimulator {
                 if let Some(config) = team_view_config.clone() {
                     session.set_team_view_observation_config(config);
                 }
-                *self.live_session.borrow_mut() = Some(session);
+                session.set_pass_candidate_debug_enabled(debug_pass_candidates);
+                session.set_marking_export_enabled(debug_marking);
+                session.set_win_probability_enabled(win_probability);
+                session.set_event_subscription(event_subscription.clone());
+                if background {
+                    let session_id = self.background_sessions.lock_or_recover().insert(session);
+                    return GString::from(
+                        json!({
+                            "success": true,
+                            "state": "not_started",
+                            "schema_version": 2,
+                            "session_id": session_id,
+                            "message": "Background session created. Drive it with the *_live_session_by_id API."
+                        })
+                        .to_string(),
+                    );
+                }
+                *self.live_session.lock_or_recover() = Some(session);
 
                 GString::from(
                     json!({
@@ -4764,6 +5632,8 @@ impl FootballMatchSimulator {
                                     equipped_skills: Vec::new(),
                                     traits: Default::default(),
                                     personality: Default::default(),
+                                    morale: of_core::player::NEUTRAL_MORALE,
+                                    form: of_core::player::NEUTRAL_FORM,
                                 });
                             }
 
@@ -4790,12 +5660,24 @@ impl FootballMatchSimulator {
                             user_player: None,
                             home_match_modifiers: of_core::engine::TeamMatchModifiers::default(),
                             away_match_modifiers: of_core::engine::TeamMatchModifiers::default(),
+                            modifier_stack: Default::default(),
                             home_instructions: request.home_instructions,
                             away_instructions: request.away_instructions,
                             home_player_instructions: None,
                             away_player_instructions: None,
                             home_ai_difficulty: None,
                             away_ai_difficulty: None,
+                            pitch: of_core::models::pitch::PitchSpec::default(),
+                            home_chemistry: None,
+                            away_chemistry: None,
+                            stadium: None,
+                            conditions: None,
+                            penalty_shootout: None,
+                            home_custom_formation: None,
+                            away_custom_formation: None,
+                            home_set_piece_takers: None,
+                            away_set_piece_takers: None,
+                            referee: None,
                         };
 
                         let mut session = match LiveMatchSession::new(plan) {
@@ -4805,7 +5687,11 @@ impl FootballMatchSimulator {
                         if let Some(config) = team_view_config.clone() {
                             session.set_team_view_observation_config(config);
                         }
-                        *self.live_session.borrow_mut() = Some(session);
+                        session.set_pass_candidate_debug_enabled(debug_pass_candidates);
+                        session.set_marking_export_enabled(debug_marking);
+                        session.set_win_probability_enabled(win_probability);
+                        session.set_event_subscription(event_subscription.clone());
+                        *self.live_session.lock_or_recover() = Some(session);
 
                         GString::from(json!({
                             "success": true,
@@ -4829,7 +5715,7 @@ impl FootballMatchSimulator {
     /// Kick off the match session (start first half).
     #[func]
     pub fn kick_off_live_match(&mut self) -> GString {
-        let mut session = self.live_session.borrow_mut();
+        let mut session = self.live_session.lock_or_recover();
         match session.as_mut() {
             Some(s) => {
                 s.kick_off();
@@ -4853,7 +5739,7 @@ impl FootballMatchSimulator {
     // Used by step_live_session() for optimal performance
     // ========================================================================
     fn step_live_internal(&mut self) -> Option<StepResult> {
-        let mut session = self.live_session.borrow_mut();
+        let mut session = self.live_session.lock_or_recover();
         session.as_mut().map(|s| s.step())
     }
 
@@ -4862,7 +5748,7 @@ impl FootballMatchSimulator {
     /// NOTE: For Godot integration, prefer step_match_session() which returns Dictionary directly.
     #[func]
     pub fn step_live_match(&mut self) -> GString {
-        let mut session = self.live_session.borrow_mut();
+        let mut session = self.live_session.lock_or_recover();
         match session.as_mut() {
             Some(s) => {
                 let result = s.step();
@@ -4886,6 +5772,7 @@ impl FootballMatchSimulator {
                         let events: Vec<serde_json::Value> = data.events.iter().map(|e| {
                             json!({
                                 "minute": e.minute,
+                                "timestamp_ms": e.timeline_ms(),
                                 "type": format!("{:?}", e.event_type),
                                 "is_home_team": e.is_home_team,
                                 "player_track_id": e.player_track_id  // C7: Use track_id
@@ -4938,6 +5825,7 @@ impl FootballMatchSimulator {
                         let events: Vec<serde_json::Value> = data.all_events.iter().map(|e| {
                             json!({
                                 "minute": e.minute,
+                                "timestamp_ms": e.timeline_ms(),
                                 "type": format!("{:?}", e.event_type),
                                 "is_home_team": e.is_home_team,
                                 "player_track_id": e.player_track_id  // C7: Use track_id
@@ -4946,7 +5834,7 @@ impl FootballMatchSimulator {
 
                         // Clear session on full time
                         drop(session);
-                        *self.live_session.borrow_mut() = None;
+                        *self.live_session.lock_or_recover() = None;
 
                         GString::from(json!({
                             "result_type": "full_time",
@@ -4964,7 +5852,7 @@ impl FootballMatchSimulator {
     /// Resume second half after half-time break.
     #[func]
     pub fn resume_second_half(&mut self) -> GString {
-        let mut session = self.live_session.borrow_mut();
+        let mut session = self.live_session.lock_or_recover();
         match session.as_mut() {
             Some(s) => {
                 s.resume_second_half();
@@ -4985,7 +5873,7 @@ impl FootballMatchSimulator {
     /// team: "home" or "away"
     #[func]
     pub fn change_live_tactic(&mut self, team: GString, instructions_json: GString) -> GString {
-        let mut session = self.live_session.borrow_mut();
+        let mut session = self.live_session.lock_or_recover();
         match session.as_mut() {
             Some(s) => {
                 let team_str = team.to_string();
@@ -4995,20 +5883,20 @@ impl FootballMatchSimulator {
                     TeamSide::Away
                 };
 
-                let instructions: Result<TeamInstructions, _> =
+                let change: Result<LiveTacticChange, _> =
                     serde_json::from_str(&instructions_json.to_string());
-                match instructions {
-                    Ok(instr) => {
-                        s.change_tactic(team_side, instr);
-                        GString::from(
+                match change {
+                    Ok(change) => match s.apply_tactic_change(team_side, change) {
+                        Ok(()) => GString::from(
                             json!({
                                 "success": true,
                                 "team": team_str,
                                 "message": "Tactics updated"
                             })
                             .to_string(),
-                        )
-                    }
+                        ),
+                        Err(e) => self.create_error_response(&e, "INVALID_SET_PIECE_TAKER"),
+                    },
                     Err(e) => self.create_error_response(
                         &format!("Invalid instructions: {}", e),
                         "PARSE_ERROR",
@@ -5019,12 +5907,60 @@ impl FootballMatchSimulator {
         }
     }
 
+    /// Replace one player's individual instructions during the match.
+    /// track_id: pitch slot (0-10 home, 11-21 away)
+    /// instructions_json: PlayerInstructions JSON (see get_player_instructions_schema)
+    #[func]
+    pub fn set_live_player_instructions(
+        &mut self,
+        track_id: i32,
+        instructions_json: GString,
+    ) -> GString {
+        use of_core::player::PlayerInstructions;
+
+        let instructions: PlayerInstructions =
+            match serde_json::from_str(&instructions_json.to_string()) {
+                Ok(instructions) => instructions,
+                Err(e) => {
+                    return self.create_error_response(
+                        &format!("Invalid player instructions: {}", e),
+                        "PARSE_ERROR",
+                    )
+                }
+            };
+
+        // Negative slots map to an out-of-range index and are rejected by the engine
+        let track = usize::try_from(track_id).unwrap_or(usize::MAX);
+
+        let mut session = self.live_session.lock_or_recover();
+        match session.as_mut() {
+            Some(s) => match s.set_player_instructions(track, instructions) {
+                Ok(()) => GString::from(
+                    json!({
+                        "success": true,
+                        "track_id": track_id,
+                        "message": "Player instructions updated"
+                    })
+                    .to_string(),
+                ),
+                Err(e) => self.create_error_response(&e, "INVALID_PLAYER_INSTRUCTIONS"),
+            },
+            None => self.create_error_response("No match session active", "NO_SESSION"),
+        }
+    }
+
+    /// JSON schema of PlayerInstructions (for editor forms / request validation)
+    #[func]
+    pub fn get_player_instructions_schema(&self) -> GString {
+        GString::from(of_core::player::player_instructions_schema().to_string())
+    }
+
     /// Change team formation during the match (Phase 5).
     /// team: "home" or "away"
     /// formation: "4-4-2", "4-3-3", "4-5-1", "3-4-3", "4-2-3-1", "3-5-2"
     #[func]
     pub fn change_formation_live_match(&mut self, team: GString, formation: GString) -> GString {
-        let mut session = self.live_session.borrow_mut();
+        let mut session = self.live_session.lock_or_recover();
         match session.as_mut() {
             Some(s) => {
                 let team_str = team.to_string();
@@ -5065,7 +6001,7 @@ impl FootballMatchSimulator {
     /// in_idx: index of player coming in from bench (11+)
     #[func]
     pub fn substitute_live_match(&mut self, team: GString, out_idx: i32, in_idx: i32) -> GString {
-        let mut session = self.live_session.borrow_mut();
+        let mut session = self.live_session.lock_or_recover();
         match session.as_mut() {
             Some(s) => {
                 let team_str = team.to_string();
@@ -5100,10 +6036,142 @@ impl FootballMatchSimulator {
         }
     }
 
+    /// Give the half-time team talk (only while the session is at half-time).
+    /// team: "home" or "away"
+    /// tone: "calm" | "encourage" | "praise" | "demand" | "criticize"
+    #[func]
+    pub fn give_live_team_talk(&mut self, team: GString, tone: GString) -> GString {
+        use of_core::models::TeamTalkTone;
+
+        let tone: TeamTalkTone = match serde_json::from_value(JsonValue::String(tone.to_string())) {
+            Ok(tone) => tone,
+            Err(_) => {
+                return self.create_error_response(
+                    &format!("Unknown team talk tone: {}", tone),
+                    "TEAM_TALK_FAILED",
+                )
+            }
+        };
+
+        let mut session = self.live_session.lock_or_recover();
+        match session.as_mut() {
+            Some(s) => {
+                let team_str = team.to_string();
+                let team_side = if team_str == "home" { TeamSide::Home } else { TeamSide::Away };
+                match s.give_team_talk(team_side, tone) {
+                    Ok(talk) => GString::from(
+                        json!({
+                            "success": true,
+                            "team": team_str,
+                            "team_talk": talk,
+                        })
+                        .to_string(),
+                    ),
+                    Err(e) => self.create_error_response(e, "TEAM_TALK_FAILED"),
+                }
+            }
+            None => self.create_error_response("No match session active", "NO_SESSION"),
+        }
+    }
+
+    /// Preview recommended substitutions for the live session (no roster change).
+    /// team: "home" or "away"
+    /// policy_json: SubstitutionPolicy JSON ("" or "{}" for defaults)
+    /// Apply a recommendation with substitute_live_match(team, out_idx, in_idx).
+    #[func]
+    pub fn plan_live_substitutions(&self, team: GString, policy_json: GString) -> GString {
+        use of_core::engine::substitution_planner::SubstitutionPolicy;
+
+        let policy_str = policy_json.to_string();
+        let policy: SubstitutionPolicy = if policy_str.trim().is_empty() {
+            SubstitutionPolicy::default()
+        } else {
+            match serde_json::from_str(&policy_str) {
+                Ok(policy) => policy,
+                Err(e) => {
+                    return self.create_error_response(
+                        &format!("Invalid substitution policy: {}", e),
+                        "SUBSTITUTION_PLAN_ERROR",
+                    )
+                }
+            }
+        };
+
+        let session = self.live_session.lock_or_recover();
+        match session.as_ref() {
+            Some(s) => {
+                let team_side =
+                    if team.to_string() == "home" { TeamSide::Home } else { TeamSide::Away };
+                match serde_json::to_string(&s.plan_substitutions(team_side, &policy)) {
+                    Ok(json) => GString::from(json),
+                    Err(e) => self.create_error_response(
+                        &format!("Failed to serialize substitution plan: {}", e),
+                        "SUBSTITUTION_PLAN_ERROR",
+                    ),
+                }
+            }
+            None => self.create_error_response("No match session active", "NO_SESSION"),
+        }
+    }
+
+    /// Ranked substitution advice for the live session: tired players, card
+    /// risk (booked) and tactical mismatches (out of position for the slot).
+    /// team: "home" or "away"
+    /// policy_json: SubstitutionPolicy JSON ("" or "{}" for defaults)
+    /// Each suggestion carries rank, urgency, reasons and the substitute_live_match() indices.
+    #[func]
+    pub fn get_substitution_advice(&self, team: GString, policy_json: GString) -> GString {
+        use of_core::engine::substitution_planner::SubstitutionPolicy;
+
+        let policy_str = policy_json.to_string();
+        let policy: SubstitutionPolicy = if policy_str.trim().is_empty() {
+            SubstitutionPolicy::default()
+        } else {
+            match serde_json::from_str(&policy_str) {
+                Ok(policy) => policy,
+                Err(e) => {
+                    return self.create_error_response(
+                        &format!("Invalid substitution policy: {}", e),
+                        "SUBSTITUTION_ADVICE_ERROR",
+                    )
+                }
+            }
+        };
+
+        let session = self.live_session.lock_or_recover();
+        match session.as_ref() {
+            Some(s) => {
+                let team_side =
+                    if team.to_string() == "home" { TeamSide::Home } else { TeamSide::Away };
+                match serde_json::to_string(&s.get_substitution_advice(team_side, &policy)) {
+                    Ok(json) => GString::from(json),
+                    Err(e) => self.create_error_response(
+                        &format!("Failed to serialize substitution advice: {}", e),
+                        "SUBSTITUTION_ADVICE_ERROR",
+                    ),
+                }
+            }
+            None => self.create_error_response("No match session active", "NO_SESSION"),
+        }
+    }
+
+    /// Recommend substitutions from an explicit team snapshot (PlanSubstitutionsRequest JSON).
+    #[func]
+    pub fn plan_substitutions(&self, request_json: GString) -> GString {
+        use of_core::api::plan_substitutions_json;
+        match plan_substitutions_json(&request_json.to_string()) {
+            Ok(result) => GString::from(result),
+            Err(e) => self.create_error_response(
+                &format!("Substitution planning failed: {}", e),
+                "SUBSTITUTION_PLAN_ERROR",
+            ),
+        }
+    }
+
     /// Get current match session state.
     #[func]
     pub fn get_live_match_state(&self) -> GString {
-        let session = self.live_session.borrow();
+        let session = self.live_session.lock_or_recover();
         match session.as_ref() {
             Some(s) => {
                 let state = match s.get_state() {
@@ -5135,10 +6203,32 @@ impl FootballMatchSimulator {
         }
     }
 
+    /// Flush the replay recorded since the last call (append each chunk to disk).
+    ///
+    /// Response: `{ "seq", "first_tick", "tick_count", "events", "positions", "is_final" }`.
+    /// Once streaming, the full-time result's `position_data` only covers the unflushed tail.
+    #[func]
+    pub fn take_live_replay_chunk(&mut self) -> GString {
+        let mut session = self.live_session.lock_or_recover();
+        let Some(s) = session.as_mut() else {
+            return self.create_error_response("No match session active", "NO_SESSION");
+        };
+        match s.take_replay_chunk() {
+            Some(chunk) => match serde_json::to_string(&chunk) {
+                Ok(json) => GString::from(json),
+                Err(e) => self.create_error_response(
+                    &format!("Replay chunk serialization failed: {}", e),
+                    "SERIALIZATION_ERROR",
+                ),
+            },
+            None => self.create_error_response("Match not kicked off", "NOT_STARTED"),
+        }
+    }
+
     /// End match session (cleanup).
     #[func]
     pub fn end_live_session(&mut self) -> GString {
-        *self.live_session.borrow_mut() = None;
+        *self.live_session.lock_or_recover() = None;
         GString::from(
             json!({
                 "success": true,
@@ -5148,6 +6238,132 @@ impl FootballMatchSimulator {
         )
     }
 
+    // ========================================================================
+    // Keyed background sessions
+    // (create with `create_live_session` + `"background": true`; the player's
+    //  own match keeps using the single-session API above)
+    // ========================================================================
+
+    /// Kick off a background session.
+    #[func]
+    pub fn kick_off_live_session_by_id(&mut self, session_id: i64) -> GString {
+        let mut sessions = self.background_sessions.lock_or_recover();
+        match sessions.get_mut(session_id as u64) {
+            Some(s) => {
+                s.kick_off();
+                GString::from(
+                    json!({ "success": true, "session_id": session_id, "state": "first_half" })
+                        .to_string(),
+                )
+            }
+            None => self.create_error_response("Unknown session_id", "NO_SESSION"),
+        }
+    }
+
+    /// Advance a background session by up to `ticks` ticks (250ms each).
+    ///
+    /// Stops early at half-time (call `resume_live_session_by_id`) and at
+    /// full-time, where the session is removed and the final result returned.
+    #[func]
+    pub fn step_live_session_by_id(&mut self, session_id: i64, ticks: i32) -> GString {
+        let mut sessions = self.background_sessions.lock_or_recover();
+        let Some(session) = sessions.get_mut(session_id as u64) else {
+            return self.create_error_response("Unknown session_id", "NO_SESSION");
+        };
+
+        let mut events = Vec::new();
+        let mut payload = json!({ "result_type": "not_started" });
+        for _ in 0..ticks.max(1) {
+            match session.step() {
+                StepResult::NotStarted => break,
+                StepResult::Tick(data) => {
+                    events.extend(data.events.iter().map(|e| {
+                        json!({
+                            "minute": e.minute,
+                            "timestamp_ms": e.timeline_ms(),
+                            "type": format!("{:?}", e.event_type),
+                            "is_home_team": e.is_home_team,
+                            "player_track_id": e.player_track_id
+                        })
+                    }));
+                    payload = json!({
+                        "result_type": "tick",
+                        "minute": data.minute,
+                        "score": { "home": data.score.0, "away": data.score.1 }
+                    });
+                }
+                StepResult::HalfTime(data) => {
+                    payload = json!({
+                        "result_type": "half_time",
+                        "score": { "home": data.score.0, "away": data.score.1 }
+                    });
+                    break;
+                }
+                StepResult::FullTime(data) => {
+                    let result = to_json_value_or_null(&data.result);
+                    sessions.remove(session_id as u64);
+                    payload = json!({
+                        "result_type": "full_time",
+                        "score": { "home": data.result.score_home, "away": data.result.score_away },
+                        "result": result,
+                        "match_complete": true
+                    });
+                    break;
+                }
+            }
+        }
+
+        if let Some(obj) = payload.as_object_mut() {
+            obj.insert("session_id".to_string(), json!(session_id));
+            obj.insert("events".to_string(), json!(events));
+        }
+        GString::from(payload.to_string())
+    }
+
+    /// Resume a background session after its half-time break.
+    #[func]
+    pub fn resume_live_session_by_id(&mut self, session_id: i64) -> GString {
+        let mut sessions = self.background_sessions.lock_or_recover();
+        match sessions.get_mut(session_id as u64) {
+            Some(s) => {
+                s.resume_second_half();
+                GString::from(
+                    json!({ "success": true, "session_id": session_id, "state": "second_half" })
+                        .to_string(),
+                )
+            }
+            None => self.create_error_response("Unknown session_id", "NO_SESSION"),
+        }
+    }
+
+    /// End (drop) a background session.
+    #[func]
+    pub fn end_live_session_by_id(&mut self, session_id: i64) -> GString {
+        let removed = self.background_sessions.lock_or_recover().remove(session_id as u64).is_some();
+        GString::from(json!({ "success": removed, "session_id": session_id }).to_string())
+    }
+
+    /// List background sessions with their state and score.
+    #[func]
+    pub fn get_live_session_ids(&self) -> GString {
+        let sessions = self.background_sessions.lock_or_recover();
+        let list: Vec<serde_json::Value> = sessions
+            .ids()
+            .into_iter()
+            .filter_map(|id| sessions.get(id).map(|s| (id, s)))
+            .map(|(id, s)| {
+                let (home, away) = s.get_score();
+                json!({
+                    "session_id": id,
+                    "state": format!("{:?}", s.get_state()),
+                    "minute": s.get_minute(),
+                    "score": { "home": home, "away": away }
+                })
+            })
+            .collect();
+        GString::from(json!({ "sessions": list }).to_string())
+    }
+
     // ============================================================================
     // Legacy session API - spec-compatible wrappers
     // (internal naming remains `*_live_*` for now; Godot should use `*_match_session` aliases)
@@ -5352,7 +6568,7 @@ impl FootballMatchSimulator {
         // Read TransitionSystem remaining time after stepping (SSOT: engine state).
         // Note: transition counts down at decision-tick cadence (250ms).
         let transition_remaining_ms: i32 = {
-            let session = self.live_session.borrow();
+            let session = self.live_session.lock_or_recover();
             session
                 .as_ref()
                 .and_then(|s| s.engine.get_transition_remaining_ms())
@@ -5416,6 +6632,10 @@ impl FootballMatchSimulator {
                     snapshot.set("decision_intents", intents);
                 }
 
+                if let Some(marking) = &data.marking {
+                    snapshot.set("marking", convert_marking_snapshot_to_dict(marking));
+                }
+
                 let mut offside = Dictionary::new();
                 offside.set("home_x", data.offside_lines.home_x);
                 offside.set("away_x", data.offside_lines.away_x);
@@ -5430,6 +6650,24 @@ impl FootballMatchSimulator {
                 }
                 dict.set("events", events_array);
 
+                if !data.audio_cues.is_empty() {
+                    if let Ok(value) = serde_json::to_value(&data.audio_cues) {
+                        dict.set("audio_cues", json_value_to_variant(&value));
+                    }
+                }
+
+                if let Some(p) = &data.win_probability {
+                    if let Ok(value) = serde_json::to_value(p) {
+                        dict.set("win_probability", json_value_to_variant(&value));
+                    }
+                }
+
+                if !data.tactical_insights.is_empty() {
+                    if let Ok(value) = serde_json::to_value(&data.tactical_insights) {
+                        dict.set("tactical_insights", json_value_to_variant(&value));
+                    }
+                }
+
                 if let Some(obs) = &data.team_view_simple {
                     dict.set("team_view_simple", convert_team_view_simple_to_dict(obs));
                 }
@@ -5504,13 +6742,66 @@ impl FootballMatchSimulator {
                 dict.set("score", score);
 
                 // Clear session on full time
-                *self.live_session.borrow_mut() = None;
+                *self.live_session.lock_or_recover() = None;
             }
         }
 
         dict
     }
 
+    /// Skip to the next displayed event (at most `max_ms` of match time) without
+    /// per-tick snapshots. Returns { ticks_advanced, t_ms, minute, score, events, ... }.
+    #[func]
+    pub fn step_until_next_event(&mut self, max_ms: i64) -> Dictionary {
+        let mut session = self.live_session.lock_or_recover();
+        let result = match session.as_mut() {
+            Some(s) => s.step_until_next_event(max_ms.max(0) as u64),
+            None => Err("No match session active".to_string()),
+        };
+        self.skip_result_to_dict(result)
+    }
+
+    /// Fast-forward the live session to `minute` without per-tick snapshots.
+    /// Stops at half-time (`halftime: true`); resume and call again to cross it.
+    #[func]
+    pub fn fast_forward_to_minute(&mut self, minute: i32) -> Dictionary {
+        let mut session = self.live_session.lock_or_recover();
+        let result = match session.as_mut() {
+            Some(s) => s.fast_forward_to_minute(minute.clamp(0, u8::MAX as i32) as u8),
+            None => Err("No match session active".to_string()),
+        };
+        self.skip_result_to_dict(result)
+    }
+
+    fn skip_result_to_dict(&self, result: Result<SkipResult, String>) -> Dictionary {
+        let mut dict = Dictionary::new();
+        let data = match result {
+            Ok(data) => data,
+            Err(e) => {
+                dict.set("error", GString::from(e));
+                return dict;
+            }
+        };
+        dict.set("ticks_advanced", data.ticks_advanced as i64);
+        dict.set("t_ms", data.timestamp_ms as i64);
+        dict.set("minute", data.minute as i32);
+        dict.set("reached_target", data.stopped_on_target);
+        dict.set("halftime", data.state == LiveMatchState::HalfTimeBreak);
+        dict.set("finished", data.state == LiveMatchState::Finished);
+
+        let mut score = Dictionary::new();
+        score.set("home", data.score.0 as i32);
+        score.set("away", data.score.1 as i32);
+        dict.set("score", score);
+
+        let mut events_array = godot::prelude::Array::<Variant>::new();
+        for event in &data.events {
+            events_array.push(&convert_event_to_dict(event).to_variant());
+        }
+        dict.set("events", events_array);
+        dict
+    }
+
     /// Step the match session with packed format (optimized for performance; internal naming remains `*_live_*`).
     /// Returns Dictionary with PackedFloat32Array for player positions instead of nested Dictionaries.
     /// This reduces Dictionary allocations from 22/tick to 1/tick (88% reduction).
@@ -5558,7 +6849,7 @@ impl FootballMatchSimulator {
 
         // Read TransitionSystem remaining time after stepping (SSOT: engine state).
         let transition_remaining_ms: i32 = {
-            let session = self.live_session.borrow();
+            let session = self.live_session.lock_or_recover();
             session
                 .as_ref()
                 .and_then(|s| s.engine.get_transition_remaining_ms())
@@ -5640,6 +6931,10 @@ impl FootballMatchSimulator {
                     snapshot.set("decision_intents", intents);
                 }
 
+                if let Some(marking) = &data.marking {
+                    snapshot.set("marking", convert_marking_snapshot_to_dict(marking));
+                }
+
                 let mut offside = Dictionary::new();
                 offside.set("home_x", data.offside_lines.home_x);
                 offside.set("away_x", data.offside_lines.away_x);
@@ -5659,6 +6954,24 @@ impl FootballMatchSimulator {
                 }
                 dict.set("events", events_array);
 
+                if !data.audio_cues.is_empty() {
+                    if let Ok(value) = serde_json::to_value(&data.audio_cues) {
+                        dict.set("audio_cues", json_value_to_variant(&value));
+                    }
+                }
+
+                if let Some(p) = &data.win_probability {
+                    if let Ok(value) = serde_json::to_value(p) {
+                        dict.set("win_probability", json_value_to_variant(&value));
+                    }
+                }
+
+                if !data.tactical_insights.is_empty() {
+                    if let Ok(value) = serde_json::to_value(&data.tactical_insights) {
+                        dict.set("tactical_insights", json_value_to_variant(&value));
+                    }
+                }
+
                 if let Some(obs) = &data.team_view_simple {
                     dict.set("team_view_simple", convert_team_view_simple_to_dict(obs));
                 }
@@ -5746,7 +7059,7 @@ impl FootballMatchSimulator {
                 dict.set("score", score);
 
                 // Clear session on full time
-                *self.live_session.borrow_mut() = None;
+                *self.live_session.lock_or_recover() = None;
             }
         }
 
@@ -5797,7 +7110,7 @@ impl FootballMatchSimulator {
 
         // Check if session exists
         {
-            let session = self.live_session.borrow();
+            let session = self.live_session.lock_or_recover();
             if session.is_none() {
                 return GString::from(
                     json!({
@@ -5939,7 +7252,7 @@ impl FootballMatchSimulator {
         let mut dict = Dictionary::new();
 
         // Borrow live_session
-        let session_ref = self.live_session.borrow();
+        let session_ref = self.live_session.lock_or_recover();
         let Some(ref session) = *session_ref else {
             dict.set("error", GString::from("No active session"));
             return dict;
@@ -5990,7 +7303,8 @@ impl FootballMatchSimulator {
     }
 
     /// Match OS v1.2 Priority 5: Get match analysis report from match result JSON
-    /// Returns Dictionary with pattern insights (possession shifts, danger timeline, attack zones, pressure patterns)
+    /// Returns Dictionary with pattern insights (possession shifts, danger timeline, attack zones,
+    /// pressure patterns, win probability, momentum curve)
     #[func]
     pub fn get_match_analysis(&self, match_result_json: GString) -> Dictionary {
         use of_core::engine::analyze_match;
@@ -6059,6 +7373,8 @@ impl FootballMatchSimulator {
             pressure_patterns,
             dsa_summary,
             interpretation_v1,
+            win_probability,
+            momentum_curve,
             generated_at_ms,
         } = report;
 
@@ -6129,6 +7445,30 @@ impl FootballMatchSimulator {
         }
         dict.set("pressure_patterns", patterns);
 
+        // Win probability (per-minute chart overlay)
+        let mut probabilities = Array::new();
+        for point in win_probability {
+            let mut point_dict = Dictionary::new();
+            point_dict.set("minute", point.minute as i32);
+            point_dict.set("home_win", point.home_win);
+            point_dict.set("draw", point.draw);
+            point_dict.set("away_win", point.away_win);
+            probabilities.push(&point_dict.to_variant());
+        }
+        dict.set("win_probability", probabilities);
+
+        // Momentum curve (per-minute momentum graph)
+        let mut momentum = Array::new();
+        for point in momentum_curve {
+            let mut point_dict = Dictionary::new();
+            point_dict.set("minute", point.minute as i32);
+            point_dict.set("home_threat", point.home_threat);
+            point_dict.set("away_threat", point.away_threat);
+            point_dict.set("momentum", point.momentum);
+            momentum.push(&point_dict.to_variant());
+        }
+        dict.set("momentum_curve", momentum);
+
         // DSA v1.1: Optional post-match summary (authoritative).
         if let Some(summary) = dsa_summary {
             fn vec_f32_to_array(values: &[f32]) -> Array<Variant> {
@@ -6311,7 +7651,10 @@ impl FootballMatchSimulator {
             Some(moments) => moments.clone(),
             None => {
                 // Generate from events if not pre-generated
-                of_core::models::generate_best_moments(&match_result.events)
+                of_core::models::generate_best_moments(
+                    &match_result.events,
+                    &of_core::models::HighlightConfig::default(),
+                )
             }
         };
 
@@ -6363,7 +7706,7 @@ impl FootballMatchSimulator {
         let mut dict = Dictionary::new();
 
         // Borrow match session
-        let session_ref = self.live_session.borrow();
+        let session_ref = self.live_session.lock_or_recover();
         let Some(ref session) = *session_ref else {
             dict.set("error", GString::from("No active session"));
             return dict;
@@ -6438,7 +7781,7 @@ impl FootballMatchSimulator {
         let mut positions = PackedVector2Array::new();
 
         // Borrow live_session
-        let session_ref = self.live_session.borrow();
+        let session_ref = self.live_session.lock_or_recover();
         let Some(ref session) = *session_ref else {
             return positions; // Return empty array if no session
         };
@@ -6561,6 +7904,32 @@ impl FootballMatchSimulator {
     // Save/Load API (Binary serialization)
     // ============================================================================
 
+    /// Prune stale match detail and replays (keep last N full replays, summarize older
+    /// matches). `policy_json`: `{ "keep_full_replays": 20, "keep_detailed_matches": 100 }`
+    /// (empty = defaults). With `dry_run` only the report is returned.
+    #[func]
+    pub fn apply_save_retention(&self, policy_json: GString, dry_run: bool) -> GString {
+        use of_core::save::{RetentionPolicy, SaveManager};
+
+        let policy_str = policy_json.to_string();
+        let policy: RetentionPolicy = if policy_str.trim().is_empty() {
+            RetentionPolicy::default()
+        } else {
+            match serde_json::from_str(&policy_str) {
+                Ok(policy) => policy,
+                Err(e) => {
+                    return self
+                        .create_error_response(&format!("Invalid policy: {}", e), "PARSE_ERROR")
+                }
+            }
+        };
+
+        match SaveManager::apply_retention(&policy, dry_run) {
+            Ok(report) => GString::from(json!({ "success": true, "report": report }).to_string()),
+            Err(e) => self.create_error_response(&e.to_string(), "SAVE_ERROR"),
+        }
+    }
+
     /// Save game state to binary format (MessagePack + LZ4 + SHA256)
     #[func]
     pub fn save_game_binary(&self, save_data_json: GString) -> PackedByteArray {
@@ -6739,7 +8108,7 @@ impl FootballMatchSimulator {
         }
 
         // Get the match session and submit command
-        let mut session = self.live_session.borrow_mut();
+        let mut session = self.live_session.lock_or_recover();
         match session.as_mut() {
             Some(s) => {
                 s.submit_user_command(cmd.clone());
@@ -6772,7 +8141,7 @@ impl FootballMatchSimulator {
             }
         };
 
-        let mut session = self.live_session.borrow_mut();
+        let mut session = self.live_session.lock_or_recover();
         match session.as_mut() {
             Some(s) => match s.set_sticky_action(track_id as usize, action, enabled) {
                 Ok(()) => GString::from(
@@ -6790,6 +8159,205 @@ impl FootballMatchSimulator {
         }
     }
 
+    /// Direct avatar control applied every tick: movement, sprint and a one-shot
+    /// action ("pass"/"shoot"/"tackle", or "" for none).
+    #[func]
+    pub fn set_user_player_input(
+        &mut self,
+        track_id: i32,
+        move_dir: Vector2,
+        sprint: bool,
+        action: GString,
+    ) -> GString {
+        use of_core::engine::match_sim::UserInputAction;
+
+        let action_str = action.to_string().to_lowercase();
+        let action = match UserInputAction::parse(&action_str) {
+            Ok(a) => a,
+            Err(e) => return self.create_error_response(&e, "INVALID_ACTION"),
+        };
+        if track_id < 0 {
+            return self.create_error_response("Invalid track_id", "INVALID_TRACK_ID");
+        }
+
+        let mut session = self.live_session.lock_or_recover();
+        match session.as_mut() {
+            Some(s) => {
+                let dir = (move_dir.x, move_dir.y);
+                match s.set_user_player_input(track_id as usize, dir, sprint, action) {
+                    Ok(()) => GString::from(
+                        json!({
+                            "success": true,
+                            "track_id": track_id,
+                            "move_dir": [dir.0, dir.1],
+                            "sprint": sprint,
+                            "action": action
+                        })
+                        .to_string(),
+                    ),
+                    Err(e) => self.create_error_response(e, "INPUT_ERROR"),
+                }
+            }
+            None => self.create_error_response("No match session active", "NO_SESSION"),
+        }
+    }
+
+    /// Lockstep multiplayer: keep rollback snapshots (last `rollback_ticks`, <= 0 = default)
+    /// and per-tick checksums for the live session. Call after kick-off.
+    #[func]
+    pub fn enable_lockstep(&mut self, rollback_ticks: i32) -> GString {
+        use of_core::engine::netcode::DEFAULT_ROLLBACK_TICKS;
+
+        let ticks =
+            if rollback_ticks > 0 { rollback_ticks as usize } else { DEFAULT_ROLLBACK_TICKS };
+        let mut session = self.live_session.lock_or_recover();
+        match session.as_mut() {
+            Some(s) => {
+                s.enable_lockstep(ticks);
+                GString::from(
+                    json!({ "success": true, "rollback_ticks": ticks, "tick": s.ticks_played() })
+                        .to_string(),
+                )
+            }
+            None => self.create_error_response("No match session active", "NO_SESSION"),
+        }
+    }
+
+    /// Apply every peer's input frame for the next tick (JSON array of `InputFrame`).
+    #[func]
+    pub fn apply_input_frames(&mut self, frames_json: GString) -> GString {
+        use of_core::engine::InputFrame;
+
+        let frames: Vec<InputFrame> = match serde_json::from_str(&frames_json.to_string()) {
+            Ok(f) => f,
+            Err(e) => {
+                return self.create_error_response(
+                    &format!("Invalid input frames JSON: {}", e),
+                    "PARSE_ERROR",
+                )
+            }
+        };
+
+        let mut session = self.live_session.lock_or_recover();
+        match session.as_mut() {
+            Some(s) => match s.apply_input_frames(&frames) {
+                Ok(input_hash) => GString::from(
+                    json!({ "success": true, "tick": s.ticks_played(), "input_hash": input_hash })
+                        .to_string(),
+                ),
+                Err(e) => self.create_error_response(&e, "INPUT_ERROR"),
+            },
+            None => self.create_error_response("No match session active", "NO_SESSION"),
+        }
+    }
+
+    /// Checksum of the last executed tick, to send to the peer (`TickChecksum` JSON).
+    #[func]
+    pub fn get_tick_checksum(&self) -> GString {
+        let session = self.live_session.lock_or_recover();
+        match session.as_ref() {
+            Some(s) => match s.last_tick_checksum() {
+                Some(checksum) => GString::from(json!(checksum).to_string()),
+                None => self.create_error_response("No lockstep tick recorded", "NO_CHECKSUM"),
+            },
+            None => self.create_error_response("No match session active", "NO_SESSION"),
+        }
+    }
+
+    /// Compare the peer's `TickChecksum` JSON with ours; `in_sync: false` carries the desync.
+    #[func]
+    pub fn verify_remote_checksum(&self, checksum_json: GString) -> GString {
+        use of_core::engine::TickChecksum;
+
+        let remote: TickChecksum = match serde_json::from_str(&checksum_json.to_string()) {
+            Ok(c) => c,
+            Err(e) => {
+                return self.create_error_response(
+                    &format!("Invalid checksum JSON: {}", e),
+                    "PARSE_ERROR",
+                )
+            }
+        };
+
+        let session = self.live_session.lock_or_recover();
+        match session.as_ref() {
+            Some(s) => match s.verify_remote_checksum(&remote) {
+                Ok(()) => GString::from(json!({ "success": true, "in_sync": true }).to_string()),
+                Err(desync) => GString::from(
+                    json!({
+                        "success": true,
+                        "in_sync": false,
+                        "input_mismatch": desync.is_input_mismatch(),
+                        "desync": desync,
+                        "message": desync.to_string()
+                    })
+                    .to_string(),
+                ),
+            },
+            None => self.create_error_response("No match session active", "NO_SESSION"),
+        }
+    }
+
+    /// Roll the live session back to the boundary before `tick` (re-apply frames after).
+    #[func]
+    pub fn rollback_to_tick(&mut self, tick: i64) -> GString {
+        if tick < 0 {
+            return self.create_error_response("Invalid tick", "INVALID_TICK");
+        }
+        let mut session = self.live_session.lock_or_recover();
+        match session.as_mut() {
+            Some(s) => match s.rollback_to_tick(tick as u64) {
+                Ok(()) => GString::from(json!({ "success": true, "tick": tick }).to_string()),
+                Err(e) => self.create_error_response(&e, "ROLLBACK_ERROR"),
+            },
+            None => self.create_error_response("No match session active", "NO_SESSION"),
+        }
+    }
+
+    /// Keep rewind snapshots every `interval_ms` of match time (newest `max_snapshots`).
+    /// Call after kick-off.
+    #[func]
+    pub fn enable_live_rewind(&mut self, interval_ms: i64, max_snapshots: i32) -> GString {
+        if interval_ms <= 0 || max_snapshots <= 0 {
+            return self
+                .create_error_response("Interval and snapshot count must be > 0", "INVALID_ARG");
+        }
+        let mut session = self.live_session.lock_or_recover();
+        match session.as_mut() {
+            Some(s) => {
+                s.enable_rewind(interval_ms as u64, max_snapshots as usize);
+                GString::from(
+                    json!({ "success": true, "window_ms": s.rewind_window_ms() }).to_string(),
+                )
+            }
+            None => self.create_error_response("No match session active", "NO_SESSION"),
+        }
+    }
+
+    /// Rewind the live session to match time `t_ms` ("retry this highlight").
+    #[func]
+    pub fn rewind_live_match_to_ms(&mut self, t_ms: i64) -> GString {
+        if t_ms < 0 {
+            return self.create_error_response("Invalid timestamp", "INVALID_ARG");
+        }
+        let mut session = self.live_session.lock_or_recover();
+        match session.as_mut() {
+            Some(s) => match s.rewind_to_ms(t_ms as u64) {
+                Ok(landed_ms) => GString::from(
+                    json!({
+                        "success": true,
+                        "timestamp_ms": landed_ms,
+                        "tick": s.ticks_played(),
+                        "window_ms": s.rewind_window_ms()
+                    })
+                    .to_string(),
+                ),
+                Err(e) => self.create_error_response(&e, "REWIND_ERROR"),
+            },
+            None => self.create_error_response("No match session active", "NO_SESSION"),
+        }
+    }
+
     /// Register a controller slot for multi-agent control
     #[func]
     pub fn register_controller_slot(
@@ -6813,7 +8381,7 @@ impl FootballMatchSimulator {
             }
         };
 
-        let mut session = self.live_session.borrow_mut();
+        let mut session = self.live_session.lock_or_recover();
         match session.as_mut() {
             Some(s) => {
                 match s.register_controller_slot(controller_id as u32, team_side, player_slot as u8)
@@ -6840,7 +8408,7 @@ impl FootballMatchSimulator {
         if controller_id < 0 {
             return self.create_error_response("Negative controller_id", "INVALID_ARG");
         }
-        let mut session = self.live_session.borrow_mut();
+        let mut session = self.live_session.lock_or_recover();
         match session.as_mut() {
             Some(s) => match s.unregister_controller_slot(controller_id as u32) {
                 Ok(()) => GString::from(
@@ -6859,7 +8427,7 @@ impl FootballMatchSimulator {
     /// Clear all controller slots
     #[func]
     pub fn clear_controller_slots(&mut self) -> GString {
-        let mut session = self.live_session.borrow_mut();
+        let mut session = self.live_session.lock_or_recover();
         match session.as_mut() {
             Some(s) => {
                 s.clear_controller_slots();
@@ -6915,7 +8483,7 @@ impl FootballMatchSimulator {
         };
 
         let count = commands.len();
-        let mut session = self.live_session.borrow_mut();
+        let mut session = self.live_session.lock_or_recover();
         match session.as_mut() {
             Some(s) => match s.submit_multi_agent_commands(commands) {
                 Ok(()) => GString::from(
@@ -6934,7 +8502,7 @@ impl FootballMatchSimulator {
     /// Enable Career Player Mode for a specific track_id
     #[func]
     pub fn enable_career_mode(&mut self, track_id: i32) -> GString {
-        let mut session = self.live_session.borrow_mut();
+        let mut session = self.live_session.lock_or_recover();
         match session.as_mut() {
             Some(s) => {
                 s.enable_controlled_mode(track_id as usize);
@@ -6954,7 +8522,7 @@ impl FootballMatchSimulator {
     /// Disable Career Player Mode
     #[func]
     pub fn disable_career_mode(&mut self) -> GString {
-        let mut session = self.live_session.borrow_mut();
+        let mut session = self.live_session.lock_or_recover();
         match session.as_mut() {
             Some(s) => {
                 s.disable_controlled_mode();
@@ -7109,12 +8677,21 @@ fn encode_player_delta(frames: &[PlayerFrameCompressed]) -> Vec<DeltaFrame> {
 
 impl FootballMatchSimulator {
     fn simulate_match_inner(&self, json: GString) -> GString {
-        match simulate_match_json(&json.to_string()) {
+        self.simulate_match_cancellable(json, &self.current_cancel_token())
+    }
+
+    fn simulate_match_cancellable(&self, json: GString, cancel: &CancellationToken) -> GString {
+        match simulate_match_json_cancellable(&json.to_string(), cancel) {
             Ok(res) => GString::from(res),
             Err(e) => GString::from(format!(r#"{{"error": "{}"}}"#, e)),
         }
     }
 
+    /// Token for a simulation starting now (cancelled by `cancel_simulations`)
+    fn current_cancel_token(&self) -> CancellationToken {
+        self.cancel_token.lock_or_recover().clone()
+    }
+
     fn create_error_response(&self, msg: &str, code: &str) -> GString {
         GString::from(json!({ "error": true, "message": msg, "code": code }).to_string())
     }
@@ -7296,8 +8873,15 @@ mod mrq0_end_to_end_tests {
         assert_eq!(away_inst.build_up_style, BuildUpStyle::Direct);
         assert!(!away_inst.use_offside_trap);
 
-        assert!((plan.home_match_modifiers.shot_power_mult - 1.2).abs() < 1e-6);
-        assert!((plan.away_match_modifiers.pass_success_mult - 1.1).abs() < 1e-6);
+        let (home_mods, _) = plan.modifier_stack.resolve(true);
+        let (away_mods, _) = plan.modifier_stack.resolve(false);
+        assert!((home_mods.shot_power_mult - 1.2).abs() < 1e-6);
+        assert!((away_mods.pass_success_mult - 1.1).abs() < 1e-6);
+        assert!(plan
+            .modifier_stack
+            .entries()
+            .iter()
+            .all(|e| e.source == of_core::engine::ModifierSource::Deck));
     }
 
     #[test]
@@ -7327,7 +8911,77 @@ mod mrq0_end_to_end_tests {
         let plan = FootballMatchSimulator::decode_mrq0_to_match_plan(&data).expect("decode MRQ0 v3");
 
         assert_eq!(plan.seed, 99_999);
-        assert!((plan.home_match_modifiers.shot_power_mult - 1.2).abs() < 1e-6);
-        assert!((plan.away_match_modifiers.pass_success_mult - 1.1).abs() < 1e-6);
+        let (home_mods, _) = plan.modifier_stack.resolve(true);
+        let (away_mods, _) = plan.modifier_stack.resolve(false);
+        assert!((home_mods.shot_power_mult - 1.2).abs() < 1e-6);
+        assert!((away_mods.pass_success_mult - 1.1).abs() < 1e-6);
+        assert!(plan
+            .modifier_stack
+            .entries()
+            .iter()
+            .all(|e| e.source == of_core::engine::ModifierSource::Deck));
+    }
+
+    /// v5 team block: first player carries a full attribute block, the rest
+    /// are bare (no UID, no attributes) and fall back to the proxy path.
+    fn push_team_v5(buf: &mut Vec<u8>, team_name: &str, attrs: &[u8; 36]) {
+        push_str_u16(buf, team_name);
+        push_str_u16(buf, "4-4-2");
+
+        let pos_codes: [u8; 11] = [0, 1, 2, 2, 3, 9, 7, 7, 10, 14, 14];
+        push_u8(buf, pos_codes.len() as u8);
+        for (i, pos_code) in pos_codes.into_iter().enumerate() {
+            push_str_u16(buf, &format!("{team_name}_{i}"));
+            push_u8(buf, pos_code);
+            push_u8(buf, 60); // overall
+            push_u8(buf, if i == 0 { 5 } else { 3 }); // condition
+            push_str_u16(buf, ""); // uid (none)
+            if i == 0 {
+                push_u8(buf, 0b0000_0001); // attr_flags: full block
+                buf.extend_from_slice(attrs);
+            } else {
+                push_u8(buf, 0);
+            }
+        }
+    }
+
+    #[test]
+    fn mrq0_v5_full_attribute_block_matches_json_embedded_attributes() {
+        use of_core::api::EmbeddedPlayerAttributes;
+
+        let mut attrs = [0u8; 36];
+        for (i, v) in attrs.iter_mut().enumerate() {
+            *v = 30 + i as u8;
+        }
+
+        let mut data: Vec<u8> = Vec::new();
+        push_u32_le(&mut data, MRQ0_MAGIC);
+        push_u32_le(&mut data, 5); // version
+        push_u64_le(&mut data, 7); // seed
+        push_u8(&mut data, 0); // use_vendor_engine
+        push_u16_le(&mut data, 100); // position_sample_rate_ms
+
+        push_team_v5(&mut data, "Home", &attrs);
+        push_instructions(&mut data, 2, 2, 2, 2, 1, false);
+        push_team_v5(&mut data, "Away", &attrs);
+        push_instructions(&mut data, 2, 2, 2, 2, 1, false);
+        data.push(0); // no match modifiers
+
+        let plan = FootballMatchSimulator::decode_mrq0_to_match_plan(&data).expect("decode MRQ0 v5");
+
+        let expected = EmbeddedPlayerAttributes::from_ordered(attrs).to_player_attributes();
+        let gk = &plan.home_team.players[0];
+        assert_eq!(gk.condition, 5);
+        assert_eq!(gk.attributes.as_ref(), Some(&expected));
+        assert_eq!(plan.away_team.players[0].attributes.as_ref(), Some(&expected));
+
+        // Bare entries keep the v1..v4 proxy derivation.
+        let lb = &plan.home_team.players[1];
+        assert_eq!(lb.condition, 3);
+        assert_ne!(lb.attributes.as_ref(), Some(&expected));
+
+        // A truncated v5 team block is an error, not a silent proxy fallback.
+        let cut = data.len() - 200;
+        assert!(FootballMatchSimulator::decode_mrq0_to_match_plan(&data[..cut]).is_err());
     }
 }
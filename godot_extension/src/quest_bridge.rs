@@ -324,7 +324,7 @@ impl QuestBridge {
     /// 목표 타입별 전체 업데이트 (이벤트 훅용)
     ///
     /// # Arguments
-    /// * `objective_type` - "win", "train", "stat", "event"
+    /// * `objective_type` - "win", "train", "stat", "event", "assist", "key_pass"
     /// * `value` - 추가할 진행값
     #[func]
     pub fn update_all_by_type(&self, objective_type: GString, value: i32) -> GString {
@@ -334,6 +334,8 @@ impl QuestBridge {
             "train" => ObjectiveType::Train,
             "stat" => ObjectiveType::Stat,
             "event" => ObjectiveType::Event,
+            "assist" => ObjectiveType::Assist,
+            "key_pass" => ObjectiveType::KeyPass,
             _ => {
                 return self
                     .create_error_response(&format!("Invalid type: {}", type_str), "INVALID_TYPE");
@@ -618,6 +620,8 @@ impl QuestBridge {
                     "win" => ObjectiveType::Win,
                     "train" => ObjectiveType::Train,
                     "stat" => ObjectiveType::Stat,
+                    "assist" => ObjectiveType::Assist,
+                    "key_pass" => ObjectiveType::KeyPass,
                     _ => ObjectiveType::Event,
                 };
 
@@ -0,0 +1,75 @@
+//! Poison-tolerant locking for simulator state
+//!
+//! `FootballMatchSimulator` can be called from Godot worker threads (threaded
+//! job queue, background analysis), so its interior state lives behind
+//! `Mutex`/`RwLock` instead of `RefCell`.
+//!
+//! A panic while a lock is held poisons it. The panic is already reported by
+//! the panic hook and every guarded value is left in a consistent (if stale)
+//! state by the code that mutates it, so the simulator recovers the guard and
+//! clears the poison instead of failing every later call.
+
+use std::sync::{Mutex, MutexGuard, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+pub(crate) trait MutexExt<T> {
+    /// Lock, recovering the guard if a previous holder panicked
+    fn lock_or_recover(&self) -> MutexGuard<'_, T>;
+}
+
+impl<T> MutexExt<T> for Mutex<T> {
+    fn lock_or_recover(&self) -> MutexGuard<'_, T> {
+        self.lock().unwrap_or_else(|poisoned| {
+            self.clear_poison();
+            PoisonError::into_inner(poisoned)
+        })
+    }
+}
+
+pub(crate) trait RwLockExt<T> {
+    /// Shared access, recovering the guard if a previous writer panicked
+    fn read_or_recover(&self) -> RwLockReadGuard<'_, T>;
+    /// Exclusive access, recovering the guard if a previous writer panicked
+    fn write_or_recover(&self) -> RwLockWriteGuard<'_, T>;
+}
+
+impl<T> RwLockExt<T> for RwLock<T> {
+    fn read_or_recover(&self) -> RwLockReadGuard<'_, T> {
+        self.read().unwrap_or_else(|poisoned| {
+            self.clear_poison();
+            PoisonError::into_inner(poisoned)
+        })
+    }
+
+    fn write_or_recover(&self) -> RwLockWriteGuard<'_, T> {
+        self.write().unwrap_or_else(|poisoned| {
+            self.clear_poison();
+            PoisonError::into_inner(poisoned)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_poisoned_locks_recover() {
+        let mutex = Arc::new(Mutex::new(1));
+        let rwlock = Arc::new(RwLock::new(1));
+        let (m, r) = (Arc::clone(&mutex), Arc::clone(&rwlock));
+        let _ = std::thread::spawn(move || {
+            let _m = m.lock().unwrap();
+            let _r = r.write().unwrap();
+            panic!("poison both locks");
+        })
+        .join();
+        assert!(mutex.is_poisoned() && rwlock.is_poisoned());
+
+        *mutex.lock_or_recover() += 1;
+        *rwlock.write_or_recover() += 1;
+        assert_eq!(*mutex.lock_or_recover(), 2);
+        assert_eq!(*rwlock.read_or_recover(), 2);
+        assert!(!mutex.is_poisoned() && !rwlock.is_poisoned());
+    }
+}